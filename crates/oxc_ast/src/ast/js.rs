@@ -78,6 +78,7 @@ pub enum Expression<'a> {
     UpdateExpression(Box<'a, UpdateExpression<'a>>),
     YieldExpression(Box<'a, YieldExpression<'a>>),
     PrivateInExpression(Box<'a, PrivateInExpression<'a>>),
+    V8IntrinsicExpression(Box<'a, V8IntrinsicExpression<'a>>),
 
     JSXElement(Box<'a, JSXElement<'a>>),
     JSXFragment(Box<'a, JSXFragment<'a>>),
@@ -549,6 +550,14 @@ impl<'a> MemberExpression<'a> {
         }
     }
 
+    pub fn object_mut(&mut self) -> &mut Expression<'a> {
+        match self {
+            MemberExpression::ComputedMemberExpression(expr) => &mut expr.object,
+            MemberExpression::StaticMemberExpression(expr) => &mut expr.object,
+            MemberExpression::PrivateFieldExpression(expr) => &mut expr.object,
+        }
+    }
+
     pub fn static_property_name(&self) -> Option<&str> {
         match self {
             MemberExpression::ComputedMemberExpression(expr) => match &expr.expression {
@@ -1848,6 +1857,7 @@ pub struct AccessorProperty<'a> {
     pub value: Option<Expression<'a>>,
     pub computed: bool,
     pub r#static: bool,
+    pub decorators: Vec<'a, Decorator<'a>>,
 }
 
 #[derive(Debug, Hash)]
@@ -1859,6 +1869,17 @@ pub struct ImportExpression<'a> {
     pub arguments: Vec<'a, Expression<'a>>,
 }
 
+/// V8 runtime call, e.g. `%DebugPrint(x)`, accepted with `Parser::allow_v8_intrinsics`
+#[derive(Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
+pub struct V8IntrinsicExpression<'a> {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub span: Span,
+    /// The name without `%`
+    pub name: IdentifierName,
+    pub arguments: Vec<'a, Argument<'a>>,
+}
+
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
 pub struct ImportDeclaration<'a> {
@@ -1866,8 +1887,8 @@ pub struct ImportDeclaration<'a> {
     pub span: Span,
     pub specifiers: Vec<'a, ImportDeclarationSpecifier>,
     pub source: StringLiteral,
-    pub assertions: Option<Vec<'a, ImportAttribute>>, // Some(vec![]) for empty assertion
-    pub import_kind: ImportOrExportKind,              // `import type { foo } from 'bar'`
+    pub with_clause: Option<WithClause<'a>>,
+    pub import_kind: ImportOrExportKind, // `import type { foo } from 'bar'`
 }
 
 #[derive(Debug, Hash)]
@@ -1891,6 +1912,7 @@ pub struct ImportSpecifier {
     pub span: Span,
     pub imported: ModuleExportName,
     pub local: BindingIdentifier,
+    pub import_kind: ImportOrExportKind, // `import { type foo } from 'bar'`
 }
 
 // import local from "source"
@@ -1911,6 +1933,17 @@ pub struct ImportNamespaceSpecifier {
     pub local: BindingIdentifier,
 }
 
+/// [Import Attributes](https://tc39.es/proposal-import-attributes), e.g. `with { type: "json" }`
+#[derive(Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
+pub struct WithClause<'a> {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub span: Span,
+    /// `with`, or `assert` of the legacy [Import Assertions](https://tc39.es/proposal-import-assertions)
+    pub attributes_keyword: IdentifierName,
+    pub with_entries: Vec<'a, ImportAttribute>,
+}
+
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct ImportAttribute {
@@ -1944,6 +1977,7 @@ pub struct ExportNamedDeclaration<'a> {
     pub declaration: Option<Declaration<'a>>,
     pub specifiers: Vec<'a, ExportSpecifier>,
     pub source: Option<StringLiteral>,
+    pub with_clause: Option<WithClause<'a>>,
     pub export_kind: ImportOrExportKind, // `export type { foo }`
 }
 
@@ -1976,8 +2010,8 @@ pub struct ExportAllDeclaration<'a> {
     pub span: Span,
     pub exported: Option<ModuleExportName>,
     pub source: StringLiteral,
-    pub assertions: Option<Vec<'a, ImportAttribute>>, // Some(vec![]) for empty assertion
-    pub export_kind: ImportOrExportKind,              // `export type *`
+    pub with_clause: Option<WithClause<'a>>,
+    pub export_kind: ImportOrExportKind, // `export type *`
 }
 
 impl<'a> ExportAllDeclaration<'a> {
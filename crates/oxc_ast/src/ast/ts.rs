@@ -870,6 +870,10 @@ impl<'a> Modifiers<'a> {
             .as_ref()
             .map_or(false, |modifiers| modifiers.iter().any(|modifier| modifier.kind == target))
     }
+
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, Modifier> {
+        self.0.as_mut().map_or(&mut [][..], |modifiers| modifiers.as_mut_slice()).iter_mut()
+    }
 }
 
 /// Export Assignment in non-module files
@@ -507,6 +507,19 @@ impl<'a> AstBuilder<'a> {
         Expression::ImportExpression(self.alloc(ImportExpression { span, source, arguments }))
     }
 
+    pub fn v8_intrinsic_expression(
+        &self,
+        span: Span,
+        name: IdentifierName,
+        arguments: Vec<'a, Argument<'a>>,
+    ) -> Expression<'a> {
+        Expression::V8IntrinsicExpression(self.alloc(V8IntrinsicExpression {
+            span,
+            name,
+            arguments,
+        }))
+    }
+
     pub fn logical_expression(
         &self,
         span: Span,
@@ -840,6 +853,29 @@ impl<'a> AstBuilder<'a> {
         }))
     }
 
+    pub fn class_method(
+        &self,
+        span: Span,
+        key: PropertyKey<'a>,
+        value: Box<'a, Function<'a>>,
+        kind: MethodDefinitionKind,
+        computed: bool,
+        r#static: bool,
+    ) -> ClassElement<'a> {
+        ClassElement::MethodDefinition(self.alloc(MethodDefinition {
+            span,
+            key,
+            value,
+            kind,
+            computed,
+            r#static,
+            r#override: false,
+            optional: false,
+            accessibility: None,
+            decorators: self.new_vec(),
+        }))
+    }
+
     pub fn accessor_property(
         &self,
         span: Span,
@@ -847,6 +883,7 @@ impl<'a> AstBuilder<'a> {
         value: Option<Expression<'a>>,
         computed: bool,
         r#static: bool,
+        decorators: Vec<'a, Decorator<'a>>,
     ) -> ClassElement<'a> {
         ClassElement::AccessorProperty(self.alloc(AccessorProperty {
             span,
@@ -854,6 +891,7 @@ impl<'a> AstBuilder<'a> {
             value,
             computed,
             r#static,
+            decorators,
         }))
     }
 
@@ -977,10 +1015,10 @@ impl<'a> AstBuilder<'a> {
         span: Span,
         specifiers: Vec<'a, ImportDeclarationSpecifier>,
         source: StringLiteral,
-        assertions: Option<Vec<'a, ImportAttribute>>,
+        with_clause: Option<WithClause<'a>>,
         import_kind: ImportOrExportKind,
     ) -> Box<'a, ImportDeclaration<'a>> {
-        self.alloc(ImportDeclaration { span, specifiers, source, assertions, import_kind })
+        self.alloc(ImportDeclaration { span, specifiers, source, with_clause, import_kind })
     }
 
     pub fn export_all_declaration(
@@ -988,10 +1026,10 @@ impl<'a> AstBuilder<'a> {
         span: Span,
         exported: Option<ModuleExportName>,
         source: StringLiteral,
-        assertions: Option<Vec<'a, ImportAttribute>>,
+        with_clause: Option<WithClause<'a>>,
         export_kind: ImportOrExportKind,
     ) -> Box<'a, ExportAllDeclaration<'a>> {
-        self.alloc(ExportAllDeclaration { span, exported, source, assertions, export_kind })
+        self.alloc(ExportAllDeclaration { span, exported, source, with_clause, export_kind })
     }
 
     pub fn export_default_declaration(
@@ -1009,9 +1047,17 @@ impl<'a> AstBuilder<'a> {
         declaration: Option<Declaration<'a>>,
         specifiers: Vec<'a, ExportSpecifier>,
         source: Option<StringLiteral>,
+        with_clause: Option<WithClause<'a>>,
         export_kind: ImportOrExportKind,
     ) -> Box<'a, ExportNamedDeclaration<'a>> {
-        self.alloc(ExportNamedDeclaration { span, declaration, specifiers, source, export_kind })
+        self.alloc(ExportNamedDeclaration {
+            span,
+            declaration,
+            specifiers,
+            source,
+            with_clause,
+            export_kind,
+        })
     }
 
     /* ---------- JSX ----------------- */
@@ -0,0 +1,365 @@
+//! [ESTree](https://github.com/estree/estree) compatible serialization of the AST
+//!
+//! The AST is serialized by its `serde` implementation, which is then mapped to the ESTree shape:
+//! * `BindingIdentifier`, `IdentifierReference`, `IdentifierName` and `LabelIdentifier` are `Identifier`
+//! * the literals are `Literal`, with their `raw` text
+//! * the member expressions are `MemberExpression`, and the assignment targets are patterns
+//! * the directives are the first statements of the bodies, and `FunctionBody` is `BlockStatement`
+//! * the parentheses are removed
+//! * `start` and `end` are UTF-16 offsets, with their lines and columns in `loc`, as in JavaScript
+//!
+//! The TypeScript nodes follow [typescript-estree](https://typescript-eslint.io/packages/typescript-estree),
+//! and the JSX nodes the [JSX AST](https://github.com/facebook/jsx/blob/main/AST.md).
+
+use oxc_span::{LineCol, LineIndex};
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+
+use crate::{ast::Program, serialize::EcmaFormatter};
+
+impl<'a> Program<'a> {
+    /// The ESTree shape of the program, `source_text` is the parsed source text
+    ///
+    /// # Panics
+    pub fn to_estree(&self, source_text: &str) -> Value {
+        let estree = ESTree::new(source_text);
+        let mut program = estree.convert(serde_json::to_value(self).unwrap());
+        estree.locate(&mut program);
+        program
+    }
+
+    /// The ESTree JSON of the program, `source_text` is the parsed source text
+    ///
+    /// # Panics
+    pub fn to_estree_json(&self, source_text: &str) -> String {
+        let mut ser = serde_json::Serializer::with_formatter(vec![], EcmaFormatter);
+        self.to_estree(source_text).serialize(&mut ser).unwrap();
+        String::from_utf8(ser.into_inner()).unwrap()
+    }
+}
+
+struct ESTree<'s> {
+    source_text: &'s str,
+    line_index: LineIndex,
+}
+
+impl<'s> ESTree<'s> {
+    fn new(source_text: &'s str) -> Self {
+        Self { source_text, line_index: LineIndex::new(source_text) }
+    }
+
+    fn convert(&self, value: Value) -> Value {
+        match value {
+            Value::Array(items) => items.into_iter().map(|item| self.convert(item)).collect(),
+            Value::Object(node) => self.convert_node(node),
+            value => value,
+        }
+    }
+
+    fn convert_node(&self, node: Map<String, Value>) -> Value {
+        let mut converted = Map::new();
+        for (key, value) in node {
+            match (key.as_str(), value) {
+                ("trailing_comma", _) => {}
+                ("span", Value::Object(span)) => converted.extend(span),
+                (_, value) => {
+                    converted.insert(camel_case(&key), self.convert(value));
+                }
+            }
+        }
+        let kind = match converted.get("type") {
+            Some(Value::String(kind)) => kind.clone(),
+            _ => return Value::Object(converted),
+        };
+        // A unit variant, e.g. `{ "type": "value" }` of `importKind`
+        if converted.len() == 1 && kind.starts_with(|c: char| c.is_ascii_lowercase()) {
+            return Value::String(kind);
+        }
+        self.convert_kind(&kind, converted)
+    }
+
+    #[allow(clippy::too_many_lines)]
+    fn convert_kind(&self, kind: &str, mut node: Map<String, Value>) -> Value {
+        // The TypeScript modifiers are flags, e.g. `abstract: true`
+        if let Some(Value::Array(modifiers)) = node.remove("modifiers") {
+            for modifier in modifiers {
+                if let Some(Value::String(kind)) = modifier.get("kind") {
+                    node.entry(kind.clone()).or_insert(Value::Bool(true));
+                }
+            }
+        }
+        let rename = match kind {
+            "Program" => {
+                let module_kind = node.get("sourceType").and_then(|t| t.get("moduleKind")).cloned();
+                node.insert("sourceType".into(), module_kind.unwrap_or(Value::Null));
+                node.remove("hashbang");
+                prepend_directives(&mut node);
+                None
+            }
+            "Directive" => Some("ExpressionStatement"),
+            "FunctionBody" => {
+                let statements = node.remove("statements").unwrap_or_default();
+                node.insert("body".into(), statements);
+                prepend_directives(&mut node);
+                Some("BlockStatement")
+            }
+            "BindingIdentifier" | "IdentifierReference" | "IdentifierName" | "LabelIdentifier" => {
+                Some("Identifier")
+            }
+            "BooleanLiteral" | "NullLiteral" | "NumberLiteral" | "StringLiteral"
+            | "BigintLiteral" | "RegExpLiteral" => {
+                if kind == "BigintLiteral" {
+                    let value = node.insert("value".into(), Value::Null);
+                    let bigint =
+                        value.as_ref().and_then(Value::as_str).map(|v| v.trim_end_matches('n'));
+                    node.insert("bigint".into(), bigint.into());
+                } else if kind == "NullLiteral" {
+                    node.insert("value".into(), Value::Null);
+                }
+                node.insert("raw".into(), self.raw(&node));
+                Some("Literal")
+            }
+            "JSXText" => {
+                node.insert("raw".into(), self.raw(&node));
+                None
+            }
+            "ParenthesizedExpression" => return node.remove("expression").unwrap_or_default(),
+            "UnaryExpression" => {
+                node.insert("prefix".into(), Value::Bool(true));
+                None
+            }
+            "StaticMemberExpression" | "ComputedMemberExpression" | "PrivateFieldExpression" => {
+                if let Some(property) = node.remove("expression").or_else(|| node.remove("field")) {
+                    node.insert("property".into(), property);
+                }
+                node.insert("computed".into(), (kind == "ComputedMemberExpression").into());
+                Some("MemberExpression")
+            }
+            "ArrowExpression" => {
+                if node.get("expression") == Some(&Value::Bool(true)) {
+                    let expression = node
+                        .get_mut("body")
+                        .and_then(|body| body.pointer_mut("/body/0/expression"))
+                        .map(Value::take);
+                    node.insert("body".into(), expression.unwrap_or_default());
+                }
+                Some("ArrowFunctionExpression")
+            }
+            "ArrayExpression" => {
+                // The holes are `null`
+                if let Some(Value::Array(elements)) = node.get_mut("elements") {
+                    for element in
+                        elements.iter_mut().filter(|element| element.get("type").is_none())
+                    {
+                        *element = Value::Null;
+                    }
+                }
+                None
+            }
+            "BindingPattern" => {
+                let Some(Value::Object(mut pattern)) = node.remove("kind") else {
+                    return Value::Null;
+                };
+                if let Some(type_annotation) =
+                    node.remove("typeAnnotation").filter(|t| !t.is_null())
+                {
+                    pattern.insert("typeAnnotation".into(), type_annotation);
+                }
+                if node.get("optional") == Some(&Value::Bool(true)) {
+                    pattern.insert("optional".into(), Value::Bool(true));
+                }
+                return Value::Object(pattern);
+            }
+            "FormalParameter" => {
+                let pattern = node.remove("pattern").unwrap_or_default();
+                let accessibility = node.get("accessibility").filter(|a| !a.is_null());
+                if accessibility.is_none() && node.get("readonly") != Some(&Value::Bool(true)) {
+                    return pattern;
+                }
+                node.insert("parameter".into(), pattern);
+                Some("TSParameterProperty")
+            }
+            "FormalParameters" => {
+                let Some(Value::Array(mut params)) = node.remove("items") else {
+                    return Value::Array(vec![]);
+                };
+                params.extend(node.remove("rest").filter(|rest| !rest.is_null()));
+                return Value::Array(params);
+            }
+            "ObjectPattern" | "ArrayPattern" => {
+                let rest = node.remove("rest").filter(|rest| !rest.is_null());
+                push_rest(&mut node, kind, rest);
+                None
+            }
+            "ObjectAssignmentTarget" | "ArrayAssignmentTarget" => {
+                let rest = node.remove("rest").filter(|rest| !rest.is_null());
+                push_rest(&mut node, kind, rest.map(|rest| self.rest_element(&rest)));
+                Some(if kind == "ObjectAssignmentTarget" {
+                    "ObjectPattern"
+                } else {
+                    "ArrayPattern"
+                })
+            }
+            "AssignmentTargetWithDefault" => {
+                rename_key(&mut node, "binding", "left");
+                rename_key(&mut node, "init", "right");
+                Some("AssignmentPattern")
+            }
+            "BindingProperty" | "ObjectProperty" => {
+                node.remove("init");
+                node.entry("kind").or_insert_with(|| "init".into());
+                node.entry("method").or_insert(Value::Bool(false));
+                Some("Property")
+            }
+            "AssignmentTargetPropertyIdentifier" => {
+                let binding = node.remove("binding").unwrap_or_default();
+                let value = node.remove("init").filter(|init| !init.is_null()).map_or_else(
+                    || binding.clone(),
+                    |init| {
+                        json!({
+                            "type": "AssignmentPattern",
+                            "start": node.get("start"),
+                            "end": node.get("end"),
+                            "left": binding,
+                            "right": init,
+                        })
+                    },
+                );
+                node.insert("key".into(), binding);
+                node.insert("value".into(), value);
+                node.insert("shorthand".into(), Value::Bool(true));
+                node.insert("computed".into(), Value::Bool(false));
+                node.insert("kind".into(), "init".into());
+                node.insert("method".into(), Value::Bool(false));
+                Some("Property")
+            }
+            "AssignmentTargetPropertyProperty" => {
+                let key = node.remove("name").unwrap_or_default();
+                let computed = key.get("start").and_then(Value::as_u64).is_some_and(|start| {
+                    self.source_text[..usize::try_from(start).unwrap_or_default()]
+                        .trim_end()
+                        .ends_with('[')
+                });
+                node.insert("key".into(), key);
+                rename_key(&mut node, "binding", "value");
+                node.insert("shorthand".into(), Value::Bool(false));
+                node.insert("computed".into(), Value::Bool(computed));
+                node.insert("kind".into(), "init".into());
+                node.insert("method".into(), Value::Bool(false));
+                Some("Property")
+            }
+            "ImportDeclaration" | "ExportNamedDeclaration" | "ExportAllDeclaration" => {
+                let attributes = node.remove("withClause").and_then(|mut with_clause| {
+                    with_clause.get_mut("withEntries").map(Value::take)
+                });
+                node.insert("attributes".into(), attributes.unwrap_or_else(|| json!([])));
+                None
+            }
+            "ExportDefaultDeclaration" => {
+                node.remove("exported");
+                None
+            }
+            "TSEnumDeclaration" => {
+                let members = node
+                    .remove("body")
+                    .and_then(|mut body| body.get_mut("members").map(Value::take));
+                node.insert("members".into(), members.unwrap_or_default());
+                None
+            }
+            _ => None,
+        };
+        if let Some(rename) = rename {
+            node.insert("type".into(), rename.into());
+        }
+        Value::Object(node)
+    }
+
+    /// The source text of the node
+    fn raw(&self, node: &Map<String, Value>) -> Value {
+        let offset =
+            |key| node.get(key).and_then(Value::as_u64).and_then(|o| usize::try_from(o).ok());
+        let (Some(start), Some(end)) = (offset("start"), offset("end")) else { return Value::Null };
+        self.source_text.get(start..end).into()
+    }
+
+    /// The `RestElement` of the rest of an assignment target, which spans from the `...`
+    fn rest_element(&self, argument: &Value) -> Value {
+        let end = argument.get("end").cloned();
+        let start = argument
+            .get("start")
+            .and_then(Value::as_u64)
+            .and_then(|start| self.source_text[..usize::try_from(start).ok()?].rfind("..."));
+        json!({ "type": "RestElement", "start": start, "end": end, "argument": argument })
+    }
+
+    /// Replace the byte offsets of the nodes by UTF-16 offsets, and add their `loc`
+    fn locate(&self, value: &mut Value) {
+        match value {
+            Value::Array(items) => items.iter_mut().for_each(|item| self.locate(item)),
+            Value::Object(node) => {
+                node.values_mut().for_each(|value| self.locate(value));
+                if !node.contains_key("type") {
+                    return;
+                }
+                let offset = |key| {
+                    node.get(key).and_then(Value::as_u64).and_then(|o| usize::try_from(o).ok())
+                };
+                let (Some(start), Some(end)) = (offset("start"), offset("end")) else { return };
+                node.insert("start".into(), self.utf16(start).into());
+                node.insert("end".into(), self.utf16(end).into());
+                let loc = json!({ "start": self.position(start), "end": self.position(end) });
+                node.insert("loc".into(), loc);
+            }
+            _ => {}
+        }
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn utf16(&self, offset: usize) -> usize {
+        self.line_index.utf16_offset(offset as u32) as usize
+    }
+
+    /// The line, from 1, and the column in UTF-16 code units, from 0, of the byte offset
+    #[allow(clippy::cast_possible_truncation)]
+    fn position(&self, offset: usize) -> Value {
+        let LineCol { line, col } = self.line_index.line_col_utf16(offset as u32);
+        json!({ "line": line + 1, "column": col })
+    }
+}
+
+/// Move the directives to the start of the `body` statements
+fn prepend_directives(node: &mut Map<String, Value>) {
+    let Some(Value::Array(mut directives)) = node.remove("directives") else { return };
+    if let Some(Value::Array(body)) = node.get_mut("body") {
+        directives.append(body);
+        *body = directives;
+    }
+}
+
+/// Push the `rest` to the `properties` of an object pattern, or the `elements` of an array one
+fn push_rest(node: &mut Map<String, Value>, kind: &str, rest: Option<Value>) {
+    let key = if kind.starts_with("Object") { "properties" } else { "elements" };
+    if let (Some(Value::Array(items)), Some(rest)) = (node.get_mut(key), rest) {
+        items.push(rest);
+    }
+}
+
+fn rename_key(node: &mut Map<String, Value>, from: &str, to: &str) {
+    if let Some(value) = node.remove(from) {
+        node.insert(to.into(), value);
+    }
+}
+
+/// `import_kind` to `importKind`
+fn camel_case(key: &str) -> String {
+    let mut words = key.split('_');
+    let first = words.next().unwrap_or_default().to_string();
+    words.fold(first, |mut camel_case, word| {
+        let mut chars = word.chars();
+        if let Some(c) = chars.next() {
+            camel_case.push(c.to_ascii_uppercase());
+            camel_case.push_str(chars.as_str());
+        }
+        camel_case
+    })
+}
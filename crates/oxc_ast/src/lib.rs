@@ -7,8 +7,10 @@
 //! * `AssignmentExpression`.`left` `Pattern` is replaced with `AssignmentTarget`
 //!
 //! ## Cargo Features
-//! * `"serde"` enables support for serde serialization
+//! * `"serde"` enables support for serde serialization, and the [ESTree](https://github.com/estree/estree) compatible serialization
 
+#[cfg(feature = "serde")]
+mod estree;
 #[cfg(feature = "serde")]
 mod serialize;
 
@@ -27,7 +29,7 @@ pub use num_bigint::BigUint;
 pub use crate::{
     ast_builder::AstBuilder,
     ast_kind::AstKind,
-    trivia::{Comment, CommentKind, Trivias, TriviasMap},
+    trivia::{AttachedComments, Comment, CommentKind, Trivias, TriviasMap},
     visit::Visit,
     visit_mut::VisitMut,
 };
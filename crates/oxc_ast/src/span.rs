@@ -1,4 +1,4 @@
-use oxc_span::{GetSpan, Span};
+use oxc_span::{GetSpan, GetSpanMut, Span};
 
 use crate::ast::*;
 
@@ -54,6 +54,7 @@ impl<'a> GetSpan for Expression<'a> {
             Self::ConditionalExpression(e) => e.span,
             Self::FunctionExpression(e) => e.span,
             Self::ImportExpression(e) => e.span,
+            Self::V8IntrinsicExpression(e) => e.span,
             Self::LogicalExpression(e) => e.span,
             Self::MemberExpression(e) => e.span(),
             Self::NewExpression(e) => e.span,
@@ -386,3 +387,117 @@ impl<'a> GetSpan for JSXExpression<'a> {
         }
     }
 }
+
+impl<'a> GetSpanMut for Statement<'a> {
+    fn span_mut(&mut self) -> &mut Span {
+        match self {
+            Self::BlockStatement(stmt) => &mut stmt.span,
+            Self::BreakStatement(stmt) => &mut stmt.span,
+            Self::ContinueStatement(stmt) => &mut stmt.span,
+            Self::DebuggerStatement(stmt) => &mut stmt.span,
+            Self::DoWhileStatement(stmt) => &mut stmt.span,
+            Self::EmptyStatement(stmt) => &mut stmt.span,
+            Self::ExpressionStatement(stmt) => &mut stmt.span,
+            Self::ForInStatement(stmt) => &mut stmt.span,
+            Self::ForOfStatement(stmt) => &mut stmt.span,
+            Self::ForStatement(stmt) => &mut stmt.span,
+            Self::IfStatement(stmt) => &mut stmt.span,
+            Self::LabeledStatement(stmt) => &mut stmt.span,
+            Self::ReturnStatement(stmt) => &mut stmt.span,
+            Self::SwitchStatement(stmt) => &mut stmt.span,
+            Self::ThrowStatement(stmt) => &mut stmt.span,
+            Self::TryStatement(stmt) => &mut stmt.span,
+            Self::WhileStatement(stmt) => &mut stmt.span,
+            Self::WithStatement(stmt) => &mut stmt.span,
+            Self::ModuleDeclaration(decl) => decl.span_mut(),
+            Self::Declaration(decl) => decl.span_mut(),
+        }
+    }
+}
+
+impl<'a> GetSpanMut for Expression<'a> {
+    fn span_mut(&mut self) -> &mut Span {
+        match self {
+            Self::BooleanLiteral(e) => &mut e.span,
+            Self::NullLiteral(e) => &mut e.span,
+            Self::NumberLiteral(e) => &mut e.span,
+            Self::BigintLiteral(e) => &mut e.span,
+            Self::RegExpLiteral(e) => &mut e.span,
+            Self::StringLiteral(e) => &mut e.span,
+            Self::TemplateLiteral(e) => &mut e.span,
+            Self::Identifier(e) => &mut e.span,
+            Self::MetaProperty(e) => &mut e.span,
+            Self::Super(e) => &mut e.span,
+            Self::ArrayExpression(e) => &mut e.span,
+            Self::ArrowExpression(e) => &mut e.span,
+            Self::AssignmentExpression(e) => &mut e.span,
+            Self::AwaitExpression(e) => &mut e.span,
+            Self::BinaryExpression(e) => &mut e.span,
+            Self::PrivateInExpression(e) => &mut e.span,
+            Self::CallExpression(e) => &mut e.span,
+            Self::ChainExpression(e) => &mut e.span,
+            Self::ClassExpression(e) => &mut e.span,
+            Self::ConditionalExpression(e) => &mut e.span,
+            Self::FunctionExpression(e) => &mut e.span,
+            Self::ImportExpression(e) => &mut e.span,
+            Self::V8IntrinsicExpression(e) => &mut e.span,
+            Self::LogicalExpression(e) => &mut e.span,
+            Self::MemberExpression(e) => e.span_mut(),
+            Self::NewExpression(e) => &mut e.span,
+            Self::ObjectExpression(e) => &mut e.span,
+            Self::ParenthesizedExpression(e) => &mut e.span,
+            Self::SequenceExpression(e) => &mut e.span,
+            Self::TaggedTemplateExpression(e) => &mut e.span,
+            Self::ThisExpression(e) => &mut e.span,
+            Self::UnaryExpression(e) => &mut e.span,
+            Self::UpdateExpression(e) => &mut e.span,
+            Self::YieldExpression(e) => &mut e.span,
+            Self::JSXElement(e) => &mut e.span,
+            Self::JSXFragment(e) => &mut e.span,
+            Self::TSAsExpression(e) => &mut e.span,
+            Self::TSSatisfiesExpression(e) => &mut e.span,
+            Self::TSTypeAssertion(e) => &mut e.span,
+            Self::TSNonNullExpression(e) => &mut e.span,
+            Self::TSInstantiationExpression(e) => &mut e.span,
+        }
+    }
+}
+
+impl<'a> GetSpanMut for MemberExpression<'a> {
+    fn span_mut(&mut self) -> &mut Span {
+        match self {
+            Self::ComputedMemberExpression(expr) => &mut expr.span,
+            Self::StaticMemberExpression(expr) => &mut expr.span,
+            Self::PrivateFieldExpression(expr) => &mut expr.span,
+        }
+    }
+}
+
+impl<'a> GetSpanMut for ModuleDeclaration<'a> {
+    fn span_mut(&mut self) -> &mut Span {
+        match self {
+            Self::ImportDeclaration(decl) => &mut decl.span,
+            Self::ExportAllDeclaration(decl) => &mut decl.span,
+            Self::ExportDefaultDeclaration(decl) => &mut decl.span,
+            Self::ExportNamedDeclaration(decl) => &mut decl.span,
+            Self::TSExportAssignment(decl) => &mut decl.span,
+            Self::TSNamespaceExportDeclaration(decl) => &mut decl.span,
+        }
+    }
+}
+
+impl<'a> GetSpanMut for Declaration<'a> {
+    fn span_mut(&mut self) -> &mut Span {
+        match self {
+            Self::VariableDeclaration(decl) => &mut decl.span,
+            Self::FunctionDeclaration(decl) => &mut decl.span,
+            Self::UsingDeclaration(decl) => &mut decl.span,
+            Self::ClassDeclaration(decl) => &mut decl.span,
+            Self::TSTypeAliasDeclaration(decl) => &mut decl.span,
+            Self::TSInterfaceDeclaration(decl) => &mut decl.span,
+            Self::TSEnumDeclaration(decl) => &mut decl.span,
+            Self::TSModuleDeclaration(decl) => &mut decl.span,
+            Self::TSImportEqualsDeclaration(decl) => &mut decl.span,
+        }
+    }
+}
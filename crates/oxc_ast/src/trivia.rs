@@ -1,6 +1,6 @@
 //! Trivias such as comments
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 
 use oxc_span::Span;
 
@@ -81,3 +81,55 @@ impl TriviasMap {
             .collect()
     }
 }
+
+/// Comments attached to the statement which follows them, so they can be printed again after
+/// the statements are transformed.
+///
+/// Keyed by the `span.start` of the statement, a statement replaced by a transform keeps its
+/// comments if the new statement has the same span, or if they are moved with
+/// [AttachedComments::move_leading].
+///
+/// The `/*#__PURE__*/` annotations are kept apart, they are printed inline before the call at
+/// their position.
+#[derive(Debug, Default, Clone)]
+pub struct AttachedComments {
+    leading: BTreeMap<u32, Vec<String>>,
+    pure: BTreeSet<u32>,
+}
+
+impl AttachedComments {
+    pub fn is_empty(&self) -> bool {
+        self.leading.is_empty() && self.pure.is_empty()
+    }
+
+    /// Attach the text of a comment, delimiters included, before the statement at `start`.
+    pub fn attach(&mut self, start: u32, comment: String) {
+        self.leading.entry(start).or_default().push(comment);
+    }
+
+    pub fn leading(&self, start: u32) -> Option<&[String]> {
+        self.leading.get(&start).map(Vec::as_slice)
+    }
+
+    /// Remove the comments before the statement at `start`, so they are printed once.
+    pub fn take_leading(&mut self, start: u32) -> Option<Vec<String>> {
+        self.leading.remove(&start)
+    }
+
+    /// Move the comments of the statement at `from` to the statement at `to`.
+    pub fn move_leading(&mut self, from: u32, to: u32) {
+        if let Some(comments) = self.leading.remove(&from) {
+            self.leading.entry(to).or_default().extend(comments);
+        }
+    }
+
+    /// Annotate the call or tagged template at `start` as free of side effects.
+    pub fn annotate_pure(&mut self, start: u32) {
+        self.pure.insert(start);
+    }
+
+    /// Remove the annotation of the call at `start`, so it is printed once.
+    pub fn take_pure(&mut self, start: u32) -> bool {
+        self.pure.remove(&start)
+    }
+}
@@ -453,6 +453,14 @@ pub trait Visit<'a>: Sized {
         if let Some(super_parameters) = &class.super_type_parameters {
             self.visit_ts_type_parameter_instantiation(super_parameters);
         }
+        if let Some(implements) = &class.implements {
+            for implement in implements {
+                self.visit_ts_type_name(&implement.expression);
+                if let Some(parameters) = &implement.type_parameters {
+                    self.visit_ts_type_parameter_instantiation(parameters);
+                }
+            }
+        }
         self.visit_class_body(&class.body);
         self.leave_node(kind);
         if is_class_expr {
@@ -573,6 +581,7 @@ pub trait Visit<'a>: Sized {
             Expression::ConditionalExpression(expr) => self.visit_conditional_expression(expr),
             Expression::FunctionExpression(expr) => self.visit_function(expr, None),
             Expression::ImportExpression(expr) => self.visit_import_expression(expr),
+            Expression::V8IntrinsicExpression(expr) => self.visit_v8_intrinsic_expression(expr),
             Expression::LogicalExpression(expr) => self.visit_logical_expression(expr),
             Expression::MemberExpression(expr) => self.visit_member_expression(expr),
             Expression::NewExpression(expr) => self.visit_new_expression(expr),
@@ -738,6 +747,12 @@ pub trait Visit<'a>: Sized {
         }
     }
 
+    fn visit_v8_intrinsic_expression(&mut self, expr: &V8IntrinsicExpression<'a>) {
+        for arg in &expr.arguments {
+            self.visit_argument(arg);
+        }
+    }
+
     fn visit_logical_expression(&mut self, expr: &LogicalExpression<'a>) {
         let kind = AstKind::LogicalExpression(self.alloc(expr));
         self.enter_node(kind);
@@ -1431,6 +1446,14 @@ pub trait Visit<'a>: Sized {
         if let Some(parameters) = &decl.type_parameters {
             self.visit_ts_type_parameter_declaration(parameters);
         }
+        if let Some(extends) = &decl.extends {
+            for heritage in extends {
+                self.visit_expression(&heritage.expression);
+                if let Some(parameters) = &heritage.type_parameters {
+                    self.visit_ts_type_parameter_instantiation(parameters);
+                }
+            }
+        }
         for signature in &decl.body.body {
             self.visit_ts_signature(signature);
         }
@@ -1499,10 +1522,19 @@ pub trait Visit<'a>: Sized {
             TSType::TSTypePredicate(ty) => self.visit_ts_type_predicate(ty),
             TSType::TSTypeLiteral(ty) => self.visit_ts_type_literal(ty),
             TSType::TSIndexedAccessType(ty) => self.visit_ts_indexed_access_type(ty),
+            TSType::TSTypeQuery(ty) => self.visit_ts_type_query(ty),
             _ => {}
         }
     }
 
+    /// `typeof a`
+    fn visit_ts_type_query(&mut self, ty: &TSTypeQuery<'a>) {
+        self.visit_ts_type_name(&ty.expr_name);
+        if let Some(parameters) = &ty.type_parameters {
+            self.visit_ts_type_parameter_instantiation(parameters);
+        }
+    }
+
     fn visit_ts_type_literal(&mut self, ty: &TSTypeLiteral<'a>) {
         let kind = AstKind::TSTypeLiteral(self.alloc(ty));
         self.enter_node(kind);
@@ -1527,7 +1559,10 @@ pub trait Visit<'a>: Sized {
     }
 
     fn visit_ts_type_operator_type(&mut self, ty: &TSTypeOperatorType<'a>) {
+        let kind = AstKind::TSTypeOperatorType(self.alloc(ty));
+        self.enter_node(kind);
         self.visit_ts_type(&ty.type_annotation);
+        self.leave_node(kind);
     }
 
     fn visit_ts_tuple_type(&mut self, ty: &TSTupleType<'a>) {
@@ -1612,13 +1647,17 @@ pub trait Visit<'a>: Sized {
     }
 
     fn visit_ts_array_type(&mut self, ty: &TSArrayType<'a>) {
+        let kind = AstKind::TSArrayType(self.alloc(ty));
+        self.enter_node(kind);
         self.visit_ts_type(&ty.element_type);
+        self.leave_node(kind);
     }
 
     fn visit_ts_type_name(&mut self, name: &TSTypeName<'a>) {
         match &name {
             TSTypeName::IdentifierReference(ident) => self.visit_identifier_reference(ident),
-            TSTypeName::QualifiedName(_) => {}
+            // `a.b.c`, only `a` is a reference
+            TSTypeName::QualifiedName(name) => self.visit_ts_type_name(&name.left),
         }
     }
 
@@ -371,6 +371,7 @@ pub trait VisitMut<'a>: Sized {
             Expression::ConditionalExpression(expr) => self.visit_conditional_expression(expr),
             Expression::FunctionExpression(expr) => self.visit_function(expr),
             Expression::ImportExpression(expr) => self.visit_import_expression(expr),
+            Expression::V8IntrinsicExpression(expr) => self.visit_v8_intrinsic_expression(expr),
             Expression::LogicalExpression(expr) => self.visit_logical_expression(expr),
             Expression::MemberExpression(expr) => self.visit_member_expression(expr),
             Expression::NewExpression(expr) => self.visit_new_expression(expr),
@@ -490,6 +491,12 @@ pub trait VisitMut<'a>: Sized {
         }
     }
 
+    fn visit_v8_intrinsic_expression(&mut self, expr: &mut V8IntrinsicExpression<'a>) {
+        for arg in expr.arguments.iter_mut() {
+            self.visit_argument(arg);
+        }
+    }
+
     fn visit_logical_expression(&mut self, expr: &mut LogicalExpression<'a>) {
         self.visit_expression(&mut expr.left);
         self.visit_expression(&mut expr.right);
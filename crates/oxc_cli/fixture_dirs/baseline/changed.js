@@ -0,0 +1,3 @@
+// new line
+debugger;
+debugger;
@@ -0,0 +1 @@
+window; describe; __DEV__; foo = 1; undefinedThing;
@@ -0,0 +1 @@
+// The only file linted unless --no-ignore
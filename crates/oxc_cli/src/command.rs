@@ -1,9 +1,10 @@
 use bpaf::{doc::Style, Bpaf};
 use oxc_linter::AllowWarnDeny;
-use std::{ffi::OsString, path::PathBuf};
+use std::{ffi::OsString, path::PathBuf, str::FromStr};
 
 #[derive(Debug, Clone, Bpaf)]
 #[bpaf(options)]
+#[allow(clippy::large_enum_variant)] // parsed once
 pub enum CliCommand {
     /// Lint this repository
     #[bpaf(command)]
@@ -50,7 +51,7 @@ impl LintCommand {
 /// Miscellaneous
 #[derive(Debug, Clone, Bpaf)]
 pub struct MiscOptions {
-    /// Display the execution time of each lint rule
+    /// Display the execution time and the number of problems of each lint rule, and the slowest files
     #[bpaf(switch, env("TIMING"), hide_usage)]
     pub timing: bool,
 
@@ -58,6 +59,11 @@ pub struct MiscOptions {
     #[bpaf(switch, hide_usage)]
     pub rules: bool,
 
+    /// Print the category, the fixability, the documentation and the examples of a rule,
+    /// e.g. `--explain no-debugger`
+    #[bpaf(argument("RULE"), hide_usage)]
+    pub explain: Option<String>,
+
     /// Number of threads to use. Set to 1 for using only 1 CPU core
     #[bpaf(argument("INT"), hide_usage)]
     pub threads: Option<usize>,
@@ -68,10 +74,46 @@ pub struct LintOptions {
     #[bpaf(external(lint_filter), map(LintFilter::into_tuple), many)]
     pub filter: Vec<(AllowWarnDeny, String)>,
 
+    /// ESLint configuration file, e.g. `.eslintrc.json` or `eslint.config.js`.
+    /// Its rules are applied over the `-A` / `-D` filters,
+    /// then the `.oxlintrc.json` or `.oxlintrc` files found from each linted file upward are applied,
+    /// the nearest last, up to the one with `"root": true`
+    #[bpaf(short, long, argument("PATH"))]
+    pub config: Option<PathBuf>,
+
+    /// Enable the globals of environments, e.g. `--env browser,jest`.
+    /// The environments are browser, node, shared-node-browser, worker, commonjs, jest, mocha,
+    /// vitest and es2015 to es2024
+    #[bpaf(argument("ENV"), many, hide_usage)]
+    pub env: Vec<String>,
+
+    /// Declare global variables, read-only unless followed by `:writable`,
+    /// e.g. `--global __DEV__,process:writable`
+    #[bpaf(argument("NAME"), many, hide_usage)]
+    pub global: Vec<String>,
+
     /// Use the experimental import plugin and detect ESM problems
     #[bpaf(switch, hide_usage)]
     pub import_plugin: bool,
 
+    /// Enable the accessibility rules of eslint-plugin-jsx-a11y
+    #[bpaf(switch, hide_usage)]
+    pub jsx_a11y_plugin: bool,
+
+    /// Enable the rules of eslint-plugin-unicorn
+    #[bpaf(switch, hide_usage)]
+    pub unicorn_plugin: bool,
+
+    /// Report the eslint-disable / oxlint-disable comments which do not suppress any problem
+    #[bpaf(switch, hide_usage)]
+    pub report_unused_disable_directives: bool,
+
+    /// Give the type-aware rules, e.g. typescript/no-floating-promises, the types of the
+    /// TypeScript compiler installed in the project.
+    /// Needs Node.js and oxlint built with the `type_aware` feature
+    #[bpaf(switch, hide_usage)]
+    pub type_aware: bool,
+
     #[bpaf(external)]
     pub fix_options: FixOptions,
 
@@ -81,6 +123,15 @@ pub struct LintOptions {
     #[bpaf(external)]
     pub warning_options: WarningOptions,
 
+    #[bpaf(external)]
+    pub output_options: OutputOptions,
+
+    #[bpaf(external)]
+    pub cache_options: CacheOptions,
+
+    #[bpaf(external)]
+    pub baseline_options: BaselineOptions,
+
     #[bpaf(external)]
     pub misc_options: MiscOptions,
 
@@ -95,6 +146,8 @@ pub struct LintOptions {
 /// For example `-D correctness -A no-debugger` or `-A all -D no-debugger`.
 /// ㅤ
 ///  The default category is "-D correctness".
+///  "-D warnings" reports the problems of all the rules as errors,
+///  the other filters apply on top of the default category.
 ///  Use "--rules" for rule names.
 ///  Use "--help --help" for rule categories.
 ///
@@ -105,6 +158,7 @@ pub struct LintOptions {
 ///  * style       - code that should be written in a more idiomatic way
 ///  * nursery     - new lints that are still under development
 ///  * restriction - lints which prevent the use of language and library features
+///  * security    - code that is potentially exploitable
 ///  * all         - all the categories listed above
 #[derive(Debug, Clone, Bpaf)]
 pub enum LintFilter {
@@ -135,10 +189,17 @@ pub struct FixOptions {
     /// Fix as many issues as possible. Only unfixed issues are reported in the output
     #[bpaf(switch)]
     pub fix: bool,
+
+    /// Fix as many issues as possible and print the fixed code instead of saving the changes
+    #[bpaf(switch, hide_usage)]
+    pub fix_dry_run: bool,
 }
 
 const NO_IGNORE_HELP: &[(&str, Style)] = &[
-    ("Disables excluding of files from .eslintignore files, ", Style::Text),
+    (
+        "Disables excluding of files from .gitignore, .eslintignore and .oxlintignore files, ",
+        Style::Text,
+    ),
     ("--ignore-path", Style::Literal),
     (" flags and ", Style::Text),
     ("--ignore-pattern", Style::Literal),
@@ -146,13 +207,14 @@ const NO_IGNORE_HELP: &[(&str, Style)] = &[
 ];
 
 /// Ignore Files
+/// The files matched by the .gitignore and .oxlintignore files, and node_modules, are skipped too.
 #[derive(Debug, Clone, Bpaf)]
 pub struct IgnoreOptions {
     /// Specify the file to use as your .eslintignore
     #[bpaf(argument("PATH"), fallback(".eslintignore".into()), hide_usage)]
     pub ignore_path: OsString,
 
-    /// Specify patterns of files to ignore (in addition to those in .eslintignore)
+    /// Specify patterns of files to ignore (in addition to those in the ignore files)
     ///
     /// The supported syntax is the same as for .eslintignore and .gitignore files
     /// You should quote your patterns in order to avoid shell interpretation of glob patterns
@@ -177,6 +239,73 @@ pub struct WarningOptions {
     pub max_warnings: Option<usize>,
 }
 
+/// Output
+#[derive(Debug, Clone, Bpaf)]
+pub struct OutputOptions {
+    /// Use a specific output format: default, json, checkstyle, junit, github, sarif
+    #[bpaf(short, long, argument("FORMAT"), fallback(OutputFormat::Default), hide_usage)]
+    pub format: OutputFormat,
+}
+
+/// Caching
+#[derive(Debug, Clone, Bpaf)]
+pub struct CacheOptions {
+    /// Only lint the changed files, the problems of the unchanged files are read from the cache.
+    /// The cache is not used together with --import-plugin
+    #[bpaf(switch, hide_usage)]
+    pub cache: bool,
+
+    /// Directory of the cache
+    #[bpaf(argument("PATH"), fallback(".oxlintcache".into()), hide_usage)]
+    pub cache_location: PathBuf,
+}
+
+/// Baseline
+/// The problems recorded in the baseline file are not reported, only the new ones are
+#[derive(Debug, Clone, Bpaf)]
+pub struct BaselineOptions {
+    /// Record the current problems in the baseline file instead of reporting them.
+    /// Run it on the whole project, the previous baseline is replaced
+    #[bpaf(switch, hide_usage)]
+    pub generate_baseline: bool,
+
+    /// Path of the baseline file, it is used when it exists
+    #[bpaf(argument("PATH"), fallback("oxlint-baseline.json".into()), hide_usage)]
+    pub baseline: PathBuf,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// The diagnostics drawn with their source code
+    Default,
+    /// The ESLint `json` format
+    Json,
+    /// Checkstyle XML
+    Checkstyle,
+    /// JUnit XML, a test case for each problem
+    Junit,
+    /// The annotations of the GitHub Actions
+    Github,
+    /// SARIF 2.1.0, e.g. for the GitHub code scanning
+    Sarif,
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "default" => Ok(Self::Default),
+            "json" => Ok(Self::Json),
+            "checkstyle" => Ok(Self::Checkstyle),
+            "junit" => Ok(Self::Junit),
+            "github" => Ok(Self::Github),
+            "sarif" => Ok(Self::Sarif),
+            _ => Err(format!("'{s}' is not a known format")),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Bpaf)]
 pub struct CheckOptions {
     /// Print called functions
@@ -226,6 +355,12 @@ mod misc_options {
         let options = get_misc_options("--rules");
         assert!(options.rules);
     }
+
+    #[test]
+    fn explain() {
+        let options = get_misc_options("--explain no-debugger");
+        assert_eq!(options.explain.as_deref(), Some("no-debugger"));
+    }
 }
 
 #[cfg(test)]
@@ -290,6 +425,68 @@ mod lint_options {
         assert!(options.fix_options.fix);
     }
 
+    #[test]
+    fn fix_dry_run() {
+        let options = get_lint_options("--fix-dry-run test.js");
+        assert!(!options.fix_options.fix);
+        assert!(options.fix_options.fix_dry_run);
+    }
+
+    #[test]
+    fn baseline() {
+        let options = get_lint_options(".");
+        assert!(!options.baseline_options.generate_baseline);
+        assert_eq!(options.baseline_options.baseline, PathBuf::from("oxlint-baseline.json"));
+        let options = get_lint_options("--generate-baseline --baseline base.json .");
+        assert!(options.baseline_options.generate_baseline);
+        assert_eq!(options.baseline_options.baseline, PathBuf::from("base.json"));
+    }
+
+    #[test]
+    fn deny_warnings() {
+        let options = get_lint_options("-D warnings -A no-debugger .");
+        assert_eq!(
+            options.filter,
+            [
+                (AllowWarnDeny::Deny, "warnings".to_string()),
+                (AllowWarnDeny::Allow, "no-debugger".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn env_and_global() {
+        let options = get_lint_options("--env browser,jest --env node --global a,b:writable .");
+        assert_eq!(options.env, ["browser,jest", "node"]);
+        assert_eq!(options.global, ["a,b:writable"]);
+    }
+
+    #[test]
+    fn jsx_a11y_plugin() {
+        let options = get_lint_options("--jsx-a11y-plugin test.js");
+        assert!(options.jsx_a11y_plugin);
+        assert!(!get_lint_options("test.js").jsx_a11y_plugin);
+    }
+
+    #[test]
+    fn unicorn_plugin() {
+        let options = get_lint_options("--unicorn-plugin test.js");
+        assert!(options.unicorn_plugin);
+        assert!(!get_lint_options("test.js").unicorn_plugin);
+    }
+
+    #[test]
+    fn report_unused_disable_directives() {
+        let options = get_lint_options("--report-unused-disable-directives test.js");
+        assert!(options.report_unused_disable_directives);
+    }
+
+    #[test]
+    fn type_aware() {
+        assert!(get_lint_options("--type-aware test.ts").type_aware);
+        assert!(!get_lint_options("test.ts").type_aware);
+    }
+
     #[test]
     fn filter() {
         let options =
@@ -348,3 +545,46 @@ mod ignore_options {
         assert_eq!(options.ignore_pattern, vec![String::from("./test"), String::from("bar.js")]);
     }
 }
+
+#[cfg(test)]
+mod output_options {
+    use super::{lint_command, OutputFormat, OutputOptions};
+
+    fn get_output_options(arg: &str) -> OutputOptions {
+        let args = arg.split(' ').map(std::string::ToString::to_string).collect::<Vec<_>>();
+        lint_command().run_inner(args.as_slice()).unwrap().lint_options.output_options
+    }
+
+    #[test]
+    fn default() {
+        let options = get_output_options(".");
+        assert_eq!(options.format, OutputFormat::Default);
+    }
+
+    #[test]
+    fn sarif() {
+        let options = get_output_options("--format sarif .");
+        assert_eq!(options.format, OutputFormat::Sarif);
+        let options = get_output_options("-f sarif .");
+        assert_eq!(options.format, OutputFormat::Sarif);
+    }
+
+    #[test]
+    fn formats() {
+        for (format, expected) in [
+            ("json", OutputFormat::Json),
+            ("checkstyle", OutputFormat::Checkstyle),
+            ("junit", OutputFormat::Junit),
+            ("github", OutputFormat::Github),
+        ] {
+            let options = get_output_options(&format!("--format {format} ."));
+            assert_eq!(options.format, expected);
+        }
+    }
+
+    #[test]
+    fn unknown() {
+        let args = ["--format", "xml", "."];
+        assert!(lint_command().run_inner(args.as_slice()).is_err());
+    }
+}
@@ -1,9 +1,19 @@
-use std::io::BufWriter;
+use std::{io::BufWriter, path::Path, sync::Arc};
 
-use oxc_diagnostics::DiagnosticService;
-use oxc_linter::{LintOptions, LintService, Linter};
+use oxc_diagnostics::{
+    reporter::{
+        CheckstyleReporter, DiagnosticReporter, GithubReporter, JsonReporter, JunitReporter,
+        SarifReporter, SarifRule,
+    },
+    Baseline, DiagnosticService,
+};
+use oxc_linter::{AllowWarnDeny, ESLintConfig, LintOptions, LintService, Linter, TypeChecker};
 
-use crate::{command::LintOptions as CliLintOptions, walk::Walk, CliRunResult, LintResult, Runner};
+use crate::{
+    command::{BaselineOptions, LintOptions as CliLintOptions, OutputFormat},
+    walk::Walk,
+    CliRunResult, LintResult, Runner,
+};
 
 pub struct LintRunner {
     options: CliLintOptions,
@@ -23,13 +33,31 @@ impl Runner for LintRunner {
             return CliRunResult::None;
         }
 
+        if let Some(name) = &self.options.misc_options.explain {
+            let mut stdout = BufWriter::new(std::io::stdout());
+            if !Linter::explain(name, &mut stdout) {
+                return CliRunResult::InvalidOptions { message: format!("Unknown rule: {name}") };
+            }
+            return CliRunResult::None;
+        }
+
         let CliLintOptions {
             paths,
-            filter,
+            mut filter,
+            config,
+            env,
+            global,
             import_plugin,
+            jsx_a11y_plugin,
+            unicorn_plugin,
+            report_unused_disable_directives,
+            type_aware,
             warning_options,
             ignore_options,
             fix_options,
+            output_options,
+            cache_options,
+            baseline_options,
             misc_options,
         } = self.options;
 
@@ -37,22 +65,89 @@ impl Runner for LintRunner {
             return CliRunResult::InvalidOptions { message: "No paths provided.".to_string() };
         }
 
+        let eslint_config = match config.as_deref().map(ESLintConfig::from_file).transpose() {
+            Ok(eslint_config) => eslint_config,
+            Err(error) => {
+                return CliRunResult::InvalidOptions { message: format!("{error:?}") };
+            }
+        };
+
+        let BaselineOptions { generate_baseline, baseline: baseline_path } = baseline_options;
+        let baseline = if generate_baseline || !baseline_path.is_file() {
+            None
+        } else {
+            match Baseline::load(&baseline_path) {
+                Ok(baseline) => Some(baseline),
+                Err(error) => {
+                    return CliRunResult::InvalidOptions {
+                        message: format!("Failed to read the baseline {baseline_path:?}: {error}"),
+                    };
+                }
+            }
+        };
+
         let now = std::time::Instant::now();
 
         let paths = Walk::new(&paths, &ignore_options).paths();
         let number_of_files = paths.len();
 
+        // `-D warnings` is not a rule, the other filters apply on top of the default rules,
+        // e.g. `-D warnings -A no-debugger` is `-D correctness -A no-debugger`.
+        let deny_warnings = filter.iter().any(|(allow_warn_deny, name)| {
+            *allow_warn_deny == AllowWarnDeny::Deny && name == "warnings"
+        });
+        filter.retain(|(_, name)| name != "warnings");
+        if deny_warnings {
+            filter.splice(0..0, LintOptions::default().filter);
+        }
+
+        let env = split_list(&env).map(str::to_string).collect();
+        let globals = split_list(&global)
+            .map(|global| match global.split_once(':') {
+                Some((name, value)) => {
+                    (name.to_string(), matches!(value, "writable" | "writeable" | "true"))
+                }
+                None => (global.to_string(), false),
+            })
+            .collect();
+
         let cwd = std::env::current_dir().unwrap().into_boxed_path();
-        let lint_options = LintOptions::default()
+        let type_checker = match Self::type_checker(type_aware, &cwd) {
+            Ok(type_checker) => type_checker,
+            Err(message) => return CliRunResult::InvalidOptions { message },
+        };
+        let mut lint_options = LintOptions::default()
             .with_filter(filter)
-            .with_fix(fix_options.fix)
+            .with_deny_warnings(deny_warnings)
+            .with_fix(fix_options.fix || fix_options.fix_dry_run)
+            .with_fix_dry_run(fix_options.fix_dry_run)
+            .with_report_fixes(matches!(
+                output_options.format,
+                OutputFormat::Json | OutputFormat::Sarif
+            ))
             .with_timing(misc_options.timing)
-            .with_import_plugin(import_plugin);
+            .with_import_plugin(import_plugin)
+            .with_jsx_a11y_plugin(jsx_a11y_plugin)
+            .with_unicorn_plugin(unicorn_plugin)
+            .with_env(env)
+            .with_globals(globals)
+            .with_report_unused_directives(report_unused_disable_directives)
+            .with_oxlintrc(true)
+            .with_cache(cache_options.cache.then_some(cache_options.cache_location))
+            .with_type_checker(type_checker);
+        if let Some(eslint_config) = eslint_config {
+            lint_options = lint_options.with_eslint_config(eslint_config);
+        }
         let lint_service = LintService::new(cwd, &paths, lint_options);
 
-        let diagnostic_service = DiagnosticService::default()
+        let mut diagnostic_service = DiagnosticService::default()
             .with_quiet(warning_options.quiet)
-            .with_max_warnings(warning_options.max_warnings);
+            .with_max_warnings(warning_options.max_warnings)
+            .with_baseline(baseline)
+            .with_record_baseline(generate_baseline);
+        if let Some(reporter) = Self::reporter(output_options.format, lint_service.linter()) {
+            diagnostic_service = diagnostic_service.with_reporter(reporter);
+        }
 
         // Spawn linting in another thread so diagnostics can be printed immediately from diagnostic_service.run.
         rayon::spawn({
@@ -64,6 +159,21 @@ impl Runner for LintRunner {
         });
         diagnostic_service.run();
 
+        if let Err(error) = lint_service.save_cache() {
+            eprintln!("Failed to write the lint cache: {error}");
+        }
+
+        if let Some(baseline) = diagnostic_service.baseline().filter(|_| generate_baseline) {
+            match baseline.save(&baseline_path) {
+                Ok(()) => println!(
+                    "Recorded {} problems in the baseline {}.",
+                    baseline.len(),
+                    baseline_path.display()
+                ),
+                Err(error) => eprintln!("Failed to write the baseline: {error}"),
+            }
+        }
+
         lint_service.linter().print_execution_times_if_enable();
 
         CliRunResult::LintResult(LintResult {
@@ -73,15 +183,107 @@ impl Runner for LintRunner {
             number_of_warnings: diagnostic_service.warnings_count(),
             number_of_errors: diagnostic_service.errors_count(),
             max_warnings_exceeded: diagnostic_service.max_warnings_exceeded(),
+            print_summary: matches!(
+                output_options.format,
+                OutputFormat::Default | OutputFormat::Github
+            ),
         })
     }
 }
 
+impl LintRunner {
+    /// The type checker of `--type-aware`, the TypeScript compiler of the project in `cwd`.
+    fn type_checker(type_aware: bool, cwd: &Path) -> Result<Option<Arc<dyn TypeChecker>>, String> {
+        if !type_aware {
+            return Ok(None);
+        }
+        #[cfg(feature = "type_aware")]
+        {
+            let type_checker = oxc_linter::TscTypeChecker::new(cwd)
+                .map_err(|error| format!("Failed to start the type checker: {error}"))?;
+            Ok(Some(Arc::new(type_checker)))
+        }
+        #[cfg(not(feature = "type_aware"))]
+        {
+            let _ = cwd;
+            Err("--type-aware needs oxlint built with the `type_aware` feature.".to_string())
+        }
+    }
+
+    /// The reporter of the output format, `None` for the default graphical output.
+    fn reporter(format: OutputFormat, linter: &Linter) -> Option<Box<dyn DiagnosticReporter>> {
+        match format {
+            OutputFormat::Default => None,
+            OutputFormat::Json => Some(Box::<JsonReporter>::default()),
+            OutputFormat::Checkstyle => Some(Box::<CheckstyleReporter>::default()),
+            OutputFormat::Junit => Some(Box::<JunitReporter>::default()),
+            OutputFormat::Github => Some(Box::new(GithubReporter)),
+            OutputFormat::Sarif => {
+                let rules = linter
+                    .rules()
+                    .iter()
+                    .map(|rule| {
+                        let help = rule.documentation().unwrap_or_default().trim().to_string();
+                        // The first paragraph of "What it does".
+                        let short_description = help
+                            .lines()
+                            .map(str::trim)
+                            .skip_while(|line| line.is_empty() || line.starts_with('#'))
+                            .take_while(|line| !line.is_empty() && !line.starts_with('#'))
+                            .collect::<Vec<_>>()
+                            .join(" ");
+                        SarifRule {
+                            id: format!("{}/{}", rule.plugin_name(), rule.name()),
+                            short_description,
+                            help,
+                            level: "warning",
+                        }
+                    })
+                    .collect();
+                Some(Box::new(SarifReporter::new(
+                    "oxlint",
+                    env!("CARGO_PKG_VERSION"),
+                    "https://github.com/web-infra-dev/oxc",
+                    rules,
+                )))
+            }
+        }
+    }
+}
+
+/// `["a,b", "c"]` -> `["a", "b", "c"]`
+fn split_list(values: &[String]) -> impl Iterator<Item = &str> {
+    values.iter().flat_map(|value| value.split(',')).map(str::trim).filter(|s| !s.is_empty())
+}
+
 #[cfg(all(test, not(target_os = "windows")))]
 mod test {
+    use std::path::{Path, PathBuf};
+
     use super::LintRunner;
     use crate::{lint_command, CliRunResult, LintResult, Runner};
 
+    /// A directory for the files written by a test, removed even when an assertion fails.
+    struct OutputDir(PathBuf);
+
+    impl OutputDir {
+        fn new(name: &str) -> Self {
+            let path = std::env::temp_dir().join(format!("oxlint-{name}-{}", std::process::id()));
+            std::fs::create_dir_all(&path).unwrap();
+            Self(path)
+        }
+
+        fn path(&self) -> &Path {
+            &self.0
+        }
+    }
+
+    impl Drop for OutputDir {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.0);
+        }
+    }
+
     fn test(args: &[&str]) -> LintResult {
         let mut new_args = vec!["--quiet"];
         new_args.extend(args);
@@ -145,6 +347,84 @@ mod test {
         assert_eq!(result.number_of_errors, 0);
     }
 
+    #[test]
+    fn ignore_files() {
+        let path = "fixture_dirs/ignore_files";
+        let result = test(&[path]);
+        assert_eq!(result.number_of_files, 1);
+        let result = test(&["--ignore-pattern", "index.js", path]);
+        assert_eq!(result.number_of_files, 0);
+        let result = test(&["--no-ignore", path]);
+        assert_eq!(result.number_of_files, 5);
+    }
+
+    #[test]
+    fn baseline() {
+        let dir = OutputDir::new("baseline");
+        let baseline = dir.path().join("baseline.json");
+        let (file, baseline_arg) = ("fixture_dirs/baseline/index.js", baseline.to_string_lossy());
+
+        let result = test(&["--generate-baseline", "--baseline", &baseline_arg, file]);
+        assert_eq!(result.number_of_warnings, 0);
+        let result = test(&["--baseline", &baseline_arg, file]);
+        assert_eq!(result.number_of_warnings, 0);
+        // The recorded problem moved by a new line, and a new problem
+        let text = std::fs::read_to_string(&baseline).unwrap().replace("index.js", "changed.js");
+        std::fs::write(&baseline, text).unwrap();
+        let result = test(&["--baseline", &baseline_arg, "fixture_dirs/baseline/changed.js"]);
+        assert_eq!(result.number_of_warnings, 1);
+    }
+
+    #[test]
+    fn deny_warnings() {
+        let result = test(&["-D", "warnings", "fixtures/debugger.js"]);
+        assert!(result.number_of_rules > 0);
+        assert_eq!(result.number_of_warnings, 0);
+        assert_eq!(result.number_of_errors, 1);
+
+        let files = ["fixtures/debugger.js", "fixtures/nan.js"];
+        for filter in
+            [["-D", "warnings", "-A", "no-debugger"], ["-A", "no-debugger", "-D", "warnings"]]
+        {
+            let result = test(&[&filter[..], &files].concat());
+            assert!(result.number_of_rules > 0);
+            assert_eq!(result.number_of_warnings, 0);
+            assert_eq!(result.number_of_errors, 1);
+        }
+    }
+
+    #[test]
+    fn max_warnings() {
+        let result = test(&["--max-warnings", "1", "fixtures"]);
+        assert_eq!(result.number_of_warnings, 2);
+        assert!(result.max_warnings_exceeded);
+        let result = test(&["--max-warnings", "2", "fixtures"]);
+        assert!(!result.max_warnings_exceeded);
+    }
+
+    #[test]
+    fn env_and_global() {
+        let path = "fixture_dirs/env_and_global/globals.js";
+        let no_undef = ["-A", "all", "-D", "no-undef"];
+        let result = test(&[&no_undef[..], &[path]].concat());
+        assert_eq!(result.number_of_warnings, 5);
+        // Only the names not covered by an environment or a global are reported
+        let args = ["--env", "browser", path];
+        let result = test(&[&no_undef[..], &args].concat());
+        assert_eq!(result.number_of_warnings, 4);
+        let args = ["--env", "browser,jest", "--global", "__DEV__,foo:writable", path];
+        let result = test(&[&no_undef[..], &args].concat());
+        assert_eq!(result.number_of_warnings, 1);
+    }
+
+    #[test]
+    fn embedded_scripts() {
+        let result = test(&["fixture_dirs/embedded"]);
+        assert_eq!(result.number_of_files, 2);
+        assert_eq!(result.number_of_warnings, 2);
+        assert_eq!(result.number_of_errors, 0);
+    }
+
     #[test]
     fn filter_allow_all() {
         let args = &["-A", "all", "fixtures"];
@@ -162,4 +442,71 @@ mod test {
         assert_eq!(result.number_of_warnings, 0);
         assert_eq!(result.number_of_errors, 0);
     }
+
+    #[test]
+    fn fix_dry_run() {
+        let args = &["--fix-dry-run", "fixtures/debugger.js"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 1);
+        assert_eq!(result.number_of_warnings, 0);
+        assert_eq!(result.number_of_errors, 0);
+        assert_eq!(std::fs::read_to_string("fixtures/debugger.js").unwrap(), "debugger;\n");
+    }
+
+    #[test]
+    fn eslintrc_json() {
+        let args =
+            &["-c", "fixtures/eslintrc/eslintrc.json", "fixtures/debugger.js", "fixtures/nan.js"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 2);
+        assert_eq!(result.number_of_warnings, 0);
+        assert_eq!(result.number_of_errors, 1);
+    }
+
+    #[test]
+    fn eslintrc_yaml() {
+        let args = &["--config", "fixtures/eslintrc/eslintrc.yaml", "fixtures/debugger.js"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 1);
+        assert_eq!(result.number_of_warnings, 0);
+        assert_eq!(result.number_of_errors, 0);
+    }
+
+    #[test]
+    fn sarif() {
+        let args = &["--format", "sarif", "fixtures/debugger.js"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 1);
+        assert_eq!(result.number_of_warnings, 1);
+        assert!(!result.print_summary);
+    }
+
+    #[test]
+    fn cache() {
+        let dir = OutputDir::new("cli-cache");
+        let cache_location = dir.path().to_string_lossy();
+        let args = &["--cache", "--cache-location", &cache_location, "fixtures/debugger.js"];
+        for _ in 0..2 {
+            let result = test(args);
+            assert_eq!(result.number_of_files, 1);
+            assert_eq!(result.number_of_warnings, 1);
+            assert!(dir.path().join("lint-cache.json").is_file());
+        }
+    }
+
+    #[test]
+    fn json() {
+        let args = &["--format", "json", "fixtures/debugger.js"];
+        let result = test(args);
+        assert_eq!(result.number_of_warnings, 1);
+        assert!(!result.print_summary);
+    }
+
+    #[test]
+    fn invalid_eslintrc() {
+        let args = ["-c", "fixtures/eslintrc/invalid.json", "fixtures"];
+        let options = lint_command().run_inner(args.as_slice()).unwrap().lint_options;
+        let result = LintRunner::new(options).run();
+        assert!(matches!(result, CliRunResult::InvalidOptions { .. }));
+    }
 }
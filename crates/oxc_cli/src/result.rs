@@ -21,6 +21,8 @@ pub struct LintResult {
     pub number_of_warnings: usize,
     pub number_of_errors: usize,
     pub max_warnings_exceeded: bool,
+    /// Print the numbers of files and problems, not for the machine-readable output formats.
+    pub print_summary: bool,
 }
 
 impl Termination for CliRunResult {
@@ -42,7 +44,13 @@ impl Termination for CliRunResult {
                 number_of_warnings,
                 number_of_errors,
                 max_warnings_exceeded,
+                print_summary,
             }) => {
+                let exit_code = u8::from(number_of_errors > 0 || max_warnings_exceeded);
+                if !print_summary {
+                    return ExitCode::from(exit_code);
+                }
+
                 let ms = duration.as_millis();
                 let threads = rayon::current_num_threads();
                 let number_of_diagnostics = number_of_warnings + number_of_errors;
@@ -67,7 +75,6 @@ impl Termination for CliRunResult {
                     if number_of_errors == 1 { "" } else { "s" }
                 );
 
-                let exit_code = u8::from(number_of_errors > 0);
                 ExitCode::from(exit_code)
             }
             Self::TypeCheckResult { duration, number_of_diagnostics } => {
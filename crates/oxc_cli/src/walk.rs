@@ -4,10 +4,17 @@ use std::{
 };
 
 use ignore::{overrides::OverrideBuilder, DirEntry};
+use oxc_linter::LINT_PARTIAL_LOADER_EXT;
 use oxc_span::VALID_EXTENSIONS;
 
 use crate::IgnoreOptions;
 
+/// Looked up in every directory like `.gitignore` and the `--ignore-path` file.
+const OXLINTIGNORE: &str = ".oxlintignore";
+
+/// Skipped unless `--no-ignore`, the dependencies are never linted.
+const DEFAULT_IGNORE_PATTERNS: &[&str] = &["**/node_modules"];
+
 pub struct Walk {
     inner: ignore::WalkParallel,
 }
@@ -68,19 +75,21 @@ impl Walk {
 
         if !options.no_ignore {
             inner.add_custom_ignore_filename(&options.ignore_path);
+            inner.add_custom_ignore_filename(OXLINTIGNORE);
 
-            if !options.ignore_pattern.is_empty() {
-                let mut override_builder = OverrideBuilder::new(Path::new("/"));
-                for pattern in &options.ignore_pattern {
-                    // Meaning of ignore pattern is reversed
-                    // <https://docs.rs/ignore/latest/ignore/overrides/struct.OverrideBuilder.html#method.add>
-                    let pattern = format!("!{pattern}");
-                    override_builder.add(&pattern).unwrap();
-                }
-                let overrides = override_builder.build().unwrap();
-                inner.overrides(overrides);
+            let mut override_builder = OverrideBuilder::new(Path::new("/"));
+            let patterns = DEFAULT_IGNORE_PATTERNS.iter().copied();
+            for pattern in patterns.chain(options.ignore_pattern.iter().map(String::as_str)) {
+                // Meaning of ignore pattern is reversed
+                // <https://docs.rs/ignore/latest/ignore/overrides/struct.OverrideBuilder.html#method.add>
+                let pattern = format!("!{pattern}");
+                override_builder.add(&pattern).unwrap();
             }
+            let overrides = override_builder.build().unwrap();
+            inner.overrides(overrides);
         }
+        // The `.gitignore` files apply outside of git repositories too.
+        inner.git_ignore(!options.no_ignore).git_exclude(!options.no_ignore).require_git(false);
         // Turning off `follow_links` because:
         // * following symlinks is a really slow syscall
         // * it is super rare to have symlinked source code
@@ -106,6 +115,8 @@ impl Walk {
             return false;
         }
         let Some(extension) = dir_entry.path().extension() else { return false };
-        VALID_EXTENSIONS.contains(&extension.to_string_lossy().as_ref())
+        let extension = extension.to_string_lossy();
+        VALID_EXTENSIONS.contains(&extension.as_ref())
+            || LINT_PARTIAL_LOADER_EXT.contains(&extension.as_ref())
     }
 }
@@ -1,6 +1,7 @@
 use oxc_allocator::{Box, Vec};
 #[allow(clippy::wildcard_imports)]
 use oxc_ast::ast::*;
+use oxc_span::GetSpan;
 use oxc_syntax::{
     operator::{BinaryOperator, UnaryOperator},
     precedence::{GetPrecedence, Precedence},
@@ -101,6 +102,8 @@ impl<const MINIFY: bool> Gen<MINIFY> for Directive {
 
 impl<'a, const MINIFY: bool> Gen<MINIFY> for Statement<'a> {
     fn gen(&self, p: &mut Codegen<{ MINIFY }>, ctx: Context) {
+        p.print_leading_comments(self.span().start);
+        p.add_source_mapping(self.span());
         match self {
             Self::BlockStatement(stmt) => stmt.gen(p, ctx),
             Self::BreakStatement(stmt) => stmt.gen(p, ctx),
@@ -528,9 +531,10 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for Declaration<'a> {
                 }
             }
             Self::UsingDeclaration(declaration) => {
+                p.print_indent();
                 p.print_space_before_identifier();
                 declaration.gen(p, ctx);
-                p.print_soft_newline();
+                p.print_semicolon_after_statement();
             }
             _ => {}
         }
@@ -541,12 +545,11 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for UsingDeclaration<'a> {
     fn gen(&self, p: &mut Codegen<{ MINIFY }>, ctx: Context) {
         if self.is_await {
             p.print_str(b"await");
-            p.print_soft_space();
+            p.print_hard_space();
         }
         p.print_str(b"using");
-        p.print_soft_space();
+        p.print_hard_space();
         p.print_list(&self.declarations, ctx);
-        p.print_semicolon_after_statement();
     }
 }
 
@@ -647,7 +650,7 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for ImportDeclaration<'a> {
             p.print(b'\'');
             p.print_str(self.source.value.as_bytes());
             p.print(b'\'');
-            self.assertions.gen(p, ctx);
+            self.with_clause.gen(p, ctx);
             p.print_semicolon_after_statement();
             return;
         }
@@ -710,16 +713,18 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for ImportDeclaration<'a> {
         }
         p.print_str(b" from ");
         self.source.gen(p, ctx);
-        self.assertions.gen(p, ctx);
+        self.with_clause.gen(p, ctx);
         p.print_semicolon_after_statement();
     }
 }
 
-impl<'a, const MINIFY: bool> Gen<MINIFY> for Option<Vec<'a, ImportAttribute>> {
+impl<'a, const MINIFY: bool> Gen<MINIFY> for Option<WithClause<'a>> {
     fn gen(&self, p: &mut Codegen<{ MINIFY }>, ctx: Context) {
-        if let Some(assertions) = &self {
-            p.print_str(b"assert");
-            p.print_block(assertions, Separator::Comma, ctx);
+        if let Some(with_clause) = &self {
+            p.print_soft_space();
+            p.print_str(with_clause.attributes_keyword.name.as_bytes());
+            p.print_soft_space();
+            p.print_block(&with_clause.with_entries, Separator::Comma, ctx);
         };
     }
 }
@@ -754,6 +759,7 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for ExportNamedDeclaration<'a> {
                 if let Some(source) = &self.source {
                     p.print_str(b"from");
                     source.gen(p, ctx);
+                    self.with_clause.gen(p, ctx);
                 }
                 p.needs_semicolon = true;
             }
@@ -797,7 +803,7 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for ExportAllDeclaration<'a> {
 
         p.print_str(b" from");
         self.source.gen(p, ctx);
-        self.assertions.gen(p, ctx);
+        self.with_clause.gen(p, ctx);
 
         p.print_semicolon_after_statement();
     }
@@ -834,6 +840,7 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for ExportDefaultDeclarationKind<'a> {
 
 impl<'a, const MINIFY: bool> GenExpr<MINIFY> for Expression<'a> {
     fn gen_expr(&self, p: &mut Codegen<{ MINIFY }>, precedence: Precedence, ctx: Context) {
+        p.add_source_mapping(self.span());
         match self {
             Self::BooleanLiteral(lit) => lit.gen(p, ctx),
             Self::NullLiteral(lit) => lit.gen(p, ctx),
@@ -859,6 +866,7 @@ impl<'a, const MINIFY: bool> GenExpr<MINIFY> for Expression<'a> {
             Self::AssignmentExpression(expr) => expr.gen_expr(p, precedence, ctx),
             Self::SequenceExpression(expr) => expr.gen_expr(p, precedence, ctx),
             Self::ImportExpression(expr) => expr.gen(p, ctx),
+            Self::V8IntrinsicExpression(expr) => expr.gen(p, ctx),
             Self::TemplateLiteral(literal) => literal.gen(p, ctx),
             Self::TaggedTemplateExpression(expr) => expr.gen(p, ctx),
             Self::Super(sup) => sup.gen(p, ctx),
@@ -1131,6 +1139,7 @@ impl<'a, const MINIFY: bool> GenExpr<MINIFY> for PrivateFieldExpression<'a> {
 impl<'a, const MINIFY: bool> GenExpr<MINIFY> for CallExpression<'a> {
     fn gen_expr(&self, p: &mut Codegen<{ MINIFY }>, precedence: Precedence, ctx: Context) {
         p.wrap(precedence > self.precedence(), |p| {
+            p.print_pure_annotation(self.span.start);
             self.callee.gen_expr(p, self.precedence(), ctx);
             if self.optional {
                 p.print_str(b"?.");
@@ -1608,6 +1617,16 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for ImportExpression<'a> {
     }
 }
 
+impl<'a, const MINIFY: bool> Gen<MINIFY> for V8IntrinsicExpression<'a> {
+    fn gen(&self, p: &mut Codegen<{ MINIFY }>, ctx: Context) {
+        p.print(b'%');
+        p.print_str(self.name.name.as_bytes());
+        p.print(b'(');
+        p.print_list(&self.arguments, ctx);
+        p.print(b')');
+    }
+}
+
 impl<'a, const MINIFY: bool> Gen<MINIFY> for TemplateLiteral<'a> {
     fn gen(&self, p: &mut Codegen<{ MINIFY }>, _ctx: Context) {
         p.print(b'`');
@@ -1629,6 +1648,7 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for TemplateLiteral<'a> {
 
 impl<'a, const MINIFY: bool> Gen<MINIFY> for TaggedTemplateExpression<'a> {
     fn gen(&self, p: &mut Codegen<{ MINIFY }>, ctx: Context) {
+        p.print_pure_annotation(self.span.start);
         self.tag.gen_expr(p, Precedence::Call, Context::default());
         self.quasi.gen(p, ctx);
     }
@@ -1661,6 +1681,7 @@ impl<'a, const MINIFY: bool> GenExpr<MINIFY> for ChainExpression<'a> {
 impl<'a, const MINIFY: bool> GenExpr<MINIFY> for NewExpression<'a> {
     fn gen_expr(&self, p: &mut Codegen<{ MINIFY }>, precedence: Precedence, ctx: Context) {
         p.wrap(precedence > self.precedence(), |p| {
+            p.print_pure_annotation(self.span.start);
             p.print_str(b"new ");
             self.callee.gen_expr(p, self.precedence(), ctx);
             p.wrap(true, |p| {
@@ -2001,6 +2022,7 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for PropertyDefinition<'a> {
 
 impl<'a, const MINIFY: bool> Gen<MINIFY> for AccessorProperty<'a> {
     fn gen(&self, p: &mut Codegen<{ MINIFY }>, ctx: Context) {
+        self.decorators.gen(p, ctx);
         if self.r#static {
             p.print_str(b"static ");
         }
@@ -11,12 +11,14 @@
 mod context;
 mod gen;
 mod operator;
+mod sourcemap;
 
 use std::str::from_utf8_unchecked;
 
 #[allow(clippy::wildcard_imports)]
 use oxc_ast::ast::*;
-use oxc_span::Atom;
+use oxc_ast::AttachedComments;
+use oxc_span::{Atom, Span};
 use oxc_syntax::{
     identifier::is_identifier_part,
     operator::{BinaryOperator, UnaryOperator, UpdateOperator},
@@ -29,6 +31,8 @@ use self::{
     gen::{Gen, GenExpr},
     operator::Operator,
 };
+
+pub use crate::sourcemap::{SourceMap, SourceMapBuilder};
 // use crate::mangler::Mangler;
 
 #[derive(Debug, Default, Clone, Copy)]
@@ -58,6 +62,11 @@ pub struct Codegen<const MINIFY: bool> {
 
     /// Track the current indentation level
     indentation: u8,
+
+    sourcemap_builder: Option<SourceMapBuilder>,
+
+    /// The comments printed before the statements.
+    comments: AttachedComments,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -85,9 +94,19 @@ impl<const MINIFY: bool> Codegen<MINIFY> {
             start_of_arrow_expr: 0,
             start_of_default_export: 0,
             indentation: 0,
+            sourcemap_builder: None,
+            comments: AttachedComments::default(),
         }
     }
 
+    /// Record the mappings of the printed code to `source_text`, the text of the `source_name`
+    /// file.
+    #[must_use]
+    pub fn with_source_map(mut self, source_name: &str, source_text: &str) -> Self {
+        self.sourcemap_builder = Some(SourceMapBuilder::new(source_name, source_text));
+        self
+    }
+
     // fn with_mangler(&mut self, mangler: Mangler) {
     // self.mangler = Some(mangler);
     // }
@@ -97,6 +116,20 @@ impl<const MINIFY: bool> Codegen<MINIFY> {
         self.into_code()
     }
 
+    /// Print the comments attached to the statements, e.g. the ones kept by the transformer.
+    #[must_use]
+    pub fn with_comments(mut self, comments: AttachedComments) -> Self {
+        self.comments = comments;
+        self
+    }
+
+    /// Print the program with its source map, `None` unless [Codegen::with_source_map] is used.
+    pub fn build_with_source_map(mut self, program: &Program<'_>) -> (String, Option<SourceMap>) {
+        program.gen(&mut self, Context::default());
+        let source_map = self.sourcemap_builder.take().map(SourceMapBuilder::into_source_map);
+        (self.into_code(), source_map)
+    }
+
     pub fn into_code(self) -> String {
         // SAFETY: criteria of `from_utf8_unchecked`.are met.
         unsafe { String::from_utf8_unchecked(self.code) }
@@ -110,6 +143,38 @@ impl<const MINIFY: bool> Codegen<MINIFY> {
         self.code().len()
     }
 
+    /// Map the current position of the output to the start of `span`. The nodes created by the
+    /// transforms without a position have empty spans, they are not mapped.
+    fn add_source_mapping(&mut self, span: Span) {
+        if span.start == 0 && span.end == 0 {
+            return;
+        }
+        if let Some(builder) = &mut self.sourcemap_builder {
+            builder.add_mapping(&self.code, span.start);
+        }
+    }
+
+    /// Print the comments attached before the statement at `start`, each on its own line.
+    fn print_leading_comments(&mut self, start: u32) {
+        if self.comments.is_empty() {
+            return;
+        }
+        let Some(comments) = self.comments.take_leading(start) else { return };
+        for comment in comments {
+            self.print_indent();
+            self.print_str(comment.as_bytes());
+            self.print(b'\n');
+        }
+    }
+
+    /// Print `/*#__PURE__*/` before the call at `start` if it is annotated.
+    fn print_pure_annotation(&mut self, start: u32) {
+        if !self.comments.is_empty() && self.comments.take_pure(start) {
+            self.print_str(b"/*#__PURE__*/");
+            self.print_soft_space();
+        }
+    }
+
     /// Push a single character into the buffer
     fn print(&mut self, ch: u8) {
         self.code.push(ch);
@@ -0,0 +1,223 @@
+//! Source Map v3
+//!
+//! <https://sourcemaps.info/spec.html>
+
+use std::fmt::Write;
+
+use oxc_span::{LineCol, LineIndex};
+
+const BASE64: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// A source map of the printed code to a single source file.
+#[derive(Debug, Clone)]
+pub struct SourceMap {
+    pub file: Option<String>,
+    pub source: String,
+    pub source_content: Option<String>,
+    /// The VLQ encoded mappings.
+    pub mappings: String,
+}
+
+impl SourceMap {
+    /// Serialize the source map to its JSON format.
+    pub fn to_json_string(&self) -> String {
+        let mut json = String::from("{\"version\":3,");
+        if let Some(file) = &self.file {
+            json.push_str("\"file\":");
+            push_json_string(&mut json, file);
+            json.push(',');
+        }
+        json.push_str("\"sources\":[");
+        push_json_string(&mut json, &self.source);
+        json.push_str("],");
+        if let Some(content) = &self.source_content {
+            json.push_str("\"sourcesContent\":[");
+            push_json_string(&mut json, content);
+            json.push_str("],");
+        }
+        json.push_str("\"names\":[],\"mappings\":");
+        push_json_string(&mut json, &self.mappings);
+        json.push('}');
+        json
+    }
+
+    /// The `//# sourceMappingURL=data:...` comment inlining the source map.
+    pub fn to_data_url(&self) -> String {
+        let json = self.to_json_string();
+        let mut url = String::from("data:application/json;charset=utf-8;base64,");
+        for chunk in json.as_bytes().chunks(3) {
+            let bytes = [chunk[0], *chunk.get(1).unwrap_or(&0), *chunk.get(2).unwrap_or(&0)];
+            let n = (u32::from(bytes[0]) << 16) | (u32::from(bytes[1]) << 8) | u32::from(bytes[2]);
+            for i in 0..4 {
+                if i <= chunk.len() {
+                    url.push(BASE64[(n >> (18 - 6 * i) & 0x3F) as usize] as char);
+                } else {
+                    url.push('=');
+                }
+            }
+        }
+        url
+    }
+}
+
+/// Records the mappings while the code is printed.
+pub struct SourceMapBuilder {
+    source: String,
+    source_text: String,
+    /// The lines and the UTF-16 columns of the offsets in the source text.
+    line_index: LineIndex,
+
+    mappings: String,
+    /// The length of the code when the generated position was last updated.
+    code_len: usize,
+    generated_line: u32,
+    generated_column: u32,
+    /// The last segment, the fields of the next segment are relative to it.
+    prev_generated_line: u32,
+    prev_generated_column: u32,
+    prev_original_line: u32,
+    prev_original_column: u32,
+    has_segment: bool,
+}
+
+impl SourceMapBuilder {
+    pub fn new(source: &str, source_text: &str) -> Self {
+        Self {
+            source: source.to_string(),
+            source_text: source_text.to_string(),
+            line_index: LineIndex::new(source_text),
+            mappings: String::new(),
+            code_len: 0,
+            generated_line: 0,
+            generated_column: 0,
+            prev_generated_line: 0,
+            prev_generated_column: 0,
+            prev_original_line: 0,
+            prev_original_column: 0,
+            has_segment: false,
+        }
+    }
+
+    /// Map the end of `code` to the `offset` of the source text.
+    pub fn add_mapping(&mut self, code: &[u8], offset: u32) {
+        self.update_generated_position(code);
+        if offset as usize > self.source_text.len() {
+            return;
+        }
+        let LineCol { line: original_line, col: original_column } =
+            self.line_index.line_col_utf16(offset);
+        if self.has_segment
+            && self.prev_generated_line == self.generated_line
+            && self.prev_generated_column == self.generated_column
+        {
+            return;
+        }
+
+        if self.prev_generated_line != self.generated_line {
+            for _ in self.prev_generated_line..self.generated_line {
+                self.mappings.push(';');
+            }
+            self.prev_generated_column = 0;
+        } else if self.has_segment {
+            self.mappings.push(',');
+        }
+        encode_vlq(&mut self.mappings, diff(self.generated_column, self.prev_generated_column));
+        // There is a single source, its index is always 0.
+        encode_vlq(&mut self.mappings, 0);
+        encode_vlq(&mut self.mappings, diff(original_line, self.prev_original_line));
+        encode_vlq(&mut self.mappings, diff(original_column, self.prev_original_column));
+
+        self.prev_generated_line = self.generated_line;
+        self.prev_generated_column = self.generated_column;
+        self.prev_original_line = original_line;
+        self.prev_original_column = original_column;
+        self.has_segment = true;
+    }
+
+    pub fn into_source_map(self) -> SourceMap {
+        SourceMap {
+            file: None,
+            source: self.source,
+            source_content: Some(self.source_text),
+            mappings: self.mappings,
+        }
+    }
+
+    /// Count the lines and the UTF-16 columns printed since the last update.
+    fn update_generated_position(&mut self, code: &[u8]) {
+        let Some(printed) = code.get(self.code_len..).and_then(|s| std::str::from_utf8(s).ok())
+        else {
+            return;
+        };
+        for ch in printed.chars() {
+            if ch == '\n' {
+                self.generated_line += 1;
+                self.generated_column = 0;
+            } else {
+                #[allow(clippy::cast_possible_truncation)]
+                let len = ch.len_utf16() as u32;
+                self.generated_column += len;
+            }
+        }
+        self.code_len = code.len();
+    }
+}
+
+fn diff(value: u32, prev: u32) -> i64 {
+    i64::from(value) - i64::from(prev)
+}
+
+/// Base64 VLQ, the sign is the least significant bit.
+fn encode_vlq(out: &mut String, value: i64) {
+    let mut vlq = if value < 0 { ((-value) << 1) | 1 } else { value << 1 };
+    loop {
+        let mut digit = vlq & 0b1_1111;
+        vlq >>= 5;
+        if vlq > 0 {
+            digit |= 0b10_0000;
+        }
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        out.push(BASE64[digit as usize] as char);
+        if vlq == 0 {
+            break;
+        }
+    }
+}
+
+fn push_json_string(json: &mut String, value: &str) {
+    json.push('"');
+    for ch in value.chars() {
+        match ch {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            ch if ch < ' ' => {
+                let _ = write!(json, "\\u{:04x}", ch as u32);
+            }
+            ch => json.push(ch),
+        }
+    }
+    json.push('"');
+}
+
+#[test]
+fn test() {
+    let mut mappings = String::new();
+    for value in [0, 1, -1, 15, 16, -16, 1000] {
+        encode_vlq(&mut mappings, value);
+        mappings.push(',');
+    }
+    assert_eq!(mappings, "A,C,D,e,gB,hB,w+B,");
+
+    let mut builder = SourceMapBuilder::new("test.js", "a;\n  b;\n");
+    builder.add_mapping(b"", 0);
+    builder.add_mapping(b"a;\n", 5);
+    let source_map = builder.into_source_map();
+    assert_eq!(source_map.mappings, "AAAA;AACE");
+    assert_eq!(
+        source_map.to_json_string(),
+        r#"{"version":3,"sources":["test.js"],"sourcesContent":["a;\n  b;\n"],"names":[],"mappings":"AAAA;AACE"}"#
+    );
+}
@@ -0,0 +1,208 @@
+use std::{
+    collections::BTreeMap,
+    fs, io,
+    path::{Component, Path},
+};
+
+use serde_json::{json, Value};
+
+use crate::{
+    reporter::{source_text, split_rule_name},
+    Error,
+};
+
+const VERSION: u64 = 1;
+
+/// The known problems of a project, which are not reported again.
+///
+/// A problem is identified by its file, its rule and a fingerprint of its message and of the
+/// code it points to, so the lines added or removed above it do not make it new.
+/// Each recorded problem suppresses one matching diagnostic, a second `debugger` statement
+/// in the same file is reported.
+///
+/// ```json
+/// {
+///   "version": 1,
+///   "files": {
+///     "src/index.js": [{ "rule": "eslint/no-debugger", "fingerprint": "af63bd4c8601b7be" }]
+///   }
+/// }
+/// ```
+#[derive(Debug, Default)]
+pub struct Baseline {
+    /// The problems of each file, sorted for stable diffs of the baseline file.
+    files: BTreeMap<String, Vec<Problem>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct Problem {
+    /// `plugin/rule`, `None` for the diagnostics of the parser and the semantic analysis.
+    rule: Option<String>,
+    fingerprint: String,
+}
+
+impl Baseline {
+    /// # Errors
+    ///
+    /// The file can not be read or is not a baseline file.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let invalid = |message: &str| io::Error::new(io::ErrorKind::InvalidData, message);
+        let value: Value = serde_json::from_str(&fs::read_to_string(path)?)?;
+        if value["version"].as_u64() != Some(VERSION) {
+            return Err(invalid("unsupported baseline version"));
+        }
+        let mut files = BTreeMap::new();
+        for (file, problems) in value["files"].as_object().into_iter().flatten() {
+            let problems = problems
+                .as_array()
+                .into_iter()
+                .flatten()
+                .map(|problem| {
+                    Some(Problem {
+                        rule: problem["rule"].as_str().map(str::to_string),
+                        fingerprint: problem["fingerprint"].as_str()?.to_string(),
+                    })
+                })
+                .collect::<Option<Vec<_>>>()
+                .ok_or_else(|| invalid("a problem has no fingerprint"))?;
+            files.insert(file.clone(), problems);
+        }
+        Ok(Self { files })
+    }
+
+    /// # Errors
+    ///
+    /// The file can not be written.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let files = self
+            .files
+            .iter()
+            .map(|(file, problems)| {
+                let problems = problems
+                    .iter()
+                    .map(|problem| json!({ "rule": problem.rule, "fingerprint": problem.fingerprint }))
+                    .collect::<Vec<_>>();
+                (file.clone(), Value::Array(problems))
+            })
+            .collect::<serde_json::Map<_, _>>();
+        fs::write(path, format!("{:#}\n", json!({ "version": VERSION, "files": files })))
+    }
+
+    /// The number of recorded problems.
+    pub fn len(&self) -> usize {
+        self.files.values().map(Vec::len).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Record the diagnostics of the file at `path`.
+    pub fn record(&mut self, path: &Path, diagnostics: &[Error]) {
+        if diagnostics.is_empty() {
+            return;
+        }
+        let problems = self.files.entry(normalize_path(path)).or_default();
+        problems.extend(diagnostics.iter().map(Problem::new));
+        problems.sort_unstable();
+    }
+
+    /// The diagnostics of the file at `path` which are not recorded, the recorded ones are
+    /// used up by the matching diagnostics.
+    pub fn filter(&mut self, path: &Path, diagnostics: Vec<Error>) -> Vec<Error> {
+        let Some(problems) = self.files.get_mut(&normalize_path(path)) else {
+            return diagnostics;
+        };
+        diagnostics
+            .into_iter()
+            .filter(|diagnostic| {
+                let problem = Problem::new(diagnostic);
+                let Some(index) = problems.iter().position(|p| *p == problem) else {
+                    return true;
+                };
+                problems.swap_remove(index);
+                false
+            })
+            .collect()
+    }
+}
+
+impl Problem {
+    fn new(diagnostic: &Error) -> Self {
+        let message = diagnostic.to_string();
+        let (rule, message) = split_rule_name(&message);
+        let code = diagnostic
+            .labels()
+            .and_then(|mut labels| labels.next())
+            .zip(source_text(diagnostic))
+            .and_then(|(label, source_text)| {
+                source_text.get(label.offset()..label.offset() + label.len())
+            })
+            .unwrap_or_default();
+        Self {
+            rule: rule.map(|(plugin_name, rule_name)| format!("{plugin_name}/{rule_name}")),
+            fingerprint: format!("{:016x}", fnv1a([message, "\n", code.trim()])),
+        }
+    }
+}
+
+/// FNV-1a, the fingerprints are stored and must not change between the builds.
+fn fnv1a<'a>(texts: impl IntoIterator<Item = &'a str>) -> u64 {
+    texts.into_iter().flat_map(str::bytes).fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// `./src\index.js` -> `src/index.js`
+fn normalize_path(path: &Path) -> String {
+    path.components()
+        .filter(|component| !matches!(component, Component::CurDir))
+        .map(|component| component.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use miette::{Diagnostic, SourceSpan};
+
+    use super::Baseline;
+    use crate::{thiserror::Error, DiagnosticService};
+
+    #[derive(Debug, Error, Diagnostic)]
+    #[error("eslint(no-debugger): `debugger` statement is not allowed")]
+    #[diagnostic(severity(warning))]
+    struct NoDebugger(#[label] SourceSpan);
+
+    fn diagnostics(source_text: &str) -> Vec<crate::Error> {
+        let diagnostics = source_text
+            .match_indices("debugger")
+            .map(|(offset, _)| crate::Error::new(NoDebugger((offset, 8).into())))
+            .collect();
+        DiagnosticService::wrap_diagnostics(Path::new("a.js"), source_text, diagnostics).1
+    }
+
+    #[test]
+    fn baseline() {
+        let mut baseline = Baseline::default();
+        baseline.record(Path::new("./src/a.js"), &diagnostics("debugger;\n"));
+        assert_eq!(baseline.len(), 1);
+
+        let path =
+            std::env::temp_dir().join(format!("oxlint-baseline-{}.json", std::process::id()));
+        baseline.save(&path).unwrap();
+        let mut baseline = Baseline::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(baseline.len(), 1);
+
+        // Moved by a new line, and a new problem
+        let diagnostics =
+            baseline.filter(Path::new("src/a.js"), diagnostics("\ndebugger;\ndebugger;"));
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].labels().unwrap().next().unwrap().offset(), 11);
+        // Other files are not suppressed
+        assert_eq!(baseline.filter(Path::new("b.js"), self::diagnostics("debugger;")).len(), 1);
+    }
+}
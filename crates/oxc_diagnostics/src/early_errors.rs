@@ -0,0 +1,83 @@
+use std::fmt;
+
+use miette::{Diagnostic, LabeledSpan, Severity, SourceCode};
+
+use crate::Error;
+
+/// How the [early errors](https://tc39.es/ecma262/#early-error) are reported, e.g. the duplicate
+/// bindings or the `delete` of an identifier in the strict mode.
+///
+/// The early errors do not change the AST, the tools working on the invalid code, e.g. the
+/// editors or the codemods, can downgrade them and keep the program.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum EarlyErrors {
+    #[default]
+    Error,
+    /// Reported with [Severity::Warning]
+    Warning,
+    /// Not reported
+    Ignore,
+}
+
+impl EarlyErrors {
+    /// The early error as it is reported, `None` when it is ignored.
+    pub fn report(self, error: Error) -> Option<Error> {
+        match self {
+            Self::Error => Some(error),
+            Self::Warning => Some(DowngradedEarlyError { error }.into()),
+            Self::Ignore => None,
+        }
+    }
+}
+
+/// An early error reported with [Severity::Warning]
+#[derive(Debug)]
+struct DowngradedEarlyError {
+    error: Error,
+}
+
+impl fmt::Display for DowngradedEarlyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.error.fmt(f)
+    }
+}
+
+impl std::error::Error for DowngradedEarlyError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.error.source()
+    }
+}
+
+impl Diagnostic for DowngradedEarlyError {
+    fn code<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        self.error.code()
+    }
+
+    fn severity(&self) -> Option<Severity> {
+        Some(Severity::Warning)
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        self.error.help()
+    }
+
+    fn url<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        self.error.url()
+    }
+
+    fn source_code(&self) -> Option<&dyn SourceCode> {
+        self.error.source_code()
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        self.error.labels()
+    }
+
+    fn related<'a>(&'a self) -> Option<Box<dyn Iterator<Item = &'a dyn Diagnostic> + 'a>> {
+        self.error.related()
+    }
+
+    fn diagnostic_source(&self) -> Option<&dyn Diagnostic> {
+        self.error.diagnostic_source()
+    }
+}
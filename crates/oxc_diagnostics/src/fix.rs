@@ -0,0 +1,67 @@
+use std::fmt;
+
+use miette::{Diagnostic, LabeledSpan, Severity, SourceCode, SourceSpan};
+
+use crate::Error;
+
+/// A diagnostic together with the edit fixing it, for the output formats reporting the fixes
+/// without applying them, e.g. SARIF.
+#[derive(Debug)]
+pub struct DiagnosticWithFix {
+    pub error: Error,
+    /// The replaced range of the source text, in bytes.
+    pub span: SourceSpan,
+    pub content: String,
+}
+
+impl DiagnosticWithFix {
+    pub fn new(error: Error, span: SourceSpan, content: String) -> Self {
+        Self { error, span, content }
+    }
+}
+
+impl fmt::Display for DiagnosticWithFix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.error.fmt(f)
+    }
+}
+
+impl std::error::Error for DiagnosticWithFix {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.error.source()
+    }
+}
+
+impl Diagnostic for DiagnosticWithFix {
+    fn code<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        self.error.code()
+    }
+
+    fn severity(&self) -> Option<Severity> {
+        self.error.severity()
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        self.error.help()
+    }
+
+    fn url<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        self.error.url()
+    }
+
+    fn source_code(&self) -> Option<&dyn SourceCode> {
+        self.error.source_code()
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        self.error.labels()
+    }
+
+    fn related<'a>(&'a self) -> Option<Box<dyn Iterator<Item = &'a dyn Diagnostic> + 'a>> {
+        self.error.related()
+    }
+
+    fn diagnostic_source(&self) -> Option<&dyn Diagnostic> {
+        self.error.diagnostic_source()
+    }
+}
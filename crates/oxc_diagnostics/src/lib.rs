@@ -1,13 +1,22 @@
 //! Diagnostics Wrapper
 //! Exports `thiserror` and `miette`
 
+mod baseline;
+mod early_errors;
+mod fix;
 mod graphic_reporter;
 mod graphical_theme;
+pub mod reporter;
 mod service;
 
 use std::path::PathBuf;
 
-pub use crate::service::{DiagnosticSender, DiagnosticService, DiagnosticTuple};
+pub use crate::{
+    baseline::Baseline,
+    early_errors::EarlyErrors,
+    fix::DiagnosticWithFix,
+    service::{DiagnosticSender, DiagnosticService, DiagnosticTuple},
+};
 pub use graphic_reporter::{GraphicalReportHandler, GraphicalTheme};
 pub use miette;
 pub use thiserror;
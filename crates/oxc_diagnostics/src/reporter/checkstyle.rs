@@ -0,0 +1,70 @@
+use std::path::Path;
+
+use miette::Severity;
+
+use super::{xml_escape, DiagnosticInfo, DiagnosticReporter};
+use crate::Error;
+
+/// The Checkstyle XML output, as the ESLint `checkstyle` formatter.
+#[derive(Default)]
+pub struct CheckstyleReporter {
+    files: Vec<String>,
+}
+
+impl DiagnosticReporter for CheckstyleReporter {
+    fn render_diagnostics(&mut self, path: &Path, diagnostics: Vec<Error>) -> Option<String> {
+        let mut file = format!("<file name=\"{}\">", xml_escape(&path.to_string_lossy()));
+        for diagnostic in &diagnostics {
+            let info = DiagnosticInfo::new(diagnostic);
+            let severity = match info.severity {
+                Severity::Error => "error",
+                Severity::Warning => "warning",
+                Severity::Advice => "info",
+            };
+            let (message, source) = match &info.rule_id {
+                Some(rule_id) => {
+                    (format!("{} ({rule_id})", info.message), format!("eslint.rules.{rule_id}"))
+                }
+                None => (info.message.clone(), String::new()),
+            };
+            file.push_str(&format!(
+                "<error line=\"{}\" column=\"{}\" severity=\"{severity}\" message=\"{}\" source=\"{}\" />",
+                info.start.0,
+                info.start.1,
+                xml_escape(&message),
+                xml_escape(&source),
+            ));
+        }
+        file.push_str("</file>");
+        self.files.push(file);
+        None
+    }
+
+    fn finish(&mut self) -> Option<String> {
+        Some(format!(
+            "<?xml version=\"1.0\" encoding=\"utf-8\"?><checkstyle version=\"4.3\">{}</checkstyle>\n",
+            self.files.join("")
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::CheckstyleReporter;
+    use crate::reporter::{test::diagnostics, DiagnosticReporter};
+
+    #[test]
+    fn checkstyle() {
+        let (path, diagnostics) = diagnostics();
+        let mut reporter = CheckstyleReporter::default();
+        assert!(reporter.render_diagnostics(&path, diagnostics).is_none());
+        assert_eq!(
+            reporter.finish().unwrap(),
+            "<?xml version=\"1.0\" encoding=\"utf-8\"?><checkstyle version=\"4.3\">\
+            <file name=\"src/index.js\">\
+            <error line=\"2\" column=\"8\" severity=\"warning\" message=\"Unexpected focused test. (jest/no-focused-tests)\" source=\"eslint.rules.jest/no-focused-tests\" />\
+            <error line=\"1\" column=\"5\" severity=\"error\" message=\"Identifier `a` has already been declared\" source=\"\" />\
+            </file></checkstyle>\n"
+        );
+    }
+}
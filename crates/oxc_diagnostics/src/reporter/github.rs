@@ -0,0 +1,69 @@
+use std::path::Path;
+
+use miette::Severity;
+
+use super::{DiagnosticInfo, DiagnosticReporter};
+use crate::Error;
+
+/// The [workflow commands](https://docs.github.com/en/actions/using-workflows/workflow-commands-for-github-actions#setting-an-error-message)
+/// annotating the problems in the GitHub Actions.
+#[derive(Default)]
+pub struct GithubReporter;
+
+impl DiagnosticReporter for GithubReporter {
+    fn render_diagnostics(&mut self, path: &Path, diagnostics: Vec<Error>) -> Option<String> {
+        let file = escape_property(&path.to_string_lossy().replace('\\', "/"));
+        let mut output = String::new();
+        for diagnostic in &diagnostics {
+            let info = DiagnosticInfo::new(diagnostic);
+            let command = match info.severity {
+                Severity::Error => "error",
+                Severity::Warning => "warning",
+                Severity::Advice => "notice",
+            };
+            let title = info.rule_id.as_deref().map_or_else(
+                || "oxlint".to_string(),
+                |rule_id| escape_property(&format!("oxlint({rule_id})")),
+            );
+            output.push_str(&format!(
+                "::{command} file={file},line={},endLine={},col={},endColumn={},title={title}::{}\n",
+                info.start.0,
+                info.end.0,
+                info.start.1,
+                info.end.1,
+                escape_data(&info.message),
+            ));
+        }
+        Some(output)
+    }
+
+    fn finish(&mut self) -> Option<String> {
+        None
+    }
+}
+
+fn escape_data(value: &str) -> String {
+    value.replace('%', "%25").replace('\r', "%0D").replace('\n', "%0A")
+}
+
+fn escape_property(value: &str) -> String {
+    escape_data(value).replace(':', "%3A").replace(',', "%2C")
+}
+
+#[cfg(test)]
+mod test {
+    use super::GithubReporter;
+    use crate::reporter::{test::diagnostics, DiagnosticReporter};
+
+    #[test]
+    fn github() {
+        let (path, diagnostics) = diagnostics();
+        let mut reporter = GithubReporter;
+        assert_eq!(
+            reporter.render_diagnostics(&path, diagnostics).unwrap(),
+            "::warning file=src/index.js,line=2,endLine=2,col=8,endColumn=11,title=oxlint(jest/no-focused-tests)::Unexpected focused test.\n\
+            ::error file=src/index.js,line=1,endLine=1,col=5,endColumn=6,title=oxlint::Identifier `a` has already been declared\n"
+        );
+        assert!(reporter.finish().is_none());
+    }
+}
@@ -0,0 +1,31 @@
+use std::path::Path;
+
+use super::DiagnosticReporter;
+use crate::{Error, GraphicalReportHandler, MinifiedFileError};
+
+/// The default output, the diagnostics are drawn with their source code.
+#[derive(Default)]
+pub struct GraphicalReporter {
+    handler: GraphicalReportHandler,
+}
+
+impl DiagnosticReporter for GraphicalReporter {
+    fn render_diagnostics(&mut self, path: &Path, diagnostics: Vec<Error>) -> Option<String> {
+        let mut output = String::new();
+        for diagnostic in diagnostics {
+            let mut err = String::new();
+            self.handler.render_report(&mut err, diagnostic.as_ref()).unwrap();
+            // Skip large output and print only once
+            if err.lines().any(|line| line.len() >= 400) {
+                let minified_diagnostic = Error::new(MinifiedFileError(path.to_path_buf()));
+                return Some(format!("{minified_diagnostic:?}"));
+            }
+            output.push_str(&err);
+        }
+        Some(output)
+    }
+
+    fn finish(&mut self) -> Option<String> {
+        None
+    }
+}
@@ -0,0 +1,90 @@
+use std::path::Path;
+
+use miette::Severity;
+use serde_json::{json, Value};
+
+use super::{DiagnosticInfo, DiagnosticReporter};
+use crate::Error;
+
+/// The output of the ESLint `json` formatter, an array of the files with their problems.
+#[derive(Default)]
+pub struct JsonReporter {
+    results: Vec<Value>,
+}
+
+impl DiagnosticReporter for JsonReporter {
+    fn render_diagnostics(&mut self, path: &Path, diagnostics: Vec<Error>) -> Option<String> {
+        let mut counts = [0; 4];
+        let messages = diagnostics
+            .iter()
+            .map(|diagnostic| {
+                let info = DiagnosticInfo::new(diagnostic);
+                let is_error = info.severity == Severity::Error;
+                counts[usize::from(!is_error)] += 1;
+                if info.fix.is_some() {
+                    counts[2 + usize::from(!is_error)] += 1;
+                }
+                let mut message = json!({
+                    "ruleId": info.rule_id,
+                    "severity": if is_error { 2 } else { 1 },
+                    "message": info.message,
+                    "line": info.start.0,
+                    "column": info.start.1,
+                    "endLine": info.end.0,
+                    "endColumn": info.end.1,
+                });
+                if let Some(((start, end), text)) = info.fix {
+                    message["fix"] = json!({ "range": [start, end], "text": text });
+                }
+                message
+            })
+            .collect::<Vec<_>>();
+        let [error_count, warning_count, fixable_error_count, fixable_warning_count] = counts;
+        self.results.push(json!({
+            "filePath": path.to_string_lossy(),
+            "messages": messages,
+            "errorCount": error_count,
+            "warningCount": warning_count,
+            "fixableErrorCount": fixable_error_count,
+            "fixableWarningCount": fixable_warning_count,
+        }));
+        None
+    }
+
+    fn finish(&mut self) -> Option<String> {
+        Some(format!("{}\n", Value::Array(std::mem::take(&mut self.results))))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::Value;
+
+    use super::JsonReporter;
+    use crate::reporter::{test::diagnostics, DiagnosticReporter};
+
+    #[test]
+    fn json() {
+        let (path, diagnostics) = diagnostics();
+        let mut reporter = JsonReporter::default();
+        assert!(reporter.render_diagnostics(&path, diagnostics).is_none());
+        let output: Value = serde_json::from_str(&reporter.finish().unwrap()).unwrap();
+
+        let result = &output[0];
+        assert_eq!(result["filePath"], "src/index.js");
+        assert_eq!(result["errorCount"], 1);
+        assert_eq!(result["warningCount"], 1);
+        assert_eq!(result["fixableWarningCount"], 1);
+        let message = &result["messages"][0];
+        assert_eq!(message["ruleId"], "jest/no-focused-tests");
+        assert_eq!(message["severity"], 1);
+        assert_eq!(message["message"], "Unexpected focused test.");
+        assert_eq!((&message["line"], &message["column"]), (&2.into(), &8.into()));
+        assert_eq!((&message["endLine"], &message["endColumn"]), (&2.into(), &11.into()));
+        assert_eq!(message["fix"]["range"], serde_json::json!([14, 17]));
+        assert_eq!(message["fix"]["text"], "it");
+        let message = &result["messages"][1];
+        assert_eq!(message["ruleId"], Value::Null);
+        assert_eq!(message["severity"], 2);
+    }
+}
@@ -0,0 +1,76 @@
+use std::path::Path;
+
+use miette::Severity;
+
+use super::{xml_escape, DiagnosticInfo, DiagnosticReporter};
+use crate::Error;
+
+/// The JUnit XML output, as the ESLint `junit` formatter: a test suite for each file
+/// and a failed test case for each problem.
+#[derive(Default)]
+pub struct JunitReporter {
+    test_suites: Vec<String>,
+}
+
+impl DiagnosticReporter for JunitReporter {
+    fn render_diagnostics(&mut self, path: &Path, diagnostics: Vec<Error>) -> Option<String> {
+        let name = xml_escape(&path.to_string_lossy());
+        let class_name = xml_escape(&path.with_extension("").to_string_lossy());
+        let mut test_cases = String::new();
+        for diagnostic in &diagnostics {
+            let info = DiagnosticInfo::new(diagnostic);
+            let severity = match info.severity {
+                Severity::Error => "Error",
+                Severity::Warning => "Warning",
+                Severity::Advice => "Advice",
+            };
+            let rule_id = info.rule_id.as_deref().unwrap_or_default();
+            let details = format!(
+                "line {}, col {}, {severity} - {} ({rule_id})",
+                info.start.0, info.start.1, info.message
+            );
+            test_cases.push_str(&format!(
+                "<testcase time=\"0\" name=\"org.eslint.{}\" classname=\"{class_name}\">\
+                <failure message=\"{}\"><![CDATA[{}]]></failure></testcase>\n",
+                xml_escape(rule_id),
+                xml_escape(&info.message),
+                details.replace("]]>", "]]]]><![CDATA[>"),
+            ));
+        }
+        let count = diagnostics.len();
+        self.test_suites.push(format!(
+            "<testsuite package=\"org.eslint\" time=\"0\" tests=\"{count}\" errors=\"{count}\" name=\"{name}\">\n{test_cases}</testsuite>\n"
+        ));
+        None
+    }
+
+    fn finish(&mut self) -> Option<String> {
+        Some(format!(
+            "<?xml version=\"1.0\" encoding=\"utf-8\"?>\n<testsuites>\n{}</testsuites>\n",
+            self.test_suites.join("")
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::JunitReporter;
+    use crate::reporter::{test::diagnostics, DiagnosticReporter};
+
+    #[test]
+    fn junit() {
+        let (path, diagnostics) = diagnostics();
+        let mut reporter = JunitReporter::default();
+        assert!(reporter.render_diagnostics(&path, diagnostics).is_none());
+        assert_eq!(
+            reporter.finish().unwrap(),
+            "<?xml version=\"1.0\" encoding=\"utf-8\"?>\n<testsuites>\n\
+            <testsuite package=\"org.eslint\" time=\"0\" tests=\"2\" errors=\"2\" name=\"src/index.js\">\n\
+            <testcase time=\"0\" name=\"org.eslint.jest/no-focused-tests\" classname=\"src/index\">\
+            <failure message=\"Unexpected focused test.\"><![CDATA[line 2, col 8, Warning - Unexpected focused test. (jest/no-focused-tests)]]></failure></testcase>\n\
+            <testcase time=\"0\" name=\"org.eslint.\" classname=\"src/index\">\
+            <failure message=\"Identifier `a` has already been declared\"><![CDATA[line 1, col 5, Error - Identifier `a` has already been declared ()]]></failure></testcase>\n\
+            </testsuite>\n</testsuites>\n"
+        );
+    }
+}
@@ -0,0 +1,187 @@
+mod checkstyle;
+mod github;
+mod graphical;
+mod json;
+mod junit;
+mod sarif;
+
+use std::path::Path;
+
+use miette::{Severity, SourceSpan};
+use oxc_span::{LineCol, LineIndex};
+
+pub use self::{
+    checkstyle::CheckstyleReporter,
+    github::GithubReporter,
+    graphical::GraphicalReporter,
+    json::JsonReporter,
+    junit::JunitReporter,
+    sarif::{SarifReporter, SarifRule},
+};
+use crate::{DiagnosticWithFix, Error};
+
+/// Renders the diagnostics received by [crate::DiagnosticService] in an output format.
+pub trait DiagnosticReporter {
+    /// Render the reported diagnostics of one file, the returned text is written right away.
+    fn render_diagnostics(&mut self, path: &Path, diagnostics: Vec<Error>) -> Option<String>;
+
+    /// Render the end of the output once all of the files are linted,
+    /// e.g. the formats with a single document.
+    fn finish(&mut self) -> Option<String>;
+}
+
+/// The fields of a diagnostic written by the machine-readable formats.
+pub(crate) struct DiagnosticInfo {
+    /// The rule as ESLint names it, e.g. `no-debugger` or `jest/no-focused-tests`.
+    pub rule_id: Option<String>,
+    pub message: String,
+    pub severity: Severity,
+    /// The 1-based line and column of the start and the end of the first label.
+    pub start: (usize, usize),
+    pub end: (usize, usize),
+    /// The replaced range in UTF-16 code units, and the replacement.
+    pub fix: Option<((usize, usize), String)>,
+}
+
+impl DiagnosticInfo {
+    pub(crate) fn new(diagnostic: &Error) -> Self {
+        let message = diagnostic.to_string();
+        let (rule, message) = split_rule_name(&message);
+        let rule_id = rule.map(|(prefix, rule_name)| match prefix {
+            "eslint" => rule_name.to_string(),
+            "typescript-eslint" => format!("@typescript-eslint/{rule_name}"),
+            prefix => format!("{}/{rule_name}", prefix.trim_start_matches("eslint-plugin-")),
+        });
+        let message = message.to_string();
+        let severity = diagnostic.severity().unwrap_or(Severity::Error);
+
+        let source_text = source_text(diagnostic);
+        let span =
+            diagnostic.labels().and_then(|mut labels| labels.next()).map(|label| *label.inner());
+        let line_index = source_text.map(LineIndex::new);
+        let (start, end) = match (&line_index, span) {
+            (Some(line_index), Some(span)) => (
+                position(line_index, span.offset()),
+                position(line_index, span.offset() + span.len()),
+            ),
+            _ => ((1, 1), (1, 1)),
+        };
+        let fix = line_index.zip(diagnostic.downcast_ref::<DiagnosticWithFix>()).map(
+            |(line_index, fix)| {
+                let utf16_offset = |offset: usize| {
+                    line_index.utf16_offset(u32::try_from(offset).unwrap_or(u32::MAX)) as usize
+                };
+                let range = (
+                    utf16_offset(fix.span.offset()),
+                    utf16_offset(fix.span.offset() + fix.span.len()),
+                );
+                (range, fix.content.clone())
+            },
+        );
+        Self { rule_id, message, severity, start, end, fix }
+    }
+}
+
+/// Escape the text of XML attributes and elements.
+pub(crate) fn xml_escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Split the rule name out of the lint diagnostic messages,
+/// `eslint(no-debugger): message` -> `(Some(("eslint", "no-debugger")), "message")`.
+pub(crate) fn split_rule_name(message: &str) -> (Option<(&str, &str)>, &str) {
+    let rule = message.split_once("): ").and_then(|(head, rest)| {
+        let (plugin_name, rule_name) = head.split_once('(')?;
+        let is_name = |name: &str| {
+            !name.is_empty() && !name.contains(|c: char| c.is_whitespace() || c == '(')
+        };
+        (is_name(plugin_name) && is_name(rule_name)).then_some(((plugin_name, rule_name), rest))
+    });
+    match rule {
+        Some((rule, message)) => (Some(rule), message),
+        None => (None, message),
+    }
+}
+
+/// The whole source text attached to the diagnostic.
+pub(crate) fn source_text(diagnostic: &Error) -> Option<&str> {
+    let source_code = diagnostic.source_code()?;
+    let contents = source_code.read_span(&SourceSpan::from((0, 0)), 0, usize::MAX).ok()?;
+    std::str::from_utf8(contents.data()).ok()
+}
+
+/// The 1-based line and the 1-based column in UTF-16 code units of the offset,
+/// as ESLint and SARIF count them.
+pub(crate) fn position(line_index: &LineIndex, offset: usize) -> (usize, usize) {
+    let LineCol { line, col } =
+        line_index.line_col_utf16(u32::try_from(offset).unwrap_or(u32::MAX));
+    (line as usize + 1, col as usize + 1)
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::{Path, PathBuf};
+
+    use miette::{Diagnostic, SourceSpan};
+
+    use oxc_span::LineIndex;
+
+    use super::split_rule_name;
+    use crate::{thiserror::Error, DiagnosticService, DiagnosticWithFix};
+
+    #[derive(Debug, Error, Diagnostic)]
+    #[error("eslint-plugin-jest(no-focused-tests): Unexpected focused test.")]
+    #[diagnostic(severity(warning))]
+    struct NoFocusedTests(#[label] SourceSpan);
+
+    #[derive(Debug, Error, Diagnostic)]
+    #[error("Identifier `a` has already been declared")]
+    struct Redeclaration(#[label] SourceSpan, #[label] SourceSpan);
+
+    /// A fixable warning of a rule and an error without a rule, as received by the reporters.
+    pub fn diagnostics() -> (PathBuf, Vec<crate::Error>) {
+        let no_focused_tests = crate::Error::new(DiagnosticWithFix::new(
+            crate::Error::new(NoFocusedTests((14, 3).into())),
+            (14, 3).into(),
+            "it".to_string(),
+        ));
+        let redeclaration = crate::Error::new(Redeclaration((4, 1).into(), (11, 1).into()));
+        DiagnosticService::wrap_diagnostics(
+            Path::new("src/index.js"),
+            "let a;\nlet a; fit(a);\n",
+            vec![no_focused_tests, redeclaration],
+        )
+    }
+
+    #[test]
+    fn rule_name() {
+        assert_eq!(
+            split_rule_name("eslint(no-debugger): `debugger` statement is not allowed"),
+            (Some(("eslint", "no-debugger")), "`debugger` statement is not allowed")
+        );
+        assert_eq!(
+            split_rule_name("Unexpected token (1): foo"),
+            (None, "Unexpected token (1): foo")
+        );
+        assert_eq!(split_rule_name("Expected `;`"), (None, "Expected `;`"));
+    }
+
+    #[test]
+    fn position() {
+        let index = LineIndex::new("a\r\nbé😀c\nd");
+        assert_eq!(super::position(&index, 0), (1, 1));
+        assert_eq!(super::position(&index, 10), (2, 5));
+        assert_eq!(super::position(&index, 100), (3, 2));
+    }
+}
@@ -0,0 +1,305 @@
+use std::path::Path;
+
+use miette::{Severity, SourceSpan};
+use oxc_span::LineIndex;
+use serde_json::{json, Value};
+
+use super::{position, source_text, split_rule_name, DiagnosticReporter};
+use crate::{DiagnosticWithFix, Error};
+
+/// The metadata of a rule in the SARIF output.
+#[derive(Debug, Clone)]
+pub struct SarifRule {
+    /// `plugin/rule`, e.g. `eslint/no-debugger`.
+    pub id: String,
+    pub short_description: String,
+    /// The documentation of the rule in Markdown.
+    pub help: String,
+    /// The severity of the rule, `error` or `warning`.
+    pub level: &'static str,
+}
+
+/// [SARIF 2.1.0](https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html) output,
+/// e.g. for the GitHub code scanning.
+///
+/// The results are collected and the whole log is rendered once all of the files are linted.
+pub struct SarifReporter {
+    name: String,
+    version: String,
+    information_uri: String,
+    rules: Vec<SarifRule>,
+    results: Vec<Value>,
+}
+
+impl SarifReporter {
+    /// `rules` are the enabled rules, the diagnostics of the other rules are added to them.
+    pub fn new(
+        name: impl Into<String>,
+        version: impl Into<String>,
+        information_uri: impl Into<String>,
+        rules: Vec<SarifRule>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            version: version.into(),
+            information_uri: information_uri.into(),
+            rules,
+            results: vec![],
+        }
+    }
+
+    /// Find the rule of a diagnostic, the prefixes of the messages are not always the plugin
+    /// names of the rules, e.g. `eslint-plugin-jest(no-focused-tests)` for `jest/no-focused-tests`.
+    fn rule_index(&mut self, prefix: &str, rule_name: &str, level: &'static str) -> usize {
+        let prefix = prefix.replace('_', "-");
+        let position = self.rules.iter().position(|rule| {
+            rule.id.rsplit_once('/').is_some_and(|(plugin_name, name)| {
+                name == rule_name && prefix.contains(&plugin_name.replace('_', "-"))
+            })
+        });
+        position.unwrap_or_else(|| {
+            let id = format!("{prefix}/{rule_name}");
+            let short_description = id.clone();
+            self.rules.push(SarifRule { id, short_description, help: String::new(), level });
+            self.rules.len() - 1
+        })
+    }
+
+    fn result(&mut self, uri: &str, diagnostic: &Error) -> Value {
+        let level = match diagnostic.severity() {
+            Some(Severity::Warning) => "warning",
+            Some(Severity::Advice) => "note",
+            Some(Severity::Error) | None => "error",
+        };
+        let message = diagnostic.to_string();
+        let (rule, message) = split_rule_name(&message);
+        let mut text = message.to_string();
+        if let Some(help) = diagnostic.help() {
+            text = format!("{text}\n{help}");
+        }
+
+        let source_text = source_text(diagnostic);
+        let line_index = source_text.map(LineIndex::new);
+        let region = |span: SourceSpan| {
+            let line_index = line_index.as_ref()?;
+            let (start_line, start_column) = position(line_index, span.offset());
+            let (end_line, end_column) = position(line_index, span.offset() + span.len());
+            Some(json!({
+                "startLine": start_line,
+                "startColumn": start_column,
+                "endLine": end_line,
+                "endColumn": end_column,
+            }))
+        };
+        let location = |span: SourceSpan| {
+            let mut location = json!({ "artifactLocation": { "uri": uri } });
+            if let Some(region) = region(span) {
+                location["region"] = region;
+            }
+            location
+        };
+
+        // The first label is the location of the problem, the other ones are related to it.
+        let labels = diagnostic.labels().map(Iterator::collect::<Vec<_>>).unwrap_or_default();
+        let mut locations = vec![];
+        let mut related_locations = vec![];
+        for (index, label) in labels.into_iter().enumerate() {
+            let physical_location = location(*label.inner());
+            if index == 0 {
+                locations.push(json!({ "physicalLocation": physical_location }));
+                continue;
+            }
+            let mut related = json!({ "id": index, "physicalLocation": physical_location });
+            if let Some(label) = label.label() {
+                related["message"] = json!({ "text": label });
+            }
+            related_locations.push(related);
+        }
+        if locations.is_empty() {
+            locations.push(json!({ "physicalLocation": { "artifactLocation": { "uri": uri } } }));
+        }
+
+        let mut result = json!({
+            "level": level,
+            "message": { "text": text },
+            "locations": locations,
+        });
+        if let Some((prefix, rule_name)) = rule {
+            let index = self.rule_index(prefix, rule_name, level);
+            result["ruleId"] = json!(self.rules[index].id);
+            result["ruleIndex"] = json!(index);
+        }
+        if !related_locations.is_empty() {
+            result["relatedLocations"] = json!(related_locations);
+        }
+        if let Some(fix) = diagnostic.downcast_ref::<DiagnosticWithFix>() {
+            let deleted_region = region(fix.span).unwrap_or_else(
+                || json!({ "byteOffset": fix.span.offset(), "byteLength": fix.span.len() }),
+            );
+            result["fixes"] = json!([{
+                "artifactChanges": [{
+                    "artifactLocation": { "uri": uri },
+                    "replacements": [{
+                        "deletedRegion": deleted_region,
+                        "insertedContent": { "text": fix.content },
+                    }],
+                }],
+            }]);
+        }
+        result
+    }
+}
+
+impl DiagnosticReporter for SarifReporter {
+    fn render_diagnostics(&mut self, path: &Path, diagnostics: Vec<Error>) -> Option<String> {
+        let uri = path_to_uri(path);
+        for diagnostic in &diagnostics {
+            let result = self.result(&uri, diagnostic);
+            self.results.push(result);
+        }
+        None
+    }
+
+    fn finish(&mut self) -> Option<String> {
+        let rules = self
+            .rules
+            .iter()
+            .map(|rule| {
+                let mut descriptor = json!({
+                    "id": rule.id,
+                    "shortDescription": { "text": rule.short_description },
+                    "defaultConfiguration": { "level": rule.level },
+                });
+                if !rule.help.is_empty() {
+                    descriptor["help"] = json!({ "text": rule.help, "markdown": rule.help });
+                }
+                descriptor
+            })
+            .collect::<Vec<_>>();
+        let log = json!({
+            "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+            "version": "2.1.0",
+            "runs": [{
+                "tool": {
+                    "driver": {
+                        "name": self.name,
+                        "version": self.version,
+                        "informationUri": self.information_uri,
+                        "rules": rules,
+                    },
+                },
+                "columnKind": "utf16CodeUnits",
+                "results": std::mem::take(&mut self.results),
+            }],
+        });
+        Some(format!("{log:#}\n"))
+    }
+}
+
+/// The relative URI reference of a path, with `/` separators and the reserved characters escaped.
+fn path_to_uri(path: &Path) -> String {
+    let mut uri = String::new();
+    for (index, component) in path.components().enumerate() {
+        if index > 0 && !uri.ends_with('/') {
+            uri.push('/');
+        }
+        let component = component.as_os_str().to_string_lossy();
+        for byte in component.bytes() {
+            if byte.is_ascii_alphanumeric() || b"-._~!$&'()*+,;=:@/".contains(&byte) {
+                uri.push(byte as char);
+            } else {
+                uri.push_str(&format!("%{byte:02X}"));
+            }
+        }
+    }
+    uri
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use miette::{Diagnostic, SourceSpan};
+    use serde_json::Value;
+
+    use super::{path_to_uri, SarifReporter, SarifRule};
+    use crate::{
+        reporter::DiagnosticReporter, thiserror::Error, DiagnosticService, DiagnosticWithFix,
+    };
+
+    #[derive(Debug, Error, Diagnostic)]
+    #[error("eslint-plugin-jest(no-focused-tests): Unexpected focused test.")]
+    #[diagnostic(severity(warning))]
+    struct NoFocusedTests(#[label] SourceSpan);
+
+    #[derive(Debug, Error, Diagnostic)]
+    #[error("Identifier `a` has already been declared")]
+    struct Redeclaration(
+        #[label("`a` has already been declared here")] SourceSpan,
+        #[label("It can not be redeclared here")] SourceSpan,
+    );
+
+    #[test]
+    fn sarif() {
+        let source_text = "let a;\nlet a; fit(a);\n";
+        let path = Path::new("src/my file.js");
+        let no_focused_tests = crate::Error::new(NoFocusedTests((14, 3).into()));
+        let no_focused_tests = crate::Error::new(DiagnosticWithFix::new(
+            no_focused_tests,
+            (14, 3).into(),
+            "it".to_string(),
+        ));
+        let redeclaration = crate::Error::new(Redeclaration((4, 1).into(), (11, 1).into()));
+        let (path, diagnostics) = DiagnosticService::wrap_diagnostics(
+            path,
+            source_text,
+            vec![no_focused_tests, redeclaration],
+        );
+
+        let rule = SarifRule {
+            id: "jest/no-focused-tests".to_string(),
+            short_description: "Disallow focused tests".to_string(),
+            help: "### What it does\nDisallow focused tests".to_string(),
+            level: "warning",
+        };
+        let mut reporter = SarifReporter::new("oxlint", "0.0.0", "https://oxc", vec![rule]);
+        assert!(reporter.render_diagnostics(&path, diagnostics).is_none());
+        let log: Value = serde_json::from_str(&reporter.finish().unwrap()).unwrap();
+
+        assert_eq!(log["version"], "2.1.0");
+        let run = &log["runs"][0];
+        assert_eq!(run["tool"]["driver"]["rules"][0]["id"], "jest/no-focused-tests");
+        let results = run["results"].as_array().unwrap();
+        assert_eq!(results.len(), 2);
+
+        let result = &results[0];
+        assert_eq!(result["ruleId"], "jest/no-focused-tests");
+        assert_eq!(result["ruleIndex"], 0);
+        assert_eq!(result["level"], "warning");
+        assert_eq!(result["message"]["text"], "Unexpected focused test.");
+        let location = &result["locations"][0]["physicalLocation"];
+        assert_eq!(location["artifactLocation"]["uri"], "src/my%20file.js");
+        assert_eq!(location["region"]["startLine"], 2);
+        assert_eq!(location["region"]["startColumn"], 8);
+        assert_eq!(location["region"]["endColumn"], 11);
+        let replacement = &result["fixes"][0]["artifactChanges"][0]["replacements"][0];
+        assert_eq!(replacement["deletedRegion"]["startColumn"], 8);
+        assert_eq!(replacement["insertedContent"]["text"], "it");
+
+        let result = &results[1];
+        assert!(result.get("ruleId").is_none());
+        assert_eq!(result["level"], "error");
+        assert_eq!(result["locations"][0]["physicalLocation"]["region"]["startLine"], 1);
+        let related = &result["relatedLocations"][0];
+        assert_eq!(related["message"]["text"], "It can not be redeclared here");
+        assert_eq!(related["physicalLocation"]["region"]["startLine"], 2);
+        assert_eq!(related["physicalLocation"]["region"]["startColumn"], 5);
+    }
+
+    #[test]
+    fn uri() {
+        assert_eq!(path_to_uri(Path::new("a/b/c.js")), "a/b/c.js");
+        assert_eq!(path_to_uri(Path::new("a b/c#.js")), "a%20b/c%23.js");
+        assert_eq!(path_to_uri(Path::new("/a/é.js")), "/a/%C3%A9.js");
+    }
+}
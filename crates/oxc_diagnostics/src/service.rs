@@ -6,7 +6,11 @@ use std::{
     sync::Arc,
 };
 
-use crate::{miette::NamedSource, Error, GraphicalReportHandler, MinifiedFileError, Severity};
+use crate::{
+    miette::NamedSource,
+    reporter::{DiagnosticReporter, GraphicalReporter},
+    Baseline, DiagnosticWithFix, Error, Severity,
+};
 
 pub type DiagnosticTuple = (PathBuf, Vec<Error>);
 pub type DiagnosticSender = mpsc::Sender<Option<DiagnosticTuple>>;
@@ -26,6 +30,15 @@ pub struct DiagnosticService {
     /// Total number of errors received
     errors_count: Cell<usize>,
 
+    /// The known problems, they are not reported
+    baseline: Option<Baseline>,
+
+    /// Record the problems in `baseline` instead of reporting them
+    record_baseline: bool,
+
+    /// Renders the diagnostics, the graphical output by default
+    reporter: Box<dyn DiagnosticReporter>,
+
     sender: DiagnosticSender,
     receiver: DiagnosticReceiver,
 }
@@ -38,6 +51,9 @@ impl Default for DiagnosticService {
             max_warnings: None,
             warnings_count: Cell::new(0),
             errors_count: Cell::new(0),
+            baseline: None,
+            record_baseline: false,
+            reporter: Box::<GraphicalReporter>::default(),
             sender,
             receiver,
         }
@@ -57,6 +73,28 @@ impl DiagnosticService {
         self
     }
 
+    #[must_use]
+    pub fn with_baseline(mut self, baseline: Option<Baseline>) -> Self {
+        self.baseline = baseline;
+        self
+    }
+
+    /// Record all of the problems in the baseline, starting from an empty one.
+    #[must_use]
+    pub fn with_record_baseline(mut self, yes: bool) -> Self {
+        if yes {
+            self.baseline = Some(Baseline::default());
+        }
+        self.record_baseline = yes;
+        self
+    }
+
+    #[must_use]
+    pub fn with_reporter(mut self, reporter: Box<dyn DiagnosticReporter>) -> Self {
+        self.reporter = reporter;
+        self
+    }
+
     pub fn sender(&self) -> &DiagnosticSender {
         &self.sender
     }
@@ -69,6 +107,10 @@ impl DiagnosticService {
         self.errors_count.get()
     }
 
+    pub fn baseline(&self) -> Option<&Baseline> {
+        self.baseline.as_ref()
+    }
+
     pub fn max_warnings_exceeded(&self) -> bool {
         self.max_warnings.map_or(false, |max_warnings| self.warnings_count.get() > max_warnings)
     }
@@ -81,7 +123,14 @@ impl DiagnosticService {
         let source = Arc::new(NamedSource::new(path.to_string_lossy(), source_text.to_owned()));
         let diagnostics = diagnostics
             .into_iter()
-            .map(|diagnostic| diagnostic.with_source_code(Arc::clone(&source)))
+            .map(|diagnostic| match diagnostic.downcast::<DiagnosticWithFix>() {
+                // Keep the fix reachable by `downcast_ref` for the reporters.
+                Ok(DiagnosticWithFix { error, span, content }) => {
+                    let error = error.with_source_code(Arc::clone(&source));
+                    Error::new(DiagnosticWithFix::new(error, span, content))
+                }
+                Err(diagnostic) => diagnostic.with_source_code(Arc::clone(&source)),
+            })
             .collect();
         (path.to_path_buf(), diagnostics)
     }
@@ -89,12 +138,19 @@ impl DiagnosticService {
     /// # Panics
     ///
     /// * When the writer fails to write
-    pub fn run(&self) {
+    pub fn run(&mut self) {
         let mut buf_writer = BufWriter::new(std::io::stdout());
-        let handler = GraphicalReportHandler::new();
 
         while let Ok(Some((path, diagnostics))) = self.receiver.recv() {
-            let mut output = String::new();
+            let diagnostics = match &mut self.baseline {
+                Some(baseline) if self.record_baseline => {
+                    baseline.record(&path, &diagnostics);
+                    continue;
+                }
+                Some(baseline) => baseline.filter(&path, diagnostics),
+                None => diagnostics,
+            };
+            let mut reported = vec![];
             for diagnostic in diagnostics {
                 let severity = diagnostic.severity();
                 let is_warning = severity == Some(Severity::Warning);
@@ -120,21 +176,16 @@ impl DiagnosticService {
                         }
                     }
                 }
-
-                let mut err = String::new();
-                handler.render_report(&mut err, diagnostic.as_ref()).unwrap();
-                // Skip large output and print only once
-                if err.lines().any(|line| line.len() >= 400) {
-                    let minified_diagnostic = Error::new(MinifiedFileError(path.clone()));
-                    err = format!("{minified_diagnostic:?}");
-                    output = err;
-                    break;
-                }
-                output.push_str(&err);
+                reported.push(diagnostic);
+            }
+            if let Some(output) = self.reporter.render_diagnostics(&path, reported) {
+                buf_writer.write_all(output.as_bytes()).unwrap();
             }
-            buf_writer.write_all(output.as_bytes()).unwrap();
         }
 
+        if let Some(output) = self.reporter.finish() {
+            buf_writer.write_all(output.as_bytes()).unwrap();
+        }
         buf_writer.flush().unwrap();
     }
 }
@@ -1,4 +1,4 @@
-use oxc_allocator::{Box, Vec};
+use oxc_allocator::Box;
 #[allow(clippy::wildcard_imports)]
 use oxc_ast::ast::*;
 use oxc_syntax::operator::BinaryOperator;
@@ -450,8 +450,9 @@ impl<'a> Gen for Declaration<'a> {
                 p.print_newline();
             }
             Self::UsingDeclaration(declaration) => {
+                p.print_indent();
                 declaration.gen(p);
-                p.print_newline();
+                p.print_semicolon_after_statement();
             }
             Self::TSTypeAliasDeclaration(_)
             | Self::TSInterfaceDeclaration(_)
@@ -482,7 +483,6 @@ impl<'a> Gen for UsingDeclaration<'a> {
         p.print_str(b"using");
         p.print_space();
         p.print_list(&self.declarations);
-        p.print_semicolon();
     }
 }
 
@@ -570,7 +570,7 @@ impl<'a> Gen for ImportDeclaration<'a> {
             p.print(b'\'');
             p.print_str(self.source.value.as_bytes());
             p.print(b'\'');
-            self.assertions.gen(p);
+            self.with_clause.gen(p);
             p.print_semicolon_after_statement();
             return;
         }
@@ -642,18 +642,18 @@ impl<'a> Gen for ImportDeclaration<'a> {
         }
         p.print_str(b" from ");
         self.source.gen(p);
-        self.assertions.gen(p);
+        self.with_clause.gen(p);
         p.print_semicolon_after_statement();
     }
 }
 
-impl<'a> Gen for Option<Vec<'a, ImportAttribute>> {
+impl<'a> Gen for Option<WithClause<'a>> {
     fn gen(&self, p: &mut Formatter) {
-        if let Some(assertions) = &self {
+        if let Some(with_clause) = &self {
             p.print_space();
-            p.print_str(b"assert");
+            p.print_str(with_clause.attributes_keyword.name.as_bytes());
             p.print_space();
-            p.print_block(assertions, Separator::Comma);
+            p.print_block(&with_clause.with_entries, Separator::Comma);
         };
     }
 }
@@ -690,6 +690,7 @@ impl<'a> Gen for ExportNamedDeclaration<'a> {
                     p.print_str(b"from");
                     p.print_space();
                     source.gen(p);
+                    self.with_clause.gen(p);
                 }
                 p.print_semicolon_after_statement();
             }
@@ -733,7 +734,7 @@ impl<'a> Gen for ExportAllDeclaration<'a> {
         p.print_str(b" from");
         p.print_space();
         self.source.gen(p);
-        self.assertions.gen(p);
+        self.with_clause.gen(p);
 
         p.print_semicolon_after_statement();
     }
@@ -787,6 +788,7 @@ impl<'a> Gen for Expression<'a> {
             Self::SequenceExpression(expr) => expr.gen(p),
             Self::ParenthesizedExpression(expr) => expr.gen(p),
             Self::ImportExpression(expr) => expr.gen(p),
+            Self::V8IntrinsicExpression(expr) => expr.gen(p),
             Self::TemplateLiteral(literal) => literal.gen(p),
             Self::TaggedTemplateExpression(expr) => expr.gen(p),
             Self::Super(sup) => sup.gen(p),
@@ -1353,6 +1355,16 @@ impl<'a> Gen for ImportExpression<'a> {
     }
 }
 
+impl<'a> Gen for V8IntrinsicExpression<'a> {
+    fn gen(&self, p: &mut Formatter) {
+        p.print(b'%');
+        p.print_str(self.name.name.as_bytes());
+        p.print(b'(');
+        p.print_list(&self.arguments);
+        p.print(b')');
+    }
+}
+
 impl<'a> Gen for TemplateLiteral<'a> {
     fn gen(&self, p: &mut Formatter) {
         p.print(b'`');
@@ -0,0 +1,559 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    rc::Rc,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc, Arc, PoisonError, RwLock,
+    },
+};
+
+use crate::options::LintOptions;
+use crate::walk::Walk;
+use miette::NamedSource;
+use oxc_allocator::Allocator;
+use oxc_diagnostics::{miette, Error, Severity};
+use oxc_linter::{ESLintConfig, Fix, LintContext, LintOptions as LinterOptions, Linter};
+use oxc_linter_plugin::{make_relative_path_parts, LinterPlugin};
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::{LineCol, LineIndex, SourceType, VALID_EXTENSIONS};
+use tower_lsp::lsp_types::{self, Position, Range, Url};
+
+#[derive(Debug)]
+struct ErrorWithPosition {
+    pub start_pos: Position,
+    pub end_pos: Position,
+    pub miette_err: Error,
+    pub fixed_content: Option<FixedContent>,
+    pub suggestions: Vec<SuggestedContent>,
+    pub disable_comments: Vec<SuggestedContent>,
+    pub labels_with_pos: Vec<LabeledSpanWithPosition>,
+}
+
+#[derive(Debug)]
+struct LabeledSpanWithPosition {
+    pub start_pos: Position,
+    pub end_pos: Position,
+    pub message: Option<String>,
+}
+
+impl ErrorWithPosition {
+    pub fn new(
+        error: Error,
+        text: &str,
+        fixed_content: Option<FixedContent>,
+        suggestions: Vec<SuggestedContent>,
+    ) -> Self {
+        let labels = error.labels().map_or(vec![], Iterator::collect);
+        let line_index = LineIndex::new(text);
+
+        let labels_with_pos: Vec<LabeledSpanWithPosition> = labels
+            .iter()
+            .map(|labeled_span| LabeledSpanWithPosition {
+                start_pos: offset_to_position(labeled_span.offset(), &line_index)
+                    .unwrap_or_default(),
+                end_pos: offset_to_position(
+                    labeled_span.offset() + labeled_span.len(),
+                    &line_index,
+                )
+                .unwrap_or_default(),
+                message: labeled_span.label().map(ToString::to_string),
+            })
+            .collect();
+
+        // The diagnostics without labels, e.g. of an invalid configuration file, are shown at the start.
+        let start_pos = labels_with_pos.first().map(|label| label.start_pos).unwrap_or_default();
+        let end_pos = labels_with_pos.last().map(|label| label.end_pos).unwrap_or_default();
+        let disable_comments = rule_id(&error.to_string())
+            .map_or(vec![], |rule| disable_comments(&rule, start_pos.line, text));
+
+        Self {
+            miette_err: error,
+            start_pos,
+            end_pos,
+            labels_with_pos,
+            fixed_content,
+            suggestions,
+            disable_comments,
+        }
+    }
+
+    fn to_lsp_diagnostic(&self, path: &PathBuf) -> lsp_types::Diagnostic {
+        let severity = match self.miette_err.severity() {
+            Some(Severity::Error) => Some(lsp_types::DiagnosticSeverity::ERROR),
+            Some(Severity::Warning) => Some(lsp_types::DiagnosticSeverity::WARNING),
+            _ => Some(lsp_types::DiagnosticSeverity::INFORMATION),
+        };
+
+        let related_information = Some(
+            self.labels_with_pos
+                .iter()
+                .map(|labeled_span| lsp_types::DiagnosticRelatedInformation {
+                    location: lsp_types::Location {
+                        uri: lsp_types::Url::from_file_path(path).unwrap(),
+                        range: lsp_types::Range {
+                            start: lsp_types::Position {
+                                line: labeled_span.start_pos.line,
+                                character: labeled_span.start_pos.character,
+                            },
+                            end: lsp_types::Position {
+                                line: labeled_span.end_pos.line,
+                                character: labeled_span.end_pos.character,
+                            },
+                        },
+                    },
+                    message: labeled_span.message.clone().unwrap_or_default(),
+                })
+                .collect(),
+        );
+
+        let message = self.miette_err.help().map_or_else(
+            || self.miette_err.to_string(),
+            |help| format!("{}\nhelp: {}", self.miette_err, help),
+        );
+
+        // The rule links to its documentation in the editors.
+        let code = rule_id(&self.miette_err.to_string()).map(lsp_types::NumberOrString::String);
+        let code_description = self
+            .miette_err
+            .url()
+            .and_then(|url| Url::parse(&url.to_string()).ok())
+            .map(|href| lsp_types::CodeDescription { href });
+
+        lsp_types::Diagnostic {
+            range: Range { start: self.start_pos, end: self.end_pos },
+            severity,
+            code,
+            message,
+            source: Some("oxc".into()),
+            code_description,
+            related_information,
+            tags: None,
+            data: None,
+        }
+    }
+
+    fn into_diagnostic_report(self, path: &PathBuf) -> DiagnosticReport {
+        DiagnosticReport {
+            diagnostic: self.to_lsp_diagnostic(path),
+            fixed_content: self.fixed_content,
+            suggestions: self.suggestions,
+            disable_comments: self.disable_comments,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DiagnosticReport {
+    pub diagnostic: lsp_types::Diagnostic,
+    pub fixed_content: Option<FixedContent>,
+    pub suggestions: Vec<SuggestedContent>,
+    /// Insert an `eslint-disable` comment for the rule of the diagnostic.
+    pub disable_comments: Vec<SuggestedContent>,
+}
+
+#[derive(Debug)]
+struct ErrorReport {
+    pub error: Error,
+    pub fixed_content: Option<FixedContent>,
+    pub suggestions: Vec<SuggestedContent>,
+}
+
+#[derive(Debug, Clone)]
+pub struct FixedContent {
+    pub code: String,
+    pub range: Range,
+}
+
+/// A suggestion of a diagnostic, offered as a quick fix but never applied automatically.
+#[derive(Debug, Clone)]
+pub struct SuggestedContent {
+    pub title: String,
+    pub fixed_content: FixedContent,
+}
+
+type Plugin = Arc<RwLock<Option<LinterPlugin>>>;
+
+#[derive(Debug)]
+pub struct IsolatedLintHandler {
+    options: Arc<LintOptions>,
+    linter: Arc<Linter>,
+    plugin: Plugin,
+}
+
+impl IsolatedLintHandler {
+    pub fn new(options: Arc<LintOptions>, linter: Arc<Linter>, plugin: Plugin) -> Self {
+        Self { options, linter, plugin }
+    }
+
+    /// # Panics
+    ///
+    /// * When `mpsc::channel` fails to send.
+    pub fn run_full(&self) -> Vec<(PathBuf, Vec<DiagnosticReport>)> {
+        let number_of_files = Arc::new(AtomicUsize::new(0));
+        let (tx_error, rx_error) = mpsc::channel::<(PathBuf, Vec<ErrorWithPosition>)>();
+
+        self.process_paths(&number_of_files, tx_error);
+        Self::process_diagnostics(&rx_error)
+    }
+
+    /// Lint the text of an open document instead of the file on the disk.
+    pub fn run_single(&self, path: &Path, source_text: &str) -> Option<Vec<DiagnosticReport>> {
+        if Self::is_wanted_ext(path) {
+            Some(
+                Self::lint_source(&self.linter, path, source_text, Arc::clone(&self.plugin))
+                    .map_or(vec![], |(p, errors)| {
+                        errors.into_iter().map(|e| e.into_diagnostic_report(&p)).collect()
+                    }),
+            )
+        } else {
+            None
+        }
+    }
+
+    fn is_wanted_ext(path: &Path) -> bool {
+        path.extension()
+            .map_or(false, |ext| VALID_EXTENSIONS.contains(&ext.to_string_lossy().as_ref()))
+    }
+
+    fn process_paths(
+        &self,
+        number_of_files: &Arc<AtomicUsize>,
+        tx_error: mpsc::Sender<(PathBuf, Vec<ErrorWithPosition>)>,
+    ) {
+        let (tx_path, rx_path) = mpsc::channel::<Box<Path>>();
+
+        let walk = Walk::new(&self.options);
+        let number_of_files = Arc::clone(number_of_files);
+        rayon::spawn(move || {
+            let mut count = 0;
+            walk.iter().for_each(|path| {
+                count += 1;
+                tx_path.send(path).unwrap();
+            });
+            number_of_files.store(count, Ordering::Relaxed);
+        });
+
+        let linter = Arc::clone(&self.linter);
+        let plugin = Arc::clone(&self.plugin);
+        rayon::spawn(move || {
+            while let Ok(path) = rx_path.recv() {
+                let tx_error = tx_error.clone();
+                let linter = Arc::clone(&linter);
+                let plugin = Arc::clone(&plugin);
+                rayon::spawn(move || {
+                    if let Some(diagnostics) = Self::lint_path(&linter, &path, plugin) {
+                        tx_error.send(diagnostics).unwrap();
+                    }
+                    drop(tx_error);
+                });
+            }
+        });
+    }
+
+    fn process_diagnostics(
+        rx_error: &mpsc::Receiver<(PathBuf, Vec<ErrorWithPosition>)>,
+    ) -> Vec<(PathBuf, Vec<DiagnosticReport>)> {
+        rx_error
+            .iter()
+            .map(|(path, errors)| {
+                (
+                    path.clone(),
+                    errors.into_iter().map(|e| e.into_diagnostic_report(&path)).collect(),
+                )
+            })
+            .collect()
+    }
+
+    fn lint_path(
+        linter: &Linter,
+        path: &Path,
+        plugin: Plugin,
+    ) -> Option<(PathBuf, Vec<ErrorWithPosition>)> {
+        let source_text =
+            fs::read_to_string(path).unwrap_or_else(|_| panic!("Failed to read {path:?}"));
+        Self::lint_source(linter, path, &source_text, plugin)
+    }
+
+    fn lint_source(
+        linter: &Linter,
+        path: &Path,
+        source_text: &str,
+        plugin: Plugin,
+    ) -> Option<(PathBuf, Vec<ErrorWithPosition>)> {
+        let allocator = Allocator::default();
+        let source_type =
+            SourceType::from_path(path).unwrap_or_else(|_| panic!("Incorrect {path:?}"));
+        let ret = Parser::new(&allocator, source_text, source_type)
+            .allow_return_outside_function(true)
+            .allow_import_assertions(true)
+            .recover_from_errors(true)
+            .parse();
+
+        if !ret.errors.is_empty() {
+            let reports = ret
+                .errors
+                .into_iter()
+                .map(|diagnostic| ErrorReport {
+                    error: diagnostic,
+                    fixed_content: None,
+                    suggestions: vec![],
+                })
+                .collect();
+
+            return Some(Self::wrap_diagnostics(path, source_text, reports));
+        };
+
+        let program = allocator.alloc(ret.program);
+        let semantic_ret = SemanticBuilder::new(source_text, source_type)
+            .with_trivias(ret.trivias)
+            .with_check_syntax_error(true)
+            .build(program);
+
+        if !semantic_ret.errors.is_empty() {
+            let reports = semantic_ret
+                .errors
+                .into_iter()
+                .map(|diagnostic| ErrorReport {
+                    error: diagnostic,
+                    fixed_content: None,
+                    suggestions: vec![],
+                })
+                .collect();
+            return Some(Self::wrap_diagnostics(path, source_text, reports));
+        };
+
+        let mut lint_ctx =
+            LintContext::new(path.to_path_buf().into_boxed_path(), &Rc::new(semantic_ret.semantic));
+        {
+            if let Ok(guard) = plugin.read() {
+                if let Some(plugin) = &*guard {
+                    plugin
+                        .lint_file(&mut lint_ctx, make_relative_path_parts(&path.into()))
+                        .unwrap();
+                }
+            }
+        }
+
+        drop(plugin); // explicitly drop plugin so that we consume the plugin in this function's body
+
+        let result = linter.run(lint_ctx);
+
+        if result.is_empty() {
+            return None;
+        }
+
+        let fix = linter.options().fix;
+        let line_index = LineIndex::new(source_text);
+        let fixed_content = |fix: Fix| FixedContent {
+            code: fix.content.to_string(),
+            range: Range {
+                start: offset_to_position(fix.span.start as usize, &line_index).unwrap_or_default(),
+                end: offset_to_position(fix.span.end as usize, &line_index).unwrap_or_default(),
+            },
+        };
+        let reports = result
+            .into_iter()
+            .map(|msg| {
+                let suggestions = msg
+                    .suggestions
+                    .into_iter()
+                    .map(|suggestion| SuggestedContent {
+                        title: suggestion.message.to_string(),
+                        fixed_content: fixed_content(suggestion.fix),
+                    })
+                    .collect();
+                let fixed_content = msg.fix.filter(|_| fix).map(fixed_content);
+                ErrorReport { error: msg.error, fixed_content, suggestions }
+            })
+            .collect::<Vec<ErrorReport>>();
+        Some(Self::wrap_diagnostics(path, source_text, reports))
+    }
+
+    fn wrap_diagnostics(
+        path: &Path,
+        source_text: &str,
+        reports: Vec<ErrorReport>,
+    ) -> (PathBuf, Vec<ErrorWithPosition>) {
+        let source = Arc::new(NamedSource::new(path.to_string_lossy(), source_text.to_owned()));
+        let diagnostics = reports
+            .into_iter()
+            .map(|report| {
+                ErrorWithPosition::new(
+                    report.error.with_source_code(Arc::clone(&source)),
+                    source_text,
+                    report.fixed_content,
+                    report.suggestions,
+                )
+            })
+            .collect();
+        (path.to_path_buf(), diagnostics)
+    }
+}
+
+/// The position of a byte offset, with the character counted in UTF-16 code units as in LSP.
+#[allow(clippy::cast_possible_truncation)]
+fn offset_to_position(offset: usize, line_index: &LineIndex) -> Option<Position> {
+    let LineCol { line, col } = line_index.line_col_utf16(u32::try_from(offset).ok()?);
+    Some(Position::new(line, col))
+}
+
+/// The rule of a lint diagnostic as it is named in the `eslint-disable` comments,
+/// e.g. `no-debugger` for `eslint(no-debugger): ...` and `jest/no-focused-tests` for
+/// `eslint-plugin-jest(no-focused-tests): ...`.
+fn rule_id(message: &str) -> Option<String> {
+    let (head, _) = message.split_once("): ")?;
+    let (prefix, rule_name) = head.split_once('(')?;
+    if prefix.is_empty() || prefix.contains(char::is_whitespace) || rule_name.is_empty() {
+        return None;
+    }
+    Some(match prefix {
+        "eslint" => rule_name.to_string(),
+        "typescript-eslint" => format!("@typescript-eslint/{rule_name}"),
+        prefix => format!("{}/{rule_name}", prefix.trim_start_matches("eslint-plugin-")),
+    })
+}
+
+/// Disable the rule for the line of the diagnostic, or for the whole file after its hashbang.
+#[allow(clippy::cast_possible_truncation)]
+fn disable_comments(rule: &str, line: u32, source_text: &str) -> Vec<SuggestedContent> {
+    let line_text = source_text.lines().nth(line as usize).unwrap_or_default();
+    let indent = &line_text[..line_text.len() - line_text.trim_start().len()];
+    let first_line = u32::from(source_text.starts_with("#!"));
+    let insert = |line: u32, code: String| FixedContent {
+        code,
+        range: Range::new(Position::new(line, 0), Position::new(line, 0)),
+    };
+    vec![
+        SuggestedContent {
+            title: format!("Disable {rule} for this line"),
+            fixed_content: insert(line, format!("{indent}// eslint-disable-next-line {rule}\n")),
+        },
+        SuggestedContent {
+            title: format!("Disable {rule} for this file"),
+            fixed_content: insert(first_line, format!("/* eslint-disable {rule} */\n")),
+        },
+    ]
+}
+
+#[derive(Debug)]
+pub struct ServerLinter {
+    linter: RwLock<Arc<Linter>>,
+    plugin: Plugin,
+}
+
+impl ServerLinter {
+    pub fn new() -> Self {
+        let linter = Linter::new().with_fix(true);
+        Self { linter: RwLock::new(Arc::new(linter)), plugin: Arc::new(RwLock::new(None)) }
+    }
+
+    /// Rebuild the linter after the configuration changed, the `.oxlintrc.json` files are read
+    /// again. `config_path` is an ESLint configuration file relative to the workspace root.
+    ///
+    /// # Errors
+    ///
+    /// The ESLint configuration can not be loaded, the linter is left unchanged.
+    pub fn reload(&self, root_uri: &Url, config_path: Option<&str>) -> Result<(), String> {
+        let mut options = LinterOptions::default().with_fix(true).with_oxlintrc(true);
+        if let Some(config_path) = config_path {
+            let path = root_uri.to_file_path().unwrap_or_default().join(config_path);
+            let config = ESLintConfig::from_file(&path).map_err(|error| format!("{error:?}"))?;
+            options = options.with_eslint_config(config);
+        }
+        let linter = Arc::new(Linter::from_options(options));
+        *self.linter.write().unwrap_or_else(PoisonError::into_inner) = linter;
+        Ok(())
+    }
+
+    fn linter(&self) -> Arc<Linter> {
+        Arc::clone(&self.linter.read().unwrap_or_else(PoisonError::into_inner))
+    }
+
+    pub fn make_plugin(&self, root_uri: &Url) {
+        let mut path = root_uri.to_file_path().unwrap();
+        path.push(".oxc/");
+        path.push("plugins");
+        if path.exists() {
+            let mut plugin = self.plugin.write().unwrap();
+            plugin.replace(LinterPlugin::new(&path).unwrap());
+        }
+    }
+
+    pub fn run_full(&self, root_uri: &Url) -> Vec<(PathBuf, Vec<DiagnosticReport>)> {
+        let options = LintOptions {
+            paths: vec![root_uri.to_file_path().unwrap()],
+            ignore_path: "node_modules".into(),
+            ignore_pattern: vec!["!**/node_modules/**/*".into()],
+            fix: true,
+            ..LintOptions::default()
+        };
+
+        IsolatedLintHandler::new(Arc::new(options), self.linter(), Arc::clone(&self.plugin))
+            .run_full()
+    }
+
+    pub fn run_single(
+        &self,
+        root_uri: &Url,
+        uri: &Url,
+        source_text: &str,
+    ) -> Option<Vec<DiagnosticReport>> {
+        let options = LintOptions {
+            paths: vec![root_uri.to_file_path().unwrap()],
+            ignore_path: "node_modules".into(),
+            ignore_pattern: vec!["!**/node_modules/**/*".into()],
+            fix: true,
+            ..LintOptions::default()
+        };
+
+        IsolatedLintHandler::new(Arc::new(options), self.linter(), Arc::clone(&self.plugin))
+            .run_single(&uri.to_file_path().ok()?, source_text)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_span::LineIndex;
+    use tower_lsp::lsp_types::Position;
+
+    use super::{disable_comments, offset_to_position, rule_id};
+
+    #[test]
+    fn position() {
+        let line_index = LineIndex::new("a\nb😀c\nd");
+        assert_eq!(offset_to_position(0, &line_index), Some(Position::new(0, 0)));
+        assert_eq!(offset_to_position(2, &line_index), Some(Position::new(1, 0)));
+        assert_eq!(offset_to_position(7, &line_index), Some(Position::new(1, 3)));
+        assert_eq!(offset_to_position(9, &line_index), Some(Position::new(2, 0)));
+    }
+
+    #[test]
+    fn rule() {
+        assert_eq!(rule_id("eslint(no-debugger): message").as_deref(), Some("no-debugger"));
+        assert_eq!(
+            rule_id("eslint-plugin-jest(no-focused-tests): message").as_deref(),
+            Some("jest/no-focused-tests")
+        );
+        assert_eq!(
+            rule_id("typescript-eslint(no-explicit-any): message").as_deref(),
+            Some("@typescript-eslint/no-explicit-any")
+        );
+        assert_eq!(rule_id("Unexpected token"), None);
+    }
+
+    #[test]
+    fn disable() {
+        let [line, file] =
+            &disable_comments("no-debugger", 1, "#!/usr/bin/env node\n  debugger;")[..]
+        else {
+            unreachable!()
+        };
+        assert_eq!(line.title, "Disable no-debugger for this line");
+        assert_eq!(line.fixed_content.code, "  // eslint-disable-next-line no-debugger\n");
+        assert_eq!(line.fixed_content.range.start, Position::new(1, 0));
+        assert_eq!(file.fixed_content.code, "/* eslint-disable no-debugger */\n");
+        assert_eq!(file.fixed_content.range.start, Position::new(1, 0));
+    }
+}
@@ -0,0 +1,301 @@
+mod linter;
+mod options;
+mod walk;
+
+use crate::linter::{DiagnosticReport, FixedContent, ServerLinter};
+use crate::options::ServerOptions;
+use std::collections::{HashMap, HashSet};
+use std::fmt::Debug;
+use std::sync::{Mutex, PoisonError};
+
+use dashmap::DashMap;
+use futures::future::join_all;
+use tokio::sync::{OnceCell, SetError};
+use tower_lsp::jsonrpc::{Error, ErrorCode, Result};
+use tower_lsp::lsp_types::{
+    CodeAction, CodeActionKind, CodeActionOptions, CodeActionOrCommand, CodeActionParams,
+    CodeActionProviderCapability, CodeActionResponse, DidChangeConfigurationParams,
+    DidChangeTextDocumentParams, DidChangeWatchedFilesParams,
+    DidChangeWatchedFilesRegistrationOptions, DidCloseTextDocumentParams,
+    DidOpenTextDocumentParams, DidSaveTextDocumentParams, FileSystemWatcher, GlobPattern,
+    InitializeParams, InitializeResult, InitializedParams, MessageType, Range, Registration,
+    SaveOptions, ServerCapabilities, ServerInfo, TextDocumentSyncCapability, TextDocumentSyncKind,
+    TextDocumentSyncOptions, TextEdit, Url, WorkDoneProgressOptions, WorkspaceEdit,
+};
+use tower_lsp::{Client, LanguageServer, LspService, Server};
+
+/// The configuration files and the plugins, the linter is reloaded when one of them changes.
+const WATCHED_FILES: &str = "**/{.oxlintrc.json,.oxlintrc,.eslintrc,.eslintrc.*,.oxc/plugins/**}";
+
+#[derive(Debug)]
+struct Backend {
+    client: Client,
+    root_uri: OnceCell<Option<Url>>,
+    options: Mutex<ServerOptions>,
+    server_linter: ServerLinter,
+    diagnostics_report_map: DashMap<Url, Vec<DiagnosticReport>>,
+    /// The text of the open documents, linted instead of the files on the disk.
+    documents: DashMap<Url, String>,
+}
+
+#[tower_lsp::async_trait]
+impl LanguageServer for Backend {
+    async fn initialize(&self, params: InitializeParams) -> Result<InitializeResult> {
+        self.init(params.root_uri)?;
+        if let Some(value) = params.initialization_options {
+            *self.options() = ServerOptions::from_value(&value);
+        }
+
+        Ok(InitializeResult {
+            server_info: Some(ServerInfo { name: "oxc".into(), version: None }),
+            offset_encoding: None,
+            capabilities: ServerCapabilities {
+                text_document_sync: Some(TextDocumentSyncCapability::Options(
+                    TextDocumentSyncOptions {
+                        open_close: Some(true),
+                        change: Some(TextDocumentSyncKind::FULL),
+                        save: Some(SaveOptions { include_text: Some(false) }.into()),
+                        ..TextDocumentSyncOptions::default()
+                    },
+                )),
+                code_action_provider: Some(CodeActionProviderCapability::Options(
+                    CodeActionOptions {
+                        code_action_kinds: Some(vec![CodeActionKind::QUICKFIX]),
+                        work_done_progress_options: WorkDoneProgressOptions {
+                            work_done_progress: None,
+                        },
+                        resolve_provider: None,
+                    },
+                )),
+                ..ServerCapabilities::default()
+            },
+        })
+    }
+
+    async fn initialized(&self, _: InitializedParams) {
+        self.client.log_message(MessageType::INFO, "oxc initialized.").await;
+        self.register_watched_files().await;
+        self.reload().await;
+    }
+
+    async fn shutdown(&self) -> Result<()> {
+        Ok(())
+    }
+
+    async fn did_change_configuration(&self, params: DidChangeConfigurationParams) {
+        let options = ServerOptions::from_value(&params.settings);
+        if *self.options() == options {
+            return;
+        }
+        *self.options() = options;
+        self.reload().await;
+    }
+
+    async fn did_change_watched_files(&self, _: DidChangeWatchedFilesParams) {
+        self.reload().await;
+    }
+
+    async fn did_save(&self, params: DidSaveTextDocumentParams) {
+        let uri = params.text_document.uri;
+        let source_text = self.documents.get(&uri).map(|text| text.clone());
+        if let Some(source_text) = source_text {
+            self.lint_document(uri, &source_text).await;
+        }
+    }
+
+    async fn did_change(&self, mut params: DidChangeTextDocumentParams) {
+        // The whole text is sent with `TextDocumentSyncKind::FULL`.
+        let Some(change) = params.content_changes.pop() else { return };
+        let uri = params.text_document.uri;
+        self.documents.insert(uri.clone(), change.text.clone());
+        self.lint_document(uri, &change.text).await;
+    }
+
+    async fn did_open(&self, params: DidOpenTextDocumentParams) {
+        let uri = params.text_document.uri;
+        let source_text = params.text_document.text;
+        self.documents.insert(uri.clone(), source_text.clone());
+        self.lint_document(uri, &source_text).await;
+    }
+
+    async fn did_close(&self, params: DidCloseTextDocumentParams) {
+        self.documents.remove(&params.text_document.uri);
+    }
+
+    async fn code_action(&self, params: CodeActionParams) -> Result<Option<CodeActionResponse>> {
+        let uri = params.text_document.uri;
+        let Some(reports) = self.diagnostics_report_map.get(&uri).map(|reports| reports.clone())
+        else {
+            return Ok(None);
+        };
+
+        let mut actions = vec![];
+        for report in reports.iter().filter(|r| overlaps(r.diagnostic.range, params.range)) {
+            if let Some(fixed_content) = report.fixed_content.clone() {
+                let title =
+                    report.diagnostic.message.split(':').next().map_or_else(
+                        || "Fix this problem".into(),
+                        |s| format!("Fix this {s} problem"),
+                    );
+                actions.push(Self::code_action(&uri, title, fixed_content, true));
+            }
+
+            for suggestion in report.suggestions.iter().chain(&report.disable_comments) {
+                actions.push(Self::code_action(
+                    &uri,
+                    suggestion.title.clone(),
+                    suggestion.fixed_content.clone(),
+                    false,
+                ));
+            }
+        }
+
+        Ok((!actions.is_empty()).then_some(actions))
+    }
+}
+
+impl Backend {
+    fn code_action(
+        uri: &Url,
+        title: String,
+        fixed_content: FixedContent,
+        is_preferred: bool,
+    ) -> CodeActionOrCommand {
+        CodeActionOrCommand::CodeAction(CodeAction {
+            title,
+            kind: Some(CodeActionKind::QUICKFIX),
+            is_preferred: Some(is_preferred),
+            edit: Some(WorkspaceEdit {
+                changes: Some(HashMap::from([(
+                    uri.clone(),
+                    vec![TextEdit { range: fixed_content.range, new_text: fixed_content.code }],
+                )])),
+                ..WorkspaceEdit::default()
+            }),
+            disabled: None,
+            data: None,
+            diagnostics: None,
+            command: None,
+        })
+    }
+
+    fn init(&self, root_uri: Option<Url>) -> Result<()> {
+        self.root_uri.set(root_uri).map_err(|err| {
+            let message = match err {
+                SetError::AlreadyInitializedError(_) => "root uri already initialized".into(),
+                SetError::InitializingError(_) => "initializing error".into(),
+            };
+
+            Error { code: ErrorCode::ParseError, message, data: None }
+        })
+    }
+
+    fn options(&self) -> std::sync::MutexGuard<'_, ServerOptions> {
+        self.options.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Ask the client to notify the changes of the configuration files,
+    /// the clients without dynamic registration notify them by their own settings.
+    async fn register_watched_files(&self) {
+        let options = DidChangeWatchedFilesRegistrationOptions {
+            watchers: vec![FileSystemWatcher {
+                glob_pattern: GlobPattern::String(WATCHED_FILES.into()),
+                kind: None,
+            }],
+        };
+        let registration = Registration {
+            id: "oxc-watched-files".into(),
+            method: "workspace/didChangeWatchedFiles".into(),
+            register_options: serde_json::to_value(options).ok(),
+        };
+        if let Err(error) = self.client.register_capability(vec![registration]).await {
+            self.client.log_message(MessageType::INFO, error.message).await;
+        }
+    }
+
+    /// Rebuild the linter with the current configuration, then lint the workspace again.
+    async fn reload(&self) {
+        let Some(Some(root_uri)) = self.root_uri.get() else { return };
+        let config_path = self.options().config_path.clone();
+        if let Err(message) = self.server_linter.reload(root_uri, config_path.as_deref()) {
+            self.client.show_message(MessageType::ERROR, message).await;
+        }
+        self.server_linter.make_plugin(root_uri);
+
+        let reports = self
+            .server_linter
+            .run_full(root_uri)
+            .into_iter()
+            .filter_map(|(path, reports)| Some((Url::from_file_path(path).ok()?, reports)))
+            .collect::<Vec<_>>();
+        // Clear the diagnostics of the files without problems anymore.
+        let linted = reports.iter().map(|(uri, _)| uri.clone()).collect::<HashSet<_>>();
+        let mut result = self
+            .diagnostics_report_map
+            .iter()
+            .map(|entry| entry.key().clone())
+            .filter(|uri| !linted.contains(uri))
+            .map(|uri| (uri, vec![]))
+            .collect::<Vec<_>>();
+        result.extend(reports);
+        self.publish_all_diagnostics(result).await;
+
+        // The open documents can differ from the files on the disk.
+        let documents = self
+            .documents
+            .iter()
+            .map(|entry| (entry.key().clone(), entry.value().clone()))
+            .collect::<Vec<_>>();
+        for (uri, source_text) in documents {
+            self.lint_document(uri, &source_text).await;
+        }
+    }
+
+    async fn lint_document(&self, uri: Url, source_text: &str) {
+        let Some(Some(root_uri)) = self.root_uri.get() else { return };
+        if let Some(reports) = self.server_linter.run_single(root_uri, &uri, source_text) {
+            self.publish_all_diagnostics(vec![(uri, reports)]).await;
+        }
+    }
+
+    async fn publish_all_diagnostics(&self, result: Vec<(Url, Vec<DiagnosticReport>)>) {
+        join_all(result.into_iter().map(|(uri, reports)| {
+            let diagnostics = reports.iter().map(|report| report.diagnostic.clone()).collect();
+            if reports.is_empty() {
+                self.diagnostics_report_map.remove(&uri);
+            } else {
+                self.diagnostics_report_map.insert(uri.clone(), reports);
+            }
+            self.client.publish_diagnostics(uri, diagnostics, None)
+        }))
+        .await;
+    }
+}
+
+/// Whether the requested range of a code action touches the range of a diagnostic.
+fn overlaps(a: Range, b: Range) -> bool {
+    a.start <= b.end && b.start <= a.end
+}
+
+#[tokio::main]
+async fn main() {
+    env_logger::init();
+
+    let stdin = tokio::io::stdin();
+    let stdout = tokio::io::stdout();
+
+    let server_linter = ServerLinter::new();
+    let diagnostics_report_map = DashMap::new();
+
+    let (service, socket) = LspService::build(|client| Backend {
+        client,
+        root_uri: OnceCell::new(),
+        options: Mutex::default(),
+        server_linter,
+        diagnostics_report_map,
+        documents: DashMap::new(),
+    })
+    .finish();
+
+    Server::new(stdin, stdout, socket).serve(service).await;
+}
@@ -0,0 +1,33 @@
+use std::path::PathBuf;
+
+use serde_json::Value;
+
+#[derive(Debug, Default)]
+pub struct LintOptions {
+    pub paths: Vec<PathBuf>,
+    pub fix: bool,
+    pub ignore_path: PathBuf,
+    pub no_ignore: bool,
+    pub ignore_pattern: Vec<String>,
+}
+
+/// The settings of the server, from the `initializationOptions` of the client
+/// and the `workspace/didChangeConfiguration` notifications.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ServerOptions {
+    /// An ESLint configuration file, relative to the workspace root.
+    pub config_path: Option<String>,
+}
+
+impl ServerOptions {
+    /// Read the settings, either at the top level or in an `oxc` section.
+    pub fn from_value(value: &Value) -> Self {
+        let value = value.get("oxc").unwrap_or(value);
+        let config_path = value
+            .get("configPath")
+            .and_then(Value::as_str)
+            .filter(|path| !path.is_empty())
+            .map(ToString::to_string);
+        Self { config_path }
+    }
+}
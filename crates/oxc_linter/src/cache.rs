@@ -0,0 +1,282 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use dashmap::DashMap;
+use oxc_diagnostics::{
+    miette::{LabeledSpan, MietteDiagnostic, Severity},
+    DiagnosticWithFix, Error,
+};
+use serde_json::{json, Value};
+
+use crate::{ast_util::calculate_hash, Message};
+
+/// Changed when the cached diagnostics are no longer compatible, e.g. the format of the file.
+const VERSION: &str = concat!("1-", env!("CARGO_PKG_VERSION"));
+
+const CACHE_FILE: &str = "lint-cache.json";
+
+/// The diagnostics of the linted files, stored in a directory between the runs.
+///
+/// An entry is reused while the source text of the file and the configuration applying to it
+/// are unchanged, the file is not linted again and its cached diagnostics are reported instead.
+pub struct LintCache {
+    path: PathBuf,
+    files: DashMap<Box<Path>, CacheEntry>,
+}
+
+struct CacheEntry {
+    source_hash: u64,
+    config_hash: u64,
+    diagnostics: Vec<CachedDiagnostic>,
+}
+
+/// The parts of a diagnostic which are rendered, the fix is kept for `report_fixes`.
+struct CachedDiagnostic {
+    message: String,
+    severity: Severity,
+    help: Option<String>,
+    url: Option<String>,
+    /// Byte offsets and lengths.
+    labels: Vec<(usize, usize, Option<String>)>,
+    fix: Option<(usize, usize, String)>,
+}
+
+impl LintCache {
+    /// Load the cache stored in `dir`, it starts empty when it is missing or outdated.
+    pub fn load(dir: &Path) -> Self {
+        let path = dir.join(CACHE_FILE);
+        let files = DashMap::default();
+        let cache = fs::read_to_string(&path)
+            .ok()
+            .and_then(|text| serde_json::from_str::<Value>(&text).ok())
+            .filter(|cache| cache["version"] == VERSION);
+        if let Some(entries) = cache.as_ref().and_then(|cache| cache["files"].as_object()) {
+            for (file, entry) in entries {
+                if let Some(entry) = CacheEntry::from_json(entry) {
+                    files.insert(PathBuf::from(file).into_boxed_path(), entry);
+                }
+            }
+        }
+        Self { path, files }
+    }
+
+    /// The cached diagnostics of the file, `None` when it has to be linted.
+    pub fn get(&self, path: &Path, source_text: &str, config_hash: u64) -> Option<Vec<Error>> {
+        let entry = self.files.get(path)?;
+        if entry.source_hash != calculate_hash(&source_text) || entry.config_hash != config_hash {
+            return None;
+        }
+        Some(entry.diagnostics.iter().map(CachedDiagnostic::to_error).collect())
+    }
+
+    pub fn insert(&self, path: &Path, source_text: &str, config_hash: u64, messages: &[Message]) {
+        let diagnostics = messages.iter().map(CachedDiagnostic::new).collect();
+        let entry =
+            CacheEntry { source_hash: calculate_hash(&source_text), config_hash, diagnostics };
+        self.files.insert(path.to_path_buf().into_boxed_path(), entry);
+    }
+
+    /// Write the cache to its directory, without the entries of the deleted files.
+    ///
+    /// # Errors
+    ///
+    /// The directory or the file can not be written.
+    pub fn save(&self) -> io::Result<()> {
+        let files = self
+            .files
+            .iter()
+            .filter(|entry| entry.key().is_file())
+            .map(|entry| (entry.key().to_string_lossy().to_string(), entry.value().to_json()))
+            .collect::<serde_json::Map<_, _>>();
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(&self.path, json!({ "version": VERSION, "files": files }).to_string())
+    }
+}
+
+impl CacheEntry {
+    fn from_json(value: &Value) -> Option<Self> {
+        Some(Self {
+            source_hash: value["source"].as_u64()?,
+            config_hash: value["config"].as_u64()?,
+            diagnostics: value["diagnostics"]
+                .as_array()?
+                .iter()
+                .map(CachedDiagnostic::from_json)
+                .collect::<Option<_>>()?,
+        })
+    }
+
+    fn to_json(&self) -> Value {
+        let diagnostics =
+            self.diagnostics.iter().map(CachedDiagnostic::to_json).collect::<Vec<_>>();
+        json!({ "source": self.source_hash, "config": self.config_hash, "diagnostics": diagnostics })
+    }
+}
+
+impl CachedDiagnostic {
+    fn new(message: &Message) -> Self {
+        let error = &message.error;
+        let labels = error
+            .labels()
+            .map(|labels| {
+                labels.map(|label| (label.offset(), label.len(), label.label().map(str::to_string)))
+            })
+            .map_or(vec![], Iterator::collect);
+        let fix = message.fix.as_ref().map(|fix| {
+            (fix.span.start as usize, fix.span.size() as usize, fix.content.to_string())
+        });
+        Self {
+            message: error.to_string(),
+            severity: error.severity().unwrap_or(Severity::Error),
+            help: error.help().map(|help| help.to_string()),
+            url: error.url().map(|url| url.to_string()),
+            labels,
+            fix,
+        }
+    }
+
+    fn to_error(&self) -> Error {
+        let mut diagnostic =
+            MietteDiagnostic::new(self.message.clone()).with_severity(self.severity).with_labels(
+                self.labels
+                    .iter()
+                    .map(|(offset, len, label)| LabeledSpan::new(label.clone(), *offset, *len)),
+            );
+        if let Some(help) = &self.help {
+            diagnostic = diagnostic.with_help(help.clone());
+        }
+        if let Some(url) = &self.url {
+            diagnostic = diagnostic.with_url(url.clone());
+        }
+        let error = Error::new(diagnostic);
+        match &self.fix {
+            Some((offset, len, content)) => {
+                Error::new(DiagnosticWithFix::new(error, (*offset, *len).into(), content.clone()))
+            }
+            None => error,
+        }
+    }
+
+    fn from_json(value: &Value) -> Option<Self> {
+        let severity = match value["severity"].as_str()? {
+            "error" => Severity::Error,
+            "warning" => Severity::Warning,
+            _ => Severity::Advice,
+        };
+        let offset = |value: &Value| value.as_u64().and_then(|n| usize::try_from(n).ok());
+        let labels = value["labels"]
+            .as_array()?
+            .iter()
+            .map(|label| {
+                Some((
+                    offset(&label["offset"])?,
+                    offset(&label["len"])?,
+                    label["label"].as_str().map(str::to_string),
+                ))
+            })
+            .collect::<Option<_>>()?;
+        let fix = match &value["fix"] {
+            Value::Null => None,
+            fix => Some((
+                offset(&fix["offset"])?,
+                offset(&fix["len"])?,
+                fix["content"].as_str()?.to_string(),
+            )),
+        };
+        Some(Self {
+            message: value["message"].as_str()?.to_string(),
+            severity,
+            help: value["help"].as_str().map(str::to_string),
+            url: value["url"].as_str().map(str::to_string),
+            labels,
+            fix,
+        })
+    }
+
+    fn to_json(&self) -> Value {
+        let severity = match self.severity {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+            Severity::Advice => "advice",
+        };
+        let labels = self
+            .labels
+            .iter()
+            .map(|(offset, len, label)| json!({ "offset": offset, "len": len, "label": label }))
+            .collect::<Vec<_>>();
+        let fix = self.fix.as_ref().map(
+            |(offset, len, content)| json!({ "offset": offset, "len": len, "content": content }),
+        );
+        json!({
+            "message": self.message,
+            "severity": severity,
+            "help": self.help,
+            "url": self.url,
+            "labels": labels,
+            "fix": fix,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_diagnostics::{
+        miette::{self, Diagnostic, Severity},
+        thiserror::Error,
+        DiagnosticWithFix,
+    };
+    use oxc_span::Span;
+
+    use super::LintCache;
+    use crate::{Fix, Message};
+
+    #[derive(Debug, Error, Diagnostic)]
+    #[error("eslint(no-debugger): `debugger` statement is not allowed")]
+    #[diagnostic(
+        severity(warning),
+        help("Delete this code."),
+        url("https://example.com/no-debugger")
+    )]
+    struct NoDebuggerDiagnostic(#[label("here")] pub Span);
+
+    #[test]
+    fn cache() {
+        let dir = std::env::temp_dir().join(format!("oxlint-cache-test-{}", std::process::id()));
+        let path = dir.join("debugger.js");
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(&path, "debugger;\n").unwrap();
+
+        let cache = LintCache::load(&dir);
+        assert!(cache.get(&path, "debugger;\n", 1).is_none());
+        let message = Message::new(
+            NoDebuggerDiagnostic(Span::new(0, 9)).into(),
+            Some(Fix::delete(Span::new(0, 9))),
+        );
+        cache.insert(&path, "debugger;\n", 1, &[message]);
+        cache.save().unwrap();
+
+        let cache = LintCache::load(&dir);
+        assert!(cache.get(&path, "debugger;\n", 2).is_none());
+        assert!(cache.get(&path, "debugger; ", 1).is_none());
+        let diagnostics = cache.get(&path, "debugger;\n", 1).unwrap();
+        assert_eq!(diagnostics.len(), 1);
+        let diagnostic = &diagnostics[0];
+        assert_eq!(
+            diagnostic.to_string(),
+            "eslint(no-debugger): `debugger` statement is not allowed"
+        );
+        assert_eq!(diagnostic.severity(), Some(Severity::Warning));
+        assert_eq!(diagnostic.help().unwrap().to_string(), "Delete this code.");
+        assert_eq!(diagnostic.url().unwrap().to_string(), "https://example.com/no-debugger");
+        let label = diagnostic.labels().unwrap().next().unwrap();
+        assert_eq!((label.offset(), label.len(), label.label()), (0, 9, Some("here")));
+        let fix = diagnostic.downcast_ref::<DiagnosticWithFix>().unwrap();
+        assert_eq!((fix.span.offset(), fix.span.len(), fix.content.as_str()), (0, 9, ""));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,505 @@
+mod oxlintrc;
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+    Error as DiagnosticError, Severity,
+};
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde_json::Value;
+
+use crate::{globals::Globals, AllowWarnDeny, RuleEnum, RULES};
+
+pub use oxlintrc::Oxlintrc;
+pub use oxlintrc::{FileConfig, OxlintrcResolver};
+
+/// Cloneable, an invalid `.oxlintrc.json` is reported for each file it applies to.
+#[derive(Debug, Clone, Error, Diagnostic)]
+enum ConfigError {
+    #[error("Failed to read the configuration file {0:?}")]
+    #[diagnostic(help("{1}"))]
+    FailedToRead(PathBuf, String),
+
+    #[error("Failed to parse the configuration file {0:?}")]
+    #[diagnostic(help("{1}"))]
+    FailedToParse(PathBuf, String),
+
+    #[error("Invalid severity for the rule {0:?}")]
+    #[diagnostic(help("{1} is not one of \"off\", \"warn\", \"error\", 0, 1 or 2"))]
+    InvalidRuleSeverity(String, String),
+
+    #[error("Invalid value for the global {0:?}")]
+    #[diagnostic(help("{1} is not one of \"readonly\", \"writable\", \"off\", true or false"))]
+    InvalidGlobal(String, String),
+
+    #[error("Invalid glob pattern {0:?} in the configuration file {1:?}")]
+    #[diagnostic(help("{2}"))]
+    InvalidGlob(String, PathBuf, String),
+
+    #[error("The configuration file {0:?} extends itself")]
+    ExtendsCycle(PathBuf),
+}
+
+/// The rules, globals and environments of an ESLint configuration file.
+///
+/// Both the `.eslintrc.*` objects and the arrays of the flat `eslint.config.*` files are read:
+/// * `.json`, `.eslintrc`, `package.json` (the `eslintConfig` field) and `.yaml` / `.yml` are parsed,
+///   comments are allowed in JSON.
+/// * `.js`, `.cjs` and `.mjs` are evaluated by `node` to get the exported configuration.
+///
+/// Only the settings applying to every file are used: `extends`, `plugins` and the `overrides` or
+/// flat configuration objects restricted by `files` / `ignores` are not supported.
+/// The globals of the flat configuration objects are read from `languageOptions.globals`.
+/// The rules which are not implemented by oxlint are ignored.
+///
+/// <https://eslint.org/docs/latest/use/configure/rules>
+#[derive(Debug, Default, Clone)]
+pub struct ESLintConfig {
+    rules: Vec<ESLintRuleConfig>,
+    /// `Some(true)` for writable, `Some(false)` for read-only and `None` for `"off"`.
+    globals: Vec<(String, Option<bool>)>,
+    env: Vec<(String, bool)>,
+}
+
+#[derive(Debug, Clone)]
+struct ESLintRuleConfig {
+    plugin_name: String,
+    rule_name: String,
+    severity: AllowWarnDeny,
+    /// The options following the severity, e.g. `[{ "allow": ["warn"] }]` of
+    /// `"no-console": ["error", { "allow": ["warn"] }]`.
+    config: Option<Value>,
+}
+
+impl ESLintConfig {
+    /// # Errors
+    ///
+    /// * The file can not be read, evaluated or parsed.
+    /// * A rule has an invalid severity.
+    pub fn from_file(path: &Path) -> Result<Self, DiagnosticError> {
+        let value = read_config_file(path)?;
+        Self::from_value(&value)
+    }
+
+    /// # Errors
+    ///
+    /// A rule has an invalid severity or a global has an invalid value.
+    pub fn from_value(value: &Value) -> Result<Self, DiagnosticError> {
+        let mut config = Self::default();
+        match value {
+            // Flat config
+            Value::Array(configs) => {
+                for flat_config in configs {
+                    if flat_config.get("files").is_none() && flat_config.get("ignores").is_none() {
+                        read_rules(flat_config, &mut config.rules)?;
+                        if let Some(language_options) = flat_config.get("languageOptions") {
+                            config.globals.extend(read_globals(language_options)?);
+                        }
+                    }
+                }
+            }
+            value => {
+                read_rules(value, &mut config.rules)?;
+                config.globals = read_globals(value)?;
+                config.env = read_env(value);
+            }
+        }
+        Ok(config)
+    }
+
+    /// Enable, disable or reconfigure the rules of the configuration, the later rules win.
+    pub(crate) fn override_rules(&self, rules: &mut FxHashSet<RuleEnum>) {
+        apply_rules(&self.rules, rules, &mut FxHashMap::default());
+    }
+
+    /// Enable, disable or reconfigure the rules, and record the severities of the enabled ones.
+    pub(crate) fn apply(
+        &self,
+        rules: &mut FxHashSet<RuleEnum>,
+        severities: &mut FxHashMap<&'static str, Severity>,
+    ) {
+        apply_rules(&self.rules, rules, severities);
+    }
+
+    /// The severities of the diagnostics of the rules enabled by the configuration.
+    pub(crate) fn severities(&self) -> FxHashMap<&'static str, Severity> {
+        let mut severities = FxHashMap::default();
+        apply_rules(&self.rules, &mut FxHashSet::default(), &mut severities);
+        severities
+    }
+
+    /// Enable the environments and the globals of the configuration.
+    pub(crate) fn apply_globals(&self, globals: &mut Globals) {
+        for (name, enabled) in &self.env {
+            globals.set_env(name, *enabled);
+        }
+        for (name, writable) in &self.globals {
+            globals.set_global(name.clone(), *writable);
+        }
+    }
+
+    /// The configured rules in order, with their severity or `None` when they are turned off.
+    /// The custom rules are found among `rules`.
+    pub(crate) fn configured_rules<'r>(
+        &self,
+        rules: &(impl Iterator<Item = &'r RuleEnum> + Clone),
+    ) -> Vec<(RuleEnum, Option<Severity>)> {
+        self.rules
+            .iter()
+            .filter_map(|rule_config| {
+                let rule = rule_config.find_rule(rules.clone())?;
+                let severity = match rule_config.severity {
+                    AllowWarnDeny::Allow => return Some((rule, None)),
+                    AllowWarnDeny::Warn => Severity::Warning,
+                    AllowWarnDeny::Deny => Severity::Error,
+                };
+                Some((rule.read_json(rule_config.config.clone()), Some(severity)))
+            })
+            .collect()
+    }
+}
+
+impl ESLintRuleConfig {
+    /// Finds the built-in rule, or the custom rule among the enabled `rules`.
+    fn find_rule<'r>(&self, rules: impl Iterator<Item = &'r RuleEnum>) -> Option<RuleEnum> {
+        RULES
+            .iter()
+            .chain(rules.filter(|rule| matches!(rule, RuleEnum::Custom(_))))
+            .find(|rule| rule.plugin_name() == self.plugin_name && rule.name() == self.rule_name)
+            .cloned()
+    }
+}
+
+/// Read the configuration object of a file in any of the formats supported by [ESLintConfig].
+fn read_config_file(path: &Path) -> Result<Value, ConfigError> {
+    let file_name = path.file_name().and_then(|name| name.to_str()).unwrap_or_default();
+    let extension = path.extension().and_then(|ext| ext.to_str()).unwrap_or_default();
+    let parse_error = |error: String| ConfigError::FailedToParse(path.to_path_buf(), error);
+
+    if matches!(extension, "js" | "cjs" | "mjs") {
+        return evaluate_config_file(path);
+    }
+
+    let text = fs::read_to_string(path)
+        .map_err(|error| ConfigError::FailedToRead(path.to_path_buf(), error.to_string()))?;
+    match extension {
+        "yaml" | "yml" => serde_yaml::from_str(&text).map_err(|e| parse_error(e.to_string())),
+        _ => {
+            let value = serde_json::from_str::<Value>(&strip_json_comments(&text))
+                .or_else(|error| {
+                    // `.eslintrc` may be YAML
+                    if file_name == ".eslintrc" {
+                        serde_yaml::from_str(&text).map_err(|e| e.to_string())
+                    } else {
+                        Err(error.to_string())
+                    }
+                })
+                .map_err(parse_error)?;
+            if file_name == "package.json" {
+                return Ok(value.get("eslintConfig").cloned().unwrap_or_default());
+            }
+            Ok(value)
+        }
+    }
+}
+
+/// Print the configuration exported by a JavaScript file as JSON with `node`.
+fn evaluate_config_file(path: &Path) -> Result<Value, ConfigError> {
+    const SCRIPT: &str = "import(require('url').pathToFileURL(process.argv[1])).then(\
+        async (m) => { let c = await m.default; \
+        process.stdout.write(JSON.stringify(typeof c === 'function' ? await c() : c)); })";
+    let path = path
+        .canonicalize()
+        .map_err(|error| ConfigError::FailedToRead(path.to_path_buf(), error.to_string()))?;
+    let output = Command::new("node")
+        .arg("-e")
+        .arg(SCRIPT)
+        .arg(&path)
+        .output()
+        .map_err(|error| ConfigError::FailedToRead(path.clone(), format!("`node`: {error}")))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+        return Err(ConfigError::FailedToParse(path, stderr));
+    }
+    serde_json::from_slice(&output.stdout)
+        .map_err(|error| ConfigError::FailedToParse(path, error.to_string()))
+}
+
+/// Read the `rules` of a configuration object.
+fn read_rules(config: &Value, rules: &mut Vec<ESLintRuleConfig>) -> Result<(), ConfigError> {
+    let Some(rule_map) = config.get("rules").and_then(Value::as_object) else {
+        return Ok(());
+    };
+    for (key, value) in rule_map {
+        let (plugin_name, rule_name) = parse_rule_key(key);
+        let (severity, config) = match value {
+            Value::Array(values) => {
+                let severity = values.first().unwrap_or(&Value::Null);
+                let config = values.get(1..).filter(|options| !options.is_empty());
+                (severity, config.map(|options| Value::Array(options.to_vec())))
+            }
+            severity => (severity, None),
+        };
+        let Some(severity) = parse_severity(severity) else {
+            return Err(ConfigError::InvalidRuleSeverity(key.clone(), severity.to_string()));
+        };
+        rules.push(ESLintRuleConfig { plugin_name, rule_name, severity, config });
+    }
+    Ok(())
+}
+
+/// Read the `globals` of a configuration object.
+fn read_globals(config: &Value) -> Result<Vec<(String, Option<bool>)>, ConfigError> {
+    let mut globals = vec![];
+    for (name, value) in config.get("globals").and_then(Value::as_object).into_iter().flatten() {
+        let writable = match value {
+            Value::Bool(writable) => Some(*writable),
+            Value::String(s) => match s.as_str() {
+                "readonly" | "readable" => Some(false),
+                "writable" | "writeable" => Some(true),
+                "off" => None,
+                _ => return Err(ConfigError::InvalidGlobal(name.clone(), value.to_string())),
+            },
+            _ => return Err(ConfigError::InvalidGlobal(name.clone(), value.to_string())),
+        };
+        globals.push((name.clone(), writable));
+    }
+    Ok(globals)
+}
+
+/// Read the `env` of a configuration object.
+fn read_env(config: &Value) -> Vec<(String, bool)> {
+    config
+        .get("env")
+        .and_then(Value::as_object)
+        .into_iter()
+        .flatten()
+        .map(|(name, enabled)| (name.clone(), enabled.as_bool().unwrap_or_default()))
+        .collect()
+}
+
+/// Enable, disable or reconfigure the rules, and record the severities of the enabled ones.
+fn apply_rules(
+    rule_configs: &[ESLintRuleConfig],
+    rules: &mut FxHashSet<RuleEnum>,
+    severities: &mut FxHashMap<&'static str, Severity>,
+) {
+    for rule_config in rule_configs {
+        let Some(rule) = rule_config.find_rule(rules.iter()) else { continue };
+        let severity = match rule_config.severity {
+            AllowWarnDeny::Allow => {
+                rules.remove(&rule);
+                severities.remove(rule.name());
+                continue;
+            }
+            AllowWarnDeny::Warn => Severity::Warning,
+            AllowWarnDeny::Deny => Severity::Error,
+        };
+        rules.replace(rule.read_json(rule_config.config.clone()));
+        severities.insert(rule.name(), severity);
+    }
+}
+
+/// `no-debugger` -> `("eslint", "no-debugger")`,
+/// `@typescript-eslint/ban-types` -> `("typescript", "ban-types")`.
+fn parse_rule_key(key: &str) -> (String, String) {
+    let Some((plugin_name, rule_name)) = key.split_once('/') else {
+        return ("eslint".to_string(), key.to_string());
+    };
+    let plugin_name = match plugin_name {
+        "@typescript-eslint" => "typescript",
+        "react-hooks" => "react",
+        "jsx-a11y" => "jsx_a11y",
+        // `@jest`
+        plugin_name => plugin_name.trim_start_matches('@'),
+    };
+    (plugin_name.to_string(), rule_name.to_string())
+}
+
+fn parse_severity(value: &Value) -> Option<AllowWarnDeny> {
+    match value {
+        Value::String(s) => match s.as_str() {
+            "off" => Some(AllowWarnDeny::Allow),
+            "warn" => Some(AllowWarnDeny::Warn),
+            "error" => Some(AllowWarnDeny::Deny),
+            _ => None,
+        },
+        Value::Number(n) => match n.as_u64()? {
+            0 => Some(AllowWarnDeny::Allow),
+            1 => Some(AllowWarnDeny::Warn),
+            2 => Some(AllowWarnDeny::Deny),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Replace the `//` and `/* */` comments of a JSON file with spaces.
+fn strip_json_comments(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    let mut in_string = false;
+    while let Some(c) = chars.next() {
+        if in_string {
+            result.push(c);
+            match c {
+                '\\' => result.extend(chars.next()),
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+        match (c, chars.peek()) {
+            ('"', _) => {
+                in_string = true;
+                result.push(c);
+            }
+            ('/', Some('/')) => while chars.next_if(|c| *c != '\n').is_some() {},
+            ('/', Some('*')) => {
+                chars.next();
+                let mut prev = ' ';
+                for c in chars.by_ref() {
+                    if prev == '*' && c == '/' {
+                        break;
+                    }
+                    // Keep the line numbers of the parse errors
+                    if c == '\n' {
+                        result.push('\n');
+                    }
+                    prev = c;
+                }
+                result.push(' ');
+            }
+            _ => result.push(c),
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use rustc_hash::FxHashSet;
+    use serde_json::json;
+
+    use super::{strip_json_comments, ESLintConfig, Severity};
+    use crate::{AllowWarnDeny, LintOptions, RuleEnum, RULES};
+
+    fn derive_rules(config: &serde_json::Value) -> Vec<RuleEnum> {
+        let config = ESLintConfig::from_value(config).unwrap();
+        LintOptions::default().with_eslint_config(config).derive_rules()
+    }
+
+    fn names(rules: &[RuleEnum]) -> FxHashSet<&'static str> {
+        rules.iter().map(RuleEnum::name).collect()
+    }
+
+    #[test]
+    fn eslintrc() {
+        let rules = names(&derive_rules(&json!({
+            "rules": {
+                "no-debugger": "off",
+                "no-console": ["error", { "allow": ["warn"] }],
+                "@typescript-eslint/no-explicit-any": 1,
+                "jest/no-focused-tests": "warn",
+                "not-implemented": "error",
+            }
+        })));
+        assert!(!rules.contains("no-debugger"));
+        assert!(rules.contains("no-console"));
+        assert!(rules.contains("no-explicit-any"));
+        assert!(rules.contains("no-focused-tests"));
+        // The other correctness rules are kept
+        assert!(rules.contains("for-direction"));
+    }
+
+    #[test]
+    fn flat_config() {
+        let rules = names(&derive_rules(&json!([
+            { "rules": { "no-console": "error", "no-debugger": 0 } },
+            { "files": ["**/*.test.js"], "rules": { "no-empty": "error" } },
+            { "rules": { "no-console": "off" } },
+        ])));
+        assert!(!rules.contains("no-console"));
+        assert!(!rules.contains("no-debugger"));
+        assert!(!rules.contains("no-empty"));
+    }
+
+    #[test]
+    fn severities() {
+        let config = ESLintConfig::from_value(&json!({
+            "rules": { "no-console": "warn", "no-debugger": "error", "no-empty": ["error"] }
+        }))
+        .unwrap();
+        let severities = config.severities();
+        assert_eq!(severities["no-console"], Severity::Warning);
+        assert_eq!(severities["no-debugger"], Severity::Error);
+        assert_eq!(config.rules[2].severity, AllowWarnDeny::Deny);
+        assert!(config.rules[2].config.is_none());
+    }
+
+    #[test]
+    fn rule_config() {
+        let config = ESLintConfig::from_value(&json!({
+            "rules": { "unicorn/catch-error-name": ["error", { "name": "err" }] }
+        }))
+        .unwrap();
+        let rules = LintOptions::default()
+            .with_unicorn_plugin(true)
+            .with_eslint_config(config)
+            .derive_rules();
+        let rule = rules.iter().find(|rule| rule.name() == "catch-error-name").unwrap();
+        let default = RULES.iter().find(|rule| rule.name() == "catch-error-name").unwrap();
+        assert_ne!(format!("{rule:?}"), format!("{default:?}"));
+    }
+
+    #[test]
+    fn globals() {
+        let globals = |config| {
+            let config = ESLintConfig::from_value(&config).unwrap();
+            LintOptions::default()
+                .with_env(vec!["node".into()])
+                .with_globals(vec![("a".into(), false)])
+                .with_eslint_config(config)
+                .derive_globals()
+        };
+        let globals = globals(json!({
+            "env": { "worker": true, "node": false },
+            "globals": { "a": "writable", "b": "readonly", "self": "off" },
+        }));
+        assert_eq!(globals.get("a"), Some(true));
+        assert_eq!(globals.get("b"), Some(false));
+        assert_eq!(globals.get("importScripts"), Some(false));
+        assert_eq!(globals.get("self"), None);
+        assert_eq!(globals.get("process"), None);
+
+        let config = ESLintConfig::from_value(&json!([
+            { "languageOptions": { "globals": { "a": true } } },
+            { "files": ["*.test.js"], "languageOptions": { "globals": { "b": true } } },
+        ]))
+        .unwrap();
+        let globals = LintOptions::default().with_eslint_config(config).derive_globals();
+        assert_eq!(globals.get("a"), Some(true));
+        assert_eq!(globals.get("b"), None);
+
+        assert!(ESLintConfig::from_value(&json!({ "globals": { "a": "on" } })).is_err());
+    }
+
+    #[test]
+    fn invalid_severity() {
+        assert!(ESLintConfig::from_value(&json!({ "rules": { "no-console": "on" } })).is_err());
+        assert!(ESLintConfig::from_value(&json!({ "rules": { "no-console": [3] } })).is_err());
+    }
+
+    #[test]
+    fn json_comments() {
+        let text = "{\n  // line\n  \"a\": \"//b\", /* block */ \"c\": \"\\\"/*\"\n}";
+        let value: serde_json::Value = serde_json::from_str(&strip_json_comments(text)).unwrap();
+        assert_eq!(value, serde_json::json!({ "a": "//b", "c": "\"/*" }));
+    }
+}
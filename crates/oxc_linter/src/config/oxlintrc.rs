@@ -0,0 +1,361 @@
+use std::{
+    iter,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use dashmap::DashMap;
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use oxc_diagnostics::{Error as DiagnosticError, Severity};
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde_json::Value;
+
+use super::{
+    apply_rules, read_config_file, read_env, read_globals, read_rules, ConfigError,
+    ESLintRuleConfig,
+};
+use crate::{globals::Globals, RuleEnum};
+
+/// The names of the configuration files looked up by [OxlintrcResolver], in the order of
+/// precedence when a directory has both.
+pub const OXLINTRC: [&str; 2] = [".oxlintrc.json", ".oxlintrc"];
+
+/// An oxlint configuration file, `.oxlintrc.json` or `.oxlintrc`, JSON with comments.
+///
+/// ```json
+/// {
+///   "root": true,
+///   "extends": ["../shared/.oxlintrc.json"],
+///   "env": { "browser": true },
+///   "globals": { "__DEV__": "readonly" },
+///   "rules": { "no-console": "error", "eqeqeq": ["warn", "smart"] },
+///   "overrides": [
+///     { "files": ["*.test.js"], "env": { "jest": true }, "rules": { "no-console": "off" } }
+///   ]
+/// }
+/// ```
+///
+/// * `extends` are paths relative to the file, to other `.oxlintrc.json` or to ESLint
+///   configuration files, they are applied before the file itself.
+/// * The `files` and `excludedFiles` globs of `overrides` are relative to the directory of the file
+///   declaring them, a glob without `/` matches the file names in any directory.
+/// * `"root": true` stops the lookup of configuration files in the parent directories.
+#[derive(Debug, Default)]
+pub struct Oxlintrc {
+    root: bool,
+    /// The extended files then the file itself, in the order they are applied.
+    layers: Vec<Layer>,
+}
+
+#[derive(Debug)]
+struct Layer {
+    dir: PathBuf,
+    settings: Settings,
+    overrides: Vec<Override>,
+}
+
+#[derive(Debug, Default)]
+struct Settings {
+    rules: Vec<ESLintRuleConfig>,
+    /// `Some(true)` for writable, `Some(false)` for read-only and `None` for `"off"`.
+    globals: Vec<(String, Option<bool>)>,
+    env: Vec<(String, bool)>,
+}
+
+#[derive(Debug)]
+struct Override {
+    files: GlobSet,
+    excluded_files: GlobSet,
+    settings: Settings,
+}
+
+/// The rules, severities and globals applying to a linted file.
+#[derive(Debug)]
+pub struct FileConfig {
+    pub rules: Vec<RuleEnum>,
+    pub severities: FxHashMap<&'static str, Severity>,
+    pub globals: Globals,
+}
+
+impl Oxlintrc {
+    /// # Errors
+    ///
+    /// * The file or one of the files it extends can not be read or parsed.
+    /// * A rule, a global or a glob is invalid.
+    /// * The file extends itself.
+    pub fn from_file(path: &Path) -> Result<Self, DiagnosticError> {
+        Ok(Self::load(path)?)
+    }
+
+    fn load(path: &Path) -> Result<Self, ConfigError> {
+        let mut layers = vec![];
+        let root = Self::read(path, &mut vec![], &mut layers)?;
+        Ok(Self { root, layers })
+    }
+
+    /// Read the file and the files it extends into `layers`, `stack` holds the extending files.
+    fn read(
+        path: &Path,
+        stack: &mut Vec<PathBuf>,
+        layers: &mut Vec<Layer>,
+    ) -> Result<bool, ConfigError> {
+        let path = path
+            .canonicalize()
+            .map_err(|error| ConfigError::FailedToRead(path.to_path_buf(), error.to_string()))?;
+        if stack.contains(&path) {
+            return Err(ConfigError::ExtendsCycle(path));
+        }
+        let config = read_config_file(&path)?;
+        let dir = path.parent().map(Path::to_path_buf).unwrap_or_default();
+
+        stack.push(path);
+        for extend in string_or_array(config.get("extends")) {
+            Self::read(&dir.join(extend), stack, layers)?;
+        }
+        let path = stack.pop().unwrap();
+
+        let settings = Settings::read(&config)?;
+        let mut overrides = vec![];
+        for config in config.get("overrides").and_then(Value::as_array).into_iter().flatten() {
+            overrides.push(Override {
+                files: build_glob_set(config.get("files"), &path)?,
+                excluded_files: build_glob_set(config.get("excludedFiles"), &path)?,
+                settings: Settings::read(config)?,
+            });
+        }
+        layers.push(Layer { dir, settings, overrides });
+        Ok(config.get("root").and_then(Value::as_bool).unwrap_or_default())
+    }
+
+    /// Apply the configuration to the file at the absolute `path`.
+    fn apply(
+        &self,
+        path: &Path,
+        rules: &mut FxHashSet<RuleEnum>,
+        severities: &mut FxHashMap<&'static str, Severity>,
+        globals: &mut Globals,
+    ) {
+        for layer in &self.layers {
+            layer.settings.apply(rules, severities, globals);
+            let Ok(relative_path) = path.strip_prefix(&layer.dir) else { continue };
+            for r#override in &layer.overrides {
+                if r#override.files.is_match(relative_path)
+                    && !r#override.excluded_files.is_match(relative_path)
+                {
+                    r#override.settings.apply(rules, severities, globals);
+                }
+            }
+        }
+    }
+}
+
+impl Settings {
+    fn read(config: &Value) -> Result<Self, ConfigError> {
+        let mut rules = vec![];
+        read_rules(config, &mut rules)?;
+
+        let globals = read_globals(config)?;
+        let env = read_env(config);
+        Ok(Self { rules, globals, env })
+    }
+
+    fn apply(
+        &self,
+        rules: &mut FxHashSet<RuleEnum>,
+        severities: &mut FxHashMap<&'static str, Severity>,
+        globals: &mut Globals,
+    ) {
+        for (name, enabled) in &self.env {
+            globals.set_env(name, *enabled);
+        }
+        for (name, writable) in &self.globals {
+            globals.set_global(name.clone(), *writable);
+        }
+        apply_rules(&self.rules, rules, severities);
+    }
+}
+
+/// `"a"` -> `["a"]`, the non-string items of an array are ignored.
+fn string_or_array(value: Option<&Value>) -> Vec<&str> {
+    match value {
+        Some(Value::String(s)) => vec![s.as_str()],
+        Some(Value::Array(values)) => values.iter().filter_map(Value::as_str).collect(),
+        _ => vec![],
+    }
+}
+
+fn build_glob_set(patterns: Option<&Value>, path: &Path) -> Result<GlobSet, ConfigError> {
+    let mut builder = GlobSetBuilder::new();
+    for pattern in string_or_array(patterns) {
+        let pattern = pattern.trim_start_matches("./");
+        let glob = if pattern.contains('/') {
+            Glob::new(pattern)
+        } else {
+            Glob::new(&format!("**/{pattern}"))
+        };
+        let glob = glob.map_err(|error| {
+            ConfigError::InvalidGlob(pattern.to_string(), path.to_path_buf(), error.to_string())
+        })?;
+        builder.add(glob);
+    }
+    builder.build().map_err(|error| {
+        ConfigError::InvalidGlob(String::new(), path.to_path_buf(), error.to_string())
+    })
+}
+
+/// Find the `.oxlintrc.json` of the linted files, from their directory up to the first
+/// configuration with `"root": true`. The configurations are applied from the outermost one,
+/// so the nearest one takes precedence, e.g. for the packages of a monorepo.
+#[derive(Debug, Default)]
+pub struct OxlintrcResolver {
+    /// The configuration files applying to a canonicalized directory, the nearest last.
+    directories: DashMap<PathBuf, Result<Configs, ConfigError>>,
+}
+
+type Configs = Arc<[Arc<Oxlintrc>]>;
+
+impl OxlintrcResolver {
+    /// The configuration of the file at `path`, starting from the `rules`, `severities` and
+    /// `globals` of the linter. `None` when no configuration file applies to it.
+    ///
+    /// # Errors
+    ///
+    /// A configuration file applying to `path` is invalid.
+    pub fn resolve(
+        &self,
+        path: &Path,
+        rules: &[RuleEnum],
+        severities: &FxHashMap<&'static str, Severity>,
+        globals: &Globals,
+    ) -> Result<Option<FileConfig>, DiagnosticError> {
+        let (Some(dir), Some(file_name)) = (path.parent(), path.file_name()) else {
+            return Ok(None);
+        };
+        let dir = if dir.as_os_str().is_empty() { Path::new(".") } else { dir };
+        let Ok(dir) = dir.canonicalize() else { return Ok(None) };
+
+        let configs = self.configs(&dir)?;
+        if configs.is_empty() {
+            return Ok(None);
+        }
+
+        let path = dir.join(file_name);
+        let mut rules = rules.iter().cloned().collect::<FxHashSet<_>>();
+        let mut severities = severities.clone();
+        let mut globals = globals.clone();
+        for config in &*configs {
+            config.apply(&path, &mut rules, &mut severities, &mut globals);
+        }
+        let mut rules = rules.into_iter().collect::<Vec<_>>();
+        rules.sort_unstable_by_key(RuleEnum::name);
+        Ok(Some(FileConfig { rules, severities, globals }))
+    }
+
+    fn configs(&self, dir: &Path) -> Result<Configs, ConfigError> {
+        if let Some(configs) = self.directories.get(dir) {
+            return configs.clone();
+        }
+        let configs = self.find_configs(dir);
+        self.directories.insert(dir.to_path_buf(), configs.clone());
+        configs
+    }
+
+    fn find_configs(&self, dir: &Path) -> Result<Configs, ConfigError> {
+        let path = OXLINTRC.iter().map(|name| dir.join(name)).find(|path| path.is_file());
+        let config = path.map(|path| Oxlintrc::load(&path)).transpose()?.map(Arc::new);
+        if let Some(config) = config.as_ref().filter(|config| config.root) {
+            return Ok(Arc::from(vec![Arc::clone(config)]));
+        }
+        let parent_configs = match dir.parent() {
+            Some(parent) => self.configs(parent)?,
+            None => Arc::from(vec![]),
+        };
+        let Some(config) = config else { return Ok(parent_configs) };
+        Ok(parent_configs.iter().cloned().chain(iter::once(config)).collect())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{env, path::PathBuf};
+
+    use super::OxlintrcResolver;
+    use crate::{globals::Globals, AllowWarnDeny, LintOptions, RuleEnum};
+
+    fn fixtures() -> PathBuf {
+        env::current_dir().unwrap().join("fixtures/oxlintrc")
+    }
+
+    fn resolve(path: &str) -> super::FileConfig {
+        let rules = LintOptions::default()
+            .with_filter(vec![(AllowWarnDeny::Deny, "no-debugger".into())])
+            .derive_rules();
+        OxlintrcResolver::default()
+            .resolve(
+                &fixtures().join(path),
+                &rules,
+                &rustc_hash::FxHashMap::default(),
+                &Globals::default(),
+            )
+            .unwrap()
+            .unwrap()
+    }
+
+    fn rule_names(rules: &[RuleEnum]) -> Vec<&'static str> {
+        rules.iter().map(RuleEnum::name).collect()
+    }
+
+    #[test]
+    fn rules() {
+        let config = resolve("index.js");
+        assert_eq!(rule_names(&config.rules), ["no-console", "no-debugger"]);
+        assert_eq!(config.severities.get("no-console"), Some(&oxc_diagnostics::Severity::Warning));
+    }
+
+    #[test]
+    fn nested() {
+        let config = resolve("nested/index.js");
+        assert_eq!(rule_names(&config.rules), ["no-console", "no-empty"]);
+        assert_eq!(config.globals.get("__DEV__"), Some(false));
+        assert_eq!(config.globals.get("window"), Some(false));
+        assert_eq!(config.globals.get("describe"), None);
+    }
+
+    #[test]
+    fn overrides() {
+        let config = resolve("nested/index.test.js");
+        assert_eq!(rule_names(&config.rules), ["no-empty"]);
+        assert_eq!(config.globals.get("describe"), Some(false));
+    }
+
+    #[test]
+    fn root() {
+        let config = resolve("root/index.js");
+        assert_eq!(rule_names(&config.rules), ["no-debugger"]);
+        assert_eq!(config.globals.get("__DEV__"), None);
+    }
+
+    #[test]
+    fn monorepo() {
+        let config = resolve("monorepo/packages/app/index.js");
+        assert_eq!(rule_names(&config.rules), ["no-console", "no-empty"]);
+        assert_eq!(config.severities.get("no-console"), Some(&oxc_diagnostics::Severity::Error));
+        assert_eq!(config.globals.get("__DEV__"), Some(false));
+
+        let config = resolve("monorepo/packages/index.js");
+        assert_eq!(rule_names(&config.rules), ["no-console"]);
+        assert_eq!(config.severities.get("no-console"), Some(&oxc_diagnostics::Severity::Warning));
+    }
+
+    #[test]
+    fn extends_cycle() {
+        let rules = LintOptions::default().derive_rules();
+        let result = OxlintrcResolver::default().resolve(
+            &fixtures().join("cycle/index.js"),
+            &rules,
+            &rustc_hash::FxHashMap::default(),
+            &Globals::default(),
+        );
+        assert!(result.is_err());
+    }
+}
@@ -1,14 +1,19 @@
-use std::{cell::RefCell, path::Path, rc::Rc};
+use std::{cell::RefCell, fmt, path::Path, rc::Rc, sync::Arc};
 
-use oxc_diagnostics::Error;
+use oxc_diagnostics::{
+    miette::{Diagnostic, LabeledSpan, SourceCode},
+    Error, Severity,
+};
 use oxc_formatter::{Formatter, FormatterOptions};
 use oxc_semantic::{AstNodes, JSDocComment, ScopeTree, Semantic, SymbolTable};
-use oxc_span::SourceType;
+use oxc_span::{Atom, SourceType, Span};
+use rustc_hash::FxHashSet;
 
 use crate::{
     disable_directives::{DisableDirectives, DisableDirectivesBuilder},
-    fixer::{Fix, Message},
-    AstNode,
+    fixer::{Fix, Message, Suggestion},
+    globals::Globals,
+    AstNode, TypeChecker, TypeInfo,
 };
 
 pub struct LintContext<'a> {
@@ -21,8 +26,30 @@ pub struct LintContext<'a> {
     /// Whether or not to apply code fixes during linting.
     fix: bool,
 
+    /// Whether or not to report the disable directives which did not suppress any diagnostic.
+    report_unused_directives: bool,
+
     current_rule_name: &'static str,
 
+    /// The severity configured for the current rule, overriding the one of its diagnostics.
+    current_rule_severity: Option<Severity>,
+
+    /// The documentation of the current rule, linked by its diagnostics.
+    current_rule_docs_url: Option<&'static str>,
+
+    /// The part of the file where the current rule reports, a rule reconfigured by a
+    /// configuration comment stops reporting at the comment.
+    current_rule_span: Span,
+
+    /// The globals configured by the `.oxlintrc.json` and the comments of the file.
+    globals: Globals,
+
+    /// The module requests which cannot be resolved, only collected with the import plugin.
+    unresolved_modules: FxHashSet<Atom>,
+
+    /// The types of the expressions for the type-aware rules.
+    type_checker: Option<Arc<dyn TypeChecker>>,
+
     file_path: Box<Path>,
 }
 
@@ -35,7 +62,14 @@ impl<'a> LintContext<'a> {
             diagnostics: RefCell::new(vec![]),
             disable_directives,
             fix: false,
+            report_unused_directives: false,
             current_rule_name: "",
+            current_rule_severity: None,
+            current_rule_docs_url: None,
+            current_rule_span: Span::new(0, u32::MAX),
+            globals: Globals::default(),
+            unresolved_modules: FxHashSet::default(),
+            type_checker: None,
             file_path,
         }
     }
@@ -46,6 +80,30 @@ impl<'a> LintContext<'a> {
         self
     }
 
+    #[must_use]
+    pub fn with_report_unused_directives(mut self, yes: bool) -> Self {
+        self.report_unused_directives = yes;
+        self
+    }
+
+    #[must_use]
+    pub fn with_globals(mut self, globals: Globals) -> Self {
+        self.globals = globals;
+        self
+    }
+
+    #[must_use]
+    pub fn with_unresolved_modules(mut self, unresolved_modules: FxHashSet<Atom>) -> Self {
+        self.unresolved_modules = unresolved_modules;
+        self
+    }
+
+    #[must_use]
+    pub fn with_type_checker(mut self, type_checker: Option<Arc<dyn TypeChecker>>) -> Self {
+        self.type_checker = type_checker;
+        self
+    }
+
     pub fn semantic(&self) -> &Rc<Semantic<'a>> {
         &self.semantic
     }
@@ -62,17 +120,65 @@ impl<'a> LintContext<'a> {
         &self.file_path
     }
 
+    pub fn globals(&self) -> &Globals {
+        &self.globals
+    }
+
+    pub fn unresolved_modules(&self) -> &FxHashSet<Atom> {
+        &self.unresolved_modules
+    }
+
+    /// The type of the expression at `span`, `None` without a type checker or when the type is
+    /// not known.
+    pub fn type_at(&self, span: Span) -> Option<TypeInfo> {
+        self.type_checker.as_ref()?.type_at(&self.file_path, self.source_text(), span)
+    }
+
     pub fn with_rule_name(&mut self, name: &'static str) {
         self.current_rule_name = name;
     }
 
+    pub fn with_rule_severity(&mut self, severity: Option<Severity>) {
+        self.current_rule_severity = severity;
+    }
+
+    pub fn with_rule_docs_url(&mut self, docs_url: Option<&'static str>) {
+        self.current_rule_docs_url = docs_url;
+    }
+
+    pub fn with_rule_span(&mut self, span: Span) {
+        self.current_rule_span = span;
+    }
+
     /* Diagnostics */
 
     pub fn into_message(self) -> Vec<Message<'a>> {
-        self.diagnostics.into_inner()
+        let mut diagnostics = self.diagnostics.into_inner();
+        if self.report_unused_directives {
+            diagnostics.extend(
+                self.disable_directives
+                    .unused_directives()
+                    .map(|diagnostic| Message::new(diagnostic.into(), None)),
+            );
+        }
+        diagnostics
     }
 
-    fn add_diagnostic(&self, message: Message<'a>) {
+    /// The number of diagnostics reported so far.
+    pub fn number_of_diagnostics(&self) -> usize {
+        self.diagnostics.borrow().len()
+    }
+
+    fn add_diagnostic(&self, mut message: Message<'a>) {
+        let span = self.current_rule_span;
+        if message.start() < span.start || message.start() >= span.end {
+            return;
+        }
+        let severity = self.current_rule_severity;
+        let docs_url = self.current_rule_docs_url;
+        if severity.is_some() || docs_url.is_some() {
+            message.error = Error::new(RuleDiagnostic { error: message.error, severity, docs_url });
+        }
         if !self.disable_directives.contains(self.current_rule_name, message.start()) {
             self.diagnostics.borrow_mut().push(message);
         }
@@ -94,6 +200,15 @@ impl<'a> LintContext<'a> {
         }
     }
 
+    /// Report a diagnostic with suggestions, which are never applied by `--fix`.
+    pub fn diagnostic_with_suggestions<T: Into<Error>>(
+        &self,
+        diagnostic: T,
+        suggestions: Vec<Suggestion<'a>>,
+    ) {
+        self.add_diagnostic(Message::new(diagnostic.into(), None).with_suggestions(suggestions));
+    }
+
     pub fn nodes(&self) -> &AstNodes<'a> {
         self.semantic().nodes()
     }
@@ -116,3 +231,61 @@ impl<'a> LintContext<'a> {
         self.semantic().jsdoc().get_by_node(node)
     }
 }
+
+/// A diagnostic reported with the severity configured for its rule, and with the documentation
+/// of its rule as [Diagnostic::url].
+#[derive(Debug)]
+struct RuleDiagnostic {
+    error: Error,
+    severity: Option<Severity>,
+    docs_url: Option<&'static str>,
+}
+
+impl fmt::Display for RuleDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.error.fmt(f)
+    }
+}
+
+impl std::error::Error for RuleDiagnostic {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.error.source()
+    }
+}
+
+impl Diagnostic for RuleDiagnostic {
+    fn code<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        self.error.code()
+    }
+
+    fn severity(&self) -> Option<Severity> {
+        self.severity.or_else(|| self.error.severity())
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        self.error.help()
+    }
+
+    fn url<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        if let Some(docs_url) = self.docs_url {
+            return Some(Box::new(docs_url));
+        }
+        self.error.url()
+    }
+
+    fn source_code(&self) -> Option<&dyn SourceCode> {
+        self.error.source_code()
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        self.error.labels()
+    }
+
+    fn related<'a>(&'a self) -> Option<Box<dyn Iterator<Item = &'a dyn Diagnostic> + 'a>> {
+        self.error.related()
+    }
+
+    fn diagnostic_source(&self) -> Option<&dyn Diagnostic> {
+        self.error.diagnostic_source()
+    }
+}
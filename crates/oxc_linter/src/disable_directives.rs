@@ -1,27 +1,75 @@
+use std::cell::Cell;
+
 use oxc_ast::TriviasMap;
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
 use oxc_span::Span;
 use rust_lapper::{Interval, Lapper};
 use rustc_hash::FxHashMap;
 
+#[derive(Debug, Error, Diagnostic)]
+#[diagnostic(severity(warning))]
+pub enum UnusedDisableDirective {
+    #[error("Unused eslint-disable directive (no problems were reported).")]
+    All(#[label] Span),
+    #[error("Unused eslint-disable directive (no problems were reported from {0:?}).")]
+    Single(String, #[label] Span),
+}
+
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 enum DisabledRule<'a> {
     All,
     Single(&'a str),
 }
 
+/// A rule disabled by a comment, shared by the intervals of the comment.
+#[derive(Debug)]
+struct DisableDirective<'a> {
+    /// The span of the comment
+    span: Span,
+    rule: DisabledRule<'a>,
+    /// Whether a diagnostic has been suppressed by the directive
+    used: Cell<bool>,
+}
+
 pub struct DisableDirectives<'a> {
-    /// All the disabled rules with their corresponding covering spans
-    intervals: Lapper<u32, DisabledRule<'a>>,
+    /// All the disabled rules with their corresponding covering spans,
+    /// the value is the index of the directive
+    intervals: Lapper<u32, usize>,
+    directives: Vec<DisableDirective<'a>>,
 }
 
 impl<'a> DisableDirectives<'a> {
     pub fn contains(&self, rule_name: &'static str, start: u32) -> bool {
-        self.intervals.find(start, start + 1).any(|interval| {
-            interval.val == DisabledRule::All
+        let mut contains = false;
+        for interval in self.intervals.find(start, start + 1) {
+            let directive = &self.directives[interval.val];
+            let disabled = match directive.rule {
+                DisabledRule::All => true,
                 // Our rule name currently does not contain the prefix.
                 // For example, this will match `@typescript-eslint/no-var-requires` given
                 // our rule_name is `no-var-requires`.
-                || matches!(interval.val, DisabledRule::Single(name) if name.contains(rule_name))
+                DisabledRule::Single(name) => name.contains(rule_name),
+            };
+            if disabled {
+                directive.used.set(true);
+                contains = true;
+            }
+        }
+        contains
+    }
+
+    /// The diagnostics of the directives which did not suppress any diagnostic.
+    pub fn unused_directives(&self) -> impl Iterator<Item = UnusedDisableDirective> + '_ {
+        self.directives.iter().filter(|directive| !directive.used.get()).map(|directive| {
+            match directive.rule {
+                DisabledRule::All => UnusedDisableDirective::All(directive.span),
+                DisabledRule::Single(name) => {
+                    UnusedDisableDirective::Single(name.to_string(), directive.span)
+                }
+            }
         })
     }
 }
@@ -30,11 +78,12 @@ pub struct DisableDirectivesBuilder<'a, 'b> {
     source_text: &'a str,
     trivias: &'b TriviasMap,
     /// All the disabled rules with their corresponding covering spans
-    intervals: Lapper<u32, DisabledRule<'a>>,
-    /// Start of `eslint-disable`
-    disable_all_start: Option<u32>,
-    /// Start of `eslint-disable rule_name`
-    disable_start_map: FxHashMap<&'a str, u32>,
+    intervals: Lapper<u32, usize>,
+    directives: Vec<DisableDirective<'a>>,
+    /// Start of `eslint-disable` and its directive
+    disable_all_start: Option<(u32, usize)>,
+    /// Start of `eslint-disable rule_name` and its directive
+    disable_start_map: FxHashMap<&'a str, (u32, usize)>,
 }
 
 impl<'a, 'b> DisableDirectivesBuilder<'a, 'b> {
@@ -43,6 +92,7 @@ impl<'a, 'b> DisableDirectivesBuilder<'a, 'b> {
             source_text,
             trivias,
             intervals: Lapper::new(vec![]),
+            directives: vec![],
             disable_all_start: None,
             disable_start_map: FxHashMap::default(),
         }
@@ -50,11 +100,16 @@ impl<'a, 'b> DisableDirectivesBuilder<'a, 'b> {
 
     pub fn build(mut self) -> DisableDirectives<'a> {
         self.build_impl();
-        DisableDirectives { intervals: self.intervals }
+        DisableDirectives { intervals: self.intervals, directives: self.directives }
     }
 
-    fn add_interval(&mut self, start: u32, stop: u32, val: DisabledRule<'a>) {
-        self.intervals.insert(Interval { start, stop, val });
+    fn add_directive(&mut self, span: Span, rule: DisabledRule<'a>) -> usize {
+        self.directives.push(DisableDirective { span, rule, used: Cell::new(false) });
+        self.directives.len() - 1
+    }
+
+    fn add_interval(&mut self, start: u32, stop: u32, directive: usize) {
+        self.intervals.insert(Interval { start, stop, val: directive });
     }
 
     #[allow(clippy::cast_possible_truncation)] // for `as u32`
@@ -67,11 +122,17 @@ impl<'a, 'b> DisableDirectivesBuilder<'a, 'b> {
             let span = Span::new(*start, comment.end());
             let text = span.source_text(self.source_text);
             let text = text.trim_start();
+            // `oxlint-disable` is the same as `eslint-disable`
+            let Some(text) = text.strip_prefix("eslint-").or_else(|| text.strip_prefix("oxlint-"))
+            else {
+                continue;
+            };
 
-            if let Some(text) = text.strip_prefix("eslint-disable") {
+            if let Some(text) = text.strip_prefix("disable") {
                 // `eslint-disable`
                 if text.trim().is_empty() {
-                    self.disable_all_start = Some(span.end);
+                    let directive = self.add_directive(span, DisabledRule::All);
+                    self.disable_all_start = Some((span.end, directive));
                     continue;
                 }
 
@@ -84,11 +145,14 @@ impl<'a, 'b> DisableDirectivesBuilder<'a, 'b> {
                         .map(|line| span.end + line.len() as u32)
                         .sum();
                     if text.trim().is_empty() {
-                        self.add_interval(span.end, stop, DisabledRule::All);
+                        let directive = self.add_directive(span, DisabledRule::All);
+                        self.add_interval(span.end, stop, directive);
                     } else {
                         // `eslint-disable-next-line rule_name1, rule_name2`
                         Self::get_rule_names(text, |rule_name| {
-                            self.add_interval(span.end, stop, DisabledRule::Single(rule_name));
+                            let directive =
+                                self.add_directive(span, DisabledRule::Single(rule_name));
+                            self.add_interval(span.end, stop, directive);
                         });
                     }
                     continue;
@@ -105,11 +169,14 @@ impl<'a, 'b> DisableDirectivesBuilder<'a, 'b> {
 
                     // `eslint-disable-line`
                     if text.trim().is_empty() {
-                        self.add_interval(start, stop, DisabledRule::All);
+                        let directive = self.add_directive(span, DisabledRule::All);
+                        self.add_interval(start, stop, directive);
                     } else {
                         // `eslint-disable-line rule-name1, rule-name2`
                         Self::get_rule_names(text, |rule_name| {
-                            self.add_interval(start, stop, DisabledRule::Single(rule_name));
+                            let directive =
+                                self.add_directive(span, DisabledRule::Single(rule_name));
+                            self.add_interval(start, stop, directive);
                         });
                     }
                     continue;
@@ -117,23 +184,24 @@ impl<'a, 'b> DisableDirectivesBuilder<'a, 'b> {
 
                 // `eslint-disable rule-name1, rule-name2`
                 Self::get_rule_names(text, |rule_name| {
-                    self.disable_start_map.insert(rule_name, span.end);
+                    let directive = self.add_directive(span, DisabledRule::Single(rule_name));
+                    self.disable_start_map.insert(rule_name, (span.end, directive));
                 });
 
                 continue;
             }
 
-            if let Some(text) = text.strip_prefix("eslint-enable") {
+            if let Some(text) = text.strip_prefix("enable") {
                 // `eslint-enable`
                 if text.trim().is_empty() {
-                    if let Some(start) = self.disable_all_start.take() {
-                        self.add_interval(start, span.start, DisabledRule::All);
+                    if let Some((start, directive)) = self.disable_all_start.take() {
+                        self.add_interval(start, span.start, directive);
                     }
                 } else {
                     // `eslint-enable rule-name1, rule-name2`
                     Self::get_rule_names(text, |rule_name| {
-                        if let Some(start) = self.disable_start_map.remove(rule_name) {
-                            self.add_interval(start, span.start, DisabledRule::Single(rule_name));
+                        if let Some((start, directive)) = self.disable_start_map.remove(rule_name) {
+                            self.add_interval(start, span.start, directive);
                         }
                     });
                 }
@@ -142,20 +210,20 @@ impl<'a, 'b> DisableDirectivesBuilder<'a, 'b> {
         }
 
         // Lone `eslint-disable`
-        if let Some(start) = self.disable_all_start {
-            self.add_interval(start, source_len, DisabledRule::All);
+        if let Some((start, directive)) = self.disable_all_start {
+            self.add_interval(start, source_len, directive);
         }
 
         // Lone `eslint-disable rule_name`
         let disable_start_map = self.disable_start_map.drain().collect::<Vec<_>>();
-        for (rule_name, start) in disable_start_map {
-            self.add_interval(start, source_len, DisabledRule::Single(rule_name));
+        for (_, (start, directive)) in disable_start_map {
+            self.add_interval(start, source_len, directive);
         }
     }
 
     fn get_rule_names<F: FnMut(&'a str)>(text: &'a str, cb: F) {
         if let Some(text) = text.split_terminator("--").next() {
-            text.split(',').map(str::trim).for_each(cb);
+            text.split(',').map(str::trim).filter(|name| !name.is_empty()).for_each(cb);
         }
     }
 }
@@ -190,11 +258,10 @@ fn test() {
             debugger;
         ",
         // To ensure that a rule is never applied (regardless of any future enable/disable lines):
-        // This is not supported.
-        // "
-        // /* eslint no-debugger: \"off\" */
-        //     debugger;
-        // ",
+        "
+        /* eslint no-debugger: \"off\" */
+            debugger;
+        ",
         // To disable all rules on a specific line, use a line or block comment in one of the following formats:
         "debugger; // eslint-disable-line
             debugger; // eslint-disable-line
@@ -248,6 +315,17 @@ fn test() {
              * along with some additional information
             **/
             debugger;
+        ",
+        // The oxlint prefix
+        "
+        /* oxlint-disable */
+            debugger;
+        /* oxlint-enable */
+
+            // oxlint-disable-next-line no-debugger
+            debugger;
+
+            debugger; // oxlint-disable-line
         "
     ];
 
@@ -286,3 +364,41 @@ fn test() {
 
     Tester::new_without_config("no-debugger", pass, fail).test();
 }
+
+#[test]
+fn test_unused_directives() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "debugger; // eslint-disable-line no-debugger",
+        "
+        /* eslint-disable */
+            debugger;
+        /* eslint-enable */
+        ",
+        "
+        // oxlint-disable-next-line no-debugger
+            debugger;
+        ",
+    ];
+
+    let fail = vec![
+        "foo(); // eslint-disable-line no-debugger",
+        "foo(); // eslint-disable-line",
+        "
+        /* eslint-disable */
+            foo();
+        /* eslint-enable */
+        ",
+        "
+        // oxlint-disable-next-line no-debugger
+            foo();
+        ",
+        // Only `no-console` is unused
+        "debugger; // eslint-disable-line no-debugger, no-console",
+    ];
+
+    Tester::new_without_config("no-debugger", pass, fail)
+        .with_report_unused_directives(true)
+        .test();
+}
@@ -17,6 +17,49 @@ impl<'a> Fix<'a> {
     pub fn new<T: Into<Cow<'a, str>>>(content: T, span: Span) -> Self {
         Self { content: content.into(), span }
     }
+
+    /// Compose the fixes into a single fix replacing the whole range they cover,
+    /// so they are applied together or not at all.
+    /// Returns `None` when there are no fixes or when two of them overlap.
+    pub fn merge(source_text: &'a str, mut fixes: Vec<Fix<'a>>) -> Option<Self> {
+        fixes.sort_by_key(|fix| fix.span);
+        let start = fixes.first()?.span.start;
+        let mut end = start;
+        let mut content = String::new();
+        for fix in &fixes {
+            if fix.span.start < end || fix.span.start > fix.span.end {
+                return None;
+            }
+            content.push_str(&source_text[end as usize..fix.span.start as usize]);
+            content.push_str(&fix.content);
+            end = fix.span.end;
+        }
+        Some(Self::new(content, Span::new(start, end)))
+    }
+}
+
+/// A fix offered to the user but never applied by the fixer, e.g. as a quick fix of an editor.
+/// A diagnostic can have many suggestions, each describing a different way to fix it.
+#[derive(Debug)]
+pub struct Suggestion<'a> {
+    /// Describes the fix, e.g. "Use `unknown` instead".
+    pub message: Cow<'static, str>,
+    pub fix: Fix<'a>,
+}
+
+impl<'a> Suggestion<'a> {
+    pub fn new<T: Into<Cow<'static, str>>>(message: T, fix: Fix<'a>) -> Self {
+        Self { message: message.into(), fix }
+    }
+
+    /// Apply the suggestion to the source text.
+    pub fn apply(&self, source_text: &str) -> String {
+        let Span { start, end } = self.fix.span;
+        let mut output = source_text[..start as usize].to_string();
+        output.push_str(&self.fix.content);
+        output.push_str(&source_text[end as usize..]);
+        output
+    }
 }
 
 #[derive(Debug)]
@@ -32,6 +75,7 @@ pub struct Message<'a> {
     start: u32,
     end: u32,
     pub fix: Option<Fix<'a>>,
+    pub suggestions: Vec<Suggestion<'a>>,
     fixed: bool,
 }
 
@@ -45,7 +89,13 @@ impl<'a> Message<'a> {
             .iter()
             .max_by_key(|span| span.offset() + span.len())
             .map_or(0, |span| (span.offset() + span.len()) as u32);
-        Self { error, start, end, fix, fixed: false }
+        Self { error, start, end, fix, suggestions: vec![], fixed: false }
+    }
+
+    #[must_use]
+    pub fn with_suggestions(mut self, suggestions: Vec<Suggestion<'a>>) -> Self {
+        self.suggestions = suggestions;
+        self
     }
 
     pub fn start(&self) -> u32 {
@@ -185,6 +235,22 @@ mod test {
     #[error("nofix2")]
     struct NoFix2(#[label] pub Span);
 
+    #[test]
+    fn merge_fixes() {
+        let fix = Fix::merge(TEST_CODE, vec![REPLACE_NUM, REPLACE_VAR, REPLACE_ID]).unwrap();
+        assert_eq!(fix.span, Span::new(0, 14));
+        assert_eq!(fix.content, "let foo = 5");
+        let result = get_fix_result(vec![create_message(ReplaceVar, Some(fix))]);
+        assert_eq!(result.fixed_code, "let foo = 5 * 7;");
+    }
+
+    #[test]
+    fn merge_overlapping_fixes() {
+        assert!(Fix::merge(TEST_CODE, vec![REMOVE_MIDDLE, REPLACE_ID]).is_none());
+        assert!(Fix::merge(TEST_CODE, vec![REVERSE_RANGE]).is_none());
+        assert!(Fix::merge(TEST_CODE, vec![]).is_none());
+    }
+
     fn get_fix_result(messages: Vec<Message>) -> FixResult {
         Fixer::new(TEST_CODE, messages).fix()
     }
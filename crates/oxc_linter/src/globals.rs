@@ -4,6 +4,7 @@
 //! A value of false indicates that the variable should be considered read-only.
 
 use phf::{phf_map, Map};
+use rustc_hash::FxHashMap;
 
 pub const BUILTINS: Map<&'static str, bool> = phf_map! {
     "AggregateError" => false,
@@ -81,3 +82,449 @@ pub const PRE_DEFINE_VAR: Map<&'static str, bool> = phf_map! {
     "eval" => false,
     "arguments" => false,
 };
+
+pub const ENV_BROWSER: Map<&'static str, bool> = phf_map! {
+    "AbortController" => false,
+    "AbortSignal" => false,
+    "Audio" => false,
+    "AudioContext" => false,
+    "Blob" => false,
+    "BroadcastChannel" => false,
+    "CSS" => false,
+    "CSSStyleSheet" => false,
+    "CanvasRenderingContext2D" => false,
+    "CustomEvent" => false,
+    "DOMException" => false,
+    "DOMParser" => false,
+    "DOMRect" => false,
+    "Document" => false,
+    "DocumentFragment" => false,
+    "Element" => false,
+    "Event" => false,
+    "EventSource" => false,
+    "EventTarget" => false,
+    "File" => false,
+    "FileList" => false,
+    "FileReader" => false,
+    "FormData" => false,
+    "HTMLAnchorElement" => false,
+    "HTMLButtonElement" => false,
+    "HTMLCanvasElement" => false,
+    "HTMLDivElement" => false,
+    "HTMLElement" => false,
+    "HTMLFormElement" => false,
+    "HTMLIFrameElement" => false,
+    "HTMLImageElement" => false,
+    "HTMLInputElement" => false,
+    "HTMLMediaElement" => false,
+    "HTMLScriptElement" => false,
+    "HTMLSelectElement" => false,
+    "HTMLTemplateElement" => false,
+    "HTMLTextAreaElement" => false,
+    "HTMLVideoElement" => false,
+    "Headers" => false,
+    "History" => false,
+    "IDBKeyRange" => false,
+    "Image" => false,
+    "IntersectionObserver" => false,
+    "KeyboardEvent" => false,
+    "Location" => false,
+    "MediaQueryList" => false,
+    "MessageChannel" => false,
+    "MessageEvent" => false,
+    "MessagePort" => false,
+    "MouseEvent" => false,
+    "MutationObserver" => false,
+    "Navigator" => false,
+    "Node" => false,
+    "NodeList" => false,
+    "Notification" => false,
+    "PointerEvent" => false,
+    "Range" => false,
+    "Request" => false,
+    "ResizeObserver" => false,
+    "Response" => false,
+    "SVGElement" => false,
+    "Screen" => false,
+    "ShadowRoot" => false,
+    "Storage" => false,
+    "Text" => false,
+    "TextDecoder" => false,
+    "TextEncoder" => false,
+    "TouchEvent" => false,
+    "UIEvent" => false,
+    "URL" => false,
+    "URLSearchParams" => false,
+    "WebAssembly" => false,
+    "WebSocket" => false,
+    "Window" => false,
+    "Worker" => false,
+    "XMLHttpRequest" => false,
+    "XMLSerializer" => false,
+    "addEventListener" => false,
+    "alert" => false,
+    "atob" => false,
+    "blur" => false,
+    "btoa" => false,
+    "caches" => false,
+    "cancelAnimationFrame" => false,
+    "cancelIdleCallback" => false,
+    "clearInterval" => false,
+    "clearTimeout" => false,
+    "close" => false,
+    "closed" => false,
+    "confirm" => false,
+    "console" => false,
+    "crypto" => false,
+    "customElements" => false,
+    "devicePixelRatio" => false,
+    "dispatchEvent" => false,
+    "document" => false,
+    "fetch" => false,
+    "focus" => false,
+    "frames" => false,
+    "getComputedStyle" => false,
+    "getSelection" => false,
+    "globalThis" => false,
+    "history" => false,
+    "indexedDB" => false,
+    "innerHeight" => false,
+    "innerWidth" => false,
+    "localStorage" => false,
+    "location" => false,
+    "matchMedia" => false,
+    "navigator" => false,
+    "open" => false,
+    "opener" => false,
+    "outerHeight" => false,
+    "outerWidth" => false,
+    "pageXOffset" => false,
+    "pageYOffset" => false,
+    "parent" => false,
+    "performance" => false,
+    "postMessage" => false,
+    "print" => false,
+    "prompt" => false,
+    "queueMicrotask" => false,
+    "removeEventListener" => false,
+    "reportError" => false,
+    "requestAnimationFrame" => false,
+    "requestIdleCallback" => false,
+    "screen" => false,
+    "screenX" => false,
+    "screenY" => false,
+    "scroll" => false,
+    "scrollBy" => false,
+    "scrollTo" => false,
+    "scrollX" => false,
+    "scrollY" => false,
+    "self" => false,
+    "sessionStorage" => false,
+    "setInterval" => false,
+    "setTimeout" => false,
+    "structuredClone" => false,
+    "top" => false,
+    "window" => false,
+    "name" => true,
+    "onload" => true,
+    "onerror" => true,
+    "onmessage" => true,
+    "onresize" => true,
+    "onscroll" => true,
+    "status" => true,
+};
+
+pub const ENV_NODE: Map<&'static str, bool> = phf_map! {
+    "__dirname" => false,
+    "__filename" => false,
+    "exports" => true,
+    "module" => false,
+    "require" => false,
+    "AbortController" => false,
+    "AbortSignal" => false,
+    "atob" => false,
+    "Blob" => false,
+    "BroadcastChannel" => false,
+    "btoa" => false,
+    "Buffer" => false,
+    "clearImmediate" => false,
+    "clearInterval" => false,
+    "clearTimeout" => false,
+    "console" => false,
+    "crypto" => false,
+    "DOMException" => false,
+    "Event" => false,
+    "EventTarget" => false,
+    "fetch" => false,
+    "FormData" => false,
+    "global" => false,
+    "Headers" => false,
+    "MessageChannel" => false,
+    "MessageEvent" => false,
+    "MessagePort" => false,
+    "performance" => false,
+    "process" => false,
+    "queueMicrotask" => false,
+    "Request" => false,
+    "Response" => false,
+    "setImmediate" => false,
+    "setInterval" => false,
+    "setTimeout" => false,
+    "structuredClone" => false,
+    "TextDecoder" => false,
+    "TextEncoder" => false,
+    "URL" => false,
+    "URLSearchParams" => false,
+    "WebAssembly" => false,
+};
+
+pub const ENV_SHARED_NODE_BROWSER: Map<&'static str, bool> = phf_map! {
+    "AbortController" => false,
+    "AbortSignal" => false,
+    "Blob" => false,
+    "BroadcastChannel" => false,
+    "DOMException" => false,
+    "Event" => false,
+    "EventTarget" => false,
+    "FormData" => false,
+    "Headers" => false,
+    "MessageChannel" => false,
+    "MessageEvent" => false,
+    "MessagePort" => false,
+    "Request" => false,
+    "Response" => false,
+    "TextDecoder" => false,
+    "TextEncoder" => false,
+    "URL" => false,
+    "URLSearchParams" => false,
+    "WebAssembly" => false,
+    "atob" => false,
+    "btoa" => false,
+    "clearInterval" => false,
+    "clearTimeout" => false,
+    "console" => false,
+    "crypto" => false,
+    "fetch" => false,
+    "performance" => false,
+    "queueMicrotask" => false,
+    "setInterval" => false,
+    "setTimeout" => false,
+    "structuredClone" => false,
+};
+
+pub const ENV_WORKER: Map<&'static str, bool> = phf_map! {
+    "AbortController" => false,
+    "AbortSignal" => false,
+    "addEventListener" => false,
+    "atob" => false,
+    "Blob" => false,
+    "BroadcastChannel" => false,
+    "btoa" => false,
+    "ByteLengthQueuingStrategy" => false,
+    "Cache" => false,
+    "caches" => false,
+    "CacheStorage" => false,
+    "cancelAnimationFrame" => false,
+    "clearInterval" => false,
+    "clearTimeout" => false,
+    "close" => false,
+    "CompressionStream" => false,
+    "console" => false,
+    "CountQueuingStrategy" => false,
+    "createImageBitmap" => false,
+    "crypto" => false,
+    "Crypto" => false,
+    "CryptoKey" => false,
+    "CustomEvent" => false,
+    "DecompressionStream" => false,
+    "dispatchEvent" => false,
+    "DOMException" => false,
+    "ErrorEvent" => false,
+    "Event" => false,
+    "EventSource" => false,
+    "EventTarget" => false,
+    "fetch" => false,
+    "File" => false,
+    "FileList" => false,
+    "FileReader" => false,
+    "FileReaderSync" => false,
+    "FormData" => false,
+    "Headers" => false,
+    "IDBCursor" => false,
+    "IDBDatabase" => false,
+    "IDBFactory" => false,
+    "IDBIndex" => false,
+    "IDBKeyRange" => false,
+    "IDBObjectStore" => false,
+    "IDBRequest" => false,
+    "IDBTransaction" => false,
+    "ImageBitmap" => false,
+    "ImageData" => false,
+    "importScripts" => false,
+    "indexedDB" => false,
+    "location" => false,
+    "MessageChannel" => false,
+    "MessageEvent" => false,
+    "MessagePort" => false,
+    "name" => false,
+    "navigator" => false,
+    "Notification" => false,
+    "OffscreenCanvas" => false,
+    "onerror" => true,
+    "onlanguagechange" => true,
+    "onmessage" => true,
+    "onmessageerror" => true,
+    "onoffline" => true,
+    "ononline" => true,
+    "onrejectionhandled" => true,
+    "onunhandledrejection" => true,
+    "performance" => false,
+    "Performance" => false,
+    "postMessage" => false,
+    "queueMicrotask" => false,
+    "ReadableStream" => false,
+    "removeEventListener" => false,
+    "reportError" => false,
+    "Request" => false,
+    "requestAnimationFrame" => false,
+    "Response" => false,
+    "self" => true,
+    "setInterval" => false,
+    "setTimeout" => false,
+    "structuredClone" => false,
+    "SubtleCrypto" => false,
+    "TextDecoder" => false,
+    "TextDecoderStream" => false,
+    "TextEncoder" => false,
+    "TextEncoderStream" => false,
+    "TransformStream" => false,
+    "URL" => false,
+    "URLSearchParams" => false,
+    "WebAssembly" => false,
+    "WebSocket" => false,
+    "Worker" => false,
+    "WorkerGlobalScope" => false,
+    "WorkerLocation" => false,
+    "WorkerNavigator" => false,
+    "WritableStream" => false,
+    "XMLHttpRequest" => false,
+};
+
+pub const ENV_COMMONJS: Map<&'static str, bool> = phf_map! {
+    "exports" => false,
+    "global" => false,
+    "module" => false,
+    "require" => false,
+};
+
+pub const ENV_JEST: Map<&'static str, bool> = phf_map! {
+    "afterAll" => false,
+    "afterEach" => false,
+    "beforeAll" => false,
+    "beforeEach" => false,
+    "describe" => false,
+    "expect" => false,
+    "fit" => false,
+    "it" => false,
+    "jest" => false,
+    "pit" => false,
+    "require" => false,
+    "test" => false,
+    "xdescribe" => false,
+    "xit" => false,
+    "xtest" => false,
+};
+
+pub const ENV_VITEST: Map<&'static str, bool> = phf_map! {
+    "afterAll" => false,
+    "afterEach" => false,
+    "assert" => false,
+    "assertType" => false,
+    "beforeAll" => false,
+    "beforeEach" => false,
+    "bench" => false,
+    "chai" => false,
+    "describe" => false,
+    "expect" => false,
+    "expectTypeOf" => false,
+    "it" => false,
+    "onTestFailed" => false,
+    "suite" => false,
+    "test" => false,
+    "vi" => false,
+    "vitest" => false,
+};
+
+pub const ENV_MOCHA: Map<&'static str, bool> = phf_map! {
+    "after" => false,
+    "afterEach" => false,
+    "before" => false,
+    "beforeEach" => false,
+    "context" => false,
+    "describe" => false,
+    "it" => false,
+    "mocha" => false,
+    "run" => false,
+    "setup" => false,
+    "specify" => false,
+    "suite" => false,
+    "suiteSetup" => false,
+    "suiteTeardown" => false,
+    "teardown" => false,
+    "test" => false,
+    "xcontext" => false,
+    "xdescribe" => false,
+    "xit" => false,
+    "xspecify" => false,
+};
+
+/// The globals of an `env` of an ESLint configuration.
+/// The ECMAScript environments (`es6`, `es2024`, `builtin`, ...) are the [BUILTINS], which are
+/// always enabled.
+pub fn environment(name: &str) -> Option<&'static Map<&'static str, bool>> {
+    match name {
+        "builtin" | "es6" | "es2015" | "es2016" | "es2017" | "es2018" | "es2019" | "es2020"
+        | "es2021" | "es2022" | "es2023" | "es2024" => Some(&BUILTINS),
+        "browser" => Some(&ENV_BROWSER),
+        "worker" => Some(&ENV_WORKER),
+        "node" => Some(&ENV_NODE),
+        "shared-node-browser" => Some(&ENV_SHARED_NODE_BROWSER),
+        "commonjs" => Some(&ENV_COMMONJS),
+        "jest" => Some(&ENV_JEST),
+        "mocha" => Some(&ENV_MOCHA),
+        "vitest" => Some(&ENV_VITEST),
+        _ => None,
+    }
+}
+
+/// The globals of a file, configured by the `globals` and `env` of its configuration files.
+#[derive(Debug, Default, Clone)]
+pub struct Globals {
+    /// `Some(true)` for writable, `Some(false)` for read-only and `None` for `"off"`.
+    globals: FxHashMap<String, Option<bool>>,
+    envs: FxHashMap<String, &'static Map<&'static str, bool>>,
+}
+
+impl Globals {
+    pub fn set_global(&mut self, name: String, writable: Option<bool>) {
+        self.globals.insert(name, writable);
+    }
+
+    /// Unknown environments are ignored.
+    pub fn set_env(&mut self, name: &str, enabled: bool) {
+        let Some(globals) = environment(name) else { return };
+        if enabled {
+            self.envs.insert(name.to_string(), globals);
+        } else {
+            self.envs.remove(name);
+        }
+    }
+
+    /// Whether `name` is a configured global: `Some(true)` when it is writable.
+    pub fn get(&self, name: &str) -> Option<bool> {
+        if let Some(writable) = self.globals.get(name) {
+            return *writable;
+        }
+        self.envs.values().find_map(|globals| globals.get(name).copied())
+    }
+}
@@ -0,0 +1,197 @@
+//! [Configuration comments](https://eslint.org/docs/latest/use/configure/rules#using-configuration-comments)
+//!
+//! * `/* global foo, bar: writable */` or `/* globals ... */` declare globals for the whole file.
+//! * `/* eslint-env node, mocha */` enables environments for the whole file.
+//! * `/* eslint eqeqeq: "off", curly: ["error", "all"] */` configures rules from the end of the
+//!   comment to the end of the file, the values are JSON.
+//!
+//! `oxlint` can be used instead of `eslint`, and a description can follow `--`,
+//! as in the disable directives.
+
+use oxc_ast::TriviasMap;
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+    Error as DiagnosticError,
+};
+use oxc_span::Span;
+use serde_json::{json, Value};
+
+use crate::{config::ESLintConfig, globals::Globals};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Invalid configuration comment")]
+#[diagnostic(help("{0}"))]
+struct InvalidConfigComment(String, #[label] Span);
+
+#[derive(Default)]
+pub struct InlineConfig {
+    /// `Some(true)` for writable, `Some(false)` for read-only and `None` for `"off"`.
+    globals: Vec<(String, Option<bool>)>,
+    envs: Vec<String>,
+    /// The rules configured by each comment, with the end of the comment.
+    pub rules: Vec<(u32, ESLintConfig)>,
+    /// The comments which can not be parsed.
+    pub errors: Vec<DiagnosticError>,
+}
+
+impl InlineConfig {
+    pub fn new(source_text: &str, trivias: &TriviasMap) -> Self {
+        let mut config = Self::default();
+        for (start, comment) in trivias.comments() {
+            if !comment.is_multi_line() {
+                continue;
+            }
+            let span = Span::new(*start, comment.end());
+            let text = span.source_text(source_text).trim_start();
+            let (directive, text) = text.split_once(char::is_whitespace).unwrap_or((text, ""));
+            let text = text.split_terminator("--").next().unwrap_or_default();
+            match directive {
+                "global" | "globals" => {
+                    for item in list_items(text) {
+                        let (name, value) = item.split_once(':').unwrap_or((&item, "readonly"));
+                        let writable = match value {
+                            "writable" | "writeable" | "true" => Some(true),
+                            "readonly" | "readable" | "false" => Some(false),
+                            "off" => None,
+                            value => {
+                                let message = format!(
+                                    "{value:?} of the global {name:?} is not one of \"readonly\", \"writable\" or \"off\""
+                                );
+                                config.errors.push(InvalidConfigComment(message, span).into());
+                                continue;
+                            }
+                        };
+                        config.globals.push((name.to_string(), writable));
+                    }
+                }
+                "eslint-env" | "oxlint-env" => {
+                    config.envs.extend(list_items(text));
+                }
+                "eslint" | "oxlint" => {
+                    let Some(rules) = parse_rules(text) else {
+                        let message = format!("Failed to parse {:?} as JSON", text.trim());
+                        config.errors.push(InvalidConfigComment(message, span).into());
+                        continue;
+                    };
+                    match ESLintConfig::from_value(&json!({ "rules": rules })) {
+                        Ok(rules) => config.rules.push((span.end, rules)),
+                        Err(error) => {
+                            let mut message = error.to_string();
+                            if let Some(help) = error.help() {
+                                message = format!("{message}: {help}");
+                            }
+                            config.errors.push(InvalidConfigComment(message, span).into());
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        config
+    }
+
+    /// Add the globals and the environments of the comments to the configured ones.
+    pub fn apply_globals(&self, globals: &mut Globals) {
+        for env in &self.envs {
+            globals.set_env(env, true);
+        }
+        for (name, writable) in &self.globals {
+            globals.set_global(name.clone(), *writable);
+        }
+    }
+}
+
+/// `foo, bar : writable baz` -> `["foo", "bar:writable", "baz"]`
+fn list_items(text: &str) -> Vec<String> {
+    let text = text.split(':').map(str::trim).collect::<Vec<_>>().join(":");
+    text.split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|item| !item.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// `no-debugger: "off", eqeqeq: ["error"]` -> `{ "no-debugger": "off", "eqeqeq": ["error"] }`,
+/// the keys may be quoted or not.
+fn parse_rules(text: &str) -> Option<Value> {
+    let mut json = String::from("{");
+    let mut depth = 0;
+    let mut in_string = false;
+    let mut escaped = false;
+    let mut expect_key = true;
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        if expect_key {
+            if c.is_whitespace() {
+                continue;
+            }
+            expect_key = false;
+            if c != '"' {
+                let mut key = String::from(c);
+                while let Some(c) = chars.next_if(|c| *c != ':') {
+                    key.push(c);
+                }
+                json.push_str(&serde_json::to_string(key.trim()).ok()?);
+                continue;
+            }
+        }
+        json.push(c);
+        if in_string {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+        match c {
+            '"' => in_string = true,
+            '[' | '{' => depth += 1,
+            ']' | '}' => depth -= 1,
+            ',' if depth == 0 => expect_key = true,
+            _ => {}
+        }
+    }
+    json.push('}');
+    serde_json::from_str(&json).ok()
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "/* eslint no-debugger: \"off\" */ debugger;",
+        "/* oxlint no-debugger: 0 -- not in production */ debugger;",
+        "/* eslint no-debugger: \"off\" */ debugger; /* eslint-enable no-debugger */ debugger;",
+        "/* eslint \"no-debugger\": [\"off\"], eqeqeq: \"error\" */ debugger;",
+    ];
+    let fail = vec![
+        "debugger; /* eslint no-debugger: \"off\" */",
+        "/* eslint no-debugger: \"off\" */ debugger; /* eslint no-debugger: \"warn\" */ debugger;",
+        "/* eslint no-debugger: \"on\" */",
+        "/* eslint no-debugger: off */",
+    ];
+    Tester::new_without_config("no-debugger", pass, fail).test();
+
+    let pass = vec![
+        "/* eslint no-console: [\"error\", { \"allow\": [\"warn\"] }] */ console.warn()",
+        "/* eslint no-console: \"off\" */ console.log()",
+    ];
+    let fail = vec![
+        "console.warn(); /* eslint no-console: [\"error\", { \"allow\": [\"warn\"] }] */",
+        "/* eslint no-console: [\"error\", { \"allow\": [\"warn\"] }] */ console.log()",
+    ];
+    Tester::new_without_config("no-console", pass, fail).test();
+
+    let pass = vec![
+        "/* global foo */ foo;",
+        "/* globals foo, bar: writable */ foo; bar = 1;",
+        "/*global foo:readonly bar : true*/ bar = foo;",
+        "/* eslint-env browser */ window;",
+    ];
+    let fail =
+        vec!["/* global foo: off */ foo;", "/* global foo: maybe */ foo;", "// global foo\nfoo;"];
+    Tester::new_without_config("no-undef", pass, fail).test();
+}
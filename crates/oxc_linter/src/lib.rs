@@ -4,36 +4,65 @@
 mod tester;
 
 mod ast_util;
+mod cache;
+mod config;
 mod context;
 mod disable_directives;
 mod fixer;
 mod globals;
+mod inline_config;
 mod options;
+mod partial_loader;
 pub mod rule;
 mod rule_timer;
 mod rules;
 mod service;
+mod type_checker;
 mod utils;
 
-use std::{self, fs, io::Write, rc::Rc, time::Duration};
+use std::{self, borrow::Cow, io::Write, path::Path, rc::Rc, sync::Mutex, time::Duration};
 
-pub(crate) use oxc_semantic::AstNode;
-use rustc_hash::FxHashMap;
+use oxc_diagnostics::{Error, Severity};
+pub use oxc_semantic::AstNode;
+use oxc_span::Span;
+use rustc_hash::{FxHashMap, FxHashSet};
 
 pub use crate::{
+    config::{ESLintConfig, Oxlintrc},
     context::LintContext,
     fixer::Fix,
-    fixer::{FixResult, Fixer, Message},
+    fixer::{FixResult, Fixer, Message, Suggestion},
     options::{AllowWarnDeny, LintOptions},
-    rule::RuleCategory,
+    partial_loader::{JavaScriptSource, PartialLoader, LINT_PARTIAL_LOADER_EXT},
+    rule::{CustomRule, Rule, RuleCategory, RuleMeta},
     service::LintService,
+    type_checker::{TypeChecker, TypeInfo, TypeKind},
 };
 pub(crate) use rules::{RuleEnum, RULES};
+#[cfg(feature = "type_aware")]
+pub use type_checker::TscTypeChecker;
+
+use crate::{
+    config::{FileConfig, OxlintrcResolver},
+    globals::Globals,
+    inline_config::InlineConfig,
+};
+
+/// The number of files listed by [Linter::print_execution_times].
+const SLOWEST_FILES: usize = 10;
 
 #[derive(Debug)]
 pub struct Linter {
     rules: Vec<RuleEnum>,
     options: LintOptions,
+    /// The severities configured by the ESLint configuration.
+    severities: FxHashMap<&'static str, Severity>,
+    /// The globals configured by the options and the ESLint configuration.
+    globals: Globals,
+    /// Finds the `.oxlintrc.json` of the linted files when [LintOptions::oxlintrc] is enabled.
+    oxlintrc: Option<OxlintrcResolver>,
+    /// The time spent on each file when [LintOptions::timing] is enabled.
+    file_times: Mutex<Vec<(Box<Path>, Duration)>>,
 }
 
 impl Linter {
@@ -43,12 +72,23 @@ impl Linter {
             .cloned()
             .filter(|rule| rule.category() == RuleCategory::Correctness)
             .collect::<Vec<_>>();
-        Self { rules, options: LintOptions::default() }
+        Self {
+            rules,
+            options: LintOptions::default(),
+            severities: FxHashMap::default(),
+            globals: Globals::default(),
+            oxlintrc: None,
+            file_times: Mutex::default(),
+        }
     }
 
     pub fn from_options(options: LintOptions) -> Self {
         let rules = options.derive_rules();
-        Self { rules, options }
+        let severities =
+            options.eslint_config.as_ref().map(ESLintConfig::severities).unwrap_or_default();
+        let globals = options.derive_globals();
+        let oxlintrc = options.oxlintrc.then(OxlintrcResolver::default);
+        Self { rules, options, severities, globals, oxlintrc, file_times: Mutex::default() }
     }
 
     #[must_use]
@@ -57,6 +97,22 @@ impl Linter {
         self
     }
 
+    /// Enables the rules declared outside of this crate in addition to the configured ones.
+    /// They are reconfigured or disabled by the ESLint configuration as `<plugin_name>/<rule name>`.
+    #[must_use]
+    pub fn with_custom_rules(mut self, custom_rules: impl IntoIterator<Item = CustomRule>) -> Self {
+        let mut rules = std::mem::take(&mut self.rules).into_iter().collect::<FxHashSet<_>>();
+        for rule in custom_rules {
+            rules.replace(RuleEnum::Custom(rule));
+        }
+        if let Some(config) = &self.options.eslint_config {
+            config.apply(&mut rules, &mut self.severities);
+        }
+        self.rules = rules.into_iter().collect();
+        self.rules.sort_unstable_by_key(RuleEnum::name);
+        self
+    }
+
     pub fn rules(&self) -> &Vec<RuleEnum> {
         &self.rules
     }
@@ -84,37 +140,125 @@ impl Linter {
     pub fn run<'a>(&self, ctx: LintContext<'a>) -> Vec<Message<'a>> {
         let timing = self.options.timing;
         let semantic = Rc::clone(ctx.semantic());
-        let mut ctx = ctx.with_fix(self.options.fix);
+        let mut ctx = ctx
+            .with_fix(self.options.fix || self.options.report_fixes)
+            .with_report_unused_directives(self.options.report_unused_directives)
+            .with_type_checker(self.options.type_checker.clone());
+
+        let (rules, severities, mut globals) = match self.resolve_oxlintrc(ctx.file_path()) {
+            Ok(Some(config)) => {
+                (Cow::Owned(config.rules), Cow::Owned(config.severities), config.globals)
+            }
+            Ok(None) => {
+                (Cow::Borrowed(&self.rules), Cow::Borrowed(&self.severities), self.globals.clone())
+            }
+            Err(error) => return vec![Message::new(error, None)],
+        };
+        let inline_config = InlineConfig::new(ctx.source_text(), semantic.trivias());
+        inline_config.apply_globals(&mut globals);
+        ctx = ctx.with_globals(globals);
+
+        let mut rules = rules
+            .iter()
+            .map(|rule| {
+                (Cow::Borrowed(rule), severities.get(rule.name()).copied(), Span::new(0, u32::MAX))
+            })
+            .collect::<Vec<_>>();
+        for (start, config) in &inline_config.rules {
+            let configured_rules = config.configured_rules(&rules.iter().map(|(rule, ..)| &**rule));
+            for (configured_rule, severity) in configured_rules {
+                // The rule configured before the comment stops reporting at it.
+                for (_, _, span) in rules.iter_mut().filter(|(rule, _, span)| {
+                    rule.name() == configured_rule.name()
+                        && rule.plugin_name() == configured_rule.plugin_name()
+                        && span.end == u32::MAX
+                }) {
+                    span.end = *start;
+                }
+                if severity.is_some() {
+                    rules.push((
+                        Cow::Owned(configured_rule),
+                        severity,
+                        Span::new(*start, u32::MAX),
+                    ));
+                }
+            }
+        }
+
+        if self.options.deny_warnings {
+            for (_, severity, _) in &mut rules {
+                *severity = Some(Severity::Error);
+            }
+        }
 
-        for rule in &self.rules {
+        for (rule, severity, span) in &rules {
             ctx.with_rule_name(rule.name());
+            ctx.with_rule_severity(*severity);
+            ctx.with_rule_docs_url(rule.docs_url());
+            ctx.with_rule_span(*span);
             rule.run_once(&ctx, timing);
         }
 
         for node in semantic.nodes().iter() {
-            for rule in &self.rules {
+            for (rule, severity, span) in &rules {
                 ctx.with_rule_name(rule.name());
+                ctx.with_rule_severity(*severity);
+                ctx.with_rule_docs_url(rule.docs_url());
+                ctx.with_rule_span(*span);
                 rule.run(node, &ctx, timing);
             }
         }
 
         for symbol in semantic.symbols().iter() {
-            for rule in &self.rules {
+            for (rule, severity, span) in &rules {
                 ctx.with_rule_name(rule.name());
+                ctx.with_rule_severity(*severity);
+                ctx.with_rule_docs_url(rule.docs_url());
+                ctx.with_rule_span(*span);
                 rule.run_on_symbol(symbol, &ctx, timing);
             }
         }
 
-        ctx.into_message()
+        let mut messages = ctx.into_message();
+        messages.extend(inline_config.errors.into_iter().map(|error| Message::new(error, None)));
+        messages
     }
 
-    #[allow(unused)]
-    fn read_rules_configuration() -> Option<serde_json::Map<String, serde_json::Value>> {
-        fs::read_to_string(".eslintrc.json")
-            .ok()
-            .and_then(|s| serde_json::from_str(&s).ok())
-            .and_then(|v: serde_json::Value| v.get("rules").cloned())
-            .and_then(|v| v.as_object().cloned())
+    /// A hash of the configuration applying to the file at `path`, the cached diagnostics of
+    /// the file are outdated once it changes. `None` when the configuration is invalid.
+    pub(crate) fn config_hash(&self, path: &Path) -> Option<u64> {
+        let config = self.resolve_oxlintrc(path).ok()?;
+        let (rules, severities) = config
+            .as_ref()
+            .map_or((&self.rules, &self.severities), |config| (&config.rules, &config.severities));
+        let rules = rules
+            .iter()
+            .map(|rule| match rule {
+                // The timer of the custom rules changes while linting.
+                RuleEnum::Custom(rule) => format!("{}/{}", rule.plugin_name(), rule.name()),
+                rule => format!("{rule:?}"),
+            })
+            .collect::<Vec<_>>();
+        let mut severities = severities
+            .iter()
+            .map(|(name, severity)| format!("{name}:{severity:?}"))
+            .collect::<Vec<_>>();
+        severities.sort_unstable();
+        let globals =
+            format!("{:?}", config.as_ref().map_or(&self.globals, |config| &config.globals));
+        let options = &self.options;
+        let options = (
+            options.fix,
+            options.report_fixes,
+            options.report_unused_directives,
+            options.deny_warnings,
+        );
+        Some(ast_util::calculate_hash(&(rules, severities, globals, options)))
+    }
+
+    fn resolve_oxlintrc(&self, path: &Path) -> Result<Option<FileConfig>, Error> {
+        let Some(resolver) = &self.oxlintrc else { return Ok(None) };
+        resolver.resolve(path, &self.rules, &self.severities, &self.globals)
     }
 
     pub fn print_rules<W: Write>(writer: &mut W) {
@@ -135,29 +279,85 @@ impl Linter {
         writeln!(writer, "Total: {}", RULES.len()).unwrap();
     }
 
+    /// Print the metadata and the documentation of the rules named `name`, `no-debugger` or
+    /// `eslint/no-debugger`, returns whether a rule is found.
+    pub fn explain<W: Write>(name: &str, writer: &mut W) -> bool {
+        let rules = RULES
+            .iter()
+            .filter(|rule| {
+                rule.name() == name || format!("{}/{}", rule.plugin_name(), rule.name()) == name
+            })
+            .collect::<Vec<_>>();
+        for rule in &rules {
+            writeln!(writer, "{}/{}", rule.plugin_name(), rule.name()).unwrap();
+            writeln!(writer, "Category: {}", rule.category()).unwrap();
+            writeln!(writer, "Fix: {}", rule.fix()).unwrap();
+            if let Some(docs_url) = rule.docs_url() {
+                writeln!(writer, "Docs: {docs_url}").unwrap();
+            }
+            writeln!(writer).unwrap();
+            writeln!(writer, "{}", rule.documentation().unwrap_or_default().trim_end()).unwrap();
+        }
+        !rules.is_empty()
+    }
+
+    pub(crate) fn record_file_time(&self, path: &Path, duration: Duration) {
+        self.file_times.lock().unwrap().push((path.into(), duration));
+    }
+
     pub fn print_execution_times_if_enable(&self) {
         if !self.options.timing {
             return;
         }
-        let mut timings =
-            self.rules().iter().map(|rule| (rule.name(), rule.execute_time())).collect::<Vec<_>>();
+        self.print_execution_times(&mut std::io::stdout().lock());
+    }
+
+    /// The time spent and the number of diagnostics reported by each rule,
+    /// followed by the slowest files.
+    ///
+    /// # Panics
+    pub fn print_execution_times<W: Write>(&self, writer: &mut W) {
+        let mut timings = self
+            .rules()
+            .iter()
+            .map(|rule| (rule.name(), rule.execute_time(), rule.number_of_diagnostics()))
+            .collect::<Vec<_>>();
 
         timings.sort_by_key(|x| x.1);
         let total = timings.iter().map(|x| x.1).sum::<Duration>().as_secs_f64();
 
-        println!("Rule timings in milliseconds:");
-        println!("Total: {:.2}ms", total * 1000.0);
-        println!("{:>7} | {:>5} | Rule", "Time", "%");
-        for (name, duration) in timings.iter().rev() {
+        writeln!(writer, "Rule timings in milliseconds:").unwrap();
+        writeln!(writer, "Total: {:.2}ms", total * 1000.0).unwrap();
+        writeln!(writer, "{:>7} | {:>5} | {:>8} | Rule", "Time", "%", "Problems").unwrap();
+        for (name, duration, diagnostics) in timings.iter().rev() {
             let millis = duration.as_secs_f64() * 1000.0;
-            let relative = duration.as_secs_f64() / total * 100.0;
-            println!("{millis:>7.2} | {relative:>4.1}% | {name}");
+            let relative = if total > 0.0 { duration.as_secs_f64() / total * 100.0 } else { 0.0 };
+            writeln!(writer, "{millis:>7.2} | {relative:>4.1}% | {diagnostics:>8} | {name}")
+                .unwrap();
+        }
+
+        let mut file_times = self.file_times.lock().unwrap().clone();
+        if file_times.is_empty() {
+            return;
+        }
+        file_times.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        let total = file_times.iter().map(|x| x.1).sum::<Duration>().as_secs_f64();
+
+        writeln!(writer).unwrap();
+        writeln!(writer, "Slowest files in milliseconds:").unwrap();
+        writeln!(writer, "Total: {:.2}ms in {} files", total * 1000.0, file_times.len()).unwrap();
+        writeln!(writer, "{:>7} | File", "Time").unwrap();
+        for (path, duration) in file_times.iter().take(SLOWEST_FILES) {
+            let millis = duration.as_secs_f64() * 1000.0;
+            writeln!(writer, "{millis:>7.2} | {}", path.display()).unwrap();
         }
     }
 }
 
 #[cfg(test)]
 mod test {
+    use std::{path::Path, time::Duration};
+
     use super::Linter;
 
     #[test]
@@ -166,4 +366,32 @@ mod test {
         Linter::print_rules(&mut writer);
         assert!(!writer.is_empty());
     }
+
+    #[test]
+    fn explain() {
+        let mut writer = Vec::new();
+        assert!(Linter::explain("eslint/no-debugger", &mut writer));
+        let output = String::from_utf8(writer).unwrap();
+        assert!(output.starts_with("eslint/no-debugger\nCategory: Correctness\nFix: Automatic fix with `--fix`\nDocs: https://github.com/web-infra-dev/oxc/blob/main/crates/oxc_linter/src/rules/eslint/no_debugger.rs\n\n### What it does"));
+
+        assert!(Linter::explain("no-debugger", &mut Vec::new()));
+        assert!(!Linter::explain("no-such-rule", &mut Vec::new()));
+    }
+
+    #[test]
+    fn print_execution_times() {
+        let linter = Linter::new().with_print_execution_times(true);
+        for (path, millis) in [("a.js", 1), ("b.js", 3), ("c.js", 2)] {
+            linter.record_file_time(Path::new(path), Duration::from_millis(millis));
+        }
+        let mut writer = Vec::new();
+        linter.print_execution_times(&mut writer);
+        let output = String::from_utf8(writer).unwrap();
+        assert!(output.contains("   Time |     % | Problems | Rule\n"));
+        let files = output.split("Slowest files in milliseconds:\n").nth(1).unwrap();
+        assert_eq!(
+            files,
+            "Total: 6.00ms in 3 files\n   Time | File\n   3.00 | b.js\n   2.00 | c.js\n   1.00 | a.js\n"
+        );
+    }
 }
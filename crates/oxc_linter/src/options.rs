@@ -1,4 +1,6 @@
-use crate::{RuleCategory, RuleEnum, RULES};
+use std::{path::PathBuf, sync::Arc};
+
+use crate::{globals::Globals, ESLintConfig, RuleCategory, RuleEnum, TypeChecker, RULES};
 use rustc_hash::FxHashSet;
 
 #[derive(Debug)]
@@ -6,18 +8,59 @@ pub struct LintOptions {
     /// Allow / Deny rules in order. [("allow" / "deny", rule name)]
     /// Defaults to [("deny", "correctness")]
     pub filter: Vec<(AllowWarnDeny, String)>,
+    /// Report the diagnostics of all the rules as errors, e.g. to fail CI on any problem.
+    pub deny_warnings: bool,
     pub fix: bool,
+    /// Print the fixed code instead of writing it to the files, together with `fix`.
+    pub fix_dry_run: bool,
+    /// Compute the fixes without applying them, and report them with the diagnostics
+    /// as [oxc_diagnostics::DiagnosticWithFix].
+    pub report_fixes: bool,
     pub timing: bool,
     pub import_plugin: bool,
+    /// Enable the `jsx-a11y` rules, they are excluded from `filter` and `eslint_config` otherwise.
+    pub jsx_a11y_plugin: bool,
+    /// Enable the `unicorn` rules, they are excluded from `filter` and `eslint_config` otherwise.
+    pub unicorn_plugin: bool,
+    /// The environments enabled for every file, e.g. `browser` or `jest`.
+    pub env: Vec<String>,
+    /// The globals declared for every file, `true` when they are writable.
+    pub globals: Vec<(String, bool)>,
+    /// The rules of an ESLint configuration, applied over the rules of `filter`,
+    /// and its globals, applied over `env` and `globals`.
+    pub eslint_config: Option<ESLintConfig>,
+    /// Look up the `.oxlintrc.json` of each linted file, applied over all of the above.
+    pub oxlintrc: bool,
+    /// Report the `eslint-disable` comments which did not suppress any diagnostic.
+    pub report_unused_directives: bool,
+    /// The directory of the lint cache, the unchanged files are not linted again.
+    /// Not used together with `import_plugin` or `type_checker`, the diagnostics of a file
+    /// depend on the other files.
+    pub cache: Option<PathBuf>,
+    /// The types of the expressions for the type-aware rules, they fall back to their syntactic
+    /// checks or report nothing without it.
+    pub type_checker: Option<Arc<dyn TypeChecker>>,
 }
 
 impl Default for LintOptions {
     fn default() -> Self {
         Self {
             filter: vec![(AllowWarnDeny::Deny, String::from("correctness"))],
+            deny_warnings: false,
             fix: false,
+            fix_dry_run: false,
+            report_fixes: false,
             timing: false,
             import_plugin: false,
+            jsx_a11y_plugin: false,
+            unicorn_plugin: false,
+            env: vec![],
+            globals: vec![],
+            eslint_config: None,
+            oxlintrc: false,
+            report_unused_directives: false,
+            cache: None,
+            type_checker: None,
         }
     }
 }
@@ -31,12 +74,30 @@ impl LintOptions {
         self
     }
 
+    #[must_use]
+    pub fn with_deny_warnings(mut self, yes: bool) -> Self {
+        self.deny_warnings = yes;
+        self
+    }
+
     #[must_use]
     pub fn with_fix(mut self, yes: bool) -> Self {
         self.fix = yes;
         self
     }
 
+    #[must_use]
+    pub fn with_fix_dry_run(mut self, yes: bool) -> Self {
+        self.fix_dry_run = yes;
+        self
+    }
+
+    #[must_use]
+    pub fn with_report_fixes(mut self, yes: bool) -> Self {
+        self.report_fixes = yes;
+        self
+    }
+
     #[must_use]
     pub fn with_timing(mut self, yes: bool) -> Self {
         self.timing = yes;
@@ -48,12 +109,66 @@ impl LintOptions {
         self.import_plugin = yes;
         self
     }
+
+    #[must_use]
+    pub fn with_jsx_a11y_plugin(mut self, yes: bool) -> Self {
+        self.jsx_a11y_plugin = yes;
+        self
+    }
+
+    #[must_use]
+    pub fn with_unicorn_plugin(mut self, yes: bool) -> Self {
+        self.unicorn_plugin = yes;
+        self
+    }
+
+    #[must_use]
+    pub fn with_env(mut self, env: Vec<String>) -> Self {
+        self.env = env;
+        self
+    }
+
+    #[must_use]
+    pub fn with_globals(mut self, globals: Vec<(String, bool)>) -> Self {
+        self.globals = globals;
+        self
+    }
+
+    #[must_use]
+    pub fn with_eslint_config(mut self, config: ESLintConfig) -> Self {
+        self.eslint_config = Some(config);
+        self
+    }
+
+    #[must_use]
+    pub fn with_oxlintrc(mut self, yes: bool) -> Self {
+        self.oxlintrc = yes;
+        self
+    }
+
+    #[must_use]
+    pub fn with_report_unused_directives(mut self, yes: bool) -> Self {
+        self.report_unused_directives = yes;
+        self
+    }
+
+    #[must_use]
+    pub fn with_cache(mut self, dir: Option<PathBuf>) -> Self {
+        self.cache = dir;
+        self
+    }
+
+    #[must_use]
+    pub fn with_type_checker(mut self, type_checker: Option<Arc<dyn TypeChecker>>) -> Self {
+        self.type_checker = type_checker;
+        self
+    }
 }
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub enum AllowWarnDeny {
     Allow,
-    // Warn,
+    Warn,
     Deny,
 }
 
@@ -61,6 +176,7 @@ impl From<&'static str> for AllowWarnDeny {
     fn from(s: &'static str) -> Self {
         match s {
             "allow" => Self::Allow,
+            "warn" => Self::Warn,
             "deny" => Self::Deny,
             _ => unreachable!(),
         }
@@ -68,13 +184,28 @@ impl From<&'static str> for AllowWarnDeny {
 }
 
 impl LintOptions {
+    /// The globals of every file, before the `.oxlintrc.json` files and the comments of the file.
+    pub(crate) fn derive_globals(&self) -> Globals {
+        let mut globals = Globals::default();
+        for env in &self.env {
+            globals.set_env(env, true);
+        }
+        for (name, writable) in &self.globals {
+            globals.set_global(name.clone(), Some(*writable));
+        }
+        if let Some(config) = &self.eslint_config {
+            config.apply_globals(&mut globals);
+        }
+        globals
+    }
+
     pub fn derive_rules(&self) -> Vec<RuleEnum> {
         let mut rules: FxHashSet<RuleEnum> = FxHashSet::default();
 
         for (allow_warn_deny, name_or_category) in &self.filter {
             let maybe_category = RuleCategory::from(name_or_category.as_str());
             match allow_warn_deny {
-                AllowWarnDeny::Deny => {
+                AllowWarnDeny::Warn | AllowWarnDeny::Deny => {
                     match maybe_category {
                         Some(category) => rules.extend(
                             RULES.iter().filter(|rule| rule.category() == category).cloned(),
@@ -108,6 +239,18 @@ impl LintOptions {
             }
         }
 
+        if let Some(config) = &self.eslint_config {
+            config.override_rules(&mut rules);
+        }
+
+        if !self.jsx_a11y_plugin {
+            rules.retain(|rule| rule.plugin_name() != "jsx_a11y");
+        }
+
+        if !self.unicorn_plugin {
+            rules.retain(|rule| rule.plugin_name() != "unicorn");
+        }
+
         let mut rules = rules.into_iter().collect::<Vec<_>>();
         // for stable diagnostics output ordering
         rules.sort_unstable_by_key(RuleEnum::name);
@@ -0,0 +1,247 @@
+//! The scripts embedded in the files of other languages, the `<script>` of the Vue, Svelte and
+//! Astro components, the frontmatter of the Astro components and the fenced code blocks of the
+//! Markdown files.
+
+use oxc_span::SourceType;
+
+/// The extensions of the files linted through their embedded scripts.
+pub const LINT_PARTIAL_LOADER_EXT: [&str; 5] = ["vue", "svelte", "astro", "md", "markdown"];
+
+/// A script embedded in a host file.
+#[derive(Debug)]
+pub struct JavaScriptSource {
+    /// The source text of the host file up to the end of the script, with everything before
+    /// the script blanked out, so the spans of the script are the spans of the host file.
+    pub source_text: String,
+    pub source_type: SourceType,
+}
+
+pub struct PartialLoader;
+
+impl PartialLoader {
+    /// The scripts of a file with the extension `ext`,
+    /// `None` if the file is not linted through its embedded scripts.
+    pub fn parse(ext: &str, source_text: &str) -> Option<Vec<JavaScriptSource>> {
+        let scripts = match ext {
+            "vue" | "svelte" => script_tags(source_text, false),
+            "astro" => {
+                let mut scripts = frontmatter(source_text).into_iter().collect::<Vec<_>>();
+                scripts.extend(script_tags(source_text, true));
+                scripts
+            }
+            "md" | "markdown" => code_blocks(source_text),
+            _ => return None,
+        };
+        let sources = scripts
+            .into_iter()
+            .map(|(start, end, source_type)| JavaScriptSource {
+                source_text: blank_out(source_text, start, end),
+                source_type,
+            })
+            .collect();
+        Some(sources)
+    }
+}
+
+/// A script, from its start to its end offset.
+type Script = (usize, usize, SourceType);
+
+/// The `<script>` elements, TypeScript with `lang="ts"` or when `typescript` is true.
+fn script_tags(source_text: &str, typescript: bool) -> Vec<Script> {
+    let mut scripts = vec![];
+    let mut offset = 0;
+    while let Some(index) = source_text[offset..].find('<') {
+        let start = offset + index;
+        let rest = &source_text[start..];
+        if rest.starts_with("<!--") {
+            let Some(end) = rest.find("-->") else { break };
+            offset = start + end + "-->".len();
+            continue;
+        }
+        offset = start + 1;
+        let Some(tag) = rest.strip_prefix("<script") else { continue };
+        if !tag.starts_with(|c: char| c == '>' || c == '/' || c.is_ascii_whitespace()) {
+            continue;
+        }
+        let Some(tag_end) = tag.find('>') else { break };
+        let attributes = &tag[..tag_end];
+        if attributes.ends_with('/') {
+            continue;
+        }
+        let content_start = start + "<script".len() + tag_end + 1;
+        let content_end = source_text[content_start..]
+            .find("</script")
+            .map_or(source_text.len(), |end| content_start + end);
+        let source_type = match attribute(attributes, "lang") {
+            Some("ts" | "typescript") => SourceType::default().with_typescript(true),
+            Some("tsx") => SourceType::default().with_typescript(true).with_jsx(true),
+            Some("jsx") => SourceType::default().with_jsx(true),
+            _ => SourceType::default().with_typescript(typescript),
+        };
+        scripts.push((content_start, content_end, source_type.with_module(true)));
+        offset = content_end;
+    }
+    scripts
+}
+
+/// The value of the attribute `name` of a tag, `lang="ts"` -> `ts`.
+fn attribute<'a>(attributes: &'a str, name: &str) -> Option<&'a str> {
+    let mut rest = attributes;
+    while let Some(index) = rest.find(name) {
+        let before = &rest[..index];
+        rest = &rest[index + name.len()..];
+        if !before.ends_with(|c: char| c.is_ascii_whitespace()) {
+            continue;
+        }
+        let Some(value) = rest.trim_start().strip_prefix('=') else { continue };
+        let value = value.trim_start().trim_start_matches(['"', '\'']);
+        return value.split(|c: char| c.is_ascii_whitespace() || c == '"' || c == '\'').next();
+    }
+    None
+}
+
+/// The TypeScript between the `---` fences at the top of an Astro component.
+fn frontmatter(source_text: &str) -> Option<Script> {
+    let start = source_text.len() - source_text.trim_start().len();
+    let rest = source_text[start..].strip_prefix("---")?;
+    let content_start = start + "---".len();
+    let content_end = content_start + rest.find("\n---")? + 1;
+    let source_type = SourceType::default().with_module(true).with_typescript(true);
+    Some((content_start, content_end, source_type))
+}
+
+/// The fenced code blocks with a JavaScript or TypeScript info string, ```` ```js ````.
+fn code_blocks(source_text: &str) -> Vec<Script> {
+    let mut scripts = vec![];
+    // The fence and the script of the open code block.
+    let mut open: Option<(&str, usize, Option<SourceType>)> = None;
+    let mut offset = 0;
+    for line in source_text.split_inclusive('\n') {
+        let line_start = offset;
+        offset += line.len();
+        let indent = line.len() - line.trim_start_matches(' ').len();
+        let trimmed = &line[indent..];
+        let fence_len = trimmed.len()
+            - trimmed.trim_start_matches('`').len().min(trimmed.trim_start_matches('~').len());
+        if indent > 3 || fence_len < 3 {
+            continue;
+        }
+        let (fence, info) = trimmed.split_at(fence_len);
+        match open {
+            None => {
+                if fence.starts_with('`') && info.contains('`') {
+                    continue;
+                }
+                let language = info.split(|c: char| c.is_whitespace() || c == '{').next();
+                open = Some((fence, offset, language.and_then(code_block_source_type)));
+            }
+            Some((open_fence, content_start, source_type)) => {
+                if fence.as_bytes()[0] != open_fence.as_bytes()[0]
+                    || fence.len() < open_fence.len()
+                    || !info.trim().is_empty()
+                {
+                    continue;
+                }
+                if let Some(source_type) = source_type {
+                    scripts.push((content_start, line_start, source_type));
+                }
+                open = None;
+            }
+        }
+    }
+    // An unclosed code block ends with the file.
+    if let Some((_, content_start, Some(source_type))) = open {
+        scripts.push((content_start, source_text.len(), source_type));
+    }
+    scripts
+}
+
+fn code_block_source_type(language: &str) -> Option<SourceType> {
+    let source_type = SourceType::default().with_module(true);
+    match language.to_ascii_lowercase().as_str() {
+        "js" | "javascript" | "mjs" | "cjs" => Some(source_type),
+        "jsx" => Some(source_type.with_jsx(true)),
+        "ts" | "typescript" | "mts" | "cts" => Some(source_type.with_typescript(true)),
+        "tsx" => Some(source_type.with_typescript(true).with_jsx(true)),
+        _ => None,
+    }
+}
+
+/// `source_text[..end]` with the text before `start` replaced by spaces, keeping the line breaks
+/// for the line numbers.
+fn blank_out(source_text: &str, start: usize, end: usize) -> String {
+    let mut text = source_text[..start]
+        .bytes()
+        .map(|byte| if matches!(byte, b'\n' | b'\r') { byte as char } else { ' ' })
+        .collect::<String>();
+    text.push_str(&source_text[start..end]);
+    text
+}
+
+#[cfg(test)]
+mod test {
+    use super::{JavaScriptSource, PartialLoader};
+
+    fn parse(ext: &str, source_text: &str) -> Vec<JavaScriptSource> {
+        let sources = PartialLoader::parse(ext, source_text).unwrap();
+        // The scripts are at their offsets of the host file.
+        for source in &sources {
+            let bytes = source.source_text.bytes().zip(source_text.bytes());
+            assert!(bytes.into_iter().all(|(a, b)| a == b || a == b' '));
+        }
+        sources
+    }
+
+    #[test]
+    fn vue() {
+        let source_text = r#"
+<template><div>{{ msg }}</div></template>
+<!-- <script>comment</script> -->
+<script lang="ts">
+export default {};
+</script>
+<script setup>
+debugger;
+</script>
+"#;
+        let sources = parse("vue", source_text);
+        assert_eq!(sources.len(), 2);
+        assert!(sources[0].source_type.is_typescript());
+        assert_eq!(sources[0].source_text.trim(), "export default {};");
+        assert!(!sources[1].source_type.is_typescript());
+        assert_eq!(sources[1].source_text.trim(), "debugger;");
+        assert_eq!(sources[1].source_text.find("debugger"), source_text.find("debugger"));
+        assert_eq!(sources[1].source_text.lines().count(), 8);
+    }
+
+    #[test]
+    fn svelte() {
+        let sources = parse("svelte", "<script context='module' lang='ts'>let a;</script><p/>");
+        assert_eq!(sources.len(), 1);
+        assert!(sources[0].source_type.is_typescript());
+        assert_eq!(sources[0].source_text.trim(), "let a;");
+        assert!(parse("svelte", "<scripts>let a;</scripts>").is_empty());
+    }
+
+    #[test]
+    fn astro() {
+        let sources =
+            parse("astro", "---\nconst a = 1;\n---\n<h1>{a}</h1>\n<script>let b;</script>");
+        assert_eq!(sources.len(), 2);
+        assert_eq!(sources[0].source_text.trim(), "const a = 1;");
+        assert!(sources.iter().all(|source| source.source_type.is_typescript()));
+        assert_eq!(sources[1].source_text.trim(), "let b;");
+    }
+
+    #[test]
+    fn markdown() {
+        let source_text = "# Title\n\n```js\ndebugger;\n```\n\n```sh\nls\n```\n\n~~~~tsx title\nlet a: A = <A />;\n~~~\n~~~~\n\n```ts\nlet b;";
+        let sources = parse("md", source_text);
+        assert_eq!(sources.len(), 3);
+        assert_eq!(sources[0].source_text.trim(), "debugger;");
+        assert_eq!(sources[1].source_text.trim(), "let a: A = <A />;\n~~~");
+        assert!(sources[1].source_type.is_jsx());
+        assert_eq!(sources[2].source_text.trim(), "let b;");
+        assert!(PartialLoader::parse("js", source_text).is_none());
+    }
+}
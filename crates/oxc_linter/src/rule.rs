@@ -1,8 +1,8 @@
-use std::fmt;
+use std::{fmt, sync::Arc};
 
 use oxc_semantic::SymbolId;
 
-use crate::{context::LintContext, AstNode};
+use crate::{context::LintContext, rule_timer::RuleTimer, AstNode};
 
 pub trait Rule: Sized + Default + fmt::Debug {
     /// Initialize from eslint json configuration
@@ -25,11 +25,170 @@ pub trait RuleMeta {
 
     const CATEGORY: RuleCategory;
 
+    /// Whether the diagnostics of the rule come with fixes, declared by the optional third
+    /// argument of `declare_oxc_lint!`, `fix` or `suggestion`.
+    const FIX: RuleFixability = RuleFixability::None;
+
     fn documentation() -> Option<&'static str> {
         None
     }
 }
 
+/// A rule declared outside of this crate, e.g. in an internal rule crate of a company.
+///
+/// The rule is written like the built-in ones, with [Rule] and the `declare_custom_lint!` macro
+/// of `oxc_macros`, and is enabled with [crate::Linter::with_custom_rules]:
+///
+/// ```ignore
+/// use oxc_ast::AstKind;
+/// use oxc_diagnostics::{
+///     miette::{self, Diagnostic},
+///     thiserror::Error,
+/// };
+/// use oxc_linter::{AstNode, CustomRule, LintContext, Linter, Rule};
+/// use oxc_macros::declare_custom_lint;
+/// use oxc_span::Span;
+///
+/// #[derive(Debug, Error, Diagnostic)]
+/// #[error("acme(no-foo): Unexpected `foo`.")]
+/// #[diagnostic(severity(warning))]
+/// struct NoFooDiagnostic(#[label] Span);
+///
+/// #[derive(Debug, Default, Clone)]
+/// struct NoFoo;
+///
+/// declare_custom_lint!(
+///     /// Disallow the identifiers named `foo`
+///     NoFoo,
+///     restriction
+/// );
+///
+/// impl Rule for NoFoo {
+///     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+///         if let AstKind::IdentifierReference(ident) = node.kind() {
+///             if ident.name == "foo" {
+///                 ctx.diagnostic(NoFooDiagnostic(ident.span));
+///             }
+///         }
+///     }
+/// }
+///
+/// let linter = Linter::new().with_custom_rules([CustomRule::new("acme", NoFoo)]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct CustomRule {
+    plugin_name: &'static str,
+    rule: Arc<dyn DynRule>,
+    timer: Arc<RuleTimer>,
+}
+
+impl CustomRule {
+    /// The rule is configured as `<plugin_name>/<rule name>` in the ESLint configuration.
+    pub fn new<R: Rule + RuleMeta + Send + Sync + 'static>(
+        plugin_name: &'static str,
+        rule: R,
+    ) -> Self {
+        Self { plugin_name, rule: Arc::new(rule), timer: Arc::new(RuleTimer::new()) }
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.rule.name()
+    }
+
+    pub fn plugin_name(&self) -> &'static str {
+        self.plugin_name
+    }
+
+    pub fn category(&self) -> RuleCategory {
+        self.rule.category()
+    }
+
+    pub fn documentation(&self) -> Option<&'static str> {
+        self.rule.documentation()
+    }
+
+    pub fn fix(&self) -> RuleFixability {
+        self.rule.fix()
+    }
+
+    pub(crate) fn read_json(&self, maybe_value: Option<serde_json::Value>) -> Self {
+        Self {
+            plugin_name: self.plugin_name,
+            rule: self.rule.configure(maybe_value),
+            timer: Arc::clone(&self.timer),
+        }
+    }
+
+    pub(crate) fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        self.rule.run(node, ctx);
+    }
+
+    pub(crate) fn run_on_symbol(&self, symbol_id: SymbolId, ctx: &LintContext<'_>) {
+        self.rule.run_on_symbol(symbol_id, ctx);
+    }
+
+    pub(crate) fn run_once(&self, ctx: &LintContext) {
+        self.rule.run_once(ctx);
+    }
+
+    pub(crate) fn timer(&self) -> &RuleTimer {
+        &self.timer
+    }
+}
+
+/// The object safe version of [Rule] and [RuleMeta], for storing the custom rules of any type.
+trait DynRule: fmt::Debug + Send + Sync {
+    fn name(&self) -> &'static str;
+
+    fn category(&self) -> RuleCategory;
+
+    fn documentation(&self) -> Option<&'static str>;
+
+    fn fix(&self) -> RuleFixability;
+
+    fn configure(&self, maybe_value: Option<serde_json::Value>) -> Arc<dyn DynRule>;
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>);
+
+    fn run_on_symbol(&self, symbol_id: SymbolId, ctx: &LintContext<'_>);
+
+    fn run_once(&self, ctx: &LintContext);
+}
+
+impl<R: Rule + RuleMeta + Send + Sync + 'static> DynRule for R {
+    fn name(&self) -> &'static str {
+        R::NAME
+    }
+
+    fn category(&self) -> RuleCategory {
+        R::CATEGORY
+    }
+
+    fn documentation(&self) -> Option<&'static str> {
+        R::documentation()
+    }
+
+    fn fix(&self) -> RuleFixability {
+        R::FIX
+    }
+
+    fn configure(&self, maybe_value: Option<serde_json::Value>) -> Arc<dyn DynRule> {
+        Arc::new(maybe_value.map(R::from_configuration).unwrap_or_default())
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        Rule::run(self, node, ctx);
+    }
+
+    fn run_on_symbol(&self, symbol_id: SymbolId, ctx: &LintContext<'_>) {
+        Rule::run_on_symbol(self, symbol_id, ctx);
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        Rule::run_once(self, ctx);
+    }
+}
+
 /// Rule categories defined by rust-clippy
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum RuleCategory {
@@ -47,6 +206,8 @@ pub enum RuleCategory {
     /// and may contradict any other lints (including other categories).
     /// Lints should be considered on a case-by-case basis before enabling.
     Restriction,
+    /// Code that is potentially exploitable, e.g. code injection or catastrophic regex backtracking
+    Security,
     /// New lints that are still under development
     Nursery,
 }
@@ -59,6 +220,7 @@ impl RuleCategory {
             "pedantic" => Some(Self::Pedantic),
             "style" => Some(Self::Style),
             "restriction" => Some(Self::Restriction),
+            "security" => Some(Self::Security),
             "nursery" => Some(Self::Nursery),
             _ => None,
         }
@@ -73,13 +235,55 @@ impl fmt::Display for RuleCategory {
             Self::Pedantic => write!(f, "Pedantic"),
             Self::Style => write!(f, "Style"),
             Self::Restriction => write!(f, "Restriction"),
+            Self::Security => write!(f, "Security"),
             Self::Nursery => write!(f, "Nursery"),
         }
     }
 }
 
+/// How the problems reported by a rule can be fixed.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum RuleFixability {
+    #[default]
+    None,
+    /// Fixed by `--fix`
+    Fix,
+    /// Suggestions, offered by the editors but never applied by `--fix`
+    Suggestion,
+}
+
+impl fmt::Display for RuleFixability {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::None => write!(f, "No fix"),
+            Self::Fix => write!(f, "Automatic fix with `--fix`"),
+            Self::Suggestion => write!(f, "Suggestions in the editors"),
+        }
+    }
+}
+
+/// The code blocks of the documentation of a rule, e.g. the examples of the bad and the good code.
+pub fn documentation_examples(documentation: &str) -> Vec<String> {
+    let mut examples = vec![];
+    let mut example: Option<String> = None;
+    for line in documentation.lines() {
+        if line.trim_start().starts_with("```") {
+            if example.is_some() {
+                examples.extend(example.take());
+            } else {
+                example = Some(String::new());
+            }
+        } else if let Some(example) = &mut example {
+            example.push_str(line);
+            example.push('\n');
+        }
+    }
+    examples
+}
+
 #[cfg(test)]
 mod test {
+    use super::documentation_examples;
     use crate::RULES;
 
     #[test]
@@ -89,4 +293,11 @@ mod test {
             assert!(rule.documentation().is_some_and(|s| !s.is_empty()), "{}", rule.name());
         }
     }
+
+    #[test]
+    fn examples() {
+        let documentation =
+            "### Example\n```javascript\n// Bad\ndebugger;\n```\n\n```js\nfoo();\n```\n";
+        assert_eq!(documentation_examples(documentation), ["// Bad\ndebugger;\n", "foo();\n"]);
+    }
 }
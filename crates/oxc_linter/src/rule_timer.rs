@@ -1,27 +1,34 @@
 use std::{
-    sync::atomic::{AtomicU32, AtomicU64, Ordering},
+    sync::atomic::{AtomicU64, AtomicUsize, Ordering},
     time::Duration,
 };
 
+/// The execution time and the number of diagnostics of a rule, across all of the files.
 #[derive(Debug)]
 pub struct RuleTimer {
-    pub secs: AtomicU64,
-    pub nanos: AtomicU32,
+    pub nanos: AtomicU64,
+    pub diagnostics: AtomicUsize,
 }
 
 impl RuleTimer {
     pub const fn new() -> Self {
-        Self { secs: AtomicU64::new(0), nanos: AtomicU32::new(0) }
+        Self { nanos: AtomicU64::new(0), diagnostics: AtomicUsize::new(0) }
     }
 
+    #[allow(clippy::cast_possible_truncation)] // for `as u64`, centuries in nanoseconds
     pub fn update(&self, duration: &Duration) {
-        self.secs.fetch_add(duration.as_secs(), Ordering::SeqCst);
-        self.nanos.fetch_add(duration.subsec_nanos(), Ordering::SeqCst);
+        self.nanos.fetch_add(duration.as_nanos() as u64, Ordering::SeqCst);
+    }
+
+    pub fn add_diagnostics(&self, count: usize) {
+        self.diagnostics.fetch_add(count, Ordering::SeqCst);
     }
 
     pub fn duration(&self) -> Duration {
-        let secs = self.secs.load(Ordering::SeqCst);
-        let nanos = self.nanos.load(Ordering::SeqCst);
-        Duration::new(secs, nanos)
+        Duration::from_nanos(self.nanos.load(Ordering::SeqCst))
+    }
+
+    pub fn diagnostics(&self) -> usize {
+        self.diagnostics.load(Ordering::SeqCst)
     }
 }
@@ -11,6 +11,8 @@ mod import {
     pub mod named;
     pub mod no_cycle;
     pub mod no_self_import;
+    pub mod no_unresolved;
+    pub mod order;
 }
 
 mod deepscan {
@@ -32,6 +34,7 @@ mod eslint {
     pub mod getter_return;
     pub mod no_array_constructor;
     pub mod no_async_promise_executor;
+    pub mod no_await_in_loop;
     pub mod no_bitwise;
     pub mod no_caller;
     pub mod no_case_declarations;
@@ -60,8 +63,10 @@ mod eslint {
     pub mod no_global_assign;
     pub mod no_import_assign;
     pub mod no_inner_declarations;
+    pub mod no_invalid_regexp;
     pub mod no_loss_of_precision;
     pub mod no_mixed_operators;
+    pub mod no_new_func;
     pub mod no_new_symbol;
     pub mod no_obj_calls;
     pub mod no_prototype_builtins;
@@ -73,10 +78,12 @@ mod eslint {
     pub mod no_shadow_restricted_names;
     pub mod no_sparse_arrays;
     pub mod no_undef;
+    pub mod no_unreachable;
     pub mod no_unsafe_finally;
     pub mod no_unsafe_negation;
     pub mod no_unsafe_optional_chaining;
     pub mod no_unused_labels;
+    pub mod no_unused_vars;
     pub mod no_useless_catch;
     pub mod no_useless_escape;
     pub mod require_yield;
@@ -86,21 +93,27 @@ mod eslint {
 
 mod typescript {
     pub mod adjacent_overload_signatures;
+    pub mod array_type;
     pub mod ban_ts_comment;
     pub mod ban_types;
     pub mod consistent_type_exports;
+    pub mod consistent_type_imports;
     pub mod no_duplicate_enum_values;
     pub mod no_empty_interface;
     pub mod no_explicit_any;
     pub mod no_extra_non_null_assertion;
+    pub mod no_floating_promises;
     pub mod no_misused_new;
     pub mod no_namespace;
     pub mod no_non_null_asserted_optional_chain;
+    pub mod no_non_null_assertion;
     pub mod no_this_alias;
     pub mod no_unnecessary_type_constraint;
+    pub mod no_unsafe_assignment;
     pub mod no_unsafe_declaration_merging;
     pub mod no_var_requires;
     pub mod prefer_as_const;
+    pub mod restrict_template_expressions;
 }
 
 mod jest {
@@ -125,21 +138,47 @@ mod jest {
 }
 
 mod react {
+    pub mod exhaustive_deps;
     pub mod jsx_key;
     pub mod jsx_no_duplicate_props;
     pub mod jsx_no_useless_fragment;
     pub mod no_children_prop;
+    pub mod rules_of_hooks;
+}
+
+/// <https://github.com/jsx-eslint/eslint-plugin-jsx-a11y>
+mod jsx_a11y {
+    pub mod alt_text;
+    pub mod anchor_is_valid;
+    pub mod aria_props;
+    pub mod aria_role;
+    pub mod no_autofocus;
+    pub mod role_has_required_aria_props;
+}
+
+mod promise {
+    pub mod prefer_await_to_then;
+}
+
+mod security {
+    pub mod no_hardcoded_credentials;
+    pub mod no_unsafe_regex;
+    pub mod no_unsanitized_inner_html;
 }
 
 mod unicorn {
     pub mod catch_error_name;
     pub mod error_message;
     pub mod filename_case;
+    pub mod no_array_for_each;
     pub mod no_console_spaces;
     pub mod no_instanceof_array;
     pub mod no_thenable;
     pub mod no_unnecessary_await;
+    pub mod no_useless_undefined;
     pub mod prefer_array_flat_map;
+    pub mod prefer_node_protocol;
+    pub mod prefer_string_slice;
     pub mod throw_new_error;
 }
 
@@ -159,6 +198,7 @@ oxc_macros::declare_all_lint_rules! {
     eslint::getter_return,
     eslint::no_array_constructor,
     eslint::no_async_promise_executor,
+    eslint::no_await_in_loop,
     eslint::no_bitwise,
     eslint::no_caller,
     eslint::no_case_declarations,
@@ -187,8 +227,10 @@ oxc_macros::declare_all_lint_rules! {
     eslint::no_global_assign,
     eslint::no_import_assign,
     eslint::no_inner_declarations,
+    eslint::no_invalid_regexp,
     eslint::no_loss_of_precision,
     eslint::no_mixed_operators,
+    eslint::no_new_func,
     eslint::no_new_symbol,
     eslint::no_obj_calls,
     eslint::no_prototype_builtins,
@@ -200,31 +242,39 @@ oxc_macros::declare_all_lint_rules! {
     eslint::no_shadow_restricted_names,
     eslint::no_sparse_arrays,
     eslint::no_undef,
+    eslint::no_unreachable,
     eslint::no_unsafe_finally,
     eslint::no_unsafe_negation,
     eslint::no_unsafe_optional_chaining,
     eslint::no_unused_labels,
+    eslint::no_unused_vars,
     eslint::no_useless_catch,
     eslint::no_useless_escape,
     eslint::require_yield,
     eslint::use_isnan,
     eslint::valid_typeof,
     typescript::adjacent_overload_signatures,
+    typescript::array_type,
     typescript::ban_ts_comment,
     typescript::ban_types,
     typescript::consistent_type_exports,
+    typescript::consistent_type_imports,
     typescript::no_duplicate_enum_values,
     typescript::no_empty_interface,
     typescript::no_explicit_any,
     typescript::no_extra_non_null_assertion,
+    typescript::no_floating_promises,
     typescript::no_non_null_asserted_optional_chain,
+    typescript::no_non_null_assertion,
     typescript::no_unnecessary_type_constraint,
+    typescript::no_unsafe_assignment,
     typescript::no_unsafe_declaration_merging,
     typescript::no_misused_new,
     typescript::no_this_alias,
     typescript::no_namespace,
     typescript::no_var_requires,
     typescript::prefer_as_const,
+    typescript::restrict_template_expressions,
     jest::no_disabled_tests,
     jest::no_test_prefixes,
     jest::no_focused_tests,
@@ -252,12 +302,30 @@ oxc_macros::declare_all_lint_rules! {
     unicorn::no_thenable,
     unicorn::throw_new_error,
     unicorn::prefer_array_flat_map,
+    unicorn::no_array_for_each,
+    unicorn::no_useless_undefined,
+    unicorn::prefer_node_protocol,
+    unicorn::prefer_string_slice,
+    react::exhaustive_deps,
     react::jsx_key,
     react::jsx_no_duplicate_props,
     react::jsx_no_useless_fragment,
     react::no_children_prop,
+    react::rules_of_hooks,
+    jsx_a11y::alt_text,
+    jsx_a11y::anchor_is_valid,
+    jsx_a11y::aria_props,
+    jsx_a11y::aria_role,
+    jsx_a11y::no_autofocus,
+    jsx_a11y::role_has_required_aria_props,
+    promise::prefer_await_to_then,
+    security::no_hardcoded_credentials,
+    security::no_unsafe_regex,
+    security::no_unsanitized_inner_html,
     import::named,
     import::no_cycle,
     import::no_self_import,
+    import::no_unresolved,
+    import::order,
     import::default
 }
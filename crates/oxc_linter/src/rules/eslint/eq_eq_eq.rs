@@ -37,7 +37,8 @@ declare_oxc_lint!(
     /// a == b
     /// ```
     EqEqEq,
-    pedantic
+    pedantic,
+    fix
 );
 
 impl Rule for EqEqEq {
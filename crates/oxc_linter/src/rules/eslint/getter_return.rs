@@ -10,9 +10,9 @@ use oxc_diagnostics::{
     thiserror::Error,
 };
 use oxc_macros::declare_oxc_lint;
+use oxc_semantic::ControlFlowGraph;
 use oxc_span::{GetSpan, Span};
 
-use super::array_callback_return::return_checker;
 use crate::{context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
@@ -41,24 +41,19 @@ declare_oxc_lint!(
     /// }
     /// ```
     GetterReturn,
-    nursery // This rule need a CFG to cover cases like
-            // `class Foo { get bar() { throw new Error() } }`
-            // which passes with ESLint
+    correctness
 );
 
 impl GetterReturn {
-    fn is_correct_getter(&self, function_body: &FunctionBody) -> bool {
-        // Filter on target methods on Arrays
-        let return_status = return_checker::check_function_body(function_body);
-
-        if self.allow_implicit {
-            return_status.must_return()
-        } else {
-            return_status == return_checker::StatementReturnStatus::AlwaysExplicit
+    fn is_correct_getter<'a>(&self, function_body: &'a FunctionBody<'a>) -> bool {
+        let cfg = ControlFlowGraph::new(&function_body.statements);
+        if cfg.can_complete_normally() {
+            return false;
         }
+        self.allow_implicit || cfg.reachable_returns().all(|stmt| stmt.argument.is_some())
     }
 
-    fn check_object_descriptor(&self, object: &ObjectExpression) -> Option<Span> {
+    fn check_object_descriptor<'a>(&self, object: &'a ObjectExpression<'a>) -> Option<Span> {
         for property in &object.properties {
             let ObjectPropertyKind::ObjectProperty(property) = property else { continue };
             if !property.key.static_name().is_some_and(|name| name == "get") {
@@ -85,7 +80,7 @@ impl GetterReturn {
         None
     }
 
-    fn check_property(&self, call: &CallExpression) -> Option<Span> {
+    fn check_property<'a>(&self, call: &'a CallExpression<'a>) -> Option<Span> {
         let Some(Argument::Expression(Expression::ObjectExpression(object))) =
             call.arguments.get(2)
         else {
@@ -95,7 +90,7 @@ impl GetterReturn {
         self.check_object_descriptor(object)
     }
 
-    fn check_properties(&self, call: &CallExpression) -> Option<Vec<Span>> {
+    fn check_properties<'a>(&self, call: &'a CallExpression<'a>) -> Option<Vec<Span>> {
         let Some(Argument::Expression(Expression::ObjectExpression(object))) =
             call.arguments.get(1)
         else {
@@ -268,6 +263,12 @@ fn test() {
         ("foo.defineProperty(null, { get() {} });", None),
         ("foo.defineProperties(null, { bar: { get() {} } });", None),
         ("foo.create(null, { bar: { get() {} } });", None),
+        ("class foo { get bar() { throw new Error(); } }", None),
+        ("var foo = { get bar() { if (baz) { return true; } throw new Error(); } };", None),
+        ("var foo = { get bar() { while (true) {} } };", None),
+        ("var foo = { get bar() { switch (baz) { case 1: return 1; default: return 2; } } };", None),
+        ("var foo = { get bar() { try { return compute(); } catch (e) { return null; } } };", None),
+        ("var foo = { get bar() { return true; return; } };", None),
     ];
 
     let fail = vec![
@@ -276,6 +277,9 @@ fn test() {
         ("var foo = { get bar(){if(baz) {return true;}} };", None),
         ("var foo = { get bar() { ~function () {return true;}} };", None),
         ("var foo = { get bar() { return; } };", None),
+        ("var foo = { get bar() { switch (baz) { case 1: return 1; } } };", None),
+        ("var foo = { get bar() { try { return compute(); } catch (e) {} } };", None),
+        ("var foo = { get bar() { if (baz) { return; } return true; } };", None),
         ("var foo = { get bar() {} };", Some(serde_json::json!([{ "allowImplicit": true }]))),
         (
             "var foo = { get bar() {if (baz) {return;}} };",
@@ -0,0 +1,135 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(no-await-in-loop): Unexpected `await` inside a loop.")]
+#[diagnostic(
+    severity(warning),
+    help("Collect the promises and `await` them together with `Promise.all()`.")
+)]
+struct NoAwaitInLoopDiagnostic(#[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoAwaitInLoop;
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Disallow `await` inside of loops
+    ///
+    /// ### Why is this bad?
+    /// Performing an operation on each element of an iterable is a common task.
+    /// However, performing an `await` as part of each operation is an indication that
+    /// the program is not taking full advantage of the parallelization benefits of `async`/`await`.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// async function foo(things) {
+    ///   const results = [];
+    ///   for (const thing of things) {
+    ///     // Bad: each loop iteration is delayed until the entire asynchronous operation completes
+    ///     results.push(await bar(thing));
+    ///   }
+    ///   return baz(results);
+    /// }
+    /// ```
+    NoAwaitInLoop,
+    pedantic
+);
+
+impl Rule for NoAwaitInLoop {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let span = match node.kind() {
+            AstKind::AwaitExpression(expr) => Span::new(expr.span.start, expr.span.start + 5),
+            // `for await (const x of xs)` inside of a loop awaits on every iteration as well.
+            AstKind::ForOfStatement(stmt) if stmt.r#await => {
+                Span::new(stmt.span.start, stmt.right.span().end)
+            }
+            _ => return,
+        };
+
+        let mut child_span = node.kind().span();
+        let mut current = node;
+        while let Some(parent) = ctx.nodes().parent_node(current.id()) {
+            match parent.kind() {
+                AstKind::Function(_) | AstKind::ArrowExpression(_) => return,
+                // The `await` in the body of a `for await` loop is expected.
+                AstKind::ForOfStatement(stmt) if stmt.r#await => return,
+                kind if is_looped(child_span, &kind) => {
+                    ctx.diagnostic(NoAwaitInLoopDiagnostic(span));
+                    return;
+                }
+                _ => {}
+            }
+            child_span = parent.kind().span();
+            current = parent;
+        }
+    }
+}
+
+/// Whether the `child` is evaluated on every iteration of the `parent` loop,
+/// e.g. the initializer of a `for` loop is not.
+fn is_looped(child: Span, parent: &AstKind) -> bool {
+    let contains = |span: Span| span.start <= child.start && child.end <= span.end;
+    match parent {
+        AstKind::ForStatement(stmt) => {
+            stmt.test.as_ref().is_some_and(|test| contains(test.span()))
+                || stmt.update.as_ref().is_some_and(|update| contains(update.span()))
+                || contains(stmt.body.span())
+        }
+        AstKind::ForInStatement(stmt) => contains(stmt.body.span()),
+        AstKind::ForOfStatement(stmt) => contains(stmt.body.span()),
+        AstKind::WhileStatement(stmt) => contains(stmt.test.span()) || contains(stmt.body.span()),
+        AstKind::DoWhileStatement(stmt) => contains(stmt.test.span()) || contains(stmt.body.span()),
+        _ => false,
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("async function foo() { await bar; }", None),
+        ("async function foo() { for (var bar in await baz) { } }", None),
+        ("async function foo() { for (var bar of await baz) { } }", None),
+        ("async function foo() { for (var bar = await baz in qux) {} }", None),
+        ("async function foo() { for (var bar = await baz; ; ) {} }", None),
+        ("async function foo() { for (let i = await bar; i < n; i++) {} }", None),
+        // Functions inside of the loops are fine.
+        ("async function foo() { while (true) { async function foo() { await bar; } } }", None),
+        ("async function foo() { while (true) { var y = async () => await foo; } }", None),
+        (
+            "async function foo() { while (true) { class Foo { async foo() { await bar; } } } }",
+            None,
+        ),
+        // `for await` loops are fine.
+        ("async function foo() { for await (var x of xs) { await f(x) } }", None),
+    ];
+
+    let fail = vec![
+        ("async function foo() { for (var bar in baz) { await bar; } }", None),
+        ("async function foo() { for (var bar of baz) { await bar; } }", None),
+        ("async function foo() { for (var bar of baz) await bar; }", None),
+        ("async function foo() { for (var bar in baz) await bar; }", None),
+        ("async function foo() { for (var i; i < n; i++) { await bar; } }", None),
+        ("async function foo() { for (var i; await foo(i); i++) { } }", None),
+        ("async function foo() { for (var i; i < n; i = await bar) { } }", None),
+        ("async function foo() { while (true) { await bar; } }", None),
+        ("async function foo() { while (xyz || 5 > await x) { } }", None),
+        ("async function foo() { do { await bar; } while (baz); }", None),
+        ("async function foo() { do { } while (await bar); }", None),
+        ("async function foo() { while (true) { for await (var x of xs) { } } }", None),
+        ("async function foo() { for (const x of xs) { const y = { z: await x }; } }", None),
+        ("for (const x of xs) { await x; }", None),
+        ("async function foo() { for await (var x of xs) { for (const y of ys) { await f(y) } } }", None),
+    ];
+
+    Tester::new(NoAwaitInLoop::NAME, pass, fail).test_and_snapshot();
+}
@@ -1,4 +1,4 @@
-use oxc_ast::AstKind;
+use oxc_ast::{ast::Expression, AstKind};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -13,9 +13,15 @@ use crate::{ast_util::IsConstant, context::LintContext, rule::Rule, AstNode};
 #[diagnostic(severity(warning), help("Constant expression as a test condition is not allowed"))]
 struct NoConstantConditionDiagnostic(#[label] pub Span);
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Clone)]
 pub struct NoConstantCondition {
-    _check_loops: bool,
+    check_loops: bool,
+}
+
+impl Default for NoConstantCondition {
+    fn default() -> Self {
+        Self { check_loops: true }
+    }
 }
 
 declare_oxc_lint!(
@@ -23,6 +29,9 @@ declare_oxc_lint!(
     ///
     /// Disallow constant expressions in conditions
     ///
+    /// The tests of the loops are checked as well, unless the `checkLoops` option is `false`.
+    /// The loops with a `yield` inside of a generator function are allowed.
+    ///
     /// ### Why is this bad?
     ///
     /// A constant expression (for example, a literal) as a test condition might be a typo or development trigger for a specific behavior.
@@ -43,10 +52,10 @@ impl Rule for NoConstantCondition {
         let obj = value.get(0);
 
         Self {
-            _check_loops: obj
+            check_loops: obj
                 .and_then(|v| v.get("checkLoops"))
                 .and_then(serde_json::Value::as_bool)
-                .unwrap_or_default(),
+                .unwrap_or(true),
         }
     }
 
@@ -62,11 +71,47 @@ impl Rule for NoConstantCondition {
                     ctx.diagnostic(NoConstantConditionDiagnostic(condition_expr.test.span()));
                 }
             }
+            AstKind::WhileStatement(stmt) => self.check_loop(node, &stmt.test, ctx),
+            AstKind::DoWhileStatement(stmt) => self.check_loop(node, &stmt.test, ctx),
+            AstKind::ForStatement(stmt) => {
+                if let Some(test) = &stmt.test {
+                    self.check_loop(node, test, ctx);
+                }
+            }
             _ => {}
         }
     }
 }
 
+impl NoConstantCondition {
+    fn check_loop<'a>(&self, node: &AstNode<'a>, test: &Expression<'a>, ctx: &LintContext<'a>) {
+        if self.check_loops && test.is_constant(true, ctx) && !has_yield_in_loop(node, ctx) {
+            ctx.diagnostic(NoConstantConditionDiagnostic(test.span()));
+        }
+    }
+}
+
+/// Whether the loop of a generator function is exited with a `yield`, e.g. `while (true) { yield; }`.
+/// The `yield` in the initializer of a `for` loop or in a nested function does not count.
+fn has_yield_in_loop(node: &AstNode, ctx: &LintContext) -> bool {
+    let init_span = match node.kind() {
+        AstKind::ForStatement(stmt) => stmt.init.as_ref().map(GetSpan::span),
+        _ => None,
+    };
+    ctx.nodes().iter().filter(|n| matches!(n.kind(), AstKind::YieldExpression(_))).any(|n| {
+        for id in ctx.nodes().ancestors(n.id()) {
+            if id == node.id() {
+                return true;
+            }
+            let kind = ctx.nodes().kind(id);
+            if matches!(kind, AstKind::Function(_)) || Some(kind.span()) == init_span {
+                return false;
+            }
+        }
+        false
+    })
+}
+
 #[test]
 fn test() {
     use crate::tester::Tester;
@@ -198,30 +243,29 @@ fn test() {
         ("`foo${a}` === a ? 1 : 2", None),
         ("tag`a` === a ? 1 : 2", None),
         ("tag`${a}` === a ? 1 : 2", None),
-        //TODO
-        // ("while(~!a);", None),
-        // ("while(a = b);", None),
-        // ("while(`${a}`);", None),
-        // ("for(;x < 10;);", None),
-        // ("for(;;);", None),
-        // ("for(;`${a}`;);", None),
-        // ("do{ }while(x)", None),
-        // ("while(x += 3) {}", None),
-        // ("while(tag`a`) {}", None),
-        // ("while(tag`${a}`) {}", None),
-        // ("while(`\\\n${a}`) {}", None),
-        // ("while(true);", Some(json!([{"checkLoops":false}]))),
-        // ("for(;true;);", Some(json!([{"checkLoops":false}]))),
-        // ("do{}while(true)", Some(json!([{"checkLoops":false}]))),
-        // ("function* foo(){while(true){yield 'foo';}}", None),
-        // ("function* foo(){for(;true;){yield 'foo';}}", None),
-        // ("function* foo(){do{yield 'foo';}while(true)}", None),
-        // ("function* foo(){while (true) { while(true) {yield;}}}", None),
-        // ("function* foo() {for (; yield; ) {}}", None),
-        // ("function* foo() {for (; ; yield) {}}", None),
-        // ("function* foo() {while (true) {function* foo() {yield;}yield;}}", None),
-        // ("function* foo() { for (let x = yield; x < 10; x++) {yield;}yield;}", None),
-        // ("function* foo() { for (let x = yield; ; x++) { yield; }}", None),
+        ("while(~!a);", None),
+        ("while(a = b);", None),
+        ("while(`${a}`);", None),
+        ("for(;x < 10;);", None),
+        ("for(;;);", None),
+        ("for(;`${a}`;);", None),
+        ("do{ }while(x)", None),
+        ("while(x += 3) {}", None),
+        ("while(tag`a`) {}", None),
+        ("while(tag`${a}`) {}", None),
+        ("while(`\\\n${a}`) {}", None),
+        ("while(true);", Some(serde_json::json!([{"checkLoops":false}]))),
+        ("for(;true;);", Some(serde_json::json!([{"checkLoops":false}]))),
+        ("do{}while(true)", Some(serde_json::json!([{"checkLoops":false}]))),
+        ("function* foo(){while(true){yield 'foo';}}", None),
+        ("function* foo(){for(;true;){yield 'foo';}}", None),
+        ("function* foo(){do{yield 'foo';}while(true)}", None),
+        ("function* foo(){while (true) { while(true) {yield;}}}", None),
+        ("function* foo() {for (; yield; ) {}}", None),
+        ("function* foo() {for (; ; yield) {}}", None),
+        ("function* foo() {while (true) {function* foo() {yield;}yield;}}", None),
+        ("function* foo() { for (let x = yield; x < 10; x++) {yield;}yield;}", None),
+        ("function* foo() { for (let x = yield; ; x++) { yield; }}", None),
     ];
 
     let fail = vec![
@@ -341,40 +385,39 @@ fn test() {
         ("`` ? 1 : 2;", None),
         ("`foo` ? 1 : 2;", None),
         ("`foo${bar}` ? 1 : 2;", None),
-        // TODO
-        // ("for(;true;);", None),
-        // ("for(;``;);", None),
-        // ("for(;`foo`;);", None),
-        // ("for(;`foo${bar}`;);", None),
-        // ("do{}while(true)", None),
-        // ("do{}while('1')", None),
-        // ("do{}while(0)", None),
-        // ("do{}while(t = -2)", None),
-        // ("do{}while(``)", None),
-        // ("do{}while(`foo`)", None),
-        // ("do{}while(`foo${bar}`)", None),
-        // ("while([]);", None),
-        // ("while(~!0);", None),
-        // ("while(x = 1);", None),
-        // ("while(function(){});", None),
-        // ("while(true);", None),
-        // ("while(1);", None),
-        // ("while(() => {});", None),
-        // ("while(`foo`);", None),
-        // ("while(``);", None),
-        // ("while(`${'foo'}`);", None),
-        // ("while(`${'foo' + 'bar'}`);", None),
-        // ("function* foo(){while(true){} yield 'foo';}", None),
-        // ("function* foo(){while(true){if (true) {yield 'foo';}}}", None),
-        // ("function* foo(){while(true){yield 'foo';} while(true) {}}", None),
-        // ("var a = function* foo(){while(true){} yield 'foo';}", None),
-        // ("while (true) { function* foo() {yield;}}", None),
-        // ("function* foo(){if (true) {yield 'foo';}}", None),
-        // ("function* foo() {for (let foo = yield; true;) {}}", None),
-        // ("function* foo() {for (foo = yield; true;) {}}", None),
-        // ("function foo() {while (true) {function* bar() {while (true) {yield;}}}}", None),
-        // ("function foo() {while (true) {const bar = function*() {while (true) {yield;}}}}", None),
-        // ("function* foo() { for (let foo = 1 + 2 + 3 + (yield); true; baz) {}}", None),
+        ("for(;true;);", None),
+        ("for(;``;);", None),
+        ("for(;`foo`;);", None),
+        ("for(;`foo${bar}`;);", None),
+        ("do{}while(true)", None),
+        ("do{}while('1')", None),
+        ("do{}while(0)", None),
+        ("do{}while(t = -2)", None),
+        ("do{}while(``)", None),
+        ("do{}while(`foo`)", None),
+        ("do{}while(`foo${bar}`)", None),
+        ("while([]);", None),
+        ("while(~!0);", None),
+        ("while(x = 1);", None),
+        ("while(function(){});", None),
+        ("while(true);", None),
+        ("while(1);", None),
+        ("while(() => {});", None),
+        ("while(`foo`);", None),
+        ("while(``);", None),
+        ("while(`${'foo'}`);", None),
+        ("while(`${'foo' + 'bar'}`);", None),
+        ("function* foo(){while(true){} yield 'foo';}", None),
+        ("function* foo(){while(true){if (true) {yield 'foo';}}}", None),
+        ("function* foo(){while(true){yield 'foo';} while(true) {}}", None),
+        ("var a = function* foo(){while(true){} yield 'foo';}", None),
+        ("while (true) { function* foo() {yield;}}", None),
+        ("function* foo(){if (true) {yield 'foo';}}", None),
+        ("function* foo() {for (let foo = yield; true;) {}}", None),
+        ("function* foo() {for (foo = yield; true;) {}}", None),
+        ("function foo() {while (true) {function* bar() {while (true) {yield;}}}}", None),
+        ("function foo() {while (true) {const bar = function*() {while (true) {yield;}}}}", None),
+        ("function* foo() { for (let foo = 1 + 2 + 3 + (yield); true; baz) {}}", None),
     ];
 
     Tester::new(NoConstantCondition::NAME, pass, fail).test_and_snapshot();
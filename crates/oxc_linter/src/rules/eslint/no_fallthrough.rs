@@ -1,38 +1,158 @@
+use lazy_static::lazy_static;
+use oxc_ast::{
+    ast::{Statement, SwitchCase},
+    AstKind,
+};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
 };
 use oxc_macros::declare_oxc_lint;
+use oxc_semantic::ControlFlowGraph;
 use oxc_span::Span;
+use regex::Regex;
 
 use crate::{context::LintContext, rule::Rule, AstNode};
 
 // Ported from https://github.com/eslint/eslint/blob/main/lib/rules/no-fallthrough.js
 #[derive(Debug, Error, Diagnostic)]
-#[error("")]
-#[diagnostic(severity(warning), help(""))]
-struct NoFallthroughDiagnostic(#[label] pub Span);
+enum NoFallthroughDiagnostic {
+    #[error("eslint(no-fallthrough): Expected a 'break' statement before 'case'.")]
+    #[diagnostic(
+        severity(warning),
+        help("Add a `break` statement, or a `// falls through` comment if the fall through is intended.")
+    )]
+    Case(#[label] Span),
+    #[error("eslint(no-fallthrough): Expected a 'break' statement before 'default'.")]
+    #[diagnostic(
+        severity(warning),
+        help("Add a `break` statement, or a `// falls through` comment if the fall through is intended.")
+    )]
+    Default(#[label] Span),
+}
 
 #[derive(Debug, Default, Clone)]
-pub struct NoFallthrough;
+pub struct NoFallthrough {
+    /// The pattern of the comments marking the intentional fall through,
+    /// `/falls?\s?through/i` by default.
+    comment_pattern: Option<Regex>,
+    allow_empty_case: bool,
+}
 
 declare_oxc_lint!(
     /// ### What it does
     ///
+    /// Disallow fallthrough of `case` statements
     ///
     /// ### Why is this bad?
     ///
+    /// The `switch` statement executes the next case when a case does not end with
+    /// a `break`, `return`, `throw` or `continue` statement.
+    /// A missing `break` is a common mistake, so the intentional fall through must be
+    /// marked with a comment matching the `commentPattern` option, e.g. `// falls through`.
+    ///
+    /// The empty cases are allowed, unless there are blank lines before the next case
+    /// and the `allowEmptyCase` option is `false`.
     ///
     /// ### Example
     /// ```javascript
+    /// switch (foo) {
+    ///     case 1:
+    ///         doSomething();
+    ///
+    ///     case 2:
+    ///         doSomethingElse();
+    /// }
     /// ```
     NoFallthrough,
-    nursery
+    correctness
 );
 
 impl Rule for NoFallthrough {
-    fn run<'a>(&self, _node: &AstNode<'a>, _ctx: &LintContext<'a>) {
-        // TODO
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let obj = value.get(0);
+        Self {
+            comment_pattern: obj
+                .and_then(|v| v.get("commentPattern"))
+                .and_then(serde_json::Value::as_str)
+                .and_then(|pattern| Regex::new(pattern).ok()),
+            allow_empty_case: obj
+                .and_then(|v| v.get("allowEmptyCase"))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or_default(),
+        }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::SwitchStatement(switch) = node.kind() else { return };
+        let Some(statements) =
+            ctx.nodes().ancestors(node.id()).find_map(|id| match ctx.nodes().kind(id) {
+                AstKind::Program(program) => Some(&program.body),
+                AstKind::FunctionBody(body) => Some(&body.statements),
+                AstKind::StaticBlock(block) => Some(&block.body),
+                _ => None,
+            })
+        else {
+            return;
+        };
+        let cfg = ControlFlowGraph::new(statements);
+
+        for (case, next_case) in switch.cases.iter().zip(switch.cases.iter().skip(1)) {
+            if !cfg.can_fall_through(case) {
+                continue;
+            }
+            let gap = &ctx.source_text()[case.span.end as usize..next_case.span.start as usize];
+            let is_empty_case = case.consequent.is_empty()
+                && (self.allow_empty_case || gap.matches('\n').count() < 2);
+            if is_empty_case || self.has_fallthrough_comment(case, next_case, ctx) {
+                continue;
+            }
+            if next_case.is_default_case() {
+                ctx.diagnostic(NoFallthroughDiagnostic::Default(next_case.span));
+            } else {
+                ctx.diagnostic(NoFallthroughDiagnostic::Case(next_case.span));
+            }
+        }
+    }
+}
+
+impl NoFallthrough {
+    /// Whether the last comment before the next case, or the last comment at the end of the sole
+    /// block statement of the case, marks the fall through.
+    fn has_fallthrough_comment(
+        &self,
+        case: &SwitchCase,
+        next_case: &SwitchCase,
+        ctx: &LintContext,
+    ) -> bool {
+        if let [Statement::BlockStatement(block)] = case.consequent.as_slice() {
+            if self.is_fallthrough_comment_before(block.span.end - 1, ctx) {
+                return true;
+            }
+        }
+        self.is_fallthrough_comment_before(next_case.span.start, ctx)
+    }
+
+    fn is_fallthrough_comment_before(&self, position: u32, ctx: &LintContext) -> bool {
+        lazy_static! {
+            static ref DEFAULT_COMMENT_PATTERN: Regex = Regex::new(r"(?i)falls?\s?through").unwrap();
+            static ref DIRECTIVE: Regex =
+                Regex::new(r"^\s*(?:eslint|oxlint|jshint\s+|jslint\s+|istanbul\s+|globals?\s+|exported\s+|jscs)")
+                    .unwrap();
+        }
+        let source_text = ctx.source_text();
+        let Some((start, comment)) =
+            ctx.semantic().trivias().comments().range(..position).next_back()
+        else {
+            return false;
+        };
+        let end = if comment.is_multi_line() { comment.end() + 2 } else { comment.end() };
+        if end > position || !source_text[end as usize..position as usize].trim().is_empty() {
+            return false;
+        }
+        let text = &source_text[*start as usize..comment.end() as usize];
+        let pattern = self.comment_pattern.as_ref().unwrap_or(&DEFAULT_COMMENT_PATTERN);
+        pattern.is_match(text) && !DIRECTIVE.is_match(text)
     }
 }
 
@@ -135,61 +255,61 @@ fn test() {
     ];
 
     let fail = vec![
-        // ("switch(foo) { case 0: a();\ncase 1: b() }", None),
-        // ("switch(foo) { case 0: a();\ndefault: b() }", None),
-        // ("switch(foo) { case 0: a(); default: b() }", None),
-        // ("switch(foo) { case 0: if (a) { break; } default: b() }", None),
-        // ("switch(foo) { case 0: try { throw 0; } catch (err) {} default: b() }", None),
-        // ("switch(foo) { case 0: while (a) { break; } default: b() }", None),
-        // ("switch(foo) { case 0: do { break; } while (a); default: b() }", None),
-        // ("switch(foo) { case 0:\n\n default: b() }", None),
-        // ("switch(foo) { case 0: {} default: b() }", None),
-        // ("switch(foo) { case 0: a(); { /* falls through */ } default: b() }", None),
-        // ("switch(foo) { case 0: { /* falls through */ } a(); default: b() }", None),
-        // ("switch(foo) { case 0: if (a) { /* falls through */ } default: b() }", None),
-        // ("switch(foo) { case 0: { { /* falls through */ } } default: b() }", None),
-        // ("switch(foo) { case 0: { /* comment */ } default: b() }", None),
-        // ("switch(foo) { case 0:\n // comment\n default: b() }", None),
-        // ("switch(foo) { case 0: a(); /* falling through */ default: b() }", None),
-        // (
-        //     "switch(foo) { case 0: a();\n/* no break */\ncase 1: b(); }",
-        //     Some(serde_json::json!([{
-        //         "commentPattern": "break omitted"
-        //     }])),
-        // ),
-        // (
-        //     "switch(foo) { case 0: a();\n/* no break */\n/* todo: fix readability */\ndefault: b() }",
-        //     Some(serde_json::json!([{
-        //         "commentPattern": "no break"
-        //     }])),
-        // ),
-        // (
-        //     "switch(foo) { case 0: { a();\n/* no break */\n/* todo: fix readability */ }\ndefault: b() }",
-        //     Some(serde_json::json!([{
-        //         "commentPattern": "no break"
-        //     }])),
-        // ),
-        // ("switch(foo) { case 0: \n /* with comments */  \ncase 1: b(); }", None),
-        // (
-        //     "switch(foo) { case 0:\n\ncase 1: b(); }",
-        //     Some(serde_json::json!([{
-        //         "allowEmptyCase": false
-        //     }])),
-        // ),
-        // ("switch(foo) { case 0:\n\ncase 1: b(); }", Some(serde_json::json!([{}]))),
-        // (
-        //     "switch (a) { case 1: \n ; case 2:  }",
-        //     Some(serde_json::json!([{ "allowEmptyCase": false }])),
-        // ),
-        // (
-        //     "switch (a) { case 1: ; case 2: ; case 3: }",
-        //     Some(serde_json::json!([{ "allowEmptyCase": true }])),
-        // ),
-        // (
-        //     "switch (foo) { case 0: a(); \n// eslint-enable no-fallthrough\n case 1: }",
-        //     Some(serde_json::json!([{}])),
-        // ),
+        ("switch(foo) { case 0: a();\ncase 1: b() }", None),
+        ("switch(foo) { case 0: a();\ndefault: b() }", None),
+        ("switch(foo) { case 0: a(); default: b() }", None),
+        ("switch(foo) { case 0: if (a) { break; } default: b() }", None),
+        ("switch(foo) { case 0: try { throw 0; } catch (err) {} default: b() }", None),
+        ("switch(foo) { case 0: while (a) { break; } default: b() }", None),
+        ("switch(foo) { case 0: do { break; } while (a); default: b() }", None),
+        ("switch(foo) { case 0:\n\n default: b() }", None),
+        ("switch(foo) { case 0: {} default: b() }", None),
+        ("switch(foo) { case 0: a(); { /* falls through */ } default: b() }", None),
+        ("switch(foo) { case 0: { /* falls through */ } a(); default: b() }", None),
+        ("switch(foo) { case 0: if (a) { /* falls through */ } default: b() }", None),
+        ("switch(foo) { case 0: { { /* falls through */ } } default: b() }", None),
+        ("switch(foo) { case 0: { /* comment */ } default: b() }", None),
+        ("switch(foo) { case 0:\n // comment\n default: b() }", None),
+        ("switch(foo) { case 0: a(); /* falling through */ default: b() }", None),
+        (
+            "switch(foo) { case 0: a();\n/* no break */\ncase 1: b(); }",
+            Some(serde_json::json!([{
+                "commentPattern": "break omitted"
+            }])),
+        ),
+        (
+            "switch(foo) { case 0: a();\n/* no break */\n/* todo: fix readability */\ndefault: b() }",
+            Some(serde_json::json!([{
+                "commentPattern": "no break"
+            }])),
+        ),
+        (
+            "switch(foo) { case 0: { a();\n/* no break */\n/* todo: fix readability */ }\ndefault: b() }",
+            Some(serde_json::json!([{
+                "commentPattern": "no break"
+            }])),
+        ),
+        ("switch(foo) { case 0: \n /* with comments */  \ncase 1: b(); }", None),
+        (
+            "switch(foo) { case 0:\n\ncase 1: b(); }",
+            Some(serde_json::json!([{
+                "allowEmptyCase": false
+            }])),
+        ),
+        ("switch(foo) { case 0:\n\ncase 1: b(); }", Some(serde_json::json!([{}]))),
+        (
+            "switch (a) { case 1: \n ; case 2:  }",
+            Some(serde_json::json!([{ "allowEmptyCase": false }])),
+        ),
+        (
+            "switch (a) { case 1: ; case 2: ; case 3: }",
+            Some(serde_json::json!([{ "allowEmptyCase": true }])),
+        ),
+        (
+            "switch (foo) { case 0: a(); \n// eslint-enable no-fallthrough\n case 1: }",
+            Some(serde_json::json!([{}])),
+        ),
     ];
 
-    Tester::new(NoFallthrough::NAME, pass, fail).test();
+    Tester::new(NoFallthrough::NAME, pass, fail).test_and_snapshot();
 }
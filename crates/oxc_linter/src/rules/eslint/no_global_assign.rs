@@ -60,7 +60,11 @@ impl Rule for NoGlobalAssign {
                 if reference.is_write() && symbol_table.is_global_reference(reference_id) {
                     let name = reference.name();
 
-                    if !self.excludes.contains(name) && BUILTINS.contains_key(name) {
+                    let read_only = ctx
+                        .globals()
+                        .get(name)
+                        .map_or_else(|| BUILTINS.contains_key(name.as_str()), |writable| !writable);
+                    if !self.excludes.contains(name) && read_only {
                         ctx.diagnostic(NoGlobalAssignDiagnostic(name.clone(), reference.span()));
                     }
                 }
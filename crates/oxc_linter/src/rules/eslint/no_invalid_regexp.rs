@@ -0,0 +1,180 @@
+use oxc_ast::{
+    ast::{Argument, Expression, RegExpFlags},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_regexp_parser::{parse_flags, EcmaVersion, Parser, ParserOptions};
+use oxc_span::Span;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+enum NoInvalidRegexpDiagnostic {
+    #[error("eslint(no-invalid-regexp): Invalid regular expression: /{0}/: {1}")]
+    #[diagnostic(severity(warning))]
+    Pattern(String, &'static str, #[label] Span),
+    #[error("eslint(no-invalid-regexp): Invalid flags supplied to RegExp constructor '{0}'")]
+    #[diagnostic(severity(warning), help("{1}"))]
+    Flags(String, &'static str, #[label] Span),
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoInvalidRegexp {
+    /// The flags which are not standard but allowed in the `RegExp` constructor, e.g. `["a"]`
+    allow_constructor_flags: Vec<char>,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow invalid regular expression strings in `RegExp` constructors.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// An invalid pattern in a regular expression literal is a syntax error of the program,
+    /// but an invalid string passed to the `RegExp` constructor throws only when the code runs.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // Bad
+    /// RegExp("[");
+    /// RegExp(".", "z");
+    /// new RegExp("\\");
+    ///
+    /// // Good
+    /// RegExp(".");
+    /// new RegExp("[a-z]", "gu");
+    /// new RegExp(pattern, flags);
+    /// ```
+    NoInvalidRegexp,
+    correctness
+);
+
+impl Rule for NoInvalidRegexp {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self {
+            allow_constructor_flags: value
+                .get(0)
+                .and_then(|v| v.get("allowConstructorFlags"))
+                .and_then(serde_json::Value::as_array)
+                .map(|v| {
+                    v.iter().filter_map(serde_json::Value::as_str).flat_map(str::chars).collect()
+                })
+                .unwrap_or_default(),
+        }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let (callee, arguments, span) = match node.kind() {
+            AstKind::NewExpression(new_expr) => {
+                (&new_expr.callee, &new_expr.arguments, new_expr.span)
+            }
+            AstKind::CallExpression(call_expr) => {
+                (&call_expr.callee, &call_expr.arguments, call_expr.span)
+            }
+            _ => return,
+        };
+        let Expression::Identifier(ident) = callee else { return };
+        if ident.name != "RegExp" || !ctx.semantic().is_reference_to_global_variable(ident) {
+            return;
+        }
+
+        let candidates = match arguments.get(1) {
+            Some(Argument::Expression(Expression::StringLiteral(lit))) => {
+                // The allowed flags are removed, they are not checked.
+                let text = lit
+                    .value
+                    .chars()
+                    .filter(|c| !self.allow_constructor_flags.contains(c))
+                    .collect::<String>();
+                match parse_flags(&text, EcmaVersion::default()) {
+                    Ok(flags) => vec![flags],
+                    Err(error) => {
+                        let diagnostic = NoInvalidRegexpDiagnostic::Flags(
+                            lit.value.to_string(),
+                            error.0,
+                            lit.span,
+                        );
+                        ctx.diagnostic(diagnostic);
+                        return;
+                    }
+                }
+            }
+            None => vec![RegExpFlags::empty()],
+            // The flags are unknown, the pattern is invalid with and without the unicode mode.
+            Some(_) => vec![RegExpFlags::empty(), RegExpFlags::U],
+        };
+
+        let Some(Argument::Expression(Expression::StringLiteral(lit))) = arguments.first() else {
+            return;
+        };
+        let pattern = lit.value.as_str();
+        let error = candidates
+            .into_iter()
+            .map(|flags| Parser::new(pattern, ParserOptions::new(flags)).parse().err())
+            .reduce(Option::and)
+            .flatten();
+        if let Some(error) = error {
+            ctx.diagnostic(NoInvalidRegexpDiagnostic::Pattern(pattern.to_string(), error.0, span));
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("RegExp('')", None),
+        ("RegExp()", None),
+        ("RegExp('.', 'g')", None),
+        ("new RegExp('.')", None),
+        ("new RegExp", None),
+        ("new RegExp('.', 'im')", None),
+        ("global.RegExp('\\\\')", None),
+        ("new RegExp('.', y)", None),
+        ("new RegExp('.', 'y')", None),
+        ("new RegExp('.', 'u')", None),
+        ("new RegExp('.', 'yu')", None),
+        ("new RegExp('/', 'yu')", None),
+        ("new RegExp('\\/', 'yu')", None),
+        ("new RegExp('\\\\u{65}', 'u')", None),
+        ("new RegExp('\\\\u{65}*', 'u')", None),
+        ("new RegExp('[\\\\u{0}-\\\\u{1F}]', 'u')", None),
+        ("new RegExp('.', 's')", None),
+        ("new RegExp('(?<=a)b')", None),
+        ("new RegExp('(?<!a)b')", None),
+        ("new RegExp('(?<a>b)\\\\k<a>')", None),
+        ("new RegExp('(?<a>b)\\\\k<a>', 'u')", None),
+        ("new RegExp('\\\\p{Letter}', 'u')", None),
+        ("new RegExp('.', 'd')", None),
+        ("new RegExp('[A--B]', 'v')", None),
+        ("new RegExp('[\\\\p{Basic_Emoji}--\\\\q{a|bc|def}]', 'v')", None),
+        ("new RegExp('(?<a>x)|(?<a>y)')", None),
+        ("new RegExp('\\\\p{L}', flags)", None),
+        ("new RegExp('.', 'ga')", Some(serde_json::json!([{ "allowConstructorFlags": ["a"] }]))),
+        ("RegExp(pattern, 'g')", None),
+        ("var RegExp; new RegExp('[')", None),
+    ];
+
+    let fail = vec![
+        ("RegExp('[');", None),
+        ("RegExp('.', 'z');", None),
+        ("RegExp('.', 'a');", Some(serde_json::json!([{ "allowConstructorFlags": ["z"] }]))),
+        ("new RegExp(')');", None),
+        ("new RegExp('\\\\a', 'u');", None),
+        ("new RegExp('\\\\');", None),
+        ("new RegExp('.', 'uv');", None),
+        ("new RegExp('.', 'gg');", None),
+        ("new RegExp('(?<a>x)(?<a>y)');", None),
+        ("new RegExp('\\\\p{Foo}', 'u');", None),
+        ("new RegExp('[A&&B--C]', 'v');", None),
+        ("new RegExp('a**', flags);", None),
+    ];
+
+    Tester::new(NoInvalidRegexp::NAME, pass, fail).test_and_snapshot();
+}
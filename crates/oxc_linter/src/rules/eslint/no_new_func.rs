@@ -0,0 +1,94 @@
+use oxc_ast::{ast::Expression, AstKind};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(no-new-func): The Function constructor is eval.")]
+#[diagnostic(severity(warning))]
+struct NoNewFuncDiagnostic(#[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoNewFunc;
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Disallow `new` operators with the `Function` object
+    ///
+    /// ### Why is this bad?
+    /// Creating the functions from the strings with the `Function` constructor evaluates the code
+    /// the same way as `eval`, and it has the same code injection vulnerabilities.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// var x = new Function("a", "b", "return a + b");
+    /// var x = Function("a", "b", "return a + b");
+    /// var x = Function.call(null, "a", "b", "return a + b");
+    /// ```
+    NoNewFunc,
+    security
+);
+
+impl Rule for NoNewFunc {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let (callee, span) = match node.kind() {
+            AstKind::NewExpression(new_expr) => (&new_expr.callee, new_expr.span),
+            AstKind::CallExpression(call_expr) => match &call_expr.callee {
+                // `Function.call(null, "code")`
+                Expression::MemberExpression(member_expr)
+                    if !member_expr.is_computed()
+                        && member_expr
+                            .static_property_name()
+                            .is_some_and(|name| matches!(name, "apply" | "bind" | "call")) =>
+                {
+                    (member_expr.object(), call_expr.span)
+                }
+                callee => (callee, call_expr.span),
+            },
+            _ => return,
+        };
+        let Expression::Identifier(ident) = callee.without_parenthesized() else { return };
+        if ident.name == "Function" && ctx.semantic().is_reference_to_global_variable(ident) {
+            ctx.diagnostic(NoNewFuncDiagnostic(span));
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("var a = new _function(\"b\", \"c\", \"return b+c\");", None),
+        ("var a = _function(\"b\", \"c\", \"return b+c\");", None),
+        ("class Function {}; new Function()", None),
+        ("const fn = () => { class Function {}; new Function() }", None),
+        ("function Function() {}; Function()", None),
+        ("var fn = function () { function Function() {}; Function() }", None),
+        ("call(Function)", None),
+        ("new Class(Function)", None),
+        ("foo[Function]()", None),
+        ("foo(Function.bind)", None),
+        ("Function.toString()", None),
+        ("Function[call]()", None),
+    ];
+
+    let fail = vec![
+        ("var a = new Function(\"b\", \"c\", \"return b+c\");", None),
+        ("var a = Function(\"b\", \"c\", \"return b+c\");", None),
+        ("var a = Function.call(null, \"b\", \"c\", \"return b+c\");", None),
+        ("var a = Function.apply(null, [\"b\", \"c\", \"return b+c\"]);", None),
+        ("var a = Function.bind(null, \"b\", \"c\", \"return b+c\")();", None),
+        ("var a = Function.bind(null, \"b\", \"c\", \"return b+c\");", None),
+        ("var a = Function?.call(null, \"b\", \"c\", \"return b+c\");", None),
+        ("const fn = () => { class Function {} }; new Function('', '')", None),
+        ("var fn = function () { function Function() {} }; Function('', '')", None),
+    ];
+
+    Tester::new(NoNewFunc::NAME, pass, fail).test_and_snapshot();
+}
@@ -53,13 +53,14 @@ impl Rule for NoUndef {
         for reference_id_list in ctx.scopes().root_unresolved_references().values() {
             for &reference_id in reference_id_list {
                 let reference = symbol_table.get_reference(reference_id);
-                if BUILTINS.contains_key(reference.name().as_str()) {
-                    return;
+                let name = reference.name().as_str();
+                if BUILTINS.contains_key(name) || ctx.globals().get(name).is_some() {
+                    continue;
                 }
 
                 let node = ctx.nodes().get_node(reference.node_id());
                 if !self.type_of && has_typeof_operator(node, ctx) {
-                    return;
+                    continue;
                 }
 
                 ctx.diagnostic(NoUndefDiagnostic(reference.name().clone(), reference.span()));
@@ -90,7 +91,7 @@ fn test() {
         ("var a; a = 1; a++;", None),
         ("var a; function f() { a = 1; }", None),
         // ("/*global b:true*/ b++;", None),
-        // ("/*eslint-env browser*/ window;", None),
+        ("/*eslint-env browser*/ window;", None),
         // ("/*eslint-env node*/ require(\"a\");", None),
         ("Object; isNaN();", None),
         ("toString()", None),
@@ -183,6 +184,8 @@ fn test() {
         ("class C { static { let a; } [a]; }", None),
         ("class C { static { function a() {} } [a]; }", None),
         ("class C { static { var a; } } a;", None),
+        ("/*eslint-env browser*/ window; undefinedThing;", None),
+        ("typeof a; b;", None),
     ];
 
     Tester::new(NoUndef::NAME, pass, fail).test_and_snapshot();
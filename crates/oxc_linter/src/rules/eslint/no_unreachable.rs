@@ -0,0 +1,190 @@
+use oxc_ast::{
+    ast::{Declaration, Statement, VariableDeclarationKind},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::ControlFlowGraph;
+use oxc_span::{GetSpan, Span};
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(no-unreachable): Unreachable code.")]
+#[diagnostic(severity(warning), help("Remove the code which is never executed."))]
+struct NoUnreachableDiagnostic(#[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnreachable;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow unreachable code after `return`, `throw`, `continue`, and `break` statements
+    ///
+    /// ### Why is this bad?
+    ///
+    /// The statements after a `return`, `throw`, `continue`, or `break` statement,
+    /// or after a loop which never exits, can never be executed.
+    /// This is usually a mistake.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// function foo() {
+    ///     return true;
+    ///     console.log("done");
+    /// }
+    ///
+    /// while (true) {}
+    /// console.log("done");
+    /// ```
+    NoUnreachable,
+    correctness
+);
+
+impl Rule for NoUnreachable {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let statements = match node.kind() {
+            AstKind::Program(program) => &program.body,
+            AstKind::FunctionBody(body) => &body.statements,
+            AstKind::StaticBlock(block) => &block.body,
+            _ => return,
+        };
+
+        let cfg = ControlFlowGraph::new(statements);
+        // The consecutive unreachable statements are reported together.
+        let mut range: Option<Span> = None;
+        for (stmt, reachable) in cfg.statements() {
+            let span = stmt.span();
+            if range.is_some_and(|range| range.start <= span.start && span.end <= range.end) {
+                continue;
+            }
+            if reachable || is_ignored(stmt) {
+                if let Some(range) = range.take() {
+                    ctx.diagnostic(NoUnreachableDiagnostic(range));
+                }
+                continue;
+            }
+            range = match range {
+                Some(range)
+                    if ctx.source_text()[range.end as usize..span.start as usize]
+                        .trim()
+                        .is_empty() =>
+                {
+                    Some(Span::new(range.start, span.end))
+                }
+                Some(range) => {
+                    ctx.diagnostic(NoUnreachableDiagnostic(range));
+                    Some(span)
+                }
+                None => Some(span),
+            };
+        }
+        if let Some(range) = range {
+            ctx.diagnostic(NoUnreachableDiagnostic(range));
+        }
+    }
+}
+
+/// The hoisted declarations and the statements without any effect are not reported.
+fn is_ignored(stmt: &Statement) -> bool {
+    match stmt {
+        Statement::EmptyStatement(_)
+        | Statement::Declaration(Declaration::FunctionDeclaration(_)) => true,
+        Statement::Declaration(Declaration::VariableDeclaration(decl)) => {
+            decl.kind == VariableDeclarationKind::Var
+                && decl.declarations.iter().all(|declarator| declarator.init.is_none())
+        }
+        Statement::Declaration(decl) => decl.is_typescript_syntax(),
+        _ => false,
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("function foo() { function bar() { return 1; } return bar(); }", None),
+        ("function foo() { return bar(); function bar() { return 1; } }", None),
+        ("function foo() { return x; var x; }", None),
+        ("function foo() { var x = 1; var y = 2; }", None),
+        ("function foo() { var x = 1; var y = 2; return; }", None),
+        ("while (true) { switch (foo) { case 1: x = 1; x = 2;} }", None),
+        ("while (true) { break; var x; }", None),
+        ("while (true) { continue; var x, y; }", None),
+        ("while (true) { throw 'message'; var x; }", None),
+        ("while (true) { if (true) break; var x = 1; }", None),
+        ("while (true) continue;", None),
+        ("switch (foo) { case 1: break; var x; }", None),
+        ("switch (foo) { case 1: break; var x; default: throw true; };", None),
+        (
+            "const arrow_direction = arrow => {  switch (arrow) { default: throw new Error();  };}",
+            None,
+        ),
+        ("var x = 1; y = 2; throw 'uh oh'; var y;", None),
+        ("function foo() { var x = 1; if (x) { return; } x = 2; }", None),
+        ("function foo() { var x = 1; if (x) { } else { return; } x = 2; }", None),
+        (
+            "function foo() { var x = 1; switch (x) { case 0: break; default: return; } x = 2; }",
+            None,
+        ),
+        ("function foo() { var x = 1; while (x) { return; } x = 2; }", None),
+        ("function foo() { var x = 1; for (x in {}) { return; } x = 2; }", None),
+        ("function foo() { var x = 1; try { return; } finally { x = 2; } }", None),
+        ("function foo() { var x = 1; for (;;) { if (x) break; } x = 2; }", None),
+        ("A: { break A; } foo()", None),
+        ("function* foo() { try { yield 1; return; } catch (err) { return err; } }", None),
+        ("function foo() { try { bar(); return; } catch (err) { return err; } }", None),
+        ("function foo() { try { a.b.c = 1; return; } catch (err) { return err; } }", None),
+        ("class C { foo = reachable; }", None),
+        ("class C { foo = reachable; constructor() {} }", None),
+        ("class C extends B { foo = reachable; }", None),
+        ("class C extends B { foo = reachable; constructor() { super(); } }", None),
+        ("class C extends B { static foo = reachable; constructor() {} }", None),
+        ("function foo() { try { throw 0; } catch { } bar(); }", None),
+        ("function foo() { label: while (true) { break label; } bar(); }", None),
+        ("function foo() { outer: for (;;) { for (;;) { continue outer; } } }", None),
+        ("do { continue; } while (a); foo();", None),
+        ("for (let x of xs) { break; } foo();", None),
+        ("while (true) { try { break; } finally { } } foo();", None),
+    ];
+
+    let fail = vec![
+        ("function foo() { return x; var x = 1; }", None),
+        ("function foo() { return x; var x, y = 1; }", None),
+        ("while (true) { continue; var x = 1; }", None),
+        ("function foo() { return; x = 1; }", None),
+        ("function foo() { throw error; x = 1; }", None),
+        ("while (true) { break; x = 1; }", None),
+        ("while (true) { continue; x = 1; }", None),
+        ("function foo() { switch (foo) { case 1: return; x = 1; } }", None),
+        ("function foo() { switch (foo) { case 1: throw e; x = 1; } }", None),
+        ("while (true) { switch (foo) { case 1: break; x = 1; } }", None),
+        ("while (true) { switch (foo) { case 1: continue; x = 1; } }", None),
+        ("var x = 1; throw 'uh oh'; var y = 2;", None),
+        ("function foo() { var x = 1; if (x) { return; } else { throw e; } x = 2; }", None),
+        ("function foo() { var x = 1; if (x) return; else throw -1; x = 2; }", None),
+        ("function foo() { var x = 1; try { return; } finally {} x = 2; }", None),
+        ("function foo() { var x = 1; try { } finally { return; } x = 2; }", None),
+        ("function foo() { var x = 1; do { return; } while (x); x = 2; }", None),
+        ("function foo() { var x = 1; while (x) { if (x) break; else continue; x = 2; } }", None),
+        ("function foo() { var x = 1; for (;;) { if (x) continue; } x = 2; }", None),
+        ("function foo() { var x = 1; while (true) { } x = 2; }", None),
+        ("const arrow_direction = arrow => {  switch (arrow) { default: throw new Error();  }; g() }", None),
+        ("function foo() { return; a(); b()\n// comment\nc(); }", None),
+        ("function foo() { if (a) { return\n a();\n c();\n } b();\n c(); }", None),
+        ("function foo() { if (a) { return; a(); b(); } c(); }", None),
+        ("function foo() { return; if (a) { a(); } }", None),
+        ("function foo() { return; a(); function bar() {} b(); }", None),
+        ("function foo() { try { return; } catch (err) { return; } bar(); }", None),
+        ("function foo() { label: { return; } bar(); }", None),
+        ("for (;;) { } foo();", None),
+        ("class C { static { throw e; foo(); } }", None),
+    ];
+
+    Tester::new(NoUnreachable::NAME, pass, fail).test_and_snapshot();
+}
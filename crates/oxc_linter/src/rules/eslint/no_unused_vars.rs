@@ -0,0 +1,549 @@
+use oxc_ast::{
+    ast::{
+        AssignmentTarget, BindingIdentifier, BindingPattern, BindingPatternKind, FormalParameters,
+        ImportDeclaration, ImportDeclarationSpecifier, ModifierKind, ModuleDeclaration,
+        SimpleAssignmentTarget, TSModuleDeclarationName,
+    },
+    syntax_directed_operations::BoundNames,
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::{Reference, SymbolFlags, SymbolId};
+use oxc_span::{Atom, GetSpan, Span};
+use oxc_syntax::module_record::ExportLocalName;
+use regex::Regex;
+use serde_json::Value;
+
+use crate::{context::LintContext, fixer::Fix, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+enum NoUnusedVarsDiagnostic {
+    #[error("eslint(no-unused-vars): '{0}' is defined but never used.")]
+    #[diagnostic(severity(warning), help("Remove it, or prefix its name with `_`."))]
+    Defined(Atom, #[label] Span),
+    #[error("eslint(no-unused-vars): '{0}' is assigned a value but never used.")]
+    #[diagnostic(severity(warning), help("Remove it, or prefix its name with `_`."))]
+    Assigned(Atom, #[label] Span),
+}
+
+#[derive(Debug, Clone)]
+pub struct NoUnusedVars {
+    vars: VarsOption,
+    args: ArgsOption,
+    caught_errors: bool,
+    ignore_rest_siblings: bool,
+    vars_ignore_pattern: Option<Regex>,
+    args_ignore_pattern: Option<Regex>,
+    caught_errors_ignore_pattern: Option<Regex>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum VarsOption {
+    All,
+    /// Only the variables which are not global
+    Local,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ArgsOption {
+    /// Only the parameters after the last used one
+    AfterUsed,
+    All,
+    None,
+}
+
+impl Default for NoUnusedVars {
+    fn default() -> Self {
+        Self {
+            vars: VarsOption::All,
+            args: ArgsOption::AfterUsed,
+            caught_errors: false,
+            ignore_rest_siblings: false,
+            vars_ignore_pattern: Some(underscore()),
+            args_ignore_pattern: Some(underscore()),
+            caught_errors_ignore_pattern: Some(underscore()),
+        }
+    }
+}
+
+/// The default ignore pattern, the names starting with `_`.
+#[allow(clippy::trivial_regex)]
+fn underscore() -> Regex {
+    Regex::new("^_").unwrap()
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow the variables, functions, classes, imports, parameters and types which are
+    /// declared but never used.
+    ///
+    /// The uses in JSX, e.g. `<Component />`, and in the TypeScript types, e.g.
+    /// `let a: Type`, count. A variable which is only assigned, e.g. `a = 1` or `a++`, or
+    /// a function which only calls itself is not used. The exported declarations are used.
+    ///
+    /// The unused imports are removed by `--fix`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// An unused declaration is most likely left over by a refactoring, or is a mistake,
+    /// e.g. a typo in the name of the variable read instead.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // Bad
+    /// import { unused } from 'module';
+    /// let count = 0;
+    /// count++;
+    /// function foo(a, b) { return a; }
+    ///
+    /// // Good
+    /// import { used } from 'module';
+    /// export const value = used();
+    /// function foo(a, _b) { return a; }
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// * `vars`: `"all"` by default, or `"local"` to not check the global variables
+    /// * `args`: `"after-used"` by default, `"all"` or `"none"`
+    /// * `caughtErrors`: `"none"` by default, or `"all"` to check the `catch` parameters
+    /// * `ignoreRestSiblings`: `false` by default, ignore the siblings of a rest property,
+    ///   e.g. `const { a, ...rest } = object`
+    /// * `varsIgnorePattern`, `argsIgnorePattern` and `caughtErrorsIgnorePattern`: the regexes
+    ///   of the names to ignore, `^_` by default
+    NoUnusedVars,
+    correctness,
+    fix
+);
+
+impl Rule for NoUnusedVars {
+    fn from_configuration(value: Value) -> Self {
+        let default = Self::default();
+        let Some(config) = value.get(0) else { return default };
+        // `"all"` or `"local"` is the same as `{ "vars": "all" }`
+        if let Some(vars) = config.as_str() {
+            return Self {
+                vars: if vars == "local" { VarsOption::Local } else { VarsOption::All },
+                ..default
+            };
+        }
+        let pattern = |name: &str, default: Option<Regex>| {
+            config
+                .get(name)
+                .and_then(Value::as_str)
+                .map_or(default, |pattern| Regex::new(pattern).ok())
+        };
+        Self {
+            vars: match config.get("vars").and_then(Value::as_str) {
+                Some("local") => VarsOption::Local,
+                _ => VarsOption::All,
+            },
+            args: match config.get("args").and_then(Value::as_str) {
+                Some("all") => ArgsOption::All,
+                Some("none") => ArgsOption::None,
+                _ => ArgsOption::AfterUsed,
+            },
+            caught_errors: config.get("caughtErrors").and_then(Value::as_str) == Some("all"),
+            ignore_rest_siblings: config
+                .get("ignoreRestSiblings")
+                .and_then(Value::as_bool)
+                .unwrap_or_default(),
+            vars_ignore_pattern: pattern("varsIgnorePattern", default.vars_ignore_pattern),
+            args_ignore_pattern: pattern("argsIgnorePattern", default.args_ignore_pattern),
+            caught_errors_ignore_pattern: pattern(
+                "caughtErrorsIgnorePattern",
+                default.caught_errors_ignore_pattern,
+            ),
+        }
+    }
+
+    fn run_on_symbol(&self, symbol_id: SymbolId, ctx: &LintContext<'_>) {
+        if !self.is_reported(symbol_id, ctx) {
+            return;
+        }
+        let symbols = ctx.symbols();
+        let name = symbols.get_name(symbol_id).clone();
+        let span = symbols.get_span(symbol_id);
+        let declaration = ctx.nodes().get_node(symbols.get_declaration(symbol_id));
+        match declaration.kind() {
+            AstKind::ModuleDeclaration(ModuleDeclaration::ImportDeclaration(import)) => {
+                ctx.diagnostic_with_fix(NoUnusedVarsDiagnostic::Defined(name, span), || {
+                    self.remove_unused_imports(import, ctx)
+                });
+            }
+            AstKind::VariableDeclarator(decl) if decl.init.is_some() => {
+                ctx.diagnostic(NoUnusedVarsDiagnostic::Assigned(name, span));
+            }
+            _ if symbols.get_resolved_references(symbol_id).any(Reference::is_write) => {
+                ctx.diagnostic(NoUnusedVarsDiagnostic::Assigned(name, span));
+            }
+            _ => ctx.diagnostic(NoUnusedVarsDiagnostic::Defined(name, span)),
+        }
+    }
+}
+
+impl NoUnusedVars {
+    /// The symbol is not used, and is not ignored by the options.
+    fn is_reported(&self, symbol_id: SymbolId, ctx: &LintContext<'_>) -> bool {
+        if ctx.source_type().is_typescript_definition() {
+            return false;
+        }
+        let symbols = ctx.symbols();
+        let flags = symbols.get_flag(symbol_id);
+        if flags.intersects(
+            SymbolFlags::Export
+                | SymbolFlags::Ambient
+                | SymbolFlags::EnumMember
+                | SymbolFlags::TypeParameter,
+        ) {
+            return false;
+        }
+        let name = symbols.get_name(symbol_id);
+        let is_ignored = |pattern: &Option<Regex>| {
+            pattern.as_ref().is_some_and(|pattern| pattern.is_match(name))
+        };
+        let declaration = ctx.nodes().get_node(symbols.get_declaration(symbol_id));
+        let is_checked = match declaration.kind() {
+            AstKind::FormalParameters(params) => {
+                if is_ignored(&self.args_ignore_pattern) || name.as_str() == "this" {
+                    return false;
+                }
+                match self.args {
+                    ArgsOption::None => false,
+                    ArgsOption::All | ArgsOption::AfterUsed => {
+                        is_checked_parameter(declaration, symbol_id, ctx)
+                            && (self.args == ArgsOption::All
+                                || !is_before_used_parameter(params, symbol_id, ctx))
+                    }
+                }
+            }
+            AstKind::CatchClause(_) => {
+                self.caught_errors && !is_ignored(&self.caught_errors_ignore_pattern)
+            }
+            kind => {
+                if is_ignored(&self.vars_ignore_pattern)
+                    || (self.vars == VarsOption::Local
+                        && symbols.get_scope_id(symbol_id) == ctx.scopes().root_scope_id())
+                {
+                    return false;
+                }
+                match kind {
+                    AstKind::VariableDeclarator(decl) => {
+                        let is_declare = matches!(
+                            ctx.nodes().parent_kind(declaration.id()),
+                            Some(AstKind::VariableDeclaration(decl)) if decl.modifiers.contains(ModifierKind::Declare)
+                        );
+                        let is_ignored_rest_sibling = self.ignore_rest_siblings
+                            && is_rest_sibling(&decl.id, symbols.get_span(symbol_id));
+                        !is_declare && !is_ignored_rest_sibling
+                    }
+                    AstKind::Function(func) => !func.is_typescript_syntax(),
+                    // `const A = class B {}`, the name of the class expression is only
+                    // visible in the class
+                    AstKind::Class(class) => class.is_declaration(),
+                    AstKind::TSModuleDeclaration(decl) => {
+                        matches!(decl.id, TSModuleDeclarationName::Identifier(_))
+                    }
+                    _ => true,
+                }
+            }
+        };
+        is_checked && !is_used(symbol_id, ctx)
+    }
+
+    /// The import without its unused specifiers, every unused specifier of the import is
+    /// fixed by the same fix.
+    #[allow(clippy::cast_possible_truncation)]
+    fn remove_unused_imports<'a>(
+        &self,
+        import: &ImportDeclaration<'a>,
+        ctx: &LintContext<'a>,
+    ) -> Fix<'a> {
+        let source_text = ctx.source_text();
+        let used = import
+            .specifiers
+            .iter()
+            .filter(|specifier| {
+                local_name(specifier)
+                    .symbol_id
+                    .get()
+                    .map_or(true, |symbol_id| !self.is_reported(symbol_id, ctx))
+            })
+            .collect::<Vec<_>>();
+        if used.is_empty() {
+            let mut span = import.span;
+            if source_text[span.end as usize..].starts_with('\n') {
+                span.end += 1;
+            }
+            return Fix::delete(span);
+        }
+
+        let is_named = |specifier: &&ImportDeclarationSpecifier| {
+            matches!(specifier, ImportDeclarationSpecifier::ImportSpecifier(_))
+        };
+        let (named, others): (Vec<_>, Vec<_>) = used.into_iter().partition(is_named);
+        let mut specifiers = others
+            .iter()
+            .map(|specifier| specifier.span().source_text(source_text).to_string())
+            .collect::<Vec<_>>();
+        if !named.is_empty() {
+            let named = named
+                .iter()
+                .map(|specifier| specifier.span().source_text(source_text))
+                .collect::<Vec<_>>();
+            specifiers.push(format!("{{ {} }}", named.join(", ")));
+        }
+
+        // The text before the first specifier, e.g. `import type `, and after the last one,
+        // e.g. ` from 'module';`
+        let mut start = import.specifiers.first().map_or(import.span.start, |s| s.span().start);
+        let mut end = import.specifiers.last().map_or(import.span.end, |s| s.span().end);
+        if import.specifiers.first().is_some_and(|specifier| is_named(&specifier)) {
+            start = source_text[..start as usize].rfind('{').map_or(start, |offset| offset as u32);
+        }
+        if import.specifiers.iter().any(|specifier| is_named(&specifier)) {
+            end += source_text[end as usize..].find('}').map_or(0, |offset| offset as u32 + 1);
+        }
+        let content = format!(
+            "{}{}{}",
+            &source_text[import.span.start as usize..start as usize],
+            specifiers.join(", "),
+            &source_text[end as usize..import.span.end as usize]
+        );
+        Fix::new(content, import.span)
+    }
+}
+
+fn local_name(specifier: &ImportDeclarationSpecifier) -> &BindingIdentifier {
+    match specifier {
+        ImportDeclarationSpecifier::ImportSpecifier(specifier) => &specifier.local,
+        ImportDeclarationSpecifier::ImportDefaultSpecifier(specifier) => &specifier.local,
+        ImportDeclarationSpecifier::ImportNamespaceSpecifier(specifier) => &specifier.local,
+    }
+}
+
+/// The symbol is read, exported by `export { name }`, or used in JSX or in a type.
+fn is_used(symbol_id: SymbolId, ctx: &LintContext<'_>) -> bool {
+    let symbols = ctx.symbols();
+    let declaration = ctx.nodes().get_node(symbols.get_declaration(symbol_id));
+    // The calls of a function in its own body, e.g. the recursive functions
+    let own_span = match declaration.kind() {
+        AstKind::Function(func) => Some(func.span),
+        AstKind::Class(class) => Some(class.span),
+        _ => None,
+    };
+    let is_read = symbols.get_resolved_references(symbol_id).any(|reference| {
+        reference.is_read()
+            && !own_span.is_some_and(|span| {
+                span.start <= reference.span().start && reference.span().end <= span.end
+            })
+            && !is_self_update(reference, symbol_id, ctx)
+    });
+    is_read || is_exported(symbol_id, ctx)
+}
+
+/// `a++;`, `a += 1;` or `a = a + 1;`, the value is read only to update the variable.
+fn is_self_update(reference: &Reference, symbol_id: SymbolId, ctx: &LintContext<'_>) -> bool {
+    let targets_symbol = |target: &SimpleAssignmentTarget| {
+        matches!(target, SimpleAssignmentTarget::AssignmentTargetIdentifier(ident)
+            if ident.reference_id.get().and_then(|id| ctx.symbols().get_reference(id).symbol_id())
+                == Some(symbol_id))
+    };
+    let mut update_span = None;
+    for node in ctx.nodes().iter_parents(reference.node_id()).skip(1) {
+        match node.kind() {
+            AstKind::UpdateExpression(expr) if targets_symbol(&expr.argument) => {
+                update_span = Some(expr.span);
+            }
+            AstKind::AssignmentExpression(expr) => {
+                if let AssignmentTarget::SimpleAssignmentTarget(target) = &expr.left {
+                    if targets_symbol(target) {
+                        update_span = Some(expr.span);
+                    }
+                }
+            }
+            AstKind::ExpressionStatement(stmt) => {
+                return update_span == Some(stmt.expression.span());
+            }
+            kind if kind.is_statement() || kind.is_function_like() => return false,
+            _ => {}
+        }
+    }
+    false
+}
+
+/// `export { name }`
+fn is_exported(symbol_id: SymbolId, ctx: &LintContext<'_>) -> bool {
+    if ctx.symbols().get_scope_id(symbol_id) != ctx.scopes().root_scope_id() {
+        return false;
+    }
+    let name = ctx.symbols().get_name(symbol_id);
+    let module_record = ctx.semantic().module_record();
+    module_record.local_export_entries.iter().chain(&module_record.indirect_export_entries).any(
+        |entry| matches!(&entry.local_name, ExportLocalName::Name(local) if local.name() == name),
+    )
+}
+
+/// Not a parameter of a function without a body, e.g. a TypeScript overload, nor a parameter
+/// property, e.g. `constructor(private name: string)`.
+fn is_checked_parameter(params: &AstNode, symbol_id: SymbolId, ctx: &LintContext<'_>) -> bool {
+    if let Some(AstKind::Function(func)) = ctx.nodes().parent_kind(params.id()) {
+        if func.is_typescript_syntax() {
+            return false;
+        }
+    }
+    let AstKind::FormalParameters(params) = params.kind() else { return false };
+    let span = ctx.symbols().get_span(symbol_id);
+    !params.items.iter().any(|param| {
+        let pattern_span = param.pattern.span();
+        (param.accessibility.is_some() || param.readonly)
+            && pattern_span.start <= span.start
+            && span.end <= pattern_span.end
+    })
+}
+
+/// A parameter followed by a used one, e.g. `a` of `function (a, b) { return b; }`.
+fn is_before_used_parameter(
+    params: &FormalParameters,
+    symbol_id: SymbolId,
+    ctx: &LintContext<'_>,
+) -> bool {
+    let span = ctx.symbols().get_span(symbol_id);
+    let mut is_after = false;
+    let mut is_before_used = false;
+    params.bound_names(&mut |ident| {
+        if is_after {
+            is_before_used |= ident.symbol_id.get().is_some_and(|id| is_used(id, ctx));
+        }
+        is_after |= ident.span == span;
+    });
+    is_before_used
+}
+
+/// `a` of `const { a, ...rest } = object`
+fn is_rest_sibling(pattern: &BindingPattern, span: Span) -> bool {
+    match &pattern.kind {
+        BindingPatternKind::BindingIdentifier(_) => false,
+        BindingPatternKind::ObjectPattern(object) => object.properties.iter().any(|property| {
+            (object.rest.is_some()
+                && matches!(&property.value.kind, BindingPatternKind::BindingIdentifier(ident) if ident.span == span))
+                || is_rest_sibling(&property.value, span)
+        }),
+        BindingPatternKind::ArrayPattern(array) => {
+            array.elements.iter().flatten().any(|element| is_rest_sibling(element, span))
+        }
+        BindingPatternKind::AssignmentPattern(assignment) => {
+            is_rest_sibling(&assignment.left, span)
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("var a = 1; console.log(a);", None),
+        ("function foo() {} foo();", None),
+        ("class A {} new A();", None),
+        ("export const a = 1;", None),
+        ("export function foo() {}", None),
+        ("const a = 1; export { a };", None),
+        ("const a = 1; export { a as b };", None),
+        ("const a = 1; export default a;", None),
+        ("import a from 'a'; export { a };", None),
+        ("import { a } from 'a'; a();", None),
+        ("import * as ns from 'a'; ns.a();", None),
+        ("import Foo from './Foo'; <Foo />;", None),
+        ("import * as UI from './ui'; <UI.Button />;", None),
+        ("import Foo from './Foo'; <div>{Foo}</div>;", None),
+        ("import type { A } from 'a'; let a: A; console.log(a);", None),
+        ("import type { A } from 'a'; export type B = A;", None),
+        ("import * as ns from 'a'; export type B = ns.A;", None),
+        ("const a = 1; export type B = typeof a;", None),
+        ("interface A {} export interface B extends A {}", None),
+        ("interface A {} export class B implements A {}", None),
+        ("type A = string; export function foo(a: A) { return a; }", None),
+        ("enum E { A } console.log(E.A);", None),
+        ("function foo(a, b) { return b; } foo();", None),
+        ("function foo(_a) {} foo();", None),
+        ("const _a = 1;", None),
+        ("try {} catch (error) {}", None),
+        ("try {} catch (_error) {}", Some(json!([{ "caughtErrors": "all" }]))),
+        ("function foo(a) {} foo();", Some(json!([{ "args": "none" }]))),
+        ("let a = 0; foo(a++);", None),
+        ("let a = 0; a++; console.log(a);", None),
+        (
+            "const { a, ...rest } = object; console.log(rest);",
+            Some(json!([{ "ignoreRestSiblings": true }])),
+        ),
+        ("var a = 1;", Some(json!(["local"]))),
+        ("const unused = 1;", Some(json!([{ "varsIgnorePattern": "^unused" }]))),
+        ("declare const a: number;", None),
+        ("declare function foo(): void;", None),
+        ("declare module 'a' {}", None),
+        ("export function foo(a: string): void; export function foo(a: any) { return a; }", None),
+        ("export class A { constructor(private a: string) {} }", None),
+        ("function foo(this: Window) {} foo();", None),
+        ("export function foo<T>() {}", None),
+        ("const a = class B {}; console.log(a);", None),
+        ("export const foo = function bar() {};", None),
+        ("function foo() { return foo; } foo();", None),
+    ];
+
+    let fail = vec![
+        ("var a = 1;", None),
+        ("let a;", None),
+        ("function foo() {}", None),
+        ("function foo() { foo(); }", None),
+        ("class A {}", None),
+        ("class A { static create() { return new A(); } }", None),
+        ("import a from 'a';", None),
+        ("import { a, b } from 'a'; b();", None),
+        ("import * as ns from 'a';", None),
+        ("import type { A } from 'a';", None),
+        ("import foo from './foo'; <div />;", None),
+        ("let a = 0; a++;", None),
+        ("let a = 0; a += 1;", None),
+        ("let a = 0; a = a + 1;", None),
+        ("let a; a = 1;", None),
+        ("function foo(a) {} foo();", None),
+        ("function foo(a, b) { return a; } foo();", None),
+        ("function foo(a, b) { return b; } foo();", Some(json!([{ "args": "all" }]))),
+        ("const foo = (a) => 1; foo();", None),
+        ("try {} catch (error) {}", Some(json!([{ "caughtErrors": "all" }]))),
+        ("const { a, ...rest } = object; console.log(rest);", None),
+        ("const [a, b] = array; console.log(b);", None),
+        ("const _a = 1;", Some(json!([{ "varsIgnorePattern": "^unused" }]))),
+        ("function foo(_a) {} foo();", Some(json!([{ "argsIgnorePattern": "^unused" }]))),
+        ("type A = string;", None),
+        ("interface A {}", None),
+        ("enum E { A }", None),
+        ("namespace N {}", None),
+        ("function foo() { const a = 1; } foo();", Some(json!(["local"]))),
+    ];
+
+    let fix = vec![
+        ("import a from 'a';\nfoo();", "foo();", None),
+        ("import a, { b } from 'a'; b();", "import { b } from 'a'; b();", None),
+        ("import a, { b } from 'a'; a();", "import a from 'a'; a();", None),
+        ("import { a, b, c } from 'a'; b();", "import { b } from 'a'; b();", None),
+        ("import { a, b as c } from 'a'; a();", "import { a } from 'a'; a();", None),
+        ("import a, * as b from 'a'; b();", "import * as b from 'a'; b();", None),
+        (
+            "import type { A, B } from 'a'; let b: B; b;",
+            "import type { B } from 'a'; let b: B; b;",
+            None,
+        ),
+        ("import { _a, b } from 'a';", "import { _a } from 'a';", None),
+        ("var a = 1;", "var a = 1;", None),
+    ];
+
+    Tester::new(NoUnusedVars::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
@@ -0,0 +1,112 @@
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{Atom, Span};
+use regex::Regex;
+
+use crate::{context::LintContext, rule::Rule};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint-plugin-import(no-unresolved): Unable to resolve path to module {0:?}")]
+#[diagnostic(severity(warning))]
+struct NoUnresolvedDiagnostic(Atom, #[label] pub Span);
+
+/// <https://github.com/import-js/eslint-plugin-import/blob/main/docs/rules/no-unresolved.md>
+#[derive(Debug, Default, Clone)]
+pub struct NoUnresolved {
+    /// The module specifiers matching one of these patterns are not reported.
+    ignore: Vec<Regex>,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Ensures an imported module can be resolved to a module on the local filesystem.
+    /// Both the ES module imports and the top level `require` calls are checked.
+    ///
+    /// Node.js builtin modules, e.g. `fs` or `node:path`, are always resolved.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// import x from './foo' // reported if './foo' does not exist
+    /// const { default: y } = require('./bar') // reported if './bar' does not exist
+    /// ```
+    NoUnresolved,
+    nursery
+);
+
+impl Rule for NoUnresolved {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self {
+            ignore: value
+                .get(0)
+                .and_then(|config| config.get("ignore"))
+                .and_then(serde_json::Value::as_array)
+                .map(|patterns| {
+                    patterns
+                        .iter()
+                        .filter_map(serde_json::Value::as_str)
+                        .filter_map(|pattern| Regex::new(pattern).ok())
+                        .collect()
+                })
+                .unwrap_or_default(),
+        }
+    }
+
+    fn run_once(&self, ctx: &LintContext<'_>) {
+        let module_record = ctx.semantic().module_record();
+        for (specifier, spans) in &module_record.requested_modules {
+            if !ctx.unresolved_modules().contains(specifier)
+                || self.ignore.iter().any(|pattern| pattern.is_match(specifier))
+            {
+                continue;
+            }
+            for span in spans {
+                ctx.diagnostic(NoUnresolvedDiagnostic(specifier.clone(), *span));
+            }
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("import foo from './bar'", None),
+        ("import foo from './bar.js'", None),
+        ("import { foo } from './named-exports'", None),
+        ("import * as foo from './jsx/MyCoolComponent'", None),
+        ("export { foo } from './bar'", None),
+        ("export * from './bar'", None),
+        ("import 'left-pad'", None),
+        ("import React from 'react'", None),
+        ("import { x } from '@org/package'", None),
+        ("import fs from 'fs'", None),
+        ("import path from 'node:path'", None),
+        ("var bar = require('./bar')", None),
+        ("const { readFile } = require('fs')", None),
+        ("import foo from './data.json'", None),
+        ("import foo from './does-not-exist'", Some(json!([{ "ignore": ["^\\./does-not"] }]))),
+    ];
+
+    let fail = vec![
+        ("import reallyfake from './reallyfake/module'", None),
+        ("import bar from './baz'", None),
+        ("import bar from './empty-folder'", None),
+        ("import { foo } from 'in-alternate-root'", None),
+        ("export { foo } from './does-not-exist'", None),
+        ("export * from './does-not-exist'", None),
+        ("import './does-not-exist'; import './does-not-exist'", None),
+        ("var bar = require('./does-not-exist')", None),
+        ("import foo from './does-not-exist'", Some(json!([{ "ignore": ["^\\./bar"] }]))),
+    ];
+
+    Tester::new(NoUnresolved::NAME, pass, fail)
+        .with_import_plugin(true)
+        .change_rule_path("index.js")
+        .test_and_snapshot();
+}
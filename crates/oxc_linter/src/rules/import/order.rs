@@ -0,0 +1,454 @@
+use oxc_ast::{
+    ast::{ImportDeclaration, ModuleDeclaration, Statement},
+    AstKind, Comment,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_resolver::BUILTINS;
+use oxc_span::{Atom, GetSpan, Span};
+use serde_json::Value;
+
+use crate::{context::LintContext, fixer::Fix, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+enum OrderDiagnostic {
+    #[error("eslint-plugin-import(order): `{0}` import should occur before import of `{1}`")]
+    #[diagnostic(severity(warning))]
+    Order(Atom, Atom, #[label] Span),
+    #[error("eslint-plugin-import(order): There should be at least one empty line between import groups")]
+    #[diagnostic(severity(warning))]
+    MissingNewline(#[label] Span),
+    #[error("eslint-plugin-import(order): There should be no empty line between import groups")]
+    #[diagnostic(severity(warning))]
+    ExtraNewlineBetweenGroups(#[label] Span),
+    #[error("eslint-plugin-import(order): There should be no empty line within import group")]
+    #[diagnostic(severity(warning))]
+    ExtraNewlineWithinGroup(#[label] Span),
+}
+
+/// <https://github.com/import-js/eslint-plugin-import/blob/main/docs/rules/order.md>
+#[derive(Debug, Clone)]
+pub struct Order {
+    /// The rank of each [ImportKind], the kinds missing from the `groups` option are ranked
+    /// after all the others.
+    ranks: [usize; ImportKind::COUNT],
+    newlines_between: NewlinesBetween,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ImportKind {
+    /// `fs`, `node:fs`
+    Builtin,
+    /// `react`, `@scope/package`
+    External,
+    /// `@/components`, `~/utils`, `#internal`
+    Internal,
+    /// `../foo`
+    Parent,
+    /// `./foo`
+    Sibling,
+    /// `.`, `./index`
+    Index,
+    /// `/absolute/path`
+    Unknown,
+}
+
+impl ImportKind {
+    const COUNT: usize = 7;
+
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "builtin" => Some(Self::Builtin),
+            "external" => Some(Self::External),
+            "internal" => Some(Self::Internal),
+            "parent" => Some(Self::Parent),
+            "sibling" => Some(Self::Sibling),
+            "index" => Some(Self::Index),
+            "unknown" => Some(Self::Unknown),
+            _ => None,
+        }
+    }
+
+    fn from_source(source: &str) -> Self {
+        if source == ".." || source.starts_with("../") {
+            return Self::Parent;
+        }
+        if matches!(source, "." | "./")
+            || source.strip_prefix("./").is_some_and(|path| {
+                path == "index" || path.strip_prefix("index.").is_some_and(|ext| !ext.contains('/'))
+            })
+        {
+            return Self::Index;
+        }
+        if source.starts_with("./") {
+            return Self::Sibling;
+        }
+        if source.starts_with('/') {
+            return Self::Unknown;
+        }
+        if source.starts_with("@/") || source.starts_with("~/") || source.starts_with('#') {
+            return Self::Internal;
+        }
+        // `fs/promises` is a builtin module, `fs/foo` is not.
+        let name = source.strip_prefix("node:").unwrap_or(source);
+        if source.starts_with("node:") || BUILTINS.contains(&name) {
+            return Self::Builtin;
+        }
+        Self::External
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum NewlinesBetween {
+    Ignore,
+    /// An empty line between the groups, and none within a group
+    Always,
+    /// No empty line between the imports
+    Never,
+}
+
+impl Default for Order {
+    fn default() -> Self {
+        Self { ranks: [0, 1, 2, 3, 4, 5, 6], newlines_between: NewlinesBetween::Ignore }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce the order of the import statements by the kind of the imported module:
+    /// the builtin modules, e.g. `fs`, the external packages, e.g. `react`, the internal
+    /// modules, e.g. `@/components`, and the parent, sibling and index relative modules.
+    ///
+    /// The imports are reordered by `--fix`, the comments on the lines directly above an import
+    /// and at the end of its line are moved with it. The imports without specifiers, e.g.
+    /// `import './polyfill'`, are kept in place for their side effects, and only the imports
+    /// between them are ordered.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Grouping the imports makes the dependencies of a module easy to read.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // Bad
+    /// import foo from './foo';
+    /// import fs from 'fs';
+    ///
+    /// // Good
+    /// import fs from 'fs';
+    /// import foo from './foo';
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// * `groups`: the order of the kinds of imports, `["builtin", "external", "internal",
+    ///   "parent", "sibling", "index"]` by default. A nested array makes a single group of its
+    ///   kinds, e.g. `["builtin", "external", ["parent", "sibling", "index"]]`, the kinds which
+    ///   are not listed make the last group.
+    /// * `newlines-between`: `"ignore"` by default, `"always"` to require an empty line between
+    ///   the groups and none within a group, or `"never"` to disallow the empty lines.
+    Order,
+    style,
+    fix
+);
+
+impl Rule for Order {
+    fn from_configuration(value: Value) -> Self {
+        let default = Self::default();
+        let Some(config) = value.get(0) else { return default };
+        let ranks =
+            config.get("groups").and_then(Value::as_array).map_or(default.ranks, |groups| {
+                let mut ranks = [groups.len(); ImportKind::COUNT];
+                for (rank, group) in groups.iter().enumerate() {
+                    let names = group
+                        .as_array()
+                        .map_or_else(|| vec![group], |names| names.iter().collect());
+                    for kind in names
+                        .into_iter()
+                        .filter_map(Value::as_str)
+                        .filter_map(ImportKind::from_name)
+                    {
+                        ranks[kind as usize] = rank;
+                    }
+                }
+                ranks
+            });
+        let newlines_between = match config.get("newlines-between").and_then(Value::as_str) {
+            Some("always") => NewlinesBetween::Always,
+            Some("never") => NewlinesBetween::Never,
+            _ => NewlinesBetween::Ignore,
+        };
+        Self { ranks, newlines_between }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::Program(program) = node.kind() else { return };
+        // The end of the code before the imports, `None` at the start of the file.
+        let mut before = program.directives.last().map_or_else(
+            || program.hashbang.as_ref().map(|hashbang| hashbang.span.end),
+            |directive| Some(directive.span.end),
+        );
+        let mut imports = vec![];
+        for stmt in &program.body {
+            if let Statement::ModuleDeclaration(decl) = stmt {
+                // The imports without specifiers are kept in place for their side effects.
+                if let ModuleDeclaration::ImportDeclaration(import) = &**decl {
+                    if !import.specifiers.is_empty() {
+                        imports.push(&**import);
+                        continue;
+                    }
+                }
+            }
+            self.check_imports(&imports, before, stmt.span().start, ctx);
+            imports.clear();
+            before = Some(stmt.span().end);
+        }
+        #[allow(clippy::cast_possible_truncation)]
+        let after = ctx.source_text().len() as u32;
+        self.check_imports(&imports, before, after, ctx);
+    }
+}
+
+impl Order {
+    /// Check the consecutive `imports`, between the code ending at `before` and the code
+    /// starting at `after`.
+    fn check_imports(
+        &self,
+        imports: &[&ImportDeclaration],
+        before: Option<u32>,
+        after: u32,
+        ctx: &LintContext,
+    ) {
+        if imports.len() < 2 {
+            return;
+        }
+        let source_text = ctx.source_text();
+        let ranks = imports
+            .iter()
+            .map(|import| self.ranks[ImportKind::from_source(&import.source.value) as usize])
+            .collect::<Vec<_>>();
+
+        // The imports with their comments, which are moved together.
+        let mut items: Vec<Span> = vec![];
+        for (i, import) in imports.iter().enumerate() {
+            let lower = items.last().map_or(before, |item| Some(item.end));
+            let upper = imports.get(i + 1).map_or(after, |next| next.span.start);
+            let start = leading_comments_start(lower, import.span.start, ctx);
+            let end = trailing_comments_end(import.span.end, upper, ctx);
+            items.push(Span::new(start, end));
+        }
+        // The comments which are not attached to an import can not be moved.
+        let gaps = items
+            .windows(2)
+            .map(|items| &source_text[items[0].end as usize..items[1].start as usize])
+            .collect::<Vec<_>>();
+        let fixable = gaps.iter().all(|gap| gap.trim().is_empty());
+        let report = |diagnostic: OrderDiagnostic| {
+            if fixable {
+                ctx.diagnostic_with_fix(diagnostic, || self.reorder(&items, &ranks, &gaps, ctx));
+            } else {
+                ctx.diagnostic(diagnostic);
+            }
+        };
+
+        let mut in_order = true;
+        for (i, import) in imports.iter().enumerate() {
+            let Some(j) = (0..i).find(|&j| ranks[j] > ranks[i]) else { continue };
+            in_order = false;
+            report(OrderDiagnostic::Order(
+                import.source.value.clone(),
+                imports[j].source.value.clone(),
+                import.span,
+            ));
+        }
+        if !in_order || self.newlines_between == NewlinesBetween::Ignore {
+            return;
+        }
+        for (i, pair) in imports.windows(2).enumerate() {
+            let gap = &source_text[pair[0].span.end as usize..pair[1].span.start as usize];
+            let has_empty_line = has_empty_line(gap);
+            let same_group = ranks[i] == ranks[i + 1];
+            let span = pair[0].span;
+            match self.newlines_between {
+                NewlinesBetween::Always if !same_group && !has_empty_line => {
+                    report(OrderDiagnostic::MissingNewline(span));
+                }
+                NewlinesBetween::Always | NewlinesBetween::Never
+                    if same_group && has_empty_line =>
+                {
+                    report(OrderDiagnostic::ExtraNewlineWithinGroup(span));
+                }
+                NewlinesBetween::Never if has_empty_line => {
+                    report(OrderDiagnostic::ExtraNewlineBetweenGroups(span));
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Sort the imports with their comments by rank, keeping the order of the imports of a group.
+    fn reorder<'a>(
+        &self,
+        items: &[Span],
+        ranks: &[usize],
+        gaps: &[&str],
+        ctx: &LintContext<'a>,
+    ) -> Fix<'a> {
+        let mut order = (0..items.len()).collect::<Vec<_>>();
+        order.sort_by_key(|&i| ranks[i]);
+        let mut content = String::new();
+        for (k, &i) in order.iter().enumerate() {
+            if k > 0 {
+                content.push_str(match self.newlines_between {
+                    NewlinesBetween::Ignore => gaps[k - 1],
+                    NewlinesBetween::Always if ranks[order[k - 1]] != ranks[i] => "\n\n",
+                    _ => "\n",
+                });
+            }
+            content.push_str(items[i].source_text(ctx.source_text()));
+        }
+        Fix::new(content, Span::new(items[0].start, items[items.len() - 1].end))
+    }
+}
+
+/// Whether there is a line with only whitespace between the first and the last line of `text`.
+fn has_empty_line(text: &str) -> bool {
+    let (Some(first), Some(last)) = (text.find('\n'), text.rfind('\n')) else { return false };
+    first < last && text[first + 1..last].split('\n').any(|line| line.trim().is_empty())
+}
+
+/// The span of a comment, with its `//` or `/*` and `*/`, and without the line terminator
+/// ending a single line comment.
+fn comment_span(start: u32, comment: Comment, source_text: &str) -> Span {
+    let end = if comment.is_single_line() {
+        let text = source_text[..comment.end() as usize]
+            .trim_end_matches(['\n', '\r', '\u{2028}', '\u{2029}']);
+        u32::try_from(text.len()).unwrap()
+    } else {
+        comment.end() + 2
+    };
+    Span::new(start - 2, end)
+}
+
+/// The start of the comments on the lines directly above the import starting at `start`,
+/// after the line of the code ending at `lower`.
+fn leading_comments_start(lower: Option<u32>, start: u32, ctx: &LintContext) -> u32 {
+    let source_text = ctx.source_text();
+    let bound = lower.map_or(0, |lower| {
+        source_text[lower as usize..start as usize]
+            .find('\n')
+            .map_or(start, |i| lower + u32::try_from(i).unwrap() + 1)
+    });
+    let mut cursor = start;
+    for (&comment_start, &comment) in ctx.semantic().trivias().comments().range(bound..start).rev()
+    {
+        let span = comment_span(comment_start, comment, source_text);
+        if source_text[span.end as usize..cursor as usize].matches('\n').count() > 1 {
+            break;
+        }
+        cursor = span.start;
+    }
+    cursor
+}
+
+/// The end of the comments on the line of the import ending at `end`.
+fn trailing_comments_end(end: u32, upper: u32, ctx: &LintContext) -> u32 {
+    let line_end = ctx.source_text()[end as usize..upper as usize]
+        .find('\n')
+        .map_or(upper, |i| end + u32::try_from(i).unwrap());
+    ctx.semantic()
+        .trivias()
+        .comments()
+        .range(end..line_end)
+        .last()
+        .map_or(end, |(&comment_start, &comment)| {
+            comment_span(comment_start, comment, ctx.source_text()).end
+        })
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("import fs from 'fs';\nimport react from 'react';\nimport foo from './foo';", None),
+        ("import fs from 'node:fs';\nimport path from 'path';", None),
+        ("import a from 'a';\nimport b from '@/b';\nimport c from '../c';\nimport d from './d';\nimport e from '.';", None),
+        ("import foo from './foo';\nimport './polyfill';\nimport fs from 'fs';", None),
+        ("import foo from './foo';\nconst bar = 1;\nimport fs from 'fs';", None),
+        ("import foo from './foo';\nimport fs from 'fs';", Some(json!([{ "groups": [["sibling", "builtin"]] }]))),
+        ("import foo from './foo';\nimport fs from 'fs';", Some(json!([{ "groups": ["sibling"] }]))),
+        ("import fs from 'fs';\n\nimport foo from './foo';", Some(json!([{ "newlines-between": "always" }]))),
+        ("import fs from 'fs';\nimport path from 'path';\n\nimport foo from './foo';", Some(json!([{ "newlines-between": "always" }]))),
+        ("import fs from 'fs';\nimport foo from './foo';", Some(json!([{ "newlines-between": "never" }]))),
+        ("import fs from 'fs';\n\nimport foo from './foo';", None),
+    ];
+
+    let fail = vec![
+        ("import foo from './foo';\nimport fs from 'fs';", None),
+        ("import foo from '../foo';\nimport bar from './bar';\nimport react from 'react';", None),
+        ("import index from './';\nimport sibling from './sibling';", None),
+        (
+            "import fs from 'fs';\nimport foo from './foo';",
+            Some(json!([{ "groups": ["sibling", "builtin"] }])),
+        ),
+        (
+            "import fs from 'fs';\nimport foo from './foo';",
+            Some(json!([{ "newlines-between": "always" }])),
+        ),
+        (
+            "import fs from 'fs';\n\nimport path from 'path';",
+            Some(json!([{ "newlines-between": "always" }])),
+        ),
+        (
+            "import fs from 'fs';\n\nimport foo from './foo';",
+            Some(json!([{ "newlines-between": "never" }])),
+        ),
+        ("import foo from './foo';\n// detached\n\nimport fs from 'fs';", None),
+    ];
+
+    let fix = vec![
+        (
+            "import foo from './foo';\nimport fs from 'fs';",
+            "import fs from 'fs';\nimport foo from './foo';",
+            None,
+        ),
+        (
+            "// foo\nimport foo from './foo'; // sibling\n/* fs */\nimport fs from 'fs';\nfoo(fs);",
+            "/* fs */\nimport fs from 'fs';\n// foo\nimport foo from './foo'; // sibling\nfoo(fs);",
+            None,
+        ),
+        (
+            "import foo from './foo';\nimport 'polyfill';\nimport bar from './bar';\nimport fs from 'fs';",
+            "import foo from './foo';\nimport 'polyfill';\nimport fs from 'fs';\nimport bar from './bar';",
+            None,
+        ),
+        (
+            "import foo from './foo';\nimport react from 'react';\nimport fs from 'fs';",
+            "import fs from 'fs';\n\nimport react from 'react';\n\nimport foo from './foo';",
+            Some(json!([{ "newlines-between": "always" }])),
+        ),
+        (
+            "import fs from 'fs';\n\nimport path from 'path';\nimport foo from './foo';",
+            "import fs from 'fs';\nimport path from 'path';\n\nimport foo from './foo';",
+            Some(json!([{ "newlines-between": "always" }])),
+        ),
+        (
+            "import fs from 'fs';\n\nimport foo from './foo';",
+            "import fs from 'fs';\nimport foo from './foo';",
+            Some(json!([{ "newlines-between": "never" }])),
+        ),
+        (
+            "import foo from './foo';\n// detached\n\nimport fs from 'fs';",
+            "import foo from './foo';\n// detached\n\nimport fs from 'fs';",
+            None,
+        ),
+    ];
+
+    Tester::new(Order::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
@@ -206,6 +206,11 @@ fn test() {
     use crate::tester::Tester;
 
     let pass = vec![
+        ("test.fails('should pass', () => {})", None),
+        (
+            "import { it, expect } from 'vitest'; it('should pass', () => expect(true).toBeDefined())",
+            None,
+        ),
         ("it.todo('will test something eventually')", None),
         ("test.todo('will test something eventually')", None),
         ("['x']();", None),
@@ -357,6 +362,9 @@ fn test() {
     ];
 
     let fail = vec![
+        ("import { it } from 'vitest'; it('should fail', () => {});", None),
+        ("import { test } from 'vitest'; test.fails('should fail', () => {});", None),
+        ("test.skipIf(isCI)('should fail', () => {}); vi.fn();", None),
         ("it(\"should fail\", () => {});", None),
         ("it(\"should fail\", myTest); function myTest() {}", None),
         ("test(\"should fail\", () => {});", None),
@@ -43,7 +43,8 @@ declare_oxc_lint!(
     /// expect(a).toThrowError();
     /// ```
     NoAliasMethods,
-    restriction
+    restriction,
+    fix
 );
 
 impl Rule for NoAliasMethods {
@@ -175,6 +175,7 @@ fn test() {
             None,
         ),
         ("import { test } from './test-utils'; test('something');", None),
+        ("import { suite } from 'vitest'; suite('foo', function () {})", None),
     ];
 
     let fail = vec![
@@ -204,6 +205,9 @@ fn test() {
         ("pending()", None),
         ("describe('contains a call to pending', function () { pending() })", None),
         ("import { test } from '@jest/globals';test('something');", None),
+        ("import { test } from 'vitest'; test('something');", None),
+        ("import { describe } from 'vitest'; describe.skip('foo', function () {})", None),
+        ("import { suite } from 'vitest'; suite.skip('foo', function () {})", None),
     ];
 
     Tester::new(NoDisabledTests::NAME, pass, fail).test_and_snapshot();
@@ -54,7 +54,8 @@ declare_oxc_lint!(
     /// `();
     /// ```
     NoFocusedTests,
-    restriction
+    restriction,
+    fix
 );
 
 impl Rule for NoFocusedTests {
@@ -112,6 +113,8 @@ fn test() {
         ("test.each()()", None),
         ("test.each`table`()", None),
         ("test.concurrent()", None),
+        ("test.fails.only()", None),
+        ("import { it } from 'vitest'; it.skipIf(isCI)('foo', () => {})", None),
     ];
 
     let fail = vec![
@@ -135,6 +138,9 @@ fn test() {
         ("fit()", None),
         ("fit.each()()", None),
         ("fit.each`table`()", None),
+        ("import { it } from 'vitest'; it.only('foo', () => {})", None),
+        ("import { test } from 'vitest'; test.fails.only()", None),
+        ("import { describe } from 'vitest'; describe.concurrent.only()", None),
     ];
 
     let fix = vec![
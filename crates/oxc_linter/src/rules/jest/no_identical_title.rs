@@ -15,7 +15,9 @@ use oxc_span::{Atom, Span};
 use crate::{
     context::LintContext,
     rule::Rule,
-    utils::{parse_general_jest_fn_call, JestFnKind, JestGeneralFnKind},
+    utils::{
+        collect_test_fn_references, parse_general_jest_fn_call, JestFnKind, JestGeneralFnKind,
+    },
     AstNode,
 };
 
@@ -55,26 +57,21 @@ declare_oxc_lint!(
 
 impl Rule for NoIdenticalTitle {
     fn run_once(&self, ctx: &LintContext) {
-        // TODO: support detect import from "@jest/globals"
-        let references = ctx.scopes().root_unresolved_references().iter().filter(|(key, _)| {
-            DESCRIBE_NAMES.contains(&key.as_str()) || TEST_NAMES.contains(&key.as_str())
-        });
+        let names = [DESCRIBE_NAMES.as_slice(), TEST_NAMES.as_slice()].concat();
+        let reference_ids = collect_test_fn_references(ctx, &names);
         let mut title_to_span_mapping = HashMap::new();
         let mut span_to_parent_mapping = HashMap::new();
 
-        for (_, reference_ids) in references {
-            for &reference_id in reference_ids {
-                let Some((span, title, kind, parent_id)) = process_reference(reference_id, ctx)
-                else {
-                    continue;
-                };
+        for reference_id in reference_ids {
+            let Some((span, title, kind, parent_id)) = process_reference(reference_id, ctx) else {
+                continue;
+            };
 
-                span_to_parent_mapping.insert(span, parent_id);
-                title_to_span_mapping
-                    .entry(title)
-                    .and_modify(|e: &mut Vec<(JestFnKind, Span)>| e.push((kind, span)))
-                    .or_insert_with(|| vec![(kind, span)]);
-            }
+            span_to_parent_mapping.insert(span, parent_id);
+            title_to_span_mapping
+                .entry(title)
+                .and_modify(|e: &mut Vec<(JestFnKind, Span)>| e.push((kind, span)))
+                .or_insert_with(|| vec![(kind, span)]);
         }
 
         for kind_and_span in title_to_span_mapping.values() {
@@ -102,7 +99,7 @@ impl Rule for NoIdenticalTitle {
     }
 }
 
-const DESCRIBE_NAMES: [&str; 3] = ["describe", "fdescribe", "xdescribe"];
+const DESCRIBE_NAMES: [&str; 4] = ["describe", "fdescribe", "xdescribe", "suite"];
 const TEST_NAMES: [&str; 5] = ["it", "fit", "xit", "test", "xtest"];
 
 fn process_reference<'a>(
@@ -185,6 +182,14 @@ fn test() {
     use crate::tester::Tester;
 
     let pass = vec![
+        (
+            "
+              import { it } from './test-utils';
+              it('works', () => {});
+              it('works', () => {});
+            ",
+            None,
+        ),
         ("it(); it();", None),
         ("describe(); describe();", None),
         ("describe('foo', () => {}); it('foo', () => {});", None),
@@ -387,6 +392,22 @@ fn test() {
     ];
 
     let fail = vec![
+        (
+            "
+              import { it } from 'vitest';
+              it('works', () => {});
+              it('works', () => {});
+            ",
+            None,
+        ),
+        (
+            "
+              import { describe as suite } from '@jest/globals';
+              suite('foo', () => {});
+              suite('foo', () => {});
+            ",
+            None,
+        ),
         (
             "
               describe('foo', () => {
@@ -377,6 +377,7 @@ fn test() {
     use crate::tester::Tester;
 
     let pass = vec![
+        ("import { expect } from 'vitest'; expect(true).toBeDefined();", None),
         ("expect.hasAssertions", None),
         ("expect.hasAssertions()", None),
         ("expect('something').toEqual('else');", None),
@@ -492,6 +493,7 @@ fn test() {
     ];
 
     let fail = vec![
+        ("import { expect } from 'vitest'; expect(true);", None),
         ("expect().toBe(2);", None),
         ("expect().toBe(true);", None),
         ("expect().toEqual('something');", None),
@@ -0,0 +1,193 @@
+use oxc_ast::{ast::JSXOpeningElement, AstKind};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{get_element_type, get_string_literal_prop_value, has_jsx_prop, is_nullish_prop_value},
+    AstNode,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+enum AltTextDiagnostic {
+    #[error("eslint-plugin-jsx-a11y(alt-text): Missing `alt` attribute.")]
+    #[diagnostic(
+        severity(warning),
+        help("Provide the `alt` attribute, use `alt=\"\"` for decorative images.")
+    )]
+    MissingAlt(#[label] Span),
+    #[error("eslint-plugin-jsx-a11y(alt-text): Invalid `alt` value.")]
+    #[diagnostic(
+        severity(warning),
+        help("Provide a string for the `alt` attribute, use `alt=\"\"` for decorative images.")
+    )]
+    InvalidAlt(#[label] Span),
+    #[error("eslint-plugin-jsx-a11y(alt-text): Prefer `alt=\"\"` over a presentational role.")]
+    #[diagnostic(severity(warning), help("Use `alt=\"\"` instead of `role={0:?}`."))]
+    PresentationalRole(String, #[label] Span),
+    #[error("eslint-plugin-jsx-a11y(alt-text): Missing alternative text.")]
+    #[diagnostic(
+        severity(warning),
+        help("Provide one of the {1} attributes of `<{0}>` with a non empty value.")
+    )]
+    MissingText(&'static str, &'static str, #[label] Span),
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct AltText;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce that the elements which require alternative text have meaningful information
+    /// to relay back to the end user: `<img>`, `<area>`, `<input type="image">` and `<object>`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Alternative text is read by screen readers in place of the content it describes.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // Bad
+    /// <img src="foo" />
+    /// <area href="foo" />
+    /// <input type="image" />
+    /// <object />
+    ///
+    /// // Good
+    /// <img src="foo" alt="Foo eating a sandwich." />
+    /// <img src="foo" alt="" />
+    /// <area href="foo" aria-label="Foo" />
+    /// <input type="image" alt="Submit" />
+    /// <object title="Foo" />
+    /// ```
+    AltText,
+    correctness
+);
+
+impl Rule for AltText {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::JSXElement(element) = node.kind() else { return };
+        let opening = &element.opening_element;
+        match get_element_type(opening) {
+            Some("img") => check_img(opening, ctx),
+            Some("area") => {
+                if !has_any_text(opening, &["alt", "aria-label", "aria-labelledby"]) {
+                    ctx.diagnostic(AltTextDiagnostic::MissingText(
+                        "area",
+                        "`alt`, `aria-label` or `aria-labelledby`",
+                        opening.span,
+                    ));
+                }
+            }
+            Some("input") => {
+                let is_image = has_jsx_prop(opening, "type")
+                    .and_then(get_string_literal_prop_value)
+                    .is_some_and(|value| value == "image");
+                if is_image && !has_any_text(opening, &["alt", "aria-label", "aria-labelledby"]) {
+                    ctx.diagnostic(AltTextDiagnostic::MissingText(
+                        "input type=\"image\"",
+                        "`alt`, `aria-label` or `aria-labelledby`",
+                        opening.span,
+                    ));
+                }
+            }
+            Some("object") => {
+                if element.children.is_empty()
+                    && !has_any_text(opening, &["title", "aria-label", "aria-labelledby"])
+                {
+                    ctx.diagnostic(AltTextDiagnostic::MissingText(
+                        "object",
+                        "`title`, `aria-label` or `aria-labelledby`",
+                        opening.span,
+                    ));
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn check_img(opening: &JSXOpeningElement, ctx: &LintContext) {
+    if let Some(alt) = has_jsx_prop(opening, "alt") {
+        if alt.value.is_none() || is_nullish_prop_value(alt) {
+            ctx.diagnostic(AltTextDiagnostic::InvalidAlt(alt.span));
+        }
+        return;
+    }
+
+    if has_any_text(opening, &["aria-label", "aria-labelledby"]) {
+        return;
+    }
+
+    if let Some(role) = has_jsx_prop(opening, "role") {
+        if let Some(value @ ("presentation" | "none")) = get_string_literal_prop_value(role) {
+            ctx.diagnostic(AltTextDiagnostic::PresentationalRole(value.to_string(), role.span));
+            return;
+        }
+    }
+
+    ctx.diagnostic(AltTextDiagnostic::MissingAlt(opening.span));
+}
+
+/// One of `names` is set to a value which is not an empty string, `undefined` or `null`.
+fn has_any_text(opening: &JSXOpeningElement, names: &[&str]) -> bool {
+    names.iter().filter_map(|name| has_jsx_prop(opening, name)).any(|attr| {
+        attr.value.is_some()
+            && !is_nullish_prop_value(attr)
+            && get_string_literal_prop_value(attr).map_or(true, |value| !value.is_empty())
+    })
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        (r#"<img src="foo" alt="Foo eating a sandwich." />"#, None),
+        (r#"<img src="foo" alt={"Foo eating a sandwich."} />"#, None),
+        (r#"<img src="foo" alt={altText} />"#, None),
+        (r#"<img src="foo" alt={`${person} smiling`} />"#, None),
+        (r#"<img src="foo" alt="" />"#, None),
+        (r#"<img src="foo" aria-label="Foo" />"#, None),
+        (r#"<img src="foo" aria-labelledby="id" />"#, None),
+        (r#"<img alt="" role="presentation" />"#, None),
+        (r"<Img />", None),
+        (r"<Foo.img />", None),
+        (r#"<area alt="foo" />"#, None),
+        (r#"<area aria-label="foo" />"#, None),
+        (r#"<area aria-labelledby="id" />"#, None),
+        (r"<input />", None),
+        (r#"<input type="text" />"#, None),
+        (r#"<input type="image" alt="Submit" />"#, None),
+        (r#"<input type="image" aria-label="Submit" />"#, None),
+        (r#"<object title="foo" />"#, None),
+        (r#"<object aria-label="foo" />"#, None),
+        (r"<object>Foo</object>", None),
+        (r"<object><p>Foo</p></object>", None),
+    ];
+
+    let fail = vec![
+        (r#"<img src="foo" />"#, None),
+        (r"<img {...props} />", None),
+        (r"<img alt />", None),
+        (r"<img alt={undefined} />", None),
+        (r"<img alt={null} />", None),
+        (r#"<img aria-label="" />"#, None),
+        (r#"<img role="presentation" />"#, None),
+        (r#"<img role="none" />"#, None),
+        (r"<area />", None),
+        (r#"<area alt="" />"#, None),
+        (r#"<input type="image" />"#, None),
+        (r#"<input type={"image"} alt={undefined} />"#, None),
+        (r"<object />", None),
+        (r#"<object title="" />"#, None),
+    ];
+
+    Tester::new(AltText::NAME, pass, fail).test_and_snapshot();
+}
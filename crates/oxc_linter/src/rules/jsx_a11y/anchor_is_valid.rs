@@ -0,0 +1,150 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{
+        get_element_type, get_string_literal_prop_value, has_jsx_prop, has_spread_attribute,
+        is_nullish_prop_value,
+    },
+    AstNode,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+enum AnchorIsValidDiagnostic {
+    #[error("eslint-plugin-jsx-a11y(anchor-is-valid): Missing `href` attribute for the anchor.")]
+    #[diagnostic(
+        severity(warning),
+        help("Provide a valid, navigable address as the `href` value.")
+    )]
+    MissingHref(#[label] Span),
+    #[error("eslint-plugin-jsx-a11y(anchor-is-valid): Invalid `href` value for the anchor.")]
+    #[diagnostic(
+        severity(warning),
+        help("Provide a valid, navigable address as the `href` value. If you cannot provide a valid `href`, but still need the element to resemble a link, use a button and change it with appropriate styles.")
+    )]
+    InvalidHref(#[label] Span),
+    #[error("eslint-plugin-jsx-a11y(anchor-is-valid): The anchor is used as a button.")]
+    #[diagnostic(
+        severity(warning),
+        help("Anchors are meant for navigation, use a `<button>` to perform an action.")
+    )]
+    PreferButton(#[label] Span),
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct AnchorIsValid;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce that all anchors are valid, navigable elements.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// An anchor without a valid `href` is not focusable by keyboard,
+    /// cannot be opened in a new tab and is not announced as a link by screen readers.
+    /// Anchors used with an `onClick` handler and no address should be buttons.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // Bad
+    /// <a>Foo</a>
+    /// <a href="#">Foo</a>
+    /// <a href="javascript:void(0)">Foo</a>
+    /// <a onClick={foo}>Foo</a>
+    ///
+    /// // Good
+    /// <a href="https://github.com">GitHub</a>
+    /// <a href="#section">Section</a>
+    /// <button onClick={foo}>Foo</button>
+    /// ```
+    AnchorIsValid,
+    correctness
+);
+
+impl Rule for AnchorIsValid {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::JSXOpeningElement(element) = node.kind() else { return };
+        if get_element_type(element) != Some("a") {
+            return;
+        }
+
+        let has_on_click = has_jsx_prop(element, "onClick").is_some();
+        let Some(href) = has_jsx_prop(element, "href") else {
+            // The `href` may be passed along with the spread props.
+            if has_spread_attribute(element) {
+                return;
+            }
+            if has_on_click {
+                ctx.diagnostic(AnchorIsValidDiagnostic::PreferButton(element.span));
+            } else {
+                ctx.diagnostic(AnchorIsValidDiagnostic::MissingHref(element.span));
+            }
+            return;
+        };
+
+        let is_invalid = href.value.is_none()
+            || is_nullish_prop_value(href)
+            || get_string_literal_prop_value(href).is_some_and(is_invalid_href);
+        if !is_invalid {
+            return;
+        }
+
+        if has_on_click {
+            ctx.diagnostic(AnchorIsValidDiagnostic::PreferButton(href.span));
+        } else {
+            ctx.diagnostic(AnchorIsValidDiagnostic::InvalidHref(href.span));
+        }
+    }
+}
+
+/// `""`, `"#"` and `"javascript:void(0)"` do not navigate anywhere.
+fn is_invalid_href(href: &str) -> bool {
+    let href = href.trim();
+    href.is_empty() || href == "#" || href.to_ascii_lowercase().starts_with("javascript:")
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        (r"<Anchor />", None),
+        (r"<a {...props} />", None),
+        (r#"<a href="foo" />"#, None),
+        (r#"<a href="https://github.com">GitHub</a>"#, None),
+        (r##"<a href="#section" />"##, None),
+        (r#"<a href="/foo/bar" />"#, None),
+        (r"<a href={someValidPath} />", None),
+        (r"<a href={`${foo}/bar`} />", None),
+        (r#"<a href="foo" onClick={foo} />"#, None),
+        (r"<a href={foo} onClick={foo} />", None),
+        (r##"<div href="#" />"##, None),
+    ];
+
+    let fail = vec![
+        (r"<a />", None),
+        (r"<a>Foo</a>", None),
+        (r"<a href />", None),
+        (r"<a href={undefined} />", None),
+        (r"<a href={null} />", None),
+        (r#"<a href="" />"#, None),
+        (r##"<a href="#" />"##, None),
+        (r##"<a href={"#"} />"##, None),
+        (r#"<a href="javascript:void(0)" />"#, None),
+        (r"<a href={`javascript:void(0)`} />", None),
+        (r"<a href={void 0} />", None),
+        (r"<a onClick={foo} />", None),
+        (r##"<a href="#" onClick={foo} />"##, None),
+        (r#"<a href="javascript:void(0)" onClick={foo} />"#, None),
+    ];
+
+    Tester::new(AnchorIsValid::NAME, pass, fail).test_and_snapshot();
+}
@@ -0,0 +1,86 @@
+use oxc_ast::{
+    ast::{JSXAttributeItem, JSXAttributeName},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{Atom, Span};
+
+use crate::{context::LintContext, rule::Rule, utils::ARIA_PROPERTIES, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint-plugin-jsx-a11y(aria-props): {0:?} is not a valid ARIA attribute.")]
+#[diagnostic(severity(warning), help("Check the spelling, ARIA attributes are lowercase."))]
+struct AriaPropsDiagnostic(Atom, #[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct AriaProps;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce that the elements do not use invalid `aria-*` attributes.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Assistive technologies ignore the unknown ARIA attributes,
+    /// a misspelled attribute does not convey the intended information.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // Bad
+    /// <input aria-labeledby="address_label" />
+    /// <div aria-hiden="true" />
+    ///
+    /// // Good
+    /// <input aria-labelledby="address_label" />
+    /// <div aria-hidden="true" />
+    /// ```
+    AriaProps,
+    correctness
+);
+
+impl Rule for AriaProps {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::JSXAttributeItem(JSXAttributeItem::Attribute(attr)) = node.kind() else {
+            return;
+        };
+        let JSXAttributeName::Identifier(ident) = &attr.name else { return };
+        if ident.name.starts_with("aria-") && !ARIA_PROPERTIES.contains(ident.name.as_str()) {
+            ctx.diagnostic(AriaPropsDiagnostic(ident.name.clone(), ident.span));
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        (r"<div />", None),
+        (r"<div></div>", None),
+        (r#"<div aria="wee"></div>"#, None),
+        (r#"<div abcARIAdef="true"></div>"#, None),
+        (r#"<div fooaria-foobar="true"></div>"#, None),
+        (r#"<div fooaria-hidden="true"></div>"#, None),
+        (r"<Bar baz />", None),
+        (r#"<input aria-labelledby="address_label" />"#, None),
+        (r#"<div aria-hidden="true" />"#, None),
+        (r"<div aria-label={label} />", None),
+        (r"<div aria-errormessage={message} />", None),
+        (r"<div {...props} />", None),
+    ];
+
+    let fail = vec![
+        (r#"<div aria-="foobar" />"#, None),
+        (r#"<div aria-labeledby="foobar" />"#, None),
+        (r#"<div aria-skldjfaria-klajsd="foobar" />"#, None),
+        (r#"<div aria-Hidden="true" />"#, None),
+        (r"<Foo aria-foo={bar} />", None),
+    ];
+
+    Tester::new(AriaProps::NAME, pass, fail).test_and_snapshot();
+}
@@ -0,0 +1,140 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{get_string_literal_prop_value, has_jsx_prop, is_dom_element, ARIA_ROLES},
+    AstNode,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint-plugin-jsx-a11y(aria-role): Elements with ARIA roles must use a valid, non-abstract ARIA role.")]
+#[diagnostic(severity(warning), help("{0:?} is not a valid ARIA role."))]
+struct AriaRoleDiagnostic(String, #[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct AriaRole {
+    /// Only check the DOM elements, e.g. `<div>` but not `<MyComponent>`.
+    ignore_non_dom: bool,
+    /// Custom roles which are allowed in addition to the ARIA roles.
+    allowed_invalid_roles: Vec<String>,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce that the elements with ARIA roles use a valid, non-abstract ARIA role.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Assistive technologies ignore the unknown roles, so the element is not announced as intended.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // Bad
+    /// <div role="datepicker" />
+    /// <div role="range" />
+    /// <div role="" />
+    ///
+    /// // Good
+    /// <div role="button" />
+    /// <div role="tabpanel row" />
+    /// <div role={role} />
+    /// ```
+    AriaRole,
+    correctness
+);
+
+impl Rule for AriaRole {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let config = value.get(0);
+        Self {
+            ignore_non_dom: config
+                .and_then(|config| config.get("ignoreNonDOM"))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or_default(),
+            allowed_invalid_roles: config
+                .and_then(|config| config.get("allowedInvalidRoles"))
+                .and_then(serde_json::Value::as_array)
+                .map(|roles| {
+                    roles
+                        .iter()
+                        .filter_map(serde_json::Value::as_str)
+                        .map(ToString::to_string)
+                        .collect()
+                })
+                .unwrap_or_default(),
+        }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::JSXOpeningElement(element) = node.kind() else { return };
+        if self.ignore_non_dom && !is_dom_element(element) {
+            return;
+        }
+        let Some(attr) = has_jsx_prop(element, "role") else { return };
+        let Some(value) = get_string_literal_prop_value(attr) else {
+            if attr.value.is_none() {
+                ctx.diagnostic(AriaRoleDiagnostic(String::new(), attr.span));
+            }
+            return;
+        };
+
+        let mut roles = value.split_ascii_whitespace().peekable();
+        if roles.peek().is_none() {
+            ctx.diagnostic(AriaRoleDiagnostic(value.to_string(), attr.span));
+            return;
+        }
+        for role in roles {
+            if !ARIA_ROLES.contains_key(role)
+                && !self.allowed_invalid_roles.iter().any(|allowed| allowed == role)
+            {
+                ctx.diagnostic(AriaRoleDiagnostic(role.to_string(), attr.span));
+            }
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        (r"<div />", None),
+        (r"<div></div>", None),
+        (r"<div role={role} />", None),
+        (r"<div role={role || 'button'} />", None),
+        (r#"<div role="button" />"#, None),
+        (r#"<div role="tabpanel row" />"#, None),
+        (r#"<div role="switch" />"#, None),
+        (r#"<div role="doc-abstract" />"#, None),
+        (r#"<div role="graphics-document" />"#, None),
+        (r#"<div role="doc-appendix doc-bibliography" />"#, None),
+        (r#"<Foo role="bar" />"#, Some(json!([{ "ignoreNonDOM": true }]))),
+        (
+            r#"<div role="invalid-role" />"#,
+            Some(json!([{ "allowedInvalidRoles": ["invalid-role"] }])),
+        ),
+    ];
+
+    let fail = vec![
+        (r#"<div role="datepicker" />"#, None),
+        (r#"<div role="range" />"#, None),
+        (r#"<div role="Button" />"#, None),
+        (r#"<div role="tabpanel row foobar" />"#, None),
+        (r#"<div role="" />"#, None),
+        (r"<div role />", None),
+        (r#"<div role={"foobar"} />"#, None),
+        (r#"<Foo role="bar" />"#, None),
+        (r#"<div role="bar" />"#, Some(json!([{ "ignoreNonDOM": true }]))),
+    ];
+
+    Tester::new(AriaRole::NAME, pass, fail).test_and_snapshot();
+}
@@ -0,0 +1,104 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{has_jsx_prop, is_dom_element, is_false_prop_value},
+    AstNode,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint-plugin-jsx-a11y(no-autofocus): The `autoFocus` prop should not be used.")]
+#[diagnostic(
+    severity(warning),
+    help("Remove the `autoFocus` prop, it reduces the usability and accessibility for users.")
+)]
+struct NoAutofocusDiagnostic(#[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoAutofocus {
+    /// Only check the DOM elements, e.g. `<div>` but not `<MyComponent>`.
+    ignore_non_dom: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce that the `autoFocus` prop is not used on elements.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Autofocusing elements can cause usability issues for sighted and non-sighted users alike.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // Bad
+    /// <div autoFocus />
+    /// <div autoFocus="true" />
+    /// <input autoFocus={true} />
+    ///
+    /// // Good
+    /// <div />
+    /// <input autoFocus={false} />
+    /// ```
+    NoAutofocus,
+    correctness
+);
+
+impl Rule for NoAutofocus {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self {
+            ignore_non_dom: value
+                .get(0)
+                .and_then(|config| config.get("ignoreNonDOM"))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or_default(),
+        }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::JSXOpeningElement(element) = node.kind() else { return };
+        if self.ignore_non_dom && !is_dom_element(element) {
+            return;
+        }
+        if let Some(attr) = has_jsx_prop(element, "autoFocus") {
+            if !is_false_prop_value(attr) {
+                ctx.diagnostic(NoAutofocusDiagnostic(attr.span));
+            }
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        (r"<div />", None),
+        (r"<input />", None),
+        (r"<input autoFocus={false} />", None),
+        (r#"<div focus="true" />"#, None),
+        (r"<Foo autoFocus />", Some(json!([{ "ignoreNonDOM": true }]))),
+        (r"<Foo.Bar autoFocus />", Some(json!([{ "ignoreNonDOM": true }]))),
+    ];
+
+    let fail = vec![
+        (r"<div autoFocus />", None),
+        (r"<div autofocus />", None),
+        (r#"<div autoFocus="true" />"#, None),
+        (r#"<div autoFocus="false" />"#, None),
+        (r"<input autoFocus={true} />", None),
+        (r"<input autoFocus={undefined} />", None),
+        (r"<Foo autoFocus />", None),
+        (r"<div autoFocus />", Some(json!([{ "ignoreNonDOM": true }]))),
+    ];
+
+    Tester::new(NoAutofocus::NAME, pass, fail).test_and_snapshot();
+}
@@ -0,0 +1,107 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{get_string_literal_prop_value, has_jsx_prop, is_dom_element, ARIA_ROLES},
+    AstNode,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint-plugin-jsx-a11y(role-has-required-aria-props): The {0:?} role is missing the required ARIA attributes.")]
+#[diagnostic(severity(warning), help("Add {1} to the element."))]
+struct RoleHasRequiredAriaPropsDiagnostic(String, String, #[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct RoleHasRequiredAriaProps;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce that the DOM elements with an explicit ARIA role have all the ARIA attributes required by the role.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// The required attributes describe the state of the element to assistive technologies,
+    /// e.g. whether a `checkbox` is checked.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // Bad
+    /// <span role="checkbox" />
+    /// <div role="slider" />
+    ///
+    /// // Good
+    /// <span role="checkbox" aria-checked="false" />
+    /// <div role="slider" aria-valuenow={value} />
+    /// ```
+    RoleHasRequiredAriaProps,
+    correctness
+);
+
+impl Rule for RoleHasRequiredAriaProps {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::JSXOpeningElement(element) = node.kind() else { return };
+        if !is_dom_element(element) {
+            return;
+        }
+        let Some(attr) = has_jsx_prop(element, "role") else { return };
+        let Some(value) = get_string_literal_prop_value(attr) else { return };
+
+        for role in value.split_ascii_whitespace() {
+            let Some(required_props) = ARIA_ROLES.get(role) else { continue };
+            let missing_props = required_props
+                .iter()
+                .filter(|prop| has_jsx_prop(element, prop).is_none())
+                .map(|prop| format!("`{prop}`"))
+                .collect::<Vec<_>>();
+            if !missing_props.is_empty() {
+                ctx.diagnostic(RoleHasRequiredAriaPropsDiagnostic(
+                    role.to_string(),
+                    missing_props.join(", "),
+                    attr.span,
+                ));
+            }
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        (r"<Bar baz />", None),
+        (r#"<MyComponent role="combobox" />"#, None),
+        (r"<div />", None),
+        (r#"<div role="button" />"#, None),
+        (r"<div role={role} />", None),
+        (r#"<div role="unknown" />"#, None),
+        (r#"<span role="checkbox" aria-checked="false" />"#, None),
+        (r#"<span role="switch" aria-checked={checked} />"#, None),
+        (r#"<div role="combobox" aria-expanded="false" aria-controls="listbox" />"#, None),
+        (r#"<div role="heading" aria-level="2" />"#, None),
+        (r#"<div role="slider" aria-valuenow={value} />"#, None),
+        (r#"<div role="checkbox radio" aria-checked="true" />"#, None),
+    ];
+
+    let fail = vec![
+        (r#"<span role="checkbox" />"#, None),
+        (r#"<span role="switch" />"#, None),
+        (r#"<div role="combobox" />"#, None),
+        (r#"<div role="combobox" aria-expanded="false" />"#, None),
+        (r#"<div role="heading" />"#, None),
+        (r#"<div role="slider" />"#, None),
+        (r#"<div role="scrollbar" aria-valuenow="10" />"#, None),
+        (r#"<div role={"radio"} />"#, None),
+        (r#"<div role="button checkbox" />"#, None),
+    ];
+
+    Tester::new(RoleHasRequiredAriaProps::NAME, pass, fail).test_and_snapshot();
+}
@@ -0,0 +1,121 @@
+use oxc_ast::{ast::Expression, AstKind};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint-plugin-promise(prefer-await-to-then): Prefer `await` to `then()`/`catch()`/`finally()`.")]
+#[diagnostic(severity(warning))]
+struct PreferAwaitToThenDiagnostic(#[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct PreferAwaitToThen {
+    /// Also report `catch()` and `finally()`.
+    strict: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Prefer `await` to `then()`/`catch()`/`finally()` for reading Promise values.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// `async`/`await` is easier to read and to reason about than the nested callbacks,
+    /// and the errors are handled by the same `try`/`catch` as the synchronous code.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // Bad
+    /// function foo() {
+    ///   return bar().then((value) => baz(value));
+    /// }
+    ///
+    /// // Good
+    /// async function foo() {
+    ///   const value = await bar();
+    ///   return baz(value);
+    /// }
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// `strict`, `false` by default, also reports `catch()` and `finally()`.
+    PreferAwaitToThen,
+    style
+);
+
+impl Rule for PreferAwaitToThen {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let strict = value
+            .get(0)
+            .and_then(|config| config.get("strict"))
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or_default();
+        Self { strict }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call_expr) = node.kind() else { return };
+        let Expression::MemberExpression(member_expr) = &call_expr.callee else { return };
+        let Some((span, name)) = member_expr.static_property_info() else { return };
+        if !(name == "then" || (self.strict && matches!(name, "catch" | "finally"))) {
+            return;
+        }
+        if is_in_function_outside_of_yield_or_await(node, ctx) {
+            ctx.diagnostic(PreferAwaitToThenDiagnostic(span));
+        }
+    }
+}
+
+/// The top level code may not be able to use `await`,
+/// and the calls wrapped in `await` or `yield` are already sequenced.
+fn is_in_function_outside_of_yield_or_await<'a>(node: &AstNode<'a>, ctx: &LintContext<'a>) -> bool {
+    let mut current = node;
+    while let Some(parent) = ctx.nodes().parent_node(current.id()) {
+        match parent.kind() {
+            AstKind::AwaitExpression(_) | AstKind::YieldExpression(_) => return false,
+            AstKind::Function(_) | AstKind::ArrowExpression(_) => return true,
+            _ => current = parent,
+        }
+    }
+    false
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("async function hi() { await thing() }", None),
+        ("async function hi() { await thing().then() }", None),
+        ("async function hi() { await thing().catch() }", None),
+        ("function * hi() { yield thing().then() }", None),
+        ("a = async () => (await something())", None),
+        ("something().then(async () => await somethingElse())", None),
+        ("function foo() { hey.somethingElse(x => {}) }", None),
+        ("function foo() { hey.then }", None),
+        ("function foo() { hey[then]() }", None),
+        ("if (foo) { hey.then(x => {}) }", None),
+        ("function foo() { somePromise.catch(handleError) }", None),
+        ("function foo() { somePromise.finally(cleanUp) }", None),
+    ];
+
+    let fail = vec![
+        ("function foo() { hey.then(x => {}) }", None),
+        ("function foo() { hey.then(x => {}).then(y => {}) }", None),
+        ("async function a() { hey.then(x => {}) }", None),
+        ("const foo = () => hey.then(x => {})", None),
+        ("function foo() { hey?.then(x => {}) }", None),
+        ("function foo() { somePromise.catch(handleError) }", Some(json!([{ "strict": true }]))),
+        ("function foo() { somePromise.finally(cleanUp) }", Some(json!([{ "strict": true }]))),
+    ];
+
+    Tester::new(PreferAwaitToThen::NAME, pass, fail).test_and_snapshot();
+}
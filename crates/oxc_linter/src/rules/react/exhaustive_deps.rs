@@ -0,0 +1,473 @@
+use oxc_ast::{
+    ast::{
+        Argument, ArrayExpressionElement, BindingPatternKind, ChainElement, Expression,
+        MemberExpression,
+    },
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::SymbolId;
+use oxc_span::{GetSpan, Span};
+use regex::Regex;
+use rustc_hash::FxHashSet;
+use serde_json::Value;
+
+use crate::{
+    context::LintContext, fixer::Fix, rule::Rule, utils::get_react_hook_name, AstNode, Suggestion,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+enum ExhaustiveDepsDiagnostic {
+    #[error("eslint-plugin-react-hooks(exhaustive-deps): React Hook {0} has {1}")]
+    Dependencies(String, String, #[label] Span),
+    #[error("eslint-plugin-react-hooks(exhaustive-deps): React Hook {0} does nothing when called with only one argument. Did you forget to pass an array of dependencies?")]
+    MissingDependencyArray(String, #[label] Span),
+    #[error("eslint-plugin-react-hooks(exhaustive-deps): React Hook {0} was passed a dependency list that is not an array literal. This means we can't statically verify whether you've passed the correct dependencies.")]
+    NonArrayDependencies(String, #[label] Span),
+    #[error("eslint-plugin-react-hooks(exhaustive-deps): React Hook {0} has a complex expression in the dependency array. Extract it to a separate variable so it can be statically checked.")]
+    ComplexDependency(String, #[label] Span),
+    #[error("eslint-plugin-react-hooks(exhaustive-deps): React Hook {0} received a function whose dependencies are unknown. Pass an inline function instead.")]
+    UnknownCallback(String, #[label] Span),
+    #[error("eslint-plugin-react-hooks(exhaustive-deps): Effect callbacks are synchronous to prevent race conditions.")]
+    #[diagnostic(help("Put the async function inside the effect and call it from there."))]
+    AsyncEffect(#[label] Span),
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct ExhaustiveDeps {
+    /// Custom effect hooks whose dependencies are checked, e.g. `(useMyEffect|useOtherEffect)`.
+    additional_hooks: Option<Regex>,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Verifies the dependency arrays of `useEffect`, `useLayoutEffect`, `useInsertionEffect`,
+    /// `useCallback`, `useMemo` and `useImperativeHandle`: every value of the component used by the
+    /// callback is listed, and nothing else.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A missing dependency makes the callback read stale props and state, an unnecessary one
+    /// reruns the effect or recomputes the value for nothing.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // Bad
+    /// function Component({ id }) {
+    ///   useEffect(() => {
+    ///     fetch(id);
+    ///   }, []);
+    /// }
+    ///
+    /// // Good
+    /// function Component({ id }) {
+    ///   useEffect(() => {
+    ///     fetch(id);
+    ///   }, [id]);
+    /// }
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// `additionalHooks`: a regex of custom effect hooks to check, taking a callback as their first
+    /// argument and a dependency array as their second.
+    ExhaustiveDeps,
+    nursery,
+    suggestion
+);
+
+impl Rule for ExhaustiveDeps {
+    fn from_configuration(value: Value) -> Self {
+        let additional_hooks = value
+            .get(0)
+            .and_then(|config| config.get("additionalHooks"))
+            .and_then(Value::as_str)
+            .and_then(|pattern| Regex::new(pattern).ok());
+        Self { additional_hooks }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call_expr) = node.kind() else { return };
+        let Some(hook_name) = get_react_hook_name(call_expr) else { return };
+        let Some(callback_index) = self.get_callback_index(hook_name) else { return };
+        let is_effect = !matches!(hook_name, "useCallback" | "useMemo");
+        let name = call_expr.callee.span().source_text(ctx.source_text()).to_string();
+
+        let Some(Argument::Expression(callback)) = call_expr.arguments.get(callback_index) else {
+            return;
+        };
+        let callback = callback.without_parenthesized();
+        let dependencies = match call_expr.arguments.get(callback_index + 1) {
+            Some(Argument::Expression(Expression::ArrayExpression(array))) => array,
+            Some(argument) => {
+                ctx.diagnostic(ExhaustiveDepsDiagnostic::NonArrayDependencies(
+                    name,
+                    argument.span(),
+                ));
+                return;
+            }
+            None => {
+                // Effects without dependencies run after every render.
+                if !is_effect {
+                    ctx.diagnostic(ExhaustiveDepsDiagnostic::MissingDependencyArray(
+                        name,
+                        call_expr.callee.span(),
+                    ));
+                }
+                return;
+            }
+        };
+
+        let Some(component) = ctx
+            .nodes()
+            .iter_parents(node.id())
+            .find(|node| matches!(node.kind(), AstKind::Function(_) | AstKind::ArrowExpression(_)))
+        else {
+            return;
+        };
+        let component_span = component.kind().span();
+        let component_id_span = match component.kind() {
+            AstKind::Function(func) => func.id.as_ref().map(|id| id.span),
+            _ => None,
+        };
+        let is_in_component = |symbol_id: SymbolId| {
+            let span = ctx.symbols().get_span(symbol_id);
+            contains(component_span, span) && Some(span) != component_id_span
+        };
+
+        let required = match callback {
+            Expression::ArrowExpression(arrow) => {
+                if arrow.r#async && is_effect {
+                    ctx.diagnostic(ExhaustiveDepsDiagnostic::AsyncEffect(arrow.span));
+                }
+                collect_required_dependencies(arrow.span, &is_in_component, ctx)
+            }
+            Expression::FunctionExpression(func) => {
+                if func.r#async && is_effect {
+                    ctx.diagnostic(ExhaustiveDepsDiagnostic::AsyncEffect(func.span));
+                }
+                collect_required_dependencies(func.span, &is_in_component, ctx)
+            }
+            // The identifier of a function declared in the component is its own dependency.
+            Expression::Identifier(ident) => {
+                let symbol_id = ident
+                    .reference_id
+                    .get()
+                    .and_then(|reference_id| ctx.symbols().get_reference(reference_id).symbol_id());
+                match symbol_id {
+                    Some(symbol_id) if is_in_component(symbol_id) => {
+                        FxHashSet::from_iter([ident.name.to_string()])
+                    }
+                    _ => FxHashSet::default(),
+                }
+            }
+            _ => {
+                ctx.diagnostic(ExhaustiveDepsDiagnostic::UnknownCallback(name, callback.span()));
+                return;
+            }
+        };
+
+        let mut declared: Vec<String> = vec![];
+        let mut missing: Vec<String> = vec![];
+        let mut unnecessary: Vec<String> = vec![];
+        let mut duplicate: Vec<String> = vec![];
+        for element in &dependencies.elements {
+            let ArrayExpressionElement::Expression(expr) = element else {
+                ctx.diagnostic(ExhaustiveDepsDiagnostic::ComplexDependency(
+                    name.clone(),
+                    element_span(element),
+                ));
+                continue;
+            };
+            let Some((path, root)) = get_dependency_path(expr) else {
+                ctx.diagnostic(ExhaustiveDepsDiagnostic::ComplexDependency(
+                    name.clone(),
+                    expr.span(),
+                ));
+                continue;
+            };
+            let root_symbol_id =
+                root.and_then(|reference_id| ctx.symbols().get_reference(reference_id).symbol_id());
+            if declared.contains(&path) {
+                duplicate.push(path);
+                continue;
+            }
+            let is_external = !root_symbol_id.is_some_and(is_in_component);
+            let is_used = required.iter().any(|dependency| is_path_prefix(&path, dependency));
+            if is_external || (!is_effect && !is_used) {
+                unnecessary.push(path);
+                continue;
+            }
+            declared.push(path);
+        }
+
+        for dependency in &required {
+            // `a` is not missing when `a.b` is required too, and neither when `a` is declared.
+            let is_covered = required
+                .iter()
+                .any(|other| other != dependency && is_path_prefix(other, dependency))
+                || declared.iter().any(|path| is_path_prefix(path, dependency));
+            if !is_covered {
+                missing.push(dependency.clone());
+            }
+        }
+
+        let problem = get_problem(&mut missing, "a", "missing", "include")
+            .or_else(|| get_problem(&mut unnecessary, "an", "unnecessary", "exclude"))
+            .or_else(|| get_problem(&mut duplicate, "a", "duplicate", "omit"));
+        let Some(problem) = problem else { return };
+
+        let suggested = declared.iter().chain(missing.iter()).cloned().collect::<Vec<_>>();
+        let suggested = format!("[{}]", suggested.join(", "));
+        ctx.diagnostic_with_suggestions(
+            ExhaustiveDepsDiagnostic::Dependencies(name, problem, dependencies.span),
+            vec![Suggestion::new(
+                format!("Update the dependencies array to be: {suggested}"),
+                Fix::new(suggested, dependencies.span),
+            )],
+        );
+    }
+}
+
+impl ExhaustiveDeps {
+    /// The index of the callback argument of the hooks with dependencies.
+    fn get_callback_index(&self, hook_name: &str) -> Option<usize> {
+        match hook_name {
+            "useEffect" | "useLayoutEffect" | "useInsertionEffect" | "useCallback" | "useMemo" => {
+                Some(0)
+            }
+            "useImperativeHandle" => Some(1),
+            _ => self
+                .additional_hooks
+                .as_ref()
+                .is_some_and(|regex| regex.is_match(hook_name))
+                .then_some(0),
+        }
+    }
+}
+
+fn contains(outer: Span, inner: Span) -> bool {
+    outer.start <= inner.start && inner.end <= outer.end
+}
+
+fn element_span(element: &ArrayExpressionElement) -> Span {
+    match element {
+        ArrayExpressionElement::SpreadElement(spread) => spread.span,
+        ArrayExpressionElement::Expression(expr) => expr.span(),
+        ArrayExpressionElement::Elision(span) => *span,
+    }
+}
+
+/// `a` and `a.b` are prefixes of `a.b`, `a.bc` is not.
+fn is_path_prefix(prefix: &str, path: &str) -> bool {
+    path.strip_prefix(prefix).is_some_and(|rest| rest.is_empty() || rest.starts_with('.'))
+}
+
+/// The path of a declared dependency, `a` or `a.b.c`, with the reference of its root identifier.
+fn get_dependency_path(expr: &Expression) -> Option<(String, Option<oxc_semantic::ReferenceId>)> {
+    match expr.without_parenthesized() {
+        Expression::Identifier(ident) => Some((ident.name.to_string(), ident.reference_id.get())),
+        Expression::MemberExpression(member_expr) => get_member_path(member_expr),
+        Expression::ChainExpression(chain) => match &chain.expression {
+            ChainElement::MemberExpression(member_expr) => get_member_path(member_expr),
+            ChainElement::CallExpression(_) => None,
+        },
+        _ => None,
+    }
+}
+
+fn get_member_path(
+    member_expr: &MemberExpression,
+) -> Option<(String, Option<oxc_semantic::ReferenceId>)> {
+    let MemberExpression::StaticMemberExpression(member_expr) = member_expr else { return None };
+    let (object, reference_id) = get_dependency_path(&member_expr.object)?;
+    Some((format!("{object}.{}", member_expr.property.name), reference_id))
+}
+
+/// The paths of the values of the component read by the callback at `callback_span`.
+fn collect_required_dependencies(
+    callback_span: Span,
+    is_in_component: &dyn Fn(SymbolId) -> bool,
+    ctx: &LintContext,
+) -> FxHashSet<String> {
+    let mut dependencies = FxHashSet::default();
+    for node in ctx.nodes().iter() {
+        let AstKind::IdentifierReference(ident) = node.kind() else { continue };
+        if !contains(callback_span, ident.span) {
+            continue;
+        }
+        let Some(reference_id) = ident.reference_id.get() else { continue };
+        let reference = ctx.symbols().get_reference(reference_id);
+        let Some(symbol_id) = reference.symbol_id() else { continue };
+        if reference.is_write() && !reference.is_read() {
+            continue;
+        }
+        let symbol_span = ctx.symbols().get_span(symbol_id);
+        if contains(callback_span, symbol_span)
+            || !is_in_component(symbol_id)
+            || is_stable_value(symbol_id, ctx)
+        {
+            continue;
+        }
+        dependencies.insert(get_reference_path(node, ident.name.as_str(), ctx));
+    }
+    dependencies
+}
+
+/// Extend a reference to the static member expression reading it: `a` -> `a.b.c` for `a.b.c`,
+/// `a` for `a.b()` and `a.current`.
+fn get_reference_path<'a>(node: &AstNode<'a>, name: &str, ctx: &LintContext<'a>) -> String {
+    let mut path = name.to_string();
+    let mut child = node;
+    for parent in ctx.nodes().iter_parents(node.id()).skip(1) {
+        let AstKind::MemberExpression(MemberExpression::StaticMemberExpression(member_expr)) =
+            parent.kind()
+        else {
+            break;
+        };
+        if member_expr.object.span() != child.kind().span() {
+            break;
+        }
+        let property = member_expr.property.name.as_str();
+        if property == "current" {
+            break;
+        }
+        let is_callee = matches!(
+            ctx.nodes().parent_kind(parent.id()),
+            Some(AstKind::CallExpression(call_expr)) if call_expr.callee.span() == member_expr.span
+        );
+        if is_callee {
+            break;
+        }
+        path.push('.');
+        path.push_str(property);
+        child = parent;
+    }
+    path
+}
+
+/// The setters of `useState`, `useReducer` and `useTransition` and the `useRef` objects declared
+/// with `const` are the same across renders.
+fn is_stable_value(symbol_id: SymbolId, ctx: &LintContext) -> bool {
+    let declaration = ctx.symbols().get_declaration(symbol_id);
+    let Some(AstKind::VariableDeclarator(decl)) = ctx
+        .nodes()
+        .ancestors(declaration)
+        .map(|node_id| ctx.nodes().kind(node_id))
+        .find(|kind| matches!(kind, AstKind::VariableDeclarator(_)))
+    else {
+        return false;
+    };
+    if !decl.kind.is_const() {
+        return false;
+    }
+    let Some(Expression::CallExpression(init)) = &decl.init else { return false };
+    match (get_react_hook_name(init), &decl.id.kind) {
+        (Some("useRef"), BindingPatternKind::BindingIdentifier(ident)) => {
+            ident.symbol_id.get() == Some(symbol_id)
+        }
+        (
+            Some("useState" | "useReducer" | "useTransition"),
+            BindingPatternKind::ArrayPattern(pattern),
+        ) => pattern.elements.get(1).and_then(Option::as_ref).is_some_and(|element| {
+            matches!(&element.kind, BindingPatternKind::BindingIdentifier(ident) if ident.symbol_id.get() == Some(symbol_id))
+        }),
+        _ => false,
+    }
+}
+
+/// `a missing dependency: 'a'. Either include it or remove the dependency array.`
+fn get_problem(
+    dependencies: &mut Vec<String>,
+    article: &str,
+    label: &str,
+    verb: &str,
+) -> Option<String> {
+    if dependencies.is_empty() {
+        return None;
+    }
+    dependencies.sort_unstable();
+    let names = dependencies.iter().map(|name| format!("'{name}'")).collect::<Vec<_>>();
+    let list = match names.as_slice() {
+        [name] => name.clone(),
+        [first, second] => format!("{first} and {second}"),
+        [rest @ .., last] => format!("{}, and {last}", rest.join(", ")),
+        [] => unreachable!(),
+    };
+    Some(if dependencies.len() == 1 {
+        format!("{article} {label} dependency: {list}. Either {verb} it or remove the dependency array.")
+    } else {
+        format!("{label} dependencies: {list}. Either {verb} them or remove the dependency array.")
+    })
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("function MyComponent() { const local = {}; useEffect(() => { console.log(local); }); }", None),
+        ("function MyComponent() { useEffect(() => { const local = {}; console.log(local); }, []); }", None),
+        ("function MyComponent() { const local = {}; useEffect(() => { console.log(local); }, [local]); }", None),
+        ("const local = {}; function MyComponent() { useEffect(() => { console.log(local); }, []); }", None),
+        ("function MyComponent(props) { useEffect(() => { console.log(props.foo); }, [props.foo]); }", None),
+        ("function MyComponent(props) { useEffect(() => { console.log(props.foo); }, [props]); }", None),
+        ("function MyComponent(props) { useEffect(() => { console.log(props.foo?.bar); }, [props.foo]); }", None),
+        ("function MyComponent(props) { useEffect(() => { console.log(props.foo, props.bar); }, [props.foo, props.bar]); }", None),
+        ("function MyComponent(props) { useEffect(() => { props.onChange(); }, [props]); }", None),
+        ("function MyComponent(props) { const local = {}; useEffect(() => { console.log(local); }, [local, props]); }", None),
+        ("function MyComponent() { const [state, setState] = useState(); useEffect(() => { setState(1); }, []); }", None),
+        ("function MyComponent() { const [state, dispatch] = React.useReducer(); useEffect(() => { dispatch(1); }, []); }", None),
+        ("function MyComponent() { const ref = useRef(); useEffect(() => { ref.current = 1; }, []); }", None),
+        ("function MyComponent() { const [state, setState] = useState(); const fn = useCallback(() => { setState(state + 1); }, [state]); }", None),
+        ("function MyComponent({ a }) { const value = useMemo(() => a * 2, [a]); }", None),
+        ("function MyComponent({ a }, ref) { useImperativeHandle(ref, () => ({ a }), [a]); }", None),
+        ("function MyComponent() { let local; useEffect(() => { local = 1; }, []); }", None),
+        ("function MyComponent() { function fn() {} useEffect(fn, [fn]); }", None),
+        ("function MyComponent({ a }) { useCustomEffect(() => { console.log(a); }, []); }", None),
+        ("function MyComponent({ a }) { useCustomEffect(() => { console.log(a); }, [a]); }", Some(json!([{ "additionalHooks": "useCustomEffect" }]))),
+    ];
+
+    let fail = vec![
+        ("function MyComponent() { const local = {}; useEffect(() => { console.log(local); }, []); }", None),
+        ("function MyComponent(props) { useEffect(() => { console.log(props.foo); }, []); }", None),
+        ("function MyComponent(props) { useEffect(() => { console.log(props.foo.bar); }, [props.foo.bar.baz]); }", None),
+        ("function MyComponent({ a, b, c }) { useEffect(() => { console.log(a, b, c); }, []); }", None),
+        ("function MyComponent() { const local = {}; useEffect(() => { console.log(local); }, [local, local]); }", None),
+        ("const local = {}; function MyComponent() { useEffect(() => { console.log(local); }, [local]); }", None),
+        ("function MyComponent() { useEffect(() => {}, [window]); }", None),
+        ("function MyComponent({ a, b }) { const fn = useCallback(() => { console.log(a); }, [a, b]); }", None),
+        ("function MyComponent() { let [state, setState] = useState(); useEffect(() => { setState(1); }, []); }", None),
+        ("function MyComponent({ a }) { const value = useMemo(() => a * 2); }", None),
+        ("function MyComponent({ a }) { useEffect(() => { console.log(a); }, deps); }", None),
+        ("function MyComponent({ a }) { useEffect(() => { console.log(a); }, [a.b()]); }", None),
+        ("function MyComponent({ a }) { const fn = useCallback(debounce(() => { console.log(a); }), []); }", None),
+        ("function MyComponent() { useEffect(async () => {}, []); }", None),
+        ("function MyComponent() { function fn() {} useEffect(fn, []); }", None),
+        ("function MyComponent({ a }) { useCustomEffect(() => { console.log(a); }, []); }", Some(json!([{ "additionalHooks": "useCustomEffect" }]))),
+    ];
+
+    let suggestions = vec![
+        (
+            "function MyComponent() { const local = {}; useEffect(() => { console.log(local); }, []); }",
+            vec!["function MyComponent() { const local = {}; useEffect(() => { console.log(local); }, [local]); }"],
+            None,
+        ),
+        (
+            "function MyComponent({ a, b }) { const fn = useCallback(() => { console.log(a); }, [a, b]); }",
+            vec!["function MyComponent({ a, b }) { const fn = useCallback(() => { console.log(a); }, [a]); }"],
+            None,
+        ),
+    ];
+
+    Tester::new(ExhaustiveDeps::NAME, pass, fail)
+        .expect_suggestions(suggestions)
+        .test_and_snapshot();
+}
@@ -0,0 +1,306 @@
+use oxc_ast::{
+    ast::{AssignmentTarget, BindingPatternKind, Expression, SimpleAssignmentTarget},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{Atom, GetSpan, Span};
+
+use crate::{
+    ast_util::get_name_from_property_key,
+    context::LintContext,
+    rule::Rule,
+    utils::{get_react_hook_name, is_react_component_name, is_react_hook_name},
+    AstNode,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+enum RulesOfHooksDiagnostic {
+    #[error("eslint-plugin-react-hooks(rules-of-hooks): React Hook {0:?} is called conditionally. React Hooks must be called in the exact same order in every component render.")]
+    Conditional(String, #[label] Span),
+    #[error("eslint-plugin-react-hooks(rules-of-hooks): React Hook {0:?} is called conditionally. React Hooks must be called in the exact same order in every component render. Did you accidentally call a React Hook after an early return?")]
+    AfterEarlyReturn(String, #[label] Span),
+    #[error("eslint-plugin-react-hooks(rules-of-hooks): React Hook {0:?} may be executed more than once. Possibly because it is called in a loop. React Hooks must be called in the exact same order in every component render.")]
+    Loop(String, #[label] Span),
+    #[error("eslint-plugin-react-hooks(rules-of-hooks): React Hook {0:?} cannot be called in an async function.")]
+    Async(String, #[label] Span),
+    #[error("eslint-plugin-react-hooks(rules-of-hooks): React Hook {0:?} cannot be called in a class component. React Hooks must be called in a React function component or a custom React Hook function.")]
+    Class(String, #[label] Span),
+    #[error("eslint-plugin-react-hooks(rules-of-hooks): React Hook {0:?} is called in function {1:?} that is neither a React function component nor a custom React Hook function. React component names must start with an uppercase letter. React Hook names must start with the word \"use\".")]
+    Function(String, Atom, #[label] Span),
+    #[error("eslint-plugin-react-hooks(rules-of-hooks): React Hook {0:?} cannot be called at the top level. React Hooks must be called in a React function component or a custom React Hook function.")]
+    TopLevel(String, #[label] Span),
+    #[error("eslint-plugin-react-hooks(rules-of-hooks): React Hook {0:?} cannot be called inside a callback. React Hooks must be called in a React function component or a custom React Hook function.")]
+    Callback(String, #[label] Span),
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct RulesOfHooks;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforces the [Rules of Hooks](https://react.dev/reference/rules/rules-of-hooks):
+    /// hooks are only called at the top level of React function components and custom hooks.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// React relies on the order in which hooks are called to associate them with their state.
+    /// A hook called conditionally, in a loop, after an early return or from a regular function
+    /// changes this order between renders.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // Bad
+    /// function Component({ enabled }) {
+    ///   if (enabled) {
+    ///     useEffect(() => {});
+    ///   }
+    /// }
+    ///
+    /// // Good
+    /// function Component({ enabled }) {
+    ///   useEffect(() => {
+    ///     if (enabled) {}
+    ///   });
+    /// }
+    /// ```
+    RulesOfHooks,
+    correctness
+);
+
+impl Rule for RulesOfHooks {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call_expr) = node.kind() else { return };
+        let Some(hook_name) = get_react_hook_name(call_expr) else { return };
+        let span = call_expr.callee.span();
+        let name = span.source_text(ctx.source_text()).to_string();
+
+        // Walk up to the enclosing function, recording whether the call is conditional.
+        let mut conditional = false;
+        let mut in_loop = false;
+        let mut child_span = call_expr.span;
+        let mut function = None;
+        for parent in ctx.nodes().iter_parents(node.id()).skip(1) {
+            let after = |span: Span| child_span.start >= span.end;
+            match parent.kind() {
+                AstKind::Function(_) | AstKind::ArrowExpression(_) => {
+                    function = Some(parent);
+                    break;
+                }
+                AstKind::Class(_) => {
+                    ctx.diagnostic(RulesOfHooksDiagnostic::Class(name, span));
+                    return;
+                }
+                AstKind::IfStatement(stmt) => conditional |= after(stmt.test.span()),
+                AstKind::ConditionalExpression(expr) => conditional |= after(expr.test.span()),
+                AstKind::LogicalExpression(expr) => conditional |= after(expr.left.span()),
+                AstKind::AssignmentExpression(expr) if expr.operator.is_logical() => {
+                    conditional |= after(expr.left.span());
+                }
+                AstKind::SwitchStatement(stmt) => conditional |= after(stmt.discriminant.span()),
+                AstKind::ChainExpression(_) => conditional = true,
+                AstKind::ForStatement(stmt) => {
+                    in_loop |= stmt.init.as_ref().map_or(true, |init| after(init.span()));
+                }
+                AstKind::ForInStatement(stmt) => in_loop |= after(stmt.right.span()),
+                AstKind::ForOfStatement(stmt) => in_loop |= after(stmt.right.span()),
+                AstKind::WhileStatement(_) | AstKind::DoWhileStatement(_) => in_loop = true,
+                _ => {}
+            }
+            child_span = parent.kind().span();
+        }
+
+        let Some(function) = function else {
+            ctx.diagnostic(RulesOfHooksDiagnostic::TopLevel(name, span));
+            return;
+        };
+
+        if is_component_or_hook(function, ctx) {
+            let is_async = match function.kind() {
+                AstKind::Function(func) => func.r#async,
+                AstKind::ArrowExpression(arrow) => arrow.r#async,
+                _ => false,
+            };
+            if is_async {
+                ctx.diagnostic(RulesOfHooksDiagnostic::Async(name, span));
+                return;
+            }
+            // `use` can be called conditionally and in loops.
+            if hook_name == "use" {
+                return;
+            }
+            if in_loop {
+                ctx.diagnostic(RulesOfHooksDiagnostic::Loop(name, span));
+            } else if conditional {
+                ctx.diagnostic(RulesOfHooksDiagnostic::Conditional(name, span));
+            } else if has_early_return(function, call_expr.span, ctx) {
+                ctx.diagnostic(RulesOfHooksDiagnostic::AfterEarlyReturn(name, span));
+            }
+            return;
+        }
+
+        if matches!(
+            ctx.nodes().parent_kind(function.id()),
+            Some(AstKind::MethodDefinition(_) | AstKind::PropertyDefinition(_))
+        ) {
+            ctx.diagnostic(RulesOfHooksDiagnostic::Class(name, span));
+        } else if let Some(function_name) = get_function_name(function, ctx) {
+            ctx.diagnostic(RulesOfHooksDiagnostic::Function(name, function_name, span));
+        } else if ctx
+            .nodes()
+            .iter_parents(function.id())
+            .skip(1)
+            .filter(|node| {
+                matches!(node.kind(), AstKind::Function(_) | AstKind::ArrowExpression(_))
+            })
+            .any(|node| is_component_or_hook(node, ctx))
+        {
+            ctx.diagnostic(RulesOfHooksDiagnostic::Callback(name, span));
+        }
+    }
+}
+
+/// Whether the function is a component or a hook by its name, or the callback of `forwardRef` /
+/// `memo`.
+fn is_component_or_hook<'a>(function: &AstNode<'a>, ctx: &LintContext<'a>) -> bool {
+    if let Some(name) = get_function_name(function, ctx) {
+        return is_react_component_name(&name) || is_react_hook_name(&name);
+    }
+    let Some(AstKind::Argument(_)) = ctx.nodes().parent_kind(function.id()) else { return false };
+    let Some(AstKind::CallExpression(call_expr)) = ctx
+        .nodes()
+        .parent_id(function.id())
+        .and_then(|argument_id| ctx.nodes().parent_kind(argument_id))
+    else {
+        return false;
+    };
+    let callee_name = match &call_expr.callee {
+        Expression::Identifier(ident) => Some(ident.name.as_str()),
+        Expression::MemberExpression(member_expr) => member_expr.static_property_name(),
+        _ => None,
+    };
+    matches!(callee_name, Some("forwardRef" | "memo"))
+}
+
+/// The name of a function declaration or expression, or of the variable, property or method it is
+/// assigned to.
+fn get_function_name<'a>(function: &AstNode<'a>, ctx: &LintContext<'a>) -> Option<Atom> {
+    if let AstKind::Function(func) = function.kind() {
+        if let Some(id) = &func.id {
+            return Some(id.name.clone());
+        }
+    }
+    let parent = ctx
+        .nodes()
+        .iter_parents(function.id())
+        .skip(1)
+        .find(|node| !matches!(node.kind(), AstKind::ParenthesizedExpression(_)))?;
+    match parent.kind() {
+        AstKind::VariableDeclarator(decl) => match &decl.id.kind {
+            BindingPatternKind::BindingIdentifier(ident) => Some(ident.name.clone()),
+            _ => None,
+        },
+        AstKind::AssignmentExpression(expr) => match &expr.left {
+            AssignmentTarget::SimpleAssignmentTarget(
+                SimpleAssignmentTarget::AssignmentTargetIdentifier(ident),
+            ) => Some(ident.name.clone()),
+            AssignmentTarget::SimpleAssignmentTarget(
+                SimpleAssignmentTarget::MemberAssignmentTarget(member_expr),
+            ) => member_expr.static_property_name().map(Atom::from),
+            _ => None,
+        },
+        AstKind::ObjectProperty(prop) => get_name_from_property_key(&prop.key),
+        AstKind::MethodDefinition(method) => get_name_from_property_key(&method.key),
+        AstKind::PropertyDefinition(prop) => get_name_from_property_key(&prop.key),
+        _ => None,
+    }
+}
+
+/// Whether the function returns before the hook call at `span`.
+fn has_early_return<'a>(function: &AstNode<'a>, span: Span, ctx: &LintContext<'a>) -> bool {
+    let function_span = function.kind().span();
+    ctx.nodes().iter().any(|node| {
+        let AstKind::ReturnStatement(stmt) = node.kind() else { return false };
+        stmt.span.start >= function_span.start
+            && stmt.span.end <= span.start
+            && ctx
+                .nodes()
+                .iter_parents(node.id())
+                .find(|node| {
+                    matches!(node.kind(), AstKind::Function(_) | AstKind::ArrowExpression(_))
+                })
+                .is_some_and(|node| node.id() == function.id())
+    })
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        // Components and hooks
+        "function ComponentWithHook() { useHook(); }",
+        "function createComponentWithHook() { return function ComponentWithHook() { useHook(); }; }",
+        "function useHookWithHook() { useHook(); }",
+        "function createHook() { return function useHookWithHook() { useHook(); } }",
+        "const ComponentWithHook = () => { useHook(); };",
+        "const useHookWithHook = () => { useHook(); };",
+        "function ComponentWithNormalFunction() { doSomething(); }",
+        "function normalFunctionWithNormalFunction() { doSomething(); }",
+        "function normalFunctionWithConditionalFunction() { if (cond) { doSomething(); } }",
+        "function functionThatStartsWithUseButIsntAHook() { if (cond) { userFetch(); } }",
+        "function useHook() { useHook1(); useHook2(); }",
+        "function ComponentWithHook() { useHook(); } ComponentWithHook.displayName = 'ComponentWithHook';",
+        "function Component() { const [state, setState] = React.useState(); }",
+        "function useHook() { return useHook1(useHook2()); }",
+        "function useHook() { return useHook1() + useHook2(); }",
+        "function useHook() { if (useHook1()) {} }",
+        "function App() { const x = useHook() ? 1 : 2; }",
+        "obj.useFoo(); function notAHook() { this.useFoo(); }",
+        // forwardRef and memo
+        "const FancyButton = React.forwardRef((props, ref) => { useHook(); return <button {...props} ref={ref} /> });",
+        "const FancyButton = forwardRef(function (props, ref) { useHook(); });",
+        "const MemoizedFunction = React.memo(props => { useHook(); return <button {...props} /> });",
+        "const MemoizedFunction = memo(function (props) { useHook(); });",
+        // Hooks used in callbacks of non components are not reported
+        "setTimeout(() => { useHook(); });",
+        // `use` can be called conditionally
+        "function App({ shouldShowText }) { if (shouldShowText) { const text = use(query); } }",
+        "function App() { for (const query of queries) { use(query); } }",
+        // Early returns after the hooks
+        "function Component({ cond }) { useHook(); if (cond) { return null; } return <div />; }",
+        "function Component() { const fn = () => { return 1; }; useHook(); }",
+    ];
+
+    let fail = vec![
+        "function ComponentWithConditionalHook() { if (cond) { useConditionalHook(); } }",
+        "function useHook() { if (a) { useState(); } else { useState(); } }",
+        "function ComponentWithTernaryHook() { cond ? useTernaryHook() : null; }",
+        "function useHook() { a && useHook1(); }",
+        "function useHook() { a ||= useHook1(); }",
+        "function useHook() { switch (a) { case 1: useHook1(); } }",
+        "function useHook() { a?.b(useHook1()); }",
+        "function ComponentWithHookInsideLoop() { while (cond) { useHookInsideLoop(); } }",
+        "function useHook() { for (let i = 0; i < 10; i++) { useHook1(); } }",
+        "function useHook() { for (const a of b) { useHook1(); } }",
+        "function useHook() { do { useHook1(); } while (a); }",
+        "function ComponentWithEarlyReturn({ cond }) { if (cond) { return null; } useHook(); }",
+        "function useHook() { if (a) return; useState(); }",
+        "function renderItem() { useState(); }",
+        "const normalFunction = () => { useHookInsideNormalFunction(); };",
+        "function normalFunctionWithHook() { React.useState(); }",
+        "useState();",
+        "class ClassComponentWithHook extends React.Component { render() { React.useState(); } }",
+        "class C { m = () => { useHook(); } }",
+        "function ComponentWithHookInsideCallback() { useEffect(() => { useHookInsideCallback(); }); }",
+        "function ComponentWithHookInsideCallback() { function handleClick() { useState(); } }",
+        "async function AsyncComponent() { useState(); }",
+        "const useAsyncHook = async () => { useState(); };",
+    ];
+
+    Tester::new_without_config(RulesOfHooks::NAME, pass, fail).test_and_snapshot();
+}
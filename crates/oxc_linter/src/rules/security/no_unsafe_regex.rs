@@ -0,0 +1,449 @@
+use oxc_ast::{
+    ast::{Argument, Expression, RegExpFlags},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_regexp_parser::{
+    ast::{self as regexp, CharacterClassEscapeKind, CharacterClassKind, ClassElement},
+    parse_flags, EcmaVersion, Parser, ParserOptions,
+};
+use oxc_span::Span;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("security(no-unsafe-regex): Regular expression is vulnerable to exponential backtracking.")]
+#[diagnostic(
+    severity(warning),
+    help(
+        "`{0}` can match the same input in exponentially many ways, rewrite it to be unambiguous."
+    )
+)]
+struct NoUnsafeRegexDiagnostic(String, #[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnsafeRegex;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow the regular expressions which are vulnerable to exponential backtracking,
+    /// also known as Regular expression Denial of Service (ReDoS).
+    ///
+    /// The pattern is parsed and the repetitions are checked for the ambiguity:
+    /// a repeated group containing another unbounded repetition which can match
+    /// the whole iteration, e.g. `(a+)+` or `(\w+\s?)*`, and a repeated alternation
+    /// whose alternatives can match the same input, e.g. `(a|a)*` or `(\d|\w)+`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// The backtracking regex engines try every way of matching an ambiguous pattern before failing,
+    /// a short crafted input is enough to block the event loop for minutes.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // Bad
+    /// /^(a+)+$/.test(input);
+    /// new RegExp("^(\\w+\\s?)*$");
+    ///
+    /// // Good
+    /// /^a+$/.test(input);
+    /// new RegExp("^(\\w+\\s)*\\w*$");
+    /// ```
+    NoUnsafeRegex,
+    security
+);
+
+impl Rule for NoUnsafeRegex {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let (pattern, flags, span) = match node.kind() {
+            AstKind::RegExpLiteral(lit) => (lit.regex.pattern.as_str(), lit.regex.flags, lit.span),
+            // `new RegExp("pattern")` and `RegExp("pattern")`
+            AstKind::NewExpression(new_expr) => {
+                let Some((pattern, flags)) =
+                    regexp_constructor_pattern(&new_expr.callee, &new_expr.arguments, ctx)
+                else {
+                    return;
+                };
+                (pattern, flags, new_expr.span)
+            }
+            AstKind::CallExpression(call_expr) => {
+                let Some((pattern, flags)) =
+                    regexp_constructor_pattern(&call_expr.callee, &call_expr.arguments, ctx)
+                else {
+                    return;
+                };
+                (pattern, flags, call_expr.span)
+            }
+            _ => return,
+        };
+
+        // The invalid patterns are reported by `no-invalid-regexp`.
+        let Ok(pattern_ast) = Parser::new(pattern, ParserOptions::new(flags)).parse() else {
+            return;
+        };
+        let alternatives = convert_alternatives(&pattern_ast.alternatives);
+        if let Some((start, end)) = find_unsafe_repetition(&alternatives) {
+            ctx.diagnostic(NoUnsafeRegexDiagnostic(pattern[start..end].to_string(), span));
+        }
+    }
+}
+
+/// The pattern and the flags of `RegExp("pattern", "flags")`, the flags are empty when unknown.
+fn regexp_constructor_pattern<'a>(
+    callee: &Expression<'a>,
+    arguments: &'a [Argument<'a>],
+    ctx: &LintContext<'a>,
+) -> Option<(&'a str, RegExpFlags)> {
+    let Expression::Identifier(ident) = callee else { return None };
+    if ident.name != "RegExp" || !ctx.semantic().is_reference_to_global_variable(ident) {
+        return None;
+    }
+    let Some(Argument::Expression(Expression::StringLiteral(pattern))) = arguments.first() else {
+        return None;
+    };
+    let flags = match arguments.get(1) {
+        Some(Argument::Expression(Expression::StringLiteral(flags))) => {
+            parse_flags(flags.value.as_str(), EcmaVersion::default()).ok()?
+        }
+        _ => RegExpFlags::empty(),
+    };
+    Some((pattern.value.as_str(), flags))
+}
+
+/// A set of characters, `negated` means all the characters except of the `ranges`.
+#[derive(Debug, Clone)]
+struct CharSet {
+    ranges: Vec<(u32, u32)>,
+    negated: bool,
+}
+
+impl CharSet {
+    fn single(value: u32) -> Self {
+        Self { ranges: vec![(value, value)], negated: false }
+    }
+
+    fn any() -> Self {
+        Self { ranges: vec![], negated: true }
+    }
+
+    fn digit() -> Self {
+        Self { ranges: vec![(0x30, 0x39)], negated: false }
+    }
+
+    fn word() -> Self {
+        Self {
+            ranges: vec![(0x30, 0x39), (0x41, 0x5A), (0x5F, 0x5F), (0x61, 0x7A)],
+            negated: false,
+        }
+    }
+
+    fn whitespace() -> Self {
+        let ranges = vec![
+            (0x09, 0x0D),
+            (0x20, 0x20),
+            (0xA0, 0xA0),
+            (0x1680, 0x1680),
+            (0x2000, 0x200A),
+            (0x2028, 0x2029),
+            (0x202F, 0x202F),
+            (0x205F, 0x205F),
+            (0x3000, 0x3000),
+            (0xFEFF, 0xFEFF),
+        ];
+        Self { ranges, negated: false }
+    }
+
+    /// `.` without the `s` flag.
+    fn dot() -> Self {
+        Self { ranges: vec![(0x0A, 0x0A), (0x0D, 0x0D), (0x2028, 0x2029)], negated: true }
+    }
+
+    fn negate(mut self) -> Self {
+        self.negated = !self.negated;
+        self
+    }
+
+    fn union(&self, other: &Self) -> Self {
+        match (self.negated, other.negated) {
+            (false, false) => {
+                let ranges = self.ranges.iter().chain(&other.ranges).copied().collect();
+                Self { ranges, negated: false }
+            }
+            // Over-approximated, the sets are only used for finding the overlaps.
+            _ => Self::any(),
+        }
+    }
+
+    fn overlaps(&self, other: &Self) -> bool {
+        match (self.negated, other.negated) {
+            (false, false) => self.ranges.iter().any(|&(start, end)| {
+                other
+                    .ranges
+                    .iter()
+                    .any(|&(other_start, other_end)| start <= other_end && other_start <= end)
+            }),
+            (true, false) => !other.is_subset_of_ranges(&self.ranges),
+            (false, true) => !self.is_subset_of_ranges(&other.ranges),
+            (true, true) => true,
+        }
+    }
+
+    fn is_subset_of_ranges(&self, ranges: &[(u32, u32)]) -> bool {
+        self.ranges.iter().all(|&(start, end)| {
+            ranges.iter().any(|&(other_start, other_end)| other_start <= start && end <= other_end)
+        })
+    }
+}
+
+type Alternatives = Vec<Vec<Term>>;
+
+#[derive(Debug)]
+enum Term {
+    Char(CharSet),
+    Group(Alternatives),
+    /// `^`, `$`, `\b`, `\B` and the lookarounds
+    Assertion,
+    Backreference,
+    Repetition {
+        term: Box<Term>,
+        min: u32,
+        max: Option<u32>,
+        /// The byte offsets of the repetition in the pattern.
+        start: usize,
+        end: usize,
+    },
+}
+
+impl Term {
+    fn is_nullable(&self) -> bool {
+        match self {
+            Self::Char(_) => false,
+            Self::Group(alternatives) => {
+                alternatives.iter().any(|terms| terms.iter().all(Self::is_nullable))
+            }
+            Self::Assertion | Self::Backreference => true,
+            Self::Repetition { term, min, .. } => *min == 0 || term.is_nullable(),
+        }
+    }
+
+    /// All the characters the term can match.
+    fn chars(&self) -> CharSet {
+        match self {
+            Self::Char(set) => set.clone(),
+            Self::Group(alternatives) => alternatives
+                .iter()
+                .flatten()
+                .fold(CharSet { ranges: vec![], negated: false }, |set, term| {
+                    set.union(&term.chars())
+                }),
+            Self::Assertion => CharSet { ranges: vec![], negated: false },
+            Self::Backreference => CharSet::any(),
+            Self::Repetition { term, .. } => term.chars(),
+        }
+    }
+
+    /// The characters of an unbounded repetition inside of the term
+    /// which can match a whole input of the term, e.g. `a` in `a+b?`.
+    fn pumpable_chars(&self) -> Option<CharSet> {
+        match self {
+            Self::Repetition { term, max: None, .. } => Some(term.chars()),
+            Self::Repetition { term, .. } => term.pumpable_chars(),
+            Self::Group(alternatives) => {
+                alternatives.iter().find_map(|terms| sequence_pumpable_chars(terms))
+            }
+            _ => None,
+        }
+    }
+}
+
+fn sequence_pumpable_chars(terms: &[Term]) -> Option<CharSet> {
+    terms.iter().enumerate().find_map(|(i, term)| {
+        let chars = term.pumpable_chars()?;
+        terms
+            .iter()
+            .enumerate()
+            .all(|(j, other)| i == j || other.is_nullable() || other.chars().overlaps(&chars))
+            .then_some(chars)
+    })
+}
+
+/// The characters of a sequence of the single characters, e.g. `\d` or `a+b`.
+fn simple_sequence_chars(terms: &[Term]) -> Option<Vec<CharSet>> {
+    if terms.is_empty() {
+        return None;
+    }
+    terms
+        .iter()
+        .map(|term| match term {
+            Term::Char(set) => Some(set.clone()),
+            Term::Repetition { term, .. } => match term.as_ref() {
+                Term::Char(set) => Some(set.clone()),
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect()
+}
+
+/// Whether two alternatives can match the same input,
+/// approximated by a character which all of their characters can be.
+fn alternatives_overlap(a: &[Term], b: &[Term]) -> bool {
+    let (Some(a), Some(b)) = (simple_sequence_chars(a), simple_sequence_chars(b)) else {
+        return false;
+    };
+    a.iter().chain(&b).all(|set| a.iter().chain(&b).all(|other| set.overlaps(other)))
+}
+
+/// The offsets of the first exponentially backtracking repetition.
+fn find_unsafe_repetition(alternatives: &Alternatives) -> Option<(usize, usize)> {
+    alternatives.iter().flatten().find_map(find_unsafe_repetition_in_term)
+}
+
+fn find_unsafe_repetition_in_term(term: &Term) -> Option<(usize, usize)> {
+    match term {
+        Term::Group(alternatives) => find_unsafe_repetition(alternatives),
+        Term::Repetition { term: inner, max, start, end, .. } => {
+            if max.is_none() {
+                // `(a+)+`
+                if inner.pumpable_chars().is_some() {
+                    return Some((*start, *end));
+                }
+                // `(a|a)+`
+                if let Term::Group(alternatives) = inner.as_ref() {
+                    let overlap = alternatives.iter().enumerate().any(|(i, a)| {
+                        alternatives[i + 1..].iter().any(|b| alternatives_overlap(a, b))
+                    });
+                    if overlap {
+                        return Some((*start, *end));
+                    }
+                }
+            }
+            find_unsafe_repetition_in_term(inner)
+        }
+        _ => None,
+    }
+}
+
+fn convert_alternatives(alternatives: &[regexp::Alternative]) -> Alternatives {
+    alternatives
+        .iter()
+        .map(|alternative| alternative.terms.iter().map(convert_term).collect())
+        .collect()
+}
+
+fn convert_term(term: &regexp::Term) -> Term {
+    match term {
+        regexp::Term::Assertion(_) | regexp::Term::LookAround(_) => Term::Assertion,
+        regexp::Term::Quantifier(quantifier) => Term::Repetition {
+            term: Box::new(convert_term(&quantifier.term)),
+            min: quantifier.min,
+            max: quantifier.max,
+            start: quantifier.span.start as usize,
+            end: quantifier.span.end as usize,
+        },
+        regexp::Term::Character(character) => Term::Char(CharSet::single(character.value)),
+        regexp::Term::Dot(_) => Term::Char(CharSet::dot()),
+        regexp::Term::CharacterClassEscape(escape) => Term::Char(class_escape_chars(escape.kind)),
+        regexp::Term::UnicodePropertyEscape(_) => Term::Char(CharSet::any()),
+        regexp::Term::CharacterClass(class) => Term::Char(class_chars(class)),
+        regexp::Term::CapturingGroup(group) => {
+            Term::Group(convert_alternatives(&group.alternatives))
+        }
+        regexp::Term::Group(group) => Term::Group(convert_alternatives(&group.alternatives)),
+        regexp::Term::Backreference(_) => Term::Backreference,
+    }
+}
+
+fn class_escape_chars(kind: CharacterClassEscapeKind) -> CharSet {
+    match kind {
+        CharacterClassEscapeKind::D => CharSet::digit(),
+        CharacterClassEscapeKind::NegativeD => CharSet::digit().negate(),
+        CharacterClassEscapeKind::S => CharSet::whitespace(),
+        CharacterClassEscapeKind::NegativeS => CharSet::whitespace().negate(),
+        CharacterClassEscapeKind::W => CharSet::word(),
+        CharacterClassEscapeKind::NegativeW => CharSet::word().negate(),
+    }
+}
+
+fn class_chars(class: &regexp::CharacterClass) -> CharSet {
+    // Over-approximated, the set operations of the `v` flag match any character.
+    if class.kind != CharacterClassKind::Union {
+        return CharSet::any();
+    }
+    let set =
+        class.elements.iter().fold(CharSet { ranges: vec![], negated: false }, |set, element| {
+            let chars = match element {
+                ClassElement::Character(character) => CharSet::single(character.value),
+                ClassElement::Range(range) => {
+                    CharSet { ranges: vec![(range.min.value, range.max.value)], negated: false }
+                }
+                ClassElement::CharacterClassEscape(escape) => class_escape_chars(escape.kind),
+                ClassElement::NestedClass(class) => class_chars(class),
+                ClassElement::UnicodePropertyEscape(_) | ClassElement::ClassStrings(_) => {
+                    CharSet::any()
+                }
+            };
+            set.union(&chars)
+        });
+    if class.negative {
+        set.negate()
+    } else {
+        set
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        (r"/^a+$/", None),
+        (r"/^(a|b)*$/", None),
+        (r"/^(a+b)+$/", None),
+        (r"/^(\w+\.)*\w+$/", None),
+        (r"/^[a-z0-9_-]+@[a-z0-9-]+(\.[a-z0-9-]+)+$/", None),
+        (r"/(a{1,3}){1,3}/", None),
+        (r"/(ab)+/", None),
+        (r"/(\d+:)+/", None),
+        (r"/(?:\s*,\s*[a-z]+)*/", None),
+        (r"/(a?){5}/", None),
+        (r"/([^,]+,)+/", None),
+        (r"/(\d|[a-z])+/", None),
+        (r"/(a+)?/", None),
+        (r"/\(a+\)+/", None),
+        (r"/[(a+)+]/", None),
+        (r"/a{/", None),
+        (r#"new RegExp("^a+$")"#, None),
+        (r#"new RegExp(pattern)"#, None),
+        (r#"class RegExp {}; new RegExp("(a+)+")"#, None),
+    ];
+
+    let fail = vec![
+        (r"/^(a+)+$/", None),
+        (r"/(a*)*/", None),
+        (r"/(a+)*b/", None),
+        (r"/(?:a+)+/", None),
+        (r"/(?<name>a+)+/", None),
+        (r"/^(\w+\s?)*$/", None),
+        (r"/(\d+\w)+/", None),
+        (r"/(.*,)*/", None),
+        (r"/(a|a)*/", None),
+        (r"/(a|aa)+/", None),
+        (r"/(\d|\w)+/", None),
+        (r"/(\s|.)*/", None),
+        (r"/([a-z]+)+$/i", None),
+        (r"/(x+x+)+y/", None),
+        (r"/((a+)b?)+/", None),
+        (r"/(a+){2,}/", None),
+        (r"/(?:[\u0061-\u007a]+)+/", None),
+        (r#"new RegExp("^(a+)+$")"#, None),
+        (r#"RegExp("(\\w+\\s?)*")"#, None),
+    ];
+
+    Tester::new(NoUnsafeRegex::NAME, pass, fail).test_and_snapshot();
+}
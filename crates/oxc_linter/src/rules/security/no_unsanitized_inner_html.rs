@@ -0,0 +1,183 @@
+use oxc_ast::{
+    ast::{Argument, AssignmentTarget, Expression, SimpleAssignmentTarget},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+use oxc_syntax::operator::{AssignmentOperator, BinaryOperator};
+
+use crate::{context::LintContext, rule::Rule, utils::get_node_name, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("security(no-unsanitized-inner-html): Unsafe HTML is written to {0:?}.")]
+#[diagnostic(
+    severity(warning),
+    help("Sanitize the value, e.g. with `DOMPurify.sanitize()`, or use `textContent` for the plain text.")
+)]
+struct NoUnsanitizedInnerHtmlDiagnostic(&'static str, #[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnsanitizedInnerHtml;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow writing the unsanitized values as HTML with `innerHTML`, `outerHTML`,
+    /// `insertAdjacentHTML()`, `document.write()` and `document.writeln()`.
+    ///
+    /// The string literals, the template literals with the literal expressions,
+    /// and the results of the known sanitizers such as `DOMPurify.sanitize()` are allowed.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// The values built from the user input can contain the markup that is parsed and executed,
+    /// which is a Cross-Site Scripting (XSS) vulnerability.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // Bad
+    /// element.innerHTML = userInput;
+    /// element.insertAdjacentHTML('beforeend', `<p>${userInput}</p>`);
+    ///
+    /// // Good
+    /// element.textContent = userInput;
+    /// element.innerHTML = DOMPurify.sanitize(userInput);
+    /// ```
+    NoUnsanitizedInnerHtml,
+    security
+);
+
+/// The functions returning the HTML which is safe to write.
+const SANITIZERS: [&str; 4] =
+    ["DOMPurify.sanitize", "escapeHTML", "sanitize", "Sanitizer.escapeHTML"];
+
+impl Rule for NoUnsanitizedInnerHtml {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            // `element.innerHTML = html`
+            AstKind::AssignmentExpression(assign_expr) => {
+                if !matches!(
+                    assign_expr.operator,
+                    AssignmentOperator::Assign | AssignmentOperator::Addition
+                ) {
+                    return;
+                }
+                let AssignmentTarget::SimpleAssignmentTarget(
+                    SimpleAssignmentTarget::MemberAssignmentTarget(member_expr),
+                ) = &assign_expr.left
+                else {
+                    return;
+                };
+                let sink = match member_expr.static_property_name() {
+                    Some("innerHTML") => "innerHTML",
+                    Some("outerHTML") => "outerHTML",
+                    _ => return,
+                };
+                if !is_safe_html(&assign_expr.right) {
+                    ctx.diagnostic(NoUnsanitizedInnerHtmlDiagnostic(sink, assign_expr.span));
+                }
+            }
+            // `element.insertAdjacentHTML(position, html)` and `document.write(html)`
+            AstKind::CallExpression(call_expr) => {
+                let Expression::MemberExpression(member_expr) = &call_expr.callee else { return };
+                let (sink, arguments) = match member_expr.static_property_name() {
+                    Some("insertAdjacentHTML") => {
+                        ("insertAdjacentHTML", call_expr.arguments.get(1..).unwrap_or_default())
+                    }
+                    Some("write") if member_expr.object().is_specific_id("document") => {
+                        ("document.write", call_expr.arguments.as_slice())
+                    }
+                    Some("writeln") if member_expr.object().is_specific_id("document") => {
+                        ("document.writeln", call_expr.arguments.as_slice())
+                    }
+                    _ => return,
+                };
+                let is_unsafe = arguments.iter().any(|argument| match argument {
+                    Argument::Expression(expr) => !is_safe_html(expr),
+                    Argument::SpreadElement(_) => true,
+                });
+                if is_unsafe {
+                    ctx.diagnostic(NoUnsanitizedInnerHtmlDiagnostic(sink, call_expr.span));
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn is_safe_html<'a>(expr: &'a Expression<'a>) -> bool {
+    match expr.without_parenthesized() {
+        Expression::StringLiteral(_)
+        | Expression::NumberLiteral(_)
+        | Expression::BooleanLiteral(_)
+        | Expression::NullLiteral(_) => true,
+        Expression::Identifier(ident) => ident.name == "undefined",
+        Expression::TemplateLiteral(lit) => lit.expressions.iter().all(is_safe_html),
+        Expression::BinaryExpression(expr) if expr.operator == BinaryOperator::Addition => {
+            is_safe_html(&expr.left) && is_safe_html(&expr.right)
+        }
+        Expression::ConditionalExpression(expr) => {
+            is_safe_html(&expr.consequent) && is_safe_html(&expr.alternate)
+        }
+        Expression::LogicalExpression(expr) => {
+            is_safe_html(&expr.left) && is_safe_html(&expr.right)
+        }
+        Expression::SequenceExpression(expr) => {
+            expr.expressions.last().is_some_and(|expr| is_safe_html(expr))
+        }
+        Expression::CallExpression(call_expr) => {
+            SANITIZERS.contains(&get_node_name(&call_expr.callee).as_str())
+        }
+        // `Sanitizer.escapeHTML`<p>${userInput}</p>``
+        Expression::TaggedTemplateExpression(expr) => {
+            SANITIZERS.contains(&get_node_name(&expr.tag).as_str())
+        }
+        _ => false,
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("element.innerHTML = '';", None),
+        ("element.innerHTML = '<p>static</p>';", None),
+        ("element.innerHTML = `<p>static</p>`;", None),
+        ("element.innerHTML = `<p>${1}</p>`;", None),
+        ("element.innerHTML = '<p>' + 'static' + '</p>';", None),
+        ("element.innerHTML = condition ? '<b>a</b>' : '<i>b</i>';", None),
+        ("element.innerHTML = DOMPurify.sanitize(userInput);", None),
+        ("element.innerHTML = Sanitizer.escapeHTML`<p>${userInput}</p>`;", None),
+        ("element.innerHTML += '<br>';", None),
+        ("element.textContent = userInput;", None),
+        ("element.innerText = userInput;", None),
+        ("const html = element.innerHTML;", None),
+        ("element.innerHTML === userInput;", None),
+        ("element.insertAdjacentHTML('beforeend', '<br>');", None),
+        ("element.insertAdjacentText('beforeend', userInput);", None),
+        ("document.write('<p>static</p>');", None),
+        ("stream.write(userInput);", None),
+    ];
+
+    let fail = vec![
+        ("element.innerHTML = userInput;", None),
+        ("element.outerHTML = userInput;", None),
+        ("element.innerHTML += userInput;", None),
+        ("element.innerHTML = `<p>${userInput}</p>`;", None),
+        ("element.innerHTML = '<p>' + userInput + '</p>';", None),
+        ("element.innerHTML = condition ? userInput : '';", None),
+        ("element['innerHTML'] = userInput;", None),
+        ("element?.insertAdjacentHTML('beforeend', userInput);", None),
+        ("element.insertAdjacentHTML('beforeend', `<p>${userInput}</p>`);", None),
+        ("document.write(userInput);", None),
+        ("document.writeln('<p>', userInput);", None),
+        ("document.write(...chunks);", None),
+    ];
+
+    Tester::new(NoUnsanitizedInnerHtml::NAME, pass, fail).test_and_snapshot();
+}
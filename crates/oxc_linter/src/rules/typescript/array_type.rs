@@ -0,0 +1,311 @@
+use oxc_ast::{
+    ast::{TSType, TSTypeName, TSTypeOperator, TSTypeReference},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{context::LintContext, fixer::Fix, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+enum ArrayTypeDiagnostic {
+    #[error(
+        "typescript-eslint(array-type): Array type using {0:?} is forbidden. Use {1:?} instead."
+    )]
+    #[diagnostic(severity(warning))]
+    Forbidden(String, String, #[label] Span),
+    #[error("typescript-eslint(array-type): Array type using {0:?} is forbidden for simple types. Use {1:?} instead.")]
+    #[diagnostic(severity(warning))]
+    ForbiddenForSimpleTypes(String, String, #[label] Span),
+    #[error("typescript-eslint(array-type): Array type using {0:?} is forbidden for non-simple types. Use {1:?} instead.")]
+    #[diagnostic(severity(warning))]
+    ForbiddenForNonSimpleTypes(String, String, #[label] Span),
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum ArrayOption {
+    /// Always use `T[]` or `readonly T[]`.
+    #[default]
+    Array,
+    /// Use `T[]` for the simple types and `Array<T>` for all the other types.
+    ArraySimple,
+    /// Always use `Array<T>` or `ReadonlyArray<T>`.
+    Generic,
+}
+
+impl ArrayOption {
+    fn from_json(value: &serde_json::Value) -> Option<Self> {
+        match value.as_str()? {
+            "array" => Some(Self::Array),
+            "array-simple" => Some(Self::ArraySimple),
+            "generic" => Some(Self::Generic),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct ArrayType {
+    default: ArrayOption,
+    /// The option for the readonly arrays, `default` when not configured.
+    readonly: Option<ArrayOption>,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Require consistently using either `T[]` or `Array<T>` for arrays.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Both forms are equivalent, mixing them makes the code harder to read.
+    ///
+    /// ### Example
+    /// ```typescript
+    /// // Bad, with the default `{ "default": "array" }`
+    /// const x: Array<string> = ['a', 'b'];
+    /// const y: ReadonlyArray<string> = ['a', 'b'];
+    ///
+    /// // Good, with the default `{ "default": "array" }`
+    /// const x: string[] = ['a', 'b'];
+    /// const y: readonly string[] = ['a', 'b'];
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// `default` and `readonly` (for the readonly arrays) are one of `"array"`, `"array-simple"` or `"generic"`.
+    /// `"array-simple"` uses `T[]` for the simple types, e.g. `string[]`, and `Array<T>` for the others.
+    ArrayType,
+    style,
+    fix
+);
+
+impl Rule for ArrayType {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let config = value.get(0);
+        Self {
+            default: config
+                .and_then(|config| config.get("default"))
+                .and_then(ArrayOption::from_json)
+                .unwrap_or_default(),
+            readonly: config
+                .and_then(|config| config.get("readonly"))
+                .and_then(ArrayOption::from_json),
+        }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::TSArrayType(array_type) => {
+                // `readonly T[]`
+                let readonly_span = match ctx.nodes().parent_kind(node.id()) {
+                    Some(AstKind::TSTypeOperatorType(operator))
+                        if matches!(operator.operator, TSTypeOperator::Readonly) =>
+                    {
+                        Some(operator.span)
+                    }
+                    _ => None,
+                };
+                let option = self.option(readonly_span.is_some());
+                let is_simple = is_simple_type(&array_type.element_type);
+                if option == ArrayOption::Array || (option == ArrayOption::ArraySimple && is_simple)
+                {
+                    return;
+                }
+
+                let span = readonly_span.unwrap_or(array_type.span);
+                let class_name = if readonly_span.is_some() { "ReadonlyArray" } else { "Array" };
+                let element_type = array_type.element_type.span().source_text(ctx.source_text());
+                let generic = format!("{class_name}<{element_type}>");
+                let found = span.source_text(ctx.source_text()).to_string();
+                let diagnostic = if option == ArrayOption::Generic {
+                    ArrayTypeDiagnostic::Forbidden(found, generic.clone(), span)
+                } else {
+                    ArrayTypeDiagnostic::ForbiddenForNonSimpleTypes(found, generic.clone(), span)
+                };
+                ctx.diagnostic_with_fix(diagnostic, || Fix::new(generic, span));
+            }
+            AstKind::TSTypeReference(reference) => {
+                let Some(is_readonly) = array_reference_kind(reference) else { return };
+                let option = self.option(is_readonly);
+                let element_type = reference
+                    .type_parameters
+                    .as_ref()
+                    .and_then(|parameters| parameters.params.first());
+                let is_simple = element_type.map_or(true, is_simple_type);
+                if option == ArrayOption::Generic
+                    || (option == ArrayOption::ArraySimple && !is_simple)
+                {
+                    return;
+                }
+
+                let element_text = element_type.map_or("any", |element_type| {
+                    element_type.span().source_text(ctx.source_text())
+                });
+                let element_text = if element_type.is_some_and(needs_parentheses) {
+                    format!("({element_text})")
+                } else {
+                    element_text.to_string()
+                };
+                let readonly = if is_readonly { "readonly " } else { "" };
+                let array = format!("{readonly}{element_text}[]");
+                let found = reference.span.source_text(ctx.source_text()).to_string();
+                let diagnostic = if option == ArrayOption::Array {
+                    ArrayTypeDiagnostic::Forbidden(found, array.clone(), reference.span)
+                } else {
+                    ArrayTypeDiagnostic::ForbiddenForSimpleTypes(
+                        found,
+                        array.clone(),
+                        reference.span,
+                    )
+                };
+                ctx.diagnostic_with_fix(diagnostic, || Fix::new(array, reference.span));
+            }
+            _ => {}
+        }
+    }
+}
+
+impl ArrayType {
+    fn option(&self, is_readonly: bool) -> ArrayOption {
+        if is_readonly {
+            self.readonly.unwrap_or(self.default)
+        } else {
+            self.default
+        }
+    }
+}
+
+/// `Some(false)` for `Array<T>` and `Some(true)` for `ReadonlyArray<T>`.
+fn array_reference_kind(reference: &TSTypeReference) -> Option<bool> {
+    let TSTypeName::IdentifierReference(ident) = &reference.type_name else { return None };
+    let parameters = reference.type_parameters.as_ref().map_or(0, |p| p.params.len());
+    if parameters > 1 {
+        return None;
+    }
+    match ident.name.as_str() {
+        "Array" => Some(false),
+        "ReadonlyArray" => Some(true),
+        _ => None,
+    }
+}
+
+/// Keywords, `this`, type names without type arguments and arrays of these.
+fn is_simple_type(ty: &TSType) -> bool {
+    match ty {
+        TSType::TSAnyKeyword(_)
+        | TSType::TSBigIntKeyword(_)
+        | TSType::TSBooleanKeyword(_)
+        | TSType::TSNeverKeyword(_)
+        | TSType::TSNullKeyword(_)
+        | TSType::TSNumberKeyword(_)
+        | TSType::TSObjectKeyword(_)
+        | TSType::TSStringKeyword(_)
+        | TSType::TSSymbolKeyword(_)
+        | TSType::TSThisKeyword(_)
+        | TSType::TSUndefinedKeyword(_)
+        | TSType::TSUnknownKeyword(_)
+        | TSType::TSVoidKeyword(_)
+        | TSType::TSQualifiedName(_) => true,
+        TSType::TSTypeReference(reference) => {
+            if array_reference_kind(reference).is_some() {
+                return reference
+                    .type_parameters
+                    .as_ref()
+                    .and_then(|parameters| parameters.params.first())
+                    .map_or(true, is_simple_type);
+            }
+            reference.type_parameters.is_none()
+        }
+        TSType::TSArrayType(array_type) => is_simple_type(&array_type.element_type),
+        _ => false,
+    }
+}
+
+/// `A | B` in `Array<A | B>` is written as `(A | B)[]`.
+fn needs_parentheses(ty: &TSType) -> bool {
+    matches!(
+        ty,
+        TSType::TSUnionType(_)
+            | TSType::TSIntersectionType(_)
+            | TSType::TSFunctionType(_)
+            | TSType::TSConstructorType(_)
+            | TSType::TSConditionalType(_)
+            | TSType::TSTypeOperatorType(_)
+            | TSType::TSInferType(_)
+            | TSType::TSTypePredicate(_)
+    )
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("let a: number[] = [];", None),
+        ("let a: (string | number)[] = [];", None),
+        ("let a: readonly number[] = [];", None),
+        ("let a: readonly (string | number)[] = [];", None),
+        ("let a: Foo<number> = [];", None),
+        ("let a: Array<number> = [];", Some(json!([{ "default": "generic" }]))),
+        ("let a: ReadonlyArray<number> = [];", Some(json!([{ "default": "generic" }]))),
+        (
+            "let a: readonly number[] = [];",
+            Some(json!([{ "default": "generic", "readonly": "array" }])),
+        ),
+        ("let a: number[] = [];", Some(json!([{ "default": "array-simple" }]))),
+        ("let a: Array<string | number> = [];", Some(json!([{ "default": "array-simple" }]))),
+        ("let a: Array<Foo<Bar>> = [];", Some(json!([{ "default": "array-simple" }]))),
+        ("let a: bar.Baz[] = [];", Some(json!([{ "default": "array-simple" }]))),
+        ("let a: number[][] = [];", Some(json!([{ "default": "array-simple" }]))),
+    ];
+
+    let fail = vec![
+        ("let a: Array<number> = [];", None),
+        ("let a: Array<string | number> = [];", None),
+        ("let a: ReadonlyArray<number> = [];", None),
+        ("let a: Array = [];", None),
+        ("function foo(a: Array<() => void>): Array<number> {}", None),
+        ("let a: number[] = [];", Some(json!([{ "default": "generic" }]))),
+        ("let a: readonly number[] = [];", Some(json!([{ "default": "generic" }]))),
+        ("let a: (string | number)[] = [];", Some(json!([{ "default": "generic" }]))),
+        (
+            "let a: ReadonlyArray<number> = [];",
+            Some(json!([{ "default": "generic", "readonly": "array" }])),
+        ),
+        ("let a: Array<number> = [];", Some(json!([{ "default": "array-simple" }]))),
+        ("let a: (string | number)[] = [];", Some(json!([{ "default": "array-simple" }]))),
+        ("let a: Foo<Bar>[] = [];", Some(json!([{ "default": "array-simple" }]))),
+        ("let a: Array<number>[] = [];", Some(json!([{ "default": "array-simple" }]))),
+    ];
+
+    let fix = vec![
+        ("let a: Array<number> = [];", "let a: number[] = [];", None),
+        ("let a: Array<string | number> = [];", "let a: (string | number)[] = [];", None),
+        ("let a: ReadonlyArray<number> = [];", "let a: readonly number[] = [];", None),
+        ("let a: Array = [];", "let a: any[] = [];", None),
+        ("let a: Array<Array<number>> = [];", "let a: Array<number>[] = [];", None),
+        (
+            "let a: number[] = [];",
+            "let a: Array<number> = [];",
+            Some(json!([{ "default": "generic" }])),
+        ),
+        (
+            "let a: readonly number[] = [];",
+            "let a: ReadonlyArray<number> = [];",
+            Some(json!([{ "default": "generic" }])),
+        ),
+        (
+            "let a: (string | number)[] = [];",
+            "let a: Array<string | number> = [];",
+            Some(json!([{ "default": "array-simple" }])),
+        ),
+    ];
+
+    Tester::new(ArrayType::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
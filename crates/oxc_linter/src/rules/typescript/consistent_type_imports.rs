@@ -0,0 +1,491 @@
+use oxc_ast::{
+    ast::{ImportDeclaration, ImportDeclarationSpecifier, ModuleDeclaration},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{Atom, GetSpan, Span};
+use rustc_hash::FxHashSet;
+
+use crate::{context::LintContext, fixer::Fix, rule::Rule};
+
+#[derive(Debug, Error, Diagnostic)]
+enum ConsistentTypeImportsDiagnostic {
+    #[error("typescript-eslint(consistent-type-imports): All imports in the declaration are only used as types.")]
+    #[diagnostic(severity(warning), help("Use `import type` instead."))]
+    TypeOverValue(#[label] Span),
+    #[error("typescript-eslint(consistent-type-imports): Imports {0:?} are only used as types.")]
+    #[diagnostic(severity(warning), help("Import them with `import type` instead."))]
+    SomeImportsAreOnlyTypes(String, #[label] Span),
+    #[error(
+        "typescript-eslint(consistent-type-imports): Use an `import` instead of an `import type`."
+    )]
+    #[diagnostic(severity(warning))]
+    AvoidImportType(#[label] Span),
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum Prefer {
+    #[default]
+    TypeImports,
+    NoTypeImports,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum FixStyle {
+    /// `import type { A } from 'mod';`
+    #[default]
+    SeparateTypeImports,
+    /// `import { type A } from 'mod';`
+    InlineTypeImports,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct ConsistentTypeImports {
+    prefer: Prefer,
+    fix_style: FixStyle,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce consistent usage of type imports.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// The imports used only as types are removed by the TypeScript compiler,
+    /// marking them with `import type` makes it clear which imports are erased
+    /// and lets the single-file transpilers drop them safely.
+    ///
+    /// ### Example
+    /// ```typescript
+    /// // Bad
+    /// import { Foo } from 'foo';
+    /// let foo: Foo;
+    ///
+    /// // Good
+    /// import type { Foo } from 'foo';
+    /// let foo: Foo;
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// * `prefer`: `"type-imports"` (default) or `"no-type-imports"` to forbid `import type`.
+    /// * `fixStyle`: `"separate-type-imports"` (default) adds a separate `import type` declaration,
+    ///   `"inline-type-imports"` marks the specifiers with inline `type` qualifiers.
+    ConsistentTypeImports,
+    nursery,
+    fix
+);
+
+/// How an import binding is referenced in the file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Usage {
+    Unused,
+    TypeOnly,
+    Value,
+}
+
+impl Rule for ConsistentTypeImports {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let config = value.get(0);
+        Self {
+            prefer: match config
+                .and_then(|config| config.get("prefer"))
+                .and_then(serde_json::Value::as_str)
+            {
+                Some("no-type-imports") => Prefer::NoTypeImports,
+                _ => Prefer::TypeImports,
+            },
+            fix_style: match config
+                .and_then(|config| config.get("fixStyle"))
+                .and_then(serde_json::Value::as_str)
+            {
+                Some("inline-type-imports") => FixStyle::InlineTypeImports,
+                _ => FixStyle::SeparateTypeImports,
+            },
+        }
+    }
+
+    fn run_once(&self, ctx: &LintContext<'_>) {
+        let mut import_declarations = vec![];
+        // `export { Foo }` needs the value of `Foo`.
+        let mut exported_names = FxHashSet::default();
+        for node in ctx.nodes().iter() {
+            let AstKind::ModuleDeclaration(decl) = node.kind() else { continue };
+            match decl {
+                ModuleDeclaration::ImportDeclaration(decl) => import_declarations.push(&**decl),
+                ModuleDeclaration::ExportNamedDeclaration(decl)
+                    if decl.source.is_none() && !decl.export_kind.is_type() =>
+                {
+                    exported_names.extend(
+                        decl.specifiers
+                            .iter()
+                            .filter(|specifier| !specifier.export_kind.is_type())
+                            .map(|specifier| specifier.local.name().clone()),
+                    );
+                }
+                _ => {}
+            }
+        }
+
+        for decl in import_declarations {
+            match self.prefer {
+                Prefer::TypeImports => self.check_type_imports(decl, &exported_names, ctx),
+                Prefer::NoTypeImports => check_no_type_imports(decl, ctx),
+            }
+        }
+    }
+}
+
+impl ConsistentTypeImports {
+    fn check_type_imports(
+        &self,
+        decl: &ImportDeclaration,
+        exported_names: &FxHashSet<Atom>,
+        ctx: &LintContext,
+    ) {
+        if decl.import_kind.is_type() {
+            return;
+        }
+        let specifiers = &decl.specifiers;
+        let usages = specifiers
+            .iter()
+            .map(|specifier| {
+                if is_inline_type_specifier(specifier) {
+                    Usage::Unused
+                } else {
+                    usage_of(specifier, exported_names, ctx)
+                }
+            })
+            .collect::<Vec<_>>();
+        if !usages.contains(&Usage::TypeOnly) {
+            return;
+        }
+
+        if !usages.contains(&Usage::Value) {
+            let diagnostic = ConsistentTypeImportsDiagnostic::TypeOverValue(decl.span);
+            if decl.with_clause.is_some() {
+                ctx.diagnostic(diagnostic);
+                return;
+            }
+            ctx.diagnostic_with_fix(diagnostic, || {
+                let statements = ImportStatements::new(decl, ctx);
+                let specifiers = specifiers.iter().collect::<Vec<_>>();
+                Fix::new(statements.render_type_only(&specifiers), decl.span)
+            });
+            return;
+        }
+
+        let type_only_names = specifiers
+            .iter()
+            .zip(&usages)
+            .filter(|(_, usage)| **usage == Usage::TypeOnly)
+            .map(|(specifier, _)| local_name(specifier).as_str())
+            .collect::<Vec<_>>()
+            .join(", ");
+        let diagnostic =
+            ConsistentTypeImportsDiagnostic::SomeImportsAreOnlyTypes(type_only_names, decl.span);
+        if decl.with_clause.is_some() {
+            ctx.diagnostic(diagnostic);
+            return;
+        }
+        ctx.diagnostic_with_fix(diagnostic, || {
+            let statements = ImportStatements::new(decl, ctx);
+            let (type_specifiers, value_specifiers): (Vec<_>, Vec<_>) =
+                specifiers.iter().zip(&usages).partition(|(_, usage)| **usage == Usage::TypeOnly);
+            let type_specifiers = type_specifiers.into_iter().map(|(s, _)| s).collect::<Vec<_>>();
+            let value_specifiers = value_specifiers.into_iter().map(|(s, _)| s).collect::<Vec<_>>();
+
+            let can_inline = type_specifiers.iter().all(|specifier| {
+                matches!(specifier, ImportDeclarationSpecifier::ImportSpecifier(_))
+            });
+            if self.fix_style == FixStyle::InlineTypeImports && can_inline {
+                let specifiers = specifiers
+                    .iter()
+                    .zip(&usages)
+                    .map(|(specifier, usage)| {
+                        let text = statements.specifier_text(specifier);
+                        if *usage == Usage::TypeOnly {
+                            SpecifierText { text: format!("type {}", text.text), ..text }
+                        } else {
+                            text
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                return Fix::new(statements.render(false, &specifiers), decl.span);
+            }
+
+            let value_specifiers = value_specifiers
+                .iter()
+                .map(|specifier| statements.specifier_text(specifier))
+                .collect::<Vec<_>>();
+            let content = format!(
+                "{}\n{}",
+                statements.render_type_only(&type_specifiers),
+                statements.render(false, &value_specifiers)
+            );
+            Fix::new(content, decl.span)
+        });
+    }
+}
+
+fn check_no_type_imports(decl: &ImportDeclaration, ctx: &LintContext) {
+    if decl.import_kind.is_type() {
+        ctx.diagnostic_with_fix(
+            ConsistentTypeImportsDiagnostic::AvoidImportType(decl.span),
+            || {
+                // `import type { A }` -> `import { A }`
+                let text = decl.span.source_text(ctx.source_text());
+                let after_import = text["import".len()..].trim_start();
+                #[allow(clippy::cast_possible_truncation)]
+                let (import_end, type_end) = (
+                    decl.span.start + "import".len() as u32,
+                    decl.span.start + (text.len() - after_import.len() + "type".len()) as u32,
+                );
+                Fix::new("", Span::new(import_end, type_end))
+            },
+        );
+        return;
+    }
+
+    for specifier in &decl.specifiers {
+        let ImportDeclarationSpecifier::ImportSpecifier(specifier) = specifier else { continue };
+        if !specifier.import_kind.is_type() {
+            continue;
+        }
+        ctx.diagnostic_with_fix(
+            ConsistentTypeImportsDiagnostic::AvoidImportType(specifier.span),
+            || {
+                // `{ type A }` -> `{ A }`
+                let text = specifier.span.source_text(ctx.source_text());
+                let after_type = text["type".len()..].trim_start();
+                #[allow(clippy::cast_possible_truncation)]
+                let end = specifier.span.start + (text.len() - after_type.len()) as u32;
+                Fix::new("", Span::new(specifier.span.start, end))
+            },
+        );
+    }
+}
+
+fn is_inline_type_specifier(specifier: &ImportDeclarationSpecifier) -> bool {
+    matches!(specifier, ImportDeclarationSpecifier::ImportSpecifier(specifier) if specifier.import_kind.is_type())
+}
+
+fn local_name(specifier: &ImportDeclarationSpecifier) -> &Atom {
+    match specifier {
+        ImportDeclarationSpecifier::ImportSpecifier(specifier) => &specifier.local.name,
+        ImportDeclarationSpecifier::ImportDefaultSpecifier(specifier) => &specifier.local.name,
+        ImportDeclarationSpecifier::ImportNamespaceSpecifier(specifier) => &specifier.local.name,
+    }
+}
+
+fn usage_of(
+    specifier: &ImportDeclarationSpecifier,
+    exported_names: &FxHashSet<Atom>,
+    ctx: &LintContext,
+) -> Usage {
+    let local = match specifier {
+        ImportDeclarationSpecifier::ImportSpecifier(specifier) => &specifier.local,
+        ImportDeclarationSpecifier::ImportDefaultSpecifier(specifier) => &specifier.local,
+        ImportDeclarationSpecifier::ImportNamespaceSpecifier(specifier) => &specifier.local,
+    };
+    if exported_names.contains(&local.name) {
+        return Usage::Value;
+    }
+    let Some(symbol_id) = local.symbol_id.get() else { return Usage::Value };
+
+    let mut usage = Usage::Unused;
+    for reference in ctx.symbols().get_resolved_references(symbol_id) {
+        // The type names are the only identifier references below a type reference.
+        if matches!(ctx.nodes().parent_kind(reference.node_id()), Some(AstKind::TSTypeReference(_)))
+        {
+            usage = Usage::TypeOnly;
+        } else {
+            return Usage::Value;
+        }
+    }
+    usage
+}
+
+/// The source text of a specifier.
+#[derive(Debug, Clone)]
+struct SpecifierText {
+    kind: SpecifierKind,
+    text: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SpecifierKind {
+    Default,
+    Namespace,
+    Named,
+}
+
+/// Renders the import declarations replacing an existing one.
+struct ImportStatements<'a> {
+    source: &'a str,
+    semicolon: &'static str,
+    source_text: &'a str,
+}
+
+impl<'a> ImportStatements<'a> {
+    fn new(decl: &ImportDeclaration, ctx: &LintContext<'a>) -> Self {
+        let source_text = ctx.source_text();
+        let semicolon = if decl.span.source_text(source_text).ends_with(';') { ";" } else { "" };
+        Self { source: decl.source.span.source_text(source_text), semicolon, source_text }
+    }
+
+    fn specifier_text(&self, specifier: &ImportDeclarationSpecifier) -> SpecifierText {
+        let text = specifier.span().source_text(self.source_text);
+        match specifier {
+            ImportDeclarationSpecifier::ImportDefaultSpecifier(_) => {
+                SpecifierText { kind: SpecifierKind::Default, text: text.to_string() }
+            }
+            ImportDeclarationSpecifier::ImportNamespaceSpecifier(_) => {
+                SpecifierText { kind: SpecifierKind::Namespace, text: text.to_string() }
+            }
+            ImportDeclarationSpecifier::ImportSpecifier(_) => {
+                SpecifierText { kind: SpecifierKind::Named, text: text.to_string() }
+            }
+        }
+    }
+
+    /// `import type` cannot have both a default and named bindings,
+    /// so the default import gets its own declaration.
+    fn render_type_only(&self, specifiers: &[&ImportDeclarationSpecifier]) -> String {
+        let (default, rest): (Vec<_>, Vec<_>) = specifiers
+            .iter()
+            .map(|specifier| {
+                let text = self.specifier_text(specifier);
+                // `import type { type A }` is invalid.
+                if is_inline_type_specifier(specifier) {
+                    let stripped = text.text["type".len()..].trim_start().to_string();
+                    SpecifierText { text: stripped, ..text }
+                } else {
+                    text
+                }
+            })
+            .partition(|specifier| specifier.kind == SpecifierKind::Default);
+        let mut statements = vec![];
+        if !default.is_empty() {
+            statements.push(self.render(true, &default));
+        }
+        if !rest.is_empty() {
+            statements.push(self.render(true, &rest));
+        }
+        statements.join("\n")
+    }
+
+    fn render(&self, type_only: bool, specifiers: &[SpecifierText]) -> String {
+        let mut clauses = vec![];
+        let mut named = vec![];
+        for specifier in specifiers {
+            match specifier.kind {
+                SpecifierKind::Default | SpecifierKind::Namespace => {
+                    clauses.push(specifier.text.clone());
+                }
+                SpecifierKind::Named => named.push(specifier.text.as_str()),
+            }
+        }
+        if !named.is_empty() {
+            clauses.push(format!("{{ {} }}", named.join(", ")));
+        }
+        let type_keyword = if type_only { "type " } else { "" };
+        format!(
+            "import {type_keyword}{} from {}{}",
+            clauses.join(", "),
+            self.source,
+            self.semicolon
+        )
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("import Foo from 'foo'; const foo: Foo = new Foo();", None),
+        ("import foo from 'foo'; const foo: foo.Foo = foo.fn();", None),
+        ("import { A, B } from 'foo'; const foo: A = B(A);", None),
+        ("import type Foo from 'foo'; const foo: Foo = 1;", None),
+        ("import type { A, B } from 'foo'; type T = A | B;", None),
+        ("import { type A, B } from 'foo'; type T = A; B();", None),
+        ("import * as foo from 'foo'; foo.bar();", None),
+        ("import { A } from 'foo';", None),
+        ("import 'foo';", None),
+        ("import { Foo } from 'foo'; export { Foo };", None),
+        ("import Foo from 'foo'; export default Foo;", None),
+        ("import Foo from 'foo'; const x = <Foo />;", None),
+        ("import Foo from 'foo'; let foo: Foo;", Some(json!([{ "prefer": "no-type-imports" }]))),
+    ];
+
+    let fail = vec![
+        ("import Foo from 'foo'; let foo: Foo;", None),
+        ("import { A, B } from 'foo'; type T = A | B;", None),
+        ("import { A, B } from 'foo'; type T = A; B();", None),
+        ("import Foo, { Bar } from 'foo'; let foo: Foo = Bar;", None),
+        ("import Foo, { Bar } from 'foo'; let foo: Foo; let bar: Bar;", None),
+        ("import { A, type B } from 'foo'; type T = A | B;", None),
+        ("import { A } from 'foo' assert { type: 'json' }; let a: A;", None),
+        (
+            "import type Foo from 'foo'; let foo: Foo;",
+            Some(json!([{ "prefer": "no-type-imports" }])),
+        ),
+        (
+            "import { type A, B } from 'foo'; type T = A | B;",
+            Some(json!([{ "prefer": "no-type-imports" }])),
+        ),
+    ];
+
+    let fix = vec![
+        ("import Foo from 'foo'; let foo: Foo;", "import type Foo from 'foo'; let foo: Foo;", None),
+        (
+            "import { A, B as C } from 'foo'; type T = A | C;",
+            "import type { A, B as C } from 'foo'; type T = A | C;",
+            None,
+        ),
+        (
+            "import Foo, { Bar } from 'foo'; let foo: Foo; let bar: Bar;",
+            "import type Foo from 'foo';\nimport type { Bar } from 'foo'; let foo: Foo; let bar: Bar;",
+            None,
+        ),
+        (
+            "import { A, B } from 'foo'\ntype T = A; B();",
+            "import type { A } from 'foo'\nimport { B } from 'foo'\ntype T = A; B();",
+            None,
+        ),
+        (
+            "import { A, B } from 'foo'; type T = A; B();",
+            "import { type A, B } from 'foo'; type T = A; B();",
+            Some(json!([{ "fixStyle": "inline-type-imports" }])),
+        ),
+        (
+            "import Foo, { Bar } from 'foo'; let foo: Foo = Bar;",
+            "import type Foo from 'foo';\nimport { Bar } from 'foo'; let foo: Foo = Bar;",
+            Some(json!([{ "fixStyle": "inline-type-imports" }])),
+        ),
+        (
+            "import { A, type B } from 'foo'; type T = A | B;",
+            "import type { A, B } from 'foo'; type T = A | B;",
+            None,
+        ),
+        (
+            "import type Foo from 'foo'; let foo: Foo;",
+            "import Foo from 'foo'; let foo: Foo;",
+            Some(json!([{ "prefer": "no-type-imports" }])),
+        ),
+        (
+            "import { type A, B } from 'foo'; type T = A | B;",
+            "import { A, B } from 'foo'; type T = A | B;",
+            Some(json!([{ "prefer": "no-type-imports" }])),
+        ),
+    ];
+
+    Tester::new(ConsistentTypeImports::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
@@ -1,4 +1,4 @@
-use crate::Fix;
+use crate::{Fix, Suggestion};
 use oxc_ast::AstKind;
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -85,7 +85,8 @@ declare_oxc_lint!(
     /// Whether to enable auto-fixing in which the `any` type is converted to the `unknown` type.
     /// `false` by default.
     NoExplicitAny,
-    restriction
+    restriction,
+    fix
 );
 
 impl Rule for NoExplicitAny {
@@ -100,7 +101,19 @@ impl Rule for NoExplicitAny {
                 Fix::new("unknown", any.span)
             });
         } else {
-            ctx.diagnostic(NoExplicitAnyDiagnostic(any.span));
+            ctx.diagnostic_with_suggestions(
+                NoExplicitAnyDiagnostic(any.span),
+                vec![
+                    Suggestion::new(
+                        "Use `unknown` instead, this will force you to explicitly, and safely assert the type is correct.",
+                        Fix::new("unknown", any.span),
+                    ),
+                    Suggestion::new(
+                        "Use `never` instead, this is useful when instantiating generic type parameters that you don't need to know the type of.",
+                        Fix::new("never", any.span),
+                    ),
+                ],
+            );
         }
     }
 
@@ -637,6 +650,25 @@ mod tests {
             // NOTE: no current way to check that fixes don't occur when `ignoreRestArgs` is
             // `true`, since no fix technically occurs and `expect_fix()` panics without a fix.
         ];
-        Tester::new(NoExplicitAny::NAME, pass, fail).expect_fix(fixes).test_and_snapshot();
+        let suggestions = vec![
+            (
+                "const number: any = 1",
+                vec!["const number: unknown = 1", "const number: never = 1"],
+                None,
+            ),
+            (
+                "function foo(args: Array<any>): void {}",
+                vec![
+                    "function foo(args: Array<unknown>): void {}",
+                    "function foo(args: Array<never>): void {}",
+                ],
+                None,
+            ),
+            ("function foo(args: any[]): void {}", vec![], Some(json!([{ "fixToUnknown": true }]))),
+        ];
+        Tester::new(NoExplicitAny::NAME, pass, fail)
+            .expect_fix(fixes)
+            .expect_suggestions(suggestions)
+            .test_and_snapshot();
     }
 }
@@ -0,0 +1,242 @@
+use oxc_ast::{
+    ast::{CallExpression, Expression},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{ast_util::get_declaration_of_variable, context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("typescript-eslint(no-floating-promises): Promises must be awaited, end with a call to `.catch`, or end with a call to `.then` with a rejection handler.")]
+#[diagnostic(
+    severity(warning),
+    help("Add `await`, handle the rejection, or mark the promise as ignored with `void`.")
+)]
+struct NoFloatingPromisesDiagnostic(#[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoFloatingPromises {
+    /// Do not report the immediately invoked async functions, e.g. `(async () => {})()`.
+    ignore_iife: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Require the Promise-like statements to be handled appropriately.
+    ///
+    /// With a type checker, the statements with a Promise-like type are checked.
+    /// Without the type information only the expressions known to return a Promise are checked:
+    /// `new Promise()`, the static `Promise` methods, `fetch()`, the `.then()` and `.finally()`
+    /// chains, and the calls of the async functions declared in the same file.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A floating Promise is created without any code set up to handle the errors it might throw,
+    /// the rejections are lost and the operations may run in an unexpected order.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // Bad
+    /// async function foo() {}
+    /// foo();
+    /// Promise.resolve('value').then(() => {});
+    ///
+    /// // Good
+    /// await foo();
+    /// void foo();
+    /// Promise.resolve('value').then(() => {}, () => {});
+    /// Promise.resolve('value').catch(() => {});
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// `ignoreIIFE`, `false` by default.
+    NoFloatingPromises,
+    suspicious
+);
+
+/// The static methods of `Promise` returning a Promise.
+const PROMISE_STATIC_METHODS: [&str; 6] = ["all", "allSettled", "any", "race", "reject", "resolve"];
+
+impl Rule for NoFloatingPromises {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let ignore_iife = value
+            .get(0)
+            .and_then(|config| config.get("ignoreIIFE"))
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or_default();
+        Self { ignore_iife }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::ExpressionStatement(stmt) = node.kind() else { return };
+        if self.is_unhandled_promise(&stmt.expression, ctx) {
+            ctx.diagnostic(NoFloatingPromisesDiagnostic(stmt.span));
+        }
+    }
+}
+
+impl NoFloatingPromises {
+    fn is_unhandled_promise(&self, expr: &Expression, ctx: &LintContext) -> bool {
+        match expr.without_parenthesized() {
+            // `a, promise`
+            Expression::SequenceExpression(expr) => {
+                expr.expressions.iter().any(|expr| self.is_unhandled_promise(expr, ctx))
+            }
+            // `cond ? promise : other`
+            Expression::ConditionalExpression(expr) => {
+                self.is_unhandled_promise(&expr.consequent, ctx)
+                    || self.is_unhandled_promise(&expr.alternate, ctx)
+            }
+            // `a && promise`
+            Expression::LogicalExpression(expr) => self.is_unhandled_promise(&expr.right, ctx),
+            expr => {
+                let Some(type_info) = ctx.type_at(expr.span()) else {
+                    return self.is_unhandled_promise_without_types(expr, ctx);
+                };
+                type_info.is_thenable() && !self.is_handled(expr, ctx)
+            }
+        }
+    }
+
+    fn is_unhandled_promise_without_types(&self, expr: &Expression, ctx: &LintContext) -> bool {
+        match expr {
+            Expression::NewExpression(new_expr) => {
+                matches!(&new_expr.callee, Expression::Identifier(ident)
+                    if ident.name == "Promise" && ctx.semantic().is_reference_to_global_variable(ident))
+            }
+            Expression::CallExpression(call_expr) => self.is_unhandled_promise_call(call_expr, ctx),
+            _ => false,
+        }
+    }
+
+    /// A Promise-like with a rejection handler, or an ignored immediately invoked async function.
+    fn is_handled(&self, expr: &Expression, ctx: &LintContext) -> bool {
+        let Expression::CallExpression(call_expr) = expr else { return false };
+        match call_expr.callee.without_parenthesized() {
+            Expression::MemberExpression(member_expr) => match member_expr.static_property_name() {
+                Some("catch") => !call_expr.arguments.is_empty(),
+                Some("then") => call_expr.arguments.len() >= 2,
+                Some("finally") => !self.is_unhandled_promise(member_expr.object(), ctx),
+                _ => false,
+            },
+            callee => self.ignore_iife && is_async_function(callee),
+        }
+    }
+
+    fn is_unhandled_promise_call(&self, call_expr: &CallExpression, ctx: &LintContext) -> bool {
+        match call_expr.callee.without_parenthesized() {
+            Expression::MemberExpression(member_expr) => {
+                let Some(name) = member_expr.static_property_name() else { return false };
+                match name {
+                    // `promise.catch(handler)`
+                    "catch" => call_expr.arguments.is_empty(),
+                    // `promise.then(onFulfilled, onRejected)`
+                    "then" => call_expr.arguments.len() < 2,
+                    // `promise.finally()` rethrows the rejection
+                    "finally" => self.is_unhandled_promise(member_expr.object(), ctx),
+                    _ if PROMISE_STATIC_METHODS.contains(&name) => {
+                        matches!(member_expr.object(), Expression::Identifier(ident)
+                            if ident.name == "Promise" && ctx.semantic().is_reference_to_global_variable(ident))
+                    }
+                    _ => false,
+                }
+            }
+            Expression::Identifier(ident) => {
+                if ident.name == "fetch" && ctx.semantic().is_reference_to_global_variable(ident) {
+                    return true;
+                }
+                get_declaration_of_variable(ident, ctx).is_some_and(|decl| match decl.kind() {
+                    AstKind::Function(func) => func.r#async && !func.generator,
+                    AstKind::VariableDeclarator(decl) => {
+                        decl.init.as_ref().is_some_and(is_async_function)
+                    }
+                    _ => false,
+                })
+            }
+            // `(async () => {})()`
+            callee => !self.ignore_iife && is_async_function(callee),
+        }
+    }
+}
+
+fn is_async_function(expr: &Expression) -> bool {
+    match expr.without_parenthesized() {
+        Expression::ArrowExpression(arrow) => arrow.r#async,
+        Expression::FunctionExpression(func) => func.r#async && !func.generator,
+        _ => false,
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("async function foo() { await Promise.resolve('value'); }", None),
+        ("async function foo() { void Promise.resolve('value'); }", None),
+        ("Promise.resolve('value').then(() => {}, () => {});", None),
+        ("Promise.resolve('value').catch(() => {});", None),
+        ("Promise.resolve('value').then(() => {}).catch(() => {});", None),
+        ("Promise.reject(new Error('message')).finally(() => {}).catch(() => {});", None),
+        ("Promise.all([]).catch(() => {});", None),
+        ("const promise = new Promise((resolve) => resolve());", None),
+        ("async function foo() { return Promise.resolve(); }", None),
+        ("function foo() {} foo();", None),
+        ("function* foo() {} foo();", None),
+        ("async function* foo() {} foo();", None),
+        ("async function foo() {} const promise = foo();", None),
+        ("async function foo() {} foo().catch(() => {});", None),
+        ("const foo = async () => {}; await foo();", None),
+        ("function foo(fetch) { fetch('url'); }", None),
+        ("class Promise {} Promise.resolve();", None),
+        ("foo.bar();", None),
+        ("(async () => { await doSomething(); })();", Some(json!([{ "ignoreIIFE": true }]))),
+    ];
+
+    let fail = vec![
+        ("Promise.resolve('value');", None),
+        ("Promise.resolve('value').then(() => {});", None),
+        ("Promise.resolve('value').finally(() => {});", None),
+        ("Promise.reject(new Error('message')).catch();", None),
+        ("Promise.all([foo, bar]);", None),
+        ("new Promise((resolve) => resolve());", None),
+        ("fetch('url');", None),
+        ("async function foo() {} foo();", None),
+        ("const foo = async () => {}; foo();", None),
+        ("const foo = async function () {}; foo();", None),
+        ("(async () => { await doSomething(); })();", None),
+        ("(async function () { await doSomething(); })();", None),
+        ("foo.then(() => {});", None),
+        ("condition && Promise.resolve();", None),
+        ("condition ? Promise.resolve() : null;", None),
+        ("foo(), Promise.resolve();", None),
+    ];
+
+    Tester::new(NoFloatingPromises::NAME, pass, fail).test_and_snapshot();
+
+    let pass = vec![
+        "declare function foo(): Promise<void>; await foo();",
+        "declare function foo(): Promise<void>; void foo();",
+        "declare function foo(): Promise<void>; foo().then(() => {}, () => {});",
+        "declare function foo(): number; foo();",
+        "declare const value: string; value;",
+    ];
+
+    let fail = vec![
+        "declare function foo(): Promise<void>; foo();",
+        "declare function foo(): Promise<void> | undefined; foo();",
+        "declare function foo(): Promise<void>; foo().catch();",
+        "declare const promise: Promise<void>; promise;",
+        "declare const promise: Promise<void>; condition && promise;",
+    ];
+
+    Tester::new_without_config(NoFloatingPromises::NAME, pass, fail).with_type_checker(true).test();
+}
@@ -0,0 +1,142 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{context::LintContext, fixer::Fix, rule::Rule, AstNode, Suggestion};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("typescript-eslint(no-non-null-assertion): Forbidden non-null assertion.")]
+#[diagnostic(
+    severity(warning),
+    help("Handle the `null` and `undefined` cases instead of asserting them away.")
+)]
+struct NoNonNullAssertionDiagnostic(#[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoNonNullAssertion;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow non-null assertions using the `!` postfix operator.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// The `!` operator tells the type checker that the value is never `null` or `undefined`
+    /// without any runtime check, it hides the bugs the type checker would catch.
+    ///
+    /// ### Example
+    /// ```typescript
+    /// // Bad
+    /// const includesBaz = foo.bar!.includes('baz');
+    ///
+    /// // Good
+    /// const includesBaz = foo.bar?.includes('baz') ?? false;
+    /// ```
+    NoNonNullAssertion,
+    restriction,
+    suggestion
+);
+
+impl Rule for NoNonNullAssertion {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::TSNonNullExpression(expr) = node.kind() else { return };
+        let diagnostic = NoNonNullAssertionDiagnostic(expr.span);
+
+        // `x!` always ends with the `!`, even when `x` is parenthesized.
+        let bang = Span::new(expr.span.end - 1, expr.span.end);
+        let replacement = match ctx.nodes().parent_kind(node.id()) {
+            Some(AstKind::MemberExpression(member_expr))
+                if !member_expr.optional()
+                    && member_expr.object().span() == expr.span
+                    && !is_assignment_target(node, ctx) =>
+            {
+                if member_expr.is_computed() {
+                    "?."
+                } else {
+                    "?"
+                }
+            }
+            Some(AstKind::CallExpression(call_expr))
+                if !call_expr.optional && call_expr.callee.span() == expr.span =>
+            {
+                "?."
+            }
+            _ => {
+                ctx.diagnostic(diagnostic);
+                return;
+            }
+        };
+
+        ctx.diagnostic_with_suggestions(
+            diagnostic,
+            vec![Suggestion::new(
+                "Consider using the optional chain operator `?.` instead.",
+                Fix::new(replacement, bang),
+            )],
+        );
+    }
+}
+
+/// `x!.y = 1` cannot be written as `x?.y = 1`.
+fn is_assignment_target(node: &AstNode, ctx: &LintContext) -> bool {
+    // The parent of `x!` is the member expression `x!.y`.
+    ctx.nodes().ancestors(node.id()).nth(2).is_some_and(|parent_id| {
+        matches!(
+            ctx.nodes().kind(parent_id),
+            AstKind::SimpleAssignmentTarget(_)
+                | AstKind::AssignmentTarget(_)
+                | AstKind::UpdateExpression(_)
+        )
+    })
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("x;", None),
+        ("x.y;", None),
+        ("x.y.z;", None),
+        ("x?.y.z;", None),
+        ("x?.y?.z;", None),
+        ("!x;", None),
+    ];
+
+    let fail = vec![
+        ("x!;", None),
+        ("x!.y;", None),
+        ("x.y!;", None),
+        ("!x!.y;", None),
+        ("x!.y?.z;", None),
+        ("x![y];", None),
+        ("x![y]?.z;", None),
+        ("x.y.z!();", None),
+        ("x.y?.z!();", None),
+        ("x!!!;", None),
+        ("x!!.y;", None),
+        ("x.y!!;", None),
+        ("x.y.z!!();", None),
+        ("x!.y = 1;", None),
+        ("x!.y++;", None),
+        ("(x!).y;", None),
+    ];
+
+    let suggestions = vec![
+        ("x!.y;", vec!["x?.y;"], None),
+        ("x![y];", vec!["x?.[y];"], None),
+        ("x.y.z!();", vec!["x.y.z?.();"], None),
+        ("x!;", vec![], None),
+        ("x!.y = 1;", vec![], None),
+        ("x!.y++;", vec![], None),
+    ];
+
+    Tester::new(NoNonNullAssertion::NAME, pass, fail)
+        .expect_suggestions(suggestions)
+        .test_and_snapshot();
+}
@@ -0,0 +1,115 @@
+use oxc_ast::{
+    ast::{Expression, TSType, TSTypeAnnotation},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::operator::AssignmentOperator;
+
+use crate::{context::LintContext, rule::Rule, AstNode, TypeKind};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("typescript-eslint(no-unsafe-assignment): Unsafe assignment of an `any` value.")]
+#[diagnostic(
+    severity(warning),
+    help("Give the value a type, or assign it to an `unknown` and narrow it before use.")
+)]
+struct NoUnsafeAssignmentDiagnostic(#[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnsafeAssignment;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow assigning a value with type `any` to variables and properties.
+    ///
+    /// The rule needs a type checker, it reports nothing without the type information.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// The `any` type turns off the type checking, assigning it to a variable spreads the
+    /// unchecked value through the code, e.g. the result of `JSON.parse`.
+    ///
+    /// ### Example
+    /// ```typescript
+    /// // Bad
+    /// const config = JSON.parse(text);
+    /// this.value = anyValue;
+    ///
+    /// // Good
+    /// const config: unknown = JSON.parse(text);
+    /// const config = JSON.parse(text) as Config;
+    /// ```
+    NoUnsafeAssignment,
+    suspicious
+);
+
+impl Rule for NoUnsafeAssignment {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::VariableDeclarator(decl) => {
+                let Some(init) = &decl.init else { return };
+                if !is_unknown(decl.id.type_annotation.as_deref()) && is_any(init, ctx) {
+                    ctx.diagnostic(NoUnsafeAssignmentDiagnostic(decl.span));
+                }
+            }
+            AstKind::PropertyDefinition(prop) => {
+                let Some(value) = &prop.value else { return };
+                if !is_unknown(prop.type_annotation.as_deref()) && is_any(value, ctx) {
+                    ctx.diagnostic(NoUnsafeAssignmentDiagnostic(prop.span));
+                }
+            }
+            AstKind::AssignmentExpression(expr) if expr.operator == AssignmentOperator::Assign => {
+                let target_is_unknown = ctx
+                    .type_at(expr.left.span())
+                    .is_some_and(|type_info| type_info.kinds == [TypeKind::Unknown]);
+                if !target_is_unknown && is_any(&expr.right, ctx) {
+                    ctx.diagnostic(NoUnsafeAssignmentDiagnostic(expr.span));
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn is_any(expr: &Expression, ctx: &LintContext) -> bool {
+    ctx.type_at(expr.span()).is_some_and(|type_info| type_info.is_any())
+}
+
+/// `: unknown`, any value can be assigned safely.
+fn is_unknown(type_annotation: Option<&TSTypeAnnotation>) -> bool {
+    type_annotation
+        .is_some_and(|annotation| matches!(annotation.type_annotation, TSType::TSUnknownKeyword(_)))
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "declare const value: number; const a = value;",
+        "declare const value: any; const a: unknown = value;",
+        "declare const value: any; class A { a: unknown = value; }",
+        "declare const value: any; declare const target: unknown; target = value;",
+        "declare const value: any; let a; a += value;",
+        "const a = 1;",
+        "let a;",
+    ];
+
+    let fail = vec![
+        "declare const value: any; const a = value;",
+        "declare const value: any; const a: number = value;",
+        "declare function parse(): any; const { a } = parse();",
+        "declare const value: any; class A { a = value; }",
+        "declare const value: any; let a; a = value;",
+    ];
+
+    Tester::new_without_config(NoUnsafeAssignment::NAME, pass, fail)
+        .with_type_checker(true)
+        .test_and_snapshot();
+}
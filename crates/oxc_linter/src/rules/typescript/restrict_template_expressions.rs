@@ -0,0 +1,153 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use serde_json::Value;
+
+use crate::{context::LintContext, rule::Rule, AstNode, TypeInfo, TypeKind};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("typescript-eslint(restrict-template-expressions): Invalid type {0:?} of template literal expression.")]
+#[diagnostic(
+    severity(warning),
+    help("Convert the value to a string explicitly, e.g. with `String()`.")
+)]
+struct RestrictTemplateExpressionsDiagnostic(String, #[label] pub Span);
+
+#[derive(Debug, Clone)]
+pub struct RestrictTemplateExpressions {
+    allow_any: bool,
+    allow_boolean: bool,
+    allow_never: bool,
+    allow_nullish: bool,
+    allow_number: bool,
+    allow_reg_exp: bool,
+}
+
+impl Default for RestrictTemplateExpressions {
+    fn default() -> Self {
+        Self {
+            allow_any: true,
+            allow_boolean: true,
+            allow_never: false,
+            allow_nullish: true,
+            allow_number: true,
+            allow_reg_exp: true,
+        }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce the expressions of the template literals to be of a type with a meaningful
+    /// string representation.
+    ///
+    /// The rule needs a type checker, it reports nothing without the type information.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// The objects are converted to `"[object Object]"` in a template literal, which is rarely
+    /// the intent.
+    ///
+    /// ### Example
+    /// ```typescript
+    /// // Bad
+    /// const user = { name: 'oxc' };
+    /// const message = `Hello ${user}`;
+    ///
+    /// // Good
+    /// const message = `Hello ${user.name}`;
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// `allowAny`, `allowBoolean`, `allowNullish`, `allowNumber` and `allowRegExp`, `true` by
+    /// default, `allowNever`, `false` by default.
+    RestrictTemplateExpressions,
+    pedantic
+);
+
+impl Rule for RestrictTemplateExpressions {
+    fn from_configuration(value: Value) -> Self {
+        let config = value.get(0);
+        let option = |name: &str, default: bool| {
+            config.and_then(|config| config.get(name)).and_then(Value::as_bool).unwrap_or(default)
+        };
+        let default = Self::default();
+        Self {
+            allow_any: option("allowAny", default.allow_any),
+            allow_boolean: option("allowBoolean", default.allow_boolean),
+            allow_never: option("allowNever", default.allow_never),
+            allow_nullish: option("allowNullish", default.allow_nullish),
+            allow_number: option("allowNumber", default.allow_number),
+            allow_reg_exp: option("allowRegExp", default.allow_reg_exp),
+        }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::TemplateLiteral(template) = node.kind() else { return };
+        // The tags receive the values, e.g. `sql`
+        if matches!(ctx.nodes().parent_kind(node.id()), Some(AstKind::TaggedTemplateExpression(_)))
+        {
+            return;
+        }
+        for expr in &template.expressions {
+            let Some(type_info) = ctx.type_at(expr.span()) else { continue };
+            if !self.is_allowed(&type_info) {
+                ctx.diagnostic(RestrictTemplateExpressionsDiagnostic(type_info.text, expr.span()));
+            }
+        }
+    }
+}
+
+impl RestrictTemplateExpressions {
+    fn is_allowed(&self, type_info: &TypeInfo) -> bool {
+        type_info.kinds.iter().all(|kind| match kind {
+            TypeKind::String => true,
+            TypeKind::Number | TypeKind::BigInt => self.allow_number,
+            TypeKind::Any => self.allow_any,
+            TypeKind::Boolean => self.allow_boolean,
+            TypeKind::Never => self.allow_never,
+            TypeKind::Null | TypeKind::Undefined => self.allow_nullish,
+            TypeKind::Object => self.allow_reg_exp && type_info.text == "RegExp",
+            TypeKind::Unknown | TypeKind::Void | TypeKind::Symbol | TypeKind::Thenable => false,
+        })
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("declare const value: string; `${value}`;", None),
+        ("declare const value: number; `${value}`;", None),
+        ("declare const value: string | number | boolean | null | undefined; `${value}`;", None),
+        ("declare const value: any; `${value}`;", None),
+        ("declare const value: RegExp; `${value}`;", None),
+        ("declare const value: User; sql`${value}`;", None),
+        ("`${1}`;", None),
+        ("`${value}`;", None),
+    ];
+
+    let fail = vec![
+        ("declare const value: User; `${value}`;", None),
+        ("declare const value: unknown; `${value}`;", None),
+        ("declare const value: string | User; `${value}`;", None),
+        ("declare const value: Promise<string>; `${value}`;", None),
+        ("declare const value: never; `${value}`;", None),
+        ("declare const value: number; `${value}`;", Some(json!([{ "allowNumber": false }]))),
+        ("declare const value: any; `${value}`;", Some(json!([{ "allowAny": false }]))),
+        ("declare const value: null; `${value}`;", Some(json!([{ "allowNullish": false }]))),
+        ("declare const value: RegExp; `${value}`;", Some(json!([{ "allowRegExp": false }]))),
+    ];
+
+    Tester::new(RestrictTemplateExpressions::NAME, pass, fail)
+        .with_type_checker(true)
+        .test_and_snapshot();
+}
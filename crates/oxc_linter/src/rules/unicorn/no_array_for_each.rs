@@ -0,0 +1,101 @@
+use oxc_ast::{ast::Expression, AstKind};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{context::LintContext, rule::Rule, utils::get_node_name, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint-plugin-unicorn(no-array-for-each): Use `for…of` instead of `.forEach(…)`.")]
+#[diagnostic(
+    severity(warning),
+    help("A `for…of` loop supports `break`, `continue`, `await` and early `return`.")
+)]
+struct NoArrayForEachDiagnostic(#[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoArrayForEach;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Prefer `for…of` over the `forEach` method.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A `for…of` loop is easier to read, it can be stopped early with `break` or `return`,
+    /// and it works with `await` in the body.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // Bad
+    /// array.forEach(element => {
+    ///     bar(element);
+    /// });
+    ///
+    /// // Good
+    /// for (const element of array) {
+    ///     bar(element);
+    /// }
+    /// ```
+    NoArrayForEach,
+    restriction
+);
+
+/// The objects whose `forEach` is not the array method.
+const IGNORED_OBJECTS: [&str; 3] = ["Children", "React.Children", "Effect"];
+
+impl Rule for NoArrayForEach {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call_expr) = node.kind() else { return };
+        let Expression::MemberExpression(member_expr) = call_expr.callee.without_parenthesized()
+        else {
+            return;
+        };
+        if member_expr.is_computed() {
+            return;
+        }
+        let Some((span, "forEach")) = member_expr.static_property_info() else { return };
+        // `forEach(callback, thisArg)`
+        if call_expr.arguments.is_empty() || call_expr.arguments.len() > 2 {
+            return;
+        }
+        if IGNORED_OBJECTS.contains(&get_node_name(member_expr.object()).as_str()) {
+            return;
+        }
+        ctx.diagnostic(NoArrayForEachDiagnostic(span));
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("new foo.forEach(element => bar())", None),
+        ("forEach(element => bar())", None),
+        ("foo.notForEach(element => bar())", None),
+        ("foo[forEach](element => bar())", None),
+        ("foo.forEach()", None),
+        ("foo.forEach(a, b, c)", None),
+        ("React.Children.forEach(children, (child) => {});", None),
+        ("Children.forEach(children, (child) => {});", None),
+        ("Effect.forEach(items, (item) => item);", None),
+        ("for (const element of array) { bar(element); }", None),
+    ];
+
+    let fail = vec![
+        ("foo.forEach?.(element => bar(element))", None),
+        ("foo?.forEach(element => bar(element))", None),
+        ("array.forEach(element => { bar(element); });", None),
+        ("array.forEach(function (element) { bar(element); });", None),
+        ("array.forEach(bar, thisArg);", None),
+        ("Object.keys(foo).forEach(key => bar(key));", None),
+        ("foo.bar().forEach(handler);", None),
+    ];
+
+    Tester::new(NoArrayForEach::NAME, pass, fail).test_and_snapshot();
+}
@@ -32,7 +32,8 @@ declare_oxc_lint!(
     /// [1,2,3] instanceof Array;
     /// ```
     NoInstanceofArray,
-    correctness
+    correctness,
+    fix
 );
 
 impl Rule for NoInstanceofArray {
@@ -0,0 +1,288 @@
+use oxc_ast::{
+    ast::{Argument, Expression, Statement, VariableDeclarationKind},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{context::LintContext, fixer::Fix, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint-plugin-unicorn(no-useless-undefined): Do not use useless `undefined`.")]
+#[diagnostic(severity(warning), help("Remove the `undefined`, it is the default value."))]
+struct NoUselessUndefinedDiagnostic(#[label] pub Span);
+
+#[derive(Debug, Clone)]
+pub struct NoUselessUndefined {
+    /// Check the trailing `undefined` arguments of the function calls.
+    check_arguments: bool,
+    /// Check the arrow functions returning `undefined`, e.g. `() => undefined`.
+    check_arrow_function_body: bool,
+}
+
+impl Default for NoUselessUndefined {
+    fn default() -> Self {
+        Self { check_arguments: true, check_arrow_function_body: true }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow useless `undefined`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// `undefined` is the default value of the variables, parameters, return values and the missing arguments,
+    /// writing it explicitly is only noise.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // Bad
+    /// let foo = undefined;
+    /// const { bar = undefined } = baz;
+    /// function qux(quux = undefined) { return undefined; }
+    /// foo(bar, undefined);
+    ///
+    /// // Good
+    /// let foo;
+    /// const { bar } = baz;
+    /// function qux(quux) { return; }
+    /// foo(bar);
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// `checkArguments` and `checkArrowFunctionBody`, both `true` by default.
+    NoUselessUndefined,
+    pedantic,
+    fix
+);
+
+/// The functions where an `undefined` argument is meaningful.
+const IGNORED_CALLEE_NAMES: [&str; 22] = [
+    "add",
+    "createContext",
+    "equal",
+    "has",
+    "include",
+    "includes",
+    "indexOf",
+    "is",
+    "lastIndexOf",
+    "not",
+    "notEqual",
+    "notStrictEqual",
+    "push",
+    "set",
+    "setState",
+    "strictEqual",
+    "toBe",
+    "toContain",
+    "toEqual",
+    "toHaveBeenCalledWith",
+    "unshift",
+    "useState",
+];
+
+impl Rule for NoUselessUndefined {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let config = value.get(0);
+        let get_bool = |name: &str| {
+            config
+                .and_then(|config| config.get(name))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or(true)
+        };
+        Self {
+            check_arguments: get_bool("checkArguments"),
+            check_arrow_function_body: get_bool("checkArrowFunctionBody"),
+        }
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            // `return undefined;`
+            AstKind::ReturnStatement(stmt) => {
+                let Some(argument) = &stmt.argument else { return };
+                if is_undefined(argument, ctx) {
+                    let span =
+                        Span::new(stmt.span.start + "return".len() as u32, argument.span().end);
+                    report(argument.span(), span, ctx);
+                }
+            }
+            // `yield undefined`
+            AstKind::YieldExpression(expr) => {
+                let Some(argument) = &expr.argument else { return };
+                if !expr.delegate && is_undefined(argument, ctx) {
+                    let span =
+                        Span::new(expr.span.start + "yield".len() as u32, argument.span().end);
+                    report(argument.span(), span, ctx);
+                }
+            }
+            // `() => undefined`
+            AstKind::ArrowExpression(arrow) if self.check_arrow_function_body => {
+                if !arrow.expression {
+                    return;
+                }
+                let Some(Statement::ExpressionStatement(stmt)) = arrow.body.statements.first()
+                else {
+                    return;
+                };
+                if is_undefined(&stmt.expression, ctx) {
+                    let span = stmt.expression.span();
+                    ctx.diagnostic_with_fix(NoUselessUndefinedDiagnostic(span), || {
+                        Fix::new("{}", span)
+                    });
+                }
+            }
+            // `let foo = undefined`
+            AstKind::VariableDeclarator(decl) => {
+                let Some(init) = &decl.init else { return };
+                if decl.kind != VariableDeclarationKind::Const && is_undefined(init, ctx) {
+                    report(init.span(), initializer_span(decl.span.start, init.span(), ctx), ctx);
+                }
+            }
+            // `function foo(bar = undefined) {}` and `const { foo = undefined } = bar`
+            AstKind::AssignmentPattern(pattern) => {
+                if is_undefined(&pattern.right, ctx) {
+                    let span = initializer_span(pattern.span.start, pattern.right.span(), ctx);
+                    report(pattern.right.span(), span, ctx);
+                }
+            }
+            // `[foo = undefined] = bar`
+            AstKind::AssignmentTargetWithDefault(target) => {
+                if is_undefined(&target.init, ctx) {
+                    let span = initializer_span(target.span.start, target.init.span(), ctx);
+                    report(target.init.span(), span, ctx);
+                }
+            }
+            // `foo(bar, undefined)`
+            AstKind::CallExpression(call_expr) if self.check_arguments => {
+                if is_ignored_callee(&call_expr.callee) {
+                    return;
+                }
+                let arguments = &call_expr.arguments;
+                let undefined_count = arguments
+                    .iter()
+                    .rev()
+                    .take_while(|argument| {
+                        matches!(argument, Argument::Expression(expr) if is_undefined(expr, ctx))
+                    })
+                    .count();
+                if undefined_count == 0 {
+                    return;
+                }
+                let first_undefined = arguments.len() - undefined_count;
+                let last_span = arguments[arguments.len() - 1].span();
+                let span = if first_undefined == 0 {
+                    // `foo(undefined,)` -> `foo()`
+                    Span::new(arguments[0].span().start, call_expr.span.end - 1)
+                } else {
+                    Span::new(arguments[first_undefined - 1].span().end, last_span.end)
+                };
+                let diagnostic_span =
+                    Span::new(arguments[first_undefined].span().start, last_span.end);
+                report(diagnostic_span, span, ctx);
+            }
+            _ => {}
+        }
+    }
+}
+
+fn report(diagnostic_span: Span, fix_span: Span, ctx: &LintContext) {
+    ctx.diagnostic_with_fix(NoUselessUndefinedDiagnostic(diagnostic_span), || {
+        Fix::new("", fix_span)
+    });
+}
+
+fn is_undefined(expr: &Expression, ctx: &LintContext) -> bool {
+    matches!(expr, Expression::Identifier(ident)
+        if ident.name == "undefined" && ctx.semantic().is_reference_to_global_variable(ident))
+}
+
+fn is_ignored_callee(callee: &Expression) -> bool {
+    let name = match callee.without_parenthesized() {
+        Expression::Identifier(ident) => ident.name.as_str(),
+        Expression::MemberExpression(member_expr) => {
+            let Some(name) = member_expr.static_property_name() else { return false };
+            name
+        }
+        _ => return false,
+    };
+    IGNORED_CALLEE_NAMES.contains(&name)
+}
+
+/// The span of ` = undefined` in `foo = undefined`, starting after `foo`.
+#[allow(clippy::cast_possible_truncation)]
+fn initializer_span(start: u32, init: Span, ctx: &LintContext) -> Span {
+    let text = Span::new(start, init.start).source_text(ctx.source_text());
+    let before_equal = text.rfind('=').map_or(text, |index| text[..index].trim_end());
+    Span::new(start + before_equal.len() as u32, init.end)
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("function foo() { return; }", None),
+        ("function* foo() { yield; }", None),
+        ("function* foo() { yield* undefined; }", None),
+        ("const foo = () => {};", None),
+        ("let foo;", None),
+        ("const foo = undefined;", None),
+        ("function foo(bar) {}", None),
+        ("const { foo } = bar;", None),
+        ("foo();", None),
+        ("foo(bar);", None),
+        ("foo(undefined, bar);", None),
+        ("set.has(undefined);", None),
+        ("array.push(undefined);", None),
+        ("Object.is(foo, undefined);", None),
+        ("expect(foo).toBe(undefined);", None),
+        ("const [state, setState] = useState(undefined);", None),
+        ("foo(...undefined);", None),
+        ("function foo() { const undefined = 1; return undefined; }", None),
+        ("foo(undefined);", Some(json!([{ "checkArguments": false }]))),
+        ("const foo = () => undefined;", Some(json!([{ "checkArrowFunctionBody": false }]))),
+    ];
+
+    let fail = vec![
+        ("function foo() { return undefined; }", None),
+        ("function* foo() { yield undefined; }", None),
+        ("const foo = () => undefined;", None),
+        ("let foo = undefined;", None),
+        ("var foo = undefined, bar = 1;", None),
+        ("function foo(bar = undefined) {}", None),
+        ("const { foo = undefined } = bar;", None),
+        ("const [foo = undefined] = bar;", None),
+        ("[foo = undefined] = bar;", None),
+        ("foo(undefined);", None),
+        ("foo(bar, undefined);", None),
+        ("foo(bar, undefined, undefined);", None),
+        ("foo.bar(undefined);", None),
+    ];
+
+    let fix = vec![
+        ("function foo() { return undefined; }", "function foo() { return; }", None),
+        ("function* foo() { yield undefined; }", "function* foo() { yield; }", None),
+        ("const foo = () => undefined;", "const foo = () => {};", None),
+        ("let foo = undefined;", "let foo;", None),
+        ("let foo: string | undefined = undefined;", "let foo: string | undefined;", None),
+        ("function foo(bar = undefined) {}", "function foo(bar) {}", None),
+        ("const { foo = undefined } = bar;", "const { foo } = bar;", None),
+        ("[foo = undefined] = bar;", "[foo] = bar;", None),
+        ("foo(undefined);", "foo();", None),
+        ("foo(undefined,);", "foo();", None),
+        ("foo(bar, undefined, undefined);", "foo(bar);", None),
+    ];
+
+    Tester::new(NoUselessUndefined::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
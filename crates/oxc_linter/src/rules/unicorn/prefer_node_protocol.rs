@@ -0,0 +1,140 @@
+use oxc_ast::{
+    ast::{Argument, Expression, ModuleDeclaration, StringLiteral},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_resolver::BUILTINS;
+use oxc_span::{Atom, Span};
+
+use crate::{context::LintContext, fixer::Fix, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint-plugin-unicorn(prefer-node-protocol): Prefer using the `node:` protocol when importing Node.js builtin modules.")]
+#[diagnostic(severity(warning), help("Replace {0:?} with \"node:{0}\"."))]
+struct PreferNodeProtocolDiagnostic(Atom, #[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct PreferNodeProtocol;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Prefer using the `node:` protocol when importing Node.js builtin modules.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// The `node:` protocol makes it clear that the module is a builtin module,
+    /// and it cannot be hijacked by a package with the same name in `node_modules`.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // Bad
+    /// import fs from 'fs';
+    /// const path = require('path');
+    ///
+    /// // Good
+    /// import fs from 'node:fs';
+    /// const path = require('node:path');
+    /// ```
+    PreferNodeProtocol,
+    style,
+    fix
+);
+
+impl Rule for PreferNodeProtocol {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let source = match node.kind() {
+            AstKind::ModuleDeclaration(ModuleDeclaration::ImportDeclaration(decl)) => &decl.source,
+            AstKind::ModuleDeclaration(ModuleDeclaration::ExportNamedDeclaration(decl)) => {
+                let Some(source) = &decl.source else { return };
+                source
+            }
+            AstKind::ModuleDeclaration(ModuleDeclaration::ExportAllDeclaration(decl)) => {
+                &decl.source
+            }
+            AstKind::CallExpression(call_expr) => {
+                let Expression::Identifier(ident) = &call_expr.callee else { return };
+                if ident.name != "require"
+                    || call_expr.arguments.len() != 1
+                    || !ctx.semantic().is_reference_to_global_variable(ident)
+                {
+                    return;
+                }
+                let Argument::Expression(Expression::StringLiteral(source)) =
+                    &call_expr.arguments[0]
+                else {
+                    return;
+                };
+                source
+            }
+            _ => return,
+        };
+        check_source(source, ctx);
+    }
+}
+
+fn check_source(source: &StringLiteral, ctx: &LintContext) {
+    // `fs/promises` is a builtin module, `fs/foo` is not.
+    if !BUILTINS.contains(&source.value.as_str()) {
+        return;
+    }
+    ctx.diagnostic_with_fix(
+        PreferNodeProtocolDiagnostic(source.value.clone(), source.span),
+        || {
+            // Inside of the quotes.
+            let start = source.span.start + 1;
+            Fix::new("node:", Span::new(start, start))
+        },
+    );
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("import unicorn from 'unicorn';", None),
+        ("import fs from './fs';", None),
+        ("import fs from 'unknown-builtin-module';", None),
+        ("import fs from 'node:fs';", None),
+        ("import 'node:fs/promises';", None),
+        ("import fs from 'fs/foo';", None),
+        ("export { default } from 'node:fs';", None),
+        ("export * from 'node:fs';", None),
+        ("const fs = require('node:fs');", None),
+        ("const fs = require('./fs');", None),
+        ("const fs = require(fs);", None),
+        ("const fs = require('fs', 'extra');", None),
+        ("const fs = notRequire('fs');", None),
+        ("const fs = foo.require('fs');", None),
+        ("function foo(require) { require('fs'); }", None),
+        ("const { default: fs } = await import('fs');", None),
+    ];
+
+    let fail = vec![
+        ("import fs from 'fs';", None),
+        ("import fs from \"fs\";", None),
+        ("import * as fsPromises from 'fs/promises';", None),
+        ("import { promises } from 'fs';", None),
+        ("import 'child_process';", None),
+        ("export { default } from 'fs';", None),
+        ("export * from 'fs';", None),
+        ("export * as path from 'path';", None),
+        ("const fs = require('fs');", None),
+        ("const { readFile } = require('fs/promises');", None),
+    ];
+
+    let fix = vec![
+        ("import fs from 'fs';", "import fs from 'node:fs';", None),
+        ("import fs from \"fs\";", "import fs from \"node:fs\";", None),
+        ("import 'fs/promises';", "import 'node:fs/promises';", None),
+        ("export * from 'fs';", "export * from 'node:fs';", None),
+        ("const fs = require('fs');", "const fs = require('node:fs');", None),
+    ];
+
+    Tester::new(PreferNodeProtocol::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
@@ -0,0 +1,134 @@
+use oxc_ast::{
+    ast::{Argument, Expression},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{context::LintContext, fixer::Fix, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint-plugin-unicorn(prefer-string-slice): Prefer `String#slice()` over `String#substr()` and `String#substring()`.")]
+#[diagnostic(severity(warning), help("Replace `{0}()` with `slice()`."))]
+struct PreferStringSliceDiagnostic(&'static str, #[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct PreferStringSlice;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Prefer `String#slice()` over `String#substr()` and `String#substring()`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// `String#substr()` is deprecated, and `String#substring()` silently swaps its arguments
+    /// and treats the negative arguments as `0`, `String#slice()` is the more consistent choice.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // Bad
+    /// foo.substr(start, length);
+    /// foo.substring(indexStart, indexEnd);
+    ///
+    /// // Good
+    /// foo.slice(beginIndex, endIndex);
+    /// ```
+    PreferStringSlice,
+    pedantic,
+    fix
+);
+
+impl Rule for PreferStringSlice {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call_expr) = node.kind() else { return };
+        let Expression::MemberExpression(member_expr) = &call_expr.callee else { return };
+        if member_expr.is_computed() {
+            return;
+        }
+        let (span, method) = match member_expr.static_property_info() {
+            Some((span, "substr")) => (span, "substr"),
+            Some((span, "substring")) => (span, "substring"),
+            _ => return,
+        };
+
+        let diagnostic = PreferStringSliceDiagnostic(method, span);
+        if has_same_result_as_slice(method, &call_expr.arguments) {
+            ctx.diagnostic_with_fix(diagnostic, || Fix::new("slice", span));
+        } else {
+            ctx.diagnostic(diagnostic);
+        }
+    }
+}
+
+/// Only the calls with the same result are fixed, e.g. `substring` differs with the negative arguments.
+fn has_same_result_as_slice(method: &str, arguments: &[Argument]) -> bool {
+    let index = |argument: &Argument| match argument {
+        Argument::Expression(Expression::NumberLiteral(lit))
+            if lit.value >= 0.0 && lit.value.fract() == 0.0 =>
+        {
+            Some(lit.value)
+        }
+        _ => None,
+    };
+    match (method, arguments) {
+        // `substr(start)` and `slice(start)` both count the negative `start` from the end.
+        (_, []) | ("substr", [Argument::Expression(_)]) => true,
+        // `substr(0, length)` is `slice(0, length)`.
+        ("substr", [start, length]) => index(start) == Some(0.0) && index(length).is_some(),
+        ("substring", [start]) => index(start).is_some(),
+        ("substring", [start, end]) => {
+            matches!((index(start), index(end)), (Some(start), Some(end)) if start <= end)
+        }
+        _ => false,
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("const substr = foo.substr", None),
+        ("const substring = foo.substring", None),
+        ("foo.slice()", None),
+        ("foo.slice(0)", None),
+        ("foo.slice(1, 2)", None),
+        ("foo[substr]()", None),
+        ("substr(foo)", None),
+        ("substring(foo)", None),
+    ];
+
+    let fail = vec![
+        ("foo.substr()", None),
+        ("foo?.substr()", None),
+        ("foo.bar?.substring()", None),
+        ("foo.substr(start)", None),
+        ("foo.substr(1, 2)", None),
+        ("foo.substring()", None),
+        ("foo.substring(1)", None),
+        ("foo.substring(-1)", None),
+        ("foo.substring(2, 1)", None),
+        ("foo.substring(start, end)", None),
+        ("'foo'.substring(0, 1)", None),
+    ];
+
+    let fix = vec![
+        ("foo.substr()", "foo.slice()", None),
+        ("foo.substr(start)", "foo.slice(start)", None),
+        ("foo.substr(0, 2)", "foo.slice(0, 2)", None),
+        ("foo.substr(1, 2)", "foo.substr(1, 2)", None),
+        ("foo.substring()", "foo.slice()", None),
+        ("foo.substring(1)", "foo.slice(1)", None),
+        ("foo.substring(-1)", "foo.substring(-1)", None),
+        ("foo.substring(1, 2)", "foo.slice(1, 2)", None),
+        ("foo.substring(2, 1)", "foo.substring(2, 1)", None),
+        ("foo.substring(start, end)", "foo.substring(start, end)", None),
+    ];
+
+    Tester::new(PreferStringSlice::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
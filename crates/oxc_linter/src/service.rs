@@ -1,23 +1,35 @@
 use std::{
     collections::HashMap,
+    ffi::OsStr,
     fs,
+    io::{self, Write},
     path::Path,
     rc::Rc,
     sync::{Arc, Condvar, Mutex},
+    time::Instant,
 };
 
 use dashmap::DashMap;
-use rayon::{iter::ParallelBridge, prelude::ParallelIterator};
+use rayon::{
+    iter::{IntoParallelIterator, ParallelBridge},
+    prelude::ParallelIterator,
+};
 use rustc_hash::FxHashSet;
 
 use oxc_allocator::Allocator;
-use oxc_diagnostics::{DiagnosticSender, DiagnosticService};
+use oxc_diagnostics::{DiagnosticSender, DiagnosticService, DiagnosticWithFix, Error};
 use oxc_parser::Parser;
-use oxc_resolver::{ResolveOptions, Resolver};
+use oxc_resolver::{ResolveError, ResolveOptions, Resolver};
 use oxc_semantic::{ModuleRecord, SemanticBuilder};
 use oxc_span::{SourceType, VALID_EXTENSIONS};
 
-use crate::{Fixer, LintContext, LintOptions, Linter, Message};
+use crate::{
+    cache::LintCache, Fixer, LintContext, LintOptions, Linter, Message, PartialLoader,
+    LINT_PARTIAL_LOADER_EXT,
+};
+
+/// The maximum number of times a file is linted and fixed, as in ESLint.
+const MAX_FIX_PASSES: usize = 10;
 
 #[derive(Clone)]
 pub struct LintService {
@@ -55,6 +67,15 @@ impl LintService {
         tx_error.send(None).unwrap();
     }
 
+    /// Write the lint cache when [LintOptions::cache] is enabled, once the files are linted.
+    ///
+    /// # Errors
+    ///
+    /// The cache directory can not be written.
+    pub fn save_cache(&self) -> io::Result<()> {
+        self.runtime.cache.as_ref().map_or(Ok(()), LintCache::save)
+    }
+
     /// For tests
     #[cfg(test)]
     pub(crate) fn run_source<'a>(
@@ -111,10 +132,17 @@ pub struct Runtime {
     resolver: Resolver,
     module_map: ModuleMap,
     cache_state: CacheState,
+    cache: Option<LintCache>,
 }
 
 impl Runtime {
     fn new(cwd: Box<Path>, paths: &[Box<Path>], linter: Linter) -> Self {
+        let options = linter.options();
+        let cache = options
+            .cache
+            .as_deref()
+            .filter(|_| !options.import_plugin && options.type_checker.is_none());
+        let cache = cache.map(LintCache::load);
         Self {
             cwd,
             paths: paths.iter().cloned().collect(),
@@ -122,44 +150,168 @@ impl Runtime {
             resolver: Self::resolver(),
             module_map: ModuleMap::default(),
             cache_state: CacheState::default(),
+            cache,
         }
     }
 
     fn resolver() -> Resolver {
         Resolver::new(ResolveOptions {
             extensions: VALID_EXTENSIONS.iter().map(|ext| format!(".{ext}")).collect(),
+            builtin_modules: true,
             ..ResolveOptions::default()
         })
     }
 
     fn process_path(&self, path: &Path, tx_error: &DiagnosticSender) {
-        let Ok(source_type) = SourceType::from_path(path) else { return };
+        if !self.linter.options().timing {
+            self.lint_path(path, tx_error);
+            return;
+        }
+        let start = Instant::now();
+        self.lint_path(path, tx_error);
+        let path = path.strip_prefix(&self.cwd).unwrap_or(path);
+        self.linter.record_file_time(path, start.elapsed());
+    }
+
+    fn lint_path(&self, path: &Path, tx_error: &DiagnosticSender) {
+        let ext = path.extension().and_then(OsStr::to_str).unwrap_or_default();
+        if SourceType::from_path(path).is_err() && !LINT_PARTIAL_LOADER_EXT.contains(&ext) {
+            return;
+        }
 
         if self.init_cache_state(path) {
             return;
         }
 
-        let allocator = Allocator::default();
-        let source_text =
+        let mut source_text =
             fs::read_to_string(path).unwrap_or_else(|_| panic!("Failed to read {path:?}"));
 
-        let mut messages =
-            self.process_source(path, &allocator, &source_text, source_type, true, tx_error);
+        let config_hash = self.cache.as_ref().and_then(|_| self.linter.config_hash(path));
+        let cached = self
+            .cache
+            .as_ref()
+            .zip(config_hash)
+            .and_then(|(cache, config_hash)| cache.get(path, &source_text, config_hash));
+        if let Some(diagnostics) = cached {
+            self.send_diagnostics(path, &source_text, diagnostics, tx_error);
+            return;
+        }
 
-        if self.linter.options().fix {
+        if !self.linter.options().fix {
+            let allocator = Allocator::default();
+            let messages = self.process_sources(path, &allocator, &source_text, tx_error);
+            self.report(path, &source_text, config_hash, messages, tx_error);
+            return;
+        }
+
+        // Fixes can uncover or enable other fixes, so the fixed code is linted again
+        // until no more fixes are applied, at most `MAX_FIX_PASSES` times.
+        let mut fixed = false;
+        for _ in 0..MAX_FIX_PASSES {
+            let allocator = Allocator::default();
+            let messages = self.process_sources(path, &allocator, &source_text, tx_error);
             let fix_result = Fixer::new(&source_text, messages).fix();
-            fs::write(path, fix_result.fixed_code.as_bytes()).unwrap();
-            messages = fix_result.messages;
+            if !fix_result.fixed {
+                self.write_fixed_code(path, &source_text, fixed);
+                self.report(path, &source_text, config_hash, fix_result.messages, tx_error);
+                return;
+            }
+            let fixed_code = fix_result.fixed_code.into_owned();
+            drop(fix_result.messages);
+            source_text = fixed_code;
+            fixed = true;
+        }
+
+        self.write_fixed_code(path, &source_text, fixed);
+        let allocator = Allocator::default();
+        let messages = self.process_sources(path, &allocator, &source_text, tx_error);
+        self.report(path, &source_text, config_hash, messages, tx_error);
+    }
+
+    fn write_fixed_code(&self, path: &Path, fixed_code: &str, fixed: bool) {
+        if !fixed {
+            return;
+        }
+        if self.linter.options().fix_dry_run {
+            let path = path.strip_prefix(&self.cwd).unwrap_or(path);
+            let mut stdout = io::stdout().lock();
+            writeln!(stdout, "{}:\n{fixed_code}", path.display()).unwrap();
+        } else {
+            fs::write(path, fixed_code.as_bytes()).unwrap();
         }
+    }
+
+    /// Send the diagnostics of a linted file, and store them in the cache with the hash of its
+    /// configuration.
+    fn report(
+        &self,
+        path: &Path,
+        source_text: &str,
+        config_hash: Option<u64>,
+        messages: Vec<Message<'_>>,
+        tx_error: &DiagnosticSender,
+    ) {
+        if let (Some(cache), Some(config_hash)) = (&self.cache, config_hash) {
+            cache.insert(path, source_text, config_hash, &messages);
+        }
+        let report_fixes = self.linter.options().report_fixes;
+        let errors = messages
+            .into_iter()
+            .map(|message| match message.fix {
+                Some(fix) if report_fixes => {
+                    let span = (fix.span.start as usize, fix.span.size() as usize).into();
+                    let content = fix.content.into_owned();
+                    Error::new(DiagnosticWithFix::new(message.error, span, content))
+                }
+                _ => message.error,
+            })
+            .collect();
+        self.send_diagnostics(path, source_text, errors, tx_error);
+    }
 
-        if !messages.is_empty() {
-            let errors = messages.into_iter().map(|m| m.error).collect();
+    fn send_diagnostics(
+        &self,
+        path: &Path,
+        source_text: &str,
+        errors: Vec<Error>,
+        tx_error: &DiagnosticSender,
+    ) {
+        if !errors.is_empty() {
             let path = path.strip_prefix(&self.cwd).unwrap_or(path);
-            let diagnostics = DiagnosticService::wrap_diagnostics(path, &source_text, errors);
+            let diagnostics = DiagnosticService::wrap_diagnostics(path, source_text, errors);
             tx_error.send(Some(diagnostics)).unwrap();
         }
     }
 
+    /// Lint the file, or each script embedded in it with the spans of the file.
+    fn process_sources<'a>(
+        &self,
+        path: &Path,
+        allocator: &'a Allocator,
+        source_text: &'a str,
+        tx_error: &DiagnosticSender,
+    ) -> Vec<Message<'a>> {
+        let ext = path.extension().and_then(OsStr::to_str).unwrap_or_default();
+        let Some(sources) = PartialLoader::parse(ext, source_text) else {
+            let source_type = SourceType::from_path(path).unwrap();
+            return self.process_source(path, allocator, source_text, source_type, true, tx_error);
+        };
+        sources
+            .into_iter()
+            .flat_map(|source| {
+                let source_text = allocator.alloc_str(&source.source_text);
+                self.process_source(
+                    path,
+                    allocator,
+                    source_text,
+                    source.source_type,
+                    true,
+                    tx_error,
+                )
+            })
+            .collect()
+    }
+
     fn process_source<'a>(
         &self,
         path: &Path,
@@ -171,6 +323,7 @@ impl Runtime {
     ) -> Vec<Message<'a>> {
         let ret = Parser::new(allocator, source_text, source_type)
             .allow_return_outside_function(true)
+            .allow_import_assertions(true)
             .parse();
 
         if !ret.errors.is_empty() {
@@ -187,6 +340,7 @@ impl Runtime {
             .build_module_record(path.to_path_buf(), program);
         let module_record = semantic_builder.module_record();
 
+        let mut unresolved_modules = FxHashSet::default();
         if self.linter.options().import_plugin {
             self.module_map
                 .insert(path.to_path_buf().into_boxed_path(), Arc::clone(&module_record));
@@ -194,14 +348,25 @@ impl Runtime {
 
             // Retrieve all dependency modules from this module.
             let dir = path.parent().unwrap();
-            module_record
+            let (resolutions, failures): (Vec<_>, Vec<_>) = module_record
                 .requested_modules
                 .keys()
                 .par_bridge()
                 .map_with(&self.resolver, |resolver, specifier| {
-                    resolver.resolve(dir, specifier).ok().map(|r| (specifier, r))
+                    (specifier, resolver.resolve(dir, specifier))
+                })
+                .partition(|(_, resolution)| resolution.is_ok());
+            // Node.js builtin modules and the modules ignored by `browser` fields are not files.
+            unresolved_modules = failures
+                .into_iter()
+                .filter(|(_, resolution)| {
+                    !matches!(resolution, Err(ResolveError::Builtin(_) | ResolveError::Ignored(_)))
                 })
-                .flatten()
+                .map(|(specifier, _)| specifier.clone())
+                .collect();
+            resolutions
+                .into_par_iter()
+                .filter_map(|(specifier, resolution)| resolution.ok().map(|r| (specifier, r)))
                 .for_each_with(tx_error, |tx_error, (specifier, resolution)| {
                     let path = resolution.path();
                     self.process_path(path, tx_error);
@@ -225,7 +390,8 @@ impl Runtime {
         };
 
         let lint_ctx =
-            LintContext::new(path.to_path_buf().into_boxed_path(), &Rc::new(semantic_ret.semantic));
+            LintContext::new(path.to_path_buf().into_boxed_path(), &Rc::new(semantic_ret.semantic))
+                .with_unresolved_modules(unresolved_modules);
         self.linter.run(lint_ctx)
     }
 
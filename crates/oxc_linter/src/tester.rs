@@ -1,14 +1,19 @@
 use std::{
     env,
     path::{Path, PathBuf},
+    sync::Arc,
 };
 
 use oxc_allocator::Allocator;
 use oxc_diagnostics::miette::{GraphicalReportHandler, GraphicalTheme, NamedSource};
 use oxc_diagnostics::DiagnosticService;
+use oxc_span::Span;
 use serde_json::Value;
 
-use crate::{rules::RULES, Fixer, LintOptions, LintService, Linter, RuleEnum};
+use crate::{
+    rules::RULES, Fixer, LintOptions, LintService, Linter, Message, RuleEnum, TypeChecker,
+    TypeInfo, TypeKind,
+};
 
 #[derive(Eq, PartialEq)]
 enum TestResult {
@@ -23,9 +28,14 @@ pub struct Tester {
     expect_pass: Vec<(String, Option<Value>)>,
     expect_fail: Vec<(String, Option<Value>)>,
     expect_fix: Vec<(String, String, Option<Value>)>,
+    /// The source with each suggestion of its diagnostics applied
+    expect_suggestions: Vec<(String, Vec<String>, Option<Value>)>,
     snapshot: String,
     current_working_directory: Box<Path>,
     import_plugin: bool,
+    report_unused_directives: bool,
+    /// Lint with [DeclaredTypes].
+    type_checker: bool,
 }
 
 impl Tester {
@@ -45,9 +55,12 @@ impl Tester {
             expect_pass,
             expect_fail,
             expect_fix: vec![],
+            expect_suggestions: vec![],
             snapshot: String::new(),
             current_working_directory,
             import_plugin: false,
+            report_unused_directives: false,
+            type_checker: false,
         }
     }
 
@@ -82,16 +95,38 @@ impl Tester {
         self
     }
 
+    pub fn with_report_unused_directives(mut self, yes: bool) -> Self {
+        self.report_unused_directives = yes;
+        self
+    }
+
+    pub fn with_type_checker(mut self, yes: bool) -> Self {
+        self.type_checker = yes;
+        self
+    }
+
     pub fn expect_fix<S: Into<String>>(mut self, expect_fix: Vec<(S, S, Option<Value>)>) -> Self {
         self.expect_fix =
             expect_fix.into_iter().map(|(s1, s2, r)| (s1.into(), s2.into(), r)).collect::<Vec<_>>();
         self
     }
 
+    pub fn expect_suggestions<S: Into<String>>(
+        mut self,
+        expect_suggestions: Vec<(S, Vec<S>, Option<Value>)>,
+    ) -> Self {
+        self.expect_suggestions = expect_suggestions
+            .into_iter()
+            .map(|(s, outputs, r)| (s.into(), outputs.into_iter().map(Into::into).collect(), r))
+            .collect::<Vec<_>>();
+        self
+    }
+
     pub fn test(&mut self) {
         self.test_pass();
         self.test_fail();
         self.test_fix();
+        self.test_suggestions();
     }
 
     pub fn test_and_snapshot(&mut self) {
@@ -133,25 +168,22 @@ impl Tester {
         }
     }
 
+    fn test_suggestions(&self) {
+        for (test, expected, config) in self.expect_suggestions.clone() {
+            let allocator = Allocator::default();
+            let messages = self.lint(&allocator, &test, config, false);
+            let outputs = messages
+                .iter()
+                .flat_map(|message| &message.suggestions)
+                .map(|suggestion| suggestion.apply(&test))
+                .collect::<Vec<_>>();
+            assert_eq!(expected, outputs, "expect suggestions: {test}");
+        }
+    }
+
     fn run(&mut self, source_text: &str, config: Option<Value>, is_fix: bool) -> TestResult {
         let allocator = Allocator::default();
-        let rule = self.find_rule().read_json(config);
-        let options =
-            LintOptions::default().with_fix(is_fix).with_import_plugin(self.import_plugin);
-        let linter = Linter::from_options(options).with_rules(vec![rule]);
-        let path_to_lint = if self.import_plugin {
-            self.current_working_directory.join(&self.rule_path)
-        } else {
-            self.rule_path.clone()
-        };
-        let lint_service = LintService::from_linter(
-            self.current_working_directory.clone(),
-            &[path_to_lint.into_boxed_path()],
-            linter,
-        );
-        let diagnostic_service = DiagnosticService::default();
-        let tx_error = diagnostic_service.sender();
-        let result = lint_service.run_source(&allocator, source_text, false, tx_error);
+        let result = self.lint(&allocator, source_text, config, is_fix);
 
         if result.is_empty() {
             return TestResult::Passed;
@@ -168,7 +200,8 @@ impl Tester {
             &self.rule_path
         }
         .to_string_lossy();
-        let handler = GraphicalReportHandler::new_themed(GraphicalTheme::unicode_nocolor());
+        let handler =
+            GraphicalReportHandler::new_themed(GraphicalTheme::unicode_nocolor()).with_urls(false);
         for diagnostic in result {
             let diagnostic = diagnostic.error.with_source_code(source_text.to_string());
             let diagnostic = diagnostic.with_source_code(NamedSource::new(
@@ -181,6 +214,37 @@ impl Tester {
         TestResult::Failed
     }
 
+    fn lint<'a>(
+        &self,
+        allocator: &'a Allocator,
+        source_text: &'a str,
+        config: Option<Value>,
+        is_fix: bool,
+    ) -> Vec<Message<'a>> {
+        let rule = self.find_rule().read_json(config);
+        let options = LintOptions::default()
+            .with_fix(is_fix)
+            .with_import_plugin(self.import_plugin)
+            .with_report_unused_directives(self.report_unused_directives)
+            .with_type_checker(
+                self.type_checker.then(|| Arc::new(DeclaredTypes) as Arc<dyn TypeChecker>),
+            );
+        let linter = Linter::from_options(options).with_rules(vec![rule]);
+        let path_to_lint = if self.import_plugin {
+            self.current_working_directory.join(&self.rule_path)
+        } else {
+            self.rule_path.clone()
+        };
+        let lint_service = LintService::from_linter(
+            self.current_working_directory.clone(),
+            &[path_to_lint.into_boxed_path()],
+            linter,
+        );
+        let diagnostic_service = DiagnosticService::default();
+        let tx_error = diagnostic_service.sender();
+        lint_service.run_source(allocator, source_text, false, tx_error)
+    }
+
     fn find_rule(&self) -> &RuleEnum {
         RULES
             .iter()
@@ -188,3 +252,34 @@ impl Tester {
             .unwrap_or_else(|| panic!("Rule not found: {}", &self.rule_name))
     }
 }
+
+/// A [TypeChecker] for the tests of the type-aware rules, the types of the identifiers and of the
+/// calls are the ones of the `declare const name: Type;` and `declare function name(): Type;`
+/// statements of the source, e.g. `Promise<void> | undefined`.
+#[derive(Debug)]
+struct DeclaredTypes;
+
+impl TypeChecker for DeclaredTypes {
+    fn type_at(&self, _path: &Path, source_text: &str, span: Span) -> Option<TypeInfo> {
+        let expression = span.source_text(source_text);
+        let callee = expression.split_once('(').map(|(callee, _)| callee);
+        let declaration = callee.map_or_else(
+            || format!("declare const {expression}: "),
+            |callee| format!("declare function {callee}("),
+        );
+        let declared = &source_text[source_text.find(&declaration)? + declaration.len()..];
+        let declared = &declared[..declared.find(';')?];
+        let text = if callee.is_some() { declared.split_once("): ")?.1 } else { declared };
+        let kinds = text
+            .split(" | ")
+            .map(|name| {
+                TypeKind::from_name(name).unwrap_or(if name.starts_with("Promise<") {
+                    TypeKind::Thenable
+                } else {
+                    TypeKind::Object
+                })
+            })
+            .collect();
+        Some(TypeInfo::new(text, kinds))
+    }
+}
@@ -0,0 +1,87 @@
+//! The type information of the type-aware rules, e.g. `typescript/no-floating-promises`.
+//!
+//! The rules ask the [TypeChecker] of [crate::LintOptions::type_checker] for the types of the
+//! expressions through [crate::LintContext::type_at], and fall back to their syntactic checks
+//! or report nothing without it.
+
+#[cfg(feature = "type_aware")]
+mod tsc;
+
+use std::{fmt, path::Path};
+
+use oxc_span::Span;
+
+#[cfg(feature = "type_aware")]
+pub use self::tsc::TscTypeChecker;
+
+/// Answers the type queries of the type-aware rules.
+pub trait TypeChecker: fmt::Debug + Send + Sync {
+    /// The type of the expression at `span` of the file at `path`, whose content is `source_text`.
+    /// `None` when the type is not known.
+    fn type_at(&self, path: &Path, source_text: &str, span: Span) -> Option<TypeInfo>;
+}
+
+/// The type of an expression.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypeInfo {
+    /// The type as printed by the type checker, e.g. `Promise<number> | undefined`.
+    pub text: String,
+    /// The kinds of the members of a union type, or the kind of the type.
+    pub kinds: Vec<TypeKind>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TypeKind {
+    Any,
+    Unknown,
+    Never,
+    String,
+    Number,
+    BigInt,
+    Boolean,
+    Null,
+    Undefined,
+    Void,
+    Symbol,
+    /// An object with a callable `then` property, e.g. a `Promise`.
+    Thenable,
+    Object,
+}
+
+impl TypeInfo {
+    pub fn new(text: impl Into<String>, kinds: Vec<TypeKind>) -> Self {
+        Self { text: text.into(), kinds }
+    }
+
+    pub fn is_any(&self) -> bool {
+        self.kinds.contains(&TypeKind::Any)
+    }
+
+    /// A member of the type is a Promise-like, e.g. `Promise<void> | undefined`.
+    pub fn is_thenable(&self) -> bool {
+        self.kinds.contains(&TypeKind::Thenable)
+    }
+}
+
+impl TypeKind {
+    /// `any`, `string`, ..., the kinds are named by the type checkers as in TypeScript.
+    pub fn from_name(name: &str) -> Option<Self> {
+        let kind = match name {
+            "any" => Self::Any,
+            "unknown" => Self::Unknown,
+            "never" => Self::Never,
+            "string" => Self::String,
+            "number" => Self::Number,
+            "bigint" => Self::BigInt,
+            "boolean" => Self::Boolean,
+            "null" => Self::Null,
+            "undefined" => Self::Undefined,
+            "void" => Self::Void,
+            "symbol" => Self::Symbol,
+            "thenable" => Self::Thenable,
+            "object" => Self::Object,
+            _ => return None,
+        };
+        Some(kind)
+    }
+}
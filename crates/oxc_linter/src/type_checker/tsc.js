@@ -0,0 +1,108 @@
+// Answers the type queries of oxlint with the TypeScript compiler installed in the project,
+// one JSON request and one JSON response per line:
+// `{ "file", "text", "start", "end" }` -> `{ "text", "kinds" }` or `null`.
+// The offsets are UTF-16 offsets, the text of a file is only sent when it changed.
+
+const path = require('path');
+const readline = require('readline');
+
+let ts;
+try {
+  ts = require(require.resolve('typescript', { paths: [process.cwd()] }));
+} catch {
+  process.stdout.write(
+    JSON.stringify({ error: `typescript is not installed in ${process.cwd()}` }) + '\n',
+  );
+  process.exit(1);
+}
+
+let options = { strict: true };
+let rootNames = [];
+const configPath = ts.findConfigFile(process.cwd(), ts.sys.fileExists);
+if (configPath) {
+  const { config } = ts.readConfigFile(configPath, ts.sys.readFile);
+  const parsed = ts.parseJsonConfigFileContent(config, ts.sys, path.dirname(configPath));
+  options = parsed.options;
+  rootNames = parsed.fileNames;
+}
+options = { ...options, allowJs: true, noEmit: true };
+
+// The sent files, `{ text, version }` by file name.
+const files = new Map();
+const host = {
+  getScriptFileNames: () => [...new Set([...rootNames, ...files.keys()])],
+  getScriptVersion: (fileName) => String(files.get(fileName)?.version ?? 0),
+  getScriptSnapshot: (fileName) => {
+    const text = files.has(fileName) ? files.get(fileName).text : ts.sys.readFile(fileName);
+    return text === undefined ? undefined : ts.ScriptSnapshot.fromString(text);
+  },
+  getCurrentDirectory: () => process.cwd(),
+  getCompilationSettings: () => options,
+  getDefaultLibFileName: ts.getDefaultLibFilePath,
+  fileExists: ts.sys.fileExists,
+  readFile: ts.sys.readFile,
+  readDirectory: ts.sys.readDirectory,
+  directoryExists: ts.sys.directoryExists,
+  getDirectories: ts.sys.getDirectories,
+};
+const service = ts.createLanguageService(host, ts.createDocumentRegistry());
+
+// The outermost node from `start` to `end`, e.g. the call `foo()` rather than `foo`.
+function findNode(node, start, end) {
+  if (node.getStart() === start && node.getEnd() === end) {
+    return node;
+  }
+  return ts.forEachChild(node, (child) =>
+    child.pos <= start && end <= child.end ? findNode(child, start, end) : undefined,
+  );
+}
+
+function kind(checker, type, node) {
+  const flags = type.flags;
+  const TypeFlags = ts.TypeFlags;
+  if (flags & TypeFlags.Any) return 'any';
+  if (flags & TypeFlags.Unknown) return 'unknown';
+  if (flags & TypeFlags.Never) return 'never';
+  if (flags & TypeFlags.StringLike) return 'string';
+  if (flags & TypeFlags.NumberLike) return 'number';
+  if (flags & TypeFlags.BigIntLike) return 'bigint';
+  if (flags & TypeFlags.BooleanLike) return 'boolean';
+  if (flags & TypeFlags.Null) return 'null';
+  if (flags & TypeFlags.Undefined) return 'undefined';
+  if (flags & TypeFlags.Void) return 'void';
+  if (flags & TypeFlags.ESSymbolLike) return 'symbol';
+  const then = checker.getPropertyOfType(type, 'then');
+  if (then && checker.getTypeOfSymbolAtLocation(then, node).getCallSignatures().length > 0) {
+    return 'thenable';
+  }
+  return 'object';
+}
+
+readline.createInterface({ input: process.stdin }).on('line', (line) => {
+  let response = null;
+  try {
+    const { file, text, start, end } = JSON.parse(line);
+    const fileName = path.resolve(file);
+    if (typeof text === 'string') {
+      const version = (files.get(fileName)?.version ?? 0) + 1;
+      files.set(fileName, { text, version });
+    }
+    const program = service.getProgram();
+    const sourceFile = program.getSourceFile(fileName);
+    const node = sourceFile && findNode(sourceFile, start, end);
+    if (node) {
+      const checker = program.getTypeChecker();
+      const type = checker.getTypeAtLocation(node);
+      const types = type.isUnion() ? type.types : [type];
+      response = {
+        text: checker.typeToString(type),
+        kinds: types.map((type) => kind(checker, type, node)),
+      };
+    }
+  } catch {
+    // The type is not known.
+  }
+  process.stdout.write(JSON.stringify(response) + '\n');
+});
+
+process.stdout.write(JSON.stringify({ ready: true }) + '\n');
@@ -0,0 +1,125 @@
+use std::{
+    io::{self, BufRead, BufReader, Write},
+    path::{Path, PathBuf},
+    process::{Child, ChildStdin, ChildStdout, Command, Stdio},
+    sync::Mutex,
+};
+
+use oxc_span::Span;
+use rustc_hash::FxHashMap;
+use serde_json::{json, Value};
+
+use super::{TypeChecker, TypeInfo, TypeKind};
+use crate::ast_util::calculate_hash;
+
+/// The script run by Node.js, it answers the queries with the TypeScript compiler API.
+const HELPER: &str = include_str!("tsc.js");
+
+/// A [TypeChecker] backed by the TypeScript compiler installed in the project, and by its
+/// `tsconfig.json`, through a Node.js process.
+#[derive(Debug)]
+pub struct TscTypeChecker {
+    process: Mutex<Process>,
+}
+
+#[derive(Debug)]
+struct Process {
+    child: Child,
+    stdin: ChildStdin,
+    stdout: BufReader<ChildStdout>,
+    /// The hash of the content last sent for each file, sent again once it changes, e.g. fixed.
+    sent: FxHashMap<PathBuf, u64>,
+}
+
+impl TscTypeChecker {
+    /// Start the type checker of the project in `cwd`.
+    ///
+    /// # Errors
+    ///
+    /// `node` can not be started or `typescript` is not installed in the project.
+    pub fn new(cwd: &Path) -> io::Result<Self> {
+        let mut child = Command::new("node")
+            .arg("-e")
+            .arg(HELPER)
+            .current_dir(cwd)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::inherit())
+            .spawn()?;
+        let (Some(stdin), Some(stdout)) = (child.stdin.take(), child.stdout.take()) else {
+            return Err(io::Error::new(io::ErrorKind::BrokenPipe, "no stdio"));
+        };
+        let mut process =
+            Process { child, stdin, stdout: BufReader::new(stdout), sent: FxHashMap::default() };
+        let ready = process.read_response()?;
+        if let Some(error) = ready["error"].as_str() {
+            return Err(io::Error::new(io::ErrorKind::NotFound, error.to_string()));
+        }
+        Ok(Self { process: Mutex::new(process) })
+    }
+}
+
+impl TypeChecker for TscTypeChecker {
+    fn type_at(&self, path: &Path, source_text: &str, span: Span) -> Option<TypeInfo> {
+        let mut process = self.process.lock().ok()?;
+        process.query(path, source_text, span).ok().flatten()
+    }
+}
+
+impl Drop for TscTypeChecker {
+    fn drop(&mut self) {
+        if let Ok(process) = self.process.get_mut() {
+            let _ = process.child.kill();
+            let _ = process.child.wait();
+        }
+    }
+}
+
+impl Process {
+    fn query(
+        &mut self,
+        path: &Path,
+        source_text: &str,
+        span: Span,
+    ) -> io::Result<Option<TypeInfo>> {
+        let hash = calculate_hash(&source_text);
+        let text =
+            (self.sent.insert(path.to_path_buf(), hash) != Some(hash)).then_some(source_text);
+        let request = json!({
+            "file": path.to_string_lossy(),
+            "text": text,
+            "start": utf16_offset(source_text, span.start),
+            "end": utf16_offset(source_text, span.end),
+        });
+        writeln!(self.stdin, "{request}")?;
+        self.stdin.flush()?;
+
+        let response = self.read_response()?;
+        let Some(text) = response["text"].as_str() else { return Ok(None) };
+        let kinds = response["kinds"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(Value::as_str)
+            .filter_map(TypeKind::from_name)
+            .collect();
+        Ok(Some(TypeInfo::new(text, kinds)))
+    }
+
+    fn read_response(&mut self) -> io::Result<Value> {
+        let mut line = String::new();
+        if self.stdout.read_line(&mut line)? == 0 {
+            return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "the type checker exited"));
+        }
+        Ok(serde_json::from_str(&line)?)
+    }
+}
+
+/// The offsets of the TypeScript compiler count UTF-16 code units.
+fn utf16_offset(source_text: &str, offset: u32) -> usize {
+    let offset = offset as usize;
+    if source_text.is_ascii() {
+        return offset;
+    }
+    source_text.get(..offset).map_or(offset, |text| text.encode_utf16().count())
+}
@@ -7,8 +7,9 @@ use oxc_ast::{
     },
     AstKind,
 };
-use oxc_semantic::{AstNode, AstNodeId};
+use oxc_semantic::{AstNode, AstNodeId, ReferenceId};
 use oxc_span::{Atom, Span};
+use oxc_syntax::module_record::ImportImportName;
 
 use crate::context::LintContext;
 
@@ -28,6 +29,10 @@ const JEST_METHOD_NAMES: [&str; 14] = [
     "xit",
     "xtest",
 ];
+
+/// The modules exporting the test functions, e.g. `import { it } from 'vitest'`.
+const TEST_FRAMEWORK_MODULES: [&str; 2] = ["@jest/globals", "vitest"];
+
 pub fn is_jest_file(ctx: &LintContext) -> bool {
     if JEST_METHOD_NAMES
         .iter()
@@ -39,10 +44,60 @@ pub fn is_jest_file(ctx: &LintContext) -> bool {
     let import_entries = &ctx.semantic().module_record().import_entries;
 
     return import_entries.iter().any(|import_entry| {
-        matches!(import_entry.module_request.name().as_str(), "@jest/globals")
+        TEST_FRAMEWORK_MODULES.contains(&import_entry.module_request.name().as_str())
     });
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TestFramework {
+    Jest,
+    Vitest,
+}
+
+/// The references to the test functions named one of `names`, both the globals
+/// and the imports from the test framework modules, e.g. `import { test as it } from 'vitest'`.
+pub fn collect_test_fn_references(ctx: &LintContext, names: &[&str]) -> Vec<ReferenceId> {
+    let mut reference_ids = ctx
+        .scopes()
+        .root_unresolved_references()
+        .iter()
+        .filter(|(name, _)| names.contains(&name.as_str()))
+        .flat_map(|(_, reference_ids)| reference_ids.iter().copied())
+        .collect::<Vec<_>>();
+
+    for import_entry in &ctx.semantic().module_record().import_entries {
+        if !TEST_FRAMEWORK_MODULES.contains(&import_entry.module_request.name().as_str()) {
+            continue;
+        }
+        let ImportImportName::Name(import_name) = &import_entry.import_name else { continue };
+        if !names.contains(&import_name.name().as_str()) {
+            continue;
+        }
+        let Some(symbol_id) = ctx.scopes().get_root_binding(import_entry.local_name.name()) else {
+            continue;
+        };
+        reference_ids.extend(ctx.symbols().get_resolved_reference_ids(symbol_id));
+    }
+
+    reference_ids
+}
+
+/// Vitest is detected from the imports of `vitest` or the usage of the `vi` global,
+/// the files without any of them are considered as jest files.
+pub fn detect_test_framework(ctx: &LintContext) -> TestFramework {
+    let imports_vitest = ctx
+        .semantic()
+        .module_record()
+        .requested_modules
+        .keys()
+        .any(|name| name.as_str() == "vitest");
+    if imports_vitest || ctx.scopes().root_unresolved_references().contains_key("vi") {
+        TestFramework::Vitest
+    } else {
+        TestFramework::Jest
+    }
+}
+
 pub fn is_type_of_jest_fn_call<'a>(
     call_expr: &'a CallExpression<'a>,
     node: &AstNode<'a>,
@@ -152,7 +207,7 @@ pub fn parse_jest_fn_call<'a>(
         }
 
         if matches!(kind, JestFnKind::General(JestGeneralFnKind::Jest)) {
-            return parse_jest_jest_fn_call(members, name);
+            return parse_jest_jest_fn_call(members, name, resolved.framework);
         }
 
         // Check every link in the chain except the last is a member expression
@@ -162,7 +217,9 @@ pub fn parse_jest_fn_call<'a>(
 
         let mut call_chains = Vec::from([Cow::Borrowed(name)]);
         call_chains.extend(members.iter().filter_map(KnownMemberExpressionProperty::name));
-        if !is_valid_jest_call(&call_chains) {
+        let is_valid_call = is_valid_jest_call(&call_chains)
+            || (resolved.framework == TestFramework::Vitest && is_valid_vitest_call(&call_chains));
+        if !is_valid_call {
             return None;
         }
 
@@ -245,8 +302,13 @@ fn parse_jest_expect_fn_call<'a>(
 fn parse_jest_jest_fn_call<'a>(
     members: Vec<KnownMemberExpressionProperty<'a>>,
     name: &'a str,
+    framework: TestFramework,
 ) -> Option<ParsedJestFnCall<'a>> {
-    if !name.to_ascii_lowercase().eq_ignore_ascii_case("jest") {
+    let is_mock_object = match framework {
+        TestFramework::Jest => name.eq_ignore_ascii_case("jest"),
+        TestFramework::Vitest => name == "vi",
+    };
+    if !is_mock_object {
         return None;
     }
 
@@ -324,7 +386,16 @@ impl ModifierName {
 
 // If find a match in `VALID_JEST_FN_CALL_CHAINS`, return true.
 fn is_valid_jest_call(members: &[Cow<str>]) -> bool {
-    VALID_JEST_FN_CALL_CHAINS
+    is_valid_call_chain(&VALID_JEST_FN_CALL_CHAINS, members)
+}
+
+// If find a match in `VALID_VITEST_FN_CALL_CHAINS`, return true.
+fn is_valid_vitest_call(members: &[Cow<str>]) -> bool {
+    is_valid_call_chain(&VALID_VITEST_FN_CALL_CHAINS, members)
+}
+
+fn is_valid_call_chain(chains: &[[&str; 4]], members: &[Cow<str>]) -> bool {
+    chains
         .binary_search_by(|chain| {
             chain
                 .iter()
@@ -346,6 +417,7 @@ fn resolve_to_jest_fn<'a>(
     ctx: &LintContext<'a>,
 ) -> Option<ResolvedJestFn<'a>> {
     let ident = resolve_first_ident(&call_expr.callee)?;
+    let framework = detect_test_framework(ctx);
     if ctx.semantic().is_reference_to_global_variable(ident) {
         // If the identifier is not a jest function, bail out
         if JestFnKind::from(&ident.name) == JestFnKind::Unknown {
@@ -356,6 +428,7 @@ fn resolve_to_jest_fn<'a>(
             local: &ident.name,
             kind: JestFnFrom::Global,
             original: None,
+            framework,
         });
     }
 
@@ -368,15 +441,22 @@ fn resolve_to_jest_fn<'a>(
         return None;
     };
 
-    if import_decl.source.value == "@jest/globals" {
+    if TEST_FRAMEWORK_MODULES.contains(&import_decl.source.value.as_str()) {
         let original = import_decl.specifiers.iter().find_map(|specifier| match specifier {
-            ImportDeclarationSpecifier::ImportSpecifier(import_specifier) => {
+            ImportDeclarationSpecifier::ImportSpecifier(import_specifier)
+                if import_specifier.local.name == ident.name =>
+            {
                 Some(import_specifier.imported.name())
             }
             _ => None,
         });
 
-        return Some(ResolvedJestFn { local: &ident.name, kind: JestFnFrom::Import, original });
+        return Some(ResolvedJestFn {
+            local: &ident.name,
+            kind: JestFnFrom::Import,
+            original,
+            framework,
+        });
     }
     None
 }
@@ -418,8 +498,10 @@ impl JestFnKind {
     pub fn from(name: &str) -> Self {
         match name {
             "expect" => Self::Expect,
-            "jest" => Self::General(JestGeneralFnKind::Jest),
-            "describe" | "fdescribe" | "xdescribe" => Self::General(JestGeneralFnKind::Describe),
+            "jest" | "vi" => Self::General(JestGeneralFnKind::Jest),
+            "describe" | "fdescribe" | "xdescribe" | "suite" => {
+                Self::General(JestGeneralFnKind::Describe)
+            }
             "fit" | "it" | "test" | "xit" | "xtest" => Self::General(JestGeneralFnKind::Test),
             "beforeAll" | "beforeEach" | "afterAll" | "afterEach" => {
                 Self::General(JestGeneralFnKind::Hook)
@@ -495,6 +577,7 @@ struct ResolvedJestFn<'a> {
     pub original: Option<&'a Atom>,
     #[allow(unused)]
     kind: JestFnFrom,
+    framework: TestFramework,
 }
 
 pub enum JestFnFrom {
@@ -762,3 +845,43 @@ const VALID_JEST_FN_CALL_CHAINS: [[&str; 4]; 51] = [
     ["xtest", "each", "", ""],
     ["xtest", "failing", "", ""],
 ];
+
+// The call chains only available in vitest, sorted list for binary search.
+const VALID_VITEST_FN_CALL_CHAINS: [[&str; 4]; 36] = [
+    ["describe", "concurrent", "", ""],
+    ["describe", "concurrent", "each", ""],
+    ["describe", "concurrent", "only", ""],
+    ["describe", "concurrent", "skip", ""],
+    ["describe", "runIf", "", ""],
+    ["describe", "sequential", "", ""],
+    ["describe", "shuffle", "", ""],
+    ["describe", "skipIf", "", ""],
+    ["describe", "todo", "", ""],
+    ["it", "concurrent", "only", ""],
+    ["it", "concurrent", "skip", ""],
+    ["it", "concurrent", "todo", ""],
+    ["it", "fails", "", ""],
+    ["it", "fails", "each", ""],
+    ["it", "fails", "only", ""],
+    ["it", "only", "fails", ""],
+    ["it", "runIf", "", ""],
+    ["it", "sequential", "", ""],
+    ["it", "skip", "fails", ""],
+    ["it", "skipIf", "", ""],
+    ["suite", "", "", ""],
+    ["suite", "concurrent", "", ""],
+    ["suite", "only", "", ""],
+    ["suite", "skip", "", ""],
+    ["suite", "todo", "", ""],
+    ["test", "concurrent", "only", ""],
+    ["test", "concurrent", "skip", ""],
+    ["test", "concurrent", "todo", ""],
+    ["test", "fails", "", ""],
+    ["test", "fails", "each", ""],
+    ["test", "fails", "only", ""],
+    ["test", "only", "fails", ""],
+    ["test", "runIf", "", ""],
+    ["test", "sequential", "", ""],
+    ["test", "skip", "fails", ""],
+    ["test", "skipIf", "", ""],
+];
@@ -0,0 +1,260 @@
+use oxc_ast::ast::{
+    Expression, JSXAttribute, JSXAttributeItem, JSXAttributeName, JSXAttributeValue,
+    JSXElementName, JSXExpression, JSXOpeningElement,
+};
+use oxc_span::Atom;
+use phf::{phf_map, phf_set};
+
+/// The valid `aria-*` properties and states of WAI-ARIA 1.2.
+pub const ARIA_PROPERTIES: phf::Set<&'static str> = phf_set! {
+    "aria-activedescendant",
+    "aria-atomic",
+    "aria-autocomplete",
+    "aria-braillelabel",
+    "aria-brailleroledescription",
+    "aria-busy",
+    "aria-checked",
+    "aria-colcount",
+    "aria-colindex",
+    "aria-colspan",
+    "aria-controls",
+    "aria-current",
+    "aria-describedby",
+    "aria-description",
+    "aria-details",
+    "aria-disabled",
+    "aria-dropeffect",
+    "aria-errormessage",
+    "aria-expanded",
+    "aria-flowto",
+    "aria-grabbed",
+    "aria-haspopup",
+    "aria-hidden",
+    "aria-invalid",
+    "aria-keyshortcuts",
+    "aria-label",
+    "aria-labelledby",
+    "aria-level",
+    "aria-live",
+    "aria-modal",
+    "aria-multiline",
+    "aria-multiselectable",
+    "aria-orientation",
+    "aria-owns",
+    "aria-placeholder",
+    "aria-posinset",
+    "aria-pressed",
+    "aria-readonly",
+    "aria-relevant",
+    "aria-required",
+    "aria-roledescription",
+    "aria-rowcount",
+    "aria-rowindex",
+    "aria-rowspan",
+    "aria-selected",
+    "aria-setsize",
+    "aria-sort",
+    "aria-valuemax",
+    "aria-valuemin",
+    "aria-valuenow",
+    "aria-valuetext",
+};
+
+/// The non-abstract WAI-ARIA 1.2, DPUB-ARIA and Graphics-ARIA roles,
+/// mapped to the properties an element with the role must have.
+pub const ARIA_ROLES: phf::Map<&'static str, &'static [&'static str]> = phf_map! {
+    "alert" => &[],
+    "alertdialog" => &[],
+    "application" => &[],
+    "article" => &[],
+    "banner" => &[],
+    "blockquote" => &[],
+    "button" => &[],
+    "caption" => &[],
+    "cell" => &[],
+    "checkbox" => &["aria-checked"],
+    "code" => &[],
+    "columnheader" => &[],
+    "combobox" => &["aria-controls", "aria-expanded"],
+    "complementary" => &[],
+    "contentinfo" => &[],
+    "definition" => &[],
+    "deletion" => &[],
+    "dialog" => &[],
+    "directory" => &[],
+    "document" => &[],
+    "emphasis" => &[],
+    "feed" => &[],
+    "figure" => &[],
+    "form" => &[],
+    "generic" => &[],
+    "grid" => &[],
+    "gridcell" => &[],
+    "group" => &[],
+    "heading" => &["aria-level"],
+    "img" => &[],
+    "insertion" => &[],
+    "link" => &[],
+    "list" => &[],
+    "listbox" => &[],
+    "listitem" => &[],
+    "log" => &[],
+    "main" => &[],
+    "marquee" => &[],
+    "math" => &[],
+    "menu" => &[],
+    "menubar" => &[],
+    "menuitem" => &[],
+    "menuitemcheckbox" => &["aria-checked"],
+    "menuitemradio" => &["aria-checked"],
+    "meter" => &["aria-valuenow"],
+    "navigation" => &[],
+    "none" => &[],
+    "note" => &[],
+    "option" => &[],
+    "paragraph" => &[],
+    "presentation" => &[],
+    "progressbar" => &[],
+    "radio" => &["aria-checked"],
+    "radiogroup" => &[],
+    "region" => &[],
+    "row" => &[],
+    "rowgroup" => &[],
+    "rowheader" => &[],
+    "scrollbar" => &["aria-controls", "aria-valuenow"],
+    "search" => &[],
+    "searchbox" => &[],
+    "separator" => &[],
+    "slider" => &["aria-valuenow"],
+    "spinbutton" => &[],
+    "status" => &[],
+    "strong" => &[],
+    "subscript" => &[],
+    "superscript" => &[],
+    "switch" => &["aria-checked"],
+    "tab" => &[],
+    "table" => &[],
+    "tablist" => &[],
+    "tabpanel" => &[],
+    "term" => &[],
+    "textbox" => &[],
+    "time" => &[],
+    "timer" => &[],
+    "toolbar" => &[],
+    "tooltip" => &[],
+    "tree" => &[],
+    "treegrid" => &[],
+    "treeitem" => &[],
+    "doc-abstract" => &[],
+    "doc-acknowledgments" => &[],
+    "doc-afterword" => &[],
+    "doc-appendix" => &[],
+    "doc-backlink" => &[],
+    "doc-biblioentry" => &[],
+    "doc-bibliography" => &[],
+    "doc-biblioref" => &[],
+    "doc-chapter" => &[],
+    "doc-colophon" => &[],
+    "doc-conclusion" => &[],
+    "doc-cover" => &[],
+    "doc-credit" => &[],
+    "doc-credits" => &[],
+    "doc-dedication" => &[],
+    "doc-endnote" => &[],
+    "doc-endnotes" => &[],
+    "doc-epigraph" => &[],
+    "doc-epilogue" => &[],
+    "doc-errata" => &[],
+    "doc-example" => &[],
+    "doc-footnote" => &[],
+    "doc-foreword" => &[],
+    "doc-glossary" => &[],
+    "doc-glossref" => &[],
+    "doc-index" => &[],
+    "doc-introduction" => &[],
+    "doc-noteref" => &[],
+    "doc-notice" => &[],
+    "doc-pagebreak" => &[],
+    "doc-pagelist" => &[],
+    "doc-part" => &[],
+    "doc-preface" => &[],
+    "doc-prologue" => &[],
+    "doc-pullquote" => &[],
+    "doc-qna" => &[],
+    "doc-subtitle" => &[],
+    "doc-tip" => &[],
+    "doc-toc" => &[],
+    "graphics-document" => &[],
+    "graphics-object" => &[],
+    "graphics-symbol" => &[],
+};
+
+/// The tag name of `<div>` or `<Foo>`, but not of `<Foo.Bar>` or `<svg:path>`.
+pub fn get_element_type<'a>(element: &'a JSXOpeningElement) -> Option<&'a str> {
+    let JSXElementName::Identifier(ident) = &element.name else { return None };
+    Some(ident.name.as_str())
+}
+
+/// Intrinsic DOM elements start with a lowercase letter, e.g. `<div>`.
+pub fn is_dom_element(element: &JSXOpeningElement) -> bool {
+    get_element_type(element).is_some_and(|name| name.starts_with(|c: char| c.is_lowercase()))
+}
+
+pub fn has_spread_attribute(element: &JSXOpeningElement) -> bool {
+    element.attributes.iter().any(|attr| matches!(attr, JSXAttributeItem::SpreadAttribute(_)))
+}
+
+/// Find the attribute `name` of the element. Attribute names are matched case insensitively.
+pub fn has_jsx_prop<'a, 'b>(
+    element: &'b JSXOpeningElement<'a>,
+    name: &str,
+) -> Option<&'b JSXAttribute<'a>> {
+    element.attributes.iter().find_map(|attr| match attr {
+        JSXAttributeItem::Attribute(attr) => match &attr.name {
+            JSXAttributeName::Identifier(ident) if ident.name.eq_ignore_ascii_case(name) => {
+                Some(&**attr)
+            }
+            _ => None,
+        },
+        JSXAttributeItem::SpreadAttribute(_) => None,
+    })
+}
+
+/// The static string value of `attr="value"`, `attr={"value"}` or attr={`value`}.
+pub fn get_string_literal_prop_value<'a>(attr: &'a JSXAttribute) -> Option<&'a str> {
+    match attr.value.as_ref()? {
+        JSXAttributeValue::StringLiteral(lit) => Some(lit.value.as_str()),
+        JSXAttributeValue::ExpressionContainer(container) => match &container.expression {
+            JSXExpression::Expression(Expression::StringLiteral(lit)) => Some(lit.value.as_str()),
+            JSXExpression::Expression(Expression::TemplateLiteral(lit))
+                if lit.is_no_substitution_template() =>
+            {
+                lit.quasi().map(Atom::as_str)
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// `attr={undefined}` and `attr={null}` are not rendered by React.
+pub fn is_nullish_prop_value(attr: &JSXAttribute) -> bool {
+    let Some(JSXAttributeValue::ExpressionContainer(container)) = &attr.value else {
+        return false;
+    };
+    match &container.expression {
+        JSXExpression::Expression(expr) => expr.is_null_or_undefined(),
+        JSXExpression::EmptyExpression(_) => false,
+    }
+}
+
+/// `attr={false}`.
+pub fn is_false_prop_value(attr: &JSXAttribute) -> bool {
+    let Some(JSXAttributeValue::ExpressionContainer(container)) = &attr.value else {
+        return false;
+    };
+    matches!(
+        &container.expression,
+        JSXExpression::Expression(Expression::BooleanLiteral(lit)) if !lit.value
+    )
+}
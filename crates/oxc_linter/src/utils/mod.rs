@@ -1,4 +1,5 @@
 mod jest;
+mod jsx_a11y;
 mod react;
 
-pub use self::{jest::*, react::*};
+pub use self::{jest::*, jsx_a11y::*, react::*};
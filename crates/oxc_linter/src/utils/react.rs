@@ -1,4 +1,4 @@
-use oxc_ast::ast::CallExpression;
+use oxc_ast::ast::{CallExpression, Expression};
 
 pub fn is_create_element_call(call_expr: &CallExpression) -> bool {
     if let Some(member_expr) = call_expr.callee.get_member_expr() {
@@ -7,3 +7,35 @@ pub fn is_create_element_call(call_expr: &CallExpression) -> bool {
 
     false
 }
+
+/// `use` and the names starting with `use` followed by a capital letter or a digit, e.g. `useState`.
+pub fn is_react_hook_name(name: &str) -> bool {
+    name == "use"
+        || name
+            .strip_prefix("use")
+            .and_then(|rest| rest.chars().next())
+            .is_some_and(|c| c.is_ascii_uppercase() || c.is_ascii_digit())
+}
+
+/// Component names start with a capital letter, e.g. `MyComponent`.
+pub fn is_react_component_name(name: &str) -> bool {
+    name.chars().next().is_some_and(|c| c.is_ascii_uppercase())
+}
+
+/// The name of the hook called by `useState()` or `React.useState()`.
+pub fn get_react_hook_name<'a>(call_expr: &'a CallExpression<'a>) -> Option<&'a str> {
+    match &call_expr.callee.without_parenthesized() {
+        Expression::Identifier(ident) => {
+            is_react_hook_name(&ident.name).then_some(ident.name.as_str())
+        }
+        Expression::MemberExpression(member_expr) => {
+            // `React.useState`, but not `obj.useState`
+            let Expression::Identifier(object) = member_expr.object() else { return None };
+            if !is_react_component_name(&object.name) {
+                return None;
+            }
+            member_expr.static_property_name().filter(|name| is_react_hook_name(name))
+        }
+        _ => None,
+    }
+}
@@ -1,4 +1,4 @@
-use oxc_linter::rule::{RuleCategory, RuleMeta};
+use oxc_linter::rule::{RuleCategory, RuleFixability, RuleMeta};
 use oxc_macros::declare_oxc_lint_test;
 
 struct TestRule;
@@ -18,7 +18,8 @@ struct TestRule2 {
 declare_oxc_lint_test!(
     /// Dummy description2
     TestRule2,
-    correctness
+    correctness,
+    fix
 );
 
 #[test]
@@ -31,4 +32,92 @@ fn test_declare_oxc_lint() {
 
     // Auto-generated kebab-case name
     assert_eq!(TestRule::NAME, "test-rule");
+
+    // Optional fixability
+    assert_eq!(TestRule::FIX, RuleFixability::None);
+    assert_eq!(TestRule2::FIX, RuleFixability::Fix);
+}
+
+mod custom_rule {
+    use std::{path::Path, rc::Rc};
+
+    use oxc_allocator::Allocator;
+    use oxc_ast::AstKind;
+    use oxc_diagnostics::{
+        miette::{self, Diagnostic},
+        thiserror::Error,
+    };
+    use oxc_linter::{AstNode, CustomRule, ESLintConfig, LintContext, LintOptions, Linter, Rule};
+    use oxc_macros::declare_custom_lint;
+    use oxc_parser::Parser;
+    use oxc_semantic::SemanticBuilder;
+    use oxc_span::{SourceType, Span};
+
+    #[derive(Debug, Error, Diagnostic)]
+    #[error("acme(no-foo): Unexpected `foo`.")]
+    #[diagnostic(severity(warning))]
+    struct NoFooDiagnostic(#[label] Span);
+
+    #[derive(Debug, Default, Clone)]
+    struct NoFoo {
+        name: Option<String>,
+    }
+
+    declare_custom_lint!(
+        /// Disallow the identifiers named `foo`
+        NoFoo,
+        restriction
+    );
+
+    impl Rule for NoFoo {
+        fn from_configuration(value: serde_json::Value) -> Self {
+            let name = value.get(0).and_then(serde_json::Value::as_str).map(ToString::to_string);
+            Self { name }
+        }
+
+        fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+            let AstKind::IdentifierReference(ident) = node.kind() else { return };
+            if ident.name == self.name.as_deref().unwrap_or("foo") {
+                ctx.diagnostic(NoFooDiagnostic(ident.span));
+            }
+        }
+    }
+
+    fn lint(linter: &Linter, source_text: &str) -> usize {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let ret = Parser::new(&allocator, source_text, source_type).parse();
+        let program = allocator.alloc(ret.program);
+        let semantic = SemanticBuilder::new(source_text, source_type).build(program).semantic;
+        let ctx = LintContext::new(Path::new("test.js").into(), &Rc::new(semantic));
+        linter.run(ctx).len()
+    }
+
+    #[test]
+    fn run() {
+        let rule = CustomRule::new("acme", NoFoo::default());
+        assert_eq!(rule.name(), "no-foo");
+        assert_eq!(rule.plugin_name(), "acme");
+        assert_eq!(rule.category(), RuleCategory::Restriction);
+
+        let linter = Linter::new().with_custom_rules([rule]);
+        assert!(linter.rules().iter().any(|rule| rule.name() == "no-foo"));
+        assert_eq!(lint(&linter, "foo(); bar(); foo;"), 2);
+    }
+
+    #[test]
+    fn configure() {
+        let config = |rules: serde_json::Value| {
+            let config = ESLintConfig::from_value(&serde_json::json!({ "rules": rules })).unwrap();
+            let options = LintOptions::default().with_filter(vec![]).with_eslint_config(config);
+            Linter::from_options(options)
+                .with_custom_rules([CustomRule::new("acme", NoFoo::default())])
+        };
+
+        let linter = config(serde_json::json!({ "acme/no-foo": ["error", "bar"] }));
+        assert_eq!(lint(&linter, "foo(); bar(); foo;"), 1);
+
+        let linter = config(serde_json::json!({ "acme/no-foo": "off" }));
+        assert_eq!(lint(&linter, "foo(); bar(); foo;"), 0);
+    }
 }
@@ -15,6 +15,17 @@ use oxc_span::Span;
 pub enum ErrorFromLinterPlugin {
     #[error("{0}")]
     PluginGenerated(String, String, #[label("{1}")] Span),
+    #[error("{0}")]
+    JsPluginGenerated(String, #[label] Span),
+    #[error("Failed to load the ESLint plugin at path: {0}")]
+    #[diagnostic(help("{1}"))]
+    JsPluginLoad(PathBuf, String),
+    #[error("Invalid configuration for the rule {0:?}")]
+    #[diagnostic(help("{1}"))]
+    JsPluginRuleConfig(String, String),
+    #[error("Failed to run the ESLint plugin on the file at path: {0}")]
+    #[diagnostic(help("{1}"))]
+    JsPluginRun(PathBuf, String),
     #[error("{error_message}")]
     Trustfall {
         error_message: String,
@@ -0,0 +1,536 @@
+// Runs the rules of an ESLint plugin for `JsLinterPlugin`.
+//
+// The plugin path is `process.argv[1]`. The host answers every line of stdin with one line of
+// stdout, both JSON:
+// * `{ "name": "plugin", "rules": ["rule"] }` is written once the plugin is loaded.
+// * `{ "filename", "source", "ast", "comments", "rules": { "rule": [options] } }` is answered with
+//   `{ "diagnostics": [{ "rule", "message", "start", "end", "fix": { "start", "end", "text" } }] }`.
+// * Any failure is answered with `{ "error": "message" }`.
+//
+// The offsets sent to and from Rust are UTF-8 byte offsets, the ESTree nodes use UTF-16 indices.
+
+'use strict';
+
+const path = require('path');
+const readline = require('readline');
+const { pathToFileURL } = require('url');
+
+const stdout = process.stdout;
+// The protocol owns stdout, the output of the rules goes to stderr.
+console.log = console.info = console.debug = console.error;
+
+function send(message) {
+  stdout.write(JSON.stringify(message) + '\n');
+}
+
+function pluginName(pluginPath, plugin) {
+  if (plugin.meta && typeof plugin.meta.name === 'string') {
+    return plugin.meta.name.replace(/^eslint-plugin-/, '').replace(/\/eslint-plugin$/, '');
+  }
+  const base = path.basename(pluginPath, path.extname(pluginPath));
+  return base === 'index' ? path.basename(path.dirname(pluginPath)) : base.replace(/^eslint-plugin-/, '');
+}
+
+/* Source positions */
+
+class Source {
+  constructor(text) {
+    this.text = text;
+    this.lineStarts = [0];
+    for (let i = 0; i < text.length; i++) {
+      const c = text.charCodeAt(i);
+      if (c === 10 || (c === 13 && text.charCodeAt(i + 1) !== 10) || c === 0x2028 || c === 0x2029) {
+        this.lineStarts.push(i + 1);
+      }
+    }
+    this.lines = text.split(/\r\n|[\r\n\u2028\u2029]/);
+    this.byteToIndex = null;
+    if (Buffer.byteLength(text) !== text.length) {
+      this.byteToIndex = new Uint32Array(Buffer.byteLength(text) + 1);
+      let byte = 0;
+      for (let index = 0; index < text.length; index++) {
+        const code = text.codePointAt(index);
+        const size = code < 0x80 ? 1 : code < 0x800 ? 2 : code < 0x10000 ? 3 : 4;
+        for (let i = 0; i < size; i++) this.byteToIndex[byte + i] = index;
+        byte += size;
+        if (size === 4) index++;
+      }
+      this.byteToIndex[byte] = text.length;
+    }
+  }
+
+  index(byte) {
+    return this.byteToIndex ? this.byteToIndex[byte] : byte;
+  }
+
+  byte(index) {
+    return this.byteToIndex ? Buffer.byteLength(this.text.slice(0, index)) : index;
+  }
+
+  loc(index) {
+    let low = 0;
+    let high = this.lineStarts.length - 1;
+    while (low < high) {
+      const mid = (low + high + 1) >> 1;
+      if (this.lineStarts[mid] <= index) low = mid;
+      else high = mid - 1;
+    }
+    return { line: low + 1, column: index - this.lineStarts[low] };
+  }
+
+  indexFromLoc(loc) {
+    const start = this.lineStarts[loc.line - 1];
+    return start === undefined ? this.text.length : Math.min(start + loc.column, this.text.length);
+  }
+}
+
+/* oxc AST to ESTree */
+
+const IDENTIFIERS = new Set([
+  'IdentifierReference',
+  'BindingIdentifier',
+  'IdentifierName',
+  'LabelIdentifier',
+]);
+
+function isNode(value) {
+  return value !== null && typeof value === 'object' && typeof value.type === 'string' && !Array.isArray(value);
+}
+
+function converter(source) {
+  function finish(node, start, end) {
+    node.start = source.index(start);
+    node.end = source.index(end);
+    node.range = [node.start, node.end];
+    node.loc = { start: source.loc(node.start), end: source.loc(node.end) };
+    return node;
+  }
+
+  function make(type, n, fields) {
+    return finish(Object.assign({ type }, fields), n.start, n.end);
+  }
+
+  function literal(n, value) {
+    return make('Literal', n, { value, raw: source.text.slice(source.index(n.start), source.index(n.end)) });
+  }
+
+  function params(n) {
+    const items = n.items.map((item) => convert(item.pattern));
+    if (n.rest) items.push(convert(n.rest));
+    return items;
+  }
+
+  function block(n) {
+    const body = n.directives.map(convert).concat(n.statements.map(convert));
+    return make('BlockStatement', n, { body });
+  }
+
+  function fn(type, n, start, end) {
+    let body = null;
+    let expression = false;
+    if (n.body && type === 'ArrowFunctionExpression' && n.expression) {
+      body = convert(n.body.statements[0].expression);
+      expression = true;
+    } else if (n.body) {
+      body = block(n.body);
+    }
+    return finish(
+      {
+        type,
+        id: n.id ? convert(n.id) : null,
+        params: params(n.params),
+        body,
+        async: n.async,
+        generator: n.generator,
+        expression,
+      },
+      start,
+      end,
+    );
+  }
+
+  // `...rest` of the assignment targets only has the span of the target.
+  function restElement(target) {
+    const argument = convert(target);
+    const start = source.text.lastIndexOf('...', argument.start);
+    const node = { type: 'RestElement', argument };
+    return finish(node, source.byte(start), target.end);
+  }
+
+  function isComputed(key) {
+    const before = source.text.slice(0, source.index(key.start)).trimEnd();
+    return before.endsWith('[');
+  }
+
+  function convert(n) {
+    if (n === null || n === undefined) return null;
+    if (IDENTIFIERS.has(n.type)) return make('Identifier', n, { name: n.name });
+    switch (n.type) {
+      case 'BindingPattern':
+        return convert(n.kind);
+      case 'ParenthesizedExpression':
+        return convert(n.expression);
+      case 'StringLiteral':
+      case 'NumberLiteral':
+      case 'BooleanLiteral':
+        return literal(n, n.value);
+      case 'NullLiteral':
+        return literal(n, null);
+      case 'BigintLiteral': {
+        const bigint = n.value.slice(0, -1).replace(/_/g, '');
+        return Object.assign(literal(n, BigInt(bigint)), { bigint });
+      }
+      case 'RegExpLiteral': {
+        let value = null;
+        try {
+          value = new RegExp(n.regex.pattern, n.regex.flags);
+        } catch {}
+        return Object.assign(literal(n, value), { regex: n.regex });
+      }
+      case 'Directive':
+        return make('ExpressionStatement', n, { expression: convert(n.expression), directive: n.directive });
+      case 'Program': {
+        const body = n.directives.map(convert).concat(n.body.map(convert));
+        const sourceType = n.sourceType.moduleKind === 'module' ? 'module' : 'script';
+        return make('Program', n, { body, sourceType, comments: [], tokens: [] });
+      }
+      case 'FunctionBody':
+        return block(n);
+      case 'FunctionDeclaration':
+      case 'FunctionExpression':
+        return fn(n.type, n, n.start, n.end);
+      case 'ArrowExpression':
+        return fn('ArrowFunctionExpression', n, n.span.start, n.span.end);
+      case 'StaticMemberExpression':
+        return make('MemberExpression', n, {
+          object: convert(n.object),
+          property: convert(n.property),
+          computed: false,
+          optional: n.optional,
+        });
+      case 'ComputedMemberExpression':
+        return make('MemberExpression', n, {
+          object: convert(n.object),
+          property: convert(n.expression),
+          computed: true,
+          optional: n.optional,
+        });
+      case 'PrivateFieldExpression':
+        return make('MemberExpression', n, {
+          object: convert(n.object),
+          property: convert(n.field),
+          computed: false,
+          optional: n.optional,
+        });
+      case 'CallExpression':
+        return make('CallExpression', n, {
+          callee: convert(n.callee),
+          arguments: n.arguments.map(convert),
+          optional: n.optional,
+        });
+      case 'NewExpression':
+        return make('NewExpression', n, { callee: convert(n.callee), arguments: n.arguments.map(convert) });
+      case 'ImportExpression':
+        return make('ImportExpression', n, { source: convert(n.source) });
+      case 'ObjectProperty':
+        return make('Property', n, {
+          key: convert(n.key),
+          value: convert(n.value),
+          kind: n.kind,
+          method: n.method,
+          shorthand: n.shorthand,
+          computed: n.computed,
+        });
+      case 'BindingProperty':
+        return make('Property', n, {
+          key: convert(n.key),
+          value: convert(n.value),
+          kind: 'init',
+          method: false,
+          shorthand: n.shorthand,
+          computed: n.computed,
+        });
+      case 'ObjectPattern':
+      case 'ObjectAssignmentTarget': {
+        const properties = n.properties.map(convert);
+        if (n.rest) properties.push(n.rest.type === 'RestElement' ? convert(n.rest) : restElement(n.rest));
+        return make('ObjectPattern', n, { properties });
+      }
+      case 'ArrayPattern':
+      case 'ArrayAssignmentTarget': {
+        const elements = n.elements.map(convert);
+        if (n.rest) elements.push(n.rest.type === 'RestElement' ? convert(n.rest) : restElement(n.rest));
+        return make('ArrayPattern', n, { elements });
+      }
+      case 'AssignmentTargetPropertyIdentifier': {
+        const key = convert(n.binding);
+        const value = n.init
+          ? make('AssignmentPattern', n, { left: convert(n.binding), right: convert(n.init) })
+          : convert(n.binding);
+        return make('Property', n, { key, value, kind: 'init', method: false, shorthand: true, computed: false });
+      }
+      case 'AssignmentTargetPropertyProperty':
+        return make('Property', n, {
+          key: convert(n.name),
+          value: convert(n.binding),
+          kind: 'init',
+          method: false,
+          shorthand: false,
+          computed: isComputed(n.name),
+        });
+      case 'AssignmentTargetWithDefault':
+        return make('AssignmentPattern', n, { left: convert(n.binding), right: convert(n.init) });
+      case 'MethodDefinition':
+        return make('MethodDefinition', n, {
+          key: convert(n.key),
+          value: convert(n.value),
+          kind: n.kind,
+          computed: n.computed,
+          static: n.static,
+        });
+      case 'PropertyDefinition':
+        return make('PropertyDefinition', n, {
+          key: convert(n.key),
+          value: convert(n.value),
+          computed: n.computed,
+          static: n.static,
+        });
+      case 'ExportDefaultDeclaration':
+        return make('ExportDefaultDeclaration', n, { declaration: convert(n.declaration) });
+      default:
+        return generic(n);
+    }
+  }
+
+  // The nodes which are the same in ESTree, only their children are converted.
+  function generic(n) {
+    const node = { type: n.type };
+    for (const key of Object.keys(n)) {
+      if (key === 'type' || key === 'start' || key === 'end') continue;
+      node[key] = value(n[key]);
+    }
+    return 'start' in n ? finish(node, n.start, n.end) : node;
+  }
+
+  function value(v) {
+    if (Array.isArray(v)) return v.map(value);
+    if (!isNode(v)) return v;
+    // Enums of unit variants such as `{ "type": "value" }` of `importKind`.
+    if (Object.keys(v).length === 1) return v.type;
+    return convert(v);
+  }
+
+  return convert;
+}
+
+/* Rule context */
+
+function interpolate(message, data) {
+  if (!data) return message;
+  return message.replace(/\{\{\s*([^{}]+?)\s*\}\}/g, (match, key) => (key in data ? String(data[key]) : match));
+}
+
+function childNodes(node) {
+  const children = [];
+  for (const key of Object.keys(node)) {
+    if (key === 'parent' || key === 'loc' || key === 'range' || key === 'comments' || key === 'tokens') continue;
+    const v = node[key];
+    if (Array.isArray(v)) {
+      for (const item of v) if (isNode(item)) children.push(item);
+    } else if (isNode(v)) {
+      children.push(v);
+    }
+  }
+  return children;
+}
+
+function fixer() {
+  const replace = (range, text) => ({ range: [range[0], range[1]], text });
+  return {
+    insertTextAfter: (node, text) => replace([node.range[1], node.range[1]], text),
+    insertTextAfterRange: (range, text) => replace([range[1], range[1]], text),
+    insertTextBefore: (node, text) => replace([node.range[0], node.range[0]], text),
+    insertTextBeforeRange: (range, text) => replace([range[0], range[0]], text),
+    remove: (node) => replace(node.range, ''),
+    removeRange: (range) => replace(range, ''),
+    replaceText: (node, text) => replace(node.range, text),
+    replaceTextRange: (range, text) => replace(range, text),
+  };
+}
+
+// Merge the fixes of one report into a single replacement, like ESLint.
+function mergeFixes(result, source) {
+  if (!result) return null;
+  const fixes = (typeof result[Symbol.iterator] === 'function' ? [...result] : [result]).filter(Boolean);
+  if (fixes.length === 0) return null;
+  fixes.sort((a, b) => a.range[0] - b.range[0] || a.range[1] - b.range[1]);
+  const start = fixes[0].range[0];
+  const end = Math.max(...fixes.map((fix) => fix.range[1]));
+  let text = '';
+  let last = start;
+  for (const fix of fixes) {
+    if (fix.range[0] < last) return null;
+    text += source.text.slice(last, fix.range[0]) + fix.text;
+    last = fix.range[1];
+  }
+  text += source.text.slice(last, end);
+  return { start: source.byte(start), end: source.byte(end), text };
+}
+
+function sourceCode(source, ast, comments) {
+  const ancestors = (node) => {
+    const result = [];
+    for (let parent = node && node.parent; parent; parent = parent.parent) result.unshift(parent);
+    return result;
+  };
+  return {
+    text: source.text,
+    ast,
+    lines: source.lines,
+    hasBOM: false,
+    parserServices: {},
+    getText(node, before = 0, after = 0) {
+      if (!node) return source.text;
+      return source.text.slice(Math.max(node.range[0] - before, 0), node.range[1] + after);
+    },
+    getLines: () => source.lines,
+    getAllComments: () => comments,
+    getCommentsBefore: (node) => comments.filter((c) => c.range[1] <= node.range[0] && !/\S/.test(source.text.slice(c.range[1], node.range[0]))),
+    getCommentsInside: (node) => comments.filter((c) => c.range[0] >= node.range[0] && c.range[1] <= node.range[1]),
+    getAncestors: ancestors,
+    getLocFromIndex: (index) => source.loc(index),
+    getIndexFromLoc: (loc) => source.indexFromLoc(loc),
+  };
+}
+
+/* Linting */
+
+function loadRule(ruleId, rule) {
+  if (typeof rule === 'function') return { meta: {}, create: rule };
+  if (rule && typeof rule.create === 'function') return rule;
+  throw new Error(`The rule "${ruleId}" has no \`create\` function.`);
+}
+
+function lint(plugin, request) {
+  const source = new Source(request.source);
+  const ast = converter(source)(request.ast);
+  const comments = request.comments.map((c) => {
+    const start = source.index(c.start);
+    const end = source.index(c.end);
+    const comment = { type: c.type, value: source.text.slice(start, end) };
+    const range = [start - 2, c.type === 'Block' ? end + 2 : end];
+    return Object.assign(comment, { range, start: range[0], end: range[1], loc: { start: source.loc(range[0]), end: source.loc(range[1]) } });
+  });
+  ast.comments = comments;
+  const code = sourceCode(source, ast, comments);
+
+  const diagnostics = [];
+  const enter = new Map();
+  const exit = new Map();
+  let current = ast;
+
+  for (const [ruleId, options] of Object.entries(request.rules)) {
+    const rule = loadRule(ruleId, plugin.rules[ruleId]);
+    const messages = (rule.meta && rule.meta.messages) || {};
+    const report = (...args) => {
+      let descriptor = args[0];
+      if (args.length > 1 || !descriptor || !(descriptor.node || descriptor.loc)) {
+        const [node, ...rest] = args;
+        descriptor = typeof rest[0] === 'string'
+          ? { node, message: rest[0], data: rest[1] }
+          : { node, loc: rest[0], message: rest[1], data: rest[2] };
+      }
+      let message = descriptor.message;
+      if (descriptor.messageId !== undefined) {
+        message = messages[descriptor.messageId];
+        if (message === undefined) throw new Error(`${ruleId}: unknown messageId "${descriptor.messageId}".`);
+      }
+      message = interpolate(String(message), descriptor.data);
+      let start;
+      let end;
+      if (descriptor.loc) {
+        const loc = descriptor.loc.start ? descriptor.loc : { start: descriptor.loc, end: descriptor.loc };
+        start = source.indexFromLoc(loc.start);
+        end = source.indexFromLoc(loc.end || loc.start);
+      } else {
+        [start, end] = descriptor.node.range;
+      }
+      const fix = typeof descriptor.fix === 'function' ? mergeFixes(descriptor.fix(fixer()), source) : null;
+      diagnostics.push({ rule: ruleId, message, start: source.byte(start), end: source.byte(end), fix });
+    };
+    const context = {
+      id: `${plugin.name}/${ruleId}`,
+      options,
+      settings: {},
+      parserOptions: {},
+      languageOptions: { sourceType: ast.sourceType, ecmaVersion: 'latest' },
+      filename: request.filename,
+      physicalFilename: request.filename,
+      cwd: process.cwd(),
+      sourceCode: code,
+      getFilename: () => request.filename,
+      getPhysicalFilename: () => request.filename,
+      getCwd: () => process.cwd(),
+      getSourceCode: () => code,
+      getAncestors: () => code.getAncestors(current),
+      getScope() {
+        throw new Error(`${ruleId}: scope analysis is not supported.`);
+      },
+      report,
+    };
+    const listeners = rule.create(context) || {};
+    for (const [key, listener] of Object.entries(listeners)) {
+      if (typeof listener !== 'function') continue;
+      // Only the node types, `*` and their `:exit` are supported as selectors.
+      for (let selector of key.split(',')) {
+        selector = selector.trim();
+        const map = selector.endsWith(':exit') ? exit : enter;
+        selector = selector.replace(/:exit$/, '');
+        if (!map.has(selector)) map.set(selector, []);
+        map.get(selector).push(listener);
+      }
+    }
+  }
+
+  const call = (map, node) => {
+    current = node;
+    for (const listener of map.get(node.type) || []) listener(node);
+    for (const listener of map.get('*') || []) listener(node);
+  };
+  const visit = (node, parent) => {
+    node.parent = parent;
+    call(enter, node);
+    for (const child of childNodes(node)) visit(child, node);
+    call(exit, node);
+  };
+  visit(ast, null);
+
+  return diagnostics;
+}
+
+async function main() {
+  const pluginPath = path.resolve(process.argv[1]);
+  let plugin;
+  try {
+    const module = await import(pathToFileURL(pluginPath).href);
+    plugin = module.default || module;
+    if (!plugin.rules || typeof plugin.rules !== 'object') {
+      throw new Error(`${pluginPath} does not export any \`rules\`.`);
+    }
+  } catch (error) {
+    send({ error: String(error && error.stack ? error.stack : error) });
+    return;
+  }
+  const name = pluginName(pluginPath, plugin);
+  send({ name, rules: Object.keys(plugin.rules) });
+
+  for await (const line of readline.createInterface({ input: process.stdin, crlfDelay: Infinity })) {
+    try {
+      send({ diagnostics: lint({ name, rules: plugin.rules }, JSON.parse(line)) });
+    } catch (error) {
+      send({ error: String(error && error.stack ? error.stack : error) });
+    }
+  }
+}
+
+main();
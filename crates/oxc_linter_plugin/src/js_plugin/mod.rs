@@ -0,0 +1,418 @@
+use std::{
+    io::{BufRead, BufReader, Write},
+    path::Path,
+    process::{Child, ChildStdin, ChildStdout, Command, Stdio},
+    sync::{Mutex, PoisonError},
+};
+
+use oxc_ast::AstKind;
+use oxc_diagnostics::Severity;
+use oxc_linter::{Fix, LintContext};
+use oxc_span::Span;
+use serde::Deserialize;
+use serde_json::{json, Map, Value};
+
+use crate::errors::ErrorFromLinterPlugin;
+
+/// The `node` script loading the plugin and running its rules, see the protocol at its top.
+const HOST: &str = include_str!("host.js");
+
+/// The rules of an ESLint plugin written in JavaScript, run by `node` on the oxc AST.
+///
+/// The AST of each file is serialized to JSON and converted to ESTree by the host script.
+/// The rules get a subset of the ESLint rule context: `report` (with fixes), `options`, `id`,
+/// the file name and `sourceCode` without tokens. Scope analysis (`context.getScope()`) and
+/// the selectors other than the node types, `*` and `:exit` are not supported.
+///
+/// One `node` process is started for the plugin and kept until the plugin is dropped.
+pub struct JsLinterPlugin {
+    name: String,
+    rules: Vec<JsRule>,
+    host: Mutex<Host>,
+}
+
+struct JsRule {
+    /// Leaked once per loaded plugin, the disable directives need `&'static str` rule names.
+    name: &'static str,
+    severity: Option<Severity>,
+    options: Value,
+}
+
+struct Host {
+    child: Child,
+    stdin: ChildStdin,
+    stdout: BufReader<ChildStdout>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Response<T> {
+    Ok(T),
+    Err { error: String },
+}
+
+#[derive(Deserialize)]
+struct Loaded {
+    name: String,
+    rules: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct Linted {
+    diagnostics: Vec<JsDiagnostic>,
+}
+
+#[derive(Deserialize)]
+struct JsDiagnostic {
+    rule: String,
+    message: String,
+    start: u32,
+    end: u32,
+    fix: Option<JsFix>,
+}
+
+#[derive(Deserialize)]
+struct JsFix {
+    start: u32,
+    end: u32,
+    text: String,
+}
+
+impl JsLinterPlugin {
+    /// Load the ESLint plugin exported by the JavaScript file at `plugin_path`, e.g.
+    /// `node_modules/eslint-plugin-foo/index.js`. All of its rules are enabled without options.
+    ///
+    /// # Errors
+    /// If `node` can not be started, or if the plugin can not be loaded.
+    pub fn new(plugin_path: &Path) -> oxc_diagnostics::Result<Self> {
+        let path = plugin_path.canonicalize().map_err(ErrorFromLinterPlugin::ReadFile)?;
+        let mut child = Command::new("node")
+            .arg("-e")
+            .arg(HOST)
+            .arg(&path)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()
+            .map_err(ErrorFromLinterPlugin::ReadFile)?;
+        let (Some(stdin), Some(stdout)) = (child.stdin.take(), child.stdout.take()) else {
+            unreachable!("stdin and stdout are piped");
+        };
+        let mut host = Host { child, stdin, stdout: BufReader::new(stdout) };
+
+        let loaded: Loaded = host
+            .receive()
+            .map_err(|error| ErrorFromLinterPlugin::JsPluginLoad(path.clone(), error))?;
+        let rules = loaded
+            .rules
+            .into_iter()
+            .map(|name| JsRule {
+                name: Box::leak(name.into_boxed_str()),
+                severity: None,
+                options: Value::Array(vec![]),
+            })
+            .collect();
+        Ok(Self { name: loaded.name, rules, host: Mutex::new(host) })
+    }
+
+    /// The name of the plugin, the prefix of its rules in the configuration, e.g. `foo` for
+    /// `eslint-plugin-foo`.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The names of the enabled rules.
+    pub fn rule_names(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.rules.iter().map(|rule| rule.name)
+    }
+
+    /// Configure the rules with the `rules` of an ESLint configuration, e.g.
+    /// `{ "foo/no-bar": "off", "foo/no-baz": ["warn", { "allow": [] }] }`.
+    /// The entries of the other plugins are ignored.
+    ///
+    /// # Errors
+    /// If a rule of this plugin does not exist or has an invalid severity.
+    pub fn with_rules_config(
+        mut self,
+        rules: &Map<String, Value>,
+    ) -> oxc_diagnostics::Result<Self> {
+        for (key, value) in rules {
+            let Some(rule_name) = key.strip_prefix(&self.name).and_then(|s| s.strip_prefix('/'))
+            else {
+                continue;
+            };
+            let Some(index) = self.rules.iter().position(|rule| rule.name == rule_name) else {
+                return Err(ErrorFromLinterPlugin::JsPluginRuleConfig(
+                    key.clone(),
+                    "The rule does not exist.".to_string(),
+                )
+                .into());
+            };
+            let (severity, options) = match value {
+                Value::Array(values) => {
+                    (values.first().unwrap_or(&Value::Null), Value::Array(values[1..].to_vec()))
+                }
+                severity => (severity, Value::Array(vec![])),
+            };
+            let severity = match severity {
+                Value::String(s) if s == "off" => None,
+                Value::Number(n) if n.as_u64() == Some(0) => None,
+                Value::String(s) if s == "warn" => Some(Severity::Warning),
+                Value::Number(n) if n.as_u64() == Some(1) => Some(Severity::Warning),
+                Value::String(s) if s == "error" => Some(Severity::Error),
+                Value::Number(n) if n.as_u64() == Some(2) => Some(Severity::Error),
+                severity => {
+                    return Err(ErrorFromLinterPlugin::JsPluginRuleConfig(
+                        key.clone(),
+                        format!("{severity} is not one of \"off\", \"warn\", \"error\", 0, 1 or 2"),
+                    )
+                    .into())
+                }
+            };
+            match severity {
+                None => {
+                    self.rules.remove(index);
+                }
+                Some(severity) => {
+                    let rule = &mut self.rules[index];
+                    rule.severity = Some(severity);
+                    rule.options = options;
+                }
+            }
+        }
+        Ok(self)
+    }
+
+    /// Run the enabled rules on parsed code.
+    ///
+    /// # Errors
+    /// If the host process has exited, or if a rule throws an error.
+    pub fn lint_file(&self, ctx: &mut LintContext) -> oxc_diagnostics::Result<()> {
+        if self.rules.is_empty() {
+            return Ok(());
+        }
+        let semantic = ctx.semantic();
+        let Some(AstKind::Program(program)) =
+            semantic.nodes().iter().next().map(oxc_semantic::AstNode::kind)
+        else {
+            return Ok(());
+        };
+        let comments = semantic
+            .trivias()
+            .comments_spans()
+            .into_iter()
+            .map(|(comment, span)| {
+                let kind = if comment.is_single_line() { "Line" } else { "Block" };
+                json!({ "type": kind, "start": span.start, "end": span.end })
+            })
+            .collect::<Vec<_>>();
+        let rules =
+            self.rules.iter().map(|rule| (rule.name.to_string(), rule.options.clone())).collect();
+        let request = format!(
+            r#"{{"filename":{},"source":{},"comments":{},"rules":{},"ast":{}}}"#,
+            Value::String(ctx.file_path().to_string_lossy().to_string()),
+            Value::String(ctx.source_text().to_string()),
+            Value::Array(comments),
+            Value::Object(rules),
+            program.to_json(),
+        );
+
+        let linted: Linted = {
+            let mut host = self.host.lock().unwrap_or_else(PoisonError::into_inner);
+            host.send(&request).and_then(|()| host.receive()).map_err(|error| {
+                ErrorFromLinterPlugin::JsPluginRun(ctx.file_path().to_path_buf(), error)
+            })?
+        };
+
+        for diagnostic in linted.diagnostics {
+            let Some(rule) = self.rules.iter().find(|rule| rule.name == diagnostic.rule) else {
+                continue;
+            };
+            ctx.with_rule_name(rule.name);
+            ctx.with_rule_severity(rule.severity);
+            let error = ErrorFromLinterPlugin::JsPluginGenerated(
+                format!("{}({}): {}", self.name, rule.name, diagnostic.message),
+                Span::new(diagnostic.start, diagnostic.end),
+            );
+            match diagnostic.fix {
+                Some(fix) => ctx.diagnostic_with_fix(error, || {
+                    Fix::new(fix.text, Span::new(fix.start, fix.end))
+                }),
+                None => ctx.diagnostic(error),
+            }
+        }
+        ctx.with_rule_severity(None);
+        Ok(())
+    }
+}
+
+impl Host {
+    fn send(&mut self, line: &str) -> Result<(), String> {
+        writeln!(self.stdin, "{line}")
+            .and_then(|()| self.stdin.flush())
+            .map_err(|error| format!("`node`: {error}"))
+    }
+
+    fn receive<T: for<'de> Deserialize<'de>>(&mut self) -> Result<T, String> {
+        let mut line = String::new();
+        match self.stdout.read_line(&mut line) {
+            Ok(0) => return Err("`node` exited unexpectedly.".to_string()),
+            Ok(_) => {}
+            Err(error) => return Err(format!("`node`: {error}")),
+        }
+        match serde_json::from_str(&line).map_err(|error| error.to_string())? {
+            Response::Ok(value) => Ok(value),
+            Response::Err { error } => Err(error),
+        }
+    }
+}
+
+impl Drop for JsLinterPlugin {
+    fn drop(&mut self) {
+        if let Ok(host) = self.host.get_mut() {
+            let _ = host.child.kill();
+            let _ = host.child.wait();
+        }
+    }
+}
+
+impl std::fmt::Debug for JsLinterPlugin {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("JsLinterPlugin")
+            .field("name", &self.name)
+            .field("rules", &self.rule_names().collect::<Vec<_>>())
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{path::PathBuf, process::Command, rc::Rc};
+
+    use oxc_allocator::Allocator;
+    use oxc_linter::LintContext;
+    use oxc_parser::Parser;
+    use oxc_semantic::SemanticBuilder;
+    use oxc_span::SourceType;
+    use serde_json::json;
+
+    use super::JsLinterPlugin;
+
+    const PLUGIN: &str = r#"
+module.exports = {
+  rules: {
+    "no-foo": {
+      meta: { messages: { foo: "Unexpected {{ name }}." }, fixable: "code" },
+      create(context) {
+        const [{ name = "foo" } = {}] = context.options;
+        return {
+          Identifier(node) {
+            if (node.name === name) {
+              context.report({
+                node,
+                messageId: "foo",
+                data: { name },
+                fix: (fixer) => fixer.replaceText(node, "bar"),
+              });
+            }
+          },
+        };
+      },
+    },
+    "no-arrow-body": {
+      create(context) {
+        return {
+          "ArrowFunctionExpression:exit"(node) {
+            if (node.expression) {
+              context.report(node.body, `Expression body ${context.sourceCode.getText(node.body)}.`);
+            }
+          },
+        };
+      },
+    },
+  },
+};
+"#;
+
+    fn lint(plugin: &JsLinterPlugin, source_text: &str) -> Vec<(String, String)> {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let ret = Parser::new(&allocator, source_text, source_type).parse();
+        let program = allocator.alloc(ret.program);
+        let semantic = SemanticBuilder::new(source_text, source_type)
+            .with_trivias(ret.trivias)
+            .build(program)
+            .semantic;
+        let mut ctx =
+            LintContext::new(PathBuf::from("test.js").into_boxed_path(), &Rc::new(semantic));
+        plugin.lint_file(&mut ctx).unwrap();
+        ctx.into_message()
+            .into_iter()
+            .map(|message| {
+                let start = message.start() as usize;
+                let end = message.end() as usize;
+                (message.error.to_string(), source_text[start..end].to_string())
+            })
+            .collect()
+    }
+
+    /// Write the plugin to a temporary file, `None` if `node` is not installed.
+    fn load_plugin(name: &str) -> Option<JsLinterPlugin> {
+        if Command::new("node").arg("--version").output().is_err() {
+            return None;
+        }
+        let dir = std::env::temp_dir().join(format!("oxc_js_plugin_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(format!("eslint-plugin-{name}.js"));
+        std::fs::write(&path, PLUGIN).unwrap();
+        Some(JsLinterPlugin::new(&path).unwrap())
+    }
+
+    #[test]
+    fn run_rules() {
+        let Some(plugin) = load_plugin("test") else { return };
+        assert_eq!(plugin.name(), "test");
+        assert_eq!(plugin.rule_names().collect::<Vec<_>>(), vec!["no-foo", "no-arrow-body"]);
+
+        let messages = lint(&plugin, "let café = foo; const f = (a) => foo + a;");
+        assert_eq!(
+            messages,
+            vec![
+                ("test(no-foo): Unexpected foo.".to_string(), "foo".to_string()),
+                ("test(no-foo): Unexpected foo.".to_string(), "foo".to_string()),
+                (
+                    "test(no-arrow-body): Expression body foo + a.".to_string(),
+                    "foo + a".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn configure_rules() {
+        let Some(plugin) = load_plugin("config") else { return };
+        let rules = json!({
+            "config/no-arrow-body": "off",
+            "config/no-foo": ["warn", { "name": "baz" }],
+            "other/no-foo": "error",
+        });
+        let plugin = plugin.with_rules_config(rules.as_object().unwrap()).unwrap();
+        assert_eq!(plugin.rule_names().collect::<Vec<_>>(), vec!["no-foo"]);
+
+        let messages = lint(&plugin, "foo(baz, () => baz);");
+        assert_eq!(messages.len(), 2);
+        assert_eq!(messages[0].0, "config(no-foo): Unexpected baz.");
+
+        let rules = json!({ "config/no-qux": "error" });
+        assert!(plugin.with_rules_config(rules.as_object().unwrap()).is_err());
+    }
+
+    #[test]
+    fn invalid_plugin() {
+        if Command::new("node").arg("--version").output().is_err() {
+            return;
+        }
+        let path = std::env::temp_dir().join(format!("oxc_js_plugin_{}.js", std::process::id()));
+        std::fs::write(&path, "module.exports = {};").unwrap();
+        assert!(JsLinterPlugin::new(&path).is_err());
+    }
+}
@@ -1,4 +1,5 @@
 mod errors;
+mod js_plugin;
 mod plugin;
 mod raw_diagnostic;
 #[cfg(test)]
@@ -7,4 +8,4 @@ mod spans;
 mod test;
 mod util;
 
-pub use {plugin::LinterPlugin, util::make_relative_path_parts};
+pub use {js_plugin::JsLinterPlugin, plugin::LinterPlugin, util::make_relative_path_parts};
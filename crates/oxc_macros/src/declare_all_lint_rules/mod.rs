@@ -40,6 +40,10 @@ impl Parse for AllLintRulesMeta {
     }
 }
 
+/// The documentation of the rules is in their source files.
+const RULES_SOURCE_URL: &str =
+    "https://github.com/web-infra-dev/oxc/blob/main/crates/oxc_linter/src/rules";
+
 #[allow(clippy::cognitive_complexity)]
 pub fn declare_all_lint_rules(metadata: AllLintRulesMeta) -> TokenStream {
     let AllLintRulesMeta { rules } = metadata;
@@ -62,42 +66,67 @@ pub fn declare_all_lint_rules(metadata: AllLintRulesMeta) -> TokenStream {
             .collect::<Vec<_>>()
             .join("/")
     });
+    let docs_urls = rules.iter().map(|node| {
+        let path = node.path.segments.iter().map(|s| s.ident.to_string()).collect::<Vec<_>>();
+        format!("{RULES_SOURCE_URL}/{}.rs", path.join("/"))
+    });
 
     quote! {
         #(#use_stmts)*
 
         use std::time::{Instant, Duration};
-        use crate::{context::LintContext, rule::{Rule, RuleCategory, RuleMeta}, rule_timer:: RuleTimer, AstNode};
+        use crate::{context::LintContext, rule::{CustomRule, Rule, RuleCategory, RuleFixability, RuleMeta}, rule_timer:: RuleTimer, AstNode};
         use oxc_semantic::SymbolId;
 
         #[derive(Debug, Clone)]
         #[allow(clippy::enum_variant_names)]
         pub enum RuleEnum {
-            #(#struct_names(#struct_names)),*
+            #(#struct_names(#struct_names),)*
+            /// A rule declared outside of this crate, see [CustomRule].
+            Custom(CustomRule),
         }
 
         impl RuleEnum {
             pub fn name(&self) -> &'static str {
                 match self {
-                    #(Self::#struct_names(_) => #struct_names::NAME),*
+                    #(Self::#struct_names(_) => #struct_names::NAME,)*
+                    Self::Custom(rule) => rule.name(),
                 }
             }
 
             pub fn category(&self) -> RuleCategory {
                 match self {
-                    #(Self::#struct_names(_) => #struct_names::CATEGORY),*
+                    #(Self::#struct_names(_) => #struct_names::CATEGORY,)*
+                    Self::Custom(rule) => rule.category(),
                 }
             }
 
             pub fn documentation(&self) -> Option<&'static str> {
                 match self {
-                    #(Self::#struct_names(_) => #struct_names::documentation()),*
+                    #(Self::#struct_names(_) => #struct_names::documentation(),)*
+                    Self::Custom(rule) => rule.documentation(),
+                }
+            }
+
+            pub fn fix(&self) -> RuleFixability {
+                match self {
+                    #(Self::#struct_names(_) => #struct_names::FIX,)*
+                    Self::Custom(rule) => rule.fix(),
+                }
+            }
+
+            /// The documentation of the rule on the web, `None` for the custom rules.
+            pub fn docs_url(&self) -> Option<&'static str> {
+                match self {
+                    #(Self::#struct_names(_) => Some(#docs_urls),)*
+                    Self::Custom(_) => None,
                 }
             }
 
             pub fn plugin_name(&self) -> &str {
                 match self {
-                    #(Self::#struct_names(_) => #mod_names),*
+                    #(Self::#struct_names(_) => #mod_names,)*
+                    Self::Custom(rule) => rule.plugin_name(),
                 }
             }
 
@@ -105,45 +134,67 @@ pub fn declare_all_lint_rules(metadata: AllLintRulesMeta) -> TokenStream {
                 match self {
                     #(Self::#struct_names(_) => Self::#struct_names(
                         maybe_value.map(#struct_names::from_configuration).unwrap_or_default(),
-                    )),*
+                    ),)*
+                    Self::Custom(rule) => Self::Custom(rule.read_json(maybe_value)),
                 }
             }
 
             pub fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>, print_execution_times: bool) {
-                let start = print_execution_times.then(|| Instant::now());
+                let start = print_execution_times.then(|| (Instant::now(), ctx.number_of_diagnostics()));
                 let result = match self {
-                    #(Self::#struct_names(rule) => rule.run(node, ctx)),*
+                    #(Self::#struct_names(rule) => rule.run(node, ctx),)*
+                    Self::Custom(rule) => rule.run(node, ctx),
                 };
-                if let Some(start) = start {
-                    RULE_TIMERS.get(self.name()).unwrap().update(&start.elapsed());
+                if let Some((start, diagnostics)) = start {
+                    let timer = self.timer();
+                    timer.update(&start.elapsed());
+                    timer.add_diagnostics(ctx.number_of_diagnostics() - diagnostics);
                 }
                 result
             }
 
             pub fn run_on_symbol<'a>(&self, symbol_id: SymbolId, ctx: &LintContext<'a>, print_execution_times: bool) {
-                let start = print_execution_times.then(|| Instant::now());
+                let start = print_execution_times.then(|| (Instant::now(), ctx.number_of_diagnostics()));
                 let result = match self {
-                    #(Self::#struct_names(rule) => rule.run_on_symbol(symbol_id, ctx)),*
+                    #(Self::#struct_names(rule) => rule.run_on_symbol(symbol_id, ctx),)*
+                    Self::Custom(rule) => rule.run_on_symbol(symbol_id, ctx),
                 };
-                if let Some(start) = start {
-                    RULE_TIMERS.get(self.name()).unwrap().update(&start.elapsed());
+                if let Some((start, diagnostics)) = start {
+                    let timer = self.timer();
+                    timer.update(&start.elapsed());
+                    timer.add_diagnostics(ctx.number_of_diagnostics() - diagnostics);
                 }
                 result
             }
 
             pub fn run_once<'a>(&self, ctx: &LintContext<'a>, print_execution_times: bool) {
-                let start = print_execution_times.then(|| Instant::now());
+                let start = print_execution_times.then(|| (Instant::now(), ctx.number_of_diagnostics()));
                 let result = match self {
-                    #(Self::#struct_names(rule) => rule.run_once(ctx)),*
+                    #(Self::#struct_names(rule) => rule.run_once(ctx),)*
+                    Self::Custom(rule) => rule.run_once(ctx),
                 };
-                if let Some(start) = start {
-                    RULE_TIMERS.get(self.name()).unwrap().update(&start.elapsed());
+                if let Some((start, diagnostics)) = start {
+                    let timer = self.timer();
+                    timer.update(&start.elapsed());
+                    timer.add_diagnostics(ctx.number_of_diagnostics() - diagnostics);
                 }
                 result
             }
 
             pub fn execute_time(&self) -> Duration {
-                RULE_TIMERS.get(self.name()).unwrap().duration()
+                self.timer().duration()
+            }
+
+            /// The number of diagnostics reported by the rule, counted together with [Self::execute_time].
+            pub fn number_of_diagnostics(&self) -> usize {
+                self.timer().diagnostics()
+            }
+
+            fn timer(&self) -> &RuleTimer {
+                match self {
+                    Self::Custom(rule) => rule.timer(),
+                    _ => RULE_TIMERS.get(self.name()).unwrap(),
+                }
             }
         }
 
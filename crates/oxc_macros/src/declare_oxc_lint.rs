@@ -9,8 +9,12 @@ use syn::{
 pub struct LintRuleMeta {
     name: Ident,
     category: Ident,
+    /// `fix` or `suggestion`
+    fix: Option<Ident>,
     documentation: String,
     pub used_in_test: bool,
+    /// Whether the rule is declared outside of `oxc_linter`, e.g. in a custom rule crate.
+    pub external: bool,
 }
 
 impl Parse for LintRuleMeta {
@@ -33,16 +37,28 @@ impl Parse for LintRuleMeta {
         let struct_name = input.parse()?;
         input.parse::<Token!(,)>()?;
         let category = input.parse()?;
+        let fix = if input.parse::<Option<Token!(,)>>()?.is_some() && input.peek(Ident) {
+            Some(input.parse()?)
+        } else {
+            None
+        };
 
         // Ignore the rest
         input.parse::<TokenStream>()?;
 
-        Ok(Self { name: struct_name, category, documentation, used_in_test: false })
+        Ok(Self {
+            name: struct_name,
+            category,
+            fix,
+            documentation,
+            used_in_test: false,
+            external: false,
+        })
     }
 }
 
 pub fn declare_oxc_lint(metadata: LintRuleMeta) -> TokenStream {
-    let LintRuleMeta { name, category, documentation, used_in_test } = metadata;
+    let LintRuleMeta { name, category, fix, documentation, used_in_test, external } = metadata;
     let canonical_name = name.to_string().to_case(Case::Kebab);
     let category = match category.to_string().as_str() {
         "correctness" => quote! { RuleCategory::Correctness },
@@ -50,14 +66,23 @@ pub fn declare_oxc_lint(metadata: LintRuleMeta) -> TokenStream {
         "pedantic" => quote! { RuleCategory::Pedantic },
         "style" => quote! { RuleCategory::Style },
         "restriction" => quote! { RuleCategory::Restriction },
+        "security" => quote! { RuleCategory::Security },
         "nursery" => quote! { RuleCategory::Nursery },
         _ => panic!("invalid rule category"),
     };
+    let fix = match fix.map(|fix| fix.to_string()).as_deref() {
+        None => quote! { RuleFixability::None },
+        Some("fix") => quote! { RuleFixability::Fix },
+        Some("suggestion") => quote! { RuleFixability::Suggestion },
+        _ => panic!("invalid rule fixability"),
+    };
 
     let import_statement = if used_in_test {
         None
+    } else if external {
+        Some(quote! { use oxc_linter::rule::{RuleCategory, RuleFixability, RuleMeta}; })
     } else {
-        Some(quote! { use crate::rule::{RuleCategory, RuleMeta}; })
+        Some(quote! { use crate::rule::{RuleCategory, RuleFixability, RuleMeta}; })
     };
 
     let output = quote! {
@@ -68,6 +93,8 @@ pub fn declare_oxc_lint(metadata: LintRuleMeta) -> TokenStream {
 
             const CATEGORY: RuleCategory = #category;
 
+            const FIX: RuleFixability = #fix;
+
             fn documentation() -> Option<&'static str> {
                 Some(#documentation)
             }
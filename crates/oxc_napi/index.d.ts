@@ -11,6 +11,8 @@
 export interface ParserOptions {
   sourceType?: 'script' | 'module' | 'unambiguous' | undefined
   sourceFilename?: string
+  /** Serialize the program in the [ESTree](https://github.com/estree/estree) shape */
+  estree?: boolean
 }
 export interface ParseResult {
   program: string
@@ -18,6 +18,8 @@ pub struct ParserOptions {
     #[napi(ts_type = "'script' | 'module' | 'unambiguous' | undefined")]
     pub source_type: Option<String>,
     pub source_filename: Option<String>,
+    /// Serialize the program in the [ESTree](https://github.com/estree/estree) shape
+    pub estree: Option<bool>,
 }
 
 #[napi(object)]
@@ -70,7 +72,11 @@ pub fn parse_sync(source_text: String, options: Option<ParserOptions>) -> ParseR
 
     let allocator = Allocator::default();
     let ret = parse(&allocator, &source_text, &options);
-    let program = serde_json::to_string(&ret.program).unwrap();
+    let program = if options.estree == Some(true) {
+        ret.program.to_estree_json(&source_text)
+    } else {
+        serde_json::to_string(&ret.program).unwrap()
+    };
 
     let errors = if ret.errors.is_empty() {
         vec![]
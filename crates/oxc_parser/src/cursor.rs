@@ -14,6 +14,7 @@ pub struct ParserCheckpoint<'a> {
     cur_token: Token<'a>,
     prev_span_end: u32,
     errors_pos: usize,
+    tokens_pos: usize,
 }
 
 impl<'a> Parser<'a> {
@@ -96,7 +97,7 @@ impl<'a> Parser<'a> {
     fn test_escaped_keyword(&mut self, kind: Kind) {
         if self.cur_token().escaped && kind.is_all_keyword() {
             let span = self.cur_token().span();
-            self.error(diagnostics::EscapedKeyword(span));
+            self.early_error(diagnostics::EscapedKeyword(span));
         }
     }
 
@@ -104,6 +105,7 @@ impl<'a> Parser<'a> {
     /// Checks if the current token is escaped if it is a keyword
     fn advance(&mut self, kind: Kind) {
         self.test_escaped_keyword(kind);
+        self.preserve_token();
         self.prev_token_end = self.token.end;
         self.token = self.lexer.next_token();
     }
@@ -112,10 +114,20 @@ impl<'a> Parser<'a> {
     /// Checks if the current token is escaped if it is a keyword
     fn advance_for_jsx_child(&mut self, kind: Kind) {
         self.test_escaped_keyword(kind);
+        self.preserve_token();
         self.prev_token_end = self.token.end;
         self.token = self.lexer.next_jsx_child();
     }
 
+    /// Keep the current token with [Parser::preserve_tokens], the initial `Eof` is not a token
+    fn preserve_token(&mut self) {
+        if let Some(tokens) = &mut self.tokens {
+            if self.token.kind != Kind::Eof {
+                tokens.push(self.token.clone());
+            }
+        }
+    }
+
     /// Advance and return true if we are at `Kind`, return false otherwise
     pub(crate) fn eat(&mut self, kind: Kind) -> bool {
         if self.at(kind) {
@@ -239,17 +251,26 @@ impl<'a> Parser<'a> {
             cur_token: self.token.clone(),
             prev_span_end: self.prev_token_end,
             errors_pos: self.errors.len(),
+            tokens_pos: self.tokens.as_ref().map_or(0, Vec::len),
         }
     }
 
     pub(crate) fn rewind(&mut self, checkpoint: ParserCheckpoint<'a>) {
-        let ParserCheckpoint { lexer, cur_token, prev_span_end, errors_pos: errors_lens } =
-            checkpoint;
+        let ParserCheckpoint {
+            lexer,
+            cur_token,
+            prev_span_end,
+            errors_pos: errors_lens,
+            tokens_pos: tokens_len,
+        } = checkpoint;
 
         self.lexer.rewind(lexer);
         self.token = cur_token;
         self.prev_token_end = prev_span_end;
         self.errors.truncate(errors_lens);
+        if let Some(tokens) = &mut self.tokens {
+            tokens.truncate(tokens_len);
+        }
     }
 
     /// # Errors
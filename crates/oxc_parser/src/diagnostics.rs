@@ -311,3 +311,32 @@ pub struct UsingDeclarationNotAllowedInForInStatement(#[label] pub Span);
 #[error("Using declarations must have an initializer.")]
 #[diagnostic()]
 pub struct UsingDeclarationsMustBeInitialized(#[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error(
+    "Await using declarations are only allowed in async functions and at the top level of modules."
+)]
+#[diagnostic()]
+pub struct AwaitUsingDeclarationOutsideAsync(#[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("`let` is not allowed to be used as a name in using declarations.")]
+#[diagnostic()]
+pub struct LetInUsingDeclaration(#[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Import assertions are deprecated")]
+#[diagnostic(help("Replace `assert` with `with`"))]
+pub struct ImportAssertions(#[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Decorators are not valid here with legacy decorators")]
+#[diagnostic(help(
+    "Decorators on class expressions and after `export` need the 2023-05 decorators"
+))]
+pub struct LegacyDecorators(#[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Decorators are not valid on parameters")]
+#[diagnostic(help("Decorators on parameters need the legacy decorators"))]
+pub struct ParameterDecorators(#[label] pub Span);
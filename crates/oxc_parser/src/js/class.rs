@@ -4,7 +4,9 @@ use oxc_diagnostics::Result;
 use oxc_span::Span;
 
 use super::list::ClassElements;
-use crate::{diagnostics, lexer::Kind, list::NormalList, Parser, StatementContext};
+use crate::{
+    diagnostics, lexer::Kind, list::NormalList, DecoratorsVersion, Parser, StatementContext,
+};
 
 type Extends<'a> =
     Vec<'a, (Expression<'a>, Option<Box<'a, TSTypeParameterInstantiation<'a>>>, Span)>;
@@ -172,8 +174,6 @@ impl<'a> Parser<'a> {
 
         let modifier = self.parse_class_element_modifiers(false);
 
-        let accessor = self.peek_kind().is_class_element_name_start() && self.eat(Kind::Accessor);
-
         let accessibility = modifier.accessibility();
 
         let declare = modifier.declare();
@@ -198,6 +198,13 @@ impl<'a> Parser<'a> {
             }
         }
 
+        // accessor ..., an identifier with the legacy decorators
+        let accessor = key_name.is_none()
+            && self.decorators != Some(DecoratorsVersion::Legacy)
+            && self.peek_kind().is_class_element_name_start()
+            && !self.peek_token().is_on_new_line
+            && self.eat(Kind::Accessor);
+
         // async ...
         if key_name.is_none() && self.at(Kind::Async) && !self.peek_at(Kind::Question) {
             if self.peek_kind().is_class_element_name_start() || self.peek_at(Kind::Star) {
@@ -248,7 +255,7 @@ impl<'a> Parser<'a> {
 
         if let PropertyKey::PrivateIdentifier(private_ident) = &key {
             if private_ident.name == "constructor" {
-                self.error(diagnostics::PrivateNameConstructor(private_ident.span));
+                self.early_error(diagnostics::PrivateNameConstructor(private_ident.span));
             }
         }
 
@@ -276,17 +283,17 @@ impl<'a> Parser<'a> {
             )?;
             if let Some((name, span)) = definition.prop_name() {
                 if r#static && name == "prototype" && !self.ctx.has_ambient() {
-                    self.error(diagnostics::StaticPrototype(span));
+                    self.early_error(diagnostics::StaticPrototype(span));
                 }
                 if !r#static && name == "constructor" {
                     if kind == MethodDefinitionKind::Get || kind == MethodDefinitionKind::Set {
-                        self.error(diagnostics::ConstructorGetterSetter(span));
+                        self.early_error(diagnostics::ConstructorGetterSetter(span));
                     }
                     if r#async {
-                        self.error(diagnostics::ConstructorAsync(span));
+                        self.early_error(diagnostics::ConstructorAsync(span));
                     }
                     if generator {
-                        self.error(diagnostics::ConstructorGenerator(span));
+                        self.early_error(diagnostics::ConstructorGenerator(span));
                     }
                 }
             }
@@ -307,10 +314,10 @@ impl<'a> Parser<'a> {
             )?;
             if let Some((name, span)) = definition.prop_name() {
                 if name == "constructor" {
-                    self.error(diagnostics::FieldConstructor(span));
+                    self.early_error(diagnostics::FieldConstructor(span));
                 }
                 if r#static && name == "prototype" && !self.ctx.has_ambient() {
-                    self.error(diagnostics::StaticPrototype(span));
+                    self.early_error(diagnostics::StaticPrototype(span));
                 }
             }
             Ok(definition)
@@ -410,7 +417,7 @@ impl<'a> Parser<'a> {
         let value = if self.eat(Kind::Eq) {
             // let current_flags = self.scope.current_flags();
             // self.scope.set_current_flags(self.scope.current_flags());
-            let expr = self.parse_expression()?;
+            let expr = self.parse_expr()?;
             // self.scope.set_current_flags(current_flags);
             Some(expr)
         } else {
@@ -465,6 +472,14 @@ impl<'a> Parser<'a> {
     ) -> Result<ClassElement<'a>> {
         let value =
             self.eat(Kind::Eq).then(|| self.parse_assignment_expression_base()).transpose()?;
-        Ok(self.ast.accessor_property(self.end_span(span), key, value, computed, r#static))
+        let decorators = self.state.consume_decorators();
+        Ok(self.ast.accessor_property(
+            self.end_span(span),
+            key,
+            value,
+            computed,
+            r#static,
+            decorators,
+        ))
     }
 }
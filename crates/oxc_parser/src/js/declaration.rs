@@ -42,11 +42,15 @@ impl<'a> Parser<'a> {
         }
     }
 
-    pub(crate) fn parse_using(&mut self) -> Result<Statement<'a>> {
+    pub(crate) fn parse_using(&mut self, stmt_ctx: StatementContext) -> Result<Statement<'a>> {
         let using_decl = self.parse_using_declaration(StatementContext::StatementList)?;
 
         self.asi()?;
 
+        if stmt_ctx.is_single_statement() {
+            self.error(diagnostics::LexicalDeclarationSingleStatement(using_decl.span));
+        }
+
         Ok(Statement::Declaration(Declaration::UsingDeclaration(self.ast.alloc(using_decl))))
     }
 
@@ -104,7 +108,7 @@ impl<'a> Parser<'a> {
                 self.error(diagnostics::InvalidDestrucuringDeclaration(id.span()));
             } else if kind == VariableDeclarationKind::Const && !self.ctx.has_ambient() {
                 // It is a Syntax Error if Initializer is not present and IsConstantDeclaration of the LexicalDeclaration containing this LexicalBinding is true.
-                self.error(diagnostics::MissinginitializerInConst(id.span()));
+                self.early_error(diagnostics::MissinginitializerInConst(id.span()));
             }
         }
 
@@ -124,6 +128,10 @@ impl<'a> Parser<'a> {
 
         self.expect(Kind::Using)?;
 
+        if is_await && !self.ctx.has_await() {
+            self.error(diagnostics::AwaitUsingDeclarationOutsideAsync(self.end_span(span)));
+        }
+
         // `[no LineTerminator here]`
         if self.cur_token().is_on_new_line {
             self.error(diagnostics::LineTerminatorBeforeUsingDeclaration(self.cur_token().span()));
@@ -136,15 +144,21 @@ impl<'a> Parser<'a> {
         }
 
         // BindingList[?In, ?Yield, ?Await, ~Pattern]
+        // The bindings are constant, and their initializers are checked below.
         let mut declarations: oxc_allocator::Vec<'_, VariableDeclarator<'_>> = self.ast.new_vec();
         loop {
             let declaration = self.parse_variable_declarator(
-                VariableDeclarationContext::new(VariableDeclarationParent::Statement),
-                VariableDeclarationKind::Var,
+                VariableDeclarationContext::new(VariableDeclarationParent::For),
+                VariableDeclarationKind::Const,
             )?;
 
-            match declaration.id.kind {
-                BindingPatternKind::BindingIdentifier(_) => {}
+            match &declaration.id.kind {
+                // It is a Syntax Error if the BoundNames of BindingList contains "let".
+                BindingPatternKind::BindingIdentifier(ident) => {
+                    if ident.name == "let" {
+                        self.early_error(diagnostics::LetInUsingDeclaration(ident.span));
+                    }
+                }
                 _ => {
                     self.error(diagnostics::InvalidIdentifierInUsingDeclaration(
                         declaration.id.span(),
@@ -154,7 +168,9 @@ impl<'a> Parser<'a> {
 
             // Excluding `for` loops, an initializer is required in a UsingDeclaration.
             if declaration.init.is_none() && !matches!(statement_ctx, StatementContext::For) {
-                self.error(diagnostics::UsingDeclarationsMustBeInitialized(declaration.id.span()));
+                self.early_error(diagnostics::UsingDeclarationsMustBeInitialized(
+                    declaration.id.span(),
+                ));
             }
 
             declarations.push(declaration);
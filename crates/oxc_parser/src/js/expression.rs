@@ -25,13 +25,13 @@ use crate::{
 impl<'a> Parser<'a> {
     pub(crate) fn parse_paren_expression(&mut self) -> Result<Expression<'a>> {
         self.expect(Kind::LParen)?;
-        let expression = self.parse_expression()?;
+        let expression = self.parse_expr()?;
         self.expect(Kind::RParen)?;
         Ok(expression)
     }
 
     /// Section [Expression](https://tc39.es/ecma262/#sec-ecmascript-language-expressions)
-    pub(crate) fn parse_expression(&mut self) -> Result<Expression<'a>> {
+    pub(crate) fn parse_expr(&mut self) -> Result<Expression<'a>> {
         let span = self.start_span();
 
         let lhs = self.parse_assignment_expression_base()?;
@@ -106,11 +106,11 @@ impl<'a> Parser<'a> {
     pub(crate) fn check_identifier(&mut self, span: Span, name: &Atom) {
         // It is a Syntax Error if this production has an [Await] parameter.
         if self.ctx.has_await() && *name == "await" {
-            self.error(diagnostics::IdentifierAsync("await", span));
+            self.early_error(diagnostics::IdentifierAsync("await", span));
         }
         // It is a Syntax Error if this production has a [Yield] parameter.
         if self.ctx.has_yield() && *name == "yield" {
-            self.error(diagnostics::IdentifierGenerator("yield", span));
+            self.early_error(diagnostics::IdentifierGenerator("yield", span));
         }
     }
 
@@ -145,6 +145,7 @@ impl<'a> Parser<'a> {
 
         if self.at(Kind::At) {
             self.eat_decorators()?;
+            self.check_legacy_decorators();
         }
 
         // FunctionExpression, GeneratorExpression
@@ -182,6 +183,7 @@ impl<'a> Parser<'a> {
                 }
             }
             Kind::LParen => self.parse_parenthesized_expression(span),
+            Kind::Percent if self.v8_intrinsics => self.parse_v8_intrinsic_expression(span),
             Kind::Slash | Kind::SlashEq => {
                 self.read_regex();
                 self.parse_literal_regexp()
@@ -218,6 +220,20 @@ impl<'a> Parser<'a> {
         Ok(self.ast.parenthesized_expression(paren_span, expression))
     }
 
+    /// V8 runtime call `%Name(arguments)`
+    fn parse_v8_intrinsic_expression(&mut self, span: Span) -> Result<Expression<'a>> {
+        self.bump_any(); // advance `%`
+        if !self.cur_kind().is_identifier_name() || self.cur_token().start != self.prev_token_end {
+            return Err(self.unexpected());
+        }
+        let name = self.parse_identifier_name()?;
+        if !self.at(Kind::LParen) {
+            return Err(self.unexpected());
+        }
+        let arguments = self.with_context(Context::In, CallArguments::parse)?.elements;
+        Ok(self.ast.v8_intrinsic_expression(self.end_span(span), name, arguments))
+    }
+
     /// Section 13.2.2 This Expression
     fn parse_this_expression(&mut self) -> Expression<'a> {
         let span = self.start_span();
@@ -366,7 +382,7 @@ impl<'a> Parser<'a> {
             Kind::TemplateHead => {
                 quasis.push(self.parse_template_element(tagged));
                 // TemplateHead Expression[+In, ?Yield, ?Await]
-                let expr = self.with_context(Context::In, Self::parse_expression)?;
+                let expr = self.with_context(Context::In, Self::parse_expr)?;
                 expressions.push(expr);
                 self.re_lex_template_substitution_tail();
                 loop {
@@ -381,7 +397,7 @@ impl<'a> Parser<'a> {
                         }
                         _ => {
                             // TemplateMiddle Expression[+In, ?Yield, ?Await]
-                            let expr = self.with_context(Context::In, Self::parse_expression)?;
+                            let expr = self.with_context(Context::In, Self::parse_expr)?;
                             expressions.push(expr);
                             self.re_lex_template_substitution_tail();
                         }
@@ -628,7 +644,7 @@ impl<'a> Parser<'a> {
         self.bump_any(); // advance `[`
         let has_in = self.ctx.has_in();
         self.ctx = self.ctx.and_in(true);
-        let property = self.parse_expression()?;
+        let property = self.parse_expr()?;
         self.ctx = self.ctx.and_in(has_in);
         self.expect(Kind::RBrack)?;
         Ok(self.ast.computed_member_expression(self.end_span(lhs_span), lhs, property, optional))
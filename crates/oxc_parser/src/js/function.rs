@@ -92,7 +92,11 @@ impl<'a> Parser<'a> {
 
         let return_type = self.parse_ts_return_type_annotation()?;
 
-        let body = if self.at(Kind::LCurly) { Some(self.parse_function_body()?) } else { None };
+        let body = if self.at(Kind::LCurly) {
+            Some(self.parse_function_body_or_skip(span.start)?)
+        } else {
+            None
+        };
 
         self.ctx =
             self.ctx.and_in(ctx.has_in()).and_await(ctx.has_await()).and_yield(ctx.has_yield());
@@ -242,7 +246,7 @@ impl<'a> Parser<'a> {
             let expr_stmt = self.ast.expression_statement(span, expr);
             self.ast.function_body(span, self.ast.new_vec(), self.ast.new_vec_single(expr_stmt))
         } else {
-            self.parse_function_body()?
+            self.parse_function_body_or_skip(span.start)?
         };
         self.ctx = self.ctx.and_await(has_await).and_yield(has_yield);
 
@@ -501,7 +505,7 @@ impl<'a> Parser<'a> {
             let expr_stmt = self.ast.expression_statement(span, expr);
             self.ast.function_body(span, self.ast.new_vec(), self.ast.new_vec_single(expr_stmt))
         } else {
-            self.parse_function_body()?
+            self.parse_function_body_or_skip(span.start)?
         };
 
         self.ctx = self.ctx.and_await(has_await).and_yield(has_yield);
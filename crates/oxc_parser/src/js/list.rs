@@ -12,7 +12,7 @@ use crate::{
     diagnostics,
     lexer::Kind,
     list::{NormalList, SeparatedList},
-    Parser,
+    DecoratorsVersion, Parser,
 };
 
 #[derive(Debug, Error, Diagnostic)]
@@ -252,7 +252,14 @@ impl<'a> SeparatedList<'a> for FormalParameterList<'a> {
     // Section 15.1 Parameter Lists
     fn parse_element(&mut self, p: &mut Parser<'a>) -> Result<()> {
         let span = p.start_span();
-        p.eat_decorators()?;
+        if p.at(Kind::At) {
+            p.eat_decorators()?;
+            if p.decorators == Some(DecoratorsVersion::V2023_05) {
+                if let Some(decorators_span) = p.state.decorators_span() {
+                    p.error(diagnostics::ParameterDecorators(decorators_span));
+                }
+            }
+        }
 
         let modifiers = p.parse_class_element_modifiers(true);
         let accessibility = modifiers.accessibility();
@@ -287,13 +294,13 @@ impl<'a> SeparatedList<'a> for FormalParameterList<'a> {
     }
 }
 
-/// [Assert Entries](https://tc39.es/proposal-import-assertions)
-pub struct AssertEntries<'a> {
+/// [With Entries](https://tc39.es/proposal-import-attributes)
+pub struct WithEntries<'a> {
     pub elements: Vec<'a, ImportAttribute>,
     keys: FxHashMap<Atom, Span>,
 }
 
-impl<'a> SeparatedList<'a> for AssertEntries<'a> {
+impl<'a> SeparatedList<'a> for WithEntries<'a> {
     fn new(p: &Parser<'a>) -> Self {
         Self { elements: p.ast.new_vec(), keys: FxHashMap::default() }
     }
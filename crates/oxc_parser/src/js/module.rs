@@ -5,7 +5,7 @@ use oxc_span::Span;
 
 use super::{
     function::FunctionKind,
-    list::{AssertEntries, ExportNamedSpecifiers, ImportSpecifierList},
+    list::{ExportNamedSpecifiers, ImportSpecifierList, WithEntries},
 };
 use crate::{diagnostics, lexer::Kind, list::SeparatedList, Context, Parser};
 
@@ -58,14 +58,14 @@ impl<'a> Parser<'a> {
         };
 
         let source = self.parse_literal_string()?;
-        let assertions = self.parse_import_attributes()?;
+        let with_clause = self.parse_import_attributes()?;
         self.asi()?;
         let span = self.end_span(span);
         let decl = ModuleDeclaration::ImportDeclaration(self.ast.import_declaration(
             span,
             specifiers,
             source,
-            assertions,
+            with_clause,
             import_kind,
         ));
         Ok(self.ast.module_declaration(decl))
@@ -135,19 +135,27 @@ impl<'a> Parser<'a> {
         Ok(specifiers)
     }
 
-    /// [Import assertion](https://tc39.es/proposal-import-assertions)
-    fn parse_import_attributes(&mut self) -> Result<Option<Vec<'a, ImportAttribute>>> {
-        if !self.at(Kind::Assert) || self.cur_token().is_on_new_line {
-            return Ok(None);
+    /// [Import Attributes](https://tc39.es/proposal-import-attributes)
+    fn parse_import_attributes(&mut self) -> Result<Option<WithClause<'a>>> {
+        match self.cur_kind() {
+            Kind::With => {}
+            // The legacy [Import Assertions](https://tc39.es/proposal-import-assertions)
+            Kind::Assert if !self.cur_token().is_on_new_line => {
+                if !self.import_assertions {
+                    self.error(diagnostics::ImportAssertions(self.cur_token().span()));
+                }
+            }
+            _ => return Ok(None),
         }
-        self.bump_any();
+        let span = self.start_span();
+        let attributes_keyword = self.parse_identifier_name()?;
 
         let ctx = self.ctx;
         self.ctx = Context::default();
-        let entries = AssertEntries::parse(self)?.elements;
+        let with_entries = WithEntries::parse(self)?.elements;
         self.ctx = ctx;
 
-        Ok(Some(entries))
+        Ok(Some(WithClause { span: self.end_span(span), attributes_keyword, with_entries }))
     }
 
     pub(crate) fn parse_ts_export_assignment_declaration(
@@ -231,11 +239,11 @@ impl<'a> Parser<'a> {
         let specifiers = ExportNamedSpecifiers::parse(self)?.elements;
         self.ctx = ctx;
 
-        let source = if self.eat(Kind::From) && self.cur_kind().is_literal() {
+        let (source, with_clause) = if self.eat(Kind::From) && self.cur_kind().is_literal() {
             let source = self.parse_literal_string()?;
-            Some(source)
+            (Some(source), self.parse_import_attributes()?)
         } else {
-            None
+            (None, None)
         };
 
         // ExportDeclaration : export NamedExports ;
@@ -243,7 +251,7 @@ impl<'a> Parser<'a> {
         if source.is_none() {
             for specifier in &specifiers {
                 if let ModuleExportName::StringLiteral(literal) = &specifier.local {
-                    self.error(diagnostics::ExportNamedString(
+                    self.early_error(diagnostics::ExportNamedString(
                         literal.value.clone(),
                         specifier.local.name().clone(),
                         literal.span,
@@ -254,7 +262,14 @@ impl<'a> Parser<'a> {
 
         self.asi()?;
         let span = self.end_span(span);
-        Ok(self.ast.export_named_declaration(span, None, specifiers, source, export_kind))
+        Ok(self.ast.export_named_declaration(
+            span,
+            None,
+            specifiers,
+            source,
+            with_clause,
+            export_kind,
+        ))
     }
 
     // export Declaration
@@ -265,7 +280,10 @@ impl<'a> Parser<'a> {
         let decl_span = self.start_span();
         // For tc39/proposal-decorators
         // For more information, please refer to <https://babeljs.io/docs/babel-plugin-proposal-decorators#decoratorsbeforeexport>
-        self.eat_decorators()?;
+        if self.at(Kind::At) {
+            self.eat_decorators()?;
+            self.check_legacy_decorators();
+        }
         let modifiers = if self.ts_enabled() {
             self.eat_modifiers_before_declaration().1
         } else {
@@ -279,6 +297,7 @@ impl<'a> Parser<'a> {
             Some(declaration),
             self.ast.new_vec(),
             None,
+            None,
             ImportOrExportKind::Value,
         ))
     }
@@ -294,7 +313,10 @@ impl<'a> Parser<'a> {
         let decl_span = self.start_span();
         // For tc39/proposal-decorators
         // For more information, please refer to <https://babeljs.io/docs/babel-plugin-proposal-decorators#decoratorsbeforeexport>
-        self.eat_decorators()?;
+        if self.at(Kind::At) {
+            self.eat_decorators()?;
+            self.check_legacy_decorators();
+        }
         let declaration = match self.cur_kind() {
             Kind::Class => self
                 .parse_class_declaration(decl_span, /* modifiers */ Modifiers::empty())
@@ -348,10 +370,10 @@ impl<'a> Parser<'a> {
         let exported = self.eat(Kind::As).then(|| self.parse_module_export_name()).transpose()?;
         self.expect(Kind::From)?;
         let source = self.parse_literal_string()?;
-        let assertions = self.parse_import_attributes()?;
+        let with_clause = self.parse_import_attributes()?;
         self.asi()?;
         let span = self.end_span(span);
-        Ok(self.ast.export_all_declaration(span, exported, source, assertions, export_kind))
+        Ok(self.ast.export_all_declaration(span, exported, source, with_clause, export_kind))
     }
 
     // ImportSpecifier :
@@ -388,7 +410,7 @@ impl<'a> Parser<'a> {
             let imported = IdentifierName { span: local.span, name: local.name.clone() };
             (ModuleExportName::Identifier(imported), local)
         };
-        Ok(ImportSpecifier { span: self.end_span(specifier_span), imported, local })
+        Ok(ImportSpecifier { span: self.end_span(specifier_span), imported, local, import_kind })
     }
 
     // ModuleExportName :
@@ -401,7 +423,7 @@ impl<'a> Parser<'a> {
                 // ModuleExportName : StringLiteral
                 // It is a Syntax Error if IsStringWellFormedUnicode(the SV of StringLiteral) is false.
                 if !literal.is_string_well_formed_unicode() {
-                    self.error(diagnostics::ExportLoneSurrogate(literal.span));
+                    self.early_error(diagnostics::ExportLoneSurrogate(literal.span));
                 };
                 Ok(ModuleExportName::StringLiteral(literal))
             }
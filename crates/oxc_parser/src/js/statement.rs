@@ -42,19 +42,26 @@ impl<'a> Parser<'a> {
             match self.cur_kind() {
                 Kind::RCurly if !is_top_level => break,
                 Kind::Import if !matches!(self.peek_kind(), Kind::Dot | Kind::LParen) => {
-                    let stmt = self.parse_import_declaration()?;
-                    statements.push(stmt);
+                    if let Some(stmt) = self.recover(Self::parse_import_declaration)? {
+                        statements.push(stmt);
+                    }
                 }
                 Kind::Export => {
-                    let stmt = self.parse_export_declaration()?;
-                    statements.push(stmt);
+                    if let Some(stmt) = self.recover(Self::parse_export_declaration)? {
+                        statements.push(stmt);
+                    }
                 }
                 Kind::At => {
                     self.eat_decorators()?;
                     continue;
                 }
                 _ => {
-                    let stmt = self.parse_statement_list_item(StatementContext::StatementList)?;
+                    let Some(stmt) = self.recover(|p| {
+                        p.parse_statement_list_item(StatementContext::StatementList)
+                    })?
+                    else {
+                        continue;
+                    };
 
                     // Section 11.2.1 Directive Prologue
                     // The only way to get a correct directive is to parse the statement first and check if it is a string literal.
@@ -84,6 +91,52 @@ impl<'a> Parser<'a> {
         Ok((directives, statements))
     }
 
+    /// Parse a statement, or with [Parser::recover_from_errors] skip it when it has a syntax
+    /// error and return `None`.
+    pub(crate) fn recover<T>(
+        &mut self,
+        parse: impl FnOnce(&mut Self) -> Result<T>,
+    ) -> Result<Option<T>> {
+        let start = self.cur_token().start;
+        let ctx = self.ctx;
+        match parse(self) {
+            Ok(stmt) => Ok(Some(stmt)),
+            Err(error) if self.recover => {
+                self.error(error);
+                self.ctx = ctx;
+                self.state.consume_decorators();
+                self.skip_statement(start);
+                Ok(None)
+            }
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Skip the tokens of the statement starting at `start` up to the start of the next statement:
+    /// after a `;`, or at a token on a new line, which are not nested in braces, or at the `}`
+    /// closing the enclosing block.
+    fn skip_statement(&mut self, start: u32) {
+        let mut depth = 0u32;
+        while !self.at(Kind::Eof) {
+            let token = self.cur_token();
+            // The statement is parsed up to here, the next one is on the current token.
+            let parsed = token.start > start;
+            if depth == 0 && parsed && (token.is_on_new_line || token.kind == Kind::RCurly) {
+                return;
+            }
+            match token.kind {
+                Kind::LCurly => depth += 1,
+                Kind::RCurly => depth = depth.saturating_sub(1),
+                Kind::Semicolon if depth == 0 => {
+                    self.bump_any();
+                    return;
+                }
+                _ => {}
+            }
+            self.bump_any();
+        }
+    }
+
     /// `StatementListItem`[Yield, Await, Return] :
     ///     Statement[?Yield, ?Await, ?Return]
     ///     Declaration[?Yield, ?Await]
@@ -125,9 +178,9 @@ impl<'a> Parser<'a> {
             Kind::Await
                 if self.peek_kind() == Kind::Using && self.nth_kind(2).is_binding_identifier() =>
             {
-                self.parse_using()
+                self.parse_using(stmt_ctx)
             }
-            Kind::Using if self.peek_kind().is_binding_identifier() => self.parse_using(),
+            Kind::Using if self.peek_kind().is_binding_identifier() => self.parse_using(stmt_ctx),
             _ if self.at_function_with_async() => self.parse_function_declaration(stmt_ctx),
             _ if self.ts_enabled() && self.at_start_of_ts_declaration() => {
                 self.parse_ts_declaration_statement(start_span)
@@ -138,7 +191,7 @@ impl<'a> Parser<'a> {
 
     fn parse_expression_or_labeled_statement(&mut self) -> Result<Statement<'a>> {
         let span = self.start_span();
-        let expr = self.parse_expression()?;
+        let expr = self.parse_expr()?;
         if let Expression::Identifier(ident) = &expr {
             // Section 14.13 Labelled Statement
             // Avoids lookahead for a labeled statement, which is on a hot path
@@ -157,8 +210,9 @@ impl<'a> Parser<'a> {
         self.expect(Kind::LCurly)?;
         let mut body = self.ast.new_vec();
         while !self.at(Kind::RCurly) && !self.at(Kind::Eof) {
-            let stmt = self.parse_statement_list_item(StatementContext::StatementList)?;
-            body.push(stmt);
+            let stmt =
+                self.recover(|p| p.parse_statement_list_item(StatementContext::StatementList))?;
+            body.extend(stmt);
         }
         self.expect(Kind::RCurly)?;
         Ok(self.ast.block(self.end_span(span), body))
@@ -277,7 +331,7 @@ impl<'a> Parser<'a> {
             return self.parse_for_loop(span, None, r#await);
         }
 
-        let init_expression = self.without_context(Context::In, Parser::parse_expression)?;
+        let init_expression = self.without_context(Context::In, Parser::parse_expr)?;
 
         // for (a.b in ...), for ([a] in ..), for ({a} in ..)
         if self.at(Kind::In) || self.at(Kind::Of) {
@@ -353,7 +407,7 @@ impl<'a> Parser<'a> {
     ) -> Result<Statement<'a>> {
         self.expect(Kind::Semicolon)?;
         let test = if !self.at(Kind::Semicolon) && !self.at(Kind::RParen) {
-            Some(self.with_context(Context::In, Parser::parse_expression)?)
+            Some(self.with_context(Context::In, Parser::parse_expr)?)
         } else {
             None
         };
@@ -361,7 +415,7 @@ impl<'a> Parser<'a> {
         let update = if self.at(Kind::RParen) {
             None
         } else {
-            Some(self.with_context(Context::In, Parser::parse_expression)?)
+            Some(self.with_context(Context::In, Parser::parse_expr)?)
         };
         self.expect(Kind::RParen)?;
         if r#await {
@@ -379,11 +433,8 @@ impl<'a> Parser<'a> {
     ) -> Result<Statement<'a>> {
         let is_for_in = self.at(Kind::In);
         self.bump_any(); // bump `in` or `of`
-        let right = if is_for_in {
-            self.parse_expression()
-        } else {
-            self.parse_assignment_expression_base()
-        }?;
+        let right =
+            if is_for_in { self.parse_expr() } else { self.parse_assignment_expression_base() }?;
         self.expect(Kind::RParen)?;
 
         if r#await && is_for_in {
@@ -427,7 +478,7 @@ impl<'a> Parser<'a> {
         let argument = if self.eat(Kind::Semicolon) || self.can_insert_semicolon() {
             None
         } else {
-            let expr = self.with_context(Context::In, Parser::parse_expression)?;
+            let expr = self.with_context(Context::In, Parser::parse_expr)?;
             self.asi()?;
             Some(expr)
         };
@@ -472,7 +523,7 @@ impl<'a> Parser<'a> {
             }
             Kind::Case => {
                 self.bump_any();
-                let expression = self.parse_expression()?;
+                let expression = self.parse_expr()?;
                 Some(expression)
             }
             _ => return Err(self.unexpected()),
@@ -497,7 +548,7 @@ impl<'a> Parser<'a> {
                 self.cur_token().span(),
             ));
         }
-        let argument = self.parse_expression()?;
+        let argument = self.parse_expr()?;
         self.asi()?;
         Ok(self.ast.throw_statement(self.end_span(span), argument))
     }
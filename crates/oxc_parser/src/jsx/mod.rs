@@ -251,7 +251,7 @@ impl<'a> Parser<'a> {
     fn parse_jsx_assignment_expression(&mut self) -> Result<Expression<'a>> {
         let ctx = self.ctx;
         self.ctx = Context::default();
-        let expr = self.parse_expression();
+        let expr = self.parse_expr();
         if let Ok(Expression::SequenceExpression(seq)) = &expr {
             return Err(diagnostics::JSXExpressionsMayNotUseTheCommaOperator(seq.span).into());
         }
@@ -0,0 +1,217 @@
+//! Lazy parsing of the function bodies
+
+use oxc_allocator::Box;
+use oxc_ast::{ast::FunctionBody, VisitMut};
+use oxc_diagnostics::{Error, Result};
+use oxc_span::Span;
+
+use crate::{lexer::Kind, Parser};
+
+/// A function body skipped by [Parser::lazy_function_bodies]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LazyFunctionBody {
+    /// The span of the body, from `{` to `}`
+    pub span: Span,
+    /// `await` is a keyword in the body
+    pub r#async: bool,
+    /// `yield` is a keyword in the body
+    pub generator: bool,
+}
+
+impl<'a> Parser<'a> {
+    /// Parse a function body skipped by [Parser::lazy_function_bodies]
+    ///
+    /// The parser is created with the same source text.
+    ///
+    /// # Errors
+    ///
+    /// The syntax errors of the body.
+    pub fn parse_lazy_function_body(
+        mut self,
+        body: &LazyFunctionBody,
+    ) -> std::result::Result<Box<'a, FunctionBody<'a>>, Vec<Error>> {
+        self.lexer.seek(body.span.start);
+        self.bump_any();
+        self.ctx = self.ctx.and_await(body.r#async).and_yield(body.generator);
+        let mut result = self.parse_function_body();
+        if let (Ok(body), Some(mut strip_parens)) = (&mut result, self.strip_parens()) {
+            strip_parens.visit_function_body(body);
+        }
+        self.finish_fragment(result)
+    }
+
+    /// Parse the body of the function starting at `start`, or skip it with
+    /// [Parser::lazy_function_bodies] when the function is not immediately invoked.
+    /// As in V8, the functions preceded by `(` or `!` are expected to be immediately invoked,
+    /// e.g. `(function () {})()`.
+    pub(crate) fn parse_function_body_or_skip(
+        &mut self,
+        start: u32,
+    ) -> Result<Box<'a, FunctionBody<'a>>> {
+        // The JSX text can not be skipped by tokens.
+        if !self.lazy || self.source_type.is_jsx() {
+            return self.parse_function_body();
+        }
+        if self.source_text[..start as usize].trim_end().ends_with(['(', '!']) {
+            return self.parse_function_body();
+        }
+        let checkpoint = self.checkpoint();
+        let trivias = self.lexer.trivia_builder.checkpoint();
+        let span = self.start_span();
+        if !self.skip_function_body()? {
+            self.rewind(checkpoint);
+            self.lexer.trivia_builder.rewind(trivias);
+            return self.parse_function_body();
+        }
+        let span = self.end_span(span);
+        self.skipped_function_bodies.push(LazyFunctionBody {
+            span,
+            r#async: self.ctx.has_await(),
+            generator: self.ctx.has_yield(),
+        });
+        Ok(self.ast.function_body(span, self.ast.new_vec(), self.ast.new_vec()))
+    }
+
+    /// Skip the tokens of a function body up to its `}`, a `/` is read as a regex where
+    /// an expression starts.
+    /// Returns `false` at a `/` after `)` or `}`, which is a division after an expression and a
+    /// regex after a statement, e.g. `if (a) /}/.test(b)` or `{} /}/`, the body must be parsed.
+    fn skip_function_body(&mut self) -> Result<bool> {
+        // The open braces, `true` for the substitutions of a template.
+        let mut braces = vec![];
+        let mut prev_kind = Kind::Undetermined;
+        loop {
+            match self.cur_kind() {
+                Kind::Eof => return self.expect(Kind::RCurly).map(|()| true),
+                Kind::LCurly => braces.push(false),
+                Kind::TemplateHead => braces.push(true),
+                Kind::RCurly => match braces.pop() {
+                    Some(true) => {
+                        self.re_lex_template_substitution_tail();
+                        if self.at(Kind::TemplateMiddle) {
+                            braces.push(true);
+                        }
+                    }
+                    _ if braces.is_empty() => {
+                        self.bump_any();
+                        return Ok(true);
+                    }
+                    _ => {}
+                },
+                Kind::Slash | Kind::SlashEq if matches!(prev_kind, Kind::RParen | Kind::RCurly) => {
+                    return Ok(false);
+                }
+                Kind::Slash | Kind::SlashEq if !ends_expression(prev_kind) => self.read_regex(),
+                _ => {}
+            }
+            prev_kind = self.cur_kind();
+            self.bump_any();
+        }
+    }
+}
+
+/// A `/` after these tokens is a division, e.g. `a / b`, otherwise a regex, e.g. `return /a/`,
+/// except after `)` and `}`, see [Parser::skip_function_body].
+fn ends_expression(kind: Kind) -> bool {
+    kind.is_literal()
+        || kind.is_identifier()
+        || kind.is_update_operator()
+        || matches!(
+            kind,
+            Kind::This
+                | Kind::Super
+                | Kind::RBrack
+                | Kind::NoSubstitutionTemplate
+                | Kind::TemplateTail
+                | Kind::PrivateIdentifier
+        )
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_ast::{ast::FunctionBody, Visit};
+    use oxc_span::{SourceType, Span};
+    use rustc_hash::FxHashMap;
+
+    use crate::Parser;
+
+    /// The JSON of the function bodies by span
+    #[derive(Default)]
+    struct FunctionBodies(FxHashMap<Span, String>);
+
+    impl<'a> Visit<'a> for FunctionBodies {
+        fn visit_function_body(&mut self, body: &FunctionBody<'a>) {
+            self.0.insert(body.span, serde_json::to_string(body).unwrap());
+            for stmt in &body.statements {
+                self.visit_statement(stmt);
+            }
+        }
+    }
+
+    #[test]
+    fn lazy_function_bodies() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default().with_module(true);
+        let source = "
+            function regex() { return /}/.exec(a).length / b / c; }
+            function template() { return `${ { a: 1 }.a }${`}`}`; }
+            async function asynchronous() { await a; }
+            function* generator() { yield a; }
+            const arrow = () => { if (a) { b(); } };
+            class A { method() { c(); } }
+            (function () { d(); })();
+            !function () { e(); }();
+        ";
+        let ret = Parser::new(&allocator, source, source_type).lazy_function_bodies(true).parse();
+        assert!(ret.errors.is_empty());
+        assert_eq!(ret.lazy_function_bodies.len(), 6);
+
+        let mut eager = FunctionBodies::default();
+        eager.visit_program(&Parser::new(&allocator, source, source_type).parse().program);
+        let mut lazy = FunctionBodies::default();
+        lazy.visit_program(&ret.program);
+        // The bodies of the immediately invoked functions are parsed.
+        assert_eq!(eager.0.values().filter(|body| lazy.0.values().any(|b| b == *body)).count(), 2);
+
+        for body in &ret.lazy_function_bodies {
+            let parsed = Parser::new(&allocator, source, source_type)
+                .parse_lazy_function_body(body)
+                .unwrap();
+            assert_eq!(serde_json::to_string(&parsed).unwrap(), eager.0[&body.span]);
+        }
+    }
+
+    #[test]
+    fn regex_after_paren_or_brace() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let source = "
+            function condition() { if (a) /}/.test(b); }
+            function block() { {} /}/; }
+            function division() { return (a) / b; }
+        ";
+        let ret = Parser::new(&allocator, source, source_type).lazy_function_bodies(true).parse();
+        assert!(ret.errors.is_empty());
+        // The bodies are parsed.
+        assert!(ret.lazy_function_bodies.is_empty());
+        let eager = Parser::new(&allocator, source, source_type).parse();
+        assert_eq!(
+            serde_json::to_string(&ret.program).unwrap(),
+            serde_json::to_string(&eager.program).unwrap()
+        );
+    }
+
+    #[test]
+    fn lazy_function_body_errors() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let source = "function foo() { let a = ; }";
+        let ret = Parser::new(&allocator, source, source_type).lazy_function_bodies(true).parse();
+        assert!(ret.errors.is_empty());
+        let errors = Parser::new(&allocator, source, source_type)
+            .parse_lazy_function_body(&ret.lazy_function_bodies[0])
+            .unwrap_err();
+        assert_eq!(errors.len(), 1);
+    }
+}
@@ -24,7 +24,7 @@ use oxc_syntax::{
     },
     unicode_id_start::is_id_start_unicode,
 };
-pub use token::{RegExp, Token, TokenValue};
+pub use token::{RegExp, Token, TokenValue, Trivia};
 
 pub use self::kind::Kind;
 use self::{
@@ -67,6 +67,9 @@ pub struct Lexer<'a> {
     context: LexerContext,
 
     pub(crate) trivia_builder: TriviaBuilder,
+
+    /// The trivia skipped before the next token, only collected for [crate::Tokens]
+    pub(crate) trivia: Option<Vec<Trivia>>,
 }
 
 #[allow(clippy::unused_self)]
@@ -87,6 +90,7 @@ impl<'a> Lexer<'a> {
             lookahead: VecDeque::with_capacity(4), // 4 is the maximum lookahead for TypeScript
             context: LexerContext::Regular,
             trivia_builder: TriviaBuilder::default(),
+            trivia: None,
         }
     }
 
@@ -95,6 +99,20 @@ impl<'a> Lexer<'a> {
         self.current.chars.as_str()
     }
 
+    /// Continue the lexing at `offset` of the source, which is at the start of a token.
+    pub fn seek(&mut self, offset: u32) {
+        self.current.chars = self.source[offset as usize..].chars();
+        self.current.token = Token { is_on_new_line: offset == 0, ..Token::default() };
+        self.lookahead.clear();
+    }
+
+    /// Start the lexing at `offset` of the source as at the start of a file, for a source
+    /// embedded in the source of a host file.
+    pub fn start_at(&mut self, offset: u32) {
+        self.seek(offset);
+        self.current.token.is_on_new_line = true;
+    }
+
     /// Creates a checkpoint storing the current lexer state.
     /// Use `rewind` to restore the lexer to the state stored in the checkpoint.
     pub fn checkpoint(&self) -> LexerCheckpoint<'a> {
@@ -164,6 +182,7 @@ impl<'a> Lexer<'a> {
     }
 
     pub fn next_jsx_child(&mut self) -> Token<'a> {
+        self.current.token.start = self.offset();
         let kind = self.read_jsx_child();
         self.finish_next(kind)
     }
@@ -194,6 +213,7 @@ impl<'a> Lexer<'a> {
     }
 
     pub fn next_right_angle(&mut self) -> Token<'a> {
+        self.current.token.start = self.offset() - 1;
         let kind = self.read_right_angle();
         self.lookahead.clear();
         self.finish_next(kind)
@@ -210,6 +230,7 @@ impl<'a> Lexer<'a> {
 
     /// Expand the current token for `JSXIdentifier`
     pub fn next_jsx_identifier(&mut self, start_offset: u32) -> Token<'a> {
+        self.current.token.start = start_offset;
         let kind = self.read_jsx_identifier(start_offset);
         self.lookahead.clear();
         self.finish_next(kind)
@@ -339,6 +360,20 @@ impl<'a> Lexer<'a> {
                 ) {
                     return kind;
                 }
+                let end = self.offset();
+                if let Some(trivia) = &mut self.trivia {
+                    // Merge the consecutive whitespaces and line terminators
+                    match trivia.last_mut() {
+                        Some(last)
+                            if last.kind == kind
+                                && last.span.end == offset
+                                && matches!(kind, Kind::WhiteSpace | Kind::NewLine) =>
+                        {
+                            last.span.end = end;
+                        }
+                        _ => trivia.push(Trivia { kind, span: Span::new(offset, end) }),
+                    }
+                }
             } else {
                 return Kind::Eof;
             }
@@ -38,6 +38,15 @@ impl<'a> Token<'a> {
     }
 }
 
+/// A whitespace, line terminator or comment skipped by the lexer before a token
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Trivia {
+    /// `WhiteSpace`, `NewLine`, `Comment` or `MultiLineComment`
+    pub kind: Kind,
+
+    pub span: Span,
+}
+
 #[derive(Debug, Clone)]
 pub enum TokenValue<'a> {
     None,
@@ -10,6 +10,15 @@ impl TriviaBuilder {
         self.trivias
     }
 
+    /// The number of trivias, to drop the ones lexed after it with [TriviaBuilder::rewind]
+    pub fn checkpoint(&self) -> usize {
+        self.trivias.len()
+    }
+
+    pub fn rewind(&mut self, checkpoint: usize) {
+        self.trivias.truncate(checkpoint);
+    }
+
     /// skip leading `//`
     pub fn add_single_line_comment(&mut self, start: u32, end: u32) {
         self.trivias.push((start + 2, end, CommentKind::SingleLine));
@@ -40,6 +40,16 @@
 #![doc = include_str!("../examples/parser.rs")]
 //! ```
 //!
+//! # Tokens
+//!
+//! The tokens and the comments can be read without parsing with [Tokens].
+//!
+//! ```rust
+//! for TokenWithTrivia { token, leading_trivia } in Tokens::new(&allocator, &source_text, source_type) {
+//!     // highlight token.kind at token.span()
+//! }
+//! ```
+//!
 //! # Visitor
 //!
 //! See [oxc_ast::Visit] and [oxc_ast::VisitMut]
@@ -71,17 +81,30 @@ mod jsx;
 mod ts;
 
 mod diagnostics;
+mod lazy;
 mod lexer;
+mod parens;
+mod reparse;
+mod tokens;
 
 use context::{Context, StatementContext};
 use oxc_allocator::Allocator;
-use oxc_ast::{ast::Program, AstBuilder, Trivias};
+use oxc_ast::{
+    ast::{Expression, Program, Statement},
+    AstBuilder, Trivias, VisitMut,
+};
 use oxc_diagnostics::{Error, Result};
 use oxc_span::{ModuleKind, SourceType, Span};
 
-use crate::{
-    lexer::{Kind, Lexer, Token},
-    state::ParserState,
+use crate::{lexer::Lexer, state::ParserState};
+
+pub use oxc_diagnostics::EarlyErrors;
+
+pub use crate::{
+    lazy::LazyFunctionBody,
+    lexer::{Kind, RegExp, Token, TokenValue, Trivia},
+    reparse::TextEdit,
+    tokens::{TokenWithTrivia, Tokens},
 };
 
 /// Return value of parser consisting of AST, errors and comments
@@ -94,6 +117,22 @@ pub struct ParserReturn<'a> {
     pub errors: Vec<Error>,
     pub trivias: Trivias,
     pub panicked: bool,
+    /// The function bodies skipped with [Parser::lazy_function_bodies]
+    pub lazy_function_bodies: Vec<LazyFunctionBody>,
+    /// All the tokens with their leading trivia with [Parser::preserve_tokens], the last one is
+    /// `Eof` with the trailing trivia
+    pub tokens: Vec<TokenWithTrivia<'a>>,
+}
+
+/// The decorators proposal which the syntax of the decorators is restricted to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecoratorsVersion {
+    /// TypeScript `experimentalDecorators`, with the decorators on the parameters,
+    /// and without `accessor`, the decorators on the class expressions and after `export`
+    Legacy,
+    /// The TC39 proposal as of the May 2023 meeting, with `accessor`, the decorators on the
+    /// class expressions and after `export`, and without the decorators on the parameters
+    V2023_05,
 }
 
 /// Recursive Descent Parser for ECMAScript and TypeScript
@@ -126,6 +165,37 @@ pub struct Parser<'a> {
 
     /// Ast builder for creating AST spans
     ast: AstBuilder<'a>,
+
+    /// Skip the statements with syntax errors instead of returning an empty program
+    recover: bool,
+
+    /// Skip the function bodies which are not immediately invoked
+    lazy: bool,
+
+    /// The function bodies skipped
+    skipped_function_bodies: Vec<LazyFunctionBody>,
+
+    /// Keep the `ParenthesizedExpression`s
+    preserve_parens: bool,
+
+    /// Accept the legacy `assert` import attributes
+    import_assertions: bool,
+
+    /// Restrict the decorators to the syntax of this proposal
+    decorators: Option<DecoratorsVersion>,
+
+    /// Accept the V8 runtime calls
+    v8_intrinsics: bool,
+
+    /// The tokens consumed, with [Parser::preserve_tokens]
+    tokens: Option<Vec<Token<'a>>>,
+
+    /// How the early errors are reported
+    early_errors: EarlyErrors,
+
+    /// The start of the source in the source text of the host file, with
+    /// [Parser::embedded_source]
+    source_start: u32,
 }
 
 impl<'a> Parser<'a> {
@@ -141,6 +211,16 @@ impl<'a> Parser<'a> {
             state: ParserState::new(allocator),
             ctx: Self::default_context(source_type),
             ast: AstBuilder::new(allocator),
+            recover: false,
+            lazy: false,
+            skipped_function_bodies: vec![],
+            preserve_parens: true,
+            import_assertions: false,
+            decorators: None,
+            v8_intrinsics: false,
+            tokens: None,
+            early_errors: EarlyErrors::default(),
+            source_start: 0,
         }
     }
 
@@ -154,12 +234,206 @@ impl<'a> Parser<'a> {
         self
     }
 
+    /// Recover from syntax errors
+    ///
+    /// By default, the first syntax error which cannot be recovered from stops the parsing and
+    /// an empty program is returned.
+    /// Set this to true to skip the statement with the error up to the start of the next one
+    /// and continue, the program without the skipped statements is returned along with the
+    /// errors of all of them. This is for the editors, where the files are parsed mid-edit.
+    #[must_use]
+    pub fn recover_from_errors(mut self, recover: bool) -> Self {
+        self.recover = recover;
+        self
+    }
+
+    /// Parse the function bodies lazily
+    ///
+    /// By default, all the function bodies are parsed.
+    /// Set this to true to skip the bodies of the functions which are not immediately invoked,
+    /// as V8 does, for the tasks which only need the top level statements of the files, e.g.
+    /// the scanning of the module graph by a bundler. The skipped bodies are empty in the
+    /// program, and returned in [ParserReturn::lazy_function_bodies] to be parsed later with
+    /// [Parser::parse_lazy_function_body].
+    #[must_use]
+    pub fn lazy_function_bodies(mut self, lazy: bool) -> Self {
+        self.lazy = lazy;
+        self
+    }
+
+    /// Allow the import assertions
+    ///
+    /// By default, the legacy `assert` form of the import attributes, e.g.
+    /// `import data from "./data.json" assert { type: "json" }`, raises an error, as it was
+    /// replaced by `with`.
+    /// Set this to true to accept such code.
+    #[must_use]
+    pub fn allow_import_assertions(mut self, allow: bool) -> Self {
+        self.import_assertions = allow;
+        self
+    }
+
+    /// Restrict the decorators to the syntax of a proposal
+    ///
+    /// By default, the syntax of both [DecoratorsVersion]s is accepted, e.g. for the linter
+    /// which does not know the configuration of the project.
+    /// Set this to the version lowered by the transformer to reject the syntax of the other one,
+    /// e.g. `accessor` is then an identifier with [DecoratorsVersion::Legacy], and the
+    /// decorators on the parameters raise an error with [DecoratorsVersion::V2023_05].
+    #[must_use]
+    pub fn decorators(mut self, version: DecoratorsVersion) -> Self {
+        self.decorators = Some(version);
+        self
+    }
+
+    /// Allow the V8 intrinsics
+    ///
+    /// By default, the V8 runtime calls of the V8 test suites and the fuzzing corpora, e.g.
+    /// `%DebugPrint(x)`, raise an error.
+    /// Set this to true to parse them as `V8IntrinsicExpression`s, like babel's `v8intrinsic`
+    /// plugin. The name must follow `%` without whitespace.
+    #[must_use]
+    pub fn allow_v8_intrinsics(mut self, allow: bool) -> Self {
+        self.v8_intrinsics = allow;
+        self
+    }
+
+    /// Preserve the parentheses
+    ///
+    /// By default, the parenthesized expressions are `ParenthesizedExpression`s in the program,
+    /// as with acorn's `preserveParens`, for the formatters and the codemods which print the
+    /// parentheses of the author.
+    /// Set this to false to replace them by their expressions, the spans of the parent nodes
+    /// still include the parentheses.
+    #[must_use]
+    pub fn preserve_parens(mut self, preserve: bool) -> Self {
+        self.preserve_parens = preserve;
+        self
+    }
+
+    /// Preserve the tokens and the trivia
+    ///
+    /// By default, only the comments are returned along with the program.
+    /// Set this to true to also return all the tokens in [ParserReturn::tokens], as tokenized
+    /// by the parser, with the whitespaces and the comments between them, for the formatters
+    /// which need to print the source text losslessly. The text of every token and trivia can
+    /// be recovered from its span, and together they cover the whole source text when the
+    /// program is parsed without panicking.
+    #[must_use]
+    pub fn preserve_tokens(mut self, preserve: bool) -> Self {
+        self.tokens = preserve.then(Vec::new);
+        self
+    }
+
+    /// Report the early errors as errors, as warnings, or not at all
+    ///
+    /// By default, the [early errors](https://tc39.es/ecma262/#early-error) found by the
+    /// parser, e.g. the duplicate constructors of a class or a `const` without an initializer,
+    /// are errors.
+    /// They do not change the program, set this to [EarlyErrors::Warning] or
+    /// [EarlyErrors::Ignore] to tolerate them, and use
+    /// [recover_from_errors](Parser::recover_from_errors) as well to collect all the errors of
+    /// the source text along with a best-effort program.
+    /// See `SemanticBuilder::with_early_errors` for the early errors of the semantic analysis.
+    #[must_use]
+    pub fn early_errors(mut self, early_errors: EarlyErrors) -> Self {
+        self.early_errors = early_errors;
+        self
+    }
+
+    /// Parse a source embedded in a host file
+    ///
+    /// By default, the whole source text is parsed, from the offset 0.
+    /// Set this to the span of a source embedded in the source text of a host file, e.g. a
+    /// `<script>` of an HTML, Vue or Svelte file or a code block of a Markdown file, to parse
+    /// only this span of the source text. The spans of the program, the tokens, the comments
+    /// and the errors are then in the coordinates of the host file, so the diagnostics can be
+    /// reported with the source text of the host file, and the semantic analysis built with
+    /// it, without remapping the offsets.
+    /// The source starts as a file does, on a new line, but without a hashbang.
+    ///
+    /// # Panics
+    ///
+    /// If the span is not in the source text, or not on its character boundaries.
+    #[must_use]
+    pub fn embedded_source(mut self, span: Span) -> Self {
+        let (start, end) = (span.start as usize, span.end as usize);
+        assert!(
+            start <= end && end <= self.source_text.len(),
+            "the embedded source {start}..{end} is not in the source text of length {}",
+            self.source_text.len()
+        );
+        assert!(
+            self.source_text.is_char_boundary(start) && self.source_text.is_char_boundary(end),
+            "the embedded source {start}..{end} is not on the character boundaries"
+        );
+        self.source_text = &self.source_text[..span.end as usize];
+        self.lexer = Lexer::new(self.ast.allocator, self.source_text, self.source_type);
+        self.source_start = span.start;
+        self
+    }
+
     /// Main entry point
     ///
     /// Returns an empty `Program` on unrecoverable error,
     /// Recoverable errors are stored inside `errors`.
     pub fn parse(mut self) -> ParserReturn<'a> {
-        let (program, panicked) = match self.parse_program() {
+        let result = self.parse_program();
+        self.finish(result)
+    }
+
+    /// Parse the source text as a single expression, e.g. `a + b`
+    ///
+    /// # Errors
+    ///
+    /// The syntax errors of the expression.
+    pub fn parse_expression(mut self) -> std::result::Result<Expression<'a>, Vec<Error>> {
+        self.bump_any();
+        let mut result = self.parse_expr().and_then(|expr| {
+            self.expect(Kind::Eof)?;
+            Ok(expr)
+        });
+        if let (Ok(expr), Some(mut strip_parens)) = (&mut result, self.strip_parens()) {
+            strip_parens.visit_expression(expr);
+        }
+        self.finish_fragment(result)
+    }
+
+    /// Parse the statement starting at `offset` of the source text, the source text after the
+    /// statement is not parsed, its end is the end of the span of the statement.
+    ///
+    /// # Errors
+    ///
+    /// The syntax errors of the statement.
+    pub fn parse_statement_at(
+        mut self,
+        offset: u32,
+    ) -> std::result::Result<Statement<'a>, Vec<Error>> {
+        self.lexer.seek(offset);
+        self.bump_any();
+        let mut result = self.parse_statement_list_item(StatementContext::StatementList);
+        if let (Ok(stmt), Some(mut strip_parens)) = (&mut result, self.strip_parens()) {
+            strip_parens.visit_statement(stmt);
+        }
+        self.finish_fragment(result)
+    }
+
+    /// The parsed fragment of the source text, or all the errors of the parser and the lexer.
+    fn finish_fragment<T>(self, result: Result<T>) -> std::result::Result<T, Vec<Error>> {
+        let mut errors = self.lexer.errors;
+        errors.extend(self.errors);
+        match result {
+            Ok(fragment) if errors.is_empty() => Ok(fragment),
+            Ok(_) => Err(errors),
+            Err(error) => {
+                errors.push(error);
+                Err(errors)
+            }
+        }
+    }
+
+    fn finish(mut self, result: Result<Program<'a>>) -> ParserReturn<'a> {
+        let (mut program, panicked) = match result {
             Ok(program) => (program, false),
             Err(error) => {
                 self.error(self.flow_error().unwrap_or(error));
@@ -173,13 +447,30 @@ impl<'a> Parser<'a> {
                 (program, true)
             }
         };
+        if let Some(mut strip_parens) = self.strip_parens() {
+            strip_parens.visit_program(&mut program);
+        }
         let errors = self.lexer.errors.into_iter().chain(self.errors).collect();
         let trivias = self.lexer.trivia_builder.build();
-        ParserReturn { program, errors, trivias, panicked }
+        let lazy_function_bodies = self.skipped_function_bodies;
+        let tokens = self.tokens.map_or_else(Vec::new, |tokens| {
+            TokenWithTrivia::attach_trivia(
+                self.ast.allocator,
+                self.source_text,
+                self.source_type,
+                self.source_start,
+                tokens,
+            )
+        });
+        ParserReturn { program, errors, trivias, panicked, lazy_function_bodies, tokens }
     }
 
     #[allow(clippy::cast_possible_truncation)]
     fn parse_program(&mut self) -> Result<Program<'a>> {
+        if self.source_start > 0 {
+            self.lexer.start_at(self.source_start);
+            self.prev_token_end = self.source_start;
+        }
         // initialize cur_token and prev_token by moving onto the first token
         self.bump_any();
 
@@ -187,7 +478,7 @@ impl<'a> Parser<'a> {
         let (directives, statements) =
             self.parse_directives_and_statements(/* is_top_level */ true)?;
 
-        let span = Span::new(0, self.source_text.len() as u32);
+        let span = Span::new(self.source_start, self.source_text.len() as u32);
         Ok(self.ast.program(span, self.source_type, directives, hashbang, statements))
     }
 
@@ -203,11 +494,12 @@ impl<'a> Parser<'a> {
     /// Check for Flow declaration if the file cannot be parsed.
     /// The declaration must be [on the first line before any code](https://flow.org/en/docs/usage/#toc-prepare-your-code-for-flow)
     fn flow_error(&self) -> Option<Error> {
+        let source_text = &self.source_text[self.source_start as usize..];
         if self.source_type.is_javascript()
-            && (self.source_text.starts_with("// @flow")
-                || self.source_text.starts_with("/* @flow */"))
+            && (source_text.starts_with("// @flow") || source_text.starts_with("/* @flow */"))
         {
-            return Some(diagnostics::Flow(Span::new(0, 8)).into());
+            let start = self.source_start;
+            return Some(diagnostics::Flow(Span::new(start, start + 8)).into());
         }
         None
     }
@@ -231,6 +523,13 @@ impl<'a> Parser<'a> {
         self.errors.push(error.into());
     }
 
+    /// Push an early error, which does not change the program
+    fn early_error<T: Into<Error>>(&mut self, error: T) {
+        if let Some(error) = self.early_errors.report(error.into()) {
+            self.errors.push(error);
+        }
+    }
+
     fn ts_enabled(&self) -> bool {
         self.source_type.is_typescript()
     }
@@ -238,6 +537,12 @@ impl<'a> Parser<'a> {
 
 #[cfg(test)]
 mod test {
+    use oxc_ast::ast::{
+        Declaration, Expression, JSXAttributeItem, JSXAttributeName, JSXChild, JSXElementName,
+        ModuleDeclaration, Statement,
+    };
+    use oxc_diagnostics::Severity;
+    use oxc_span::{GetSpan, PackageType};
 
     use super::*;
 
@@ -265,4 +570,455 @@ mod test {
         assert!(ret.program.is_empty());
         assert_eq!(ret.errors.first().unwrap().to_string(), "Flow is not supported");
     }
+
+    #[test]
+    fn recover_from_errors() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let source =
+            "let a = ;\nlet b = 1;\nfunction foo() {\n  if (a {}\n  return b;\n}\nlet c = )";
+        let ret = Parser::new(&allocator, source, source_type).parse();
+        assert!(ret.panicked);
+        assert!(ret.program.is_empty());
+        assert_eq!(ret.errors.len(), 1);
+
+        let ret = Parser::new(&allocator, source, source_type).recover_from_errors(true).parse();
+        assert!(!ret.panicked);
+        assert_eq!(ret.errors.len(), 3);
+        // `let b`, `function foo` with its `return b`
+        assert_eq!(ret.program.body.len(), 2);
+        let Statement::Declaration(Declaration::FunctionDeclaration(function)) =
+            &ret.program.body[1]
+        else {
+            unreachable!()
+        };
+        assert_eq!(function.body.as_ref().unwrap().statements.len(), 1);
+
+        let source = "}\nlet a = 1;";
+        let ret = Parser::new(&allocator, source, source_type).recover_from_errors(true).parse();
+        assert_eq!(ret.errors.len(), 1);
+        assert_eq!(ret.program.body.len(), 1);
+    }
+
+    #[test]
+    fn parse_expression() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let expr = Parser::new(&allocator, "a + b", source_type).parse_expression().unwrap();
+        assert!(matches!(expr, Expression::BinaryExpression(_)));
+        assert_eq!(expr.span(), Span::new(0, 5));
+
+        let errors = Parser::new(&allocator, "a +", source_type).parse_expression().unwrap_err();
+        assert_eq!(errors.len(), 1);
+        let errors = Parser::new(&allocator, "a b", source_type).parse_expression().unwrap_err();
+        assert_eq!(errors.len(), 1);
+    }
+
+    #[test]
+    fn parse_statement_at() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let source = "let a = 1;\nif (a) { b(); }\nlet c = ;";
+        let stmt = Parser::new(&allocator, source, source_type).parse_statement_at(11).unwrap();
+        assert!(matches!(stmt, Statement::IfStatement(_)));
+        assert_eq!(stmt.span(), Span::new(11, 26));
+
+        let errors =
+            Parser::new(&allocator, source, source_type).parse_statement_at(27).unwrap_err();
+        assert_eq!(errors.len(), 1);
+    }
+
+    #[test]
+    fn estree() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default().with_module(true);
+        let source = "'use strict';\nlet [a, ...b] = (1n);\n/* é */ ({ c = 2 } = d.e);\n";
+        let ret = Parser::new(&allocator, source, source_type).parse();
+        let program = ret.program.to_estree(source);
+
+        assert_eq!(program["sourceType"], "module");
+        assert_eq!(program["body"][0]["directive"], "use strict");
+        let declarator = &program["body"][1]["declarations"][0];
+        assert_eq!(declarator["id"]["type"], "ArrayPattern");
+        assert_eq!(declarator["id"]["elements"][0]["type"], "Identifier");
+        assert_eq!(declarator["id"]["elements"][1]["type"], "RestElement");
+        assert_eq!(declarator["init"]["type"], "Literal");
+        assert_eq!(declarator["init"]["bigint"], "1");
+        assert_eq!(declarator["init"]["raw"], "1n");
+
+        let assignment = &program["body"][2]["expression"];
+        assert_eq!(assignment["type"], "AssignmentExpression");
+        assert_eq!(assignment["left"]["type"], "ObjectPattern");
+        assert_eq!(assignment["left"]["properties"][0]["type"], "Property");
+        assert_eq!(assignment["left"]["properties"][0]["value"]["type"], "AssignmentPattern");
+        assert_eq!(assignment["right"]["type"], "MemberExpression");
+        // The offsets are in UTF-16 code units, `é` is 2 bytes
+        assert_eq!(program["body"][2]["start"], 44);
+        assert_eq!(
+            program["body"][2]["loc"]["start"],
+            serde_json::json!({ "line": 3, "column": 8 })
+        );
+    }
+
+    #[test]
+    fn preserve_parens() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let source = "(a + b) * ((c));";
+        let ret = Parser::new(&allocator, source, source_type).parse();
+        let Statement::ExpressionStatement(stmt) = &ret.program.body[0] else { unreachable!() };
+        let Expression::BinaryExpression(expr) = &stmt.expression else { unreachable!() };
+        assert!(matches!(expr.left, Expression::ParenthesizedExpression(_)));
+        assert!(matches!(expr.right, Expression::ParenthesizedExpression(_)));
+
+        let ret = Parser::new(&allocator, source, source_type).preserve_parens(false).parse();
+        let Statement::ExpressionStatement(stmt) = &ret.program.body[0] else { unreachable!() };
+        let Expression::BinaryExpression(expr) = &stmt.expression else { unreachable!() };
+        assert!(matches!(expr.left, Expression::BinaryExpression(_)));
+        assert!(matches!(expr.right, Expression::Identifier(_)));
+        assert_eq!(expr.span, Span::new(0, 15));
+
+        let expr =
+            Parser::new(&allocator, "(a)", source_type).preserve_parens(false).parse_expression();
+        assert!(matches!(expr, Ok(Expression::Identifier(_))));
+    }
+
+    #[test]
+    fn import_attributes() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default().with_module(true);
+        let source = "import data from './data.json' with { type: 'json' };
+            export { default } from './data.json' with { type: 'json' };";
+        let ret = Parser::new(&allocator, source, source_type).parse();
+        assert!(ret.errors.is_empty());
+        let Statement::ModuleDeclaration(decl) = &ret.program.body[0] else { unreachable!() };
+        let ModuleDeclaration::ImportDeclaration(decl) = &**decl else { unreachable!() };
+        let with_clause = decl.with_clause.as_ref().unwrap();
+        assert_eq!(with_clause.attributes_keyword.name, "with");
+        assert_eq!(with_clause.with_entries[0].value.value, "json");
+
+        let source = "import data from './data.json' assert { type: 'json' };";
+        let ret = Parser::new(&allocator, source, source_type).parse();
+        assert_eq!(ret.errors.len(), 1);
+        let ret =
+            Parser::new(&allocator, source, source_type).allow_import_assertions(true).parse();
+        assert!(ret.errors.is_empty());
+    }
+
+    #[test]
+    fn using_declaration() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default().with_module(true);
+        let pass = [
+            "using a = foo();",
+            "await using a = foo(), b = bar();",
+            "for (using a of b);",
+            "for (await using a of b);",
+            "async function foo() { await using a = bar(); }",
+        ];
+        for source in pass {
+            let ret = Parser::new(&allocator, source, source_type).parse();
+            assert!(ret.errors.is_empty(), "{source}");
+            assert!(!ret.program.body.is_empty(), "{source}");
+        }
+        let fail = [
+            "using a;",
+            "using a = foo(), b;",
+            "using let = foo();",
+            "if (a) using b = foo();",
+            "for (using a in b);",
+            "function foo() { await using a = bar(); }",
+        ];
+        for source in fail {
+            let ret = Parser::new(&allocator, source, source_type).parse();
+            assert_eq!(ret.errors.len(), 1, "{source}");
+        }
+    }
+
+    #[test]
+    fn decorators() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default().with_module(true).with_typescript(true);
+        let errors = |source: &str, version: Option<DecoratorsVersion>| {
+            let parser = Parser::new(&allocator, source, source_type);
+            let parser =
+                if let Some(version) = version { parser.decorators(version) } else { parser };
+            parser.parse().errors.len()
+        };
+        let legacy = Some(DecoratorsVersion::Legacy);
+        let v2023_05 = Some(DecoratorsVersion::V2023_05);
+        for (source, legacy_errors, v2023_05_errors) in [
+            ("@dec export class A {}", 0, 0),
+            ("export @dec class A {}", 1, 0),
+            ("export default @dec class {}", 1, 0),
+            ("(@dec class {});", 1, 0),
+            ("class A { @dec accessor a; }", 1, 0),
+            ("class A { method(@dec a) {} }", 0, 1),
+        ] {
+            assert_eq!(errors(source, None), 0, "{source}");
+            assert_eq!(errors(source, legacy), legacy_errors, "{source}");
+            assert_eq!(errors(source, v2023_05), v2023_05_errors, "{source}");
+        }
+
+        let source = "class A { accessor\n a; }";
+        let ret = Parser::new(&allocator, source, source_type).parse();
+        let Statement::Declaration(Declaration::ClassDeclaration(class)) = &ret.program.body[0]
+        else {
+            unreachable!()
+        };
+        assert_eq!(class.body.body.len(), 2);
+    }
+
+    #[test]
+    fn early_errors() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default().with_module(true);
+        let source = "class A { static prototype() {} }\nconst a;\nexport { 'b' };";
+        let parse = |early_errors: EarlyErrors| {
+            Parser::new(&allocator, source, source_type).early_errors(early_errors).parse()
+        };
+
+        let ret = parse(EarlyErrors::Error);
+        assert_eq!(ret.errors.len(), 3);
+        assert!(ret.errors.iter().all(|error| error.severity().is_none()));
+
+        let ret = parse(EarlyErrors::Warning);
+        assert_eq!(ret.errors.len(), 3);
+        assert!(ret.errors.iter().all(|error| error.severity() == Some(Severity::Warning)));
+        assert_eq!(ret.program.body.len(), 3);
+
+        let ret = parse(EarlyErrors::Ignore);
+        assert!(ret.errors.is_empty());
+        assert_eq!(ret.program.body.len(), 3);
+
+        // The syntax errors are still errors, the statements with them are skipped.
+        let source = "const a;\nlet;;\n1 +;\nlet b = 1;";
+        let ret = Parser::new(&allocator, source, source_type)
+            .early_errors(EarlyErrors::Warning)
+            .recover_from_errors(true)
+            .parse();
+        let severities = ret.errors.iter().map(|error| error.severity()).collect::<Vec<_>>();
+        assert_eq!(severities, vec![Some(Severity::Warning), None]);
+        assert!(!ret.panicked);
+    }
+
+    #[test]
+    fn tokens() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let kinds = |source| {
+            Tokens::new(&allocator, source, source_type)
+                .map(|token| token.token.kind)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(kinds("a / b"), vec![Kind::Ident, Kind::Slash, Kind::Ident, Kind::Eof]);
+        assert_eq!(kinds("x = /b/g"), vec![Kind::Ident, Kind::Eq, Kind::RegExp, Kind::Eof]);
+        assert_eq!(
+            kinds("`a${ {b} }c${d}e`"),
+            vec![
+                Kind::TemplateHead,
+                Kind::LCurly,
+                Kind::Ident,
+                Kind::RCurly,
+                Kind::TemplateMiddle,
+                Kind::Ident,
+                Kind::TemplateTail,
+                Kind::Eof
+            ]
+        );
+        assert_eq!(
+            kinds("a >>= 1"),
+            vec![Kind::Ident, Kind::ShiftRightEq, Kind::Decimal, Kind::Eof]
+        );
+
+        let source = "let a = 'b'; // c\n/* d */ a\n";
+        let mut iter = Tokens::new(&allocator, source, source_type);
+        let tokens = iter.by_ref().collect::<Vec<_>>();
+        assert!(iter.errors().is_empty());
+        assert_eq!(tokens.len(), 7);
+        assert_eq!(tokens[3].token.span(), Span::new(8, 11));
+        assert_eq!(tokens[3].token.value.get_string(), Some("b"));
+        let trivia = |token: &TokenWithTrivia| {
+            token
+                .leading_trivia
+                .iter()
+                .map(|trivia| {
+                    (trivia.kind, &source[trivia.span.start as usize..trivia.span.end as usize])
+                })
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            trivia(&tokens[5]),
+            vec![
+                (Kind::WhiteSpace, " "),
+                (Kind::Comment, "// c\n"),
+                (Kind::MultiLineComment, "/* d */"),
+                (Kind::WhiteSpace, " "),
+            ]
+        );
+        assert!(tokens[5].token.is_on_new_line);
+        assert_eq!(trivia(&tokens[6]), vec![(Kind::NewLine, "\n")]);
+    }
+
+    #[test]
+    fn module_syntax() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::from_path("a.js").unwrap();
+        let has_module_syntax =
+            |source| Tokens::new(&allocator, source, source_type).has_module_syntax();
+        assert!(has_module_syntax("import a from 'a'"));
+        assert!(has_module_syntax("import 'a'"));
+        assert!(has_module_syntax("export default 1"));
+        assert!(has_module_syntax("console.log(import.meta.url)"));
+        assert!(!has_module_syntax("import('a').then(a => a.import())"));
+        assert!(!has_module_syntax("const a = { import: 1, export() {} }; a.export = 1;"));
+
+        assert!(SourceType::from_path("a.cjs").unwrap().is_script());
+        assert!(SourceType::from_path("a.cts").unwrap().is_module());
+        let package_type = |package_type| {
+            SourceType::from_path_with_package_type("a.js", |_| Some(package_type)).unwrap()
+        };
+        assert!(package_type(PackageType::CommonJs).is_script());
+        assert!(package_type(PackageType::Module).is_module());
+    }
+
+    #[test]
+    fn v8_intrinsics() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let source = "%DebugPrint(a, ...b);\nx = %GetOptimizationStatus(f) % 2;";
+        let ret = Parser::new(&allocator, source, source_type).allow_v8_intrinsics(true).parse();
+        assert!(ret.errors.is_empty());
+        let Statement::ExpressionStatement(stmt) = &ret.program.body[0] else { unreachable!() };
+        let Expression::V8IntrinsicExpression(expr) = &stmt.expression else { unreachable!() };
+        assert_eq!(expr.name.name, "DebugPrint");
+        assert_eq!(expr.arguments.len(), 2);
+        assert_eq!(expr.span, Span::new(0, 20));
+
+        let ret = Parser::new(&allocator, source, source_type).parse();
+        assert_eq!(ret.errors.len(), 1);
+        for source in ["% DebugPrint(a)", "%DebugPrint"] {
+            let ret =
+                Parser::new(&allocator, source, source_type).allow_v8_intrinsics(true).parse();
+            assert_eq!(ret.errors.len(), 1);
+        }
+    }
+
+    #[test]
+    fn jsx_children_spans() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default().with_jsx(true);
+        let source = "<p><>{a}</><b-c d-e=\"f\" /> g </p>";
+        let ret = Parser::new(&allocator, source, source_type).parse();
+        assert!(ret.errors.is_empty());
+        let Statement::ExpressionStatement(stmt) = &ret.program.body[0] else { unreachable!() };
+        let Expression::JSXElement(element) = &stmt.expression else { unreachable!() };
+        let [JSXChild::Fragment(fragment), JSXChild::Element(child), JSXChild::Text(text)] =
+            element.children.as_slice()
+        else {
+            unreachable!()
+        };
+        assert_eq!(fragment.span.source_text(source), "<>{a}</>");
+        assert_eq!(child.span.source_text(source), "<b-c d-e=\"f\" />");
+        assert_eq!(text.span.source_text(source), " g ");
+        let JSXElementName::Identifier(name) = &child.opening_element.name else { unreachable!() };
+        assert_eq!(name.span.source_text(source), "b-c");
+        let JSXAttributeItem::Attribute(attribute) = &child.opening_element.attributes[0] else {
+            unreachable!()
+        };
+        let JSXAttributeName::Identifier(name) = &attribute.name else { unreachable!() };
+        assert_eq!(name.span.source_text(source), "d-e");
+
+        let ret = Parser::new(&allocator, source, source_type).preserve_tokens(true).parse();
+        let tokens = ret
+            .tokens
+            .iter()
+            .map(|token| &source[token.token.start as usize..token.token.end as usize])
+            .collect::<Vec<_>>();
+        assert_eq!(
+            tokens,
+            [
+                "<", "p", ">", "<", ">", "{", "a", "}", "<", "/", ">", "<", "b-c", "d-e", "=",
+                "\"f\"", "/", ">", " g ", "<", "/", "p", ">", ""
+            ]
+        );
+    }
+
+    #[test]
+    fn preserve_tokens() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default().with_jsx(true);
+        let source = "#!/usr/bin/env node\n/* a */ let f = (a) => a / 2; // b\nx = /c/g.test(`${f}d`), <A b={1}> e </A>;\n";
+        let ret = Parser::new(&allocator, source, source_type).preserve_tokens(true).parse();
+        assert!(ret.errors.is_empty());
+
+        // The tokens and the trivia cover the whole source text
+        let mut end = 0;
+        for TokenWithTrivia { token, leading_trivia } in &ret.tokens {
+            for trivia in leading_trivia {
+                assert_eq!(trivia.span.start, end);
+                end = trivia.span.end;
+            }
+            assert_eq!(token.start, end);
+            end = token.end;
+        }
+        assert_eq!(end as usize, source.len());
+
+        let kinds = ret.tokens.iter().map(|token| token.token.kind).collect::<Vec<_>>();
+        assert!(kinds.contains(&Kind::RegExp));
+        assert!(kinds.contains(&Kind::JSXText));
+        assert_eq!(kinds.last(), Some(&Kind::Eof));
+        let trivia = &ret.tokens[1].leading_trivia;
+        assert_eq!(trivia.len(), 2);
+        assert_eq!(trivia[0].kind, Kind::MultiLineComment);
+
+        let ret = Parser::new(&allocator, source, source_type).parse();
+        assert!(ret.tokens.is_empty());
+    }
+
+    #[test]
+    #[allow(clippy::cast_possible_truncation)]
+    fn embedded_source() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let source = "<p>a</p>\n<script>\n--> b\nlet c = 1; // d\n</script>\n<p>e</p>";
+        let start = source.find("<script>").unwrap() + "<script>".len();
+        let end = source.find("</script>").unwrap();
+        let span = Span::new(start as u32, end as u32);
+        let ret = Parser::new(&allocator, source, source_type)
+            .embedded_source(span)
+            .preserve_tokens(true)
+            .parse();
+        assert!(ret.errors.is_empty());
+        assert_eq!(ret.program.span, span);
+        assert_eq!(ret.program.body[0].span().source_text(source), "let c = 1;");
+        let comments = ret.trivias;
+        assert_eq!(comments.len(), 2);
+        assert_eq!(&source[comments[1].0 as usize..comments[1].1 as usize], " d\n");
+        assert_eq!(ret.tokens[0].leading_trivia[0].span.start, span.start);
+        assert_eq!(ret.tokens.last().unwrap().token.end, span.end);
+
+        let source = "<script>let c = ;</script>";
+        let ret =
+            Parser::new(&allocator, source, source_type).embedded_source(Span::new(8, 17)).parse();
+        assert_eq!(ret.errors.len(), 1);
+        let label = ret.errors[0].labels().unwrap().next().unwrap();
+        assert_eq!(label.offset(), 16);
+    }
+
+    #[test]
+    #[should_panic(expected = "is not in the source text")]
+    fn embedded_source_out_of_bounds() {
+        let allocator = Allocator::default();
+        let _ = Parser::new(&allocator, "<script></script>", SourceType::default())
+            .embedded_source(Span::new(8, 20));
+    }
+
+    #[test]
+    #[should_panic(expected = "is not on the character boundaries")]
+    fn embedded_source_char_boundary() {
+        let allocator = Allocator::default();
+        let _ = Parser::new(&allocator, "<script>'é'</script>", SourceType::default())
+            .embedded_source(Span::new(8, 10));
+    }
 }
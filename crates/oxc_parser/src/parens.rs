@@ -0,0 +1,26 @@
+//! Removal of the parentheses, see [Parser::preserve_parens]
+
+use oxc_ast::{ast::Expression, AstBuilder, VisitMut};
+
+use crate::Parser;
+
+/// Replaces the `ParenthesizedExpression`s by their expressions
+pub struct StripParens<'a> {
+    ast: AstBuilder<'a>,
+}
+
+impl<'a> VisitMut<'a> for StripParens<'a> {
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        while let Expression::ParenthesizedExpression(paren_expr) = expr {
+            *expr = self.ast.move_expression(&mut paren_expr.expression);
+        }
+        self.visit_expression_match(expr);
+    }
+}
+
+impl<'a> Parser<'a> {
+    /// The visitor removing the parentheses, unless they are preserved
+    pub(crate) fn strip_parens(&self) -> Option<StripParens<'a>> {
+        (!self.preserve_parens).then(|| StripParens { ast: AstBuilder::new(self.ast.allocator) })
+    }
+}
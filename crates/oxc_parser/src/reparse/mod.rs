@@ -0,0 +1,221 @@
+//! Incremental re-parsing for the editors
+
+mod shift_spans;
+
+use oxc_allocator::Vec;
+use oxc_ast::ast::{Program, Statement};
+use oxc_diagnostics::Result;
+use oxc_span::{GetSpan, Span};
+
+use self::shift_spans::ShiftSpans;
+use crate::{lexer::Kind, Parser, ParserReturn, StatementContext};
+
+/// A change of the source text, the text of `span` is replaced by `text`
+#[derive(Debug, Clone, Copy)]
+pub struct TextEdit<'e> {
+    /// The replaced range of the previous source text
+    pub span: Span,
+    /// The inserted text
+    pub text: &'e str,
+}
+
+impl<'e> TextEdit<'e> {
+    pub fn new(span: Span, text: &'e str) -> Self {
+        Self { span, text }
+    }
+
+    /// The source text with the edit applied
+    pub fn apply(&self, source_text: &str) -> String {
+        let mut output = source_text[..self.span.start as usize].to_string();
+        output.push_str(self.text);
+        output.push_str(&source_text[self.span.end as usize..]);
+        output
+    }
+
+    /// The change of the length of the source text
+    #[allow(clippy::cast_possible_wrap)]
+    fn length_delta(&self) -> i64 {
+        self.text.len() as i64 - i64::from(self.span.size())
+    }
+}
+
+impl<'a> Parser<'a> {
+    /// Parse again a source text changed by `edit`, reusing the unchanged statements
+    ///
+    /// The parser is created with the new source text, and `old` is the return of the parse of
+    /// the previous source text, allocated with the same allocator.
+    ///
+    /// The top level statements before the edit are reused, and the ones after it with their
+    /// spans moved by the change of the length of the text. The other statements are parsed
+    /// again. The whole source text is parsed again when the previous one had syntax errors, or
+    /// when the edit is in its first statements.
+    pub fn reparse(mut self, old: ParserReturn<'a>, edit: &TextEdit) -> ParserReturn<'a> {
+        if old.panicked || !old.errors.is_empty() {
+            return self.parse();
+        }
+        let Program { directives, hashbang, body, .. } = old.program;
+        // The last statement before the edit may be continued by it, e.g. `a` by `.b`.
+        let reused = body
+            .iter()
+            .take_while(|stmt| stmt.span().end < edit.span.start)
+            .count()
+            .saturating_sub(1);
+        if reused == 0 {
+            return self.parse();
+        }
+
+        let mut statements = self.ast.new_vec();
+        let mut old_statements = body.into_iter();
+        statements.extend(old_statements.by_ref().take(reused));
+        // The first statement after the edit may continue the edited one, e.g. `(b)` after `a`.
+        let delta = edit.length_delta();
+        let following = old_statements
+            .filter(|stmt| stmt.span().start > edit.span.end)
+            .skip(1)
+            .map(|mut stmt| {
+                stmt.shift_spans(delta);
+                stmt
+            })
+            .collect();
+
+        let start = statements.last().map_or(0, |stmt| stmt.span().end);
+        self.lexer.seek(start);
+        self.bump_any();
+        let result = self.parse_edited_statements(&mut statements, following);
+        let end = result.as_ref().ok().copied().flatten();
+        #[allow(clippy::cast_possible_truncation)]
+        let span = Span::new(0, self.source_text.len() as u32);
+        let source_type = self.source_type;
+        let result =
+            result.map(|_| self.ast.program(span, source_type, directives, hashbang, statements));
+
+        let mut ret = self.finish(result);
+        if !ret.panicked {
+            // The comments of the reused statements are not lexed again.
+            let lexed = std::mem::take(&mut ret.trivias);
+            ret.trivias = old
+                .trivias
+                .iter()
+                .filter(|trivia| trivia.0 < start)
+                .chain(lexed.iter().filter(|trivia| end.map_or(true, |end| trivia.0 < end)))
+                .copied()
+                .chain(old.trivias.iter().filter_map(|&(trivia_start, trivia_end, kind)| {
+                    let mut span = Span::new(trivia_start, trivia_end);
+                    span.shift_spans(delta);
+                    end.is_some_and(|end| span.start >= end).then_some((span.start, span.end, kind))
+                }))
+                .collect();
+        }
+        ret
+    }
+
+    /// Parse the top level statements up to the start of one of the `following` statements,
+    /// which are reused from there. Returns the start of the reused statements.
+    fn parse_edited_statements(
+        &mut self,
+        statements: &mut Vec<'a, Statement<'a>>,
+        following: std::vec::Vec<Statement<'a>>,
+    ) -> Result<Option<u32>> {
+        let mut following = following.into_iter().peekable();
+        while !self.at(Kind::Eof) {
+            let start = self.cur_token().start;
+            while following.next_if(|stmt| stmt.span().start < start).is_some() {}
+            if following.peek().is_some_and(|stmt| stmt.span().start == start) {
+                statements.extend(following);
+                return Ok(Some(start));
+            }
+            let stmt =
+                self.recover(|p| p.parse_statement_list_item(StatementContext::StatementList))?;
+            statements.extend(stmt);
+        }
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_ast::ast::{Declaration, Statement};
+    use oxc_span::{GetSpan, SourceType, Span};
+
+    use super::TextEdit;
+    use crate::Parser;
+
+    const SOURCE: &str = "let a = 1;\nlet b = 2;\nfoo(a);\nlet c = 3;\n// comment\nbar(c);\n";
+
+    /// Reparse `source` changed by `edit`, and compare with the parse of the whole new source.
+    /// Returns whether the first statement was reused.
+    fn reparse(source: &str, edit: &TextEdit) -> bool {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let new_source = edit.apply(source);
+        let old = Parser::new(&allocator, source, source_type).parse();
+        let Statement::Declaration(Declaration::VariableDeclaration(first)) = &old.program.body[0]
+        else {
+            unreachable!()
+        };
+        let first = std::ptr::addr_of!(**first);
+
+        let ret = Parser::new(&allocator, &new_source, source_type).reparse(old, edit);
+        let expected = Parser::new(&allocator, &new_source, source_type).parse();
+        assert_eq!(
+            serde_json::to_string(&ret.program).unwrap(),
+            serde_json::to_string(&expected.program).unwrap(),
+            "{new_source}"
+        );
+        assert_eq!(ret.trivias, expected.trivias);
+        assert_eq!(ret.errors.len(), expected.errors.len());
+        matches!(ret.program.body.first(), Some(Statement::Declaration(Declaration::VariableDeclaration(decl)))
+            if std::ptr::eq(first, std::ptr::addr_of!(**decl)))
+    }
+
+    #[test]
+    fn reuse() {
+        // `foo` renamed to `baz`
+        assert!(reparse(SOURCE, &TextEdit::new(Span::new(22, 25), "baz")));
+        // `foo(a)` changed to `foo(a, b)`
+        assert!(reparse(SOURCE, &TextEdit::new(Span::new(27, 27), ", b")));
+        // `foo` renamed to `f`
+        assert!(reparse(SOURCE, &TextEdit::new(Span::new(22, 25), "f")));
+        // `let b` continued by `foo`
+        assert!(reparse(
+            "let a = 1;\nlet b = 2\nfoo(a);\nbar;",
+            &TextEdit::new(Span::new(21, 21), ".")
+        ));
+        // `(c)` continues the edited `bar`
+        assert!(reparse(
+            "let a = 1;\nlet b = 2;\nfoo\n(c);\nbar;",
+            &TextEdit::new(Span::new(22, 25), "baz")
+        ));
+    }
+
+    #[test]
+    fn shift_spans() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        // `foo(a)` changed to `foo(a, b)`, `bar(c);` is reused 3 bytes further
+        let edit = TextEdit::new(Span::new(27, 27), ", b");
+        let new_source = edit.apply(SOURCE);
+        let old = Parser::new(&allocator, SOURCE, source_type).parse();
+        let Statement::ExpressionStatement(last) = &old.program.body[4] else { unreachable!() };
+        let (last, span) = (std::ptr::addr_of!(**last), last.span);
+
+        let ret = Parser::new(&allocator, &new_source, source_type).reparse(old, &edit);
+        let Statement::ExpressionStatement(stmt) = &ret.program.body[4] else { unreachable!() };
+        assert!(std::ptr::eq(last, std::ptr::addr_of!(**stmt)));
+        assert_eq!(stmt.span, Span::new(span.start + 3, span.end + 3));
+        assert_eq!(stmt.expression.span().source_text(&new_source), "bar(c)");
+        let comment = ret.trivias[0];
+        assert_eq!(&new_source[comment.0 as usize..comment.1 as usize], " comment\n");
+    }
+
+    #[test]
+    fn parse_all() {
+        // in the first statement
+        assert!(!reparse(SOURCE, &TextEdit::new(Span::new(4, 5), "d")));
+        // in the second statement, which may be continued by the edit
+        assert!(!reparse(SOURCE, &TextEdit::new(Span::new(15, 16), "d")));
+        // a syntax error, the program is empty
+        assert!(!reparse(SOURCE, &TextEdit::new(Span::new(27, 27), ")")));
+    }
+}
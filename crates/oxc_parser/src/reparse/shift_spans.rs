@@ -0,0 +1,358 @@
+//! Shift the spans of the statements reused by [Parser::reparse](crate::Parser::reparse) after
+//! an edit which changes the length of the source text
+
+use oxc_allocator::{Box, Vec};
+use oxc_ast::ast::*;
+use oxc_span::Span;
+
+pub trait ShiftSpans {
+    /// Move the spans of the node and of its children by `delta` bytes
+    fn shift_spans(&mut self, delta: i64);
+}
+
+impl ShiftSpans for Span {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn shift_spans(&mut self, delta: i64) {
+        self.start = (i64::from(self.start) + delta) as u32;
+        self.end = (i64::from(self.end) + delta) as u32;
+    }
+}
+
+impl<T: ShiftSpans> ShiftSpans for Option<T> {
+    fn shift_spans(&mut self, delta: i64) {
+        if let Some(node) = self {
+            node.shift_spans(delta);
+        }
+    }
+}
+
+impl<'a, T: ShiftSpans> ShiftSpans for Box<'a, T> {
+    fn shift_spans(&mut self, delta: i64) {
+        (**self).shift_spans(delta);
+    }
+}
+
+impl<'a, T: ShiftSpans> ShiftSpans for Vec<'a, T> {
+    fn shift_spans(&mut self, delta: i64) {
+        for node in self.iter_mut() {
+            node.shift_spans(delta);
+        }
+    }
+}
+
+impl<'a> ShiftSpans for Modifiers<'a> {
+    fn shift_spans(&mut self, delta: i64) {
+        for modifier in self.iter_mut() {
+            modifier.span.shift_spans(delta);
+        }
+    }
+}
+
+/// `Node { fields }` shifts the fields of the node which contain spans
+macro_rules! shift_struct_spans {
+    ($($name:ident $(<$lt:lifetime>)? { $($field:ident),* $(,)? })*) => {
+        $(
+            impl$(<$lt>)? ShiftSpans for $name$(<$lt>)? {
+                fn shift_spans(&mut self, delta: i64) {
+                    $(self.$field.shift_spans(delta);)*
+                }
+            }
+        )*
+    };
+}
+
+/// `Node { variants }` shifts the node of each variant
+macro_rules! shift_enum_spans {
+    ($($name:ident $(<$lt:lifetime>)? { $($variant:ident),* $(,)? })*) => {
+        $(
+            impl$(<$lt>)? ShiftSpans for $name$(<$lt>)? {
+                fn shift_spans(&mut self, delta: i64) {
+                    match self {
+                        $(Self::$variant(node) => node.shift_spans(delta),)*
+                    }
+                }
+            }
+        )*
+    };
+}
+
+shift_struct_spans! {
+    Program<'a> { span, directives, hashbang, body }
+    IdentifierName { span }
+    IdentifierReference { span }
+    BindingIdentifier { span }
+    LabelIdentifier { span }
+    ThisExpression { span }
+    ArrayExpression<'a> { span, elements, trailing_comma }
+    ObjectExpression<'a> { span, properties, trailing_comma }
+    ObjectProperty<'a> { span, key, value, init }
+    TemplateLiteral<'a> { span, quasis, expressions }
+    TaggedTemplateExpression<'a> { span, tag, quasi, type_parameters }
+    TemplateElement { span }
+    ComputedMemberExpression<'a> { span, object, expression }
+    StaticMemberExpression<'a> { span, object, property }
+    PrivateFieldExpression<'a> { span, object, field }
+    CallExpression<'a> { span, callee, arguments, type_parameters }
+    NewExpression<'a> { span, callee, arguments, type_parameters }
+    MetaProperty { span, meta, property }
+    SpreadElement<'a> { span, argument }
+    UpdateExpression<'a> { span, argument }
+    UnaryExpression<'a> { span, argument }
+    BinaryExpression<'a> { span, left, right }
+    PrivateInExpression<'a> { span, left, right }
+    LogicalExpression<'a> { span, left, right }
+    ConditionalExpression<'a> { span, test, consequent, alternate }
+    AssignmentExpression<'a> { span, left, right }
+    ArrayAssignmentTarget<'a> { span, elements, rest, trailing_comma }
+    ObjectAssignmentTarget<'a> { span, properties, rest }
+    AssignmentTargetWithDefault<'a> { span, binding, init }
+    AssignmentTargetPropertyIdentifier<'a> { span, binding, init }
+    AssignmentTargetPropertyProperty<'a> { span, name, binding }
+    SequenceExpression<'a> { span, expressions }
+    Super { span }
+    AwaitExpression<'a> { span, argument }
+    ChainExpression<'a> { span, expression }
+    ParenthesizedExpression<'a> { span, expression }
+    Directive { span, expression }
+    Hashbang { span }
+    BlockStatement<'a> { span, body }
+    VariableDeclaration<'a> { span, declarations, modifiers }
+    VariableDeclarator<'a> { span, id, init }
+    UsingDeclaration<'a> { span, declarations }
+    EmptyStatement { span }
+    ExpressionStatement<'a> { span, expression }
+    IfStatement<'a> { span, test, consequent, alternate }
+    DoWhileStatement<'a> { span, body, test }
+    WhileStatement<'a> { span, test, body }
+    ForStatement<'a> { span, init, test, update, body }
+    ForInStatement<'a> { span, left, right, body }
+    ForOfStatement<'a> { span, left, right, body }
+    ContinueStatement { span, label }
+    BreakStatement { span, label }
+    ReturnStatement<'a> { span, argument }
+    WithStatement<'a> { span, object, body }
+    SwitchStatement<'a> { span, discriminant, cases }
+    SwitchCase<'a> { span, test, consequent }
+    LabeledStatement<'a> { span, label, body }
+    ThrowStatement<'a> { span, argument }
+    TryStatement<'a> { span, block, handler, finalizer }
+    CatchClause<'a> { span, param, body }
+    DebuggerStatement { span }
+    BindingPattern<'a> { kind, type_annotation }
+    AssignmentPattern<'a> { span, left, right }
+    ObjectPattern<'a> { span, properties, rest }
+    BindingProperty<'a> { span, key, value }
+    ArrayPattern<'a> { span, elements, rest }
+    RestElement<'a> { span, argument }
+    Function<'a> { span, id, params, body, type_parameters, return_type, modifiers }
+    FormalParameters<'a> { span, items, rest }
+    FormalParameter<'a> { span, pattern, decorators }
+    FunctionBody<'a> { span, directives, statements }
+    ArrowExpression<'a> { span, params, body, type_parameters, return_type }
+    YieldExpression<'a> { span, argument }
+    Class<'a> {
+        span, id, super_class, body, type_parameters, super_type_parameters, implements,
+        decorators, modifiers,
+    }
+    ClassBody<'a> { span, body }
+    MethodDefinition<'a> { span, key, value, decorators }
+    PropertyDefinition<'a> { span, key, value, type_annotation, decorators }
+    PrivateIdentifier { span }
+    StaticBlock<'a> { span, body }
+    AccessorProperty<'a> { span, key, value, decorators }
+    ImportExpression<'a> { span, source, arguments }
+    V8IntrinsicExpression<'a> { span, name, arguments }
+    ImportDeclaration<'a> { span, specifiers, source, with_clause }
+    ImportSpecifier { span, imported, local }
+    ImportDefaultSpecifier { span, local }
+    ImportNamespaceSpecifier { span, local }
+    WithClause<'a> { span, attributes_keyword, with_entries }
+    ImportAttribute { span, key, value }
+    ExportNamedDeclaration<'a> { span, declaration, specifiers, source, with_clause }
+    ExportDefaultDeclaration<'a> { span, declaration, exported }
+    ExportAllDeclaration<'a> { span, exported, source, with_clause }
+    ExportSpecifier { span, local, exported }
+    JSDocNullableType<'a> { span, type_annotation }
+    JSDocUnknownType { span }
+    JSXElement<'a> { span, opening_element, closing_element, children }
+    JSXOpeningElement<'a> { span, name, attributes, type_parameters }
+    JSXClosingElement<'a> { span, name }
+    JSXFragment<'a> { span, opening_fragment, closing_fragment, children }
+    JSXOpeningFragment { span }
+    JSXClosingFragment { span }
+    JSXNamespacedName { span, namespace, property }
+    JSXMemberExpression<'a> { span, object, property }
+    JSXExpressionContainer<'a> { span, expression }
+    JSXEmptyExpression { span }
+    JSXAttribute<'a> { span, name, value }
+    JSXSpreadAttribute<'a> { span, argument }
+    JSXIdentifier { span }
+    JSXSpreadChild<'a> { span, expression }
+    JSXText { span }
+    BooleanLiteral { span }
+    NullLiteral { span }
+    NumberLiteral<'a> { span }
+    BigintLiteral { span }
+    RegExpLiteral { span }
+    StringLiteral { span }
+    TSEnumDeclaration<'a> { span, id, body, modifiers }
+    TSEnumBody<'a> { span, members }
+    TSEnumMember<'a> { span, id, initializer }
+    TSTypeAnnotation<'a> { span, type_annotation }
+    TSLiteralType<'a> { span, literal }
+    TSConditionalType<'a> { span, check_type, extends_type, true_type, false_type }
+    TSUnionType<'a> { span, types }
+    TSIntersectionType<'a> { span, types }
+    TSTypeOperatorType<'a> { span, type_annotation }
+    TSArrayType<'a> { span, element_type }
+    TSIndexedAccessType<'a> { span, object_type, index_type }
+    TSTupleType<'a> { span, element_types }
+    TSNamedTupleMember<'a> { span, element_type, label }
+    TSOptionalType<'a> { span, type_annotation }
+    TSRestType<'a> { span, type_annotation }
+    TSAnyKeyword { span }
+    TSStringKeyword { span }
+    TSBooleanKeyword { span }
+    TSNumberKeyword { span }
+    TSNeverKeyword { span }
+    TSUnknownKeyword { span }
+    TSNullKeyword { span }
+    TSUndefinedKeyword { span }
+    TSVoidKeyword { span }
+    TSSymbolKeyword { span }
+    TSThisKeyword { span }
+    TSObjectKeyword { span }
+    TSBigIntKeyword { span }
+    TSTypeReference<'a> { span, type_name, type_parameters }
+    TSQualifiedName<'a> { span, left, right }
+    TSTypeParameterInstantiation<'a> { span, params }
+    TSTypeParameter<'a> { span, name, constraint, default }
+    TSTypeParameterDeclaration<'a> { span, params }
+    TSTypeAliasDeclaration<'a> { span, id, type_annotation, type_parameters, modifiers }
+    TSAbstractMethodDefinition<'a> { method_definition }
+    TSAbstractPropertyDefinition<'a> { property_definition }
+    TSClassImplements<'a> { span, expression, type_parameters }
+    TSInterfaceDeclaration<'a> { span, id, body, type_parameters, extends, modifiers }
+    TSInterfaceBody<'a> { span, body }
+    TSPropertySignature<'a> { span, key, type_annotation }
+    TSIndexSignature<'a> { span, parameters, type_annotation }
+    TSCallSignatureDeclaration<'a> { span, params, return_type, type_parameters }
+    TSMethodSignature<'a> { span, key, params, return_type, type_parameters }
+    TSConstructSignatureDeclaration<'a> { span, params, return_type, type_parameters }
+    TSIndexSignatureName<'a> { span, type_annotation }
+    TSInterfaceHeritage<'a> { span, expression, type_parameters }
+    TSTypePredicate<'a> { span, parameter_name, type_annotation }
+    TSModuleDeclaration<'a> { span, id, body, modifiers }
+    TSModuleBlock<'a> { span, body }
+    TSTypeLiteral<'a> { span, members }
+    TSInferType<'a> { span, type_parameter }
+    TSTypeQuery<'a> { span, expr_name, type_parameters }
+    TSImportType<'a> { span, parameter, qualifier, type_parameters }
+    TSFunctionType<'a> { span, params, return_type, type_parameters }
+    TSConstructorType<'a> { span, params, return_type, type_parameters }
+    TSMappedType<'a> { span, type_parameter, name_type, type_annotation }
+    TSTemplateLiteralType<'a> { span, quasis, types }
+    TSAsExpression<'a> { span, expression, type_annotation }
+    TSSatisfiesExpression<'a> { span, expression, type_annotation }
+    TSTypeAssertion<'a> { span, expression, type_annotation }
+    TSImportEqualsDeclaration<'a> { span, id, module_reference }
+    TSExternalModuleReference { span, expression }
+    TSNonNullExpression<'a> { span, expression }
+    Decorator<'a> { span, expression }
+    Modifier { span }
+    TSExportAssignment<'a> { span, expression }
+    TSNamespaceExportDeclaration { span, id }
+    TSInstantiationExpression<'a> { span, expression, type_parameters }
+}
+
+shift_enum_spans! {
+    Expression<'a> {
+        BooleanLiteral, NullLiteral, NumberLiteral, BigintLiteral, RegExpLiteral, StringLiteral,
+        TemplateLiteral, Identifier, MetaProperty, Super, ArrayExpression, ArrowExpression,
+        AssignmentExpression, AwaitExpression, BinaryExpression, CallExpression, ChainExpression,
+        ClassExpression, ConditionalExpression, FunctionExpression, ImportExpression,
+        LogicalExpression, MemberExpression, NewExpression, ObjectExpression,
+        ParenthesizedExpression, SequenceExpression, TaggedTemplateExpression, ThisExpression,
+        UnaryExpression, UpdateExpression, YieldExpression, PrivateInExpression,
+        V8IntrinsicExpression, JSXElement, JSXFragment, TSAsExpression, TSSatisfiesExpression,
+        TSTypeAssertion, TSNonNullExpression, TSInstantiationExpression,
+    }
+    ArrayExpressionElement<'a> { SpreadElement, Expression, Elision }
+    ObjectPropertyKind<'a> { ObjectProperty, SpreadProperty }
+    PropertyKey<'a> { Identifier, PrivateIdentifier, Expression }
+    MemberExpression<'a> {
+        ComputedMemberExpression, StaticMemberExpression, PrivateFieldExpression,
+    }
+    Argument<'a> { SpreadElement, Expression }
+    AssignmentTarget<'a> { SimpleAssignmentTarget, AssignmentTargetPattern }
+    SimpleAssignmentTarget<'a> {
+        AssignmentTargetIdentifier, MemberAssignmentTarget, TSAsExpression, TSSatisfiesExpression,
+        TSNonNullExpression, TSTypeAssertion,
+    }
+    AssignmentTargetPattern<'a> { ArrayAssignmentTarget, ObjectAssignmentTarget }
+    AssignmentTargetMaybeDefault<'a> { AssignmentTarget, AssignmentTargetWithDefault }
+    AssignmentTargetProperty<'a> {
+        AssignmentTargetPropertyIdentifier, AssignmentTargetPropertyProperty,
+    }
+    ChainElement<'a> { CallExpression, MemberExpression }
+    Statement<'a> {
+        BlockStatement, BreakStatement, ContinueStatement, DebuggerStatement, DoWhileStatement,
+        EmptyStatement, ExpressionStatement, ForInStatement, ForOfStatement, ForStatement,
+        IfStatement, LabeledStatement, ReturnStatement, SwitchStatement, ThrowStatement,
+        TryStatement, WhileStatement, WithStatement, ModuleDeclaration, Declaration,
+    }
+    Declaration<'a> {
+        VariableDeclaration, FunctionDeclaration, ClassDeclaration, UsingDeclaration,
+        TSTypeAliasDeclaration, TSInterfaceDeclaration, TSEnumDeclaration, TSModuleDeclaration,
+        TSImportEqualsDeclaration,
+    }
+    ForStatementInit<'a> { VariableDeclaration, Expression, UsingDeclaration }
+    ForStatementLeft<'a> { VariableDeclaration, AssignmentTarget, UsingDeclaration }
+    BindingPatternKind<'a> { BindingIdentifier, ObjectPattern, ArrayPattern, AssignmentPattern }
+    ClassElement<'a> {
+        StaticBlock, MethodDefinition, PropertyDefinition, AccessorProperty,
+        TSAbstractMethodDefinition, TSAbstractPropertyDefinition, TSIndexSignature,
+    }
+    ModuleDeclaration<'a> {
+        ImportDeclaration, ExportAllDeclaration, ExportDefaultDeclaration, ExportNamedDeclaration,
+        TSExportAssignment, TSNamespaceExportDeclaration,
+    }
+    ImportDeclarationSpecifier { ImportSpecifier, ImportDefaultSpecifier, ImportNamespaceSpecifier }
+    ImportAttributeKey { Identifier, StringLiteral }
+    ExportDefaultDeclarationKind<'a> {
+        Expression, FunctionDeclaration, ClassDeclaration, TSInterfaceDeclaration,
+        TSEnumDeclaration,
+    }
+    ModuleExportName { Identifier, StringLiteral }
+    JSXElementName<'a> { Identifier, NamespacedName, MemberExpression }
+    JSXMemberExpressionObject<'a> { Identifier, MemberExpression }
+    JSXExpression<'a> { Expression, EmptyExpression }
+    JSXAttributeItem<'a> { Attribute, SpreadAttribute }
+    JSXAttributeName<'a> { Identifier, NamespacedName }
+    JSXAttributeValue<'a> { StringLiteral, ExpressionContainer, Element, Fragment }
+    JSXChild<'a> { Text, Element, Fragment, ExpressionContainer, Spread }
+    TSEnumMemberName<'a> { Identifier, StringLiteral, ComputedPropertyName, NumberLiteral }
+    TSLiteral<'a> {
+        BooleanLiteral, NullLiteral, NumberLiteral, BigintLiteral, RegExpLiteral, StringLiteral,
+        TemplateLiteral, UnaryExpression,
+    }
+    TSType<'a> {
+        TSAnyKeyword, TSBigIntKeyword, TSBooleanKeyword, TSNeverKeyword, TSNullKeyword,
+        TSNumberKeyword, TSObjectKeyword, TSStringKeyword, TSSymbolKeyword, TSThisKeyword,
+        TSUndefinedKeyword, TSUnknownKeyword, TSVoidKeyword, TSArrayType, TSConditionalType,
+        TSConstructorType, TSFunctionType, TSImportType, TSIndexedAccessType, TSInferType,
+        TSIntersectionType, TSLiteralType, TSMappedType, TSQualifiedName, TSTemplateLiteralType,
+        TSTupleType, TSTypeLiteral, TSTypeOperatorType, TSTypePredicate, TSTypeQuery,
+        TSTypeReference, TSUnionType, JSDocNullableType, JSDocUnknownType,
+    }
+    TSTupleElement<'a> { TSType, TSOptionalType, TSRestType, TSNamedTupleMember }
+    TSTypeName<'a> { IdentifierReference, QualifiedName }
+    TSSignature<'a> {
+        TSIndexSignature, TSPropertySignature, TSCallSignatureDeclaration,
+        TSConstructSignatureDeclaration, TSMethodSignature,
+    }
+    TSTypePredicateName { Identifier, This }
+    TSModuleDeclarationName { Identifier, StringLiteral }
+    TSModuleDeclarationBody<'a> { TSModuleDeclaration, TSModuleBlock }
+    TSModuleReference<'a> { TypeName, ExternalModuleReference }
+}
@@ -2,6 +2,7 @@ use std::collections::HashSet;
 
 use oxc_allocator::{Allocator, Vec};
 use oxc_ast::ast::Decorator;
+use oxc_span::Span;
 
 pub struct ParserState<'a> {
     allocator: &'a Allocator,
@@ -23,4 +24,11 @@ impl<'a> ParserState<'a> {
     pub fn consume_decorators(&mut self) -> Vec<'a, Decorator<'a>> {
         std::mem::replace(&mut self.decorators, Vec::new_in(self.allocator))
     }
+
+    /// The span from the first to the last of the eaten decorators
+    pub fn decorators_span(&self) -> Option<Span> {
+        let first = self.decorators.first()?;
+        let last = self.decorators.last()?;
+        Some(Span::new(first.span.start, last.span.end))
+    }
 }
@@ -0,0 +1,186 @@
+//! Standalone tokenization for the tools which do not need an AST
+
+use oxc_allocator::Allocator;
+use oxc_diagnostics::Error;
+use oxc_span::SourceType;
+
+use crate::lexer::{Kind, Lexer, Token, Trivia};
+
+/// A token with the trivia skipped before it
+#[derive(Debug, Clone)]
+pub struct TokenWithTrivia<'a> {
+    pub token: Token<'a>,
+
+    /// The whitespaces, line terminators and comments between the previous token and this one
+    pub leading_trivia: Vec<Trivia>,
+}
+
+impl<'a> TokenWithTrivia<'a> {
+    /// The tokens consumed by the parser from `start` of the source with the trivia between
+    /// them, and the `Eof` token
+    #[allow(clippy::cast_possible_truncation)]
+    pub(crate) fn attach_trivia(
+        allocator: &'a Allocator,
+        source: &'a str,
+        source_type: SourceType,
+        start: u32,
+        tokens: Vec<Token<'a>>,
+    ) -> Vec<Self> {
+        let mut lexer = Lexer::new(allocator, source, source_type);
+        lexer.trivia = Some(vec![]);
+        let end = source.len() as u32;
+        let eof = Token { kind: Kind::Eof, start: end, end, ..Token::default() };
+        let mut prev_end = start;
+        tokens
+            .into_iter()
+            .chain(std::iter::once(eof))
+            .map(|token| {
+                // The trivia are the ones before the next token lexed from the end of the
+                // previous one
+                let leading_trivia = if token.start > prev_end {
+                    lexer.seek(prev_end);
+                    lexer.next_token();
+                    lexer.trivia.replace(vec![]).unwrap_or_default()
+                } else {
+                    vec![]
+                };
+                prev_end = token.end;
+                Self { token, leading_trivia }
+            })
+            .collect()
+    }
+}
+
+/// An iterator over the tokens of a source text, for syntax highlighters, codemods and formatters
+///
+/// The last token is `Kind::Eof`, its leading trivia are the trivia at the end of the source text.
+///
+/// Without a parser, the tokens which depend on the syntactic context are resolved by the
+/// previous token: a `/` starts a regular expression when it cannot be a division, a `}` closes
+/// the substitution of a template literal when it matches its `${`, and the consecutive `>` are
+/// merged into a shift operator. JSX texts are not recognized.
+pub struct Tokens<'a> {
+    lexer: Lexer<'a>,
+
+    prev_kind: Option<Kind>,
+
+    /// The number of unclosed `{` and `${`
+    braces: u32,
+
+    /// The values of `braces` at the `${` of the unclosed template substitutions
+    templates: Vec<u32>,
+
+    done: bool,
+}
+
+impl<'a> Tokens<'a> {
+    pub fn new(allocator: &'a Allocator, source: &'a str, source_type: SourceType) -> Self {
+        let mut lexer = Lexer::new(allocator, source, source_type);
+        lexer.trivia = Some(vec![]);
+        Self { lexer, prev_kind: None, braces: 0, templates: vec![], done: false }
+    }
+
+    /// The syntax errors found by the lexer so far
+    pub fn errors(&self) -> &[Error] {
+        &self.lexer.errors
+    }
+
+    /// Whether the source text has the syntax of the ES modules: an `import` or `export`
+    /// declaration, or `import.meta`
+    ///
+    /// This detects the module kind of the files which may be either scripts or modules, the
+    /// dynamic `import()` is not counted because it is valid in the scripts.
+    pub fn has_module_syntax(self) -> bool {
+        let mut kinds = [None, None];
+        for TokenWithTrivia { token, .. } in self {
+            let [prev2, prev] = kinds;
+            let member = matches!(prev2, Some(Kind::Dot | Kind::QuestionDot));
+            let found = match (prev, token.kind) {
+                // not `import()`, `{ import: a }` nor `import.meta`, which is checked below
+                (Some(Kind::Import), kind) => {
+                    !member && !matches!(kind, Kind::LParen | Kind::Colon | Kind::Dot)
+                }
+                (Some(Kind::Export), kind) => {
+                    !member && !matches!(kind, Kind::LParen | Kind::Colon)
+                }
+                (Some(Kind::Dot), Kind::Meta) => prev2 == Some(Kind::Import),
+                _ => false,
+            };
+            if found {
+                return true;
+            }
+            kinds = [prev, Some(token.kind)];
+        }
+        false
+    }
+
+    fn regex_allowed(&self) -> bool {
+        let Some(kind) = self.prev_kind else { return true };
+        match kind {
+            Kind::RParen
+            | Kind::RBrack
+            | Kind::Plus2
+            | Kind::Minus2
+            | Kind::This
+            | Kind::Super
+            | Kind::NoSubstitutionTemplate
+            | Kind::TemplateTail
+            | Kind::PrivateIdentifier => false,
+            Kind::Await
+            | Kind::Case
+            | Kind::Delete
+            | Kind::Do
+            | Kind::Else
+            | Kind::In
+            | Kind::Instanceof
+            | Kind::New
+            | Kind::Of
+            | Kind::Return
+            | Kind::Throw
+            | Kind::Typeof
+            | Kind::Void
+            | Kind::Yield => true,
+            kind => !kind.is_identifier_name() && !kind.is_literal(),
+        }
+    }
+}
+
+impl<'a> Iterator for Tokens<'a> {
+    type Item = TokenWithTrivia<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let mut token = self.lexer.next_token();
+        let is_on_new_line = token.is_on_new_line;
+        match token.kind {
+            Kind::Slash | Kind::SlashEq if self.regex_allowed() => {
+                token = self.lexer.next_regex(token.kind);
+            }
+            Kind::RAngle => token = self.lexer.next_right_angle(),
+            Kind::RCurly if self.templates.last() == Some(&self.braces) => {
+                token = self.lexer.next_template_substitution_tail();
+            }
+            _ => {}
+        }
+        token.is_on_new_line = is_on_new_line;
+        match token.kind {
+            Kind::LCurly => self.braces += 1,
+            Kind::TemplateHead => {
+                self.braces += 1;
+                self.templates.push(self.braces);
+            }
+            Kind::RCurly => self.braces = self.braces.saturating_sub(1),
+            Kind::TemplateTail => {
+                self.braces -= 1;
+                self.templates.pop();
+            }
+            Kind::Eof => self.done = true,
+            _ => {}
+        }
+        self.prev_kind = Some(token.kind);
+        let leading_trivia = self.lexer.trivia.replace(vec![]).unwrap_or_default();
+        Some(TokenWithTrivia { token, leading_trivia })
+    }
+}
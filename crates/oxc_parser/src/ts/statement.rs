@@ -8,13 +8,14 @@ use super::{
     types::ModifierFlags,
 };
 use crate::{
+    diagnostics,
     js::{
         declaration::{VariableDeclarationContext, VariableDeclarationParent},
         function::FunctionKind,
     },
     lexer::Kind,
     list::{NormalList, SeparatedList},
-    Parser, StatementContext,
+    DecoratorsVersion, Parser, StatementContext,
 };
 
 impl<'a> Parser<'a> {
@@ -394,9 +395,9 @@ impl<'a> Parser<'a> {
         is_export: bool,
     ) -> Result<Declaration<'a>> {
         let import_kind = if !self.peek_at(Kind::Eq) && self.eat(Kind::Type) {
-            ImportOrExportKind::Value
-        } else {
             ImportOrExportKind::Type
+        } else {
+            ImportOrExportKind::Value
         };
 
         let id = self.parse_binding_identifier()?;
@@ -457,6 +458,16 @@ impl<'a> Parser<'a> {
         Ok(())
     }
 
+    /// Report the eaten decorators, on a class expression or after `export`,
+    /// which the legacy decorators do not support
+    pub(crate) fn check_legacy_decorators(&mut self) {
+        if self.decorators == Some(DecoratorsVersion::Legacy) {
+            if let Some(span) = self.state.decorators_span() {
+                self.error(diagnostics::LegacyDecorators(span));
+            }
+        }
+    }
+
     pub(crate) fn eat_modifiers_before_declaration(&mut self) -> (ModifierFlags, Modifiers<'a>) {
         let mut flags = ModifierFlags::empty();
         let mut modifiers = self.ast.new_vec();
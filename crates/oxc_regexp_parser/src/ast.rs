@@ -0,0 +1,245 @@
+//! AST of the regular expression patterns
+//!
+//! The spans are the byte offsets in the pattern, without the `/` of the literal.
+
+use oxc_ast::ast::RegExpFlags;
+use oxc_span::Span;
+
+/// The whole pattern, e.g. `a|b` in `/a|b/`
+#[derive(Debug)]
+pub struct Pattern {
+    pub span: Span,
+    pub alternatives: Vec<Alternative>,
+    /// The number of the capturing groups
+    pub capturing_groups: u32,
+}
+
+/// One of the alternatives of a disjunction, e.g. `ab` in `ab|c`
+#[derive(Debug)]
+pub struct Alternative {
+    pub span: Span,
+    pub terms: Vec<Term>,
+}
+
+#[derive(Debug)]
+pub enum Term {
+    /// `^`, `$`, `\b`, `\B`
+    Assertion(Assertion),
+    /// `(?=a)`, `(?!a)`, `(?<=a)`, `(?<!a)`
+    LookAround(Box<LookAround>),
+    /// `a*`, `a+?`, `a{1,2}`
+    Quantifier(Box<Quantifier>),
+    /// `a`, `\n`, `\u0061`
+    Character(Character),
+    /// `.`
+    Dot(Span),
+    /// `\d`, `\W`
+    CharacterClassEscape(CharacterClassEscape),
+    /// `\p{L}`, `\P{Script=Greek}`
+    UnicodePropertyEscape(Box<UnicodePropertyEscape>),
+    /// `[a-z]`
+    CharacterClass(Box<CharacterClass>),
+    /// `(a)`, `(?<name>a)`
+    CapturingGroup(Box<CapturingGroup>),
+    /// `(?:a)`, `(?i:a)`
+    Group(Box<Group>),
+    /// `\1`, `\k<name>`
+    Backreference(Backreference),
+}
+
+impl Term {
+    pub fn span(&self) -> Span {
+        match self {
+            Self::Assertion(assertion) => assertion.span,
+            Self::LookAround(look_around) => look_around.span,
+            Self::Quantifier(quantifier) => quantifier.span,
+            Self::Character(character) => character.span,
+            Self::Dot(span) => *span,
+            Self::CharacterClassEscape(escape) => escape.span,
+            Self::UnicodePropertyEscape(escape) => escape.span,
+            Self::CharacterClass(class) => class.span,
+            Self::CapturingGroup(group) => group.span,
+            Self::Group(group) => group.span,
+            Self::Backreference(reference) => reference.span,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Assertion {
+    pub span: Span,
+    pub kind: AssertionKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AssertionKind {
+    /// `^`
+    Start,
+    /// `$`
+    End,
+    /// `\b`
+    WordBoundary,
+    /// `\B`
+    NegatedWordBoundary,
+}
+
+#[derive(Debug)]
+pub struct LookAround {
+    pub span: Span,
+    pub kind: LookAroundKind,
+    pub alternatives: Vec<Alternative>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LookAroundKind {
+    /// `(?=a)`
+    Lookahead,
+    /// `(?!a)`
+    NegativeLookahead,
+    /// `(?<=a)`
+    Lookbehind,
+    /// `(?<!a)`
+    NegativeLookbehind,
+}
+
+impl LookAroundKind {
+    pub fn is_lookbehind(self) -> bool {
+        matches!(self, Self::Lookbehind | Self::NegativeLookbehind)
+    }
+}
+
+#[derive(Debug)]
+pub struct Quantifier {
+    pub span: Span,
+    pub min: u32,
+    /// `None` for the unbounded quantifiers, e.g. `*` or `{2,}`
+    pub max: Option<u32>,
+    /// `false` for the lazy quantifiers, e.g. `*?`
+    pub greedy: bool,
+    pub term: Term,
+}
+
+/// A single character, `value` is its code point
+#[derive(Debug, Clone, Copy)]
+pub struct Character {
+    pub span: Span,
+    pub value: u32,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct CharacterClassEscape {
+    pub span: Span,
+    pub kind: CharacterClassEscapeKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CharacterClassEscapeKind {
+    /// `\d`
+    D,
+    /// `\D`
+    NegativeD,
+    /// `\s`
+    S,
+    /// `\S`
+    NegativeS,
+    /// `\w`
+    W,
+    /// `\W`
+    NegativeW,
+}
+
+#[derive(Debug)]
+pub struct UnicodePropertyEscape {
+    pub span: Span,
+    /// `\P`
+    pub negative: bool,
+    /// `Script` in `\p{Script=Greek}`, or the property alone, e.g. `L` in `\p{L}`
+    pub name: String,
+    /// `Greek` in `\p{Script=Greek}`
+    pub value: Option<String>,
+    /// A property of the strings, e.g. `\p{RGI_Emoji}` with the `v` flag
+    pub strings: bool,
+}
+
+#[derive(Debug)]
+pub struct CharacterClass {
+    pub span: Span,
+    /// `[^a]`
+    pub negative: bool,
+    /// The set operation of the elements with the `v` flag, e.g. `[\w--\d]`
+    pub kind: CharacterClassKind,
+    pub elements: Vec<ClassElement>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CharacterClassKind {
+    Union,
+    /// `[a&&b]`
+    Intersection,
+    /// `[a--b]`
+    Subtraction,
+}
+
+#[derive(Debug)]
+pub enum ClassElement {
+    Character(Character),
+    /// `a-z`
+    Range(ClassRange),
+    CharacterClassEscape(CharacterClassEscape),
+    UnicodePropertyEscape(Box<UnicodePropertyEscape>),
+    /// `[a[b]]` with the `v` flag
+    NestedClass(Box<CharacterClass>),
+    /// `\q{abc|def}` with the `v` flag
+    ClassStrings(ClassStrings),
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ClassRange {
+    pub span: Span,
+    pub min: Character,
+    pub max: Character,
+}
+
+#[derive(Debug)]
+pub struct ClassStrings {
+    pub span: Span,
+    pub strings: Vec<Vec<Character>>,
+}
+
+#[derive(Debug)]
+pub struct CapturingGroup {
+    pub span: Span,
+    /// The index of the group, from 1 in the order of their `(`
+    pub index: u32,
+    pub name: Option<String>,
+    pub alternatives: Vec<Alternative>,
+}
+
+#[derive(Debug)]
+pub struct Group {
+    pub span: Span,
+    /// `(?i-m:a)`
+    pub modifiers: Option<Modifiers>,
+    pub alternatives: Vec<Alternative>,
+}
+
+/// The flags enabled and disabled in a group, `i`, `m` or `s`
+#[derive(Debug, Clone, Copy)]
+pub struct Modifiers {
+    pub enabling: RegExpFlags,
+    pub disabling: RegExpFlags,
+}
+
+#[derive(Debug, Clone)]
+pub struct Backreference {
+    pub span: Span,
+    pub kind: BackreferenceKind,
+}
+
+#[derive(Debug, Clone)]
+pub enum BackreferenceKind {
+    /// `\1`
+    Index(u32),
+    /// `\k<name>`
+    Named(String),
+}
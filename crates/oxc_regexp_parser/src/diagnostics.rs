@@ -0,0 +1,11 @@
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_span::Span;
+
+/// A syntax error of a regular expression, the span is in the pattern or the flags
+#[derive(Debug, Clone, Error, Diagnostic)]
+#[error("Invalid regular expression: {0}")]
+#[diagnostic()]
+pub struct RegExpError(pub &'static str, #[label] pub Span);
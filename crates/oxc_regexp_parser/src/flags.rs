@@ -0,0 +1,46 @@
+use oxc_ast::ast::RegExpFlags;
+use oxc_span::Span;
+
+use crate::{diagnostics::RegExpError, EcmaVersion};
+
+/// Parse the flags of a regular expression, e.g. the second argument of `new RegExp()`
+///
+/// # Errors
+///
+/// An unknown, repeated or too recent flag, or both `u` and `v`.
+pub fn parse_flags(text: &str, ecma_version: EcmaVersion) -> Result<RegExpFlags, RegExpError> {
+    let mut flags = RegExpFlags::empty();
+    for (start, c) in text.char_indices() {
+        #[allow(clippy::cast_possible_truncation)]
+        let span = Span::new(start as u32, (start + c.len_utf8()) as u32);
+        let flag = RegExpFlags::try_from(c).map_err(|_| RegExpError("Invalid flag", span))?;
+        if flags.contains(flag) {
+            return Err(RegExpError("Duplicate flag", span));
+        }
+        if ecma_version < flag_version(flag) {
+            return Err(RegExpError("Flag not supported by the ECMAScript version", span));
+        }
+        flags |= flag;
+    }
+    if flags.contains(RegExpFlags::U | RegExpFlags::V) {
+        #[allow(clippy::cast_possible_truncation)]
+        let span = Span::new(0, text.len() as u32);
+        return Err(RegExpError("The 'u' and 'v' flags cannot be enabled at the same time", span));
+    }
+    Ok(flags)
+}
+
+/// The version which added the flag
+pub fn flag_version(flag: RegExpFlags) -> EcmaVersion {
+    if flag == RegExpFlags::U || flag == RegExpFlags::Y {
+        EcmaVersion::ES2015
+    } else if flag == RegExpFlags::S {
+        EcmaVersion::ES2018
+    } else if flag == RegExpFlags::D {
+        EcmaVersion::ES2022
+    } else if flag == RegExpFlags::V {
+        EcmaVersion::ES2024
+    } else {
+        EcmaVersion::ES5
+    }
+}
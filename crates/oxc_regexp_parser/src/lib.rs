@@ -0,0 +1,154 @@
+//! Parser and validator of the ECMAScript regular expressions
+//!
+//! <https://tc39.es/ecma262/#sec-regexp-regular-expression-objects>
+
+pub mod ast;
+mod diagnostics;
+mod flags;
+mod options;
+mod parser;
+mod unicode_property;
+mod visit;
+
+pub use crate::{
+    diagnostics::RegExpError,
+    flags::parse_flags,
+    options::{EcmaVersion, ParserOptions},
+    parser::Parser,
+    visit::Visit,
+};
+
+#[cfg(test)]
+mod test {
+    use oxc_ast::ast::RegExpFlags;
+
+    use crate::{ast::*, parse_flags, EcmaVersion, Parser, ParserOptions};
+
+    fn parse(pattern: &str, flags: &str) -> Result<Pattern, &'static str> {
+        let flags = parse_flags(flags, EcmaVersion::default()).unwrap();
+        Parser::new(pattern, ParserOptions::new(flags)).parse().map_err(|error| error.0)
+    }
+
+    #[test]
+    fn valid() {
+        let pass = [
+            ("", ""),
+            ("a|b|", ""),
+            ("^a*?b+c?d{1}e{1,}f{1,2}$", ""),
+            ("(a)(?:b)(?<c>d)\\1\\k<c>", ""),
+            ("(?=a)(?!b)(?<=c)(?<!d)", ""),
+            ("[a-z\\d\\-]", ""),
+            ("[\\w-a]", ""),
+            ("\\p{L}\\P{Script=Greek}\\p{gc=Lu}", "u"),
+            ("\\u{1F600}\\uD83D\\uDE00", "u"),
+            ("(?<$𝒜>a)\\k<$𝒜>", "u"),
+            ("(?<\\u{61}>a)\\k<a>", "u"),
+            ("(?i:a)(?-m:b)(?s-i:c)", ""),
+            ("(?<a>x)|(?<a>y)", ""),
+            ("[\\w--\\d][a&&[b]][\\q{abc|d}]", "v"),
+            ("\\p{RGI_Emoji}[\\p{Basic_Emoji}]", "v"),
+            // Annex B
+            ("a{", ""),
+            ("a{1", ""),
+            ("}]", ""),
+            ("\\1", ""),
+            ("\\8\\00\\377", ""),
+            ("\\k", ""),
+            ("\\c", ""),
+            ("\\x1\\u12\\a", ""),
+            ("(?=a)*", ""),
+            ("[\\c_]", ""),
+        ];
+        for (pattern, flags) in pass {
+            assert!(parse(pattern, flags).is_ok(), "/{pattern}/{flags}");
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        let fail = [
+            ("a**", "", "Nothing to repeat"),
+            ("{1}", "", "Nothing to repeat"),
+            ("^*", "", "Nothing to repeat"),
+            ("a{2,1}", "", "Numbers out of order in {} quantifier"),
+            ("(a", "", "Unterminated group"),
+            ("a)", "", "Unmatched ')'"),
+            ("[a", "", "Unterminated character class"),
+            ("[z-a]", "", "Range out of order in character class"),
+            ("\\", "", "\\ at end of pattern"),
+            ("(?<1>a)", "", "Invalid capture group name"),
+            ("(?<a>x)(?<a>y)", "", "Duplicate capture group name"),
+            ("(?<a>x)|((?<a>y)(?<a>z))", "", "Duplicate capture group name"),
+            ("(?<a>x)\\k<b>", "", "Invalid named capture referenced"),
+            ("(?ii:a)", "", "Repeated flag in modifiers"),
+            ("(?i-i:a)", "", "Repeated flag in modifiers"),
+            ("(?-:a)", "", "Invalid group"),
+            ("a{", "u", "Lone quantifier brackets"),
+            ("]", "u", "Lone quantifier brackets"),
+            ("\\1", "u", "Invalid escape"),
+            ("\\a", "u", "Invalid escape"),
+            ("\\k", "u", "Invalid named reference"),
+            ("\\u{110000}", "u", "Invalid unicode escape"),
+            ("[\\w-a]", "u", "Invalid character class"),
+            ("(?=a)*", "u", "Nothing to repeat"),
+            ("\\p{Foo}", "u", "Invalid property name"),
+            ("\\p{RGI_Emoji}", "u", "Invalid property name"),
+            ("\\P{RGI_Emoji}", "v", "Negated character class may contain strings"),
+            ("[^\\q{ab}]", "v", "Negated character class may contain strings"),
+            ("[a&&b--c]", "v", "Invalid set operation in character class"),
+            ("[ab&&c]", "v", "Invalid set operation in character class"),
+            ("[a!!b]", "v", "Invalid set operation in character class"),
+            ("[(]", "v", "Invalid character in character class"),
+        ];
+        for (pattern, flags, message) in fail {
+            assert_eq!(parse(pattern, flags).err(), Some(message), "/{pattern}/{flags}");
+        }
+    }
+
+    #[test]
+    fn ecma_version() {
+        let parse = |pattern: &str, flags: RegExpFlags, ecma_version: EcmaVersion| {
+            let options = ParserOptions::new(flags).with_ecma_version(ecma_version);
+            Parser::new(pattern, options).parse().map_err(|error| error.0)
+        };
+        let error = Some("Syntax not supported by the ECMAScript version");
+        assert!(parse("(?<a>b)", RegExpFlags::empty(), EcmaVersion::ES2015).is_err());
+        assert!(parse("(?<a>b)", RegExpFlags::empty(), EcmaVersion::ES2018).is_ok());
+        assert_eq!(parse("(?<=a)", RegExpFlags::empty(), EcmaVersion::ES2015).err(), error);
+        assert_eq!(parse("(?i:a)", RegExpFlags::empty(), EcmaVersion::ES2024).err(), error);
+        assert_eq!(
+            parse("(?<a>x)|(?<a>y)", RegExpFlags::empty(), EcmaVersion::ES2024).err(),
+            Some("Duplicate capture group name")
+        );
+        assert_eq!(
+            parse("a", RegExpFlags::V, EcmaVersion::ES2022).err(),
+            Some("Flag not supported by the ECMAScript version")
+        );
+
+        assert!(parse_flags("dgimsuy", EcmaVersion::ES2022).is_ok());
+        assert!(parse_flags("v", EcmaVersion::ES2022).is_err());
+        assert_eq!(parse_flags("gg", EcmaVersion::ES2025).map_err(|e| e.0), Err("Duplicate flag"));
+        assert_eq!(parse_flags("x", EcmaVersion::ES2025).map_err(|e| e.0), Err("Invalid flag"));
+        assert!(parse_flags("uv", EcmaVersion::ES2025).is_err());
+    }
+
+    #[test]
+    fn ast() {
+        let pattern = parse("(a)|(?<b>[^c-e]{2,})", "").unwrap();
+        assert_eq!(pattern.capturing_groups, 2);
+        assert_eq!(pattern.alternatives.len(), 2);
+        let Term::CapturingGroup(group) = &pattern.alternatives[1].terms[0] else { panic!() };
+        assert_eq!((group.index, group.name.as_deref()), (2, Some("b")));
+        let Term::Quantifier(quantifier) = &group.alternatives[0].terms[0] else { panic!() };
+        assert_eq!((quantifier.min, quantifier.max, quantifier.greedy), (2, None, true));
+        let Term::CharacterClass(class) = &quantifier.term else { panic!() };
+        assert!(class.negative);
+        let ClassElement::Range(range) = &class.elements[0] else { panic!() };
+        assert_eq!((range.min.value, range.max.value), (u32::from('c'), u32::from('e')));
+        assert_eq!((quantifier.span.start, quantifier.span.end), (9, 19));
+
+        let pattern = parse("\\uD83D\\uDE00", "u").unwrap();
+        let Term::Character(character) = &pattern.alternatives[0].terms[0] else { panic!() };
+        assert_eq!(character.value, 0x1F600);
+    }
+}
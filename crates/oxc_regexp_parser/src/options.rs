@@ -0,0 +1,60 @@
+use oxc_ast::ast::RegExpFlags;
+
+/// The ECMAScript versions which added syntax to the regular expressions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum EcmaVersion {
+    ES5,
+    /// The `u` and `y` flags
+    ES2015,
+    /// The `s` flag, the named capturing groups, the lookbehind assertions and the Unicode
+    /// property escapes
+    ES2018,
+    /// The `d` flag
+    ES2022,
+    /// The `v` flag
+    ES2024,
+    /// The duplicate named capturing groups and the modifiers
+    ES2025,
+}
+
+impl Default for EcmaVersion {
+    fn default() -> Self {
+        Self::ES2025
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ParserOptions {
+    /// The flags of the regular expression, the `u` and `v` flags change the syntax
+    pub flags: RegExpFlags,
+    /// The syntax added after this version is an error
+    pub ecma_version: EcmaVersion,
+}
+
+impl Default for ParserOptions {
+    fn default() -> Self {
+        Self::new(RegExpFlags::empty())
+    }
+}
+
+impl ParserOptions {
+    pub fn new(flags: RegExpFlags) -> Self {
+        Self { flags, ecma_version: EcmaVersion::default() }
+    }
+
+    #[must_use]
+    pub fn with_ecma_version(mut self, ecma_version: EcmaVersion) -> Self {
+        self.ecma_version = ecma_version;
+        self
+    }
+
+    /// The `u` or `v` flag
+    pub fn unicode_mode(&self) -> bool {
+        self.flags.intersects(RegExpFlags::U | RegExpFlags::V)
+    }
+
+    /// The `v` flag
+    pub fn unicode_sets_mode(&self) -> bool {
+        self.flags.contains(RegExpFlags::V)
+    }
+}
@@ -0,0 +1,934 @@
+//! Recursive descent parser of the patterns
+//!
+//! <https://tc39.es/ecma262/#sec-patterns>, with the syntax of
+//! [Annex B](https://tc39.es/ecma262/#sec-regular-expressions-patterns) outside of the unicode mode.
+
+use oxc_ast::ast::RegExpFlags;
+use oxc_span::Span;
+use oxc_syntax::identifier::{is_identifier_part, is_identifier_start_all};
+
+use crate::{
+    ast::{
+        Alternative, Assertion, AssertionKind, Backreference, BackreferenceKind, CapturingGroup,
+        Character, CharacterClass, CharacterClassEscape, CharacterClassEscapeKind,
+        CharacterClassKind, ClassElement, ClassRange, ClassStrings, Group, LookAround,
+        LookAroundKind, Modifiers, Pattern, Quantifier, Term, UnicodePropertyEscape,
+    },
+    diagnostics::RegExpError,
+    flags::flag_version,
+    unicode_property, EcmaVersion, ParserOptions,
+};
+
+type Result<T> = std::result::Result<T, RegExpError>;
+
+/// The characters escaped by `\` in the unicode mode
+const SYNTAX_CHARACTERS: &str = "^$\\.*+?()[]{}|/";
+
+/// The characters which are only valid escaped in a class with the `v` flag
+const CLASS_SET_SYNTAX_CHARACTERS: &str = "()[]{}/-|";
+
+/// The characters which can be escaped in a class with the `v` flag
+const CLASS_SET_RESERVED_PUNCTUATORS: &str = "&-!#%,:;<=>@`~";
+
+/// The characters which can not be doubled in a class with the `v` flag, e.g. `!!`
+const CLASS_SET_RESERVED_DOUBLE_PUNCTUATORS: &str = "&!#$%*+,.:;<=>?@^`~";
+
+pub struct Parser<'a> {
+    source: &'a str,
+    options: ParserOptions,
+    /// The byte offset of the next character
+    index: usize,
+    /// The number of the capturing groups of the whole pattern, and whether some are named,
+    /// the backreferences can precede their groups
+    capturing_groups: u32,
+    has_named_groups: bool,
+    /// The names of the groups, and the named backreferences to check once they are all known
+    group_names: Vec<String>,
+    named_references: Vec<(String, Span)>,
+    /// The index of the next capturing group
+    next_group: u32,
+    /// The names of the current and the previous alternatives of each disjunction being parsed,
+    /// to find the duplicate names
+    names: Vec<(Vec<String>, Vec<String>)>,
+}
+
+impl<'a> Parser<'a> {
+    pub fn new(source: &'a str, options: ParserOptions) -> Self {
+        Self {
+            source,
+            options,
+            index: 0,
+            capturing_groups: 0,
+            has_named_groups: false,
+            group_names: vec![],
+            named_references: vec![],
+            next_group: 1,
+            names: vec![],
+        }
+    }
+
+    /// Parse the pattern
+    ///
+    /// # Errors
+    ///
+    /// The first syntax error of the pattern.
+    pub fn parse(mut self) -> Result<Pattern> {
+        for flag in self.options.flags.iter() {
+            if self.options.ecma_version < flag_version(flag) {
+                let span = self.span_from(self.source.len());
+                return Err(RegExpError("Flag not supported by the ECMAScript version", span));
+            }
+        }
+        (self.capturing_groups, self.has_named_groups) =
+            scan_capturing_groups(self.source, self.options.unicode_sets_mode());
+
+        let alternatives = self.parse_disjunction()?;
+        if self.index < self.source.len() {
+            return Err(RegExpError("Unmatched ')'", self.span_from(self.index)));
+        }
+        for (name, span) in &self.named_references {
+            if !self.group_names.contains(name) {
+                return Err(RegExpError("Invalid named capture referenced", *span));
+            }
+        }
+        Ok(Pattern {
+            span: self.span_from(0),
+            alternatives,
+            capturing_groups: self.capturing_groups,
+        })
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.source[self.index..].chars().next()
+    }
+
+    fn peek_nth(&self, n: usize) -> Option<char> {
+        self.source[self.index..].chars().nth(n)
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.index += c.len_utf8();
+        Some(c)
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        let eaten = self.peek() == Some(c);
+        if eaten {
+            self.index += c.len_utf8();
+        }
+        eaten
+    }
+
+    fn eat_str(&mut self, s: &str) -> bool {
+        let eaten = self.source[self.index..].starts_with(s);
+        if eaten {
+            self.index += s.len();
+        }
+        eaten
+    }
+
+    /// The span from `start` to the next character, or to the following one when it is empty
+    #[allow(clippy::cast_possible_truncation)]
+    fn span_from(&self, start: usize) -> Span {
+        let end = if start == self.index {
+            self.peek().map_or(self.index, |c| self.index + c.len_utf8())
+        } else {
+            self.index
+        };
+        Span::new(start as u32, end as u32)
+    }
+
+    fn character(&self, start: usize, value: u32) -> Character {
+        Character { span: self.span_from(start), value }
+    }
+
+    fn check_version(&self, version: EcmaVersion, start: usize) -> Result<()> {
+        if self.options.ecma_version < version {
+            return Err(RegExpError(
+                "Syntax not supported by the ECMAScript version",
+                self.span_from(start),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Disjunction :: Alternative | Alternative `|` Disjunction
+    fn parse_disjunction(&mut self) -> Result<Vec<Alternative>> {
+        self.names.push((vec![], vec![]));
+        let mut alternatives = vec![self.parse_alternative()?];
+        while self.eat('|') {
+            let (current, previous) = self.names.last_mut().unwrap();
+            previous.append(current);
+            alternatives.push(self.parse_alternative()?);
+        }
+        let (current, mut names) = self.names.pop().unwrap();
+        names.extend(current);
+        if let Some((parent, _)) = self.names.last_mut() {
+            parent.extend(names);
+        }
+        Ok(alternatives)
+    }
+
+    fn parse_alternative(&mut self) -> Result<Alternative> {
+        let start = self.index;
+        let mut terms = vec![];
+        while !matches!(self.peek(), None | Some('|' | ')')) {
+            terms.push(self.parse_term()?);
+        }
+        Ok(Alternative { span: self.span_from(start), terms })
+    }
+
+    /// Term :: Assertion | Atom Quantifier?
+    fn parse_term(&mut self) -> Result<Term> {
+        let start = self.index;
+        let (term, quantifiable) = self.parse_atom()?;
+        let quantifier_start = self.index;
+        let Some((min, max)) = self.parse_quantifier()? else { return Ok(term) };
+        if !quantifiable {
+            return Err(RegExpError("Nothing to repeat", self.span_from(quantifier_start)));
+        }
+        let greedy = !self.eat('?');
+        Ok(Term::Quantifier(Box::new(Quantifier {
+            span: self.span_from(start),
+            min,
+            max,
+            greedy,
+            term,
+        })))
+    }
+
+    /// `*`, `+`, `?`, `{n}`, `{n,}`, `{n,m}`
+    fn parse_quantifier(&mut self) -> Result<Option<(u32, Option<u32>)>> {
+        let start = self.index;
+        let quantifier = match self.peek() {
+            Some('*') => (0, None),
+            Some('+') => (1, None),
+            Some('?') => (0, Some(1)),
+            Some('{') => {
+                let Some((min, max)) = self.parse_braced_quantifier() else { return Ok(None) };
+                if max.is_some_and(|max| max < min) {
+                    return Err(RegExpError(
+                        "Numbers out of order in {} quantifier",
+                        self.span_from(start),
+                    ));
+                }
+                return Ok(Some((min, max)));
+            }
+            _ => return Ok(None),
+        };
+        self.bump();
+        Ok(Some(quantifier))
+    }
+
+    /// `{n}`, `{n,}` or `{n,m}`, the index is restored when it is not one of them
+    fn parse_braced_quantifier(&mut self) -> Option<(u32, Option<u32>)> {
+        let start = self.index;
+        let quantifier = (|| {
+            self.eat('{').then_some(())?;
+            let min = self.parse_decimal()?;
+            let max = if self.eat(',') { self.parse_decimal() } else { Some(min) };
+            self.eat('}').then_some((min, max))
+        })();
+        if quantifier.is_none() {
+            self.index = start;
+        }
+        quantifier
+    }
+
+    fn parse_decimal(&mut self) -> Option<u32> {
+        let mut value: Option<u32> = None;
+        while let Some(digit) = self.peek().and_then(|c| c.to_digit(10)) {
+            self.bump();
+            value = Some(value.unwrap_or(0).saturating_mul(10).saturating_add(digit));
+        }
+        value
+    }
+
+    /// The atom, and whether it can be quantified
+    fn parse_atom(&mut self) -> Result<(Term, bool)> {
+        let start = self.index;
+        let Some(c) = self.bump() else { unreachable!() };
+        let term = match c {
+            '^' => {
+                let span = self.span_from(start);
+                return Ok((
+                    Term::Assertion(Assertion { span, kind: AssertionKind::Start }),
+                    false,
+                ));
+            }
+            '$' => {
+                let span = self.span_from(start);
+                return Ok((Term::Assertion(Assertion { span, kind: AssertionKind::End }), false));
+            }
+            '.' => Term::Dot(self.span_from(start)),
+            '(' => return self.parse_group(start),
+            '[' => Term::CharacterClass(Box::new(self.parse_character_class(start)?)),
+            '\\' => return self.parse_atom_escape(start),
+            '*' | '+' | '?' => return Err(RegExpError("Nothing to repeat", self.span_from(start))),
+            '{' if self.options.unicode_mode() => {
+                return Err(RegExpError("Lone quantifier brackets", self.span_from(start)));
+            }
+            '{' => {
+                // Annex B: `{` is a character unless it starts a quantifier.
+                self.index = start;
+                if self.parse_braced_quantifier().is_some() {
+                    return Err(RegExpError("Nothing to repeat", self.span_from(start)));
+                }
+                self.index = start + 1;
+                Term::Character(self.character(start, u32::from(c)))
+            }
+            '}' | ']' if self.options.unicode_mode() => {
+                return Err(RegExpError("Lone quantifier brackets", self.span_from(start)));
+            }
+            _ => Term::Character(self.character(start, u32::from(c))),
+        };
+        Ok((term, true))
+    }
+
+    /// The groups, the capturing groups and the lookarounds, after `(`
+    fn parse_group(&mut self, start: usize) -> Result<(Term, bool)> {
+        if !self.eat('?') {
+            let index = self.next_group;
+            self.next_group += 1;
+            let alternatives = self.parse_group_body(start)?;
+            let span = self.span_from(start);
+            let group = CapturingGroup { span, index, name: None, alternatives };
+            return Ok((Term::CapturingGroup(Box::new(group)), true));
+        }
+
+        let look_around = if self.eat('=') {
+            Some(LookAroundKind::Lookahead)
+        } else if self.eat('!') {
+            Some(LookAroundKind::NegativeLookahead)
+        } else if self.eat_str("<=") {
+            Some(LookAroundKind::Lookbehind)
+        } else if self.eat_str("<!") {
+            Some(LookAroundKind::NegativeLookbehind)
+        } else {
+            None
+        };
+        if let Some(kind) = look_around {
+            if kind.is_lookbehind() {
+                self.check_version(EcmaVersion::ES2018, start)?;
+            }
+            let alternatives = self.parse_group_body(start)?;
+            let span = self.span_from(start);
+            // Annex B: the lookaheads can be quantified outside of the unicode mode.
+            let quantifiable = !kind.is_lookbehind() && !self.options.unicode_mode();
+            let look_around = LookAround { span, kind, alternatives };
+            return Ok((Term::LookAround(Box::new(look_around)), quantifiable));
+        }
+
+        if self.eat('<') {
+            self.check_version(EcmaVersion::ES2018, start)?;
+            let index = self.next_group;
+            self.next_group += 1;
+            let name_start = self.index;
+            let name = self.parse_group_name()?;
+            let duplicate = if self.options.ecma_version < EcmaVersion::ES2025 {
+                self.names
+                    .iter()
+                    .any(|(current, previous)| current.contains(&name) || previous.contains(&name))
+            } else {
+                self.names.iter().any(|(current, _)| current.contains(&name))
+            };
+            if duplicate {
+                return Err(RegExpError(
+                    "Duplicate capture group name",
+                    self.span_from(name_start),
+                ));
+            }
+            self.names.last_mut().unwrap().0.push(name.clone());
+            self.group_names.push(name.clone());
+            let alternatives = self.parse_group_body(start)?;
+            let span = self.span_from(start);
+            let group = CapturingGroup { span, index, name: Some(name), alternatives };
+            return Ok((Term::CapturingGroup(Box::new(group)), true));
+        }
+
+        let modifiers = if self.eat(':') {
+            None
+        } else {
+            let modifiers_start = self.index;
+            let enabling = self.parse_modifier_flags()?;
+            let disabling = if self.eat('-') {
+                let disabling = self.parse_modifier_flags()?;
+                if enabling.is_empty() && disabling.is_empty() {
+                    return Err(RegExpError("Invalid group", self.span_from(start)));
+                }
+                disabling
+            } else {
+                RegExpFlags::empty()
+            };
+            if !self.eat(':') || (enabling.is_empty() && disabling.is_empty()) {
+                return Err(RegExpError("Invalid group", self.span_from(start)));
+            }
+            if enabling.intersects(disabling) {
+                return Err(RegExpError(
+                    "Repeated flag in modifiers",
+                    self.span_from(modifiers_start),
+                ));
+            }
+            self.check_version(EcmaVersion::ES2025, start)?;
+            Some(Modifiers { enabling, disabling })
+        };
+        let alternatives = self.parse_group_body(start)?;
+        let span = self.span_from(start);
+        Ok((Term::Group(Box::new(Group { span, modifiers, alternatives })), true))
+    }
+
+    fn parse_group_body(&mut self, start: usize) -> Result<Vec<Alternative>> {
+        let alternatives = self.parse_disjunction()?;
+        if !self.eat(')') {
+            return Err(RegExpError("Unterminated group", self.span_from(start)));
+        }
+        Ok(alternatives)
+    }
+
+    /// The `i`, `m` and `s` flags of the modifiers, e.g. `im` in `(?im-s:a)`
+    fn parse_modifier_flags(&mut self) -> Result<RegExpFlags> {
+        let mut flags = RegExpFlags::empty();
+        while let Some(c @ ('i' | 'm' | 's')) = self.peek() {
+            let start = self.index;
+            self.bump();
+            let flag = RegExpFlags::try_from(c).unwrap();
+            if flags.contains(flag) {
+                return Err(RegExpError("Repeated flag in modifiers", self.span_from(start)));
+            }
+            flags |= flag;
+        }
+        Ok(flags)
+    }
+
+    /// GroupName :: `<` RegExpIdentifierName `>`, after `<`
+    fn parse_group_name(&mut self) -> Result<String> {
+        let start = self.index;
+        let mut name = String::new();
+        loop {
+            let c = match self.bump() {
+                Some('>') if !name.is_empty() => return Ok(name),
+                Some('\\') if self.eat('u') => {
+                    self.parse_unicode_escape(true).and_then(char::from_u32)
+                }
+                c => c,
+            };
+            let valid = c.filter(|c| {
+                if name.is_empty() {
+                    is_identifier_start_all(*c)
+                } else {
+                    is_identifier_part(*c)
+                }
+            });
+            let Some(c) = valid else {
+                return Err(RegExpError("Invalid capture group name", self.span_from(start)));
+            };
+            name.push(c);
+        }
+    }
+
+    /// AtomEscape, after `\`
+    fn parse_atom_escape(&mut self, start: usize) -> Result<(Term, bool)> {
+        let unicode_mode = self.options.unicode_mode();
+        let term = match self.peek() {
+            None => return Err(RegExpError("\\ at end of pattern", self.span_from(start))),
+            Some(c @ ('b' | 'B')) => {
+                self.bump();
+                let kind = if c == 'b' {
+                    AssertionKind::WordBoundary
+                } else {
+                    AssertionKind::NegatedWordBoundary
+                };
+                let span = self.span_from(start);
+                return Ok((Term::Assertion(Assertion { span, kind }), false));
+            }
+            Some('1'..='9') => {
+                let digits_start = self.index;
+                let index = self.parse_decimal().unwrap_or_default();
+                if index <= self.capturing_groups {
+                    let span = self.span_from(start);
+                    let kind = BackreferenceKind::Index(index);
+                    Term::Backreference(Backreference { span, kind })
+                } else if unicode_mode {
+                    return Err(RegExpError("Invalid escape", self.span_from(start)));
+                } else {
+                    // Annex B: a legacy octal escape, or the identity escape of `8` or `9`.
+                    self.index = digits_start;
+                    let value = self.parse_character_escape(start, false)?;
+                    Term::Character(self.character(start, value))
+                }
+            }
+            Some('k') if unicode_mode || self.has_named_groups => {
+                self.bump();
+                let name_start = self.index;
+                if !self.eat('<') {
+                    return Err(RegExpError("Invalid named reference", self.span_from(start)));
+                }
+                let name = self.parse_group_name()?;
+                self.named_references.push((name.clone(), self.span_from(name_start)));
+                let span = self.span_from(start);
+                Term::Backreference(Backreference { span, kind: BackreferenceKind::Named(name) })
+            }
+            Some('d' | 'D' | 's' | 'S' | 'w' | 'W') => {
+                Term::CharacterClassEscape(self.parse_character_class_escape(start))
+            }
+            Some('p' | 'P') if unicode_mode => {
+                Term::UnicodePropertyEscape(Box::new(self.parse_unicode_property_escape(start)?))
+            }
+            Some(_) => {
+                let value = self.parse_character_escape(start, false)?;
+                Term::Character(self.character(start, value))
+            }
+        };
+        Ok((term, true))
+    }
+
+    /// `\d`, `\D`, `\s`, `\S`, `\w`, `\W`, after `\`
+    fn parse_character_class_escape(&mut self, start: usize) -> CharacterClassEscape {
+        let kind = match self.bump() {
+            Some('d') => CharacterClassEscapeKind::D,
+            Some('D') => CharacterClassEscapeKind::NegativeD,
+            Some('s') => CharacterClassEscapeKind::S,
+            Some('S') => CharacterClassEscapeKind::NegativeS,
+            Some('w') => CharacterClassEscapeKind::W,
+            _ => CharacterClassEscapeKind::NegativeW,
+        };
+        CharacterClassEscape { span: self.span_from(start), kind }
+    }
+
+    /// `\p{…}` and `\P{…}`, after `\`
+    fn parse_unicode_property_escape(&mut self, start: usize) -> Result<UnicodePropertyEscape> {
+        let negative = self.bump() == Some('P');
+        self.check_version(EcmaVersion::ES2018, start)?;
+        let invalid = |p: &Self| RegExpError("Invalid property name", p.span_from(start));
+        if !self.eat('{') {
+            return Err(invalid(self));
+        }
+        let Some(len) = self.source[self.index..].find('}') else { return Err(invalid(self)) };
+        let body = &self.source[self.index..self.index + len];
+        self.index += len + 1;
+        let (name, value) = match body.split_once('=') {
+            Some((name, value)) => (name, Some(value)),
+            None => (body, None),
+        };
+        let valid = value.map_or_else(
+            || unicode_property::is_valid_lone_name(name),
+            |value| unicode_property::is_valid_name_and_value(name, value),
+        );
+        let strings = !valid
+            && value.is_none()
+            && self.options.unicode_sets_mode()
+            && unicode_property::is_property_of_strings(name);
+        if strings && negative {
+            return Err(RegExpError(
+                "Negated character class may contain strings",
+                self.span_from(start),
+            ));
+        }
+        if !valid && !strings {
+            return Err(invalid(self));
+        }
+        Ok(UnicodePropertyEscape {
+            span: self.span_from(start),
+            negative,
+            name: name.to_string(),
+            value: value.map(ToString::to_string),
+            strings,
+        })
+    }
+
+    /// CharacterEscape, after `\`, returns the code point
+    fn parse_character_escape(&mut self, start: usize, in_class: bool) -> Result<u32> {
+        let unicode_mode = self.options.unicode_mode();
+        let invalid = |p: &Self| RegExpError("Invalid escape", p.span_from(start));
+        let Some(c) = self.bump() else {
+            return Err(RegExpError("\\ at end of pattern", self.span_from(start)));
+        };
+        let value = match c {
+            'f' => 0x0C,
+            'n' => 0x0A,
+            'r' => 0x0D,
+            't' => 0x09,
+            'v' => 0x0B,
+            'c' => match self.peek() {
+                Some(letter) if letter.is_ascii_alphabetic() => {
+                    self.bump();
+                    u32::from(letter) % 32
+                }
+                // Annex B: ClassControlLetter
+                Some(letter)
+                    if in_class && !unicode_mode && (letter.is_ascii_digit() || letter == '_') =>
+                {
+                    self.bump();
+                    u32::from(letter) % 32
+                }
+                _ if unicode_mode => return Err(invalid(self)),
+                // Annex B: `\` is a character, and `c` is the next one.
+                _ => {
+                    self.index -= 1;
+                    u32::from('\\')
+                }
+            },
+            '0' if !self.peek().is_some_and(|c| c.is_ascii_digit()) => 0,
+            '0'..='9' if unicode_mode => return Err(invalid(self)),
+            // Annex B: LegacyOctalEscapeSequence
+            '0'..='7' => {
+                let mut value = c.to_digit(8).unwrap();
+                if let Some(digit) = self.peek().and_then(|c| c.to_digit(8)) {
+                    self.bump();
+                    value = value * 8 + digit;
+                    if c <= '3' {
+                        if let Some(digit) = self.peek().and_then(|c| c.to_digit(8)) {
+                            self.bump();
+                            value = value * 8 + digit;
+                        }
+                    }
+                }
+                value
+            }
+            'x' => match self.parse_hex(2) {
+                Some(value) => value,
+                None if unicode_mode => return Err(invalid(self)),
+                None => u32::from('x'),
+            },
+            'u' => match self.parse_unicode_escape(unicode_mode) {
+                Some(value) => value,
+                None if unicode_mode => {
+                    return Err(RegExpError("Invalid unicode escape", self.span_from(start)));
+                }
+                None => u32::from('u'),
+            },
+            '-' if in_class && unicode_mode => u32::from(c),
+            c if self.options.unicode_sets_mode()
+                && in_class
+                && CLASS_SET_RESERVED_PUNCTUATORS.contains(c) =>
+            {
+                u32::from(c)
+            }
+            c if unicode_mode => {
+                if !SYNTAX_CHARACTERS.contains(c) {
+                    return Err(invalid(self));
+                }
+                u32::from(c)
+            }
+            // Annex B: `\k` is a named reference in the patterns with the named groups.
+            'k' if self.has_named_groups => return Err(invalid(self)),
+            c => u32::from(c),
+        };
+        Ok(value)
+    }
+
+    /// `XXXX` or `{X…}` after `\u`, and the trail surrogate of a lead one in the unicode mode,
+    /// the index is restored when it is none of them
+    fn parse_unicode_escape(&mut self, unicode_mode: bool) -> Option<u32> {
+        let start = self.index;
+        if unicode_mode && self.eat('{') {
+            let value = self.parse_hex(usize::MAX).filter(|value| *value <= 0x0010_FFFF);
+            if value.is_some() && self.eat('}') {
+                return value;
+            }
+            self.index = start;
+            return None;
+        }
+        let value = self.parse_hex(4)?;
+        if unicode_mode && (0xD800..=0xDBFF).contains(&value) {
+            let trail_start = self.index;
+            if self.eat_str("\\u") {
+                if let Some(trail @ 0xDC00..=0xDFFF) = self.parse_hex(4) {
+                    return Some(0x10000 + ((value - 0xD800) << 10) + (trail - 0xDC00));
+                }
+            }
+            self.index = trail_start;
+        }
+        Some(value)
+    }
+
+    /// Exactly `count` hexadecimal digits, or at least one and as many as there are with
+    /// `usize::MAX`, the index is restored when there are not enough of them
+    fn parse_hex(&mut self, count: usize) -> Option<u32> {
+        let start = self.index;
+        let mut value: u32 = 0;
+        let mut digits = 0;
+        while digits < count {
+            let Some(digit) = self.peek().and_then(|c| c.to_digit(16)) else { break };
+            self.bump();
+            value = value.saturating_mul(16).saturating_add(digit);
+            digits += 1;
+        }
+        if digits == 0 || (count != usize::MAX && digits < count) {
+            self.index = start;
+            return None;
+        }
+        Some(value)
+    }
+
+    /// CharacterClass, after `[`
+    fn parse_character_class(&mut self, start: usize) -> Result<CharacterClass> {
+        let negative = self.eat('^');
+        let (kind, elements) = if self.options.unicode_sets_mode() {
+            self.parse_class_set_expression(start)?
+        } else {
+            (CharacterClassKind::Union, self.parse_class_ranges(start)?)
+        };
+        if !self.eat(']') {
+            return Err(RegExpError("Unterminated character class", self.span_from(start)));
+        }
+        let span = self.span_from(start);
+        if negative && elements.iter().any(ClassElement::may_contain_strings) {
+            return Err(RegExpError("Negated character class may contain strings", span));
+        }
+        Ok(CharacterClass { span, negative, kind, elements })
+    }
+
+    /// ClassContents without the `v` flag, up to `]`
+    fn parse_class_ranges(&mut self, start: usize) -> Result<Vec<ClassElement>> {
+        let mut elements = vec![];
+        loop {
+            match self.peek() {
+                None => {
+                    return Err(RegExpError("Unterminated character class", self.span_from(start)))
+                }
+                Some(']') => return Ok(elements),
+                _ => {}
+            }
+            let min_start = self.index;
+            let min = self.parse_class_atom()?;
+            if self.peek() != Some('-') || matches!(self.peek_nth(1), None | Some(']')) {
+                elements.push(min);
+                continue;
+            }
+            let dash_start = self.index;
+            self.bump();
+            let dash = self.character(dash_start, u32::from('-'));
+            let max = self.parse_class_atom()?;
+            match (min, max) {
+                (ClassElement::Character(min), ClassElement::Character(max)) => {
+                    if min.value > max.value {
+                        return Err(RegExpError(
+                            "Range out of order in character class",
+                            self.span_from(min_start),
+                        ));
+                    }
+                    let span = self.span_from(min_start);
+                    elements.push(ClassElement::Range(ClassRange { span, min, max }));
+                }
+                _ if self.options.unicode_mode() => {
+                    return Err(RegExpError("Invalid character class", self.span_from(min_start)));
+                }
+                // Annex B: `-` is a character when a class escape is one of the ends.
+                (min, max) => {
+                    elements.push(min);
+                    elements.push(ClassElement::Character(dash));
+                    elements.push(max);
+                }
+            }
+        }
+    }
+
+    /// ClassAtom
+    fn parse_class_atom(&mut self) -> Result<ClassElement> {
+        let start = self.index;
+        let Some(c) = self.bump() else { unreachable!() };
+        if c != '\\' {
+            return Ok(ClassElement::Character(self.character(start, u32::from(c))));
+        }
+        let element = match self.peek() {
+            Some('d' | 'D' | 's' | 'S' | 'w' | 'W') => {
+                ClassElement::CharacterClassEscape(self.parse_character_class_escape(start))
+            }
+            Some('p' | 'P') if self.options.unicode_mode() => ClassElement::UnicodePropertyEscape(
+                Box::new(self.parse_unicode_property_escape(start)?),
+            ),
+            Some('b') => {
+                self.bump();
+                ClassElement::Character(self.character(start, 0x08))
+            }
+            _ => {
+                let value = self.parse_character_escape(start, true)?;
+                ClassElement::Character(self.character(start, value))
+            }
+        };
+        Ok(element)
+    }
+
+    /// ClassSetExpression with the `v` flag, up to `]`
+    fn parse_class_set_expression(
+        &mut self,
+        start: usize,
+    ) -> Result<(CharacterClassKind, Vec<ClassElement>)> {
+        let mut kind = None;
+        let mut elements = vec![];
+        loop {
+            let operand_start = self.index;
+            match self.peek() {
+                None => {
+                    return Err(RegExpError("Unterminated character class", self.span_from(start)))
+                }
+                Some(']') => return Ok((kind.unwrap_or(CharacterClassKind::Union), elements)),
+                _ => {}
+            }
+            let operator = if self.eat_str("&&") {
+                Some(CharacterClassKind::Intersection)
+            } else if self.eat_str("--") {
+                Some(CharacterClassKind::Subtraction)
+            } else {
+                None
+            };
+            let valid = match (operator, kind) {
+                (Some(_), _) if elements.is_empty() => false,
+                (Some(operator), None) => {
+                    elements.len() == 1 && {
+                        kind = Some(operator);
+                        true
+                    }
+                }
+                (Some(operator), Some(kind)) => operator == kind,
+                (
+                    None,
+                    Some(CharacterClassKind::Intersection | CharacterClassKind::Subtraction),
+                ) => false,
+                (None, _) => {
+                    if !elements.is_empty() {
+                        kind = Some(CharacterClassKind::Union);
+                    }
+                    true
+                }
+            };
+            if !valid || (operator.is_some() && matches!(self.peek(), None | Some(']' | '&'))) {
+                return Err(RegExpError(
+                    "Invalid set operation in character class",
+                    self.span_from(operand_start),
+                ));
+            }
+            let operand_start = self.index;
+            let operand = self.parse_class_set_operand()?;
+            // ClassSetRange
+            if operator.is_none() && self.peek() == Some('-') && self.peek_nth(1) != Some('-') {
+                self.bump();
+                let max = self.parse_class_set_operand()?;
+                let (ClassElement::Character(min), ClassElement::Character(max)) = (operand, max)
+                else {
+                    return Err(RegExpError(
+                        "Invalid character class",
+                        self.span_from(operand_start),
+                    ));
+                };
+                if min.value > max.value {
+                    return Err(RegExpError(
+                        "Range out of order in character class",
+                        self.span_from(operand_start),
+                    ));
+                }
+                let span = self.span_from(operand_start);
+                elements.push(ClassElement::Range(ClassRange { span, min, max }));
+            } else {
+                elements.push(operand);
+            }
+        }
+    }
+
+    /// ClassSetOperand with the `v` flag
+    fn parse_class_set_operand(&mut self) -> Result<ClassElement> {
+        let start = self.index;
+        match self.peek() {
+            Some('[') => {
+                self.bump();
+                return Ok(ClassElement::NestedClass(Box::new(self.parse_character_class(start)?)));
+            }
+            Some('\\') if self.source[self.index..].starts_with("\\q{") => {
+                self.index += 3;
+                return Ok(ClassElement::ClassStrings(self.parse_class_strings(start)?));
+            }
+            Some('\\') => return self.parse_class_atom(),
+            Some(c) if CLASS_SET_SYNTAX_CHARACTERS.contains(c) => {
+                return Err(RegExpError(
+                    "Invalid character in character class",
+                    self.span_from(start),
+                ));
+            }
+            Some(c)
+                if CLASS_SET_RESERVED_DOUBLE_PUNCTUATORS.contains(c)
+                    && self.peek_nth(1) == Some(c) =>
+            {
+                return Err(RegExpError(
+                    "Invalid set operation in character class",
+                    self.span_from(start),
+                ));
+            }
+            _ => {}
+        }
+        self.parse_class_atom()
+    }
+
+    /// `\q{abc|def}`, after `{`
+    fn parse_class_strings(&mut self, start: usize) -> Result<ClassStrings> {
+        let mut strings = vec![vec![]];
+        loop {
+            let char_start = self.index;
+            let value = match self.bump() {
+                None => return Err(RegExpError("Invalid escape", self.span_from(start))),
+                Some('}') => return Ok(ClassStrings { span: self.span_from(start), strings }),
+                Some('|') => {
+                    strings.push(vec![]);
+                    continue;
+                }
+                Some('\\') => self.parse_character_escape(char_start, true)?,
+                Some(c) if CLASS_SET_SYNTAX_CHARACTERS.contains(c) => {
+                    return Err(RegExpError(
+                        "Invalid character in character class",
+                        self.span_from(char_start),
+                    ));
+                }
+                Some(c) => u32::from(c),
+            };
+            let character = self.character(char_start, value);
+            strings.last_mut().unwrap().push(character);
+        }
+    }
+}
+
+impl ClassElement {
+    /// With the `v` flag, a string which is not a single character may be in the element
+    fn may_contain_strings(&self) -> bool {
+        match self {
+            Self::UnicodePropertyEscape(escape) => escape.strings,
+            Self::ClassStrings(strings) => strings.strings.iter().any(|string| string.len() != 1),
+            Self::NestedClass(class) => {
+                !class.negative && class.elements.iter().any(Self::may_contain_strings)
+            }
+            _ => false,
+        }
+    }
+}
+
+/// The number of the capturing groups, and whether some are named
+fn scan_capturing_groups(source: &str, unicode_sets_mode: bool) -> (u32, bool) {
+    let mut count = 0;
+    let mut named = false;
+    // The depth of the classes, which can be nested with the `v` flag
+    let mut classes = 0;
+    let mut chars = source.char_indices();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '\\' => {
+                chars.next();
+            }
+            '[' if classes == 0 || unicode_sets_mode => classes += 1,
+            ']' if classes > 0 => classes -= 1,
+            '(' if classes == 0 => {
+                let rest = &source[i + 1..];
+                if !rest.starts_with('?') {
+                    count += 1;
+                } else if rest.starts_with("?<")
+                    && !rest.starts_with("?<=")
+                    && !rest.starts_with("?<!")
+                {
+                    count += 1;
+                    named = true;
+                }
+            }
+            _ => {}
+        }
+    }
+    (count, named)
+}
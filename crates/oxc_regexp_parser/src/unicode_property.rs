@@ -0,0 +1,224 @@
+//! The names and values of the Unicode properties accepted in `\p{…}`
+//!
+//! <https://tc39.es/ecma262/#sec-runtime-semantics-unicodematchproperty-p>
+//!
+//! There is no Unicode data in the crate, the values of `Script` and `Script_Extensions` are
+//! only checked to be made of the word characters.
+
+/// `\p{Name=Value}`
+pub fn is_valid_name_and_value(name: &str, value: &str) -> bool {
+    match name {
+        "General_Category" | "gc" => GENERAL_CATEGORY_VALUES.contains(&value),
+        "Script" | "sc" | "Script_Extensions" | "scx" => {
+            !value.is_empty() && value.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+        }
+        _ => false,
+    }
+}
+
+/// `\p{Value}`, a value of `General_Category` or a binary property
+pub fn is_valid_lone_name(name: &str) -> bool {
+    GENERAL_CATEGORY_VALUES.contains(&name) || BINARY_PROPERTIES.contains(&name)
+}
+
+/// `\p{Name}` with the `v` flag
+pub fn is_property_of_strings(name: &str) -> bool {
+    PROPERTIES_OF_STRINGS.contains(&name)
+}
+
+/// <https://tc39.es/ecma262/#table-nonbinary-unicode-properties>
+const GENERAL_CATEGORY_VALUES: &[&str] = &[
+    "C",
+    "Other",
+    "Cased_Letter",
+    "LC",
+    "Cc",
+    "Control",
+    "cntrl",
+    "Cf",
+    "Format",
+    "Close_Punctuation",
+    "Pe",
+    "Cn",
+    "Unassigned",
+    "Co",
+    "Private_Use",
+    "Connector_Punctuation",
+    "Pc",
+    "Cs",
+    "Surrogate",
+    "Currency_Symbol",
+    "Sc",
+    "Dash_Punctuation",
+    "Pd",
+    "Decimal_Number",
+    "Nd",
+    "digit",
+    "Enclosing_Mark",
+    "Me",
+    "Final_Punctuation",
+    "Pf",
+    "Initial_Punctuation",
+    "Pi",
+    "L",
+    "Letter",
+    "Letter_Number",
+    "Nl",
+    "Line_Separator",
+    "Zl",
+    "Ll",
+    "Lowercase_Letter",
+    "Lm",
+    "Modifier_Letter",
+    "Lo",
+    "Other_Letter",
+    "Lt",
+    "Titlecase_Letter",
+    "Lu",
+    "Uppercase_Letter",
+    "M",
+    "Mark",
+    "Combining_Mark",
+    "Math_Symbol",
+    "Sm",
+    "Mc",
+    "Spacing_Mark",
+    "Mn",
+    "Nonspacing_Mark",
+    "Modifier_Symbol",
+    "Sk",
+    "N",
+    "Number",
+    "No",
+    "Other_Number",
+    "Open_Punctuation",
+    "Ps",
+    "Other_Punctuation",
+    "Po",
+    "Other_Symbol",
+    "So",
+    "P",
+    "Punctuation",
+    "punct",
+    "Paragraph_Separator",
+    "Zp",
+    "S",
+    "Symbol",
+    "Space_Separator",
+    "Zs",
+    "Z",
+    "Separator",
+];
+
+/// <https://tc39.es/ecma262/#table-binary-unicode-properties>
+const BINARY_PROPERTIES: &[&str] = &[
+    "ASCII",
+    "ASCII_Hex_Digit",
+    "AHex",
+    "Alphabetic",
+    "Alpha",
+    "Any",
+    "Assigned",
+    "Bidi_Control",
+    "Bidi_C",
+    "Bidi_Mirrored",
+    "Bidi_M",
+    "Case_Ignorable",
+    "CI",
+    "Cased",
+    "Changes_When_Casefolded",
+    "CWCF",
+    "Changes_When_Casemapped",
+    "CWCM",
+    "Changes_When_Lowercased",
+    "CWL",
+    "Changes_When_NFKC_Casefolded",
+    "CWKCF",
+    "Changes_When_Titlecased",
+    "CWT",
+    "Changes_When_Uppercased",
+    "CWU",
+    "Dash",
+    "Default_Ignorable_Code_Point",
+    "DI",
+    "Deprecated",
+    "Dep",
+    "Diacritic",
+    "Dia",
+    "Emoji",
+    "Emoji_Component",
+    "EComp",
+    "Emoji_Modifier",
+    "EMod",
+    "Emoji_Modifier_Base",
+    "EBase",
+    "Emoji_Presentation",
+    "EPres",
+    "Extended_Pictographic",
+    "ExtPict",
+    "Extender",
+    "Ext",
+    "Grapheme_Base",
+    "Gr_Base",
+    "Grapheme_Extend",
+    "Gr_Ext",
+    "Hex_Digit",
+    "Hex",
+    "IDS_Binary_Operator",
+    "IDSB",
+    "IDS_Trinary_Operator",
+    "IDST",
+    "ID_Continue",
+    "IDC",
+    "ID_Start",
+    "IDS",
+    "Ideographic",
+    "Ideo",
+    "Join_Control",
+    "Join_C",
+    "Logical_Order_Exception",
+    "LOE",
+    "Lowercase",
+    "Lower",
+    "Math",
+    "Noncharacter_Code_Point",
+    "NChar",
+    "Pattern_Syntax",
+    "Pat_Syn",
+    "Pattern_White_Space",
+    "Pat_WS",
+    "Quotation_Mark",
+    "QMark",
+    "Radical",
+    "Regional_Indicator",
+    "RI",
+    "Sentence_Terminal",
+    "STerm",
+    "Soft_Dotted",
+    "SD",
+    "Terminal_Punctuation",
+    "Term",
+    "Unified_Ideograph",
+    "UIdeo",
+    "Uppercase",
+    "Upper",
+    "Variation_Selector",
+    "VS",
+    "White_Space",
+    "space",
+    "XID_Continue",
+    "XIDC",
+    "XID_Start",
+    "XIDS",
+];
+
+/// <https://tc39.es/ecma262/#table-binary-unicode-properties-of-strings>
+const PROPERTIES_OF_STRINGS: &[&str] = &[
+    "Basic_Emoji",
+    "Emoji_Keycap_Sequence",
+    "RGI_Emoji_Modifier_Sequence",
+    "RGI_Emoji_Flag_Sequence",
+    "RGI_Emoji_Tag_Sequence",
+    "RGI_Emoji_ZWJ_Sequence",
+    "RGI_Emoji",
+];
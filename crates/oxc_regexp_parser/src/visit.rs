@@ -0,0 +1,73 @@
+//! Visitor of the regular expression AST
+
+use crate::ast::{
+    Alternative, Backreference, CapturingGroup, CharacterClass, ClassElement, Group, LookAround,
+    Pattern, Quantifier, Term, UnicodePropertyEscape,
+};
+
+/// Every method walks into the children by default
+pub trait Visit: Sized {
+    fn visit_pattern(&mut self, pattern: &Pattern) {
+        self.visit_alternatives(&pattern.alternatives);
+    }
+
+    fn visit_alternatives(&mut self, alternatives: &[Alternative]) {
+        for alternative in alternatives {
+            self.visit_alternative(alternative);
+        }
+    }
+
+    fn visit_alternative(&mut self, alternative: &Alternative) {
+        for term in &alternative.terms {
+            self.visit_term(term);
+        }
+    }
+
+    fn visit_term(&mut self, term: &Term) {
+        match term {
+            Term::LookAround(look_around) => self.visit_look_around(look_around),
+            Term::Quantifier(quantifier) => self.visit_quantifier(quantifier),
+            Term::CharacterClass(class) => self.visit_character_class(class),
+            Term::CapturingGroup(group) => self.visit_capturing_group(group),
+            Term::Group(group) => self.visit_group(group),
+            Term::Backreference(reference) => self.visit_backreference(reference),
+            Term::UnicodePropertyEscape(escape) => self.visit_unicode_property_escape(escape),
+            Term::Assertion(_)
+            | Term::Character(_)
+            | Term::Dot(_)
+            | Term::CharacterClassEscape(_) => {}
+        }
+    }
+
+    fn visit_look_around(&mut self, look_around: &LookAround) {
+        self.visit_alternatives(&look_around.alternatives);
+    }
+
+    fn visit_quantifier(&mut self, quantifier: &Quantifier) {
+        self.visit_term(&quantifier.term);
+    }
+
+    fn visit_character_class(&mut self, class: &CharacterClass) {
+        for element in &class.elements {
+            match element {
+                ClassElement::NestedClass(class) => self.visit_character_class(class),
+                ClassElement::UnicodePropertyEscape(escape) => {
+                    self.visit_unicode_property_escape(escape);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn visit_capturing_group(&mut self, group: &CapturingGroup) {
+        self.visit_alternatives(&group.alternatives);
+    }
+
+    fn visit_group(&mut self, group: &Group) {
+        self.visit_alternatives(&group.alternatives);
+    }
+
+    fn visit_backreference(&mut self, _reference: &Backreference) {}
+
+    fn visit_unicode_property_escape(&mut self, _escape: &UnicodePropertyEscape) {}
+}
@@ -1,5 +1,6 @@
 // <https://nodejs.org/api/modules.html#core-modules>
 // node -p "[...require('module').builtinModules].map(b => JSON.stringify(b)).join(',\n')"
+/// The Node.js builtin modules, without the `node:` prefix.
 pub const BUILTINS: &[&str] = &[
     "_http_agent",
     "_http_client",
@@ -42,16 +42,8 @@ use std::{
     sync::Arc,
 };
 
-use crate::{
-    builtins::BUILTINS,
-    cache::{Cache, CachedPath},
-    file_system::FileSystemOs,
-    package_json::{ExportsField, ExportsKey, MatchObject},
-    path::PathUtil,
-    specifier::Specifier,
-    tsconfig::{ProjectReference, TsConfig},
-};
 pub use crate::{
+    builtins::BUILTINS,
     error::{JSONError, ResolveError, SpecifierError},
     file_system::{FileMetadata, FileSystem},
     options::{
@@ -61,6 +53,14 @@ pub use crate::{
     package_json::PackageJson,
     resolution::Resolution,
 };
+use crate::{
+    cache::{Cache, CachedPath},
+    file_system::FileSystemOs,
+    package_json::{ExportsField, ExportsKey, MatchObject},
+    path::PathUtil,
+    specifier::Specifier,
+    tsconfig::{ProjectReference, TsConfig},
+};
 
 /// Resolver with the current operating system as the file system
 pub type Resolver = ResolverGeneric<FileSystemOs>;
@@ -5,7 +5,7 @@ use std::{cell::RefCell, path::PathBuf, rc::Rc, sync::Arc};
 use itertools::Itertools;
 #[allow(clippy::wildcard_imports)]
 use oxc_ast::{ast::*, AstKind, Trivias, TriviasMap, Visit};
-use oxc_diagnostics::Error;
+use oxc_diagnostics::{EarlyErrors, Error};
 use oxc_span::{Atom, SourceType, Span};
 use oxc_syntax::{module_record::ModuleRecord, operator::AssignmentOperator};
 use rustc_hash::FxHashMap;
@@ -13,6 +13,7 @@ use rustc_hash::FxHashMap;
 use crate::{
     binder::Binder,
     checker::{EarlyErrorJavaScript, EarlyErrorTypeScript},
+    comments::NodeComments,
     diagnostics::Redeclaration,
     jsdoc::JSDocBuilder,
     module_record::ModuleRecordBuilder,
@@ -57,6 +58,8 @@ pub struct SemanticBuilder<'a> {
     /// Semantic early errors such as redeclaration errors.
     errors: RefCell<Vec<Error>>,
 
+    early_errors: EarlyErrors,
+
     // states
     pub current_node_id: AstNodeId,
     pub current_node_flags: NodeFlags,
@@ -102,6 +105,7 @@ impl<'a> SemanticBuilder<'a> {
             source_type,
             trivias: Rc::clone(&trivias),
             errors: RefCell::new(vec![]),
+            early_errors: EarlyErrors::default(),
             current_node_id: AstNodeId::new(0),
             current_node_flags: NodeFlags::empty(),
             current_symbol_flags: SymbolFlags::empty(),
@@ -132,6 +136,14 @@ impl<'a> SemanticBuilder<'a> {
         self
     }
 
+    /// Report the early errors as errors (the default), as warnings, or not at all.
+    /// The semantic is built the same in all the cases.
+    #[must_use]
+    pub fn with_early_errors(mut self, early_errors: EarlyErrors) -> Self {
+        self.early_errors = early_errors;
+        self
+    }
+
     /// Get the built module record from `build_module_record`
     pub fn module_record(&self) -> Arc<ModuleRecord> {
         Arc::clone(&self.module_record)
@@ -162,6 +174,7 @@ impl<'a> SemanticBuilder<'a> {
             }
         }
 
+        let comments = NodeComments::new(self.source_text, &self.trivias, &self.nodes);
         let semantic = Semantic {
             source_text: self.source_text,
             source_type: self.source_type,
@@ -171,6 +184,7 @@ impl<'a> SemanticBuilder<'a> {
             symbols: self.symbols,
             module_record: Arc::clone(&self.module_record),
             jsdoc: self.jsdoc.build(),
+            comments,
             unused_labels: self.unused_labels.labels,
             redeclare_variables: self.redeclare_variables.variables,
         };
@@ -178,6 +192,7 @@ impl<'a> SemanticBuilder<'a> {
     }
 
     pub fn build2(self) -> Semantic<'a> {
+        let comments = NodeComments::new(self.source_text, &self.trivias, &self.nodes);
         Semantic {
             source_text: self.source_text,
             source_type: self.source_type,
@@ -187,6 +202,7 @@ impl<'a> SemanticBuilder<'a> {
             symbols: self.symbols,
             module_record: Arc::new(ModuleRecord::default()),
             jsdoc: self.jsdoc.build(),
+            comments,
             unused_labels: self.unused_labels.labels,
             redeclare_variables: self.redeclare_variables.variables,
         }
@@ -194,7 +210,9 @@ impl<'a> SemanticBuilder<'a> {
 
     /// Push a Syntax Error
     pub fn error<T: Into<Error>>(&self, error: T) {
-        self.errors.borrow_mut().push(error.into());
+        if let Some(error) = self.early_errors.report(error.into()) {
+            self.errors.borrow_mut().push(error);
+        }
     }
 
     fn create_ast_node(&mut self, kind: AstKind<'a>) {
@@ -8,6 +8,9 @@ use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::{self, Error},
 };
+use oxc_regexp_parser::{
+    Parser as RegExpParser, ParserOptions as RegExpParserOptions, RegExpError,
+};
 use oxc_span::{Atom, GetSpan, ModuleKind, Span};
 use oxc_syntax::{
     module_record::ExportLocalName,
@@ -56,6 +59,7 @@ impl EarlyErrorJavaScript {
             AstKind::BreakStatement(stmt) => check_break_statement(stmt, node, ctx),
             AstKind::ContinueStatement(stmt) => check_continue_statement(stmt, node, ctx),
             AstKind::LabeledStatement(stmt) => check_labeled_statement(stmt, node, ctx),
+            AstKind::UsingDeclaration(decl) => check_using_declaration(decl, node, ctx),
             AstKind::ForInStatement(stmt) => check_for_statement_left(&stmt.left, true, node, ctx),
             AstKind::ForOfStatement(stmt) => check_for_statement_left(&stmt.left, false, node, ctx),
 
@@ -599,6 +603,15 @@ fn check_regexp_literal(lit: &RegExpLiteral, ctx: &SemanticBuilder<'_>) {
     let flags = lit.regex.flags;
     if flags.contains(RegExpFlags::U | RegExpFlags::V) {
         ctx.error(RegExpFlagUAndV(lit.span));
+        return;
+    }
+    let options = RegExpParserOptions::new(flags);
+    if let Err(RegExpError(message, span)) =
+        RegExpParser::new(lit.regex.pattern.as_str(), options).parse()
+    {
+        // The spans of the pattern start after the `/` of the literal.
+        let start = lit.span.start + 1;
+        ctx.error(RegExpError(message, Span::new(start + span.start, start + span.end)));
     }
 }
 
@@ -626,6 +639,34 @@ fn check_switch_statement<'a>(stmt: &SwitchStatement<'a>, ctx: &SemanticBuilder<
     }
 }
 
+fn check_using_declaration<'a>(
+    decl: &UsingDeclaration<'a>,
+    node: &AstNode<'a>,
+    ctx: &SemanticBuilder<'a>,
+) {
+    #[derive(Debug, Error, Diagnostic)]
+    #[error("Using declarations are not allowed at the top level of a script")]
+    #[diagnostic(help("Wrap this declaration in a block statement"))]
+    struct UsingDeclarationTopLevelScript(#[label] Span);
+
+    #[derive(Debug, Error, Diagnostic)]
+    #[error("Using declarations are not allowed directly in case or default clauses")]
+    #[diagnostic(help("Wrap this declaration in a block statement"))]
+    struct UsingDeclarationInCaseClause(#[label] Span);
+
+    match ctx.nodes.parent_kind(node.id()) {
+        // It is ambiguous between script and module for `TypeScript`, as in `check_module_declaration`.
+        Some(AstKind::Program(_))
+            if ctx.source_type.module_kind() == ModuleKind::Script
+                && !ctx.source_type.is_typescript() =>
+        {
+            ctx.error(UsingDeclarationTopLevelScript(decl.span));
+        }
+        Some(AstKind::SwitchCase(_)) => ctx.error(UsingDeclarationInCaseClause(decl.span)),
+        _ => {}
+    }
+}
+
 #[derive(Debug, Error, Diagnostic)]
 #[error("Jump target cannot cross function boundary.")]
 #[diagnostic()]
@@ -0,0 +1,193 @@
+//! Attachment of the comments to the AST nodes
+
+use std::collections::BTreeMap;
+
+use oxc_ast::{Comment, TriviasMap};
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::identifier::is_line_terminator;
+use rustc_hash::FxHashMap;
+
+use crate::{AstNodeId, AstNodes};
+
+/// The comments attached to the AST nodes, by their position
+///
+/// A comment is attached around the children of the innermost node containing it, as the:
+/// * trailing comment of the preceding node, when it ends the line of that node, e.g. `a; // comment`
+/// * leading comment of the following node, e.g. `/* comment */ a`
+/// * trailing comment of the preceding node, when there is no following node
+/// * dangling comment of the node containing it, when it has no children, e.g. `{ /* comment */ }`
+///
+/// The comments are the spans of their content, as in [TriviasMap::comments].
+#[derive(Debug, Default)]
+pub struct NodeComments {
+    leading: FxHashMap<AstNodeId, Vec<Span>>,
+    trailing: FxHashMap<AstNodeId, Vec<Span>>,
+    dangling: FxHashMap<AstNodeId, Vec<Span>>,
+}
+
+impl NodeComments {
+    /// Attach the comments to the nodes
+    pub fn new(source_text: &str, trivias: &TriviasMap, nodes: &AstNodes) -> Self {
+        let mut attached = Self::default();
+        let Some(root) = nodes.iter().next() else { return attached };
+        // The children of the nodes, in source order
+        let mut children: Vec<Vec<(AstNodeId, Span)>> = nodes.iter().map(|_| vec![]).collect();
+        for node in nodes.iter() {
+            if let Some(parent_id) = nodes.parent_id(node.id()) {
+                children[parent_id.index()].push((node.id(), node.kind().span()));
+            }
+        }
+        for children in &mut children {
+            children.sort_by_key(|(_, span)| span.start);
+        }
+
+        let comments = trivias.comments();
+        for (start, comment) in comments {
+            let span = Span::new(*start, comment.end());
+            let comment_start = start - 2;
+            let comment_end =
+                if comment.is_single_line() { comment.end() } else { comment.end() + 2 };
+
+            // The innermost node containing the comment, and its children around it
+            let mut enclosing = root.id();
+            let (preceding, following) = loop {
+                let children = &children[enclosing.index()];
+                let index = children.partition_point(|(_, span)| span.start <= comment_start);
+                let preceding = index.checked_sub(1).map(|index| children[index]);
+                match preceding {
+                    Some((node_id, span)) if comment_end <= span.end => enclosing = node_id,
+                    _ => break (preceding, children.get(index)),
+                }
+            };
+
+            let ends_line = preceding.is_some_and(|(_, preceding)| {
+                source_text
+                    .get(preceding.end as usize..comment_start as usize)
+                    .is_some_and(|text| !text.contains(is_line_terminator))
+                    && (comment.is_single_line() || ends_line(source_text, comments, comment_end))
+            });
+            let (map, node_id) = match (preceding, following) {
+                (Some((node_id, _)), _) if ends_line => (&mut attached.trailing, node_id),
+                (_, Some((node_id, _))) => (&mut attached.leading, *node_id),
+                (Some((node_id, _)), None) => (&mut attached.trailing, node_id),
+                (None, None) => (&mut attached.dangling, enclosing),
+            };
+            map.entry(node_id).or_default().push(span);
+        }
+        attached
+    }
+
+    /// The comments before the node
+    pub fn leading(&self, node_id: AstNodeId) -> &[Span] {
+        self.leading.get(&node_id).map_or(&[], Vec::as_slice)
+    }
+
+    /// The comments after the node
+    pub fn trailing(&self, node_id: AstNodeId) -> &[Span] {
+        self.trailing.get(&node_id).map_or(&[], Vec::as_slice)
+    }
+
+    /// The comments inside the node without children around them
+    pub fn dangling(&self, node_id: AstNodeId) -> &[Span] {
+        self.dangling.get(&node_id).map_or(&[], Vec::as_slice)
+    }
+}
+
+/// The comment ending at `end` is the last thing on its line, other comments aside.
+fn ends_line(source_text: &str, comments: &BTreeMap<u32, Comment>, mut end: u32) -> bool {
+    loop {
+        let rest = &source_text[end as usize..];
+        let trimmed =
+            rest.trim_start_matches(|c: char| c.is_whitespace() && !is_line_terminator(c));
+        if trimmed.is_empty() || trimmed.starts_with(is_line_terminator) {
+            return true;
+        }
+        if !trimmed.starts_with("/*") {
+            return false;
+        }
+        #[allow(clippy::cast_possible_truncation)]
+        let start = end + (rest.len() - trimmed.len()) as u32 + 2;
+        match comments.get(&start) {
+            Some(comment) if comment.is_single_line() => return true,
+            Some(comment) => end = comment.end() + 2,
+            None => return false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_span::SourceType;
+
+    use crate::SemanticBuilder;
+
+    /// The attached comments as `(comment, position, node)`
+    fn attach(source_text: &str) -> Vec<(String, &'static str, String)> {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let ret = Parser::new(&allocator, source_text, source_type).parse();
+        let program = allocator.alloc(ret.program);
+        let semantic = SemanticBuilder::new(source_text, source_type)
+            .with_trivias(ret.trivias)
+            .build(program)
+            .semantic;
+        let comments = semantic.comments();
+        let mut attached = vec![];
+        for node in semantic.nodes().iter() {
+            let id = node.id();
+            for (position, spans) in [
+                ("leading", comments.leading(id)),
+                ("trailing", comments.trailing(id)),
+                ("dangling", comments.dangling(id)),
+            ] {
+                for span in spans {
+                    let comment = span.source_text(source_text).trim().to_string();
+                    attached.push((comment, position, node.kind().debug_name().into_owned()));
+                }
+            }
+        }
+        attached.sort();
+        attached
+    }
+
+    fn test(source_text: &str, expected: &[(&str, &str, &str)]) {
+        let mut expected = expected
+            .iter()
+            .map(|(comment, position, node)| {
+                ((*comment).to_string(), *position, (*node).to_string())
+            })
+            .collect::<Vec<_>>();
+        expected.sort();
+        assert_eq!(attach(source_text), expected, "{source_text}");
+    }
+
+    #[test]
+    fn leading() {
+        test("// a\nfoo();", &[("a", "leading", "ExpressionStatement")]);
+        test("/* a */ foo;", &[("a", "leading", "ExpressionStatement")]);
+        test("foo(/* a */ b);", &[("a", "leading", "Argument")]);
+        test("foo; /* a */ bar;", &[("a", "leading", "ExpressionStatement")]);
+        test("function foo() {\n  // a\n  return;\n}", &[("a", "leading", "ReturnStatement")]);
+    }
+
+    #[test]
+    fn trailing() {
+        test("foo(); // a\nbar();", &[("a", "trailing", "ExpressionStatement")]);
+        test(
+            "foo; /* a */ /* b */\nbar;",
+            &[("a", "trailing", "ExpressionStatement"), ("b", "trailing", "ExpressionStatement")],
+        );
+        test("foo(b /* a */);", &[("a", "trailing", "Argument")]);
+        test("foo(/* a */).bar(b);", &[("a", "trailing", "IdentifierReference(foo)")]);
+        test("foo();\n// a", &[("a", "trailing", "ExpressionStatement")]);
+    }
+
+    #[test]
+    fn dangling() {
+        test("// a", &[("a", "dangling", "Program")]);
+        test("{ /* a */ }", &[("a", "dangling", "BlockStatement")]);
+        test("function foo() {\n  // a\n}", &[("a", "dangling", "FunctionBody")]);
+    }
+}
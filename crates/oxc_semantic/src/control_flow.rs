@@ -0,0 +1,471 @@
+//! Control Flow Graph
+//!
+//! The graph is built on demand for the statements of a function body, a class static block or
+//! a program, and answers the reachability questions of the lint rules such as `no-unreachable`,
+//! `no-fallthrough` and `getter-return`.
+
+use std::mem;
+
+use oxc_ast::ast::{
+    Expression, ReturnStatement, Statement, SwitchCase, SwitchStatement, TryStatement,
+};
+use oxc_index::{define_index_type, IndexVec};
+use oxc_span::Span;
+use rustc_hash::FxHashMap;
+
+define_index_type! {
+    pub struct BasicBlockId = u32;
+}
+
+/// The graph of the basic blocks of the statements.
+///
+/// Nested functions are not entered and the expressions are assumed to complete normally,
+/// but any statement inside of a `try` block may throw.
+/// Only the constant tests of the loops are folded, e.g. `while (true) {}` never exits,
+/// while the consequent of `if (false) {}` is still considered reachable.
+pub struct ControlFlowGraph<'a> {
+    successors: IndexVec<BasicBlockId, Vec<BasicBlockId>>,
+    reachable: IndexVec<BasicBlockId, bool>,
+    /// The statements in the source order with the blocks they start in.
+    /// The statements inside of a `finally` clause start in two blocks,
+    /// one for the normal completion and one for the abrupt completion of the `try` statement.
+    statements: Vec<(&'a Statement<'a>, Vec<BasicBlockId>)>,
+    /// The blocks at the end of the consequents of the switch cases.
+    switch_case_ends: FxHashMap<Span, Vec<BasicBlockId>>,
+    /// The block reached by completing the statements normally.
+    end: BasicBlockId,
+}
+
+impl<'a> ControlFlowGraph<'a> {
+    pub fn new(statements: &'a [Statement<'a>]) -> Self {
+        let mut builder = ControlFlowGraphBuilder::new();
+        builder.build_statements(statements);
+        builder.finish()
+    }
+
+    /// Whether the statement can be executed.
+    /// Returns `true` for the statements which are not in the graph, e.g. the ones of the nested functions.
+    pub fn is_reachable(&self, statement: &Statement<'a>) -> bool {
+        self.statements
+            .iter()
+            .find(|(stmt, _)| std::ptr::eq(*stmt, statement))
+            .map_or(true, |(_, blocks)| self.any_reachable(blocks))
+    }
+
+    /// The statements in the source order and whether they can be executed.
+    pub fn statements(&self) -> impl Iterator<Item = (&'a Statement<'a>, bool)> + '_ {
+        self.statements.iter().map(|(stmt, blocks)| (*stmt, self.any_reachable(blocks)))
+    }
+
+    /// Whether the control can reach the end of the statements,
+    /// e.g. a function returns `undefined` implicitly.
+    pub fn can_complete_normally(&self) -> bool {
+        self.reachable[self.end]
+    }
+
+    /// Whether the control can fall through from the end of the switch case into the next one.
+    pub fn can_fall_through(&self, case: &SwitchCase<'a>) -> bool {
+        self.switch_case_ends.get(&case.span).is_some_and(|blocks| self.any_reachable(blocks))
+    }
+
+    /// The `return` statements which can be executed.
+    pub fn reachable_returns(&self) -> impl Iterator<Item = &'a ReturnStatement<'a>> + '_ {
+        self.statements().filter_map(|(stmt, reachable)| match stmt {
+            Statement::ReturnStatement(stmt) if reachable => Some(&**stmt),
+            _ => None,
+        })
+    }
+
+    pub fn successors(&self, block: BasicBlockId) -> &[BasicBlockId] {
+        &self.successors[block]
+    }
+
+    fn any_reachable(&self, blocks: &[BasicBlockId]) -> bool {
+        blocks.iter().any(|block| self.reachable[*block])
+    }
+}
+
+/// A pending jump out of a `try` statement, continued from the end of its `finally` clause.
+#[derive(Debug, Clone, Copy)]
+enum Jump {
+    /// A `break` or `continue` to the block at the index of the jump target stack.
+    To(usize, BasicBlockId),
+    Return,
+    Throw,
+}
+
+struct JumpTarget<'a> {
+    labels: Vec<&'a str>,
+    break_block: BasicBlockId,
+    /// `None` for the labeled statements and the switch statements.
+    continue_block: Option<BasicBlockId>,
+    /// Whether an unlabeled `break` jumps out of this target, i.e. it is a loop or a switch statement.
+    breakable: bool,
+    /// The number of the enclosing `try` statements.
+    try_depth: usize,
+}
+
+struct TryContext {
+    /// The entry of the `catch` clause while building the `try` block.
+    catch_entry: Option<BasicBlockId>,
+    /// The entry of the `finally` clause executed on the abrupt completion.
+    finally_entry: Option<BasicBlockId>,
+    pending_jumps: Vec<Jump>,
+}
+
+struct ControlFlowGraphBuilder<'a> {
+    successors: IndexVec<BasicBlockId, Vec<BasicBlockId>>,
+    statements: Vec<(&'a Statement<'a>, Vec<BasicBlockId>)>,
+    switch_case_ends: FxHashMap<Span, Vec<BasicBlockId>>,
+    current: BasicBlockId,
+    /// The block reached by `return` and uncaught `throw`.
+    exit: BasicBlockId,
+    jump_targets: Vec<JumpTarget<'a>>,
+    try_contexts: Vec<TryContext>,
+    /// The labels of the loop or the switch statement which is built next.
+    pending_labels: Vec<&'a str>,
+    /// The index of the next statement in `statements` when a `finally` clause is built again.
+    replay: Option<usize>,
+}
+
+impl<'a> ControlFlowGraphBuilder<'a> {
+    fn new() -> Self {
+        let mut successors = IndexVec::new();
+        let entry = successors.push(vec![]);
+        let exit = successors.push(vec![]);
+        Self {
+            successors,
+            statements: vec![],
+            switch_case_ends: FxHashMap::default(),
+            current: entry,
+            exit,
+            jump_targets: vec![],
+            try_contexts: vec![],
+            pending_labels: vec![],
+            replay: None,
+        }
+    }
+
+    fn finish(self) -> ControlFlowGraph<'a> {
+        let mut reachable = IndexVec::from_vec(vec![false; self.successors.len()]);
+        // The entry block is the first one.
+        let mut stack = vec![BasicBlockId::new(0)];
+        while let Some(block) = stack.pop() {
+            if mem::replace(&mut reachable[block], true) {
+                continue;
+            }
+            stack.extend(self.successors[block].iter().filter(|block| !reachable[**block]));
+        }
+        ControlFlowGraph {
+            successors: self.successors,
+            reachable,
+            statements: self.statements,
+            switch_case_ends: self.switch_case_ends,
+            end: self.current,
+        }
+    }
+
+    fn new_block(&mut self) -> BasicBlockId {
+        self.successors.push(vec![])
+    }
+
+    fn add_edge(&mut self, from: BasicBlockId, to: BasicBlockId) {
+        self.successors[from].push(to);
+    }
+
+    /// Continues in a new block reached from the current one.
+    fn next_block(&mut self) -> BasicBlockId {
+        let block = self.new_block();
+        self.add_edge(self.current, block);
+        self.current = block;
+        block
+    }
+
+    fn record_statement(&mut self, statement: &'a Statement<'a>) {
+        if let Some(index) = self.replay.as_mut() {
+            self.statements[*index].1.push(self.current);
+            *index += 1;
+        } else {
+            self.statements.push((statement, vec![self.current]));
+        }
+    }
+
+    fn build_statements(&mut self, statements: &'a [Statement<'a>]) {
+        for statement in statements {
+            self.build_statement(statement);
+        }
+    }
+
+    fn build_statement(&mut self, statement: &'a Statement<'a>) {
+        self.record_statement(statement);
+        match statement {
+            Statement::BlockStatement(stmt) => self.build_statements(&stmt.body),
+            Statement::IfStatement(stmt) => {
+                let start = self.current;
+                self.next_block();
+                self.build_statement(&stmt.consequent);
+                let consequent_end = self.current;
+                self.current = start;
+                if let Some(alternate) = &stmt.alternate {
+                    self.next_block();
+                    self.build_statement(alternate);
+                }
+                let alternate_end = self.current;
+                self.current = self.new_block();
+                self.add_edge(consequent_end, self.current);
+                self.add_edge(alternate_end, self.current);
+            }
+            Statement::WhileStatement(stmt) => {
+                let test = self.next_block();
+                self.build_loop(test, !is_constant_truthy(&stmt.test), &stmt.body, test);
+            }
+            Statement::DoWhileStatement(stmt) => {
+                let labels = mem::take(&mut self.pending_labels);
+                let body = self.next_block();
+                let test = self.new_block();
+                let exit = self.new_block();
+                self.push_loop_target(labels, exit, test);
+                self.build_statement(&stmt.body);
+                self.jump_targets.pop();
+                self.add_edge(self.current, test);
+                self.add_edge(test, body);
+                if !is_constant_truthy(&stmt.test) {
+                    self.add_edge(test, exit);
+                }
+                self.current = exit;
+            }
+            Statement::ForStatement(stmt) => {
+                let test = self.next_block();
+                let update = self.new_block();
+                self.add_edge(update, test);
+                let can_exit = stmt.test.as_ref().is_some_and(|test| !is_constant_truthy(test));
+                self.build_loop(test, can_exit, &stmt.body, update);
+            }
+            Statement::ForInStatement(stmt) => {
+                let test = self.next_block();
+                self.build_loop(test, true, &stmt.body, test);
+            }
+            Statement::ForOfStatement(stmt) => {
+                let test = self.next_block();
+                self.build_loop(test, true, &stmt.body, test);
+            }
+            Statement::LabeledStatement(stmt) => {
+                self.pending_labels.push(stmt.label.name.as_str());
+                match &stmt.body {
+                    Statement::WhileStatement(_)
+                    | Statement::DoWhileStatement(_)
+                    | Statement::ForStatement(_)
+                    | Statement::ForInStatement(_)
+                    | Statement::ForOfStatement(_)
+                    | Statement::SwitchStatement(_)
+                    | Statement::LabeledStatement(_) => self.build_statement(&stmt.body),
+                    body => {
+                        let exit = self.new_block();
+                        self.jump_targets.push(JumpTarget {
+                            labels: mem::take(&mut self.pending_labels),
+                            break_block: exit,
+                            continue_block: None,
+                            breakable: false,
+                            try_depth: self.try_contexts.len(),
+                        });
+                        self.build_statement(body);
+                        self.jump_targets.pop();
+                        self.add_edge(self.current, exit);
+                        self.current = exit;
+                    }
+                }
+            }
+            Statement::SwitchStatement(stmt) => self.build_switch(stmt),
+            Statement::TryStatement(stmt) => self.build_try(stmt),
+            Statement::BreakStatement(stmt) => {
+                let label = stmt.label.as_ref().map(|label| label.name.as_str());
+                let target = self.jump_targets.iter().enumerate().rev().find(|(_, target)| {
+                    label.map_or(target.breakable, |label| target.labels.contains(&label))
+                });
+                let jump = target
+                    .map_or(Jump::Return, |(index, target)| Jump::To(index, target.break_block));
+                self.jump(jump);
+            }
+            Statement::ContinueStatement(stmt) => {
+                let label = stmt.label.as_ref().map(|label| label.name.as_str());
+                let target = self.jump_targets.iter().enumerate().rev().find(|(_, target)| {
+                    target.continue_block.is_some()
+                        && label.map_or(true, |label| target.labels.contains(&label))
+                });
+                let jump = target
+                    .and_then(|(index, target)| Some(Jump::To(index, target.continue_block?)))
+                    .unwrap_or(Jump::Return);
+                self.jump(jump);
+            }
+            Statement::ReturnStatement(_) => self.jump(Jump::Return),
+            Statement::ThrowStatement(_) => self.jump(Jump::Throw),
+            Statement::WithStatement(stmt) => self.build_statement(&stmt.body),
+            Statement::DebuggerStatement(_)
+            | Statement::EmptyStatement(_)
+            | Statement::ExpressionStatement(_)
+            | Statement::ModuleDeclaration(_)
+            | Statement::Declaration(_) => {}
+        }
+    }
+
+    /// Builds the body of a loop entered from the `test` block,
+    /// and continues in the block after the loop.
+    fn build_loop(
+        &mut self,
+        test: BasicBlockId,
+        can_exit: bool,
+        body: &'a Statement<'a>,
+        continue_block: BasicBlockId,
+    ) {
+        let labels = mem::take(&mut self.pending_labels);
+        let exit = self.new_block();
+        if can_exit {
+            self.add_edge(test, exit);
+        }
+        self.push_loop_target(labels, exit, continue_block);
+        self.next_block();
+        self.build_statement(body);
+        self.jump_targets.pop();
+        self.add_edge(self.current, continue_block);
+        self.current = exit;
+    }
+
+    fn push_loop_target(
+        &mut self,
+        labels: Vec<&'a str>,
+        break_block: BasicBlockId,
+        continue_block: BasicBlockId,
+    ) {
+        self.jump_targets.push(JumpTarget {
+            labels,
+            break_block,
+            continue_block: Some(continue_block),
+            breakable: true,
+            try_depth: self.try_contexts.len(),
+        });
+    }
+
+    fn build_switch(&mut self, stmt: &'a SwitchStatement<'a>) {
+        let start = self.current;
+        let exit = self.new_block();
+        self.jump_targets.push(JumpTarget {
+            labels: mem::take(&mut self.pending_labels),
+            break_block: exit,
+            continue_block: None,
+            breakable: true,
+            try_depth: self.try_contexts.len(),
+        });
+        let mut previous_case_end = None;
+        for case in &stmt.cases {
+            self.current = self.new_block();
+            self.add_edge(start, self.current);
+            if let Some(previous_case_end) = previous_case_end {
+                self.add_edge(previous_case_end, self.current);
+            }
+            self.build_statements(&case.consequent);
+            self.switch_case_ends.entry(case.span).or_default().push(self.current);
+            previous_case_end = Some(self.current);
+        }
+        self.jump_targets.pop();
+        if let Some(last_case_end) = previous_case_end {
+            self.add_edge(last_case_end, exit);
+        }
+        if !stmt.cases.iter().any(SwitchCase::is_default_case) {
+            self.add_edge(start, exit);
+        }
+        self.current = exit;
+    }
+
+    fn build_try(&mut self, stmt: &'a TryStatement<'a>) {
+        let catch_entry = stmt.handler.as_ref().map(|_| self.new_block());
+        let finally_entry = stmt.finalizer.as_ref().map(|_| self.new_block());
+        let mut context = TryContext { catch_entry, finally_entry, pending_jumps: vec![] };
+        // Any statement of the `try` block may throw.
+        if let Some(catch_entry) = catch_entry {
+            self.add_edge(self.current, catch_entry);
+        } else if let Some(finally_entry) = finally_entry {
+            self.add_edge(self.current, finally_entry);
+            context.pending_jumps.push(Jump::Throw);
+        }
+        self.try_contexts.push(context);
+
+        self.next_block();
+        self.build_statements(&stmt.block.body);
+        let mut ends = vec![self.current];
+
+        if let (Some(handler), Some(catch_entry)) = (&stmt.handler, catch_entry) {
+            let context = self.try_contexts.last_mut().unwrap();
+            context.catch_entry = None;
+            if let Some(finally_entry) = finally_entry {
+                // The `catch` clause may throw as well.
+                context.pending_jumps.push(Jump::Throw);
+                self.add_edge(catch_entry, finally_entry);
+            }
+            self.current = catch_entry;
+            self.build_statements(&handler.body.body);
+            ends.push(self.current);
+        }
+        let context = self.try_contexts.pop().unwrap();
+
+        self.current = self.new_block();
+        for end in ends {
+            self.add_edge(end, self.current);
+        }
+
+        if let (Some(finalizer), Some(finally_entry)) = (&stmt.finalizer, finally_entry) {
+            // The `finally` clause is built twice, once after the normal completion of the
+            // `try` statement and once for the pending jumps, which continue from its end.
+            let start = self.replay.unwrap_or(self.statements.len());
+            self.build_statements(&finalizer.body);
+            let normal_end = self.current;
+            let replay = mem::replace(&mut self.replay, Some(start));
+
+            self.current = finally_entry;
+            self.build_statements(&finalizer.body);
+            let abrupt_end = self.current;
+            for jump in context.pending_jumps {
+                self.current = abrupt_end;
+                self.jump(jump);
+            }
+
+            self.replay = replay;
+            self.current = normal_end;
+        }
+    }
+
+    /// Jumps from the current block through the `finally` clauses to the target,
+    /// and continues in a new unreachable block.
+    fn jump(&mut self, jump: Jump) {
+        let try_depth = match jump {
+            Jump::To(index, _) => self.jump_targets[index].try_depth,
+            Jump::Return | Jump::Throw => 0,
+        };
+        let mut target = match jump {
+            Jump::To(_, block) => block,
+            Jump::Return | Jump::Throw => self.exit,
+        };
+        for context in self.try_contexts[try_depth..].iter_mut().rev() {
+            if let (Jump::Throw, Some(catch_entry)) = (jump, context.catch_entry) {
+                target = catch_entry;
+                break;
+            }
+            if let Some(finally_entry) = context.finally_entry {
+                context.pending_jumps.push(jump);
+                target = finally_entry;
+                break;
+            }
+        }
+        self.add_edge(self.current, target);
+        self.current = self.new_block();
+    }
+}
+
+/// Whether the loop test is a literal which is always truthy, e.g. `while (true)`.
+fn is_constant_truthy(test: &Expression) -> bool {
+    match test.without_parenthesized() {
+        Expression::BooleanLiteral(lit) => lit.value,
+        Expression::NumberLiteral(lit) => lit.value != 0.0 && !lit.value.is_nan(),
+        Expression::StringLiteral(lit) => !lit.value.is_empty(),
+        _ => false,
+    }
+}
@@ -24,26 +24,27 @@ impl<'a> JSDocBuilder<'a> {
 
     /// Save the span if the given kind has a jsdoc comment attached
     pub fn retrieve_jsdoc_comment(&mut self, kind: AstKind<'a>) -> bool {
-        if !kind.is_declaration() {
+        if !kind.is_declaration()
+            && !matches!(kind, AstKind::MethodDefinition(_) | AstKind::PropertyDefinition(_))
+        {
             return false;
         }
         let span = kind.span();
-        let comment_text = self.find_jsdoc_comment(span);
-        if let Some(comment_text) = comment_text {
-            self.docs.insert(span, JSDocComment::new(comment_text));
-        }
-        comment_text.is_some()
+        let Some(comment) = self.find_jsdoc_comment(span) else { return false };
+        self.docs.insert(span, comment);
+        true
     }
 
     /// Find the jsdoc doc in frontend this span, a.k.a leading comment
-    fn find_jsdoc_comment(&self, span: Span) -> Option<&'a str> {
+    fn find_jsdoc_comment(&self, span: Span) -> Option<JSDocComment<'a>> {
         let (start, comment) = self.trivias.comments().range(..span.start).next()?;
 
         if comment.is_single_line() {
             return None;
         }
 
-        let comment_text = Span::new(*start, comment.end()).source_text(self.source_text);
+        let comment_span = Span::new(*start, comment.end());
+        let comment_text = comment_span.source_text(self.source_text);
 
         // Comments beginning with /*, /***, or more than 3 stars will be ignored.
         let mut chars = comment_text.chars();
@@ -61,7 +62,7 @@ impl<'a> JSDocBuilder<'a> {
             return None;
         }
 
-        Some(comment_text)
+        Some(JSDocComment::new(comment_text, comment_span))
     }
 }
 
@@ -142,4 +143,27 @@ mod test {
             test_jsdoc(source_text, "function foo() {}");
         }
     }
+
+    #[test]
+    fn tags() {
+        let allocator = Allocator::default();
+        let source_text = "
+            class A {
+                /**
+                 * @param {string} a - the input
+                 * @returns {number}
+                 */
+                foo(a) {}
+            }";
+        let jsdoc = get_jsdoc(&allocator, source_text, "foo(a) {}").unwrap();
+        let params = jsdoc.params().collect::<Vec<_>>();
+        assert_eq!(params.len(), 1);
+        assert_eq!((params[0].name, params[0].r#type.map(|t| t.value)), ("a", Some("string")));
+        assert_eq!(jsdoc.returns().map(|t| t.value), Some("number"));
+        assert!(!jsdoc.is_deprecated());
+        let span = jsdoc.tags()[0].span;
+        assert_eq!(span.source_text(source_text), "@param {string} a - the input");
+        let span = jsdoc.returns().unwrap().span;
+        assert_eq!(span.source_text(source_text), "number");
+    }
 }
@@ -6,7 +6,7 @@ pub use builder::JSDocBuilder;
 use oxc_span::{GetSpan, Span};
 
 use self::parser::JSDocParser;
-pub use self::parser::JSDocTag;
+pub use self::parser::{JSDocTag, JSDocTagKind, JSDocType, JSDocTypeKind, Param, Template};
 use crate::AstNode;
 
 mod parser;
@@ -20,6 +20,8 @@ pub struct JSDoc<'a> {
 #[derive(Debug, Clone)]
 pub struct JSDocComment<'a> {
     comment: &'a str,
+    /// The span of `comment` in the source text, without the `/*` and `*/`
+    span: Span,
     /// Cached JSDocTags
     tags: OnceCell<Vec<JSDocTag<'a>>>,
 }
@@ -43,11 +45,51 @@ impl<'a> JSDoc<'a> {
 }
 
 impl<'a> JSDocComment<'a> {
-    pub fn new(comment: &'a str) -> JSDocComment<'a> {
-        Self { comment, tags: OnceCell::new() }
+    pub fn new(comment: &'a str, span: Span) -> JSDocComment<'a> {
+        Self { comment, span, tags: OnceCell::new() }
+    }
+
+    pub fn span(&self) -> Span {
+        self.span
     }
 
     pub fn tags<'b>(&'b self) -> &'b Vec<JSDocTag<'a>> {
-        self.tags.get_or_init(|| JSDocParser::new(self.comment).parse())
+        self.tags.get_or_init(|| JSDocParser::new(self.comment, self.span.start).parse())
+    }
+
+    pub fn is_deprecated(&self) -> bool {
+        self.tags().iter().any(JSDocTag::is_deprecated)
+    }
+
+    /// The `@param` tags
+    pub fn params<'b>(&'b self) -> impl Iterator<Item = Param<'a>> + 'b {
+        self.tags().iter().filter_map(|tag| match tag.kind {
+            JSDocTagKind::Param(param) => Some(param),
+            _ => None,
+        })
+    }
+
+    /// The type of the `@returns` tag
+    pub fn returns(&self) -> Option<JSDocType<'a>> {
+        self.tags().iter().find_map(|tag| match tag.kind {
+            JSDocTagKind::Returns(r#type) => r#type,
+            _ => None,
+        })
+    }
+
+    /// The type of the `@type` tag
+    pub fn r#type(&self) -> Option<JSDocType<'a>> {
+        self.tags().iter().find_map(|tag| match tag.kind {
+            JSDocTagKind::Type(r#type) => r#type,
+            _ => None,
+        })
+    }
+
+    /// The `@template` tags
+    pub fn templates<'b>(&'b self) -> impl Iterator<Item = Template<'a>> + 'b {
+        self.tags().iter().filter_map(|tag| match tag.kind {
+            JSDocTagKind::Template(template) => Some(template),
+            _ => None,
+        })
     }
 }
@@ -1,24 +1,27 @@
 use std::str::FromStr;
 
+use oxc_span::Span;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum ParamTypeKind {
+pub enum JSDocTypeKind {
     Any,
     Repeated,
 }
 
+/// The type expression between the braces, e.g. `string` in `@param {string} a`
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct ParamType<'a> {
-    value: &'a str,
+pub struct JSDocType<'a> {
+    pub value: &'a str,
+    pub span: Span,
 }
 
-impl<'a> ParamType<'a> {
-    #[allow(unused)]
-    pub fn kind(&self) -> Option<ParamTypeKind> {
-        ParamTypeKind::from_str(self.value).map(Option::Some).unwrap_or_default()
+impl<'a> JSDocType<'a> {
+    pub fn kind(&self) -> Option<JSDocTypeKind> {
+        JSDocTypeKind::from_str(self.value).ok()
     }
 }
 
-impl FromStr for ParamTypeKind {
+impl FromStr for JSDocTypeKind {
     type Err = ();
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
@@ -35,34 +38,50 @@ impl FromStr for ParamTypeKind {
     }
 }
 
-#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+/// `@param {type} name - description`, `@arg` and `@argument` are its synonyms
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Param<'a> {
-    name: &'a str,
-    r#type: Option<ParamType<'a>>,
+    /// `a` in `@param a` and `@param [a=1]`, `a.b` for the properties of a parameter
+    pub name: &'a str,
+    pub r#type: Option<JSDocType<'a>>,
+    /// `@param [a]`
+    pub optional: bool,
 }
 
+/// `@template {constraint} T, U`
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum JSDocTagKind<'a> {
-    Deprecated,
-    Param(Param<'a>),
+pub struct Template<'a> {
+    pub constraint: Option<JSDocType<'a>>,
+    /// The names separated by the commas, see [Template::names]
+    pub names: &'a str,
 }
 
-impl<'a> FromStr for JSDocTagKind<'a> {
-    type Err = ();
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "deprecated" => Ok(Self::Deprecated),
-            "param" => Ok(Self::Param(Param::default())),
-            _ => Err(()),
-        }
+impl<'a> Template<'a> {
+    pub fn names(&self) -> impl Iterator<Item = &'a str> {
+        self.names.split(',').map(str::trim).filter(|name| !name.is_empty())
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JSDocTagKind<'a> {
+    Deprecated,
+    Param(Param<'a>),
+    /// `@returns {type}`, or its synonym `@return`
+    Returns(Option<JSDocType<'a>>),
+    /// `@type {type}`
+    Type(Option<JSDocType<'a>>),
+    Template(Template<'a>),
+    /// The other tags, with their name
+    Unknown(&'a str),
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct JSDocTag<'a> {
     pub kind: JSDocTagKind<'a>,
+    /// The rest of the line of the tag
     pub description: &'a str,
+    /// From the `@` to the end of the description, in the source text
+    pub span: Span,
 }
 
 impl<'a> JSDocTag<'a> {
@@ -71,143 +90,215 @@ impl<'a> JSDocTag<'a> {
     }
 }
 
+/// Parser of the tags of a JSDoc comment, the tags start their line, e.g. ` * @param a`
 #[derive(Debug)]
 pub struct JSDocParser<'a> {
     source_text: &'a str,
+    /// The offset of `source_text` in the whole source text
+    offset: u32,
     current: usize,
 }
 
 impl<'a> JSDocParser<'a> {
-    pub fn new(source_text: &'a str) -> Self {
-        Self { source_text, current: 0 }
+    pub fn new(source_text: &'a str, offset: u32) -> Self {
+        Self { source_text, offset, current: 0 }
     }
 
     pub fn parse(mut self) -> Vec<JSDocTag<'a>> {
-        self.parse_comment(self.source_text)
+        let mut tags = vec![];
+        let mut line_start = true;
+        while let Some(c) = self.peek() {
+            match c {
+                '@' if line_start => {
+                    tags.push(self.parse_tag());
+                }
+                '\n' => {
+                    line_start = true;
+                    self.current += 1;
+                }
+                // The leading `*` of the lines, and the `/**` of the comment
+                ' ' | '\t' | '\r' | '*' | '/' => self.current += 1,
+                _ => {
+                    line_start = false;
+                    self.current += c.len_utf8();
+                }
+            }
+        }
+        tags
     }
 
-    fn advance(&mut self) {
-        if self.current < self.source_text.len() {
-            self.current += 1;
-        }
+    fn peek(&self) -> Option<char> {
+        self.source_text[self.current..].chars().next()
     }
 
-    fn at(&mut self, c: char) -> bool {
-        let Some(ch) = self.source_text.chars().nth(self.current) else { return false };
-        if ch == c {
-            self.advance();
-            true
-        } else {
-            false
+    fn eat(&mut self, c: char) -> bool {
+        let eaten = self.peek() == Some(c);
+        if eaten {
+            self.current += c.len_utf8();
         }
+        eaten
     }
 
-    fn take_until(&mut self, s: &'a str, predicate: fn(char) -> bool) -> &'a str {
+    fn take_until(&mut self, predicate: fn(char) -> bool) -> &'a str {
         let start = self.current;
-        while let Some(c) = s.chars().nth(self.current) {
-            if predicate(c) {
-                break;
-            }
-            self.current += 1;
-        }
-        &s[start..self.current]
+        let rest = &self.source_text[start..];
+        self.current += rest.find(predicate).unwrap_or(rest.len());
+        &self.source_text[start..self.current]
     }
 
-    fn skip_whitespace(&mut self, s: &'a str) {
-        while let Some(c) = s.chars().nth(self.current) {
-            if c != ' ' {
-                break;
-            }
-            self.current += 1;
-        }
+    fn skip_whitespace(&mut self) {
+        self.take_until(|c| c != ' ' && c != '\t');
     }
 
-    fn parse_comment(&mut self, comment: &'a str) -> Vec<JSDocTag<'a>> {
-        let mut tags = vec![];
+    #[allow(clippy::cast_possible_truncation)]
+    fn span(&self, start: usize, end: usize) -> Span {
+        Span::new(self.offset + start as u32, self.offset + end as u32)
+    }
+
+    /// The tag from its `@`
+    fn parse_tag(&mut self) -> JSDocTag<'a> {
+        let start = self.current;
+        self.current += 1;
+        let name = self.take_until(|c| c.is_whitespace() || c == '*' || c == '{');
+        self.skip_whitespace();
+        let kind = match name {
+            "deprecated" => JSDocTagKind::Deprecated,
+            "param" | "arg" | "argument" => JSDocTagKind::Param(self.parse_param()),
+            "returns" | "return" => JSDocTagKind::Returns(self.parse_type()),
+            "type" => JSDocTagKind::Type(self.parse_type()),
+            "template" => JSDocTagKind::Template(self.parse_template()),
+            _ => JSDocTagKind::Unknown(name),
+        };
+        let content_end = self.source_text[..self.current].trim_end().len();
+        let (description_start, description) = self.parse_description();
+        let end = if description.is_empty() {
+            content_end
+        } else {
+            description_start + description.len()
+        };
+        JSDocTag { kind, description, span: self.span(start, end) }
+    }
 
-        while let Some(c) = comment.chars().nth(self.current) {
+    /// `{type}`, the braces can be nested, e.g. `{{ a: string }}`.
+    /// An unterminated type ends at the next whitespace.
+    fn parse_type(&mut self) -> Option<JSDocType<'a>> {
+        if !self.eat('{') {
+            return None;
+        }
+        let start = self.current;
+        let mut depth = 0;
+        let mut end = None;
+        for (i, c) in self.source_text[start..].char_indices() {
             match c {
-                '@' => {
-                    self.current += 1;
-                    let Some(tag) = self.parse_tag(comment) else { break };
-                    self.current += tag.description.len();
-                    tags.push(tag);
-                }
-                _ => {
-                    self.current += 1;
+                '{' => depth += 1,
+                '}' if depth == 0 => {
+                    end = Some(start + i);
+                    break;
                 }
+                '}' => depth -= 1,
+                '\n' => break,
+                _ => {}
             }
         }
-
-        tags
-    }
-
-    fn parse_tag(&mut self, comment: &'a str) -> Option<JSDocTag<'a>> {
-        let tag = self.take_until(comment, |c| c == ' ' || c == '\n');
-        JSDocTagKind::from_str(tag).map_or(None, |kind| match kind {
-            JSDocTagKind::Deprecated => Some(self.parse_deprecated_tag(comment)),
-            JSDocTagKind::Param { .. } => Some(self.parse_param_tag(comment)),
-        })
+        let value = if let Some(end) = end {
+            self.current = end + 1;
+            &self.source_text[start..end]
+        } else {
+            self.take_until(char::is_whitespace)
+        };
+        self.skip_whitespace();
+        let span = self.span(start, start + value.len());
+        Some(JSDocType { value: value.trim(), span })
     }
 
-    fn parse_deprecated_tag(&mut self, comment: &'a str) -> JSDocTag<'a> {
-        self.skip_whitespace(comment);
-        let description = self.take_until(comment, |c| c == '\n' || c == '*');
-        JSDocTag { kind: JSDocTagKind::Deprecated, description }
+    /// `{type} name`, `{type} [name]` or `{type} [name=default]`
+    fn parse_param(&mut self) -> Param<'a> {
+        let r#type = self.parse_type();
+        let optional = self.eat('[');
+        let name = if optional {
+            let name = self.take_until(|c| c == ']' || c == '=' || c == '\n');
+            self.take_until(|c| c == ']' || c == '\n');
+            self.eat(']');
+            name.trim()
+        } else {
+            self.take_until(|c| c.is_whitespace() || c == '*')
+        };
+        self.skip_whitespace();
+        Param { name, r#type, optional }
     }
 
-    fn parse_param_tag(&mut self, comment: &'a str) -> JSDocTag<'a> {
-        self.skip_whitespace(comment);
-
-        let mut r#type = None;
-
-        if self.at('{') {
-            // If we hit a space, then treat it as the end of the type annotation.
-            let type_annotation = self.take_until(comment, |c| c == '}' || c == ' ');
-            r#type = Some(ParamType { value: type_annotation });
-            if self.at('}') {
-                self.skip_whitespace(comment);
+    /// `{constraint} T, U`
+    fn parse_template(&mut self) -> Template<'a> {
+        let constraint = self.parse_type();
+        let start = self.current;
+        loop {
+            self.skip_whitespace();
+            let name = self.take_until(|c| !(c.is_alphanumeric() || c == '_' || c == '$'));
+            self.skip_whitespace();
+            if name.is_empty() || !self.eat(',') {
+                break;
             }
-            self.skip_whitespace(comment);
         }
+        let names = self.source_text[start..self.current].trim_end();
+        self.skip_whitespace();
+        Template { constraint, names }
+    }
 
-        let name = self.take_until(comment, |c| c == ' ' || c == '\n');
-
-        self.skip_whitespace(comment);
-        if self.at('-') {
-            self.skip_whitespace(comment);
+    /// The rest of the line after the optional `-` separator, and its offset
+    fn parse_description(&mut self) -> (usize, &'a str) {
+        if self.eat('-') {
+            self.skip_whitespace();
         }
-
-        let description = self.take_until(comment, |c| c == '\n' || c == '*');
-
-        JSDocTag { kind: JSDocTagKind::Param(Param { name, r#type }), description }
+        let start = self.current;
+        let line = self.take_until(|c| c == '\n').trim_end();
+        (start, line.strip_suffix("*/").unwrap_or(line).trim_end_matches('*').trim_end())
     }
 }
 
 #[cfg(test)]
 mod test {
+    use oxc_span::Span;
+
     use super::JSDocParser;
-    use crate::jsdoc::parser::{JSDocTag, JSDocTagKind, Param, ParamType, ParamTypeKind};
+    use crate::jsdoc::parser::{JSDocTag, JSDocTagKind, JSDocType, JSDocTypeKind, Param, Template};
+
+    fn parse(source: &str) -> Vec<JSDocTag<'_>> {
+        JSDocParser::new(source, 0).parse()
+    }
+
+    fn kinds<'a>(tags: &[JSDocTag<'a>]) -> Vec<JSDocTagKind<'a>> {
+        tags.iter().map(|tag| tag.kind).collect()
+    }
+
+    fn param<'a>(name: &'a str, r#type: Option<JSDocType<'a>>) -> JSDocTagKind<'a> {
+        JSDocTagKind::Param(Param { name, r#type, optional: false })
+    }
 
     #[test]
-    fn deduces_correct_param_kind() {
-        let param = Param { name: "a", r#type: Some(ParamType { value: "string" }) };
-        assert_eq!(param.r#type.and_then(|t| t.kind()), None);
+    fn deduces_correct_type_kind() {
+        let r#type = JSDocType { value: "string", span: Span::default() };
+        assert_eq!(r#type.kind(), None);
 
-        let param = Param { name: "a", r#type: Some(ParamType { value: "...string" }) };
-        assert_eq!(param.r#type.and_then(|t| t.kind()), Some(ParamTypeKind::Repeated));
+        let r#type = JSDocType { value: "...string", span: Span::default() };
+        assert_eq!(r#type.kind(), Some(JSDocTypeKind::Repeated));
 
-        let param = Param { name: "a", r#type: Some(ParamType { value: "*" }) };
-        assert_eq!(param.r#type.and_then(|t| t.kind()), Some(ParamTypeKind::Any));
+        let r#type = JSDocType { value: "*", span: Span::default() };
+        assert_eq!(r#type.kind(), Some(JSDocTypeKind::Any));
     }
 
     #[test]
     fn parses_single_line_jsdoc() {
         let source = "/** @deprecated */";
 
-        let tags = JSDocParser::new(source).parse();
-        assert_eq!(tags.len(), 1);
-        assert_eq!(tags, vec![JSDocTag { kind: JSDocTagKind::Deprecated, description: "" }]);
+        let tags = parse(source);
+        assert_eq!(
+            tags,
+            vec![JSDocTag {
+                kind: JSDocTagKind::Deprecated,
+                description: "",
+                span: Span::new(4, 15)
+            }]
+        );
     }
 
     #[test]
@@ -216,9 +307,9 @@ mod test {
         */
         "#;
 
-        let tags = JSDocParser::new(source).parse();
-        assert_eq!(tags.len(), 1);
-        assert_eq!(tags, vec![JSDocTag { kind: JSDocTagKind::Deprecated, description: "" }]);
+        let tags = parse(source);
+        assert_eq!(kinds(&tags), vec![JSDocTagKind::Deprecated]);
+        assert_eq!(tags[0].description, "");
     }
 
     #[test]
@@ -229,18 +320,8 @@ mod test {
         */
        "#;
 
-        let tags = JSDocParser::new(source).parse();
-        assert_eq!(tags.len(), 2);
-        assert_eq!(
-            tags,
-            vec![
-                JSDocTag {
-                    kind: JSDocTagKind::Param(Param { name: "a", r#type: None }),
-                    description: ""
-                },
-                JSDocTag { kind: JSDocTagKind::Deprecated, description: "" },
-            ]
-        );
+        let tags = parse(source);
+        assert_eq!(kinds(&tags), vec![param("a", None), JSDocTagKind::Deprecated]);
     }
 
     #[test]
@@ -251,56 +332,85 @@ mod test {
         */
        "#;
 
-        let tags = JSDocParser::new(source).parse();
-        assert_eq!(tags.len(), 2);
+        let tags = parse(source);
+        assert_eq!(kinds(&tags), vec![param("a", None), JSDocTagKind::Deprecated]);
+        assert_eq!(tags[0].description, "");
+        assert_eq!(tags[1].description, "since version 1.0");
+        let span = tags[1].span;
         assert_eq!(
-            tags,
-            vec![
-                JSDocTag {
-                    kind: JSDocTagKind::Param(Param { name: "a", r#type: None }),
-                    description: ""
-                },
-                JSDocTag { kind: JSDocTagKind::Deprecated, description: "since version 1.0" },
-            ]
+            &source[span.start as usize..span.end as usize],
+            "@deprecated since version 1.0"
         );
     }
 
+    #[test]
+    fn ignores_inline_at_signs() {
+        let source = "/** Send an email to foo@example.com, see {@link bar} */";
+        assert!(parse(source).is_empty());
+    }
+
     #[test]
     fn parses_param_type_annotation() {
         let source = r#"/**
         * @param {string} a
         * @param {string b
         * @param {string} c - description
+        * @arg {{ x: number }} [d=1] optional
         */
        "#;
 
-        let tags = JSDocParser::new(source).parse();
-        assert_eq!(tags.len(), 3);
+        let tags = parse(source);
+        let types = tags
+            .iter()
+            .map(|tag| {
+                let JSDocTagKind::Param(param) = tag.kind else { unreachable!() };
+                let r#type = param.r#type.unwrap();
+                assert_eq!(
+                    &source[r#type.span.start as usize..r#type.span.end as usize].trim(),
+                    &r#type.value
+                );
+                (param.name, r#type.value, param.optional, tag.description)
+            })
+            .collect::<Vec<_>>();
         assert_eq!(
-            tags,
+            types,
             vec![
-                JSDocTag {
-                    kind: JSDocTagKind::Param(Param {
-                        name: "a",
-                        r#type: Some(ParamType { value: "string" })
-                    }),
-                    description: ""
-                },
-                JSDocTag {
-                    kind: JSDocTagKind::Param(Param {
-                        name: "b",
-                        r#type: Some(ParamType { value: "string" })
-                    }),
-                    description: ""
-                },
-                JSDocTag {
-                    kind: JSDocTagKind::Param(Param {
-                        name: "c",
-                        r#type: Some(ParamType { value: "string" })
-                    }),
-                    description: "description"
-                },
+                ("a", "string", false, ""),
+                ("b", "string", false, ""),
+                ("c", "string", false, "description"),
+                ("d", "{ x: number }", true, "optional"),
             ]
         );
     }
+
+    #[test]
+    fn parses_returns_type_template() {
+        let source = r#"/**
+        * @template T, U
+        * @template {string} K - the keys
+        * @type {(a: T) => U}
+        * @returns {Promise<void>} nothing
+        * @return
+        * @see foo
+        */"#;
+
+        let tags = parse(source);
+        let JSDocTagKind::Template(template) = tags[0].kind else { unreachable!() };
+        assert_eq!(template.names().collect::<Vec<_>>(), vec!["T", "U"]);
+        assert_eq!(template.constraint, None);
+        let JSDocTagKind::Template(template @ Template { constraint: Some(constraint), .. }) =
+            tags[1].kind
+        else {
+            unreachable!()
+        };
+        assert_eq!((template.names, constraint.value), ("K", "string"));
+        assert_eq!(tags[1].description, "the keys");
+        let JSDocTagKind::Type(Some(r#type)) = tags[2].kind else { unreachable!() };
+        assert_eq!(r#type.value, "(a: T) => U");
+        let JSDocTagKind::Returns(Some(r#type)) = tags[3].kind else { unreachable!() };
+        assert_eq!((r#type.value, tags[3].description), ("Promise<void>", "nothing"));
+        assert_eq!(tags[4].kind, JSDocTagKind::Returns(None));
+        assert_eq!((tags[5].kind, tags[5].description), (JSDocTagKind::Unknown("see"), "foo"));
+        assert_eq!(tags.len(), 6);
+    }
 }
@@ -1,6 +1,8 @@
 mod binder;
 mod builder;
 mod checker;
+mod comments;
+mod control_flow;
 mod diagnostics;
 mod jsdoc;
 mod module_record;
@@ -12,7 +14,12 @@ mod symbol;
 use std::{rc::Rc, sync::Arc};
 
 pub use builder::{SemanticBuilder, SemanticBuilderReturn};
-pub use jsdoc::{JSDoc, JSDocComment, JSDocTag};
+pub use comments::NodeComments;
+pub use control_flow::{BasicBlockId, ControlFlowGraph};
+pub use jsdoc::{
+    JSDoc, JSDocComment, JSDocTag, JSDocTagKind, JSDocType, JSDocTypeKind, Param as JSDocParam,
+    Template as JSDocTemplate,
+};
 use oxc_ast::{ast::IdentifierReference, AstKind, TriviasMap};
 use oxc_span::SourceType;
 pub use oxc_syntax::{
@@ -46,6 +53,8 @@ pub struct Semantic<'a> {
 
     jsdoc: JSDoc<'a>,
 
+    comments: NodeComments,
+
     unused_labels: Vec<AstNodeId>,
 
     redeclare_variables: Vec<VariableInfo>,
@@ -80,6 +89,11 @@ impl<'a> Semantic<'a> {
         &self.jsdoc
     }
 
+    /// The comments attached to the nodes
+    pub fn comments(&self) -> &NodeComments {
+        &self.comments
+    }
+
     pub fn module_record(&self) -> &Arc<ModuleRecord> {
         &self.module_record
     }
@@ -136,9 +136,15 @@ impl ScopeTree {
         let mut parts = std::vec::Vec::with_capacity(1);
         expr.gather(&mut |part| parts.push(part));
         let name = parts.join("$");
-        let name = name.trim_start_matches('_');
+        self.generate_uid(&name)
+    }
+
+    /// Generate a unique identifier `_name`, `_name2`, `_name3` ... which is not bound in the root scope.
+    /// <https://github.com/babel/babel/blob/419644f27c5c59deb19e71aaabd417a3bc5483ca/packages/babel-traverse/src/scope/index.ts#L495>
+    pub fn generate_uid(&self, name: &str) -> Atom {
+        let name = name.trim_start_matches('_').trim_end_matches(|c: char| c.is_ascii_digit());
         for i in 0.. {
-            let name = Self::generate_uid(name, i);
+            let name = Self::uid_name(name, i);
             if !self.has_binding(ScopeId::new(0), &name) {
                 return name;
             }
@@ -146,7 +152,7 @@ impl ScopeTree {
         unreachable!()
     }
 
-    fn generate_uid(name: &str, i: i32) -> Atom {
+    fn uid_name(name: &str, i: i32) -> Atom {
         Atom::from(if i > 1 { format!("_{name}{i}") } else { format!("_{name}") })
     }
 }
@@ -1,6 +1,6 @@
 mod util;
 
-use oxc_semantic::SymbolFlags;
+use oxc_semantic::{ScopeFlags, SymbolFlags};
 use util::SemanticTester;
 
 #[test]
@@ -36,6 +36,15 @@ fn test_var_simple() {
         .test();
 }
 
+#[test]
+fn test_using_declaration() {
+    SemanticTester::js("{ using x = foo(); }")
+        .has_some_symbol("x")
+        .contains_flags(SymbolFlags::BlockScopedVariable | SymbolFlags::ConstVariable)
+        .is_not_in_scope(ScopeFlags::Top)
+        .test();
+}
+
 #[test]
 fn test_var_read_write() {
     SemanticTester::js("let x; x += 1")
@@ -88,3 +97,27 @@ fn test_types_simple() {
         .has_number_of_references(1)
         .test();
 }
+
+#[test]
+fn test_types_heritage_and_query_references() {
+    SemanticTester::ts("import { A } from 'a'; export class B implements A {}")
+        .has_root_symbol("A")
+        .has_number_of_references(1)
+        .test();
+
+    SemanticTester::ts("import { A } from 'a'; export interface B extends A {}")
+        .has_root_symbol("A")
+        .has_number_of_references(1)
+        .test();
+
+    SemanticTester::ts("const a = 1; export type B = typeof a;")
+        .has_root_symbol("a")
+        .has_number_of_references(1)
+        .test();
+
+    // Only the left-most identifier of a qualified name is a reference
+    SemanticTester::ts("import * as ns from 'a'; export type B = ns.A.C;")
+        .has_root_symbol("ns")
+        .has_number_of_references(1)
+        .test();
+}
@@ -1,9 +1,13 @@
 mod atom;
+mod line_index;
 mod source_type;
 mod span;
 
 pub use crate::{
     atom::Atom,
-    source_type::{Language, LanguageVariant, ModuleKind, SourceType, VALID_EXTENSIONS},
-    span::{GetSpan, Span},
+    line_index::{LineCol, LineIndex},
+    source_type::{
+        Language, LanguageVariant, ModuleKind, PackageType, SourceType, VALID_EXTENSIONS,
+    },
+    span::{GetSpan, GetSpanMut, Span},
 };
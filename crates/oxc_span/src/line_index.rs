@@ -0,0 +1,169 @@
+use crate::Span;
+
+/// A line and a column, both from 0
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LineCol {
+    pub line: u32,
+    pub col: u32,
+}
+
+impl LineCol {
+    pub const fn new(line: u32, col: u32) -> Self {
+        Self { line, col }
+    }
+}
+
+/// Converts the byte offsets of a source text to lines and columns, and back
+///
+/// The line starts are computed once, the lines end at the ECMAScript line terminators: `\n`,
+/// `\r\n`, `\r`, U+2028 and U+2029. The columns count the UTF-8 bytes, or the UTF-16 code units
+/// as the LSP, the source maps and ESTree do.
+/// The offsets past the end of the source text are at its end.
+#[derive(Debug, Clone)]
+pub struct LineIndex {
+    len: u32,
+    /// The byte offsets of the line starts
+    line_starts: Vec<u32>,
+    non_ascii: Vec<NonAscii>,
+}
+
+/// A non-ASCII character of the source text
+#[derive(Debug, Clone, Copy)]
+struct NonAscii {
+    /// The byte offset after it
+    end: u32,
+    /// The number of UTF-8 bytes exceeding the UTF-16 code units up to its end
+    exceeding: u32,
+    /// Whether it is a surrogate pair in UTF-16
+    surrogate_pair: bool,
+}
+
+impl LineIndex {
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn new(source_text: &str) -> Self {
+        let mut line_starts = vec![0];
+        let mut non_ascii = vec![];
+        let mut chars = source_text.char_indices().peekable();
+        while let Some((offset, c)) = chars.next() {
+            let end = (offset + c.len_utf8()) as u32;
+            match c {
+                '\r' if chars.peek().is_some_and(|(_, c)| *c == '\n') => {}
+                '\n' | '\r' | '\u{2028}' | '\u{2029}' => line_starts.push(end),
+                _ => {}
+            }
+            if !c.is_ascii() {
+                let exceeding = non_ascii.last().map_or(0, |c: &NonAscii| c.exceeding);
+                let exceeding = exceeding + (c.len_utf8() - c.len_utf16()) as u32;
+                non_ascii.push(NonAscii { end, exceeding, surrogate_pair: c.len_utf16() == 2 });
+            }
+        }
+        Self { len: source_text.len() as u32, line_starts, non_ascii }
+    }
+
+    pub fn line_count(&self) -> usize {
+        self.line_starts.len()
+    }
+
+    /// The line and the column in UTF-8 bytes of the byte offset
+    pub fn line_col(&self, offset: u32) -> LineCol {
+        let offset = offset.min(self.len);
+        let line = self.line(offset);
+        LineCol::new(line, offset - self.line_starts[line as usize])
+    }
+
+    /// The line and the column in UTF-16 code units of the byte offset
+    pub fn line_col_utf16(&self, offset: u32) -> LineCol {
+        let offset = offset.min(self.len);
+        let line = self.line(offset);
+        let col = self.utf16_offset(offset) - self.utf16_offset(self.line_starts[line as usize]);
+        LineCol::new(line, col)
+    }
+
+    /// The start and the end of the span, with the columns in UTF-8 bytes
+    pub fn range(&self, span: Span) -> (LineCol, LineCol) {
+        (self.line_col(span.start), self.line_col(span.end))
+    }
+
+    /// The start and the end of the span, with the columns in UTF-16 code units
+    pub fn range_utf16(&self, span: Span) -> (LineCol, LineCol) {
+        (self.line_col_utf16(span.start), self.line_col_utf16(span.end))
+    }
+
+    /// The offset in UTF-16 code units from the start of the source text of the byte offset
+    pub fn utf16_offset(&self, offset: u32) -> u32 {
+        let offset = offset.min(self.len);
+        let index = self.non_ascii.partition_point(|c| c.end <= offset);
+        offset - self.exceeding_before(index)
+    }
+
+    /// The byte offset of a line and a column in UTF-8 bytes,
+    /// `None` if the line does not exist or the column is past its end
+    pub fn offset(&self, line_col: LineCol) -> Option<u32> {
+        let start = *self.line_starts.get(line_col.line as usize)?;
+        self.offset_in_line(line_col.line, start.checked_add(line_col.col)?)
+    }
+
+    /// The byte offset of a line and a column in UTF-16 code units,
+    /// `None` if the line does not exist, the column is past its end or between the two halves
+    /// of a surrogate pair
+    pub fn offset_utf16(&self, line_col: LineCol) -> Option<u32> {
+        let start = *self.line_starts.get(line_col.line as usize)?;
+        let utf16_offset = self.utf16_offset(start).checked_add(line_col.col)?;
+        let index = self.non_ascii.partition_point(|c| c.end - c.exceeding <= utf16_offset);
+        // The character after the offset ends in UTF-16 right after it
+        if self
+            .non_ascii
+            .get(index)
+            .is_some_and(|c| c.surrogate_pair && c.end - c.exceeding == utf16_offset + 1)
+        {
+            return None;
+        }
+        let offset = utf16_offset + self.exceeding_before(index);
+        self.offset_in_line(line_col.line, offset)
+    }
+
+    /// The number of UTF-8 bytes exceeding the UTF-16 code units before the non-ASCII
+    /// character at `index`
+    fn exceeding_before(&self, index: usize) -> u32 {
+        index.checked_sub(1).map_or(0, |index| self.non_ascii[index].exceeding)
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn line(&self, offset: u32) -> u32 {
+        (self.line_starts.partition_point(|start| *start <= offset) - 1) as u32
+    }
+
+    fn offset_in_line(&self, line: u32, offset: u32) -> Option<u32> {
+        let end = self.line_starts.get(line as usize + 1).map_or(self.len + 1, |start| *start);
+        (offset < end).then_some(offset)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{LineCol, LineIndex};
+    use crate::Span;
+
+    #[test]
+    fn line_col() {
+        let index = LineIndex::new("a\r\nbé😀c\nd");
+        assert_eq!(index.line_count(), 3);
+        assert_eq!(index.line_col_utf16(0), LineCol::new(0, 0));
+        assert_eq!(index.line_col_utf16(3), LineCol::new(1, 0));
+        assert_eq!(index.line_col_utf16(6), LineCol::new(1, 2));
+        assert_eq!(index.line_col_utf16(10), LineCol::new(1, 4));
+        assert_eq!(index.line_col_utf16(12), LineCol::new(2, 0));
+        assert_eq!(index.line_col_utf16(100), LineCol::new(2, 1));
+        assert_eq!(index.line_col(10), LineCol::new(1, 7));
+        assert_eq!(index.range(Span::new(4, 12)), (LineCol::new(1, 1), LineCol::new(2, 0)));
+        assert_eq!(index.utf16_offset(11), 8);
+
+        assert_eq!(index.offset(LineCol::new(1, 7)), Some(10));
+        assert_eq!(index.offset_utf16(LineCol::new(1, 4)), Some(10));
+        assert_eq!(index.offset_utf16(LineCol::new(2, 1)), Some(13));
+        assert_eq!(index.offset_utf16(LineCol::new(1, 9)), None);
+        assert_eq!(index.offset_utf16(LineCol::new(1, 2)), Some(6));
+        assert_eq!(index.offset_utf16(LineCol::new(1, 3)), None);
+        assert_eq!(index.offset(LineCol::new(3, 0)), None);
+    }
+}
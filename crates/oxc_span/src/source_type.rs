@@ -48,6 +48,14 @@ pub enum LanguageVariant {
     Jsx,
 }
 
+/// The `type` field of a `package.json`, which decides whether the `.js` and `.jsx` files of the
+/// package are CommonJS scripts or ES modules
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PackageType {
+    CommonJs,
+    Module,
+}
+
 #[derive(Debug)]
 pub struct UnknownExtension(pub String);
 
@@ -150,8 +158,26 @@ impl SourceType {
     /// returns `SourceTypeError::UnknownExtension` if:
     ///   * there is no file name
     ///   * the file extension is not one of "js", "mjs", "cjs", "jsx", "ts", "mts", "cts", "tsx"
+    ///
+    /// The `.cjs` files are scripts, the other files are modules. The TypeScript files are
+    /// always modules, because their `import` and `export` are compiled to CommonJS when needed.
     /// # Errors
     pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Self, UnknownExtension> {
+        Self::from_path_with_package_type(path, |_| None)
+    }
+
+    /// Converts file path to `SourceType`, like [SourceType::from_path], with the `type` of the
+    /// `package.json` of the file for the `.js` and `.jsx` files
+    ///
+    /// `package_type` is called with the file path only for these extensions, it returns `None`
+    /// when the file has no `package.json` or its `package.json` has no `type`, then the file is
+    /// a module. [SourceType::with_module] can then be used for a module kind detected from the
+    /// content of the file, e.g. by `oxc_parser::Tokens::has_module_syntax`.
+    /// # Errors
+    pub fn from_path_with_package_type<P: AsRef<Path>>(
+        path: P,
+        package_type: impl FnOnce(&Path) -> Option<PackageType>,
+    ) -> Result<Self, UnknownExtension> {
         let file_name = path
             .as_ref()
             .file_name()
@@ -185,6 +211,15 @@ impl SourceType {
             _ => LanguageVariant::Standard,
         };
 
-        Ok(Self { language, module_kind: ModuleKind::Module, variant, always_strict: false })
+        let module_kind = match extension {
+            "cjs" => ModuleKind::Script,
+            "js" | "jsx" => match package_type(path.as_ref()) {
+                Some(PackageType::CommonJs) => ModuleKind::Script,
+                _ => ModuleKind::Module,
+            },
+            _ => ModuleKind::Module,
+        };
+
+        Ok(Self { language, module_kind, variant, always_strict: false })
     }
 }
@@ -48,3 +48,8 @@ impl From<Span> for SourceSpan {
 pub trait GetSpan {
     fn span(&self) -> Span;
 }
+
+/// Get the span for an AST node to change it, e.g. to detach a generated node from the source
+pub trait GetSpanMut {
+    fn span_mut(&mut self) -> &mut Span;
+}
@@ -8,8 +8,134 @@ use serde::Deserialize;
 #[cfg_attr(feature = "serde", derive(Deserialize))]
 #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct CompilerAssumptions {
+    /// When spreading or destructuring an iterable, assume that it is an array.
+    /// See <https://babeljs.io/docs/assumptions#iterableisarray>.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub iterable_is_array: bool,
+
     /// When using operators that check for null or undefined, assume that they are never used with the special value document.all.
     /// See <https://babeljs.io/docs/assumptions#nodocumentall>.
     #[cfg_attr(feature = "serde", serde(default))]
     pub no_document_all: bool,
+
+    /// Assume that objects don't have setters for computed property keys, so that they can be
+    /// assigned rather than defined with `Object.defineProperty`.
+    /// See <https://babeljs.io/docs/assumptions#setcomputedproperties>.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub set_computed_properties: bool,
+
+    /// When using class fields, assume that they don't shadow any getter in the current class,
+    /// in its subclasses or in its superclass. Thus, it's safe to assign them rather than using `Object.defineProperty`.
+    /// See <https://babeljs.io/docs/assumptions#setpublicclassfields>.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub set_public_class_fields: bool,
+
+    /// Assume that "soft privacy" is enough for private fields, and thus they can be stored as
+    /// public non-enumerable properties with an unique name (rather than using an external `WeakMap`).
+    /// See <https://babeljs.io/docs/assumptions#privatefieldsasproperties>.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub private_fields_as_properties: bool,
+
+    /// When spreading or iterating an array-like object, assume that it implements a `[Symbol.iterator]`
+    /// method with the same behavior of the native `Array.prototype[Symbol.iterator]`.
+    /// See <https://babeljs.io/docs/assumptions#arraylikeisiterable>.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub array_like_is_iterable: bool,
+
+    /// When re-exporting a binding from a module, assume that it doesn't change and can be copied
+    /// to the exports object once.
+    /// See <https://babeljs.io/docs/assumptions#constantreexports>.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub constant_reexports: bool,
+
+    /// The super class of a class can be changed at any time with `Object.setPrototypeOf`, assume
+    /// that it never is.
+    /// See <https://babeljs.io/docs/assumptions#constantsuper>.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub constant_super: bool,
+
+    /// Assume that the `__esModule` property of the exports object can be enumerable.
+    /// See <https://babeljs.io/docs/assumptions#enumerablemodulemeta>.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub enumerable_module_meta: bool,
+
+    /// Functions have a `length` property of the number of parameters up to the first optional or
+    /// rest one, assume that it is never read so it doesn't need to be kept.
+    /// See <https://babeljs.io/docs/assumptions#ignorefunctionlength>.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub ignore_function_length: bool,
+
+    /// When using language features that might call `[Symbol.toPrimitive]`, assume that it
+    /// doesn't change its behavior with the `hint` parameter.
+    /// See <https://babeljs.io/docs/assumptions#ignoretoprimitivehint>.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub ignore_to_primitive_hint: bool,
+
+    /// Assume that the template object of a tagged template is never mutated, so it doesn't need
+    /// to be frozen.
+    /// See <https://babeljs.io/docs/assumptions#mutabletemplateobject>.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub mutable_template_object: bool,
+
+    /// When transforming classes, assume that they are always instantiated with `new` and never
+    /// called as functions.
+    /// See <https://babeljs.io/docs/assumptions#noclasscalls>.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub no_class_calls: bool,
+
+    /// Assume that the exports of a namespace import are not read before the module is
+    /// initialized.
+    /// See <https://babeljs.io/docs/assumptions#noincompletensimportdetection>.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub no_incomplete_ns_import_detection: bool,
+
+    /// Assume that arrow functions are never called with `new`, which throws for native ones.
+    /// See <https://babeljs.io/docs/assumptions#nonewarrows>.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub no_new_arrows: bool,
+
+    /// Assume that private fields are never accessed before they are initialized.
+    /// See <https://babeljs.io/docs/assumptions#nouninitializedprivatefieldaccess>.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub no_uninitialized_private_field_access: bool,
+
+    /// When using object rest, assume that the object doesn't have symbol keys or that copying them
+    /// isn't needed.
+    /// See <https://babeljs.io/docs/assumptions#objectrestnosymbols>.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub object_rest_no_symbols: bool,
+
+    /// Assume that "soft privacy" is enough for private fields, and thus they can be stored as
+    /// public properties keyed by a symbol (rather than using an external `WeakMap`).
+    /// See <https://babeljs.io/docs/assumptions#privatefieldsassymbols>.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub private_fields_as_symbols: bool,
+
+    /// Assume that getters, if present, don't have side effects and can be accessed multiple times.
+    /// See <https://babeljs.io/docs/assumptions#puregetters>.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub pure_getters: bool,
+
+    /// When declaring classes, assume that methods don't shadow getters on the superclass and that
+    /// the program doesn't depend on methods being non-enumerable.
+    /// See <https://babeljs.io/docs/assumptions#setclassmethods>.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub set_class_methods: bool,
+
+    /// When using object spread, assume that spread properties don't trigger getters on the target
+    /// object and thus it's safe to assign them rather than defining them.
+    /// See <https://babeljs.io/docs/assumptions#setspreadproperties>.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub set_spread_properties: bool,
+
+    /// When using `for of` with an iterator, it should always be closed with `.return()` and
+    /// `.throw()` in case of an error, assume that it doesn't need to be.
+    /// See <https://babeljs.io/docs/assumptions#skipforofiterationclosing>.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub skip_for_of_iteration_closing: bool,
+
+    /// When extending classes, assume that the super class is callable.
+    /// See <https://babeljs.io/docs/assumptions#superiscallableconstructor>.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub super_is_callable_constructor: bool,
 }
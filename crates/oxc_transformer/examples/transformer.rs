@@ -41,8 +41,17 @@ fn main() {
     let program = allocator.alloc(ret.program);
     let transform_options =
         TransformOptions { target: TransformTarget::ES2015, ..TransformOptions::default() };
-    Transformer::new(&allocator, source_type, &symbols, &scopes, transform_options).build(program);
-    let printed = Codegen::<false>::new(source_text.len(), codegen_options).build(program);
+    let comments = Transformer::new(&allocator, source_type, &symbols, &scopes, transform_options)
+        .with_comments(&source_text, &ret.trivias)
+        .build(program);
+    let (printed, source_map) = Codegen::<false>::new(source_text.len(), codegen_options)
+        .with_source_map(&name, &source_text)
+        .with_comments(comments)
+        .build_with_source_map(program);
     println!("Transformed:\n");
     println!("{printed}");
+    if let Some(source_map) = source_map {
+        println!("Source map:\n");
+        println!("{}", source_map.to_json_string());
+    }
 }
@@ -0,0 +1,142 @@
+use oxc_ast::{ast::*, AttachedComments, CommentKind, Trivias, Visit};
+use oxc_span::GetSpan;
+use serde::Deserialize;
+
+/// Which comments of the source are kept in the transformed output.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PreserveComments {
+    #[default]
+    All,
+    /// The legal comments, `/*! ... */` or containing `@license` or `@preserve`.
+    /// <https://esbuild.github.io/api/#legal-comments>
+    License,
+    None,
+}
+
+/// Attaches the comments of the source to the statement which follows them.
+///
+/// The comments inside a statement which are not followed by a nested statement, e.g. the
+/// ones between the arguments of a call, are dropped.
+pub struct CommentCollector<'a> {
+    source_text: &'a str,
+    /// The spans of the comment texts without the delimiters, sorted.
+    comments: std::vec::Vec<(u32, u32, CommentKind)>,
+    preserve: PreserveComments,
+    /// The index of the next comment to attach.
+    index: usize,
+    /// The end of the last statement visited, the comments before it were inside a statement.
+    last_end: u32,
+    attached: AttachedComments,
+}
+
+impl<'a> CommentCollector<'a> {
+    pub fn new(source_text: &'a str, trivias: &Trivias, preserve: PreserveComments) -> Self {
+        let mut comments = trivias.clone();
+        comments.sort_unstable_by_key(|(start, _, _)| *start);
+        Self {
+            source_text,
+            comments,
+            preserve,
+            index: 0,
+            last_end: 0,
+            attached: AttachedComments::default(),
+        }
+    }
+
+    pub fn build(mut self, program: &Program<'a>) -> AttachedComments {
+        if self.preserve != PreserveComments::None {
+            self.visit_program(program);
+        }
+        self.attached
+    }
+
+    fn is_preserved(&self, comment: &str) -> bool {
+        match self.preserve {
+            PreserveComments::All => true,
+            PreserveComments::License => {
+                comment.starts_with("/*!")
+                    || comment.contains("@license")
+                    || comment.contains("@preserve")
+            }
+            PreserveComments::None => false,
+        }
+    }
+}
+
+impl<'a> Visit<'a> for CommentCollector<'a> {
+    fn visit_statement(&mut self, stmt: &Statement<'a>) {
+        let span = stmt.span();
+        while let Some(&(start, end, kind)) = self.comments.get(self.index) {
+            if end > span.start {
+                break;
+            }
+            self.index += 1;
+            let Some(text) = self.source_text.get(start as usize..end as usize) else {
+                continue;
+            };
+            let comment = match kind {
+                CommentKind::SingleLine => format!("//{}", text.trim_end()),
+                CommentKind::MultiLine => format!("/*{text}*/"),
+            };
+            if start >= self.last_end && self.is_preserved(&comment) {
+                self.attached.attach(span.start, comment);
+            }
+        }
+        self.visit_statement_match(stmt);
+        self.last_end = self.last_end.max(span.end);
+    }
+}
+
+#[test]
+fn test() {
+    use std::{cell::RefCell, rc::Rc};
+
+    use oxc_allocator::Allocator;
+    use oxc_codegen::{Codegen, CodegenOptions};
+    use oxc_parser::Parser;
+    use oxc_semantic::SemanticBuilder;
+    use oxc_span::SourceType;
+
+    use crate::{TransformOptions, TransformTarget, Transformer};
+
+    let transform = |source_text: &str, preserve_comments| {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let ret = Parser::new(&allocator, source_text, source_type).parse();
+        let semantic = SemanticBuilder::new(source_text, source_type).build(&ret.program).semantic;
+        let (symbols, scopes) = semantic.into_symbol_table_and_scope_tree();
+        let program = allocator.alloc(ret.program);
+        let options = TransformOptions {
+            target: TransformTarget::ES2020,
+            preserve_comments,
+            ..TransformOptions::default()
+        };
+        let comments = Transformer::new(
+            &allocator,
+            source_type,
+            &Rc::new(RefCell::new(symbols)),
+            &Rc::new(RefCell::new(scopes)),
+            options,
+        )
+        .with_comments(source_text, &ret.trivias)
+        .build(program);
+        Codegen::<false>::new(source_text.len(), CodegenOptions)
+            .with_comments(comments)
+            .build(program)
+    };
+
+    let source_text = "/*! MIT */\n// set a\na ||= f(/* dropped */ b);\nif (c) {\n  /** @license */\n  c.d ??= 1;\n}\n";
+    assert_eq!(
+        transform(source_text, PreserveComments::All),
+        "var _c;\n/*! MIT */\n// set a\na || (a = f(b));\nif (c) {\n\t/** @license */\n\t(_c = c).d ?? (_c.d = 1);\n}\n",
+    );
+    assert_eq!(
+        transform(source_text, PreserveComments::License),
+        "var _c;\n/*! MIT */\na || (a = f(b));\nif (c) {\n\t/** @license */\n\t(_c = c).d ?? (_c.d = 1);\n}\n",
+    );
+    assert_eq!(
+        transform(source_text, PreserveComments::None),
+        "var _c;\na || (a = f(b));\nif (c) {\n\t(_c = c).d ?? (_c.d = 1);\n}\n",
+    );
+}
@@ -3,15 +3,23 @@ use std::{
     rc::Rc,
 };
 
-use oxc_ast::AstBuilder;
+use oxc_allocator::Vec;
+use oxc_ast::{ast::*, AstBuilder, AttachedComments};
+use oxc_diagnostics::Error;
 use oxc_semantic::{ScopeId, ScopeTree, SymbolId, SymbolTable};
-use oxc_span::Atom;
+use oxc_span::{Atom, Span};
+
+use crate::helpers::{HelperLoader, HelperNotInlined};
 
 #[derive(Clone)]
 pub struct TransformerCtx<'a> {
     pub ast: Rc<AstBuilder<'a>>,
     pub symbols: Rc<RefCell<SymbolTable>>,
     pub scopes: Rc<RefCell<ScopeTree>>,
+    pub helpers: Rc<RefCell<HelperLoader>>,
+    pub comments: Rc<RefCell<AttachedComments>>,
+    /// The code the targets can't run, which can't be transformed either.
+    pub errors: Rc<RefCell<std::vec::Vec<Error>>>,
 }
 
 impl<'a> TransformerCtx<'a> {
@@ -23,8 +31,59 @@ impl<'a> TransformerCtx<'a> {
         self.scopes.borrow()
     }
 
+    pub fn error<T: Into<Error>>(&self, error: T) {
+        self.errors.borrow_mut().push(error.into());
+    }
+
     pub fn add_binding(&self, name: Atom) {
         // TODO: use the correct scope and symbol id
         self.scopes.borrow_mut().add_binding(ScopeId::new(0), name, SymbolId::new(0));
     }
+
+    /// Keep the comments of a statement replaced by a statement with another span.
+    pub fn move_comments(&self, from: Span, to: Span) {
+        if from != to {
+            self.comments.borrow_mut().move_leading(from.start, to.start);
+        }
+    }
+
+    /// A reference to a Babel helper, `babelHelpers.name` or the binding imported from the
+    /// runtime module, or defined in the file with the helpers it calls.
+    /// <https://github.com/babel/babel/tree/main/packages/babel-helpers>
+    pub fn helper_reference(&self, name: &str) -> Expression<'a> {
+        let span = Span::default();
+        self.helpers.borrow_mut().mark_used(name);
+        if self.helpers.borrow().is_external() {
+            let object = IdentifierReference::new(span, "babelHelpers".into());
+            let object = self.ast.identifier_reference_expression(object);
+            let property = IdentifierName::new(span, name.into());
+            return self.ast.static_member_expression(span, object, property, false);
+        }
+        let local = self.helpers.borrow().local_name(name).cloned();
+        let local = local.unwrap_or_else(|| {
+            let local = self.scopes().generate_uid(name);
+            self.add_binding(local.clone());
+            self.helpers.borrow_mut().add(name, local.clone());
+            if !self.helpers.borrow().is_available(name) {
+                self.error(HelperNotInlined(name.to_string()));
+            }
+            let dependencies = self.helpers.borrow().dependencies(name);
+            for dependency in dependencies {
+                self.helper_reference(dependency);
+            }
+            local
+        });
+        self.ast.identifier_reference_expression(IdentifierReference::new(span, local))
+    }
+
+    /// Create a call to a Babel helper, e.g. `babelHelpers.defineProperty(obj, key, value)`.
+    pub fn helper_call(&self, name: &str, arguments: Vec<'a, Argument<'a>>) -> Expression<'a> {
+        let callee = self.helper_reference(name);
+        self.ast.call_expression(Span::default(), callee, arguments, false, None)
+    }
+
+    /// Import the helpers used from the runtime module, or define them, at the top of `stmts`.
+    pub fn add_helper_imports(&self, stmts: &mut Vec<'a, Statement<'a>>) {
+        self.helpers.borrow_mut().add_imports(&self.ast, stmts);
+    }
 }
@@ -0,0 +1,488 @@
+use std::{mem, rc::Rc};
+
+use oxc_allocator::{Box, Vec};
+use oxc_ast::{ast::*, AstBuilder};
+use oxc_span::{Atom, Span};
+use oxc_syntax::operator::AssignmentOperator;
+
+use crate::{
+    context::TransformerCtx, helpers::parse_helper, options::TransformOptions, utils::CreateVars,
+};
+
+use super::{member_key, metadata::DecoratorMetadata, DecoratorsVersion};
+
+/// Legacy Decorators
+///
+/// Lowers TypeScript `experimentalDecorators` the way `tsc` does, by calling the `__decorate` and
+/// `__param` helpers of `tslib` after the class is defined. The helpers used are defined at the
+/// top of the file like `tsc` does without `importHelpers`, and the ones already in scope are
+/// kept: `var __decorate = (this && this.__decorate) || function (...) { ... }`.
+///
+/// ```TypeScript
+/// @dec class A { @m method(@p a) {} }
+/// // =>
+/// var __decorate = ...;
+/// var __param = ...;
+/// let A = class A { method(a) {} };
+/// __decorate([m, __param(0, p)], A.prototype, "method", null);
+/// A = __decorate([dec], A);
+/// ```
+///
+/// References:
+/// * <https://www.typescriptlang.org/docs/handbook/decorators.html>
+/// * <https://github.com/microsoft/TypeScript/blob/main/src/compiler/transformers/legacyDecorators.ts>
+pub struct LegacyDecorators<'a> {
+    ast: Rc<AstBuilder<'a>>,
+    ctx: TransformerCtx<'a>,
+    vars: Vec<'a, VariableDeclarator<'a>>,
+    metadata: Option<DecoratorMetadata<'a>>,
+    /// The `tslib` helpers used by the file.
+    helpers: std::vec::Vec<&'static str>,
+}
+
+/// The `tslib` helpers in the order `tsc` emits them.
+/// <https://github.com/microsoft/TypeScript/blob/main/src/compiler/factory/emitHelpers.ts>
+pub(super) const HELPERS: [(&str, &str); 3] = [
+    (
+        "__decorate",
+        r#"var __decorate = (this && this.__decorate) || function (decorators, target, key, desc) {
+    var c = arguments.length, r = c < 3 ? target : desc === null ? desc = Object.getOwnPropertyDescriptor(target, key) : desc, d;
+    if (typeof Reflect === "object" && typeof Reflect.decorate === "function") r = Reflect.decorate(decorators, target, key, desc);
+    else for (var i = decorators.length - 1; i >= 0; i--) if (d = decorators[i]) r = (c < 3 ? d(r) : c > 3 ? d(target, key, r) : d(target, key)) || r;
+    return c > 3 && r && Object.defineProperty(target, key, r), r;
+};"#,
+    ),
+    (
+        "__metadata",
+        r#"var __metadata = (this && this.__metadata) || function (k, v) {
+    if (typeof Reflect === "object" && typeof Reflect.metadata === "function") return Reflect.metadata(k, v);
+};"#,
+    ),
+    (
+        "__param",
+        r#"var __param = (this && this.__param) || function (paramIndex, decorator) {
+    return function (target, key) { decorator(target, key, paramIndex); }
+};"#,
+    ),
+];
+
+impl<'a> CreateVars<'a> for LegacyDecorators<'a> {
+    fn ctx(&self) -> &TransformerCtx<'a> {
+        &self.ctx
+    }
+
+    fn vars_mut(&mut self) -> &mut Vec<'a, VariableDeclarator<'a>> {
+        &mut self.vars
+    }
+}
+
+/// How the decorated class is exported.
+#[derive(Clone, Copy)]
+enum ClassExport {
+    None,
+    Named,
+    Default,
+}
+
+impl<'a> LegacyDecorators<'a> {
+    pub fn new(
+        ast: Rc<AstBuilder<'a>>,
+        ctx: TransformerCtx<'a>,
+        options: &TransformOptions,
+    ) -> Option<Self> {
+        let options =
+            options.decorators.filter(|options| options.version == DecoratorsVersion::Legacy)?;
+        let vars = ast.new_vec();
+        let metadata =
+            options.emit_decorator_metadata.then(|| DecoratorMetadata::new(Rc::clone(&ast)));
+        Some(Self { ast, ctx, vars, metadata, helpers: vec![] })
+    }
+
+    /// Define the helpers used at the top of the file.
+    pub fn add_helpers(&mut self, stmts: &mut Vec<'a, Statement<'a>>) {
+        if self.metadata.as_ref().is_some_and(DecoratorMetadata::is_used) {
+            self.helpers.push("__metadata");
+        }
+        let helpers = HELPERS.iter().filter(|(name, _)| self.helpers.contains(name));
+        for (i, stmt) in helpers.flat_map(|(_, source)| parse_helper(&self.ast, source)).enumerate()
+        {
+            stmts.insert(i, stmt);
+        }
+    }
+
+    pub fn transform_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>) {
+        if !stmts.iter().any(|stmt| Self::decorated_class(stmt).is_some()) {
+            return;
+        }
+        let old_stmts = mem::replace(stmts, self.ast.new_vec());
+        for stmt in old_stmts {
+            let Some(export) = Self::decorated_class(&stmt) else {
+                stmts.push(stmt);
+                continue;
+            };
+            let class = match stmt {
+                Statement::Declaration(Declaration::ClassDeclaration(class)) => class,
+                Statement::ModuleDeclaration(decl) => match decl.unbox() {
+                    ModuleDeclaration::ExportNamedDeclaration(mut decl) => {
+                        let Some(Declaration::ClassDeclaration(class)) = decl.declaration.take()
+                        else {
+                            unreachable!()
+                        };
+                        class
+                    }
+                    ModuleDeclaration::ExportDefaultDeclaration(decl) => {
+                        let ExportDefaultDeclarationKind::ClassDeclaration(class) =
+                            decl.unbox().declaration
+                        else {
+                            unreachable!()
+                        };
+                        class
+                    }
+                    _ => unreachable!(),
+                },
+                _ => unreachable!(),
+            };
+            self.transform_class(stmts, class, export);
+        }
+        // The memoized keys are declared before the nested statements are visited.
+        self.add_vars_to_statements(stmts);
+    }
+
+    /// The class declaration of the statement, if it or its members have decorators.
+    fn decorated_class(stmt: &Statement<'a>) -> Option<ClassExport> {
+        let (class, export) = match stmt {
+            Statement::Declaration(Declaration::ClassDeclaration(class)) => {
+                (class, ClassExport::None)
+            }
+            Statement::ModuleDeclaration(decl) => match &**decl {
+                ModuleDeclaration::ExportNamedDeclaration(decl) => match &decl.declaration {
+                    Some(Declaration::ClassDeclaration(class)) => (class, ClassExport::Named),
+                    _ => return None,
+                },
+                ModuleDeclaration::ExportDefaultDeclaration(decl) => match &decl.declaration {
+                    ExportDefaultDeclarationKind::ClassDeclaration(class) => {
+                        (class, ClassExport::Default)
+                    }
+                    _ => return None,
+                },
+                _ => return None,
+            },
+            _ => return None,
+        };
+        let has_decorators = !class.decorators.is_empty()
+            || class.body.body.iter().any(|element| match element {
+                ClassElement::MethodDefinition(def) => {
+                    !def.decorators.is_empty()
+                        || def.value.params.items.iter().any(|param| !param.decorators.is_empty())
+                }
+                ClassElement::PropertyDefinition(def) => !def.decorators.is_empty(),
+                _ => false,
+            });
+        has_decorators.then_some(export)
+    }
+
+    fn transform_class(
+        &mut self,
+        stmts: &mut Vec<'a, Statement<'a>>,
+        mut class: Box<'a, Class<'a>>,
+        export: ClassExport,
+    ) {
+        let span = class.span;
+        let name = match &class.id {
+            Some(id) => id.name.clone(),
+            None => {
+                let name = self.ctx.scopes().generate_uid("default");
+                self.ctx.add_binding(name.clone());
+                name
+            }
+        };
+
+        // Decorators of the class itself and of the constructor parameters.
+        let mut class_decorators = self.ast.new_vec();
+        for decorator in mem::replace(&mut class.decorators, self.ast.new_vec()) {
+            class_decorators.push(ArrayExpressionElement::Expression(decorator.expression));
+        }
+        let mut constructor_metadata = None;
+        // Instance members are decorated before static members.
+        let mut instance_decorations = self.ast.new_vec();
+        let mut static_decorations = self.ast.new_vec();
+        for element in class.body.body.iter_mut() {
+            let (decorations, key, descriptor, r#static) = match element {
+                ClassElement::MethodDefinition(def) => {
+                    let mut decorations = self.ast.new_vec();
+                    for decorator in mem::replace(&mut def.decorators, self.ast.new_vec()) {
+                        decorations.push(ArrayExpressionElement::Expression(decorator.expression));
+                    }
+                    self.push_parameter_decorators(&mut decorations, &mut def.value.params);
+                    if def.kind == MethodDefinitionKind::Constructor {
+                        class_decorators.extend(decorations);
+                        constructor_metadata = self
+                            .metadata
+                            .as_ref()
+                            .map(|metadata| metadata.parameter_types(&def.value.params));
+                        continue;
+                    }
+                    if decorations.is_empty() {
+                        continue;
+                    }
+                    if let Some(metadata) = &self.metadata {
+                        decorations.extend(metadata.method(def));
+                    }
+                    let Some(key) = member_key(self, &mut def.key) else { continue };
+                    let null = self.ast.literal_null_expression(NullLiteral::new(Span::default()));
+                    (decorations, key, null, def.r#static)
+                }
+                ClassElement::PropertyDefinition(def) if !def.decorators.is_empty() => {
+                    let mut decorations = self.ast.new_vec();
+                    for decorator in mem::replace(&mut def.decorators, self.ast.new_vec()) {
+                        decorations.push(ArrayExpressionElement::Expression(decorator.expression));
+                    }
+                    if let Some(metadata) = &self.metadata {
+                        decorations.push(metadata.property(def));
+                    }
+                    let Some(key) = member_key(self, &mut def.key) else { continue };
+                    (decorations, key, self.ast.void_0(), def.r#static)
+                }
+                _ => continue,
+            };
+            // __decorate([decorators], A.prototype, "key", descriptor);
+            let mut target = self.identifier(name.clone());
+            if !r#static {
+                let property = IdentifierName::new(Span::default(), "prototype".into());
+                target =
+                    self.ast.static_member_expression(Span::default(), target, property, false);
+            }
+            let mut arguments = self.ast.new_vec_with_capacity(4);
+            arguments.push(Argument::Expression(self.ast.array_expression(
+                Span::default(),
+                decorations,
+                None,
+            )));
+            arguments.push(Argument::Expression(target));
+            arguments.push(Argument::Expression(key));
+            arguments.push(Argument::Expression(descriptor));
+            let call = self.tslib_call("__decorate", arguments);
+            let stmt = self.ast.expression_statement(Span::default(), call);
+            if r#static {
+                static_decorations.push(stmt);
+            } else {
+                instance_decorations.push(stmt);
+            }
+        }
+
+        if let Some(metadata) = constructor_metadata.filter(|_| !class_decorators.is_empty()) {
+            class_decorators.push(metadata);
+        }
+
+        if class_decorators.is_empty() {
+            // Only the members are decorated, the class stays a declaration.
+            if class.id.is_none() {
+                class.id = Some(BindingIdentifier::new(Span::default(), name));
+            }
+            let decl = Declaration::ClassDeclaration(class);
+            stmts.push(match export {
+                ClassExport::None => Statement::Declaration(decl),
+                ClassExport::Named => self.export_named(span, Some(decl)),
+                ClassExport::Default => {
+                    let Declaration::ClassDeclaration(class) = decl else { unreachable!() };
+                    self.export_default(span, ExportDefaultDeclarationKind::ClassDeclaration(class))
+                }
+            });
+            stmts.extend(instance_decorations);
+            stmts.extend(static_decorations);
+            return;
+        }
+
+        // let A = class A {};
+        class.r#type = ClassType::ClassExpression;
+        let id = self.ast.binding_pattern(
+            self.ast
+                .binding_pattern_identifier(BindingIdentifier::new(Span::default(), name.clone())),
+            None,
+            false,
+        );
+        let declarator = self.ast.variable_declarator(
+            Span::default(),
+            VariableDeclarationKind::Let,
+            id,
+            Some(self.ast.class_expression(class)),
+            false,
+        );
+        let declaration = self.ast.variable_declaration(
+            span,
+            VariableDeclarationKind::Let,
+            self.ast.new_vec_single(declarator),
+            Modifiers::empty(),
+        );
+        stmts.push(Statement::Declaration(Declaration::VariableDeclaration(declaration)));
+        stmts.extend(instance_decorations);
+        stmts.extend(static_decorations);
+
+        // A = __decorate([decorators], A);
+        let mut arguments = self.ast.new_vec_with_capacity(2);
+        arguments.push(Argument::Expression(self.ast.array_expression(
+            Span::default(),
+            class_decorators,
+            None,
+        )));
+        arguments.push(Argument::Expression(self.identifier(name.clone())));
+        let target = self.ast.simple_assignment_target_identifier(IdentifierReference::new(
+            Span::default(),
+            name.clone(),
+        ));
+        let decorate = self.tslib_call("__decorate", arguments);
+        let assignment = self.ast.assignment_expression(
+            Span::default(),
+            AssignmentOperator::Assign,
+            AssignmentTarget::SimpleAssignmentTarget(target),
+            decorate,
+        );
+        stmts.push(self.ast.expression_statement(Span::default(), assignment));
+
+        match export {
+            ClassExport::None => {}
+            // export { A };
+            ClassExport::Named => {
+                let mut specifiers = self.ast.new_vec_single(ExportSpecifier {
+                    span: Span::default(),
+                    local: ModuleExportName::Identifier(IdentifierName::new(
+                        Span::default(),
+                        name.clone(),
+                    )),
+                    exported: ModuleExportName::Identifier(IdentifierName::new(
+                        Span::default(),
+                        name,
+                    )),
+                    export_kind: ImportOrExportKind::Value,
+                });
+                let decl = self.ast.export_named_declaration(
+                    Span::default(),
+                    None,
+                    mem::replace(&mut specifiers, self.ast.new_vec()),
+                    None,
+                    None,
+                    ImportOrExportKind::Value,
+                );
+                stmts.push(
+                    self.ast.module_declaration(ModuleDeclaration::ExportNamedDeclaration(decl)),
+                );
+            }
+            // export default A;
+            ClassExport::Default => {
+                let expr = self.identifier(name);
+                stmts.push(self.export_default(
+                    Span::default(),
+                    ExportDefaultDeclarationKind::Expression(expr),
+                ));
+            }
+        }
+    }
+
+    /// `method(@a x, @b @c y)` -> `__param(0, a), __param(1, b), __param(1, c)`
+    fn push_parameter_decorators(
+        &mut self,
+        decorations: &mut Vec<'a, ArrayExpressionElement<'a>>,
+        params: &mut FormalParameters<'a>,
+    ) {
+        for (index, param) in params.items.iter_mut().enumerate() {
+            for decorator in mem::replace(&mut param.decorators, self.ast.new_vec()) {
+                let mut arguments = self.ast.new_vec_with_capacity(2);
+                #[allow(clippy::cast_precision_loss)]
+                let index = index as f64;
+                let raw = self.ast.new_str(&index.to_string());
+                arguments.push(Argument::Expression(self.ast.literal_number_expression(
+                    NumberLiteral::new(
+                        Span::default(),
+                        index,
+                        raw,
+                        oxc_syntax::NumberBase::Decimal,
+                    ),
+                )));
+                arguments.push(Argument::Expression(decorator.expression));
+                decorations.push(ArrayExpressionElement::Expression(
+                    self.tslib_call("__param", arguments),
+                ));
+            }
+        }
+    }
+
+    fn identifier(&self, name: Atom) -> Expression<'a> {
+        self.ast.identifier_reference_expression(IdentifierReference::new(Span::default(), name))
+    }
+
+    fn export_named(&self, span: Span, declaration: Option<Declaration<'a>>) -> Statement<'a> {
+        let decl = self.ast.export_named_declaration(
+            span,
+            declaration,
+            self.ast.new_vec(),
+            None,
+            None,
+            ImportOrExportKind::Value,
+        );
+        self.ast.module_declaration(ModuleDeclaration::ExportNamedDeclaration(decl))
+    }
+
+    fn export_default(
+        &self,
+        span: Span,
+        declaration: ExportDefaultDeclarationKind<'a>,
+    ) -> Statement<'a> {
+        let exported = ModuleExportName::Identifier(IdentifierName::new(span, "default".into()));
+        let decl = self.ast.export_default_declaration(span, declaration, exported);
+        self.ast.module_declaration(ModuleDeclaration::ExportDefaultDeclaration(decl))
+    }
+
+    /// `__name(arguments)`, a helper of `tslib`.
+    fn tslib_call(
+        &mut self,
+        name: &'static str,
+        arguments: Vec<'a, Argument<'a>>,
+    ) -> Expression<'a> {
+        if !self.helpers.contains(&name) {
+            self.helpers.push(name);
+        }
+        let callee = self.identifier(name.into());
+        self.ast.call_expression(Span::default(), callee, arguments, false, None)
+    }
+}
+
+#[test]
+#[rustfmt::skip]
+fn test() {
+    use crate::{decorators::DecoratorsOptions, tester::Tester};
+
+    let options = TransformOptions {
+        decorators: Some(DecoratorsOptions {
+            version: DecoratorsVersion::Legacy,
+            emit_decorator_metadata: false,
+        }),
+        ..TransformOptions::default()
+    };
+    let [(_, decorate), _, (_, param)] = HELPERS;
+    Tester::new("test.ts", options).test(&[
+        (
+            "class A { @m method() {} @p prop = 1; @s static x; }",
+            format!("{decorate}\nclass A {{ method() {{}} prop = 1; static x; }}\n__decorate([m], A.prototype, 'method', null);\n__decorate([p], A.prototype, 'prop', void 0);\n__decorate([s], A, 'x', void 0);").as_str(),
+        ),
+        (
+            "@dec class A { constructor(@inject a: string) {} }",
+            format!("{decorate}\n{param}\nlet A = class A {{ constructor(a) {{}} }};\nA = __decorate([dec, __param(0, inject)], A);").as_str(),
+        ),
+        (
+            "@dec export class A { method(a, @p() b) {} }",
+            format!("{decorate}\n{param}\nlet A = class A {{ method(a, b) {{}} }};\n__decorate([__param(1, p())], A.prototype, 'method', null);\nA = __decorate([dec], A);\nexport {{ A }};").as_str(),
+        ),
+        (
+            "export default @dec class {}",
+            format!("{decorate}\nlet _default = class {{}};\n_default = __decorate([dec], _default);\nexport default _default;").as_str(),
+        ),
+        (
+            "export default class { @m 'a b'() {} @n [key]() {} }",
+            format!("{decorate}\nvar _key;\nexport default class _default {{ 'a b'() {{}} [_key = key]() {{}} }}\n__decorate([m], _default.prototype, 'a b', null);\n__decorate([n], _default.prototype, _key, null);").as_str(),
+        ),
+        (
+            "class A {}",
+            "class A {}",
+        ),
+    ]);
+}
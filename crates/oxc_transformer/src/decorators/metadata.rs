@@ -0,0 +1,267 @@
+use std::{cell::Cell, rc::Rc};
+
+use oxc_allocator::Vec;
+use oxc_ast::{ast::*, AstBuilder};
+use oxc_span::{Atom, Span};
+use oxc_syntax::operator::{BinaryOperator, UnaryOperator};
+
+/// Decorator Metadata
+///
+/// The `design:type`, `design:paramtypes` and `design:returntype` metadata of decorated members,
+/// as emitted by `tsc --emitDecoratorMetadata` through the `__metadata` helper of `tslib`, which
+/// forwards to `Reflect.metadata`.
+///
+/// ```TypeScript
+/// class A { @dec method(a: string): number {} }
+/// // =>
+/// __decorate([dec, __metadata("design:type", Function), __metadata("design:paramtypes", [String]), __metadata("design:returntype", Number)], A.prototype, "method", null);
+/// ```
+///
+/// Types are serialized without type information, so type references are guarded as they may
+/// not have a runtime value: `Foo` -> `typeof Foo === "undefined" ? Object : Foo`.
+///
+/// References:
+/// * <https://www.typescriptlang.org/tsconfig#emitDecoratorMetadata>
+/// * <https://github.com/microsoft/TypeScript/blob/main/src/compiler/transformers/typeSerializer.ts>
+pub struct DecoratorMetadata<'a> {
+    ast: Rc<AstBuilder<'a>>,
+    /// Whether `__metadata` is called.
+    used: Cell<bool>,
+}
+
+/// The runtime value of a type.
+enum SerializedType<'b, 'a> {
+    Void,
+    Global(&'static str),
+    Reference(&'b TSTypeName<'a>),
+}
+
+impl<'b, 'a> PartialEq for SerializedType<'b, 'a> {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Void, Self::Void) => true,
+            (Self::Global(a), Self::Global(b)) => a == b,
+            (
+                Self::Reference(TSTypeName::IdentifierReference(a)),
+                Self::Reference(TSTypeName::IdentifierReference(b)),
+            ) => a.name == b.name,
+            _ => false,
+        }
+    }
+}
+
+impl<'a> DecoratorMetadata<'a> {
+    pub fn new(ast: Rc<AstBuilder<'a>>) -> Self {
+        Self { ast, used: Cell::new(false) }
+    }
+
+    pub fn is_used(&self) -> bool {
+        self.used.get()
+    }
+
+    /// `__metadata("design:type", String)`
+    pub fn property(&self, def: &PropertyDefinition<'a>) -> ArrayExpressionElement<'a> {
+        let ty = self.serialize_annotation(def.type_annotation.as_deref());
+        self.metadata("design:type", ty)
+    }
+
+    pub fn method(&self, def: &MethodDefinition<'a>) -> Vec<'a, ArrayExpressionElement<'a>> {
+        let mut metadata = self.ast.new_vec_with_capacity(3);
+        let func = &def.value;
+        match def.kind {
+            MethodDefinitionKind::Get => {
+                let ty = self.serialize_annotation(func.return_type.as_deref());
+                metadata.push(self.metadata("design:type", ty));
+                metadata.push(self.parameter_types(&func.params));
+            }
+            MethodDefinitionKind::Set => {
+                let param = func.params.items.iter().find(|param| !Self::is_this(param));
+                let ty = self.serialize_annotation(
+                    param.and_then(|param| param.pattern.type_annotation.as_deref()),
+                );
+                metadata.push(self.metadata("design:type", ty));
+                metadata.push(self.parameter_types(&func.params));
+            }
+            _ => {
+                metadata.push(self.metadata("design:type", self.identifier("Function".into())));
+                metadata.push(self.parameter_types(&func.params));
+                let ty = match &func.return_type {
+                    Some(annotation) => self.serialize_annotation(Some(annotation)),
+                    None if func.r#async => self.identifier("Promise".into()),
+                    None => self.ast.void_0(),
+                };
+                metadata.push(self.metadata("design:returntype", ty));
+            }
+        }
+        metadata
+    }
+
+    /// `__metadata("design:paramtypes", [String, Number])`
+    pub fn parameter_types(&self, params: &FormalParameters<'a>) -> ArrayExpressionElement<'a> {
+        let mut types = self.ast.new_vec_with_capacity(params.items.len());
+        for param in params.items.iter().filter(|param| !Self::is_this(param)) {
+            let ty = self.serialize_annotation(param.pattern.type_annotation.as_deref());
+            types.push(ArrayExpressionElement::Expression(ty));
+        }
+        if let Some(rest) = &params.rest {
+            let ty = self.serialize_annotation(rest.argument.type_annotation.as_deref());
+            types.push(ArrayExpressionElement::Expression(ty));
+        }
+        let types = self.ast.array_expression(Span::default(), types, None);
+        self.metadata("design:paramtypes", types)
+    }
+
+    fn is_this(param: &FormalParameter<'a>) -> bool {
+        matches!(&param.pattern.kind, BindingPatternKind::BindingIdentifier(ident) if ident.name == "this")
+    }
+
+    fn metadata(&self, key: &str, value: Expression<'a>) -> ArrayExpressionElement<'a> {
+        let mut arguments = self.ast.new_vec_with_capacity(2);
+        arguments.push(Argument::Expression(
+            self.ast.literal_string_expression(StringLiteral::new(Span::default(), key.into())),
+        ));
+        arguments.push(Argument::Expression(value));
+        self.used.set(true);
+        let callee = self.identifier("__metadata".into());
+        ArrayExpressionElement::Expression(self.ast.call_expression(
+            Span::default(),
+            callee,
+            arguments,
+            false,
+            None,
+        ))
+    }
+
+    fn serialize_annotation(&self, annotation: Option<&TSTypeAnnotation<'a>>) -> Expression<'a> {
+        match annotation.map(|annotation| Self::serialize_type(&annotation.type_annotation)) {
+            None => self.identifier("Object".into()),
+            Some(SerializedType::Void) => self.ast.void_0(),
+            Some(SerializedType::Global(name)) => self.identifier(name.into()),
+            Some(SerializedType::Reference(name)) => self.type_reference(name),
+        }
+    }
+
+    fn serialize_type<'b>(ty: &'b TSType<'a>) -> SerializedType<'b, 'a> {
+        match ty {
+            TSType::TSVoidKeyword(_)
+            | TSType::TSUndefinedKeyword(_)
+            | TSType::TSNullKeyword(_)
+            | TSType::TSNeverKeyword(_) => SerializedType::Void,
+            TSType::TSNumberKeyword(_) => SerializedType::Global("Number"),
+            TSType::TSStringKeyword(_) | TSType::TSTemplateLiteralType(_) => {
+                SerializedType::Global("String")
+            }
+            TSType::TSBooleanKeyword(_) | TSType::TSTypePredicate(_) => {
+                SerializedType::Global("Boolean")
+            }
+            TSType::TSBigIntKeyword(_) => SerializedType::Global("BigInt"),
+            TSType::TSSymbolKeyword(_) => SerializedType::Global("Symbol"),
+            TSType::TSFunctionType(_) | TSType::TSConstructorType(_) => {
+                SerializedType::Global("Function")
+            }
+            TSType::TSArrayType(_) | TSType::TSTupleType(_) => SerializedType::Global("Array"),
+            TSType::TSLiteralType(ty) => match &ty.literal {
+                TSLiteral::BooleanLiteral(_) => SerializedType::Global("Boolean"),
+                TSLiteral::NumberLiteral(_) | TSLiteral::UnaryExpression(_) => {
+                    SerializedType::Global("Number")
+                }
+                TSLiteral::StringLiteral(_) | TSLiteral::TemplateLiteral(_) => {
+                    SerializedType::Global("String")
+                }
+                TSLiteral::BigintLiteral(_) => SerializedType::Global("BigInt"),
+                TSLiteral::NullLiteral(_) => SerializedType::Void,
+                TSLiteral::RegExpLiteral(_) => SerializedType::Global("Object"),
+            },
+            TSType::TSTypeOperatorType(ty) if matches!(ty.operator, TSTypeOperator::Readonly) => {
+                Self::serialize_type(&ty.type_annotation)
+            }
+            TSType::TSTypeReference(ty) => SerializedType::Reference(&ty.type_name),
+            // `string | null` -> `String`, but `string | number` -> `Object`
+            TSType::TSUnionType(ty) => Self::serialize_constituents(&ty.types),
+            TSType::TSIntersectionType(ty) => Self::serialize_constituents(&ty.types),
+            _ => SerializedType::Global("Object"),
+        }
+    }
+
+    fn serialize_constituents<'b>(types: &'b [TSType<'a>]) -> SerializedType<'b, 'a> {
+        let mut serialized = SerializedType::Void;
+        for ty in types {
+            match Self::serialize_type(ty) {
+                SerializedType::Void => {}
+                ty if serialized == SerializedType::Void => serialized = ty,
+                ty if ty == serialized => {}
+                _ => return SerializedType::Global("Object"),
+            }
+        }
+        serialized
+    }
+
+    /// `typeof A === "undefined" ? Object : A.B`
+    fn type_reference(&self, name: &TSTypeName<'a>) -> Expression<'a> {
+        let span = Span::default();
+        let mut left = name;
+        while let TSTypeName::QualifiedName(name) = left {
+            left = &name.left;
+        }
+        let TSTypeName::IdentifierReference(ident) = left else { unreachable!() };
+        let type_of = self.ast.unary_expression(
+            span,
+            UnaryOperator::Typeof,
+            self.identifier(ident.name.clone()),
+        );
+        let undefined =
+            self.ast.literal_string_expression(StringLiteral::new(span, "undefined".into()));
+        let test =
+            self.ast.binary_expression(span, type_of, BinaryOperator::StrictEquality, undefined);
+        self.ast.conditional_expression(
+            span,
+            test,
+            self.identifier("Object".into()),
+            self.type_name(name),
+        )
+    }
+
+    fn type_name(&self, name: &TSTypeName<'a>) -> Expression<'a> {
+        match name {
+            TSTypeName::IdentifierReference(ident) => self.identifier(ident.name.clone()),
+            TSTypeName::QualifiedName(name) => {
+                let object = self.type_name(&name.left);
+                let property = IdentifierName::new(Span::default(), name.right.name.clone());
+                self.ast.static_member_expression(Span::default(), object, property, false)
+            }
+        }
+    }
+
+    fn identifier(&self, name: Atom) -> Expression<'a> {
+        self.ast.identifier_reference_expression(IdentifierReference::new(Span::default(), name))
+    }
+}
+
+#[test]
+#[rustfmt::skip]
+fn test() {
+    use crate::{
+        decorators::{legacy::HELPERS, DecoratorsOptions, DecoratorsVersion},
+        options::TransformOptions,
+        tester::Tester,
+    };
+
+    let options = TransformOptions {
+        decorators: Some(DecoratorsOptions {
+            version: DecoratorsVersion::Legacy,
+            emit_decorator_metadata: true,
+        }),
+        ..TransformOptions::default()
+    };
+    let [(_, decorate), (_, metadata), (_, param)] = HELPERS;
+    Tester::new("test.ts", options).test(&[
+        (
+            "class A { @d a: string | null; @d b: number | string; @d c; @d m(this: A, x: Foo, y: N.Bar, ...z: boolean[]): void {} @d async n() {} }",
+            format!("{decorate}\n{metadata}\nclass A {{ a; b; c; m(x, y, ...z) {{}} async n() {{}} }}\n__decorate([d, __metadata('design:type', String)], A.prototype, 'a', void 0);\n__decorate([d, __metadata('design:type', Object)], A.prototype, 'b', void 0);\n__decorate([d, __metadata('design:type', Object)], A.prototype, 'c', void 0);\n__decorate([d, __metadata('design:type', Function), __metadata('design:paramtypes', [typeof Foo === 'undefined' ? Object : Foo, typeof N === 'undefined' ? Object : N.Bar, Array]), __metadata('design:returntype', void 0)], A.prototype, 'm', null);\n__decorate([d, __metadata('design:type', Function), __metadata('design:paramtypes', []), __metadata('design:returntype', Promise)], A.prototype, 'n', null);").as_str(),
+        ),
+        (
+            "@d class A { constructor(@i a: 'x', b: () => void) {} @d get x(): readonly number[] { return []; } }",
+            format!("{decorate}\n{metadata}\n{param}\nlet A = class A {{ constructor(a, b) {{}} get x() {{ return []; }} }};\n__decorate([d, __metadata('design:type', Array), __metadata('design:paramtypes', [])], A.prototype, 'x', null);\nA = __decorate([d, __param(0, i), __metadata('design:paramtypes', [String, Function])], A);").as_str(),
+        ),
+    ]);
+}
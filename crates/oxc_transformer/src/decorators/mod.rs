@@ -0,0 +1,71 @@
+mod legacy;
+mod metadata;
+mod stage3;
+
+use oxc_ast::ast::*;
+use oxc_span::Span;
+use oxc_syntax::operator::AssignmentOperator;
+use serde::Deserialize;
+
+pub use legacy::LegacyDecorators;
+pub use stage3::Stage3Decorators;
+
+use crate::utils::CreateVars;
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DecoratorsOptions {
+    pub version: DecoratorsVersion,
+    /// Emit the design-time types of decorated members, see [metadata::DecoratorMetadata].
+    /// Only supported by legacy decorators.
+    #[serde(default)]
+    pub emit_decorator_metadata: bool,
+}
+
+/// The decorators proposal which the decorators are lowered according to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum DecoratorsVersion {
+    /// TypeScript `experimentalDecorators`
+    #[serde(rename = "legacy")]
+    Legacy,
+    /// The TC39 proposal as of the May 2023 meeting
+    #[serde(rename = "2023-05")]
+    V2023_05,
+}
+
+/// The name of a decorated member passed to the helpers, computed keys are evaluated once into
+/// a variable: `[a.b]() {}` -> `[_a$b = a.b]() {}`
+///
+/// Returns `None` for private members.
+fn member_key<'a>(
+    transform: &mut impl CreateVars<'a>,
+    key: &mut PropertyKey<'a>,
+) -> Option<Expression<'a>> {
+    let ast = std::rc::Rc::clone(&transform.ctx().ast);
+    let span = Span::default();
+    match key {
+        PropertyKey::Identifier(ident) => {
+            Some(ast.literal_string_expression(StringLiteral::new(span, ident.name.clone())))
+        }
+        PropertyKey::PrivateIdentifier(_) => None,
+        PropertyKey::Expression(Expression::StringLiteral(lit)) => {
+            Some(ast.literal_string_expression(StringLiteral::new(span, lit.value.clone())))
+        }
+        PropertyKey::Expression(Expression::NumberLiteral(lit)) => Some(
+            ast.literal_number_expression(NumberLiteral::new(span, lit.value, lit.raw, lit.base)),
+        ),
+        PropertyKey::Expression(expr) => {
+            let temp = transform.create_new_var(expr);
+            let value = ast.move_expression(expr);
+            *expr = ast.assignment_expression(
+                span,
+                AssignmentOperator::Assign,
+                AssignmentTarget::SimpleAssignmentTarget(
+                    ast.simple_assignment_target_identifier(temp.clone()),
+                ),
+                value,
+            );
+            Some(ast.identifier_reference_expression(temp))
+        }
+    }
+}
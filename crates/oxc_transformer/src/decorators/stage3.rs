@@ -0,0 +1,749 @@
+use std::{mem, rc::Rc};
+
+use oxc_allocator::Vec;
+use oxc_ast::{ast::*, AstBuilder, VisitMut};
+use oxc_span::{Atom, Span};
+use oxc_syntax::{operator::AssignmentOperator, NumberBase};
+use rustc_hash::FxHashSet;
+
+use crate::{context::TransformerCtx, options::TransformOptions, utils::CreateVars};
+
+use super::{member_key, DecoratorsVersion};
+
+// The kinds of decorated elements of `applyDecs2305`, `STATIC` is a flag.
+const FIELD: u8 = 0;
+const ACCESSOR: u8 = 1;
+const METHOD: u8 = 2;
+const GETTER: u8 = 3;
+const SETTER: u8 = 4;
+const STATIC: u8 = 8;
+
+/// Stage 3 Decorators (2023-05)
+///
+/// Decorators are applied by the `applyDecs2305` helper in a static block which runs before
+/// anything else in the class, its result holds the initializers of the decorated fields and
+/// accessors and the decorated class.
+///
+/// ```JavaScript
+/// @dec class A { @m method() {} @p prop = 1; }
+/// // =>
+/// var _A, _initClass, _init_prop, _initProto;
+/// class A {
+///   static { ({ e: [_init_prop, _initProto], c: [_A, _initClass] } = babelHelpers.applyDecs2305(this, [[m, 2, "method"], [p, 0, "prop"]], [dec])); }
+///   method() {}
+///   prop = (_initProto(this), _init_prop(this, 1));
+///   static { _initClass(); }
+/// }
+/// A = _A;
+/// ```
+///
+/// `accessor` properties are lowered to a private field with a getter and a setter.
+///
+/// References:
+/// * <https://github.com/tc39/proposal-decorators>
+/// * <https://babeljs.io/docs/babel-plugin-proposal-decorators>
+/// * <https://github.com/babel/babel/blob/main/packages/babel-helpers/src/helpers/applyDecs2305.js>
+pub struct Stage3Decorators<'a> {
+    ast: Rc<AstBuilder<'a>>,
+    ctx: TransformerCtx<'a>,
+    vars: Vec<'a, VariableDeclarator<'a>>,
+}
+
+impl<'a> CreateVars<'a> for Stage3Decorators<'a> {
+    fn ctx(&self) -> &TransformerCtx<'a> {
+        &self.ctx
+    }
+
+    fn vars_mut(&mut self) -> &mut Vec<'a, VariableDeclarator<'a>> {
+        &mut self.vars
+    }
+}
+
+impl<'a> Stage3Decorators<'a> {
+    pub fn new(
+        ast: Rc<AstBuilder<'a>>,
+        ctx: TransformerCtx<'a>,
+        options: &TransformOptions,
+    ) -> Option<Self> {
+        options
+            .decorators
+            .is_some_and(|options| options.version == DecoratorsVersion::V2023_05)
+            .then(|| {
+                let vars = ast.new_vec();
+                Self { ast, ctx, vars }
+            })
+    }
+
+    pub fn transform_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>) {
+        if !stmts.iter_mut().any(|stmt| Self::class_declaration(stmt).is_some()) {
+            return;
+        }
+        let old_stmts = mem::replace(stmts, self.ast.new_vec());
+        for mut stmt in old_stmts {
+            let Some(class) = Self::class_declaration(&mut stmt) else {
+                stmts.push(stmt);
+                continue;
+            };
+            let decorated =
+                !class.decorators.is_empty() || class.body.body.iter().any(Self::is_decorated);
+            if !decorated {
+                self.transform_class(class);
+                stmts.push(stmt);
+                continue;
+            }
+            let (before, after) = self.decorate_class(class);
+            stmts.extend(before);
+            stmts.push(stmt);
+            stmts.extend(after);
+        }
+        // The memoized keys and decorators are declared before the nested statements are visited.
+        self.add_vars_to_statements(stmts);
+    }
+
+    /// Lowers the `accessor` properties which are not decorated, class declarations are lowered
+    /// by [Self::transform_statements].
+    pub fn transform_class(&mut self, class: &mut Class<'a>) {
+        if !class
+            .body
+            .body
+            .iter()
+            .any(|element| matches!(element, ClassElement::AccessorProperty(_)))
+        {
+            return;
+        }
+        let mut private_names = Self::private_names(class);
+        for element in mem::replace(&mut class.body.body, self.ast.new_vec()) {
+            if let ClassElement::AccessorProperty(def) = element {
+                let mut def = def.unbox();
+                let setter_key = self.duplicate_key(&mut def.key);
+                self.lower_accessor(&mut class.body.body, def, setter_key, &mut private_names);
+            } else {
+                class.body.body.push(element);
+            }
+        }
+    }
+
+    /// The class declaration of the statement, if it has decorators or `accessor` properties.
+    fn class_declaration<'b>(stmt: &'b mut Statement<'a>) -> Option<&'b mut Class<'a>> {
+        let class = match stmt {
+            Statement::Declaration(Declaration::ClassDeclaration(class)) => class,
+            Statement::ModuleDeclaration(decl) => match &mut **decl {
+                ModuleDeclaration::ExportNamedDeclaration(decl) => match &mut decl.declaration {
+                    Some(Declaration::ClassDeclaration(class)) => class,
+                    _ => return None,
+                },
+                ModuleDeclaration::ExportDefaultDeclaration(decl) => match &mut decl.declaration {
+                    ExportDefaultDeclarationKind::ClassDeclaration(class) => class,
+                    _ => return None,
+                },
+                _ => return None,
+            },
+            _ => return None,
+        };
+        let transform = !class.decorators.is_empty()
+            || class.body.body.iter().any(|element| {
+                Self::is_decorated(element) || matches!(element, ClassElement::AccessorProperty(_))
+            });
+        transform.then_some(&mut **class)
+    }
+
+    /// Decorators of private members are not supported.
+    fn is_decorated(element: &ClassElement<'a>) -> bool {
+        let (decorators, key) = match element {
+            ClassElement::MethodDefinition(def) => (&def.decorators, &def.key),
+            ClassElement::PropertyDefinition(def) => (&def.decorators, &def.key),
+            ClassElement::AccessorProperty(def) => (&def.decorators, &def.key),
+            _ => return false,
+        };
+        !decorators.is_empty() && !matches!(key, PropertyKey::PrivateIdentifier(_))
+    }
+
+    /// Returns the statements inserted before and after the class declaration.
+    fn decorate_class(
+        &mut self,
+        class: &mut Class<'a>,
+    ) -> (Option<Statement<'a>>, Option<Statement<'a>>) {
+        let span = Span::default();
+        // Decorators are evaluated before the class: `_dec = a.b;`
+        let mut memos = self.ast.new_vec();
+        let class_decorators = self.decorator_expressions(&mut class.decorators, &mut memos);
+
+        let mut private_names = Self::private_names(class);
+        let mut decorations = self.ast.new_vec();
+        let mut initializers = self.ast.new_vec();
+        let mut has_proto_initializer = false;
+        let mut has_static_initializer = false;
+        for element in mem::replace(&mut class.body.body, self.ast.new_vec()) {
+            if !Self::is_decorated(&element) {
+                if let ClassElement::AccessorProperty(def) = element {
+                    let mut def = def.unbox();
+                    let setter_key = self.duplicate_key(&mut def.key);
+                    self.lower_accessor(&mut class.body.body, def, setter_key, &mut private_names);
+                } else {
+                    class.body.body.push(element);
+                }
+                continue;
+            }
+            match element {
+                ClassElement::MethodDefinition(mut def) => {
+                    let decorators = self.decorator_expressions(&mut def.decorators, &mut memos);
+                    let kind = match def.kind {
+                        MethodDefinitionKind::Get => GETTER,
+                        MethodDefinitionKind::Set => SETTER,
+                        _ => METHOD,
+                    };
+                    let key = member_key(self, &mut def.key).unwrap();
+                    decorations.push(self.decoration(decorators, kind, def.r#static, key));
+                    if def.r#static {
+                        has_static_initializer = true;
+                    } else {
+                        has_proto_initializer = true;
+                    }
+                    class.body.body.push(ClassElement::MethodDefinition(def));
+                }
+                ClassElement::PropertyDefinition(mut def) => {
+                    let decorators = self.decorator_expressions(&mut def.decorators, &mut memos);
+                    let initializer = self.initializer_var(&def.key);
+                    let key = member_key(self, &mut def.key).unwrap();
+                    decorations.push(self.decoration(decorators, FIELD, def.r#static, key));
+                    def.value = Some(self.call_initializer(initializer.clone(), def.value.take()));
+                    initializers.push(Some(self.assignment_target(initializer)));
+                    class.body.body.push(ClassElement::PropertyDefinition(def));
+                }
+                ClassElement::AccessorProperty(def) => {
+                    let mut def = def.unbox();
+                    let decorators = self.decorator_expressions(&mut def.decorators, &mut memos);
+                    let initializer = self.initializer_var(&def.key);
+                    let setter_key = self.duplicate_key(&mut def.key);
+                    let key = self.key_expression(&setter_key);
+                    decorations.push(self.decoration(decorators, ACCESSOR, def.r#static, key));
+                    if def.r#static {
+                        has_static_initializer = true;
+                    } else {
+                        has_proto_initializer = true;
+                    }
+                    def.value = Some(self.call_initializer(initializer.clone(), def.value.take()));
+                    initializers.push(Some(self.assignment_target(initializer)));
+                    self.lower_accessor(&mut class.body.body, def, setter_key, &mut private_names);
+                }
+                _ => unreachable!(),
+            }
+        }
+
+        if has_proto_initializer {
+            let initializer = self.create_new_named_var("initProto");
+            self.call_proto_initializer(class, initializer.clone());
+            initializers.push(Some(self.assignment_target(initializer)));
+        }
+        let static_initializer = has_static_initializer.then(|| {
+            let initializer = self.create_new_named_var("initStatic");
+            initializers.push(Some(self.assignment_target(initializer.clone())));
+            initializer
+        });
+
+        // The decorated class replaces the class binding: `A = _A;`
+        let mut after = None;
+        let class_targets = (!class_decorators.is_empty()).then(|| {
+            let name = match &class.id {
+                Some(id) => id.name.clone(),
+                None => {
+                    let name = self.ctx.scopes().generate_uid("default");
+                    self.ctx.add_binding(name.clone());
+                    class.id = Some(BindingIdentifier::new(span, name.clone()));
+                    name
+                }
+            };
+            let decorated = self.create_new_named_var(&name);
+            let class_initializer = self.create_new_named_var("initClass");
+            // References to the class in its body are to the decorated class.
+            ClassReferenceRenamer { name: name.clone(), replacement: decorated.name.clone() }
+                .visit_class_body(&mut class.body);
+            let callee = self.ast.identifier_reference_expression(class_initializer.clone());
+            let call = self.ast.call_expression(span, callee, self.ast.new_vec(), false, None);
+            let block = self.ast.new_vec_single(self.ast.expression_statement(span, call));
+            class.body.body.push(self.ast.static_block(span, block));
+            let target =
+                self.ast.simple_assignment_target_identifier(IdentifierReference::new(span, name));
+            let assignment = self.ast.assignment_expression(
+                span,
+                AssignmentOperator::Assign,
+                AssignmentTarget::SimpleAssignmentTarget(target),
+                self.ast.identifier_reference_expression(decorated.clone()),
+            );
+            after = Some(self.ast.expression_statement(span, assignment));
+            let mut targets = self.ast.new_vec_with_capacity(2);
+            targets.push(Some(self.assignment_target(decorated)));
+            targets.push(Some(self.assignment_target(class_initializer)));
+            targets
+        });
+
+        // static { [_init_a, _initProto] = babelHelpers.applyDecs2305(this, [...], []).e; }
+        let mut arguments = self.ast.new_vec_with_capacity(3);
+        arguments.push(Argument::Expression(self.ast.this_expression(span)));
+        arguments.push(Argument::Expression(self.ast.array_expression(span, decorations, None)));
+        arguments.push(Argument::Expression(self.ast.array_expression(
+            span,
+            class_decorators,
+            None,
+        )));
+        let call = self.ctx.helper_call("applyDecs2305", arguments);
+        let assignment = match class_targets {
+            None => self.destructure(self.array_target(initializers), self.member(call, "e")),
+            Some(targets) if initializers.is_empty() => {
+                self.destructure(self.array_target(targets), self.member(call, "c"))
+            }
+            Some(targets) => {
+                let mut properties = self.ast.new_vec_with_capacity(2);
+                for (name, elements) in [("e", initializers), ("c", targets)] {
+                    let binding =
+                        AssignmentTargetMaybeDefault::AssignmentTarget(self.array_target(elements));
+                    let name = PropertyKey::Identifier(
+                        self.ast.alloc(IdentifierName::new(span, name.into())),
+                    );
+                    properties.push(AssignmentTargetProperty::AssignmentTargetPropertyProperty(
+                        self.ast.alloc(AssignmentTargetPropertyProperty { span, name, binding }),
+                    ));
+                }
+                let target = AssignmentTarget::AssignmentTargetPattern(
+                    AssignmentTargetPattern::ObjectAssignmentTarget(
+                        self.ast.alloc(ObjectAssignmentTarget { span, properties, rest: None }),
+                    ),
+                );
+                let assignment = self.destructure(target, call);
+                self.ast.parenthesized_expression(span, assignment)
+            }
+        };
+        let mut block = self.ast.new_vec_single(self.ast.expression_statement(span, assignment));
+        if let Some(initializer) = static_initializer {
+            let call = self.call_initializer(initializer, None);
+            block.push(self.ast.expression_statement(span, call));
+        }
+        class.body.body.insert(0, self.ast.static_block(span, block));
+
+        let before = match memos.len() {
+            0 => None,
+            1 => memos.pop(),
+            _ => Some(self.ast.sequence_expression(span, memos)),
+        };
+        (before.map(|expr| self.ast.expression_statement(span, expr)), after)
+    }
+
+    /// Identifiers are used as is, other decorators are memoized: `@a.b()` -> `_dec = a.b()`
+    fn decorator_expressions(
+        &mut self,
+        decorators: &mut Vec<'a, Decorator<'a>>,
+        memos: &mut Vec<'a, Expression<'a>>,
+    ) -> Vec<'a, ArrayExpressionElement<'a>> {
+        let mut elements = self.ast.new_vec_with_capacity(decorators.len());
+        for decorator in mem::replace(decorators, self.ast.new_vec()) {
+            let expr = match decorator.expression {
+                expr @ Expression::Identifier(_) => expr,
+                expr => {
+                    let temp = self.create_new_named_var("dec");
+                    let target = self.ast.simple_assignment_target_identifier(temp.clone());
+                    memos.push(self.ast.assignment_expression(
+                        Span::default(),
+                        AssignmentOperator::Assign,
+                        AssignmentTarget::SimpleAssignmentTarget(target),
+                        expr,
+                    ));
+                    self.ast.identifier_reference_expression(temp)
+                }
+            };
+            elements.push(ArrayExpressionElement::Expression(expr));
+        }
+        elements
+    }
+
+    /// `[dec, kind, key]`, or `[[dec1, dec2], kind, key]` for multiple decorators.
+    fn decoration(
+        &self,
+        mut decorators: Vec<'a, ArrayExpressionElement<'a>>,
+        kind: u8,
+        r#static: bool,
+        key: Expression<'a>,
+    ) -> ArrayExpressionElement<'a> {
+        let span = Span::default();
+        let decorators = if decorators.len() == 1 {
+            let Some(ArrayExpressionElement::Expression(expr)) = decorators.pop() else {
+                unreachable!()
+            };
+            expr
+        } else {
+            self.ast.array_expression(span, decorators, None)
+        };
+        let kind = f64::from(if r#static { kind | STATIC } else { kind });
+        let raw = self.ast.new_str(&kind.to_string());
+        let kind = self.ast.literal_number_expression(NumberLiteral::new(
+            span,
+            kind,
+            raw,
+            NumberBase::Decimal,
+        ));
+        let mut elements = self.ast.new_vec_with_capacity(3);
+        elements.push(ArrayExpressionElement::Expression(decorators));
+        elements.push(ArrayExpressionElement::Expression(kind));
+        elements.push(ArrayExpressionElement::Expression(key));
+        ArrayExpressionElement::Expression(self.ast.array_expression(span, elements, None))
+    }
+
+    /// `var _init_name` for the initializer of a decorated field or accessor.
+    fn initializer_var(&mut self, key: &PropertyKey<'a>) -> IdentifierReference {
+        let name = match key {
+            PropertyKey::Identifier(ident) => ident.name.clone(),
+            _ => "computedKey".into(),
+        };
+        self.create_new_named_var(&format!("init_{name}"))
+    }
+
+    /// `_init_a(this, value)`
+    fn call_initializer(
+        &self,
+        initializer: IdentifierReference,
+        value: Option<Expression<'a>>,
+    ) -> Expression<'a> {
+        let mut arguments = self.ast.new_vec_with_capacity(2);
+        arguments.push(Argument::Expression(self.ast.this_expression(Span::default())));
+        if let Some(value) = value {
+            arguments.push(Argument::Expression(value));
+        }
+        let callee = self.ast.identifier_reference_expression(initializer);
+        self.ast.call_expression(Span::default(), callee, arguments, false, None)
+    }
+
+    /// Calls `_initProto(this)` before the first instance field is initialized, or at the start
+    /// of the constructor.
+    fn call_proto_initializer(&self, class: &mut Class<'a>, initializer: IdentifierReference) {
+        let span = Span::default();
+        let call = self.call_initializer(initializer, None);
+
+        // `a = (_initProto(this), 1)`
+        for element in class.body.body.iter_mut() {
+            if let ClassElement::PropertyDefinition(def) = element {
+                if !def.r#static {
+                    let value = def.value.take().unwrap_or_else(|| self.ast.void_0());
+                    let mut expressions = self.ast.new_vec_with_capacity(2);
+                    expressions.push(call);
+                    expressions.push(value);
+                    def.value = Some(self.ast.sequence_expression(span, expressions));
+                    return;
+                }
+            }
+        }
+
+        let stmt = self.ast.expression_statement(span, call);
+        for element in class.body.body.iter_mut() {
+            if let ClassElement::MethodDefinition(def) = element {
+                if def.kind == MethodDefinitionKind::Constructor {
+                    if let Some(body) = &mut def.value.body {
+                        let index = body
+                            .statements
+                            .iter()
+                            .position(Self::is_super_call)
+                            .map_or(0, |index| index + 1);
+                        body.statements.insert(index, stmt);
+                    }
+                    return;
+                }
+            }
+        }
+
+        // constructor(...args) { super(...args); _initProto(this); }
+        let mut statements = self.ast.new_vec();
+        let mut rest = None;
+        if class.super_class.is_some() {
+            let args: Atom = "args".into();
+            let pattern = self.ast.binding_pattern(
+                self.ast.binding_pattern_identifier(BindingIdentifier::new(span, args.clone())),
+                None,
+                false,
+            );
+            rest = Some(self.ast.rest_element(span, pattern));
+            let argument =
+                self.ast.identifier_reference_expression(IdentifierReference::new(span, args));
+            let arguments = self
+                .ast
+                .new_vec_single(Argument::SpreadElement(self.ast.spread_element(span, argument)));
+            let call =
+                self.ast.call_expression(span, self.ast.super_(span), arguments, false, None);
+            statements.push(self.ast.expression_statement(span, call));
+        }
+        statements.push(stmt);
+        let params = self.ast.formal_parameters(
+            span,
+            FormalParameterKind::UniqueFormalParameters,
+            self.ast.new_vec(),
+            rest,
+        );
+        let body = self.ast.function_body(span, self.ast.new_vec(), statements);
+        let constructor = self.method(params, body);
+        let key = PropertyKey::Identifier(
+            self.ast.alloc(IdentifierName::new(span, "constructor".into())),
+        );
+        class.body.body.insert(
+            0,
+            self.ast.class_method(
+                span,
+                key,
+                constructor,
+                MethodDefinitionKind::Constructor,
+                false,
+                false,
+            ),
+        );
+    }
+
+    fn is_super_call(stmt: &Statement<'a>) -> bool {
+        matches!(stmt, Statement::ExpressionStatement(stmt)
+            if matches!(&stmt.expression, Expression::CallExpression(call)
+                if matches!(call.callee, Expression::Super(_))))
+    }
+
+    /// `accessor x = 1` -> `#_x = 1; get x() { return this.#_x; } set x(v) { this.#_x = v; }`
+    fn lower_accessor(
+        &self,
+        body: &mut Vec<'a, ClassElement<'a>>,
+        def: AccessorProperty<'a>,
+        setter_key: PropertyKey<'a>,
+        private_names: &mut FxHashSet<Atom>,
+    ) {
+        let AccessorProperty { span, key, value, computed, r#static, .. } = def;
+        let name = Self::private_name(&key, private_names);
+        let field = PropertyKey::PrivateIdentifier(
+            self.ast.alloc(PrivateIdentifier { span, name: name.clone() }),
+        );
+        body.push(self.ast.class_property(span, field, value, false, r#static, self.ast.new_vec()));
+
+        let params = self.ast.formal_parameters(
+            span,
+            FormalParameterKind::UniqueFormalParameters,
+            self.ast.new_vec(),
+            None,
+        );
+        let field = self.ast.member_expression(self.private_field(name.clone()));
+        let statements = self.ast.new_vec_single(self.ast.return_statement(span, Some(field)));
+        let getter =
+            self.method(params, self.ast.function_body(span, self.ast.new_vec(), statements));
+        body.push(self.ast.class_method(
+            span,
+            key,
+            getter,
+            MethodDefinitionKind::Get,
+            computed,
+            r#static,
+        ));
+
+        let value: Atom = "v".into();
+        let pattern = self.ast.binding_pattern(
+            self.ast.binding_pattern_identifier(BindingIdentifier::new(span, value.clone())),
+            None,
+            false,
+        );
+        let param = self.ast.formal_parameter(span, pattern, None, false, self.ast.new_vec());
+        let params = self.ast.formal_parameters(
+            span,
+            FormalParameterKind::UniqueFormalParameters,
+            self.ast.new_vec_single(param),
+            None,
+        );
+        let target = self.ast.simple_assignment_target_member_expression(self.private_field(name));
+        let assignment = self.ast.assignment_expression(
+            span,
+            AssignmentOperator::Assign,
+            AssignmentTarget::SimpleAssignmentTarget(target),
+            self.ast.identifier_reference_expression(IdentifierReference::new(span, value)),
+        );
+        let statements = self.ast.new_vec_single(self.ast.expression_statement(span, assignment));
+        let setter =
+            self.method(params, self.ast.function_body(span, self.ast.new_vec(), statements));
+        body.push(self.ast.class_method(
+            span,
+            setter_key,
+            setter,
+            MethodDefinitionKind::Set,
+            computed,
+            r#static,
+        ));
+    }
+
+    /// A copy of the key for the setter of an accessor, computed keys are memoized.
+    fn duplicate_key(&mut self, key: &mut PropertyKey<'a>) -> PropertyKey<'a> {
+        let span = Span::default();
+        match key {
+            PropertyKey::Identifier(ident) => PropertyKey::Identifier(
+                self.ast.alloc(IdentifierName::new(span, ident.name.clone())),
+            ),
+            PropertyKey::PrivateIdentifier(ident) => PropertyKey::PrivateIdentifier(
+                self.ast.alloc(PrivateIdentifier { span, name: ident.name.clone() }),
+            ),
+            PropertyKey::Expression(_) => PropertyKey::Expression(member_key(self, key).unwrap()),
+        }
+    }
+
+    /// The name passed to the helper for a key returned by [Self::duplicate_key].
+    fn key_expression(&self, key: &PropertyKey<'a>) -> Expression<'a> {
+        let span = Span::default();
+        match key {
+            PropertyKey::Identifier(ident) => {
+                self.ast.literal_string_expression(StringLiteral::new(span, ident.name.clone()))
+            }
+            PropertyKey::Expression(Expression::StringLiteral(lit)) => {
+                self.ast.literal_string_expression(StringLiteral::new(span, lit.value.clone()))
+            }
+            PropertyKey::Expression(Expression::NumberLiteral(lit)) => self
+                .ast
+                .literal_number_expression(NumberLiteral::new(span, lit.value, lit.raw, lit.base)),
+            PropertyKey::Expression(Expression::Identifier(ident)) => {
+                self.ast.identifier_reference_expression(IdentifierReference::new(
+                    span,
+                    ident.name.clone(),
+                ))
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    fn private_names(class: &Class<'a>) -> FxHashSet<Atom> {
+        class
+            .body
+            .body
+            .iter()
+            .filter_map(|element| match element.property_key() {
+                Some(PropertyKey::PrivateIdentifier(ident)) => Some(ident.name.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// A unique private name for the storage of an accessor: `accessor x` -> `#_x`
+    fn private_name(key: &PropertyKey<'a>, private_names: &mut FxHashSet<Atom>) -> Atom {
+        let base = match key {
+            PropertyKey::Identifier(ident) => format!("_{}", ident.name),
+            PropertyKey::PrivateIdentifier(ident) => format!("_{}", ident.name),
+            PropertyKey::Expression(_) => "_accessor".to_string(),
+        };
+        let mut name = base.clone();
+        let mut i = 2;
+        while private_names.contains(name.as_str()) {
+            name = format!("{base}{i}");
+            i += 1;
+        }
+        let name = Atom::from(name);
+        private_names.insert(name.clone());
+        name
+    }
+
+    /// `this.#name`
+    fn private_field(&self, name: Atom) -> MemberExpression<'a> {
+        let span = Span::default();
+        MemberExpression::PrivateFieldExpression(PrivateFieldExpression {
+            span,
+            object: self.ast.this_expression(span),
+            field: PrivateIdentifier { span, name },
+            optional: false,
+        })
+    }
+
+    fn method(
+        &self,
+        params: oxc_allocator::Box<'a, FormalParameters<'a>>,
+        body: oxc_allocator::Box<'a, FunctionBody<'a>>,
+    ) -> oxc_allocator::Box<'a, Function<'a>> {
+        self.ast.function(
+            FunctionType::FunctionExpression,
+            Span::default(),
+            None,
+            false,
+            false,
+            false,
+            params,
+            Some(body),
+            None,
+            None,
+            Modifiers::empty(),
+        )
+    }
+
+    fn member(&self, object: Expression<'a>, property: &str) -> Expression<'a> {
+        let property = IdentifierName::new(Span::default(), property.into());
+        self.ast.static_member_expression(Span::default(), object, property, false)
+    }
+
+    fn assignment_target(&self, ident: IdentifierReference) -> AssignmentTargetMaybeDefault<'a> {
+        AssignmentTargetMaybeDefault::AssignmentTarget(AssignmentTarget::SimpleAssignmentTarget(
+            self.ast.simple_assignment_target_identifier(ident),
+        ))
+    }
+
+    fn array_target(
+        &self,
+        elements: Vec<'a, Option<AssignmentTargetMaybeDefault<'a>>>,
+    ) -> AssignmentTarget<'a> {
+        AssignmentTarget::AssignmentTargetPattern(AssignmentTargetPattern::ArrayAssignmentTarget(
+            self.ast.alloc(ArrayAssignmentTarget {
+                span: Span::default(),
+                elements,
+                rest: None,
+                trailing_comma: None,
+            }),
+        ))
+    }
+
+    fn destructure(&self, target: AssignmentTarget<'a>, value: Expression<'a>) -> Expression<'a> {
+        self.ast.assignment_expression(Span::default(), AssignmentOperator::Assign, target, value)
+    }
+}
+
+/// Renames the references to a class inside its body.
+struct ClassReferenceRenamer {
+    name: Atom,
+    replacement: Atom,
+}
+
+impl<'a> VisitMut<'a> for ClassReferenceRenamer {
+    fn visit_identifier_reference(&mut self, ident: &mut IdentifierReference) {
+        if ident.name == self.name {
+            ident.name = self.replacement.clone();
+        }
+    }
+}
+
+#[test]
+#[rustfmt::skip]
+fn test() {
+    use crate::{decorators::DecoratorsOptions, tester::Tester};
+
+    let options = TransformOptions {
+        decorators: Some(DecoratorsOptions {
+            version: DecoratorsVersion::V2023_05,
+            emit_decorator_metadata: false,
+        }),
+        ..TransformOptions::default()
+    };
+    Tester::new("test.js", options).test(&[
+        (
+            "class A { @m method() {} @p prop = 1; @s static x; }",
+            "var _init_prop, _init_x, _initProto;\nclass A {\n static { [_init_prop, _init_x, _initProto] = babelHelpers.applyDecs2305(this, [[m, 2, 'method'], [p, 0, 'prop'], [s, 8, 'x']], []).e; }\n method() {}\n prop = (_initProto(this), _init_prop(this, 1));\n static x = _init_x(this);\n}",
+        ),
+        (
+            "@dec class A { static create() { return new A(); } }",
+            "var _A, _initClass;\nclass A {\n static { [_A, _initClass] = babelHelpers.applyDecs2305(this, [], [dec]).c; }\n static create() { return new _A(); }\n static { _initClass(); }\n}\nA = _A;",
+        ),
+        (
+            "export default @a.b() class extends B { @c get x() {} @d @e accessor y = 1; }",
+            "var _dec, _init_y, _initProto, _default2, _initClass;\n_dec = a.b();\nexport default class _default extends B {\n static { ({ e: [_init_y, _initProto], c: [_default2, _initClass] } = babelHelpers.applyDecs2305(this, [[c, 3, 'x'], [[d, e], 1, 'y']], [_dec])); }\n get x() {}\n #_y = (_initProto(this), _init_y(this, 1));\n get y() { return this.#_y; }\n set y(v) { this.#_y = v; }\n static { _initClass(); }\n}\n_default = _default2;",
+        ),
+        (
+            "class A extends B { @m method() {} @s static f() {} }",
+            "var _initProto, _initStatic;\nclass A extends B {\n static { [_initProto, _initStatic] = babelHelpers.applyDecs2305(this, [[m, 2, 'method'], [s, 10, 'f']], []).e; _initStatic(this); }\n constructor(...args) { super(...args); _initProto(this); }\n method() {}\n static f() {}\n}",
+        ),
+        (
+            "class A { #_x; accessor x; static accessor [y] = 1; }",
+            "var _y;\nclass A { #_x; #_x2; get x() { return this.#_x2; } set x(v) { this.#_x2 = v; } static #_accessor = 1; static get [_y = y]() { return this.#_accessor; } static set [_y](v) { this.#_accessor = v; } }",
+        ),
+        (
+            "const A = class { accessor x = 1; };",
+            "const A = class { #_x = 1; get x() { return this.#_x; } set x(v) { this.#_x = v; } };",
+        ),
+    ]);
+}
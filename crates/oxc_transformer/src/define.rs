@@ -0,0 +1,393 @@
+use std::rc::Rc;
+
+use oxc_ast::{ast::*, AstBuilder, Visit};
+use oxc_parser::Parser;
+use oxc_span::{Atom, GetSpan, SourceType, Span};
+use oxc_syntax::operator::{BinaryOperator, LogicalOperator, UnaryOperator};
+use oxc_syntax::scope::ScopeFlags;
+
+use crate::{context::TransformerCtx, options::TransformOptions};
+
+/// Replaces global expressions with constants, e.g. `process.env.NODE_ENV` -> `"production"`.
+///
+/// The expressions containing the replaced constants are folded afterwards, so the dead branches
+/// of `if (process.env.NODE_ENV !== "production")` can be removed by the minifier:
+///
+/// * `"production" !== "production"` -> `false`
+/// * `!true` -> `false`
+/// * `false && a` -> `false`, `true && a` -> `a`
+/// * `true ? a : b` -> `a`
+///
+/// The dead branches of the `if` statements with a constant test are removed, unless they
+/// declare a `var`, and so are the imports which were only referenced in them, so the code for
+/// development is removed without a minifier.
+///
+/// The keys are identifiers or member chains of a global, `import.meta` included. The values are
+/// parsed as JavaScript expressions, the invalid ones are ignored.
+///
+/// References:
+/// * <https://esbuild.github.io/api/#define>
+/// * <https://webpack.js.org/plugins/define-plugin>
+pub struct Define<'a> {
+    ast: Rc<AstBuilder<'a>>,
+    ctx: TransformerCtx<'a>,
+    defines: Vec<(Vec<String>, String)>,
+    /// The names referenced in the removed branches, their imports may be unused.
+    removed_references: Vec<Atom>,
+    /// The start of the `if` statements removed, which are left as empty statements.
+    removed_statements: Vec<u32>,
+}
+
+/// A literal folded at compile time.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Constant<'b> {
+    String(&'b str),
+    Number(f64),
+    Boolean(bool),
+    Null,
+}
+
+impl<'a> Define<'a> {
+    pub fn new(
+        ast: Rc<AstBuilder<'a>>,
+        ctx: TransformerCtx<'a>,
+        options: &TransformOptions,
+    ) -> Option<Self> {
+        let defines = options
+            .define
+            .iter()
+            .map(|(key, value)| (key.split('.').map(String::from).collect(), value.clone()))
+            .collect::<Vec<_>>();
+        (!defines.is_empty()).then(|| Self {
+            ast,
+            ctx,
+            defines,
+            removed_references: vec![],
+            removed_statements: vec![],
+        })
+    }
+
+    pub fn transform_expression(&self, expr: &mut Expression<'a>) {
+        let Some(value) =
+            self.defines.iter().find(|(key, _)| self.matches(expr, key)).map(|(_, value)| value)
+        else {
+            return;
+        };
+        let Some(mut replacement) = self.parse(value) else { return };
+        // The spans of the parsed nodes are offsets in the value, map the constants to the
+        // replaced expression instead.
+        let span = expr.span();
+        match &mut replacement {
+            Expression::StringLiteral(lit) => lit.span = span,
+            Expression::NumberLiteral(lit) => lit.span = span,
+            Expression::BooleanLiteral(lit) => lit.span = span,
+            Expression::NullLiteral(lit) => lit.span = span,
+            Expression::Identifier(ident) => ident.span = span,
+            _ => {}
+        }
+        *expr = replacement;
+    }
+
+    /// Fold the expression once its operands are replaced.
+    pub fn fold_expression(&self, expr: &mut Expression<'a>) {
+        match expr {
+            Expression::UnaryExpression(unary) if unary.operator == UnaryOperator::LogicalNot => {
+                if let Some(value) = constant(&unary.argument) {
+                    *expr = self.boolean(!truthy(value));
+                }
+            }
+            Expression::BinaryExpression(binary) => {
+                let (Some(left), Some(right)) = (constant(&binary.left), constant(&binary.right))
+                else {
+                    return;
+                };
+                let equal = match binary.operator {
+                    BinaryOperator::StrictEquality | BinaryOperator::StrictInequality => {
+                        Some(left == right)
+                    }
+                    BinaryOperator::Equality | BinaryOperator::Inequality => {
+                        loose_equals(left, right)
+                    }
+                    _ => None,
+                };
+                let Some(equal) = equal else { return };
+                let negated = matches!(
+                    binary.operator,
+                    BinaryOperator::StrictInequality | BinaryOperator::Inequality
+                );
+                *expr = self.boolean(equal != negated);
+            }
+            Expression::LogicalExpression(logical) => {
+                let Some(left) = constant(&logical.left) else { return };
+                let keep_left = match logical.operator {
+                    LogicalOperator::And => !truthy(left),
+                    LogicalOperator::Or => truthy(left),
+                    LogicalOperator::Coalesce => left != Constant::Null,
+                };
+                *expr = if keep_left {
+                    self.ast.move_expression(&mut logical.left)
+                } else {
+                    self.ast.move_expression(&mut logical.right)
+                };
+            }
+            Expression::ConditionalExpression(conditional) => {
+                let Some(test) = constant(&conditional.test) else { return };
+                *expr = if truthy(test) {
+                    self.ast.move_expression(&mut conditional.consequent)
+                } else {
+                    self.ast.move_expression(&mut conditional.alternate)
+                };
+            }
+            Expression::ParenthesizedExpression(paren) if constant(&paren.expression).is_some() => {
+                *expr = self.ast.move_expression(&mut paren.expression);
+            }
+            _ => {}
+        }
+    }
+
+    /// `if (true) a; else b;` -> `a;`
+    pub fn fold_statement(&mut self, stmt: &mut Statement<'a>) {
+        let Statement::IfStatement(if_stmt) = stmt else { return };
+        if matches!(&if_stmt.alternate, Some(Statement::EmptyStatement(empty)) if self.removed_statements.contains(&empty.span.start))
+        {
+            if_stmt.alternate = None;
+        }
+        let Some(test) = constant(&if_stmt.test).map(truthy) else { return };
+        let if_stmt = &mut **if_stmt;
+        let (kept, removed) = if test {
+            (Some(&mut if_stmt.consequent), if_stmt.alternate.as_ref())
+        } else {
+            (if_stmt.alternate.as_mut(), Some(&if_stmt.consequent))
+        };
+        let mut references = References::default();
+        if let Some(removed) = removed {
+            references.visit_statement(removed);
+        }
+        if references.has_var {
+            return;
+        }
+        self.removed_references.extend(references.names);
+        *stmt = if let Some(kept) = kept {
+            self.ast.move_statement(kept)
+        } else {
+            self.removed_statements.push(if_stmt.span.start);
+            self.ast.empty_statement(if_stmt.span)
+        };
+    }
+
+    /// Remove the `if` statements without a branch left.
+    pub fn remove_dead_statements(&self, stmts: &mut oxc_allocator::Vec<'a, Statement<'a>>) {
+        if self.removed_statements.is_empty() {
+            return;
+        }
+        stmts.retain(|stmt| {
+            !matches!(stmt, Statement::EmptyStatement(empty) if self.removed_statements.contains(&empty.span.start))
+        });
+    }
+
+    /// Remove the imports which were only referenced in the removed branches.
+    pub fn remove_unused_imports(&mut self, program: &mut Program<'a>) {
+        if self.removed_references.is_empty() {
+            return;
+        }
+        let mut references = References::default();
+        references.visit_program(program);
+        let unused = |local: &BindingIdentifier| {
+            self.removed_references.contains(&local.name) && !references.names.contains(&local.name)
+        };
+        let mut emptied = vec![];
+        for stmt in program.body.iter_mut() {
+            let Statement::ModuleDeclaration(decl) = stmt else { continue };
+            let ModuleDeclaration::ImportDeclaration(decl) = &mut **decl else { continue };
+            if decl.specifiers.is_empty() {
+                continue;
+            }
+            decl.specifiers.retain(|specifier| match specifier {
+                ImportDeclarationSpecifier::ImportSpecifier(s) => !unused(&s.local),
+                ImportDeclarationSpecifier::ImportDefaultSpecifier(s) => !unused(&s.local),
+                ImportDeclarationSpecifier::ImportNamespaceSpecifier(s) => !unused(&s.local),
+            });
+            if decl.specifiers.is_empty() {
+                emptied.push(decl.span);
+            }
+        }
+        program.body.retain(|stmt| !emptied.contains(&stmt.span()));
+        self.removed_references.clear();
+    }
+
+    /// `a.b.c` matches the key `["a", "b", "c"]` when `a` is a global.
+    fn matches(&self, expr: &Expression<'a>, key: &[String]) -> bool {
+        match expr {
+            Expression::Identifier(ident) => {
+                key.len() == 1
+                    && ident.name.as_str() == key[0]
+                    && ident
+                        .reference_id
+                        .get()
+                        .map_or(true, |id| self.ctx.symbols().is_global_reference(id))
+            }
+            Expression::MetaProperty(meta) => {
+                key.len() == 2
+                    && meta.meta.name.as_str() == key[0]
+                    && meta.property.name.as_str() == key[1]
+            }
+            Expression::MemberExpression(member) => {
+                let MemberExpression::StaticMemberExpression(member) = &**member else {
+                    return false;
+                };
+                let Some((last, rest)) = key.split_last() else { return false };
+                member.property.name.as_str() == last && self.matches(&member.object, rest)
+            }
+            _ => false,
+        }
+    }
+
+    /// Parse the replacement, keeping the parentheses unless it is a single term.
+    fn parse(&self, value: &str) -> Option<Expression<'a>> {
+        let source = self.ast.new_str(&format!("({value})"));
+        let ret = Parser::new(self.ast.allocator, source, SourceType::default()).parse();
+        if !ret.errors.is_empty() {
+            return None;
+        }
+        let mut program = ret.program;
+        let Some(Statement::ExpressionStatement(stmt)) = program.body.first_mut() else {
+            return None;
+        };
+        let mut expr = self.ast.move_expression(&mut stmt.expression);
+        if let Expression::ParenthesizedExpression(paren) = &mut expr {
+            if matches!(
+                paren.expression,
+                Expression::StringLiteral(_)
+                    | Expression::NumberLiteral(_)
+                    | Expression::BooleanLiteral(_)
+                    | Expression::NullLiteral(_)
+                    | Expression::Identifier(_)
+                    | Expression::MemberExpression(_)
+                    | Expression::ArrayExpression(_)
+            ) {
+                expr = self.ast.move_expression(&mut paren.expression);
+            }
+        }
+        Some(expr)
+    }
+
+    fn boolean(&self, value: bool) -> Expression<'a> {
+        self.ast.literal_boolean_expression(self.ast.boolean_literal(Span::default(), value))
+    }
+}
+
+/// The names referenced in a part of the program, and whether it declares a `var` outside of
+/// the functions.
+#[derive(Default)]
+struct References {
+    names: Vec<Atom>,
+    has_var: bool,
+    function_depth: u32,
+}
+
+impl<'a> Visit<'a> for References {
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference) {
+        self.names.push(ident.name.clone());
+    }
+
+    fn visit_jsx_element_name(&mut self, name: &JSXElementName<'a>) {
+        match name {
+            JSXElementName::Identifier(ident) => self.names.push(ident.name.clone()),
+            JSXElementName::MemberExpression(expr) => {
+                self.names.push(expr.get_object_identifier().name.clone());
+            }
+            JSXElementName::NamespacedName(_) => {}
+        }
+    }
+
+    fn visit_variable_declaration(&mut self, decl: &VariableDeclaration<'a>) {
+        self.has_var |= self.function_depth == 0 && decl.kind == VariableDeclarationKind::Var;
+        for declarator in &decl.declarations {
+            self.visit_variable_declarator(declarator);
+        }
+    }
+
+    fn visit_function(&mut self, func: &Function<'a>, _flags: Option<ScopeFlags>) {
+        self.function_depth += 1;
+        if let Some(ident) = &func.id {
+            self.visit_binding_identifier(ident);
+        }
+        self.visit_formal_parameters(&func.params);
+        if let Some(body) = &func.body {
+            self.visit_function_body(body);
+        }
+        self.function_depth -= 1;
+    }
+}
+
+fn constant<'b>(expr: &'b Expression) -> Option<Constant<'b>> {
+    match expr {
+        Expression::StringLiteral(lit) => Some(Constant::String(lit.value.as_str())),
+        Expression::NumberLiteral(lit) => Some(Constant::Number(lit.value)),
+        Expression::BooleanLiteral(lit) => Some(Constant::Boolean(lit.value)),
+        Expression::NullLiteral(_) => Some(Constant::Null),
+        _ => None,
+    }
+}
+
+fn truthy(value: Constant) -> bool {
+    match value {
+        Constant::String(value) => !value.is_empty(),
+        Constant::Number(value) => value != 0.0 && !value.is_nan(),
+        Constant::Boolean(value) => value,
+        Constant::Null => false,
+    }
+}
+
+/// `==` of the constants, `None` when it depends on the type conversions.
+fn loose_equals(left: Constant, right: Constant) -> Option<bool> {
+    match (left, right) {
+        (Constant::Null, Constant::Null) => Some(true),
+        (Constant::Null, _) | (_, Constant::Null) => Some(false),
+        (Constant::String(_), Constant::String(_))
+        | (Constant::Number(_), Constant::Number(_))
+        | (Constant::Boolean(_), Constant::Boolean(_)) => Some(left == right),
+        _ => None,
+    }
+}
+
+#[test]
+#[rustfmt::skip]
+fn test() {
+    use crate::tester::Tester;
+
+    let options = TransformOptions {
+        define: vec![
+            ("process.env.NODE_ENV".into(), "'production'".into()),
+            ("DEBUG".into(), "false".into()),
+            ("import.meta.env.MODE".into(), "\"test\"".into()),
+            ("VERSION".into(), "1 + 1".into()),
+        ],
+        ..TransformOptions::default()
+    };
+    let tests = &[
+        ("x = process.env.NODE_ENV;", "x = 'production';"),
+        ("if (process.env.NODE_ENV !== 'production') a();", ""),
+        ("x = DEBUG && log();", "x = false;"),
+        ("x = !DEBUG ? a : b;", "x = a;"),
+        ("x = import.meta.env.MODE == 'test';", "x = true;"),
+        ("x = VERSION * 2;", "x = (1 + 1) * 2;"),
+        ("x = process.env.HOME;", "x = process.env.HOME;"),
+        ("let DEBUG = true; x = DEBUG;", "let DEBUG = true; x = DEBUG;"),
+        (
+            "import { log } from 'log'; if (process.env.NODE_ENV !== 'production') { log(); } a();",
+            "a();",
+        ),
+        (
+            "import { log, warn } from 'log'; if (DEBUG) log(); else if (c) warn(); else b(log);",
+            "import { log, warn } from 'log'; if (c) warn(); else b(log);",
+        ),
+        (
+            "import Dev from 'dev'; if (!DEBUG) { render(); } else { render(<Dev />); }",
+            "{ render(); }",
+        ),
+        ("if (c) a(); else if (DEBUG) b();", "if (c) a();"),
+        ("if (DEBUG) { var x = 1; }", "if (false) { var x = 1; }"),
+    ];
+    Tester::new("test.jsx", options).test(tests);
+}
@@ -0,0 +1,379 @@
+use std::{path::Path, rc::Rc};
+
+use oxc_allocator::Vec;
+use oxc_ast::{ast::*, AstBuilder};
+use oxc_codegen::SourceMapBuilder;
+use oxc_span::{Atom, GetSpan, Span};
+use serde::Deserialize;
+
+use crate::{
+    context::TransformerCtx,
+    options::TransformOptions,
+    react_jsx::{JsxMode, ReactJsxRuntime},
+    utils::short_hash,
+};
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct EmotionOptions {
+    /// Add a `label` with the name of the variable the styles are assigned to, which is part of
+    /// the class name.
+    pub auto_label: bool,
+    /// The `label`, `[local]` is replaced with the name of the variable, `[filename]` and
+    /// `[dirname]` with the name of the file and of its directory.
+    pub label_format: String,
+    /// Append an inline source map to the styles, for the browser devtools.
+    pub source_map: bool,
+    /// Wrap the objects and templates of the `css` prop in a `css()` call, so they are
+    /// serialized once, with the automatic JSX runtime of `@emotion/react`.
+    pub css_prop_optimization: bool,
+}
+
+impl Default for EmotionOptions {
+    fn default() -> Self {
+        Self {
+            auto_label: true,
+            label_format: "[local]".into(),
+            source_map: true,
+            css_prop_optimization: true,
+        }
+    }
+}
+
+/// [@emotion/babel-plugin](https://emotion.sh/docs/@emotion/babel-plugin)
+///
+/// * ``const title = css`color: red;` `` -> ``const title = css`color: red;label:title;` ``
+/// * `const title = css({ color: "red" })` -> `const title = css({ color: "red" }, "label:title;")`
+/// * `const Title = styled.h1(...)` ->
+///   `const Title = styled("h1", { target: "e1x2y3z0", label: "Title" })(...)`
+/// * `<div css={{ color: "red" }} />` -> `<div css={_css({ color: "red" })} />`
+///
+/// The `css` of `@emotion/react` and `@emotion/css` and the `styled` of `@emotion/styled` are
+/// transformed.
+pub struct Emotion<'a> {
+    ast: Rc<AstBuilder<'a>>,
+    ctx: TransformerCtx<'a>,
+    options: EmotionOptions,
+    /// The `css` prop is optimized, with the automatic runtime of `@emotion/react`.
+    css_prop: bool,
+    /// The local names of `css`, the one imported for the `css` prop included.
+    css: std::vec::Vec<Atom>,
+    /// The local name of `styled`.
+    styled: Option<Atom>,
+    /// `import { css as _css } from "@emotion/react"` for the `css` prop.
+    css_prop_import: Option<Atom>,
+    /// The path and text of the source file, for the labels and the source maps.
+    source: Option<(String, &'a str)>,
+    file_hash: String,
+    /// The number of styled components with a `target`.
+    count: usize,
+    /// The start of the initializer of the variable visited and the name of the variable.
+    variable: Option<(u32, Atom)>,
+}
+
+impl<'a> Emotion<'a> {
+    pub fn new(
+        ast: Rc<AstBuilder<'a>>,
+        ctx: TransformerCtx<'a>,
+        options: &TransformOptions,
+    ) -> Option<Self> {
+        let css_prop = options.jsx == JsxMode::React
+            && options.react_jsx.as_ref().is_some_and(|jsx| {
+                jsx.runtime == ReactJsxRuntime::Automatic
+                    && jsx.import_source.as_deref() == Some("@emotion/react")
+            });
+        options.emotion.clone().map(|options| Self {
+            ast,
+            ctx,
+            css_prop: css_prop && options.css_prop_optimization,
+            options,
+            css: vec![],
+            styled: None,
+            css_prop_import: None,
+            source: None,
+            file_hash: short_hash(""),
+            count: 0,
+            variable: None,
+        })
+    }
+
+    pub fn set_source(&mut self, source_path: &Path, source_text: &'a str) {
+        let path = source_path.to_string_lossy().to_string();
+        self.file_hash = short_hash(if path.is_empty() { source_text } else { &path });
+        self.source = Some((path, source_text));
+    }
+
+    pub fn collect_imports(&mut self, stmts: &Vec<'a, Statement<'a>>) {
+        for stmt in stmts {
+            let Statement::ModuleDeclaration(decl) = stmt else { continue };
+            let ModuleDeclaration::ImportDeclaration(decl) = &**decl else { continue };
+            match decl.source.value.as_str() {
+                "@emotion/react" | "@emotion/css" => {
+                    for specifier in &decl.specifiers {
+                        if let ImportDeclarationSpecifier::ImportSpecifier(s) = specifier {
+                            if s.imported.name().as_str() == "css" {
+                                self.css.push(s.local.name.clone());
+                            }
+                        }
+                    }
+                }
+                "@emotion/styled" => {
+                    for specifier in &decl.specifiers {
+                        if let ImportDeclarationSpecifier::ImportDefaultSpecifier(s) = specifier {
+                            self.styled = Some(s.local.name.clone());
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Record the name of `const name = css(...)` for its label.
+    pub fn transform_variable_declarator(&mut self, declarator: &VariableDeclarator<'a>) {
+        if let (BindingPatternKind::BindingIdentifier(ident), Some(init)) =
+            (&declarator.id.kind, &declarator.init)
+        {
+            self.variable = Some((init.span().start, ident.name.clone()));
+        }
+    }
+
+    pub fn transform_expression(&mut self, expr: &mut Expression<'a>) {
+        match expr {
+            Expression::JSXElement(element) if self.css_prop => {
+                self.transform_css_prop(&mut element.opening_element);
+            }
+            // css`color: red;`
+            Expression::TaggedTemplateExpression(tagged) if self.is_css(&tagged.tag) => {
+                let styles = self.styles_suffix(tagged.span);
+                if let Some(quasi) = tagged.quasi.quasis.last_mut().filter(|_| !styles.is_empty()) {
+                    quasi.value.raw = format!("{}{styles}", quasi.value.raw).into();
+                    quasi.value.cooked = quasi
+                        .value
+                        .cooked
+                        .as_ref()
+                        .map(|cooked| format!("{cooked}{styles}").into());
+                }
+            }
+            // css({ color: "red" })
+            Expression::CallExpression(call) if self.is_css(&call.callee) => {
+                let label = self.label(call.span);
+                if let Some(label) = label {
+                    call.arguments.push(Argument::Expression(self.string(label)));
+                }
+                if let Some(source_map) = self.source_map_comment(call.span) {
+                    call.arguments.push(Argument::Expression(self.string(source_map)));
+                }
+            }
+            // styled.h1 -> styled("h1", {})
+            Expression::MemberExpression(member) => {
+                let MemberExpression::StaticMemberExpression(member) = &mut **member else {
+                    return;
+                };
+                if !self.is_styled(&member.object) {
+                    return;
+                }
+                let span = member.span;
+                let tag = StringLiteral::new(member.property.span, member.property.name.clone());
+                let callee = self.ast.move_expression(&mut member.object);
+                let mut arguments = self.ast.new_vec_with_capacity(2);
+                arguments.push(Argument::Expression(self.ast.literal_string_expression(tag)));
+                arguments.push(Argument::Expression(self.styled_options(span)));
+                *expr = self.ast.call_expression(span, callee, arguments, false, None);
+            }
+            // styled(Component) -> styled(Component, {})
+            Expression::CallExpression(call)
+                if self.is_styled(&call.callee) && call.arguments.len() == 1 =>
+            {
+                let options = self.styled_options(call.span);
+                call.arguments.push(Argument::Expression(options));
+            }
+            _ => {}
+        }
+    }
+
+    /// `import { css as _css } from "@emotion/react"`, when the `css` prop is optimized.
+    pub fn add_imports(&mut self, stmts: &mut Vec<'a, Statement<'a>>) {
+        let Some(local) = self.css_prop_import.take() else { return };
+        let span = Span::default();
+        let specifier = ImportSpecifier {
+            span,
+            imported: ModuleExportName::Identifier(IdentifierName::new(span, "css".into())),
+            local: BindingIdentifier::new(span, local),
+            import_kind: ImportOrExportKind::Value,
+        };
+        let decl = self.ast.import_declaration(
+            span,
+            self.ast.new_vec_single(ImportDeclarationSpecifier::ImportSpecifier(specifier)),
+            StringLiteral::new(span, "@emotion/react".into()),
+            None,
+            ImportOrExportKind::Value,
+        );
+        stmts.insert(0, self.ast.module_declaration(ModuleDeclaration::ImportDeclaration(decl)));
+    }
+
+    /// `<div css={{ color: "red" }} />` -> `<div css={_css({ color: "red" })} />`
+    fn transform_css_prop(&mut self, element: &mut JSXOpeningElement<'a>) {
+        for attribute in element.attributes.iter_mut() {
+            let JSXAttributeItem::Attribute(attribute) = attribute else { continue };
+            if !matches!(&attribute.name, JSXAttributeName::Identifier(name) if name.name == "css")
+            {
+                continue;
+            }
+            let Some(JSXAttributeValue::ExpressionContainer(container)) = &mut attribute.value
+            else {
+                continue;
+            };
+            let JSXExpression::Expression(value) = &mut container.expression else { continue };
+            if !matches!(
+                value,
+                Expression::ObjectExpression(_)
+                    | Expression::ArrayExpression(_)
+                    | Expression::TemplateLiteral(_)
+            ) {
+                continue;
+            }
+            let local = self.css_prop_import.clone().unwrap_or_else(|| {
+                let local = self.ctx.scopes().generate_uid("css");
+                self.ctx.add_binding(local.clone());
+                self.css.push(local.clone());
+                self.css_prop_import = Some(local.clone());
+                local
+            });
+            let span = value.span();
+            let callee = IdentifierReference::new(Span::default(), local);
+            let callee = self.ast.identifier_reference_expression(callee);
+            let argument = Argument::Expression(self.ast.move_expression(value));
+            let arguments = self.ast.new_vec_single(argument);
+            *value = self.ast.call_expression(span, callee, arguments, false, None);
+        }
+    }
+
+    /// `{ target: "e1x2y3z0", label: "Name" }`
+    fn styled_options(&mut self, span: Span) -> Expression<'a> {
+        let mut properties = self.ast.new_vec_with_capacity(2);
+        let target = format!("e{}{}", self.file_hash, self.count);
+        self.count += 1;
+        properties.push(self.string_property("target", target));
+        if let Some(label) = self.label_text(span) {
+            properties.push(self.string_property("label", label));
+        }
+        self.ast.object_expression(Span::default(), properties, None)
+    }
+
+    /// The label and the source map appended to a template.
+    fn styles_suffix(&mut self, span: Span) -> String {
+        let mut suffix = self.label(span).unwrap_or_default();
+        if let Some(source_map) = self.source_map_comment(span) {
+            suffix.push_str(&source_map);
+        }
+        suffix
+    }
+
+    /// `label:name;`
+    fn label(&mut self, span: Span) -> Option<String> {
+        self.label_text(span).map(|label| format!("label:{label};"))
+    }
+
+    fn label_text(&mut self, span: Span) -> Option<String> {
+        if !self.options.auto_label {
+            return None;
+        }
+        let (_, local) = self.variable.take().filter(|(start, _)| *start == span.start)?;
+        let path = self.source.as_ref().map(|(path, _)| Path::new(path));
+        let file_name = path.and_then(Path::file_stem).map(|s| s.to_string_lossy());
+        let dir_name =
+            path.and_then(Path::parent).and_then(Path::file_name).map(|s| s.to_string_lossy());
+        let label = self
+            .options
+            .label_format
+            .replace("[local]", local.as_str())
+            .replace("[filename]", file_name.as_deref().unwrap_or_default())
+            .replace("[dirname]", dir_name.as_deref().unwrap_or_default());
+        Some(label)
+    }
+
+    /// `/*# sourceMappingURL=data:application/json;base64,... */` of the styles at `span`.
+    fn source_map_comment(&self, span: Span) -> Option<String> {
+        if !self.options.source_map || span.start == span.end {
+            return None;
+        }
+        let (path, source_text) = self.source.as_ref()?;
+        let mut builder = SourceMapBuilder::new(path, source_text);
+        builder.add_mapping(b"", span.start);
+        Some(format!("/*# sourceMappingURL={} */", builder.into_source_map().to_data_url()))
+    }
+
+    fn string(&self, value: String) -> Expression<'a> {
+        self.ast.literal_string_expression(StringLiteral::new(Span::default(), value.into()))
+    }
+
+    fn string_property(&self, key: &str, value: String) -> ObjectPropertyKind<'a> {
+        let key = IdentifierName::new(Span::default(), key.into());
+        let key = PropertyKey::Identifier(self.ast.alloc(key));
+        ObjectPropertyKind::ObjectProperty(self.ast.object_property(
+            Span::default(),
+            PropertyKind::Init,
+            key,
+            self.string(value),
+            None,
+            false,
+            false,
+            false,
+        ))
+    }
+
+    fn is_css(&self, expr: &Expression<'a>) -> bool {
+        matches!(expr, Expression::Identifier(ident) if self.css.contains(&ident.name))
+    }
+
+    fn is_styled(&self, expr: &Expression<'a>) -> bool {
+        matches!((expr, &self.styled), (Expression::Identifier(ident), Some(styled)) if ident.name == *styled)
+    }
+}
+
+#[test]
+#[rustfmt::skip]
+fn test() {
+    use crate::{react_jsx::ReactJsxOptions, tester::Tester};
+
+    let options = TransformOptions {
+        emotion: Some(EmotionOptions {
+            label_format: "[filename]--[local]".into(),
+            source_map: false,
+            ..EmotionOptions::default()
+        }),
+        ..TransformOptions::default()
+    };
+    let tests = &[
+        (
+            "import { css } from '@emotion/react'; const title = css`color: red;`;",
+            "import { css } from '@emotion/react'; const title = css`color: red;label:test--title;`;",
+        ),
+        (
+            "import { css } from '@emotion/css'; const title = css({ color: 'red' }); f(css({}));",
+            "import { css } from '@emotion/css'; const title = css({ color: 'red' }, 'label:test--title;'); f(css({}));",
+        ),
+        (
+            "import styled from '@emotion/styled'; const Title = styled.h1({}); const Link = styled(A)``;",
+            "import styled from '@emotion/styled'; const Title = styled('h1', { target: 'e15mqx3q0', label: 'test--Title' })({}); const Link = styled(A, { target: 'e15mqx3q1', label: 'test--Link' })``;",
+        ),
+    ];
+    Tester::new("test.js", options).test(tests);
+
+    let options = TransformOptions {
+        jsx: JsxMode::React,
+        react_jsx: Some(ReactJsxOptions {
+            runtime: ReactJsxRuntime::Automatic,
+            import_source: Some("@emotion/react".into()),
+            ..ReactJsxOptions::default()
+        }),
+        emotion: Some(EmotionOptions::default()),
+        ..TransformOptions::default()
+    };
+    let tests = &[(
+        "<div css={{ color: 'red' }} className={c} />;",
+        "import { css as _css } from '@emotion/react'; import { jsx as _jsx } from '@emotion/react/jsx-runtime'; _jsx('div', { css: _css({ color: 'red' }, '/*# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJzb3VyY2VzIjpbInRlc3QuanN4Il0sInNvdXJjZXNDb250ZW50IjpbIjxkaXYgY3NzPXt7IGNvbG9yOiAncmVkJyB9fSBjbGFzc05hbWU9e2N9IC8+OyJdLCJuYW1lcyI6W10sIm1hcHBpbmdzIjoiQUFBVSJ9 */'), className: c });",
+    )];
+    Tester::new("test.jsx", options).test(tests);
+}
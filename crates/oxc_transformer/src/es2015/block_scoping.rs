@@ -0,0 +1,930 @@
+use std::{
+    collections::{HashMap, HashSet},
+    mem,
+    rc::Rc,
+};
+
+use oxc_allocator::Vec;
+use oxc_ast::{
+    ast::*, syntax_directed_operations::BoundNames, AstBuilder, AstKind, Visit, VisitMut,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_semantic::{ReferenceId, ScopeId, SymbolFlags, SymbolId, SymbolTable};
+use oxc_span::{Atom, Span};
+use oxc_syntax::operator::{AssignmentOperator, BinaryOperator, UnaryOperator};
+
+use crate::{
+    context::TransformerCtx,
+    options::{TransformOptions, TransformTarget},
+    targets::Feature,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("The body of this loop can't be moved into a function because of this expression")]
+#[diagnostic(help(
+    "Closures in the loop capture its block scoped bindings, so each iteration needs a function of its own; move the closures out of the loop"
+))]
+struct LoopBodyUnsupported(#[label] Span);
+
+/// ES2015: Block Scoping
+///
+/// `let` and `const` are turned into `var`. Bindings which would conflict with another binding
+/// once hoisted to the enclosing function are renamed, and loop bodies whose bindings are captured
+/// by closures are moved into a function so every iteration gets its own copy.
+///
+/// References:
+/// * <https://babel.dev/docs/babel-plugin-transform-block-scoping>
+/// * <https://github.com/babel/babel/blob/main/packages/babel-plugin-transform-block-scoping>
+pub struct BlockScoping<'a> {
+    ast: Rc<AstBuilder<'a>>,
+    ctx: TransformerCtx<'a>,
+    /// New names of block scoped bindings which conflict with another binding once hoisted.
+    renames: HashMap<SymbolId, Atom>,
+    /// New names of references in loop bodies moved into functions, to loop head bindings
+    /// written in the body and to `arguments`.
+    reference_renames: HashMap<ReferenceId, Atom>,
+    /// Expressions reported for preventing a loop body from being moved into a function, which
+    /// may be found again from the enclosing loops.
+    reported: HashSet<Span>,
+    /// Number of loops entered in the current function.
+    loop_depth: u32,
+}
+
+impl<'a> BlockScoping<'a> {
+    pub fn new(
+        ast: Rc<AstBuilder<'a>>,
+        ctx: TransformerCtx<'a>,
+        options: &TransformOptions,
+    ) -> Option<Self> {
+        (options.target < TransformTarget::ES2015
+            || options.block_scoping
+            || options.targets.needs_transform(Feature::BlockScoping))
+        .then(|| {
+            let renames = Self::collect_renames(&ctx);
+            let reference_renames = HashMap::new();
+            Self { ast, ctx, renames, reference_renames, reported: HashSet::new(), loop_depth: 0 }
+        })
+    }
+
+    /// Rename `let` and `const` bindings which would shadow or be shadowed by another binding
+    /// after being hoisted to the nearest function scope.
+    fn collect_renames(ctx: &TransformerCtx<'a>) -> HashMap<SymbolId, Atom> {
+        let symbols = ctx.symbols();
+        let mut renames = HashMap::new();
+        let mut hoisted_names: HashMap<ScopeId, HashSet<Atom>> = HashMap::new();
+        for symbol_id in symbols.iter() {
+            let flags = symbols.get_flag(symbol_id);
+            if !flags.contains(SymbolFlags::BlockScopedVariable)
+                || flags.contains(SymbolFlags::CatchVariable)
+            {
+                continue;
+            }
+            let scope_id = symbols.get_scope_id(symbol_id);
+            let name = symbols.get_name(symbol_id);
+
+            let scopes = ctx.scopes();
+            if scopes.get_flags(scope_id).is_var() {
+                continue;
+            }
+            let var_scope_id = scopes
+                .ancestors(scope_id)
+                .find(|scope_id| scopes.get_flags(*scope_id).is_var())
+                .unwrap_or_else(|| scopes.root_scope_id());
+            let hoisted = hoisted_names.entry(var_scope_id).or_default();
+            let conflicts = hoisted.contains(name)
+                || scopes
+                    .ancestors(scope_id)
+                    .skip(1)
+                    .any(|scope_id| scopes.has_binding(scope_id, name))
+                || scopes.root_unresolved_references().contains_key(name);
+            if !conflicts {
+                hoisted.insert(name.clone());
+                continue;
+            }
+            let new_name = scopes.generate_uid(name);
+            drop(scopes);
+            ctx.add_binding(new_name.clone());
+            hoisted.insert(new_name.clone());
+            renames.insert(symbol_id, new_name);
+        }
+        renames
+    }
+
+    pub fn enter_function(&mut self) -> u32 {
+        mem::take(&mut self.loop_depth)
+    }
+
+    pub fn leave_function(&mut self, prev_loop_depth: u32) {
+        self.loop_depth = prev_loop_depth;
+    }
+
+    pub fn enter_statement(&mut self, stmt: &mut Statement<'a>) {
+        match stmt {
+            Statement::ForInStatement(stmt) => {
+                self.loop_depth += 1;
+                Self::hoist_for_statement_left(&mut stmt.left);
+            }
+            Statement::ForOfStatement(stmt) => {
+                self.loop_depth += 1;
+                Self::hoist_for_statement_left(&mut stmt.left);
+            }
+            Statement::ForStatement(_)
+            | Statement::WhileStatement(_)
+            | Statement::DoWhileStatement(_) => self.loop_depth += 1,
+            _ => {}
+        }
+    }
+
+    pub fn leave_statement(&mut self, stmt: &Statement<'a>) {
+        if matches!(
+            stmt,
+            Statement::ForInStatement(_)
+                | Statement::ForOfStatement(_)
+                | Statement::ForStatement(_)
+                | Statement::WhileStatement(_)
+                | Statement::DoWhileStatement(_)
+        ) {
+            self.loop_depth -= 1;
+        }
+    }
+
+    /// `for (let x of y)` -> `for (var x of y)`
+    ///
+    /// Handled before the declaration is visited because the head must not get an initializer.
+    fn hoist_for_statement_left(left: &mut ForStatementLeft<'a>) {
+        if let ForStatementLeft::VariableDeclaration(decl) = left {
+            Self::hoist_variable_declaration(decl);
+        }
+    }
+
+    fn hoist_variable_declaration(decl: &mut VariableDeclaration<'a>) {
+        decl.kind = VariableDeclarationKind::Var;
+        for declarator in decl.declarations.iter_mut() {
+            declarator.kind = VariableDeclarationKind::Var;
+        }
+    }
+
+    /// * `let x = 1` -> `var x = 1`
+    /// * `let x` -> `var x = void 0` inside of loops, so the value does not leak into the next iteration
+    pub fn transform_variable_declaration(&mut self, decl: &mut VariableDeclaration<'a>) {
+        if !decl.kind.is_lexical() {
+            return;
+        }
+        Self::hoist_variable_declaration(decl);
+        if self.loop_depth == 0 {
+            return;
+        }
+        for declarator in decl.declarations.iter_mut() {
+            if declarator.init.is_none() && declarator.id.kind.is_binding_identifier() {
+                declarator.init = Some(self.ast.void_0());
+            }
+        }
+    }
+
+    pub fn transform_binding_identifier(&self, ident: &mut BindingIdentifier) {
+        if let Some(name) = ident.symbol_id.get().and_then(|symbol_id| self.renames.get(&symbol_id))
+        {
+            ident.name = name.clone();
+        }
+    }
+
+    pub fn transform_identifier_reference(&self, ident: &mut IdentifierReference) {
+        if let Some(name) = self.renamed_reference(ident) {
+            ident.name = name;
+        }
+    }
+
+    /// `({ x } = y)` -> `({ x: _x } = y)`
+    pub fn transform_assignment_target_property(
+        &self,
+        property: &mut AssignmentTargetProperty<'a>,
+    ) {
+        let AssignmentTargetProperty::AssignmentTargetPropertyIdentifier(ident) = property else {
+            return;
+        };
+        if self.renamed_reference(&ident.binding).is_none() {
+            return;
+        }
+        let span = ident.span;
+        let binding = self.ast.copy(&ident.binding);
+        let name = PropertyKey::Identifier(
+            self.ast.alloc(IdentifierName::new(span, binding.name.clone())),
+        );
+        let target = AssignmentTarget::SimpleAssignmentTarget(
+            self.ast.simple_assignment_target_identifier(binding),
+        );
+        let binding = match ident.init.take() {
+            Some(init) => AssignmentTargetMaybeDefault::AssignmentTargetWithDefault(
+                self.ast.alloc(AssignmentTargetWithDefault { span, binding: target, init }),
+            ),
+            None => AssignmentTargetMaybeDefault::AssignmentTarget(target),
+        };
+        *property = AssignmentTargetProperty::AssignmentTargetPropertyProperty(
+            self.ast.alloc(AssignmentTargetPropertyProperty { span, name, binding }),
+        );
+    }
+
+    fn renamed_reference(&self, ident: &IdentifierReference) -> Option<Atom> {
+        let reference_id = ident.reference_id.get()?;
+        if let Some(name) = self.reference_renames.get(&reference_id) {
+            return Some(name.clone());
+        }
+        let symbol_id = self.ctx.symbols().get_reference(reference_id).symbol_id()?;
+        self.renames.get(&symbol_id).cloned()
+    }
+
+    /// Move the bodies of loops which are direct children of a statement list into functions.
+    pub fn transform_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>) {
+        let old_stmts = mem::replace(stmts, self.ast.new_vec());
+        for mut stmt in old_stmts {
+            if let Some(declarations) = self.wrap_loop_body(&mut stmt) {
+                stmts.extend(declarations);
+            }
+            stmts.push(stmt);
+        }
+    }
+
+    /// Move the bodies of loops in single statement positions, e.g. `if (x) for (let y of z);`.
+    pub fn transform_statement(&mut self, stmt: &mut Statement<'a>) {
+        let Some(mut body) = self.wrap_loop_body(stmt) else { return };
+        body.push(self.ast.move_statement(stmt));
+        *stmt = self.ast.block_statement(self.ast.block(Span::default(), body));
+    }
+
+    /// ```JavaScript
+    /// for (let i = 0; i < 3; i++) {
+    ///   fns.push(() => i);
+    /// }
+    /// ```
+    /// ->
+    /// ```JavaScript
+    /// var _loop = function (i) {
+    ///   fns.push(() => i);
+    /// };
+    /// for (let i = 0; i < 3; i++) {
+    ///   _loop(i);
+    /// }
+    /// ```
+    ///
+    /// * `continue` -> `return;`
+    /// * `break` -> `return "break";` with `if (_ret === "break") break;`
+    /// * `continue outer` -> `return "continue|outer";` with
+    ///   `if (_ret === "continue|outer") continue outer;`
+    /// * `return x` -> `return { v: x };` with `if (typeof _ret === "object") return _ret.v;`
+    /// * `this` and `arguments` -> `_this` and `_arguments`, declared before the loop.
+    /// * The head bindings of a `for` statement written in the body are renamed in the function,
+    ///   and copied back at the end of each iteration: `i++` -> `_i++; i = _i;`
+    ///
+    /// The loops with `super`, `yield` or `await` in their body are reported as errors.
+    ///
+    /// Returns the declarations to be inserted before the loop.
+    fn wrap_loop_body(&mut self, stmt: &mut Statement<'a>) -> Option<Vec<'a, Statement<'a>>> {
+        let mut labels = vec![];
+        let mut stmt = stmt;
+        while let Statement::LabeledStatement(labeled) = stmt {
+            labels.push(labeled.label.name.clone());
+            stmt = &mut labeled.body;
+        }
+        let is_for_statement = matches!(stmt, Statement::ForStatement(_));
+        let (head, body) = match stmt {
+            Statement::ForStatement(stmt) => {
+                let stmt = &mut **stmt;
+                let head = match &stmt.init {
+                    Some(ForStatementInit::VariableDeclaration(decl)) => Some(&**decl),
+                    _ => None,
+                };
+                (head, &mut stmt.body)
+            }
+            Statement::ForInStatement(stmt) => {
+                let stmt = &mut **stmt;
+                let head = match &stmt.left {
+                    ForStatementLeft::VariableDeclaration(decl) => Some(&**decl),
+                    _ => None,
+                };
+                (head, &mut stmt.body)
+            }
+            Statement::ForOfStatement(stmt) => {
+                let stmt = &mut **stmt;
+                let head = match &stmt.left {
+                    ForStatementLeft::VariableDeclaration(decl) => Some(&**decl),
+                    _ => None,
+                };
+                (head, &mut stmt.body)
+            }
+            Statement::WhileStatement(stmt) => (None, &mut stmt.body),
+            Statement::DoWhileStatement(stmt) => (None, &mut stmt.body),
+            _ => return None,
+        };
+
+        let mut head_bindings = vec![];
+        if let Some(decl) = head.filter(|decl| decl.kind.is_lexical()) {
+            decl.bound_names(&mut |ident| {
+                let name = ident
+                    .symbol_id
+                    .get()
+                    .and_then(|symbol_id| self.renames.get(&symbol_id))
+                    .unwrap_or(&ident.name);
+                head_bindings.push((ident.symbol_id.get(), name.clone()));
+            });
+        }
+
+        let loop_body = {
+            let symbols = self.ctx.symbols();
+            let mut loop_body = LoopBody::new(&symbols, &labels);
+            loop_body.visit_statement(body);
+            loop_body.info
+        };
+        let is_captured = loop_body.captured.iter().any(|symbol_id| {
+            loop_body.declared.contains(symbol_id)
+                || head_bindings.iter().any(|(id, _)| *id == Some(*symbol_id))
+        });
+        if !is_captured {
+            return None;
+        }
+        if let Some(span) = loop_body.unsupported {
+            if self.reported.insert(span) {
+                self.ctx.error(LoopBodyUnsupported(span));
+            }
+            return None;
+        }
+
+        let span = Span::default();
+        let mut declarations = self.ast.new_vec_with_capacity(3);
+        if loop_body.uses_this {
+            // `var _this = this;`
+            let name = self.generate_uid("this");
+            declarations.push(self.var_declaration(name.clone(), self.ast.this_expression(span)));
+            LoopThis { ast: &self.ast, name: &name }.visit_statement(body);
+        }
+        if !loop_body.arguments.is_empty() {
+            // `var _arguments = arguments;`
+            let name = self.generate_uid("arguments");
+            let arguments = self.identifier(&"arguments".into());
+            declarations.push(self.var_declaration(name.clone(), arguments));
+            for reference_id in &loop_body.arguments {
+                self.reference_renames.insert(*reference_id, name.clone());
+            }
+        }
+
+        // `i = _i;` for the head bindings written in the body
+        let mut params = vec![];
+        let mut updates = vec![];
+        for (symbol_id, name) in &head_bindings {
+            let is_written = symbol_id.is_some_and(|id| loop_body.written.contains(&id));
+            if !(is_for_statement && is_written) {
+                params.push(name.clone());
+                continue;
+            }
+            let new_name = self.generate_uid(name);
+            for (id, reference_id) in &loop_body.references {
+                if Some(*id) == *symbol_id {
+                    self.reference_renames.insert(*reference_id, new_name.clone());
+                }
+            }
+            params.push(new_name.clone());
+            updates.push((name.clone(), new_name));
+        }
+
+        LoopJumps::new(&self.ast, &labels, &updates).visit_statement(body);
+        let mut statements = match self.ast.move_statement(body) {
+            Statement::BlockStatement(block) => block.unbox().body,
+            stmt => self.ast.new_vec_single(stmt),
+        };
+        statements.extend(updates.iter().map(|(name, new_name)| update(&self.ast, name, new_name)));
+
+        // `var _loop = function (i) { body };`
+        let loop_name = self.generate_uid("loop");
+        let mut items = self.ast.new_vec_with_capacity(params.len());
+        for name in params {
+            let pattern = self.binding_pattern(name);
+            items.push(self.ast.formal_parameter(span, pattern, None, false, self.ast.new_vec()));
+        }
+        let params =
+            self.ast.formal_parameters(span, FormalParameterKind::FormalParameter, items, None);
+        let function = self.ast.function(
+            FunctionType::FunctionExpression,
+            span,
+            None,
+            false,
+            false,
+            false,
+            params,
+            Some(self.ast.function_body(span, self.ast.new_vec(), statements)),
+            None,
+            None,
+            Modifiers::empty(),
+        );
+        declarations
+            .push(self.var_declaration(loop_name.clone(), self.ast.function_expression(function)));
+
+        // `_loop(i);`
+        let callee = self.identifier(&loop_name);
+        let mut arguments = self.ast.new_vec_with_capacity(head_bindings.len());
+        arguments.extend(
+            head_bindings.iter().map(|(_, name)| Argument::Expression(self.identifier(name))),
+        );
+        let call = self.ast.call_expression(span, callee, arguments, false, None);
+
+        let mut new_body = self.ast.new_vec_with_capacity(3);
+        if loop_body.has_break || loop_body.has_return || !loop_body.outer_jumps.is_empty() {
+            let ret_name = self.generate_uid("ret");
+            new_body.push(self.var_declaration(ret_name.clone(), call));
+            let jumps = loop_body
+                .has_break
+                .then_some(JumpKind::Break)
+                .map(|kind| (kind, None))
+                .into_iter()
+                .chain(loop_body.outer_jumps.iter().map(|(kind, label)| (*kind, Some(label))));
+            for (kind, label) in jumps {
+                // `if (_ret === "break") break;`, `if (_ret === "continue|outer") continue outer;`
+                let value = label.map_or_else(
+                    || Atom::from(kind.as_str()),
+                    |label| format!("{}|{label}", kind.as_str()).into(),
+                );
+                let test = self.ast.binary_expression(
+                    span,
+                    self.identifier(&ret_name),
+                    BinaryOperator::StrictEquality,
+                    self.ast.literal_string_expression(StringLiteral::new(span, value)),
+                );
+                let label = label.map(|label| LabelIdentifier { span, name: label.clone() });
+                let consequent = match kind {
+                    JumpKind::Break => self.ast.break_statement(span, label),
+                    JumpKind::Continue => self.ast.continue_statement(span, label),
+                };
+                new_body.push(self.ast.if_statement(span, test, consequent, None));
+            }
+            if loop_body.has_return {
+                // `if (typeof _ret === "object") return _ret.v;`
+                let test = self.ast.binary_expression(
+                    span,
+                    self.ast.unary_expression(
+                        span,
+                        UnaryOperator::Typeof,
+                        self.identifier(&ret_name),
+                    ),
+                    BinaryOperator::StrictEquality,
+                    self.ast.literal_string_expression(StringLiteral::new(span, "object".into())),
+                );
+                let argument = self.ast.static_member_expression(
+                    span,
+                    self.identifier(&ret_name),
+                    IdentifierName::new(span, "v".into()),
+                    false,
+                );
+                let consequent = self.ast.return_statement(span, Some(argument));
+                new_body.push(self.ast.if_statement(span, test, consequent, None));
+            }
+        } else {
+            new_body.push(self.ast.expression_statement(span, call));
+        }
+        *body = self.ast.block_statement(self.ast.block(span, new_body));
+
+        Some(declarations)
+    }
+
+    fn generate_uid(&self, name: &str) -> Atom {
+        let name = self.ctx.scopes().generate_uid(name);
+        self.ctx.add_binding(name.clone());
+        name
+    }
+
+    fn identifier(&self, name: &Atom) -> Expression<'a> {
+        self.ast.identifier_reference_expression(IdentifierReference::new(
+            Span::default(),
+            name.clone(),
+        ))
+    }
+
+    fn binding_pattern(&self, name: Atom) -> BindingPattern<'a> {
+        let ident = BindingIdentifier::new(Span::default(), name);
+        self.ast.binding_pattern(self.ast.binding_pattern_identifier(ident), None, false)
+    }
+
+    /// `var name = init;`
+    fn var_declaration(&self, name: Atom, init: Expression<'a>) -> Statement<'a> {
+        let span = Span::default();
+        let kind = VariableDeclarationKind::Var;
+        let declarator =
+            self.ast.variable_declarator(span, kind, self.binding_pattern(name), Some(init), false);
+        let decl = self.ast.variable_declaration(
+            span,
+            kind,
+            self.ast.new_vec_single(declarator),
+            Modifiers::empty(),
+        );
+        Statement::Declaration(Declaration::VariableDeclaration(decl))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum JumpKind {
+    Break,
+    Continue,
+}
+
+impl JumpKind {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Break => "break",
+            Self::Continue => "continue",
+        }
+    }
+}
+
+/// `name = new_name;`
+fn update<'a>(ast: &AstBuilder<'a>, name: &Atom, new_name: &Atom) -> Statement<'a> {
+    let span = Span::default();
+    let target = AssignmentTarget::SimpleAssignmentTarget(
+        ast.simple_assignment_target_identifier(IdentifierReference::new(span, name.clone())),
+    );
+    let value =
+        ast.identifier_reference_expression(IdentifierReference::new(span, new_name.clone()));
+    let assignment = ast.assignment_expression(span, AssignmentOperator::Assign, target, value);
+    ast.expression_statement(span, assignment)
+}
+
+/// Collects what a loop body declares and captures, and whether it can be moved into a function.
+struct LoopBody<'b> {
+    symbols: &'b SymbolTable,
+    /// Labels of the loop itself.
+    labels: &'b [Atom],
+    /// Labels declared inside of the loop body.
+    inner_labels: std::vec::Vec<Atom>,
+    /// Depth of functions and arrow functions.
+    function_depth: u32,
+    /// Depth of functions and classes which have their own `this`.
+    this_depth: u32,
+    /// Depth of loops, which `continue` targets.
+    loop_depth: u32,
+    /// Depth of loops and switches, which `break` targets.
+    breakable_depth: u32,
+    info: LoopInfo,
+}
+
+/// What a loop body declares, captures and uses.
+#[derive(Default)]
+struct LoopInfo {
+    /// Block scoped bindings declared outside of closures.
+    declared: HashSet<SymbolId>,
+    /// Bindings referenced from inside of closures.
+    captured: HashSet<SymbolId>,
+    /// Bindings which are assigned to.
+    written: HashSet<SymbolId>,
+    /// References to bindings, outside of closures or not.
+    references: std::vec::Vec<(SymbolId, ReferenceId)>,
+    /// References to the `arguments` of the enclosing function.
+    arguments: std::vec::Vec<ReferenceId>,
+    /// Jumps to labels outside of the loop.
+    outer_jumps: std::vec::Vec<(JumpKind, Atom)>,
+    has_break: bool,
+    has_return: bool,
+    /// The body uses the `this` of the enclosing function.
+    uses_this: bool,
+    /// `super`, `yield` or `await`, which can't be moved into a function.
+    unsupported: Option<Span>,
+}
+
+impl<'b> LoopBody<'b> {
+    fn new(symbols: &'b SymbolTable, labels: &'b [Atom]) -> Self {
+        Self {
+            symbols,
+            labels,
+            inner_labels: vec![],
+            function_depth: 0,
+            this_depth: 0,
+            loop_depth: 0,
+            breakable_depth: 0,
+            info: LoopInfo::default(),
+        }
+    }
+
+    /// Returns whether a jump with `label` targets the loop itself.
+    fn targets_loop(
+        &mut self,
+        kind: JumpKind,
+        label: Option<&LabelIdentifier>,
+        depth: u32,
+    ) -> bool {
+        match label {
+            Some(label) if self.labels.contains(&label.name) => true,
+            Some(label) => {
+                let jump = (kind, label.name.clone());
+                if !self.inner_labels.contains(&label.name)
+                    && !self.info.outer_jumps.contains(&jump)
+                {
+                    self.info.outer_jumps.push(jump);
+                }
+                false
+            }
+            None => depth == 0,
+        }
+    }
+}
+
+impl<'a, 'b> Visit<'a> for LoopBody<'b> {
+    fn enter_node(&mut self, kind: AstKind<'a>) {
+        if kind.is_iteration_statement() {
+            self.loop_depth += 1;
+            self.breakable_depth += 1;
+        }
+        match kind {
+            AstKind::Function(_) => {
+                self.function_depth += 1;
+                self.this_depth += 1;
+            }
+            AstKind::Class(_) => self.this_depth += 1,
+            AstKind::ArrowExpression(_) => self.function_depth += 1,
+            AstKind::ThisExpression(_) if self.this_depth == 0 => self.info.uses_this = true,
+            AstKind::Super(expr) if self.this_depth == 0 => {
+                self.info.unsupported.get_or_insert(expr.span);
+            }
+            _ if self.function_depth > 0 => {
+                if let AstKind::IdentifierReference(ident) = kind {
+                    self.reference(ident);
+                }
+            }
+            AstKind::SwitchStatement(_) => self.breakable_depth += 1,
+            AstKind::LabeledStatement(stmt) => self.inner_labels.push(stmt.label.name.clone()),
+            AstKind::ForOfStatement(stmt) if stmt.r#await => {
+                self.info.unsupported.get_or_insert(stmt.span);
+            }
+            AstKind::YieldExpression(expr) => {
+                self.info.unsupported.get_or_insert(expr.span);
+            }
+            AstKind::AwaitExpression(expr) => {
+                self.info.unsupported.get_or_insert(expr.span);
+            }
+            AstKind::BreakStatement(stmt) => {
+                // `break` targets the enclosing loop or switch, which has already been entered.
+                let depth = self.breakable_depth;
+                self.info.has_break |=
+                    self.targets_loop(JumpKind::Break, stmt.label.as_ref(), depth);
+            }
+            AstKind::ContinueStatement(stmt) => {
+                let depth = self.loop_depth;
+                self.targets_loop(JumpKind::Continue, stmt.label.as_ref(), depth);
+            }
+            AstKind::ReturnStatement(_) => self.info.has_return = true,
+            AstKind::BindingIdentifier(ident) => {
+                let Some(symbol_id) = ident.symbol_id.get() else { return };
+                let flags = self.symbols.get_flag(symbol_id);
+                if flags.contains(SymbolFlags::BlockScopedVariable)
+                    && !flags.contains(SymbolFlags::CatchVariable)
+                {
+                    self.info.declared.insert(symbol_id);
+                }
+            }
+            AstKind::IdentifierReference(ident) => self.reference(ident),
+            _ => {}
+        }
+    }
+
+    fn leave_node(&mut self, kind: AstKind<'a>) {
+        if kind.is_iteration_statement() {
+            self.loop_depth -= 1;
+            self.breakable_depth -= 1;
+        }
+        match kind {
+            AstKind::Function(_) => {
+                self.function_depth -= 1;
+                self.this_depth -= 1;
+            }
+            AstKind::Class(_) => self.this_depth -= 1,
+            AstKind::ArrowExpression(_) => self.function_depth -= 1,
+            AstKind::SwitchStatement(_) if self.function_depth == 0 => self.breakable_depth -= 1,
+            AstKind::LabeledStatement(_) if self.function_depth == 0 => {
+                self.inner_labels.pop();
+            }
+            _ => {}
+        }
+    }
+}
+
+impl<'b> LoopBody<'b> {
+    fn reference(&mut self, ident: &IdentifierReference) {
+        let Some(reference_id) = ident.reference_id.get() else { return };
+        let reference = self.symbols.get_reference(reference_id);
+        let Some(symbol_id) = reference.symbol_id() else {
+            if ident.name == "arguments" && self.this_depth == 0 {
+                self.info.arguments.push(reference_id);
+            }
+            return;
+        };
+        self.info.references.push((symbol_id, reference_id));
+        if self.function_depth > 0 {
+            self.info.captured.insert(symbol_id);
+        }
+        if reference.is_write() {
+            self.info.written.insert(symbol_id);
+        }
+    }
+}
+
+/// Replaces `break`, `continue` and `return` of a loop body moved into a function.
+struct LoopJumps<'a, 'b> {
+    ast: &'b AstBuilder<'a>,
+    labels: &'b [Atom],
+    /// Head bindings copied back before the next iteration.
+    updates: &'b [(Atom, Atom)],
+    /// Labels declared inside of the loop body.
+    inner_labels: std::vec::Vec<Atom>,
+    loop_depth: u32,
+    breakable_depth: u32,
+}
+
+impl<'a, 'b> LoopJumps<'a, 'b> {
+    fn new(ast: &'b AstBuilder<'a>, labels: &'b [Atom], updates: &'b [(Atom, Atom)]) -> Self {
+        Self { ast, labels, updates, inner_labels: vec![], loop_depth: 0, breakable_depth: 0 }
+    }
+
+    fn targets_loop(&self, label: Option<&LabelIdentifier>, depth: u32) -> bool {
+        label.map_or(depth == 0, |label| self.labels.contains(&label.name))
+    }
+
+    fn is_outer(&self, label: Option<&LabelIdentifier>) -> bool {
+        label.is_some_and(|label| !self.inner_labels.contains(&label.name))
+    }
+
+    /// `return "continue|outer";`
+    fn return_jump(&self, kind: JumpKind, label: &LabelIdentifier) -> Statement<'a> {
+        let span = Span::default();
+        let value = format!("{}|{}", kind.as_str(), label.name);
+        let argument = self.ast.literal_string_expression(StringLiteral::new(span, value.into()));
+        self.ast.return_statement(span, Some(argument))
+    }
+}
+
+impl<'a, 'b> VisitMut<'a> for LoopJumps<'a, 'b> {
+    fn visit_statement(&mut self, stmt: &mut Statement<'a>) {
+        let span = Span::default();
+        match stmt {
+            Statement::BreakStatement(break_stmt)
+                if self.targets_loop(break_stmt.label.as_ref(), self.breakable_depth) =>
+            {
+                let argument =
+                    self.ast.literal_string_expression(StringLiteral::new(span, "break".into()));
+                *stmt = self.ast.return_statement(break_stmt.span, Some(argument));
+            }
+            Statement::BreakStatement(break_stmt) if self.is_outer(break_stmt.label.as_ref()) => {
+                let label = break_stmt.label.as_ref().unwrap();
+                *stmt = self.return_jump(JumpKind::Break, label);
+            }
+            Statement::ContinueStatement(continue_stmt)
+                if self.targets_loop(continue_stmt.label.as_ref(), self.loop_depth) =>
+            {
+                let return_stmt = self.ast.return_statement(continue_stmt.span, None);
+                if self.updates.is_empty() {
+                    *stmt = return_stmt;
+                } else {
+                    // `{ i = _i; return; }`
+                    let mut body = self.ast.new_vec_with_capacity(self.updates.len() + 1);
+                    body.extend(
+                        self.updates
+                            .iter()
+                            .map(|(name, new_name)| update(self.ast, name, new_name)),
+                    );
+                    body.push(return_stmt);
+                    *stmt = self.ast.block_statement(self.ast.block(span, body));
+                }
+            }
+            Statement::ContinueStatement(continue_stmt)
+                if self.is_outer(continue_stmt.label.as_ref()) =>
+            {
+                let label = continue_stmt.label.as_ref().unwrap();
+                *stmt = self.return_jump(JumpKind::Continue, label);
+            }
+            Statement::ReturnStatement(return_stmt) => {
+                let value = return_stmt.argument.take().unwrap_or_else(|| self.ast.void_0());
+                let key =
+                    PropertyKey::Identifier(self.ast.alloc(IdentifierName::new(span, "v".into())));
+                let property = self.ast.object_property(
+                    span,
+                    PropertyKind::Init,
+                    key,
+                    value,
+                    None,
+                    false,
+                    false,
+                    false,
+                );
+                let properties =
+                    self.ast.new_vec_single(ObjectPropertyKind::ObjectProperty(property));
+                return_stmt.argument = Some(self.ast.object_expression(span, properties, None));
+            }
+            Statement::ForStatement(_)
+            | Statement::ForInStatement(_)
+            | Statement::ForOfStatement(_)
+            | Statement::WhileStatement(_)
+            | Statement::DoWhileStatement(_) => {
+                self.loop_depth += 1;
+                self.breakable_depth += 1;
+                self.visit_statement_match(stmt);
+                self.loop_depth -= 1;
+                self.breakable_depth -= 1;
+            }
+            Statement::SwitchStatement(_) => {
+                self.breakable_depth += 1;
+                self.visit_statement_match(stmt);
+                self.breakable_depth -= 1;
+            }
+            Statement::LabeledStatement(labeled) => {
+                self.inner_labels.push(labeled.label.name.clone());
+                self.visit_statement_match(stmt);
+                self.inner_labels.pop();
+            }
+            _ => self.visit_statement_match(stmt),
+        }
+    }
+
+    // Jumps do not cross function boundaries.
+    fn visit_function(&mut self, _func: &mut Function<'a>) {}
+
+    fn visit_arrow_expression(&mut self, _expr: &mut ArrowExpression<'a>) {}
+
+    fn visit_class(&mut self, _class: &mut Class<'a>) {}
+}
+
+/// Replaces the `this` of a loop body moved into a function.
+struct LoopThis<'a, 'b> {
+    ast: &'b AstBuilder<'a>,
+    name: &'b Atom,
+}
+
+impl<'a, 'b> VisitMut<'a> for LoopThis<'a, 'b> {
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        if let Expression::ThisExpression(this) = expr {
+            let ident = IdentifierReference::new(this.span, self.name.clone());
+            *expr = self.ast.identifier_reference_expression(ident);
+        } else {
+            self.visit_expression_match(expr);
+        }
+    }
+
+    // Functions and classes have their own `this`.
+    fn visit_function(&mut self, _func: &mut Function<'a>) {}
+
+    fn visit_class(&mut self, _class: &mut Class<'a>) {}
+}
+
+#[test]
+fn test() {
+    use crate::{options::TransformOptions, tester::Tester};
+
+    let options = TransformOptions { block_scoping: true, ..TransformOptions::default() };
+    Tester::new("test.js", options).test(&[
+        ("let a = 1; const b = 2;", "var a = 1; var b = 2;"),
+        ("let a = 1; { let a = 2; a; } a;", "var a = 1; { var _a = 2; _a; } a;"),
+        ("{ let a = 1; } { let a = 2; a; }", "{ var a = 1; } { var _a = 2; _a; }"),
+        ("{ let a; } a;", "{ var _a; } a;"),
+        ("function f() { let a; { let a; ({ a } = b); } }", "function f() { var a; { var _a; ({a: _a} = b); } }"),
+        ("while (x) { let a; }", "while (x) { var a = void 0; }"),
+        ("for (let a of b) { let c = a; }", "for (var a of b) { var c = a; }"),
+        (
+            "for (let i = 0; i < 3; i++) { fns.push(() => i); }",
+            "var _loop = function(i) { fns.push(() => i); }; for (var i = 0; i < 3; i++) { _loop(i); }",
+        ),
+        (
+            "for (const x of y) { if (x) continue; if (x > 1) break; fns.push(() => x); }",
+            "var _loop = function(x) { if (x) return; if (x > 1) return 'break'; fns.push(() => x); }; for (var x of y) { var _ret = _loop(x); if (_ret === 'break') break; }",
+        ),
+        (
+            "function f() { while (x) { let y = x; if (y) return y; fns.push(() => y); } }",
+            "function f() { var _loop = function() { var y = x; if (y) return {v: y}; fns.push(() => y); }; while (x) { var _ret = _loop(); if (typeof _ret === 'object') return _ret.v; } }",
+        ),
+        (
+            "outer: for (let i of a) { switch (i) { case 1: break; default: continue outer; } fns.push(() => i); }",
+            "var _loop = function(i) { switch (i) { case 1: break; default: return; } fns.push(() => i); }; outer: for (var i of a) { _loop(i); }",
+        ),
+        (
+            "for (let i = 0; i < 3; i++) { if (i) { i++; continue; } fns.push(() => i); }",
+            "var _loop = function(_i) { if (_i) { _i++; { i = _i; return; } } fns.push(() => _i); i = _i; }; for (var i = 0; i < 3; i++) { _loop(i); }",
+        ),
+        (
+            "for (let x of y) { x++; fns.push(() => x); }",
+            "var _loop = function(x) { x++; fns.push(() => x); }; for (var x of y) { _loop(x); }",
+        ),
+        (
+            "function f() { for (let i of a) { fns.push(() => this[i] + arguments[i]); } }",
+            "function f() { var _this = this; var _arguments = arguments; var _loop = function(i) { fns.push(() => _this[i] + _arguments[i]); }; for (var i of a) { _loop(i); } }",
+        ),
+        (
+            "outer: { for (let i of a) { if (i) break outer; fns.push(() => i); } }",
+            "outer: { var _loop = function(i) { if (i) return 'break|outer'; fns.push(() => i); }; for (var i of a) { var _ret = _loop(i); if (_ret === 'break|outer') break outer; } }",
+        ),
+    ]);
+
+    // `super`, `yield` and `await` can't be moved into a function
+    let options = TransformOptions { block_scoping: true, ..TransformOptions::default() };
+    for source in [
+        "class A extends B { m() { for (let i of a) { super.m(); fns.push(() => i); } } }",
+        "function* f() { for (let i of a) { yield; fns.push(() => i); } }",
+        "async function f() { for (let i of a) { await i; fns.push(() => i); } }",
+    ] {
+        let ret = crate::transform(source, oxc_span::SourceType::default(), options.clone());
+        assert_eq!(ret.errors.len(), 1, "{source}");
+    }
+}
@@ -0,0 +1,218 @@
+use std::rc::Rc;
+
+use oxc_allocator::Vec;
+use oxc_ast::{ast::*, AstBuilder};
+use oxc_span::Span;
+use oxc_syntax::operator::AssignmentOperator;
+use serde::Deserialize;
+
+use crate::{
+    context::TransformerCtx,
+    options::{TransformOptions, TransformTarget},
+    targets::Feature,
+    utils::CreateVars,
+};
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+pub struct ComputedPropertiesOptions {
+    /// When true, properties are assigned instead of defined with `Object.defineProperty`.
+    #[serde(default)]
+    loose: bool,
+}
+
+/// ES2015: Computed Properties
+///
+/// Runs after the properties of an object are visited, so `__proto__` shorthands made computed by
+/// the shorthand properties transform are lowered as well.
+///
+/// References:
+/// * <https://babel.dev/docs/babel-plugin-transform-computed-properties>
+/// * <https://github.com/babel/babel/blob/main/packages/babel-plugin-transform-computed-properties>
+pub struct ComputedProperties<'a> {
+    ast: Rc<AstBuilder<'a>>,
+    ctx: TransformerCtx<'a>,
+    vars: Vec<'a, VariableDeclarator<'a>>,
+    set_computed_properties: bool,
+}
+
+impl<'a> CreateVars<'a> for ComputedProperties<'a> {
+    fn ctx(&self) -> &TransformerCtx<'a> {
+        &self.ctx
+    }
+
+    fn vars_mut(&mut self) -> &mut Vec<'a, VariableDeclarator<'a>> {
+        &mut self.vars
+    }
+}
+
+impl<'a> ComputedProperties<'a> {
+    pub fn new(
+        ast: Rc<AstBuilder<'a>>,
+        ctx: TransformerCtx<'a>,
+        options: &TransformOptions,
+    ) -> Option<Self> {
+        (options.target < TransformTarget::ES2015
+            || options.computed_properties.is_some()
+            || options.targets.needs_transform(Feature::ComputedProperties))
+        .then(|| {
+            let set_computed_properties = options.assumptions.set_computed_properties
+                || options.computed_properties.is_some_and(|o| o.loose);
+            let vars = ast.new_vec();
+            Self { ast, ctx, vars, set_computed_properties }
+        })
+    }
+
+    /// * `{ a: 1, [b]: 2 }` -> `babelHelpers.defineProperty({ a: 1 }, b, 2)`
+    /// * `{ [a]: 1, b: 2 }` -> `(_obj = {}, babelHelpers.defineProperty(_obj, a, 1), babelHelpers.defineProperty(_obj, "b", 2), _obj)`
+    pub fn transform_expression(&mut self, expr: &mut Expression<'a>) {
+        let Expression::ObjectExpression(obj_expr) = expr else { return };
+        let Some(first_computed) = obj_expr.properties.iter().position(
+            |prop| matches!(prop, ObjectPropertyKind::ObjectProperty(prop) if prop.computed),
+        ) else {
+            return;
+        };
+        let span = obj_expr.span;
+
+        let mut initial = self.ast.new_vec_with_capacity(first_computed);
+        let mut rest = self.ast.new_vec();
+        let properties = std::mem::replace(&mut obj_expr.properties, self.ast.new_vec());
+        for (i, prop) in properties.into_iter().enumerate() {
+            if i < first_computed {
+                initial.push(prop);
+            } else {
+                rest.push(prop);
+            }
+        }
+        let initial = self.ast.object_expression(Span::default(), initial, None);
+
+        // A single data property can be defined on the initial object directly.
+        if rest.len() == 1 && !self.set_computed_properties {
+            if let Some(ObjectPropertyKind::ObjectProperty(prop)) = rest.first() {
+                if prop.kind == PropertyKind::Init {
+                    let Some(ObjectPropertyKind::ObjectProperty(prop)) = rest.pop() else {
+                        unreachable!()
+                    };
+                    *expr = self.define_property(initial, prop.unbox());
+                    return;
+                }
+            }
+        }
+
+        let ident = self.create_new_named_var("obj");
+        let mut expressions = self.ast.new_vec_with_capacity(rest.len() + 2);
+        let target = AssignmentTarget::SimpleAssignmentTarget(
+            self.ast.simple_assignment_target_identifier(ident.clone()),
+        );
+        expressions.push(self.ast.assignment_expression(
+            Span::default(),
+            AssignmentOperator::Assign,
+            target,
+            initial,
+        ));
+        for prop in rest {
+            let object = self.ast.identifier_reference_expression(ident.clone());
+            expressions.push(match prop {
+                ObjectPropertyKind::ObjectProperty(prop) => match prop.kind {
+                    PropertyKind::Init if self.set_computed_properties => {
+                        self.assign_property(object, prop.unbox())
+                    }
+                    PropertyKind::Init => self.define_property(object, prop.unbox()),
+                    PropertyKind::Get | PropertyKind::Set => {
+                        self.define_accessor(object, prop.unbox())
+                    }
+                },
+                // `babelHelpers.objectSpread2(_obj, spread)`
+                ObjectPropertyKind::SpreadProperty(spread) => {
+                    let mut arguments = self.ast.new_vec_with_capacity(2);
+                    arguments.push(Argument::Expression(object));
+                    arguments.push(Argument::Expression(spread.unbox().argument));
+                    self.ctx.helper_call("objectSpread2", arguments)
+                }
+            });
+        }
+        expressions.push(self.ast.identifier_reference_expression(ident));
+        *expr = self.ast.sequence_expression(span, expressions);
+    }
+
+    /// `babelHelpers.defineProperty(object, key, value)`
+    fn define_property(&self, object: Expression<'a>, prop: ObjectProperty<'a>) -> Expression<'a> {
+        let mut arguments = self.ast.new_vec_with_capacity(3);
+        arguments.push(Argument::Expression(object));
+        arguments.push(Argument::Expression(self.key(prop.key)));
+        arguments.push(Argument::Expression(prop.value));
+        self.ctx.helper_call("defineProperty", arguments)
+    }
+
+    /// `babelHelpers.defineAccessor("get", object, key, function () {})`
+    fn define_accessor(&self, object: Expression<'a>, prop: ObjectProperty<'a>) -> Expression<'a> {
+        let span = Span::default();
+        let kind = if prop.kind == PropertyKind::Get { "get" } else { "set" };
+        let mut arguments = self.ast.new_vec_with_capacity(4);
+        arguments.push(Argument::Expression(
+            self.ast.literal_string_expression(StringLiteral::new(span, kind.into())),
+        ));
+        arguments.push(Argument::Expression(object));
+        arguments.push(Argument::Expression(self.key(prop.key)));
+        arguments.push(Argument::Expression(prop.value));
+        self.ctx.helper_call("defineAccessor", arguments)
+    }
+
+    /// * `object.key = value`
+    /// * `object[key] = value`
+    fn assign_property(&self, object: Expression<'a>, prop: ObjectProperty<'a>) -> Expression<'a> {
+        let span = Span::default();
+        let member_expr = match prop.key {
+            PropertyKey::Identifier(ident) if !prop.computed => {
+                self.ast.static_member(span, object, ident.unbox(), false)
+            }
+            key => self.ast.computed_member(span, object, self.key(key), false),
+        };
+        let target = AssignmentTarget::SimpleAssignmentTarget(
+            self.ast.simple_assignment_target_member_expression(member_expr),
+        );
+        self.ast.assignment_expression(span, AssignmentOperator::Assign, target, prop.value)
+    }
+
+    /// The key of a property as an expression, e.g. `"a"` for `a: 1`.
+    fn key(&self, key: PropertyKey<'a>) -> Expression<'a> {
+        match key {
+            PropertyKey::Identifier(ident) => self
+                .ast
+                .literal_string_expression(StringLiteral::new(ident.span, ident.unbox().name)),
+            PropertyKey::PrivateIdentifier(ident) => self
+                .ast
+                .literal_string_expression(StringLiteral::new(ident.span, ident.unbox().name)),
+            PropertyKey::Expression(expr) => expr,
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::{options::TransformOptions, tester::Tester};
+
+    let options = TransformOptions {
+        computed_properties: Some(ComputedPropertiesOptions::default()),
+        ..TransformOptions::default()
+    };
+    Tester::new("test.js", options).test(&[
+        ("({ [a]: 1 })", "babelHelpers.defineProperty({}, a, 1)"),
+        ("({ a: 1, [b]: 2 })", "babelHelpers.defineProperty({ a: 1 }, b, 2)"),
+        (
+            "({ [a]: 1, b: 2, 'c': 3, ...d })",
+            "var _obj; _obj = {}, babelHelpers.defineProperty(_obj, a, 1), babelHelpers.defineProperty(_obj, 'b', 2), babelHelpers.defineProperty(_obj, 'c', 3), babelHelpers.objectSpread2(_obj, d), _obj",
+        ),
+        (
+            "({ [a]: 1, get b() { return 2; } })",
+            "var _obj; _obj = {}, babelHelpers.defineProperty(_obj, a, 1), babelHelpers.defineAccessor('get', _obj, 'b', function() { return 2; }), _obj",
+        ),
+        ("({ a: 1 })", "({ a: 1 })"),
+    ]);
+
+    let options = TransformOptions {
+        computed_properties: Some(ComputedPropertiesOptions { loose: true }),
+        ..TransformOptions::default()
+    };
+    Tester::new("test.js", options)
+        .test(&[("({ [a]: 1, b: 2 })", "var _obj; _obj = {}, _obj[a] = 1, _obj.b = 2, _obj")]);
+}
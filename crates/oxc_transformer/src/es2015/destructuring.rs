@@ -0,0 +1,861 @@
+use std::{mem, rc::Rc};
+
+use oxc_allocator::Vec;
+use oxc_ast::{ast::*, AstBuilder};
+use oxc_span::{Atom, Span};
+use oxc_syntax::operator::{AssignmentOperator, BinaryOperator, LogicalOperator};
+
+use crate::{
+    context::TransformerCtx,
+    options::{TransformOptions, TransformTarget},
+    targets::Feature,
+    utils::CreateVars,
+};
+
+/// ES2015: Destructuring
+///
+/// References:
+/// * <https://babel.dev/docs/babel-plugin-transform-destructuring>
+/// * <https://github.com/babel/babel/blob/main/packages/babel-plugin-transform-destructuring>
+pub struct Destructuring<'a> {
+    ast: Rc<AstBuilder<'a>>,
+    ctx: TransformerCtx<'a>,
+    vars: Vec<'a, VariableDeclarator<'a>>,
+
+    iterable_is_array: bool,
+    object_rest_no_symbols: bool,
+}
+
+/// A binding pattern or an assignment target pattern, flattened in the same way.
+enum Target<'a> {
+    Binding(BindingPattern<'a>),
+    Assignment(AssignmentTarget<'a>),
+}
+
+/// `target = default` in a pattern.
+type TargetWithDefault<'a> = (Target<'a>, Option<Expression<'a>>);
+
+/// Where the flattened pattern goes to.
+enum Destructured<'a> {
+    /// `var a = _ref.a, b = _ref.b`
+    Declarators(VariableDeclarationKind, Vec<'a, VariableDeclarator<'a>>),
+    /// `a = _ref.a, b = _ref.b`
+    Expressions(Vec<'a, Expression<'a>>),
+}
+
+impl<'a> CreateVars<'a> for Destructuring<'a> {
+    fn ctx(&self) -> &TransformerCtx<'a> {
+        &self.ctx
+    }
+
+    fn vars_mut(&mut self) -> &mut Vec<'a, VariableDeclarator<'a>> {
+        &mut self.vars
+    }
+}
+
+impl<'a> Destructuring<'a> {
+    pub fn new(
+        ast: Rc<AstBuilder<'a>>,
+        ctx: TransformerCtx<'a>,
+        options: &TransformOptions,
+    ) -> Option<Self> {
+        (options.target < TransformTarget::ES2015
+            || options.destructuring
+            || options.targets.needs_transform(Feature::Destructuring))
+        .then(|| {
+            let vars = ast.new_vec();
+            let iterable_is_array = options.assumptions.iterable_is_array;
+            let object_rest_no_symbols = options.assumptions.object_rest_no_symbols;
+            Self { ast, ctx, vars, iterable_is_array, object_rest_no_symbols }
+        })
+    }
+
+    /// `var { a, b } = obj` -> `var a = obj.a, b = obj.b`
+    pub fn transform_variable_declaration(&mut self, decl: &mut VariableDeclaration<'a>) {
+        if !decl.declarations.iter().any(|declarator| {
+            declarator.init.is_some() && declarator.id.kind.is_destructuring_pattern()
+        }) {
+            return;
+        }
+        let declarations = mem::replace(&mut decl.declarations, self.ast.new_vec());
+        let mut out = Destructured::Declarators(decl.kind, self.ast.new_vec());
+        for declarator in declarations {
+            match declarator.init {
+                Some(init) if declarator.id.kind.is_destructuring_pattern() => {
+                    self.flatten(Target::Binding(declarator.id), init, &mut out);
+                }
+                _ => {
+                    if let Destructured::Declarators(_, declarations) = &mut out {
+                        declarations.push(declarator);
+                    }
+                }
+            }
+        }
+        if let Destructured::Declarators(_, declarations) = out {
+            decl.declarations = declarations;
+        }
+    }
+
+    /// `[a, b] = arr` -> `_arr = babelHelpers.slicedToArray(arr, 2), a = _arr[0], b = _arr[1], arr`
+    pub fn transform_expression(&mut self, expr: &mut Expression<'a>) {
+        let Expression::AssignmentExpression(assign_expr) = expr else { return };
+        if assign_expr.operator != AssignmentOperator::Assign
+            || !assign_expr.left.is_destructuring_pattern()
+        {
+            return;
+        }
+        let left = self.ast.move_assignment_target(&mut assign_expr.left);
+        let right = self.ast.move_expression(&mut assign_expr.right);
+        let mut out = Destructured::Expressions(self.ast.new_vec());
+        let name = match right {
+            Expression::Identifier(ident) => ident.name.clone(),
+            right => self.memoise(right, &mut out),
+        };
+        self.flatten(Target::Assignment(left), self.identifier(&name), &mut out);
+        if let Destructured::Expressions(mut expressions) = out {
+            expressions.push(self.identifier(&name));
+            *expr = self.ast.sequence_expression(Span::default(), expressions);
+        }
+    }
+
+    /// `function f({ a }) {}` -> `function f(_ref) { let { a } = _ref; }`
+    pub fn transform_function(&mut self, func: &mut Function<'a>) {
+        if let Some(body) = &mut func.body {
+            self.transform_parameters(&mut func.params, body, false);
+        }
+    }
+
+    pub fn transform_arrow_expression(&mut self, expr: &mut ArrowExpression<'a>) {
+        self.transform_parameters(&mut expr.params, &mut expr.body, true);
+    }
+
+    /// `for (const [a, b] of arr) {}` -> `for (const _ref of arr) { const [a, b] = _ref; }`
+    pub fn transform_statement(&mut self, stmt: &mut Statement<'a>) {
+        let (left, body) = match stmt {
+            Statement::ForOfStatement(stmt) => {
+                let stmt = &mut **stmt;
+                (&mut stmt.left, &mut stmt.body)
+            }
+            Statement::ForInStatement(stmt) => {
+                let stmt = &mut **stmt;
+                (&mut stmt.left, &mut stmt.body)
+            }
+            _ => return,
+        };
+        let span = Span::default();
+        let binding = match left {
+            ForStatementLeft::VariableDeclaration(decl) => {
+                let Some(declarator) = decl.declarations.first_mut() else { return };
+                if !declarator.id.kind.is_destructuring_pattern() {
+                    return;
+                }
+                let name = self.generate_uid("ref");
+                let pattern = mem::replace(&mut declarator.id, self.binding_pattern(name.clone()));
+                self.declaration(decl.kind, pattern, self.identifier(&name))
+            }
+            ForStatementLeft::AssignmentTarget(target) if target.is_destructuring_pattern() => {
+                let name = self.generate_uid("ref");
+                let target = self.ast.move_assignment_target(target);
+                let kind = VariableDeclarationKind::Var;
+                let declarations = self.ast.new_vec_single(self.ast.variable_declarator(
+                    span,
+                    kind,
+                    self.binding_pattern(name.clone()),
+                    None,
+                    false,
+                ));
+                *left = ForStatementLeft::VariableDeclaration(self.ast.variable_declaration(
+                    span,
+                    kind,
+                    declarations,
+                    Modifiers::empty(),
+                ));
+                let expr = self.ast.assignment_expression(
+                    span,
+                    AssignmentOperator::Assign,
+                    target,
+                    self.identifier(&name),
+                );
+                self.ast.expression_statement(span, expr)
+            }
+            _ => return,
+        };
+        if let Statement::BlockStatement(block) = body {
+            block.body.insert(0, binding);
+        } else {
+            let mut stmts = self.ast.new_vec_with_capacity(2);
+            stmts.push(binding);
+            stmts.push(self.ast.move_statement(body));
+            *body = self.ast.block_statement(self.ast.block(span, stmts));
+        }
+    }
+
+    /// `catch ({ message }) {}` -> `catch (_ref) { let { message } = _ref; }`
+    pub fn transform_catch_clause(&mut self, clause: &mut CatchClause<'a>) {
+        let Some(param) = &mut clause.param else { return };
+        if !param.kind.is_destructuring_pattern() {
+            return;
+        }
+        let name = self.generate_uid("ref");
+        let pattern = mem::replace(param, self.binding_pattern(name.clone()));
+        let stmt = self.declaration(VariableDeclarationKind::Let, pattern, self.identifier(&name));
+        clause.body.body.insert(0, stmt);
+    }
+
+    /// The parameters after the first destructured one may read its bindings in their defaults,
+    /// so from the first of them with a default, they are moved into the body in order and read
+    /// from `arguments`, which keeps the `length` of the function like Babel does:
+    /// `function f({ a }, b = a) {}` ->
+    /// `function f(_ref) { let { a } = _ref; let b = arguments.length > 1 && arguments[1] !== void 0 ? arguments[1] : a; }`
+    ///
+    /// Arrow functions have no `arguments`, so these parameters are renamed instead:
+    /// `({ a }, b = a) => {}` -> `(_ref, _b) => { let { a } = _ref; let b = _b === void 0 ? a : _b; }`
+    fn transform_parameters(
+        &mut self,
+        params: &mut FormalParameters<'a>,
+        body: &mut FunctionBody<'a>,
+        is_arrow: bool,
+    ) {
+        let has_default = |param: &FormalParameter| {
+            matches!(param.pattern.kind, BindingPatternKind::AssignmentPattern(_))
+        };
+        let first = params.items.iter().position(|param| match &param.pattern.kind {
+            BindingPatternKind::AssignmentPattern(assign_pattern) => {
+                assign_pattern.left.kind.is_destructuring_pattern()
+            }
+            kind => kind.is_destructuring_pattern(),
+        });
+        let cut = first.filter(|_| !is_arrow).and_then(|first| {
+            params.items.iter().skip(first + 1).position(has_default).map(|i| first + 1 + i)
+        });
+
+        let kind = VariableDeclarationKind::Let;
+        let mut stmts = self.ast.new_vec();
+        let items = mem::replace(&mut params.items, self.ast.new_vec());
+        let length = items.len();
+        for (index, mut param) in items.into_iter().enumerate() {
+            if cut.is_some_and(|cut| index >= cut) {
+                let (pattern, default) = match param.pattern.kind {
+                    BindingPatternKind::AssignmentPattern(assign_pattern) => {
+                        let AssignmentPattern { left, right, .. } = assign_pattern.unbox();
+                        (left, Some(right))
+                    }
+                    _ => (param.pattern, None),
+                };
+                stmts.push(self.declaration(kind, pattern, self.argument(index, default)));
+                continue;
+            }
+            if is_arrow && first.is_some_and(|first| index > first) && has_default(&param) {
+                let name = match &param.pattern.kind {
+                    BindingPatternKind::AssignmentPattern(assign_pattern) => {
+                        match &assign_pattern.left.kind {
+                            BindingPatternKind::BindingIdentifier(ident) => ident.name.to_string(),
+                            _ => "ref".to_string(),
+                        }
+                    }
+                    _ => unreachable!(),
+                };
+                let name = self.generate_uid(&name);
+                let pattern = mem::replace(&mut param.pattern, self.binding_pattern(name.clone()));
+                let BindingPatternKind::AssignmentPattern(assign_pattern) = pattern.kind else {
+                    unreachable!()
+                };
+                let AssignmentPattern { left, right, .. } = assign_pattern.unbox();
+                // `_b === void 0 ? a : _b`
+                let test = self.ast.binary_expression(
+                    Span::default(),
+                    self.identifier(&name),
+                    BinaryOperator::StrictEquality,
+                    self.ast.void_0(),
+                );
+                let value = self.ast.conditional_expression(
+                    Span::default(),
+                    test,
+                    right,
+                    self.identifier(&name),
+                );
+                stmts.push(self.declaration(kind, left, value));
+            } else {
+                // `function f({ a } = {})` keeps the default on the parameter.
+                let pattern = match &mut param.pattern.kind {
+                    BindingPatternKind::AssignmentPattern(assign_pattern) => {
+                        &mut assign_pattern.left
+                    }
+                    _ => &mut param.pattern,
+                };
+                if pattern.kind.is_destructuring_pattern() {
+                    let name = self.generate_uid("ref");
+                    let pattern = mem::replace(pattern, self.binding_pattern(name.clone()));
+                    stmts.push(self.declaration(kind, pattern, self.identifier(&name)));
+                }
+            }
+            params.items.push(param);
+        }
+
+        if cut.is_some() {
+            // `...rest` -> `let rest = Array.prototype.slice.call(arguments, 2)`
+            if let Some(rest) = params.rest.take() {
+                let callee = self.member(self.identifier(&"Array".into()), "prototype");
+                let callee = self.member(self.member(callee, "slice"), "call");
+                let mut arguments = self.ast.new_vec_with_capacity(2);
+                arguments.push(Argument::Expression(self.identifier(&"arguments".into())));
+                arguments.push(Argument::Expression(self.number_literal(length)));
+                let value =
+                    self.ast.call_expression(Span::default(), callee, arguments, false, None);
+                stmts.push(self.declaration(kind, rest.unbox().argument, value));
+            }
+        } else if let Some(rest) = &mut params.rest {
+            if rest.argument.kind.is_destructuring_pattern() {
+                let name = self.generate_uid("ref");
+                let pattern = mem::replace(&mut rest.argument, self.binding_pattern(name.clone()));
+                stmts.push(self.declaration(kind, pattern, self.identifier(&name)));
+            }
+        }
+
+        if !stmts.is_empty() {
+            stmts.extend(mem::replace(&mut body.statements, self.ast.new_vec()));
+            body.statements = stmts;
+        }
+    }
+
+    /// `arguments.length > 1 && arguments[1] !== void 0 ? arguments[1] : a`, or
+    /// `arguments.length > 1 ? arguments[1] : void 0` without a default.
+    fn argument(&self, index: usize, default: Option<Expression<'a>>) -> Expression<'a> {
+        let span = Span::default();
+        let arguments = || self.identifier(&"arguments".into());
+        let element = || {
+            self.ast.computed_member_expression(
+                span,
+                arguments(),
+                self.number_literal(index),
+                false,
+            )
+        };
+        let test = self.ast.binary_expression(
+            span,
+            self.member(arguments(), "length"),
+            BinaryOperator::GreaterThan,
+            self.number_literal(index),
+        );
+        let (test, default) = match default {
+            Some(default) => {
+                let is_defined = self.ast.binary_expression(
+                    span,
+                    element(),
+                    BinaryOperator::StrictInequality,
+                    self.ast.void_0(),
+                );
+                (self.ast.logical_expression(span, test, LogicalOperator::And, is_defined), default)
+            }
+            None => (test, self.ast.void_0()),
+        };
+        self.ast.conditional_expression(span, test, element(), default)
+    }
+
+    fn flatten(&mut self, target: Target<'a>, value: Expression<'a>, out: &mut Destructured<'a>) {
+        match target {
+            Target::Binding(pattern) => match pattern.kind {
+                BindingPatternKind::BindingIdentifier(_) => match out {
+                    Destructured::Declarators(kind, declarations) => {
+                        let declarator = self.ast.variable_declarator(
+                            Span::default(),
+                            *kind,
+                            pattern,
+                            Some(value),
+                            false,
+                        );
+                        declarations.push(declarator);
+                    }
+                    Destructured::Expressions(expressions) => {
+                        let BindingPatternKind::BindingIdentifier(ident) = pattern.kind else {
+                            unreachable!()
+                        };
+                        expressions.push(self.assign(&ident.name, value));
+                    }
+                },
+                BindingPatternKind::ObjectPattern(pattern) => {
+                    let ObjectPattern { properties, rest, .. } = pattern.unbox();
+                    let properties = properties
+                        .into_iter()
+                        .map(|property| (property.key, (Target::Binding(property.value), None)))
+                        .collect();
+                    let rest = rest.map(|rest| Target::Binding(rest.unbox().argument));
+                    self.flatten_object(properties, rest, value, out);
+                }
+                BindingPatternKind::ArrayPattern(pattern) => {
+                    let ArrayPattern { elements, rest, .. } = pattern.unbox();
+                    let elements = elements
+                        .into_iter()
+                        .map(|element| element.map(|element| (Target::Binding(element), None)))
+                        .collect();
+                    let rest = rest.map(|rest| Target::Binding(rest.unbox().argument));
+                    self.flatten_array(elements, rest, value, out);
+                }
+                BindingPatternKind::AssignmentPattern(pattern) => {
+                    let AssignmentPattern { left, right, .. } = pattern.unbox();
+                    self.flatten_default((Target::Binding(left), Some(right)), value, out);
+                }
+            },
+            Target::Assignment(target) => match target {
+                AssignmentTarget::SimpleAssignmentTarget(_) => {
+                    if let Destructured::Expressions(expressions) = out {
+                        let expr = self.ast.assignment_expression(
+                            Span::default(),
+                            AssignmentOperator::Assign,
+                            target,
+                            value,
+                        );
+                        expressions.push(expr);
+                    }
+                }
+                AssignmentTarget::AssignmentTargetPattern(
+                    AssignmentTargetPattern::ObjectAssignmentTarget(target),
+                ) => {
+                    let ObjectAssignmentTarget { properties, rest, .. } = target.unbox();
+                    let properties = properties
+                        .into_iter()
+                        .map(|property| match property {
+                            AssignmentTargetProperty::AssignmentTargetPropertyIdentifier(
+                                property,
+                            ) => {
+                                let AssignmentTargetPropertyIdentifier { binding, init, .. } =
+                                    property.unbox();
+                                let key = PropertyKey::Identifier(self.ast.alloc(
+                                    IdentifierName::new(Span::default(), binding.name.clone()),
+                                ));
+                                let target = AssignmentTarget::SimpleAssignmentTarget(
+                                    self.ast.simple_assignment_target_identifier(binding),
+                                );
+                                (key, (Target::Assignment(target), init))
+                            }
+                            AssignmentTargetProperty::AssignmentTargetPropertyProperty(
+                                property,
+                            ) => {
+                                let AssignmentTargetPropertyProperty { name, binding, .. } =
+                                    property.unbox();
+                                (name, Self::assignment_target_with_default(binding))
+                            }
+                        })
+                        .collect();
+                    let rest = rest.map(Target::Assignment);
+                    self.flatten_object(properties, rest, value, out);
+                }
+                AssignmentTarget::AssignmentTargetPattern(
+                    AssignmentTargetPattern::ArrayAssignmentTarget(target),
+                ) => {
+                    let ArrayAssignmentTarget { elements, rest, .. } = target.unbox();
+                    let elements = elements
+                        .into_iter()
+                        .map(|element| element.map(Self::assignment_target_with_default))
+                        .collect();
+                    let rest = rest.map(Target::Assignment);
+                    self.flatten_array(elements, rest, value, out);
+                }
+            },
+        }
+    }
+
+    fn assignment_target_with_default(
+        target: AssignmentTargetMaybeDefault<'a>,
+    ) -> TargetWithDefault<'a> {
+        match target {
+            AssignmentTargetMaybeDefault::AssignmentTarget(target) => {
+                (Target::Assignment(target), None)
+            }
+            AssignmentTargetMaybeDefault::AssignmentTargetWithDefault(target) => {
+                let AssignmentTargetWithDefault { binding, init, .. } = target.unbox();
+                (Target::Assignment(binding), Some(init))
+            }
+        }
+    }
+
+    /// `a = 1` -> `_ref = value, a = _ref === void 0 ? 1 : _ref`
+    fn flatten_default(
+        &mut self,
+        (target, default): TargetWithDefault<'a>,
+        value: Expression<'a>,
+        out: &mut Destructured<'a>,
+    ) {
+        let Some(default) = default else {
+            self.flatten(target, value, out);
+            return;
+        };
+        let span = Span::default();
+        let name = match value {
+            Expression::Identifier(ident) => ident.name.clone(),
+            value => self.memoise(value, out),
+        };
+        let test = self.ast.binary_expression(
+            span,
+            self.identifier(&name),
+            BinaryOperator::StrictEquality,
+            self.ast.void_0(),
+        );
+        let value = self.ast.conditional_expression(span, test, default, self.identifier(&name));
+        self.flatten(target, value, out);
+    }
+
+    /// `{ a, b: c, ...rest } = obj` ->
+    /// `a = obj.a, c = obj.b, rest = babelHelpers.objectWithoutProperties(obj, ["a", "b"])`
+    fn flatten_object(
+        &mut self,
+        properties: std::vec::Vec<(PropertyKey<'a>, TargetWithDefault<'a>)>,
+        rest: Option<Target<'a>>,
+        value: Expression<'a>,
+        out: &mut Destructured<'a>,
+    ) {
+        let span = Span::default();
+        if properties.is_empty() && rest.is_none() {
+            // `var {} = obj` still throws for `null` and `undefined`.
+            let arguments = self.ast.new_vec_single(Argument::Expression(value));
+            let value = self.ctx.helper_call("objectDestructuringEmpty", arguments);
+            match out {
+                Destructured::Declarators(..) => {
+                    self.memoise(value, out);
+                }
+                Destructured::Expressions(expressions) => expressions.push(value),
+            }
+            return;
+        }
+
+        // The object is only evaluated once, a single property access needs no reference.
+        let uses = properties.len().max(1) + usize::from(rest.is_some());
+        let mut single = None;
+        let reference = match value {
+            Expression::Identifier(ident) => Some(ident.name.clone()),
+            value if uses == 1 => {
+                single = Some(value);
+                None
+            }
+            value => Some(self.memoise(value, out)),
+        };
+
+        let mut excluded_keys = self.ast.new_vec();
+        let mut has_computed_key = false;
+        for (key, target) in properties {
+            let object = self.object_reference(reference.as_ref(), &mut single);
+            let value = match key {
+                PropertyKey::Identifier(ident) => {
+                    if rest.is_some() {
+                        excluded_keys.push(self.string_literal(&ident.name));
+                    }
+                    let property = ident.unbox();
+                    self.ast.static_member_expression(span, object, property, false)
+                }
+                PropertyKey::Expression(expr) => {
+                    let key = match expr {
+                        Expression::StringLiteral(lit) if rest.is_some() => {
+                            excluded_keys.push(self.string_literal(&lit.value));
+                            Expression::StringLiteral(lit)
+                        }
+                        Expression::NumberLiteral(lit) if rest.is_some() => {
+                            excluded_keys.push(self.string_literal(&lit.value.to_string()));
+                            Expression::NumberLiteral(lit)
+                        }
+                        expr if rest.is_some() => {
+                            let name = self.memoise(expr, out);
+                            excluded_keys.push(self.identifier(&name));
+                            has_computed_key = true;
+                            self.identifier(&name)
+                        }
+                        expr => expr,
+                    };
+                    self.ast.computed_member_expression(span, object, key, false)
+                }
+                PropertyKey::PrivateIdentifier(_) => continue,
+            };
+            self.flatten_default(target, value, out);
+        }
+
+        let Some(rest) = rest else { return };
+        let value = if excluded_keys.is_empty() {
+            // `{ ...rest } = obj` -> `rest = babelHelpers.extends({}, (babelHelpers.objectDestructuringEmpty(obj), obj))`
+            let arguments = self.ast.new_vec_single(Argument::Expression(
+                self.object_reference(reference.as_ref(), &mut single),
+            ));
+            let check = self.ctx.helper_call("objectDestructuringEmpty", arguments);
+            let mut expressions = self.ast.new_vec_with_capacity(2);
+            expressions.push(check);
+            expressions.push(self.object_reference(reference.as_ref(), &mut single));
+            let source = self.ast.sequence_expression(span, expressions);
+            let source = self.ast.parenthesized_expression(span, source);
+            let mut arguments = self.ast.new_vec_with_capacity(2);
+            let target = self.ast.object_expression(span, self.ast.new_vec(), None);
+            arguments.push(Argument::Expression(target));
+            arguments.push(Argument::Expression(source));
+            self.ctx.helper_call("extends", arguments)
+        } else {
+            let mut elements = self.ast.new_vec_with_capacity(excluded_keys.len());
+            elements.extend(excluded_keys.into_iter().map(ArrayExpressionElement::Expression));
+            let mut keys = self.ast.array_expression(span, elements, None);
+            if has_computed_key {
+                // `[_key].map(babelHelpers.toPropertyKey)`
+                let callee = self.member(keys, "map");
+                let helper = self.ctx.helper_reference("toPropertyKey");
+                let arguments = self.ast.new_vec_single(Argument::Expression(helper));
+                keys = self.ast.call_expression(span, callee, arguments, false, None);
+            }
+            let mut arguments = self.ast.new_vec_with_capacity(2);
+            arguments
+                .push(Argument::Expression(self.object_reference(reference.as_ref(), &mut single)));
+            arguments.push(Argument::Expression(keys));
+            let helper = if self.object_rest_no_symbols {
+                "objectWithoutPropertiesLoose"
+            } else {
+                "objectWithoutProperties"
+            };
+            self.ctx.helper_call(helper, arguments)
+        };
+        self.flatten(rest, value, out);
+    }
+
+    fn object_reference(
+        &self,
+        reference: Option<&Atom>,
+        single: &mut Option<Expression<'a>>,
+    ) -> Expression<'a> {
+        reference.map_or_else(
+            || single.take().unwrap_or_else(|| self.ast.void_0()),
+            |name| self.identifier(name),
+        )
+    }
+
+    /// `[a, b, ...rest] = arr` ->
+    /// `_arr = babelHelpers.toArray(arr), a = _arr[0], b = _arr[1], rest = _arr.slice(2)`
+    fn flatten_array(
+        &mut self,
+        elements: std::vec::Vec<Option<TargetWithDefault<'a>>>,
+        rest: Option<Target<'a>>,
+        value: Expression<'a>,
+        out: &mut Destructured<'a>,
+    ) {
+        let span = Span::default();
+        let name = match value {
+            // `[a, b] = arr` -> `_arr = arr, a = _arr[0], b = _arr[1]`
+            value if self.iterable_is_array => self.memoise(value, out),
+            value => {
+                let name = self.ctx.scopes().generate_uid_based_on_node(&value);
+                let mut arguments = self.ast.new_vec_with_capacity(2);
+                arguments.push(Argument::Expression(value));
+                let value = if rest.is_some() {
+                    self.ctx.helper_call("toArray", arguments)
+                } else {
+                    arguments.push(Argument::Expression(self.number_literal(elements.len())));
+                    self.ctx.helper_call("slicedToArray", arguments)
+                };
+                self.memoise_with_name(name, value, out)
+            }
+        };
+
+        let len = elements.len();
+        for (i, element) in elements.into_iter().enumerate() {
+            let Some(element) = element else { continue };
+            let value = self.ast.computed_member_expression(
+                span,
+                self.identifier(&name),
+                self.number_literal(i),
+                false,
+            );
+            self.flatten_default(element, value, out);
+        }
+
+        if let Some(rest) = rest {
+            let callee = self.member(self.identifier(&name), "slice");
+            let arguments = self.ast.new_vec_single(Argument::Expression(self.number_literal(len)));
+            let value = self.ast.call_expression(span, callee, arguments, false, None);
+            self.flatten(rest, value, out);
+        }
+    }
+
+    /// Evaluate `value` once into an unique reference.
+    fn memoise(&mut self, value: Expression<'a>, out: &mut Destructured<'a>) -> Atom {
+        let name = self.ctx.scopes().generate_uid_based_on_node(&value);
+        self.memoise_with_name(name, value, out)
+    }
+
+    fn memoise_with_name(
+        &mut self,
+        name: Atom,
+        value: Expression<'a>,
+        out: &mut Destructured<'a>,
+    ) -> Atom {
+        match out {
+            Destructured::Declarators(kind, declarations) => {
+                self.ctx.add_binding(name.clone());
+                let declarator = self.ast.variable_declarator(
+                    Span::default(),
+                    *kind,
+                    self.binding_pattern(name.clone()),
+                    Some(value),
+                    false,
+                );
+                declarations.push(declarator);
+            }
+            Destructured::Expressions(expressions) => {
+                self.create_var_with_name(name.clone());
+                expressions.push(self.assign(&name, value));
+            }
+        }
+        name
+    }
+
+    fn generate_uid(&self, name: &str) -> Atom {
+        let name = self.ctx.scopes().generate_uid(name);
+        self.ctx.add_binding(name.clone());
+        name
+    }
+
+    /// `kind pattern = init;`
+    fn declaration(
+        &self,
+        kind: VariableDeclarationKind,
+        pattern: BindingPattern<'a>,
+        init: Expression<'a>,
+    ) -> Statement<'a> {
+        let declarator =
+            self.ast.variable_declarator(Span::default(), kind, pattern, Some(init), false);
+        let decl = self.ast.variable_declaration(
+            Span::default(),
+            kind,
+            self.ast.new_vec_single(declarator),
+            Modifiers::empty(),
+        );
+        Statement::Declaration(Declaration::VariableDeclaration(decl))
+    }
+
+    fn binding_pattern(&self, name: Atom) -> BindingPattern<'a> {
+        let ident = BindingIdentifier::new(Span::default(), name);
+        self.ast.binding_pattern(self.ast.binding_pattern_identifier(ident), None, false)
+    }
+
+    fn identifier(&self, name: &Atom) -> Expression<'a> {
+        self.ast.identifier_reference_expression(IdentifierReference::new(
+            Span::default(),
+            name.clone(),
+        ))
+    }
+
+    fn member(&self, object: Expression<'a>, property: &str) -> Expression<'a> {
+        let property = IdentifierName::new(Span::default(), property.into());
+        self.ast.static_member_expression(Span::default(), object, property, false)
+    }
+
+    fn assign(&self, name: &Atom, value: Expression<'a>) -> Expression<'a> {
+        let target =
+            AssignmentTarget::SimpleAssignmentTarget(self.ast.simple_assignment_target_identifier(
+                IdentifierReference::new(Span::default(), name.clone()),
+            ));
+        self.ast.assignment_expression(Span::default(), AssignmentOperator::Assign, target, value)
+    }
+
+    fn string_literal(&self, value: &str) -> Expression<'a> {
+        self.ast.literal_string_expression(StringLiteral::new(Span::default(), value.into()))
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn number_literal(&self, value: usize) -> Expression<'a> {
+        self.ast.literal_number_expression(NumberLiteral::new(
+            Span::default(),
+            value as f64,
+            self.ast.new_str(&value.to_string()),
+            oxc_syntax::NumberBase::Decimal,
+        ))
+    }
+}
+
+#[test]
+fn test() {
+    use oxc_syntax::assumptions::CompilerAssumptions;
+
+    use crate::{options::TransformOptions, tester::Tester};
+
+    let options = TransformOptions { destructuring: true, ..TransformOptions::default() };
+    Tester::new("test.js", options).test(&[
+        ("var { a, b: c } = obj;", "var a = obj.a, c = obj.b;"),
+        ("var { a } = foo();", "var a = foo().a;"),
+        ("var { a, b } = foo();", "var _ref = foo(), a = _ref.a, b = _ref.b;"),
+        ("var { a: { b } } = obj;", "var b = obj.a.b;"),
+        ("const { a = 1 } = obj;", "const _obj$a = obj.a, a = _obj$a === void 0 ? 1 : _obj$a;"),
+        ("let { ['a' + b]: c, 'd-e': f } = obj;", "let c = obj['a' + b], f = obj['d-e'];"),
+        (
+            "var { a, ...rest } = obj;",
+            "var a = obj.a, rest = babelHelpers.objectWithoutProperties(obj, ['a']);",
+        ),
+        (
+            "var { [a]: b, ...rest } = obj;",
+            "var _a = a, b = obj[_a], rest = babelHelpers.objectWithoutProperties(obj, [_a].map(babelHelpers.toPropertyKey));",
+        ),
+        (
+            "var { ...rest } = obj;",
+            "var rest = babelHelpers.extends({}, (babelHelpers.objectDestructuringEmpty(obj), obj));",
+        ),
+        ("var {} = obj;", "var _ref = babelHelpers.objectDestructuringEmpty(obj);"),
+        (
+            "var [a, , b] = arr;",
+            "var _arr = babelHelpers.slicedToArray(arr, 3), a = _arr[0], b = _arr[2];",
+        ),
+        (
+            "var [a, ...rest] = arr;",
+            "var _arr = babelHelpers.toArray(arr), a = _arr[0], rest = _arr.slice(1);",
+        ),
+        (
+            "var [{ a }, [b]] = arr;",
+            "var _arr = babelHelpers.slicedToArray(arr, 2), a = _arr[0].a, _arr$ref = babelHelpers.slicedToArray(_arr[1], 1), b = _arr$ref[0];",
+        ),
+        ("({ a, b: c } = obj);", "a = obj.a, c = obj.b, obj;"),
+        ("({ a = 1 } = obj);", "var _obj$a; _obj$a = obj.a, a = _obj$a === void 0 ? 1 : _obj$a, obj;"),
+        (
+            "[a, b] = [b, a];",
+            "var _ref, _ref2; _ref = [b, a], _ref2 = babelHelpers.slicedToArray(_ref, 2), a = _ref2[0], b = _ref2[1], _ref;",
+        ),
+        (
+            "function f({ a } = {}, [b] = []) {}",
+            "function f(_ref = {}) { let a = _ref.a; let _ref2 = babelHelpers.slicedToArray(arguments.length > 1 && arguments[1] !== void 0 ? arguments[1] : [], 1), b = _ref2[0]; }",
+        ),
+        // The defaults after a destructured parameter read its bindings
+        (
+            "function f({ a }, b, c = a, d, ...rest) {}",
+            "function f(_ref, b) { let a = _ref.a; let c = arguments.length > 2 && arguments[2] !== void 0 ? arguments[2] : a; let d = arguments.length > 3 ? arguments[3] : void 0; let rest = Array.prototype.slice.call(arguments, 4); }",
+        ),
+        ("const f = ({ a }) => a;", "const f = (_ref) => { let a = _ref.a; return a; };"),
+        (
+            "const f = ({ a }, b = a, ...rest) => b;",
+            "const f = (_ref, _b, ...rest) => { let a = _ref.a; let b = _b === void 0 ? a : _b; return b; };",
+        ),
+        (
+            "for (const { a } of arr) f(a);",
+            "for (const _ref of arr) { const a = _ref.a; f(a); }",
+        ),
+        ("for ({ a } in obj) {}", "for (var _ref in obj) { a = _ref.a, _ref; }"),
+        // The temporaries are declared in the scope they are created in
+        (
+            "[x, y] = arr; function f() {}",
+            "var _arr; _arr = babelHelpers.slicedToArray(arr, 2), x = _arr[0], y = _arr[1], arr; function f() {}",
+        ),
+        (
+            "function f() { [x] = a; } [y] = b;",
+            "var _b; function f() { var _a; _a = babelHelpers.slicedToArray(a, 1), x = _a[0], a; } _b = babelHelpers.slicedToArray(b, 1), y = _b[0], b;",
+        ),
+        ("try {} catch ({ message }) {}", "try {} catch (_ref) { let message = _ref.message; }"),
+    ]);
+
+    let options = TransformOptions {
+        destructuring: true,
+        assumptions: CompilerAssumptions {
+            iterable_is_array: true,
+            object_rest_no_symbols: true,
+            ..CompilerAssumptions::default()
+        },
+        ..TransformOptions::default()
+    };
+    Tester::new("test.js", options).test(&[
+        (
+            "var [a, , b, ...rest] = arr;",
+            "var _arr = arr, a = _arr[0], b = _arr[2], rest = _arr.slice(3);",
+        ),
+        (
+            "var { a, ...rest } = obj;",
+            "var a = obj.a, rest = babelHelpers.objectWithoutPropertiesLoose(obj, ['a']);",
+        ),
+    ]);
+}
@@ -0,0 +1,54 @@
+use std::rc::Rc;
+
+use oxc_ast::{ast::*, AstBuilder};
+use oxc_syntax::NumberBase;
+
+use crate::{
+    options::{TransformOptions, TransformTarget},
+    targets::Feature,
+};
+
+/// ES2015: Literals
+///
+/// * `0b11` -> `3`
+/// * `0o7` -> `7`
+///
+/// The legacy octal literals, `07`, are kept.
+///
+/// References:
+/// * <https://babel.dev/docs/babel-plugin-transform-literals>
+/// * <https://github.com/babel/babel/tree/main/packages/babel-plugin-transform-literals>
+pub struct Literals<'a> {
+    ast: Rc<AstBuilder<'a>>,
+}
+
+impl<'a> Literals<'a> {
+    pub fn new(ast: Rc<AstBuilder<'a>>, options: &TransformOptions) -> Option<Self> {
+        (options.target < TransformTarget::ES2015
+            || options.literals
+            || options.targets.needs_transform(Feature::Literals))
+        .then(|| Self { ast })
+    }
+
+    pub fn transform_expression(&self, expr: &mut Expression<'a>) {
+        let Expression::NumberLiteral(literal) = expr else { return };
+        let prefixed = literal.raw.len() > 1 && literal.raw.as_bytes()[1].is_ascii_alphabetic();
+        if matches!(literal.base, NumberBase::Binary | NumberBase::Octal) && prefixed {
+            literal.raw = self.ast.new_str(&literal.value.to_string());
+            literal.base = NumberBase::Decimal;
+        }
+    }
+}
+
+#[test]
+#[rustfmt::skip]
+fn test() {
+    use crate::tester::Tester;
+
+    let options = TransformOptions { target: TransformTarget::ES5, ..TransformOptions::default() };
+    Tester::new("test.js", options).test(&[
+        ("x = 0b11 + 0B1_01 + 0o17 + 0O7;", "x = 3 + 5 + 15 + 7;"),
+        ("x = 0xFF + 017 + 1.5;", "x = 0xFF + 017 + 1.5;"),
+        ("x = { 0b11: a };", "x = { 3: a };"),
+    ]);
+}
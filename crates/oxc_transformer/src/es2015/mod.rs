@@ -1,3 +1,15 @@
+mod block_scoping;
+mod computed_properties;
+mod destructuring;
+mod literals;
+mod regenerator;
 mod shorthand_properties;
+mod spread;
 
+pub use block_scoping::BlockScoping;
+pub use computed_properties::{ComputedProperties, ComputedPropertiesOptions};
+pub use destructuring::Destructuring;
+pub use literals::Literals;
+pub use regenerator::Regenerator;
 pub use shorthand_properties::ShorthandProperties;
+pub use spread::{Spread, SpreadOptions};
@@ -0,0 +1,1071 @@
+//! Emission of the state machine of a generator function.
+//!
+//! <https://github.com/facebook/regenerator/blob/main/packages/transform/src/emit.js>
+
+use std::{collections::HashSet, rc::Rc};
+
+use oxc_allocator::Vec;
+use oxc_ast::{ast::*, AstBuilder, VisitMut};
+use oxc_semantic::SymbolId;
+use oxc_span::{Atom, GetSpan, Span};
+use oxc_syntax::{
+    operator::{AssignmentOperator, BinaryOperator, LogicalOperator, UnaryOperator},
+    NumberBase,
+};
+
+use super::{
+    meta::{expression_contains_yield, statement_contains_leap},
+    runtime_call,
+};
+use crate::context::TransformerCtx;
+
+/// A location in the listing. Marked locations become cases of the dispatch loop.
+#[derive(Debug, Clone, Copy)]
+struct Loc(usize);
+
+/// A temporary variable stored on the context, e.g. `_context.t0`.
+#[derive(Debug, Clone, Copy)]
+struct Temp(usize);
+
+/// Targets of `break` and `continue`.
+enum LeapEntry {
+    Loop { break_loc: Loc, continue_loc: Loc, label: Option<Atom> },
+    Switch { break_loc: Loc },
+    Labeled { break_loc: Loc, label: Atom },
+}
+
+struct TryEntry {
+    try_loc: Loc,
+    catch_loc: Option<Loc>,
+    finally_loc: Option<Loc>,
+    after_loc: Loc,
+}
+
+pub struct Emitter<'a> {
+    ast: Rc<AstBuilder<'a>>,
+    ctx: TransformerCtx<'a>,
+    /// The parameter of the state machine, e.g. `_context`.
+    context: Atom,
+    listing: std::vec::Vec<Statement<'a>>,
+    /// Indices of the listing which start a case of the dispatch loop.
+    marked: HashSet<usize>,
+    /// Listing index of each location, known once it is marked.
+    locs: std::vec::Vec<Option<usize>>,
+    temps: usize,
+    final_loc: Loc,
+    try_entries: std::vec::Vec<TryEntry>,
+    leaps: std::vec::Vec<LeapEntry>,
+}
+
+impl<'a> Emitter<'a> {
+    pub fn new(ast: Rc<AstBuilder<'a>>, ctx: TransformerCtx<'a>, context: Atom) -> Self {
+        Self {
+            ast,
+            ctx,
+            context,
+            listing: vec![],
+            marked: HashSet::from([0]),
+            locs: vec![None],
+            temps: 0,
+            final_loc: Loc(0),
+            try_entries: vec![],
+            leaps: vec![],
+        }
+    }
+
+    fn loc(&mut self) -> Loc {
+        self.locs.push(None);
+        Loc(self.locs.len() - 1)
+    }
+
+    /// Sets the location to the current end of the listing and starts a new case there.
+    fn mark(&mut self, loc: Loc) {
+        let index = self.listing.len();
+        self.locs[loc.0] = Some(index);
+        self.marked.insert(index);
+    }
+
+    /// The current end of the listing, without starting a new case.
+    fn unmarked_current_loc(&mut self) -> Loc {
+        let loc = self.loc();
+        self.locs[loc.0] = Some(self.listing.len());
+        loc
+    }
+
+    /// Locations are not known until they are marked, so they are emitted as negative
+    /// placeholders which are resolved once the whole listing is emitted.
+    fn loc_expression(&self, loc: Loc) -> Expression<'a> {
+        #[allow(clippy::cast_precision_loss)]
+        let value = -(loc.0 as f64) - 1.0;
+        self.ast.literal_number_expression(self.ast.number_literal(
+            Span::default(),
+            value,
+            "",
+            NumberBase::Decimal,
+        ))
+    }
+
+    fn number(&self, value: usize) -> Expression<'a> {
+        #[allow(clippy::cast_precision_loss)]
+        let literal = self.ast.number_literal(
+            Span::default(),
+            value as f64,
+            self.ast.new_str(&value.to_string()),
+            NumberBase::Decimal,
+        );
+        self.ast.literal_number_expression(literal)
+    }
+
+    fn string(&self, value: &str) -> Expression<'a> {
+        self.ast.literal_string_expression(StringLiteral::new(Span::default(), value.into()))
+    }
+
+    fn context_member(&self, name: &str) -> MemberExpression<'a> {
+        let object = IdentifierReference::new(Span::default(), self.context.clone());
+        let object = self.ast.identifier_reference_expression(object);
+        let property = IdentifierName::new(Span::default(), name.into());
+        self.ast.static_member(Span::default(), object, property, false)
+    }
+
+    /// `_context.name`
+    fn context_property(&self, name: &str) -> Expression<'a> {
+        self.ast.member_expression(self.context_member(name))
+    }
+
+    /// `_context.name(arguments)`
+    fn context_call(&self, name: &str, arguments: Vec<'a, Argument<'a>>) -> Expression<'a> {
+        let callee = self.context_property(name);
+        self.ast.call_expression(Span::default(), callee, arguments, false, None)
+    }
+
+    fn temp_name(temp: Temp) -> String {
+        format!("t{}", temp.0)
+    }
+
+    fn make_temp(&mut self) -> Temp {
+        self.temps += 1;
+        Temp(self.temps - 1)
+    }
+
+    fn temp_expression(&self, temp: Temp) -> Expression<'a> {
+        self.context_property(&Self::temp_name(temp))
+    }
+
+    fn emit(&mut self, stmt: Statement<'a>) {
+        self.listing.push(stmt);
+    }
+
+    fn emit_expression(&mut self, expr: Expression<'a>) {
+        self.emit(self.ast.expression_statement(Span::default(), expr));
+    }
+
+    /// `_context.name = value`
+    fn assign(&self, name: &str, value: Expression<'a>) -> Statement<'a> {
+        let target = self.ast.simple_assignment_target_member_expression(self.context_member(name));
+        let expr = self.ast.assignment_expression(
+            Span::default(),
+            AssignmentOperator::Assign,
+            AssignmentTarget::SimpleAssignmentTarget(target),
+            value,
+        );
+        self.ast.expression_statement(Span::default(), expr)
+    }
+
+    fn emit_assign(&mut self, name: &str, value: Expression<'a>) {
+        self.emit(self.assign(name, value));
+    }
+
+    fn emit_assign_temp(&mut self, temp: Temp, value: Expression<'a>) -> Expression<'a> {
+        self.emit_assign(&Self::temp_name(temp), value);
+        self.temp_expression(temp)
+    }
+
+    /// `_context.next = loc; break;`
+    fn jump(&mut self, loc: Loc) {
+        let loc = self.loc_expression(loc);
+        self.jump_to(loc);
+    }
+
+    fn jump_to(&mut self, loc: Expression<'a>) {
+        self.emit_assign("next", loc);
+        self.emit(self.ast.break_statement(Span::default(), None));
+    }
+
+    /// `if (test) { _context.next = loc; break; }`
+    fn jump_if(&mut self, test: Expression<'a>, loc: Loc) {
+        let mut body = self.ast.new_vec_with_capacity(2);
+        body.push(self.assign("next", self.loc_expression(loc)));
+        body.push(self.ast.break_statement(Span::default(), None));
+        let consequent = self.ast.block_statement(self.ast.block(Span::default(), body));
+        self.emit(self.ast.if_statement(Span::default(), test, consequent, None));
+    }
+
+    fn jump_if_not(&mut self, test: Expression<'a>, loc: Loc) {
+        let test = match test {
+            Expression::UnaryExpression(expr) if expr.operator == UnaryOperator::LogicalNot => {
+                expr.unbox().argument
+            }
+            test => self.ast.unary_expression(Span::default(), UnaryOperator::LogicalNot, test),
+        };
+        self.jump_if(test, loc);
+    }
+
+    /// `return _context.abrupt("break", loc);`
+    fn emit_abrupt_completion(&mut self, kind: &str, value: Option<Expression<'a>>) {
+        let mut arguments = self.ast.new_vec_with_capacity(2);
+        arguments.push(Argument::Expression(self.string(kind)));
+        if let Some(value) = value {
+            arguments.push(Argument::Expression(value));
+        }
+        let call = self.context_call("abrupt", arguments);
+        self.emit(self.ast.return_statement(Span::default(), Some(call)));
+    }
+
+    /// `_context.prev = loc;`
+    fn update_context_prev_loc(&mut self, loc: Loc) {
+        self.emit_assign("prev", self.loc_expression(loc));
+    }
+
+    fn find_leap_location(
+        &self,
+        label: Option<&LabelIdentifier>,
+        is_continue: bool,
+    ) -> Option<Loc> {
+        self.leaps.iter().rev().find_map(|entry| match (entry, label) {
+            (LeapEntry::Loop { continue_loc, .. }, None) if is_continue => Some(*continue_loc),
+            (LeapEntry::Loop { break_loc, .. } | LeapEntry::Switch { break_loc }, None)
+                if !is_continue =>
+            {
+                Some(*break_loc)
+            }
+            (LeapEntry::Loop { break_loc, continue_loc, label: Some(name) }, Some(label))
+                if *name == label.name =>
+            {
+                Some(if is_continue { *continue_loc } else { *break_loc })
+            }
+            (LeapEntry::Labeled { break_loc, label: name }, Some(label))
+                if !is_continue && *name == label.name =>
+            {
+                Some(*break_loc)
+            }
+            _ => None,
+        })
+    }
+
+    pub fn explode_statement(&mut self, stmt: Statement<'a>, label: Option<Atom>) {
+        if let Statement::BlockStatement(block) = stmt {
+            for stmt in block.unbox().body {
+                self.explode_statement(stmt, None);
+            }
+            return;
+        }
+        if !statement_contains_leap(&stmt) {
+            self.emit(stmt);
+            return;
+        }
+        match stmt {
+            Statement::ExpressionStatement(stmt) => {
+                self.explode_expression(stmt.unbox().expression, true);
+            }
+            Statement::LabeledStatement(stmt) => {
+                let stmt = stmt.unbox();
+                let after = self.loc();
+                let label = stmt.label.name;
+                self.leaps.push(LeapEntry::Labeled { break_loc: after, label: label.clone() });
+                self.explode_statement(stmt.body, Some(label));
+                self.leaps.pop();
+                self.mark(after);
+            }
+            Statement::WhileStatement(stmt) => {
+                let stmt = stmt.unbox();
+                let before = self.loc();
+                let after = self.loc();
+                self.mark(before);
+                let test = self.explode(stmt.test);
+                self.jump_if_not(test, after);
+                self.leaps.push(LeapEntry::Loop { break_loc: after, continue_loc: before, label });
+                self.explode_statement(stmt.body, None);
+                self.leaps.pop();
+                self.jump(before);
+                self.mark(after);
+            }
+            Statement::DoWhileStatement(stmt) => {
+                let stmt = stmt.unbox();
+                let first = self.loc();
+                let test = self.loc();
+                let after = self.loc();
+                self.mark(first);
+                self.leaps.push(LeapEntry::Loop { break_loc: after, continue_loc: test, label });
+                self.explode_statement(stmt.body, None);
+                self.leaps.pop();
+                self.mark(test);
+                let test = self.explode(stmt.test);
+                self.jump_if(test, first);
+                self.mark(after);
+            }
+            Statement::ForStatement(stmt) => {
+                let stmt = stmt.unbox();
+                let head = self.loc();
+                let update = self.loc();
+                let after = self.loc();
+                match stmt.init {
+                    Some(ForStatementInit::Expression(init)) => {
+                        self.explode_expression(init, true);
+                    }
+                    Some(ForStatementInit::VariableDeclaration(decl)) => {
+                        self.emit(Statement::Declaration(Declaration::VariableDeclaration(decl)));
+                    }
+                    Some(ForStatementInit::UsingDeclaration(_)) | None => {}
+                }
+                self.mark(head);
+                if let Some(test) = stmt.test {
+                    let test = self.explode(test);
+                    self.jump_if_not(test, after);
+                }
+                self.leaps.push(LeapEntry::Loop { break_loc: after, continue_loc: update, label });
+                self.explode_statement(stmt.body, None);
+                self.leaps.pop();
+                self.mark(update);
+                if let Some(update) = stmt.update {
+                    self.explode_expression(update, true);
+                }
+                self.jump(head);
+                self.mark(after);
+            }
+            Statement::ForInStatement(stmt) => {
+                let stmt = stmt.unbox();
+                let ForStatementLeft::AssignmentTarget(left) = stmt.left else {
+                    unreachable!("declarations are hoisted out of `for in` statements")
+                };
+                let head = self.loc();
+                let after = self.loc();
+                // `_context.t0 = babelHelpers.regeneratorRuntime().keys(right)`
+                let keys = self.make_temp();
+                let right = self.explode(stmt.right);
+                let arguments = self.ast.new_vec_single(Argument::Expression(right));
+                self.emit_assign_temp(keys, runtime_call(&self.ctx, "keys", arguments));
+                self.mark(head);
+                // `if ((_context.t1 = _context.t0()).done) { ... }`
+                let info = self.make_temp();
+                let next = self.ast.call_expression(
+                    Span::default(),
+                    self.temp_expression(keys),
+                    self.ast.new_vec(),
+                    false,
+                    None,
+                );
+                let info_target = self.ast.simple_assignment_target_member_expression(
+                    self.context_member(&Self::temp_name(info)),
+                );
+                let next = self.ast.assignment_expression(
+                    Span::default(),
+                    AssignmentOperator::Assign,
+                    AssignmentTarget::SimpleAssignmentTarget(info_target),
+                    next,
+                );
+                let done = self.ast.static_member_expression(
+                    Span::default(),
+                    next,
+                    IdentifierName::new(Span::default(), "done".into()),
+                    false,
+                );
+                self.jump_if(done, after);
+                // `left = _context.t1.value`
+                let value = self.ast.static_member_expression(
+                    Span::default(),
+                    self.temp_expression(info),
+                    IdentifierName::new(Span::default(), "value".into()),
+                    false,
+                );
+                self.emit_expression(self.ast.assignment_expression(
+                    Span::default(),
+                    AssignmentOperator::Assign,
+                    left,
+                    value,
+                ));
+                self.leaps.push(LeapEntry::Loop { break_loc: after, continue_loc: head, label });
+                self.explode_statement(stmt.body, None);
+                self.leaps.pop();
+                self.jump(head);
+                self.mark(after);
+            }
+            Statement::BreakStatement(break_stmt) => {
+                match self.find_leap_location(break_stmt.label.as_ref(), false) {
+                    Some(loc) => {
+                        self.emit_abrupt_completion("break", Some(self.loc_expression(loc)));
+                    }
+                    None => self.emit(Statement::BreakStatement(break_stmt)),
+                }
+            }
+            Statement::ContinueStatement(continue_stmt) => {
+                match self.find_leap_location(continue_stmt.label.as_ref(), true) {
+                    Some(loc) => {
+                        self.emit_abrupt_completion("continue", Some(self.loc_expression(loc)));
+                    }
+                    None => self.emit(Statement::ContinueStatement(continue_stmt)),
+                }
+            }
+            Statement::SwitchStatement(stmt) => self.explode_switch_statement(stmt.unbox()),
+            Statement::IfStatement(stmt) => {
+                let stmt = stmt.unbox();
+                let else_loc = stmt.alternate.is_some().then(|| self.loc());
+                let after = self.loc();
+                let test = self.explode(stmt.test);
+                self.jump_if_not(test, else_loc.unwrap_or(after));
+                self.explode_statement(stmt.consequent, None);
+                if let (Some(else_loc), Some(alternate)) = (else_loc, stmt.alternate) {
+                    self.jump(after);
+                    self.mark(else_loc);
+                    self.explode_statement(alternate, None);
+                }
+                self.mark(after);
+            }
+            Statement::ReturnStatement(stmt) => {
+                let value = stmt.unbox().argument.map(|argument| self.explode(argument));
+                self.emit_abrupt_completion("return", value);
+            }
+            Statement::ThrowStatement(stmt) => {
+                let argument = self.explode(stmt.unbox().argument);
+                self.emit(self.ast.throw_statement(Span::default(), argument));
+            }
+            Statement::TryStatement(stmt) => self.explode_try_statement(stmt.unbox()),
+            // `for of` and `with` statements without a `yield` run as they are.
+            mut stmt => {
+                let mut rewriter = ReturnRewriter { emitter: self };
+                rewriter.visit_statement(&mut stmt);
+                self.emit(stmt);
+            }
+        }
+    }
+
+    /// The discriminant is matched against the case tests up front, then the cases are emitted
+    /// in order so that they fall through.
+    fn explode_switch_statement(&mut self, stmt: SwitchStatement<'a>) {
+        let discriminant = self.make_temp();
+        let value = self.explode(stmt.discriminant);
+        self.emit_assign_temp(discriminant, value);
+        let after = self.loc();
+        let default_loc = self.loc();
+
+        // `_context.t0 === a ? 1 : _context.t0 === b ? 2 : default`
+        let mut cases = stmt.cases;
+        let mut case_locs = vec![default_loc; cases.len()];
+        let mut condition = self.loc_expression(default_loc);
+        for (i, case) in cases.iter_mut().enumerate().rev() {
+            let Some(test) = case.test.take() else { continue };
+            let loc = self.loc();
+            case_locs[i] = loc;
+            let test = self.ast.binary_expression(
+                Span::default(),
+                self.temp_expression(discriminant),
+                BinaryOperator::StrictEquality,
+                test,
+            );
+            condition = self.ast.conditional_expression(
+                Span::default(),
+                test,
+                self.loc_expression(loc),
+                condition,
+            );
+        }
+        self.jump_to(condition);
+
+        self.leaps.push(LeapEntry::Switch { break_loc: after });
+        for (case, loc) in cases.into_iter().zip(case_locs) {
+            self.mark(loc);
+            for stmt in case.consequent {
+                self.explode_statement(stmt, None);
+            }
+        }
+        self.leaps.pop();
+        self.mark(after);
+        if self.locs[default_loc.0].is_none() {
+            self.mark(default_loc);
+        }
+    }
+
+    fn explode_try_statement(&mut self, stmt: TryStatement<'a>) {
+        let after = self.loc();
+        let catch_loc = stmt.handler.is_some().then(|| self.loc());
+        let finally_loc = stmt.finalizer.is_some().then(|| self.loc());
+        let try_loc = self.unmarked_current_loc();
+        self.try_entries.push(TryEntry { try_loc, catch_loc, finally_loc, after_loc: after });
+
+        self.update_context_prev_loc(try_loc);
+        self.explode_statement(self.ast.block_statement(stmt.block), None);
+
+        if let (Some(catch_loc), Some(handler)) = (catch_loc, stmt.handler) {
+            self.jump(finally_loc.unwrap_or(after));
+            self.mark(catch_loc);
+            self.update_context_prev_loc(catch_loc);
+
+            // `_context.t0 = _context["catch"](tryLoc)`
+            let mut handler = handler.unbox();
+            let callee = self.ast.computed_member_expression(
+                Span::default(),
+                self.ast.identifier_reference_expression(IdentifierReference::new(
+                    Span::default(),
+                    self.context.clone(),
+                )),
+                self.string("catch"),
+                false,
+            );
+            let arguments =
+                self.ast.new_vec_single(Argument::Expression(self.loc_expression(try_loc)));
+            let call = self.ast.call_expression(Span::default(), callee, arguments, false, None);
+            let symbol_id = handler.param.as_ref().and_then(|param| match &param.kind {
+                BindingPatternKind::BindingIdentifier(ident) => ident.symbol_id.get(),
+                _ => None,
+            });
+            if let Some(symbol_id) = symbol_id {
+                let temp = self.make_temp();
+                self.emit_assign_temp(temp, call);
+                let mut replacer = CatchParamReplacer { emitter: self, symbol_id, temp };
+                replacer.visit_statements(&mut handler.body.body);
+            } else {
+                self.emit_expression(call);
+            }
+            self.explode_statement(self.ast.block_statement(handler.body), None);
+        }
+
+        if let (Some(finally_loc), Some(finalizer)) = (finally_loc, stmt.finalizer) {
+            self.mark(finally_loc);
+            self.update_context_prev_loc(finally_loc);
+            self.explode_statement(self.ast.block_statement(finalizer), None);
+            // `return _context.finish(finallyLoc)`
+            let arguments =
+                self.ast.new_vec_single(Argument::Expression(self.loc_expression(finally_loc)));
+            let call = self.context_call("finish", arguments);
+            self.emit(self.ast.return_statement(Span::default(), Some(call)));
+        }
+
+        self.mark(after);
+    }
+
+    fn explode(&mut self, expr: Expression<'a>) -> Expression<'a> {
+        self.explode_expression(expr, false).expect("the result is used")
+    }
+
+    /// Explodes an expression and stores its result in a temporary variable, so that it is
+    /// evaluated before the `yield` of a sibling expression.
+    fn explode_via_temp(
+        &mut self,
+        temp: Option<Temp>,
+        expr: Expression<'a>,
+        ignore_result: bool,
+    ) -> Option<Expression<'a>> {
+        let result = self.explode_expression(expr, ignore_result)?;
+        if temp.is_some() || !result.is_literal() {
+            let temp = temp.unwrap_or_else(|| self.make_temp());
+            return Some(self.emit_assign_temp(temp, result));
+        }
+        Some(result)
+    }
+
+    fn explode_argument(&mut self, argument: Argument<'a>) -> Argument<'a> {
+        match argument {
+            Argument::SpreadElement(spread) => {
+                let argument = self.explode_via_temp(None, spread.unbox().argument, false);
+                let argument = argument.expect("the result is used");
+                Argument::SpreadElement(self.ast.spread_element(Span::default(), argument))
+            }
+            Argument::Expression(expr) => {
+                let expr = self.explode_via_temp(None, expr, false);
+                Argument::Expression(expr.expect("the result is used"))
+            }
+        }
+    }
+
+    /// Emits the parts of an expression which contain a `yield`, and returns what is left of it.
+    /// The result is emitted as a statement instead when `ignore_result` is set.
+    #[allow(clippy::too_many_lines)]
+    fn explode_expression(
+        &mut self,
+        expr: Expression<'a>,
+        ignore_result: bool,
+    ) -> Option<Expression<'a>> {
+        if !expression_contains_yield(&expr) {
+            return self.finish(expr, ignore_result);
+        }
+        let span = expr.span();
+        match expr {
+            Expression::ParenthesizedExpression(expr) => {
+                self.explode_expression(expr.unbox().expression, ignore_result)
+            }
+            Expression::MemberExpression(member) => {
+                let member = match member.unbox() {
+                    MemberExpression::ComputedMemberExpression(member) => {
+                        let (object, property) = if expression_contains_yield(&member.expression) {
+                            let object = self.explode_via_temp(None, member.object, false);
+                            let property = self.explode_via_temp(None, member.expression, false);
+                            (
+                                object.expect("the result is used"),
+                                property.expect("the result is used"),
+                            )
+                        } else {
+                            (self.explode(member.object), member.expression)
+                        };
+                        self.ast.computed_member(span, object, property, false)
+                    }
+                    MemberExpression::StaticMemberExpression(member) => {
+                        let object = self.explode(member.object);
+                        self.ast.static_member(span, object, member.property, false)
+                    }
+                    MemberExpression::PrivateFieldExpression(member) => {
+                        let object = self.explode(member.object);
+                        MemberExpression::PrivateFieldExpression(PrivateFieldExpression {
+                            span,
+                            object,
+                            field: member.field,
+                            optional: false,
+                        })
+                    }
+                };
+                self.finish(self.ast.member_expression(member), ignore_result)
+            }
+            Expression::CallExpression(call) => {
+                let call = call.unbox();
+                let has_leaping_args = call.arguments.iter().any(|argument| match argument {
+                    Argument::SpreadElement(spread) => expression_contains_yield(&spread.argument),
+                    Argument::Expression(expr) => expression_contains_yield(expr),
+                });
+                let mut this_arg = None;
+                let callee = match call.callee {
+                    // The arguments are evaluated after the callee, so the object of the callee
+                    // is kept in a temporary variable and passed as `this` with `.call`.
+                    Expression::MemberExpression(member) if has_leaping_args => {
+                        let object = self.make_temp();
+                        let member = match member.unbox() {
+                            MemberExpression::ComputedMemberExpression(member) => {
+                                self.explode_via_temp(Some(object), member.object, false);
+                                let property =
+                                    self.explode_via_temp(None, member.expression, false);
+                                let property = property.expect("the result is used");
+                                let object = self.temp_expression(object);
+                                self.ast.computed_member(span, object, property, false)
+                            }
+                            MemberExpression::StaticMemberExpression(member) => {
+                                self.explode_via_temp(Some(object), member.object, false);
+                                let object = self.temp_expression(object);
+                                self.ast.static_member(span, object, member.property, false)
+                            }
+                            MemberExpression::PrivateFieldExpression(member) => {
+                                self.explode_via_temp(Some(object), member.object, false);
+                                let object = self.temp_expression(object);
+                                MemberExpression::PrivateFieldExpression(PrivateFieldExpression {
+                                    span,
+                                    object,
+                                    field: member.field,
+                                    optional: false,
+                                })
+                            }
+                        };
+                        this_arg = Some(object);
+                        self.ast.static_member_expression(
+                            span,
+                            self.ast.member_expression(member),
+                            IdentifierName::new(Span::default(), "call".into()),
+                            false,
+                        )
+                    }
+                    callee @ Expression::MemberExpression(_) => self.explode(callee),
+                    callee => {
+                        let callee = self.explode_via_temp(None, callee, false);
+                        let callee = callee.expect("the result is used");
+                        // `(0, _context.t0)()` calls the function without `_context` as `this`.
+                        if matches!(callee, Expression::MemberExpression(_)) {
+                            let mut expressions = self.ast.new_vec_with_capacity(2);
+                            expressions.push(self.number(0));
+                            expressions.push(callee);
+                            self.ast.sequence_expression(Span::default(), expressions)
+                        } else {
+                            callee
+                        }
+                    }
+                };
+                let arguments = if has_leaping_args {
+                    let mut arguments = self.ast.new_vec_with_capacity(call.arguments.len() + 1);
+                    if let Some(this_arg) = this_arg {
+                        arguments.push(Argument::Expression(self.temp_expression(this_arg)));
+                    }
+                    for argument in call.arguments {
+                        arguments.push(self.explode_argument(argument));
+                    }
+                    arguments
+                } else {
+                    call.arguments
+                };
+                let call = self.ast.call_expression(span, callee, arguments, false, None);
+                self.finish(call, ignore_result)
+            }
+            Expression::NewExpression(expr) => {
+                let expr = expr.unbox();
+                let callee = self.explode_via_temp(None, expr.callee, false);
+                let mut arguments = self.ast.new_vec_with_capacity(expr.arguments.len());
+                for argument in expr.arguments {
+                    arguments.push(self.explode_argument(argument));
+                }
+                let callee = callee.expect("the result is used");
+                let expr = self.ast.new_expression(span, callee, arguments, None);
+                self.finish(expr, ignore_result)
+            }
+            Expression::ObjectExpression(expr) => {
+                let expr = expr.unbox();
+                let mut properties = self.ast.new_vec_with_capacity(expr.properties.len());
+                for property in expr.properties {
+                    properties.push(match property {
+                        ObjectPropertyKind::ObjectProperty(prop)
+                            if prop.kind == PropertyKind::Init && !prop.method =>
+                        {
+                            let prop = prop.unbox();
+                            let value = self.explode_via_temp(None, prop.value, false);
+                            ObjectPropertyKind::ObjectProperty(self.ast.object_property(
+                                prop.span,
+                                PropertyKind::Init,
+                                prop.key,
+                                value.expect("the result is used"),
+                                None,
+                                false,
+                                false,
+                                prop.computed,
+                            ))
+                        }
+                        ObjectPropertyKind::SpreadProperty(spread) => {
+                            let argument =
+                                self.explode_via_temp(None, spread.unbox().argument, false);
+                            let argument = argument.expect("the result is used");
+                            ObjectPropertyKind::SpreadProperty(
+                                self.ast.spread_element(Span::default(), argument),
+                            )
+                        }
+                        property @ ObjectPropertyKind::ObjectProperty(_) => property,
+                    });
+                }
+                let expr = self.ast.object_expression(span, properties, None);
+                self.finish(expr, ignore_result)
+            }
+            Expression::ArrayExpression(expr) => {
+                let expr = expr.unbox();
+                let mut elements = self.ast.new_vec_with_capacity(expr.elements.len());
+                for element in expr.elements {
+                    elements.push(match element {
+                        ArrayExpressionElement::SpreadElement(spread) => {
+                            let argument =
+                                self.explode_via_temp(None, spread.unbox().argument, false);
+                            let argument = argument.expect("the result is used");
+                            ArrayExpressionElement::SpreadElement(
+                                self.ast.spread_element(Span::default(), argument),
+                            )
+                        }
+                        ArrayExpressionElement::Expression(expr) => {
+                            let expr = self.explode_via_temp(None, expr, false);
+                            ArrayExpressionElement::Expression(expr.expect("the result is used"))
+                        }
+                        elision @ ArrayExpressionElement::Elision(_) => elision,
+                    });
+                }
+                let expr = self.ast.array_expression(span, elements, None);
+                self.finish(expr, ignore_result)
+            }
+            Expression::SequenceExpression(expr) => {
+                let expressions = expr.unbox().expressions;
+                let last = expressions.len() - 1;
+                let mut result = None;
+                for (i, expr) in expressions.into_iter().enumerate() {
+                    if i == last {
+                        result = self.explode_expression(expr, ignore_result);
+                    } else {
+                        self.explode_expression(expr, true);
+                    }
+                }
+                result
+            }
+            Expression::LogicalExpression(expr) if expr.operator != LogicalOperator::Coalesce => {
+                let expr = expr.unbox();
+                let after = self.loc();
+                let result = (!ignore_result).then(|| self.make_temp());
+                let left = self.explode_via_temp(result, expr.left, false);
+                let left = left.expect("the result is used");
+                if expr.operator == LogicalOperator::And {
+                    self.jump_if_not(left, after);
+                } else {
+                    self.jump_if(left, after);
+                }
+                self.explode_via_temp(result, expr.right, ignore_result);
+                self.mark(after);
+                result.map(|temp| self.temp_expression(temp))
+            }
+            Expression::ConditionalExpression(expr) => {
+                let expr = expr.unbox();
+                let else_loc = self.loc();
+                let after = self.loc();
+                let test = self.explode(expr.test);
+                self.jump_if_not(test, else_loc);
+                let result = (!ignore_result).then(|| self.make_temp());
+                self.explode_via_temp(result, expr.consequent, ignore_result);
+                self.jump(after);
+                self.mark(else_loc);
+                self.explode_via_temp(result, expr.alternate, ignore_result);
+                self.mark(after);
+                result.map(|temp| self.temp_expression(temp))
+            }
+            Expression::UnaryExpression(expr) => {
+                let expr = expr.unbox();
+                let argument = self.explode(expr.argument);
+                let expr = self.ast.unary_expression(span, expr.operator, argument);
+                self.finish(expr, ignore_result)
+            }
+            Expression::BinaryExpression(expr) => {
+                let expr = expr.unbox();
+                let left = self.explode_via_temp(None, expr.left, false);
+                let right = self.explode_via_temp(None, expr.right, false);
+                let expr = self.ast.binary_expression(
+                    span,
+                    left.expect("the result is used"),
+                    expr.operator,
+                    right.expect("the result is used"),
+                );
+                self.finish(expr, ignore_result)
+            }
+            Expression::AssignmentExpression(expr) => {
+                let expr = expr.unbox();
+                if expr.operator == AssignmentOperator::Assign {
+                    let right = self.explode(expr.right);
+                    let expr =
+                        self.ast.assignment_expression(span, expr.operator, expr.left, right);
+                    return self.finish(expr, ignore_result);
+                }
+                // `a += yield` -> `_context.t0 = a; ...; a = _context.t0 += _context.sent`, so that
+                // `a` is read before the `yield`.
+                let AssignmentTarget::SimpleAssignmentTarget(
+                    SimpleAssignmentTarget::AssignmentTargetIdentifier(ident),
+                ) = &expr.left
+                else {
+                    unreachable!("only identifiers are compound assigned a `yield`")
+                };
+                let value = self.ast.identifier_reference_expression(IdentifierReference::new(
+                    span,
+                    ident.name.clone(),
+                ));
+                let temp = self.make_temp();
+                self.emit_assign_temp(temp, value);
+                let right = self.explode(expr.right);
+                let temp_target = self.ast.simple_assignment_target_member_expression(
+                    self.context_member(&Self::temp_name(temp)),
+                );
+                let right = self.ast.assignment_expression(
+                    Span::default(),
+                    expr.operator,
+                    AssignmentTarget::SimpleAssignmentTarget(temp_target),
+                    right,
+                );
+                let expr = self.ast.assignment_expression(
+                    span,
+                    AssignmentOperator::Assign,
+                    expr.left,
+                    right,
+                );
+                self.finish(expr, ignore_result)
+            }
+            Expression::YieldExpression(expr) => {
+                let expr = expr.unbox();
+                let after = self.loc();
+                let mut argument = expr.argument.map(|argument| self.explode(argument));
+                if let (true, Some(argument)) = (expr.delegate, &mut argument) {
+                    let argument = self.ast.move_expression(argument);
+                    // `return _context.delegateYield(argument, "t0", after)`
+                    let result = self.make_temp();
+                    let mut arguments = self.ast.new_vec_with_capacity(3);
+                    arguments.push(Argument::Expression(argument));
+                    arguments.push(Argument::Expression(self.string(&Self::temp_name(result))));
+                    arguments.push(Argument::Expression(self.loc_expression(after)));
+                    let call = self.context_call("delegateYield", arguments);
+                    self.emit(self.ast.return_statement(span, Some(call)));
+                    self.mark(after);
+                    return (!ignore_result).then(|| self.temp_expression(result));
+                }
+                // `_context.next = after; return argument;`
+                self.emit_assign("next", self.loc_expression(after));
+                self.emit(self.ast.return_statement(span, argument));
+                self.mark(after);
+                // The sent value has no side effects.
+                (!ignore_result).then(|| self.context_property("sent"))
+            }
+            expr => self.finish(expr, ignore_result),
+        }
+    }
+
+    fn finish(&mut self, expr: Expression<'a>, ignore_result: bool) -> Option<Expression<'a>> {
+        if ignore_result {
+            self.emit_expression(expr);
+            None
+        } else {
+            Some(expr)
+        }
+    }
+
+    /// ```JavaScript
+    /// while (1) switch (_context.prev = _context.next) {
+    ///   case 0: ...
+    ///   case 3:
+    ///   case "end":
+    ///     return _context.stop();
+    /// }
+    /// ```
+    pub fn build_dispatch_loop(&mut self) -> Statement<'a> {
+        let span = Span::default();
+        let mut cases = self.ast.new_vec();
+        let mut already_ended = false;
+        let listing = std::mem::take(&mut self.listing);
+        let listing_len = listing.len();
+        for (i, stmt) in listing.into_iter().enumerate() {
+            if self.marked.contains(&i) {
+                cases.push(self.ast.switch_case(span, Some(self.number(i)), self.ast.new_vec()));
+                already_ended = false;
+            }
+            if !already_ended {
+                already_ended = matches!(
+                    stmt,
+                    Statement::BreakStatement(_)
+                        | Statement::ContinueStatement(_)
+                        | Statement::ReturnStatement(_)
+                        | Statement::ThrowStatement(_)
+                );
+                if let Some(case) = cases.last_mut() {
+                    case.consequent.push(stmt);
+                }
+            }
+        }
+        self.locs[self.final_loc.0] = Some(listing_len);
+        cases.push(self.ast.switch_case(span, Some(self.number(listing_len)), self.ast.new_vec()));
+        let stop = self.context_call("stop", self.ast.new_vec());
+        let stop = self.ast.new_vec_single(self.ast.return_statement(span, Some(stop)));
+        cases.push(self.ast.switch_case(span, Some(self.string("end")), stop));
+
+        let target =
+            self.ast.simple_assignment_target_member_expression(self.context_member("prev"));
+        let discriminant = self.ast.assignment_expression(
+            span,
+            AssignmentOperator::Assign,
+            AssignmentTarget::SimpleAssignmentTarget(target),
+            self.context_property("next"),
+        );
+        let switch = self.ast.switch_statement(span, discriminant, cases);
+        let mut stmt = self.ast.while_statement(span, self.number(1), switch);
+        LocResolver { emitter: self }.visit_statement(&mut stmt);
+        stmt
+    }
+
+    /// `[[tryLoc, catchLoc, finallyLoc, afterLoc]]`, or `None` without `try` statements.
+    pub fn build_try_locs_list(&mut self) -> Option<Expression<'a>> {
+        if self.try_entries.is_empty() {
+            return None;
+        }
+        let span = Span::default();
+        let mut entries = self.ast.new_vec_with_capacity(self.try_entries.len());
+        for entry in &self.try_entries {
+            let mut locs = self.ast.new_vec_with_capacity(4);
+            locs.push(ArrayExpressionElement::Expression(self.loc_expression(entry.try_loc)));
+            locs.push(entry.catch_loc.map_or(ArrayExpressionElement::Elision(span), |loc| {
+                ArrayExpressionElement::Expression(self.loc_expression(loc))
+            }));
+            if let Some(finally_loc) = entry.finally_loc {
+                locs.push(ArrayExpressionElement::Expression(self.loc_expression(finally_loc)));
+                locs.push(ArrayExpressionElement::Expression(self.loc_expression(entry.after_loc)));
+            }
+            let locs = self.ast.array_expression(span, locs, None);
+            entries.push(ArrayExpressionElement::Expression(locs));
+        }
+        let mut expr = self.ast.array_expression(span, entries, None);
+        LocResolver { emitter: self }.visit_expression(&mut expr);
+        Some(expr)
+    }
+}
+
+/// Replaces the location placeholders with the listing indices they were marked at.
+struct LocResolver<'e, 'a> {
+    emitter: &'e Emitter<'a>,
+}
+
+impl<'e, 'a> VisitMut<'a> for LocResolver<'e, 'a> {
+    fn visit_number_literal(&mut self, lit: &mut NumberLiteral<'a>) {
+        if lit.value >= 0.0 {
+            return;
+        }
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let loc = (-lit.value) as usize - 1;
+        let index = self.emitter.locs[loc].expect("every location is marked");
+        #[allow(clippy::cast_precision_loss)]
+        {
+            lit.value = index as f64;
+        }
+        lit.raw = self.emitter.ast.new_str(&index.to_string());
+    }
+
+    // Only the locations in the listing are placeholders.
+    fn visit_function(&mut self, _func: &mut Function<'a>) {}
+
+    fn visit_arrow_expression(&mut self, _expr: &mut ArrowExpression<'a>) {}
+}
+
+/// `return x` -> `return _context.abrupt("return", x)` in statements which are not exploded.
+struct ReturnRewriter<'e, 'a> {
+    emitter: &'e Emitter<'a>,
+}
+
+impl<'e, 'a> VisitMut<'a> for ReturnRewriter<'e, 'a> {
+    fn visit_return_statement(&mut self, stmt: &mut ReturnStatement<'a>) {
+        let ast = &self.emitter.ast;
+        let mut arguments = ast.new_vec_with_capacity(2);
+        arguments.push(Argument::Expression(self.emitter.string("return")));
+        if let Some(argument) = stmt.argument.take() {
+            arguments.push(Argument::Expression(argument));
+        }
+        stmt.argument = Some(self.emitter.context_call("abrupt", arguments));
+    }
+
+    fn visit_function(&mut self, _func: &mut Function<'a>) {}
+
+    fn visit_arrow_expression(&mut self, _expr: &mut ArrowExpression<'a>) {}
+}
+
+/// Replaces references to the parameter of a `catch` clause with the temporary variable holding
+/// the caught error.
+struct CatchParamReplacer<'e, 'a> {
+    emitter: &'e Emitter<'a>,
+    symbol_id: SymbolId,
+    temp: Temp,
+}
+
+impl<'e, 'a> CatchParamReplacer<'e, 'a> {
+    fn is_param(&self, ident: &IdentifierReference) -> bool {
+        ident.reference_id.get().is_some_and(|reference_id| {
+            self.emitter.ctx.symbols().get_reference(reference_id).symbol_id()
+                == Some(self.symbol_id)
+        })
+    }
+}
+
+impl<'e, 'a> VisitMut<'a> for CatchParamReplacer<'e, 'a> {
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        match expr {
+            Expression::Identifier(ident) if self.is_param(ident) => {
+                *expr = self.emitter.temp_expression(self.temp);
+            }
+            _ => self.visit_expression_match(expr),
+        }
+    }
+
+    fn visit_simple_assignment_target(&mut self, target: &mut SimpleAssignmentTarget<'a>) {
+        match target {
+            SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) if self.is_param(ident) => {
+                let member = self.emitter.context_member(&Emitter::temp_name(self.temp));
+                *target = self.emitter.ast.simple_assignment_target_member_expression(member);
+            }
+            SimpleAssignmentTarget::MemberAssignmentTarget(member) => {
+                self.visit_member_expression(member);
+            }
+            _ => {}
+        }
+    }
+}
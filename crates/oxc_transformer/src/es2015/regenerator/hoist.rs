@@ -0,0 +1,180 @@
+//! Hoisting of declarations out of generator bodies.
+//!
+//! <https://github.com/facebook/regenerator/blob/main/packages/transform/src/hoist.js>
+
+use std::rc::Rc;
+
+use oxc_allocator::Vec;
+use oxc_ast::{ast::*, AstBuilder, VisitMut};
+use oxc_span::{Atom, GetSpan, Span};
+use oxc_syntax::operator::AssignmentOperator;
+
+/// Turns the declarations of a generator body into assignments, so that the bindings survive
+/// between the steps of the state machine.
+///
+/// * `var a = 1, b;` -> `a = 1;`
+/// * `function f() {}` is moved out of the body
+/// * `class A {}` -> `A = class A {};`
+pub struct Hoister<'a> {
+    ast: Rc<AstBuilder<'a>>,
+    /// Names of the hoisted variables, in order of declaration.
+    pub names: std::vec::Vec<Atom>,
+    pub functions: Vec<'a, Statement<'a>>,
+}
+
+impl<'a> Hoister<'a> {
+    pub fn new(ast: Rc<AstBuilder<'a>>) -> Self {
+        let functions = ast.new_vec();
+        Self { ast, names: vec![], functions }
+    }
+
+    fn add_name(&mut self, name: &Atom) {
+        if !self.names.contains(name) {
+            self.names.push(name.clone());
+        }
+    }
+
+    /// `a = 1, b = 2` for the initialized declarators of `var a = 1, b = 2, c`.
+    fn declaration_to_expression(
+        &mut self,
+        decl: &mut VariableDeclaration<'a>,
+    ) -> Option<Expression<'a>> {
+        let mut expressions = self.ast.new_vec();
+        for declarator in decl.declarations.iter_mut() {
+            let BindingPatternKind::BindingIdentifier(ident) = &declarator.id.kind else {
+                continue;
+            };
+            let name = ident.name.clone();
+            self.add_name(&name);
+            if let Some(init) = declarator.init.take() {
+                expressions.push(self.assignment(name, init));
+            }
+        }
+        match expressions.len() {
+            0 => None,
+            1 => expressions.pop(),
+            _ => Some(self.ast.sequence_expression(Span::default(), expressions)),
+        }
+    }
+
+    fn assignment(&self, name: Atom, value: Expression<'a>) -> Expression<'a> {
+        let target = self
+            .ast
+            .simple_assignment_target_identifier(IdentifierReference::new(Span::default(), name));
+        self.ast.assignment_expression(
+            Span::default(),
+            AssignmentOperator::Assign,
+            AssignmentTarget::SimpleAssignmentTarget(target),
+            value,
+        )
+    }
+}
+
+impl<'a> VisitMut<'a> for Hoister<'a> {
+    fn visit_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>) {
+        for stmt in stmts.iter_mut() {
+            self.visit_statement(stmt);
+        }
+        stmts.retain(|stmt| !matches!(stmt, Statement::EmptyStatement(_)));
+    }
+
+    fn visit_statement(&mut self, stmt: &mut Statement<'a>) {
+        let Statement::Declaration(decl) = stmt else {
+            self.visit_statement_match(stmt);
+            return;
+        };
+        let span = decl.span();
+        let expr = match decl {
+            Declaration::VariableDeclaration(decl) => {
+                let mut expr = self.declaration_to_expression(decl);
+                if let Some(expr) = &mut expr {
+                    self.visit_expression(expr);
+                }
+                expr
+            }
+            Declaration::FunctionDeclaration(_) => {
+                let empty = self.ast.empty_statement(span);
+                self.functions.push(std::mem::replace(stmt, empty));
+                return;
+            }
+            Declaration::ClassDeclaration(class) => {
+                let Some(id) = &class.id else { return };
+                let name = id.name.clone();
+                self.add_name(&name);
+                let empty = self.ast.empty_statement(span);
+                let Statement::Declaration(Declaration::ClassDeclaration(mut class)) =
+                    std::mem::replace(stmt, empty)
+                else {
+                    unreachable!()
+                };
+                class.r#type = ClassType::ClassExpression;
+                Some(self.assignment(name, self.ast.class_expression(class)))
+            }
+            _ => return,
+        };
+        *stmt = match expr {
+            Some(expr) => self.ast.expression_statement(span, expr),
+            None => self.ast.empty_statement(span),
+        };
+    }
+
+    fn visit_for_statement(&mut self, stmt: &mut ForStatement<'a>) {
+        if let Some(ForStatementInit::VariableDeclaration(decl)) = &mut stmt.init {
+            let init = self.declaration_to_expression(decl);
+            stmt.init = init.map(ForStatementInit::Expression);
+        }
+        if let Some(init) = &mut stmt.init {
+            self.visit_for_statement_init(init);
+        }
+        if let Some(test) = &mut stmt.test {
+            self.visit_expression(test);
+        }
+        if let Some(update) = &mut stmt.update {
+            self.visit_expression(update);
+        }
+        self.visit_statement(&mut stmt.body);
+    }
+
+    /// `for (var x in y)` -> `for (x in y)`
+    fn visit_for_statement_left(&mut self, left: &mut ForStatementLeft<'a>) {
+        if let ForStatementLeft::VariableDeclaration(decl) = left {
+            let Some(BindingPatternKind::BindingIdentifier(ident)) =
+                decl.declarations.first().map(|declarator| &declarator.id.kind)
+            else {
+                return;
+            };
+            let name = ident.name.clone();
+            self.add_name(&name);
+            let target = self.ast.simple_assignment_target_identifier(IdentifierReference::new(
+                Span::default(),
+                name,
+            ));
+            *left = ForStatementLeft::AssignmentTarget(AssignmentTarget::SimpleAssignmentTarget(
+                target,
+            ));
+        }
+    }
+
+    // Nested functions and classes have their own declarations.
+    fn visit_function(&mut self, _func: &mut Function<'a>) {}
+
+    fn visit_arrow_expression(&mut self, _expr: &mut ArrowExpression<'a>) {}
+
+    fn visit_class(&mut self, _class: &mut Class<'a>) {}
+}
+
+/// Replaces `arguments` of the generator with a variable, since the state machine is a function
+/// of its own.
+pub struct ArgumentsReplacer {
+    pub name: Atom,
+}
+
+impl<'a> VisitMut<'a> for ArgumentsReplacer {
+    fn visit_identifier_reference(&mut self, ident: &mut IdentifierReference) {
+        if ident.name == "arguments" {
+            ident.name = self.name.clone();
+        }
+    }
+
+    fn visit_function(&mut self, _func: &mut Function<'a>) {}
+}
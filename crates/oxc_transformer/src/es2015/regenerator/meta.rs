@@ -0,0 +1,196 @@
+//! Analysis of generator bodies.
+//!
+//! <https://github.com/facebook/regenerator/blob/main/packages/transform/src/meta.js>
+
+use oxc_ast::{ast::*, AstKind, Visit};
+use oxc_syntax::operator::{AssignmentOperator, LogicalOperator};
+
+/// Whether an expression contains a `yield` of the current generator.
+pub fn expression_contains_yield(expr: &Expression) -> bool {
+    let mut finder = LeapFinder::default();
+    finder.visit_expression(expr);
+    finder.found
+}
+
+/// Whether a statement contains a `yield`, `break`, `continue`, `return` or `throw` of the current
+/// generator. Such statements are exploded into the state machine.
+pub fn statement_contains_leap(stmt: &Statement) -> bool {
+    let mut finder = LeapFinder::default();
+    finder.visit_statement(stmt);
+    finder.found
+}
+
+#[derive(Default)]
+struct LeapFinder {
+    function_depth: u32,
+    found: bool,
+}
+
+impl<'a> Visit<'a> for LeapFinder {
+    fn enter_node(&mut self, kind: AstKind<'a>) {
+        match kind {
+            AstKind::Function(_) | AstKind::ArrowExpression(_) => self.function_depth += 1,
+            AstKind::YieldExpression(_)
+            | AstKind::BreakStatement(_)
+            | AstKind::ContinueStatement(_)
+            | AstKind::ReturnStatement(_)
+            | AstKind::ThrowStatement(_) => self.found |= self.function_depth == 0,
+            _ => {}
+        }
+    }
+
+    fn leave_node(&mut self, kind: AstKind<'a>) {
+        if matches!(kind, AstKind::Function(_) | AstKind::ArrowExpression(_)) {
+            self.function_depth -= 1;
+        }
+    }
+}
+
+/// Facts about a generator body which decide whether and how it can be transformed.
+#[derive(Default)]
+pub struct GeneratorBody<'a> {
+    stack: Vec<AstKind<'a>>,
+    function_depth: u32,
+    /// Depth of functions which have their own `this`, `arguments` and `super`.
+    this_depth: u32,
+    pub uses_this: bool,
+    pub uses_arguments: bool,
+    /// The body contains something the state machine cannot express, e.g. a `yield` inside of a
+    /// `for of` loop or a destructuring pattern.
+    pub unsupported: bool,
+}
+
+impl<'a> GeneratorBody<'a> {
+    pub fn new(body: &FunctionBody<'a>) -> Self {
+        let mut generator_body = Self::default();
+        generator_body.visit_function_body(body);
+        generator_body
+    }
+
+    /// Whether the current `yield` is nested in a node which cannot be exploded.
+    fn is_yield_supported(&self) -> bool {
+        self.stack.iter().all(|kind| match kind {
+            AstKind::ForOfStatement(_)
+            | AstKind::WithStatement(_)
+            | AstKind::ChainExpression(_)
+            | AstKind::TaggedTemplateExpression(_)
+            | AstKind::TemplateLiteral(_)
+            | AstKind::Class(_)
+            | AstKind::PropertyKey(_)
+            | AstKind::AssignmentTarget(_)
+            | AstKind::SimpleAssignmentTarget(_)
+            | AstKind::AssignmentTargetWithDefault(_)
+            | AstKind::ArrayPattern(_)
+            | AstKind::ObjectPattern(_)
+            | AstKind::AssignmentPattern(_)
+            | AstKind::AwaitExpression(_)
+            | AstKind::JSXElement(_)
+            | AstKind::JSXFragment(_)
+            | AstKind::TSAsExpression(_)
+            | AstKind::TSSatisfiesExpression(_)
+            | AstKind::TSNonNullExpression(_) => false,
+            AstKind::LogicalExpression(expr) => expr.operator != LogicalOperator::Coalesce,
+            // `obj.x += yield` would need to evaluate `obj` twice, and `x ||= yield` must not
+            // always evaluate the `yield`.
+            AstKind::AssignmentExpression(expr) => {
+                expr.operator == AssignmentOperator::Assign
+                    || (!expr.operator.is_logical()
+                        && matches!(
+                            expr.left,
+                            AssignmentTarget::SimpleAssignmentTarget(
+                                SimpleAssignmentTarget::AssignmentTargetIdentifier(_)
+                            )
+                        ))
+            }
+            _ => true,
+        })
+    }
+
+    /// Whether a `break` or `continue` jumps out of a `for of` or `with` statement, which cannot
+    /// be exploded.
+    fn is_jump_supported(&self, label: Option<&LabelIdentifier>, is_continue: bool) -> bool {
+        for (i, kind) in self.stack.iter().enumerate().rev() {
+            let is_target = label.map_or_else(
+                || {
+                    kind.is_iteration_statement()
+                        || (!is_continue && matches!(kind, AstKind::SwitchStatement(_)))
+                },
+                |label| {
+                    matches!(
+                        kind,
+                        AstKind::LabeledStatement(stmt) if stmt.label.name == label.name
+                    )
+                },
+            );
+            if is_target {
+                return true;
+            }
+            if matches!(kind, AstKind::ForOfStatement(_) | AstKind::WithStatement(_)) {
+                // `label: for (x of y) { break label; }` targets the loop itself.
+                return label.is_some_and(|label| {
+                    matches!(
+                        self.stack.get(i.wrapping_sub(1)),
+                        Some(AstKind::LabeledStatement(stmt)) if stmt.label.name == label.name
+                    )
+                });
+            }
+        }
+        true
+    }
+}
+
+impl<'a> Visit<'a> for GeneratorBody<'a> {
+    fn enter_node(&mut self, kind: AstKind<'a>) {
+        match kind {
+            AstKind::Function(_) => {
+                self.function_depth += 1;
+                self.this_depth += 1;
+            }
+            AstKind::ArrowExpression(_) => self.function_depth += 1,
+            AstKind::ThisExpression(_) if self.this_depth == 0 => self.uses_this = true,
+            AstKind::Super(_) | AstKind::MetaProperty(_) if self.this_depth == 0 => {
+                self.unsupported = true;
+            }
+            AstKind::IdentifierReference(ident)
+                if self.this_depth == 0 && ident.name == "arguments" =>
+            {
+                self.uses_arguments = true;
+            }
+            _ if self.function_depth > 0 => {}
+            AstKind::YieldExpression(_) => self.unsupported |= !self.is_yield_supported(),
+            AstKind::BreakStatement(stmt) => {
+                self.unsupported |= !self.is_jump_supported(stmt.label.as_ref(), false);
+            }
+            AstKind::ContinueStatement(stmt) => {
+                self.unsupported |= !self.is_jump_supported(stmt.label.as_ref(), true);
+            }
+            // Declarations are hoisted out of the state machine, which is only possible for identifiers.
+            AstKind::VariableDeclarator(decl) => {
+                self.unsupported |= !decl.id.kind.is_binding_identifier();
+            }
+            AstKind::CatchClause(clause) => {
+                self.unsupported |=
+                    clause.param.as_ref().is_some_and(|param| !param.kind.is_binding_identifier());
+            }
+            // Case tests are evaluated up front to pick the case to jump to.
+            AstKind::SwitchCase(case) => {
+                self.unsupported |= case.test.as_ref().is_some_and(expression_contains_yield);
+            }
+            AstKind::UsingDeclaration(_) => self.unsupported = true,
+            _ => {}
+        }
+        self.stack.push(kind);
+    }
+
+    fn leave_node(&mut self, kind: AstKind<'a>) {
+        self.stack.pop();
+        match kind {
+            AstKind::Function(_) => {
+                self.function_depth -= 1;
+                self.this_depth -= 1;
+            }
+            AstKind::ArrowExpression(_) => self.function_depth -= 1,
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,399 @@
+mod emit;
+mod hoist;
+mod meta;
+
+use std::{mem, rc::Rc};
+
+use oxc_allocator::Vec;
+use oxc_ast::{ast::*, AstBuilder, VisitMut};
+use oxc_span::{Atom, Span};
+
+use self::{
+    emit::Emitter,
+    hoist::{ArgumentsReplacer, Hoister},
+    meta::GeneratorBody,
+};
+use crate::{context::TransformerCtx, options::TransformOptions, utils::CreateVars};
+
+/// ES2015: Generator Functions
+///
+/// Lowers generator functions to state machines driven by `regenerator-runtime`.
+///
+/// Block scoped declarations are hoisted to the generator function as `var`s, so they should be
+/// lowered by the block scoping transform first. Generators with a `yield` inside of `for of`
+/// loops, templates, optional chains or destructuring patterns are left as they are.
+///
+/// References:
+/// * <https://babel.dev/docs/babel-plugin-transform-regenerator>
+/// * <https://github.com/facebook/regenerator/tree/main/packages/transform>
+pub struct Regenerator<'a> {
+    ast: Rc<AstBuilder<'a>>,
+    ctx: TransformerCtx<'a>,
+    vars: Vec<'a, VariableDeclarator<'a>>,
+    /// Name of the generator function which was just transformed, if it is a function expression
+    /// which still has to be marked.
+    marked_expression: Option<Atom>,
+}
+
+impl<'a> CreateVars<'a> for Regenerator<'a> {
+    fn ctx(&self) -> &TransformerCtx<'a> {
+        &self.ctx
+    }
+
+    fn vars_mut(&mut self) -> &mut Vec<'a, VariableDeclarator<'a>> {
+        &mut self.vars
+    }
+}
+
+/// `babelHelpers.regeneratorRuntime().name(arguments)`
+fn runtime_call<'a>(
+    ctx: &TransformerCtx<'a>,
+    name: &str,
+    arguments: Vec<'a, Argument<'a>>,
+) -> Expression<'a> {
+    let runtime = ctx.helper_call("regeneratorRuntime", ctx.ast.new_vec());
+    let property = IdentifierName::new(Span::default(), name.into());
+    let callee = ctx.ast.static_member_expression(Span::default(), runtime, property, false);
+    ctx.ast.call_expression(Span::default(), callee, arguments, false, None)
+}
+
+impl<'a> Regenerator<'a> {
+    pub fn new(
+        ast: Rc<AstBuilder<'a>>,
+        ctx: TransformerCtx<'a>,
+        options: &TransformOptions,
+    ) -> Option<Self> {
+        options.regenerator.then(|| {
+            let vars = ast.new_vec();
+            Self { ast, ctx, vars, marked_expression: None }
+        })
+    }
+
+    /// ```JavaScript
+    /// function* f() { yield 1; }
+    /// // =>
+    /// var _marked = babelHelpers.regeneratorRuntime().mark(f);
+    /// function f() {
+    ///   return babelHelpers.regeneratorRuntime().wrap(function f$(_context) {
+    ///     while (1) switch (_context.prev = _context.next) {
+    ///       case 0:
+    ///         _context.next = 2;
+    ///         return 1;
+    ///       case 2:
+    ///       case "end":
+    ///         return _context.stop();
+    ///     }
+    ///   }, _marked);
+    /// }
+    /// ```
+    pub fn leave_function(&mut self, func: &mut Function<'a>) {
+        self.marked_expression = None;
+        if !func.generator || func.r#async {
+            return;
+        }
+        let Some(body) = &mut func.body else { return };
+        let generator_body = GeneratorBody::new(body);
+        if generator_body.unsupported {
+            return;
+        }
+        let span = Span::default();
+
+        // The outer function of the state machine, which becomes its prototype.
+        let outer_fn = match (&func.r#type, &func.id) {
+            (FunctionType::FunctionDeclaration, Some(id)) => {
+                let marked = self.create_new_named_var("marked");
+                let name = self.ast.identifier_reference_expression(IdentifierReference::new(
+                    span,
+                    id.name.clone(),
+                ));
+                let arguments = self.ast.new_vec_single(Argument::Expression(name));
+                if let Some(decl) = self.vars.last_mut() {
+                    decl.init = Some(runtime_call(&self.ctx, "mark", arguments));
+                }
+                Some(self.ast.identifier_reference_expression(marked))
+            }
+            (FunctionType::FunctionExpression, id) => {
+                let name =
+                    id.as_ref().map_or_else(|| self.generate_uid("callee"), |id| id.name.clone());
+                self.marked_expression = Some(name);
+                None
+            }
+            _ => None,
+        };
+        let inner_name = match (&func.id, &self.marked_expression) {
+            (Some(id), _) => id.name.clone(),
+            (None, Some(name)) => name.clone(),
+            (None, None) => "callee".into(),
+        };
+        let inner_name = Atom::from(format!("{inner_name}$"));
+        let context = self.generate_uid("context");
+
+        let mut statements = mem::replace(&mut body.statements, self.ast.new_vec());
+        let args = generator_body.uses_arguments.then(|| {
+            let name = self.generate_uid("args");
+            let mut replacer = ArgumentsReplacer { name: name.clone() };
+            replacer.visit_statements(&mut statements);
+            name
+        });
+        let mut hoister = Hoister::new(Rc::clone(&self.ast));
+        hoister.visit_statements(&mut statements);
+
+        let mut emitter = Emitter::new(Rc::clone(&self.ast), self.ctx.clone(), context.clone());
+        for stmt in statements {
+            emitter.explode_statement(stmt, None);
+        }
+        let dispatch_loop = emitter.build_dispatch_loop();
+        let try_locs_list = emitter.build_try_locs_list();
+
+        // function f$(_context) { while (1) switch (...) {} }
+        let param = self.ast.binding_pattern(
+            self.ast.binding_pattern_identifier(BindingIdentifier::new(span, context)),
+            None,
+            false,
+        );
+        let params = self.ast.formal_parameters(
+            span,
+            FormalParameterKind::FormalParameter,
+            self.ast.new_vec_single(self.ast.formal_parameter(
+                span,
+                param,
+                None,
+                false,
+                self.ast.new_vec(),
+            )),
+            None,
+        );
+        let inner_body = self.ast.function_body(
+            span,
+            self.ast.new_vec(),
+            self.ast.new_vec_single(dispatch_loop),
+        );
+        let inner_fn = self.ast.function(
+            FunctionType::FunctionExpression,
+            span,
+            Some(BindingIdentifier::new(span, inner_name)),
+            false,
+            false,
+            false,
+            params,
+            Some(inner_body),
+            None,
+            None,
+            Modifiers::empty(),
+        );
+
+        // `wrap(innerFn, outerFn, self, tryLocsList)`, without trailing nulls
+        let null = || self.ast.literal_null_expression(NullLiteral::new(span));
+        let mut arguments = self.ast.new_vec_with_capacity(4);
+        arguments.push(Argument::Expression(self.ast.function_expression(inner_fn)));
+        arguments.push(Argument::Expression(outer_fn.unwrap_or_else(null)));
+        arguments.push(Argument::Expression(if generator_body.uses_this {
+            self.ast.this_expression(span)
+        } else {
+            null()
+        }));
+        arguments.push(Argument::Expression(try_locs_list.unwrap_or_else(null)));
+        while matches!(arguments.last(), Some(Argument::Expression(Expression::NullLiteral(_)))) {
+            arguments.pop();
+        }
+        let wrap = runtime_call(&self.ctx, "wrap", arguments);
+
+        // var hoisted, _args = arguments;
+        let mut declarations = self.ast.new_vec();
+        for name in hoister.names {
+            declarations.push(self.declarator(name, None));
+        }
+        if let Some(args) = args {
+            let arguments = IdentifierReference::new(span, "arguments".into());
+            let arguments = self.ast.identifier_reference_expression(arguments);
+            declarations.push(self.declarator(args, Some(arguments)));
+        }
+        if !declarations.is_empty() {
+            let kind = VariableDeclarationKind::Var;
+            let decl = self.ast.variable_declaration(span, kind, declarations, Modifiers::empty());
+            body.statements.push(Statement::Declaration(Declaration::VariableDeclaration(decl)));
+        }
+        body.statements.extend(hoister.functions);
+        body.statements.push(self.ast.return_statement(span, Some(wrap)));
+        func.generator = false;
+    }
+
+    /// `function* () {}` -> `babelHelpers.regeneratorRuntime().mark(function _callee() {})`
+    pub fn transform_expression(&mut self, expr: &mut Expression<'a>) {
+        let Some(name) = self.marked_expression.take() else { return };
+        let Expression::FunctionExpression(func) = expr else { return };
+        if func.id.is_none() {
+            func.id = Some(BindingIdentifier::new(Span::default(), name.clone()));
+        }
+        // The function expression is its own outer function.
+        let outer_fn = IdentifierReference::new(Span::default(), name);
+        let outer_fn = Argument::Expression(self.ast.identifier_reference_expression(outer_fn));
+        if let Some(Statement::ReturnStatement(stmt)) =
+            func.body.as_mut().and_then(|body| body.statements.last_mut())
+        {
+            if let Some(Expression::CallExpression(call)) = &mut stmt.argument {
+                if let Some(argument) = call.arguments.get_mut(1) {
+                    *argument = outer_fn;
+                } else {
+                    call.arguments.push(outer_fn);
+                }
+            }
+        }
+        let func = self.ast.move_expression(expr);
+        let arguments = self.ast.new_vec_single(Argument::Expression(func));
+        *expr = runtime_call(&self.ctx, "mark", arguments);
+    }
+
+    /// `{ *f() {} }` -> `{ f: babelHelpers.regeneratorRuntime().mark(function f() {}) }`
+    #[allow(clippy::unused_self)]
+    pub fn transform_object_property(&self, prop: &mut ObjectProperty<'a>) {
+        if prop.method && !matches!(prop.value, Expression::FunctionExpression(_)) {
+            prop.method = false;
+        }
+    }
+
+    fn generate_uid(&self, name: &str) -> Atom {
+        let name = self.ctx.scopes().generate_uid(name);
+        self.ctx.add_binding(name.clone());
+        name
+    }
+
+    fn declarator(&self, name: Atom, init: Option<Expression<'a>>) -> VariableDeclarator<'a> {
+        let ident =
+            self.ast.binding_pattern_identifier(BindingIdentifier::new(Span::default(), name));
+        let id = self.ast.binding_pattern(ident, None, false);
+        self.ast.variable_declarator(Span::default(), VariableDeclarationKind::Var, id, init, false)
+    }
+}
+
+#[test]
+fn test() {
+    use crate::{options::TransformOptions, tester::Tester};
+
+    let options = TransformOptions { regenerator: true, ..TransformOptions::default() };
+    Tester::new("test.js", options).test(&[
+        (
+            "function* f() { yield 1; }",
+            r#"
+var _marked = babelHelpers.regeneratorRuntime().mark(f);
+function f() {
+  return babelHelpers.regeneratorRuntime().wrap(function f$(_context) {
+    while (1) switch (_context.prev = _context.next) {
+      case 0:
+        _context.next = 2;
+        return 1;
+      case 2:
+      case "end":
+        return _context.stop();
+    }
+  }, _marked);
+}
+"#,
+        ),
+        (
+            "var g = function* () { var x = yield 1; return x + 1; };",
+            r#"
+var g = babelHelpers.regeneratorRuntime().mark(function _callee() {
+  var x;
+  return babelHelpers.regeneratorRuntime().wrap(function _callee$(_context) {
+    while (1) switch (_context.prev = _context.next) {
+      case 0:
+        _context.next = 2;
+        return 1;
+      case 2:
+        x = _context.sent;
+        return _context.abrupt("return", x + 1);
+      case 4:
+      case "end":
+        return _context.stop();
+    }
+  }, _callee);
+});
+"#,
+        ),
+        (
+            "function* f() { for (var i = 0; i < 3; i++) { yield i; } }",
+            r#"
+var _marked = babelHelpers.regeneratorRuntime().mark(f);
+function f() {
+  var i;
+  return babelHelpers.regeneratorRuntime().wrap(function f$(_context) {
+    while (1) switch (_context.prev = _context.next) {
+      case 0:
+        i = 0;
+      case 1:
+        if (!(i < 3)) {
+          _context.next = 7;
+          break;
+        }
+        _context.next = 4;
+        return i;
+      case 4:
+        i++;
+        _context.next = 1;
+        break;
+      case 7:
+      case "end":
+        return _context.stop();
+    }
+  }, _marked);
+}
+"#,
+        ),
+        (
+            "function* f() { try { yield 1; } catch (e) { console.log(e); } }",
+            r#"
+var _marked = babelHelpers.regeneratorRuntime().mark(f);
+function f() {
+  return babelHelpers.regeneratorRuntime().wrap(function f$(_context) {
+    while (1) switch (_context.prev = _context.next) {
+      case 0:
+        _context.prev = 0;
+        _context.next = 3;
+        return 1;
+      case 3:
+        _context.next = 8;
+        break;
+      case 5:
+        _context.prev = 5;
+        _context.t0 = _context["catch"](0);
+        console.log(_context.t0);
+      case 8:
+      case "end":
+        return _context.stop();
+    }
+  }, _marked, null, [[0, 5]]);
+}
+"#,
+        ),
+        (
+            "function* f() { console.log(this, arguments, yield* g()); }",
+            r#"
+var _marked = babelHelpers.regeneratorRuntime().mark(f);
+function f() {
+  var _args = arguments;
+  return babelHelpers.regeneratorRuntime().wrap(function f$(_context) {
+    while (1) switch (_context.prev = _context.next) {
+      case 0:
+        _context.t0 = console;
+        _context.t1 = this;
+        _context.t2 = _args;
+        return _context.delegateYield(g(), "t3", 4);
+      case 4:
+        _context.t4 = _context.t3;
+        _context.t0.log.call(_context.t0, _context.t1, _context.t2, _context.t4);
+      case 6:
+      case "end":
+        return _context.stop();
+    }
+  }, _marked, this);
+}
+"#,
+        ),
+        // A `yield` inside of `for of` is not supported.
+        (
+            "function* f() { for (var x of y) yield x; }",
+            "function* f() { for (var x of y) yield x; }",
+        ),
+    ]);
+}
@@ -3,7 +3,10 @@ use std::rc::Rc;
 use oxc_ast::{ast::*, AstBuilder};
 use oxc_span::GetSpan;
 
-use crate::options::{TransformOptions, TransformTarget};
+use crate::{
+    options::{TransformOptions, TransformTarget},
+    targets::Feature,
+};
 
 /// ES2015: Shorthand Properties
 ///
@@ -16,8 +19,10 @@ pub struct ShorthandProperties<'a> {
 
 impl<'a> ShorthandProperties<'a> {
     pub fn new(ast: Rc<AstBuilder<'a>>, options: &TransformOptions) -> Option<Self> {
-        (options.target < TransformTarget::ES2015 || options.shorthand_properties)
-            .then(|| Self { ast })
+        (options.target < TransformTarget::ES2015
+            || options.shorthand_properties
+            || options.targets.needs_transform(Feature::ShorthandProperties))
+        .then(|| Self { ast })
     }
 
     pub fn transform_object_property<'b>(&mut self, obj_prop: &'b mut ObjectProperty<'a>) {
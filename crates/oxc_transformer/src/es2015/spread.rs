@@ -0,0 +1,299 @@
+use std::rc::Rc;
+
+use oxc_allocator::Vec;
+use oxc_ast::{ast::*, AstBuilder};
+use oxc_span::Span;
+use oxc_syntax::operator::AssignmentOperator;
+use serde::Deserialize;
+
+use crate::{
+    context::TransformerCtx,
+    options::{TransformOptions, TransformTarget},
+    targets::Feature,
+    utils::CreateVars,
+};
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+pub struct SpreadOptions {
+    /// When true, all iterables are assumed to be arrays.
+    #[serde(default)]
+    loose: bool,
+}
+
+/// ES2015: Spread
+///
+/// References:
+/// * <https://babel.dev/docs/babel-plugin-transform-spread>
+/// * <https://github.com/babel/babel/blob/main/packages/babel-plugin-transform-spread>
+pub struct Spread<'a> {
+    ast: Rc<AstBuilder<'a>>,
+    ctx: TransformerCtx<'a>,
+    vars: Vec<'a, VariableDeclarator<'a>>,
+    iterable_is_array: bool,
+}
+
+impl<'a> CreateVars<'a> for Spread<'a> {
+    fn ctx(&self) -> &TransformerCtx<'a> {
+        &self.ctx
+    }
+
+    fn vars_mut(&mut self) -> &mut Vec<'a, VariableDeclarator<'a>> {
+        &mut self.vars
+    }
+}
+
+impl<'a> Spread<'a> {
+    pub fn new(
+        ast: Rc<AstBuilder<'a>>,
+        ctx: TransformerCtx<'a>,
+        options: &TransformOptions,
+    ) -> Option<Self> {
+        (options.target < TransformTarget::ES2015
+            || options.spread.is_some()
+            || options.targets.needs_transform(Feature::Spread))
+        .then(|| {
+            let iterable_is_array =
+                options.assumptions.iterable_is_array || options.spread.is_some_and(|o| o.loose);
+            let vars = ast.new_vec();
+            Self { ast, ctx, vars, iterable_is_array }
+        })
+    }
+
+    pub fn transform_expression(&mut self, expr: &mut Expression<'a>) {
+        match expr {
+            Expression::ArrayExpression(array_expr) => {
+                if let Some(new_expr) = self.transform_array_expression(array_expr) {
+                    *expr = new_expr;
+                }
+            }
+            Expression::CallExpression(call_expr) => self.transform_call_expression(call_expr),
+            Expression::NewExpression(new_expr) => {
+                if let Some(new_expr) = self.transform_new_expression(new_expr) {
+                    *expr = new_expr;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// * `[...a]` -> `babelHelpers.toConsumableArray(a)`
+    /// * `[a, ...b]` -> `[a].concat(babelHelpers.toConsumableArray(b))`
+    fn transform_array_expression(
+        &mut self,
+        array_expr: &mut ArrayExpression<'a>,
+    ) -> Option<Expression<'a>> {
+        if !Self::has_spread(&array_expr.elements) {
+            return None;
+        }
+        let elements = std::mem::replace(&mut array_expr.elements, self.ast.new_vec());
+        let mut nodes = self.build(elements);
+
+        // `toConsumableArray` and `Array.prototype.slice.call` already copy the array.
+        if nodes.len() == 1 && !self.iterable_is_array {
+            return nodes.pop();
+        }
+
+        let first = if matches!(nodes.first(), Some(Expression::ArrayExpression(_))) {
+            nodes.remove(0)
+        } else {
+            self.ast.array_expression(Span::default(), self.ast.new_vec(), None)
+        };
+        Some(self.concat(first, nodes))
+    }
+
+    /// * `f(...a)` -> `f.apply(void 0, babelHelpers.toConsumableArray(a))`
+    /// * `obj.f(a, ...b)` -> `obj.f.apply(obj, [a].concat(babelHelpers.toConsumableArray(b)))`
+    fn transform_call_expression(&mut self, call_expr: &mut CallExpression<'a>) {
+        if !Self::has_spread(&call_expr.arguments)
+            || matches!(call_expr.callee, Expression::Super(_))
+        {
+            return;
+        }
+        let span = Span::default();
+        let arguments = self.arguments(&mut call_expr.arguments);
+
+        let mut context = self.ast.void_0();
+        if let Expression::MemberExpression(member_expr) = &mut call_expr.callee {
+            let object = member_expr.object_mut();
+            if let Some(ident) = self.maybe_generate_memoised(object) {
+                // `(_obj = obj()).f.apply(_obj, args)`
+                let target = AssignmentTarget::SimpleAssignmentTarget(
+                    self.ast.simple_assignment_target_identifier(ident.clone()),
+                );
+                let value = self.ast.move_expression(object);
+                let assign_expr =
+                    self.ast.assignment_expression(span, AssignmentOperator::Assign, target, value);
+                *object = self.ast.parenthesized_expression(span, assign_expr);
+                context = self.ast.identifier_reference_expression(ident);
+            } else if let Expression::Super(_) = object {
+                context = self.ast.this_expression(span);
+            } else {
+                context = self.ast.copy(object);
+            }
+        }
+
+        let callee = self.ast.move_expression(&mut call_expr.callee);
+        let property = IdentifierName::new(span, "apply".into());
+        call_expr.callee =
+            self.ast.static_member_expression(span, callee, property, call_expr.optional);
+        call_expr.optional = false;
+        call_expr.arguments.push(Argument::Expression(context));
+        call_expr.arguments.push(Argument::Expression(arguments));
+    }
+
+    /// `new C(...a)` -> `babelHelpers.construct(C, babelHelpers.toConsumableArray(a))`
+    fn transform_new_expression(
+        &mut self,
+        new_expr: &mut NewExpression<'a>,
+    ) -> Option<Expression<'a>> {
+        if !Self::has_spread(&new_expr.arguments) {
+            return None;
+        }
+        let arguments = self.arguments(&mut new_expr.arguments);
+        let callee = self.ast.move_expression(&mut new_expr.callee);
+        let mut helper_arguments = self.ast.new_vec_with_capacity(2);
+        helper_arguments.push(Argument::Expression(callee));
+        helper_arguments.push(Argument::Expression(arguments));
+        Some(self.ctx.helper_call("construct", helper_arguments))
+    }
+
+    fn has_spread<T: HasSpread>(elements: &[T]) -> bool {
+        elements.iter().any(HasSpread::is_spread)
+    }
+
+    /// Build the arguments array of a call, e.g. `[a].concat(babelHelpers.toConsumableArray(b))`.
+    fn arguments(&mut self, arguments: &mut Vec<'a, Argument<'a>>) -> Expression<'a> {
+        let arguments = std::mem::replace(arguments, self.ast.new_vec());
+        let mut elements = self.ast.new_vec_with_capacity(arguments.len());
+        for argument in arguments {
+            elements.push(match argument {
+                Argument::SpreadElement(spread) => ArrayExpressionElement::SpreadElement(spread),
+                Argument::Expression(expr) => ArrayExpressionElement::Expression(expr),
+            });
+        }
+        let mut nodes = self.build(elements);
+        let first = nodes.remove(0);
+        if nodes.is_empty() {
+            first
+        } else {
+            self.concat(first, nodes)
+        }
+    }
+
+    /// Group consecutive elements into array literals, and convert each spread into an array.
+    fn build(
+        &mut self,
+        elements: Vec<'a, ArrayExpressionElement<'a>>,
+    ) -> std::vec::Vec<Expression<'a>> {
+        let span = Span::default();
+        let mut nodes = vec![];
+        let mut props = self.ast.new_vec();
+        for element in elements {
+            match element {
+                ArrayExpressionElement::SpreadElement(spread) => {
+                    if !props.is_empty() {
+                        let props = std::mem::replace(&mut props, self.ast.new_vec());
+                        nodes.push(self.ast.array_expression(span, props, None));
+                    }
+                    nodes.push(self.spread_literal(spread.unbox().argument));
+                }
+                element => props.push(element),
+            }
+        }
+        if !props.is_empty() {
+            nodes.push(self.ast.array_expression(span, props, None));
+        }
+        nodes
+    }
+
+    /// * `babelHelpers.toConsumableArray(a)`
+    /// * `Array.prototype.slice.call(arguments)`
+    /// * `a` when `iterableIsArray`
+    fn spread_literal(&self, argument: Expression<'a>) -> Expression<'a> {
+        let span = Span::default();
+        let is_arguments =
+            matches!(&argument, Expression::Identifier(ident) if ident.name == "arguments");
+        if is_arguments {
+            let mut callee = self
+                .ast
+                .identifier_reference_expression(IdentifierReference::new(span, "Array".into()));
+            for property in ["prototype", "slice", "call"] {
+                let property = IdentifierName::new(span, property.into());
+                callee = self.ast.static_member_expression(span, callee, property, false);
+            }
+            let arguments = self.ast.new_vec_single(Argument::Expression(argument));
+            return self.ast.call_expression(span, callee, arguments, false, None);
+        }
+        if self.iterable_is_array {
+            return argument;
+        }
+        let arguments = self.ast.new_vec_single(Argument::Expression(argument));
+        self.ctx.helper_call("toConsumableArray", arguments)
+    }
+
+    /// `first.concat(...rest)`
+    fn concat(&self, first: Expression<'a>, rest: std::vec::Vec<Expression<'a>>) -> Expression<'a> {
+        let span = Span::default();
+        let property = IdentifierName::new(span, "concat".into());
+        let callee = self.ast.static_member_expression(span, first, property, false);
+        let mut arguments = self.ast.new_vec_with_capacity(rest.len());
+        arguments.extend(rest.into_iter().map(Argument::Expression));
+        self.ast.call_expression(span, callee, arguments, false, None)
+    }
+}
+
+trait HasSpread {
+    fn is_spread(&self) -> bool;
+}
+
+impl<'a> HasSpread for Argument<'a> {
+    fn is_spread(&self) -> bool {
+        matches!(self, Self::SpreadElement(_))
+    }
+}
+
+impl<'a> HasSpread for ArrayExpressionElement<'a> {
+    fn is_spread(&self) -> bool {
+        matches!(self, Self::SpreadElement(_))
+    }
+}
+
+#[test]
+fn test() {
+    use crate::{options::TransformOptions, tester::Tester};
+
+    let options =
+        TransformOptions { spread: Some(SpreadOptions::default()), ..TransformOptions::default() };
+    Tester::new("test.js", options).test(&[
+        ("[...a]", "babelHelpers.toConsumableArray(a)"),
+        ("[a, ...b, c]", "[a].concat(babelHelpers.toConsumableArray(b), [c])"),
+        (
+            "[...a, ...b]",
+            "[].concat(babelHelpers.toConsumableArray(a), babelHelpers.toConsumableArray(b))",
+        ),
+        ("f(...a)", "f.apply(void 0, babelHelpers.toConsumableArray(a))"),
+        ("f(a, ...b)", "f.apply(void 0, [a].concat(babelHelpers.toConsumableArray(b)))"),
+        ("f(...arguments)", "f.apply(void 0, Array.prototype.slice.call(arguments))"),
+        (
+            "const obj = {}; obj.f(...a)",
+            "const obj = {}; obj.f.apply(obj, babelHelpers.toConsumableArray(a))",
+        ),
+        (
+            "foo.bar.f(...a)",
+            "var _foo$bar; (_foo$bar = foo.bar).f.apply(_foo$bar, babelHelpers.toConsumableArray(a))",
+        ),
+        ("new C(...a)", "babelHelpers.construct(C, babelHelpers.toConsumableArray(a))"),
+        ("f(a)", "f(a)"),
+    ]);
+
+    let options = TransformOptions {
+        spread: Some(SpreadOptions { loose: true }),
+        ..TransformOptions::default()
+    };
+    Tester::new("test.js", options).test(&[
+        ("[...a]", "[].concat(a)"),
+        ("[a, ...b]", "[a].concat(b)"),
+        ("f(...a)", "f.apply(void 0, a)"),
+        ("new C(a, ...b)", "babelHelpers.construct(C, [a].concat(b))"),
+    ]);
+}
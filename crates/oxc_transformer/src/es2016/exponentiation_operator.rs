@@ -8,6 +8,7 @@ use oxc_syntax::operator::{AssignmentOperator, BinaryOperator};
 use crate::{
     context::TransformerCtx,
     options::{TransformOptions, TransformTarget},
+    targets::Feature,
     utils::CreateVars,
 };
 
@@ -44,7 +45,10 @@ impl<'a> ExponentiationOperator<'a> {
         ctx: TransformerCtx<'a>,
         options: &TransformOptions,
     ) -> Option<Self> {
-        (options.target < TransformTarget::ES2016 || options.exponentiation_operator).then(|| {
+        (options.target < TransformTarget::ES2016
+            || options.exponentiation_operator
+            || options.targets.needs_transform(Feature::ExponentiationOperator))
+        .then(|| {
             let vars = ast.new_vec();
             Self { ast, ctx, vars }
         })
@@ -56,7 +60,7 @@ impl<'a> ExponentiationOperator<'a> {
             if binary_expr.operator == BinaryOperator::Exponential {
                 let left = self.ast.move_expression(&mut binary_expr.left);
                 let right = self.ast.move_expression(&mut binary_expr.right);
-                *expr = self.math_pow(left, right);
+                *expr = self.math_pow(binary_expr.span, left, right);
             }
         }
 
@@ -69,7 +73,8 @@ impl<'a> ExponentiationOperator<'a> {
                     return;
                 };
                 let right = self.ast.move_expression(&mut assign_expr.right);
-                let right = self.math_pow(uid, right);
+                let right = self.math_pow(Span::default(), uid, right);
+                let span = assign_expr.span;
                 let assign_expr = self.ast.assignment_expression(
                     Span::default(),
                     AssignmentOperator::Assign,
@@ -77,13 +82,18 @@ impl<'a> ExponentiationOperator<'a> {
                     right,
                 );
                 nodes.push(assign_expr);
-                *expr = self.ast.sequence_expression(Span::default(), nodes);
+                *expr = self.ast.sequence_expression(span, nodes);
             }
         }
     }
 
     /// `left ** right` -> `Math.pow(left, right)`
-    fn math_pow(&mut self, left: Expression<'a>, right: Expression<'a>) -> Expression<'a> {
+    fn math_pow(
+        &mut self,
+        span: Span,
+        left: Expression<'a>,
+        right: Expression<'a>,
+    ) -> Expression<'a> {
         let ident_math = IdentifierReference::new(Span::default(), Atom::from("Math"));
         let object = self.ast.identifier_reference_expression(ident_math);
         let property = IdentifierName::new(Span::default(), Atom::from("pow"));
@@ -91,7 +101,7 @@ impl<'a> ExponentiationOperator<'a> {
         let mut arguments = self.ast.new_vec_with_capacity(2);
         arguments.push(Argument::Expression(left));
         arguments.push(Argument::Expression(right));
-        self.ast.call_expression(Span::default(), callee, arguments, false, None)
+        self.ast.call_expression(span, callee, arguments, false, None)
     }
 
     /// Change `lhs **= 2` to `var temp; temp = lhs, lhs = Math.pow(temp, 2);`.
@@ -0,0 +1,448 @@
+use std::{mem, rc::Rc};
+
+use oxc_allocator::Vec;
+use oxc_ast::{ast::*, AstBuilder};
+use oxc_span::{Atom, GetSpan, Span};
+use oxc_syntax::operator::{AssignmentOperator, BinaryOperator, LogicalOperator, UnaryOperator};
+
+use crate::{
+    context::TransformerCtx,
+    options::{TransformOptions, TransformTarget},
+    targets::Feature,
+};
+
+/// ES2018: Async Generator Functions
+///
+/// References:
+/// * <https://babel.dev/docs/babel-plugin-transform-async-generator-functions>
+/// * <https://github.com/babel/babel/tree/main/packages/babel-plugin-transform-async-generator-functions>
+pub struct AsyncGeneratorFunctions<'a> {
+    ast: Rc<AstBuilder<'a>>,
+    ctx: TransformerCtx<'a>,
+    /// Whether each enclosing function is an async generator.
+    functions: std::vec::Vec<bool>,
+}
+
+impl<'a> AsyncGeneratorFunctions<'a> {
+    pub fn new(
+        ast: Rc<AstBuilder<'a>>,
+        ctx: TransformerCtx<'a>,
+        options: &TransformOptions,
+    ) -> Option<Self> {
+        (options.target < TransformTarget::ES2018
+            || options.async_generator_functions
+            || options.targets.needs_transform(Feature::AsyncGeneratorFunctions))
+        .then(|| Self { ast, ctx, functions: vec![] })
+    }
+
+    pub fn enter_function(&mut self, func: &Function<'a>) {
+        self.functions.push(func.r#async && func.generator);
+    }
+
+    /// `async function* f() { body }` ->
+    /// `function f() { return babelHelpers.wrapAsyncGenerator(function* () { body }).apply(this, arguments); }`
+    pub fn leave_function(&mut self, func: &mut Function<'a>) {
+        if !self.functions.pop().unwrap_or_default() {
+            return;
+        }
+        let Some(body) = &mut func.body else { return };
+        let span = Span::default();
+
+        let statements = mem::replace(&mut body.statements, self.ast.new_vec());
+        let params = self.ast.formal_parameters(
+            span,
+            FormalParameterKind::FormalParameter,
+            self.ast.new_vec(),
+            None,
+        );
+        let generator = self.ast.function(
+            FunctionType::FunctionExpression,
+            span,
+            None,
+            false,
+            true,
+            false,
+            params,
+            Some(self.ast.function_body(span, self.ast.new_vec(), statements)),
+            None,
+            None,
+            Modifiers::empty(),
+        );
+        let arguments =
+            self.ast.new_vec_single(Argument::Expression(self.ast.function_expression(generator)));
+        let wrapped = self.ctx.helper_call("wrapAsyncGenerator", arguments);
+
+        let callee = self.member(wrapped, "apply");
+        let mut arguments = self.ast.new_vec_with_capacity(2);
+        arguments.push(Argument::Expression(self.ast.this_expression(span)));
+        arguments.push(Argument::Expression(self.identifier(&"arguments".into())));
+        let call = self.ast.call_expression(span, callee, arguments, false, None);
+        body.statements.push(self.ast.return_statement(span, Some(call)));
+
+        func.r#async = false;
+        func.generator = false;
+    }
+
+    /// Arrow functions cannot be generators, but `await` inside of them belongs to the arrow.
+    pub fn enter_arrow_expression(&mut self) {
+        self.functions.push(false);
+    }
+
+    pub fn leave_arrow_expression(&mut self) {
+        self.functions.pop();
+    }
+
+    /// * `await x` -> `yield babelHelpers.awaitAsyncGenerator(x)`
+    /// * `yield* x` -> `yield* babelHelpers.asyncGeneratorDelegate(babelHelpers.asyncIterator(x), babelHelpers.awaitAsyncGenerator)`
+    pub fn transform_expression(&mut self, expr: &mut Expression<'a>) {
+        if !self.functions.last().copied().unwrap_or_default() {
+            return;
+        }
+        match expr {
+            Expression::AwaitExpression(await_expr) => {
+                let argument = self.ast.move_expression(&mut await_expr.argument);
+                let arguments = self.ast.new_vec_single(Argument::Expression(argument));
+                let argument = self.ctx.helper_call("awaitAsyncGenerator", arguments);
+                *expr = self.ast.yield_expression(await_expr.span, false, Some(argument));
+            }
+            Expression::YieldExpression(yield_expr) if yield_expr.delegate => {
+                let Some(argument) = &mut yield_expr.argument else { return };
+                let iterable = self.ast.move_expression(argument);
+                let arguments = self.ast.new_vec_single(Argument::Expression(iterable));
+                let iterator = self.ctx.helper_call("asyncIterator", arguments);
+                let mut arguments = self.ast.new_vec_with_capacity(2);
+                arguments.push(Argument::Expression(iterator));
+                arguments.push(Argument::Expression(self.helper("awaitAsyncGenerator")));
+                *argument = self.ctx.helper_call("asyncGeneratorDelegate", arguments);
+            }
+            _ => {}
+        }
+    }
+
+    /// Lower `for await` loops which are direct children of a statement list in place.
+    pub fn transform_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>) {
+        if !stmts.iter().any(Self::is_for_await) {
+            return;
+        }
+        let old_stmts = mem::replace(stmts, self.ast.new_vec());
+        for mut stmt in old_stmts {
+            if Self::is_for_await(&stmt) {
+                stmts.extend(self.lower_for_await(&mut stmt));
+            } else {
+                stmts.push(stmt);
+            }
+        }
+    }
+
+    /// Lower `for await` loops in single statement positions, e.g. `if (x) for await (y of z);`.
+    pub fn transform_statement(&mut self, stmt: &mut Statement<'a>) {
+        if !Self::is_for_await(stmt) {
+            return;
+        }
+        let mut body = self.ast.new_vec_with_capacity(4);
+        body.extend(self.lower_for_await(stmt));
+        *stmt = self.ast.block_statement(self.ast.block(Span::default(), body));
+    }
+
+    fn is_for_await(stmt: &Statement<'a>) -> bool {
+        match stmt {
+            Statement::ForOfStatement(stmt) => stmt.r#await,
+            Statement::LabeledStatement(stmt) => Self::is_for_await(&stmt.body),
+            _ => false,
+        }
+    }
+
+    /// ```JavaScript
+    /// var _iteratorAbruptCompletion = false;
+    /// var _didIteratorError = false;
+    /// var _iteratorError;
+    /// try {
+    ///   for (var _iterator = babelHelpers.asyncIterator(y), _step; _iteratorAbruptCompletion = !(_step = await _iterator.next()).done; _iteratorAbruptCompletion = false) {
+    ///     const x = _step.value;
+    ///     body;
+    ///   }
+    /// } catch (err) {
+    ///   _didIteratorError = true;
+    ///   _iteratorError = err;
+    /// } finally {
+    ///   try {
+    ///     if (_iteratorAbruptCompletion && _iterator.return != null) {
+    ///       await _iterator.return();
+    ///     }
+    ///   } finally {
+    ///     if (_didIteratorError) {
+    ///       throw _iteratorError;
+    ///     }
+    ///   }
+    /// }
+    /// ```
+    fn lower_for_await(&mut self, stmt: &mut Statement<'a>) -> [Statement<'a>; 4] {
+        let span = Span::default();
+        // The `try` statement keeps the comments and the source position of the loop.
+        let stmt_span = stmt.span();
+        let abrupt_completion = self.generate_uid("iteratorAbruptCompletion");
+        let did_iterator_error = self.generate_uid("didIteratorError");
+        let iterator_error = self.generate_uid("iteratorError");
+        let iterator = self.generate_uid("iterator");
+        let step = self.generate_uid("step");
+
+        let for_statement = self.lower_for_await_loop(stmt, &abrupt_completion, &iterator, &step);
+        let block = self.ast.block(span, self.ast.new_vec_single(for_statement));
+
+        // catch (err) { _didIteratorError = true; _iteratorError = err; }
+        let err: Atom = "err".into();
+        let mut catch_body = self.ast.new_vec_with_capacity(2);
+        catch_body.push(self.assignment_statement(&did_iterator_error, self.boolean(true)));
+        catch_body.push(self.assignment_statement(&iterator_error, self.identifier(&err)));
+        let param = self.ast.binding_pattern(
+            self.ast.binding_pattern_identifier(BindingIdentifier::new(span, err)),
+            None,
+            false,
+        );
+        let handler = self.ast.catch_clause(span, Some(param), self.ast.block(span, catch_body));
+
+        // if (_iteratorAbruptCompletion && _iterator.return != null) { await _iterator.return(); }
+        let return_method = self.member(self.identifier(&iterator), "return");
+        let has_return = self.ast.binary_expression(
+            span,
+            return_method,
+            BinaryOperator::Inequality,
+            self.ast.literal_null_expression(NullLiteral::new(span)),
+        );
+        let test = self.ast.logical_expression(
+            span,
+            self.identifier(&abrupt_completion),
+            LogicalOperator::And,
+            has_return,
+        );
+        let callee = self.member(self.identifier(&iterator), "return");
+        let call = self.ast.call_expression(span, callee, self.ast.new_vec(), false, None);
+        let close = self.ast.expression_statement(span, self.ast.await_expression(span, call));
+        let close = self.ast.if_statement(span, test, self.block_statement(close), None);
+
+        // if (_didIteratorError) { throw _iteratorError; }
+        let rethrow = self.ast.throw_statement(span, self.identifier(&iterator_error));
+        let rethrow = self.ast.if_statement(
+            span,
+            self.identifier(&did_iterator_error),
+            self.block_statement(rethrow),
+            None,
+        );
+
+        let finalizer = self.ast.try_statement(
+            span,
+            self.ast.block(span, self.ast.new_vec_single(close)),
+            None,
+            Some(self.ast.block(span, self.ast.new_vec_single(rethrow))),
+        );
+        let finalizer = self.ast.block(span, self.ast.new_vec_single(finalizer));
+
+        [
+            self.var_statement(abrupt_completion, Some(self.boolean(false))),
+            self.var_statement(did_iterator_error, Some(self.boolean(false))),
+            self.var_statement(iterator_error, None),
+            self.ast.try_statement(stmt_span, block, Some(handler), Some(finalizer)),
+        ]
+    }
+
+    /// `for (var _iterator = babelHelpers.asyncIterator(y), _step; _iteratorAbruptCompletion = !(_step = await _iterator.next()).done; _iteratorAbruptCompletion = false) {}`
+    ///
+    /// Labels are kept on the lowered loop so `break` and `continue` still target it.
+    fn lower_for_await_loop(
+        &self,
+        stmt: &mut Statement<'a>,
+        abrupt_completion: &Atom,
+        iterator: &Atom,
+        step: &Atom,
+    ) -> Statement<'a> {
+        let span = Span::default();
+        let stmt = match stmt {
+            Statement::LabeledStatement(labeled) => {
+                let body =
+                    self.lower_for_await_loop(&mut labeled.body, abrupt_completion, iterator, step);
+                return self.ast.labeled_statement(labeled.span, labeled.label.clone(), body);
+            }
+            Statement::ForOfStatement(stmt) => stmt,
+            _ => unreachable!(),
+        };
+
+        // var _iterator = babelHelpers.asyncIterator(y), _step
+        let right = self.ast.move_expression(&mut stmt.right);
+        let arguments = self.ast.new_vec_single(Argument::Expression(right));
+        let init = self.ctx.helper_call("asyncIterator", arguments);
+        let mut declarations = self.ast.new_vec_with_capacity(2);
+        declarations.push(self.var_declarator(iterator.clone(), Some(init)));
+        declarations.push(self.var_declarator(step.clone(), None));
+        let init = self.ast.variable_declaration(
+            span,
+            VariableDeclarationKind::Var,
+            declarations,
+            Modifiers::empty(),
+        );
+
+        // _iteratorAbruptCompletion = !(_step = await _iterator.next()).done
+        let callee = self.member(self.identifier(iterator), "next");
+        let next = self.ast.call_expression(span, callee, self.ast.new_vec(), false, None);
+        let next = self.assign(step, self.ast.await_expression(span, next));
+        let done = self.member(self.ast.parenthesized_expression(span, next), "done");
+        let test = self.ast.unary_expression(span, UnaryOperator::LogicalNot, done);
+        let test = self.assign(abrupt_completion, test);
+
+        // _iteratorAbruptCompletion = false
+        let update = self.assign(abrupt_completion, self.boolean(false));
+
+        // const x = _step.value;
+        let value = self.member(self.identifier(step), "value");
+        let left = match &mut stmt.left {
+            ForStatementLeft::VariableDeclaration(decl) => {
+                let mut decl = self.ast.copy(decl);
+                if let Some(declarator) = decl.declarations.first_mut() {
+                    declarator.init = Some(value);
+                }
+                Statement::Declaration(Declaration::VariableDeclaration(decl))
+            }
+            ForStatementLeft::UsingDeclaration(decl) => {
+                let mut decl = self.ast.copy(decl);
+                if let Some(declarator) = decl.declarations.first_mut() {
+                    declarator.init = Some(value);
+                }
+                Statement::Declaration(Declaration::UsingDeclaration(decl))
+            }
+            ForStatementLeft::AssignmentTarget(target) => {
+                let target = self.ast.move_assignment_target(target);
+                let expr =
+                    self.ast.assignment_expression(span, AssignmentOperator::Assign, target, value);
+                self.ast.expression_statement(span, expr)
+            }
+        };
+        let mut body = self.ast.new_vec_with_capacity(2);
+        body.push(left);
+        body.push(self.ast.move_statement(&mut stmt.body));
+        let body = self.ast.block_statement(self.ast.block(span, body));
+
+        self.ast.for_statement(
+            stmt.span,
+            Some(ForStatementInit::VariableDeclaration(init)),
+            Some(test),
+            Some(update),
+            body,
+        )
+    }
+
+    fn generate_uid(&self, name: &str) -> Atom {
+        let name = self.ctx.scopes().generate_uid(name);
+        self.ctx.add_binding(name.clone());
+        name
+    }
+
+    fn helper(&self, name: &str) -> Expression<'a> {
+        self.ctx.helper_reference(name)
+    }
+
+    fn identifier(&self, name: &Atom) -> Expression<'a> {
+        self.ast.identifier_reference_expression(IdentifierReference::new(
+            Span::default(),
+            name.clone(),
+        ))
+    }
+
+    fn member(&self, object: Expression<'a>, property: &str) -> Expression<'a> {
+        let property = IdentifierName::new(Span::default(), property.into());
+        self.ast.static_member_expression(Span::default(), object, property, false)
+    }
+
+    fn boolean(&self, value: bool) -> Expression<'a> {
+        self.ast.literal_boolean_expression(self.ast.boolean_literal(Span::default(), value))
+    }
+
+    fn assign(&self, name: &Atom, value: Expression<'a>) -> Expression<'a> {
+        let target =
+            AssignmentTarget::SimpleAssignmentTarget(self.ast.simple_assignment_target_identifier(
+                IdentifierReference::new(Span::default(), name.clone()),
+            ));
+        self.ast.assignment_expression(Span::default(), AssignmentOperator::Assign, target, value)
+    }
+
+    fn assignment_statement(&self, name: &Atom, value: Expression<'a>) -> Statement<'a> {
+        self.ast.expression_statement(Span::default(), self.assign(name, value))
+    }
+
+    fn block_statement(&self, stmt: Statement<'a>) -> Statement<'a> {
+        let block = self.ast.block(Span::default(), self.ast.new_vec_single(stmt));
+        self.ast.block_statement(block)
+    }
+
+    fn var_declarator(&self, name: Atom, init: Option<Expression<'a>>) -> VariableDeclarator<'a> {
+        let binding = self.ast.binding_pattern(
+            self.ast.binding_pattern_identifier(BindingIdentifier::new(Span::default(), name)),
+            None,
+            false,
+        );
+        self.ast.variable_declarator(
+            Span::default(),
+            VariableDeclarationKind::Var,
+            binding,
+            init,
+            false,
+        )
+    }
+
+    fn var_statement(&self, name: Atom, init: Option<Expression<'a>>) -> Statement<'a> {
+        let declarations = self.ast.new_vec_single(self.var_declarator(name, init));
+        let decl = self.ast.variable_declaration(
+            Span::default(),
+            VariableDeclarationKind::Var,
+            declarations,
+            Modifiers::empty(),
+        );
+        Statement::Declaration(Declaration::VariableDeclaration(decl))
+    }
+}
+
+#[test]
+fn test() {
+    use crate::{
+        options::{TransformOptions, TransformTarget},
+        tester::Tester,
+    };
+
+    let options =
+        TransformOptions { target: TransformTarget::ES2017, ..TransformOptions::default() };
+    Tester::new("test.js", options).test(&[
+        (
+            "async function* f(a) { await a; yield 1; }",
+            "function f(a) { return babelHelpers.wrapAsyncGenerator(function* () { yield babelHelpers.awaitAsyncGenerator(a); yield 1; }).apply(this, arguments); }",
+        ),
+        (
+            "async function* f() { yield* g(); }",
+            "function f() { return babelHelpers.wrapAsyncGenerator(function* () { yield* babelHelpers.asyncGeneratorDelegate(babelHelpers.asyncIterator(g()), babelHelpers.awaitAsyncGenerator); }).apply(this, arguments); }",
+        ),
+        (
+            "async function* f() { async () => await 1; }",
+            "function f() { return babelHelpers.wrapAsyncGenerator(function* () { async () => await 1; }).apply(this, arguments); }",
+        ),
+        (
+            "class C { async *m() { await 1; } }",
+            "class C { m() { return babelHelpers.wrapAsyncGenerator(function* () { yield babelHelpers.awaitAsyncGenerator(1); }).apply(this, arguments); } }",
+        ),
+        (
+            "async function f() { for await (const x of y) { g(x); } }",
+            "async function f() { var _iteratorAbruptCompletion = false; var _didIteratorError = false; var _iteratorError; try { for (var _iterator = babelHelpers.asyncIterator(y), _step; _iteratorAbruptCompletion = !(_step = await _iterator.next()).done; _iteratorAbruptCompletion = false) { const x = _step.value; { g(x); } } } catch (err) { _didIteratorError = true; _iteratorError = err; } finally { try { if (_iteratorAbruptCompletion && _iterator.return != null) { await _iterator.return(); } } finally { if (_didIteratorError) { throw _iteratorError; } } } }",
+        ),
+        (
+            "async function f() { a: for await (x of y) continue a; }",
+            "async function f() { var _iteratorAbruptCompletion = false; var _didIteratorError = false; var _iteratorError; try { a: for (var _iterator = babelHelpers.asyncIterator(y), _step; _iteratorAbruptCompletion = !(_step = await _iterator.next()).done; _iteratorAbruptCompletion = false) { x = _step.value; continue a; } } catch (err) { _didIteratorError = true; _iteratorError = err; } finally { try { if (_iteratorAbruptCompletion && _iterator.return != null) { await _iterator.return(); } } finally { if (_didIteratorError) { throw _iteratorError; } } } }",
+        ),
+        (
+            "async function* f() { for await (x of y); }",
+            "function f() { return babelHelpers.wrapAsyncGenerator(function* () { var _iteratorAbruptCompletion = false; var _didIteratorError = false; var _iteratorError; try { for (var _iterator = babelHelpers.asyncIterator(y), _step; _iteratorAbruptCompletion = !(_step = yield babelHelpers.awaitAsyncGenerator(_iterator.next())).done; _iteratorAbruptCompletion = false) { x = _step.value; ; } } catch (err) { _didIteratorError = true; _iteratorError = err; } finally { try { if (_iteratorAbruptCompletion && _iterator.return != null) { yield babelHelpers.awaitAsyncGenerator(_iterator.return()); } } finally { if (_didIteratorError) { throw _iteratorError; } } } }).apply(this, arguments); }",
+        ),
+    ]);
+
+    let options =
+        TransformOptions { async_generator_functions: true, ..TransformOptions::default() };
+    Tester::new("test.js", options).test(&[(
+        "async function f() { if (a) for await (x of y); }",
+        "async function f() { if (a) { var _iteratorAbruptCompletion = false; var _didIteratorError = false; var _iteratorError; try { for (var _iterator = babelHelpers.asyncIterator(y), _step; _iteratorAbruptCompletion = !(_step = await _iterator.next()).done; _iteratorAbruptCompletion = false) { x = _step.value; ; } } catch (err) { _didIteratorError = true; _iteratorError = err; } finally { try { if (_iteratorAbruptCompletion && _iterator.return != null) { await _iterator.return(); } } finally { if (_didIteratorError) { throw _iteratorError; } } } } }",
+    )]);
+}
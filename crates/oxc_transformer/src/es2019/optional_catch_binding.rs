@@ -3,7 +3,11 @@ use std::rc::Rc;
 use oxc_ast::{ast::*, AstBuilder};
 use oxc_span::Span;
 
-use crate::options::{TransformOptions, TransformTarget};
+use crate::{
+    context::TransformerCtx,
+    options::{TransformOptions, TransformTarget},
+    targets::Feature,
+};
 
 /// ES2019: Optional Catch Binding
 ///
@@ -12,21 +16,43 @@ use crate::options::{TransformOptions, TransformTarget};
 /// * <https://github.com/babel/babel/tree/main/packages/babel-plugin-transform-optional-catch-binding>
 pub struct OptionalCatchBinding<'a> {
     ast: Rc<AstBuilder<'a>>,
+    ctx: TransformerCtx<'a>,
 }
 
 impl<'a> OptionalCatchBinding<'a> {
-    pub fn new(ast: Rc<AstBuilder<'a>>, options: &TransformOptions) -> Option<Self> {
-        (options.target < TransformTarget::ES2019 || options.optional_catch_binding)
-            .then(|| Self { ast })
+    pub fn new(
+        ast: Rc<AstBuilder<'a>>,
+        ctx: TransformerCtx<'a>,
+        options: &TransformOptions,
+    ) -> Option<Self> {
+        (options.target < TransformTarget::ES2019
+            || options.optional_catch_binding
+            || options.targets.needs_transform(Feature::OptionalCatchBinding))
+        .then(|| Self { ast, ctx })
     }
 
     pub fn transform_catch_clause<'b>(&mut self, clause: &'b mut CatchClause<'a>) {
         if clause.param.is_some() {
             return;
         }
-        let binding_identifier = BindingIdentifier::new(Span::default(), "_unused".into());
+        let name = self.ctx.scopes().generate_uid("unused");
+        self.ctx.add_binding(name.clone());
+        let binding_identifier = BindingIdentifier::new(Span::default(), name);
         let binding_pattern_kind = self.ast.binding_pattern_identifier(binding_identifier);
         let binding_pattern = self.ast.binding_pattern(binding_pattern_kind, None, false);
         clause.param = Some(binding_pattern);
     }
 }
+
+#[test]
+fn test() {
+    use crate::{options::TransformOptions, tester::Tester};
+
+    let options = TransformOptions { optional_catch_binding: true, ..TransformOptions::default() };
+    Tester::new("test.js", options).test(&[
+        ("try {} catch {}", "try {} catch (_unused) {}"),
+        ("try {} catch (e) {}", "try {} catch (e) {}"),
+        ("var _unused; try {} catch {}", "var _unused; try {} catch (_unused2) {}"),
+        ("try {} catch {} try {} catch {}", "try {} catch (_unused) {} try {} catch (_unused2) {}"),
+    ]);
+}
@@ -0,0 +1,76 @@
+use oxc_ast::ast::*;
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_span::Span;
+
+use crate::{
+    context::TransformerCtx,
+    options::{TransformOptions, TransformTarget},
+    targets::Feature,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("BigInt literals are not supported by the target")]
+#[diagnostic(help(
+    "BigInt can't be polyfilled, use `BigInt(\"{0}\")` with a BigInt library, or a newer target"
+))]
+struct BigIntUnsupported(String, #[label] Span);
+
+/// ES2020: BigInt
+///
+/// The BigInt arithmetic can't be transformed for older engines, so the literals are reported
+/// as errors instead of being printed for a target which fails to parse them.
+///
+/// References:
+/// * <https://github.com/tc39/proposal-bigint>
+pub struct BigIntLiterals<'a> {
+    ctx: TransformerCtx<'a>,
+}
+
+impl<'a> BigIntLiterals<'a> {
+    pub fn new(ctx: TransformerCtx<'a>, options: &TransformOptions) -> Option<Self> {
+        (options.target < TransformTarget::ES2020
+            || options.targets.needs_transform(Feature::BigInt))
+        .then_some(Self { ctx })
+    }
+
+    pub fn transform_expression(&self, expr: &Expression<'a>) {
+        if let Expression::BigintLiteral(literal) = expr {
+            self.ctx.error(BigIntUnsupported(literal.value.to_string(), literal.span));
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use std::{cell::RefCell, rc::Rc};
+
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_semantic::SemanticBuilder;
+    use oxc_span::SourceType;
+
+    use crate::Transformer;
+
+    let allocator = Allocator::default();
+    let source_text = "x = 1n + y; z = 2n;";
+    let source_type = SourceType::default();
+    let program = Parser::new(&allocator, source_text, source_type).parse().program;
+    let semantic = SemanticBuilder::new(source_text, source_type).build(&program).semantic;
+    let program = allocator.alloc(program);
+    let (symbols, scopes) = semantic.into_symbol_table_and_scope_tree();
+    let (symbols, scopes) = (Rc::new(RefCell::new(symbols)), Rc::new(RefCell::new(scopes)));
+    let options =
+        TransformOptions { target: TransformTarget::ES2019, ..TransformOptions::default() };
+    let transformer = Transformer::new(&allocator, source_type, &symbols, &scopes, options);
+    let errors = transformer.errors();
+    transformer.build(program);
+    let errors = errors.take();
+    assert_eq!(errors.len(), 2);
+    assert_eq!(errors[0].to_string(), "BigInt literals are not supported by the target");
+
+    let ret = crate::transform(source_text, source_type, TransformOptions::default());
+    assert!(ret.errors.is_empty());
+}
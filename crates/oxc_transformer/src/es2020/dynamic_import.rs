@@ -0,0 +1,233 @@
+use std::rc::Rc;
+
+use oxc_allocator::Vec;
+use oxc_ast::{ast::*, AstBuilder};
+use oxc_span::{Atom, Span};
+
+use crate::{
+    context::TransformerCtx,
+    modules::ModuleFormat,
+    options::{TransformOptions, TransformTarget},
+    targets::Feature,
+};
+
+/// ES2020: Dynamic Import
+///
+/// For CommonJS and UMD output:
+/// * `import("foo")` -> `Promise.resolve().then(() => _interopRequireWildcard(require("foo")))`
+/// * `import(foo)` ->
+///   ``Promise.resolve(`${foo}`).then((s) => _interopRequireWildcard(require(s)))``
+///
+/// For AMD output:
+/// * `import(foo)` -> `new Promise((resolve, reject) => require([foo], resolve, reject))
+///   .then(_interopRequireWildcard)`
+///
+/// `_interopRequireWildcard` is the `interopRequireWildcard` helper.
+///
+/// ES modules keep `import()`, unless the engines don't support it and a runtime function is
+/// given with `dynamic_import_function`, e.g. `__import`: `import("foo")` -> `__import("foo")`.
+///
+/// References:
+/// * <https://babeljs.io/docs/babel-plugin-transform-dynamic-import>
+/// * <https://www.typescriptlang.org/tsconfig#module>
+pub struct DynamicImport<'a> {
+    ast: Rc<AstBuilder<'a>>,
+    ctx: TransformerCtx<'a>,
+    format: ModuleFormat,
+    function: Option<String>,
+}
+
+impl<'a> DynamicImport<'a> {
+    pub fn new(
+        ast: Rc<AstBuilder<'a>>,
+        ctx: TransformerCtx<'a>,
+        options: &TransformOptions,
+    ) -> Option<Self> {
+        let function = options.dynamic_import_function.clone().filter(|_| {
+            options.target < TransformTarget::ES2020
+                || options.targets.needs_transform(Feature::DynamicImport)
+        });
+        (options.module != ModuleFormat::Preserve || function.is_some()).then(|| Self {
+            ast,
+            ctx,
+            format: options.module,
+            function,
+        })
+    }
+
+    pub fn transform_expression(&self, expr: &mut Expression<'a>) {
+        let Expression::ImportExpression(import) = expr else { return };
+        if self.format == ModuleFormat::Preserve && self.function.is_none() {
+            return;
+        }
+        let source = self.ast.move_expression(&mut import.source);
+        *expr = match self.format {
+            ModuleFormat::CommonJs | ModuleFormat::Umd => self.require(source),
+            ModuleFormat::Amd => self.require_amd(source),
+            ModuleFormat::Preserve => {
+                let callee = self.member_chain(self.function.as_deref().unwrap_or_default());
+                let mut arguments = self.ast.new_vec_with_capacity(1 + import.arguments.len());
+                arguments.push(Argument::Expression(source));
+                let rest = std::mem::replace(&mut import.arguments, self.ast.new_vec());
+                arguments.extend(rest.into_iter().map(Argument::Expression));
+                self.ast.call_expression(import.span, callee, arguments, false, None)
+            }
+        };
+    }
+
+    /// `Promise.resolve().then(() => _interopRequireWildcard(require("foo")))`
+    fn require(&self, source: Expression<'a>) -> Expression<'a> {
+        let (resolved, params, source) = if is_string(&source) {
+            (None, self.ast.new_vec(), source)
+        } else {
+            // `${foo}`, the specifier is converted to a string before the module is required
+            let quasi = |tail| {
+                let value = self.ast.template_element_value(Atom::from(""), Some(Atom::from("")));
+                self.ast.template_element(Span::default(), tail, value)
+            };
+            let mut quasis = self.ast.new_vec_with_capacity(2);
+            quasis.push(quasi(false));
+            quasis.push(quasi(true));
+            let expressions = self.ast.new_vec_single(source);
+            let template = self.ast.template_literal(Span::default(), quasis, expressions);
+            let template = self.ast.template_literal_expression(template);
+            (Some(template), self.ast.new_vec_single(self.param("s")), self.identifier("s"))
+        };
+
+        let require = self.call(self.identifier("require"), self.ast.new_vec_single(source));
+        let interop = self.ctx.helper_call(
+            "interopRequireWildcard",
+            self.ast.new_vec_single(Argument::Expression(require)),
+        );
+        let then_callback = self.arrow(params, interop);
+
+        let promise_resolve = self.member_chain("Promise.resolve");
+        let mut arguments = self.ast.new_vec();
+        arguments.extend(resolved);
+        let resolved = self.call(promise_resolve, arguments);
+        let then = self.member(resolved, "then");
+        self.call(then, self.ast.new_vec_single(then_callback))
+    }
+
+    /// `new Promise((resolve, reject) => require([foo], resolve, reject))
+    /// .then(_interopRequireWildcard)`
+    fn require_amd(&self, source: Expression<'a>) -> Expression<'a> {
+        let mut params = self.ast.new_vec_with_capacity(2);
+        params.push(self.param("resolve"));
+        params.push(self.param("reject"));
+        let elements = self.ast.new_vec_single(ArrayExpressionElement::Expression(source));
+        let mut arguments = self.ast.new_vec_with_capacity(3);
+        arguments.push(self.ast.array_expression(Span::default(), elements, None));
+        arguments.push(self.identifier("resolve"));
+        arguments.push(self.identifier("reject"));
+        let require = self.call(self.identifier("require"), arguments);
+        let executor = self.arrow(params, require);
+        let arguments = self.ast.new_vec_single(Argument::Expression(executor));
+        let promise =
+            self.ast.new_expression(Span::default(), self.identifier("Promise"), arguments, None);
+        let then = self.member(promise, "then");
+        let interop = self.ctx.helper_reference("interopRequireWildcard");
+        self.call(then, self.ast.new_vec_single(interop))
+    }
+
+    /// `(params) => body`
+    fn arrow(&self, params: Vec<'a, FormalParameter<'a>>, body: Expression<'a>) -> Expression<'a> {
+        let span = Span::default();
+        let params = self.ast.formal_parameters(
+            span,
+            FormalParameterKind::ArrowFormalParameters,
+            params,
+            None,
+        );
+        let body = self.ast.new_vec_single(self.ast.expression_statement(span, body));
+        let body = self.ast.function_body(span, self.ast.new_vec(), body);
+        self.ast.arrow_expression(span, true, false, false, params, body, None, None)
+    }
+
+    fn param(&self, name: &str) -> FormalParameter<'a> {
+        let span = Span::default();
+        let binding =
+            self.ast.binding_pattern_identifier(BindingIdentifier::new(span, name.into()));
+        let binding = self.ast.binding_pattern(binding, None, false);
+        self.ast.formal_parameter(span, binding, None, false, self.ast.new_vec())
+    }
+
+    fn call(&self, callee: Expression<'a>, arguments: Vec<'a, Expression<'a>>) -> Expression<'a> {
+        let mut args = self.ast.new_vec_with_capacity(arguments.len());
+        args.extend(arguments.into_iter().map(Argument::Expression));
+        self.ast.call_expression(Span::default(), callee, args, false, None)
+    }
+
+    /// `a.b.c`
+    fn member_chain(&self, path: &str) -> Expression<'a> {
+        let mut parts = path.split('.');
+        let object = self.identifier(parts.next().unwrap_or_default());
+        parts.fold(object, |object, property| self.member(object, property))
+    }
+
+    fn member(&self, object: Expression<'a>, property: &str) -> Expression<'a> {
+        let property = IdentifierName::new(Span::default(), property.into());
+        self.ast.static_member_expression(Span::default(), object, property, false)
+    }
+
+    fn identifier(&self, name: &str) -> Expression<'a> {
+        let ident = IdentifierReference::new(Span::default(), name.into());
+        self.ast.identifier_reference_expression(ident)
+    }
+}
+
+/// A string literal, or a template literal without expressions.
+fn is_string(expr: &Expression) -> bool {
+    match expr {
+        Expression::StringLiteral(_) => true,
+        Expression::TemplateLiteral(template) => template.expressions.is_empty(),
+        _ => false,
+    }
+}
+
+#[test]
+#[rustfmt::skip]
+fn test() {
+    use crate::tester::Tester;
+
+    let options = TransformOptions { module: ModuleFormat::CommonJs, ..TransformOptions::default() };
+    let tests = &[
+        (
+            "import('foo');",
+            "'use strict'; Promise.resolve().then(() => babelHelpers.interopRequireWildcard(require('foo')));",
+        ),
+        (
+            "import(foo).then(f);",
+            "'use strict'; Promise.resolve(`${foo}`).then((s) => babelHelpers.interopRequireWildcard(require(s))).then(f);",
+        ),
+    ];
+    Tester::new("test.js", options.clone()).test(tests);
+
+    let options = TransformOptions { module: ModuleFormat::Amd, ..options };
+    let tests = &[
+        (
+            "import('foo');",
+            "define(['require', 'exports'], function(require, exports) { 'use strict'; new Promise((resolve, reject) => require(['foo'], resolve, reject)).then(babelHelpers.interopRequireWildcard); });",
+        ),
+    ];
+    Tester::new("test.js", options).test(tests);
+
+    let options = TransformOptions {
+        target: TransformTarget::ES2019,
+        dynamic_import_function: Some("System.import".into()),
+        ..TransformOptions::default()
+    };
+    let tests = &[
+        ("import('foo');", "System.import('foo');"),
+    ];
+    Tester::new("test.js", options).test(tests);
+
+    let options = TransformOptions {
+        dynamic_import_function: Some("__import".into()),
+        ..TransformOptions::default()
+    };
+    let tests = &[
+        ("import('foo');", "import('foo');"),
+    ];
+    Tester::new("test.js", options).test(tests);
+}
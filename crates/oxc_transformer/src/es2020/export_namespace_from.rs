@@ -0,0 +1,139 @@
+use std::rc::Rc;
+
+use oxc_allocator::Vec;
+use oxc_ast::{ast::*, AstBuilder};
+use oxc_span::{Atom, Span};
+
+use crate::{
+    context::TransformerCtx,
+    modules::ModuleFormat,
+    options::{TransformOptions, TransformTarget},
+    targets::Feature,
+};
+
+/// ES2020: Export Namespace From
+///
+/// * `export * as ns from "mod"` -> `import * as _ns from "mod"; export { _ns as ns };`
+///
+/// The CommonJS, AMD and UMD output already exports the namespace with a getter, so the
+/// declarations are only lowered when the modules are preserved.
+///
+/// References:
+/// * <https://babeljs.io/docs/babel-plugin-transform-export-namespace-from>
+/// * <https://github.com/babel/babel/tree/main/packages/babel-plugin-transform-export-namespace-from>
+pub struct ExportNamespaceFrom<'a> {
+    ast: Rc<AstBuilder<'a>>,
+    ctx: TransformerCtx<'a>,
+}
+
+impl<'a> ExportNamespaceFrom<'a> {
+    pub fn new(
+        ast: Rc<AstBuilder<'a>>,
+        ctx: TransformerCtx<'a>,
+        options: &TransformOptions,
+    ) -> Option<Self> {
+        (options.module == ModuleFormat::Preserve
+            && (options.target < TransformTarget::ES2020
+                || options.export_namespace_from
+                || options.targets.needs_transform(Feature::ExportNamespaceFrom)))
+        .then(|| Self { ast, ctx })
+    }
+
+    pub fn transform_statements(&self, stmts: &mut Vec<'a, Statement<'a>>) {
+        if !stmts.iter().any(|stmt| Self::export_namespace(stmt).is_some()) {
+            return;
+        }
+        let mut new_stmts = self.ast.new_vec_with_capacity(stmts.len() + 1);
+        for stmt in stmts.drain(..) {
+            let Some(exported) = Self::export_namespace(&stmt).cloned() else {
+                new_stmts.push(stmt);
+                continue;
+            };
+            let Statement::ModuleDeclaration(decl) = stmt else { unreachable!() };
+            let ModuleDeclaration::ExportAllDeclaration(decl) = decl.unbox() else {
+                unreachable!()
+            };
+            let decl = decl.unbox();
+            let local = self.ctx.scopes().generate_uid(&Self::local_name(&exported));
+            self.ctx.add_binding(local.clone());
+
+            let specifier = ImportNamespaceSpecifier {
+                span: Span::default(),
+                local: BindingIdentifier::new(Span::default(), local.clone()),
+            };
+            let import = self.ast.import_declaration(
+                decl.span,
+                self.ast.new_vec_single(ImportDeclarationSpecifier::ImportNamespaceSpecifier(
+                    specifier,
+                )),
+                decl.source,
+                decl.with_clause,
+                ImportOrExportKind::Value,
+            );
+            new_stmts
+                .push(self.ast.module_declaration(ModuleDeclaration::ImportDeclaration(import)));
+
+            let specifier = ExportSpecifier {
+                span: Span::default(),
+                local: ModuleExportName::Identifier(IdentifierName::new(Span::default(), local)),
+                exported,
+                export_kind: ImportOrExportKind::Value,
+            };
+            let export = self.ast.export_named_declaration(
+                Span::default(),
+                None,
+                self.ast.new_vec_single(specifier),
+                None,
+                None,
+                ImportOrExportKind::Value,
+            );
+            new_stmts.push(
+                self.ast.module_declaration(ModuleDeclaration::ExportNamedDeclaration(export)),
+            );
+        }
+        *stmts = new_stmts;
+    }
+
+    /// The exported name of `export * as ns from "mod"`.
+    fn export_namespace<'b>(stmt: &'b Statement<'a>) -> Option<&'b ModuleExportName> {
+        let Statement::ModuleDeclaration(decl) = stmt else { return None };
+        let ModuleDeclaration::ExportAllDeclaration(decl) = &**decl else { return None };
+        decl.exported.as_ref().filter(|_| decl.export_kind.is_value())
+    }
+
+    /// `export * as "a-b" from "mod"` imports the namespace as `_ab`.
+    fn local_name(exported: &ModuleExportName) -> Atom {
+        let name = match exported {
+            ModuleExportName::Identifier(ident) => return ident.name.clone(),
+            ModuleExportName::StringLiteral(literal) => literal
+                .value
+                .chars()
+                .filter(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '$'))
+                .collect::<String>(),
+        };
+        if name.is_empty() || name.starts_with(|c: char| c.is_ascii_digit()) {
+            return "ns".into();
+        }
+        name.into()
+    }
+}
+
+#[test]
+#[rustfmt::skip]
+fn test() {
+    use crate::tester::Tester;
+
+    let options = TransformOptions { target: TransformTarget::ES2019, ..TransformOptions::default() };
+    Tester::new("test.mjs", options).test(&[
+        ("export * as ns from 'mod';", "import * as _ns from 'mod';\nexport { _ns as ns };"),
+        ("export * as default from 'mod';", "import * as _default from 'mod';\nexport { _default as default };"),
+        ("export * as 'a-b' from 'mod';", "import * as _ab from 'mod';\nexport { _ab as 'a-b' };"),
+        ("let _ns; export * as ns from 'mod';", "let _ns;\nimport * as _ns2 from 'mod';\nexport { _ns2 as ns };"),
+        ("export * from 'mod';", "export * from 'mod';"),
+    ]);
+
+    let options = TransformOptions { export_namespace_from: true, ..TransformOptions::default() };
+    Tester::new("test.mjs", options).test(&[
+        ("export * as ns from 'mod';", "import * as _ns from 'mod';\nexport { _ns as ns };"),
+    ]);
+}
@@ -1,5 +1,13 @@
+mod bigint;
+mod dynamic_import;
+mod export_namespace_from;
 mod nullish_coalescing_operator;
+mod optional_chaining;
 
+pub use bigint::BigIntLiterals;
+pub use dynamic_import::DynamicImport;
+pub use export_namespace_from::ExportNamespaceFrom;
 pub use nullish_coalescing_operator::{
     NullishCoalescingOperator, NullishCoalescingOperatorOptions,
 };
+pub use optional_chaining::{OptionalChaining, OptionalChainingOptions};
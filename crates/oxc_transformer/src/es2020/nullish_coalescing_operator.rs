@@ -1,5 +1,5 @@
 use serde::Deserialize;
-use std::rc::Rc;
+use std::{mem, rc::Rc};
 
 use oxc_allocator::Vec;
 use oxc_ast::{ast::*, AstBuilder};
@@ -9,6 +9,7 @@ use oxc_syntax::operator::{AssignmentOperator, BinaryOperator, LogicalOperator};
 use crate::{
     context::TransformerCtx,
     options::{TransformOptions, TransformTarget},
+    targets::Feature,
     utils::CreateVars,
 };
 
@@ -28,6 +29,10 @@ pub struct NullishCoalescingOperatorOptions {
 pub struct NullishCoalescingOperator<'a> {
     no_document_all: bool,
 
+    /// Whether the expression being transformed belongs to a parameter default value,
+    /// where a `var` can not be injected into the function body.
+    in_formal_parameters: bool,
+
     ast: Rc<AstBuilder<'a>>,
     ctx: TransformerCtx<'a>,
 
@@ -50,13 +55,33 @@ impl<'a> NullishCoalescingOperator<'a> {
         ctx: TransformerCtx<'a>,
         options: &TransformOptions,
     ) -> Option<Self> {
-        (options.target < TransformTarget::ES2020 || options.nullish_coalescing_operator.is_some())
-            .then(|| {
-                let no_document_all = options.assumptions.no_document_all
-                    || options.nullish_coalescing_operator.is_some_and(|o| o.loose);
-                let vars = ast.new_vec();
-                Self { no_document_all, ast, ctx, vars }
-            })
+        (options.target < TransformTarget::ES2020
+            || options.nullish_coalescing_operator.is_some()
+            || options.targets.needs_transform(Feature::NullishCoalescingOperator))
+        .then(|| {
+            let no_document_all = options.assumptions.no_document_all
+                || options.nullish_coalescing_operator.is_some_and(|o| o.loose);
+            let vars = ast.new_vec();
+            Self { no_document_all, in_formal_parameters: false, ast, ctx, vars }
+        })
+    }
+
+    /// Returns the previous state, to be restored by `leave_formal_parameters`.
+    pub fn enter_formal_parameters(&mut self) -> bool {
+        mem::replace(&mut self.in_formal_parameters, true)
+    }
+
+    pub fn leave_formal_parameters(&mut self, prev: bool) {
+        self.in_formal_parameters = prev;
+    }
+
+    /// Returns the previous state, to be restored by `leave_function_body`.
+    pub fn enter_function_body(&mut self) -> bool {
+        mem::replace(&mut self.in_formal_parameters, false)
+    }
+
+    pub fn leave_function_body(&mut self, prev: bool) {
+        self.in_formal_parameters = prev;
     }
 
     pub fn transform_expression(&mut self, expr: &mut Expression<'a>) {
@@ -67,6 +92,31 @@ impl<'a> NullishCoalescingOperator<'a> {
         }
 
         let span = Span::default();
+
+        // Replace `function (a, x = a.b ?? c) {}` with `function (a, x = (() => a.b ?? c)() ){}`
+        // so the temporary variable can be injected in the correct scope.
+        // The wrapped expression is transformed when the arrow function body is visited.
+        if self.in_formal_parameters && !self.ctx.symbols().is_static(&logical_expr.left) {
+            let expression = self.ast.move_expression(expr);
+            let statement = self.ast.expression_statement(span, expression);
+            let body = self.ast.function_body(
+                span,
+                self.ast.new_vec(),
+                self.ast.new_vec_single(statement),
+            );
+            let params = self.ast.formal_parameters(
+                span,
+                FormalParameterKind::ArrowFormalParameters,
+                self.ast.new_vec(),
+                None,
+            );
+            let arrow =
+                self.ast.arrow_expression(span, true, false, false, params, body, None, None);
+            let callee = self.ast.parenthesized_expression(span, arrow);
+            *expr = self.ast.call_expression(span, callee, self.ast.new_vec(), false, None);
+            return;
+        }
+
         let reference;
         let assignment;
 
@@ -101,6 +151,38 @@ impl<'a> NullishCoalescingOperator<'a> {
 
         let right = self.ast.move_expression(&mut logical_expr.right);
 
-        *expr = self.ast.conditional_expression(span, test, reference, right);
+        *expr = self.ast.conditional_expression(logical_expr.span, test, reference, right);
     }
 }
+
+#[test]
+fn test() {
+    use crate::{
+        options::{TransformOptions, TransformTarget},
+        tester::Tester,
+    };
+
+    let options =
+        TransformOptions { target: TransformTarget::ES2019, ..TransformOptions::default() };
+
+    let tests = &[
+        ("const x = 0; x ?? y", "const x = 0; x !== null && x !== void 0 ? x : y"),
+        ("foo.bar ?? y", "var _foo$bar; (_foo$bar = foo.bar) !== null && _foo$bar !== void 0 ? _foo$bar : y"),
+        (
+            "function foo(foo = bar.baz ?? 'qux') {}",
+            "function foo(foo = (() => { var _bar$baz; return (_bar$baz = bar.baz) !== null && _bar$baz !== void 0 ? _bar$baz : 'qux'; })()) {}",
+        ),
+    ];
+
+    Tester::new("test.js", options).test(tests);
+
+    let options = TransformOptions {
+        target: TransformTarget::ES2019,
+        nullish_coalescing_operator: Some(NullishCoalescingOperatorOptions { loose: true }),
+        ..TransformOptions::default()
+    };
+
+    let tests = &[("foo.bar ?? y", "var _foo$bar; (_foo$bar = foo.bar) != null ? _foo$bar : y")];
+
+    Tester::new("test.js", options).test(tests);
+}
@@ -0,0 +1,400 @@
+use serde::Deserialize;
+use std::{mem, rc::Rc};
+
+use oxc_allocator::Vec;
+use oxc_ast::{ast::*, AstBuilder};
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::operator::{AssignmentOperator, BinaryOperator, LogicalOperator, UnaryOperator};
+
+use crate::{
+    context::TransformerCtx,
+    options::{TransformOptions, TransformTarget},
+    targets::Feature,
+    utils::CreateVars,
+};
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+pub struct OptionalChainingOptions {
+    /// When true, this transform will pretend `document.all` does not exist,
+    /// and perform loose equality checks with null instead of strict equality checks against both null and undefined.
+    #[serde(default)]
+    loose: bool,
+}
+
+/// ES2020: Optional Chaining
+///
+/// * `a?.b.c` -> `a === null || a === void 0 ? void 0 : a.b.c`
+/// * `a.b?.()` -> `(_a$b = (_a = a).b) === null || _a$b === void 0 ? void 0 : _a$b.call(_a)`
+/// * `delete a?.b` -> `a === null || a === void 0 ? true : delete a.b`
+///
+/// The outermost optional link of a chain is lowered first, the part of the chain before it is
+/// left in a new chain expression which is lowered when the children are visited.
+///
+/// References:
+/// * <https://babeljs.io/docs/babel-plugin-transform-optional-chaining>
+/// * <https://github.com/babel/babel/tree/main/packages/babel-plugin-transform-optional-chaining>
+pub struct OptionalChaining<'a> {
+    no_document_all: bool,
+
+    /// Whether the expression being transformed belongs to a parameter default value,
+    /// where a `var` can not be injected into the function body.
+    in_formal_parameters: bool,
+
+    ast: Rc<AstBuilder<'a>>,
+    ctx: TransformerCtx<'a>,
+
+    vars: Vec<'a, VariableDeclarator<'a>>,
+}
+
+impl<'a> CreateVars<'a> for OptionalChaining<'a> {
+    fn ctx(&self) -> &TransformerCtx<'a> {
+        &self.ctx
+    }
+
+    fn vars_mut(&mut self) -> &mut Vec<'a, VariableDeclarator<'a>> {
+        &mut self.vars
+    }
+}
+
+impl<'a> OptionalChaining<'a> {
+    pub fn new(
+        ast: Rc<AstBuilder<'a>>,
+        ctx: TransformerCtx<'a>,
+        options: &TransformOptions,
+    ) -> Option<Self> {
+        (options.target < TransformTarget::ES2020
+            || options.optional_chaining.is_some()
+            || options.targets.needs_transform(Feature::OptionalChaining))
+        .then(|| {
+            let no_document_all = options.assumptions.no_document_all
+                || options.optional_chaining.is_some_and(|o| o.loose);
+            let vars = ast.new_vec();
+            Self { no_document_all, in_formal_parameters: false, ast, ctx, vars }
+        })
+    }
+
+    /// Returns the previous state, to be restored by `leave_formal_parameters`.
+    pub fn enter_formal_parameters(&mut self) -> bool {
+        mem::replace(&mut self.in_formal_parameters, true)
+    }
+
+    pub fn leave_formal_parameters(&mut self, prev: bool) {
+        self.in_formal_parameters = prev;
+    }
+
+    /// Returns the previous state, to be restored by `leave_function_body`.
+    pub fn enter_function_body(&mut self) -> bool {
+        mem::replace(&mut self.in_formal_parameters, false)
+    }
+
+    pub fn leave_function_body(&mut self, prev: bool) {
+        self.in_formal_parameters = prev;
+    }
+
+    pub fn transform_expression(&mut self, expr: &mut Expression<'a>) {
+        let is_delete = match expr {
+            Expression::ChainExpression(_) => false,
+            Expression::UnaryExpression(unary_expr)
+                if unary_expr.operator == UnaryOperator::Delete
+                    && matches!(unary_expr.argument, Expression::ChainExpression(_)) =>
+            {
+                true
+            }
+            _ => return,
+        };
+
+        if self.in_formal_parameters && self.needs_var(expr) {
+            self.wrap_in_arrow_function(expr);
+            return;
+        }
+
+        let span = expr.span();
+        let chain = if is_delete {
+            let Expression::UnaryExpression(unary_expr) = expr else { unreachable!() };
+            self.ast.move_expression(&mut unary_expr.argument)
+        } else {
+            self.ast.move_expression(expr)
+        };
+        let Expression::ChainExpression(chain_expr) = chain else { unreachable!() };
+        let mut top = match chain_expr.unbox().expression {
+            ChainElement::CallExpression(call_expr) => Expression::CallExpression(call_expr),
+            ChainElement::MemberExpression(member_expr) => {
+                Expression::MemberExpression(member_expr)
+            }
+        };
+
+        let Some(link) = Self::outermost_optional_link(&mut top) else {
+            // `a?.b!` in TypeScript may leave nothing to lower.
+            Self::restore(expr, top, is_delete);
+            return;
+        };
+
+        let (test, reference) = match link {
+            Expression::MemberExpression(member_expr) => {
+                Self::clear_member_optional(member_expr);
+                let object = member_expr.object_mut();
+                self.memoise(object)
+            }
+            Expression::CallExpression(call_expr) => {
+                call_expr.optional = false;
+                let context = self.memoise_context(&mut call_expr.callee);
+                let (test, reference) = self.memoise(&mut call_expr.callee);
+                if let Some(context) = context {
+                    let call = IdentifierName::new(Span::default(), "call".into());
+                    let callee = self.ast.copy(&reference);
+                    call_expr.callee =
+                        self.ast.static_member_expression(Span::default(), callee, call, false);
+                    call_expr.arguments.insert(0, Argument::Expression(context));
+                }
+                (test, reference)
+            }
+            _ => unreachable!(),
+        };
+        let test = self.nullish_test(test, reference);
+
+        let (consequent, alternate) = if is_delete {
+            let literal = self.ast.boolean_literal(Span::default(), true);
+            let delete = self.ast.unary_expression(span, UnaryOperator::Delete, top);
+            (self.ast.literal_boolean_expression(literal), delete)
+        } else {
+            (self.ast.void_0(), top)
+        };
+        *expr = self.ast.conditional_expression(span, test, consequent, alternate);
+    }
+
+    fn restore(expr: &mut Expression<'a>, top: Expression<'a>, is_delete: bool) {
+        if is_delete {
+            let Expression::UnaryExpression(unary_expr) = expr else { unreachable!() };
+            unary_expr.argument = top;
+        } else {
+            *expr = top;
+        }
+    }
+
+    /// The member or call expression of the chain with the last `?.` in the source.
+    fn outermost_optional_link<'b>(expr: &'b mut Expression<'a>) -> Option<&'b mut Expression<'a>> {
+        let optional = match expr {
+            Expression::MemberExpression(member_expr) => member_expr.optional(),
+            Expression::CallExpression(call_expr) => call_expr.optional,
+            _ => return None,
+        };
+        if optional {
+            return Some(expr);
+        }
+        match expr {
+            Expression::MemberExpression(member_expr) => {
+                Self::outermost_optional_link(member_expr.object_mut())
+            }
+            Expression::CallExpression(call_expr) => {
+                Self::outermost_optional_link(&mut call_expr.callee)
+            }
+            _ => None,
+        }
+    }
+
+    fn clear_member_optional(member_expr: &mut MemberExpression<'a>) {
+        match member_expr {
+            MemberExpression::ComputedMemberExpression(expr) => expr.optional = false,
+            MemberExpression::StaticMemberExpression(expr) => expr.optional = false,
+            MemberExpression::PrivateFieldExpression(expr) => expr.optional = false,
+        }
+    }
+
+    /// Whether lowering `expr` declares a temporary variable.
+    fn needs_var(&self, expr: &Expression<'a>) -> bool {
+        let chain = match expr {
+            Expression::UnaryExpression(unary_expr) => &unary_expr.argument,
+            _ => expr,
+        };
+        let Expression::ChainExpression(chain_expr) = chain else { return false };
+        let object = match &chain_expr.expression {
+            ChainElement::CallExpression(call_expr) if call_expr.optional => &call_expr.callee,
+            ChainElement::MemberExpression(member_expr) if member_expr.optional() => {
+                member_expr.object()
+            }
+            // The optional link is nested, its object is never static.
+            _ => return true,
+        };
+        !self.ctx.symbols().is_static(object)
+    }
+
+    /// Replace `function (a, x = a.b?.c) {}` with `function (a, x = (() => a.b?.c)() ){}`
+    /// so the temporary variable can be injected in the correct scope.
+    /// The wrapped expression is transformed when the arrow function body is visited.
+    fn wrap_in_arrow_function(&self, expr: &mut Expression<'a>) {
+        let span = Span::default();
+        let expression = self.ast.move_expression(expr);
+        let statement = self.ast.expression_statement(span, expression);
+        let body =
+            self.ast.function_body(span, self.ast.new_vec(), self.ast.new_vec_single(statement));
+        let params = self.ast.formal_parameters(
+            span,
+            FormalParameterKind::ArrowFormalParameters,
+            self.ast.new_vec(),
+            None,
+        );
+        let arrow = self.ast.arrow_expression(span, true, false, false, params, body, None, None);
+        let callee = self.ast.parenthesized_expression(span, arrow);
+        *expr = self.ast.call_expression(span, callee, self.ast.new_vec(), false, None);
+    }
+
+    /// Replace `expr` with a reference to its value, returning the expression to test
+    /// (`expr` itself when it is static, `_ref = expr` otherwise) and the reference.
+    fn memoise(&mut self, expr: &mut Expression<'a>) -> (Expression<'a>, Expression<'a>) {
+        if self.ctx.symbols().is_static(expr) {
+            return (self.ast.copy(expr), self.ast.copy(expr));
+        }
+        // The object memoised for the `this` of `a?.b?.()` is reused.
+        if let Expression::AssignmentExpression(assign_expr) = expr {
+            if let AssignmentTarget::SimpleAssignmentTarget(
+                SimpleAssignmentTarget::AssignmentTargetIdentifier(ident),
+            ) = &assign_expr.left
+            {
+                if assign_expr.operator == AssignmentOperator::Assign {
+                    let reference = self.ast.identifier_reference_expression((**ident).clone());
+                    let assignment = self.ast.move_expression(expr);
+                    *expr = self.ast.copy(&reference);
+                    return (assignment, reference);
+                }
+            }
+        }
+        let ident = self.create_new_var(expr);
+        let reference = self.ast.identifier_reference_expression(ident.clone());
+        let target = AssignmentTarget::SimpleAssignmentTarget(
+            self.ast.simple_assignment_target_identifier(ident),
+        );
+        let value = self.wrap_chain(self.ast.move_expression(expr));
+        let assignment = self.ast.assignment_expression(
+            Span::default(),
+            AssignmentOperator::Assign,
+            target,
+            value,
+        );
+        *expr = self.ast.copy(&reference);
+        (assignment, reference)
+    }
+
+    /// The `this` of an optional call to a member, e.g. `_a` of `(_a = a).b?.()`.
+    fn memoise_context(&mut self, callee: &mut Expression<'a>) -> Option<Expression<'a>> {
+        let Expression::MemberExpression(member_expr) = callee else { return None };
+        let object = member_expr.object_mut();
+        match object {
+            Expression::Super(_) => return Some(self.ast.this_expression(Span::default())),
+            _ if self.ctx.symbols().is_static(object) => return Some(self.ast.copy(object)),
+            _ => {}
+        }
+        let ident = self.create_new_var(object);
+        let target = AssignmentTarget::SimpleAssignmentTarget(
+            self.ast.simple_assignment_target_identifier(ident.clone()),
+        );
+        let value = self.ast.move_expression(object);
+        *object = self.ast.assignment_expression(
+            Span::default(),
+            AssignmentOperator::Assign,
+            target,
+            value,
+        );
+        Some(self.ast.identifier_reference_expression(ident))
+    }
+
+    /// Keep the optional links before the lowered one in a chain expression,
+    /// so they are lowered when the children are visited.
+    fn wrap_chain(&self, expr: Expression<'a>) -> Expression<'a> {
+        let element = match expr {
+            Expression::CallExpression(call_expr) => ChainElement::CallExpression(call_expr),
+            Expression::MemberExpression(member_expr) => {
+                ChainElement::MemberExpression(member_expr)
+            }
+            expr => return expr,
+        };
+        let mut chain = self.ast.chain_expression(Span::default(), element);
+        let Expression::ChainExpression(chain_expr) = &mut chain else { unreachable!() };
+        let has_optional_link = match &mut chain_expr.expression {
+            ChainElement::CallExpression(call_expr) => {
+                call_expr.optional || Self::outermost_optional_link(&mut call_expr.callee).is_some()
+            }
+            ChainElement::MemberExpression(member_expr) => {
+                member_expr.optional()
+                    || Self::outermost_optional_link(member_expr.object_mut()).is_some()
+            }
+        };
+        if has_optional_link {
+            return chain;
+        }
+        let Expression::ChainExpression(chain_expr) = chain else { unreachable!() };
+        match chain_expr.unbox().expression {
+            ChainElement::CallExpression(call_expr) => Expression::CallExpression(call_expr),
+            ChainElement::MemberExpression(member_expr) => {
+                Expression::MemberExpression(member_expr)
+            }
+        }
+    }
+
+    /// `test === null || reference === void 0`, or `test == null` without `document.all`.
+    fn nullish_test(&self, test: Expression<'a>, reference: Expression<'a>) -> Expression<'a> {
+        let span = Span::default();
+        let null = self.ast.literal_null_expression(NullLiteral::new(span));
+        if self.no_document_all {
+            return self.ast.binary_expression(span, test, BinaryOperator::Equality, null);
+        }
+        let op = BinaryOperator::StrictEquality;
+        let left = self.ast.binary_expression(span, test, op, null);
+        let right = self.ast.binary_expression(span, reference, op, self.ast.void_0());
+        self.ast.logical_expression(span, left, LogicalOperator::Or, right)
+    }
+}
+
+#[test]
+#[rustfmt::skip]
+fn test() {
+    use crate::tester::Tester;
+
+    let options = TransformOptions { target: TransformTarget::ES2019, ..TransformOptions::default() };
+    Tester::new("test.js", options).test(&[
+        ("const a = {}; a?.b.c", "const a = {}; a === null || a === void 0 ? void 0 : a.b.c"),
+        ("foo.bar?.baz", "var _foo$bar; (_foo$bar = foo.bar) === null || _foo$bar === void 0 ? void 0 : _foo$bar.baz"),
+        ("foo()?.bar", "var _ref; (_ref = foo()) === null || _ref === void 0 ? void 0 : _ref.bar"),
+        ("this?.x", "this === null || this === void 0 ? void 0 : this.x"),
+        (
+            "a?.b?.c",
+            "var _a$b, _a; (_a$b = (_a = a) === null || _a === void 0 ? void 0 : _a.b) === null || _a$b === void 0 ? void 0 : _a$b.c",
+        ),
+        ("a.b?.()", "var _a, _a$b; (_a$b = (_a = a).b) === null || _a$b === void 0 ? void 0 : _a$b.call(_a)"),
+        (
+            "a?.b?.(1)",
+            "var _a, _a$b; (_a$b = (_a = a) === null || _a === void 0 ? void 0 : _a.b) === null || _a$b === void 0 ? void 0 : _a$b.call(_a, 1)",
+        ),
+        ("delete a?.b", "var _a; (_a = a) === null || _a === void 0 ? true : delete _a.b"),
+        ("(a?.b).c", "var _a; ((_a = a) === null || _a === void 0 ? void 0 : _a.b).c"),
+        (
+            "class A extends B { m() { return super.m?.() } }",
+            "class A extends B { m() { var _ref$m; return (_ref$m = super.m) === null || _ref$m === void 0 ? void 0 : _ref$m.call(this) } }",
+        ),
+        (
+            "function f(x = a.b?.c) {}",
+            "function f(x = (() => { var _a$b; return (_a$b = a.b) === null || _a$b === void 0 ? void 0 : _a$b.c; })()) {}",
+        ),
+        // Lowered together with the newer passes.
+        (
+            "x ??= a?.b",
+            "var _a; var _x; (_x = x) !== null && _x !== void 0 ? _x : x = (_a = a) === null || _a === void 0 ? void 0 : _a.b",
+        ),
+        (
+            "x[a?.b] ||= c",
+            "var _a; var _x, _ref; (_x = x)[_ref = (_a = a) === null || _a === void 0 ? void 0 : _a.b] || (_x[_ref] = c)",
+        ),
+        (
+            "a?.b ?? c",
+            "var _a; var _ref; (_ref = (_a = a) === null || _a === void 0 ? void 0 : _a.b) !== null && _ref !== void 0 ? _ref : c",
+        ),
+    ]);
+
+    let options = TransformOptions {
+        optional_chaining: Some(OptionalChainingOptions { loose: true }),
+        ..TransformOptions::default()
+    };
+    Tester::new("test.js", options).test(&[
+        ("foo.bar?.baz", "var _foo$bar; (_foo$bar = foo.bar) == null ? void 0 : _foo$bar.baz"),
+        ("a?.b ?? c", "var _a; ((_a = a) == null ? void 0 : _a.b) ?? c"),
+    ]);
+}
@@ -3,11 +3,12 @@ use std::rc::Rc;
 use oxc_allocator::Vec;
 use oxc_ast::{ast::*, AstBuilder};
 use oxc_span::Span;
-use oxc_syntax::operator::{AssignmentOperator, LogicalOperator};
+use oxc_syntax::operator::AssignmentOperator;
 
 use crate::{
     context::TransformerCtx,
     options::{TransformOptions, TransformTarget},
+    targets::Feature,
     utils::CreateVars,
 };
 
@@ -39,24 +40,20 @@ impl<'a> LogicalAssignmentOperators<'a> {
         ctx: TransformerCtx<'a>,
         options: &TransformOptions,
     ) -> Option<Self> {
-        (options.target < TransformTarget::ES2021 || options.logical_assignment_operators).then(
-            || {
-                let vars = ast.new_vec();
-                Self { ast, ctx, vars }
-            },
-        )
+        (options.target < TransformTarget::ES2021
+            || options.logical_assignment_operators
+            || options.targets.needs_transform(Feature::LogicalAssignmentOperators))
+        .then(|| {
+            let vars = ast.new_vec();
+            Self { ast, ctx, vars }
+        })
     }
 
     pub fn transform_expression<'b>(&mut self, expr: &'b mut Expression<'a>) {
         let Expression::AssignmentExpression(assignment_expr) = expr else { return };
 
         // `&&=` `||=` `??=`
-        let operator = match assignment_expr.operator {
-            AssignmentOperator::LogicalAnd => LogicalOperator::And,
-            AssignmentOperator::LogicalOr => LogicalOperator::Or,
-            AssignmentOperator::LogicalNullish => LogicalOperator::Coalesce,
-            _ => return,
-        };
+        let Some(operator) = assignment_expr.operator.to_logical_operator() else { return };
 
         // `a &&= c` -> `a && (a = c);`
         //               ^     ^ assign_target
@@ -65,7 +62,7 @@ impl<'a> LogicalAssignmentOperators<'a> {
         let left_expr: Expression<'a>;
         let assign_target: SimpleAssignmentTarget<'a>;
 
-        // TODO: refactor this block, add tests, cover private identifier
+        // TODO: refactor this block, add tests
         match &assignment_expr.left {
             AssignmentTarget::SimpleAssignmentTarget(target) => match target {
                 SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) => {
@@ -79,7 +76,9 @@ impl<'a> LogicalAssignmentOperators<'a> {
                     // `a.b &&= c` -> `var _a; (_a = a).b && (_a.b = c)`
                     match &**member_expr {
                         MemberExpression::StaticMemberExpression(static_expr) => {
-                            if let Some(ident) = self.maybe_generate_memoised(&static_expr.object) {
+                            if let Some(ident) =
+                                self.maybe_generate_memoised_object(&static_expr.object)
+                            {
                                 let right = self.ast.copy(&static_expr.object);
                                 let mut expr = self.ast.copy(static_expr);
                                 let target = AssignmentTarget::SimpleAssignmentTarget(
@@ -111,7 +110,8 @@ impl<'a> LogicalAssignmentOperators<'a> {
                         // `a[b.y] &&= c;` ->
                         // `var _a, _b$y; (_a = a)[_b$y = b.y] && (_a[_b$y] = c);`
                         MemberExpression::ComputedMemberExpression(computed_expr) => {
-                            if let Some(ident) = self.maybe_generate_memoised(&computed_expr.object)
+                            if let Some(ident) =
+                                self.maybe_generate_memoised_object(&computed_expr.object)
                             {
                                 let property_ident =
                                     self.maybe_generate_memoised(&computed_expr.expression);
@@ -185,7 +185,27 @@ impl<'a> LogicalAssignmentOperators<'a> {
                                     );
                             };
                         }
-                        MemberExpression::PrivateFieldExpression(_) => return,
+                        // `a.#b &&= c` -> `var _a; (_a = a).#b && (_a.#b = c)`, the private field
+                        // reads and writes are then lowered by the class properties transform.
+                        MemberExpression::PrivateFieldExpression(private_expr) => {
+                            let mut expr = self.ast.copy(private_expr);
+                            let mut target = self.ast.copy(private_expr);
+                            if let Some(ident) = self.maybe_generate_memoised(&private_expr.object)
+                            {
+                                let left = AssignmentTarget::SimpleAssignmentTarget(
+                                    self.ast.simple_assignment_target_identifier(ident.clone()),
+                                );
+                                let right = self.ast.copy(&private_expr.object);
+                                expr.object = self.ast.assignment_expression(span, op, left, right);
+                                target.object = self.ast.identifier_reference_expression(ident);
+                            }
+                            left_expr = self
+                                .ast
+                                .member_expression(MemberExpression::PrivateFieldExpression(expr));
+                            assign_target = self.ast.simple_assignment_target_member_expression(
+                                MemberExpression::PrivateFieldExpression(target),
+                            );
+                        }
                     }
                 }
                 // All other are TypeScript syntax.
@@ -202,10 +222,51 @@ impl<'a> LogicalAssignmentOperators<'a> {
         let right =
             self.ast.assignment_expression(Span::default(), assign_op, assign_target, right);
 
-        let logical_expr = self.ast.logical_expression(Span::default(), left_expr, operator, right);
+        let logical_expr =
+            self.ast.logical_expression(assignment_expr.span, left_expr, operator, right);
 
         *expr = logical_expr;
     }
+
+    /// `super` is not a value which can be assigned to a variable, only the key of `super[key]`
+    /// is memoised: `super[a()] &&= b` -> `super[_ref = a()] && (super[_ref] = b)`.
+    fn maybe_generate_memoised_object(
+        &mut self,
+        object: &Expression<'a>,
+    ) -> Option<IdentifierReference> {
+        if matches!(object, Expression::Super(_)) {
+            return None;
+        }
+        self.maybe_generate_memoised(object)
+    }
 }
 
-// TODO: test all permutations
+#[test]
+#[rustfmt::skip]
+fn test() {
+    use crate::tester::Tester;
+
+    let options = TransformOptions { target: TransformTarget::ES2020, ..TransformOptions::default() };
+    let tests = &[
+        ("a &&= b;", "a && (a = b);"),
+        ("a ||= b;", "a || (a = b);"),
+        ("a ??= b;", "a ?? (a = b);"),
+        ("a.b ||= c;", "var _a; (_a = a).b || (_a.b = c);"),
+        ("a[b] ??= c;", "var _a, _b; (_a = a)[_b = b] ?? (_a[_b] = c);"),
+        (
+            "class A extends B { m() { super.x &&= 1; super[k()] ||= 2; super[k] ??= 3; } }",
+            "class A extends B { m() { var _ref, _k; super.x && (super.x = 1); super[_ref = k()] || (super[_ref] = 2); super[_k = k] ?? (super[_k] = 3); } }",
+        ),
+        (
+            "var o = { m() { super.x ||= 1; } };",
+            "var o = { m() { super.x || (super.x = 1); } };",
+        ),
+        ("class A { #x; m(o) { this.#x ??= 1; o.#x ||= 2; o.p.#x &&= 3; } }", "var _x = new WeakMap(); class A { constructor() { babelHelpers.classPrivateFieldInitSpec(this, _x, { writable: true, value: void 0 }); } m(o) { var _o$p; babelHelpers.classPrivateFieldGet(this, _x) ?? babelHelpers.classPrivateFieldSet(this, _x, 1); babelHelpers.classPrivateFieldGet(o, _x) || babelHelpers.classPrivateFieldSet(o, _x, 2); babelHelpers.classPrivateFieldGet(_o$p = o.p, _x) && babelHelpers.classPrivateFieldSet(_o$p, _x, 3); } }"),
+    ];
+    Tester::new("test.js", options).test(tests);
+
+    let options = TransformOptions { logical_assignment_operators: true, ..TransformOptions::default() };
+    Tester::new("test.js", options).test(&[
+        ("class A { #x; m(o) { o.#x ||= 1; o.p.#x ??= 2; } }", "class A { #x; m(o) { var _o$p; o.#x || (o.#x = 1); (_o$p = o.p).#x ?? (_o$p.#x = 2); } }"),
+    ]);
+}
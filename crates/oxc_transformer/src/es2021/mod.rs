@@ -1,3 +1,5 @@
 mod logical_assignment_operators;
+mod numeric_separator;
 
 pub use logical_assignment_operators::LogicalAssignmentOperators;
+pub use numeric_separator::NumericSeparator;
@@ -0,0 +1,51 @@
+use std::rc::Rc;
+
+use oxc_ast::{ast::*, AstBuilder};
+
+use crate::{
+    options::{TransformOptions, TransformTarget},
+    targets::Feature,
+};
+
+/// ES2021: Numeric Separator
+///
+/// * `1_000_000` -> `1000000`
+/// * `0xFF_FF` -> `0xFFFF`
+///
+/// The BigInt literals are printed from their value, without the separators.
+///
+/// References:
+/// * <https://babel.dev/docs/babel-plugin-transform-numeric-separator>
+/// * <https://github.com/babel/babel/tree/main/packages/babel-plugin-transform-numeric-separator>
+pub struct NumericSeparator<'a> {
+    ast: Rc<AstBuilder<'a>>,
+}
+
+impl<'a> NumericSeparator<'a> {
+    pub fn new(ast: Rc<AstBuilder<'a>>, options: &TransformOptions) -> Option<Self> {
+        (options.target < TransformTarget::ES2021
+            || options.numeric_separator
+            || options.targets.needs_transform(Feature::NumericSeparator))
+        .then(|| Self { ast })
+    }
+
+    pub fn transform_expression(&self, expr: &mut Expression<'a>) {
+        let Expression::NumberLiteral(literal) = expr else { return };
+        if literal.raw.contains('_') {
+            literal.raw = self.ast.new_str(&literal.raw.replace('_', ""));
+        }
+    }
+}
+
+#[test]
+#[rustfmt::skip]
+fn test() {
+    use crate::tester::Tester;
+
+    let options = TransformOptions { target: TransformTarget::ES2020, ..TransformOptions::default() };
+    Tester::new("test.js", options).test(&[
+        ("x = 1_000_000 + 0.000_001 + 1e1_0;", "x = 1000000 + 0.000001 + 1e10;"),
+        ("x = 0xFF_FF + 0o7_7 + 0b1_0;", "x = 0xFFFF + 0o77 + 0b10;"),
+        ("x = 1_000n;", "x = 1000n;"),
+    ]);
+}
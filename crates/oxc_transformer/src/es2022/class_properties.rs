@@ -0,0 +1,1216 @@
+use serde::Deserialize;
+use std::{
+    collections::{HashMap, HashSet},
+    mem,
+    rc::Rc,
+};
+
+use oxc_allocator::Vec;
+use oxc_ast::{ast::*, AstBuilder, VisitMut};
+use oxc_span::{Atom, Span};
+use oxc_syntax::operator::{AssignmentOperator, BinaryOperator, UnaryOperator, UpdateOperator};
+
+use crate::{
+    context::TransformerCtx,
+    options::{TransformOptions, TransformTarget},
+    targets::Feature,
+    utils::CreateVars,
+};
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+pub struct ClassPropertiesOptions {
+    /// When true, public class fields are compiled to assignments instead of `Object.defineProperty`,
+    /// and private fields are stored as non-enumerable properties instead of in a `WeakMap`.
+    #[serde(default)]
+    loose: bool,
+}
+
+/// ES2022: Class Properties and Private Methods
+///
+/// Private methods and accessors share private names, helpers and the class rewriting with class properties,
+/// so both are lowered by this pass.
+///
+/// References:
+/// * <https://babel.dev/docs/babel-plugin-transform-class-properties>
+/// * <https://babel.dev/docs/babel-plugin-transform-private-methods>
+/// * <https://github.com/babel/babel/tree/main/packages/babel-plugin-transform-class-properties>
+/// * <https://github.com/babel/babel/tree/main/packages/babel-plugin-transform-private-methods>
+/// * <https://github.com/babel/babel/tree/main/packages/babel-helper-create-class-features-plugin>
+pub struct ClassProperties<'a> {
+    /// Lower public and private fields.
+    class_properties: bool,
+    /// Lower private methods and accessors.
+    private_methods: bool,
+
+    set_public_class_fields: bool,
+    private_fields_as_properties: bool,
+    /// Private fields are keyed by a `Symbol` rather than a `classPrivateFieldLooseKey` string.
+    private_fields_as_symbols: bool,
+
+    ast: Rc<AstBuilder<'a>>,
+    ctx: TransformerCtx<'a>,
+
+    vars: Vec<'a, VariableDeclarator<'a>>,
+
+    /// The enclosing classes, the innermost class is last.
+    classes: std::vec::Vec<ClassScope>,
+
+    /// Static initializers of the class expression which has just been visited,
+    /// they are attached to the class expression by `transform_class_expression`.
+    class_expression: Option<(Atom, Vec<'a, Expression<'a>>)>,
+
+    /// Statements to insert before and after the statement being visited.
+    before_statements: Vec<'a, Statement<'a>>,
+    after_statements: Vec<'a, Statement<'a>>,
+}
+
+struct ClassScope {
+    /// The binding to reference the class with from outside of the class body,
+    /// only available when the class has static members.
+    name: Option<Atom>,
+    private_names: HashMap<Atom, PrivateName>,
+}
+
+#[derive(Debug, Clone)]
+struct PrivateName {
+    /// `x` in `#x`
+    name: Atom,
+    /// The `WeakMap`, `WeakSet` or private key variable holding the private member.
+    binding: Atom,
+    r#static: bool,
+    /// The binding referencing the class, used by static private members.
+    class_name: Option<Atom>,
+    kind: PrivateKind,
+}
+
+#[derive(Debug, Clone)]
+enum PrivateKind {
+    Field,
+    /// `#m() {}`, hoisted to the function `_m2`.
+    Method(Atom),
+    /// `get #x() {}` and `set #x(v) {}`, hoisted to the functions `_get_x` and `_set_x`.
+    Accessor {
+        getter: Option<Atom>,
+        setter: Option<Atom>,
+    },
+}
+
+/// A static member initialized after the class is defined.
+enum StaticInitializer<'a> {
+    Expression(Expression<'a>),
+    /// `var _x = { writable: true, value: 1 }` for a static private field.
+    Var(Atom, Expression<'a>),
+}
+
+impl<'a> CreateVars<'a> for ClassProperties<'a> {
+    fn ctx(&self) -> &TransformerCtx<'a> {
+        &self.ctx
+    }
+
+    fn vars_mut(&mut self) -> &mut Vec<'a, VariableDeclarator<'a>> {
+        &mut self.vars
+    }
+}
+
+impl<'a> ClassProperties<'a> {
+    pub fn new(
+        ast: Rc<AstBuilder<'a>>,
+        ctx: TransformerCtx<'a>,
+        options: &TransformOptions,
+    ) -> Option<Self> {
+        let lower = options.target < TransformTarget::ES2022;
+        let class_properties = lower
+            || options.class_properties.is_some()
+            || options.targets.needs_transform(Feature::ClassProperties);
+        let private_methods = lower
+            || options.private_methods
+            || options.targets.needs_transform(Feature::PrivateMethods);
+        (class_properties || private_methods).then(|| {
+            let loose = options.class_properties.is_some_and(|o| o.loose);
+            let set_public_class_fields = options.assumptions.set_public_class_fields || loose;
+            let private_fields_as_symbols = options.assumptions.private_fields_as_symbols;
+            let private_fields_as_properties = options.assumptions.private_fields_as_properties
+                || private_fields_as_symbols
+                || loose;
+            Self {
+                class_properties,
+                private_methods,
+                set_public_class_fields,
+                private_fields_as_properties,
+                private_fields_as_symbols,
+                vars: ast.new_vec(),
+                classes: vec![],
+                class_expression: None,
+                before_statements: ast.new_vec(),
+                after_statements: ast.new_vec(),
+                ast,
+                ctx,
+            }
+        })
+    }
+
+    /// Take the statements to be inserted before and after the statement which has just been visited.
+    pub fn take_statements(&mut self) -> (Vec<'a, Statement<'a>>, Vec<'a, Statement<'a>>) {
+        let before = mem::replace(&mut self.before_statements, self.ast.new_vec());
+        let after = mem::replace(&mut self.after_statements, self.ast.new_vec());
+        (before, after)
+    }
+
+    pub fn enter_class(&mut self, class: &mut Class<'a>) {
+        let has_static_member =
+            class.body.body.iter().any(|element| self.is_lowered(element) && element.r#static());
+        let class_name = has_static_member.then(|| self.class_name(class));
+
+        let mut private_names: HashMap<Atom, PrivateName> = HashMap::new();
+        for element in &class.body.body {
+            if !self.is_lowered(element) {
+                continue;
+            }
+            let Some(PropertyKey::PrivateIdentifier(ident)) = element.property_key() else {
+                continue;
+            };
+            let name = &ident.name;
+            let kind = match element.method_definition_kind() {
+                None => PrivateKind::Field,
+                Some(MethodDefinitionKind::Get | MethodDefinitionKind::Set) => {
+                    let is_getter =
+                        element.method_definition_kind() == Some(MethodDefinitionKind::Get);
+                    let function = self
+                        .generate_uid(&format!("{}_{name}", if is_getter { "get" } else { "set" }));
+                    // The other half of an accessor pair has been seen already.
+                    if let Some(PrivateName {
+                        kind: PrivateKind::Accessor { getter, setter },
+                        ..
+                    }) = private_names.get_mut(name)
+                    {
+                        *(if is_getter { getter } else { setter }) = Some(function);
+                        continue;
+                    }
+                    let (getter, setter) =
+                        if is_getter { (Some(function), None) } else { (None, Some(function)) };
+                    PrivateKind::Accessor { getter, setter }
+                }
+                Some(_) => {
+                    // `_m` is the brand check `WeakSet`, `_m2` is the method.
+                    let binding = self.generate_uid(name);
+                    let function = self.generate_uid(name);
+                    let private_name = PrivateName {
+                        name: name.clone(),
+                        binding,
+                        r#static: element.r#static(),
+                        class_name: class_name.clone(),
+                        kind: PrivateKind::Method(function),
+                    };
+                    private_names.insert(name.clone(), private_name);
+                    continue;
+                }
+            };
+            let binding = self.generate_uid(name);
+            let private_name = PrivateName {
+                name: name.clone(),
+                binding,
+                r#static: element.r#static(),
+                class_name: class_name.clone(),
+                kind,
+            };
+            private_names.insert(name.clone(), private_name);
+        }
+
+        self.classes.push(ClassScope { name: class_name, private_names });
+    }
+
+    /// Whether the class element is lowered by this pass.
+    fn is_lowered(&self, element: &ClassElement<'a>) -> bool {
+        match element {
+            ClassElement::PropertyDefinition(prop) => self.class_properties && !prop.declare,
+            ClassElement::MethodDefinition(method) => {
+                self.private_methods && method.key.is_private_identifier()
+            }
+            _ => false,
+        }
+    }
+
+    fn generate_uid(&self, name: &str) -> Atom {
+        let name = self.ctx.scopes().generate_uid(name);
+        self.ctx.add_binding(name.clone());
+        name
+    }
+
+    pub fn leave_class(&mut self, class: &mut Class<'a>) {
+        let Some(scope) = self.classes.pop() else { return };
+        if !class.body.body.iter().any(|element| self.is_lowered(element)) {
+            return;
+        }
+
+        let span = Span::default();
+        let mut method_initializers = self.ast.new_vec();
+        let mut instance_initializers = self.ast.new_vec();
+        let mut static_initializers = vec![];
+        let mut functions = self.ast.new_vec();
+        let mut initialized_private_names = HashSet::new();
+
+        let elements = mem::replace(&mut class.body.body, self.ast.new_vec());
+        for element in elements {
+            if !self.is_lowered(&element) {
+                class.body.body.push(element);
+                continue;
+            }
+            let mut prop = match element {
+                ClassElement::PropertyDefinition(prop) => prop,
+                ClassElement::MethodDefinition(method) => {
+                    let Some(private_name) =
+                        method.key.private_name().and_then(|name| scope.private_names.get(&name))
+                    else {
+                        continue;
+                    };
+                    functions.push(self.private_method_function(&method, private_name));
+                    let object = if private_name.r#static {
+                        self.class_reference(scope.name.as_ref())
+                    } else {
+                        self.ast.this_expression(span)
+                    };
+                    // Accessor pairs are initialized once.
+                    if !initialized_private_names.insert(private_name.binding.clone()) {
+                        continue;
+                    }
+                    match self.private_method_initializer(object, private_name) {
+                        Some(StaticInitializer::Expression(init)) if !private_name.r#static => {
+                            method_initializers.push(self.ast.expression_statement(span, init));
+                        }
+                        Some(initializer) => static_initializers.push(initializer),
+                        None => {}
+                    }
+                    continue;
+                }
+                _ => unreachable!(),
+            };
+
+            let mut value = prop.value.take().unwrap_or_else(|| self.ast.void_0());
+            let object = if prop.r#static {
+                if let Some(class_name) = &scope.name {
+                    ReplaceThis { ast: &self.ast, class_name }.visit_expression(&mut value);
+                }
+                self.class_reference(scope.name.as_ref())
+            } else {
+                self.ast.this_expression(span)
+            };
+
+            let initializer = if let PropertyKey::PrivateIdentifier(ident) = &prop.key {
+                let Some(private_name) = scope.private_names.get(&ident.name) else {
+                    continue;
+                };
+                self.private_field_initializer(object, private_name, value)
+            } else {
+                StaticInitializer::Expression(
+                    self.public_field_initializer(&mut prop, object, value),
+                )
+            };
+            match initializer {
+                StaticInitializer::Expression(init) if !prop.r#static => {
+                    instance_initializers.push(self.ast.expression_statement(span, init));
+                }
+                initializer => static_initializers.push(initializer),
+            }
+        }
+
+        // Private methods are initialized before fields.
+        method_initializers.extend(instance_initializers);
+        if !method_initializers.is_empty() {
+            self.insert_into_constructor(class, method_initializers);
+        }
+
+        // Function declarations are hoisted, so they can be placed before a class expression.
+        if class.is_declaration() {
+            self.after_statements.extend(functions);
+        } else {
+            self.before_statements.extend(functions);
+        }
+
+        if static_initializers.is_empty() {
+            return;
+        }
+
+        if class.is_declaration() {
+            for initializer in static_initializers {
+                let stmt = match initializer {
+                    StaticInitializer::Expression(expr) => {
+                        self.ast.expression_statement(span, expr)
+                    }
+                    StaticInitializer::Var(name, init) => self.var_statement(name, init),
+                };
+                self.after_statements.push(stmt);
+            }
+        } else {
+            let mut expressions = self.ast.new_vec();
+            for initializer in static_initializers {
+                let expr = match initializer {
+                    StaticInitializer::Expression(expr) => expr,
+                    StaticInitializer::Var(name, init) => {
+                        let binding = BindingIdentifier::new(span, name.clone());
+                        let binding = self.ast.binding_pattern(
+                            self.ast.binding_pattern_identifier(binding),
+                            None,
+                            false,
+                        );
+                        let kind = VariableDeclarationKind::Var;
+                        self.vars
+                            .push(self.ast.variable_declarator(span, kind, binding, None, false));
+                        self.assign(&name, init)
+                    }
+                };
+                expressions.push(expr);
+            }
+            self.class_expression = scope.name.map(|name| (name, expressions));
+        }
+    }
+
+    /// Private fields are stored in a `WeakMap`, or as properties with an unique key when `privateFieldsAsProperties`.
+    /// Instance initializers are returned as `StaticInitializer::Expression` to be put into the constructor.
+    fn private_field_initializer(
+        &mut self,
+        object: Expression<'a>,
+        private_name: &PrivateName,
+        value: Expression<'a>,
+    ) -> StaticInitializer<'a> {
+        let span = Span::default();
+        let binding = private_name.binding.clone();
+        if self.private_fields_as_properties {
+            // var _x = babelHelpers.classPrivateFieldLooseKey("x");
+            let init = self.private_key(&private_name.name);
+            let stmt = self.var_statement(binding.clone(), init);
+            self.before_statements.push(stmt);
+            // Object.defineProperty(this, _x, { writable: true, value: 1 })
+            let descriptor = self.private_field_descriptor(value);
+            let init = self.object_define_property(object, self.identifier(&binding), descriptor);
+            return StaticInitializer::Expression(init);
+        }
+        if private_name.r#static {
+            // var _x = { writable: true, value: 1 };
+            let descriptor = self.private_field_descriptor(value);
+            return StaticInitializer::Var(binding, descriptor);
+        }
+        // var _x = new WeakMap();
+        let callee = self.identifier("WeakMap");
+        let init = self.ast.new_expression(span, callee, self.ast.new_vec(), None);
+        let stmt = self.var_statement(binding.clone(), init);
+        self.before_statements.push(stmt);
+        // babelHelpers.classPrivateFieldInitSpec(this, _x, { writable: true, value: 1 })
+        let mut arguments = self.ast.new_vec_with_capacity(3);
+        arguments.push(Argument::Expression(object));
+        arguments.push(Argument::Expression(self.identifier(&binding)));
+        arguments.push(Argument::Expression(self.private_field_descriptor(value)));
+        StaticInitializer::Expression(self.ctx.helper_call("classPrivateFieldInitSpec", arguments))
+    }
+
+    /// `this.x = 1` when `setPublicClassFields`, otherwise `babelHelpers.defineProperty(this, "x", 1)`
+    fn public_field_initializer(
+        &mut self,
+        prop: &mut PropertyDefinition<'a>,
+        object: Expression<'a>,
+        value: Expression<'a>,
+    ) -> Expression<'a> {
+        let span = Span::default();
+        let key = self.property_key(prop);
+        if self.set_public_class_fields {
+            let target = match key {
+                PropertyKeyExpression::Static(name) => {
+                    self.ast.static_member(span, object, IdentifierName::new(span, name), false)
+                }
+                PropertyKeyExpression::Computed(key) => {
+                    self.ast.computed_member(span, object, key, false)
+                }
+            };
+            let target = AssignmentTarget::SimpleAssignmentTarget(
+                self.ast.simple_assignment_target_member_expression(target),
+            );
+            return self.ast.assignment_expression(span, AssignmentOperator::Assign, target, value);
+        }
+        let key = match key {
+            PropertyKeyExpression::Static(name) => self.string_literal(&name),
+            PropertyKeyExpression::Computed(key) => key,
+        };
+        let mut arguments = self.ast.new_vec_with_capacity(3);
+        arguments.push(Argument::Expression(object));
+        arguments.push(Argument::Expression(key));
+        arguments.push(Argument::Expression(value));
+        self.ctx.helper_call("defineProperty", arguments)
+    }
+
+    /// `#m() {}` -> `function _m2() {}`
+    fn private_method_function(
+        &self,
+        method: &MethodDefinition<'a>,
+        private_name: &PrivateName,
+    ) -> Statement<'a> {
+        let name = match (&private_name.kind, method.kind) {
+            (PrivateKind::Method(function), _) => function.clone(),
+            (PrivateKind::Accessor { getter: Some(getter), .. }, MethodDefinitionKind::Get) => {
+                getter.clone()
+            }
+            (PrivateKind::Accessor { setter: Some(setter), .. }, MethodDefinitionKind::Set) => {
+                setter.clone()
+            }
+            _ => unreachable!(),
+        };
+        // The method definition is discarded, so its function can be taken out.
+        let mut function = self.ast.copy(&method.value);
+        function.r#type = FunctionType::FunctionDeclaration;
+        function.id = Some(BindingIdentifier::new(Span::default(), name));
+        self.ast.function_declaration(function)
+    }
+
+    /// Brand check and descriptor of a private method or accessor.
+    /// Instance initializers are returned as `StaticInitializer::Expression` to be put into the constructor.
+    fn private_method_initializer(
+        &mut self,
+        object: Expression<'a>,
+        private_name: &PrivateName,
+    ) -> Option<StaticInitializer<'a>> {
+        let span = Span::default();
+        let binding = private_name.binding.clone();
+        let descriptor = match &private_name.kind {
+            PrivateKind::Method(function) => {
+                // `{ value: _m2 }`
+                self.descriptor([("value", self.identifier(function))])
+            }
+            PrivateKind::Accessor { getter, setter } => {
+                // `{ get: _get_x, set: void 0 }`
+                let getter =
+                    getter.as_ref().map_or_else(|| self.ast.void_0(), |g| self.identifier(g));
+                let setter =
+                    setter.as_ref().map_or_else(|| self.ast.void_0(), |s| self.identifier(s));
+                self.descriptor([("get", getter), ("set", setter)])
+            }
+            PrivateKind::Field => unreachable!(),
+        };
+
+        if self.private_fields_as_properties {
+            // var _m = babelHelpers.classPrivateFieldLooseKey("m");
+            let init = self.private_key(&private_name.name);
+            let stmt = self.var_statement(binding.clone(), init);
+            self.before_statements.push(stmt);
+            // Object.defineProperty(this, _m, { value: _m2 })
+            let init = self.object_define_property(object, self.identifier(&binding), descriptor);
+            return Some(StaticInitializer::Expression(init));
+        }
+
+        match (&private_name.kind, private_name.r#static) {
+            // Static methods are called directly.
+            (PrivateKind::Method(_), true) => None,
+            // var _x = { get: _get_x, set: void 0 };
+            (_, true) => Some(StaticInitializer::Var(binding, descriptor)),
+            // var _m = new WeakSet();
+            // babelHelpers.classPrivateMethodInitSpec(this, _m)
+            (PrivateKind::Method(_), false) => {
+                let callee = self.identifier("WeakSet");
+                let init = self.ast.new_expression(span, callee, self.ast.new_vec(), None);
+                let stmt = self.var_statement(binding.clone(), init);
+                self.before_statements.push(stmt);
+                let mut arguments = self.ast.new_vec_with_capacity(2);
+                arguments.push(Argument::Expression(object));
+                arguments.push(Argument::Expression(self.identifier(&binding)));
+                let init = self.ctx.helper_call("classPrivateMethodInitSpec", arguments);
+                Some(StaticInitializer::Expression(init))
+            }
+            // var _x = new WeakMap();
+            // babelHelpers.classPrivateFieldInitSpec(this, _x, { get: _get_x, set: void 0 })
+            (_, false) => {
+                let callee = self.identifier("WeakMap");
+                let init = self.ast.new_expression(span, callee, self.ast.new_vec(), None);
+                let stmt = self.var_statement(binding.clone(), init);
+                self.before_statements.push(stmt);
+                let mut arguments = self.ast.new_vec_with_capacity(3);
+                arguments.push(Argument::Expression(object));
+                arguments.push(Argument::Expression(self.identifier(&binding)));
+                arguments.push(Argument::Expression(descriptor));
+                let init = self.ctx.helper_call("classPrivateFieldInitSpec", arguments);
+                Some(StaticInitializer::Expression(init))
+            }
+        }
+    }
+
+    /// Attach the static initializers of a class expression:
+    /// `(_Foo = class Foo {}, babelHelpers.defineProperty(_Foo, "x", 1), _Foo)`
+    pub fn transform_class_expression(&mut self, expr: &mut Expression<'a>) {
+        if !matches!(expr, Expression::ClassExpression(_)) {
+            return;
+        }
+        let Some((name, initializers)) = self.class_expression.take() else { return };
+        let class = self.ast.move_expression(expr);
+        let mut expressions = self.ast.new_vec_with_capacity(initializers.len() + 2);
+        expressions.push(self.assign(&name, class));
+        expressions.extend(initializers);
+        expressions.push(self.identifier(&name));
+        *expr = self.ast.sequence_expression(Span::default(), expressions);
+    }
+
+    pub fn transform_expression(&mut self, expr: &mut Expression<'a>) {
+        match expr {
+            // `obj.#x`
+            Expression::MemberExpression(member_expr) => {
+                let MemberExpression::PrivateFieldExpression(field_expr) = &mut **member_expr
+                else {
+                    return;
+                };
+                let Some(private_name) = self.lookup(&field_expr.field.name) else { return };
+                let object = self.ast.move_expression(&mut field_expr.object);
+                *expr = self.private_field_get(object, &private_name);
+            }
+            // `obj.#x = value`, `obj.#x += value`
+            Expression::AssignmentExpression(assign_expr) => {
+                let Some((object, private_name)) = self.private_field_target(&mut assign_expr.left)
+                else {
+                    return;
+                };
+                let value = self.ast.move_expression(&mut assign_expr.right);
+                if assign_expr.operator == AssignmentOperator::Assign {
+                    *expr = self.private_field_set(object, &private_name, value);
+                    return;
+                }
+                if let Some(operator) = assign_expr.operator.to_logical_operator() {
+                    // `babelHelpers.classPrivateFieldGet(_obj = obj, _x) ?? babelHelpers.classPrivateFieldSet(_obj, _x, value)`
+                    let (object, reference) = self.memoise(object);
+                    let current = self.private_field_get(object, &private_name);
+                    let value = self.private_field_set(reference, &private_name, value);
+                    *expr = self.ast.logical_expression(Span::default(), current, operator, value);
+                    return;
+                }
+                let Some(operator) = assign_expr.operator.to_binary_operator() else { return };
+                // `babelHelpers.classPrivateFieldSet(_obj = obj, _x, babelHelpers.classPrivateFieldGet(_obj, _x) + value)`
+                let (object, reference) = self.memoise(object);
+                let current = self.private_field_get(reference, &private_name);
+                let value = self.ast.binary_expression(Span::default(), current, operator, value);
+                *expr = self.private_field_set(object, &private_name, value);
+            }
+            // `obj.#x++`, `--obj.#x`
+            Expression::UpdateExpression(update_expr) => {
+                let SimpleAssignmentTarget::MemberAssignmentTarget(member_expr) =
+                    &mut update_expr.argument
+                else {
+                    return;
+                };
+                let MemberExpression::PrivateFieldExpression(field_expr) = &mut **member_expr
+                else {
+                    return;
+                };
+                let Some(private_name) = self.lookup(&field_expr.field.name) else { return };
+                let object = self.ast.move_expression(&mut field_expr.object);
+                let span = Span::default();
+
+                if self.private_fields_as_properties {
+                    update_expr.argument = SimpleAssignmentTarget::MemberAssignmentTarget(
+                        self.ast.alloc(self.private_field_loose_member(object, &private_name)),
+                    );
+                    return;
+                }
+
+                let operator = match update_expr.operator {
+                    UpdateOperator::Increment => BinaryOperator::Addition,
+                    UpdateOperator::Decrement => BinaryOperator::Subtraction,
+                };
+                let (object, reference) = self.memoise(object);
+                let current = self.private_field_get(reference, &private_name);
+                let current = self.ast.unary_expression(span, UnaryOperator::UnaryPlus, current);
+                let one = self.number_literal(1.0, "1");
+                if update_expr.prefix {
+                    // `babelHelpers.classPrivateFieldSet(obj, _x, +babelHelpers.classPrivateFieldGet(obj, _x) + 1)`
+                    let value = self.ast.binary_expression(span, current, operator, one);
+                    *expr = self.private_field_set(object, &private_name, value);
+                } else {
+                    // `(babelHelpers.classPrivateFieldSet(obj, _x, (_old = +babelHelpers.classPrivateFieldGet(obj, _x)) + 1), _old)`
+                    let old = self.create_new_named_var("old");
+                    let old_assign = self.assign(&old.name, current);
+                    let value = self.ast.binary_expression(span, old_assign, operator, one);
+                    let mut expressions = self.ast.new_vec_with_capacity(2);
+                    expressions.push(self.private_field_set(object, &private_name, value));
+                    expressions.push(self.ast.identifier_reference_expression(old));
+                    *expr = self.ast.sequence_expression(span, expressions);
+                }
+            }
+            // `obj.#x(...args)` -> `babelHelpers.classPrivateFieldGet(_obj = obj, _x).call(_obj, ...args)`
+            Expression::CallExpression(call_expr) => {
+                let Expression::MemberExpression(member_expr) = &mut call_expr.callee else {
+                    return;
+                };
+                let MemberExpression::PrivateFieldExpression(field_expr) = &mut **member_expr
+                else {
+                    return;
+                };
+                let Some(private_name) = self.lookup(&field_expr.field.name) else { return };
+                let object = self.ast.move_expression(&mut field_expr.object);
+                if self.private_fields_as_properties {
+                    call_expr.callee = self
+                        .ast
+                        .member_expression(self.private_field_loose_member(object, &private_name));
+                    return;
+                }
+                let span = Span::default();
+                let (object, reference) = self.memoise(object);
+                let callee = self.private_field_get(object, &private_name);
+                let property = IdentifierName::new(span, "call".into());
+                call_expr.callee = self.ast.static_member_expression(span, callee, property, false);
+                call_expr.arguments.insert(0, Argument::Expression(reference));
+            }
+            // `#x in obj`
+            Expression::PrivateInExpression(private_in_expr) => {
+                let Some(private_name) = self.lookup(&private_in_expr.left.name) else { return };
+                let span = Span::default();
+                let object = self.ast.move_expression(&mut private_in_expr.right);
+                let binding = self.identifier(&private_name.binding);
+                *expr = if self.private_fields_as_properties {
+                    // `Object.prototype.hasOwnProperty.call(obj, _x)`
+                    let callee =
+                        self.member_chain(&["Object", "prototype", "hasOwnProperty", "call"]);
+                    let mut arguments = self.ast.new_vec_with_capacity(2);
+                    arguments.push(Argument::Expression(object));
+                    arguments.push(Argument::Expression(binding));
+                    self.ast.call_expression(span, callee, arguments, false, None)
+                } else {
+                    let object = self.ctx.helper_call(
+                        "checkInRHS",
+                        self.ast.new_vec_single(Argument::Expression(object)),
+                    );
+                    if private_name.r#static {
+                        // `babelHelpers.checkInRHS(obj) === Foo`
+                        let class_name = self.class_reference(private_name.class_name.as_ref());
+                        self.ast.binary_expression(
+                            span,
+                            object,
+                            BinaryOperator::StrictEquality,
+                            class_name,
+                        )
+                    } else {
+                        // `_x.has(babelHelpers.checkInRHS(obj))`
+                        let property = IdentifierName::new(span, "has".into());
+                        let callee =
+                            self.ast.static_member_expression(span, binding, property, false);
+                        let arguments = self.ast.new_vec_single(Argument::Expression(object));
+                        self.ast.call_expression(span, callee, arguments, false, None)
+                    }
+                };
+            }
+            _ => {}
+        }
+    }
+
+    fn lookup(&self, name: &Atom) -> Option<PrivateName> {
+        self.classes.iter().rev().find_map(|scope| scope.private_names.get(name)).cloned()
+    }
+
+    /// Get the binding to refer to the class from outside of its body,
+    /// creating one when the class is anonymous or is an expression.
+    fn class_name(&mut self, class: &mut Class<'a>) -> Atom {
+        if class.is_declaration() {
+            if let Some(id) = &class.id {
+                return id.name.clone();
+            }
+            let name = self.ctx.scopes().generate_uid("Class");
+            self.ctx.add_binding(name.clone());
+            class.id = Some(BindingIdentifier::new(Span::default(), name.clone()));
+            return name;
+        }
+        let name = class.id.as_ref().map_or_else(|| Atom::from("Class"), |id| id.name.clone());
+        self.create_new_named_var(&name).name
+    }
+
+    /// Reference the class from outside of its body, static members always have a class name.
+    fn class_reference(&self, class_name: Option<&Atom>) -> Expression<'a> {
+        class_name
+            .map_or_else(|| self.ast.this_expression(Span::default()), |name| self.identifier(name))
+    }
+
+    /// Find where the initializers of instance properties go, creating the constructor when it does not exist.
+    fn insert_into_constructor(&mut self, class: &mut Class<'a>, stmts: Vec<'a, Statement<'a>>) {
+        let span = Span::default();
+        let is_derived = class.super_class.is_some();
+
+        let constructor = class.body.body.iter_mut().find_map(|element| match element {
+            ClassElement::MethodDefinition(method)
+                if method.kind == MethodDefinitionKind::Constructor =>
+            {
+                method.value.body.as_mut()
+            }
+            _ => None,
+        });
+
+        if let Some(body) = constructor {
+            // Insert after the top level `super()` call in derived classes.
+            let index = if is_derived {
+                body.statements
+                    .iter()
+                    .position(|stmt| {
+                        matches!(stmt, Statement::ExpressionStatement(stmt)
+                            if matches!(&stmt.expression, Expression::CallExpression(call)
+                                if matches!(call.callee, Expression::Super(_))))
+                    })
+                    .map_or(0, |i| i + 1)
+            } else {
+                0
+            };
+            for (i, stmt) in stmts.into_iter().enumerate() {
+                body.statements.insert(index + i, stmt);
+            }
+            return;
+        }
+
+        // `constructor(...args) { super(...args); }` for derived classes, `constructor() {}` otherwise.
+        let mut statements = self.ast.new_vec();
+        let mut rest = None;
+        if is_derived {
+            let name = self.ctx.scopes().generate_uid("args");
+            let binding = BindingIdentifier::new(span, name.clone());
+            let binding =
+                self.ast.binding_pattern(self.ast.binding_pattern_identifier(binding), None, false);
+            rest = Some(self.ast.rest_element(span, binding));
+            let argument = self.ast.spread_element(span, self.identifier(&name));
+            let arguments = self.ast.new_vec_single(Argument::SpreadElement(argument));
+            let super_call =
+                self.ast.call_expression(span, self.ast.super_(span), arguments, false, None);
+            statements.push(self.ast.expression_statement(span, super_call));
+        }
+        statements.extend(stmts);
+
+        let params = self.ast.formal_parameters(
+            span,
+            FormalParameterKind::UniqueFormalParameters,
+            self.ast.new_vec(),
+            rest,
+        );
+        let body = self.ast.function_body(span, self.ast.new_vec(), statements);
+        let function = self.ast.function(
+            FunctionType::FunctionExpression,
+            span,
+            None,
+            false,
+            false,
+            false,
+            params,
+            Some(body),
+            None,
+            None,
+            Modifiers::empty(),
+        );
+        let key = PropertyKey::Identifier(
+            self.ast.alloc(IdentifierName::new(span, "constructor".into())),
+        );
+        let constructor = self.ast.class_method(
+            span,
+            key,
+            function,
+            MethodDefinitionKind::Constructor,
+            false,
+            false,
+        );
+        class.body.body.insert(0, constructor);
+    }
+
+    /// The key of a public property, computed keys are memoised before the class definition
+    /// so they are evaluated only once.
+    fn property_key(&mut self, prop: &mut PropertyDefinition<'a>) -> PropertyKeyExpression<'a> {
+        match &mut prop.key {
+            PropertyKey::Identifier(ident) => PropertyKeyExpression::Static(ident.name.clone()),
+            PropertyKey::Expression(Expression::StringLiteral(lit)) if !prop.computed => {
+                PropertyKeyExpression::Computed(self.string_literal(&lit.value))
+            }
+            PropertyKey::Expression(key) => {
+                if key.is_literal() || self.ctx.symbols().is_static(key) {
+                    return PropertyKeyExpression::Computed(self.ast.move_expression(key));
+                }
+                let ident = self.create_new_var(key);
+                let key = self.ast.move_expression(key);
+                let init = self.assign(&ident.name, key);
+                self.before_statements.push(self.ast.expression_statement(Span::default(), init));
+                PropertyKeyExpression::Computed(self.ast.identifier_reference_expression(ident))
+            }
+            PropertyKey::PrivateIdentifier(_) => unreachable!(),
+        }
+    }
+
+    /// `{ writable: true, value: value }`
+    fn private_field_descriptor(&self, value: Expression<'a>) -> Expression<'a> {
+        let writable = self
+            .ast
+            .literal_boolean_expression(BooleanLiteral { span: Span::default(), value: true });
+        self.descriptor([("writable", writable), ("value", value)])
+    }
+
+    /// `{ key: value, ... }`
+    fn descriptor<const N: usize>(
+        &self,
+        properties: [(&str, Expression<'a>); N],
+    ) -> Expression<'a> {
+        let span = Span::default();
+        let mut object_properties = self.ast.new_vec_with_capacity(N);
+        for (name, value) in properties {
+            let key =
+                PropertyKey::Identifier(self.ast.alloc(IdentifierName::new(span, name.into())));
+            let property = self.ast.object_property(
+                span,
+                PropertyKind::Init,
+                key,
+                value,
+                None,
+                false,
+                false,
+                false,
+            );
+            object_properties.push(ObjectPropertyKind::ObjectProperty(property));
+        }
+        self.ast.object_expression(span, object_properties, None)
+    }
+
+    /// Read a private field.
+    fn private_field_get(
+        &mut self,
+        object: Expression<'a>,
+        private_name: &PrivateName,
+    ) -> Expression<'a> {
+        if self.private_fields_as_properties {
+            return self
+                .ast
+                .member_expression(self.private_field_loose_member(object, private_name));
+        }
+        let mut arguments = self.ast.new_vec_with_capacity(3);
+        arguments.push(Argument::Expression(object));
+        let helper = match (&private_name.kind, private_name.r#static) {
+            // `babelHelpers.classStaticPrivateMethodGet(obj, Foo, _m2)`
+            (PrivateKind::Method(function), true) => {
+                let class_name = self.class_reference(private_name.class_name.as_ref());
+                arguments.push(Argument::Expression(class_name));
+                arguments.push(Argument::Expression(self.identifier(function)));
+                return self.ctx.helper_call("classStaticPrivateMethodGet", arguments);
+            }
+            // `babelHelpers.classPrivateMethodGet(obj, _m, _m2)`
+            (PrivateKind::Method(function), false) => {
+                arguments.push(Argument::Expression(self.identifier(&private_name.binding)));
+                arguments.push(Argument::Expression(self.identifier(function)));
+                return self.ctx.helper_call("classPrivateMethodGet", arguments);
+            }
+            (_, true) => {
+                let class_name = self.class_reference(private_name.class_name.as_ref());
+                arguments.push(Argument::Expression(class_name));
+                "classStaticPrivateFieldSpecGet"
+            }
+            (_, false) => "classPrivateFieldGet",
+        };
+        arguments.push(Argument::Expression(self.identifier(&private_name.binding)));
+        self.ctx.helper_call(helper, arguments)
+    }
+
+    /// Write a private field.
+    fn private_field_set(
+        &mut self,
+        object: Expression<'a>,
+        private_name: &PrivateName,
+        value: Expression<'a>,
+    ) -> Expression<'a> {
+        if self.private_fields_as_properties {
+            let target = self.private_field_loose_member(object, private_name);
+            let target = AssignmentTarget::SimpleAssignmentTarget(
+                self.ast.simple_assignment_target_member_expression(target),
+            );
+            return self.ast.assignment_expression(
+                Span::default(),
+                AssignmentOperator::Assign,
+                target,
+                value,
+            );
+        }
+        if matches!(private_name.kind, PrivateKind::Method(_)) {
+            // Private methods are not writable.
+            // `(value, babelHelpers.readOnlyError("#m"))`
+            let name = self.string_literal(&format!("#{}", private_name.name));
+            let error = self
+                .ctx
+                .helper_call("readOnlyError", self.ast.new_vec_single(Argument::Expression(name)));
+            let mut expressions = self.ast.new_vec_with_capacity(3);
+            expressions.push(object);
+            expressions.push(value);
+            expressions.push(error);
+            return self.ast.sequence_expression(Span::default(), expressions);
+        }
+        let mut arguments = self.ast.new_vec_with_capacity(4);
+        arguments.push(Argument::Expression(object));
+        let helper = if private_name.r#static {
+            let class_name = self.class_reference(private_name.class_name.as_ref());
+            arguments.push(Argument::Expression(class_name));
+            "classStaticPrivateFieldSpecSet"
+        } else {
+            "classPrivateFieldSet"
+        };
+        arguments.push(Argument::Expression(self.identifier(&private_name.binding)));
+        arguments.push(Argument::Expression(value));
+        self.ctx.helper_call(helper, arguments)
+    }
+
+    /// `babelHelpers.classPrivateFieldLooseKey("x")`, or `Symbol("x")` for `privateFieldsAsSymbols`
+    fn private_key(&self, name: &Atom) -> Expression<'a> {
+        let key = self.ast.new_vec_single(Argument::Expression(self.string_literal(name)));
+        if self.private_fields_as_symbols {
+            let callee = self.identifier("Symbol");
+            return self.ast.call_expression(Span::default(), callee, key, false, None);
+        }
+        self.ctx.helper_call("classPrivateFieldLooseKey", key)
+    }
+
+    /// `babelHelpers.classPrivateFieldLooseBase(obj, _x)[_x]`, or `obj[_x]` for
+    /// `privateFieldsAsSymbols`
+    fn private_field_loose_member(
+        &self,
+        object: Expression<'a>,
+        private_name: &PrivateName,
+    ) -> MemberExpression<'a> {
+        if self.private_fields_as_symbols {
+            let key = self.identifier(&private_name.binding);
+            return self.ast.computed_member(Span::default(), object, key, false);
+        }
+        let mut arguments = self.ast.new_vec_with_capacity(2);
+        arguments.push(Argument::Expression(object));
+        arguments.push(Argument::Expression(self.identifier(&private_name.binding)));
+        let base = self.ctx.helper_call("classPrivateFieldLooseBase", arguments);
+        self.ast.computed_member(
+            Span::default(),
+            base,
+            self.identifier(&private_name.binding),
+            false,
+        )
+    }
+
+    /// Take `obj.#x` out of an assignment target.
+    fn private_field_target(
+        &mut self,
+        target: &mut AssignmentTarget<'a>,
+    ) -> Option<(Expression<'a>, PrivateName)> {
+        let AssignmentTarget::SimpleAssignmentTarget(
+            SimpleAssignmentTarget::MemberAssignmentTarget(member_expr),
+        ) = target
+        else {
+            return None;
+        };
+        let MemberExpression::PrivateFieldExpression(field_expr) = &mut **member_expr else {
+            return None;
+        };
+        let private_name = self.lookup(&field_expr.field.name)?;
+        let object = self.ast.move_expression(&mut field_expr.object);
+        Some((object, private_name))
+    }
+
+    /// Returns `(_obj = obj, _obj)` when `obj` needs to be memoised, otherwise `(obj, obj)`.
+    fn memoise(&mut self, object: Expression<'a>) -> (Expression<'a>, Expression<'a>) {
+        if let Some(ident) = self.maybe_generate_memoised(&object) {
+            let reference = self.ast.identifier_reference_expression(ident.clone());
+            (self.assign(&ident.name, object), reference)
+        } else {
+            let reference = self.ast.copy(&object);
+            (object, reference)
+        }
+    }
+
+    /// `Object.defineProperty(object, key, descriptor)`
+    fn object_define_property(
+        &self,
+        object: Expression<'a>,
+        key: Expression<'a>,
+        descriptor: Expression<'a>,
+    ) -> Expression<'a> {
+        let callee = self.member_chain(&["Object", "defineProperty"]);
+        let mut arguments = self.ast.new_vec_with_capacity(3);
+        arguments.push(Argument::Expression(object));
+        arguments.push(Argument::Expression(key));
+        arguments.push(Argument::Expression(descriptor));
+        self.ast.call_expression(Span::default(), callee, arguments, false, None)
+    }
+
+    /// `a.b.c`
+    fn member_chain(&self, names: &[&str]) -> Expression<'a> {
+        let mut expr = self.identifier(names[0]);
+        for name in &names[1..] {
+            let property = IdentifierName::new(Span::default(), (*name).into());
+            expr = self.ast.static_member_expression(Span::default(), expr, property, false);
+        }
+        expr
+    }
+
+    /// `name = value`
+    fn assign(&self, name: &Atom, value: Expression<'a>) -> Expression<'a> {
+        let target = self.ast.simple_assignment_target_identifier(IdentifierReference::new(
+            Span::default(),
+            name.clone(),
+        ));
+        self.ast.assignment_expression(
+            Span::default(),
+            AssignmentOperator::Assign,
+            AssignmentTarget::SimpleAssignmentTarget(target),
+            value,
+        )
+    }
+
+    /// `var name = init;`
+    fn var_statement(&self, name: Atom, init: Expression<'a>) -> Statement<'a> {
+        let span = Span::default();
+        let kind = VariableDeclarationKind::Var;
+        let binding = BindingIdentifier::new(span, name);
+        let binding =
+            self.ast.binding_pattern(self.ast.binding_pattern_identifier(binding), None, false);
+        let decl = self.ast.variable_declarator(span, kind, binding, Some(init), false);
+        let decl = self.ast.variable_declaration(
+            span,
+            kind,
+            self.ast.new_vec_single(decl),
+            Modifiers::empty(),
+        );
+        Statement::Declaration(Declaration::VariableDeclaration(decl))
+    }
+
+    fn identifier(&self, name: &str) -> Expression<'a> {
+        self.ast
+            .identifier_reference_expression(IdentifierReference::new(Span::default(), name.into()))
+    }
+
+    fn string_literal(&self, value: &str) -> Expression<'a> {
+        self.ast.literal_string_expression(StringLiteral::new(Span::default(), value.into()))
+    }
+
+    fn number_literal(&self, value: f64, raw: &'a str) -> Expression<'a> {
+        self.ast.literal_number_expression(NumberLiteral::new(
+            Span::default(),
+            value,
+            raw,
+            oxc_syntax::NumberBase::Decimal,
+        ))
+    }
+}
+
+enum PropertyKeyExpression<'a> {
+    /// `x` in `this.x = 1` or `"x"` in `babelHelpers.defineProperty(this, "x", 1)`
+    Static(Atom),
+    Computed(Expression<'a>),
+}
+
+/// Replace `this` with the class name in static property initializers, which are moved out of the class body.
+struct ReplaceThis<'a, 'b> {
+    ast: &'b AstBuilder<'a>,
+    class_name: &'b Atom,
+}
+
+impl<'a, 'b> VisitMut<'a> for ReplaceThis<'a, 'b> {
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        if let Expression::ThisExpression(this) = expr {
+            let ident = IdentifierReference::new(this.span, self.class_name.clone());
+            *expr = self.ast.identifier_reference_expression(ident);
+            return;
+        }
+        self.visit_expression_match(expr);
+    }
+
+    // `this` is rebound inside functions and classes.
+    fn visit_function(&mut self, _func: &mut Function<'a>) {}
+
+    fn visit_class(&mut self, _class: &mut Class<'a>) {}
+}
+
+#[test]
+fn test() {
+    use oxc_syntax::assumptions::CompilerAssumptions;
+
+    use crate::{
+        options::{TransformOptions, TransformTarget},
+        tester::Tester,
+    };
+
+    let options =
+        TransformOptions { target: TransformTarget::ES2021, ..TransformOptions::default() };
+
+    let tests = &[
+        (
+            "class Foo { bar = 1; baz; }",
+            "class Foo { constructor() { babelHelpers.defineProperty(this, 'bar', 1); babelHelpers.defineProperty(this, 'baz', void 0); } }",
+        ),
+        (
+            "class Foo extends Bar { bar = 1; }",
+            "class Foo extends Bar { constructor(..._args) { super(..._args); babelHelpers.defineProperty(this, 'bar', 1); } }",
+        ),
+        (
+            "class Foo extends Bar { bar = 1; constructor() { foo(); super(); } }",
+            "class Foo extends Bar { constructor() { foo(); super(); babelHelpers.defineProperty(this, 'bar', 1); } }",
+        ),
+        (
+            "class Foo { static bar = this.baz; }",
+            "class Foo {} babelHelpers.defineProperty(Foo, 'bar', Foo.baz);",
+        ),
+        (
+            "var Foo = class { static bar = 1; }",
+            "var _Class; var Foo = (_Class = class {}, babelHelpers.defineProperty(_Class, 'bar', 1), _Class);",
+        ),
+        (
+            "class Foo { #bar = 1; get() { return this.#bar; } set(v) { this.#bar = v; } has(o) { return #bar in o; } }",
+            "var _bar = new WeakMap(); class Foo { constructor() { babelHelpers.classPrivateFieldInitSpec(this, _bar, { writable: true, value: 1 }); } get() { return babelHelpers.classPrivateFieldGet(this, _bar); } set(v) { babelHelpers.classPrivateFieldSet(this, _bar, v); } has(o) { return _bar.has(babelHelpers.checkInRHS(o)); } }",
+        ),
+        (
+            "class Foo { #bar = 1; inc() { this.#bar += 2; return this.#bar++; } }",
+            "var _bar = new WeakMap(); class Foo { constructor() { babelHelpers.classPrivateFieldInitSpec(this, _bar, { writable: true, value: 1 }); } inc() { var _old; babelHelpers.classPrivateFieldSet(this, _bar, babelHelpers.classPrivateFieldGet(this, _bar) + 2); return babelHelpers.classPrivateFieldSet(this, _bar, (_old = +babelHelpers.classPrivateFieldGet(this, _bar)) + 1), _old; } }",
+        ),
+        (
+            "class Foo { #bar; set(o) { o.#bar ??= 1; o.p.#bar ||= 2; } }",
+            "var _bar = new WeakMap(); class Foo { constructor() { babelHelpers.classPrivateFieldInitSpec(this, _bar, { writable: true, value: void 0 }); } set(o) { var _o$p; babelHelpers.classPrivateFieldGet(o, _bar) ?? babelHelpers.classPrivateFieldSet(o, _bar, 1); babelHelpers.classPrivateFieldGet(_o$p = o.p, _bar) || babelHelpers.classPrivateFieldSet(_o$p, _bar, 2); } }",
+        ),
+        (
+            "class Foo { static #bar = 1; static get() { return Foo.#bar; } }",
+            "class Foo { static get() { return babelHelpers.classStaticPrivateFieldSpecGet(Foo, Foo, _bar); } } var _bar = { writable: true, value: 1 };",
+        ),
+    ];
+
+    Tester::new("test.js", options.clone()).test(tests);
+
+    // Private methods
+    let tests = &[
+        (
+            "class Foo { #m() { return 1; } test() { return this.#m(); } }",
+            "var _m = new WeakSet(); class Foo { constructor() { babelHelpers.classPrivateMethodInitSpec(this, _m); } test() { return babelHelpers.classPrivateMethodGet(this, _m, _m2).call(this); } } function _m2() { return 1; }",
+        ),
+        (
+            "class Foo { get #x() { return 1; } set #x(v) {} test() { this.#x = this.#x; } }",
+            "var _x = new WeakMap(); class Foo { constructor() { babelHelpers.classPrivateFieldInitSpec(this, _x, { get: _get_x, set: _set_x }); } test() { babelHelpers.classPrivateFieldSet(this, _x, babelHelpers.classPrivateFieldGet(this, _x)); } } function _get_x() { return 1; } function _set_x(v) {}",
+        ),
+        (
+            "class Foo { static #m() {} static test() { Foo.#m(); } }",
+            "class Foo { static test() { babelHelpers.classStaticPrivateMethodGet(Foo, Foo, _m2).call(Foo); } } function _m2() {}",
+        ),
+    ];
+
+    Tester::new("test.js", options).test(tests);
+
+    let options = TransformOptions {
+        target: TransformTarget::ES2021,
+        class_properties: Some(ClassPropertiesOptions { loose: true }),
+        ..TransformOptions::default()
+    };
+
+    let tests = &[
+        ("class Foo { bar = 1; static baz = 2; }", "class Foo { constructor() { this.bar = 1; } } Foo.baz = 2;"),
+        (
+            "class Foo { #bar = 1; get() { return this.#bar; } }",
+            "var _bar = babelHelpers.classPrivateFieldLooseKey('bar'); class Foo { constructor() { Object.defineProperty(this, _bar, { writable: true, value: 1 }); } get() { return babelHelpers.classPrivateFieldLooseBase(this, _bar)[_bar]; } }",
+        ),
+    ];
+
+    Tester::new("test.js", options).test(tests);
+
+    let options = TransformOptions {
+        target: TransformTarget::ES2021,
+        assumptions: CompilerAssumptions {
+            private_fields_as_symbols: true,
+            ..CompilerAssumptions::default()
+        },
+        ..TransformOptions::default()
+    };
+
+    let tests = &[
+        (
+            "class Foo { #bar = 1; get() { return this.#bar; } }",
+            "var _bar = Symbol('bar'); class Foo { constructor() { Object.defineProperty(this, _bar, { writable: true, value: 1 }); } get() { return this[_bar]; } }",
+        ),
+    ];
+
+    Tester::new("test.js", options).test(tests);
+}
@@ -3,7 +3,10 @@ use std::{collections::HashSet, rc::Rc};
 use oxc_ast::{ast::*, AstBuilder};
 use oxc_span::{Atom, Span};
 
-use crate::options::{TransformOptions, TransformTarget};
+use crate::{
+    options::{TransformOptions, TransformTarget},
+    targets::Feature,
+};
 
 /// ES2022: Class Static Block
 ///
@@ -16,8 +19,10 @@ pub struct ClassStaticBlock<'a> {
 
 impl<'a> ClassStaticBlock<'a> {
     pub fn new(ast: Rc<AstBuilder<'a>>, options: &TransformOptions) -> Option<Self> {
-        (options.target < TransformTarget::ES2022 || options.class_static_block)
-            .then(|| Self { ast })
+        (options.target < TransformTarget::ES2022
+            || options.class_static_block
+            || options.targets.needs_transform(Feature::ClassStaticBlock))
+        .then(|| Self { ast })
     }
 
     pub fn transform_class_body<'b>(&mut self, class_body: &'b mut ClassBody<'a>) {
@@ -125,3 +130,29 @@ fn generate_uid(deny_list: &HashSet<Atom>, i: &mut u32) -> Atom {
 
     uid
 }
+
+#[test]
+fn test() {
+    use crate::{
+        options::{TransformOptions, TransformTarget},
+        tester::Tester,
+    };
+
+    let options = TransformOptions { class_static_block: true, ..TransformOptions::default() };
+    Tester::new("test.js", options).test(&[
+        ("class Foo { static #_ = 1; static {} }", "class Foo { static #_ = 1; static #_2; }"),
+        ("class Foo { static { this.bar = 1; } }", "class Foo { static #_ = this.bar = 1; }"),
+        (
+            "class Foo { static { foo(); bar(); } }",
+            "class Foo { static #_ = (() => { foo(); bar(); })(); }",
+        ),
+    ]);
+
+    // Lowered static blocks are further lowered by class properties.
+    let options =
+        TransformOptions { target: TransformTarget::ES2021, ..TransformOptions::default() };
+    Tester::new("test.js", options).test(&[(
+        "class Foo { static { foo(); } }",
+        "class Foo {} var _ = { writable: true, value: foo() };",
+    )]);
+}
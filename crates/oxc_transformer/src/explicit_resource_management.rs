@@ -0,0 +1,421 @@
+use std::rc::Rc;
+
+use oxc_allocator::{Box, Vec};
+use oxc_ast::{ast::*, syntax_directed_operations::BoundNames, AstBuilder};
+use oxc_span::{Atom, Span};
+use oxc_syntax::operator::AssignmentOperator;
+
+use crate::{
+    context::TransformerCtx,
+    options::{TransformOptions, TransformTarget},
+    targets::Feature,
+};
+
+/// Explicit Resource Management
+///
+/// The statements of a block with `using` declarations are moved into a `try` block, which
+/// registers the resources in a disposal stack of the `usingCtx` helper:
+///
+/// ```js
+/// using x = open();
+/// read(x);
+/// ```
+///
+/// ```js
+/// try {
+///   var _usingCtx = babelHelpers.usingCtx();
+///   const x = _usingCtx.u(open());
+///   read(x);
+/// } catch (_) {
+///   _usingCtx.e = _;
+/// } finally {
+///   _usingCtx.d();
+/// }
+/// ```
+///
+/// The dispose method is looked up when the resource is declared, the resources are disposed
+/// in reverse order and the errors thrown by the block and while disposing are combined into a
+/// `SuppressedError`. `await using` declarations are registered with `_usingCtx.a`, and each of
+/// their disposals is awaited in turn by `await _usingCtx.d()`.
+///
+/// At the top level of modules, the declarations stay visible to the imports, exports and
+/// functions kept out of the `try` block, see [ExplicitResourceManagement::transform_statements].
+///
+/// References:
+/// * <https://github.com/tc39/proposal-explicit-resource-management>
+/// * <https://babeljs.io/docs/babel-plugin-proposal-explicit-resource-management>
+pub struct ExplicitResourceManagement<'a> {
+    ast: Rc<AstBuilder<'a>>,
+    ctx: TransformerCtx<'a>,
+}
+
+impl<'a> ExplicitResourceManagement<'a> {
+    pub fn new(
+        ast: Rc<AstBuilder<'a>>,
+        ctx: TransformerCtx<'a>,
+        options: &TransformOptions,
+    ) -> Option<Self> {
+        (options.target < TransformTarget::ESNext
+            || options.explicit_resource_management
+            || options.targets.needs_transform(Feature::ExplicitResourceManagement))
+        .then(|| Self { ast, ctx })
+    }
+
+    /// `a; using x = b; rest` -> `try { var _usingCtx = usingCtx(); a; const x = _usingCtx.u(b); rest }`
+    ///
+    /// At the top level of a module, the imports, exports and function declarations stay out of
+    /// the `try` block, so the declarations moved into it become `var`s to stay visible to them:
+    /// `export const y = 1` -> `export { y }` and `var y = 1` in the `try` block.
+    ///
+    /// The `using` declarations of nested blocks are lowered when these are visited.
+    pub fn transform_statements(&self, stmts: &mut Vec<'a, Statement<'a>>) {
+        if !stmts
+            .iter()
+            .any(|stmt| matches!(stmt, Statement::Declaration(Declaration::UsingDeclaration(_))))
+        {
+            return;
+        }
+        let is_module = stmts.iter().any(|stmt| matches!(stmt, Statement::ModuleDeclaration(_)));
+
+        let context = self.generate_uid("usingCtx");
+        let mut is_await = false;
+        let old_stmts = std::mem::replace(stmts, self.ast.new_vec());
+        let mut body = self.ast.new_vec();
+        for stmt in old_stmts {
+            match stmt {
+                Statement::Declaration(Declaration::UsingDeclaration(decl)) => {
+                    is_await |= decl.is_await;
+                    let kind = if is_module {
+                        VariableDeclarationKind::Var
+                    } else {
+                        VariableDeclarationKind::Const
+                    };
+                    body.push(self.using_declaration(&context, decl.unbox(), kind));
+                }
+                Statement::ModuleDeclaration(decl) => {
+                    let (decl, declaration) = self.split_export(decl);
+                    stmts.extend(decl);
+                    body.extend(declaration);
+                }
+                stmt @ Statement::Declaration(Declaration::FunctionDeclaration(_)) if is_module => {
+                    stmts.push(stmt);
+                }
+                Statement::Declaration(declaration) if is_module => {
+                    body.push(self.var_declaration(declaration));
+                }
+                stmt => body.push(stmt),
+            }
+        }
+        stmts.push(self.try_using(&context, body, is_await));
+    }
+
+    /// `for (using x of y) body` ->
+    /// `for (const _x of y) { try { var _usingCtx = usingCtx(); const x = _usingCtx.u(_x); body } }`
+    pub fn transform_statement(&self, stmt: &mut Statement<'a>) {
+        let Statement::ForOfStatement(for_of) = stmt else { return };
+        let ForStatementLeft::UsingDeclaration(using) = &mut for_of.left else { return };
+        let Some(declarator) = using.declarations.first_mut() else { return };
+        let BindingPatternKind::BindingIdentifier(ident) = &declarator.id.kind else { return };
+        let value = self.generate_uid(&ident.name);
+        declarator.init = Some(self.identifier(&value));
+        let is_await = using.is_await;
+
+        let span = using.span;
+        let kind = VariableDeclarationKind::Const;
+        let binding = self.ast.binding_pattern(
+            self.ast.binding_pattern_identifier(BindingIdentifier::new(Span::default(), value)),
+            None,
+            false,
+        );
+        let declarator = self.ast.variable_declarator(span, kind, binding, None, false);
+        let using = std::mem::replace(
+            &mut for_of.left,
+            ForStatementLeft::VariableDeclaration(self.ast.variable_declaration(
+                span,
+                kind,
+                self.ast.new_vec_single(declarator),
+                Modifiers::empty(),
+            )),
+        );
+        let ForStatementLeft::UsingDeclaration(using) = using else { unreachable!() };
+
+        let context = self.generate_uid("usingCtx");
+        let mut body = self.ast.new_vec_with_capacity(2);
+        let kind = VariableDeclarationKind::Const;
+        body.push(self.using_declaration(&context, using.unbox(), kind));
+        body.push(self.ast.move_statement(&mut for_of.body));
+        let try_statement = self.try_using(&context, body, is_await);
+        let block = self.ast.block(Span::default(), self.ast.new_vec_single(try_statement));
+        for_of.body = self.ast.block_statement(block);
+    }
+
+    /// An export declaration split into the export of its bindings and their declaration, which
+    /// is moved into the `try` block:
+    /// * `export const x = 1` -> `export { x }` and `var x = 1`
+    /// * `export default a` -> `export { _default as default }` and `var _default = a`
+    fn split_export(
+        &self,
+        decl: Box<'a, ModuleDeclaration<'a>>,
+    ) -> (Option<Statement<'a>>, Option<Statement<'a>>) {
+        let (names, declaration) = match decl.unbox() {
+            ModuleDeclaration::ExportNamedDeclaration(mut export)
+                if matches!(
+                    export.declaration,
+                    Some(Declaration::VariableDeclaration(_) | Declaration::ClassDeclaration(_))
+                ) =>
+            {
+                let declaration = export.declaration.take().unwrap();
+                let mut names = vec![];
+                declaration.bound_names(&mut |ident| {
+                    names.push((ident.name.clone(), ident.name.clone()));
+                });
+                (names, self.var_declaration(declaration))
+            }
+            ModuleDeclaration::ExportDefaultDeclaration(export)
+                if matches!(
+                    export.declaration,
+                    ExportDefaultDeclarationKind::Expression(_)
+                        | ExportDefaultDeclarationKind::ClassDeclaration(_)
+                ) =>
+            {
+                let (name, init) = match export.unbox().declaration {
+                    ExportDefaultDeclarationKind::Expression(expr) => {
+                        (self.generate_uid("default"), expr)
+                    }
+                    ExportDefaultDeclarationKind::ClassDeclaration(mut class) => {
+                        let name = class
+                            .id
+                            .as_ref()
+                            .map_or_else(|| self.generate_uid("default"), |id| id.name.clone());
+                        class.r#type = ClassType::ClassExpression;
+                        (name, self.ast.class_expression(class))
+                    }
+                    _ => unreachable!(),
+                };
+                let declaration = self.var(name.clone(), init);
+                (vec![(name, "default".into())], declaration)
+            }
+            decl => return (Some(self.ast.module_declaration(decl)), None),
+        };
+        let mut specifiers = self.ast.new_vec_with_capacity(names.len());
+        for (local, exported) in names {
+            specifiers.push(ExportSpecifier {
+                span: Span::default(),
+                local: ModuleExportName::Identifier(IdentifierName::new(Span::default(), local)),
+                exported: ModuleExportName::Identifier(IdentifierName::new(
+                    Span::default(),
+                    exported,
+                )),
+                export_kind: ImportOrExportKind::Value,
+            });
+        }
+        let export = self.ast.export_named_declaration(
+            Span::default(),
+            None,
+            specifiers,
+            None,
+            None,
+            ImportOrExportKind::Value,
+        );
+        let export = self.ast.module_declaration(ModuleDeclaration::ExportNamedDeclaration(export));
+        (Some(export), Some(declaration))
+    }
+
+    /// `let x = 1` -> `var x = 1`, `class A {}` -> `var A = class A {}`
+    fn var_declaration(&self, declaration: Declaration<'a>) -> Statement<'a> {
+        match declaration {
+            Declaration::VariableDeclaration(mut decl) => {
+                decl.kind = VariableDeclarationKind::Var;
+                for declarator in decl.declarations.iter_mut() {
+                    declarator.kind = VariableDeclarationKind::Var;
+                }
+                Statement::Declaration(Declaration::VariableDeclaration(decl))
+            }
+            Declaration::ClassDeclaration(mut class) => {
+                let Some(name) = class.id.as_ref().map(|id| id.name.clone()) else {
+                    return Statement::Declaration(Declaration::ClassDeclaration(class));
+                };
+                class.r#type = ClassType::ClassExpression;
+                self.var(name, self.ast.class_expression(class))
+            }
+            declaration => Statement::Declaration(declaration),
+        }
+    }
+
+    /// `var name = init`
+    fn var(&self, name: Atom, init: Expression<'a>) -> Statement<'a> {
+        let span = Span::default();
+        let binding = self.ast.binding_pattern(
+            self.ast.binding_pattern_identifier(BindingIdentifier::new(span, name)),
+            None,
+            false,
+        );
+        let kind = VariableDeclarationKind::Var;
+        let declarator = self.ast.variable_declarator(span, kind, binding, Some(init), false);
+        let declaration = self.ast.variable_declaration(
+            span,
+            kind,
+            self.ast.new_vec_single(declarator),
+            Modifiers::empty(),
+        );
+        Statement::Declaration(Declaration::VariableDeclaration(declaration))
+    }
+
+    /// `using x = a, y = b` -> `const x = _usingCtx.u(a), y = _usingCtx.u(b)`, with `_usingCtx.a`
+    /// for `await using`.
+    fn using_declaration(
+        &self,
+        context: &Atom,
+        mut using: UsingDeclaration<'a>,
+        kind: VariableDeclarationKind,
+    ) -> Statement<'a> {
+        let span = Span::default();
+        let method = if using.is_await { "a" } else { "u" };
+        for declarator in using.declarations.iter_mut() {
+            declarator.kind = kind;
+            let init = declarator.init.take().unwrap_or_else(|| self.ast.void_0());
+            let callee = self.member(self.identifier(context), method);
+            let arguments = self.ast.new_vec_single(Argument::Expression(init));
+            declarator.init = Some(self.ast.call_expression(span, callee, arguments, false, None));
+        }
+        let decl =
+            self.ast.variable_declaration(using.span, kind, using.declarations, Modifiers::empty());
+        Statement::Declaration(Declaration::VariableDeclaration(decl))
+    }
+
+    /// ```js
+    /// try {
+    ///   var _usingCtx = babelHelpers.usingCtx();
+    ///   body
+    /// } catch (_) {
+    ///   _usingCtx.e = _;
+    /// } finally {
+    ///   await _usingCtx.d();
+    /// }
+    /// ```
+    fn try_using(
+        &self,
+        context: &Atom,
+        body: Vec<'a, Statement<'a>>,
+        is_await: bool,
+    ) -> Statement<'a> {
+        let span = Span::default();
+
+        let init = self.ctx.helper_call("usingCtx", self.ast.new_vec());
+        let binding = self.ast.binding_pattern(
+            self.ast.binding_pattern_identifier(BindingIdentifier::new(span, context.clone())),
+            None,
+            false,
+        );
+        let kind = VariableDeclarationKind::Var;
+        let declarator = self.ast.variable_declarator(span, kind, binding, Some(init), false);
+        let declaration = self.ast.variable_declaration(
+            span,
+            kind,
+            self.ast.new_vec_single(declarator),
+            Modifiers::empty(),
+        );
+        let mut block = self.ast.new_vec_with_capacity(body.len() + 1);
+        block.push(Statement::Declaration(Declaration::VariableDeclaration(declaration)));
+        block.extend(body);
+
+        // `_usingCtx.e = _`, the error of the block is rethrown after the disposal
+        let error = self.ast.binding_pattern(
+            self.ast.binding_pattern_identifier(BindingIdentifier::new(span, "_".into())),
+            None,
+            false,
+        );
+        let target = self.ast.static_member(
+            span,
+            self.identifier(context),
+            IdentifierName::new(span, "e".into()),
+            false,
+        );
+        let assignment = self.ast.assignment_expression(
+            span,
+            AssignmentOperator::Assign,
+            AssignmentTarget::SimpleAssignmentTarget(
+                self.ast.simple_assignment_target_member_expression(target),
+            ),
+            self.identifier(&"_".into()),
+        );
+        let catch_body = self.ast.new_vec_single(self.ast.expression_statement(span, assignment));
+        let handler = self.ast.catch_clause(span, Some(error), self.ast.block(span, catch_body));
+
+        let callee = self.member(self.identifier(context), "d");
+        let mut dispose = self.ast.call_expression(span, callee, self.ast.new_vec(), false, None);
+        if is_await {
+            dispose = self.ast.await_expression(span, dispose);
+        }
+        let finalizer = self.ast.new_vec_single(self.ast.expression_statement(span, dispose));
+
+        self.ast.try_statement(
+            span,
+            self.ast.block(span, block),
+            Some(handler),
+            Some(self.ast.block(span, finalizer)),
+        )
+    }
+
+    fn generate_uid(&self, name: &str) -> Atom {
+        let name = self.ctx.scopes().generate_uid(name);
+        self.ctx.add_binding(name.clone());
+        name
+    }
+
+    fn member(&self, object: Expression<'a>, name: &str) -> Expression<'a> {
+        let property = IdentifierName::new(Span::default(), name.into());
+        self.ast.static_member_expression(Span::default(), object, property, false)
+    }
+
+    fn identifier(&self, name: &Atom) -> Expression<'a> {
+        self.ast.identifier_reference_expression(IdentifierReference::new(
+            Span::default(),
+            name.clone(),
+        ))
+    }
+}
+
+#[test]
+#[rustfmt::skip]
+fn test() {
+    use crate::tester::Tester;
+
+    let options = TransformOptions { explicit_resource_management: true, ..TransformOptions::default() };
+    let tests = &[
+        (
+            "{ using x = open(); read(x); }",
+            "{ try { var _usingCtx = babelHelpers.usingCtx(); const x = _usingCtx.u(open()); read(x); } catch (_) { _usingCtx.e = _; } finally { _usingCtx.d(); } }",
+        ),
+        (
+            "async function f() { a(); await using x = open(), y = x.child(); g(); function g() {} }",
+            "async function f() { try { var _usingCtx = babelHelpers.usingCtx(); a(); const x = _usingCtx.a(open()), y = _usingCtx.a(x.child()); g(); function g() {} } catch (_) { _usingCtx.e = _; } finally { await _usingCtx.d(); } }",
+        ),
+        (
+            "{ using x = a; using y = b; }",
+            "{ try { var _usingCtx = babelHelpers.usingCtx(); const x = _usingCtx.u(a); const y = _usingCtx.u(b); } catch (_) { _usingCtx.e = _; } finally { _usingCtx.d(); } }",
+        ),
+        (
+            "for (using x of xs) read(x);",
+            "for (const _x of xs) { try { var _usingCtx = babelHelpers.usingCtx(); const x = _usingCtx.u(_x); read(x); } catch (_) { _usingCtx.e = _; } finally { _usingCtx.d(); } }",
+        ),
+        // The error of the body and the error of the disposal are combined into a `SuppressedError`
+        (
+            "{ using x = { [Symbol.dispose]() { throw 1; } }; throw 2; }",
+            "{ try { var _usingCtx = babelHelpers.usingCtx(); const x = _usingCtx.u({ [Symbol.dispose]() { throw 1; } }); throw 2; } catch (_) { _usingCtx.e = _; } finally { _usingCtx.d(); } }",
+        ),
+    ];
+    Tester::new("test.js", options.clone()).test(tests);
+
+    let tests = &[
+        (
+            "import a from 'a'; using x = a(); export const y = x; export default x; export function f() {}",
+            "import a from 'a'; export { y }; export { _default as default }; export function f() {} try { var _usingCtx = babelHelpers.usingCtx(); var x = _usingCtx.u(a()); var y = x; var _default = x; } catch (_) { _usingCtx.e = _; } finally { _usingCtx.d(); }",
+        ),
+        (
+            "using x = a(); export class A {}",
+            "export { A }; try { var _usingCtx = babelHelpers.usingCtx(); var x = _usingCtx.u(a()); var A = class A {}; } catch (_) { _usingCtx.e = _; } finally { _usingCtx.d(); }",
+        ),
+    ];
+    Tester::new("test.mjs", options).test(tests);
+}
@@ -0,0 +1,1319 @@
+//! The sources of the helpers defined in the files with [HelperLoaderMode::Inline].
+//!
+//! They behave as the Babel helpers of the same names for the code the transforms generate.
+//! The helpers they call are referenced as `$name`, which is replaced by the local binding of
+//! the helper in the file.
+//!
+//! [HelperLoaderMode::Inline]: super::HelperLoaderMode::Inline
+
+use oxc_span::Atom;
+
+/// `(name, source)`
+const HELPERS: &[(&str, &str)] = &[
+    (
+        "typeof",
+        r#"function $typeof(obj) {
+  return typeof Symbol === "function" && typeof obj === "object" && obj !== null && obj.constructor === Symbol && obj !== Symbol.prototype ? "symbol" : typeof obj;
+}"#,
+    ),
+    (
+        "toPrimitive",
+        r#"function $toPrimitive(input, hint) {
+  if ($typeof(input) !== "object" || input === null) return input;
+  var prim = input[Symbol.toPrimitive];
+  if (prim !== undefined) {
+    var res = prim.call(input, hint || "default");
+    if ($typeof(res) !== "object") return res;
+    throw new TypeError("@@toPrimitive must return a primitive value.");
+  }
+  return (hint === "string" ? String : Number)(input);
+}"#,
+    ),
+    (
+        "toPropertyKey",
+        r#"function $toPropertyKey(arg) {
+  var key = $toPrimitive(arg, "string");
+  return $typeof(key) === "symbol" ? key : String(key);
+}"#,
+    ),
+    (
+        "defineProperty",
+        r#"function $defineProperty(obj, key, value) {
+  key = $toPropertyKey(key);
+  if (key in obj) {
+    Object.defineProperty(obj, key, { value: value, enumerable: true, configurable: true, writable: true });
+  } else {
+    obj[key] = value;
+  }
+  return obj;
+}"#,
+    ),
+    (
+        "defineAccessor",
+        r#"function $defineAccessor(type, obj, key, fn) {
+  var desc = { configurable: true, enumerable: true };
+  desc[type] = fn;
+  return Object.defineProperty(obj, key, desc);
+}"#,
+    ),
+    (
+        "extends",
+        r#"function $extends() {
+  $extends = Object.assign ? Object.assign.bind() : function (target) {
+    for (var i = 1; i < arguments.length; i++) {
+      var source = arguments[i];
+      for (var key in source) {
+        if (Object.prototype.hasOwnProperty.call(source, key)) target[key] = source[key];
+      }
+    }
+    return target;
+  };
+  return $extends.apply(this, arguments);
+}"#,
+    ),
+    (
+        "ownKeys",
+        r#"function $ownKeys(object, enumerableOnly) {
+  var keys = Object.keys(object);
+  if (Object.getOwnPropertySymbols) {
+    var symbols = Object.getOwnPropertySymbols(object);
+    if (enumerableOnly) {
+      symbols = symbols.filter(function (sym) {
+        return Object.getOwnPropertyDescriptor(object, sym).enumerable;
+      });
+    }
+    keys.push.apply(keys, symbols);
+  }
+  return keys;
+}"#,
+    ),
+    (
+        "objectSpread2",
+        r#"function $objectSpread2(target) {
+  for (var i = 1; i < arguments.length; i++) {
+    var source = arguments[i] != null ? arguments[i] : {};
+    if (i % 2) {
+      $ownKeys(Object(source), true).forEach(function (key) {
+        $defineProperty(target, key, source[key]);
+      });
+    } else if (Object.getOwnPropertyDescriptors) {
+      Object.defineProperties(target, Object.getOwnPropertyDescriptors(source));
+    } else {
+      $ownKeys(Object(source)).forEach(function (key) {
+        Object.defineProperty(target, key, Object.getOwnPropertyDescriptor(source, key));
+      });
+    }
+  }
+  return target;
+}"#,
+    ),
+    (
+        "objectDestructuringEmpty",
+        r#"function $objectDestructuringEmpty(obj) {
+  if (obj == null) throw new TypeError("Cannot destructure " + obj);
+}"#,
+    ),
+    (
+        "objectWithoutPropertiesLoose",
+        r#"function $objectWithoutPropertiesLoose(source, excluded) {
+  if (source == null) return {};
+  var target = {};
+  for (var key in source) {
+    if (Object.prototype.hasOwnProperty.call(source, key) && excluded.indexOf(key) < 0) {
+      target[key] = source[key];
+    }
+  }
+  return target;
+}"#,
+    ),
+    (
+        "objectWithoutProperties",
+        r#"function $objectWithoutProperties(source, excluded) {
+  if (source == null) return {};
+  var target = $objectWithoutPropertiesLoose(source, excluded);
+  if (Object.getOwnPropertySymbols) {
+    var symbols = Object.getOwnPropertySymbols(source);
+    for (var i = 0; i < symbols.length; i++) {
+      var key = symbols[i];
+      if (excluded.indexOf(key) < 0 && Object.prototype.propertyIsEnumerable.call(source, key)) {
+        target[key] = source[key];
+      }
+    }
+  }
+  return target;
+}"#,
+    ),
+    (
+        "arrayLikeToArray",
+        r#"function $arrayLikeToArray(arr, len) {
+  if (len == null || len > arr.length) len = arr.length;
+  for (var i = 0, arr2 = new Array(len); i < len; i++) arr2[i] = arr[i];
+  return arr2;
+}"#,
+    ),
+    (
+        "arrayWithHoles",
+        r#"function $arrayWithHoles(arr) {
+  if (Array.isArray(arr)) return arr;
+}"#,
+    ),
+    (
+        "arrayWithoutHoles",
+        r#"function $arrayWithoutHoles(arr) {
+  if (Array.isArray(arr)) return $arrayLikeToArray(arr);
+}"#,
+    ),
+    (
+        "iterableToArray",
+        r#"function $iterableToArray(iter) {
+  if (typeof Symbol !== "undefined" && iter[Symbol.iterator] != null || iter["@@iterator"] != null) return Array.from(iter);
+}"#,
+    ),
+    (
+        "iterableToArrayLimit",
+        r#"function $iterableToArrayLimit(arr, i) {
+  var it = arr == null ? null : typeof Symbol !== "undefined" && arr[Symbol.iterator] || arr["@@iterator"];
+  if (it == null) return;
+  var result = [], done = true, error = false, err, step, ret;
+  try {
+    it = it.call(arr);
+    if (i === 0) {
+      if (Object(it) !== it) return;
+      done = false;
+    } else {
+      for (; !(done = (step = it.next()).done); done = true) {
+        result.push(step.value);
+        if (result.length === i) break;
+      }
+    }
+  } catch (e) {
+    error = true;
+    err = e;
+  } finally {
+    try {
+      if (!done && it["return"] != null && (ret = it["return"](), Object(ret) !== ret)) return;
+    } finally {
+      if (error) throw err;
+    }
+  }
+  return result;
+}"#,
+    ),
+    (
+        "unsupportedIterableToArray",
+        r#"function $unsupportedIterableToArray(o, minLen) {
+  if (!o) return;
+  if (typeof o === "string") return $arrayLikeToArray(o, minLen);
+  var n = Object.prototype.toString.call(o).slice(8, -1);
+  if (n === "Object" && o.constructor) n = o.constructor.name;
+  if (n === "Map" || n === "Set") return Array.from(o);
+  if (n === "Arguments" || /^(?:Ui|I)nt(?:8|16|32)(?:Clamped)?Array$/.test(n)) return $arrayLikeToArray(o, minLen);
+}"#,
+    ),
+    (
+        "nonIterableRest",
+        r#"function $nonIterableRest() {
+  throw new TypeError("Invalid attempt to destructure non-iterable instance.\nIn order to be iterable, non-array objects must have a [Symbol.iterator]() method.");
+}"#,
+    ),
+    (
+        "nonIterableSpread",
+        r#"function $nonIterableSpread() {
+  throw new TypeError("Invalid attempt to spread non-iterable instance.\nIn order to be iterable, non-array objects must have a [Symbol.iterator]() method.");
+}"#,
+    ),
+    (
+        "slicedToArray",
+        r#"function $slicedToArray(arr, i) {
+  return $arrayWithHoles(arr) || $iterableToArrayLimit(arr, i) || $unsupportedIterableToArray(arr, i) || $nonIterableRest();
+}"#,
+    ),
+    (
+        "toArray",
+        r#"function $toArray(arr) {
+  return $arrayWithHoles(arr) || $iterableToArray(arr) || $unsupportedIterableToArray(arr) || $nonIterableRest();
+}"#,
+    ),
+    (
+        "toConsumableArray",
+        r#"function $toConsumableArray(arr) {
+  return $arrayWithoutHoles(arr) || $iterableToArray(arr) || $unsupportedIterableToArray(arr) || $nonIterableSpread();
+}"#,
+    ),
+    (
+        "setPrototypeOf",
+        r#"function $setPrototypeOf(o, p) {
+  $setPrototypeOf = Object.setPrototypeOf ? Object.setPrototypeOf.bind() : function (o, p) {
+    o.__proto__ = p;
+    return o;
+  };
+  return $setPrototypeOf(o, p);
+}"#,
+    ),
+    (
+        "inherits",
+        r#"function $inherits(subClass, superClass) {
+  if (typeof superClass !== "function" && superClass !== null) {
+    throw new TypeError("Super expression must either be null or a function");
+  }
+  subClass.prototype = Object.create(superClass && superClass.prototype, {
+    constructor: { value: subClass, writable: true, configurable: true }
+  });
+  Object.defineProperty(subClass, "prototype", { writable: false });
+  if (superClass) $setPrototypeOf(subClass, superClass);
+}"#,
+    ),
+    (
+        "isNativeReflectConstruct",
+        r#"function $isNativeReflectConstruct() {
+  try {
+    var result = !Boolean.prototype.valueOf.call(Reflect.construct(Boolean, [], function () {}));
+  } catch (e) {}
+  return ($isNativeReflectConstruct = function () {
+    return !!result;
+  })();
+}"#,
+    ),
+    (
+        "construct",
+        r#"function $construct(Parent, args, Class) {
+  if ($isNativeReflectConstruct()) return Reflect.construct.apply(null, arguments);
+  var a = [null];
+  a.push.apply(a, args);
+  var instance = new (Parent.bind.apply(Parent, a))();
+  if (Class) $setPrototypeOf(instance, Class.prototype);
+  return instance;
+}"#,
+    ),
+    (
+        "checkPrivateRedeclaration",
+        r#"function $checkPrivateRedeclaration(obj, privateCollection) {
+  if (privateCollection.has(obj)) {
+    throw new TypeError("Cannot initialize the same private elements twice on an object");
+  }
+}"#,
+    ),
+    (
+        "classPrivateFieldInitSpec",
+        r#"function $classPrivateFieldInitSpec(obj, privateMap, value) {
+  $checkPrivateRedeclaration(obj, privateMap);
+  privateMap.set(obj, value);
+}"#,
+    ),
+    (
+        "classPrivateMethodInitSpec",
+        r#"function $classPrivateMethodInitSpec(obj, privateSet) {
+  $checkPrivateRedeclaration(obj, privateSet);
+  privateSet.add(obj);
+}"#,
+    ),
+    (
+        "classExtractFieldDescriptor",
+        r#"function $classExtractFieldDescriptor(receiver, privateMap, action) {
+  if (!privateMap.has(receiver)) {
+    throw new TypeError("attempted to " + action + " private field on non-instance");
+  }
+  return privateMap.get(receiver);
+}"#,
+    ),
+    (
+        "classApplyDescriptorGet",
+        r#"function $classApplyDescriptorGet(receiver, descriptor) {
+  if (descriptor.get) return descriptor.get.call(receiver);
+  return descriptor.value;
+}"#,
+    ),
+    (
+        "classApplyDescriptorSet",
+        r#"function $classApplyDescriptorSet(receiver, descriptor, value) {
+  if (descriptor.set) {
+    descriptor.set.call(receiver, value);
+  } else {
+    if (!descriptor.writable) throw new TypeError("attempted to set read only private field");
+    descriptor.value = value;
+  }
+}"#,
+    ),
+    (
+        "classPrivateFieldGet",
+        r#"function $classPrivateFieldGet(receiver, privateMap) {
+  var descriptor = $classExtractFieldDescriptor(receiver, privateMap, "get");
+  return $classApplyDescriptorGet(receiver, descriptor);
+}"#,
+    ),
+    (
+        "classPrivateFieldSet",
+        r#"function $classPrivateFieldSet(receiver, privateMap, value) {
+  var descriptor = $classExtractFieldDescriptor(receiver, privateMap, "set");
+  $classApplyDescriptorSet(receiver, descriptor, value);
+  return value;
+}"#,
+    ),
+    (
+        "classCheckPrivateStaticAccess",
+        r#"function $classCheckPrivateStaticAccess(receiver, classConstructor) {
+  if (receiver !== classConstructor) throw new TypeError("Private static access of wrong provenance");
+}"#,
+    ),
+    (
+        "classCheckPrivateStaticFieldDescriptor",
+        r#"function $classCheckPrivateStaticFieldDescriptor(descriptor, action) {
+  if (descriptor === undefined) {
+    throw new TypeError("attempted to " + action + " private static field before its declaration");
+  }
+}"#,
+    ),
+    (
+        "classStaticPrivateFieldSpecGet",
+        r#"function $classStaticPrivateFieldSpecGet(receiver, classConstructor, descriptor) {
+  $classCheckPrivateStaticAccess(receiver, classConstructor);
+  $classCheckPrivateStaticFieldDescriptor(descriptor, "get");
+  return $classApplyDescriptorGet(receiver, descriptor);
+}"#,
+    ),
+    (
+        "classStaticPrivateFieldSpecSet",
+        r#"function $classStaticPrivateFieldSpecSet(receiver, classConstructor, descriptor, value) {
+  $classCheckPrivateStaticAccess(receiver, classConstructor);
+  $classCheckPrivateStaticFieldDescriptor(descriptor, "set");
+  $classApplyDescriptorSet(receiver, descriptor, value);
+  return value;
+}"#,
+    ),
+    (
+        "classPrivateMethodGet",
+        r#"function $classPrivateMethodGet(receiver, privateSet, fn) {
+  if (!privateSet.has(receiver)) throw new TypeError("attempted to get private field on non-instance");
+  return fn;
+}"#,
+    ),
+    (
+        "classStaticPrivateMethodGet",
+        r#"function $classStaticPrivateMethodGet(receiver, classConstructor, method) {
+  $classCheckPrivateStaticAccess(receiver, classConstructor);
+  return method;
+}"#,
+    ),
+    (
+        "classPrivateFieldLooseKey",
+        r#"function $classPrivateFieldLooseKey(name) {
+  $classPrivateFieldLooseKey.id = ($classPrivateFieldLooseKey.id || 0) + 1;
+  return "__private_" + $classPrivateFieldLooseKey.id + "_" + name;
+}"#,
+    ),
+    (
+        "classPrivateFieldLooseBase",
+        r#"function $classPrivateFieldLooseBase(receiver, privateKey) {
+  if (!Object.prototype.hasOwnProperty.call(receiver, privateKey)) {
+    throw new TypeError("attempted to use private field on non-instance");
+  }
+  return receiver;
+}"#,
+    ),
+    (
+        "readOnlyError",
+        r#"function $readOnlyError(name) {
+  throw new TypeError("\"" + name + "\" is read-only");
+}"#,
+    ),
+    (
+        "checkInRHS",
+        r#"function $checkInRHS(value) {
+  if (Object(value) !== value) {
+    throw new TypeError("right-hand side of 'in' should be an object, got " + (value !== null ? typeof value : "null"));
+  }
+  return value;
+}"#,
+    ),
+    (
+        "OverloadYield",
+        r#"function $OverloadYield(value, kind) {
+  this.v = value;
+  this.k = kind;
+}"#,
+    ),
+    (
+        "awaitAsyncGenerator",
+        r#"function $awaitAsyncGenerator(value) {
+  return new $OverloadYield(value, 0);
+}"#,
+    ),
+    (
+        "AsyncGenerator",
+        r#"function $AsyncGenerator(gen) {
+  var front, back;
+  function send(key, arg) {
+    return new Promise(function (resolve, reject) {
+      var request = { key: key, arg: arg, resolve: resolve, reject: reject, next: null };
+      if (back) {
+        back = back.next = request;
+      } else {
+        front = back = request;
+        resume(key, arg);
+      }
+    });
+  }
+  function resume(key, arg) {
+    try {
+      var result = gen[key](arg);
+      var value = result.value;
+      var overloaded = value instanceof $OverloadYield;
+      Promise.resolve(overloaded ? value.v : value).then(function (arg) {
+        if (overloaded) {
+          var nextKey = key === "return" ? "return" : "next";
+          if (!value.k || arg.done) return resume(nextKey, arg);
+          arg = gen[nextKey](arg).value;
+        }
+        settle(result.done ? "return" : "normal", arg);
+      }, function (err) {
+        resume("throw", err);
+      });
+    } catch (err) {
+      settle("throw", err);
+    }
+  }
+  function settle(type, value) {
+    if (type === "return") front.resolve({ value: value, done: true });
+    else if (type === "throw") front.reject(value);
+    else front.resolve({ value: value, done: false });
+    front = front.next;
+    if (front) resume(front.key, front.arg);
+    else back = null;
+  }
+  this._invoke = send;
+  if (typeof gen["return"] !== "function") this["return"] = undefined;
+}
+$AsyncGenerator.prototype[typeof Symbol === "function" && Symbol.asyncIterator || "@@asyncIterator"] = function () {
+  return this;
+};
+$AsyncGenerator.prototype.next = function (arg) {
+  return this._invoke("next", arg);
+};
+$AsyncGenerator.prototype["throw"] = function (arg) {
+  return this._invoke("throw", arg);
+};
+$AsyncGenerator.prototype["return"] = function (arg) {
+  return this._invoke("return", arg);
+};"#,
+    ),
+    (
+        "wrapAsyncGenerator",
+        r#"function $wrapAsyncGenerator(fn) {
+  return function () {
+    return new $AsyncGenerator(fn.apply(this, arguments));
+  };
+}"#,
+    ),
+    (
+        "asyncIterator",
+        r#"function $asyncIterator(iterable) {
+  function AsyncFromSyncIterator(s) {
+    this.s = s;
+    this.n = s.next;
+  }
+  function continuation(r) {
+    if (Object(r) !== r) return Promise.reject(new TypeError(r + " is not an object."));
+    var done = r.done;
+    return Promise.resolve(r.value).then(function (value) {
+      return { value: value, done: done };
+    });
+  }
+  AsyncFromSyncIterator.prototype = {
+    next: function () {
+      return continuation(this.n.apply(this.s, arguments));
+    },
+    "return": function (value) {
+      var ret = this.s["return"];
+      if (ret === undefined) return Promise.resolve({ value: value, done: true });
+      return continuation(ret.apply(this.s, arguments));
+    },
+    "throw": function (value) {
+      var thr = this.s["throw"];
+      if (thr === undefined) return Promise.reject(value);
+      return continuation(thr.apply(this.s, arguments));
+    }
+  };
+  var method, async, sync, retry = 2;
+  if (typeof Symbol !== "undefined") {
+    async = Symbol.asyncIterator;
+    sync = Symbol.iterator;
+  }
+  while (retry--) {
+    if (async && (method = iterable[async]) != null) return method.call(iterable);
+    if (sync && (method = iterable[sync]) != null) return new AsyncFromSyncIterator(method.call(iterable));
+    async = "@@asyncIterator";
+    sync = "@@iterator";
+  }
+  throw new TypeError("Object is not async iterable");
+}"#,
+    ),
+    (
+        "asyncGeneratorDelegate",
+        r#"function $asyncGeneratorDelegate(inner, awaitWrap) {
+  var iter = {}, waiting = false;
+  function pump(key, value) {
+    waiting = true;
+    value = new Promise(function (resolve) {
+      resolve(inner[key](value));
+    });
+    return { done: false, value: awaitWrap(value) };
+  }
+  iter[typeof Symbol !== "undefined" && Symbol.iterator || "@@iterator"] = function () {
+    return this;
+  };
+  iter.next = function (value) {
+    if (waiting) {
+      waiting = false;
+      return value;
+    }
+    return pump("next", value);
+  };
+  if (typeof inner["throw"] === "function") {
+    iter["throw"] = function (value) {
+      if (waiting) {
+        waiting = false;
+        throw value;
+      }
+      return pump("throw", value);
+    };
+  }
+  if (typeof inner["return"] === "function") {
+    iter["return"] = function (value) {
+      if (waiting) {
+        waiting = false;
+        return value;
+      }
+      return pump("return", value);
+    };
+  }
+  return iter;
+}"#,
+    ),
+    (
+        "wrapRegExp",
+        r#"function $wrapRegExp() {
+  $wrapRegExp = function (re, groups) {
+    return new BabelRegExp(re, undefined, groups);
+  };
+  var _super = RegExp.prototype;
+  var _groups = new WeakMap();
+  function BabelRegExp(re, flags, groups) {
+    var _this = new RegExp(re, flags);
+    _groups.set(_this, groups || _groups.get(re));
+    return $setPrototypeOf(_this, BabelRegExp.prototype);
+  }
+  $inherits(BabelRegExp, RegExp);
+  BabelRegExp.prototype.exec = function (str) {
+    var result = _super.exec.call(this, str);
+    if (result) {
+      result.groups = buildGroups(result, this);
+      var indices = result.indices;
+      if (indices) indices.groups = buildGroups(indices, this);
+    }
+    return result;
+  };
+  BabelRegExp.prototype[Symbol.replace] = function (str, substitution) {
+    if (typeof substitution === "string") {
+      var groups = _groups.get(this);
+      return _super[Symbol.replace].call(this, str, substitution.replace(/\$<([^>]+)>/g, function (_, name) {
+        var group = groups[name];
+        return "$" + (Array.isArray(group) ? group.join("$") : group);
+      }));
+    }
+    if (typeof substitution === "function") {
+      var _this = this;
+      return _super[Symbol.replace].call(this, str, function () {
+        var args = arguments;
+        if (typeof args[args.length - 1] !== "object") {
+          args = [].slice.call(args);
+          args.push(buildGroups(args, _this));
+        }
+        return substitution.apply(this, args);
+      });
+    }
+    return _super[Symbol.replace].call(this, str, substitution);
+  };
+  function buildGroups(result, re) {
+    var g = _groups.get(re);
+    return Object.keys(g).reduce(function (groups, name) {
+      var i = g[name];
+      if (typeof i === "number") {
+        groups[name] = result[i];
+      } else {
+        var k = 0;
+        while (result[i[k]] === undefined && k + 1 < i.length) k++;
+        groups[name] = result[i[k]];
+      }
+      return groups;
+    }, Object.create(null));
+  }
+  return $wrapRegExp.apply(this, arguments);
+}"#,
+    ),
+    (
+        "interopRequireDefault",
+        r#"function $interopRequireDefault(obj) {
+  return obj && obj.__esModule ? obj : { "default": obj };
+}"#,
+    ),
+    (
+        "getRequireWildcardCache",
+        r#"function $getRequireWildcardCache(nodeInterop) {
+  if (typeof WeakMap !== "function") return null;
+  var cacheBabelInterop = new WeakMap();
+  var cacheNodeInterop = new WeakMap();
+  return ($getRequireWildcardCache = function (nodeInterop) {
+    return nodeInterop ? cacheNodeInterop : cacheBabelInterop;
+  })(nodeInterop);
+}"#,
+    ),
+    (
+        "interopRequireWildcard",
+        r#"function $interopRequireWildcard(obj, nodeInterop) {
+  if (!nodeInterop && obj && obj.__esModule) return obj;
+  if (obj === null || typeof obj !== "object" && typeof obj !== "function") return { "default": obj };
+  var cache = $getRequireWildcardCache(nodeInterop);
+  if (cache && cache.has(obj)) return cache.get(obj);
+  var newObj = { __proto__: null };
+  var hasPropertyDescriptor = Object.defineProperty && Object.getOwnPropertyDescriptor;
+  for (var key in obj) {
+    if (key !== "default" && Object.prototype.hasOwnProperty.call(obj, key)) {
+      var desc = hasPropertyDescriptor ? Object.getOwnPropertyDescriptor(obj, key) : null;
+      if (desc && (desc.get || desc.set)) Object.defineProperty(newObj, key, desc);
+      else newObj[key] = obj[key];
+    }
+  }
+  newObj["default"] = obj;
+  if (cache) cache.set(obj, newObj);
+  return newObj;
+}"#,
+    ),
+    (
+        "exportStar",
+        r#"function $exportStar(from, to) {
+  Object.keys(from).forEach(function (key) {
+    if (key === "default" || key === "__esModule" || Object.prototype.hasOwnProperty.call(to, key)) return;
+    Object.defineProperty(to, key, {
+      enumerable: true,
+      get: function () {
+        return from[key];
+      }
+    });
+  });
+  return from;
+}"#,
+    ),
+    (
+        "usingCtx",
+        r#"function $usingCtx() {
+  var _disposeSuppressedError = typeof SuppressedError === "function" ? SuppressedError : function (error, suppressed) {
+    var err = new Error();
+    err.name = "SuppressedError";
+    err.error = error;
+    err.suppressed = suppressed;
+    return err;
+  };
+  var empty = {}, stack = [];
+  function using(isAwait, value) {
+    if (value != null) {
+      if (Object(value) !== value) {
+        throw new TypeError("using declarations can only be used with objects, functions, null, or undefined.");
+      }
+      if (isAwait) var dispose = value[Symbol.asyncDispose || Symbol["for"]("Symbol.asyncDispose")];
+      if (dispose == null) dispose = value[Symbol.dispose || Symbol["for"]("Symbol.dispose")];
+      if (typeof dispose !== "function") throw new TypeError("Property [Symbol.dispose] is not a function.");
+      stack.push({ v: value, d: dispose, a: isAwait });
+    } else if (isAwait) {
+      stack.push({ d: value, a: isAwait });
+    }
+    return value;
+  }
+  return {
+    e: empty,
+    u: using.bind(null, false),
+    a: using.bind(null, true),
+    d: function () {
+      var error = this.e, resource;
+      function next() {
+        while (resource = stack.pop()) {
+          try {
+            var result = resource.d && resource.d.call(resource.v);
+            if (resource.a) return Promise.resolve(result).then(next, err);
+          } catch (e) {
+            return err(e);
+          }
+        }
+        if (error !== empty) throw error;
+      }
+      function err(e) {
+        error = error !== empty ? new _disposeSuppressedError(e, error) : e;
+        return next();
+      }
+      return next();
+    }
+  };
+}"#,
+    ),
+    ("applyDecs2305", APPLY_DECS_2305),
+    ("regeneratorRuntime", REGENERATOR_RUNTIME),
+];
+
+/// The 2023-05 decorators, for the public members and the classes
+const APPLY_DECS_2305: &str = r#"function $applyDecs2305(targetClass, memberDecs, classDecs) {
+  var FIELD = 0, ACCESSOR = 1, METHOD = 2, GETTER = 3, SETTER = 4, STATIC = 8;
+  var symbolMetadata = Symbol.metadata || Symbol["for"]("Symbol.metadata");
+  var metadata = Object.create(null);
+  function assertCallable(fn, hint) {
+    if (typeof fn !== "function") throw new TypeError(hint + " must be a function");
+  }
+  function createAddInitializerMethod(initializers, decoratorFinishedRef) {
+    return function addInitializer(initializer) {
+      if (decoratorFinishedRef.v) {
+        throw new Error("attempted to call addInitializer after decoration was finished");
+      }
+      assertCallable(initializer, "An initializer");
+      initializers.push(initializer);
+    };
+  }
+  function assertValidReturnValue(kind, value) {
+    if (kind === ACCESSOR) {
+      if (typeof value !== "object" || value === null) {
+        throw new TypeError("accessor decorators must return an object with get, set, or init properties or void 0");
+      }
+      if (value.get !== undefined) assertCallable(value.get, "accessor.get");
+      if (value.set !== undefined) assertCallable(value.set, "accessor.set");
+      if (value.init !== undefined) assertCallable(value.init, "accessor.init");
+    } else if (typeof value !== "function") {
+      var hint = kind === FIELD ? "field" : kind === 10 ? "class" : "method";
+      throw new TypeError(hint + " decorators must return a function or void 0");
+    }
+  }
+  function defineMetadata(Class) {
+    return Object.defineProperty(Class, symbolMetadata, { configurable: true, enumerable: true, value: metadata });
+  }
+  function memberDec(dec, name, initializers, kind, isStatic, value) {
+    var kindStr = ["field", "accessor", "method", "getter", "setter"][kind];
+    var decoratorFinishedRef = { v: false };
+    var ctx = { kind: kindStr, name: name, "static": isStatic, "private": false, metadata: metadata };
+    if (kind !== FIELD) ctx.addInitializer = createAddInitializerMethod(initializers, decoratorFinishedRef);
+    var access = {};
+    if (kind !== SETTER) access.get = function (target) { return target[name]; };
+    if (kind === FIELD || kind === ACCESSOR || kind === SETTER) access.set = function (target, v) { target[name] = v; };
+    access.has = function (target) { return name in target; };
+    ctx.access = access;
+    try {
+      return dec(value, ctx);
+    } finally {
+      decoratorFinishedRef.v = true;
+    }
+  }
+  function applyMemberDec(ret, base, decInfo, name, kind, isStatic, initializers) {
+    var decs = Array.isArray(decInfo[0]) ? decInfo[0] : [decInfo[0]];
+    var desc, init, value;
+    if (kind !== FIELD) {
+      desc = Object.getOwnPropertyDescriptor(base, name);
+      if (kind === ACCESSOR) value = { get: desc.get, set: desc.set };
+      else if (kind === METHOD) value = desc.value;
+      else if (kind === GETTER) value = desc.get;
+      else value = desc.set;
+    }
+    for (var i = decs.length - 1; i >= 0; i--) {
+      var newValue = memberDec(decs[i], name, initializers, kind, isStatic, value);
+      if (newValue === undefined) continue;
+      assertValidReturnValue(kind, newValue);
+      var newInit;
+      if (kind === FIELD) {
+        newInit = newValue;
+      } else if (kind === ACCESSOR) {
+        newInit = newValue.init;
+        value = { get: newValue.get || value.get, set: newValue.set || value.set };
+      } else {
+        value = newValue;
+      }
+      if (newInit !== undefined) {
+        if (init === undefined) init = [];
+        init.push(newInit);
+      }
+    }
+    if (kind === FIELD || kind === ACCESSOR) {
+      var ownInitializers = init || [];
+      ret.push(function (instance, value) {
+        for (var i = 0; i < ownInitializers.length; i++) value = ownInitializers[i].call(instance, value);
+        return value;
+      });
+    }
+    if (kind !== FIELD) {
+      if (kind === ACCESSOR) {
+        desc.get = value.get;
+        desc.set = value.set;
+      } else if (kind === METHOD) {
+        desc.value = value;
+      } else if (kind === GETTER) {
+        desc.get = value;
+      } else {
+        desc.set = value;
+      }
+      Object.defineProperty(base, name, desc);
+    }
+  }
+  function pushInitializers(ret, initializers) {
+    if (initializers) {
+      ret.push(function (instance) {
+        for (var i = 0; i < initializers.length; i++) initializers[i].call(instance);
+        return instance;
+      });
+    }
+  }
+  var ret = [], protoInitializers, staticInitializers;
+  for (var i = 0; i < memberDecs.length; i++) {
+    var decInfo = memberDecs[i];
+    var kind = decInfo[1], isStatic = (kind & STATIC) !== 0, base, initializers;
+    kind &= ~STATIC;
+    if (isStatic) {
+      base = targetClass;
+      if (kind !== FIELD) initializers = staticInitializers = staticInitializers || [];
+    } else {
+      base = targetClass.prototype;
+      if (kind !== FIELD) initializers = protoInitializers = protoInitializers || [];
+    }
+    applyMemberDec(ret, base, decInfo, decInfo[2], kind, isStatic, initializers);
+  }
+  pushInitializers(ret, protoInitializers);
+  pushInitializers(ret, staticInitializers);
+  if (!classDecs.length) defineMetadata(targetClass);
+  return {
+    e: ret,
+    get c() {
+      if (!classDecs.length) return undefined;
+      var initializers = [], newClass = targetClass, name = targetClass.name;
+      for (var i = classDecs.length - 1; i >= 0; i--) {
+        var decoratorFinishedRef = { v: false };
+        try {
+          var nextNewClass = classDecs[i](newClass, {
+            kind: "class",
+            name: name,
+            addInitializer: createAddInitializerMethod(initializers, decoratorFinishedRef),
+            metadata: metadata
+          });
+        } finally {
+          decoratorFinishedRef.v = true;
+        }
+        if (nextNewClass !== undefined) {
+          assertValidReturnValue(10, nextNewClass);
+          newClass = nextNewClass;
+        }
+      }
+      return [defineMetadata(newClass), function () {
+        for (var i = 0; i < initializers.length; i++) initializers[i].call(newClass);
+      }];
+    }
+  };
+}"#;
+
+/// The runtime of the generators lowered to state machines, with `wrap`, `mark`, `keys` and
+/// `values`
+const REGENERATOR_RUNTIME: &str = r#"function $regeneratorRuntime() {
+  "use strict";
+  var exports = {};
+  $regeneratorRuntime = function () {
+    return exports;
+  };
+  var Op = Object.prototype, hasOwn = Op.hasOwnProperty;
+  var Sym = typeof Symbol === "function" ? Symbol : {};
+  var iteratorSymbol = Sym.iterator || "@@iterator";
+  var toStringTagSymbol = Sym.toStringTag || "@@toStringTag";
+  var ContinueSentinel = {};
+  function define(obj, key, value) {
+    Object.defineProperty(obj, key, { value: value, enumerable: true, configurable: true, writable: true });
+    return obj[key];
+  }
+  function tryCatch(fn, obj, arg) {
+    try {
+      return { type: "normal", arg: fn.call(obj, arg) };
+    } catch (err) {
+      return { type: "throw", arg: err };
+    }
+  }
+  function Generator() {}
+  function GeneratorFunction() {}
+  function GeneratorFunctionPrototype() {}
+  var IteratorPrototype = {};
+  define(IteratorPrototype, iteratorSymbol, function () {
+    return this;
+  });
+  var getProto = Object.getPrototypeOf;
+  var NativeIteratorPrototype = getProto && getProto(getProto(values([])));
+  if (NativeIteratorPrototype && NativeIteratorPrototype !== Op && hasOwn.call(NativeIteratorPrototype, iteratorSymbol)) {
+    IteratorPrototype = NativeIteratorPrototype;
+  }
+  var Gp = GeneratorFunctionPrototype.prototype = Generator.prototype = Object.create(IteratorPrototype);
+  GeneratorFunction.prototype = GeneratorFunctionPrototype;
+  define(Gp, "constructor", GeneratorFunctionPrototype);
+  define(GeneratorFunctionPrototype, "constructor", GeneratorFunction);
+  GeneratorFunction.displayName = define(GeneratorFunctionPrototype, toStringTagSymbol, "GeneratorFunction");
+  ["next", "throw", "return"].forEach(function (method) {
+    define(Gp, method, function (arg) {
+      return this._invoke(method, arg);
+    });
+  });
+  define(Gp, toStringTagSymbol, "Generator");
+  define(Gp, "toString", function () {
+    return "[object Generator]";
+  });
+  exports.mark = function (genFun) {
+    if (Object.setPrototypeOf) {
+      Object.setPrototypeOf(genFun, GeneratorFunctionPrototype);
+    } else {
+      genFun.__proto__ = GeneratorFunctionPrototype;
+      define(genFun, toStringTagSymbol, "GeneratorFunction");
+    }
+    genFun.prototype = Object.create(Gp);
+    return genFun;
+  };
+  exports.wrap = function (innerFn, outerFn, self, tryLocsList) {
+    var protoGenerator = outerFn && outerFn.prototype instanceof Generator ? outerFn : Generator;
+    var generator = Object.create(protoGenerator.prototype);
+    var context = new Context(tryLocsList || []);
+    Object.defineProperty(generator, "_invoke", { value: makeInvokeMethod(innerFn, self, context) });
+    return generator;
+  };
+  function makeInvokeMethod(innerFn, self, context) {
+    var state = "suspendedStart";
+    return function invoke(method, arg) {
+      if (state === "executing") throw new Error("Generator is already running");
+      if (state === "completed") {
+        if (method === "throw") throw arg;
+        return { value: undefined, done: true };
+      }
+      context.method = method;
+      context.arg = arg;
+      while (true) {
+        var delegate = context.delegate;
+        if (delegate) {
+          var delegateResult = maybeInvokeDelegate(delegate, context);
+          if (delegateResult) {
+            if (delegateResult === ContinueSentinel) continue;
+            return delegateResult;
+          }
+        }
+        if (context.method === "next") {
+          context.sent = context._sent = context.arg;
+        } else if (context.method === "throw") {
+          if (state === "suspendedStart") {
+            state = "completed";
+            throw context.arg;
+          }
+          context.dispatchException(context.arg);
+        } else if (context.method === "return") {
+          context.abrupt("return", context.arg);
+        }
+        state = "executing";
+        var record = tryCatch(innerFn, self, context);
+        if (record.type === "normal") {
+          state = context.done ? "completed" : "suspendedYield";
+          if (record.arg === ContinueSentinel) continue;
+          return { value: record.arg, done: context.done };
+        }
+        state = "completed";
+        context.method = "throw";
+        context.arg = record.arg;
+      }
+    };
+  }
+  function maybeInvokeDelegate(delegate, context) {
+    var methodName = context.method;
+    var method = delegate.iterator[methodName];
+    if (method === undefined) {
+      context.delegate = null;
+      if (methodName === "throw" && delegate.iterator["return"]) {
+        context.method = "return";
+        context.arg = undefined;
+        maybeInvokeDelegate(delegate, context);
+        if (context.method === "throw") return ContinueSentinel;
+      }
+      if (methodName !== "return") {
+        context.method = "throw";
+        context.arg = new TypeError("The iterator does not provide a '" + methodName + "' method");
+      }
+      return ContinueSentinel;
+    }
+    var record = tryCatch(method, delegate.iterator, context.arg);
+    if (record.type === "throw") {
+      context.method = "throw";
+      context.arg = record.arg;
+      context.delegate = null;
+      return ContinueSentinel;
+    }
+    var info = record.arg;
+    if (!info) {
+      context.method = "throw";
+      context.arg = new TypeError("iterator result is not an object");
+      context.delegate = null;
+      return ContinueSentinel;
+    }
+    if (!info.done) return info;
+    context[delegate.resultName] = info.value;
+    context.next = delegate.nextLoc;
+    if (context.method !== "return") {
+      context.method = "next";
+      context.arg = undefined;
+    }
+    context.delegate = null;
+    return ContinueSentinel;
+  }
+  function pushTryEntry(locs) {
+    var entry = { tryLoc: locs[0] };
+    if (1 in locs) entry.catchLoc = locs[1];
+    if (2 in locs) {
+      entry.finallyLoc = locs[2];
+      entry.afterLoc = locs[3];
+    }
+    this.tryEntries.push(entry);
+  }
+  function resetTryEntry(entry) {
+    var record = entry.completion || {};
+    record.type = "normal";
+    delete record.arg;
+    entry.completion = record;
+  }
+  function Context(tryLocsList) {
+    this.tryEntries = [{ tryLoc: "root" }];
+    tryLocsList.forEach(pushTryEntry, this);
+    this.reset(true);
+  }
+  exports.keys = function (val) {
+    var object = Object(val), keys = [];
+    for (var key in object) keys.push(key);
+    keys.reverse();
+    return function next() {
+      while (keys.length) {
+        var key = keys.pop();
+        if (key in object) {
+          next.value = key;
+          next.done = false;
+          return next;
+        }
+      }
+      next.done = true;
+      return next;
+    };
+  };
+  function values(iterable) {
+    if (iterable || iterable === "") {
+      var iteratorMethod = iterable[iteratorSymbol];
+      if (iteratorMethod) return iteratorMethod.call(iterable);
+      if (typeof iterable.next === "function") return iterable;
+      if (!isNaN(iterable.length)) {
+        var i = -1;
+        var next = function next() {
+          while (++i < iterable.length) {
+            if (hasOwn.call(iterable, i)) {
+              next.value = iterable[i];
+              next.done = false;
+              return next;
+            }
+          }
+          next.value = undefined;
+          next.done = true;
+          return next;
+        };
+        return next.next = next;
+      }
+    }
+    throw new TypeError(typeof iterable + " is not iterable");
+  }
+  exports.values = values;
+  Context.prototype = {
+    constructor: Context,
+    reset: function (skipTempReset) {
+      this.prev = 0;
+      this.next = 0;
+      this.sent = this._sent = undefined;
+      this.done = false;
+      this.delegate = null;
+      this.method = "next";
+      this.arg = undefined;
+      this.tryEntries.forEach(resetTryEntry);
+      if (!skipTempReset) {
+        for (var name in this) {
+          if (name.charAt(0) === "t" && hasOwn.call(this, name) && !isNaN(+name.slice(1))) this[name] = undefined;
+        }
+      }
+    },
+    stop: function () {
+      this.done = true;
+      var rootRecord = this.tryEntries[0].completion;
+      if (rootRecord.type === "throw") throw rootRecord.arg;
+      return this.rval;
+    },
+    dispatchException: function (exception) {
+      if (this.done) throw exception;
+      var context = this;
+      function handle(record, loc, caught) {
+        record.type = "throw";
+        record.arg = exception;
+        context.next = loc;
+        if (caught) {
+          context.method = "next";
+          context.arg = undefined;
+        }
+        return !!caught;
+      }
+      for (var i = this.tryEntries.length - 1; i >= 0; --i) {
+        var entry = this.tryEntries[i];
+        var record = entry.completion;
+        if (entry.tryLoc === "root") return handle(record, "end");
+        if (entry.tryLoc <= this.prev) {
+          var hasCatch = hasOwn.call(entry, "catchLoc");
+          var hasFinally = hasOwn.call(entry, "finallyLoc");
+          if (hasCatch && this.prev < entry.catchLoc) return handle(record, entry.catchLoc, true);
+          if (hasFinally && this.prev < entry.finallyLoc) return handle(record, entry.finallyLoc);
+          if (!hasCatch && !hasFinally) throw new Error("try statement without catch or finally");
+        }
+      }
+    },
+    abrupt: function (type, arg) {
+      var finallyEntry;
+      for (var i = this.tryEntries.length - 1; i >= 0; --i) {
+        var entry = this.tryEntries[i];
+        if (entry.tryLoc <= this.prev && hasOwn.call(entry, "finallyLoc") && this.prev < entry.finallyLoc) {
+          finallyEntry = entry;
+          break;
+        }
+      }
+      if (finallyEntry && (type === "break" || type === "continue") && finallyEntry.tryLoc <= arg && arg <= finallyEntry.finallyLoc) {
+        finallyEntry = null;
+      }
+      var record = finallyEntry ? finallyEntry.completion : {};
+      record.type = type;
+      record.arg = arg;
+      if (finallyEntry) {
+        this.method = "next";
+        this.next = finallyEntry.finallyLoc;
+        return ContinueSentinel;
+      }
+      return this.complete(record);
+    },
+    complete: function (record, afterLoc) {
+      if (record.type === "throw") throw record.arg;
+      if (record.type === "break" || record.type === "continue") {
+        this.next = record.arg;
+      } else if (record.type === "return") {
+        this.rval = this.arg = record.arg;
+        this.method = "return";
+        this.next = "end";
+      } else if (record.type === "normal" && afterLoc) {
+        this.next = afterLoc;
+      }
+      return ContinueSentinel;
+    },
+    finish: function (finallyLoc) {
+      for (var i = this.tryEntries.length - 1; i >= 0; --i) {
+        var entry = this.tryEntries[i];
+        if (entry.finallyLoc === finallyLoc) {
+          this.complete(entry.completion, entry.afterLoc);
+          resetTryEntry(entry);
+          return ContinueSentinel;
+        }
+      }
+    },
+    "catch": function (tryLoc) {
+      for (var i = this.tryEntries.length - 1; i >= 0; --i) {
+        var entry = this.tryEntries[i];
+        if (entry.tryLoc === tryLoc) {
+          var record = entry.completion, thrown;
+          if (record.type === "throw") {
+            thrown = record.arg;
+            resetTryEntry(entry);
+          }
+          return thrown;
+        }
+      }
+      throw new Error("illegal catch attempt");
+    },
+    delegateYield: function (iterable, resultName, nextLoc) {
+      this.delegate = { iterator: values(iterable), resultName: resultName, nextLoc: nextLoc };
+      if (this.method === "next") this.arg = undefined;
+      return ContinueSentinel;
+    }
+  };
+  return exports;
+}"#;
+
+/// The source of a helper, `None` if it can't be inlined.
+pub fn source(name: &str) -> Option<&'static str> {
+    HELPERS.iter().find(|(helper, _)| *helper == name).map(|(_, source)| *source)
+}
+
+/// The other helpers called by a helper.
+pub fn dependencies(name: &str) -> std::vec::Vec<&'static str> {
+    let mut dependencies = vec![];
+    if let Some(source) = source(name) {
+        placeholders(source, |placeholder| {
+            if placeholder != name && !dependencies.contains(&placeholder) {
+                dependencies.push(placeholder);
+            }
+        });
+    }
+    dependencies
+}
+
+/// The source of a helper with the `$name` of the helpers replaced by their local bindings.
+pub fn resolve<F: Fn(&str) -> Option<Atom>>(source: &str, local: F) -> String {
+    let mut resolved = String::with_capacity(source.len());
+    let mut last = 0;
+    placeholders_with_offsets(source, |start, name| {
+        if let Some(local) = local(name) {
+            resolved.push_str(&source[last..start]);
+            resolved.push_str(local.as_str());
+            last = start + 1 + name.len();
+        }
+    });
+    resolved.push_str(&source[last..]);
+    resolved
+}
+
+fn placeholders<F: FnMut(&'static str)>(source: &'static str, mut f: F) {
+    placeholders_with_offsets(source, |_, name| f(name));
+}
+
+/// Calls `f` with the offset and the name of the `$name` of the helpers in `source`.
+fn placeholders_with_offsets<'s, F: FnMut(usize, &'s str)>(source: &'s str, mut f: F) {
+    let bytes = source.as_bytes();
+    let mut i = 0;
+    while let Some(offset) = source[i..].find('$') {
+        let start = i + offset;
+        let end = bytes[start + 1..]
+            .iter()
+            .position(|b| !b.is_ascii_alphanumeric() && *b != b'_')
+            .map_or(bytes.len(), |len| start + 1 + len);
+        let name = &source[start + 1..end];
+        if HELPERS.iter().any(|(helper, _)| *helper == name) {
+            f(start, name);
+        }
+        i = end.max(start + 1);
+    }
+}
+
+#[test]
+fn sources() {
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_span::SourceType;
+
+    let allocator = Allocator::default();
+    for (name, text) in HELPERS {
+        for (i, _) in text.match_indices('$') {
+            let placeholder: String =
+                text[i + 1..].chars().take_while(char::is_ascii_alphanumeric).collect();
+            assert!(placeholder.is_empty() || source(&placeholder).is_some(), "{name}");
+        }
+        let source = resolve(text, |name| Some(format!("_{name}").into()));
+        let ret = Parser::new(&allocator, &source, SourceType::default()).parse();
+        assert!(ret.errors.is_empty(), "{name}: {:?}", ret.errors);
+    }
+    assert_eq!(
+        dependencies("slicedToArray"),
+        ["arrayWithHoles", "iterableToArrayLimit", "unsupportedIterableToArray", "nonIterableRest"]
+    );
+}
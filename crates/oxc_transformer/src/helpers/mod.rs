@@ -0,0 +1,311 @@
+mod inline;
+
+use oxc_allocator::Vec;
+use oxc_ast::{ast::*, AstBuilder, VisitMut};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_parser::Parser;
+use oxc_span::{Atom, GetSpanMut, SourceType, Span};
+use serde::Deserialize;
+
+/// Where the code of Babel helpers (`defineProperty`, `classPrivateFieldGet`, ...) comes from.
+/// <https://babel.dev/docs/babel-plugin-transform-runtime#helpers>
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum HelperLoaderMode {
+    /// Reference the `babelHelpers` global, e.g. `babelHelpers.defineProperty(obj, key, value)`.
+    #[default]
+    External,
+    /// Import each helper once per file from a runtime module, e.g. `"@babel/runtime"`:
+    /// `import _defineProperty from "@babel/runtime/helpers/defineProperty"`.
+    Runtime(String),
+    /// Import the helpers by name from one module shared by the files, e.g. a chunk emitted by a
+    /// bundler which transforms each module separately:
+    /// `import { defineProperty as _defineProperty } from "\0helpers"`.
+    ///
+    /// The helpers each file needs are reported by [HelperLoader::names], so the bundler can
+    /// emit only these in the shared module.
+    Shared(String),
+    /// Define each helper once per file at its top, with the helpers it calls, e.g.
+    /// `function _defineProperty(obj, key, value) { ... }`.
+    Inline,
+}
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("The helper `{0}` can't be inlined")]
+#[diagnostic(help("Import the helpers from a runtime module instead"))]
+pub struct HelperNotInlined(pub String);
+
+/// Tracks the helpers used by a file, so they can be imported once at its top.
+#[derive(Debug)]
+pub struct HelperLoader {
+    mode: HelperLoaderMode,
+    module: bool,
+    /// The helpers imported in order of first use, with their local bindings.
+    used: std::vec::Vec<(Atom, Atom)>,
+    /// The number of helpers in `used` which are already imported.
+    added: usize,
+    /// All the helpers referenced by the file, in order of first use.
+    names: std::vec::Vec<Atom>,
+}
+
+impl HelperLoader {
+    pub fn new(mode: HelperLoaderMode, module: bool) -> Self {
+        Self { mode, module, used: vec![], added: 0, names: vec![] }
+    }
+
+    /// The helpers used by the file, in order of first use.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.names.iter().map(Atom::as_str)
+    }
+
+    pub fn mark_used(&mut self, name: &str) {
+        if !self.names.iter().any(|n| n.as_str() == name) {
+            self.names.push(name.into());
+        }
+    }
+
+    /// The local binding of a helper imported from the runtime module, if one is used.
+    pub fn local_name(&self, name: &str) -> Option<&Atom> {
+        self.used.iter().find(|(helper, _)| helper.as_str() == name).map(|(_, local)| local)
+    }
+
+    pub fn is_external(&self) -> bool {
+        self.mode == HelperLoaderMode::External
+    }
+
+    pub fn is_inline(&self) -> bool {
+        self.mode == HelperLoaderMode::Inline
+    }
+
+    /// Whether a helper can be used with the mode, i.e. it has a source to inline.
+    pub fn is_available(&self, name: &str) -> bool {
+        !self.is_inline() || inline::source(name).is_some()
+    }
+
+    /// The other helpers defined with a helper in the file.
+    pub fn dependencies(&self, name: &str) -> std::vec::Vec<&'static str> {
+        if self.is_inline() {
+            inline::dependencies(name)
+        } else {
+            vec![]
+        }
+    }
+
+    /// The modules the helpers used are required from, which AMD modules list as dependencies.
+    pub fn modules(&self) -> std::vec::Vec<Atom> {
+        match &self.mode {
+            HelperLoaderMode::Runtime(module) => self
+                .used
+                .iter()
+                .map(|(name, _)| format!("{module}/helpers/{name}").into())
+                .collect(),
+            HelperLoaderMode::Shared(module) if !self.used.is_empty() => {
+                vec![module.as_str().into()]
+            }
+            _ => vec![],
+        }
+    }
+
+    pub fn add(&mut self, name: &str, local: Atom) {
+        self.used.push((name.into(), local));
+    }
+
+    /// `import _name from "{module}/helpers/{name}"` for modules,
+    /// `var _name = require("{module}/helpers/{name}")` for scripts.
+    ///
+    /// With a shared module, `import { name as _name } from "{module}"` for modules,
+    /// `var _name = require("{module}").name` for scripts.
+    ///
+    /// Inline, `function _name() { ... }` in order of first use.
+    ///
+    /// Only the helpers used since the last call are added, for the transforms which use
+    /// helpers after the others, such as the CommonJS modules.
+    pub fn add_imports<'a>(&mut self, ast: &AstBuilder<'a>, stmts: &mut Vec<'a, Statement<'a>>) {
+        let all = &self.used;
+        let used = self.used[self.added..].to_vec();
+        self.added = self.used.len();
+        match &self.mode {
+            HelperLoaderMode::External => {}
+            HelperLoaderMode::Runtime(module) => {
+                for (name, local) in used.into_iter().rev() {
+                    let source: Atom = format!("{module}/helpers/{name}").into();
+                    let stmt = if self.module {
+                        let local = BindingIdentifier::new(Span::default(), local);
+                        let specifier = ImportDefaultSpecifier { span: Span::default(), local };
+                        let specifier =
+                            ImportDeclarationSpecifier::ImportDefaultSpecifier(specifier);
+                        import_declaration(ast, ast.new_vec_single(specifier), source)
+                    } else {
+                        var_declaration(ast, local, require(ast, source))
+                    };
+                    stmts.insert(0, stmt);
+                }
+            }
+            HelperLoaderMode::Shared(module) if self.module => {
+                if used.is_empty() {
+                    return;
+                }
+                let mut specifiers = ast.new_vec_with_capacity(used.len());
+                for (name, local) in used {
+                    let specifier = ImportSpecifier {
+                        span: Span::default(),
+                        imported: ModuleExportName::Identifier(IdentifierName::new(
+                            Span::default(),
+                            name,
+                        )),
+                        local: BindingIdentifier::new(Span::default(), local),
+                        import_kind: ImportOrExportKind::Value,
+                    };
+                    specifiers.push(ImportDeclarationSpecifier::ImportSpecifier(specifier));
+                }
+                stmts.insert(0, import_declaration(ast, specifiers, module.as_str().into()));
+            }
+            HelperLoaderMode::Inline => {
+                let local = |name: &str| {
+                    all.iter().find(|(helper, _)| helper.as_str() == name).map(|(_, l)| l.clone())
+                };
+                let mut helpers = ast.new_vec();
+                for (name, _) in &used {
+                    let Some(source) = inline::source(name) else { continue };
+                    helpers.extend(parse_helper(ast, &inline::resolve(source, local)));
+                }
+                for (i, stmt) in helpers.into_iter().enumerate() {
+                    stmts.insert(i, stmt);
+                }
+            }
+            HelperLoaderMode::Shared(module) => {
+                for (name, local) in used.into_iter().rev() {
+                    let object = require(ast, module.as_str().into());
+                    let property = IdentifierName::new(Span::default(), name);
+                    let init =
+                        ast.static_member_expression(Span::default(), object, property, false);
+                    stmts.insert(0, var_declaration(ast, local, init));
+                }
+            }
+        }
+    }
+}
+
+/// The statements of the source of a helper defined in the file, detached from its source.
+pub fn parse_helper<'a>(ast: &AstBuilder<'a>, source: &str) -> Vec<'a, Statement<'a>> {
+    let source = ast.new_str(source);
+    let ret = Parser::new(ast.allocator, source, SourceType::default()).parse();
+    debug_assert!(ret.errors.is_empty(), "invalid helper source: {source}");
+    let mut program = ret.program;
+    SpanResetter.visit_program(&mut program);
+    program.body
+}
+
+/// Detach the statements and expressions of a helper from the source of the file, so they are
+/// not mapped to it and don't take its comments.
+struct SpanResetter;
+
+impl<'a> VisitMut<'a> for SpanResetter {
+    fn visit_statement(&mut self, stmt: &mut Statement<'a>) {
+        *stmt.span_mut() = Span::default();
+        self.visit_statement_match(stmt);
+    }
+
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        *expr.span_mut() = Span::default();
+        self.visit_expression_match(expr);
+    }
+}
+
+fn import_declaration<'a>(
+    ast: &AstBuilder<'a>,
+    specifiers: Vec<'a, ImportDeclarationSpecifier>,
+    source: Atom,
+) -> Statement<'a> {
+    let source = StringLiteral::new(Span::default(), source);
+    let decl = ast.import_declaration(
+        Span::default(),
+        specifiers,
+        source,
+        None,
+        ImportOrExportKind::Value,
+    );
+    ast.module_declaration(ModuleDeclaration::ImportDeclaration(decl))
+}
+
+/// `require("source")`
+fn require<'a>(ast: &AstBuilder<'a>, source: Atom) -> Expression<'a> {
+    let callee = IdentifierReference::new(Span::default(), "require".into());
+    let callee = ast.identifier_reference_expression(callee);
+    let argument = ast.literal_string_expression(StringLiteral::new(Span::default(), source));
+    let arguments = ast.new_vec_single(Argument::Expression(argument));
+    ast.call_expression(Span::default(), callee, arguments, false, None)
+}
+
+/// `var local = init`
+fn var_declaration<'a>(ast: &AstBuilder<'a>, local: Atom, init: Expression<'a>) -> Statement<'a> {
+    let local = BindingIdentifier::new(Span::default(), local);
+    let binding = ast.binding_pattern(ast.binding_pattern_identifier(local), None, false);
+    let kind = VariableDeclarationKind::Var;
+    let decl = ast.variable_declarator(Span::default(), kind, binding, Some(init), false);
+    let decl = ast.variable_declaration(
+        Span::default(),
+        kind,
+        ast.new_vec_single(decl),
+        Modifiers::empty(),
+    );
+    Statement::Declaration(Declaration::VariableDeclaration(decl))
+}
+
+#[test]
+#[rustfmt::skip]
+fn test() {
+    use crate::{es2022::ClassPropertiesOptions, options::TransformOptions, tester::Tester};
+
+    let options = TransformOptions {
+        helpers: HelperLoaderMode::Runtime("@babel/runtime".into()),
+        class_properties: Some(ClassPropertiesOptions::default()),
+        ..TransformOptions::default()
+    };
+    let tests = &[
+        (
+            "class Foo { a = 1; b = 2; }",
+            "import _defineProperty from '@babel/runtime/helpers/defineProperty'; class Foo { constructor() { _defineProperty(this, 'a', 1); _defineProperty(this, 'b', 2); } }",
+        ),
+    ];
+    Tester::new("test.js", options).test(tests);
+
+    let options = TransformOptions {
+        helpers: HelperLoaderMode::Shared("\0helpers".into()),
+        class_properties: Some(ClassPropertiesOptions::default()),
+        ..TransformOptions::default()
+    };
+    let source = "class Foo { a = 1; #b = 2; }";
+    let ret = crate::transform(source, oxc_span::SourceType::default().with_module(true), options.clone());
+    assert_eq!(ret.helpers, ["defineProperty", "classPrivateFieldInitSpec"]);
+    Tester::new("test.mjs", options.clone()).test(&[
+        (
+            "class Foo { a = 1; b = 2; }",
+            "import { defineProperty as _defineProperty } from '\\0helpers'; class Foo { constructor() { _defineProperty(this, 'a', 1); _defineProperty(this, 'b', 2); } }",
+        ),
+    ]);
+    let options = TransformOptions { module: crate::ModuleFormat::CommonJs, ..options };
+    Tester::new("test.js", options).test(&[
+        (
+            "class Foo { a = 1; }",
+            "'use strict'; var _defineProperty = require('\\0helpers').defineProperty; class Foo { constructor() { _defineProperty(this, 'a', 1); } }",
+        ),
+    ]);
+
+    let options = TransformOptions {
+        helpers: HelperLoaderMode::Inline,
+        class_properties: Some(ClassPropertiesOptions::default()),
+        ..TransformOptions::default()
+    };
+    let source = "class Foo { a = 1; b = 2; } class Bar { c = 3; }";
+    let ret = crate::transform(source, oxc_span::SourceType::default().with_module(true), options);
+    assert!(ret.errors.is_empty());
+    for helper in ["defineProperty", "toPropertyKey", "toPrimitive", "typeof"] {
+        assert_eq!(ret.code.matches(&format!("function _{helper}(")).count(), 1, "{helper}");
+    }
+    assert!(ret.code.starts_with("function _defineProperty("));
+    assert!(!ret.code.contains("import"));
+}
@@ -7,63 +7,206 @@
 //! * <https://babel.dev/docs/presets>
 //! * <https://github.com/microsoft/TypeScript/blob/main/src/compiler/transformer.ts>
 
+mod comments;
 mod context;
+mod decorators;
+mod define;
+mod emotion;
 mod es2015;
 mod es2016;
+mod es2018;
 mod es2019;
 mod es2020;
 mod es2021;
 mod es2022;
+mod explicit_resource_management;
+mod helpers;
+mod modular_imports;
+mod modules;
 mod options;
+mod plugin;
+mod polyfills;
 mod react_jsx;
 mod regexp;
+mod styled_components;
+mod targets;
 #[cfg(test)]
 mod tester;
 mod typescript;
 mod utils;
 
-use std::{cell::RefCell, rc::Rc};
+use std::{cell::RefCell, path::Path, rc::Rc};
 
 use oxc_allocator::{Allocator, Vec};
-use oxc_ast::{ast::*, AstBuilder, VisitMut};
-use oxc_semantic::{ScopeTree, SymbolTable};
-use oxc_span::SourceType;
+use oxc_ast::{ast::*, AstBuilder, AttachedComments, Trivias, VisitMut};
+use oxc_codegen::{Codegen, CodegenOptions, SourceMap};
+use oxc_diagnostics::Error;
+use oxc_parser::Parser;
+use oxc_semantic::{ScopeTree, SemanticBuilder, SymbolTable};
+use oxc_span::{SourceType, Span};
 
 use crate::{
-    context::TransformerCtx, es2015::ShorthandProperties, es2016::ExponentiationOperator,
-    es2019::OptionalCatchBinding, es2020::NullishCoalescingOperator,
-    es2021::LogicalAssignmentOperators, es2022::ClassStaticBlock, react_jsx::ReactJsx,
-    regexp::RegexpFlags, typescript::TypeScript, utils::CreateVars,
+    comments::CommentCollector,
+    decorators::{LegacyDecorators, Stage3Decorators},
+    define::Define,
+    emotion::Emotion,
+    es2015::{
+        BlockScoping, ComputedProperties, Destructuring, Literals, Regenerator,
+        ShorthandProperties, Spread,
+    },
+    es2016::ExponentiationOperator,
+    es2018::AsyncGeneratorFunctions,
+    es2019::OptionalCatchBinding,
+    es2020::{
+        BigIntLiterals, DynamicImport, ExportNamespaceFrom, NullishCoalescingOperator,
+        OptionalChaining,
+    },
+    es2021::{LogicalAssignmentOperators, NumericSeparator},
+    es2022::{ClassProperties, ClassStaticBlock},
+    explicit_resource_management::ExplicitResourceManagement,
+    modular_imports::ModularImports,
+    modules::CommonJs,
+    polyfills::CoreJsUsage,
+    react_jsx::ReactJsx,
+    regexp::{RegexpFeatures, RegexpFlags},
+    styled_components::StyledComponents,
+    typescript::TypeScript,
+    utils::CreateVars,
 };
 
 pub use crate::{
-    es2020::NullishCoalescingOperatorOptions,
-    options::{TransformOptions, TransformTarget},
-    react_jsx::{ReactJsxOptions, ReactJsxRuntime},
+    comments::PreserveComments,
+    context::TransformerCtx,
+    decorators::{DecoratorsOptions, DecoratorsVersion},
+    emotion::EmotionOptions,
+    es2015::{ComputedPropertiesOptions, SpreadOptions},
+    es2020::{NullishCoalescingOperatorOptions, OptionalChainingOptions},
+    es2022::ClassPropertiesOptions,
+    helpers::{HelperLoader, HelperLoaderMode},
+    modular_imports::ModularImportOptions,
+    modules::ModuleFormat,
+    options::{TransformOptions, TransformOverride, TransformTarget},
+    plugin::TransformPlugin,
+    react_jsx::{JsxMode, ReactJsxOptions, ReactJsxRuntime},
+    styled_components::StyledComponentsOptions,
+    targets::{Engine, EngineTargets, Version},
+    typescript::{ImportsNotUsedAsValues, TypeScriptOptions},
 };
+pub use oxc_syntax::assumptions::CompilerAssumptions;
 
 pub struct Transformer<'a> {
-    #[allow(unused)]
+    ast: Rc<AstBuilder<'a>>,
+    ctx: TransformerCtx<'a>,
     typescript: Option<TypeScript<'a>>,
-    #[allow(unused)]
+    decorators_legacy: Option<LegacyDecorators<'a>>,
+    decorators_stage3: Option<Stage3Decorators<'a>>,
     react_jsx: Option<ReactJsx<'a>>,
+    styled_components: Option<StyledComponents<'a>>,
+    emotion: Option<Emotion<'a>>,
+    define: Option<Define<'a>>,
+    polyfills: Option<CoreJsUsage<'a>>,
+    modular_imports: Option<ModularImports<'a>>,
+    modules_commonjs: Option<CommonJs<'a>>,
+    regexp_features: Option<RegexpFeatures<'a>>,
     regexp_flags: Option<RegexpFlags<'a>>,
+    // esnext
+    explicit_resource_management: Option<ExplicitResourceManagement<'a>>,
     // es2022
+    es2022_class_properties: Option<ClassProperties<'a>>,
     es2022_class_static_block: Option<ClassStaticBlock<'a>>,
     // es2021
     es2021_logical_assignment_operators: Option<LogicalAssignmentOperators<'a>>,
+    es2021_numeric_separator: Option<NumericSeparator<'a>>,
     // es2020
     es2020_nullish_coalescing_operators: Option<NullishCoalescingOperator<'a>>,
+    es2020_optional_chaining: Option<OptionalChaining<'a>>,
+    es2020_dynamic_import: Option<DynamicImport<'a>>,
+    es2020_export_namespace_from: Option<ExportNamespaceFrom<'a>>,
+    es2020_bigint: Option<BigIntLiterals<'a>>,
     // es2019
     es2019_optional_catch_binding: Option<OptionalCatchBinding<'a>>,
+    // es2018
+    es2018_async_generator_functions: Option<AsyncGeneratorFunctions<'a>>,
     // es2016
     es2016_exponentiation_operator: Option<ExponentiationOperator<'a>>,
     // es2015
+    es2015_block_scoping: Option<BlockScoping<'a>>,
+    es2015_computed_properties: Option<ComputedProperties<'a>>,
+    es2015_destructuring: Option<Destructuring<'a>>,
+    es2015_literals: Option<Literals<'a>>,
+    es2015_regenerator: Option<Regenerator<'a>>,
     es2015_shorthand_properties: Option<ShorthandProperties<'a>>,
+    es2015_spread: Option<Spread<'a>>,
+    preserve_comments: PreserveComments,
+    comment_collector: Option<CommentCollector<'a>>,
+    // custom passes, sorted by priority
+    plugins: std::vec::Vec<std::boxed::Box<dyn TransformPlugin<'a> + 'a>>,
+}
+
+/// The output of [transform].
+pub struct TransformResult {
+    pub code: String,
+    /// The source map of the code, when `source_file_name` is set.
+    pub map: Option<SourceMap>,
+    /// The helpers the code uses, see [HelperLoaderMode::Shared].
+    pub helpers: std::vec::Vec<String>,
+    /// The syntax and semantic errors of the source, the code is empty if it can't be parsed,
+    /// and the syntax which can't be transformed for the target.
+    pub errors: std::vec::Vec<Error>,
+}
+
+/// Parse, transform and print `source_text`.
+pub fn transform(
+    source_text: &str,
+    source_type: SourceType,
+    options: TransformOptions,
+) -> TransformResult {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    if !ret.errors.is_empty() {
+        return TransformResult {
+            code: String::new(),
+            map: None,
+            helpers: vec![],
+            errors: ret.errors,
+        };
+    }
+
+    let semantic_ret = SemanticBuilder::new(source_text, source_type).build(&ret.program);
+    let mut errors = semantic_ret.errors;
+    let (symbols, scopes) = semantic_ret.semantic.into_symbol_table_and_scope_tree();
+    let symbols = Rc::new(RefCell::new(symbols));
+    let scopes = Rc::new(RefCell::new(scopes));
+
+    let source_file_name = options.source_file_name.clone();
+    let options = match &source_file_name {
+        Some(name) => options.for_path(Path::new(name)),
+        None => options,
+    };
+    let program = allocator.alloc(ret.program);
+    let mut transformer = Transformer::new(&allocator, source_type, &symbols, &scopes, options)
+        .with_comments(source_text, &ret.trivias);
+    // Without a path, the transforms still refer to the locations, in the file `""` as Babel
+    let source_path = Path::new(source_file_name.as_deref().unwrap_or_default());
+    transformer = transformer.with_source(source_path, source_text);
+    let transform_errors = transformer.errors();
+    let helpers = transformer.helpers();
+    let comments = transformer.build(program);
+    errors.extend(transform_errors.take());
+    let helpers = helpers.borrow().names().map(String::from).collect();
+
+    let mut codegen =
+        Codegen::<false>::new(source_text.len(), CodegenOptions).with_comments(comments);
+    if let Some(name) = &source_file_name {
+        codegen = codegen.with_source_map(name, source_text);
+    }
+    let (code, map) = codegen.build_with_source_map(program);
+    TransformResult { code, map, helpers, errors }
 }
 
 impl<'a> Transformer<'a> {
     #[rustfmt::skip]
+    #[allow(clippy::needless_pass_by_value)]
     pub fn new(
         allocator: &'a Allocator,
         source_type: SourceType,
@@ -72,54 +215,342 @@ impl<'a> Transformer<'a> {
         options: TransformOptions,
     ) -> Self {
         let ast = Rc::new(AstBuilder::new(allocator));
+        // Whether the imports added by the transforms can be `import` declarations.
+        let module = source_type.is_module() && options.module == ModuleFormat::Preserve;
         let ctx = TransformerCtx {
             ast: Rc::clone(&ast),
             symbols: Rc::clone(symbols),
             scopes: Rc::clone(scopes),
+            helpers: Rc::new(RefCell::new(HelperLoader::new(options.helpers.clone(), module))),
+            comments: Rc::new(RefCell::new(AttachedComments::default())),
+            errors: Rc::new(RefCell::new(vec![])),
         };
         Self {
-            typescript: source_type.is_typescript().then(|| TypeScript::new(Rc::clone(&ast))),
-            react_jsx: options.react_jsx.map(|options| ReactJsx::new(Rc::clone(&ast), options)),
+            ast: Rc::clone(&ast),
+            ctx: ctx.clone(),
+            typescript: source_type.is_typescript().then(|| TypeScript::new(Rc::clone(&ast), ctx.clone(), &options)),
+            decorators_legacy: LegacyDecorators::new(Rc::clone(&ast), ctx.clone(), &options),
+            decorators_stage3: Stage3Decorators::new(Rc::clone(&ast), ctx.clone(), &options),
+            react_jsx: ReactJsx::new(Rc::clone(&ast), ctx.clone(), source_type, &options),
+            styled_components: StyledComponents::new(Rc::clone(&ast), ctx.clone(), &options),
+            emotion: Emotion::new(Rc::clone(&ast), ctx.clone(), &options),
+            define: Define::new(Rc::clone(&ast), ctx.clone(), &options),
+            polyfills: CoreJsUsage::new(Rc::clone(&ast), ctx.clone(), module, &options),
+            modular_imports: ModularImports::new(Rc::clone(&ast), &options),
+            modules_commonjs: CommonJs::new(Rc::clone(&ast), ctx.clone(), source_type, &options),
+            regexp_features: RegexpFeatures::new(Rc::clone(&ast), ctx.clone(), &options),
             regexp_flags: RegexpFlags::new(Rc::clone(&ast), &options),
+            explicit_resource_management: ExplicitResourceManagement::new(Rc::clone(&ast), ctx.clone(), &options),
+            es2022_class_properties: ClassProperties::new(Rc::clone(&ast), ctx.clone(), &options),
             es2022_class_static_block: es2022::ClassStaticBlock::new(Rc::clone(&ast), &options),
             es2021_logical_assignment_operators: LogicalAssignmentOperators::new(Rc::clone(&ast), ctx.clone(), &options),
+            es2021_numeric_separator: NumericSeparator::new(Rc::clone(&ast), &options),
             es2020_nullish_coalescing_operators: NullishCoalescingOperator::new(Rc::clone(&ast), ctx.clone(), &options),
-            es2019_optional_catch_binding: OptionalCatchBinding::new(Rc::clone(&ast), &options),
+            es2020_optional_chaining: OptionalChaining::new(Rc::clone(&ast), ctx.clone(), &options),
+            es2020_dynamic_import: DynamicImport::new(Rc::clone(&ast), ctx.clone(), &options),
+            es2020_export_namespace_from: ExportNamespaceFrom::new(Rc::clone(&ast), ctx.clone(), &options),
+            es2020_bigint: BigIntLiterals::new(ctx.clone(), &options),
+            es2019_optional_catch_binding: OptionalCatchBinding::new(Rc::clone(&ast), ctx.clone(), &options),
+            es2018_async_generator_functions: AsyncGeneratorFunctions::new(Rc::clone(&ast), ctx.clone(), &options),
             es2016_exponentiation_operator: ExponentiationOperator::new(Rc::clone(&ast), ctx.clone(), &options),
+            es2015_block_scoping: BlockScoping::new(Rc::clone(&ast), ctx.clone(), &options),
+            es2015_computed_properties: ComputedProperties::new(Rc::clone(&ast), ctx.clone(), &options),
+            es2015_destructuring: Destructuring::new(Rc::clone(&ast), ctx.clone(), &options),
+            es2015_literals: Literals::new(Rc::clone(&ast), &options),
+            es2015_regenerator: Regenerator::new(Rc::clone(&ast), ctx.clone(), &options),
             es2015_shorthand_properties: ShorthandProperties::new(Rc::clone(&ast), &options),
+            es2015_spread: Spread::new(Rc::clone(&ast), ctx.clone(), &options),
+            preserve_comments: options.preserve_comments,
+            comment_collector: None,
+            plugins: vec![],
         }
     }
 
-    pub fn build(mut self, program: &mut Program<'a>) {
+    /// The path and text of the source file, for transforms which refer to source locations,
+    /// e.g. the `__source` of JSX elements in development.
+    #[must_use]
+    pub fn with_source(mut self, source_path: &Path, source_text: &'a str) -> Self {
+        self.react_jsx.as_mut().map(|t| t.set_source(source_path, source_text));
+        self.styled_components.as_mut().map(|t| t.set_source(source_path, source_text));
+        self.emotion.as_mut().map(|t| t.set_source(source_path, source_text));
+        self
+    }
+
+    /// Register a custom pass, see [TransformPlugin].
+    #[must_use]
+    pub fn with_plugin(mut self, plugin: impl TransformPlugin<'a> + 'a) -> Self {
+        let priority = plugin.priority();
+        let index = self.plugins.partition_point(|p| p.priority() <= priority);
+        self.plugins.insert(index, std::boxed::Box::new(plugin));
+        self
+    }
+
+    /// Keep the comments of the source, as configured by `preserve_comments`.
+    #[must_use]
+    pub fn with_comments(mut self, source_text: &'a str, trivias: &Trivias) -> Self {
+        self.comment_collector =
+            Some(CommentCollector::new(source_text, trivias, self.preserve_comments));
+        self
+    }
+
+    /// Transform the program, returns the comments kept to print them with
+    /// `Codegen::with_comments`.
+    pub fn build(mut self, program: &mut Program<'a>) -> AttachedComments {
+        if let Some(collector) = self.comment_collector.take() {
+            *self.ctx.comments.borrow_mut() = collector.build(program);
+        }
         self.visit_program(program);
+        self.ctx.comments.take()
+    }
+
+    /// The errors of the transforms, filled by [Transformer::build], e.g. the syntax which can't
+    /// be transformed for the target.
+    pub fn errors(&self) -> Rc<RefCell<std::vec::Vec<Error>>> {
+        Rc::clone(&self.ctx.errors)
+    }
+
+    /// The helpers of the transforms, which lists the ones used by the file after
+    /// [Transformer::build].
+    pub fn helpers(&self) -> Rc<RefCell<HelperLoader>> {
+        Rc::clone(&self.ctx.helpers)
+    }
+
+    /// The enabled transforms which create temporary variables.
+    fn create_vars(&mut self) -> impl Iterator<Item = &mut dyn CreateVars<'a>> {
+        [
+            self.es2021_logical_assignment_operators.as_mut().map(|t| t as &mut dyn CreateVars<'a>),
+            self.es2020_nullish_coalescing_operators.as_mut().map(|t| t as &mut dyn CreateVars<'a>),
+            self.es2020_optional_chaining.as_mut().map(|t| t as &mut dyn CreateVars<'a>),
+            self.es2016_exponentiation_operator.as_mut().map(|t| t as &mut dyn CreateVars<'a>),
+            self.es2022_class_properties.as_mut().map(|t| t as &mut dyn CreateVars<'a>),
+            self.decorators_stage3.as_mut().map(|t| t as &mut dyn CreateVars<'a>),
+            self.es2015_computed_properties.as_mut().map(|t| t as &mut dyn CreateVars<'a>),
+            self.es2015_destructuring.as_mut().map(|t| t as &mut dyn CreateVars<'a>),
+            self.es2015_regenerator.as_mut().map(|t| t as &mut dyn CreateVars<'a>),
+            self.es2015_spread.as_mut().map(|t| t as &mut dyn CreateVars<'a>),
+        ]
+        .into_iter()
+        .flatten()
+    }
+
+    /// Declare the temporary variables created in a program or function body at its top.
+    fn declare_vars(&mut self, stmts: &mut Vec<'a, Statement<'a>>) {
+        for t in self.create_vars() {
+            t.add_vars_to_statements(stmts);
+        }
+    }
+
+    /// Run the syntax pass `pass` on `expr`, when it is enabled.
+    fn transform_expression_with(&mut self, pass: SyntaxPass, expr: &mut Expression<'a>) {
+        match pass {
+            SyntaxPass::LogicalAssignmentOperators => {
+                self.es2021_logical_assignment_operators
+                    .as_mut()
+                    .map(|t| t.transform_expression(expr));
+            }
+            SyntaxPass::NumericSeparator => {
+                self.es2021_numeric_separator.as_mut().map(|t| t.transform_expression(expr));
+            }
+            SyntaxPass::NullishCoalescingOperator => {
+                self.es2020_nullish_coalescing_operators
+                    .as_mut()
+                    .map(|t| t.transform_expression(expr));
+            }
+            SyntaxPass::OptionalChaining => {
+                self.es2020_optional_chaining.as_mut().map(|t| t.transform_expression(expr));
+            }
+            SyntaxPass::DynamicImport => {
+                self.es2020_dynamic_import.as_mut().map(|t| t.transform_expression(expr));
+            }
+            SyntaxPass::BigIntLiterals => {
+                self.es2020_bigint.as_mut().map(|t| t.transform_expression(expr));
+            }
+            SyntaxPass::AsyncGeneratorFunctions => {
+                self.es2018_async_generator_functions
+                    .as_mut()
+                    .map(|t| t.transform_expression(expr));
+            }
+            SyntaxPass::ExponentiationOperator => {
+                self.es2016_exponentiation_operator.as_mut().map(|t| t.transform_expression(expr));
+            }
+            SyntaxPass::Destructuring => {
+                self.es2015_destructuring.as_mut().map(|t| t.transform_expression(expr));
+            }
+            SyntaxPass::Literals => {
+                self.es2015_literals.as_mut().map(|t| t.transform_expression(expr));
+            }
+            SyntaxPass::Spread => {
+                self.es2015_spread.as_mut().map(|t| t.transform_expression(expr));
+            }
+            SyntaxPass::ClassProperties => {
+                self.es2022_class_properties.as_mut().map(|t| t.transform_expression(expr));
+            }
+        }
+    }
+
+    /// Run the plugins which go `before` or after the built-in transforms.
+    fn run_plugins(
+        &mut self,
+        before: bool,
+        mut f: impl FnMut(&mut dyn TransformPlugin<'a>, &TransformerCtx<'a>),
+    ) {
+        for plugin in self.plugins.iter_mut().filter(|p| (p.priority() < 0) == before) {
+            f(plugin.as_mut(), &self.ctx);
+        }
     }
 }
 
+/// The syntax passes which lower an expression when it is entered, see [SYNTAX_PASSES].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SyntaxPass {
+    LogicalAssignmentOperators,
+    NumericSeparator,
+    NullishCoalescingOperator,
+    OptionalChaining,
+    DynamicImport,
+    BigIntLiterals,
+    AsyncGeneratorFunctions,
+    ExponentiationOperator,
+    Destructuring,
+    Literals,
+    Spread,
+    ClassProperties,
+}
+
+/// The order of the syntax passes on an expression.
+///
+/// They run from the newest edition to the oldest, so the syntax output by a pass is lowered by
+/// the older ones, either on the same node or when its children are visited: `x ??= a?.b`
+/// becomes `x ?? (x = a?.b)`, then its `??` and then its `?.` are lowered. A pass must not emit
+/// syntax newer than its own edition.
+/// Class properties run last, they lower the private fields the other passes output.
+const SYNTAX_PASSES: [SyntaxPass; 12] = [
+    SyntaxPass::LogicalAssignmentOperators,
+    SyntaxPass::NumericSeparator,
+    SyntaxPass::NullishCoalescingOperator,
+    SyntaxPass::OptionalChaining,
+    SyntaxPass::DynamicImport,
+    SyntaxPass::BigIntLiterals,
+    SyntaxPass::AsyncGeneratorFunctions,
+    SyntaxPass::ExponentiationOperator,
+    SyntaxPass::Destructuring,
+    SyntaxPass::Literals,
+    SyntaxPass::Spread,
+    SyntaxPass::ClassProperties,
+];
+
 impl<'a> VisitMut<'a> for Transformer<'a> {
+    fn visit_program(&mut self, program: &mut Program<'a>) {
+        self.run_plugins(true, |p, ctx| p.enter_program(program, ctx));
+        self.typescript.as_mut().map(|t| t.transform_program(program));
+        self.modular_imports.as_mut().map(|t| t.transform_statements(&mut program.body));
+        self.es2020_export_namespace_from
+            .as_mut()
+            .map(|t| t.transform_statements(&mut program.body));
+        self.styled_components.as_mut().map(|t| t.collect_imports(&program.body));
+        self.emotion.as_mut().map(|t| t.collect_imports(&program.body));
+        self.modules_commonjs.as_mut().map(|t| t.collect_imports(&program.body));
+        self.run_plugins(false, |p, ctx| p.enter_program(program, ctx));
+
+        for directive in program.directives.iter_mut() {
+            self.visit_directive(directive);
+        }
+        self.visit_statements(&mut program.body);
+        self.declare_vars(&mut program.body);
+        self.define.as_mut().map(|t| t.remove_unused_imports(program));
+
+        self.react_jsx.as_mut().map(|t| t.add_runtime_imports(&mut program.body));
+        self.emotion.as_mut().map(|t| t.add_imports(&mut program.body));
+        self.decorators_legacy.as_mut().map(|t| t.add_helpers(&mut program.body));
+        // The CommonJS modules add the helpers after their own interop helpers are used.
+        if self.modules_commonjs.is_none() {
+            self.ctx.add_helper_imports(&mut program.body);
+        }
+        self.polyfills.as_mut().map(|t| t.add_imports(&mut program.body));
+        self.run_plugins(true, |p, ctx| p.exit_program(program, ctx));
+        self.modules_commonjs.as_mut().map(|t| t.transform_program(program));
+        self.run_plugins(false, |p, ctx| p.exit_program(program, ctx));
+    }
+
     fn visit_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>) {
-        for stmt in stmts.iter_mut() {
-            self.visit_statement(stmt);
+        self.run_plugins(true, |p, ctx| p.enter_statements(stmts, ctx));
+        self.typescript.as_mut().map(|t| t.transform_statements(stmts));
+        self.decorators_legacy.as_mut().map(|t| t.transform_statements(stmts));
+        self.decorators_stage3.as_mut().map(|t| t.transform_statements(stmts));
+        self.explicit_resource_management.as_mut().map(|t| t.transform_statements(stmts));
+        self.es2018_async_generator_functions.as_mut().map(|t| t.transform_statements(stmts));
+        self.es2015_block_scoping.as_mut().map(|t| t.transform_statements(stmts));
+        self.run_plugins(false, |p, ctx| p.enter_statements(stmts, ctx));
+
+        let mut i = 0;
+        while i < stmts.len() {
+            self.visit_statement(&mut (**stmts)[i]);
+            if let Some(t) = self.es2022_class_properties.as_mut() {
+                let (before, after) = t.take_statements();
+                for stmt in after.into_iter().rev() {
+                    stmts.insert(i + 1, stmt);
+                }
+                for stmt in before.into_iter().rev() {
+                    stmts.insert(i, stmt);
+                    i += 1;
+                }
+            }
+            i += 1;
         }
-        // TODO: we need scope id to insert the vars into the correct statements
-        self.es2021_logical_assignment_operators.as_mut().map(|t| t.add_vars_to_statements(stmts));
-        self.es2020_nullish_coalescing_operators.as_mut().map(|t| t.add_vars_to_statements(stmts));
-        self.es2016_exponentiation_operator.as_mut().map(|t| t.add_vars_to_statements(stmts));
+        self.run_plugins(true, |p, ctx| p.exit_statements(stmts, ctx));
+        self.define.as_mut().map(|t| t.remove_dead_statements(stmts));
+        self.run_plugins(false, |p, ctx| p.exit_statements(stmts, ctx));
+    }
+
+    fn visit_statement(&mut self, stmt: &mut Statement<'a>) {
+        self.run_plugins(true, |p, ctx| p.enter_statement(stmt, ctx));
+        self.explicit_resource_management.as_mut().map(|t| t.transform_statement(stmt));
+        self.es2018_async_generator_functions.as_mut().map(|t| t.transform_statement(stmt));
+        self.es2015_destructuring.as_mut().map(|t| t.transform_statement(stmt));
+        self.es2015_block_scoping.as_mut().map(|t| t.transform_statement(stmt));
+        self.es2015_block_scoping.as_mut().map(|t| t.enter_statement(stmt));
+        self.run_plugins(false, |p, ctx| p.enter_statement(stmt, ctx));
+
+        self.visit_statement_match(stmt);
+
+        self.run_plugins(true, |p, ctx| p.exit_statement(stmt, ctx));
+        self.define.as_mut().map(|t| t.fold_statement(stmt));
+        self.es2015_block_scoping.as_mut().map(|t| t.leave_statement(stmt));
+        self.run_plugins(false, |p, ctx| p.exit_statement(stmt, ctx));
     }
 
     fn visit_expression(&mut self, expr: &mut Expression<'a>) {
-        // self.typescript.as_mut().map(|t| t.transform_expression(expr));
-        // self.react_jsx.as_mut().map(|t| t.transform_expression(expr));
+        self.run_plugins(true, |p, ctx| p.enter_expression(expr, ctx));
+        self.define.as_mut().map(|t| t.transform_expression(expr));
+        self.polyfills.as_mut().map(|t| t.transform_expression(expr));
+        self.modules_commonjs.as_mut().map(|t| t.transform_expression(expr));
+        self.typescript.as_mut().map(|t| t.transform_expression(expr));
+        // The `css` prop is wrapped before the element is transformed.
+        self.emotion.as_mut().map(|t| t.transform_expression(expr));
+        self.react_jsx.as_mut().map(|t| t.transform_expression(expr));
+        self.styled_components.as_mut().map(|t| t.transform_expression(expr));
+        self.regexp_features.as_mut().map(|t| t.transform_expression(expr));
         self.regexp_flags.as_mut().map(|t| t.transform_expression(expr));
 
-        self.es2021_logical_assignment_operators.as_mut().map(|t| t.transform_expression(expr));
-        self.es2020_nullish_coalescing_operators.as_mut().map(|t| t.transform_expression(expr));
-        self.es2016_exponentiation_operator.as_mut().map(|t| t.transform_expression(expr));
+        for pass in SYNTAX_PASSES {
+            self.transform_expression_with(pass, expr);
+        }
+        self.run_plugins(false, |p, ctx| p.enter_expression(expr, ctx));
 
         self.visit_expression_match(expr);
+
+        self.run_plugins(true, |p, ctx| p.exit_expression(expr, ctx));
+        self.define.as_mut().map(|t| t.fold_expression(expr));
+        self.es2015_regenerator.as_mut().map(|t| t.transform_expression(expr));
+        // Shorthand `__proto__` properties are made computed while visiting the object.
+        self.es2015_computed_properties.as_mut().map(|t| t.transform_expression(expr));
+        self.es2022_class_properties.as_mut().map(|t| t.transform_class_expression(expr));
+        self.run_plugins(false, |p, ctx| p.exit_expression(expr, ctx));
     }
 
     fn visit_catch_clause(&mut self, clause: &mut CatchClause<'a>) {
         self.es2019_optional_catch_binding.as_mut().map(|t| t.transform_catch_clause(clause));
+        self.es2015_destructuring.as_mut().map(|t| t.transform_catch_clause(clause));
 
         if let Some(param) = &mut clause.param {
             self.visit_binding_pattern(param);
@@ -127,6 +558,18 @@ impl<'a> VisitMut<'a> for Transformer<'a> {
         self.visit_statements(&mut clause.body.body);
     }
 
+    fn visit_variable_declaration(&mut self, decl: &mut VariableDeclaration<'a>) {
+        self.es2015_destructuring.as_mut().map(|t| t.transform_variable_declaration(decl));
+        self.es2015_block_scoping.as_mut().map(|t| t.transform_variable_declaration(decl));
+
+        for declarator in decl.declarations.iter_mut() {
+            self.typescript.as_mut().map(|t| t.transform_variable_declarator(declarator));
+            self.styled_components.as_mut().map(|t| t.transform_variable_declarator(declarator));
+            self.emotion.as_mut().map(|t| t.transform_variable_declarator(declarator));
+            self.visit_variable_declarator(declarator);
+        }
+    }
+
     fn visit_object_property(&mut self, prop: &mut ObjectProperty<'a>) {
         self.es2015_shorthand_properties.as_mut().map(|t| t.transform_object_property(prop));
 
@@ -135,23 +578,249 @@ impl<'a> VisitMut<'a> for Transformer<'a> {
         if let Some(init) = &mut prop.init {
             self.visit_expression(init);
         }
+
+        self.es2015_regenerator.as_ref().map(|t| t.transform_object_property(prop));
+    }
+
+    fn visit_assignment_target_property(&mut self, property: &mut AssignmentTargetProperty<'a>) {
+        self.es2015_block_scoping
+            .as_ref()
+            .map(|t| t.transform_assignment_target_property(property));
+
+        match property {
+            AssignmentTargetProperty::AssignmentTargetPropertyIdentifier(ident) => {
+                self.visit_assignment_target_property_identifier(ident);
+            }
+            AssignmentTargetProperty::AssignmentTargetPropertyProperty(prop) => {
+                self.visit_assignment_target_property_property(prop);
+            }
+        }
+    }
+
+    fn visit_simple_assignment_target(&mut self, target: &mut SimpleAssignmentTarget<'a>) {
+        self.typescript.as_mut().map(|t| t.transform_simple_assignment_target(target));
+
+        match target {
+            SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) => {
+                self.visit_identifier_reference(ident);
+            }
+            SimpleAssignmentTarget::MemberAssignmentTarget(expr) => {
+                self.visit_member_expression(expr);
+            }
+            SimpleAssignmentTarget::TSAsExpression(expr) => {
+                self.visit_expression(&mut expr.expression);
+            }
+            SimpleAssignmentTarget::TSSatisfiesExpression(expr) => {
+                self.visit_expression(&mut expr.expression);
+            }
+            SimpleAssignmentTarget::TSNonNullExpression(expr) => {
+                self.visit_expression(&mut expr.expression);
+            }
+            SimpleAssignmentTarget::TSTypeAssertion(expr) => {
+                self.visit_expression(&mut expr.expression);
+            }
+        }
+    }
+
+    fn visit_binding_pattern(&mut self, pat: &mut BindingPattern<'a>) {
+        self.typescript.as_mut().map(|t| t.transform_binding_pattern(pat));
+
+        match &mut pat.kind {
+            BindingPatternKind::BindingIdentifier(ident) => {
+                self.visit_binding_identifier(ident);
+            }
+            BindingPatternKind::ObjectPattern(pat) => self.visit_object_pattern(pat),
+            BindingPatternKind::ArrayPattern(pat) => self.visit_array_pattern(pat),
+            BindingPatternKind::AssignmentPattern(pat) => self.visit_assignment_pattern(pat),
+        }
     }
 
-    fn visit_class_body(&mut self, class_body: &mut ClassBody<'a>) {
-        self.es2022_class_static_block.as_mut().map(|t| t.transform_class_body(class_body));
+    fn visit_binding_identifier(&mut self, ident: &mut BindingIdentifier) {
+        self.es2015_block_scoping.as_ref().map(|t| t.transform_binding_identifier(ident));
+    }
 
-        class_body.body.iter_mut().for_each(|class_element| {
-            self.visit_class_element(class_element);
-        });
+    fn visit_identifier_reference(&mut self, ident: &mut IdentifierReference) {
+        self.es2015_block_scoping.as_ref().map(|t| t.transform_identifier_reference(ident));
+    }
+
+    fn visit_class(&mut self, class: &mut Class<'a>) {
+        self.typescript.as_mut().map(|t| t.transform_class(class));
+        self.decorators_stage3.as_mut().map(|t| t.transform_class(class));
+        // Static blocks are lowered to static private fields, which class properties may lower further.
+        self.es2022_class_static_block.as_mut().map(|t| t.transform_class_body(&mut class.body));
+        self.es2022_class_properties.as_mut().map(|t| t.enter_class(class));
+
+        for decorator in class.decorators.iter_mut() {
+            self.visit_decorator(decorator);
+        }
+        if let Some(id) = &mut class.id {
+            self.visit_binding_identifier(id);
+        }
+        if let Some(parameters) = &mut class.type_parameters {
+            self.visit_ts_type_parameter_declaration(parameters);
+        }
+        if let Some(super_class) = &mut class.super_class {
+            self.visit_class_heritage(super_class);
+        }
+        if let Some(super_parameters) = &mut class.super_type_parameters {
+            self.visit_ts_type_parameter_instantiation(super_parameters);
+        }
+        self.visit_class_body(&mut class.body);
+
+        self.es2022_class_properties.as_mut().map(|t| t.leave_class(class));
     }
 
     fn visit_formal_parameters(&mut self, params: &mut FormalParameters<'a>) {
         self.typescript.as_mut().map(|t| t.transform_formal_parameters(params));
+
+        let prev = self
+            .es2020_nullish_coalescing_operators
+            .as_mut()
+            .map(NullishCoalescingOperator::enter_formal_parameters);
+        let prev_optional_chaining =
+            self.es2020_optional_chaining.as_mut().map(OptionalChaining::enter_formal_parameters);
         for param in params.items.iter_mut() {
             self.visit_formal_parameter(param);
         }
         if let Some(rest) = &mut params.rest {
             self.visit_rest_element(rest);
         }
+        self.es2020_nullish_coalescing_operators
+            .as_mut()
+            .zip(prev)
+            .map(|(t, prev)| t.leave_formal_parameters(prev));
+        self.es2020_optional_chaining
+            .as_mut()
+            .zip(prev_optional_chaining)
+            .map(|(t, prev)| t.leave_formal_parameters(prev));
     }
+
+    fn visit_function_body(&mut self, body: &mut FunctionBody<'a>) {
+        let prev = self
+            .es2020_nullish_coalescing_operators
+            .as_mut()
+            .map(NullishCoalescingOperator::enter_function_body);
+        let prev_optional_chaining =
+            self.es2020_optional_chaining.as_mut().map(OptionalChaining::enter_function_body);
+        let vars: std::vec::Vec<_> =
+            self.create_vars().map(CreateVars::enter_function_scope).collect();
+        for directive in body.directives.iter_mut() {
+            self.visit_directive(directive);
+        }
+        self.visit_statements(&mut body.statements);
+        self.declare_vars(&mut body.statements);
+        for (t, vars) in self.create_vars().zip(vars) {
+            t.leave_function_scope(vars);
+        }
+        self.es2020_nullish_coalescing_operators
+            .as_mut()
+            .zip(prev)
+            .map(|(t, prev)| t.leave_function_body(prev));
+        self.es2020_optional_chaining
+            .as_mut()
+            .zip(prev_optional_chaining)
+            .map(|(t, prev)| t.leave_function_body(prev));
+    }
+
+    fn visit_function(&mut self, func: &mut Function<'a>) {
+        self.typescript.as_mut().map(|t| t.transform_function(func));
+        self.es2018_async_generator_functions.as_mut().map(|t| t.enter_function(func));
+        self.es2015_destructuring.as_mut().map(|t| t.transform_function(func));
+        let prev = self.es2015_block_scoping.as_mut().map(BlockScoping::enter_function);
+
+        if let Some(ident) = &mut func.id {
+            self.visit_binding_identifier(ident);
+        }
+        self.visit_formal_parameters(&mut func.params);
+        if let Some(body) = &mut func.body {
+            self.visit_function_body(body);
+        }
+        if let Some(parameters) = &mut func.type_parameters {
+            self.visit_ts_type_parameter_declaration(parameters);
+        }
+        if let Some(annotation) = &mut func.return_type {
+            self.visit_ts_type_annotation(annotation);
+        }
+
+        self.es2015_block_scoping.as_mut().zip(prev).map(|(t, prev)| t.leave_function(prev));
+        self.es2018_async_generator_functions.as_mut().map(|t| t.leave_function(func));
+        // Async generators are lowered to generators first.
+        self.es2015_regenerator.as_mut().map(|t| t.leave_function(func));
+    }
+
+    fn visit_arrow_expression(&mut self, expr: &mut ArrowExpression<'a>) {
+        self.typescript.as_mut().map(|t| t.transform_arrow_expression(expr));
+        self.es2018_async_generator_functions
+            .as_mut()
+            .map(AsyncGeneratorFunctions::enter_arrow_expression);
+        self.es2015_destructuring.as_mut().map(|t| t.transform_arrow_expression(expr));
+        let prev = self.es2015_block_scoping.as_mut().map(BlockScoping::enter_function);
+        self.visit_formal_parameters(&mut expr.params);
+        self.visit_function_body(&mut expr.body);
+        if let Some(parameters) = &mut expr.type_parameters {
+            self.visit_ts_type_parameter_declaration(parameters);
+        }
+        self.es2015_block_scoping.as_mut().zip(prev).map(|(t, prev)| t.leave_function(prev));
+        self.es2018_async_generator_functions
+            .as_mut()
+            .map(AsyncGeneratorFunctions::leave_arrow_expression);
+
+        // `() => expr` can no longer be an expression body after variables are injected into it.
+        if expr.expression && expr.body.statements.len() > 1 {
+            expr.expression = false;
+            if let Some(Statement::ExpressionStatement(stmt)) = expr.body.statements.last_mut() {
+                let argument = self.ast.move_expression(&mut stmt.expression);
+                *expr.body.statements.last_mut().unwrap() =
+                    self.ast.return_statement(Span::default(), Some(argument));
+            }
+        }
+    }
+}
+
+#[test]
+fn test() {
+    let options = TransformOptions {
+        target: TransformTarget::ES2015,
+        source_file_name: Some("test.js".into()),
+        ..TransformOptions::default()
+    };
+    let ret = transform("// square\nx = y ** 2;\n", SourceType::default(), options);
+    assert!(ret.errors.is_empty());
+    assert_eq!(ret.code, "// square\nx = Math.pow(y, 2);\n");
+    assert_eq!(ret.map.map(|map| map.mappings), Some(";AACA,IAAI,SAAA,GAAK".into()));
+
+    let ret = transform("x = ;", SourceType::default(), TransformOptions::default());
+    assert_eq!((ret.code.as_str(), ret.errors.len()), ("", 1));
+}
+
+#[test]
+fn syntax_passes() {
+    let edition = |pass| match pass {
+        SyntaxPass::ClassProperties => TransformTarget::ES2022,
+        SyntaxPass::LogicalAssignmentOperators | SyntaxPass::NumericSeparator => {
+            TransformTarget::ES2021
+        }
+        SyntaxPass::NullishCoalescingOperator
+        | SyntaxPass::OptionalChaining
+        | SyntaxPass::DynamicImport
+        | SyntaxPass::BigIntLiterals => TransformTarget::ES2020,
+        SyntaxPass::AsyncGeneratorFunctions => TransformTarget::ES2018,
+        SyntaxPass::ExponentiationOperator => TransformTarget::ES2016,
+        SyntaxPass::Destructuring | SyntaxPass::Literals | SyntaxPass::Spread => {
+            TransformTarget::ES2015
+        }
+    };
+    let (last, passes) = SYNTAX_PASSES.split_last().unwrap();
+    assert_eq!(*last, SyntaxPass::ClassProperties);
+    assert!(passes.windows(2).all(|pair| edition(pair[0]) >= edition(pair[1])));
+
+    // The logical assignment is lowered first, then the `??` and the `?.` of its value.
+    let options =
+        TransformOptions { target: TransformTarget::ES2019, ..TransformOptions::default() };
+    let ret = transform("x.y ||= a?.b.c ?? 1_000;\n", SourceType::default(), options);
+    assert!(ret.errors.is_empty());
+    assert_eq!(
+        ret.code,
+        "var _a;\nvar _ref;\nvar _x;\n(_x = x).y || (_x.y = (_ref = (_a = a) === null || _a === void 0 ? void 0 : _a.b.c) !== null && _ref !== void 0 ? _ref : 1000);\n"
+    );
 }
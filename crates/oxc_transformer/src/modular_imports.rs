@@ -0,0 +1,199 @@
+use std::rc::Rc;
+
+use oxc_allocator::Vec;
+use oxc_ast::{ast::*, AstBuilder};
+use oxc_span::{Atom, Span};
+use serde::Deserialize;
+
+use crate::options::TransformOptions;
+
+/// The imports of a package rewritten by [ModularImports].
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModularImportOptions {
+    /// The package, e.g. `lodash`.
+    pub source: String,
+    /// The source of each member, e.g. `lodash/{{member}}`.
+    ///
+    /// `{{member}}` is replaced with the imported name, `{{kebabCase member}}`,
+    /// `{{snakeCase member}}` and `{{camelCase member}}` with the name in that case.
+    pub transform: String,
+    /// Import the members by name instead of as the default export of their module.
+    #[serde(default)]
+    pub skip_default_conversion: bool,
+}
+
+/// Imports the members of a package from their own modules, so the bundle doesn't include the
+/// whole package when it can't be tree shaken.
+///
+/// * `import { debounce, map as m } from "lodash"` ->
+///   `import debounce from "lodash/debounce"; import m from "lodash/map";`
+///
+/// The default and namespace imports of the package are kept.
+///
+/// References:
+/// * <https://www.npmjs.com/package/babel-plugin-transform-imports>
+/// * <https://swc.rs/docs/configuration/compilation#jsctransformmodularizeimports>
+pub struct ModularImports<'a> {
+    ast: Rc<AstBuilder<'a>>,
+    imports: std::vec::Vec<ModularImportOptions>,
+}
+
+impl<'a> ModularImports<'a> {
+    pub fn new(ast: Rc<AstBuilder<'a>>, options: &TransformOptions) -> Option<Self> {
+        (!options.modular_imports.is_empty())
+            .then(|| Self { ast, imports: options.modular_imports.clone() })
+    }
+
+    pub fn transform_statements(&self, stmts: &mut Vec<'a, Statement<'a>>) {
+        let mut new_stmts = self.ast.new_vec_with_capacity(stmts.len());
+        for mut stmt in stmts.drain(..) {
+            let Statement::ModuleDeclaration(module_decl) = &mut stmt else {
+                new_stmts.push(stmt);
+                continue;
+            };
+            let ModuleDeclaration::ImportDeclaration(decl) = &mut **module_decl else {
+                new_stmts.push(stmt);
+                continue;
+            };
+            let Some(options) =
+                self.imports.iter().find(|o| o.source == decl.source.value.as_str())
+            else {
+                new_stmts.push(stmt);
+                continue;
+            };
+
+            let specifiers = std::mem::replace(&mut decl.specifiers, self.ast.new_vec());
+            let mut members = vec![];
+            for specifier in specifiers {
+                match specifier {
+                    ImportDeclarationSpecifier::ImportSpecifier(specifier)
+                        if specifier.imported.name().as_str() != "default" =>
+                    {
+                        members.push(specifier);
+                    }
+                    _ => decl.specifiers.push(specifier),
+                }
+            }
+            let import_kind = decl.import_kind;
+            // `import "lodash"` and the declarations left with a default or namespace import.
+            if members.is_empty() || !decl.specifiers.is_empty() {
+                new_stmts.push(stmt);
+            }
+            for specifier in members {
+                new_stmts.push(self.member_import(options, specifier, import_kind));
+            }
+        }
+        *stmts = new_stmts;
+    }
+
+    /// `import local from "source/member"`
+    fn member_import(
+        &self,
+        options: &ModularImportOptions,
+        specifier: ImportSpecifier,
+        import_kind: ImportOrExportKind,
+    ) -> Statement<'a> {
+        let span = specifier.span;
+        let source: Atom = expand_template(&options.transform, specifier.imported.name()).into();
+        let specifier = if options.skip_default_conversion {
+            ImportDeclarationSpecifier::ImportSpecifier(specifier)
+        } else {
+            ImportDeclarationSpecifier::ImportDefaultSpecifier(ImportDefaultSpecifier {
+                span,
+                local: specifier.local,
+            })
+        };
+        let decl = self.ast.import_declaration(
+            span,
+            self.ast.new_vec_single(specifier),
+            StringLiteral::new(Span::default(), source),
+            None,
+            import_kind,
+        );
+        self.ast.module_declaration(ModuleDeclaration::ImportDeclaration(decl))
+    }
+}
+
+/// Replace the `{{member}}` placeholders of the template.
+fn expand_template(template: &str, member: &str) -> String {
+    let mut result = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find("{{") {
+        let Some(end) = rest[start..].find("}}").map(|end| start + end) else { break };
+        let expanded = match rest[start + 2..end].split_whitespace().collect::<std::vec::Vec<_>>()[..]
+        {
+            ["member"] => member.to_string(),
+            ["kebabCase", "member"] => separate_words(member, '-'),
+            ["snakeCase", "member"] => separate_words(member, '_'),
+            ["camelCase", "member"] => {
+                let mut chars = member.chars();
+                chars.next().map_or_else(String::new, |first| {
+                    first.to_lowercase().chain(chars).collect::<String>()
+                })
+            }
+            _ => rest[start..end + 2].to_string(),
+        };
+        result.push_str(&rest[..start]);
+        result.push_str(&expanded);
+        rest = &rest[end + 2..];
+    }
+    result.push_str(rest);
+    result
+}
+
+/// `camelCase` -> `camel-case` with `-` as the separator.
+fn separate_words(name: &str, separator: char) -> String {
+    let mut result = String::with_capacity(name.len() + 4);
+    for (i, c) in name.chars().enumerate() {
+        if c.is_ascii_uppercase() {
+            if i > 0 {
+                result.push(separator);
+            }
+            result.push(c.to_ascii_lowercase());
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
+
+#[test]
+#[rustfmt::skip]
+fn test() {
+    use crate::tester::Tester;
+
+    let options = TransformOptions {
+        modular_imports: vec![
+            ModularImportOptions {
+                source: "lodash".into(),
+                transform: "lodash/{{member}}".into(),
+                skip_default_conversion: false,
+            },
+            ModularImportOptions {
+                source: "my-icons".into(),
+                transform: "my-icons/esm/{{kebabCase member}}".into(),
+                skip_default_conversion: true,
+            },
+        ],
+        ..TransformOptions::default()
+    };
+    let tests = &[
+        (
+            "import { debounce, map as m } from 'lodash';",
+            "import debounce from 'lodash/debounce'; import m from 'lodash/map';",
+        ),
+        (
+            "import _, { debounce } from 'lodash';",
+            "import _ from 'lodash'; import debounce from 'lodash/debounce';",
+        ),
+        ("import * as _ from 'lodash';", "import * as _ from 'lodash';"),
+        ("import 'lodash';", "import 'lodash';"),
+        (
+            "import { ArrowLeft } from 'my-icons';",
+            "import { ArrowLeft } from 'my-icons/esm/arrow-left';",
+        ),
+        ("import { debounce } from 'lodash-es';", "import { debounce } from 'lodash-es';"),
+    ];
+    Tester::new("test.mjs", options).test(tests);
+}
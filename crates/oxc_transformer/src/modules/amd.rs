@@ -0,0 +1,187 @@
+use oxc_allocator::Vec;
+use oxc_ast::{ast::*, AstBuilder};
+use oxc_span::{Atom, Span};
+use oxc_syntax::operator::{BinaryOperator, LogicalOperator, UnaryOperator};
+
+/// Wraps a CommonJS module body into an AMD or UMD module, like TypeScript does.
+///
+/// The dependencies are listed in `define`, so the `require` calls of the body return the
+/// loaded modules synchronously.
+///
+/// References:
+/// * <https://github.com/amdjs/amdjs-api/blob/master/AMD.md#simplified-commonjs-wrapping->
+/// * <https://github.com/umdjs/umd/blob/master/templates/commonjsStrict.js>
+pub struct ModuleWrapper<'a, 'b> {
+    pub ast: &'b AstBuilder<'a>,
+    pub dependencies: &'b [Atom],
+}
+
+impl<'a, 'b> ModuleWrapper<'a, 'b> {
+    /// `define(["require", "exports", "foo"], function (require, exports) { body })`
+    pub fn amd(
+        &self,
+        directives: Vec<'a, Directive>,
+        body: Vec<'a, Statement<'a>>,
+    ) -> Statement<'a> {
+        let factory = self.factory(directives, body);
+        let call = self.define(factory);
+        self.ast.expression_statement(Span::default(), call)
+    }
+
+    /// ```js
+    /// (function (factory) {
+    ///     if (typeof module === "object" && typeof module.exports === "object") {
+    ///         factory(require, exports);
+    ///     } else if (typeof define === "function" && define.amd) {
+    ///         define(["require", "exports", "foo"], factory);
+    ///     }
+    /// })(function (require, exports) { body });
+    /// ```
+    pub fn umd(
+        &self,
+        directives: Vec<'a, Directive>,
+        body: Vec<'a, Statement<'a>>,
+    ) -> Statement<'a> {
+        let span = Span::default();
+
+        // typeof module === "object" && typeof module.exports === "object"
+        let module = self.identifier("module");
+        let module_exports = self.ast.static_member_expression(
+            span,
+            module,
+            IdentifierName::new(span, "exports".into()),
+            false,
+        );
+        let is_commonjs = self.ast.logical_expression(
+            span,
+            self.is_typeof(self.identifier("module"), "object"),
+            LogicalOperator::And,
+            self.is_typeof(module_exports, "object"),
+        );
+        // factory(require, exports);
+        let mut arguments = self.ast.new_vec_with_capacity(2);
+        arguments.push(Argument::Expression(self.identifier("require")));
+        arguments.push(Argument::Expression(self.identifier("exports")));
+        let call =
+            self.ast.call_expression(span, self.identifier("factory"), arguments, false, None);
+        let commonjs = self.block(self.ast.expression_statement(span, call));
+
+        // typeof define === "function" && define.amd
+        let define_amd = self.ast.static_member_expression(
+            span,
+            self.identifier("define"),
+            IdentifierName::new(span, "amd".into()),
+            false,
+        );
+        let is_amd = self.ast.logical_expression(
+            span,
+            self.is_typeof(self.identifier("define"), "function"),
+            LogicalOperator::And,
+            define_amd,
+        );
+        // define([...], factory);
+        let define = self.define(self.identifier("factory"));
+        let amd = self.block(self.ast.expression_statement(span, define));
+
+        let amd = self.ast.if_statement(span, is_amd, amd, None);
+        let detect = self.ast.if_statement(span, is_commonjs, commonjs, Some(amd));
+
+        let param = self.ast.binding_pattern(
+            self.ast.binding_pattern_identifier(BindingIdentifier::new(span, "factory".into())),
+            None,
+            false,
+        );
+        let param = self.ast.formal_parameter(span, param, None, false, self.ast.new_vec());
+        let wrapper = self.function(
+            self.ast.new_vec_single(param),
+            self.ast.new_vec(),
+            self.ast.new_vec_single(detect),
+        );
+        let wrapper = self.ast.parenthesized_expression(span, wrapper);
+        let factory = self.factory(directives, body);
+        let arguments = self.ast.new_vec_single(Argument::Expression(factory));
+        let call = self.ast.call_expression(span, wrapper, arguments, false, None);
+        self.ast.expression_statement(span, call)
+    }
+
+    /// `define(["require", "exports", "foo"], factory)`
+    fn define(&self, factory: Expression<'a>) -> Expression<'a> {
+        let span = Span::default();
+        let mut elements = self.ast.new_vec_with_capacity(self.dependencies.len() + 2);
+        for name in ["require", "exports"] {
+            elements.push(ArrayExpressionElement::Expression(self.string_literal(name.into())));
+        }
+        for name in self.dependencies {
+            elements.push(ArrayExpressionElement::Expression(self.string_literal(name.clone())));
+        }
+        let mut arguments = self.ast.new_vec_with_capacity(2);
+        arguments.push(Argument::Expression(self.ast.array_expression(span, elements, None)));
+        arguments.push(Argument::Expression(factory));
+        self.ast.call_expression(span, self.identifier("define"), arguments, false, None)
+    }
+
+    /// `function (require, exports) { body }`
+    fn factory(
+        &self,
+        directives: Vec<'a, Directive>,
+        body: Vec<'a, Statement<'a>>,
+    ) -> Expression<'a> {
+        let span = Span::default();
+        let mut params = self.ast.new_vec_with_capacity(2);
+        for name in ["require", "exports"] {
+            let binding = self.ast.binding_pattern(
+                self.ast.binding_pattern_identifier(BindingIdentifier::new(span, name.into())),
+                None,
+                false,
+            );
+            params.push(self.ast.formal_parameter(span, binding, None, false, self.ast.new_vec()));
+        }
+        self.function(params, directives, body)
+    }
+
+    fn function(
+        &self,
+        params: Vec<'a, FormalParameter<'a>>,
+        directives: Vec<'a, Directive>,
+        body: Vec<'a, Statement<'a>>,
+    ) -> Expression<'a> {
+        let span = Span::default();
+        let params =
+            self.ast.formal_parameters(span, FormalParameterKind::FormalParameter, params, None);
+        let function = self.ast.function(
+            FunctionType::FunctionExpression,
+            span,
+            None,
+            false,
+            false,
+            false,
+            params,
+            Some(self.ast.function_body(span, directives, body)),
+            None,
+            None,
+            Modifiers::empty(),
+        );
+        self.ast.function_expression(function)
+    }
+
+    /// `typeof value === "type"`
+    fn is_typeof(&self, value: Expression<'a>, r#type: &str) -> Expression<'a> {
+        let span = Span::default();
+        let value = self.ast.unary_expression(span, UnaryOperator::Typeof, value);
+        let r#type = self.string_literal(r#type.into());
+        self.ast.binary_expression(span, value, BinaryOperator::StrictEquality, r#type)
+    }
+
+    fn block(&self, stmt: Statement<'a>) -> Statement<'a> {
+        self.ast.block_statement(self.ast.block(Span::default(), self.ast.new_vec_single(stmt)))
+    }
+
+    fn identifier(&self, name: &str) -> Expression<'a> {
+        let ident = IdentifierReference::new(Span::default(), name.into());
+        self.ast.identifier_reference_expression(ident)
+    }
+
+    fn string_literal(&self, value: Atom) -> Expression<'a> {
+        self.ast.literal_string_expression(StringLiteral::new(Span::default(), value))
+    }
+}
@@ -0,0 +1,752 @@
+use std::{mem, rc::Rc};
+
+use oxc_allocator::Vec;
+use oxc_ast::{ast::*, syntax_directed_operations::BoundNames, AstBuilder};
+use oxc_semantic::SymbolId;
+use oxc_span::{Atom, SourceType, Span};
+use oxc_syntax::{operator::AssignmentOperator, NumberBase};
+use rustc_hash::FxHashMap;
+
+use crate::{
+    context::TransformerCtx,
+    modules::{amd::ModuleWrapper, ModuleFormat},
+    options::TransformOptions,
+};
+
+/// ES Modules to CommonJS
+///
+/// ```js
+/// import foo, { bar } from "foo";
+/// export const baz = foo(bar);
+/// ```
+///
+/// ```js
+/// "use strict";
+/// Object.defineProperty(exports, "__esModule", { value: true });
+/// Object.defineProperty(exports, "baz", { enumerable: true, get: function () { return baz; } });
+/// var _foo = babelHelpers.interopRequireWildcard(require("foo"));
+/// const baz = (0, _foo.default)(_foo.bar);
+/// ```
+///
+/// Imported bindings are rewritten into members of the required module and exports are
+/// getters, so both stay live. The interop with the CommonJS modules goes through the helpers
+/// `interopRequireDefault`, `interopRequireWildcard` and `exportStar`, which are loaded like the
+/// other helpers and added to the module body, inside the AMD and UMD factories.
+///
+/// AMD and UMD modules are this CommonJS module wrapped in a factory function.
+///
+/// References:
+/// * <https://www.typescriptlang.org/tsconfig#module>
+/// * <https://www.typescriptlang.org/tsconfig#esModuleInterop>
+/// * <https://babel.dev/docs/babel-plugin-transform-modules-commonjs>
+pub struct CommonJs<'a> {
+    ast: Rc<AstBuilder<'a>>,
+    ctx: TransformerCtx<'a>,
+    format: ModuleFormat,
+    /// The imported bindings and what they refer to, the namespace of the module or one of its
+    /// exports.
+    bindings: FxHashMap<SymbolId, (Atom, Option<Atom>)>,
+    /// The same by name, for references created by other transforms.
+    binding_names: FxHashMap<Atom, (Atom, Option<Atom>)>,
+    /// The namespace variable of each import declaration, keyed by the start of its source.
+    namespaces: FxHashMap<u32, Atom>,
+}
+
+/// The statements an ES module is turned into.
+struct Output<'a> {
+    getters: Vec<'a, Statement<'a>>,
+    requires: Vec<'a, Statement<'a>>,
+    body: Vec<'a, Statement<'a>>,
+}
+
+impl<'a> CommonJs<'a> {
+    pub fn new(
+        ast: Rc<AstBuilder<'a>>,
+        ctx: TransformerCtx<'a>,
+        source_type: SourceType,
+        options: &TransformOptions,
+    ) -> Option<Self> {
+        let format = options.module;
+        (format != ModuleFormat::Preserve && source_type.is_module()).then(|| Self {
+            ast,
+            ctx,
+            format,
+            bindings: FxHashMap::default(),
+            binding_names: FxHashMap::default(),
+            namespaces: FxHashMap::default(),
+        })
+    }
+
+    /// Record the bindings of the imports before their references are visited.
+    pub fn collect_imports(&mut self, stmts: &Vec<'a, Statement<'a>>) {
+        for stmt in stmts {
+            let Statement::ModuleDeclaration(decl) = stmt else { continue };
+            let ModuleDeclaration::ImportDeclaration(decl) = &**decl else { continue };
+            if decl.import_kind.is_type() || decl.specifiers.is_empty() {
+                continue;
+            }
+            let namespace = decl.specifiers.iter().find_map(|specifier| match specifier {
+                ImportDeclarationSpecifier::ImportNamespaceSpecifier(s) => {
+                    Some(s.local.name.clone())
+                }
+                _ => None,
+            });
+            let namespace = namespace.unwrap_or_else(|| self.generate_uid(&decl.source.value));
+            for specifier in &decl.specifiers {
+                let (local, imported) = match specifier {
+                    ImportDeclarationSpecifier::ImportSpecifier(s) => {
+                        if s.import_kind.is_type() {
+                            continue;
+                        }
+                        (&s.local, Some(Atom::from(s.imported.to_string())))
+                    }
+                    ImportDeclarationSpecifier::ImportDefaultSpecifier(s) => {
+                        (&s.local, Some(Atom::from("default")))
+                    }
+                    ImportDeclarationSpecifier::ImportNamespaceSpecifier(s) => (&s.local, None),
+                };
+                let target = (namespace.clone(), imported);
+                if let Some(symbol_id) = local.symbol_id.get() {
+                    self.bindings.insert(symbol_id, target.clone());
+                }
+                self.binding_names.insert(local.name.clone(), target);
+            }
+            self.namespaces.insert(decl.source.span.start, namespace);
+        }
+    }
+
+    pub fn transform_expression(&mut self, expr: &mut Expression<'a>) {
+        match expr {
+            Expression::Identifier(ident) => {
+                if let Some(target) = self.import_target(ident) {
+                    *expr = self.import_reference(target);
+                }
+            }
+            // `foo()` -> `(0, _foo.default)()`, imports are called without a `this`
+            Expression::CallExpression(call) => {
+                if let Some(callee) = self.unbound_callee(&call.callee) {
+                    call.callee = callee;
+                }
+            }
+            Expression::TaggedTemplateExpression(tagged) => {
+                if let Some(tag) = self.unbound_callee(&tagged.tag) {
+                    tagged.tag = tag;
+                }
+            }
+            // `{ foo }` -> `{ foo: _foo.default }`
+            Expression::ObjectExpression(object) => {
+                for property in object.properties.iter_mut() {
+                    let ObjectPropertyKind::ObjectProperty(property) = property else { continue };
+                    if let Expression::Identifier(ident) = &property.value {
+                        if property.shorthand && self.import_target(ident).is_some() {
+                            property.shorthand = false;
+                        }
+                    }
+                }
+            }
+            // `import.meta.url` -> `require("url").pathToFileURL(__filename).toString()`
+            Expression::MemberExpression(member) => {
+                let MemberExpression::StaticMemberExpression(member) = &**member else { return };
+                let Expression::MetaProperty(meta) = &member.object else { return };
+                if meta.meta.name == "import"
+                    && meta.property.name == "meta"
+                    && member.property.name == "url"
+                {
+                    *expr = self.import_meta_url();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Replace the import and export declarations with `require` calls and `exports`.
+    pub fn transform_program(&mut self, program: &mut Program<'a>) {
+        if !program.directives.iter().any(|d| d.directive == "use strict") {
+            let span = Span::default();
+            let directive = StringLiteral::new(span, "use strict".into());
+            program.directives.insert(0, self.ast.directive(span, directive, "use strict".into()));
+        }
+
+        let mut output = Output {
+            getters: self.ast.new_vec(),
+            requires: self.ast.new_vec(),
+            body: self.ast.new_vec(),
+        };
+        let mut dependencies = vec![];
+        let mut has_exports = false;
+        for stmt in mem::replace(&mut program.body, self.ast.new_vec()) {
+            match stmt {
+                Statement::ModuleDeclaration(decl) => {
+                    has_exports |= Self::is_value_export(&decl);
+                    if let Some(source) = Self::dependency(&decl) {
+                        if !dependencies.contains(source) {
+                            dependencies.push(source.clone());
+                        }
+                    }
+                    self.transform_module_declaration(decl.unbox(), &mut output);
+                }
+                stmt => output.body.push(stmt),
+            }
+        }
+
+        let Output { getters, requires, body } = output;
+        // Whatever is exported, e.g. only `export default 1` or `export * from "foo"`, so
+        // `interopRequireDefault` reads the `default` export of the module.
+        if has_exports {
+            program.body.push(self.es_module_marker());
+        }
+        program.body.extend(getters);
+        program.body.extend(requires);
+        program.body.extend(body);
+        // Before the requires which call the interop helpers
+        self.ctx.add_helper_imports(&mut program.body);
+        for module in self.ctx.helpers.borrow().modules() {
+            if !dependencies.contains(&module) {
+                dependencies.push(module);
+            }
+        }
+
+        let wrapper = ModuleWrapper { ast: &self.ast, dependencies: &dependencies };
+        let wrap = match self.format {
+            ModuleFormat::Amd => ModuleWrapper::amd,
+            ModuleFormat::Umd => ModuleWrapper::umd,
+            ModuleFormat::Preserve | ModuleFormat::CommonJs => return,
+        };
+        let directives = mem::replace(&mut program.directives, self.ast.new_vec());
+        let body = mem::replace(&mut program.body, self.ast.new_vec());
+        program.body.push(wrap(&wrapper, directives, body));
+    }
+
+    /// An ES export of a value, the module is then marked with `__esModule`.
+    fn is_value_export(decl: &ModuleDeclaration<'a>) -> bool {
+        match decl {
+            ModuleDeclaration::ExportNamedDeclaration(decl) => !decl.is_typescript_syntax(),
+            ModuleDeclaration::ExportAllDeclaration(decl) => !decl.is_typescript_syntax(),
+            ModuleDeclaration::ExportDefaultDeclaration(decl) => !decl.is_typescript_syntax(),
+            _ => false,
+        }
+    }
+
+    /// The module requested by an import or re-export.
+    fn dependency<'d>(decl: &'d ModuleDeclaration<'a>) -> Option<&'d Atom> {
+        let source = match decl {
+            ModuleDeclaration::ImportDeclaration(decl) if !decl.import_kind.is_type() => {
+                &decl.source
+            }
+            ModuleDeclaration::ExportNamedDeclaration(decl) if !decl.export_kind.is_type() => {
+                decl.source.as_ref()?
+            }
+            ModuleDeclaration::ExportAllDeclaration(decl) if !decl.export_kind.is_type() => {
+                &decl.source
+            }
+            _ => return None,
+        };
+        Some(&source.value)
+    }
+
+    fn transform_module_declaration(
+        &mut self,
+        decl: ModuleDeclaration<'a>,
+        output: &mut Output<'a>,
+    ) {
+        match decl {
+            ModuleDeclaration::ImportDeclaration(decl) => {
+                let decl = decl.unbox();
+                if decl.import_kind.is_type() {
+                    return;
+                }
+                output.requires.extend(self.import_declaration(decl));
+            }
+            ModuleDeclaration::ExportNamedDeclaration(decl) => {
+                let decl = decl.unbox();
+                if decl.export_kind.is_type() {
+                    return;
+                }
+                if let Some(declaration) = decl.declaration {
+                    declaration.bound_names(&mut |ident| {
+                        let value = self.identifier(ident.name.clone());
+                        output.getters.push(self.export_getter(&ident.name, value));
+                    });
+                    output.body.push(Statement::Declaration(declaration));
+                    return;
+                }
+                let namespace = decl.source.map(|source| {
+                    let namespace = self.generate_uid(&source.value);
+                    let init = self.require(source);
+                    output.requires.push(self.var_declaration(namespace.clone(), init));
+                    namespace
+                });
+                for specifier in decl.specifiers {
+                    if specifier.export_kind.is_type() {
+                        continue;
+                    }
+                    let local = Atom::from(specifier.local.to_string());
+                    let value = match &namespace {
+                        Some(namespace) => self.member(self.identifier(namespace.clone()), &local),
+                        None => self.local_reference(local),
+                    };
+                    let exported = Atom::from(specifier.exported.to_string());
+                    output.getters.push(self.export_getter(&exported, value));
+                }
+            }
+            ModuleDeclaration::ExportAllDeclaration(decl) => {
+                let decl = decl.unbox();
+                if decl.export_kind.is_type() {
+                    return;
+                }
+                let require = self.require(decl.source.clone());
+                if let Some(exported) = decl.exported {
+                    // `export * as ns from "foo"`
+                    let namespace = self.generate_uid(&decl.source.value);
+                    let init = self.interop_call("interopRequireWildcard", require);
+                    output.requires.push(self.var_declaration(namespace.clone(), init));
+                    let exported = Atom::from(exported.to_string());
+                    let value = self.identifier(namespace);
+                    output.getters.push(self.export_getter(&exported, value));
+                } else {
+                    // `babelHelpers.exportStar(require("foo"), exports)`
+                    let mut arguments = self.ast.new_vec_with_capacity(2);
+                    arguments.push(Argument::Expression(require));
+                    arguments.push(Argument::Expression(self.identifier("exports".into())));
+                    let call = self.ctx.helper_call("exportStar", arguments);
+                    output.requires.push(self.ast.expression_statement(Span::default(), call));
+                }
+            }
+            ModuleDeclaration::ExportDefaultDeclaration(decl) => {
+                let decl = decl.unbox();
+                let span = decl.span;
+                let declaration = match decl.declaration {
+                    ExportDefaultDeclarationKind::Expression(expr) => {
+                        // `exports.default = expr`
+                        let exports = self.identifier("exports".into());
+                        let target = self.member(exports, "default");
+                        let Expression::MemberExpression(target) = target else { unreachable!() };
+                        let target =
+                            self.ast.simple_assignment_target_member_expression(target.unbox());
+                        let assignment = self.ast.assignment_expression(
+                            span,
+                            AssignmentOperator::Assign,
+                            AssignmentTarget::SimpleAssignmentTarget(target),
+                            expr,
+                        );
+                        output.body.push(self.ast.expression_statement(span, assignment));
+                        return;
+                    }
+                    ExportDefaultDeclarationKind::FunctionDeclaration(mut func) => {
+                        func.r#type = FunctionType::FunctionDeclaration;
+                        let id = func.id.get_or_insert_with(|| self.default_binding());
+                        let value = self.identifier(id.name.clone());
+                        output.getters.push(self.export_getter(&"default".into(), value));
+                        Declaration::FunctionDeclaration(func)
+                    }
+                    ExportDefaultDeclarationKind::ClassDeclaration(mut class) => {
+                        class.r#type = ClassType::ClassDeclaration;
+                        let id = class.id.get_or_insert_with(|| self.default_binding());
+                        let value = self.identifier(id.name.clone());
+                        output.getters.push(self.export_getter(&"default".into(), value));
+                        Declaration::ClassDeclaration(class)
+                    }
+                    ExportDefaultDeclarationKind::TSInterfaceDeclaration(_)
+                    | ExportDefaultDeclarationKind::TSEnumDeclaration(_) => return,
+                };
+                output.body.push(Statement::Declaration(declaration));
+            }
+            // `export = foo` -> `module.exports = foo`
+            ModuleDeclaration::TSExportAssignment(decl) => {
+                let decl = decl.unbox();
+                let module = self.identifier("module".into());
+                let target = self.member(module, "exports");
+                let Expression::MemberExpression(target) = target else { unreachable!() };
+                let target = self.ast.simple_assignment_target_member_expression(target.unbox());
+                let assignment = self.ast.assignment_expression(
+                    decl.span,
+                    AssignmentOperator::Assign,
+                    AssignmentTarget::SimpleAssignmentTarget(target),
+                    decl.expression,
+                );
+                output.body.push(self.ast.expression_statement(decl.span, assignment));
+            }
+            ModuleDeclaration::TSNamespaceExportDeclaration(_) => {}
+        }
+    }
+
+    /// * `import "foo"` -> `require("foo")`
+    /// * `import foo from "foo"` ->
+    ///   `var _foo = babelHelpers.interopRequireDefault(require("foo"))`
+    /// * `import * as foo from "foo"` ->
+    ///   `var foo = babelHelpers.interopRequireWildcard(require("foo"))`
+    /// * `import { foo } from "foo"` -> `var _foo = require("foo")`
+    fn import_declaration(&mut self, decl: ImportDeclaration<'a>) -> Option<Statement<'a>> {
+        let source_start = decl.source.span.start;
+        let require = self.require(decl.source);
+        if decl.specifiers.is_empty() {
+            return Some(self.ast.expression_statement(Span::default(), require));
+        }
+        let Some(namespace) = self.namespaces.get(&source_start).cloned() else {
+            return Some(self.unrecorded_import_declaration(decl.specifiers, require));
+        };
+        let has_namespace = decl
+            .specifiers
+            .iter()
+            .any(|s| matches!(s, ImportDeclarationSpecifier::ImportNamespaceSpecifier(_)));
+        let has_default = decl
+            .specifiers
+            .iter()
+            .any(|s| matches!(s, ImportDeclarationSpecifier::ImportDefaultSpecifier(_)));
+        let has_named = decl
+            .specifiers
+            .iter()
+            .any(|s| matches!(s, ImportDeclarationSpecifier::ImportSpecifier(s) if !s.import_kind.is_type()));
+        let init = if has_namespace || (has_default && has_named) {
+            self.interop_call("interopRequireWildcard", require)
+        } else if has_default {
+            self.interop_call("interopRequireDefault", require)
+        } else if has_named {
+            require
+        } else {
+            return None;
+        };
+        Some(self.var_declaration(namespace, init))
+    }
+
+    /// The imports added by other transforms, such as the JSX runtime, which have no references
+    /// to rewrite: `var _jsx = require("react/jsx-runtime").jsx`.
+    fn unrecorded_import_declaration(
+        &self,
+        specifiers: Vec<'a, ImportDeclarationSpecifier>,
+        require: Expression<'a>,
+    ) -> Statement<'a> {
+        let mut declarations = self.ast.new_vec();
+        let mut require = Some(require);
+        let namespace = (specifiers.len() > 1).then(|| {
+            let namespace = self.generate_uid("require");
+            declarations.push(self.var_declarator(namespace.clone(), require.take().unwrap()));
+            namespace
+        });
+        for specifier in specifiers {
+            let module = namespace.as_ref().map_or_else(
+                || require.take().unwrap(),
+                |namespace| self.identifier(namespace.clone()),
+            );
+            let (local, init) = match specifier {
+                ImportDeclarationSpecifier::ImportSpecifier(s) => {
+                    (s.local.name, self.member(module, &s.imported.to_string()))
+                }
+                ImportDeclarationSpecifier::ImportDefaultSpecifier(s) => {
+                    let module = self.interop_call("interopRequireDefault", module);
+                    (s.local.name, self.member(module, "default"))
+                }
+                ImportDeclarationSpecifier::ImportNamespaceSpecifier(s) => {
+                    (s.local.name, self.interop_call("interopRequireWildcard", module))
+                }
+            };
+            declarations.push(self.var_declarator(local, init));
+        }
+        let kind = VariableDeclarationKind::Var;
+        let decl =
+            self.ast.variable_declaration(Span::default(), kind, declarations, Modifiers::empty());
+        Statement::Declaration(Declaration::VariableDeclaration(decl))
+    }
+
+    fn import_target(&self, ident: &IdentifierReference) -> Option<(Atom, Option<Atom>)> {
+        match ident.reference_id.get() {
+            Some(reference_id) => {
+                let symbol_id = self.ctx.symbols().get_reference(reference_id).symbol_id()?;
+                self.bindings.get(&symbol_id).cloned()
+            }
+            None => self.binding_names.get(&ident.name).cloned(),
+        }
+    }
+
+    /// `_foo` or `_foo.bar`
+    fn import_reference(&self, (namespace, imported): (Atom, Option<Atom>)) -> Expression<'a> {
+        let namespace = self.identifier(namespace);
+        match imported {
+            Some(imported) => self.member(namespace, &imported),
+            None => namespace,
+        }
+    }
+
+    /// `(0, _foo.bar)` for a callee which is an imported binding.
+    fn unbound_callee(&self, callee: &Expression<'a>) -> Option<Expression<'a>> {
+        let Expression::Identifier(ident) = callee else { return None };
+        let target = self.import_target(ident)?;
+        target.1.as_ref()?;
+        let span = Span::default();
+        let zero = self.ast.number_literal(span, 0.0, "0", NumberBase::Decimal);
+        let mut expressions = self.ast.new_vec_with_capacity(2);
+        expressions.push(self.ast.literal_number_expression(zero));
+        expressions.push(self.import_reference(target));
+        let sequence = self.ast.sequence_expression(span, expressions);
+        Some(self.ast.parenthesized_expression(span, sequence))
+    }
+
+    /// A local binding, or what it refers to if it is imported.
+    fn local_reference(&self, name: Atom) -> Expression<'a> {
+        let symbol_id = self.ctx.scopes().get_root_binding(&name);
+        let target = symbol_id.map_or_else(
+            || self.binding_names.get(&name).cloned(),
+            |symbol_id| self.bindings.get(&symbol_id).cloned(),
+        );
+        target.map_or_else(|| self.identifier(name), |target| self.import_reference(target))
+    }
+
+    /// `Object.defineProperty(exports, "name", { enumerable: true, get: function () { return value; } })`
+    fn export_getter(&self, name: &Atom, value: Expression<'a>) -> Statement<'a> {
+        let span = Span::default();
+        let body = self.ast.new_vec_single(self.ast.return_statement(span, Some(value)));
+        let params = self.ast.formal_parameters(
+            span,
+            FormalParameterKind::FormalParameter,
+            self.ast.new_vec(),
+            None,
+        );
+        let getter = self.ast.function(
+            FunctionType::FunctionExpression,
+            span,
+            None,
+            false,
+            false,
+            false,
+            params,
+            Some(self.ast.function_body(span, self.ast.new_vec(), body)),
+            None,
+            None,
+            Modifiers::empty(),
+        );
+        let mut properties = self.ast.new_vec_with_capacity(2);
+        let enumerable = self.ast.literal_boolean_expression(self.ast.boolean_literal(span, true));
+        properties.push(self.property("enumerable", enumerable));
+        properties.push(self.property("get", self.ast.function_expression(getter)));
+        let descriptor = self.ast.object_expression(span, properties, None);
+        self.define_property(name, descriptor)
+    }
+
+    /// `Object.defineProperty(exports, "__esModule", { value: true })`
+    fn es_module_marker(&self) -> Statement<'a> {
+        let span = Span::default();
+        let value = self.ast.literal_boolean_expression(self.ast.boolean_literal(span, true));
+        let properties = self.ast.new_vec_single(self.property("value", value));
+        let descriptor = self.ast.object_expression(span, properties, None);
+        self.define_property(&"__esModule".into(), descriptor)
+    }
+
+    fn define_property(&self, name: &Atom, descriptor: Expression<'a>) -> Statement<'a> {
+        let span = Span::default();
+        let callee = self.member(self.identifier("Object".into()), "defineProperty");
+        let mut arguments = self.ast.new_vec_with_capacity(3);
+        arguments.push(Argument::Expression(self.identifier("exports".into())));
+        arguments.push(Argument::Expression(self.string_literal(name.clone())));
+        arguments.push(Argument::Expression(descriptor));
+        let call = self.ast.call_expression(span, callee, arguments, false, None);
+        self.ast.expression_statement(span, call)
+    }
+
+    fn property(&self, name: &str, value: Expression<'a>) -> ObjectPropertyKind<'a> {
+        let span = Span::default();
+        let key = PropertyKey::Identifier(self.ast.alloc(IdentifierName::new(span, name.into())));
+        ObjectPropertyKind::ObjectProperty(self.ast.object_property(
+            span,
+            PropertyKind::Init,
+            key,
+            value,
+            None,
+            false,
+            false,
+            false,
+        ))
+    }
+
+    /// `require("url").pathToFileURL(__filename).toString()`
+    fn import_meta_url(&self) -> Expression<'a> {
+        let span = Span::default();
+        let url = self.require(StringLiteral::new(span, "url".into()));
+        let callee = self.member(url, "pathToFileURL");
+        let arguments =
+            self.ast.new_vec_single(Argument::Expression(self.identifier("__filename".into())));
+        let file_url = self.ast.call_expression(span, callee, arguments, false, None);
+        let callee = self.member(file_url, "toString");
+        self.ast.call_expression(span, callee, self.ast.new_vec(), false, None)
+    }
+
+    /// `require("foo")`
+    fn require(&self, source: StringLiteral) -> Expression<'a> {
+        let callee = self.identifier("require".into());
+        let argument = self.ast.literal_string_expression(source);
+        let arguments = self.ast.new_vec_single(Argument::Expression(argument));
+        self.ast.call_expression(Span::default(), callee, arguments, false, None)
+    }
+
+    fn interop_call(&self, helper: &str, argument: Expression<'a>) -> Expression<'a> {
+        self.ctx.helper_call(helper, self.ast.new_vec_single(Argument::Expression(argument)))
+    }
+
+    fn var_declaration(&self, name: Atom, init: Expression<'a>) -> Statement<'a> {
+        let kind = VariableDeclarationKind::Var;
+        let declarations = self.ast.new_vec_single(self.var_declarator(name, init));
+        let decl =
+            self.ast.variable_declaration(Span::default(), kind, declarations, Modifiers::empty());
+        Statement::Declaration(Declaration::VariableDeclaration(decl))
+    }
+
+    fn var_declarator(&self, name: Atom, init: Expression<'a>) -> VariableDeclarator<'a> {
+        let span = Span::default();
+        let binding = self.ast.binding_pattern_identifier(BindingIdentifier::new(span, name));
+        let binding = self.ast.binding_pattern(binding, None, false);
+        let kind = VariableDeclarationKind::Var;
+        self.ast.variable_declarator(span, kind, binding, Some(init), false)
+    }
+
+    /// `_default`, the name of an anonymous default exported function or class.
+    fn default_binding(&self) -> BindingIdentifier {
+        BindingIdentifier::new(Span::default(), self.generate_uid("default"))
+    }
+
+    /// `_fooBar` for `"./foo-bar.js"`
+    fn generate_uid(&self, source: &str) -> Atom {
+        let file_name = source.rsplit('/').next().unwrap_or(source);
+        let file_name = file_name.split('.').next().filter(|s| !s.is_empty()).unwrap_or("module");
+        let mut name = String::new();
+        for (i, part) in file_name.split(|c: char| !c.is_ascii_alphanumeric()).enumerate() {
+            let mut chars = part.chars();
+            if let Some(first) = chars.next() {
+                if i > 0 && !name.is_empty() {
+                    name.push(first.to_ascii_uppercase());
+                } else {
+                    name.push(first);
+                }
+                name.extend(chars);
+            }
+        }
+        let name = self.ctx.scopes().generate_uid(&name);
+        self.ctx.add_binding(name.clone());
+        name
+    }
+
+    fn identifier(&self, name: Atom) -> Expression<'a> {
+        self.ast.identifier_reference_expression(IdentifierReference::new(Span::default(), name))
+    }
+
+    fn string_literal(&self, value: Atom) -> Expression<'a> {
+        self.ast.literal_string_expression(StringLiteral::new(Span::default(), value))
+    }
+
+    /// `object.name`, or `object["name"]` when the name is not an identifier.
+    fn member(&self, object: Expression<'a>, name: &str) -> Expression<'a> {
+        let span = Span::default();
+        if is_identifier_name(name) {
+            let property = IdentifierName::new(span, name.into());
+            self.ast.static_member_expression(span, object, property, false)
+        } else {
+            let property = self.string_literal(name.into());
+            self.ast.computed_member_expression(span, object, property, false)
+        }
+    }
+}
+
+fn is_identifier_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next().is_some_and(|c| c.is_ascii_alphabetic() || c == '_' || c == '$')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$')
+}
+
+#[test]
+#[rustfmt::skip]
+fn test() {
+    use crate::tester::Tester;
+
+    let options = TransformOptions { module: ModuleFormat::CommonJs, ..TransformOptions::default() };
+    let tests = &[
+        (
+            "import 'foo';",
+            "'use strict'; require('foo');",
+        ),
+        (
+            "import foo, { bar } from 'foo'; export const baz = foo(bar);",
+            "'use strict'; Object.defineProperty(exports, '__esModule', { value: true }); Object.defineProperty(exports, 'baz', { enumerable: true, get: function() { return baz; } }); var _foo = babelHelpers.interopRequireWildcard(require('foo')); const baz = (0, _foo.default)(_foo.bar);",
+        ),
+        (
+            "import foo from './foo-bar.js'; console.log({ foo }, foo.x);",
+            "'use strict'; var _fooBar = babelHelpers.interopRequireDefault(require('./foo-bar.js')); console.log({ foo: _fooBar.default }, _fooBar.default.x);",
+        ),
+        (
+            "import * as ns from 'foo'; import { a as b } from 'bar'; ns.a(b`c`);",
+            "'use strict'; var ns = babelHelpers.interopRequireWildcard(require('foo')); var _bar = require('bar'); ns.a((0, _bar.a)`c`);",
+        ),
+        (
+            "import { a } from 'foo'; let b = 1; export { a, b as c }; export default function () {}",
+            "'use strict'; Object.defineProperty(exports, '__esModule', { value: true }); Object.defineProperty(exports, 'a', { enumerable: true, get: function() { return _foo.a; } }); Object.defineProperty(exports, 'c', { enumerable: true, get: function() { return b; } }); Object.defineProperty(exports, 'default', { enumerable: true, get: function() { return _default; } }); var _foo = require('foo'); let b = 1; function _default() {}",
+        ),
+        (
+            "export * from 'foo'; export * as ns from 'bar'; export { x } from 'baz'; export default 1;",
+            "'use strict'; Object.defineProperty(exports, '__esModule', { value: true }); Object.defineProperty(exports, 'ns', { enumerable: true, get: function() { return _bar; } }); Object.defineProperty(exports, 'x', { enumerable: true, get: function() { return _baz.x; } }); babelHelpers.exportStar(require('foo'), exports); var _bar = babelHelpers.interopRequireWildcard(require('bar')); var _baz = require('baz'); exports.default = 1;",
+        ),
+        (
+            "export default 1;",
+            "'use strict'; Object.defineProperty(exports, '__esModule', { value: true }); exports.default = 1;",
+        ),
+        (
+            "export * from 'foo';",
+            "'use strict'; Object.defineProperty(exports, '__esModule', { value: true }); babelHelpers.exportStar(require('foo'), exports);",
+        ),
+        (
+            "console.log(import.meta.url);",
+            "'use strict'; console.log(require('url').pathToFileURL(__filename).toString());",
+        ),
+    ];
+    Tester::new("test.js", options.clone()).test(tests);
+
+    let options = TransformOptions { module: ModuleFormat::Amd, ..options };
+    let tests = &[
+        (
+            "import foo from 'foo'; import 'bar'; foo();",
+            "define(['require', 'exports', 'foo', 'bar'], function(require, exports) { 'use strict'; var _foo = babelHelpers.interopRequireDefault(require('foo')); require('bar'); (0, _foo.default)(); });",
+        ),
+    ];
+    Tester::new("test.js", options.clone()).test(tests);
+
+    let options = TransformOptions { module: ModuleFormat::Umd, ..options };
+    let tests = &[
+        (
+            "export { x } from 'foo';",
+            "(function(factory) { if (typeof module === 'object' && typeof module.exports === 'object') { factory(require, exports); } else if (typeof define === 'function' && define.amd) { define(['require', 'exports', 'foo'], factory); } })(function(require, exports) { 'use strict'; Object.defineProperty(exports, '__esModule', { value: true }); Object.defineProperty(exports, 'x', { enumerable: true, get: function() { return _foo.x; } }); var _foo = require('foo'); });",
+        ),
+    ];
+    Tester::new("test.js", options).test(tests);
+
+    let options = TransformOptions {
+        module: ModuleFormat::CommonJs,
+        helpers: crate::HelperLoaderMode::Runtime("@babel/runtime".into()),
+        ..TransformOptions::default()
+    };
+    let tests = &[
+        (
+            "import foo from 'foo'; foo();",
+            "'use strict'; var _interopRequireDefault = require('@babel/runtime/helpers/interopRequireDefault'); var _foo = _interopRequireDefault(require('foo')); (0, _foo.default)();",
+        ),
+    ];
+    Tester::new("test.js", options).test(tests);
+
+    let options = TransformOptions {
+        module: ModuleFormat::Amd,
+        helpers: crate::HelperLoaderMode::Inline,
+        ..TransformOptions::default()
+    };
+    let source = "import * as foo from 'foo'; foo.bar();";
+    let ret = crate::transform(source, SourceType::default().with_module(true), options.clone());
+    assert!(ret.code.starts_with("define("));
+    assert_eq!(ret.code.matches("function _interopRequireWildcard(").count(), 1);
+    assert_eq!(ret.code.matches("function _getRequireWildcardCache(").count(), 1);
+
+    let options = TransformOptions {
+        helpers: crate::HelperLoaderMode::Runtime("@babel/runtime".into()),
+        ..options
+    };
+    let tests = &[
+        (
+            "import foo from 'foo'; foo();",
+            "define(['require', 'exports', 'foo', '@babel/runtime/helpers/interopRequireDefault'], function(require, exports) { 'use strict'; var _interopRequireDefault = require('@babel/runtime/helpers/interopRequireDefault'); var _foo = _interopRequireDefault(require('foo')); (0, _foo.default)(); });",
+        ),
+    ];
+    Tester::new("test.js", options).test(tests);
+}
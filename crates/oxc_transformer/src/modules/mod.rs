@@ -0,0 +1,26 @@
+mod amd;
+mod commonjs;
+
+use serde::Deserialize;
+
+pub use commonjs::CommonJs;
+
+/// The module format of the output, the `module` compiler option of TypeScript.
+///
+/// See <https://www.typescriptlang.org/tsconfig#module>
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum ModuleFormat {
+    /// Keep `import` and `export` as they are.
+    #[default]
+    #[serde(rename = "preserve")]
+    Preserve,
+    /// `require` and `exports`
+    #[serde(rename = "commonjs")]
+    CommonJs,
+    /// `define(["require", "exports", ...], function (require, exports) {})`
+    #[serde(rename = "amd")]
+    Amd,
+    /// CommonJS or AMD, whichever the environment supports.
+    #[serde(rename = "umd")]
+    Umd,
+}
@@ -1,41 +1,218 @@
+use std::path::Path;
+
 use oxc_syntax::assumptions::CompilerAssumptions;
 
-use crate::{es2020::NullishCoalescingOperatorOptions, react_jsx::ReactJsxOptions};
+use crate::{
+    comments::PreserveComments,
+    decorators::DecoratorsOptions,
+    emotion::EmotionOptions,
+    es2015::{ComputedPropertiesOptions, SpreadOptions},
+    es2020::{NullishCoalescingOperatorOptions, OptionalChainingOptions},
+    es2022::ClassPropertiesOptions,
+    helpers::HelperLoaderMode,
+    modular_imports::ModularImportOptions,
+    modules::ModuleFormat,
+    react_jsx::{JsxMode, ReactJsxOptions},
+    styled_components::StyledComponentsOptions,
+    targets::EngineTargets,
+    typescript::TypeScriptOptions,
+};
 
-#[derive(Debug, Default, Clone, Copy)]
+#[derive(Debug, Default, Clone)]
 pub struct TransformOptions {
     pub target: TransformTarget,
+    /// The engines to support, transforms run for the syntax which any of them lacks.
+    pub targets: EngineTargets,
     pub assumptions: CompilerAssumptions,
+    /// How the Babel helpers used by the transforms are referenced.
+    pub helpers: HelperLoaderMode,
 
+    /// How JSX is emitted, the `jsx` compiler option of TypeScript.
+    pub jsx: JsxMode,
     pub react_jsx: Option<ReactJsxOptions>,
 
+    pub decorators: Option<DecoratorsOptions>,
+
+    pub typescript: TypeScriptOptions,
+
+    pub styled_components: Option<StyledComponentsOptions>,
+    pub emotion: Option<EmotionOptions>,
+
+    /// The module format of the output.
+    pub module: ModuleFormat,
+
+    /// Import the `core-js` polyfills of the built-ins used, `useBuiltIns: "usage"`.
+    pub polyfills: bool,
+
+    /// The global expressions replaced with constants, e.g. `process.env.NODE_ENV` with
+    /// `"production"`.
+    pub define: Vec<(String, String)>,
+
+    /// The packages whose members are imported from their own modules, e.g. `lodash/debounce`.
+    pub modular_imports: Vec<ModularImportOptions>,
+
+    /// The comments kept, see [crate::Transformer::with_comments].
+    pub preserve_comments: PreserveComments,
+
+    /// The path of the source file for [crate::transform], to generate a source map, apply the
+    /// `overrides` and refer to the source in the transforms.
+    pub source_file_name: Option<String>,
+
+    /// The options of the files matching a pattern, applied in order over these options.
+    pub overrides: Vec<TransformOverride>,
+
+    // esnext
+    pub explicit_resource_management: bool,
     // es2022
+    pub class_properties: Option<ClassPropertiesOptions>,
     pub class_static_block: bool,
+    pub private_methods: bool,
     // es2021
     pub logical_assignment_operators: bool,
+    pub numeric_separator: bool,
     // es2020
     pub nullish_coalescing_operator: Option<NullishCoalescingOperatorOptions>,
+    pub optional_chaining: Option<OptionalChainingOptions>,
+    /// The runtime function `import()` is replaced with for engines without it, e.g. `System.import`.
+    pub dynamic_import_function: Option<String>,
+    pub export_namespace_from: bool,
     // es2019
     pub optional_catch_binding: bool,
+    // es2018
+    pub async_generator_functions: bool,
     // es2016
     pub exponentiation_operator: bool,
     // es2015
+    pub block_scoping: bool,
+    pub computed_properties: Option<ComputedPropertiesOptions>,
+    pub destructuring: bool,
+    pub literals: bool,
+    pub regenerator: bool,
     pub shorthand_properties: bool,
+    pub spread: Option<SpreadOptions>,
     pub sticky_regex: bool,
 }
 
+impl TransformOptions {
+    /// The options of the file at `path`, with the matching overrides applied.
+    #[must_use]
+    pub fn for_path(&self, path: &Path) -> Self {
+        let mut options = self.clone();
+        let path = path.to_string_lossy().replace('\\', "/");
+        for r#override in self.overrides.iter().filter(|o| o.matches(&path)) {
+            r#override.apply(&mut options);
+        }
+        options
+    }
+}
+
+/// Options for a subset of the files, e.g. the development JSX transform for `*.test.tsx`.
+///
+/// The fields which are set replace the ones of the base options, `define` is appended to them.
+/// <https://babeljs.io/docs/options#overrides>
+#[derive(Debug, Default, Clone)]
+pub struct TransformOverride {
+    /// Glob patterns of the file paths, e.g. `src/**/*.js`. `*` doesn't match `/`, `**` does.
+    /// The patterns without a `/` match the file name, e.g. `*.test.tsx`.
+    pub test: Vec<String>,
+    /// The preset of syntax transforms.
+    pub target: Option<TransformTarget>,
+    pub targets: Option<EngineTargets>,
+    pub jsx: Option<JsxMode>,
+    pub react_jsx: Option<ReactJsxOptions>,
+    pub decorators: Option<DecoratorsOptions>,
+    pub module: Option<ModuleFormat>,
+    pub define: Vec<(String, String)>,
+}
+
+impl TransformOverride {
+    fn matches(&self, path: &str) -> bool {
+        let file_name = path.rsplit('/').next().unwrap_or(path);
+        self.test.iter().any(|pattern| {
+            let text = if pattern.contains('/') { path } else { file_name };
+            glob_match(pattern.as_bytes(), text.as_bytes())
+        })
+    }
+
+    fn apply(&self, options: &mut TransformOptions) {
+        if let Some(target) = self.target {
+            options.target = target;
+        }
+        if let Some(targets) = &self.targets {
+            options.targets = targets.clone();
+        }
+        if let Some(jsx) = self.jsx {
+            options.jsx = jsx;
+        }
+        if let Some(react_jsx) = &self.react_jsx {
+            options.react_jsx = Some(react_jsx.clone());
+        }
+        if self.decorators.is_some() {
+            options.decorators = self.decorators;
+        }
+        if let Some(module) = self.module {
+            options.module = module;
+        }
+        options.define.extend(self.define.iter().cloned());
+    }
+}
+
+/// Match `text` with a glob `pattern` of `*`, `**` and `?`.
+fn glob_match(pattern: &[u8], text: &[u8]) -> bool {
+    match pattern {
+        [] => text.is_empty(),
+        [b'*', b'*', rest @ ..] => {
+            // `**/` also matches no directory
+            let rest_without_slash = rest.strip_prefix(b"/").unwrap_or(rest);
+            glob_match(rest_without_slash, text)
+                || (0..text.len()).any(|i| glob_match(rest, &text[i..]))
+        }
+        [b'*', rest @ ..] => (0..=text.len())
+            .take_while(|&i| i == 0 || text[i - 1] != b'/')
+            .any(|i| glob_match(rest, &text[i..])),
+        [b'?', rest @ ..] => {
+            text.first().is_some_and(|&c| c != b'/') && glob_match(rest, &text[1..])
+        }
+        [c, rest @ ..] => text.first() == Some(c) && glob_match(rest, &text[1..]),
+    }
+}
+
 /// See <https://www.typescriptlang.org/tsconfig#target>
 #[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Ord, PartialOrd)]
 pub enum TransformTarget {
     ES5,
     ES2015,
     ES2016,
+    ES2017,
     ES2018,
     ES2019,
     ES2020,
     ES2021,
     ES2022,
     ES2024,
+    ES2025,
     #[default]
     ESNext,
 }
+
+#[test]
+#[rustfmt::skip]
+fn test() {
+    use crate::tester::Tester;
+
+    let options = TransformOptions {
+        overrides: vec![
+            TransformOverride { test: vec!["*.test.js".into()], target: Some(TransformTarget::ES2015), ..TransformOverride::default() },
+            TransformOverride { test: vec!["legacy/**/*.js".into()], define: vec![("DEBUG".into(), "false".into())], ..TransformOverride::default() },
+        ],
+        ..TransformOptions::default()
+    };
+    Tester::new("src/app.js", options.clone()).test(&[("x = a ** b;", "x = a ** b;")]);
+    Tester::new("src/app.test.js", options.clone()).test(&[("x = a ** b;", "x = Math.pow(a, b);")]);
+    Tester::new("legacy/a/b.js", options.clone()).test(&[("x = DEBUG;", "x = false;")]);
+    Tester::new("legacy/b.js", options).test(&[("x = DEBUG;", "x = false;")]);
+
+    assert!(glob_match(b"src/**/*.tsx", b"src/a/b/c.tsx"));
+    assert!(!glob_match(b"src/*.tsx", b"src/a/c.tsx"));
+    assert!(glob_match(b"?.js", b"a.js"));
+}
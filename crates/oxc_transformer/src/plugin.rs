@@ -0,0 +1,113 @@
+use oxc_allocator::Vec;
+use oxc_ast::ast::*;
+
+use crate::context::TransformerCtx;
+
+/// A custom pass run by the [crate::Transformer] along with the built-in transforms.
+///
+/// The `enter_*` hooks are called before the children of the node are visited, the `exit_*`
+/// hooks after them.
+///
+/// The plugins run in ascending order of [TransformPlugin::priority], the ones with a negative
+/// priority before the built-in transforms, the others after them.
+///
+/// ```ignore
+/// struct RemoveDebugger;
+///
+/// impl<'a> TransformPlugin<'a> for RemoveDebugger {
+///     fn enter_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>, _ctx: &TransformerCtx<'a>) {
+///         stmts.retain(|stmt| !matches!(stmt, Statement::DebuggerStatement(_)));
+///     }
+/// }
+///
+/// Transformer::new(&allocator, source_type, &symbols, &scopes, options)
+///     .with_plugin(RemoveDebugger)
+///     .build(program);
+/// ```
+#[allow(unused_variables)]
+pub trait TransformPlugin<'a> {
+    fn priority(&self) -> i32 {
+        0
+    }
+
+    fn enter_program(&mut self, program: &mut Program<'a>, ctx: &TransformerCtx<'a>) {}
+
+    fn exit_program(&mut self, program: &mut Program<'a>, ctx: &TransformerCtx<'a>) {}
+
+    fn enter_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>, ctx: &TransformerCtx<'a>) {}
+
+    fn exit_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>, ctx: &TransformerCtx<'a>) {}
+
+    fn enter_statement(&mut self, stmt: &mut Statement<'a>, ctx: &TransformerCtx<'a>) {}
+
+    fn exit_statement(&mut self, stmt: &mut Statement<'a>, ctx: &TransformerCtx<'a>) {}
+
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &TransformerCtx<'a>) {}
+
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &TransformerCtx<'a>) {}
+}
+
+#[test]
+fn test() {
+    use std::{cell::RefCell, rc::Rc};
+
+    use oxc_allocator::Allocator;
+    use oxc_codegen::{Codegen, CodegenOptions};
+    use oxc_parser::Parser;
+    use oxc_semantic::SemanticBuilder;
+    use oxc_span::{Atom, SourceType};
+
+    use crate::{TransformOptions, TransformTarget, Transformer};
+
+    /// Renames the `__DEV__` references, after `**` is lowered.
+    struct Rename(&'static str);
+
+    impl<'a> TransformPlugin<'a> for Rename {
+        fn exit_expression(&mut self, expr: &mut Expression<'a>, _ctx: &TransformerCtx<'a>) {
+            if let Expression::Identifier(ident) = expr {
+                if ident.name == "__DEV__" {
+                    ident.name = Atom::from(self.0);
+                }
+            }
+        }
+    }
+
+    /// Removes the `debugger` statements, before the other plugins.
+    struct RemoveDebugger;
+
+    impl<'a> TransformPlugin<'a> for RemoveDebugger {
+        fn priority(&self) -> i32 {
+            -1
+        }
+
+        fn enter_statements(
+            &mut self,
+            stmts: &mut Vec<'a, Statement<'a>>,
+            _ctx: &TransformerCtx<'a>,
+        ) {
+            stmts.retain(|stmt| !matches!(stmt, Statement::DebuggerStatement(_)));
+        }
+    }
+
+    let source_text = "debugger; x = __DEV__ ** 2; if (a) { debugger; }";
+    let allocator = Allocator::default();
+    let source_type = SourceType::default();
+    let program = Parser::new(&allocator, source_text, source_type).parse().program;
+    let semantic = SemanticBuilder::new(source_text, source_type).build(&program).semantic;
+    let (symbols, scopes) = semantic.into_symbol_table_and_scope_tree();
+    let program = allocator.alloc(program);
+    let options =
+        TransformOptions { target: TransformTarget::ES2015, ..TransformOptions::default() };
+    Transformer::new(
+        &allocator,
+        source_type,
+        &Rc::new(RefCell::new(symbols)),
+        &Rc::new(RefCell::new(scopes)),
+        options,
+    )
+    .with_plugin(Rename("false"))
+    .with_plugin(RemoveDebugger)
+    .build(program);
+    let printed = Codegen::<false>::new(source_text.len(), CodegenOptions).build(program);
+    assert_eq!(printed, "x = Math.pow(false, 2);\nif (a) {\n}\n");
+}
@@ -0,0 +1,227 @@
+use std::rc::Rc;
+
+use oxc_allocator::Vec;
+use oxc_ast::{ast::*, AstBuilder};
+use oxc_span::{Atom, Span};
+
+use crate::{
+    context::TransformerCtx,
+    options::TransformOptions,
+    targets::{Engine, EngineTargets, Version},
+};
+
+/// Polyfills: core-js, `useBuiltIns: "usage"`
+///
+/// Imports the `core-js` modules of the built-ins used by the file which the targets don't
+/// support, all of them without targets:
+///
+/// ```js
+/// arr.flat();
+/// ```
+///
+/// ```js
+/// import "core-js/modules/es.array.flat.js";
+/// import "core-js/modules/es.array.unscopables.flat.js";
+/// arr.flat();
+/// ```
+///
+/// Instance methods are matched by name, as the type of the object is unknown.
+///
+/// References:
+/// * <https://babeljs.io/docs/babel-preset-env#usebuiltins-usage>
+/// * <https://github.com/babel/babel-polyfills/tree/main/packages/babel-plugin-polyfill-corejs3>
+/// * <https://github.com/zloirock/core-js/blob/master/packages/core-js-compat/src/data.mjs>
+pub struct CoreJsUsage<'a> {
+    ast: Rc<AstBuilder<'a>>,
+    ctx: TransformerCtx<'a>,
+    targets: EngineTargets,
+    module: bool,
+    /// The modules to import, in order of first use.
+    modules: std::vec::Vec<&'static str>,
+}
+
+impl<'a> CoreJsUsage<'a> {
+    pub fn new(
+        ast: Rc<AstBuilder<'a>>,
+        ctx: TransformerCtx<'a>,
+        module: bool,
+        options: &TransformOptions,
+    ) -> Option<Self> {
+        options.polyfills.then(|| Self {
+            ast,
+            ctx,
+            targets: options.targets.clone(),
+            module,
+            modules: vec![],
+        })
+    }
+
+    pub fn transform_expression(&mut self, expr: &Expression<'a>) {
+        match expr {
+            Expression::Identifier(ident) if self.is_global(ident) => {
+                self.add_modules(global_modules(&ident.name));
+            }
+            Expression::MemberExpression(member) => {
+                let MemberExpression::StaticMemberExpression(member) = &**member else { return };
+                let property = member.property.name.as_str();
+                let modules = match &member.object {
+                    Expression::Identifier(ident) if self.is_global(ident) => {
+                        static_modules(&ident.name, property)
+                    }
+                    _ => None,
+                };
+                self.add_modules(modules.or_else(|| instance_modules(property)));
+            }
+            _ => {}
+        }
+    }
+
+    /// `import "core-js/modules/{name}.js"` for modules, `require("core-js/modules/{name}.js")`
+    /// for scripts.
+    pub fn add_imports(&mut self, stmts: &mut Vec<'a, Statement<'a>>) {
+        let span = Span::default();
+        for name in std::mem::take(&mut self.modules).into_iter().rev() {
+            let source = StringLiteral::new(span, Atom::from(format!("core-js/modules/{name}.js")));
+            let stmt = if self.module {
+                let decl = self.ast.import_declaration(
+                    span,
+                    self.ast.new_vec(),
+                    source,
+                    None,
+                    ImportOrExportKind::Value,
+                );
+                self.ast.module_declaration(ModuleDeclaration::ImportDeclaration(decl))
+            } else {
+                let callee = IdentifierReference::new(span, "require".into());
+                let callee = self.ast.identifier_reference_expression(callee);
+                let argument = self.ast.literal_string_expression(source);
+                let arguments = self.ast.new_vec_single(Argument::Expression(argument));
+                let call = self.ast.call_expression(span, callee, arguments, false, None);
+                self.ast.expression_statement(span, call)
+            };
+            stmts.insert(0, stmt);
+        }
+    }
+
+    /// A reference which isn't shadowed by a local binding.
+    fn is_global(&self, ident: &IdentifierReference) -> bool {
+        ident.reference_id.get().map_or(true, |id| self.ctx.symbols().is_global_reference(id))
+    }
+
+    fn add_modules(&mut self, modules: Option<&'static [&'static str]>) {
+        for &name in modules.unwrap_or_default() {
+            if self.modules.contains(&name) {
+                continue;
+            }
+            if self.targets.is_empty() || self.targets.is_unsupported(supported_since(name)) {
+                self.modules.push(name);
+            }
+        }
+    }
+}
+
+fn global_modules(name: &str) -> Option<&'static [&'static str]> {
+    let modules: &[&str] = match name {
+        "AggregateError" => &["es.aggregate-error"],
+        "globalThis" => &["es.global-this"],
+        "Map" => &["es.map"],
+        "Promise" => &["es.promise"],
+        "Set" => &["es.set"],
+        "Symbol" => &["es.symbol"],
+        "WeakMap" => &["es.weak-map"],
+        "WeakSet" => &["es.weak-set"],
+        _ => return None,
+    };
+    Some(modules)
+}
+
+fn static_modules(object: &str, property: &str) -> Option<&'static [&'static str]> {
+    let modules: &[&str] = match (object, property) {
+        ("Array", "from") => &["es.array.from"],
+        ("Object", "entries") => &["es.object.entries"],
+        ("Object", "fromEntries") => &["es.object.from-entries"],
+        ("Object", "hasOwn") => &["es.object.has-own"],
+        ("Object", "values") => &["es.object.values"],
+        ("Promise", "allSettled") => &["es.promise", "es.promise.all-settled"],
+        ("Promise", "any") => &["es.aggregate-error", "es.promise", "es.promise.any"],
+        _ => return None,
+    };
+    Some(modules)
+}
+
+fn instance_modules(property: &str) -> Option<&'static [&'static str]> {
+    let modules: &[&str] = match property {
+        "at" => &["es.array.at", "es.string.at-alternative"],
+        "findLast" => &["es.array.find-last"],
+        "findLastIndex" => &["es.array.find-last-index"],
+        "flat" => &["es.array.flat", "es.array.unscopables.flat"],
+        "flatMap" => &["es.array.flat-map", "es.array.unscopables.flat-map"],
+        "includes" => &["es.array.includes", "es.string.includes"],
+        "padEnd" => &["es.string.pad-end"],
+        "padStart" => &["es.string.pad-start"],
+        "replaceAll" => &["es.string.replace-all"],
+        "trimEnd" => &["es.string.trim-end"],
+        "trimStart" => &["es.string.trim-start"],
+        _ => return None,
+    };
+    Some(modules)
+}
+
+/// The first versions of each engine which support the core-js module.
+#[rustfmt::skip]
+fn supported_since(module: &str) -> &'static [(Engine, Version)] {
+    use Engine::{
+        Chrome, Deno, Edge, Electron, Firefox, Ios, Node, Opera, OperaMobile, Safari, Samsung,
+    };
+    match module {
+        "es.aggregate-error" | "es.promise.any" => &[(Chrome, Version(85, 0)), (Edge, Version(85, 0)), (Firefox, Version(79, 0)), (Safari, Version(14, 0)), (Node, Version(15, 0)), (Deno, Version(1, 2)), (Ios, Version(14, 0)), (Samsung, Version(14, 0)), (Opera, Version(71, 0)), (OperaMobile, Version(60, 0)), (Electron, Version(10, 0))],
+        "es.array.at" | "es.string.at-alternative" => &[(Chrome, Version(92, 0)), (Edge, Version(92, 0)), (Firefox, Version(90, 0)), (Safari, Version(15, 4)), (Node, Version(16, 6)), (Deno, Version(1, 12)), (Ios, Version(15, 4)), (Samsung, Version(16, 0)), (Opera, Version(78, 0)), (OperaMobile, Version(65, 0)), (Electron, Version(14, 0))],
+        "es.array.find-last" | "es.array.find-last-index" => &[(Chrome, Version(97, 0)), (Edge, Version(97, 0)), (Firefox, Version(104, 0)), (Safari, Version(15, 4)), (Node, Version(18, 0)), (Deno, Version(1, 16)), (Ios, Version(15, 4)), (Samsung, Version(18, 0)), (Opera, Version(83, 0)), (OperaMobile, Version(68, 0)), (Electron, Version(17, 0))],
+        "es.array.flat" | "es.array.unscopables.flat" | "es.array.flat-map" | "es.array.unscopables.flat-map" => &[(Chrome, Version(69, 0)), (Edge, Version(79, 0)), (Firefox, Version(62, 0)), (Safari, Version(12, 0)), (Node, Version(11, 0)), (Deno, Version(1, 0)), (Ios, Version(12, 0)), (Samsung, Version(10, 0)), (Opera, Version(56, 0)), (OperaMobile, Version(48, 0)), (Electron, Version(4, 0))],
+        "es.array.from" | "es.map" | "es.set" | "es.weak-map" | "es.weak-set" => &[(Chrome, Version(51, 0)), (Edge, Version(15, 0)), (Firefox, Version(53, 0)), (Safari, Version(10, 0)), (Node, Version(6, 5)), (Deno, Version(1, 0)), (Ios, Version(10, 0)), (Samsung, Version(5, 0)), (Opera, Version(38, 0)), (OperaMobile, Version(41, 0)), (Electron, Version(1, 2))],
+        "es.array.includes" | "es.string.includes" => &[(Chrome, Version(53, 0)), (Edge, Version(14, 0)), (Firefox, Version(48, 0)), (Safari, Version(10, 0)), (Node, Version(7, 0)), (Deno, Version(1, 0)), (Ios, Version(10, 0)), (Samsung, Version(6, 0)), (Opera, Version(40, 0)), (OperaMobile, Version(40, 0)), (Electron, Version(1, 4))],
+        "es.global-this" | "es.object.from-entries" => &[(Chrome, Version(73, 0)), (Edge, Version(79, 0)), (Firefox, Version(65, 0)), (Safari, Version(12, 1)), (Node, Version(12, 0)), (Deno, Version(1, 0)), (Ios, Version(12, 2)), (Samsung, Version(11, 0)), (Opera, Version(60, 0)), (OperaMobile, Version(52, 0)), (Electron, Version(5, 0))],
+        "es.object.entries" | "es.object.values" => &[(Chrome, Version(54, 0)), (Edge, Version(14, 0)), (Firefox, Version(47, 0)), (Safari, Version(10, 1)), (Node, Version(7, 0)), (Deno, Version(1, 0)), (Ios, Version(10, 3)), (Samsung, Version(6, 0)), (Opera, Version(41, 0)), (OperaMobile, Version(41, 0)), (Electron, Version(1, 4))],
+        "es.object.has-own" => &[(Chrome, Version(93, 0)), (Edge, Version(93, 0)), (Firefox, Version(92, 0)), (Safari, Version(15, 4)), (Node, Version(16, 9)), (Deno, Version(1, 13)), (Ios, Version(15, 4)), (Samsung, Version(17, 0)), (Opera, Version(79, 0)), (OperaMobile, Version(66, 0)), (Electron, Version(14, 0))],
+        "es.promise" => &[(Chrome, Version(67, 0)), (Edge, Version(79, 0)), (Firefox, Version(69, 0)), (Safari, Version(11, 0)), (Node, Version(10, 4)), (Deno, Version(1, 0)), (Ios, Version(11, 0)), (Samsung, Version(9, 0)), (Opera, Version(54, 0)), (OperaMobile, Version(48, 0)), (Electron, Version(4, 0))],
+        "es.promise.all-settled" => &[(Chrome, Version(76, 0)), (Edge, Version(79, 0)), (Firefox, Version(71, 0)), (Safari, Version(13, 0)), (Node, Version(12, 9)), (Deno, Version(1, 0)), (Ios, Version(13, 0)), (Samsung, Version(12, 0)), (Opera, Version(63, 0)), (OperaMobile, Version(54, 0)), (Electron, Version(6, 0))],
+        "es.string.pad-end" | "es.string.pad-start" => &[(Chrome, Version(57, 0)), (Edge, Version(15, 0)), (Firefox, Version(48, 0)), (Safari, Version(11, 0)), (Node, Version(8, 0)), (Deno, Version(1, 0)), (Ios, Version(11, 0)), (Samsung, Version(7, 0)), (Opera, Version(44, 0)), (OperaMobile, Version(43, 0)), (Electron, Version(1, 7))],
+        "es.string.replace-all" => &[(Chrome, Version(85, 0)), (Edge, Version(85, 0)), (Firefox, Version(77, 0)), (Safari, Version(13, 1)), (Node, Version(15, 0)), (Deno, Version(1, 2)), (Ios, Version(13, 4)), (Samsung, Version(14, 0)), (Opera, Version(71, 0)), (OperaMobile, Version(60, 0)), (Electron, Version(10, 0))],
+        "es.string.trim-end" | "es.string.trim-start" => &[(Chrome, Version(66, 0)), (Edge, Version(79, 0)), (Firefox, Version(61, 0)), (Safari, Version(12, 0)), (Node, Version(10, 0)), (Deno, Version(1, 0)), (Ios, Version(12, 0)), (Samsung, Version(9, 0)), (Opera, Version(53, 0)), (OperaMobile, Version(47, 0)), (Electron, Version(3, 0))],
+        "es.symbol" => &[(Chrome, Version(49, 0)), (Edge, Version(15, 0)), (Firefox, Version(51, 0)), (Safari, Version(10, 0)), (Node, Version(6, 0)), (Deno, Version(1, 0)), (Ios, Version(10, 0)), (Samsung, Version(5, 0)), (Opera, Version(36, 0)), (OperaMobile, Version(36, 0)), (Electron, Version(0, 37))],
+        _ => &[],
+    }
+}
+
+#[test]
+#[rustfmt::skip]
+fn test() {
+    use crate::tester::Tester;
+
+    let options = TransformOptions { polyfills: true, ..TransformOptions::default() };
+    let tests = &[
+        (
+            "arr.flat(); arr.flat();",
+            "import 'core-js/modules/es.array.flat.js'; import 'core-js/modules/es.array.unscopables.flat.js'; arr.flat(); arr.flat();",
+        ),
+        (
+            "Promise.allSettled(a).then(f);",
+            "import 'core-js/modules/es.promise.js'; import 'core-js/modules/es.promise.all-settled.js'; Promise.allSettled(a).then(f);",
+        ),
+        ("function f(Promise) { return new Promise(a); }", "function f(Promise) { return new Promise(a); }"),
+        ("foo.bar(baz);", "foo.bar(baz);"),
+    ];
+    Tester::new("test.js", options.clone()).test(tests);
+
+    let options = TransformOptions {
+        targets: EngineTargets::from_query("chrome 80").unwrap(),
+        ..options
+    };
+    let tests = &[
+        (
+            "Object.fromEntries(a); Object.hasOwn(a, b); s.replaceAll(a, b);",
+            "import 'core-js/modules/es.object.has-own.js'; import 'core-js/modules/es.string.replace-all.js'; Object.fromEntries(a); Object.hasOwn(a, b); s.replaceAll(a, b);",
+        ),
+    ];
+    Tester::new("test.js", options).test(tests);
+}
@@ -0,0 +1,3 @@
+mod core_js;
+
+pub use core_js::CoreJsUsage;
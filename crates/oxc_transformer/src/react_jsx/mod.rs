@@ -1,32 +1,850 @@
-use std::rc::Rc;
+use std::{mem, path::Path, rc::Rc};
 
-use oxc_ast::AstBuilder;
+use oxc_allocator::Vec;
+use oxc_ast::{ast::*, AstBuilder};
+use oxc_span::{Atom, LineCol, LineIndex, SourceType, Span};
+use oxc_syntax::{
+    identifier::{is_identifier_part, is_identifier_start_all},
+    NumberBase,
+};
+use serde::Deserialize;
 
-#[derive(Debug, Default, Clone, Copy)]
+use crate::{context::TransformerCtx, options::TransformOptions};
+
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
 pub struct ReactJsxOptions {
-    _runtime: ReactJsxRuntime,
+    pub runtime: ReactJsxRuntime,
+    /// The function called for each element in the classic runtime, `React.createElement` by
+    /// default.
+    pub pragma: Option<String>,
+    /// The component used for fragments in the classic runtime, `React.Fragment` by default.
+    pub pragma_frag: Option<String>,
+    /// Use `Object.assign` instead of `babelHelpers.extends` to merge spread attributes.
+    pub use_built_ins: bool,
+    /// Use object spread to merge spread attributes.
+    pub use_spread: bool,
+    /// The module the automatic runtime imports from, `react` by default.
+    pub import_source: Option<String>,
+    /// Pass the `__self` and `__source` of elements to React for debugging, the automatic
+    /// runtime calls `jsxDEV` from `react/jsx-dev-runtime`.
+    pub development: bool,
 }
 
-#[derive(Debug, Default, Clone, Copy)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum ReactJsxRuntime {
     #[default]
     Classic,
-    #[allow(unused)]
     Automatic,
 }
 
+/// How JSX is emitted, the `jsx` compiler option of TypeScript.
+///
+/// <https://www.typescriptlang.org/tsconfig#jsx>
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum JsxMode {
+    /// Transform JSX as configured by `TransformOptions::react_jsx`.
+    #[default]
+    React,
+    /// Keep JSX for a later step to transform, the output is a `.jsx` file.
+    Preserve,
+    /// Keep JSX for the React Native bundler to transform, the output is a `.js` file.
+    ReactNative,
+}
+
+impl JsxMode {
+    /// The extension of the output file for a source file of `source_type`.
+    pub fn output_extension(self, source_type: SourceType) -> &'static str {
+        if self == Self::Preserve && source_type.is_jsx() {
+            "jsx"
+        } else {
+            "js"
+        }
+    }
+}
+
 /// Transform React JSX
 ///
+/// The classic runtime turns elements into `React.createElement` calls:
+///
+/// ```JavaScript
+/// <div className="a" {...props}>Hello {name}</div>
+/// // =>
+/// React.createElement("div", babelHelpers.extends({ className: "a" }, props), "Hello ", name)
+/// ```
+///
+/// The automatic runtime imports `jsx`, `jsxs` and `Fragment` from `react/jsx-runtime`, and passes
+/// the children as a prop:
+///
+/// ```JavaScript
+/// <div key="a" {...props}>Hello {name}</div>
+/// // =>
+/// import { jsxs as _jsxs } from "react/jsx-runtime";
+/// _jsxs("div", { ...props, children: ["Hello ", name] }, "a")
+/// ```
+///
 /// References:
 /// * <https://babeljs.io/docs/babel-plugin-transform-react-jsx>
 /// * <https://github.com/babel/babel/tree/main/packages/babel-helper-builder-react-jsx>
+/// * <https://github.com/babel/babel/blob/main/packages/babel-plugin-transform-react-jsx/src/create-plugin.ts>
 pub struct ReactJsx<'a> {
-    _ast: Rc<AstBuilder<'a>>,
-    _options: ReactJsxOptions,
+    ast: Rc<AstBuilder<'a>>,
+    ctx: TransformerCtx<'a>,
+    options: ReactJsxOptions,
+    /// `(imported, local, source)` of the automatic runtime imports, in the order of first use.
+    imports: std::vec::Vec<(&'static str, Atom, Atom)>,
+    /// The path and the lines of the source file, for the `__source` of elements in development.
+    source: Option<(Atom, LineIndex)>,
+    /// `var _jsxFileName = "path"`
+    file_name_var: Option<Atom>,
 }
 
 impl<'a> ReactJsx<'a> {
-    pub fn new(_ast: Rc<AstBuilder<'a>>, _options: ReactJsxOptions) -> Self {
-        Self { _ast, _options }
+    pub fn new(
+        ast: Rc<AstBuilder<'a>>,
+        ctx: TransformerCtx<'a>,
+        source_type: SourceType,
+        options: &TransformOptions,
+    ) -> Option<Self> {
+        if options.jsx != JsxMode::React || !source_type.is_jsx() {
+            return None;
+        }
+        options.react_jsx.clone().map(|options| Self {
+            ast,
+            ctx,
+            options,
+            imports: vec![],
+            source: None,
+            file_name_var: None,
+        })
+    }
+
+    pub fn set_source(&mut self, source_path: &Path, source_text: &'a str) {
+        self.source =
+            Some((source_path.to_string_lossy().as_ref().into(), LineIndex::new(source_text)));
+    }
+
+    pub fn transform_expression(&mut self, expr: &mut Expression<'a>) {
+        match expr {
+            Expression::JSXElement(_) => {
+                let Expression::JSXElement(element) = self.ast.move_expression(expr) else {
+                    unreachable!()
+                };
+                *expr = self.transform_element(element.unbox());
+            }
+            Expression::JSXFragment(_) => {
+                let Expression::JSXFragment(fragment) = self.ast.move_expression(expr) else {
+                    unreachable!()
+                };
+                *expr = self.transform_fragment(fragment.unbox());
+            }
+            _ => {}
+        }
+    }
+
+    /// Adds `import { jsx as _jsx } from "react/jsx-runtime"` for the helpers used by the
+    /// automatic runtime, and `var _jsxFileName = "path"` for the `__source` of elements.
+    pub fn add_runtime_imports(&mut self, stmts: &mut Vec<'a, Statement<'a>>) {
+        let span = Span::default();
+        if let (Some(name), Some((path, _))) = (self.file_name_var.take(), &self.source) {
+            let binding = self.ast.binding_pattern(
+                self.ast.binding_pattern_identifier(BindingIdentifier::new(span, name)),
+                None,
+                false,
+            );
+            let kind = VariableDeclarationKind::Var;
+            let init = Some(self.string_literal(path.clone()));
+            let decl = self.ast.variable_declarator(span, kind, binding, init, false);
+            let decl = self.ast.variable_declaration(
+                span,
+                kind,
+                self.ast.new_vec_single(decl),
+                Modifiers::empty(),
+            );
+            stmts.insert(0, Statement::Declaration(Declaration::VariableDeclaration(decl)));
+        }
+        for (imported, local, source) in mem::take(&mut self.imports).into_iter().rev() {
+            let specifier = ImportSpecifier {
+                span,
+                imported: ModuleExportName::Identifier(IdentifierName::new(span, imported.into())),
+                local: BindingIdentifier::new(span, local),
+                import_kind: ImportOrExportKind::Value,
+            };
+            let specifiers =
+                self.ast.new_vec_single(ImportDeclarationSpecifier::ImportSpecifier(specifier));
+            let source = StringLiteral::new(span, source);
+            let decl = self.ast.import_declaration(
+                span,
+                specifiers,
+                source,
+                None,
+                ImportOrExportKind::Value,
+            );
+            let decl = ModuleDeclaration::ImportDeclaration(decl);
+            stmts.insert(0, self.ast.module_declaration(decl));
+        }
+    }
+
+    fn transform_element(&mut self, element: JSXElement<'a>) -> Expression<'a> {
+        match self.options.runtime {
+            ReactJsxRuntime::Classic => self.transform_element_classic(element),
+            ReactJsxRuntime::Automatic => self.transform_element_automatic(element),
+        }
+    }
+
+    fn transform_fragment(&mut self, fragment: JSXFragment<'a>) -> Expression<'a> {
+        match self.options.runtime {
+            ReactJsxRuntime::Classic => self.transform_fragment_classic(fragment),
+            ReactJsxRuntime::Automatic => self.transform_fragment_automatic(fragment),
+        }
+    }
+
+    /// `<A b="c">d</A>` -> `React.createElement(A, { b: "c" }, "d")`
+    fn transform_element_classic(&mut self, element: JSXElement<'a>) -> Expression<'a> {
+        let JSXElement { span, opening_element, children, .. } = element;
+        let opening_element = opening_element.unbox();
+        let element_type = self.element_name(opening_element.name);
+        let dev_properties = self.dev_properties(span);
+        let props = self.attributes(opening_element.attributes, dev_properties);
+        let callee = self.pragma(self.options.pragma.as_deref().unwrap_or("React.createElement"));
+        self.create_element(callee, element_type, props, children)
+    }
+
+    /// `<>a</>` -> `React.createElement(React.Fragment, null, "a")`
+    fn transform_fragment_classic(&mut self, fragment: JSXFragment<'a>) -> Expression<'a> {
+        let fragment_type =
+            self.pragma(self.options.pragma_frag.as_deref().unwrap_or("React.Fragment"));
+        let props = self.ast.literal_null_expression(NullLiteral::new(Span::default()));
+        let callee = self.pragma(self.options.pragma.as_deref().unwrap_or("React.createElement"));
+        self.create_element(callee, fragment_type, props, fragment.children)
+    }
+
+    fn create_element(
+        &mut self,
+        callee: Expression<'a>,
+        element_type: Expression<'a>,
+        props: Expression<'a>,
+        children: Vec<'a, JSXChild<'a>>,
+    ) -> Expression<'a> {
+        let mut arguments = self.ast.new_vec_with_capacity(children.len() + 2);
+        arguments.push(Argument::Expression(element_type));
+        arguments.push(Argument::Expression(props));
+        arguments.extend(self.children(children));
+        self.ast.call_expression(Span::default(), callee, arguments, false, None)
+    }
+
+    /// `<A key="k" b="c">d</A>` -> `_jsx(A, { b: "c", children: "d" }, "k")`
+    ///
+    /// A `key` after a spread attribute can't be told apart from a `key` inside the spread, so
+    /// these elements fall back to `createElement`:
+    /// `<A {...b} key="k" />` -> `_createElement(A, { ...b, key: "k" })`
+    fn transform_element_automatic(&mut self, element: JSXElement<'a>) -> Expression<'a> {
+        let JSXElement { span, opening_element, children, .. } = element;
+        let opening_element = opening_element.unbox();
+        let element_type = self.element_name(opening_element.name);
+
+        let mut has_spread = false;
+        let key_after_spread = opening_element.attributes.iter().any(|attribute| match attribute {
+            JSXAttributeItem::SpreadAttribute(_) => {
+                has_spread = true;
+                false
+            }
+            JSXAttributeItem::Attribute(attribute) => has_spread && Self::is_key(attribute),
+        });
+
+        let mut key = None;
+        let mut properties = self.ast.new_vec_with_capacity(opening_element.attributes.len() + 1);
+        for attribute in opening_element.attributes {
+            match attribute {
+                JSXAttributeItem::Attribute(attribute)
+                    if !key_after_spread && Self::is_key(&attribute) =>
+                {
+                    key = Some(self.attribute_value(attribute.unbox().value));
+                }
+                JSXAttributeItem::Attribute(attribute) => {
+                    properties.push(self.attribute(attribute.unbox()));
+                }
+                JSXAttributeItem::SpreadAttribute(spread) => {
+                    let argument = spread.unbox().argument;
+                    properties.push(ObjectPropertyKind::SpreadProperty(
+                        self.ast.spread_element(Span::default(), argument),
+                    ));
+                }
+            }
+        }
+
+        if key_after_spread {
+            properties.extend(self.dev_properties(span));
+            let props = self.ast.object_expression(Span::default(), properties, None);
+            let callee = self.import_from(
+                "createElement",
+                self.options.import_source.as_deref().unwrap_or("react").into(),
+            );
+            return self.create_element(callee, element_type, props, children);
+        }
+        self.jsx_call(span, element_type, properties, children, key)
+    }
+
+    /// `<>a</>` -> `_jsx(_Fragment, { children: "a" })`
+    fn transform_fragment_automatic(&mut self, fragment: JSXFragment<'a>) -> Expression<'a> {
+        let runtime = if self.options.development { "jsx-dev-runtime" } else { "jsx-runtime" };
+        let fragment_type = self.import_from_runtime(runtime, "Fragment");
+        self.jsx_call(fragment.span, fragment_type, self.ast.new_vec(), fragment.children, None)
+    }
+
+    /// `_jsx(type, { ...props, children: a })` or `_jsxs(type, { ...props, children: [a, b] })`
+    /// for static children.
+    ///
+    /// In development: `_jsxDEV(type, props, key, isStaticChildren, source, this)`
+    fn jsx_call(
+        &mut self,
+        span: Span,
+        element_type: Expression<'a>,
+        mut properties: Vec<'a, ObjectPropertyKind<'a>>,
+        children: Vec<'a, JSXChild<'a>>,
+        key: Option<Expression<'a>>,
+    ) -> Expression<'a> {
+        let mut children = self.children(children);
+        let is_static = children.len() > 1;
+        let children = match children.len() {
+            0 => None,
+            1 => children.pop().map(Self::argument_expression),
+            _ => {
+                let elements = children.into_iter().map(|child| {
+                    ArrayExpressionElement::Expression(Self::argument_expression(child))
+                });
+                let mut array = self.ast.new_vec_with_capacity(elements.len());
+                array.extend(elements);
+                Some(self.ast.array_expression(Span::default(), array, None))
+            }
+        };
+        if let Some(children) = children {
+            properties.push(self.property("children".into(), children));
+        }
+
+        let props = self.ast.object_expression(Span::default(), properties, None);
+        if self.options.development {
+            let callee = self.import_from_runtime("jsx-dev-runtime", "jsxDEV");
+            let mut arguments = self.ast.new_vec_with_capacity(6);
+            arguments.push(Argument::Expression(element_type));
+            arguments.push(Argument::Expression(props));
+            arguments.push(Argument::Expression(key.unwrap_or_else(|| self.ast.void_0())));
+            arguments.push(Argument::Expression(
+                self.ast.literal_boolean_expression(BooleanLiteral { span, value: is_static }),
+            ));
+            let source = self.source_object(span).unwrap_or_else(|| self.ast.void_0());
+            arguments.push(Argument::Expression(source));
+            arguments.push(Argument::Expression(self.ast.this_expression(Span::default())));
+            return self.ast.call_expression(Span::default(), callee, arguments, false, None);
+        }
+
+        let callee =
+            self.import_from_runtime("jsx-runtime", if is_static { "jsxs" } else { "jsx" });
+        let mut arguments = self.ast.new_vec_with_capacity(3);
+        arguments.push(Argument::Expression(element_type));
+        arguments.push(Argument::Expression(props));
+        if let Some(key) = key {
+            arguments.push(Argument::Expression(key));
+        }
+        self.ast.call_expression(Span::default(), callee, arguments, false, None)
+    }
+
+    /// `__self: this, __source: { fileName: _jsxFileName, lineNumber: 1, columnNumber: 1 }`
+    fn dev_properties(&mut self, span: Span) -> Vec<'a, ObjectPropertyKind<'a>> {
+        let mut properties = self.ast.new_vec();
+        if !self.options.development {
+            return properties;
+        }
+        properties.push(self.property("__self".into(), self.ast.this_expression(Span::default())));
+        if let Some(source) = self.source_object(span) {
+            properties.push(self.property("__source".into(), source));
+        }
+        properties
+    }
+
+    /// `{ fileName: _jsxFileName, lineNumber: 1, columnNumber: 1 }`
+    fn source_object(&mut self, span: Span) -> Option<Expression<'a>> {
+        let (_, line_index) = self.source.as_ref()?;
+        // 1-based, in UTF-16 code units, as reported by Babel
+        let LineCol { line, col } = line_index.line_col_utf16(span.start);
+        let (line, column) = (line as usize + 1, col as usize + 1);
+        let file_name = match &self.file_name_var {
+            Some(name) => name.clone(),
+            None => {
+                let name = self.ctx.scopes().generate_uid("jsxFileName");
+                self.ctx.add_binding(name.clone());
+                self.file_name_var = Some(name.clone());
+                name
+            }
+        };
+        let mut properties = self.ast.new_vec_with_capacity(3);
+        properties.push(self.property("fileName".into(), self.identifier(file_name)));
+        properties.push(self.property("lineNumber".into(), self.number(line)));
+        properties.push(self.property("columnNumber".into(), self.number(column)));
+        Some(self.ast.object_expression(Span::default(), properties, None))
+    }
+
+    /// Spread children are not supported by the automatic runtime, their argument is passed as
+    /// the children instead.
+    fn argument_expression(argument: Argument<'a>) -> Expression<'a> {
+        match argument {
+            Argument::Expression(expr) => expr,
+            Argument::SpreadElement(spread) => spread.unbox().argument,
+        }
+    }
+
+    fn is_key(attribute: &JSXAttribute<'a>) -> bool {
+        matches!(&attribute.name, JSXAttributeName::Identifier(ident) if ident.name == "key")
+    }
+
+    fn import_from_runtime(&mut self, runtime: &str, name: &'static str) -> Expression<'a> {
+        let import_source = self.options.import_source.as_deref().unwrap_or("react");
+        let source = format!("{import_source}/{runtime}").into();
+        self.import_from(name, source)
+    }
+
+    /// Reference the local name of `import { name as _name } from "source"`.
+    fn import_from(&mut self, name: &'static str, source: Atom) -> Expression<'a> {
+        let local = if let Some((_, local, _)) =
+            self.imports.iter().find(|(imported, _, s)| *imported == name && *s == source)
+        {
+            local.clone()
+        } else {
+            let local = self.ctx.scopes().generate_uid(name);
+            self.ctx.add_binding(local.clone());
+            self.imports.push((name, local.clone(), source));
+            local
+        };
+        self.identifier(local)
+    }
+
+    /// `React.createElement` -> `React.createElement` as a member expression
+    fn pragma(&self, pragma: &str) -> Expression<'a> {
+        let mut parts = pragma.split('.');
+        let object = parts.next().unwrap_or_default();
+        let mut expr = if object == "this" {
+            self.ast.this_expression(Span::default())
+        } else {
+            self.identifier(object.into())
+        };
+        for property in parts {
+            let property = IdentifierName::new(Span::default(), property.into());
+            expr = self.ast.static_member_expression(Span::default(), expr, property, false);
+        }
+        expr
+    }
+
+    /// Lowercase names are intrinsic elements: `<div>` -> `"div"`, `<Foo>` -> `Foo`
+    fn element_name(&self, name: JSXElementName<'a>) -> Expression<'a> {
+        match name {
+            JSXElementName::Identifier(ident) => {
+                let name = ident.name;
+                if name == "this" {
+                    self.ast.this_expression(Span::default())
+                } else if name.starts_with(|c: char| c.is_ascii_lowercase()) || name.contains('-') {
+                    self.string_literal(name)
+                } else {
+                    self.identifier(name)
+                }
+            }
+            JSXElementName::NamespacedName(name) => self
+                .string_literal(format!("{}:{}", name.namespace.name, name.property.name).into()),
+            JSXElementName::MemberExpression(expr) => self.member_expression(expr.unbox()),
+        }
+    }
+
+    fn member_expression(&self, expr: JSXMemberExpression<'a>) -> Expression<'a> {
+        let object = match expr.object {
+            JSXMemberExpressionObject::Identifier(ident) if ident.name == "this" => {
+                self.ast.this_expression(Span::default())
+            }
+            JSXMemberExpressionObject::Identifier(ident) => self.identifier(ident.name),
+            JSXMemberExpressionObject::MemberExpression(expr) => {
+                self.member_expression(expr.unbox())
+            }
+        };
+        let property = IdentifierName::new(Span::default(), expr.property.name);
+        self.ast.static_member_expression(Span::default(), object, property, false)
+    }
+
+    /// The props object, spread attributes are merged with `babelHelpers.extends`:
+    /// `<a b="c" {...d} />` -> `babelHelpers.extends({ b: "c" }, d)`
+    fn attributes(
+        &mut self,
+        attributes: Vec<'a, JSXAttributeItem<'a>>,
+        dev_properties: Vec<'a, ObjectPropertyKind<'a>>,
+    ) -> Expression<'a> {
+        let span = Span::default();
+        let mut objects = self.ast.new_vec();
+        let mut properties = self.ast.new_vec();
+        let mut single_spread_object = false;
+        let count = attributes.len();
+        for attribute in attributes {
+            match attribute {
+                JSXAttributeItem::Attribute(attribute) => {
+                    properties.push(self.attribute(attribute.unbox()));
+                }
+                JSXAttributeItem::SpreadAttribute(spread) => {
+                    let argument = spread.unbox().argument;
+                    if self.options.use_spread {
+                        properties.push(ObjectPropertyKind::SpreadProperty(
+                            self.ast.spread_element(span, argument),
+                        ));
+                        continue;
+                    }
+                    if !properties.is_empty() {
+                        let properties = mem::replace(&mut properties, self.ast.new_vec());
+                        objects.push(self.ast.object_expression(span, properties, None));
+                    }
+                    single_spread_object =
+                        count == 1 && matches!(argument, Expression::ObjectExpression(_));
+                    objects.push(argument);
+                }
+            }
+        }
+        properties.extend(dev_properties);
+        if !properties.is_empty() {
+            objects.push(self.ast.object_expression(span, properties, None));
+        }
+
+        if objects.is_empty() {
+            return self.ast.literal_null_expression(NullLiteral::new(span));
+        }
+        // `<a {...b} />` -> `b`
+        if objects.len() == 1 && !single_spread_object {
+            return objects.pop().unwrap();
+        }
+        if !matches!(objects.first(), Some(Expression::ObjectExpression(_))) {
+            objects.insert(0, self.ast.object_expression(span, self.ast.new_vec(), None));
+        }
+        let mut arguments = self.ast.new_vec_with_capacity(objects.len());
+        arguments.extend(objects.into_iter().map(Argument::Expression));
+        if self.options.use_built_ins {
+            let callee = self.pragma("Object.assign");
+            self.ast.call_expression(span, callee, arguments, false, None)
+        } else {
+            self.ctx.helper_call("extends", arguments)
+        }
+    }
+
+    /// `b="c"` -> `b: "c"`, `b` -> `b: true`
+    fn attribute(&mut self, attribute: JSXAttribute<'a>) -> ObjectPropertyKind<'a> {
+        let name = match attribute.name {
+            JSXAttributeName::Identifier(ident) => ident.name,
+            JSXAttributeName::NamespacedName(name) => {
+                format!("{}:{}", name.namespace.name, name.property.name).into()
+            }
+        };
+        let value = self.attribute_value(attribute.value);
+        self.property(name, value)
+    }
+
+    fn attribute_value(&mut self, value: Option<JSXAttributeValue<'a>>) -> Expression<'a> {
+        let span = Span::default();
+        match value {
+            None => self.ast.literal_boolean_expression(BooleanLiteral { span, value: true }),
+            Some(JSXAttributeValue::StringLiteral(lit)) => {
+                let value = decode_entities(&lit.value);
+                // Line breaks with the indentation after them are collapsed to a space.
+                let value =
+                    value.split('\n').enumerate().fold(String::new(), |mut acc, (i, line)| {
+                        if i > 0 {
+                            acc = acc.trim_end().to_string();
+                            acc.push(' ');
+                            acc.push_str(line.trim_start());
+                        } else {
+                            acc.push_str(line);
+                        }
+                        acc
+                    });
+                self.string_literal(value.into())
+            }
+            Some(JSXAttributeValue::ExpressionContainer(container)) => match container.expression {
+                JSXExpression::Expression(expr) => expr,
+                JSXExpression::EmptyExpression(_) => {
+                    self.ast.literal_boolean_expression(BooleanLiteral { span, value: true })
+                }
+            },
+            Some(JSXAttributeValue::Element(element)) => self.transform_element(element.unbox()),
+            Some(JSXAttributeValue::Fragment(fragment)) => {
+                self.transform_fragment(fragment.unbox())
+            }
+        }
+    }
+
+    fn property(&self, name: Atom, value: Expression<'a>) -> ObjectPropertyKind<'a> {
+        let span = Span::default();
+        // `__proto__` would set the prototype of the props object.
+        let (key, computed) = if name == "__proto__" {
+            (PropertyKey::Expression(self.string_literal(name)), true)
+        } else if Self::is_identifier_name(&name) {
+            (PropertyKey::Identifier(self.ast.alloc(IdentifierName::new(span, name))), false)
+        } else {
+            (PropertyKey::Expression(self.string_literal(name)), false)
+        };
+        ObjectPropertyKind::ObjectProperty(self.ast.object_property(
+            span,
+            PropertyKind::Init,
+            key,
+            value,
+            None,
+            false,
+            false,
+            computed,
+        ))
+    }
+
+    fn children(&mut self, children: Vec<'a, JSXChild<'a>>) -> Vec<'a, Argument<'a>> {
+        let mut arguments = self.ast.new_vec_with_capacity(children.len());
+        for child in children {
+            let argument = match child {
+                JSXChild::Text(text) => {
+                    let Some(text) = clean_jsx_text(&text.value) else { continue };
+                    Argument::Expression(self.string_literal(text.into()))
+                }
+                JSXChild::Element(element) => {
+                    Argument::Expression(self.transform_element(element.unbox()))
+                }
+                JSXChild::Fragment(fragment) => {
+                    Argument::Expression(self.transform_fragment(fragment.unbox()))
+                }
+                JSXChild::ExpressionContainer(container) => match container.expression {
+                    JSXExpression::Expression(expr) => Argument::Expression(expr),
+                    JSXExpression::EmptyExpression(_) => continue,
+                },
+                JSXChild::Spread(spread) => Argument::SpreadElement(
+                    self.ast.spread_element(Span::default(), spread.expression),
+                ),
+            };
+            arguments.push(argument);
+        }
+        arguments
+    }
+
+    fn is_identifier_name(name: &str) -> bool {
+        let mut chars = name.chars();
+        chars.next().is_some_and(is_identifier_start_all) && chars.all(is_identifier_part)
+    }
+
+    fn identifier(&self, name: Atom) -> Expression<'a> {
+        self.ast.identifier_reference_expression(IdentifierReference::new(Span::default(), name))
+    }
+
+    fn string_literal(&self, value: Atom) -> Expression<'a> {
+        self.ast.literal_string_expression(StringLiteral::new(Span::default(), value))
+    }
+
+    fn number(&self, value: usize) -> Expression<'a> {
+        let raw = self.ast.new_str(&value.to_string());
+        #[allow(clippy::cast_precision_loss)]
+        let literal = NumberLiteral::new(Span::default(), value as f64, raw, NumberBase::Decimal);
+        self.ast.literal_number_expression(literal)
+    }
+}
+
+/// Trims the whitespace of JSX text the way React does: lines are trimmed, and lines which are
+/// not empty are joined with a space.
+/// <https://github.com/babel/babel/blob/main/packages/babel-types/src/utils/react/cleanJSXElementLiteralChild.ts>
+fn clean_jsx_text(text: &str) -> Option<String> {
+    let lines = text.split(['\n', '\r']).collect::<std::vec::Vec<_>>();
+    let last_non_empty_line =
+        lines.iter().rposition(|line| line.contains(|c| c != ' ' && c != '\t')).unwrap_or(0);
+    let mut result = String::new();
+    for (i, line) in lines.iter().enumerate() {
+        let mut line = line.replace('\t', " ");
+        if i != 0 {
+            line = line.trim_start_matches(' ').to_string();
+        }
+        if i != lines.len() - 1 {
+            line = line.trim_end_matches(' ').to_string();
+        }
+        if !line.is_empty() {
+            result.push_str(&line);
+            if i != last_non_empty_line {
+                result.push(' ');
+            }
+        }
+    }
+    (!result.is_empty()).then(|| decode_entities(&result))
+}
+
+/// Decodes the HTML entities of JSX text: `&amp;` -> `&`, `&#123;` -> `{`
+fn decode_entities(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        result.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let decoded = rest.find(';').and_then(|end| {
+            let entity = &rest[1..end];
+            let c = match entity.strip_prefix('#') {
+                Some(hex) if hex.starts_with('x') => {
+                    u32::from_str_radix(&hex[1..], 16).ok().and_then(char::from_u32)
+                }
+                Some(decimal) => decimal.parse::<u32>().ok().and_then(char::from_u32),
+                None => named_entity(entity),
+            };
+            c.map(|c| (c, end))
+        });
+        if let Some((c, end)) = decoded {
+            result.push(c);
+            rest = &rest[end + 1..];
+        } else {
+            result.push('&');
+            rest = &rest[1..];
+        }
+    }
+    result.push_str(rest);
+    result
+}
+
+fn named_entity(name: &str) -> Option<char> {
+    let c = match name {
+        "quot" => '"',
+        "amp" => '&',
+        "apos" => '\'',
+        "lt" => '<',
+        "gt" => '>',
+        "nbsp" => '\u{a0}',
+        "copy" => '©',
+        "reg" => '®',
+        "trade" => '™',
+        "deg" => '°',
+        "plusmn" => '±',
+        "times" => '×',
+        "divide" => '÷',
+        "middot" => '·',
+        "bull" => '•',
+        "hellip" => '…',
+        "ndash" => '–',
+        "mdash" => '—',
+        "lsquo" => '‘',
+        "rsquo" => '’',
+        "ldquo" => '“',
+        "rdquo" => '”',
+        "laquo" => '«',
+        "raquo" => '»',
+        "euro" => '€',
+        "pound" => '£',
+        "yen" => '¥',
+        "cent" => '¢',
+        "sect" => '§',
+        "para" => '¶',
+        "larr" => '←',
+        "rarr" => '→',
+        "uarr" => '↑',
+        "darr" => '↓',
+        "hearts" => '♥',
+        "zwj" => '\u{200d}',
+        "zwnj" => '\u{200c}',
+        _ => return None,
+    };
+    Some(c)
+}
+
+#[test]
+#[rustfmt::skip]
+fn test() {
+    use crate::tester::Tester;
+
+    let options = TransformOptions {
+        react_jsx: Some(ReactJsxOptions::default()),
+        ..TransformOptions::default()
+    };
+    Tester::new("test.jsx", options).test(&[
+        ("<div />", "React.createElement('div', null);"),
+        ("<Foo.Bar a=\"b\" c d={e} aria-label='f' />", "React.createElement(Foo.Bar, { a: 'b', c: true, d: e, 'aria-label': 'f' });"),
+        ("<a {...b} />", "React.createElement('a', b);"),
+        ("<a {...b} c=\"d\" {...e} />", "React.createElement('a', babelHelpers.extends({}, b, { c: 'd' }, e));"),
+        ("<a>\n  Hello &amp; {name}\n  <b>&#33;</b>{/* comment */}\n</a>", "React.createElement('a', null, 'Hello & ', name, React.createElement('b', null, '!'));"),
+        ("<>  a  b  <this.c /></>", "React.createElement(React.Fragment, null, '  a  b  ', React.createElement(this.c, null));"),
+        ("<a __proto__={b} x:y=\"z\" />", "React.createElement('a', { ['__proto__']: b, 'x:y': 'z' });"),
+    ]);
+
+    let options = TransformOptions {
+        react_jsx: Some(ReactJsxOptions {
+            pragma: Some("h".into()),
+            pragma_frag: Some("Fragment".into()),
+            use_built_ins: true,
+            ..ReactJsxOptions::default()
+        }),
+        ..TransformOptions::default()
+    };
+    Tester::new("test.jsx", options).test(&[
+        ("<><a {...b} c /></>", "h(Fragment, null, h('a', Object.assign({}, b, { c: true })));"),
+    ]);
+
+    let options = TransformOptions {
+        react_jsx: Some(ReactJsxOptions { use_spread: true, ..ReactJsxOptions::default() }),
+        ..TransformOptions::default()
+    };
+    Tester::new("test.jsx", options).test(&[
+        ("<a {...b} c />", "React.createElement('a', { ...b, c: true });"),
+    ]);
+
+    let options = TransformOptions {
+        react_jsx: Some(ReactJsxOptions { runtime: ReactJsxRuntime::Automatic, ..ReactJsxOptions::default() }),
+        ..TransformOptions::default()
+    };
+    Tester::new("test.jsx", options).test(&[
+        ("<div key=\"a\" {...b} c />", "import { jsx as _jsx } from 'react/jsx-runtime';\n_jsx('div', { ...b, c: true }, 'a');"),
+        ("<A>a {b}<>c</></A>", "import { Fragment as _Fragment } from 'react/jsx-runtime';\nimport { jsx as _jsx } from 'react/jsx-runtime';\nimport { jsxs as _jsxs } from 'react/jsx-runtime';\n_jsxs(A, { children: ['a ', b, _jsx(_Fragment, { children: 'c' })] });"),
+        ("<div {...a} key={b}>c</div>", "import { createElement as _createElement } from 'react';\n_createElement('div', { ...a, key: b }, 'c');"),
+    ]);
+
+    let options = TransformOptions {
+        react_jsx: Some(ReactJsxOptions {
+            runtime: ReactJsxRuntime::Automatic,
+            import_source: Some("preact".into()),
+            ..ReactJsxOptions::default()
+        }),
+        ..TransformOptions::default()
+    };
+    Tester::new("test.jsx", options).test(&[
+        ("const jsx = <></>;", "import { Fragment as _Fragment } from 'preact/jsx-runtime';\nimport { jsx as _jsx } from 'preact/jsx-runtime';\nconst jsx = _jsx(_Fragment, {});"),
+    ]);
+
+    let options = TransformOptions {
+        react_jsx: Some(ReactJsxOptions { development: true, ..ReactJsxOptions::default() }),
+        ..TransformOptions::default()
+    };
+    Tester::new("test.jsx", options).test(&[
+        ("<a {...b}>\n  <c />\n</a>", "var _jsxFileName = 'test.jsx';\nReact.createElement('a', babelHelpers.extends({}, b, { __self: this, __source: { fileName: _jsxFileName, lineNumber: 1, columnNumber: 1 } }), React.createElement('c', { __self: this, __source: { fileName: _jsxFileName, lineNumber: 2, columnNumber: 3 } }));"),
+    ]);
+
+    let options = TransformOptions {
+        react_jsx: Some(ReactJsxOptions {
+            runtime: ReactJsxRuntime::Automatic,
+            development: true,
+            ..ReactJsxOptions::default()
+        }),
+        ..TransformOptions::default()
+    };
+    Tester::new("test.jsx", options.clone()).test(&[
+        ("const a = <>\n  <b key=\"c\" />d</>;", "import { Fragment as _Fragment } from 'react/jsx-dev-runtime';\nimport { jsxDEV as _jsxDEV } from 'react/jsx-dev-runtime';\nvar _jsxFileName = 'test.jsx';\nconst a = _jsxDEV(_Fragment, { children: [_jsxDEV('b', {}, 'c', false, { fileName: _jsxFileName, lineNumber: 2, columnNumber: 3 }, this), 'd'] }, void 0, true, { fileName: _jsxFileName, lineNumber: 1, columnNumber: 11 }, this);"),
+    ]);
+
+    // The `fileName` is the path of the source file for `transform`, `""` without a path
+    for (source_file_name, file_name) in [(Some("src/a.jsx"), "'src/a.jsx'"), (None, "''")] {
+        let options = TransformOptions { source_file_name: source_file_name.map(Into::into), ..options.clone() };
+        let ret = crate::transform("<a />", SourceType::default().with_jsx(true), options);
+        assert!(ret.code.contains(&format!("var _jsxFileName = {file_name};")), "{}", ret.code);
+        assert!(ret.code.contains("fileName:_jsxFileName,\n\tlineNumber:1,\n\tcolumnNumber:1\n"), "{}", ret.code);
+    }
+
+    for jsx in [JsxMode::Preserve, JsxMode::ReactNative] {
+        let options = TransformOptions {
+            jsx,
+            react_jsx: Some(ReactJsxOptions::default()),
+            ..TransformOptions::default()
+        };
+        Tester::new("test.tsx", options).test(&[
+            ("const a: A = <A b=\"c\">{d}</A>;", "const a = <A b=\"c\">{d}</A>;"),
+        ]);
     }
+    assert_eq!(JsxMode::Preserve.output_extension(SourceType::from_path("test.tsx").unwrap()), "jsx");
+    assert_eq!(JsxMode::Preserve.output_extension(SourceType::from_path("test.ts").unwrap()), "js");
+    assert_eq!(JsxMode::ReactNative.output_extension(SourceType::from_path("test.tsx").unwrap()), "js");
 }
@@ -1,3 +1,5 @@
+mod regexp_features;
 mod regexp_flags;
 
+pub use regexp_features::RegexpFeatures;
 pub use regexp_flags::RegexpFlags;
@@ -0,0 +1,364 @@
+use oxc_ast::{ast::*, AstBuilder};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_regexp_parser::{ast::CapturingGroup, Parser, ParserOptions, Visit};
+use oxc_span::Span;
+use oxc_syntax::NumberBase;
+use regex_syntax::hir::{Class, HirKind};
+
+use std::{fmt::Write, rc::Rc};
+
+use crate::{context::TransformerCtx, targets::Feature, TransformOptions, TransformTarget};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("The {0} of this regular expression can't be transformed for the targets")]
+#[diagnostic(help("Rewrite the pattern without it, or target engines which support it"))]
+struct RegExpUnsupported(&'static str, #[label] Span);
+
+/// Rewrites regex literals using syntax unsupported by the targets into ES5 patterns.
+///
+/// * ES2018 [Dotall s](https://babel.dev/docs/babel-plugin-transform-dotall-regex):
+///   `/a.b/s` -> `/a[\s\S]b/`
+/// * ES2018 [Named Capturing Groups](https://babel.dev/docs/babel-plugin-transform-named-capturing-groups-regex):
+///   `/(?<year>\d+)-\k<year>/` -> `babelHelpers.wrapRegExp(/(\d+)-\1/, { year: 1 })`
+/// * ES2018 [Unicode Property Escapes](https://babel.dev/docs/babel-plugin-transform-unicode-property-regex):
+///   `/\p{ASCII}/u` -> `/[\x00-\x7F]/u`, with the Unicode data of `regex-syntax`
+/// * ES2025 [Duplicate Named Capturing Groups](https://babel.dev/docs/babel-plugin-transform-duplicate-named-capturing-groups-regex):
+///   `/(?<x>a)|(?<x>b)/` -> `babelHelpers.wrapRegExp(/(a)|(b)/, { x: [1, 2] })`
+///
+/// ES2018 [Lookbehind Assertions](https://github.com/tc39/proposal-regexp-lookbehind) have no
+/// equivalent, they are reported as errors like the unknown Unicode properties.
+pub struct RegexpFeatures<'a> {
+    ast: Rc<AstBuilder<'a>>,
+    ctx: TransformerCtx<'a>,
+    dotall: bool,
+    named_groups: bool,
+    unicode_property: bool,
+    lookbehind: bool,
+    duplicate_named_groups: bool,
+}
+
+/// A pattern rewritten by [RegexpFeatures].
+struct Rewritten {
+    pattern: String,
+    flags: RegExpFlags,
+    /// The named groups removed, with their index.
+    names: Vec<(String, u32)>,
+    /// The syntax unsupported by the targets which is left in the pattern.
+    unsupported: Option<&'static str>,
+}
+
+impl<'a> RegexpFeatures<'a> {
+    pub fn new(
+        ast: Rc<AstBuilder<'a>>,
+        ctx: TransformerCtx<'a>,
+        options: &TransformOptions,
+    ) -> Option<Self> {
+        let target = options.target;
+        let targets = &options.targets;
+        let es2018 = target < TransformTarget::ES2018;
+        let dotall = es2018 || targets.needs_transform(Feature::DotallRegex);
+        let named_groups = es2018 || targets.needs_transform(Feature::NamedCaptureGroupsRegex);
+        let unicode_property = es2018 || targets.needs_transform(Feature::UnicodePropertyRegex);
+        let lookbehind = es2018 || targets.needs_transform(Feature::LookbehindRegex);
+        let duplicate_named_groups = target < TransformTarget::ES2025
+            || targets.needs_transform(Feature::DuplicateNamedCaptureGroupsRegex);
+        (dotall || named_groups || unicode_property || lookbehind || duplicate_named_groups).then(
+            || Self {
+                ast,
+                ctx,
+                dotall,
+                named_groups,
+                unicode_property,
+                lookbehind,
+                duplicate_named_groups,
+            },
+        )
+    }
+
+    pub fn transform_expression(&self, expr: &mut Expression<'a>) {
+        let Expression::RegExpLiteral(literal) = expr else { return };
+        let Some(rewritten) = self.rewrite(&literal.regex) else { return };
+        let span = literal.span;
+        if let Some(syntax) = rewritten.unsupported {
+            self.ctx.error(RegExpUnsupported(syntax, span));
+            return;
+        }
+
+        let literal = self.ast.reg_exp_literal(span, rewritten.pattern.into(), rewritten.flags);
+        let regex = self.ast.literal_regexp_expression(literal);
+        if rewritten.names.is_empty() {
+            *expr = regex;
+            return;
+        }
+
+        // babelHelpers.wrapRegExp(regex, { name: index, duplicate: [index, index] })
+        let mut groups: std::vec::Vec<(String, std::vec::Vec<u32>)> = vec![];
+        for (name, index) in rewritten.names {
+            match groups.iter_mut().find(|(n, _)| *n == name) {
+                Some((_, indices)) => indices.push(index),
+                None => groups.push((name, vec![index])),
+            }
+        }
+        let mut properties = self.ast.new_vec_with_capacity(groups.len());
+        for (name, indices) in groups {
+            let key = IdentifierName::new(Span::default(), name.into());
+            let key = PropertyKey::Identifier(self.ast.alloc(key));
+            let value = if let [index] = indices[..] {
+                self.number(index)
+            } else {
+                let mut elements = self.ast.new_vec_with_capacity(indices.len());
+                for index in indices {
+                    elements.push(ArrayExpressionElement::Expression(self.number(index)));
+                }
+                self.ast.array_expression(Span::default(), elements, None)
+            };
+            let property = self.ast.object_property(
+                Span::default(),
+                PropertyKind::Init,
+                key,
+                value,
+                None,
+                false,
+                false,
+                false,
+            );
+            properties.push(ObjectPropertyKind::ObjectProperty(property));
+        }
+        let mut arguments = self.ast.new_vec_with_capacity(2);
+        arguments.push(Argument::Expression(regex));
+        arguments.push(Argument::Expression(self.ast.object_expression(
+            Span::default(),
+            properties,
+            None,
+        )));
+        *expr = self.ctx.helper_call("wrapRegExp", arguments);
+    }
+
+    fn rewrite(&self, regex: &RegExp) -> Option<Rewritten> {
+        // The invalid patterns are left as they are.
+        let options = ParserOptions::new(regex.flags);
+        let pattern = Parser::new(regex.pattern.as_str(), options).parse().ok()?;
+        let mut names = CaptureGroupNames::default();
+        names.visit_pattern(&pattern);
+        let names = names.0;
+
+        let chars = regex.pattern.chars().collect::<std::vec::Vec<_>>();
+        let dotall = self.dotall && regex.flags.contains(RegExpFlags::S);
+        let unicode = regex.flags.contains(RegExpFlags::U);
+        let has_duplicates = names
+            .iter()
+            .enumerate()
+            .any(|(i, (name, _))| names[..i].iter().any(|(n, _)| n == name));
+        let names = if self.named_groups || (self.duplicate_named_groups && has_duplicates) {
+            names
+        } else {
+            vec![]
+        };
+
+        let mut pattern = String::with_capacity(regex.pattern.len());
+        let mut unsupported = None;
+        let mut in_class = false;
+        let mut i = 0;
+        while i < chars.len() {
+            let c = chars[i];
+            match c {
+                '\\' => match chars.get(i + 1) {
+                    // `\k<name>` -> `\1`, `(?:\1\2)` for a name shared by the groups 1 and 2,
+                    // only one of them matched so the others are empty.
+                    Some('k') if !names.is_empty() && chars.get(i + 2) == Some(&'<') => {
+                        let end = find(&chars, i + 3, '>');
+                        let name = chars[i + 3..end].iter().collect::<String>();
+                        let indices = names
+                            .iter()
+                            .filter(|(n, _)| *n == name)
+                            .map(|(_, index)| index.to_string())
+                            .collect::<std::vec::Vec<_>>();
+                        match indices.len() {
+                            0 => pattern.extend(&chars[i..=end.min(chars.len() - 1)]),
+                            1 => pattern.push_str(&format!("\\{}", indices[0])),
+                            _ => pattern.push_str(&format!("(?:\\{})", indices.join("\\"))),
+                        }
+                        i = end + 1;
+                        continue;
+                    }
+                    // `\p{ASCII}` -> `[\x00-\x7F]`
+                    Some(&p @ ('p' | 'P'))
+                        if self.unicode_property && unicode && chars.get(i + 2) == Some(&'{') =>
+                    {
+                        let end = find(&chars, i + 3, '}');
+                        let name = chars[i + 3..end].iter().collect::<String>();
+                        match unicode_property_ranges(&name, p == 'P') {
+                            Some(ranges) if in_class => pattern.push_str(&ranges),
+                            Some(ranges) => {
+                                pattern.push('[');
+                                pattern.push_str(&ranges);
+                                pattern.push(']');
+                            }
+                            None => {
+                                unsupported.get_or_insert("Unicode property escape");
+                                pattern.extend(&chars[i..=end.min(chars.len() - 1)]);
+                            }
+                        }
+                        i = end + 1;
+                        continue;
+                    }
+                    Some(&next) => {
+                        pattern.push(c);
+                        pattern.push(next);
+                        i += 2;
+                        continue;
+                    }
+                    None => pattern.push(c),
+                },
+                '[' if !in_class => {
+                    in_class = true;
+                    pattern.push(c);
+                }
+                ']' if in_class => {
+                    in_class = false;
+                    pattern.push(c);
+                }
+                '.' if dotall && !in_class => pattern.push_str("[\\s\\S]"),
+                '(' if !in_class
+                    && chars.get(i + 1) == Some(&'?')
+                    && chars.get(i + 2) == Some(&'<') =>
+                {
+                    match chars.get(i + 3) {
+                        Some('=' | '!') => {
+                            if self.lookbehind {
+                                unsupported.get_or_insert("lookbehind assertion");
+                            }
+                            pattern.push(c);
+                        }
+                        // `(?<name>` -> `(`
+                        _ if !names.is_empty() => {
+                            pattern.push(c);
+                            i = find(&chars, i + 3, '>') + 1;
+                            continue;
+                        }
+                        _ => pattern.push(c),
+                    }
+                }
+                _ => pattern.push(c),
+            }
+            i += 1;
+        }
+
+        let mut flags = regex.flags;
+        if dotall {
+            flags.remove(RegExpFlags::S);
+        }
+        (pattern != regex.pattern.as_str() || flags != regex.flags || unsupported.is_some())
+            .then_some(Rewritten { pattern, flags, names, unsupported })
+    }
+
+    fn number(&self, value: u32) -> Expression<'a> {
+        let raw = self.ast.new_str(&value.to_string());
+        let literal =
+            self.ast.number_literal(Span::default(), f64::from(value), raw, NumberBase::Decimal);
+        self.ast.literal_number_expression(literal)
+    }
+}
+
+/// The index of `target` from `start`, or the end of the pattern.
+fn find(chars: &[char], start: usize, target: char) -> usize {
+    chars.iter().skip(start).position(|c| *c == target).map_or(chars.len(), |i| start + i)
+}
+
+/// The names of the named capturing groups, with their index among all the capturing groups.
+#[derive(Default)]
+struct CaptureGroupNames(std::vec::Vec<(String, u32)>);
+
+impl Visit for CaptureGroupNames {
+    fn visit_capturing_group(&mut self, group: &CapturingGroup) {
+        if let Some(name) = &group.name {
+            self.0.push((name.clone(), group.index));
+        }
+        self.visit_alternatives(&group.alternatives);
+    }
+}
+
+/// The class ranges of a Unicode property, e.g. `Script=Greek`, or of its complement.
+fn unicode_property_ranges(name: &str, negated: bool) -> Option<String> {
+    let hir = regex_syntax::Parser::new().parse(&format!("\\p{{{name}}}")).ok()?;
+    let mut class = match hir.into_kind() {
+        HirKind::Class(Class::Unicode(class)) => class,
+        // A property of a single character
+        HirKind::Literal(literal) => {
+            let c = std::str::from_utf8(&literal.0).ok()?.chars().next()?;
+            regex_syntax::hir::ClassUnicode::new([regex_syntax::hir::ClassUnicodeRange::new(c, c)])
+        }
+        _ => return None,
+    };
+    if negated {
+        class.negate();
+    }
+    // The lone surrogates are not characters, the ranges around them include them.
+    let mut ranges: std::vec::Vec<(u32, u32)> = vec![];
+    for range in class.iter() {
+        let (start, end) = (u32::from(range.start()), u32::from(range.end()));
+        match ranges.last_mut() {
+            Some(last) if last.1 == 0xD7FF && start == 0xE000 => last.1 = end,
+            _ => ranges.push((start, end)),
+        }
+    }
+    let mut class = String::new();
+    for (start, end) in ranges {
+        push_class_char(&mut class, start);
+        if end != start {
+            class.push('-');
+            push_class_char(&mut class, end);
+        }
+    }
+    Some(class)
+}
+
+/// Alphanumeric characters, or `\x7F`, `\uFFFF` and `\u{10FFFF}` escapes.
+fn push_class_char(class: &mut String, c: u32) {
+    match char::from_u32(c) {
+        Some(c) if c.is_ascii_alphanumeric() => class.push(c),
+        _ if c <= 0xFF => write!(class, "\\x{c:02X}").unwrap(),
+        _ if c <= 0xFFFF => write!(class, "\\u{c:04X}").unwrap(),
+        _ => write!(class, "\\u{{{c:X}}}").unwrap(),
+    }
+}
+
+#[test]
+#[rustfmt::skip]
+fn test() {
+    use crate::tester::Tester;
+
+    let options = TransformOptions { target: TransformTarget::ES2017, ..TransformOptions::default() };
+    let tests = &[
+        ("x = /a.b[.]/s;", "x = /a[\\s\\S]b[.]/;"),
+        ("x = /a.b/;", "x = /a.b/;"),
+        (
+            "x = /(?<year>\\d+)-(\\d+)-\\k<year>/;",
+            "x = babelHelpers.wrapRegExp(/(\\d+)-(\\d+)-\\1/, { year: 1 });",
+        ),
+        ("x = /\\p{ASCII}[\\p{AHex}_]\\P{Any}/u;", "x = /[\\x00-\\x7F][0-9A-Fa-f_][]/u;"),
+        ("x = /[^\\P{ASCII}]/u;", "x = /[^\\x80-\\u{10FFFF}]/u;"),
+        ("x = /\\p{Script=Ogham}+/u;", "x = /[\\u1680-\\u169C]+/u;"),
+        (
+            "x = /(?<y>\\d{4})-\\d|\\d-(?<y>\\d{4})\\k<y>/;",
+            "x = babelHelpers.wrapRegExp(/(\\d{4})-\\d|\\d-(\\d{4})(?:\\1\\2)/, { y: [1, 2] });",
+        ),
+    ];
+    Tester::new("test.js", options.clone()).test(tests);
+
+    // Lookbehind assertions and unknown properties can't be transformed
+    for source in ["x = /(?<=\\$)\\d+/;", "x = /\\p{Script=Garay}/u;"] {
+        let ret = crate::transform(source, oxc_span::SourceType::default(), options.clone());
+        assert_eq!(ret.errors.len(), 1, "{source}");
+    }
+
+    let options = TransformOptions { target: TransformTarget::ES2024, ..TransformOptions::default() };
+    Tester::new("test.js", options).test(&[
+        ("x = /(?<a>x)|(?<a>y)(?<b>z)/;", "x = babelHelpers.wrapRegExp(/(x)|(y)(z)/, { a: [1, 2], b: 3 });"),
+        ("x = /(?<a>x)|(?<b>y)/;", "x = /(?<a>x)|(?<b>y)/;"),
+        ("x = /(?<a>x)(?<a>y)/;", "x = /(?<a>x)(?<a>y)/;"),
+    ]);
+}
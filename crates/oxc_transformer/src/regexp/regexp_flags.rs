@@ -3,7 +3,7 @@ use oxc_span::{Atom, Span};
 
 use std::rc::Rc;
 
-use crate::{TransformOptions, TransformTarget};
+use crate::{targets::Feature, TransformOptions, TransformTarget};
 
 /// Transforms unsupported regex flags into Regex constructors.
 ///
@@ -28,19 +28,23 @@ impl<'a> RegexpFlags<'a> {
     fn from_transform_target(options: &TransformOptions) -> RegExpFlags {
         let target = options.target;
         let mut flag = RegExpFlags::empty();
-        if target < TransformTarget::ES2015 || options.sticky_regex {
+        let targets = &options.targets;
+        if target < TransformTarget::ES2015
+            || options.sticky_regex
+            || targets.needs_transform(Feature::StickyRegex)
+        {
             flag |= RegExpFlags::Y;
         }
-        if target < TransformTarget::ES2015 {
+        if target < TransformTarget::ES2015 || targets.needs_transform(Feature::UnicodeRegex) {
             flag |= RegExpFlags::U;
         }
-        if target < TransformTarget::ES2018 {
+        if target < TransformTarget::ES2018 || targets.needs_transform(Feature::DotallRegex) {
             flag |= RegExpFlags::S;
         }
-        if target < TransformTarget::ES2022 {
+        if target < TransformTarget::ES2022 || targets.needs_transform(Feature::MatchIndicesRegex) {
             flag |= RegExpFlags::D;
         }
-        if target < TransformTarget::ES2024 {
+        if target < TransformTarget::ES2024 || targets.needs_transform(Feature::UnicodeSetsRegex) {
             flag |= RegExpFlags::V;
         }
         if target < TransformTarget::ESNext {
@@ -66,6 +70,6 @@ impl<'a> RegexpFlags<'a> {
         let mut arguments = self.ast.new_vec_with_capacity(2);
         arguments.push(Argument::Expression(pattern_literal));
         arguments.push(Argument::Expression(flags_literal));
-        *expr = self.ast.new_expression(Span::default(), callee, arguments, None);
+        *expr = self.ast.new_expression(literal.span, callee, arguments, None);
     }
 }
@@ -0,0 +1,362 @@
+use std::{path::Path, rc::Rc};
+
+use oxc_allocator::Vec;
+use oxc_ast::{ast::*, AstBuilder};
+use oxc_span::{Atom, GetSpan, Span};
+use serde::Deserialize;
+
+use crate::{context::TransformerCtx, options::TransformOptions, utils::short_hash};
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct StyledComponentsOptions {
+    /// Add the name of the variable a component is assigned to as its `displayName`.
+    pub display_name: bool,
+    /// Prefix the `displayName` with the name of the file, `Button.js` -> `Button__Primary`.
+    pub file_name: bool,
+    /// Add a `componentId` stable between the server and the client render.
+    pub ssr: bool,
+    /// Remove the comments and the whitespace of the CSS in the templates.
+    pub minify: bool,
+    /// Annotate the components and the helpers with `/*#__PURE__*/` for the minifiers.
+    pub pure: bool,
+    /// The prefix of the `componentId`, to avoid conflicts between the libraries.
+    pub namespace: Option<String>,
+}
+
+impl Default for StyledComponentsOptions {
+    fn default() -> Self {
+        Self {
+            display_name: true,
+            file_name: true,
+            ssr: true,
+            minify: true,
+            pure: false,
+            namespace: None,
+        }
+    }
+}
+
+/// [babel-plugin-styled-components](https://styled-components.com/docs/tooling#babel-plugin)
+///
+/// * ``const Button = styled.div`color: red;` `` ->
+///   ``const Button = styled.div.withConfig({ displayName: "Button", componentId: "sc-1h74p5a-0" })`color:red;` ``
+///
+/// The components are the templates tagged with `styled.tag`, `styled(Component)` or their
+/// `.attrs()`, the helpers the ones tagged with `css`, `keyframes` and `createGlobalStyle`,
+/// imported from `styled-components`.
+pub struct StyledComponents<'a> {
+    ast: Rc<AstBuilder<'a>>,
+    ctx: TransformerCtx<'a>,
+    options: StyledComponentsOptions,
+    /// The local name of the default import.
+    styled: Option<Atom>,
+    /// The local names of the helpers.
+    helpers: std::vec::Vec<Atom>,
+    /// The `displayName` prefix, from the name of the file.
+    block_name: Option<String>,
+    file_hash: String,
+    /// The number of components with a `componentId`.
+    count: usize,
+    /// The span of the initializer of the variable visited and the name of the variable.
+    variable: Option<(Span, Atom)>,
+}
+
+impl<'a> StyledComponents<'a> {
+    pub fn new(
+        ast: Rc<AstBuilder<'a>>,
+        ctx: TransformerCtx<'a>,
+        options: &TransformOptions,
+    ) -> Option<Self> {
+        options.styled_components.clone().map(|options| Self {
+            ast,
+            ctx,
+            options,
+            styled: None,
+            helpers: vec![],
+            block_name: None,
+            file_hash: short_hash(""),
+            count: 0,
+            variable: None,
+        })
+    }
+
+    pub fn set_source(&mut self, source_path: &Path, source_text: &str) {
+        let file_stem = source_path.file_stem().map(|stem| stem.to_string_lossy().to_string());
+        // `Button/index.js` -> `Button`
+        self.block_name = if file_stem.as_deref() == Some("index") {
+            source_path
+                .parent()
+                .and_then(Path::file_name)
+                .map(|name| name.to_string_lossy().to_string())
+        } else {
+            file_stem
+        };
+        let path = source_path.to_string_lossy();
+        self.file_hash = short_hash(if path.is_empty() { source_text } else { &path });
+    }
+
+    pub fn collect_imports(&mut self, stmts: &Vec<'a, Statement<'a>>) {
+        for stmt in stmts {
+            let Statement::ModuleDeclaration(decl) = stmt else { continue };
+            let ModuleDeclaration::ImportDeclaration(decl) = &**decl else { continue };
+            if !decl.source.value.as_str().starts_with("styled-components") {
+                continue;
+            }
+            for specifier in &decl.specifiers {
+                match specifier {
+                    ImportDeclarationSpecifier::ImportDefaultSpecifier(s) => {
+                        self.styled = Some(s.local.name.clone());
+                    }
+                    ImportDeclarationSpecifier::ImportSpecifier(s) => {
+                        match s.imported.name().as_str() {
+                            "default" => self.styled = Some(s.local.name.clone()),
+                            "css" | "keyframes" | "createGlobalStyle" | "injectGlobal" => {
+                                self.helpers.push(s.local.name.clone());
+                            }
+                            _ => {}
+                        }
+                    }
+                    ImportDeclarationSpecifier::ImportNamespaceSpecifier(_) => {}
+                }
+            }
+        }
+    }
+
+    /// Record the name of ``const Name = styled.div``;`` for its `displayName`.
+    pub fn transform_variable_declarator(&mut self, declarator: &VariableDeclarator<'a>) {
+        if let (BindingPatternKind::BindingIdentifier(ident), Some(init)) =
+            (&declarator.id.kind, &declarator.init)
+        {
+            self.variable = Some((init.span(), ident.name.clone()));
+        }
+    }
+
+    pub fn transform_expression(&mut self, expr: &mut Expression<'a>) {
+        let Expression::TaggedTemplateExpression(tagged) = expr else { return };
+        let is_component = self.is_component(&tagged.tag);
+        if !is_component && !self.is_helper(&tagged.tag) {
+            return;
+        }
+
+        if self.options.minify {
+            for (i, quasi) in tagged.quasi.quasis.iter_mut().enumerate() {
+                let first = i == 0;
+                quasi.value.raw = minify(&quasi.value.raw, first, quasi.tail).into();
+                quasi.value.cooked = quasi
+                    .value
+                    .cooked
+                    .as_ref()
+                    .map(|cooked| minify(cooked, first, quasi.tail).into());
+            }
+        }
+        if is_component {
+            let name =
+                self.variable.take().filter(|(span, _)| *span == tagged.span).map(|(_, name)| name);
+            self.add_config(&mut tagged.tag, name);
+        }
+        if self.options.pure {
+            self.ctx.comments.borrow_mut().annotate_pure(tagged.span.start);
+        }
+    }
+
+    /// `styled.div` -> `styled.div.withConfig({ displayName: "Name", componentId: "sc-hash-0" })`
+    fn add_config(&mut self, tag: &mut Expression<'a>, name: Option<Atom>) {
+        let mut properties = self.ast.new_vec();
+        if self.options.display_name {
+            if let Some(name) = name {
+                let display_name = match &self.block_name {
+                    Some(block_name) if self.options.file_name && *block_name != name.as_str() => {
+                        format!("{block_name}__{name}")
+                    }
+                    _ => name.to_string(),
+                };
+                properties.push(self.string_property("displayName", display_name));
+            }
+        }
+        if self.options.ssr {
+            let namespace =
+                self.options.namespace.as_ref().map_or(String::new(), |ns| format!("{ns}__"));
+            let component_id = format!("{namespace}sc-{}-{}", self.file_hash, self.count);
+            self.count += 1;
+            properties.push(self.string_property("componentId", component_id));
+        }
+        if properties.is_empty() {
+            return;
+        }
+
+        let span = tag.span();
+        let object = self.ast.move_expression(tag);
+        let property = IdentifierName::new(Span::default(), "withConfig".into());
+        let callee = self.ast.static_member_expression(span, object, property, false);
+        let argument = self.ast.object_expression(Span::default(), properties, None);
+        let arguments = self.ast.new_vec_single(Argument::Expression(argument));
+        *tag = self.ast.call_expression(span, callee, arguments, false, None);
+    }
+
+    fn string_property(&self, key: &str, value: String) -> ObjectPropertyKind<'a> {
+        let key = IdentifierName::new(Span::default(), key.into());
+        let key = PropertyKey::Identifier(self.ast.alloc(key));
+        let value = StringLiteral::new(Span::default(), value.into());
+        let value = self.ast.literal_string_expression(value);
+        ObjectPropertyKind::ObjectProperty(self.ast.object_property(
+            Span::default(),
+            PropertyKind::Init,
+            key,
+            value,
+            None,
+            false,
+            false,
+            false,
+        ))
+    }
+
+    /// `styled.div`, `styled(Component)` and their `.attrs()`, without a `.withConfig()` yet.
+    fn is_component(&self, tag: &Expression<'a>) -> bool {
+        match tag {
+            Expression::MemberExpression(member) => match &**member {
+                MemberExpression::StaticMemberExpression(member) => self.is_styled(&member.object),
+                _ => false,
+            },
+            Expression::CallExpression(call) => match &call.callee {
+                Expression::MemberExpression(member) => match &**member {
+                    MemberExpression::StaticMemberExpression(member) => {
+                        member.property.name == "attrs" && self.is_component(&member.object)
+                    }
+                    _ => false,
+                },
+                callee => self.is_styled(callee),
+            },
+            _ => false,
+        }
+    }
+
+    fn is_styled(&self, expr: &Expression<'a>) -> bool {
+        matches!((expr, &self.styled), (Expression::Identifier(ident), Some(styled)) if ident.name == *styled)
+    }
+
+    fn is_helper(&self, tag: &Expression<'a>) -> bool {
+        matches!(tag, Expression::Identifier(ident) if self.helpers.contains(&ident.name))
+    }
+}
+
+/// Remove the comments and the whitespace of a part of the CSS, the `first` and the `last`
+/// parts are trimmed, the whitespace next to the interpolations is kept as a single space.
+fn minify(css: &str, first: bool, last: bool) -> String {
+    let chars = css.chars().collect::<std::vec::Vec<_>>();
+    let mut result = String::with_capacity(css.len());
+    let mut quote = None;
+    let mut space = false;
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        if let Some(q) = quote {
+            result.push(c);
+            if c == '\\' {
+                if let Some(&next) = chars.get(i + 1) {
+                    result.push(next);
+                    i += 1;
+                }
+            } else if c == q {
+                quote = None;
+            }
+            i += 1;
+            continue;
+        }
+        match c {
+            '/' if chars.get(i + 1) == Some(&'*') => {
+                let end = (i + 2..chars.len().saturating_sub(1))
+                    .find(|&j| chars[j] == '*' && chars[j + 1] == '/')
+                    .map_or(chars.len(), |j| j + 2);
+                i = end;
+                space = true;
+                continue;
+            }
+            // Line comments, not the `//` of URLs such as `url(http://...)`
+            '/' if chars.get(i + 1) == Some(&'/') && (i == 0 || chars[i - 1].is_whitespace()) => {
+                i = (i..chars.len()).find(|&j| chars[j] == '\n').unwrap_or(chars.len());
+                space = true;
+                continue;
+            }
+            c if c.is_whitespace() => space = true,
+            c => {
+                let punctuation = |c: char| matches!(c, '{' | '}' | ':' | ';' | ',');
+                let leading = first && result.is_empty();
+                if space && !leading && !punctuation(c) && !result.ends_with(punctuation) {
+                    result.push(' ');
+                }
+                space = false;
+                if c == '"' || c == '\'' {
+                    quote = Some(c);
+                }
+                result.push(c);
+            }
+        }
+        i += 1;
+    }
+    let leading = first && result.is_empty();
+    if space && !last && !leading && !result.ends_with([';', '{', '}']) {
+        result.push(' ');
+    }
+    result
+}
+
+#[test]
+#[rustfmt::skip]
+fn test() {
+    use crate::tester::Tester;
+
+    let options = TransformOptions {
+        styled_components: Some(StyledComponentsOptions::default()),
+        ..TransformOptions::default()
+    };
+    let tests = &[
+        (
+            "import styled from 'styled-components'; const Button = styled.div`\n  color: red; /* brand */\n  margin: 0 ${m}px;\n`;",
+            "import styled from 'styled-components'; const Button = styled.div.withConfig({ displayName: 'Button.test__Button', componentId: 'sc-jyp0xa-0' })`color:red;margin:0 ${m}px;`;",
+        ),
+        (
+            "import styled from 'styled-components'; const Link = styled(A).attrs({ href: '#' })`\n  background: url(http://a.b/c.png);\n`;",
+            "import styled from 'styled-components'; const Link = styled(A).attrs({ href: '#' }).withConfig({ displayName: 'Button.test__Link', componentId: 'sc-jyp0xa-0' })`background:url(http://a.b/c.png);`;",
+        ),
+        (
+            "import { css } from 'styled-components'; const mixin = css`\n  content: \"a  b\";\n`;",
+            "import { css } from 'styled-components'; const mixin = css`content:\"a  b\";`;",
+        ),
+        ("const Button = styled.div` color: red; `;", "const Button = styled.div` color: red; `;"),
+    ];
+    Tester::new("Button.test.js", options).test(tests);
+
+    // The `/*#__PURE__*/` annotations are returned with the comments.
+    let source_text = "import styled from 'styled-components'; export const Title = styled.h1``;";
+    let allocator = oxc_allocator::Allocator::default();
+    let source_type = oxc_span::SourceType::default().with_module(true);
+    let program = oxc_parser::Parser::new(&allocator, source_text, source_type).parse().program;
+    let semantic = oxc_semantic::SemanticBuilder::new(source_text, source_type).build(&program).semantic;
+    let (symbols, scopes) = semantic.into_symbol_table_and_scope_tree();
+    let program = allocator.alloc(program);
+    let options = TransformOptions {
+        styled_components: Some(StyledComponentsOptions {
+            file_name: false,
+            ssr: false,
+            pure: true,
+            ..StyledComponentsOptions::default()
+        }),
+        ..TransformOptions::default()
+    };
+    let comments = crate::Transformer::new(
+        &allocator,
+        source_type,
+        &Rc::new(std::cell::RefCell::new(symbols)),
+        &Rc::new(std::cell::RefCell::new(scopes)),
+        options,
+    )
+    .build(program);
+    let printed = oxc_codegen::Codegen::<false>::new(source_text.len(), oxc_codegen::CodegenOptions)
+        .with_comments(comments)
+        .build(program);
+    assert_eq!(
+        printed,
+        "import styled from 'styled-components';\nexport const Title = /*#__PURE__*/ styled.h1.withConfig({\n\tdisplayName:'Title'\n})``;\n",
+    );
+}
@@ -0,0 +1,258 @@
+use std::{fmt, str::FromStr};
+
+use rustc_hash::FxHashMap;
+use serde::Deserialize;
+
+/// The engines to compile for, e.g. `{ "chrome": "88", "safari": "13.1" }` or
+/// `"chrome >= 88, safari 13.1"`.
+///
+/// A transform runs when any of the engines doesn't support the syntax it lowers, like the
+/// `targets` option of `@babel/preset-env`. The browserslist queries are limited to
+/// `engine version` and `engine >= version` entries, the ones which need usage data such as
+/// `defaults` or `> 0.5%` are rejected.
+///
+/// References:
+/// * <https://babeljs.io/docs/options#targets>
+/// * <https://github.com/babel/babel/blob/main/packages/babel-compat-data/data/plugins.json>
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize)]
+#[serde(try_from = "RawTargets")]
+pub struct EngineTargets(Vec<(Engine, Version)>);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Engine {
+    Chrome,
+    Deno,
+    Edge,
+    Electron,
+    Firefox,
+    Ios,
+    Node,
+    Opera,
+    OperaMobile,
+    Safari,
+    Samsung,
+}
+
+/// `major.minor`, the patch version is not needed by the compat data.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Version(pub u16, pub u16);
+
+/// The syntax lowered by a transform, named after the Babel plugin.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Feature {
+    UnicodeSetsRegex,
+    ClassStaticBlock,
+    ClassProperties,
+    PrivateMethods,
+    MatchIndicesRegex,
+    ExplicitResourceManagement,
+    LogicalAssignmentOperators,
+    NumericSeparator,
+    NullishCoalescingOperator,
+    OptionalChaining,
+    DynamicImport,
+    BigInt,
+    ExportNamespaceFrom,
+    OptionalCatchBinding,
+    AsyncGeneratorFunctions,
+    DotallRegex,
+    NamedCaptureGroupsRegex,
+    UnicodePropertyRegex,
+    LookbehindRegex,
+    DuplicateNamedCaptureGroupsRegex,
+    ExponentiationOperator,
+    BlockScoping,
+    ComputedProperties,
+    Destructuring,
+    Literals,
+    ShorthandProperties,
+    Spread,
+    StickyRegex,
+    UnicodeRegex,
+}
+
+impl EngineTargets {
+    pub fn new(targets: Vec<(Engine, Version)>) -> Self {
+        Self(targets)
+    }
+
+    /// Parse a browserslist query, e.g. `"chrome >= 88, safari 13.1"`.
+    ///
+    /// # Errors
+    ///
+    /// * An entry is not an `engine version` pair of a known engine
+    pub fn from_query(query: &str) -> Result<Self, String> {
+        let mut targets = vec![];
+        for entry in query.split([',', '\n']).map(str::trim).filter(|s| !s.is_empty()) {
+            let mut parts = entry.split_whitespace();
+            let (Some(engine), Some(mut version)) = (parts.next(), parts.next()) else {
+                return Err(format!("Unsupported browserslist query: `{entry}`"));
+            };
+            if version == ">=" {
+                version = parts.next().unwrap_or_default();
+            }
+            if parts.next().is_some() {
+                return Err(format!("Unsupported browserslist query: `{entry}`"));
+            }
+            targets.push((engine.parse()?, version.parse()?));
+        }
+        Ok(Self(targets))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Whether any of the engines lacks support for `feature`, an engine missing from the
+    /// compat data doesn't support it.
+    pub fn needs_transform(&self, feature: Feature) -> bool {
+        self.is_unsupported(feature.supported_since())
+    }
+
+    /// Whether any of the engines is older than the versions in `supported`, the first ones to
+    /// support a feature.
+    pub fn is_unsupported(&self, supported: &[(Engine, Version)]) -> bool {
+        self.0.iter().any(|(engine, version)| {
+            supported.iter().find(|(e, _)| e == engine).map_or(true, |(_, since)| version < since)
+        })
+    }
+}
+
+impl Feature {
+    /// The first versions of each engine which support the feature.
+    #[rustfmt::skip]
+    fn supported_since(self) -> &'static [(Engine, Version)] {
+        use Engine::{
+            Chrome, Deno, Edge, Electron, Firefox, Ios, Node, Opera, OperaMobile, Safari, Samsung,
+        };
+        match self {
+            Self::UnicodeSetsRegex => &[(Chrome, Version(112, 0)), (Edge, Version(112, 0)), (Firefox, Version(116, 0)), (Safari, Version(17, 0)), (Node, Version(20, 0)), (Deno, Version(1, 32)), (Ios, Version(17, 0)), (Opera, Version(98, 0)), (OperaMobile, Version(75, 0)), (Electron, Version(24, 0))],
+            Self::ClassStaticBlock => &[(Chrome, Version(94, 0)), (Edge, Version(94, 0)), (Firefox, Version(93, 0)), (Safari, Version(16, 4)), (Node, Version(16, 11)), (Deno, Version(1, 14)), (Ios, Version(16, 4)), (Samsung, Version(17, 0)), (Opera, Version(80, 0)), (OperaMobile, Version(66, 0)), (Electron, Version(15, 0))],
+            Self::ClassProperties => &[(Chrome, Version(74, 0)), (Edge, Version(79, 0)), (Firefox, Version(90, 0)), (Safari, Version(14, 1)), (Node, Version(12, 0)), (Deno, Version(1, 0)), (Ios, Version(14, 5)), (Samsung, Version(11, 0)), (Opera, Version(62, 0)), (OperaMobile, Version(53, 0)), (Electron, Version(6, 0))],
+            Self::PrivateMethods => &[(Chrome, Version(84, 0)), (Edge, Version(84, 0)), (Firefox, Version(90, 0)), (Safari, Version(15, 0)), (Node, Version(14, 6)), (Deno, Version(1, 0)), (Ios, Version(15, 0)), (Samsung, Version(14, 0)), (Opera, Version(70, 0)), (OperaMobile, Version(60, 0)), (Electron, Version(10, 0))],
+            Self::MatchIndicesRegex => &[(Chrome, Version(90, 0)), (Edge, Version(90, 0)), (Firefox, Version(88, 0)), (Safari, Version(15, 0)), (Node, Version(16, 0)), (Deno, Version(1, 8)), (Ios, Version(15, 0)), (Samsung, Version(15, 0)), (Opera, Version(76, 0)), (OperaMobile, Version(64, 0)), (Electron, Version(12, 0))],
+            Self::ExplicitResourceManagement => &[(Chrome, Version(134, 0)), (Edge, Version(134, 0)), (Firefox, Version(141, 0)), (Node, Version(24, 0)), (Opera, Version(119, 0)), (Electron, Version(35, 0))],
+            Self::LogicalAssignmentOperators => &[(Chrome, Version(85, 0)), (Edge, Version(85, 0)), (Firefox, Version(79, 0)), (Safari, Version(14, 0)), (Node, Version(15, 0)), (Deno, Version(1, 2)), (Ios, Version(14, 0)), (Samsung, Version(14, 0)), (Opera, Version(71, 0)), (OperaMobile, Version(60, 0)), (Electron, Version(10, 0))],
+            Self::NullishCoalescingOperator => &[(Chrome, Version(80, 0)), (Edge, Version(80, 0)), (Firefox, Version(72, 0)), (Safari, Version(13, 1)), (Node, Version(14, 0)), (Deno, Version(1, 0)), (Ios, Version(13, 4)), (Samsung, Version(13, 0)), (Opera, Version(67, 0)), (OperaMobile, Version(57, 0)), (Electron, Version(8, 0))],
+            Self::OptionalChaining => &[(Chrome, Version(91, 0)), (Edge, Version(91, 0)), (Firefox, Version(74, 0)), (Safari, Version(13, 1)), (Node, Version(16, 9)), (Deno, Version(1, 9)), (Ios, Version(13, 4)), (Samsung, Version(16, 0)), (Opera, Version(77, 0)), (OperaMobile, Version(64, 0)), (Electron, Version(13, 0))],
+            Self::NumericSeparator => &[(Chrome, Version(75, 0)), (Edge, Version(79, 0)), (Firefox, Version(70, 0)), (Safari, Version(13, 0)), (Node, Version(12, 5)), (Deno, Version(1, 0)), (Ios, Version(13, 0)), (Samsung, Version(11, 0)), (Opera, Version(62, 0)), (OperaMobile, Version(54, 0)), (Electron, Version(6, 0))],
+            Self::BigInt => &[(Chrome, Version(67, 0)), (Edge, Version(79, 0)), (Firefox, Version(68, 0)), (Safari, Version(14, 0)), (Node, Version(10, 4)), (Deno, Version(1, 0)), (Ios, Version(14, 0)), (Samsung, Version(9, 0)), (Opera, Version(54, 0)), (OperaMobile, Version(48, 0)), (Electron, Version(4, 0))],
+            Self::DynamicImport => &[(Chrome, Version(63, 0)), (Edge, Version(79, 0)), (Firefox, Version(67, 0)), (Safari, Version(11, 1)), (Node, Version(13, 2)), (Deno, Version(1, 0)), (Ios, Version(11, 3)), (Samsung, Version(8, 0)), (Opera, Version(50, 0)), (OperaMobile, Version(46, 0)), (Electron, Version(3, 0))],
+            Self::ExportNamespaceFrom => &[(Chrome, Version(72, 0)), (Edge, Version(79, 0)), (Firefox, Version(80, 0)), (Safari, Version(14, 1)), (Node, Version(13, 2)), (Deno, Version(1, 0)), (Ios, Version(14, 5)), (Samsung, Version(11, 0)), (Opera, Version(60, 0)), (OperaMobile, Version(51, 0)), (Electron, Version(5, 0))],
+            Self::OptionalCatchBinding => &[(Chrome, Version(66, 0)), (Edge, Version(79, 0)), (Firefox, Version(58, 0)), (Safari, Version(11, 1)), (Node, Version(10, 0)), (Deno, Version(1, 0)), (Ios, Version(11, 3)), (Samsung, Version(9, 0)), (Opera, Version(53, 0)), (OperaMobile, Version(47, 0)), (Electron, Version(3, 0))],
+            Self::AsyncGeneratorFunctions => &[(Chrome, Version(63, 0)), (Edge, Version(79, 0)), (Firefox, Version(57, 0)), (Safari, Version(12, 0)), (Node, Version(10, 0)), (Deno, Version(1, 0)), (Ios, Version(12, 0)), (Samsung, Version(8, 0)), (Opera, Version(50, 0)), (OperaMobile, Version(46, 0)), (Electron, Version(3, 0))],
+            Self::DotallRegex => &[(Chrome, Version(62, 0)), (Edge, Version(79, 0)), (Firefox, Version(78, 0)), (Safari, Version(11, 1)), (Node, Version(8, 10)), (Deno, Version(1, 0)), (Ios, Version(11, 3)), (Samsung, Version(8, 0)), (Opera, Version(49, 0)), (OperaMobile, Version(46, 0)), (Electron, Version(3, 0))],
+            Self::NamedCaptureGroupsRegex | Self::UnicodePropertyRegex => &[(Chrome, Version(64, 0)), (Edge, Version(79, 0)), (Firefox, Version(78, 0)), (Safari, Version(11, 1)), (Node, Version(10, 0)), (Deno, Version(1, 0)), (Ios, Version(11, 3)), (Samsung, Version(9, 0)), (Opera, Version(51, 0)), (OperaMobile, Version(47, 0)), (Electron, Version(3, 0))],
+            Self::LookbehindRegex => &[(Chrome, Version(62, 0)), (Edge, Version(79, 0)), (Firefox, Version(78, 0)), (Safari, Version(16, 4)), (Node, Version(8, 10)), (Deno, Version(1, 0)), (Ios, Version(16, 4)), (Samsung, Version(8, 0)), (Opera, Version(49, 0)), (OperaMobile, Version(46, 0)), (Electron, Version(3, 0))],
+            Self::DuplicateNamedCaptureGroupsRegex => &[(Chrome, Version(126, 0)), (Edge, Version(126, 0)), (Firefox, Version(129, 0)), (Safari, Version(17, 4)), (Node, Version(23, 0)), (Ios, Version(17, 4)), (Opera, Version(112, 0)), (Electron, Version(31, 0))],
+            Self::ExponentiationOperator => &[(Chrome, Version(52, 0)), (Edge, Version(14, 0)), (Firefox, Version(52, 0)), (Safari, Version(10, 1)), (Node, Version(7, 0)), (Deno, Version(1, 0)), (Ios, Version(10, 3)), (Samsung, Version(6, 0)), (Opera, Version(39, 0)), (OperaMobile, Version(41, 0)), (Electron, Version(1, 3))],
+            Self::BlockScoping => &[(Chrome, Version(50, 0)), (Edge, Version(14, 0)), (Firefox, Version(53, 0)), (Safari, Version(11, 0)), (Node, Version(6, 0)), (Deno, Version(1, 0)), (Ios, Version(11, 0)), (Samsung, Version(5, 0)), (Opera, Version(37, 0)), (OperaMobile, Version(37, 0)), (Electron, Version(1, 1))],
+            Self::ComputedProperties => &[(Chrome, Version(44, 0)), (Edge, Version(12, 0)), (Firefox, Version(34, 0)), (Safari, Version(7, 1)), (Node, Version(4, 0)), (Deno, Version(1, 0)), (Ios, Version(8, 0)), (Samsung, Version(4, 0)), (Opera, Version(31, 0)), (OperaMobile, Version(32, 0)), (Electron, Version(0, 30))],
+            Self::Destructuring => &[(Chrome, Version(51, 0)), (Edge, Version(15, 0)), (Firefox, Version(53, 0)), (Safari, Version(10, 0)), (Node, Version(6, 5)), (Deno, Version(1, 0)), (Ios, Version(10, 0)), (Samsung, Version(5, 0)), (Opera, Version(38, 0)), (OperaMobile, Version(41, 0)), (Electron, Version(1, 2))],
+            Self::Literals => &[(Chrome, Version(44, 0)), (Edge, Version(12, 0)), (Firefox, Version(53, 0)), (Safari, Version(9, 0)), (Node, Version(4, 0)), (Deno, Version(1, 0)), (Ios, Version(9, 0)), (Samsung, Version(4, 0)), (Opera, Version(31, 0)), (OperaMobile, Version(32, 0)), (Electron, Version(0, 30))],
+            Self::ShorthandProperties => &[(Chrome, Version(43, 0)), (Edge, Version(12, 0)), (Firefox, Version(33, 0)), (Safari, Version(9, 0)), (Node, Version(4, 0)), (Deno, Version(1, 0)), (Ios, Version(9, 0)), (Samsung, Version(4, 0)), (Opera, Version(30, 0)), (OperaMobile, Version(30, 0)), (Electron, Version(0, 27))],
+            Self::Spread => &[(Chrome, Version(46, 0)), (Edge, Version(13, 0)), (Firefox, Version(45, 0)), (Safari, Version(10, 0)), (Node, Version(5, 0)), (Deno, Version(1, 0)), (Ios, Version(10, 0)), (Samsung, Version(5, 0)), (Opera, Version(33, 0)), (OperaMobile, Version(33, 0)), (Electron, Version(0, 36))],
+            Self::StickyRegex => &[(Chrome, Version(49, 0)), (Edge, Version(13, 0)), (Firefox, Version(3, 0)), (Safari, Version(10, 0)), (Node, Version(6, 0)), (Deno, Version(1, 0)), (Ios, Version(10, 0)), (Samsung, Version(5, 0)), (Opera, Version(36, 0)), (OperaMobile, Version(36, 0)), (Electron, Version(0, 37))],
+            Self::UnicodeRegex => &[(Chrome, Version(50, 0)), (Edge, Version(13, 0)), (Firefox, Version(46, 0)), (Safari, Version(12, 0)), (Node, Version(6, 0)), (Deno, Version(1, 0)), (Ios, Version(12, 0)), (Samsung, Version(5, 0)), (Opera, Version(37, 0)), (OperaMobile, Version(37, 0)), (Electron, Version(1, 1))],
+        }
+    }
+}
+
+impl FromStr for Engine {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let engine = match s.to_ascii_lowercase().as_str() {
+            "chrome" | "and_chr" => Self::Chrome,
+            "deno" => Self::Deno,
+            "edge" => Self::Edge,
+            "electron" => Self::Electron,
+            "firefox" | "and_ff" => Self::Firefox,
+            "ios" | "ios_saf" => Self::Ios,
+            "node" => Self::Node,
+            "opera" => Self::Opera,
+            "op_mob" | "opera_mobile" => Self::OperaMobile,
+            "safari" => Self::Safari,
+            "samsung" => Self::Samsung,
+            _ => return Err(format!("Unknown engine `{s}`")),
+        };
+        Ok(engine)
+    }
+}
+
+impl FromStr for Version {
+    type Err = String;
+
+    /// `"13"`, `"13.1"` or `"16.11.0"`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split('.').map(str::parse::<u16>);
+        match (parts.next(), parts.next()) {
+            (Some(Ok(major)), None) => Ok(Self(major, 0)),
+            (Some(Ok(major)), Some(Ok(minor))) => Ok(Self(major, minor)),
+            _ => Err(format!("Invalid version `{s}`")),
+        }
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}", self.0, self.1)
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawTargets {
+    Query(String),
+    Map(FxHashMap<String, RawVersion>),
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawVersion {
+    String(String),
+    Number(f64),
+}
+
+impl TryFrom<RawTargets> for EngineTargets {
+    type Error = String;
+
+    fn try_from(raw: RawTargets) -> Result<Self, Self::Error> {
+        let entries = match raw {
+            RawTargets::Query(query) => return Self::from_query(&query),
+            RawTargets::Map(entries) => entries,
+        };
+        let mut targets = entries
+            .into_iter()
+            .map(|(engine, version)| {
+                let version = match version {
+                    RawVersion::String(version) => version.parse()?,
+                    RawVersion::Number(version) => version.to_string().parse()?,
+                };
+                Ok((engine.parse()?, version))
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+        targets.sort_unstable();
+        Ok(Self(targets))
+    }
+}
+
+#[test]
+#[rustfmt::skip]
+fn test() {
+    use crate::{options::TransformOptions, tester::Tester};
+
+    let targets = EngineTargets::from_query("chrome >= 79, safari 14.1").unwrap();
+    assert_eq!(targets, EngineTargets::new(vec![(Engine::Chrome, Version(79, 0)), (Engine::Safari, Version(14, 1))]));
+    assert!(targets.needs_transform(Feature::NullishCoalescingOperator));
+    assert!(!targets.needs_transform(Feature::OptionalCatchBinding));
+    assert!(EngineTargets::from_query("defaults").is_err());
+    assert!(EngineTargets::from_query("netscape 4").is_err());
+
+    let options = TransformOptions { targets, ..TransformOptions::default() };
+    Tester::new("test.js", options).test(&[
+        ("a ||= b", "a || (a = b);"),
+        ("try {} catch {}", "try {} catch {}"),
+        ("a ** b", "a ** b;"),
+    ]);
+}
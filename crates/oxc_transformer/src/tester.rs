@@ -1,4 +1,4 @@
-use std::{cell::RefCell, rc::Rc};
+use std::{cell::RefCell, path::PathBuf, rc::Rc};
 
 use oxc_allocator::Allocator;
 use oxc_codegen::{Codegen, CodegenOptions};
@@ -9,6 +9,8 @@ use oxc_span::SourceType;
 use crate::{TransformOptions, Transformer};
 
 pub struct Tester {
+    source_path: PathBuf,
+
     source_type: SourceType,
 
     options: TransformOptions,
@@ -19,7 +21,7 @@ pub struct Tester {
 impl Tester {
     pub fn new(filename: &str, options: TransformOptions) -> Self {
         let source_type = SourceType::from_path(filename).unwrap();
-        Self { source_type, options, allocator: Allocator::default() }
+        Self { source_path: filename.into(), source_type, options, allocator: Allocator::default() }
     }
 
     pub fn test(&self, tests: &[(&str, &str)]) {
@@ -39,8 +41,15 @@ impl Tester {
         let scopes = Rc::new(RefCell::new(scopes));
 
         let program = self.allocator.alloc(program);
-        Transformer::new(&self.allocator, self.source_type, &symbols, &scopes, self.options)
-            .build(program);
+        Transformer::new(
+            &self.allocator,
+            self.source_type,
+            &symbols,
+            &scopes,
+            self.options.for_path(&self.source_path),
+        )
+        .with_source(&self.source_path, source_text)
+        .build(program);
         Codegen::<false>::new(source_text.len(), CodegenOptions).build(program)
     }
 
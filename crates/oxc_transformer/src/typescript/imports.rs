@@ -0,0 +1,134 @@
+use oxc_allocator::Vec;
+use oxc_ast::{ast::*, Visit};
+use oxc_span::Atom;
+use rustc_hash::FxHashSet;
+
+use super::{ImportsNotUsedAsValues, TypeScript};
+
+impl<'a> TypeScript<'a> {
+    /// Removes the imports which are only used as types, before the types are erased.
+    ///
+    /// ```TypeScript
+    /// import { A, b } from "a";
+    /// let x: A = b;
+    /// // =>
+    /// import { b } from "a";
+    /// let x = b;
+    /// ```
+    ///
+    /// With `verbatimModuleSyntax`, only the `import type` declarations and the `type` specifiers
+    /// are removed, the declarations left without a specifier are kept for their side effects.
+    pub fn transform_program(&self, program: &mut Program<'a>) {
+        if self.options.verbatim_module_syntax {
+            return;
+        }
+        let mut references = ValueReferences::default();
+        references.visit_program(program);
+        if references.has_jsx {
+            references.names.insert(self.jsx_pragma.clone());
+        }
+        let preserve = self.options.imports_not_used_as_values == ImportsNotUsedAsValues::Preserve;
+
+        let old_stmts = std::mem::replace(&mut program.body, self.ast.new_vec());
+        for mut stmt in old_stmts {
+            if let Statement::ModuleDeclaration(decl) = &mut stmt {
+                if let ModuleDeclaration::ImportDeclaration(decl) = &mut **decl {
+                    if !Self::elide_unused_specifiers(decl, &references.names) && !preserve {
+                        continue;
+                    }
+                }
+            }
+            program.body.push(stmt);
+        }
+    }
+
+    /// Returns `false` if all the specifiers of the import are removed.
+    fn elide_unused_specifiers(decl: &mut ImportDeclaration<'a>, used: &FxHashSet<Atom>) -> bool {
+        if decl.import_kind.is_type() || decl.specifiers.is_empty() {
+            return true;
+        }
+        decl.specifiers.retain(|specifier| {
+            let local = match specifier {
+                ImportDeclarationSpecifier::ImportSpecifier(s) => &s.local,
+                ImportDeclarationSpecifier::ImportDefaultSpecifier(s) => &s.local,
+                ImportDeclarationSpecifier::ImportNamespaceSpecifier(s) => &s.local,
+            };
+            used.contains(&local.name)
+        });
+        !decl.specifiers.is_empty()
+    }
+
+    /// Returns `false` if the import should be removed, `import { type A } from "a"` becomes
+    /// `import "a"` with `verbatimModuleSyntax`.
+    pub(super) fn remove_type_specifiers(
+        &self,
+        specifiers: &mut Vec<'a, ImportDeclarationSpecifier>,
+    ) -> bool {
+        if specifiers.is_empty() {
+            return true;
+        }
+        specifiers.retain(|specifier| {
+            !matches!(specifier, ImportDeclarationSpecifier::ImportSpecifier(specifier) if specifier.import_kind.is_type())
+        });
+        !specifiers.is_empty()
+            || self.options.verbatim_module_syntax
+            || self.options.imports_not_used_as_values == ImportsNotUsedAsValues::Preserve
+    }
+}
+
+/// The names referenced as values, the references in the types are skipped.
+#[derive(Default)]
+struct ValueReferences {
+    names: FxHashSet<Atom>,
+    has_jsx: bool,
+}
+
+impl<'a> Visit<'a> for ValueReferences {
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference) {
+        self.names.insert(ident.name.clone());
+    }
+
+    fn visit_jsx_element_name(&mut self, name: &JSXElementName<'a>) {
+        self.has_jsx = true;
+        match name {
+            JSXElementName::Identifier(ident) => {
+                self.names.insert(ident.name.clone());
+            }
+            JSXElementName::MemberExpression(expr) => {
+                self.names.insert(expr.get_object_identifier().name.clone());
+            }
+            JSXElementName::NamespacedName(_) => {}
+        }
+    }
+
+    fn visit_jsx_fragment(&mut self, fragment: &JSXFragment<'a>) {
+        self.has_jsx = true;
+        for child in &fragment.children {
+            self.visit_jsx_child(child);
+        }
+    }
+
+    // `export { A }` keeps the import of `A`.
+    fn visit_export_named_declaration(&mut self, decl: &ExportNamedDeclaration<'a>) {
+        if let Some(decl) = &decl.declaration {
+            self.visit_declaration(decl);
+        }
+        if decl.source.is_none() && decl.export_kind.is_value() {
+            for specifier in decl.specifiers.iter().filter(|s| s.export_kind.is_value()) {
+                self.names.insert(specifier.local.name().clone());
+            }
+        }
+    }
+
+    fn visit_ts_type_annotation(&mut self, _annotation: &TSTypeAnnotation<'a>) {}
+
+    fn visit_ts_type(&mut self, _ty: &TSType<'a>) {}
+
+    fn visit_ts_type_parameter_declaration(&mut self, _ty: &TSTypeParameterDeclaration<'a>) {}
+
+    fn visit_ts_type_parameter_instantiation(&mut self, _ty: &TSTypeParameterInstantiation<'a>) {}
+
+    fn visit_ts_type_alias_declaration(&mut self, _decl: &TSTypeAliasDeclaration<'a>) {}
+
+    fn visit_ts_interface_declaration(&mut self, _decl: &TSInterfaceDeclaration<'a>) {}
+}
@@ -1,20 +1,201 @@
+mod imports;
+mod namespace;
+
+use oxc_allocator::Vec;
 use oxc_ast::ast::*;
-use oxc_ast::AstBuilder;
+use oxc_ast::{AstBuilder, VisitMut};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_span::{Atom, Span};
+use oxc_syntax::operator::AssignmentOperator;
+use serde::Deserialize;
 
 use std::rc::Rc;
 
+use crate::{context::TransformerCtx, options::TransformOptions};
+
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct TypeScriptOptions {
+    /// Only remove the `import type` declarations and the `type` specifiers, the imports which
+    /// are only used as types are kept. `onlyRemoveTypeImports` in Babel.
+    /// <https://www.typescriptlang.org/tsconfig#verbatimModuleSyntax>
+    pub verbatim_module_syntax: bool,
+    pub imports_not_used_as_values: ImportsNotUsedAsValues,
+}
+
+/// What happens to the imports whose specifiers are all removed, because they are only used as
+/// types.
+/// <https://www.typescriptlang.org/tsconfig#importsNotUsedAsValues>
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ImportsNotUsedAsValues {
+    /// The import is removed.
+    #[default]
+    Remove,
+    /// The import is kept for its side effects, `import "a"`.
+    Preserve,
+}
+
 /// Transform TypeScript
 ///
+/// Erases type annotations, type only imports and exports, interfaces, type aliases, `declare`d
+/// statements and type assertions, leaving the plain JavaScript behind. Namespaces are lowered to
+/// functions which populate the namespace object.
+///
 /// References:
 /// * <https://babeljs.io/docs/babel-plugin-transform-typescript>
 /// * <https://github.com/babel/babel/tree/main/packages/babel-plugin-transform-typescript>
 pub struct TypeScript<'a> {
-    _ast: Rc<AstBuilder<'a>>,
+    ast: Rc<AstBuilder<'a>>,
+    ctx: TransformerCtx<'a>,
+    options: TypeScriptOptions,
+    /// The binding the JSX elements are created with, its import is kept when there is JSX.
+    jsx_pragma: Atom,
 }
 
 impl<'a> TypeScript<'a> {
-    pub fn new(_ast: Rc<AstBuilder<'a>>) -> Self {
-        Self { _ast }
+    pub fn new(
+        ast: Rc<AstBuilder<'a>>,
+        ctx: TransformerCtx<'a>,
+        options: &TransformOptions,
+    ) -> Self {
+        let pragma = options.react_jsx.as_ref().and_then(|jsx| jsx.pragma.as_deref());
+        let jsx_pragma =
+            pragma.map_or("React", |pragma| pragma.split('.').next().unwrap_or(pragma));
+        Self { ast, ctx, options: options.typescript.clone(), jsx_pragma: jsx_pragma.into() }
+    }
+
+    pub fn transform_statements(&self, stmts: &mut Vec<'a, Statement<'a>>) {
+        self.remove_type_only_statements(stmts);
+        self.transform_namespaces(stmts, None);
+    }
+
+    /// Removes the statements which only exist in the type system.
+    fn remove_type_only_statements(&self, stmts: &mut Vec<'a, Statement<'a>>) {
+        let old_stmts = std::mem::replace(stmts, self.ast.new_vec());
+        for mut stmt in old_stmts {
+            let keep = match &mut stmt {
+                Statement::Declaration(decl) => !Self::is_type_only_declaration(decl),
+                Statement::ModuleDeclaration(decl) => self.transform_module_declaration(decl),
+                _ => true,
+            };
+            if keep {
+                stmts.push(stmt);
+            }
+        }
+    }
+
+    /// Whether a declaration has no runtime semantics, e.g. `interface A {}` or `declare let a;`.
+    fn is_type_only_declaration(decl: &Declaration<'a>) -> bool {
+        match decl {
+            Declaration::VariableDeclaration(decl) => decl.is_typescript_syntax(),
+            Declaration::FunctionDeclaration(func) => func.is_typescript_syntax(),
+            Declaration::ClassDeclaration(class) => class.is_typescript_syntax(),
+            Declaration::UsingDeclaration(_) => false,
+            Declaration::TSTypeAliasDeclaration(_) | Declaration::TSInterfaceDeclaration(_) => true,
+            Declaration::TSEnumDeclaration(decl) => decl.modifiers.contains(ModifierKind::Declare),
+            Declaration::TSModuleDeclaration(decl) => {
+                decl.modifiers.contains(ModifierKind::Declare)
+            }
+            Declaration::TSImportEqualsDeclaration(decl) => decl.import_kind.is_type(),
+        }
+    }
+
+    /// Returns `false` if the module declaration should be removed.
+    fn transform_module_declaration(&self, decl: &mut ModuleDeclaration<'a>) -> bool {
+        match decl {
+            // import type { A } from 'a';
+            // import { type B } from 'b';
+            ModuleDeclaration::ImportDeclaration(decl) => {
+                !decl.import_kind.is_type() && self.remove_type_specifiers(&mut decl.specifiers)
+            }
+            // export type { A };
+            // export { type B };
+            // export interface C {}
+            ModuleDeclaration::ExportNamedDeclaration(decl) => {
+                if decl.export_kind.is_type()
+                    || decl.declaration.as_ref().is_some_and(Self::is_type_only_declaration)
+                {
+                    return false;
+                }
+                decl.specifiers.retain(|specifier| specifier.export_kind.is_value());
+                true
+            }
+            // export type * from 'a';
+            ModuleDeclaration::ExportAllDeclaration(decl) => decl.export_kind.is_value(),
+            // export default interface A {}
+            ModuleDeclaration::ExportDefaultDeclaration(decl) => match &decl.declaration {
+                ExportDefaultDeclarationKind::TSInterfaceDeclaration(_) => false,
+                ExportDefaultDeclarationKind::FunctionDeclaration(func) => {
+                    !func.is_typescript_syntax()
+                }
+                _ => true,
+            },
+            // export as namespace A;
+            ModuleDeclaration::TSNamespaceExportDeclaration(_) => false,
+            ModuleDeclaration::TSExportAssignment(_) => true,
+        }
+    }
+
+    /// `a as T`, `a satisfies T`, `<T>a`, `a!` and `f<T>` -> `a`
+    pub fn transform_expression(&self, expr: &mut Expression<'a>) {
+        loop {
+            let inner = match expr {
+                Expression::TSAsExpression(e) => &mut e.expression,
+                Expression::TSSatisfiesExpression(e) => &mut e.expression,
+                Expression::TSTypeAssertion(e) => &mut e.expression,
+                Expression::TSNonNullExpression(e) => &mut e.expression,
+                Expression::TSInstantiationExpression(e) => &mut e.expression,
+                _ => break,
+            };
+            *expr = self.ast.move_expression(inner);
+        }
+        match expr {
+            Expression::CallExpression(e) => e.type_parameters = None,
+            Expression::NewExpression(e) => e.type_parameters = None,
+            Expression::TaggedTemplateExpression(e) => e.type_parameters = None,
+            _ => {}
+        }
+    }
+
+    /// `(a as T) = b` -> `a = b`
+    pub fn transform_simple_assignment_target(&self, target: &mut SimpleAssignmentTarget<'a>) {
+        let mut expr = match target {
+            SimpleAssignmentTarget::TSAsExpression(e) => {
+                self.ast.move_expression(&mut e.expression)
+            }
+            SimpleAssignmentTarget::TSSatisfiesExpression(e) => {
+                self.ast.move_expression(&mut e.expression)
+            }
+            SimpleAssignmentTarget::TSNonNullExpression(e) => {
+                self.ast.move_expression(&mut e.expression)
+            }
+            SimpleAssignmentTarget::TSTypeAssertion(e) => {
+                self.ast.move_expression(&mut e.expression)
+            }
+            _ => return,
+        };
+        loop {
+            self.transform_expression(&mut expr);
+            match expr {
+                Expression::ParenthesizedExpression(mut e) => {
+                    expr = self.ast.move_expression(&mut e.expression);
+                }
+                Expression::Identifier(ident) => {
+                    *target = self.ast.simple_assignment_target_identifier(ident.unbox());
+                    return;
+                }
+                Expression::MemberExpression(member) => {
+                    *target = self.ast.simple_assignment_target_member_expression(member.unbox());
+                    return;
+                }
+                // Not a valid assignment target, which the parser has already reported.
+                _ => return,
+            }
+        }
     }
 
     #[allow(clippy::unused_self)]
@@ -23,4 +204,267 @@ impl<'a> TypeScript<'a> {
             params.items.remove(0);
         }
     }
+
+    #[allow(clippy::unused_self)]
+    pub fn transform_binding_pattern(&self, pat: &mut BindingPattern<'a>) {
+        pat.type_annotation = None;
+        pat.optional = false;
+    }
+
+    #[allow(clippy::unused_self)]
+    pub fn transform_variable_declarator(&self, declarator: &mut VariableDeclarator<'a>) {
+        declarator.definite = false;
+    }
+
+    #[allow(clippy::unused_self)]
+    pub fn transform_function(&self, func: &mut Function<'a>) {
+        func.type_parameters = None;
+        func.return_type = None;
+    }
+
+    #[allow(clippy::unused_self)]
+    pub fn transform_arrow_expression(&self, expr: &mut ArrowExpression<'a>) {
+        expr.type_parameters = None;
+        expr.return_type = None;
+    }
+
+    /// Removes `implements`, type parameters, index signatures, abstract members, `declare`
+    /// fields, overloads and member modifiers.
+    pub fn transform_class(&self, class: &mut Class<'a>) {
+        class.type_parameters = None;
+        class.super_type_parameters = None;
+        class.implements = None;
+        class.body.body.retain(|element| !element.is_typescript_syntax());
+        let is_derived = class.super_class.is_some();
+        for element in class.body.body.iter_mut() {
+            match element {
+                ClassElement::MethodDefinition(def) => {
+                    if def.kind == MethodDefinitionKind::Constructor {
+                        self.transform_parameter_properties(&mut def.value, is_derived);
+                    }
+                    def.accessibility = None;
+                    def.optional = false;
+                    def.r#override = false;
+                }
+                ClassElement::PropertyDefinition(def) => {
+                    def.type_annotation = None;
+                    def.accessibility = None;
+                    def.optional = false;
+                    def.definite = false;
+                    def.readonly = false;
+                    def.r#override = false;
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// `constructor(private readonly a, public b = 1) {}` -> `constructor(a, b = 1) { this.a = a; this.b = b; }`
+    ///
+    /// The assignments are inserted after every `super()` call statement in derived classes,
+    /// including the ones nested in blocks, as tsc does.
+    fn transform_parameter_properties(&self, constructor: &mut Function<'a>, is_derived: bool) {
+        let mut names = vec![];
+        for param in constructor.params.items.iter_mut() {
+            if param.accessibility.is_none() && !param.readonly {
+                continue;
+            }
+            param.accessibility = None;
+            param.readonly = false;
+            let ident = match &param.pattern.kind {
+                BindingPatternKind::BindingIdentifier(ident) => ident,
+                BindingPatternKind::AssignmentPattern(pattern) => match &pattern.left.kind {
+                    BindingPatternKind::BindingIdentifier(ident) => ident,
+                    _ => continue,
+                },
+                _ => continue,
+            };
+            names.push(ident.name.clone());
+        }
+        let Some(body) = constructor.body.as_mut().filter(|_| !names.is_empty()) else {
+            return;
+        };
+        if is_derived {
+            let mut inserter =
+                SuperCallInserter { typescript: self, names: &names, found: false, error: None };
+            inserter.visit_statements(&mut body.statements);
+            if let Some(span) = inserter.error {
+                self.ctx.error(ParameterPropertiesAfterSuperCall(span));
+            }
+            if inserter.found || inserter.error.is_some() {
+                return;
+            }
+        }
+        for (i, stmt) in self.parameter_property_assignments(&names).into_iter().enumerate() {
+            body.statements.insert(i, stmt);
+        }
+    }
+
+    /// `this.a = a; this.b = b;`
+    fn parameter_property_assignments(&self, names: &[Atom]) -> Vec<'a, Statement<'a>> {
+        let mut assignments = self.ast.new_vec_with_capacity(names.len());
+        for name in names {
+            let span = Span::default();
+            let property = IdentifierName::new(span, name.clone());
+            let member =
+                self.ast.static_member(span, self.ast.this_expression(span), property, false);
+            let value = IdentifierReference::new(span, name.clone());
+            let assignment = self.ast.assignment_expression(
+                span,
+                AssignmentOperator::Assign,
+                AssignmentTarget::SimpleAssignmentTarget(
+                    self.ast.simple_assignment_target_member_expression(member),
+                ),
+                self.ast.identifier_reference_expression(value),
+            );
+            assignments.push(self.ast.expression_statement(span, assignment));
+        }
+        assignments
+    }
+}
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Parameter properties can't be initialized after this `super()` call")]
+#[diagnostic(help(
+    "Call `super()` as a statement of its own, the parameter properties are assigned after it"
+))]
+struct ParameterPropertiesAfterSuperCall(#[label] Span);
+
+/// Inserts the assignments of the parameter properties after the `super()` call statements of
+/// a constructor, and finds the `super()` calls which are not statements.
+struct SuperCallInserter<'a, 'b> {
+    typescript: &'b TypeScript<'a>,
+    names: &'b [Atom],
+    found: bool,
+    error: Option<Span>,
+}
+
+impl<'a, 'b> SuperCallInserter<'a, 'b> {
+    fn is_super_call(stmt: &Statement<'a>) -> bool {
+        matches!(stmt, Statement::ExpressionStatement(stmt)
+            if matches!(&stmt.expression, Expression::CallExpression(call)
+                if matches!(call.callee, Expression::Super(_))))
+    }
+}
+
+impl<'a, 'b> VisitMut<'a> for SuperCallInserter<'a, 'b> {
+    fn visit_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>) {
+        let mut i = 0;
+        while i < stmts.len() {
+            if Self::is_super_call(&stmts[i]) {
+                self.found = true;
+                for stmt in self.typescript.parameter_property_assignments(self.names) {
+                    i += 1;
+                    stmts.insert(i, stmt);
+                }
+            } else {
+                self.visit_statement(&mut stmts.as_mut_slice()[i]);
+            }
+            i += 1;
+        }
+    }
+
+    /// `if (a) super();` -> `if (a) { super(); this.a = a; }`
+    fn visit_statement(&mut self, stmt: &mut Statement<'a>) {
+        if Self::is_super_call(stmt) {
+            let ast = &self.typescript.ast;
+            let mut stmts = ast.new_vec_single(ast.move_statement(stmt));
+            self.visit_statements(&mut stmts);
+            *stmt = ast.block_statement(ast.block(Span::default(), stmts));
+        } else {
+            self.visit_statement_match(stmt);
+        }
+    }
+
+    fn visit_call_expression(&mut self, expr: &mut CallExpression<'a>) {
+        if matches!(expr.callee, Expression::Super(_)) {
+            self.error.get_or_insert(expr.span);
+        }
+        for arg in expr.arguments.iter_mut() {
+            self.visit_argument(arg);
+        }
+        self.visit_expression(&mut expr.callee);
+    }
+
+    fn visit_function(&mut self, _func: &mut Function<'a>) {}
+
+    fn visit_class(&mut self, _class: &mut Class<'a>) {}
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    Tester::new("test.ts", TransformOptions::default()).test(&[
+        ("let a: number = 1 as number;", "let a = 1;"),
+        ("let a!: string; a = b!;", "let a; a = b;"),
+        ("function f<T>(this: Window, a?: T, { b }: { b: T } = {}): T { return a; }", "function f(a, { b } = {}) { return a; }"),
+        ("const f = <T,>(a: T): T => a satisfies T;", "const f = (a) => a;"),
+        ("interface A { a: number } type B = A; declare const c: B; declare function d(): void;", ""),
+        ("function f(a: string): void; function f(a) {}", "function f(a) {}"),
+        (
+            "import type A from 'a'; import { type B, C } from 'b'; import { type D } from 'd'; import 'e'; C();",
+            "import { C } from 'b'; import 'e'; C();",
+        ),
+        (
+            "import A, { B, type C } from 'a'; import * as D from 'd'; import E from 'e'; let x: A = new B<E>() as typeof D; export { E };",
+            "import { B } from 'a'; import E from 'e'; let x = new B(); export { E };",
+        ),
+        (
+            "export type { A } from 'a'; export type * from 'b'; export { type C, D }; export interface E {} export default interface F {}",
+            "export { D };",
+        ),
+        ("(a as any) = 1; (<any>b).c = 2; d! += 3;", "a = 1; b.c = 2; d += 3;"),
+        ("f<string>(new G<number>());", "f(new G());"),
+        (
+            "abstract class A<T> extends B<T> implements C { [key: string]: any; declare a: number; private readonly b?: T = 1; abstract c(): void; public d(): void; public d() {} }",
+            "class A extends B { b = 1; d() {} }",
+        ),
+        ("declare module 'a' {} declare enum E {} declare global {}", ""),
+        (
+            "class A { constructor(private a: number, public readonly b = 1, c) {} }",
+            "class A { constructor(a, b = 1, c) { this.a = a; this.b = b; } }",
+        ),
+        (
+            "class A extends B { constructor(protected a) { foo(); super(); bar(); } }",
+            "class A extends B { constructor(a) { foo(); super(); this.a = a; bar(); } }",
+        ),
+        (
+            "class A extends B { constructor(private a) { if (c) { super(1); } else try { super(2); } finally {} } }",
+            "class A extends B { constructor(a) { if (c) { super(1); this.a = a; } else try { super(2); this.a = a; } finally {} } }",
+        ),
+        (
+            "class A extends B { constructor(public a, public b) { if (c) super(); else { d = () => { super(); }; d(); } } }",
+            "class A extends B { constructor(a, b) { if (c) { super(); this.a = a; this.b = b; } else { d = () => { super(); this.a = a; this.b = b; }; d(); } } }",
+        ),
+    ]);
+
+    let source =
+        "import type A from 'a'; import { type B, C } from 'b'; import { D } from 'd'; let x: D;";
+    let options = |typescript| TransformOptions { typescript, ..TransformOptions::default() };
+    Tester::new(
+        "test.ts",
+        options(TypeScriptOptions { verbatim_module_syntax: true, ..TypeScriptOptions::default() }),
+    )
+    .test(&[
+        (source, "import { C } from 'b'; import { D } from 'd'; let x;"),
+        ("import { type A } from 'a';", "import 'a';"),
+    ]);
+    Tester::new(
+        "test.ts",
+        options(TypeScriptOptions {
+            imports_not_used_as_values: ImportsNotUsedAsValues::Preserve,
+            ..TypeScriptOptions::default()
+        }),
+    )
+    .test(&[(source, "import 'b'; import 'd'; let x;")]);
+    Tester::new("test.tsx", TransformOptions::default()).test(&[
+        ("import React from 'react'; import { Props } from './props'; const a = (p: Props) => <div />;", "import React from 'react'; const a = (p) => <div />;"),
+    ]);
+
+    // The assignments can't be inserted after a `super()` call in an expression
+    let source_type = oxc_span::SourceType::from_path("test.ts").unwrap();
+    let source = "class A extends B { constructor(private a) { f(super()); } }";
+    let ret = crate::transform(source, source_type, TransformOptions::default());
+    assert_eq!(ret.errors.len(), 1);
 }
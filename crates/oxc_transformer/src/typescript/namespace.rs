@@ -0,0 +1,660 @@
+use oxc_allocator::{Box, Vec};
+use oxc_ast::{ast::*, syntax_directed_operations::BoundNames, VisitMut};
+use oxc_semantic::SymbolId;
+use oxc_span::{Atom, Span};
+use oxc_syntax::operator::{AssignmentOperator, LogicalOperator};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use super::TypeScript;
+
+impl<'a> TypeScript<'a> {
+    /// Lowers the namespaces and `import A = B.C` aliases of a statement list.
+    ///
+    /// `parent` is the parameter of the enclosing namespace, which the exports of the statement
+    /// list are assigned to.
+    ///
+    /// ```TypeScript
+    /// namespace N { export const x = 1; }
+    /// // =>
+    /// let N;
+    /// (function (_N) { const x = _N.x = 1; })(N || (N = {}));
+    /// ```
+    pub(super) fn transform_namespaces(
+        &self,
+        stmts: &mut Vec<'a, Statement<'a>>,
+        parent: Option<&Atom>,
+    ) {
+        if parent.is_none() && !stmts.iter().any(Self::is_namespace_or_alias) {
+            return;
+        }
+
+        // Namespaces merge with each other and with classes, functions and enums of the same name.
+        let mut names = FxHashSet::default();
+        for decl in stmts.iter().filter_map(Self::declaration) {
+            match decl {
+                Declaration::VariableDeclaration(decl) => {
+                    decl.bound_names(&mut |ident| {
+                        names.insert(ident.name.clone());
+                    });
+                }
+                Declaration::FunctionDeclaration(func) => {
+                    names.extend(func.id.as_ref().map(|id| id.name.clone()));
+                }
+                Declaration::ClassDeclaration(class) => {
+                    names.extend(class.id.as_ref().map(|id| id.name.clone()));
+                }
+                Declaration::TSEnumDeclaration(decl) => {
+                    names.insert(decl.id.name.clone());
+                }
+                _ => {}
+            }
+        }
+
+        // The members exported by all the blocks of a namespace, which the other blocks refer to
+        // without the namespace.
+        let mut members: FxHashMap<Atom, FxHashSet<Atom>> = FxHashMap::default();
+        for decl in stmts.iter().filter_map(Self::declaration) {
+            if let Declaration::TSModuleDeclaration(decl) = decl {
+                if Self::is_namespace(decl) {
+                    let members = members.entry(decl.id.name().clone()).or_default();
+                    Self::exported_members(decl, members);
+                }
+            }
+        }
+
+        let old_stmts = std::mem::replace(stmts, self.ast.new_vec());
+        for stmt in old_stmts {
+            match stmt {
+                Statement::Declaration(Declaration::TSModuleDeclaration(decl))
+                    if Self::is_namespace(&decl) =>
+                {
+                    self.push_namespace(stmts, decl, false, parent, &mut names, &members);
+                }
+                Statement::Declaration(Declaration::TSImportEqualsDeclaration(decl))
+                    if Self::is_alias(&decl) =>
+                {
+                    stmts.push(self.alias(decl, None));
+                }
+                Statement::ModuleDeclaration(decl)
+                    if matches!(&*decl, ModuleDeclaration::ExportNamedDeclaration(_)) =>
+                {
+                    let ModuleDeclaration::ExportNamedDeclaration(mut export) = decl.unbox() else {
+                        unreachable!()
+                    };
+                    match (export.declaration.take(), parent) {
+                        (Some(Declaration::TSModuleDeclaration(decl)), _)
+                            if Self::is_namespace(&decl) =>
+                        {
+                            self.push_namespace(stmts, decl, true, parent, &mut names, &members);
+                        }
+                        (Some(Declaration::TSImportEqualsDeclaration(decl)), Some(parent))
+                            if Self::is_alias(&decl) =>
+                        {
+                            stmts.push(self.alias(decl, Some(parent)));
+                        }
+                        (Some(Declaration::TSImportEqualsDeclaration(decl)), None)
+                            if Self::is_alias(&decl) =>
+                        {
+                            let Statement::Declaration(declaration) = self.alias(decl, None) else {
+                                unreachable!()
+                            };
+                            export.declaration = Some(declaration);
+                            stmts.push(self.export_statement(export));
+                        }
+                        (Some(declaration), Some(parent)) => {
+                            self.push_exported_declaration(stmts, declaration, parent);
+                        }
+                        (declaration, _) => {
+                            export.declaration = declaration;
+                            stmts.push(self.export_statement(export));
+                        }
+                    }
+                }
+                stmt => stmts.push(stmt),
+            }
+        }
+    }
+
+    /// The declaration of `decl` or `export decl`
+    fn declaration<'b>(stmt: &'b Statement<'a>) -> Option<&'b Declaration<'a>> {
+        match stmt {
+            Statement::Declaration(decl) => Some(decl),
+            Statement::ModuleDeclaration(decl) => match &**decl {
+                ModuleDeclaration::ExportNamedDeclaration(export) => export.declaration.as_ref(),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    fn is_namespace_or_alias(stmt: &Statement<'a>) -> bool {
+        Self::declaration(stmt).is_some_and(|decl| match decl {
+            Declaration::TSModuleDeclaration(decl) => Self::is_namespace(decl),
+            Declaration::TSImportEqualsDeclaration(decl) => Self::is_alias(decl),
+            _ => false,
+        })
+    }
+
+    /// `namespace N {}`, but not `declare namespace N {}` or `module "m" {}`
+    fn is_namespace(decl: &TSModuleDeclaration<'a>) -> bool {
+        matches!(decl.id, TSModuleDeclarationName::Identifier(_))
+            && !decl.modifiers.contains(ModifierKind::Declare)
+    }
+
+    /// `import A = B.C`, but not `import A = require("a")`
+    fn is_alias(decl: &TSImportEqualsDeclaration<'a>) -> bool {
+        decl.import_kind.is_value()
+            && matches!(*decl.module_reference, TSModuleReference::TypeName(_))
+    }
+
+    /// The names of the values exported by a namespace block.
+    fn exported_members(decl: &TSModuleDeclaration<'a>, members: &mut FxHashSet<Atom>) {
+        let block = match &decl.body {
+            TSModuleDeclarationBody::TSModuleBlock(block) => block,
+            TSModuleDeclarationBody::TSModuleDeclaration(decl) => {
+                members.insert(decl.id.name().clone());
+                return;
+            }
+        };
+        for stmt in &block.body {
+            let Statement::ModuleDeclaration(decl) = stmt else { continue };
+            let ModuleDeclaration::ExportNamedDeclaration(export) = &**decl else { continue };
+            match &export.declaration {
+                Some(Declaration::VariableDeclaration(decl)) => {
+                    decl.bound_names(&mut |ident| {
+                        members.insert(ident.name.clone());
+                    });
+                }
+                Some(Declaration::FunctionDeclaration(func)) => {
+                    members.extend(func.id.as_ref().map(|id| id.name.clone()));
+                }
+                Some(Declaration::ClassDeclaration(class)) => {
+                    members.extend(class.id.as_ref().map(|id| id.name.clone()));
+                }
+                Some(Declaration::TSEnumDeclaration(decl)) => {
+                    members.insert(decl.id.name.clone());
+                }
+                Some(Declaration::TSModuleDeclaration(decl)) if Self::is_namespace(decl) => {
+                    members.insert(decl.id.name().clone());
+                }
+                Some(Declaration::TSImportEqualsDeclaration(decl)) if Self::is_alias(decl) => {
+                    members.insert(decl.id.name.clone());
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn export_statement(&self, export: Box<'a, ExportNamedDeclaration<'a>>) -> Statement<'a> {
+        self.ast.module_declaration(ModuleDeclaration::ExportNamedDeclaration(export))
+    }
+
+    /// `let N; (function (_N) {})(N || (N = {}));`, without the declaration if `N` is already
+    /// declared. Nested namespaces are only assigned to their parent if they are exported.
+    fn push_namespace(
+        &self,
+        stmts: &mut Vec<'a, Statement<'a>>,
+        decl: Box<'a, TSModuleDeclaration<'a>>,
+        is_export: bool,
+        parent: Option<&Atom>,
+        names: &mut FxHashSet<Atom>,
+        members: &FxHashMap<Atom, FxHashSet<Atom>>,
+    ) {
+        let name = decl.id.name().clone();
+        let Some(iife) =
+            self.namespace_iife(decl, parent.filter(|_| is_export), members.get(&name))
+        else {
+            return;
+        };
+        if names.insert(name.clone()) {
+            let declaration = self.let_declaration(name);
+            if is_export && parent.is_none() {
+                let export = self.ast.export_named_declaration(
+                    Span::default(),
+                    Some(declaration),
+                    self.ast.new_vec(),
+                    None,
+                    None,
+                    ImportOrExportKind::Value,
+                );
+                stmts.push(
+                    self.ast.module_declaration(ModuleDeclaration::ExportNamedDeclaration(export)),
+                );
+            } else {
+                stmts.push(Statement::Declaration(declaration));
+            }
+        }
+        stmts.push(iife);
+    }
+
+    /// `(function (_N) { body })(N || (N = {}))`, or `None` if the namespace only contains types.
+    ///
+    /// Exported namespaces of a parent namespace `_P` are also assigned to the parent:
+    /// `(N || (N = _P.N || (_P.N = {})))`
+    ///
+    /// `members` are the members exported by all the blocks of the namespace.
+    fn namespace_iife(
+        &self,
+        decl: Box<'a, TSModuleDeclaration<'a>>,
+        parent: Option<&Atom>,
+        members: Option<&FxHashSet<Atom>>,
+    ) -> Option<Statement<'a>> {
+        let span = decl.span;
+        let decl = decl.unbox();
+        let name = decl.id.name().clone();
+        let mut body = match decl.body {
+            TSModuleDeclarationBody::TSModuleBlock(block) => block.unbox().body,
+            // namespace A.B {} -> namespace A { export namespace B {} }
+            TSModuleDeclarationBody::TSModuleDeclaration(decl) => {
+                let export = self.ast.export_named_declaration(
+                    Span::default(),
+                    Some(Declaration::TSModuleDeclaration(decl)),
+                    self.ast.new_vec(),
+                    None,
+                    None,
+                    ImportOrExportKind::Value,
+                );
+                self.ast.new_vec_single(
+                    self.ast.module_declaration(ModuleDeclaration::ExportNamedDeclaration(export)),
+                )
+            }
+        };
+
+        self.remove_type_only_statements(&mut body);
+        if body.is_empty() {
+            return None;
+        }
+        let mut variables = FxHashSet::default();
+        for stmt in &body {
+            if let Some(Declaration::VariableDeclaration(decl)) = Self::declaration(stmt) {
+                if matches!(stmt, Statement::ModuleDeclaration(_)) {
+                    decl.bound_names(&mut |ident| variables.extend(ident.symbol_id.get()));
+                }
+            }
+        }
+        let param_name = self.ctx.scopes().generate_uid(&name);
+        self.ctx.add_binding(param_name.clone());
+        self.transform_namespaces(&mut body, Some(&param_name));
+        let empty = FxHashSet::default();
+        MemberReferences {
+            typescript: self,
+            namespace: &param_name,
+            variables,
+            members: members.unwrap_or(&empty),
+        }
+        .visit_statements(&mut body);
+
+        let param = self.ast.binding_pattern(
+            self.ast
+                .binding_pattern_identifier(BindingIdentifier::new(Span::default(), param_name)),
+            None,
+            false,
+        );
+        let params = self.ast.formal_parameters(
+            Span::default(),
+            FormalParameterKind::FormalParameter,
+            self.ast.new_vec_single(self.ast.formal_parameter(
+                Span::default(),
+                param,
+                None,
+                false,
+                self.ast.new_vec(),
+            )),
+            None,
+        );
+        let function = self.ast.function(
+            FunctionType::FunctionExpression,
+            Span::default(),
+            None,
+            false,
+            false,
+            false,
+            params,
+            Some(self.ast.function_body(Span::default(), self.ast.new_vec(), body)),
+            None,
+            None,
+            Modifiers::empty(),
+        );
+        let callee = self
+            .ast
+            .parenthesized_expression(Span::default(), self.ast.function_expression(function));
+
+        // N || (N = {})
+        let object = self.ast.object_expression(Span::default(), self.ast.new_vec(), None);
+        let object = match parent {
+            Some(parent) => {
+                let member = self.member_expression(parent.clone(), name.clone());
+                let assignment = self.assign_member(parent.clone(), name.clone(), object);
+                self.ast.logical_expression(
+                    Span::default(),
+                    member,
+                    LogicalOperator::Or,
+                    self.ast.parenthesized_expression(Span::default(), assignment),
+                )
+            }
+            None => object,
+        };
+        let target = self.ast.simple_assignment_target_identifier(IdentifierReference::new(
+            Span::default(),
+            name.clone(),
+        ));
+        let assignment = self.ast.assignment_expression(
+            Span::default(),
+            AssignmentOperator::Assign,
+            AssignmentTarget::SimpleAssignmentTarget(target),
+            object,
+        );
+        let argument = self.ast.logical_expression(
+            Span::default(),
+            self.identifier_expression(name),
+            LogicalOperator::Or,
+            self.ast.parenthesized_expression(Span::default(), assignment),
+        );
+
+        let call = self.ast.call_expression(
+            Span::default(),
+            callee,
+            self.ast.new_vec_single(Argument::Expression(argument)),
+            false,
+            None,
+        );
+        Some(self.ast.expression_statement(span, call))
+    }
+
+    /// Assigns the exported declarations of a namespace to the namespace object `_N`.
+    ///
+    /// * `export const a = 1, { b } = c;` -> `const a = _N.a = 1, { b } = c; _N.b = b;`
+    /// * `export function f() {}` -> `function f() {} _N.f = f;`
+    fn push_exported_declaration(
+        &self,
+        stmts: &mut Vec<'a, Statement<'a>>,
+        mut declaration: Declaration<'a>,
+        parent: &Atom,
+    ) {
+        let mut names = vec![];
+        match &mut declaration {
+            Declaration::VariableDeclaration(decl) => {
+                for declarator in decl.declarations.iter_mut() {
+                    match (&declarator.id.kind, &mut declarator.init) {
+                        (BindingPatternKind::BindingIdentifier(ident), Some(init)) => {
+                            let value = self.ast.move_expression(init);
+                            *init = self.assign_member(parent.clone(), ident.name.clone(), value);
+                        }
+                        _ => declarator.id.bound_names(&mut |ident| names.push(ident.name.clone())),
+                    }
+                }
+            }
+            Declaration::FunctionDeclaration(func) => {
+                names.extend(func.id.as_ref().map(|id| id.name.clone()));
+            }
+            Declaration::ClassDeclaration(class) => {
+                names.extend(class.id.as_ref().map(|id| id.name.clone()));
+            }
+            _ => {}
+        }
+        stmts.push(Statement::Declaration(declaration));
+        for name in names {
+            let value = self.identifier_expression(name.clone());
+            let assignment = self.assign_member(parent.clone(), name, value);
+            stmts.push(self.ast.expression_statement(Span::default(), assignment));
+        }
+    }
+
+    /// `import A = B.C` -> `var A = B.C`, and `export import A = B.C` -> `var A = _N.A = B.C` in a
+    /// namespace `_N`.
+    fn alias(
+        &self,
+        decl: Box<'a, TSImportEqualsDeclaration<'a>>,
+        parent: Option<&Atom>,
+    ) -> Statement<'a> {
+        let decl = decl.unbox();
+        let TSModuleReference::TypeName(type_name) = decl.module_reference.unbox() else {
+            unreachable!()
+        };
+        let mut init = self.type_name_to_expression(type_name);
+        if let Some(parent) = parent {
+            init = self.assign_member(parent.clone(), decl.id.name.clone(), init);
+        }
+        let id =
+            self.ast.binding_pattern(self.ast.binding_pattern_identifier(decl.id), None, false);
+        let declarator = self.ast.variable_declarator(
+            Span::default(),
+            VariableDeclarationKind::Var,
+            id,
+            Some(init),
+            false,
+        );
+        let declaration = self.ast.variable_declaration(
+            decl.span,
+            VariableDeclarationKind::Var,
+            self.ast.new_vec_single(declarator),
+            Modifiers::empty(),
+        );
+        Statement::Declaration(Declaration::VariableDeclaration(declaration))
+    }
+
+    /// `A.B.C` -> `A.B.C`
+    fn type_name_to_expression(&self, type_name: TSTypeName<'a>) -> Expression<'a> {
+        match type_name {
+            TSTypeName::IdentifierReference(ident) => {
+                self.ast.identifier_reference_expression(ident.unbox())
+            }
+            TSTypeName::QualifiedName(name) => {
+                let name = name.unbox();
+                let object = self.type_name_to_expression(name.left);
+                self.ast.static_member_expression(name.span, object, name.right, false)
+            }
+        }
+    }
+
+    fn let_declaration(&self, name: Atom) -> Declaration<'a> {
+        let id = self.ast.binding_pattern(
+            self.ast.binding_pattern_identifier(BindingIdentifier::new(Span::default(), name)),
+            None,
+            false,
+        );
+        let declarator = self.ast.variable_declarator(
+            Span::default(),
+            VariableDeclarationKind::Let,
+            id,
+            None,
+            false,
+        );
+        Declaration::VariableDeclaration(self.ast.variable_declaration(
+            Span::default(),
+            VariableDeclarationKind::Let,
+            self.ast.new_vec_single(declarator),
+            Modifiers::empty(),
+        ))
+    }
+
+    fn identifier_expression(&self, name: Atom) -> Expression<'a> {
+        self.ast.identifier_reference_expression(IdentifierReference::new(Span::default(), name))
+    }
+
+    /// `object.property`
+    fn member_expression(&self, object: Atom, property: Atom) -> Expression<'a> {
+        let object = self.identifier_expression(object);
+        let property = IdentifierName::new(Span::default(), property);
+        self.ast.static_member_expression(Span::default(), object, property, false)
+    }
+
+    /// `object.property = value`
+    fn assign_member(&self, object: Atom, property: Atom, value: Expression<'a>) -> Expression<'a> {
+        let object = self.identifier_expression(object);
+        let property = IdentifierName::new(Span::default(), property);
+        let member = self.ast.static_member(Span::default(), object, property, false);
+        let target = self.ast.simple_assignment_target_member_expression(member);
+        self.ast.assignment_expression(
+            Span::default(),
+            AssignmentOperator::Assign,
+            AssignmentTarget::SimpleAssignmentTarget(target),
+            value,
+        )
+    }
+}
+
+/// Rewrites the references to the exported variables of a namespace block, and the unresolved
+/// references to the members exported by the other blocks of the namespace, into members of the
+/// namespace object as tsc does, e.g. `x++` -> `_N.x++`.
+struct MemberReferences<'a, 'b> {
+    typescript: &'b TypeScript<'a>,
+    namespace: &'b Atom,
+    variables: FxHashSet<SymbolId>,
+    members: &'b FxHashSet<Atom>,
+}
+
+impl<'a, 'b> MemberReferences<'a, 'b> {
+    fn is_member(&self, ident: &IdentifierReference) -> bool {
+        // The references created by the transforms, e.g. `_N.a = a`, have no reference ids.
+        let Some(reference_id) = ident.reference_id.get() else { return false };
+        self.typescript.ctx.symbols().get_reference(reference_id).symbol_id().map_or_else(
+            || self.members.contains(&ident.name),
+            |symbol_id| self.variables.contains(&symbol_id),
+        )
+    }
+
+    fn member_target(&self, name: Atom) -> SimpleAssignmentTarget<'a> {
+        let object = self.typescript.identifier_expression(self.namespace.clone());
+        let property = IdentifierName::new(Span::default(), name);
+        let member = self.typescript.ast.static_member(Span::default(), object, property, false);
+        self.typescript.ast.simple_assignment_target_member_expression(member)
+    }
+}
+
+impl<'a, 'b> VisitMut<'a> for MemberReferences<'a, 'b> {
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        match expr {
+            Expression::Identifier(ident) if self.is_member(ident) => {
+                *expr =
+                    self.typescript.member_expression(self.namespace.clone(), ident.name.clone());
+            }
+            _ => self.visit_expression_match(expr),
+        }
+    }
+
+    fn visit_simple_assignment_target(&mut self, target: &mut SimpleAssignmentTarget<'a>) {
+        match target {
+            SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) => {
+                if self.is_member(ident) {
+                    *target = self.member_target(ident.name.clone());
+                }
+            }
+            SimpleAssignmentTarget::MemberAssignmentTarget(expr) => {
+                self.visit_member_expression(expr);
+            }
+            SimpleAssignmentTarget::TSAsExpression(expr) => {
+                self.visit_expression(&mut expr.expression);
+            }
+            SimpleAssignmentTarget::TSSatisfiesExpression(expr) => {
+                self.visit_expression(&mut expr.expression);
+            }
+            SimpleAssignmentTarget::TSNonNullExpression(expr) => {
+                self.visit_expression(&mut expr.expression);
+            }
+            SimpleAssignmentTarget::TSTypeAssertion(expr) => {
+                self.visit_expression(&mut expr.expression);
+            }
+        }
+    }
+
+    /// `{ x }` -> `{ x: _N.x }`
+    fn visit_object_property(&mut self, prop: &mut ObjectProperty<'a>) {
+        if matches!(&prop.value, Expression::Identifier(ident) if self.is_member(ident)) {
+            prop.shorthand = false;
+        }
+        self.visit_property_key(&mut prop.key);
+        self.visit_expression(&mut prop.value);
+        if let Some(init) = &mut prop.init {
+            self.visit_expression(init);
+        }
+    }
+
+    /// `({ x } = o)` -> `({ x: _N.x } = o)`
+    fn visit_assignment_target_property(&mut self, property: &mut AssignmentTargetProperty<'a>) {
+        if let AssignmentTargetProperty::AssignmentTargetPropertyIdentifier(ident) = property {
+            if self.is_member(&ident.binding) {
+                let span = ident.span;
+                let name = ident.binding.name.clone();
+                let key = PropertyKey::Identifier(
+                    self.typescript.ast.alloc(IdentifierName::new(span, name.clone())),
+                );
+                let target = AssignmentTarget::SimpleAssignmentTarget(self.member_target(name));
+                let binding = match ident.init.take() {
+                    Some(init) => AssignmentTargetMaybeDefault::AssignmentTargetWithDefault(
+                        self.typescript.ast.alloc(AssignmentTargetWithDefault {
+                            span,
+                            binding: target,
+                            init,
+                        }),
+                    ),
+                    None => AssignmentTargetMaybeDefault::AssignmentTarget(target),
+                };
+                *property = AssignmentTargetProperty::AssignmentTargetPropertyProperty(
+                    self.typescript.ast.alloc(AssignmentTargetPropertyProperty {
+                        span,
+                        name: key,
+                        binding,
+                    }),
+                );
+            }
+        }
+        match property {
+            AssignmentTargetProperty::AssignmentTargetPropertyIdentifier(ident) => {
+                self.visit_assignment_target_property_identifier(ident);
+            }
+            AssignmentTargetProperty::AssignmentTargetPropertyProperty(prop) => {
+                self.visit_assignment_target_property_property(prop);
+            }
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::{options::TransformOptions, tester::Tester};
+
+    Tester::new("test.ts", TransformOptions::default()).test(&[
+        (
+            "namespace N { export const x = 1; const y: number = 2; }",
+            "let N; (function (_N) { const x = _N.x = 1; const y = 2; })(N || (N = {}));",
+        ),
+        (
+            "namespace A.B { export function f() {} export class C {} }",
+            "
+let A;
+(function (_A) {
+  let B;
+  (function (_B) { function f() {} _B.f = f; class C {} _B.C = C; })(B || (B = _A.B || (_A.B = {})));
+})(A || (A = {}));
+",
+        ),
+        (
+            "namespace A { namespace B { export const b = 1; } }",
+            "let A; (function (_A) { let B; (function (_B) { const b = _B.b = 1; })(B || (B = {})); })(A || (A = {}));",
+        ),
+        (
+            "namespace N { export const a = 1; }\nnamespace N { export const b = 2; }",
+            "let N; (function (_N) { const a = _N.a = 1; })(N || (N = {})); (function (_N2) { const b = _N2.b = 2; })(N || (N = {}));",
+        ),
+        (
+            "class C {}\nnamespace C { export const { a, b: [c] } = o; }",
+            "class C {} (function (_C) { const { a, b: [c] } = o; _C.a = a; _C.c = c; })(C || (C = {}));",
+        ),
+        (
+            "export namespace N { export import X = M.Y; import Z = M.Z; }",
+            "export let N; (function (_N) { var X = _N.X = M.Y; var Z = M.Z; })(N || (N = {}));",
+        ),
+        (
+            "namespace N { export const a = 1; }\nnamespace N { export const b = a; }",
+            "let N; (function (_N) { const a = _N.a = 1; })(N || (N = {})); (function (_N2) { const b = _N2.b = _N2.a; })(N || (N = {}));",
+        ),
+        (
+            "namespace N { export let x = 1; export function inc() { x++; ({ x } = { x }); } }",
+            "let N; (function (_N) { let x = _N.x = 1; function inc() { _N.x++; ({ x: _N.x } = { x: _N.x }); } _N.inc = inc; })(N || (N = {}));",
+        ),
+        ("import type A = B.C; import D = E.F;", "var D = E.F;"),
+        ("namespace T { export interface I {} export type U = I; }", ""),
+        ("declare namespace D { const d: number; }", ""),
+    ]);
+}
@@ -2,7 +2,7 @@ use std::mem;
 
 use oxc_allocator::Vec;
 use oxc_ast::ast::*;
-use oxc_span::Span;
+use oxc_span::{Atom, Span};
 
 use crate::context::TransformerCtx;
 
@@ -24,8 +24,29 @@ pub trait CreateVars<'a> {
         stmts.insert(0, stmt);
     }
 
+    /// Sets aside the variables of the enclosing scope, so the ones created in a function are
+    /// declared in its body. Returns them to be restored by `leave_function_scope`.
+    fn enter_function_scope(&mut self) -> Vec<'a, VariableDeclarator<'a>> {
+        let new_vec = self.ctx().ast.new_vec();
+        mem::replace(self.vars_mut(), new_vec)
+    }
+
+    fn leave_function_scope(&mut self, vars: Vec<'a, VariableDeclarator<'a>>) {
+        *self.vars_mut() = vars;
+    }
+
     fn create_new_var(&mut self, expr: &Expression<'a>) -> IdentifierReference {
         let name = self.ctx().scopes().generate_uid_based_on_node(expr);
+        self.create_var_with_name(name)
+    }
+
+    /// Create `var _name` with an unique name derived from `name`.
+    fn create_new_named_var(&mut self, name: &str) -> IdentifierReference {
+        let name = self.ctx().scopes().generate_uid(name);
+        self.create_var_with_name(name)
+    }
+
+    fn create_var_with_name(&mut self, name: Atom) -> IdentifierReference {
         self.ctx().add_binding(name.clone());
 
         // Add `var name` to scope
@@ -50,3 +71,21 @@ pub trait CreateVars<'a> {
         }
     }
 }
+
+/// FNV-1a in base 36, for the ids which must be stable between the builds of a file.
+pub fn short_hash(text: &str) -> String {
+    let mut hash: u32 = 0x811c_9dc5;
+    for byte in text.bytes() {
+        hash ^= u32::from(byte);
+        hash = hash.wrapping_mul(0x0100_0193);
+    }
+    let mut digits = vec![];
+    loop {
+        digits.push(std::char::from_digit(hash % 36, 36).unwrap_or('0'));
+        hash /= 36;
+        if hash == 0 {
+            break;
+        }
+    }
+    digits.iter().rev().collect()
+}
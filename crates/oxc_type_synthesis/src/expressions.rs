@@ -197,6 +197,13 @@ pub(crate) fn synthesize_expression<T: FSResolver>(
             );
             return TypeId::ERROR_TYPE;
         }
+        ast::Expression::V8IntrinsicExpression(item) => {
+            checking_data.raise_unimplemented_error(
+                "v8 intrinsic expression",
+                oxc_span_to_source_map_span(item.span),
+            );
+            return TypeId::ERROR_TYPE;
+        }
         ast::Expression::LogicalExpression(item) => {
             checking_data.raise_unimplemented_error(
                 "logical expression",
@@ -73,6 +73,9 @@ impl OxcRunOptions {
 pub struct OxcParserOptions {
     #[wasm_bindgen(js_name = allowReturnOutsideFunction)]
     pub allow_return_outside_function: bool,
+
+    /// Serialize the AST in the ESTree shape
+    pub estree: bool,
 }
 
 #[wasm_bindgen]
@@ -70,7 +70,7 @@ export async function activate(context: ExtensionContext) {
   const traceOutputChannel = window.createOutputChannel(traceOutputChannelName);
 
   const command = process.env.NODE_ENV === 'production' 
-                ? join(context.extensionPath, './target/release/oxc_vscode') 
+                ? join(context.extensionPath, './target/release/oxc_language_server') 
                 : process.env.SERVER_PATH_DEV ;
 
   window.showInformationMessage(`oxc server path: ${command}`);
@@ -101,9 +101,12 @@ export async function activate(context: ExtensionContext) {
     ].map(lang => ({ language: lang, scheme: "file" })),
 
     synchronize: {
-      // Notify the server about file changes to '.clientrc files contained in the workspace
-      fileEvents: workspace.createFileSystemWatcher("**/.clientrc"),
+      // Notify the server about the changes of the settings and of the configuration files,
+      // the linter is reloaded then.
+      configurationSection: "oxc",
+      fileEvents: workspace.createFileSystemWatcher("**/{.oxlintrc.json,.eslintrc,.eslintrc.*,.oxc/plugins/**}"),
     },
+    initializationOptions: workspace.getConfiguration("oxc"),
     outputChannel,
     traceOutputChannel,
   };
@@ -0,0 +1,78 @@
+// The subset of Jest's `expect` used by Babel's `exec.js` tests.
+// The helpers are not provided, the tests need the transforms to use `babelHelpers` or none.
+"use strict";
+
+globalThis.expect = (function () {
+  function format(value) {
+    try {
+      return typeof value === "string" ? JSON.stringify(value) : String(value);
+    } catch {
+      return Object.prototype.toString.call(value);
+    }
+  }
+
+  function equals(a, b) {
+    if (Object.is(a, b)) return true;
+    if (typeof a !== "object" || typeof b !== "object" || a === null || b === null) {
+      return false;
+    }
+    if (Array.isArray(a) !== Array.isArray(b)) return false;
+    const keys = Object.keys(a);
+    if (keys.length !== Object.keys(b).length) return false;
+    return keys.every((key) => Object.prototype.hasOwnProperty.call(b, key) && equals(a[key], b[key]));
+  }
+
+  function matchers(actual, negated) {
+    function check(pass, message) {
+      if (pass === negated) {
+        throw new Error(`expect(${format(actual)})${negated ? ".not" : ""}.${message}`);
+      }
+    }
+    return {
+      toBe: (expected) => check(Object.is(actual, expected), `toBe(${format(expected)})`),
+      toEqual: (expected) => check(equals(actual, expected), `toEqual(${format(expected)})`),
+      toStrictEqual: (expected) => check(equals(actual, expected), `toStrictEqual(${format(expected)})`),
+      toBeUndefined: () => check(actual === undefined, "toBeUndefined()"),
+      toBeDefined: () => check(actual !== undefined, "toBeDefined()"),
+      toBeNull: () => check(actual === null, "toBeNull()"),
+      toBeTruthy: () => check(!!actual, "toBeTruthy()"),
+      toBeFalsy: () => check(!actual, "toBeFalsy()"),
+      toBeNaN: () => check(Number.isNaN(actual), "toBeNaN()"),
+      toBeGreaterThan: (n) => check(actual > n, `toBeGreaterThan(${n})`),
+      toBeLessThan: (n) => check(actual < n, `toBeLessThan(${n})`),
+      toBeInstanceOf: (type) => check(actual instanceof type, `toBeInstanceOf(${type.name})`),
+      toHaveLength: (n) => check(actual != null && actual.length === n, `toHaveLength(${n})`),
+      toContain: (item) => check(actual.includes(item), `toContain(${format(item)})`),
+      toMatch: (pattern) =>
+        check(
+          typeof pattern === "string" ? actual.includes(pattern) : pattern.test(actual),
+          `toMatch(${format(pattern)})`,
+        ),
+      toHaveProperty: (key) => check(actual != null && key in Object(actual), `toHaveProperty(${format(key)})`),
+      toThrow: (expected) => {
+        let error;
+        try {
+          actual();
+        } catch (e) {
+          error = e;
+        }
+        let pass = error !== undefined;
+        if (pass && expected !== undefined) {
+          const message = String(error && error.message);
+          if (typeof expected === "string") pass = message.includes(expected);
+          else if (expected instanceof RegExp) pass = expected.test(message);
+          else if (typeof expected === "function") pass = error instanceof expected;
+        }
+        check(pass, `toThrow(${expected === undefined ? "" : format(expected)})`);
+      },
+    };
+  }
+
+  return (actual) => {
+    const expect = matchers(actual, false);
+    expect.not = matchers(actual, true);
+    expect.toThrowError = expect.toThrow;
+    expect.not.toThrowError = expect.not.toThrow;
+    return expect;
+  };
+})();
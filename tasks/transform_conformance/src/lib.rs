@@ -1,10 +1,12 @@
 use serde::de::DeserializeOwned;
 use serde_json::Value;
+use similar::{ChangeTag, TextDiff};
 use std::{
     cell::RefCell,
     fs::{self, File},
     io::Write,
-    path::PathBuf,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
     rc::Rc,
 };
 use walkdir::WalkDir;
@@ -16,8 +18,10 @@ use oxc_semantic::SemanticBuilder;
 use oxc_span::{SourceType, VALID_EXTENSIONS};
 use oxc_tasks_common::{normalize_path, project_root, BabelOptions};
 use oxc_transformer::{
-    NullishCoalescingOperatorOptions, ReactJsxOptions, TransformOptions, TransformTarget,
-    Transformer,
+    ClassPropertiesOptions, ComputedPropertiesOptions, DecoratorsOptions, DecoratorsVersion,
+    EngineTargets, HelperLoaderMode, JsxMode, ModuleFormat, NullishCoalescingOperatorOptions,
+    OptionalChainingOptions, PreserveComments, ReactJsxOptions, SpreadOptions, TransformOptions,
+    TransformTarget, Transformer, TypeScriptOptions,
 };
 
 #[test]
@@ -29,8 +33,15 @@ fn test() {
 #[derive(Default)]
 pub struct TestRunnerOptions {
     pub filter: Option<String>,
+    /// Print the diff of the transformed code and the expected output of the failed tests.
+    pub diff: bool,
+    /// Run the `exec.js` tests with Node.js, instead of comparing the `input.js` and `output.js`.
+    pub exec: bool,
 }
 
+/// The globals of Babel's `exec.js` tests, a subset of Jest's `expect`.
+const EXEC_PRELUDE: &str = include_str!("exec_prelude.js");
+
 /// The test runner which walks the babel repository and searches for transformation tests.
 pub struct TestRunner {
     options: TestRunnerOptions,
@@ -41,6 +52,8 @@ fn root() -> PathBuf {
 }
 
 const CASES: &[&str] = &[
+    // ES2025
+    // [Regex] "babel-plugin-transform-duplicate-named-capturing-groups-regex",
     // ES2024
     "babel-plugin-transform-unicode-sets-regex",
     // ES2022
@@ -75,9 +88,17 @@ const CASES: &[&str] = &[
     // ES2016
     "babel-plugin-transform-exponentiation-operator",
     // ES2015
+    "babel-plugin-transform-block-scoping",
+    "babel-plugin-transform-computed-properties",
+    "babel-plugin-transform-destructuring",
+    "babel-plugin-transform-literals",
+    "babel-plugin-transform-regenerator",
     "babel-plugin-transform-shorthand-properties",
+    "babel-plugin-transform-spread",
     "babel-plugin-transform-sticky-regex",
     "babel-plugin-transform-unicode-regex",
+    // Proposals
+    "babel-plugin-proposal-decorators",
     // TypeScript
     "babel-plugin-transform-typescript",
     // React
@@ -97,13 +118,14 @@ impl TestRunner {
         let mut all_passed = vec![];
         let mut all_passed_count = 0;
 
+        let test_file_stem = if self.options.exec { "exec" } else { "input" };
         for case in CASES {
             let root = root.join(case).join("test/fixtures");
             let mut cases = WalkDir::new(&root)
                 .into_iter()
                 .filter_map(Result::ok)
                 .filter(|e| {
-                    e.path().file_stem().is_some_and(|name| name == "input")
+                    e.path().file_stem().is_some_and(|name| name == test_file_stem)
                         && e.path()
                             .extension()
                             .is_some_and(|ext| VALID_EXTENSIONS.contains(&ext.to_str().unwrap()))
@@ -118,7 +140,7 @@ impl TestRunner {
 
             // Run the test
             let (passed, failed): (Vec<TestCase>, Vec<TestCase>) =
-                cases.into_iter().partition(|case| case.test(self.options.filter.as_deref()));
+                cases.into_iter().partition(|case| case.test(&self.options));
             let passed = passed.into_iter().map(|case| case.path).collect::<Vec<_>>();
             let failed = failed.into_iter().map(|case| case.path).collect::<Vec<_>>();
 
@@ -146,7 +168,8 @@ impl TestRunner {
             let snapshot = format!(
                 "Passed: {all_passed_count}/{total}\n\n# All Passed:\n{all_passed}\n\n\n{snapshot}"
             );
-            let path = project_root().join("tasks/transform_conformance/babel.snap.md");
+            let name = if self.options.exec { "babel_exec.snap.md" } else { "babel.snap.md" };
+            let path = project_root().join("tasks/transform_conformance").join(name);
             let mut file = File::create(path).unwrap();
             file.write_all(snapshot.as_bytes()).unwrap();
         }
@@ -173,23 +196,77 @@ impl TestCase {
         let options = &self.options;
         TransformOptions {
             target: TransformTarget::ESNext,
-            react_jsx: Some(ReactJsxOptions::default()),
+            targets: EngineTargets::default(),
+            jsx: JsxMode::default(),
+            react_jsx: options
+                .get_plugin("transform-react-jsx")
+                .map(get_options::<ReactJsxOptions>),
+            typescript: TypeScriptOptions {
+                verbatim_module_syntax: options
+                    .get_plugin("transform-typescript")
+                    .flatten()
+                    .and_then(|v| {
+                        v.get("onlyRemoveTypeImports").and_then(serde_json::Value::as_bool)
+                    })
+                    .unwrap_or_default(),
+                ..TypeScriptOptions::default()
+            },
+            styled_components: None,
+            emotion: None,
+            // Babel's legacy decorators are lowered differently from `tsc`
+            decorators: options
+                .get_plugin("proposal-decorators")
+                .flatten()
+                .and_then(|v| serde_json::from_value::<DecoratorsOptions>(v).ok())
+                .filter(|options| options.version != DecoratorsVersion::Legacy),
             assumptions: options.assumptions,
+            helpers: HelperLoaderMode::default(),
+            module: ModuleFormat::default(),
+            polyfills: false,
+            define: vec![],
+            modular_imports: vec![],
+            preserve_comments: PreserveComments::default(),
+            source_file_name: None,
+            overrides: vec![],
+            explicit_resource_management: options
+                .get_plugin("proposal-explicit-resource-management")
+                .is_some(),
+            dynamic_import_function: None,
+            export_namespace_from: options.get_plugin("transform-export-namespace-from").is_some(),
+            class_properties: options
+                .get_plugin("transform-class-properties")
+                .map(get_options::<ClassPropertiesOptions>),
             class_static_block: options.get_plugin("transform-class-static-block").is_some(),
+            private_methods: options.get_plugin("transform-private-methods").is_some(),
             logical_assignment_operators: options
                 .get_plugin("transform-logical-assignment-operators")
                 .is_some(),
+            numeric_separator: options.get_plugin("transform-numeric-separator").is_some(),
             nullish_coalescing_operator: self
                 .options
                 .get_plugin("transform-nullish-coalescing-operator")
                 .map(get_options::<NullishCoalescingOperatorOptions>),
+            optional_chaining: options
+                .get_plugin("transform-optional-chaining")
+                .map(get_options::<OptionalChainingOptions>),
             optional_catch_binding: options
                 .get_plugin("transform-optional-catch-binding")
                 .is_some(),
+            async_generator_functions: options
+                .get_plugin("transform-async-generator-functions")
+                .is_some(),
             exponentiation_operator: options
                 .get_plugin("transform-exponentiation-operator")
                 .is_some(),
+            block_scoping: options.get_plugin("transform-block-scoping").is_some(),
+            computed_properties: options
+                .get_plugin("transform-computed-properties")
+                .map(get_options::<ComputedPropertiesOptions>),
+            destructuring: options.get_plugin("transform-destructuring").is_some(),
+            literals: options.get_plugin("transform-literals").is_some(),
+            regenerator: options.get_plugin("transform-regenerator").is_some(),
             shorthand_properties: options.get_plugin("transform-shorthand-properties").is_some(),
+            spread: options.get_plugin("transform-spread").map(get_options::<SpreadOptions>),
             sticky_regex: options.get_plugin("transform-sticky-regex").is_some(),
         }
     }
@@ -210,10 +287,32 @@ impl TestCase {
         false
     }
 
-    /// Test conformance by comparing the parsed babel code and transformed code.
-    fn test(&self, filter: Option<&str>) -> bool {
-        let filtered = filter.is_some_and(|f| self.path.to_string_lossy().as_ref().contains(f));
+    fn transform(&self, source_text: &str, source_type: SourceType) -> String {
+        let allocator = Allocator::default();
+        let program = Parser::new(&allocator, source_text, source_type).parse().program;
+        let semantic = SemanticBuilder::new(source_text, source_type).build(&program).semantic;
+        let (symbols, scopes) = semantic.into_symbol_table_and_scope_tree();
+        let symbols = Rc::new(RefCell::new(symbols));
+        let scopes = Rc::new(RefCell::new(scopes));
+        let program = allocator.alloc(program);
+        Transformer::new(&allocator, source_type, &symbols, &scopes, self.transform_options())
+            .with_source(&self.path, source_text)
+            .build(program);
+        Codegen::<false>::new(source_text.len(), CodegenOptions).build(program)
+    }
+
+    fn test(&self, options: &TestRunnerOptions) -> bool {
+        let filtered =
+            options.filter.as_deref().is_some_and(|f| self.path.to_string_lossy().contains(f));
+        if options.exec {
+            self.test_exec(filtered)
+        } else {
+            self.test_output(filtered, options.diff)
+        }
+    }
 
+    /// Test conformance by comparing the parsed babel code and transformed code.
+    fn test_output(&self, filtered: bool, diff: bool) -> bool {
         let output_path = self.path.parent().unwrap().read_dir().unwrap().find_map(|entry| {
             let path = entry.ok()?.path();
             let file_stem = path.file_stem()?;
@@ -229,16 +328,7 @@ impl TestCase {
             println!("output_path: {output_path:?}");
         }
 
-        // Transform input.js
-        let program = Parser::new(&allocator, &input, source_type).parse().program;
-        let semantic = SemanticBuilder::new(&input, source_type).build(&program).semantic;
-        let (symbols, scopes) = semantic.into_symbol_table_and_scope_tree();
-        let symbols = Rc::new(RefCell::new(symbols));
-        let scopes = Rc::new(RefCell::new(scopes));
-        let program = allocator.alloc(program);
-        Transformer::new(&allocator, source_type, &symbols, &scopes, self.transform_options())
-            .build(program);
-        let transformed_code = Codegen::<false>::new(input.len(), CodegenOptions).build(program);
+        let transformed_code = self.transform(&input, source_type);
 
         // Get output.js by using our codeg so code comparison can match.
         let output = output_path.and_then(|path| fs::read_to_string(path).ok()).map_or_else(
@@ -264,6 +354,66 @@ impl TestCase {
             println!("{transformed_code}\n");
             println!("Passed: {passed}");
         }
+        if diff && !passed {
+            println!("Mismatch: {}", normalize_path(&self.path));
+            print_diff(&output, &transformed_code);
+        }
         passed
     }
+
+    /// Run the transformed `exec.js` with Node.js, its `expect` assertions throw on failure.
+    fn test_exec(&self, filtered: bool) -> bool {
+        let input = fs::read_to_string(&self.path).unwrap();
+        let source_type = SourceType::from_path(&self.path).unwrap();
+        let transformed_code = self.transform(&input, source_type);
+        let result = run_node(&self.path, &transformed_code);
+        if filtered {
+            println!("exec_path: {:?}", &self.path);
+            println!("Transformed:\n");
+            println!("{transformed_code}\n");
+        }
+        if let Err(error) = &result {
+            if filtered {
+                println!("Error:\n");
+                println!("{error}\n");
+            }
+        }
+        result.is_ok()
+    }
+}
+
+/// Run the code after the [EXEC_PRELUDE], the error output is returned if it throws.
+fn run_node(path: &Path, code: &str) -> Result<(), String> {
+    let is_module = path.extension().is_some_and(|ext| ext == "mjs");
+    let mut child = Command::new("node")
+        .arg(if is_module { "--input-type=module" } else { "--input-type=commonjs" })
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|error| format!("Failed to run node: {error}"))?;
+    let mut stdin = child.stdin.take().unwrap();
+    stdin.write_all(EXEC_PRELUDE.as_bytes()).map_err(|error| error.to_string())?;
+    stdin.write_all(code.as_bytes()).map_err(|error| error.to_string())?;
+    drop(stdin);
+    let output = child.wait_with_output().map_err(|error| error.to_string())?;
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(String::from_utf8_lossy(&output.stderr).into_owned())
+    }
+}
+
+/// Print the lines of `expected` missing from `actual` with `-`, the extra ones with `+`.
+fn print_diff(expected: &str, actual: &str) {
+    let diff = TextDiff::from_lines(expected, actual);
+    for change in diff.iter_all_changes() {
+        let sign = match change.tag() {
+            ChangeTag::Delete => "-",
+            ChangeTag::Insert => "+",
+            ChangeTag::Equal => continue,
+        };
+        print!("{sign}{change}");
+    }
+    println!();
 }
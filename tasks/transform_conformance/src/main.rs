@@ -12,7 +12,11 @@ use pico_args::Arguments;
 fn main() {
     let mut args = Arguments::from_env();
 
-    let options = TestRunnerOptions { filter: args.opt_value_from_str("--filter").unwrap() };
+    let options = TestRunnerOptions {
+        filter: args.opt_value_from_str("--filter").unwrap(),
+        diff: args.contains("--diff"),
+        exec: args.contains("--exec"),
+    };
 
     TestRunner::new(options).run();
 }